@@ -0,0 +1,207 @@
+extern crate pinyin;
+
+use pinyin::{ syllables, Syllable, Show, ToneFormat, TONE_TABLE };
+
+use std::alloc::{ GlobalAlloc, Layout, System };
+use std::fmt;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+use std::time::{ Duration, Instant };
+
+
+// 统计堆内存分配次数
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+
+const ROUNDS: usize = 200;
+
+
+// 作为对比的实现方式: 支持 `no_std` 之前的格式化代码 ( 原样复制 )，
+// 每次格式化都要先生成 `String` 形式的拼写，调号和数字也通过 `write!` 写入。
+mod before {
+    use pinyin::{ Syllable, SyllableKind, PrimitiveSyllable, Tone, ToneMark, ToneFormat };
+
+    use std::fmt;
+
+
+    // 上标数字: ⁰¹²³⁴⁵⁶⁷⁸⁹
+    const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+    // 整体认读音节表
+    const PRIMITIVE_SYLLABLE_TABLE: [[char; 4]; 16] = [
+        ['z', 'h', 'i', ' '], ['c', 'h', 'i', ' '], ['s', 'h', 'i', ' '], ['r', 'i', ' ', ' '],
+        ['z', 'i', ' ', ' '], ['c', 'i', ' ', ' '], ['s', 'i', ' ', ' '], ['y', 'i', ' ', ' '],
+        ['w', 'u', ' ', ' '], ['y', 'u', ' ', ' '], ['y', 'e', ' ', ' '], ['y', 'u', 'e', ' '],
+        ['y', 'u', 'a', 'n'], ['y', 'i', 'n', ' '], ['y', 'u', 'n', ' '], ['y', 'i', 'n', 'g'],
+    ];
+
+
+    // NOTE: 整体认读音节的拼写是私有字段，这里通过公开的构造函数找到对应的记录
+    fn primitive_spelling(syllable: &Syllable) -> String {
+        let primitive = PRIMITIVE_SYLLABLE_TABLE.iter()
+                            .find(|chars| PrimitiveSyllable::new(**chars, syllable.tone()).map(Syllable::from).ok() == Some(*syllable))
+                            .expect("整体认读音节必须来自整体认读音节表！");
+
+        primitive.iter()
+            .filter(|c| **c != ' ')
+            .collect::<String>()
+    }
+
+    fn normal_spelling(syllable: &Syllable) -> String {
+        let initial = syllable.initial().expect("常规音节必须包含声母！");
+        let rhyme = syllable.finals().expect("常规音节必须包含韵母！").to_string();
+        let is_jqx = initial.to_string() == "j"
+            || initial.to_string() == "q"
+            || initial.to_string() == "x";
+
+        let finals = match rhyme.as_str() {
+            "iou" => "iu".to_string(),
+            "uei" => "ui".to_string(),
+            "uen" => "un".to_string(),
+            "ü" | "üe" | "üan" | "ün" if is_jqx => rhyme.replace('ü', "u"),
+            _ => rhyme,
+        };
+
+        format!("{}{}", initial, finals)
+    }
+
+    fn rhyme_spelling(syllable: &Syllable) -> String {
+        let rhyme = syllable.finals().expect("自成音节必须包含韵母！").to_string();
+
+        match rhyme.as_str() {
+            "i" | "in" | "ing" => format!("y{}", rhyme),
+            "u" => "wu".to_string(),
+            "ü" | "üe" | "üan" | "ün" => rhyme.replace('ü', "yu"),
+            _ if rhyme.starts_with('i') => rhyme.replacen('i', "y", 1),
+            _ if rhyme.starts_with('u') => rhyme.replacen('u', "w", 1),
+            _ => rhyme,
+        }
+    }
+
+    fn spelling(syllable: &Syllable) -> String {
+        match syllable.kind() {
+            SyllableKind::Primitive => primitive_spelling(syllable),
+            SyllableKind::Normal => normal_spelling(syllable),
+            SyllableKind::Rhyme => rhyme_spelling(syllable),
+            SyllableKind::Nasal => syllable.initial().expect("鼻音音节必须包含声母！").to_string(),
+        }
+    }
+
+    fn tone_mark_position(chars: &[char]) -> usize {
+        if let Some(pos) = chars.iter().position(|c| *c == 'a') {
+            return pos;
+        }
+
+        if let Some(pos) = chars.iter().position(|c| *c == 'o' || *c == 'e' || *c == 'ê') {
+            return pos;
+        }
+
+        chars.iter()
+            .rposition(|c| *c == 'i' || *c == 'u' || *c == 'ü')
+            .unwrap_or(0)
+    }
+
+    fn write_spelling(w: &mut dyn fmt::Write,
+                      spelling: &str,
+                      tone: Tone,
+                      tone_format: ToneFormat) -> fmt::Result {
+        match tone_format {
+            ToneFormat::Symbol => {
+                let chars = spelling.chars().collect::<Vec<char>>();
+                let pos = tone_mark_position(&chars);
+
+                for (i, c) in chars.iter().enumerate() {
+                    if i == pos && tone != Tone::Neutral {
+                        let tone_mark = ToneMark::new(*c, tone).map_err(|_| fmt::Error)?;
+                        write!(w, "{}", tone_mark)?;
+                    } else {
+                        w.write_char(*c)?;
+                    }
+                }
+
+                Ok(())
+            },
+            ToneFormat::Index => {
+                w.write_str(spelling)?;
+
+                if tone != Tone::Neutral {
+                    write!(w, "{}", u8::from(tone))?;
+                }
+
+                Ok(())
+            },
+            ToneFormat::Digit => {
+                w.write_str(spelling)?;
+
+                if let Some(value) = tone.value() {
+                    for digit in value.to_string().chars() {
+                        let n = digit.to_digit(10).expect("调值只包含数字！") as usize;
+                        w.write_char(SUPERSCRIPT_DIGITS[n])?;
+                    }
+                }
+
+                Ok(())
+            },
+            _ => Err(fmt::Error),
+        }
+    }
+
+    pub fn show_to(w: &mut dyn fmt::Write, syllable: &Syllable, tone_format: ToneFormat) -> fmt::Result {
+        write_spelling(w, &spelling(syllable), syllable.tone(), tone_format)
+    }
+}
+
+
+// 格式化完整的带声调音节表 `ROUNDS` 次，返回耗时以及堆内存分配次数
+fn measure<F>(all: &[Syllable], mut show_to: F) -> (Duration, usize)
+    where F: FnMut(&mut String, &Syllable) -> fmt::Result
+{
+    let mut buffer = String::with_capacity(64);
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+
+    for _ in 0..ROUNDS {
+        for syllable in all.iter() {
+            buffer.clear();
+            show_to(&mut buffer, syllable).unwrap();
+        }
+    }
+
+    (start.elapsed(), ALLOCATIONS.load(Ordering::Relaxed) - before)
+}
+
+fn main() {
+    let all = syllables(&TONE_TABLE).collect::<Vec<Syllable>>();
+    let count = ROUNDS * all.len();
+
+    for tone_format in [ToneFormat::Symbol, ToneFormat::Index, ToneFormat::Digit].iter() {
+        // 两种实现的输出必须相同
+        for syllable in all.iter() {
+            let mut s = String::new();
+            before::show_to(&mut s, syllable, *tone_format).unwrap();
+            assert_eq!(s, syllable.show(*tone_format));
+        }
+
+        let (elapsed, allocations) = measure(&all, |w, syllable| syllable.show_to(w, *tone_format));
+        let (baseline, baseline_allocations) = measure(&all, |w, syllable| before::show_to(w, syllable, *tone_format));
+
+        println!("{:?}: {} 个音节, 耗时 {:?} ( 堆内存分配 {} 次 ), 原实现耗时 {:?} ( 堆内存分配 {} 次 )",
+                 tone_format, count, elapsed, allocations, baseline, baseline_allocations);
+        assert_eq!(allocations, 0);
+    }
+}
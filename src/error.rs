@@ -1,8 +1,154 @@
+use core::fmt;
+use core::ops::Deref;
+use core::str;
+#[cfg(feature = "std")]
+use std::error;
 
-#[derive(Debug)]
-pub enum Error {
-    InvalidInitial,
-    InvalidRhyme,
-    InvalidToneMark,
+
+// 错误信息当中最多保存的输入字节数
+const ERROR_TEXT_CAPACITY: usize = 32;
+
+
+/// 定长的错误文本，不需要分配堆内存，超出容量的部分会被截断
+#[derive(PartialEq, Eq, Copy, Clone)]
+pub struct ErrorText {
+    bytes: [u8; ERROR_TEXT_CAPACITY],
+    len: usize,
+}
+
+impl ErrorText {
+    pub fn new<T: fmt::Display>(text: T) -> Self {
+        let mut error_text = ErrorText { bytes: [0u8; ERROR_TEXT_CAPACITY], len: 0 };
+        // NOTE: 写满之后返回的错误代表截断，可以忽略。
+        let _ = fmt::Write::write_fmt(&mut error_text, format_args!("{}", text));
+        error_text
+    }
+
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(&self.bytes[..self.len]).expect("错误文本只会按照字符边界截断！")
+    }
+}
+
+impl fmt::Write for ErrorText {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let len = c.len_utf8();
+            if self.len + len > ERROR_TEXT_CAPACITY {
+                return Err(fmt::Error);
+            }
+
+            c.encode_utf8(&mut self.bytes[self.len..]);
+            self.len += len;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deref for ErrorText {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for ErrorText {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for ErrorText {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+
+/// 错误原因
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ErrorKind {
+    /// 未知的声母
+    InvalidInitial(char),
+    /// 未知的韵母
+    InvalidRhyme(ErrorText),
+    /// 该字母无法标注声调
+    InvalidToneMark(char),
+    /// 无效的声调 ( 未知的声调数字，或者音节不存在该声调 )
     InvalidTone,
-}
\ No newline at end of file
+    /// 同一个音节当中出现了多个声调
+    MultipleToneMarks,
+    /// 声调数字没有紧跟在标注声调的字母之后
+    MisplacedToneNumber,
+    /// 声母和韵母无法拼合
+    IllegalCombination,
+    /// 无法识别的音节
+    InvalidSyllable,
+    /// 无效的音节编号
+    InvalidId,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::ErrorKind::*;
+
+        match *self {
+            InvalidInitial(c) => write!(f, "unknown initial '{}'", c),
+            InvalidRhyme(ref rhyme) => write!(f, "unknown final '{}'", rhyme),
+            InvalidToneMark(c) => write!(f, "'{}' cannot carry a tone mark", c),
+            InvalidTone => write!(f, "invalid tone"),
+            MultipleToneMarks => write!(f, "multiple tone marks"),
+            MisplacedToneNumber => write!(f, "tone number must follow the vowel carrying the tone"),
+            IllegalCombination => write!(f, "illegal initial/final combination"),
+            InvalidSyllable => write!(f, "unknown syllable"),
+            InvalidId => write!(f, "invalid syllable id"),
+        }
+    }
+}
+
+
+/// 错误: 包含出错的输入、出错的字符位置 ( 从 0 开始 ) 以及错误原因
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Error {
+    kind: ErrorKind,
+    input: ErrorText,
+    position: usize,
+}
+
+impl Error {
+    pub fn new<T: fmt::Display>(kind: ErrorKind, input: T, position: usize) -> Self {
+        Error {
+            kind,
+            input: ErrorText::new(input),
+            position,
+        }
+    }
+
+    // 替换出错的输入以及位置 ( 用于把内部的错误对应到用户的原始输入 )
+    pub(crate) fn with_input(self, input: &str, position: usize) -> Self {
+        Error::new(self.kind, input, position)
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// 出错的输入 ( 过长的输入会被截断 )
+    pub fn input(&self) -> &str {
+        self.input.as_str()
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at position {} in \"{}\"", self.kind, self.position, self.input)
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for Error { }
@@ -1,32 +1,617 @@
-use tone::ToneFormat;
-use syllable::{ PrimitiveSyllable, NormalSyllable, RhymeSyllable, NasalSyllable };
+use tone::{ Tone, ToneMark, ToneFormat };
+#[cfg(feature = "alloc")]
+use tone::{ TONE_MARK_TABLE, combining_tone, split_tone_mark };
+#[cfg(feature = "alloc")]
+use segment;
+use romatzyh;
+use syllable::{ Syllable, PrimitiveSyllable, NormalSyllable, RhymeSyllable, NasalSyllable };
+
+use SYLLABLE_DIVIDING_MARK;
+
+use core::fmt;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+
+
+// 上标数字: ⁰¹²³⁴⁵⁶⁷⁸⁹
+pub(crate) const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+// 赵元任五度标调符号，依次为调值 1 至 5: ˩˨˧˦˥
+pub(crate) const CHAO_TONE_LETTERS: [&str; 5] = ["˩", "˨", "˧", "˦", "˥"];
+
+// 第一声至第四声的组合附加符号: ̄ ́ ̌ ̀
+pub(crate) const COMBINING_TONE_MARKS: [char; 4] = ['\u{304}', '\u{301}', '\u{30C}', '\u{300}'];
+
+
+// 把写入的内容收集成字符串 ( 各种 `xxx_to` 方法对应的 `xxx` 方法 )
+#[cfg(feature = "alloc")]
+pub(crate) fn write_to_string<F: FnOnce(&mut dyn fmt::Write) -> fmt::Result>(write: F) -> String {
+    let mut s = String::new();
+    write(&mut s).expect("写入字符串不应该失败！");
+    s
+}
 
 
 pub trait Show {
-    fn show(&self, tone_format: ToneFormat) -> Result<(), ()>;
+    /// 按照指定的音调标记方式写入
+    fn show_to(&self, w: &mut dyn fmt::Write, tone_format: ToneFormat) -> fmt::Result;
+
+    /// 按照指定的音调标记方式输出
+    #[cfg(feature = "alloc")]
+    fn show(&self, tone_format: ToneFormat) -> String {
+        write_to_string(|w| self.show_to(w, tone_format))
+    }
+}
+
+
+// 声调标注位置: 有 `a` 标 `a`，没 `a` 找 `o`、`e`，`i`、`u` 并列标在后，
+// 如果都没有（鼻音音节，以及耶鲁拼音的 `jr`、`sz` 等），则标在最后一个字母上。
+pub(crate) fn tone_mark_position(chars: &[char]) -> usize {
+    if let Some(pos) = chars.iter().position(|c| *c == 'a') {
+        return pos;
+    }
+
+    if let Some(pos) = chars.iter().position(|c| *c == 'o' || *c == 'e' || *c == 'ê') {
+        return pos;
+    }
+
+    chars.iter()
+        .rposition(|c| *c == 'i' || *c == 'u' || *c == 'ü')
+        .unwrap_or(chars.len().saturating_sub(1))
+}
+
+// 按照音调标记方式写入拼写，`position` 为调号标注的位置
+//
+// NOTE: 国语罗马字以拼写区分声调，不能用于任意的拼写，这里只写入不带声调的拼写。
+pub(crate) fn write_toned(w: &mut dyn fmt::Write,
+                          spelling: &[char],
+                          position: usize,
+                          tone: Tone,
+                          tone_format: ToneFormat) -> fmt::Result {
+    match tone_format {
+        ToneFormat::Symbol => {
+            for (i, c) in spelling.iter().enumerate() {
+                if i != position || tone == Tone::Neutral {
+                    w.write_char(*c)?;
+                    continue;
+                }
+
+                match ToneMark::new(*c, tone) {
+                    Ok(tone_mark) => w.write_str(tone_mark.as_str())?,
+                    Err(_) => {
+                        // 没有对应调号的字母 ( 例如耶鲁拼音的 `jr̀` ) 使用组合附加符号
+                        w.write_char(*c)?;
+                        w.write_char(COMBINING_TONE_MARKS[u8::from(tone) as usize - 1])?;
+                    },
+                }
+            }
+
+            Ok(())
+        },
+        ToneFormat::Index => {
+            for c in spelling.iter() {
+                w.write_char(*c)?;
+            }
+
+            if tone != Tone::Neutral {
+                w.write_char((b'0' + u8::from(tone)) as char)?;
+            }
+
+            Ok(())
+        },
+        ToneFormat::Digit | ToneFormat::ToneLetter => {
+            for c in spelling.iter() {
+                w.write_char(*c)?;
+            }
+
+            write_tone_value(w, tone.value(), tone_format)
+        },
+        ToneFormat::GwoyeuRomatzyh => {
+            for c in spelling.iter() {
+                w.write_char(*c)?;
+            }
+
+            Ok(())
+        },
+    }
+}
+
+// 调值的各位数字 ( 214 => 2, 1, 4 )
+fn tone_digits(value: u8) -> impl Iterator<Item = u8> {
+    // 调值最多三位数 ( 214 )
+    let digits = [value / 100, value / 10 % 10, value % 10];
+    let skip = if value >= 100 { 0 } else if value >= 10 { 1 } else { 2 };
+
+    IntoIterator::into_iter(digits).skip(skip)
+}
+
+// 按照调值写入标调符号 ( 依次为调值 1 至 5 )，平调只写一个符号 ( 55 => ˥, 214 => ˨˩˦ )
+pub(crate) fn write_tone_letters(w: &mut dyn fmt::Write, value: Option<u8>, tone_letters: &[&str; 5]) -> fmt::Result {
+    let mut last = None;
+
+    for n in value.into_iter().flat_map(tone_digits) {
+        if last != Some(n) {
+            w.write_str(tone_letters[(n as usize).saturating_sub(1)])?;
+            last = Some(n);
+        }
+    }
+
+    Ok(())
+}
+
+// 使用上标数字或者五度标调符号写入调值 ( ²¹⁴, ˨˩˦ )，轻声没有调值的时候不写
+fn write_tone_value(w: &mut dyn fmt::Write, value: Option<u8>, tone_format: ToneFormat) -> fmt::Result {
+    if tone_format == ToneFormat::ToneLetter {
+        return write_tone_letters(w, value, &CHAO_TONE_LETTERS);
+    }
+
+    for n in value.into_iter().flat_map(tone_digits) {
+        w.write_char(SUPERSCRIPT_DIGITS[n as usize])?;
+    }
+
+    Ok(())
 }
 
+// 按照音调标记方式写入音节
+fn write_syllable(w: &mut dyn fmt::Write,
+                  syllable: &Syllable,
+                  tone_format: ToneFormat) -> fmt::Result {
+    if tone_format == ToneFormat::GwoyeuRomatzyh {
+        return romatzyh::write_syllable(w, syllable);
+    }
+
+    let spelling = syllable.spelling();
+    write_toned(w, &spelling, tone_mark_position(&spelling), syllable.tone(), tone_format)
+}
 
 impl Show for PrimitiveSyllable {
-    fn show(&self, _tone_format: ToneFormat) -> Result<(), ()> {
-        unimplemented!()
+    fn show_to(&self, w: &mut dyn fmt::Write, tone_format: ToneFormat) -> fmt::Result {
+        write_syllable(w, &Syllable::from(*self), tone_format)
     }
 }
 
 impl Show for NormalSyllable {
-    fn show(&self, _tone_format: ToneFormat) -> Result<(), ()> {
-        unimplemented!()
+    fn show_to(&self, w: &mut dyn fmt::Write, tone_format: ToneFormat) -> fmt::Result {
+        write_syllable(w, &Syllable::from(*self), tone_format)
     }
 }
 
 impl Show for RhymeSyllable {
-    fn show(&self, _tone_format: ToneFormat) -> Result<(), ()> {
-        unimplemented!()
+    fn show_to(&self, w: &mut dyn fmt::Write, tone_format: ToneFormat) -> fmt::Result {
+        write_syllable(w, &Syllable::from(*self), tone_format)
     }
 }
 
 impl Show for NasalSyllable {
-    fn show(&self, _tone_format: ToneFormat) -> Result<(), ()> {
-        unimplemented!()
+    fn show_to(&self, w: &mut dyn fmt::Write, tone_format: ToneFormat) -> fmt::Result {
+        write_syllable(w, &Syllable::from(*self), tone_format)
+    }
+}
+
+impl Show for Syllable {
+    fn show_to(&self, w: &mut dyn fmt::Write, tone_format: ToneFormat) -> fmt::Result {
+        write_syllable(w, self, tone_format)
+    }
+}
+
+
+// 判断音节的拼写是否以 `a`、`o`、`e` 开头
+pub(crate) fn starts_with_vowel(syllable: &Syllable) -> bool {
+    matches!(syllable.spelling().first().cloned(), Some('a') | Some('o') | Some('e') | Some('ê'))
+}
+
+// 两个音节之间是否需要隔音符号
+fn needs_separator(prev: &Syllable, next: &Syllable, tone_format: ToneFormat) -> bool {
+    if tone_format == ToneFormat::GwoyeuRomatzyh {
+        romatzyh::is_ambiguous(prev, next)
+    } else {
+        starts_with_vowel(next)
+    }
+}
+
+// 连写音节，必要的时候插入隔音符号，每个音节 ( 以及它的位置 ) 交给 `write` 写入
+fn join_with<F>(w: &mut dyn fmt::Write,
+                syllables: &[Syllable],
+                tone_format: ToneFormat,
+                mut write: F) -> fmt::Result
+    where F: FnMut(&mut dyn fmt::Write, usize, &Syllable) -> fmt::Result {
+    for (i, syllable) in syllables.iter().enumerate() {
+        if i > 0 && needs_separator(&syllables[i - 1], syllable, tone_format) {
+            w.write_char(SYLLABLE_DIVIDING_MARK)?;
+        }
+
+        write(w, i, syllable)?;
+    }
+
+    Ok(())
+}
+
+/// 将多个音节连写成一个单词并写入
+/// 
+/// 按照《汉语拼音方案》的规定，`a`、`o`、`e` 开头的音节连接在其它音节后面的时候，
+/// 使用隔音符号 `'` 隔开 ( pí'ǎo, Xī'ān )。国语罗马字以拼写区分声调，
+/// 连写以后可能被拆分成其它音节的时候使用隔音符号 ( shi'i, 而不是读作 `xǐ` 的 shii )。
+pub fn join_to(w: &mut dyn fmt::Write,
+               syllables: &[Syllable],
+               tone_format: ToneFormat) -> fmt::Result {
+    join_with(w, syllables, tone_format, |w, _i, syllable| syllable.show_to(w, tone_format))
+}
+
+/// 将多个音节连写成一个单词，必要的时候插入隔音符号
+#[cfg(feature = "alloc")]
+pub fn join(syllables: &[Syllable], tone_format: ToneFormat) -> String {
+    write_to_string(|w| join_to(w, syllables, tone_format))
+}
+
+/// 将多个音节连写成一个单词并写入，调值使用连读变调以后的实际调值 ( ni³⁵hao²¹⁴, ma⁵⁵ma², hao˨˩ren˧˥ )
+///
+/// 只有 `ToneFormat::Digit` 和 `ToneFormat::ToneLetter` 标注调值，
+/// 其它标记方式仍然使用本调，与 `join_to` 相同。变调规则参见 `Tone::surface_value`。
+///
+/// `hanzi` 为音节对应的汉字 ( 与 `syllables` 一一对应 )，提供的时候同时执行 `一`、`不` 的变调。
+pub fn join_surface_to(w: &mut dyn fmt::Write,
+                       syllables: &[Syllable],
+                       hanzi: Option<&str>,
+                       tone_format: ToneFormat) -> fmt::Result {
+    if tone_format != ToneFormat::Digit && tone_format != ToneFormat::ToneLetter {
+        return join_to(w, syllables, tone_format);
+    }
+
+    let mut hanzi = hanzi.map(str::chars);
+
+    join_with(w, syllables, tone_format, |w, i, syllable| {
+        let c = hanzi.as_mut().and_then(Iterator::next);
+        let prev = i.checked_sub(1).map(|i| syllables[i].tone());
+        let next = syllables.get(i + 1).map(|syllable| syllable.tone());
+
+        for c in syllable.spelling().iter() {
+            w.write_char(*c)?;
+        }
+
+        write_tone_value(w, syllable.tone().surface_value(c, prev, next), tone_format)
+    })
+}
+
+/// 将多个音节连写成一个单词，调值使用连读变调以后的实际调值
+#[cfg(feature = "alloc")]
+pub fn join_surface(syllables: &[Syllable], hanzi: Option<&str>, tone_format: ToneFormat) -> String {
+    write_to_string(|w| join_surface_to(w, syllables, hanzi, tone_format))
+}
+
+
+// 判断字符是否可能属于拼音单词 ( 字母、声调数字、调号以及组合附加符号 )
+#[cfg(feature = "alloc")]
+fn is_pinyin_char(c: char) -> bool {
+    if c.is_ascii_alphanumeric() || ('\u{300}'..='\u{36F}').contains(&c) {
+        return true;
+    }
+
+    c.to_lowercase()
+        .all(|lower| TONE_MARK_TABLE.iter().any(|(k, _c, _t)| k.starts_with(lower)))
+}
+
+// 单词当中的一个字母 ( 包括后面的组合附加符号 )、声调数字或者隔音符号
+#[cfg(feature = "alloc")]
+struct Letter {
+    // 在单词当中的字节范围
+    range: Range<usize>,
+    // 不带调号的小写字母，`u\u{308}`、`e\u{302}` 合并为 `ü`、`ê`
+    base: char,
+    // 调号标注的声调
+    tone: Option<Tone>,
+}
+
+#[cfg(feature = "alloc")]
+impl Letter {
+    fn is_digit(&self) -> bool {
+        self.base.is_ascii_digit()
+    }
+
+    fn is_uppercase(&self, word: &str) -> bool {
+        word[self.range.clone()].chars().next().is_some_and(char::is_uppercase)
+    }
+}
+
+// 拆分单词当中的字母，存在无法识别的字符或者组合附加符号的时候返回 `None`
+#[cfg(feature = "alloc")]
+fn letters(word: &str) -> Option<Vec<Letter>> {
+    let mut letters: Vec<Letter> = Vec::with_capacity(word.len());
+
+    for (index, c) in word.char_indices() {
+        let range = index..index + c.len_utf8();
+
+        if ('\u{300}'..='\u{36F}').contains(&c) {
+            let letter = letters.last_mut().filter(|letter| !letter.is_digit() && letter.base != SYLLABLE_DIVIDING_MARK)?;
+            letter.range.end = range.end;
+
+            match (letter.base, c, combining_tone(c)) {
+                (_, _, Some(tone)) if letter.tone.is_none() => letter.tone = Some(tone),
+                ('u', '\u{308}', None) => letter.base = 'ü',
+                ('e', '\u{302}', None) => letter.base = 'ê',
+                _ => return None,
+            }
+            continue;
+        }
+
+        let (base, tone) = match split_tone_mark(c) {
+            Some((base, tone)) => (base, Some(tone)),
+            None => (c, None),
+        };
+        let mut lower = base.to_lowercase();
+        let base = match (lower.next(), lower.next()) {
+            (Some(base), None) => base,
+            _ => return None,
+        };
+
+        letters.push(Letter { range, base, tone });
+    }
+
+    Some(letters)
+}
+
+// 按照新的音调标记方式写入一个音节，保留原来的字母，只替换调号或者声调数字
+#[cfg(feature = "alloc")]
+fn push_syllable(result: &mut String, word: &str, letters: &[Letter], tone: Tone, tone_format: ToneFormat) {
+    let spelling = letters.iter()
+                    .filter(|letter| !letter.is_digit())
+                    .map(|letter| if letter.base == 'v' { 'ü' } else { letter.base })
+                    .collect::<Vec<char>>();
+    let position = tone_mark_position(&spelling);
+
+    for (i, letter) in letters.iter().filter(|letter| !letter.is_digit()).enumerate() {
+        if tone_format != ToneFormat::Symbol || i != position || tone == Tone::Neutral {
+            result.push_str(&ToneMark::replace_tone_marks(&word[letter.range.clone()]));
+            continue;
+        }
+
+        let mut marked = String::new();
+        write_toned(&mut marked, &spelling[i..=i], 0, tone, ToneFormat::Symbol).expect("写入字符串不应该失败！");
+
+        if letter.is_uppercase(word) {
+            result.push_str(&marked.to_uppercase());
+        } else {
+            result.push_str(&marked);
+        }
+    }
+
+    match tone_format {
+        ToneFormat::Index if tone != Tone::Neutral => result.push((b'0' + u8::from(tone)) as char),
+        ToneFormat::Digit | ToneFormat::ToneLetter => {
+            write_tone_value(result, tone.value(), tone_format).expect("写入字符串不应该失败！");
+        },
+        _ => {},
+    }
+}
+
+// 按照原来的大小写写入国语罗马字 ( 国语罗马字改变了拼写，只能保留全部大写或者首字母大写 )
+#[cfg(feature = "alloc")]
+fn push_recased(result: &mut String, word: &str, s: &str) {
+    let mut chars = word.chars().filter(|c| c.is_alphabetic());
+    let is_capitalized = chars.next().is_some_and(char::is_uppercase);
+    let is_uppercase = is_capitalized && chars.clone().count() > 0 && chars.all(char::is_uppercase);
+
+    if is_uppercase {
+        result.push_str(&s.to_uppercase());
+    } else if is_capitalized {
+        let mut chars = s.chars();
+        if let Some(first) = chars.next() {
+            result.extend(first.to_uppercase());
+            result.push_str(chars.as_str());
+        }
+    } else {
+        result.push_str(s);
+    }
+}
+
+// 转换单个单词的音调标记方式，只替换调号或者声调数字，无法确定是拼音的单词原样保留。
+//
+// 拼音单词需要满足:
+//     * 所有音节都能解析，并且满足隔音符号规则 ( `née` 不是 `né'e` )；
+//     * 至少一个音节标注了声调，不标声调的 ( 轻声 ) 音节只出现在词尾 ( `māma`，但不是 `résumé` )；
+//     * 不是单独一个带调号的元音字母 ( 无法与 `à`、`é` 等外语单词区分 )。
+#[cfg(feature = "alloc")]
+fn convert_word(result: &mut String, word: &str, tone_format: ToneFormat) {
+    if !try_convert_word(result, word, tone_format) {
+        result.push_str(word);
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn try_convert_word(result: &mut String, word: &str, tone_format: ToneFormat) -> bool {
+    let letters = match letters(word) {
+        Some(letters) => letters,
+        None => return false,
+    };
+
+    // 用于切分的小写文本，以及每个字符所属的字母
+    let mut text = String::with_capacity(word.len());
+    let mut owners = Vec::with_capacity(word.len());
+    for (i, letter) in letters.iter().enumerate() {
+        let start = text.len();
+
+        match letter.tone {
+            Some(tone) => write_toned(&mut text, &[letter.base], 0, tone, ToneFormat::Symbol).expect("写入字符串不应该失败！"),
+            None => text.push(letter.base),
+        }
+
+        owners.extend(text[start..].chars().map(|_| i));
+    }
+
+    let split = match segment::split_text(&text).into_iter().next() {
+        Some(split) if split.violations == 0 => split,
+        _ => return false,
+    };
+
+    // 每个音节包含的字母，以及是否标注了声调
+    let pieces = split.pieces.iter()
+                    .map(|(range, syllable)| {
+                        let letters = &letters[owners[range.start]..=owners[range.end - 1]];
+                        let is_toned = letters.iter().any(|letter| letter.tone.is_some() || letter.is_digit());
+                        (letters, *syllable, is_toned)
+                    })
+                    .collect::<Vec<_>>();
+
+    // 不标声调的音节只出现在最后一个标注声调的音节后面
+    let last = match pieces.iter().rposition(|(_, _, is_toned)| *is_toned) {
+        Some(last) => last,
+        None => return false,
+    };
+    if pieces[..last].iter().any(|(_, _, is_toned)| !is_toned) {
+        return false;
+    }
+
+    if let [([Letter { base: 'a' | 'o' | 'e' | 'ê', .. }], _, _)] = &pieces[..] {
+        return false;
+    }
+
+    if tone_format == ToneFormat::GwoyeuRomatzyh {
+        let syllables = pieces.iter().map(|(_, syllable, _)| *syllable).collect::<Vec<Syllable>>();
+        push_recased(result, word, &join(&syllables, tone_format));
+        return true;
+    }
+
+    let mut end = 0;
+    for (letters, syllable, is_toned) in pieces.iter() {
+        let range = letters[0].range.start..letters[letters.len() - 1].range.end;
+        // 隔音符号
+        result.push_str(&word[end..range.start]);
+        end = range.end;
+
+        let is_symbol = letters.iter().any(|letter| letter.tone.is_some());
+        let is_index = letters.iter().any(Letter::is_digit);
+        let unchanged = !is_toned
+            || (tone_format == ToneFormat::Symbol && is_symbol && !is_index)
+            || (tone_format == ToneFormat::Index && is_index && !is_symbol);
+
+        if unchanged {
+            result.push_str(&word[range]);
+        } else {
+            push_syllable(result, word, letters, syllable.tone(), tone_format);
+        }
+    }
+
+    true
+}
+
+/// 转换文本当中拼音的音调标记方式 ( 调号 <=> 数字 )
+/// 
+/// 只替换调号或者声调数字，保留原来的字母、大小写以及隔音符号 ( 国语罗马字除外 )，
+/// 标点符号以及无法确定是拼音的单词 ( `résumé`、`à la carte` ) 保持不变。
+#[cfg(feature = "alloc")]
+pub fn convert_tone_format(text: &str, tone_format: ToneFormat) -> String {
+    let mut result = String::with_capacity(text.len());
+    // 当前单词的起始位置
+    let mut word_start: Option<usize> = None;
+
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        // 隔音符号只出现在单词中间
+        let is_dividing_mark = c == SYLLABLE_DIVIDING_MARK
+            && word_start.is_some()
+            && chars.peek().is_some_and(|(_, next)| is_pinyin_char(*next));
+
+        if is_pinyin_char(c) || is_dividing_mark {
+            word_start = word_start.or(Some(index));
+            continue;
+        }
+
+        if let Some(start) = word_start.take() {
+            convert_word(&mut result, &text[start..index], tone_format);
+        }
+
+        result.push(c);
+    }
+
+    if let Some(start) = word_start {
+        convert_word(&mut result, &text[start..], tone_format);
+    }
+
+    result
+}
+
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{ convert_tone_format, join_surface };
+    use tone::{ ToneFormat, ToneMark };
+    use syllable::Syllable;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn convert_identity() {
+        let symbol = "Wǒ zài Xī'ān, NǏ HǍO, māma, nǚ'ér, lǘ";
+        let index = "Wo3 zai4 Xi1'an1, NI3 HAO3, ma1ma, nv3'er2";
+
+        assert_eq!(convert_tone_format(symbol, ToneFormat::Symbol), symbol);
+        assert_eq!(convert_tone_format(index, ToneFormat::Index), index);
+        assert_eq!(convert_tone_format("ma5 ma0", ToneFormat::Index), "ma5 ma0");
+    }
+
+    #[test]
+    fn convert_directions() {
+        assert_eq!(convert_tone_format("Wǒ zài Xī'ān, NǏ HǍO", ToneFormat::Index), "Wo3 zai4 Xi1'an1, NI3 HAO3");
+        assert_eq!(convert_tone_format("Wo3 zai4 Xi1'an1, NI3 HAO3", ToneFormat::Symbol), "Wǒ zài Xī'ān, NǏ HǍO");
+        assert_eq!(convert_tone_format("zhong1guo2 lv3 xue2sheng", ToneFormat::Symbol), "zhōngguó lǚ xuésheng");
+        assert_eq!(convert_tone_format("Zhōngguó māma", ToneFormat::Digit), "Zhong⁵⁵guo³⁵ ma⁵⁵ma");
+        assert_eq!(convert_tone_format("nǐ hǎo", ToneFormat::ToneLetter), "ni˨˩˦ hao˨˩˦");
+        assert_eq!(convert_tone_format("Zhōngguó", ToneFormat::GwoyeuRomatzyh), "Jonggwo");
+    }
+
+    #[test]
+    fn convert_keeps_other_words() {
+        for text in ["née", "résumé", "à la carte", "café", "hello world", "xi''an", "Ωmega"].iter() {
+            assert_eq!(convert_tone_format(text, ToneFormat::Index), *text);
+            assert_eq!(convert_tone_format(text, ToneFormat::Symbol), *text);
+        }
+
+        // 外语单词原样保留，拼音照常转换
+        assert_eq!(convert_tone_format("Běijīng à la carte", ToneFormat::Index), "Bei3jing1 à la carte");
+    }
+
+    #[test]
+    fn convert_combining_marks() {
+        assert_eq!(ToneMark::replace_tone_marks("Lǘ ma\u{300} Ê̄ NǏ"), "Lü ma Ê NI");
+        assert_eq!(convert_tone_format("ma\u{300}", ToneFormat::Index), "ma4");
+        assert_eq!(convert_tone_format("lu\u{308}\u{30C}", ToneFormat::Index), "lu\u{308}3");
+        assert_eq!(convert_tone_format("ma\u{300}", ToneFormat::Symbol), "ma\u{300}");
+        assert_eq!(convert_tone_format("lu\u{308}3", ToneFormat::Symbol), "lǚ");
+        assert_eq!(convert_tone_format("e\u{302}1", ToneFormat::Symbol), "ê̄");
+    }
+
+    #[test]
+    fn surface() {
+        let surface = |pinyin: &str, hanzi: Option<&str>, tone_format: ToneFormat| {
+            let syllables = pinyin.split(' ').map(|s| s.parse().unwrap()).collect::<Vec<Syllable>>();
+            join_surface(&syllables, hanzi, tone_format)
+        };
+        let digit = |pinyin: &str, hanzi: Option<&str>| surface(pinyin, hanzi, ToneFormat::Digit);
+
+        // 上声连读
+        assert_eq!(digit("nǐ hǎo", None), "ni³⁵hao²¹⁴");
+        assert_eq!(surface("hǎo rén", None, ToneFormat::ToneLetter), "hao˨˩ren˧˥");
+
+        // 轻声跟在各个声调后面
+        assert_eq!(digit("mā ma", None), "ma⁵⁵ma²");
+        assert_eq!(digit("yé ye", None), "ye³⁵ye³");
+        assert_eq!(digit("nǎi nai", None), "nai²¹nai⁴");
+        assert_eq!(digit("bà ba", None), "ba⁵¹ba¹");
+
+        // 一、不 需要提供汉字
+        assert_eq!(digit("yī gè", Some("一个")), "yi³⁵ge⁵¹");
+        assert_eq!(digit("yī tiān", Some("一天")), "yi⁵¹tian⁵⁵");
+        assert_eq!(digit("dì yī", Some("第一")), "di⁵¹yi⁵⁵");
+        assert_eq!(digit("bù shì", Some("不是")), "bu³⁵shi⁵¹");
+        assert_eq!(digit("bù hǎo", Some("不好")), "bu⁵¹hao²¹⁴");
+        assert_eq!(digit("yī gè", None), "yi⁵⁵ge⁵¹");
+        assert_eq!(digit("yī fu", Some("衣服")), "yi⁵⁵fu²");
+
+        // 其它标记方式使用本调
+        assert_eq!(surface("nǐ hǎo", None, ToneFormat::Symbol), "nǐhǎo");
+        assert_eq!(surface("pí ǎo", None, ToneFormat::Digit), "pi³⁵'ao²¹⁴");
     }
 }
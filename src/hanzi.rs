@@ -0,0 +1,44984 @@
+// 汉字读音表
+//
+// 由 `tools/gen_hanzi.py` 生成。可以手动补充多音字读音，重新生成的时候会被保留。
+// 数据来源: ICU 的 `Han-Latin` 转写规则 ( CLDR 根据 Unihan 数据库的 `kMandarin` 字段生成 )，
+// 以及表格原有的多音字读音。
+// 不在音节表当中的读音 ( 例如 `哟`、`唷` 的 `yo`，叹词 `hm`、`ng` ) 无法表示，生成的时候被跳过。
+// 每个汉字的多个读音以 `,` 分隔，第一个读音为最常用读音。
+//
+// NOTE: 该表按照码位排序，以便使用二分查找。
+pub static PINYIN_MAP: [(char, &str); 44901] = [
+    ('〇', "líng"),
+    ('㐀', "qiū"),
+    ('㐁', "tiàn"),
+    ('㐄', "kuà"),
+    ('㐅', "wǔ"),
+    ('㐆', "yǐn"),
+    ('㐌', "yí"),
+    ('㐖', "xié"),
+    ('㐜', "chóu"),
+    ('㐡', "nuò"),
+    ('㐤', "dān"),
+    ('㐨', "xù"),
+    ('㐩', "xíng"),
+    ('㐫', "xiōng"),
+    ('㐬', "liú"),
+    ('㐭', "lǐn"),
+    ('㐮', "xiāng"),
+    ('㐯', "yōng"),
+    ('㐰', "xìn"),
+    ('㐱', "zhěn"),
+    ('㐲', "dài"),
+    ('㐳', "wù"),
+    ('㐴', "pān"),
+    ('㐵', "rú"),
+    ('㐷', "mǎ"),
+    ('㐸', "qiàn"),
+    ('㐹', "yì"),
+    ('㐺', "yín"),
+    ('㐻', "nèi"),
+    ('㐼', "chèng"),
+    ('㐽', "fēng"),
+    ('㑁', "zhuō"),
+    ('㑂', "fǎng"),
+    ('㑃', "ǎo"),
+    ('㑄', "wǔ"),
+    ('㑅', "zuò"),
+    ('㑇', "zhòu"),
+    ('㑈', "dòng"),
+    ('㑉', "sù"),
+    ('㑊', "yì"),
+    ('㑋', "qióng"),
+    ('㑌', "kuāng"),
+    ('㑍', "lèi"),
+    ('㑎', "nǎo"),
+    ('㑏', "zhù"),
+    ('㑐', "shū"),
+    ('㑔', "xǔ"),
+    ('㑗', "shēn"),
+    ('㑘', "jiè"),
+    ('㑙', "dié"),
+    ('㑚', "nuó"),
+    ('㑛', "sù"),
+    ('㑜', "yì"),
+    ('㑝', "lòng"),
+    ('㑞', "yìng"),
+    ('㑟', "běng"),
+    ('㑣', "lán"),
+    ('㑤', "miáo"),
+    ('㑥', "yì"),
+    ('㑦', "lì"),
+    ('㑧', "jì"),
+    ('㑨', "yǔ"),
+    ('㑩', "luó"),
+    ('㑪', "chái"),
+    ('㑮', "hún"),
+    ('㑯', "xǔ"),
+    ('㑰', "huì"),
+    ('㑱', "rǎo"),
+    ('㑳', "zhòu"),
+    ('㑵', "hàn"),
+    ('㑶', "xì"),
+    ('㑷', "tài"),
+    ('㑸', "yáo"),
+    ('㑹', "huì"),
+    ('㑺', "jùn"),
+    ('㑻', "mà"),
+    ('㑼', "lüè"),
+    ('㑽', "táng"),
+    ('㑾', "yáo"),
+    ('㑿', "zhào"),
+    ('㒀', "zhāi"),
+    ('㒁', "yǔ"),
+    ('㒂', "zhuó"),
+    ('㒃', "èr"),
+    ('㒄', "rǎn"),
+    ('㒅', "qǐ"),
+    ('㒆', "chì"),
+    ('㒇', "wǔ"),
+    ('㒈', "hàn"),
+    ('㒉', "tǎng"),
+    ('㒊', "sè"),
+    ('㒋', "sī"),
+    ('㒌', "qióng"),
+    ('㒍', "léi"),
+    ('㒎', "sà"),
+    ('㒑', "kuǐ"),
+    ('㒒', "pú"),
+    ('㒓', "tà"),
+    ('㒔', "shú"),
+    ('㒕', "yāng"),
+    ('㒖', "ǒu"),
+    ('㒗', "tái"),
+    ('㒙', "mián"),
+    ('㒚', "yìn"),
+    ('㒛', "diào"),
+    ('㒜', "yǔ"),
+    ('㒝', "miè"),
+    ('㒞', "jùn"),
+    ('㒟', "niǎo"),
+    ('㒠', "xiè"),
+    ('㒡', "yóu"),
+    ('㒤', "chè"),
+    ('㒥', "fēng"),
+    ('㒦', "lěi"),
+    ('㒧', "lì"),
+    ('㒩', "luǒ"),
+    ('㒫', "jì"),
+    ('㒰', "quán"),
+    ('㒲', "cái"),
+    ('㒳', "liǎng"),
+    ('㒴', "gǔ"),
+    ('㒵', "mào"),
+    ('㒷', "guǎ"),
+    ('㒸', "suì"),
+    ('㒻', "mào"),
+    ('㒼', "mán"),
+    ('㒽', "quān"),
+    ('㒾', "shì"),
+    ('㒿', "lí"),
+    ('㓁', "wǎng"),
+    ('㓂', "kòu"),
+    ('㓃', "dù"),
+    ('㓄', "zhèn"),
+    ('㓅', "tīng"),
+    ('㓈', "bìng"),
+    ('㓉', "huò"),
+    ('㓊', "dòng"),
+    ('㓋', "gòng"),
+    ('㓌', "chēng"),
+    ('㓎', "qīn"),
+    ('㓏', "jiǒng"),
+    ('㓐', "lù"),
+    ('㓑', "xìng"),
+    ('㓓', "nán"),
+    ('㓔', "xiè"),
+    ('㓖', "bì"),
+    ('㓗', "jié"),
+    ('㓘', "sù"),
+    ('㓚', "gōng"),
+    ('㓜', "yòu"),
+    ('㓝', "xíng"),
+    ('㓞', "qià"),
+    ('㓟', "pí"),
+    ('㓠', "diàn"),
+    ('㓡', "fǔ"),
+    ('㓢', "luò"),
+    ('㓣', "qià"),
+    ('㓤', "qià"),
+    ('㓥', "tāng"),
+    ('㓦', "bāi"),
+    ('㓧', "gān"),
+    ('㓨', "cí"),
+    ('㓩', "xuān"),
+    ('㓪', "lǎng"),
+    ('㓭', "shé"),
+    ('㓮', "diāo"),
+    ('㓯', "lí"),
+    ('㓰', "huà"),
+    ('㓱', "tóu"),
+    ('㓲', "piān"),
+    ('㓳', "dī"),
+    ('㓴', "ruǎn"),
+    ('㓵', "è"),
+    ('㓶', "qiè"),
+    ('㓷', "yì"),
+    ('㓸', "zhuō"),
+    ('㓹', "ruì"),
+    ('㓺', "jiān"),
+    ('㓼', "chì"),
+    ('㓽', "chóng"),
+    ('㓾', "xī"),
+    ('㔀', "lüè"),
+    ('㔁', "dēng"),
+    ('㔂', "lín"),
+    ('㔃', "jué"),
+    ('㔄', "sù"),
+    ('㔅', "xiào"),
+    ('㔆', "zàn"),
+    ('㔉', "zhǔ"),
+    ('㔊', "zhǎn"),
+    ('㔋', "jiān"),
+    ('㔌', "zòu"),
+    ('㔍', "chuā"),
+    ('㔎', "xiè"),
+    ('㔏', "lì"),
+    ('㔑', "chì"),
+    ('㔒', "xí"),
+    ('㔓', "jiǎn"),
+    ('㔕', "jí"),
+    ('㔗', "fèi"),
+    ('㔘', "chù"),
+    ('㔙', "bēng"),
+    ('㔚', "jié"),
+    ('㔜', "bá"),
+    ('㔝', "liǎng"),
+    ('㔞', "kuài"),
+    ('㔠', "xiā"),
+    ('㔡', "biē"),
+    ('㔢', "jué"),
+    ('㔣', "léi"),
+    ('㔤', "xìn"),
+    ('㔥', "bài"),
+    ('㔦', "yǎng"),
+    ('㔧', "lǜ"),
+    ('㔨', "bèi"),
+    ('㔩', "è"),
+    ('㔪', "lǔ"),
+    ('㔭', "chè"),
+    ('㔮', "nuó"),
+    ('㔯', "xuán"),
+    ('㔰', "héng"),
+    ('㔱', "yǔ"),
+    ('㔳', "guǐ"),
+    ('㔴', "yì"),
+    ('㔵', "xuǎn"),
+    ('㔶', "gòng"),
+    ('㔷', "lòu"),
+    ('㔸', "tī"),
+    ('㔹', "lè"),
+    ('㔺', "shì"),
+    ('㔼', "sǔn"),
+    ('㔽', "yào"),
+    ('㔾', "xiān"),
+    ('㔿', "zòu"),
+    ('㕁', "què"),
+    ('㕂', "yín"),
+    ('㕃', "xī"),
+    ('㕄', "zhǐ"),
+    ('㕅', "jiá"),
+    ('㕆', "hù"),
+    ('㕇', "lā"),
+    ('㕈', "yǐ"),
+    ('㕉', "kè"),
+    ('㕊', "fū"),
+    ('㕋', "qín"),
+    ('㕌', "ài"),
+    ('㕎', "kè"),
+    ('㕏', "chú"),
+    ('㕐', "xiě"),
+    ('㕑', "chú"),
+    ('㕒', "wēi"),
+    ('㕕', "huàn"),
+    ('㕖', "sù"),
+    ('㕗', "yòu"),
+    ('㕙', "jùn"),
+    ('㕚', "zhǎo"),
+    ('㕛', "xù"),
+    ('㕜', "shǐ"),
+    ('㕞', "shuā"),
+    ('㕟', "kuì"),
+    ('㕠', "shuāng"),
+    ('㕡', "hé"),
+    ('㕢', "gài"),
+    ('㕣', "yǎn"),
+    ('㕤', "qiú"),
+    ('㕥', "shēn"),
+    ('㕦', "huà"),
+    ('㕧', "xī"),
+    ('㕨', "fàn"),
+    ('㕩', "pàng"),
+    ('㕪', "dǎn"),
+    ('㕫', "fǎng"),
+    ('㕬', "gōng"),
+    ('㕭', "āo"),
+    ('㕮', "fǔ"),
+    ('㕯', "nè"),
+    ('㕰', "xuè"),
+    ('㕱', "yóu"),
+    ('㕲', "huá"),
+    ('㕴', "chén"),
+    ('㕵', "guó"),
+    ('㕶', "ň"),
+    ('㕷', "huà"),
+    ('㕸', "lì"),
+    ('㕹', "fá"),
+    ('㕺', "xiāo"),
+    ('㕻', "pǒu"),
+    ('㕽', "sì"),
+    ('㖀', "lè"),
+    ('㖁', "lìn"),
+    ('㖂', "yì"),
+    ('㖃', "hǒu"),
+    ('㖅', "xù"),
+    ('㖆', "qú"),
+    ('㖇', "ér"),
+    ('㖊', "xún"),
+    ('㖏', "niè"),
+    ('㖐', "wěi"),
+    ('㖑', "xiè"),
+    ('㖒', "tí"),
+    ('㖓', "hóng"),
+    ('㖔', "tǔn"),
+    ('㖕', "niè"),
+    ('㖖', "niè"),
+    ('㖗', "yín"),
+    ('㖘', "zhēn"),
+    ('㖞', "wāi"),
+    ('㖟', "shòu"),
+    ('㖠', "nuò"),
+    ('㖡', "yè"),
+    ('㖢', "qí"),
+    ('㖣', "tòu"),
+    ('㖤', "hán"),
+    ('㖥', "jùn"),
+    ('㖦', "dǒng"),
+    ('㖧', "hūn"),
+    ('㖨', "lù"),
+    ('㖩', "jū"),
+    ('㖪', "huò"),
+    ('㖫', "líng"),
+    ('㖭', "tiǎn"),
+    ('㖮', "lún"),
+    ('㖵', "gé"),
+    ('㖶', "yān"),
+    ('㖷', "shí"),
+    ('㖸', "xué"),
+    ('㖹', "pēn"),
+    ('㖺', "chǔn"),
+    ('㖻', "niú"),
+    ('㖼', "duǒ"),
+    ('㖽', "zé"),
+    ('㖾', "è"),
+    ('㖿', "xié"),
+    ('㗀', "yōu"),
+    ('㗁', "è"),
+    ('㗂', "shěng"),
+    ('㗃', "wěn"),
+    ('㗄', "kū"),
+    ('㗅', "hú"),
+    ('㗆', "gé"),
+    ('㗇', "xiá"),
+    ('㗈', "màn"),
+    ('㗉', "lüè"),
+    ('㗊', "jí"),
+    ('㗋', "hóu"),
+    ('㗌', "zhì"),
+    ('㗏', "wāi"),
+    ('㗑', "bai"),
+    ('㗒', "ài"),
+    ('㗓', "zhuī"),
+    ('㗔', "qiān"),
+    ('㗕', "gòu"),
+    ('㗖', "dàn"),
+    ('㗗', "bēi"),
+    ('㗘', "bó"),
+    ('㗙', "chū"),
+    ('㗚', "lì"),
+    ('㗛', "xiào"),
+    ('㗜', "xiù"),
+    ('㗢', "hóng"),
+    ('㗣', "tì"),
+    ('㗤', "cù"),
+    ('㗥', "kuò"),
+    ('㗦', "láo"),
+    ('㗧', "zhì"),
+    ('㗨', "xiē"),
+    ('㗩', "xī"),
+    ('㗫', "qiè"),
+    ('㗬', "zhā"),
+    ('㗭', "xī"),
+    ('㗰', "cóng"),
+    ('㗱', "jí"),
+    ('㗲', "huò"),
+    ('㗳', "tǎ"),
+    ('㗴', "yán"),
+    ('㗵', "xù"),
+    ('㗶', "pō"),
+    ('㗷', "sǎi"),
+    ('㗻', "guō"),
+    ('㗼', "yè"),
+    ('㗽', "xiǎng"),
+    ('㗾', "xuē"),
+    ('㗿', "hé"),
+    ('㘀', "zuò"),
+    ('㘁', "yì"),
+    ('㘂', "cí"),
+    ('㘄', "lēng"),
+    ('㘅', "xián"),
+    ('㘆', "tǎi"),
+    ('㘇', "róng"),
+    ('㘈', "yì"),
+    ('㘉', "zhì"),
+    ('㘊', "xī"),
+    ('㘋', "xián"),
+    ('㘌', "jù"),
+    ('㘍', "jí"),
+    ('㘎', "hǎn"),
+    ('㘐', "pào"),
+    ('㘑', "lì"),
+    ('㘓', "lán"),
+    ('㘔', "sǎi"),
+    ('㘕', "hǎn"),
+    ('㘖', "yán"),
+    ('㘗', "qū"),
+    ('㘙', "yán"),
+    ('㘚', "hǎn"),
+    ('㘛', "kān"),
+    ('㘜', "chǐ"),
+    ('㘝', "niè"),
+    ('㘞', "huò"),
+    ('㘠', "bì"),
+    ('㘡', "xiá"),
+    ('㘢', "wěng"),
+    ('㘣', "xuán"),
+    ('㘤', "wān"),
+    ('㘥', "yóu"),
+    ('㘦', "qín"),
+    ('㘧', "xù"),
+    ('㘨', "niè"),
+    ('㘩', "bì"),
+    ('㘪', "hào"),
+    ('㘫', "jǐng"),
+    ('㘬', "ào"),
+    ('㘭', "ào"),
+    ('㘰', "zhēn"),
+    ('㘱', "tān"),
+    ('㘲', "jú"),
+    ('㘴', "zuò"),
+    ('㘵', "bù"),
+    ('㘶', "jié"),
+    ('㘷', "ài"),
+    ('㘸', "zàng"),
+    ('㘹', "cí"),
+    ('㘺', "fá"),
+    ('㘿', "niè"),
+    ('㙀', "liù"),
+    ('㙁', "méi"),
+    ('㙂', "duì"),
+    ('㙃', "bāng"),
+    ('㙄', "bì"),
+    ('㙅', "bǎo"),
+    ('㙇', "chù"),
+    ('㙈', "xià"),
+    ('㙉', "tiǎn"),
+    ('㙊', "cháng"),
+    ('㙍', "duō"),
+    ('㙎', "wēi"),
+    ('㙏', "fù"),
+    ('㙐', "duǒ"),
+    ('㙑', "yǔ"),
+    ('㙒', "yě"),
+    ('㙓', "kuí"),
+    ('㙔', "wěi"),
+    ('㙕', "kuài"),
+    ('㙗', "wēi"),
+    ('㙘', "yāo"),
+    ('㙙', "lǒng"),
+    ('㙚', "xīng"),
+    ('㙛', "bǔ"),
+    ('㙜', "chí"),
+    ('㙝', "xié"),
+    ('㙞', "niè"),
+    ('㙟', "lǎng"),
+    ('㙠', "yī"),
+    ('㙡', "zōng"),
+    ('㙢', "mán"),
+    ('㙣', "zhàng"),
+    ('㙤', "xià"),
+    ('㙥', "gùn"),
+    ('㙦', "xié"),
+    ('㙨', "jì"),
+    ('㙩', "liáo"),
+    ('㙪', "yì"),
+    ('㙫', "jí"),
+    ('㙬', "yín"),
+    ('㙮', "dā"),
+    ('㙯', "yì"),
+    ('㙰', "xiè"),
+    ('㙱', "hào"),
+    ('㙲', "yǒng"),
+    ('㙳', "kǎn"),
+    ('㙴', "chàn"),
+    ('㙵', "tái"),
+    ('㙶', "táng"),
+    ('㙷', "zhí"),
+    ('㙸', "bào"),
+    ('㙹', "méng"),
+    ('㙺', "kuí"),
+    ('㙻', "chán"),
+    ('㙼', "lěi"),
+    ('㙾', "xì"),
+    ('㚀', "xī"),
+    ('㚁', "qiào"),
+    ('㚂', "nàng"),
+    ('㚃', "yūn"),
+    ('㚅', "lóng"),
+    ('㚆', "fù"),
+    ('㚇', "zōng"),
+    ('㚉', "gǔ"),
+    ('㚊', "kāi"),
+    ('㚋', "diāo"),
+    ('㚌', "huà"),
+    ('㚍', "kuǐ"),
+    ('㚏', "gǎo"),
+    ('㚐', "tào"),
+    ('㚒', "shǎn"),
+    ('㚓', "lǎi"),
+    ('㚔', "niè"),
+    ('㚕', "fú"),
+    ('㚖', "gǎo"),
+    ('㚗', "qié"),
+    ('㚘', "bàn"),
+    ('㚙', "jiā"),
+    ('㚚', "kōng"),
+    ('㚛', "xì"),
+    ('㚜', "yù"),
+    ('㚝', "zhuī"),
+    ('㚞', "shěn"),
+    ('㚟', "chuò"),
+    ('㚠', "xiāo"),
+    ('㚡', "jǐ"),
+    ('㚢', "nú"),
+    ('㚣', "xiáo"),
+    ('㚤', "yì"),
+    ('㚥', "yú"),
+    ('㚦', "yí"),
+    ('㚧', "yǎn"),
+    ('㚨', "shěn"),
+    ('㚩', "rǎn"),
+    ('㚪', "hào"),
+    ('㚫', "sà"),
+    ('㚬', "jūn"),
+    ('㚭', "yóu"),
+    ('㚯', "xín"),
+    ('㚰', "pēi"),
+    ('㚱', "qiū"),
+    ('㚲', "chān"),
+    ('㚴', "bù"),
+    ('㚵', "dōng"),
+    ('㚶', "sì"),
+    ('㚷', "ěr"),
+    ('㚹', "mǎo"),
+    ('㚺', "yùn"),
+    ('㚻', "jī"),
+    ('㚽', "qiǎo"),
+    ('㚾', "xiōng"),
+    ('㚿', "páo"),
+    ('㛀', "chú"),
+    ('㛁', "pēng"),
+    ('㛂', "nuǒ"),
+    ('㛃', "jié"),
+    ('㛄', "yī"),
+    ('㛅', "èr"),
+    ('㛆', "duò"),
+    ('㛊', "duǒ"),
+    ('㛍', "qiè"),
+    ('㛎', "lǚ"),
+    ('㛏', "qiú"),
+    ('㛐', "sǒu"),
+    ('㛑', "càn"),
+    ('㛒', "dòu"),
+    ('㛓', "xī"),
+    ('㛔', "fēng"),
+    ('㛕', "yì"),
+    ('㛖', "suō"),
+    ('㛗', "qiē"),
+    ('㛘', "pò"),
+    ('㛙', "xīn"),
+    ('㛚', "tǒng"),
+    ('㛛', "xìn"),
+    ('㛜', "yóu"),
+    ('㛝', "bèi"),
+    ('㛞', "lòng"),
+    ('㛣', "yún"),
+    ('㛤', "lí"),
+    ('㛥', "tà"),
+    ('㛦', "lǎn"),
+    ('㛧', "mǎn"),
+    ('㛨', "qiǎng"),
+    ('㛩', "zhóu"),
+    ('㛪', "yàn"),
+    ('㛫', "xī"),
+    ('㛬', "lù"),
+    ('㛭', "xī"),
+    ('㛮', "sǎo"),
+    ('㛯', "fàn"),
+    ('㛱', "wěi"),
+    ('㛲', "fà"),
+    ('㛳', "yì"),
+    ('㛴', "nǎo"),
+    ('㛵', "chēng"),
+    ('㛶', "tàn"),
+    ('㛷', "jī"),
+    ('㛸', "shù"),
+    ('㛹', "pián"),
+    ('㛺', "ān"),
+    ('㛻', "kuā"),
+    ('㛼', "chā"),
+    ('㛾', "xián"),
+    ('㛿', "zhì"),
+    ('㜂', "fēng"),
+    ('㜃', "liàn"),
+    ('㜄', "xún"),
+    ('㜅', "xù"),
+    ('㜆', "mì"),
+    ('㜇', "huì"),
+    ('㜈', "mù"),
+    ('㜉', "yōng"),
+    ('㜊', "zhǎn"),
+    ('㜋', "yì"),
+    ('㜌', "nǒu"),
+    ('㜍', "táng"),
+    ('㜎', "xī"),
+    ('㜏', "yún"),
+    ('㜐', "shù"),
+    ('㜑', "fú"),
+    ('㜒', "yì"),
+    ('㜓', "dá"),
+    ('㜕', "lián"),
+    ('㜖', "cáo"),
+    ('㜗', "cān"),
+    ('㜘', "jù"),
+    ('㜙', "lù"),
+    ('㜚', "sù"),
+    ('㜛', "nèn"),
+    ('㜜', "ào"),
+    ('㜝', "ǎn"),
+    ('㜞', "qiàn"),
+    ('㜠', "cuī"),
+    ('㜡', "cōng"),
+    ('㜣', "rán"),
+    ('㜤', "niǎn"),
+    ('㜥', "mái"),
+    ('㜦', "xín"),
+    ('㜧', "yuè"),
+    ('㜨', "nái"),
+    ('㜩', "ào"),
+    ('㜪', "shēn"),
+    ('㜫', "mà"),
+    ('㜮', "làn"),
+    ('㜯', "xī"),
+    ('㜰', "yuè"),
+    ('㜱', "zhì"),
+    ('㜲', "wěng"),
+    ('㜳', "huái"),
+    ('㜴', "mèng"),
+    ('㜵', "niǎo"),
+    ('㜶', "wǎn"),
+    ('㜷', "mí"),
+    ('㜸', "niè"),
+    ('㜹', "qú"),
+    ('㜺', "zàn"),
+    ('㜻', "liàn"),
+    ('㜼', "zhí"),
+    ('㜽', "zǐ"),
+    ('㜾', "hái"),
+    ('㜿', "xù"),
+    ('㝀', "hào"),
+    ('㝁', "xuān"),
+    ('㝂', "zhì"),
+    ('㝃', "miǎn"),
+    ('㝄', "chún"),
+    ('㝅', "gòu"),
+    ('㝇', "chún"),
+    ('㝈', "luán"),
+    ('㝉', "zhù"),
+    ('㝊', "shǒu"),
+    ('㝋', "liǎo"),
+    ('㝌', "jiù"),
+    ('㝍', "xiě"),
+    ('㝎', "dìng"),
+    ('㝏', "jiè"),
+    ('㝐', "róng"),
+    ('㝑', "máng"),
+    ('㝓', "kè"),
+    ('㝔', "yǎo"),
+    ('㝕', "níng"),
+    ('㝖', "yí"),
+    ('㝗', "láng"),
+    ('㝘', "yóng"),
+    ('㝙', "yín"),
+    ('㝚', "yán"),
+    ('㝛', "sù"),
+    ('㝝', "lín"),
+    ('㝞', "yā"),
+    ('㝟', "máo"),
+    ('㝠', "míng"),
+    ('㝡', "zuì"),
+    ('㝢', "yǔ"),
+    ('㝣', "yì"),
+    ('㝤', "gòu"),
+    ('㝥', "mǐ"),
+    ('㝦', "jùn"),
+    ('㝧', "wěn"),
+    ('㝩', "kāng"),
+    ('㝪', "diàn"),
+    ('㝫', "lóng"),
+    ('㝭', "xǐng"),
+    ('㝮', "cuì"),
+    ('㝯', "qiáo"),
+    ('㝰', "mián"),
+    ('㝱', "mèng"),
+    ('㝲', "qǐn"),
+    ('㝴', "wán"),
+    ('㝵', "dé"),
+    ('㝶', "ài"),
+    ('㝸', "biàn"),
+    ('㝹', "nóu"),
+    ('㝺', "lián"),
+    ('㝻', "jǐn"),
+    ('㝼', "yū"),
+    ('㝽', "chuí"),
+    ('㝾', "zuǒ"),
+    ('㝿', "bǒ"),
+    ('㞀', "huī"),
+    ('㞁', "yào"),
+    ('㞂', "tuǐ"),
+    ('㞃', "jì"),
+    ('㞄', "ān"),
+    ('㞅', "luò"),
+    ('㞆', "jǐ"),
+    ('㞇', "wěi"),
+    ('㞈', "bō"),
+    ('㞉', "zā"),
+    ('㞊', "xù"),
+    ('㞋', "niǎn"),
+    ('㞌', "yùn"),
+    ('㞎', "bǎ"),
+    ('㞏', "zhé"),
+    ('㞐', "jū"),
+    ('㞑', "wěi"),
+    ('㞒', "xiè"),
+    ('㞓', "qì"),
+    ('㞔', "yí"),
+    ('㞕', "xiè"),
+    ('㞖', "cí"),
+    ('㞗', "qiú"),
+    ('㞘', "dū"),
+    ('㞙', "niào"),
+    ('㞚', "qì"),
+    ('㞛', "jǐ"),
+    ('㞜', "tuī"),
+    ('㞞', "sóng"),
+    ('㞟', "diàn"),
+    ('㞠', "láo"),
+    ('㞡', "zhǎn"),
+    ('㞤', "yín"),
+    ('㞥', "cén"),
+    ('㞦', "jǐ"),
+    ('㞧', "huì"),
+    ('㞨', "zǐ"),
+    ('㞩', "lán"),
+    ('㞪', "náo"),
+    ('㞫', "jù"),
+    ('㞬', "qìn"),
+    ('㞭', "dài"),
+    ('㞯', "jié"),
+    ('㞰', "xǔ"),
+    ('㞱', "cōng"),
+    ('㞲', "yòng"),
+    ('㞳', "dǒu"),
+    ('㞴', "chí"),
+    ('㞶', "mǐn"),
+    ('㞷', "huáng"),
+    ('㞸', "suì"),
+    ('㞹', "kě"),
+    ('㞺', "zú"),
+    ('㞻', "hào"),
+    ('㞼', "chéng"),
+    ('㞽', "xuè"),
+    ('㞾', "ní"),
+    ('㞿', "chì"),
+    ('㟀', "lián"),
+    ('㟁', "àn"),
+    ('㟂', "mǔ"),
+    ('㟃', "sī"),
+    ('㟄', "xiáng"),
+    ('㟅', "yáng"),
+    ('㟆', "huá"),
+    ('㟇', "cuò"),
+    ('㟈', "qiú"),
+    ('㟉', "láo"),
+    ('㟊', "fú"),
+    ('㟋', "duì"),
+    ('㟌', "máng"),
+    ('㟍', "láng"),
+    ('㟎', "tuǒ"),
+    ('㟏', "hán"),
+    ('㟐', "mǎng"),
+    ('㟑', "bó"),
+    ('㟒', "qūn"),
+    ('㟓', "qí"),
+    ('㟔', "hán"),
+    ('㟖', "lòng"),
+    ('㟗', "bīn"),
+    ('㟘', "tiáo"),
+    ('㟙', "zé"),
+    ('㟚', "qí"),
+    ('㟛', "zàn"),
+    ('㟜', "mí"),
+    ('㟝', "péi"),
+    ('㟞', "zhàn"),
+    ('㟟', "xiàng"),
+    ('㟠', "gǎng"),
+    ('㟢', "qí"),
+    ('㟤', "lù"),
+    ('㟥', "cēn"),
+    ('㟦', "yùn"),
+    ('㟧', "è"),
+    ('㟨', "duān"),
+    ('㟩', "mín"),
+    ('㟪', "wēi"),
+    ('㟫', "quán"),
+    ('㟬', "sǒu"),
+    ('㟭', "mín"),
+    ('㟮', "tū"),
+    ('㟰', "mǐng"),
+    ('㟱', "yǎo"),
+    ('㟲', "jué"),
+    ('㟳', "lì"),
+    ('㟴', "kuài"),
+    ('㟵', "gǎng"),
+    ('㟶', "yuán"),
+    ('㟷', "da"),
+    ('㟹', "láo"),
+    ('㟺', "lóu"),
+    ('㟻', "qiàn"),
+    ('㟼', "áo"),
+    ('㟽', "biǎo"),
+    ('㟾', "yōng"),
+    ('㟿', "mǎng"),
+    ('㠀', "dǎo"),
+    ('㠂', "áo"),
+    ('㠄', "xí"),
+    ('㠅', "fú"),
+    ('㠆', "dān"),
+    ('㠇', "jiù"),
+    ('㠈', "rùn"),
+    ('㠉', "tóng"),
+    ('㠊', "qū"),
+    ('㠋', "è"),
+    ('㠌', "qī"),
+    ('㠍', "jí"),
+    ('㠎', "jí"),
+    ('㠏', "huá"),
+    ('㠐', "jiào"),
+    ('㠑', "zuì"),
+    ('㠒', "biǎo"),
+    ('㠓', "méng"),
+    ('㠔', "bài"),
+    ('㠕', "wěi"),
+    ('㠖', "yǐ"),
+    ('㠗', "ào"),
+    ('㠘', "yǔ"),
+    ('㠙', "háo"),
+    ('㠚', "duì"),
+    ('㠛', "wò"),
+    ('㠜', "nì"),
+    ('㠝', "cuán"),
+    ('㠟', "lí"),
+    ('㠠', "lú"),
+    ('㠡', "niǎo"),
+    ('㠢', "huái"),
+    ('㠣', "lì"),
+    ('㠥', "lǜ"),
+    ('㠦', "fēng"),
+    ('㠧', "mǐ"),
+    ('㠨', "yù"),
+    ('㠪', "jù"),
+    ('㠭', "zhǎn"),
+    ('㠮', "pēng"),
+    ('㠯', "yǐ"),
+    ('㠱', "jì"),
+    ('㠲', "bǐ"),
+    ('㠴', "rèn"),
+    ('㠵', "huāng"),
+    ('㠶', "fán"),
+    ('㠷', "gé"),
+    ('㠸', "kù"),
+    ('㠹', "jiè"),
+    ('㠺', "shā"),
+    ('㠼', "sī"),
+    ('㠽', "tóng"),
+    ('㠾', "yuān"),
+    ('㠿', "zī"),
+    ('㡀', "bì"),
+    ('㡁', "kuǎ"),
+    ('㡂', "lì"),
+    ('㡃', "huāng"),
+    ('㡄', "xún"),
+    ('㡅', "nuǒ"),
+    ('㡇', "zhé"),
+    ('㡈', "wèn"),
+    ('㡉', "xián"),
+    ('㡊', "qià"),
+    ('㡋', "yé"),
+    ('㡌', "mào"),
+    ('㡎', "shān"),
+    ('㡏', "shù"),
+    ('㡑', "qiāo"),
+    ('㡒', "zhūn"),
+    ('㡓', "kūn"),
+    ('㡔', "wù"),
+    ('㡕', "yīng"),
+    ('㡖', "chuáng"),
+    ('㡗', "tí"),
+    ('㡘', "lián"),
+    ('㡙', "bī"),
+    ('㡚', "gōu"),
+    ('㡛', "máng"),
+    ('㡜', "xiè"),
+    ('㡝', "fèng"),
+    ('㡞', "lóu"),
+    ('㡟', "zāo"),
+    ('㡠', "zhèng"),
+    ('㡡', "chú"),
+    ('㡢', "màn"),
+    ('㡣', "lóng"),
+    ('㡥', "yìn"),
+    ('㡦', "pīn"),
+    ('㡧', "zhèng"),
+    ('㡨', "jiān"),
+    ('㡩', "luán"),
+    ('㡪', "nié"),
+    ('㡫', "yì"),
+    ('㡭', "jì"),
+    ('㡮', "jí"),
+    ('㡯', "zhái"),
+    ('㡰', "yǔ"),
+    ('㡱', "jiǔ"),
+    ('㡲', "huán"),
+    ('㡳', "zhǐ"),
+    ('㡴', "lā"),
+    ('㡵', "líng"),
+    ('㡶', "zhǐ"),
+    ('㡷', "běn"),
+    ('㡸', "zhà"),
+    ('㡹', "jū"),
+    ('㡺', "dàn"),
+    ('㡻', "liào"),
+    ('㡼', "yì"),
+    ('㡽', "zhào"),
+    ('㡾', "xiàn"),
+    ('㡿', "chì"),
+    ('㢀', "cì"),
+    ('㢁', "chǐ"),
+    ('㢂', "yǎn"),
+    ('㢃', "láng"),
+    ('㢄', "dòu"),
+    ('㢅', "lòng"),
+    ('㢆', "chán"),
+    ('㢈', "tuí"),
+    ('㢉', "chá"),
+    ('㢊', "ǎi"),
+    ('㢋', "chǐ"),
+    ('㢍', "yǐng"),
+    ('㢎', "zhé"),
+    ('㢏', "tóu"),
+    ('㢑', "tuí"),
+    ('㢒', "chá"),
+    ('㢓', "yǎo"),
+    ('㢔', "zǒng"),
+    ('㢖', "pān"),
+    ('㢗', "qiào"),
+    ('㢘', "lián"),
+    ('㢙', "qín"),
+    ('㢚', "lǔ"),
+    ('㢛', "yàn"),
+    ('㢜', "kàng"),
+    ('㢝', "sū"),
+    ('㢞', "yì"),
+    ('㢟', "chān"),
+    ('㢠', "jiǒng"),
+    ('㢡', "jiǎng"),
+    ('㢣', "jìng"),
+    ('㢥', "dòng"),
+    ('㢧', "juàn"),
+    ('㢨', "hàn"),
+    ('㢩', "dì"),
+    ('㢬', "hóng"),
+    ('㢮', "chí"),
+    ('㢯', "diāo"),
+    ('㢰', "bì"),
+    ('㢲', "xùn"),
+    ('㢳', "lú"),
+    ('㢵', "xié"),
+    ('㢶', "bì"),
+    ('㢸', "bì"),
+    ('㢺', "xián"),
+    ('㢻', "ruì"),
+    ('㢼', "biè"),
+    ('㢽', "ěr"),
+    ('㢾', "juàn"),
+    ('㣀', "zhèn"),
+    ('㣁', "bèi"),
+    ('㣂', "è"),
+    ('㣃', "yǔ"),
+    ('㣄', "qú"),
+    ('㣅', "zàn"),
+    ('㣆', "mí"),
+    ('㣇', "yì"),
+    ('㣈', "sì"),
+    ('㣌', "shàn"),
+    ('㣍', "tái"),
+    ('㣎', "mù"),
+    ('㣏', "jìng"),
+    ('㣐', "biàn"),
+    ('㣑', "róng"),
+    ('㣒', "cèng"),
+    ('㣓', "càn"),
+    ('㣔', "dīng"),
+    ('㣙', "dí"),
+    ('㣚', "tǒng"),
+    ('㣛', "tà"),
+    ('㣜', "xíng"),
+    ('㣝', "sōng"),
+    ('㣞', "duó"),
+    ('㣟', "xì"),
+    ('㣠', "tāo"),
+    ('㣢', "tí"),
+    ('㣣', "shàn"),
+    ('㣤', "jiàn"),
+    ('㣥', "zhì"),
+    ('㣦', "wēi"),
+    ('㣧', "yìn"),
+    ('㣪', "huǎn"),
+    ('㣫', "zhǒng"),
+    ('㣬', "qì"),
+    ('㣭', "zōng"),
+    ('㣯', "xiè"),
+    ('㣰', "xiè"),
+    ('㣱', "zé"),
+    ('㣲', "wéi"),
+    ('㣵', "tà"),
+    ('㣶', "zhān"),
+    ('㣷', "nìng"),
+    ('㣺', "xīn"),
+    ('㣻', "yì"),
+    ('㣼', "rěn"),
+    ('㣽', "shù"),
+    ('㣾', "chà"),
+    ('㣿', "zhuó"),
+    ('㤁', "miǎn"),
+    ('㤂', "jí"),
+    ('㤃', "fáng"),
+    ('㤄', "pèi"),
+    ('㤅', "ài"),
+    ('㤆', "fàn"),
+    ('㤇', "ǎo"),
+    ('㤈', "qìn"),
+    ('㤉', "qiā"),
+    ('㤊', "xiào"),
+    ('㤋', "fēn"),
+    ('㤌', "gān"),
+    ('㤍', "qiāo"),
+    ('㤎', "gē"),
+    ('㤏', "tóng"),
+    ('㤐', "chān"),
+    ('㤑', "yòu"),
+    ('㤒', "gāo"),
+    ('㤓', "bèn"),
+    ('㤔', "fù"),
+    ('㤕', "chù"),
+    ('㤖', "zhù"),
+    ('㤘', "zhòu"),
+    ('㤚', "háng"),
+    ('㤛', "nín"),
+    ('㤜', "jué"),
+    ('㤝', "chōng"),
+    ('㤞', "chà"),
+    ('㤟', "kǒng"),
+    ('㤠', "liè"),
+    ('㤡', "lì"),
+    ('㤢', "yù"),
+    ('㤤', "yú"),
+    ('㤥', "hài"),
+    ('㤦', "lì"),
+    ('㤧', "hóu"),
+    ('㤨', "gǒng"),
+    ('㤩', "kè"),
+    ('㤪', "yuàn"),
+    ('㤫', "dé"),
+    ('㤬', "huì"),
+    ('㤭', "jiāo"),
+    ('㤮', "guàng"),
+    ('㤯', "jiǒng"),
+    ('㤰', "zuò"),
+    ('㤱', "fù"),
+    ('㤲', "qiè"),
+    ('㤳', "běi"),
+    ('㤴', "chè"),
+    ('㤵', "cí"),
+    ('㤶', "máng"),
+    ('㤷', "hān"),
+    ('㤸', "xì"),
+    ('㤹', "qiú"),
+    ('㤺', "huǎng"),
+    ('㤽', "chóu"),
+    ('㤾', "sàn"),
+    ('㤿', "yān"),
+    ('㥀', "zhí"),
+    ('㥁', "dé"),
+    ('㥂', "tè"),
+    ('㥃', "mèn"),
+    ('㥄', "líng"),
+    ('㥅', "shòu"),
+    ('㥆', "tuì"),
+    ('㥇', "cán"),
+    ('㥈', "dié"),
+    ('㥉', "chè"),
+    ('㥊', "péng"),
+    ('㥋', "yī"),
+    ('㥌', "jú"),
+    ('㥍', "jì"),
+    ('㥎', "lái"),
+    ('㥏', "tiǎn"),
+    ('㥐', "yuàn"),
+    ('㥒', "cǎi"),
+    ('㥓', "qī"),
+    ('㥔', "yù"),
+    ('㥕', "lián"),
+    ('㥖', "cōng"),
+    ('㥚', "yú"),
+    ('㥛', "jí"),
+    ('㥜', "wèi"),
+    ('㥝', "mǐ"),
+    ('㥞', "suì"),
+    ('㥟', "xié"),
+    ('㥠', "xū"),
+    ('㥡', "chì"),
+    ('㥢', "qiú"),
+    ('㥣', "huì"),
+    ('㥥', "yú"),
+    ('㥦', "qiè"),
+    ('㥧', "shùn"),
+    ('㥨', "shuì"),
+    ('㥩', "duǒ"),
+    ('㥪', "lóu"),
+    ('㥬', "páng"),
+    ('㥭', "tài"),
+    ('㥮', "zhòu"),
+    ('㥯', "yǐn"),
+    ('㥰', "sāo"),
+    ('㥱', "fěi"),
+    ('㥲', "chēn"),
+    ('㥳', "yuán"),
+    ('㥴', "yí"),
+    ('㥵', "hùn"),
+    ('㥶', "sè"),
+    ('㥷', "yè"),
+    ('㥸', "mǐn"),
+    ('㥹', "fěn"),
+    ('㥺', "hé"),
+    ('㥼', "yìn"),
+    ('㥽', "cè"),
+    ('㥾', "nì"),
+    ('㥿', "ào"),
+    ('㦀', "féng"),
+    ('㦁', "lián"),
+    ('㦂', "cháng"),
+    ('㦃', "chǎn"),
+    ('㦄', "má"),
+    ('㦅', "diē"),
+    ('㦆', "hū"),
+    ('㦇', "lù"),
+    ('㦈', "ài"),
+    ('㦉', "yì"),
+    ('㦊', "huá"),
+    ('㦋', "zhā"),
+    ('㦌', "hū"),
+    ('㦍', "è"),
+    ('㦎', "huò"),
+    ('㦏', "sǔn"),
+    ('㦐', "nì"),
+    ('㦑', "xiàn"),
+    ('㦒', "lí"),
+    ('㦓', "xiàn"),
+    ('㦔', "yàn"),
+    ('㦕', "lóng"),
+    ('㦖', "mèn"),
+    ('㦗', "jīn"),
+    ('㦘', "jī"),
+    ('㦚', "biǎn"),
+    ('㦛', "yǔ"),
+    ('㦜', "huò"),
+    ('㦝', "miǎo"),
+    ('㦞', "chóu"),
+    ('㦟', "mái"),
+    ('㦡', "lè"),
+    ('㦢', "jié"),
+    ('㦣', "wèi"),
+    ('㦤', "yì"),
+    ('㦥', "xuān"),
+    ('㦦', "xì"),
+    ('㦧', "cǎn"),
+    ('㦨', "lán"),
+    ('㦩', "yǐn"),
+    ('㦪', "xiè"),
+    ('㦫', "zā"),
+    ('㦬', "luǒ"),
+    ('㦭', "líng"),
+    ('㦮', "qián"),
+    ('㦯', "huò"),
+    ('㦰', "jiān"),
+    ('㦱', "wǒ"),
+    ('㦴', "gé"),
+    ('㦵', "zhū"),
+    ('㦶', "dié"),
+    ('㦷', "yǒng"),
+    ('㦸', "jǐ"),
+    ('㦹', "yáng"),
+    ('㦺', "rù"),
+    ('㦻', "xí"),
+    ('㦼', "shuàng"),
+    ('㦽', "yù"),
+    ('㦾', "yí"),
+    ('㦿', "qiǎn"),
+    ('㧀', "jí"),
+    ('㧁', "qù"),
+    ('㧂', "tián"),
+    ('㧃', "shōu"),
+    ('㧄', "qiǎn"),
+    ('㧅', "mù"),
+    ('㧆', "jīn"),
+    ('㧇', "mǎo"),
+    ('㧈', "yǐn"),
+    ('㧉', "gài"),
+    ('㧊', "pō"),
+    ('㧋', "xuǎn"),
+    ('㧌', "mào"),
+    ('㧍', "fǎng"),
+    ('㧎', "yá"),
+    ('㧏', "gāng"),
+    ('㧐', "sǒng"),
+    ('㧑', "huī"),
+    ('㧒', "yù"),
+    ('㧓', "guā"),
+    ('㧔', "guài"),
+    ('㧕', "liǔ"),
+    ('㧖', "è"),
+    ('㧗', "zǐ"),
+    ('㧘', "zì"),
+    ('㧙', "bì"),
+    ('㧚', "wǎ"),
+    ('㧛', "lǎn"),
+    ('㧜', "liè"),
+    ('㧟', "kuǎi"),
+    ('㧡', "hài"),
+    ('㧢', "yīn"),
+    ('㧣', "zhū"),
+    ('㧤', "chòng"),
+    ('㧥', "xiǎn"),
+    ('㧦', "xuàn"),
+    ('㧨', "qiú"),
+    ('㧩', "pèi"),
+    ('㧪', "guǐ"),
+    ('㧫', "ér"),
+    ('㧬', "gǒng"),
+    ('㧭', "qióng"),
+    ('㧮', "hū"),
+    ('㧯', "lǎo"),
+    ('㧰', "lì"),
+    ('㧱', "chèn"),
+    ('㧲', "sǎn"),
+    ('㧳', "zhuò"),
+    ('㧴', "wǒ"),
+    ('㧵', "póu"),
+    ('㧶', "kēng"),
+    ('㧷', "tùn"),
+    ('㧸', "pēng"),
+    ('㧹', "tè"),
+    ('㧺', "tà"),
+    ('㧻', "zhuó"),
+    ('㧼', "biào"),
+    ('㧽', "gù"),
+    ('㧾', "hū"),
+    ('㨀', "bǐng"),
+    ('㨁', "zhì"),
+    ('㨂', "dǒng"),
+    ('㨃', "duǐ"),
+    ('㨄', "zhōu"),
+    ('㨅', "nèi"),
+    ('㨆', "lǐn"),
+    ('㨇', "pó"),
+    ('㨈', "jǐ"),
+    ('㨉', "mín"),
+    ('㨊', "wěi"),
+    ('㨋', "chě"),
+    ('㨌', "gòu"),
+    ('㨍', "bāng"),
+    ('㨎', "rú"),
+    ('㨏', "tān"),
+    ('㨐', "bǔ"),
+    ('㨑', "zōng"),
+    ('㨒', "kuī"),
+    ('㨓', "láo"),
+    ('㨔', "hàn"),
+    ('㨕', "yíng"),
+    ('㨖', "zhì"),
+    ('㨗', "jié"),
+    ('㨘', "xǐng"),
+    ('㨙', "xié"),
+    ('㨚', "xún"),
+    ('㨛', "shǎn"),
+    ('㨜', "qián"),
+    ('㨝', "xiē"),
+    ('㨞', "sù"),
+    ('㨟', "hāi"),
+    ('㨠', "mì"),
+    ('㨡', "hún"),
+    ('㨢', "pī"),
+    ('㨤', "huì"),
+    ('㨥', "nà"),
+    ('㨦', "sǒng"),
+    ('㨧', "bèn"),
+    ('㨨', "chōu"),
+    ('㨩', "jié"),
+    ('㨪', "huàng"),
+    ('㨫', "lǎn"),
+    ('㨭', "hù"),
+    ('㨮', "dōu"),
+    ('㨯', "huò"),
+    ('㨰', "gǔn"),
+    ('㨱', "yáo"),
+    ('㨲', "cè"),
+    ('㨳', "guǐ"),
+    ('㨴', "jiàn"),
+    ('㨵', "jiǎn"),
+    ('㨶', "dǎo"),
+    ('㨷', "jìn"),
+    ('㨸', "mà"),
+    ('㨹', "huì"),
+    ('㨺', "miǎn"),
+    ('㨻', "cán"),
+    ('㨼', "lüè"),
+    ('㨽', "pì"),
+    ('㨾', "yàng"),
+    ('㨿', "jù"),
+    ('㩀', "jù"),
+    ('㩁', "què"),
+    ('㩃', "qiān"),
+    ('㩄', "shāi"),
+    ('㩆', "jiù"),
+    ('㩇', "huò"),
+    ('㩈', "yǔn"),
+    ('㩉', "dá"),
+    ('㩊', "xuān"),
+    ('㩋', "xiāo"),
+    ('㩌', "fèi"),
+    ('㩍', "cè"),
+    ('㩎', "yè"),
+    ('㩐', "dèn"),
+    ('㩒', "qín"),
+    ('㩓', "huǐ"),
+    ('㩔', "tún"),
+    ('㩖', "qiáng"),
+    ('㩗', "xí"),
+    ('㩘', "nǐ"),
+    ('㩙', "sāi"),
+    ('㩚', "méng"),
+    ('㩛', "tuán"),
+    ('㩜', "lǎn"),
+    ('㩝', "háo"),
+    ('㩞', "cì"),
+    ('㩟', "zhài"),
+    ('㩠', "āo"),
+    ('㩡', "luǒ"),
+    ('㩢', "miè"),
+    ('㩤', "fū"),
+    ('㩦', "xié"),
+    ('㩧', "bó"),
+    ('㩨', "huì"),
+    ('㩩', "qǐng"),
+    ('㩪', "xié"),
+    ('㩭', "bó"),
+    ('㩮', "qián"),
+    ('㩯', "pó"),
+    ('㩰', "jiǎo"),
+    ('㩱', "jué"),
+    ('㩲', "kǔn"),
+    ('㩳', "sǒng"),
+    ('㩴', "jú"),
+    ('㩵', "è"),
+    ('㩶', "niè"),
+    ('㩷', "qiān"),
+    ('㩸', "dié"),
+    ('㩹', "dié"),
+    ('㩻', "qī"),
+    ('㩼', "zhī"),
+    ('㩽', "qí"),
+    ('㩾', "zhuì"),
+    ('㩿', "kū"),
+    ('㪀', "yú"),
+    ('㪁', "qín"),
+    ('㪂', "kū"),
+    ('㪃', "hé"),
+    ('㪄', "fú"),
+    ('㪅', "gèng"),
+    ('㪆', "dǐ"),
+    ('㪇', "xiàn"),
+    ('㪈', "guì"),
+    ('㪉', "hé"),
+    ('㪊', "qún"),
+    ('㪋', "hàn"),
+    ('㪌', "tǒng"),
+    ('㪍', "bó"),
+    ('㪎', "shǎn"),
+    ('㪏', "bǐ"),
+    ('㪐', "lù"),
+    ('㪑', "yè"),
+    ('㪒', "ní"),
+    ('㪓', "chuái"),
+    ('㪔', "sàn"),
+    ('㪕', "diào"),
+    ('㪖', "lù"),
+    ('㪗', "tǒu"),
+    ('㪘', "liǎn"),
+    ('㪙', "kě"),
+    ('㪚', "sàn"),
+    ('㪛', "zhěn"),
+    ('㪜', "chuǎi"),
+    ('㪝', "liàn"),
+    ('㪞', "mào"),
+    ('㪠', "qiān"),
+    ('㪡', "kài"),
+    ('㪢', "shǎo"),
+    ('㪣', "xiāo"),
+    ('㪤', "bì"),
+    ('㪥', "zhā"),
+    ('㪦', "yìn"),
+    ('㪧', "xī"),
+    ('㪨', "shàn"),
+    ('㪩', "sù"),
+    ('㪪', "sà"),
+    ('㪫', "ruì"),
+    ('㪬', "chuō"),
+    ('㪭', "lú"),
+    ('㪮', "líng"),
+    ('㪯', "chá"),
+    ('㪱', "huàn"),
+    ('㪴', "jiá"),
+    ('㪵', "bàn"),
+    ('㪶', "hú"),
+    ('㪷', "dǒu"),
+    ('㪹', "lǒu"),
+    ('㪺', "jū"),
+    ('㪻', "juàn"),
+    ('㪼', "kě"),
+    ('㪽', "suǒ"),
+    ('㪾', "luò"),
+    ('㪿', "zhé"),
+    ('㫀', "dǐng"),
+    ('㫁', "duàn"),
+    ('㫂', "zhù"),
+    ('㫃', "yǎn"),
+    ('㫄', "páng"),
+    ('㫅', "chá"),
+    ('㫊', "yǐ"),
+    ('㫍', "yóu"),
+    ('㫎', "huī"),
+    ('㫏', "yǎo"),
+    ('㫐', "yǎo"),
+    ('㫑', "zhǐ"),
+    ('㫒', "gǒng"),
+    ('㫓', "qǐ"),
+    ('㫔', "gèn"),
+    ('㫗', "hòu"),
+    ('㫘', "mì"),
+    ('㫙', "fú"),
+    ('㫚', "hū"),
+    ('㫛', "guàng"),
+    ('㫜', "tǎn"),
+    ('㫝', "dī"),
+    ('㫟', "yán"),
+    ('㫢', "qù"),
+    ('㫤', "chǎng"),
+    ('㫥', "mǐng"),
+    ('㫦', "tāo"),
+    ('㫧', "bào"),
+    ('㫨', "ān"),
+    ('㫫', "xiǎn"),
+    ('㫯', "mào"),
+    ('㫰', "làng"),
+    ('㫱', "nǎn"),
+    ('㫲', "bèi"),
+    ('㫳', "chén"),
+    ('㫵', "fēi"),
+    ('㫶', "zhǒu"),
+    ('㫷', "jī"),
+    ('㫸', "jiē"),
+    ('㫹', "shù"),
+    ('㫻', "kùn"),
+    ('㫼', "dié"),
+    ('㫽', "lù"),
+    ('㬂', "yú"),
+    ('㬃', "tái"),
+    ('㬄', "chàn"),
+    ('㬅', "màn"),
+    ('㬆', "mǐn"),
+    ('㬇', "huàn"),
+    ('㬈', "wēn"),
+    ('㬉', "nuǎn"),
+    ('㬊', "huàn"),
+    ('㬋', "hóu"),
+    ('㬌', "jìng"),
+    ('㬍', "bó"),
+    ('㬎', "xiǎn"),
+    ('㬏', "lì"),
+    ('㬐', "jìn"),
+    ('㬒', "mǎng"),
+    ('㬓', "piào"),
+    ('㬔', "háo"),
+    ('㬕', "yáng"),
+    ('㬗', "xiàn"),
+    ('㬘', "sù"),
+    ('㬙', "wěi"),
+    ('㬚', "chè"),
+    ('㬛', "xī"),
+    ('㬜', "jìn"),
+    ('㬝', "céng"),
+    ('㬞', "hè"),
+    ('㬟', "fēn"),
+    ('㬠', "shài"),
+    ('㬡', "líng"),
+    ('㬣', "duì"),
+    ('㬤', "qī"),
+    ('㬥', "pù"),
+    ('㬦', "yuè"),
+    ('㬧', "bó"),
+    ('㬩', "huì"),
+    ('㬪', "dié"),
+    ('㬫', "yàn"),
+    ('㬬', "jù"),
+    ('㬭', "jiào"),
+    ('㬮', "nàn"),
+    ('㬯', "liè"),
+    ('㬰', "yú"),
+    ('㬱', "tì"),
+    ('㬲', "tiān"),
+    ('㬳', "wǔ"),
+    ('㬴', "hǒng"),
+    ('㬵', "xiáo"),
+    ('㬶', "hào"),
+    ('㬸', "tiāo"),
+    ('㬹', "zhēng"),
+    ('㬻', "huāng"),
+    ('㬼', "fù"),
+    ('㬿', "tūn"),
+    ('㭁', "réng"),
+    ('㭂', "jiǎo"),
+    ('㭄', "xìn"),
+    ('㭇', "yuàn"),
+    ('㭈', "jué"),
+    ('㭉', "huá"),
+    ('㭋', "bàng"),
+    ('㭌', "móu"),
+    ('㭎', "gāng"),
+    ('㭏', "wěi"),
+    ('㭑', "mèi"),
+    ('㭒', "sì"),
+    ('㭓', "biàn"),
+    ('㭔', "lú"),
+    ('㭕', "qū"),
+    ('㭘', "gé"),
+    ('㭙', "zhé"),
+    ('㭚', "lǚ"),
+    ('㭛', "pài"),
+    ('㭜', "róng"),
+    ('㭝', "qiú"),
+    ('㭞', "liè"),
+    ('㭟', "gǒng"),
+    ('㭠', "xiǎn"),
+    ('㭡', "xì"),
+    ('㭢', "xīn"),
+    ('㭤', "niǎo"),
+    ('㭨', "xié"),
+    ('㭩', "liè"),
+    ('㭪', "fū"),
+    ('㭫', "cuó"),
+    ('㭬', "zhuó"),
+    ('㭭', "bā"),
+    ('㭮', "zuò"),
+    ('㭯', "zhé"),
+    ('㭰', "zuī"),
+    ('㭱', "hé"),
+    ('㭲', "jí"),
+    ('㭴', "jiān"),
+    ('㭸', "tú"),
+    ('㭹', "xián"),
+    ('㭺', "yǎn"),
+    ('㭻', "táng"),
+    ('㭼', "tà"),
+    ('㭽', "dǐ"),
+    ('㭾', "jué"),
+    ('㭿', "áng"),
+    ('㮀', "hán"),
+    ('㮁', "xiáo"),
+    ('㮂', "jú"),
+    ('㮃', "wēi"),
+    ('㮄', "bǎng"),
+    ('㮅', "zhuī"),
+    ('㮆', "niè"),
+    ('㮇', "tiàn"),
+    ('㮈', "nài"),
+    ('㮋', "yǒu"),
+    ('㮌', "mián"),
+    ('㮏', "nài"),
+    ('㮐', "shěng"),
+    ('㮑', "chā"),
+    ('㮒', "yān"),
+    ('㮓', "gèn"),
+    ('㮔', "chòng"),
+    ('㮕', "ruǎn"),
+    ('㮖', "jiá"),
+    ('㮗', "qín"),
+    ('㮘', "máo"),
+    ('㮙', "è"),
+    ('㮚', "lì"),
+    ('㮛', "chí"),
+    ('㮜', "zāng"),
+    ('㮝', "hé"),
+    ('㮞', "jié"),
+    ('㮟', "niǎn"),
+    ('㮡', "guàn"),
+    ('㮢', "hóu"),
+    ('㮣', "gài"),
+    ('㮥', "bèn"),
+    ('㮦', "suǒ"),
+    ('㮧', "wū"),
+    ('㮨', "jì"),
+    ('㮩', "xī"),
+    ('㮪', "qióng"),
+    ('㮫', "hé"),
+    ('㮬', "wēng"),
+    ('㮭', "xián"),
+    ('㮮', "jié"),
+    ('㮯', "hún"),
+    ('㮰', "pí"),
+    ('㮱', "shēn"),
+    ('㮲', "chōu"),
+    ('㮳', "zhèn"),
+    ('㮵', "zhān"),
+    ('㮶', "shuò"),
+    ('㮷', "jī"),
+    ('㮸', "sòng"),
+    ('㮹', "zhǐ"),
+    ('㮺', "běn"),
+    ('㮾', "lǎng"),
+    ('㮿', "bì"),
+    ('㯀', "xuàn"),
+    ('㯁', "péi"),
+    ('㯂', "dài"),
+    ('㯃', "qī"),
+    ('㯄', "zhī"),
+    ('㯅', "pí"),
+    ('㯆', "chǎn"),
+    ('㯇', "bì"),
+    ('㯈', "sù"),
+    ('㯉', "huò"),
+    ('㯊', "hén"),
+    ('㯋', "jiǒng"),
+    ('㯌', "chuán"),
+    ('㯍', "jiǎng"),
+    ('㯎', "nèn"),
+    ('㯏', "gǔ"),
+    ('㯐', "fǎng"),
+    ('㯓', "tà"),
+    ('㯔', "cuì"),
+    ('㯕', "xī"),
+    ('㯖', "dé"),
+    ('㯗', "xián"),
+    ('㯘', "kuǎn"),
+    ('㯙', "zhé"),
+    ('㯚', "tā"),
+    ('㯛', "hú"),
+    ('㯜', "cuì"),
+    ('㯝', "lù"),
+    ('㯞', "juàn"),
+    ('㯟', "lù"),
+    ('㯠', "qiàn"),
+    ('㯡', "pào"),
+    ('㯢', "zhèn"),
+    ('㯤', "lì"),
+    ('㯥', "cáo"),
+    ('㯦', "qí"),
+    ('㯩', "tì"),
+    ('㯪', "líng"),
+    ('㯫', "qú"),
+    ('㯬', "liǎn"),
+    ('㯭', "lǔ"),
+    ('㯮', "shú"),
+    ('㯯', "gòng"),
+    ('㯰', "zhé"),
+    ('㯱', "pāo"),
+    ('㯲', "jìn"),
+    ('㯳', "qíng"),
+    ('㯶', "zōng"),
+    ('㯷', "pú"),
+    ('㯸', "jǐn"),
+    ('㯹', "biǎo"),
+    ('㯺', "jiàn"),
+    ('㯻', "gǔn"),
+    ('㯽', "bīn"),
+    ('㯾', "zāo"),
+    ('㯿', "liè"),
+    ('㰀', "lí"),
+    ('㰁', "luǒ"),
+    ('㰂', "shěn"),
+    ('㰃', "mián"),
+    ('㰄', "jiàn"),
+    ('㰅', "dí"),
+    ('㰆', "bèi"),
+    ('㰈', "liǎn"),
+    ('㰊', "xián"),
+    ('㰋', "pín"),
+    ('㰌', "què"),
+    ('㰍', "lóng"),
+    ('㰎', "zuì"),
+    ('㰐', "jué"),
+    ('㰑', "shān"),
+    ('㰒', "xué"),
+    ('㰔', "xiè"),
+    ('㰖', "lǎn"),
+    ('㰗', "qí"),
+    ('㰘', "yí"),
+    ('㰙', "nuó"),
+    ('㰚', "lí"),
+    ('㰛', "yuè"),
+    ('㰝', "yǐ"),
+    ('㰞', "chī"),
+    ('㰟', "jì"),
+    ('㰠', "hāng"),
+    ('㰡', "xiè"),
+    ('㰢', "kēng"),
+    ('㰣', "zī"),
+    ('㰤', "hē"),
+    ('㰥', "xì"),
+    ('㰦', "qù"),
+    ('㰧', "hāi"),
+    ('㰨', "xiā"),
+    ('㰩', "hāi"),
+    ('㰪', "guī"),
+    ('㰫', "chān"),
+    ('㰬', "xún"),
+    ('㰭', "xū"),
+    ('㰮', "shèn"),
+    ('㰯', "kòu"),
+    ('㰰', "xiā"),
+    ('㰱', "shà"),
+    ('㰲', "yū"),
+    ('㰳', "yà"),
+    ('㰴', "pǒu"),
+    ('㰵', "zú"),
+    ('㰶', "yǒu"),
+    ('㰷', "zì"),
+    ('㰸', "liǎn"),
+    ('㰹', "xiān"),
+    ('㰺', "xià"),
+    ('㰻', "yǐ"),
+    ('㰼', "shà"),
+    ('㰽', "yàn"),
+    ('㰾', "jiào"),
+    ('㰿', "xī"),
+    ('㱀', "chǐ"),
+    ('㱁', "shì"),
+    ('㱂', "kāng"),
+    ('㱃', "yǐn"),
+    ('㱄', "hēi"),
+    ('㱅', "yì"),
+    ('㱆', "xī"),
+    ('㱇', "sè"),
+    ('㱈', "jìn"),
+    ('㱉', "yè"),
+    ('㱊', "yōu"),
+    ('㱋', "què"),
+    ('㱌', "yé"),
+    ('㱍', "luán"),
+    ('㱎', "kūn"),
+    ('㱏', "zhèng"),
+    ('㱔', "xiē"),
+    ('㱖', "cuì"),
+    ('㱗', "xiū"),
+    ('㱘', "àn"),
+    ('㱙', "xiǔ"),
+    ('㱚', "cán"),
+    ('㱛', "chuǎn"),
+    ('㱜', "zhá"),
+    ('㱞', "yì"),
+    ('㱟', "pī"),
+    ('㱠', "kū"),
+    ('㱡', "shēng"),
+    ('㱢', "láng"),
+    ('㱣', "tuǐ"),
+    ('㱤', "xī"),
+    ('㱥', "líng"),
+    ('㱦', "qī"),
+    ('㱧', "wò"),
+    ('㱨', "liàn"),
+    ('㱩', "dú"),
+    ('㱪', "mèn"),
+    ('㱫', "làn"),
+    ('㱬', "wěi"),
+    ('㱭', "duàn"),
+    ('㱮', "kuài"),
+    ('㱯', "ái"),
+    ('㱰', "zǎi"),
+    ('㱱', "huì"),
+    ('㱲', "yì"),
+    ('㱳', "mò"),
+    ('㱴', "zì"),
+    ('㱵', "fèn"),
+    ('㱶', "péng"),
+    ('㱸', "bì"),
+    ('㱹', "lì"),
+    ('㱺', "lú"),
+    ('㱻', "luò"),
+    ('㱼', "hāi"),
+    ('㱽', "zhěn"),
+    ('㱾', "gāi"),
+    ('㱿', "què"),
+    ('㲀', "zhēn"),
+    ('㲁', "kōng"),
+    ('㲂', "chéng"),
+    ('㲃', "jiù"),
+    ('㲄', "jué"),
+    ('㲅', "jì"),
+    ('㲆', "líng"),
+    ('㲈', "sháo"),
+    ('㲉', "què"),
+    ('㲊', "ruì"),
+    ('㲋', "chuò"),
+    ('㲌', "nèng"),
+    ('㲍', "zhī"),
+    ('㲎', "lóu"),
+    ('㲏', "pāo"),
+    ('㲒', "bào"),
+    ('㲓', "róng"),
+    ('㲔', "xiān"),
+    ('㲕', "lèi"),
+    ('㲖', "xiāo"),
+    ('㲗', "fū"),
+    ('㲘', "qú"),
+    ('㲚', "shā"),
+    ('㲛', "zhǐ"),
+    ('㲜', "tán"),
+    ('㲝', "rǒng"),
+    ('㲞', "sū"),
+    ('㲟', "yǐng"),
+    ('㲠', "máo"),
+    ('㲡', "nài"),
+    ('㲢', "biàn"),
+    ('㲤', "shuāi"),
+    ('㲥', "táng"),
+    ('㲦', "hàn"),
+    ('㲧', "sào"),
+    ('㲨', "róng"),
+    ('㲪', "dēng"),
+    ('㲫', "pú"),
+    ('㲬', "jiāo"),
+    ('㲭', "tǎn"),
+    ('㲯', "rán"),
+    ('㲰', "níng"),
+    ('㲱', "liè"),
+    ('㲲', "dié"),
+    ('㲳', "dié"),
+    ('㲴', "zhòng"),
+    ('㲶', "lǜ"),
+    ('㲷', "dàn"),
+    ('㲸', "xī"),
+    ('㲹', "guǐ"),
+    ('㲺', "jí"),
+    ('㲻', "nì"),
+    ('㲼', "yì"),
+    ('㲽', "niàn"),
+    ('㲾', "yǔ"),
+    ('㲿', "wǎng"),
+    ('㳀', "guò"),
+    ('㳁', "zè"),
+    ('㳂', "yán"),
+    ('㳃', "cuì"),
+    ('㳄', "xián"),
+    ('㳅', "jiǎo"),
+    ('㳆', "tǒu"),
+    ('㳇', "fù"),
+    ('㳈', "pèi"),
+    ('㳊', "yōu"),
+    ('㳋', "qiū"),
+    ('㳌', "yā"),
+    ('㳍', "bù"),
+    ('㳎', "biàn"),
+    ('㳏', "shì"),
+    ('㳐', "zhá"),
+    ('㳑', "yì"),
+    ('㳒', "biàn"),
+    ('㳔', "duì"),
+    ('㳕', "lán"),
+    ('㳖', "yī"),
+    ('㳗', "chài"),
+    ('㳘', "chōng"),
+    ('㳙', "xuàn"),
+    ('㳚', "xù"),
+    ('㳛', "yú"),
+    ('㳜', "xiū"),
+    ('㳠', "tà"),
+    ('㳡', "guō"),
+    ('㳥', "lòng"),
+    ('㳦', "xiè"),
+    ('㳧', "chè"),
+    ('㳨', "jiǎn"),
+    ('㳩', "tān"),
+    ('㳪', "pì"),
+    ('㳫', "zǎn"),
+    ('㳬', "xuán"),
+    ('㳭', "xián"),
+    ('㳮', "niào"),
+    ('㳴', "mì"),
+    ('㳵', "jì"),
+    ('㳶', "nǒu"),
+    ('㳷', "hū"),
+    ('㳸', "huā"),
+    ('㳹', "wǎng"),
+    ('㳺', "yóu"),
+    ('㳻', "zé"),
+    ('㳼', "bì"),
+    ('㳽', "mǐ"),
+    ('㳾', "qiāng"),
+    ('㳿', "xiè"),
+    ('㴀', "fàn"),
+    ('㴁', "yì"),
+    ('㴂', "tān"),
+    ('㴃', "lèi"),
+    ('㴄', "yǒng"),
+    ('㴆', "jìn"),
+    ('㴇', "shè"),
+    ('㴈', "yìn"),
+    ('㴉', "jǐ"),
+    ('㴋', "sù"),
+    ('㴎', "nài"),
+    ('㴏', "wǎng"),
+    ('㴐', "miàn"),
+    ('㴑', "sù"),
+    ('㴒', "yì"),
+    ('㴓', "shāi"),
+    ('㴔', "xī"),
+    ('㴕', "jí"),
+    ('㴖', "luò"),
+    ('㴗', "yōu"),
+    ('㴘', "mào"),
+    ('㴙', "zhǎ"),
+    ('㴚', "suì"),
+    ('㴛', "zhì"),
+    ('㴜', "biàn"),
+    ('㴝', "lí"),
+    ('㴥', "qiào"),
+    ('㴦', "guàn"),
+    ('㴧', "xī"),
+    ('㴨', "zhèn"),
+    ('㴩', "yōng"),
+    ('㴪', "niè"),
+    ('㴫', "jùn"),
+    ('㴬', "xiè"),
+    ('㴭', "yǎo"),
+    ('㴮', "xiè"),
+    ('㴯', "zhī"),
+    ('㴰', "néng"),
+    ('㴲', "sī"),
+    ('㴳', "lǒng"),
+    ('㴴', "chén"),
+    ('㴵', "mì"),
+    ('㴶', "què"),
+    ('㴷', "dān"),
+    ('㴸', "shǎn"),
+    ('㴼', "sù"),
+    ('㴽', "xiè"),
+    ('㴾', "bó"),
+    ('㴿', "dǐng"),
+    ('㵀', "zú"),
+    ('㵂', "shù"),
+    ('㵃', "shé"),
+    ('㵄', "hàn"),
+    ('㵅', "tān"),
+    ('㵆', "gǎo"),
+    ('㵊', "nà"),
+    ('㵋', "mì"),
+    ('㵌', "xún"),
+    ('㵍', "mèn"),
+    ('㵎', "jiàn"),
+    ('㵏', "cuǐ"),
+    ('㵐', "jué"),
+    ('㵑', "hè"),
+    ('㵒', "fèi"),
+    ('㵓', "shí"),
+    ('㵔', "chě"),
+    ('㵕', "shèn"),
+    ('㵖', "nǜ"),
+    ('㵗', "píng"),
+    ('㵘', "màn"),
+    ('㵝', "yì"),
+    ('㵞', "chóu"),
+    ('㵠', "kū"),
+    ('㵡', "báo"),
+    ('㵢', "léi"),
+    ('㵣', "kě"),
+    ('㵤', "shà"),
+    ('㵥', "bì"),
+    ('㵦', "suí"),
+    ('㵧', "gé"),
+    ('㵨', "pì"),
+    ('㵩', "yì"),
+    ('㵪', "xián"),
+    ('㵫', "nì"),
+    ('㵬', "yíng"),
+    ('㵭', "zhǔ"),
+    ('㵮', "chún"),
+    ('㵯', "féng"),
+    ('㵰', "xù"),
+    ('㵱', "piǎo"),
+    ('㵲', "wǔ"),
+    ('㵳', "liáo"),
+    ('㵴', "cáng"),
+    ('㵵', "zòu"),
+    ('㵶', "zuō"),
+    ('㵷', "biàn"),
+    ('㵸', "yào"),
+    ('㵹', "huán"),
+    ('㵺', "pài"),
+    ('㵻', "xiū"),
+    ('㵽', "lěi"),
+    ('㵾', "qìng"),
+    ('㵿', "xiào"),
+    ('㶀', "jiāo"),
+    ('㶁', "guó"),
+    ('㶄', "yán"),
+    ('㶅', "xué"),
+    ('㶆', "zhū"),
+    ('㶇', "héng"),
+    ('㶈', "yíng"),
+    ('㶉', "xī"),
+    ('㶌', "lián"),
+    ('㶍', "xiǎn"),
+    ('㶎', "huán"),
+    ('㶏', "yīn"),
+    ('㶑', "liàn"),
+    ('㶒', "shǎn"),
+    ('㶓', "cáng"),
+    ('㶔', "bèi"),
+    ('㶕', "jiǎn"),
+    ('㶖', "shù"),
+    ('㶗', "fàn"),
+    ('㶘', "diàn"),
+    ('㶚', "bà"),
+    ('㶛', "yú"),
+    ('㶞', "nǎng"),
+    ('㶟', "lěi"),
+    ('㶠', "yì"),
+    ('㶡', "dài"),
+    ('㶣', "chán"),
+    ('㶤', "chǎo"),
+    ('㶥', "gān"),
+    ('㶦', "jìn"),
+    ('㶧', "nèn"),
+    ('㶫', "liǎo"),
+    ('㶬', "mò"),
+    ('㶭', "yǒu"),
+    ('㶯', "liù"),
+    ('㶰', "hán"),
+    ('㶲', "yòng"),
+    ('㶳', "jìn"),
+    ('㶴', "chǐ"),
+    ('㶵', "rèn"),
+    ('㶶', "nóng"),
+    ('㶹', "hòng"),
+    ('㶺', "tiàn"),
+    ('㶼', "āi"),
+    ('㶽', "guā"),
+    ('㶾', "biāo"),
+    ('㶿', "bó"),
+    ('㷀', "qióng"),
+    ('㷂', "shù"),
+    ('㷃', "chuǐ"),
+    ('㷄', "huǐ"),
+    ('㷅', "chǎo"),
+    ('㷆', "fù"),
+    ('㷇', "huī"),
+    ('㷈', "è"),
+    ('㷉', "wèi"),
+    ('㷊', "fén"),
+    ('㷋', "tán"),
+    ('㷍', "lún"),
+    ('㷎', "hè"),
+    ('㷏', "yǒng"),
+    ('㷐', "huǐ"),
+    ('㷒', "yú"),
+    ('㷓', "zǒng"),
+    ('㷔', "yàn"),
+    ('㷕', "qiú"),
+    ('㷖', "zhào"),
+    ('㷗', "jiǒng"),
+    ('㷘', "tái"),
+    ('㷟', "tuì"),
+    ('㷠', "lín"),
+    ('㷡', "jiǒng"),
+    ('㷢', "zhǎ"),
+    ('㷣', "xīng"),
+    ('㷤', "hù"),
+    ('㷦', "xù"),
+    ('㷪', "cuì"),
+    ('㷫', "qǐng"),
+    ('㷬', "mò"),
+    ('㷮', "zāo"),
+    ('㷯', "bèng"),
+    ('㷰', "chī"),
+    ('㷳', "yàn"),
+    ('㷴', "gé"),
+    ('㷵', "mò"),
+    ('㷶', "bèi"),
+    ('㷷', "juǎn"),
+    ('㷸', "dié"),
+    ('㷹', "zhào"),
+    ('㷻', "wú"),
+    ('㷼', "yàn"),
+    ('㷾', "jué"),
+    ('㷿', "xiān"),
+    ('㸀', "tái"),
+    ('㸁', "hǎn"),
+    ('㸃', "diǎn"),
+    ('㸄', "jì"),
+    ('㸅', "jié"),
+    ('㸆', "kào"),
+    ('㸇', "zuǎn"),
+    ('㸉', "xiè"),
+    ('㸊', "lài"),
+    ('㸋', "fán"),
+    ('㸌', "huò"),
+    ('㸍', "xì"),
+    ('㸎', "niè"),
+    ('㸏', "mí"),
+    ('㸐', "rán"),
+    ('㸑', "cuàn"),
+    ('㸒', "yín"),
+    ('㸓', "mì"),
+    ('㸕', "jué"),
+    ('㸖', "qū"),
+    ('㸗', "tóng"),
+    ('㸘', "wàn"),
+    ('㸙', "zhē"),
+    ('㸚', "lǐ"),
+    ('㸛', "sháo"),
+    ('㸜', "kòng"),
+    ('㸝', "xiān"),
+    ('㸞', "zhé"),
+    ('㸟', "zhī"),
+    ('㸠', "tiǎo"),
+    ('㸡', "shū"),
+    ('㸢', "bèi"),
+    ('㸣', "yè"),
+    ('㸤', "piàn"),
+    ('㸥', "chàn"),
+    ('㸦', "hù"),
+    ('㸧', "kèn"),
+    ('㸨', "jiū"),
+    ('㸩', "ān"),
+    ('㸪', "chún"),
+    ('㸫', "qián"),
+    ('㸬', "bèi"),
+    ('㸭', "bā"),
+    ('㸮', "fén"),
+    ('㸯', "kē"),
+    ('㸰', "tuó"),
+    ('㸱', "tuó"),
+    ('㸲', "zuó"),
+    ('㸳', "líng"),
+    ('㸵', "guǐ"),
+    ('㸶', "yān"),
+    ('㸷', "shì"),
+    ('㸸', "hǒu"),
+    ('㸹', "liè"),
+    ('㸺', "shā"),
+    ('㸻', "sì"),
+    ('㸽', "bèi"),
+    ('㸾', "rèn"),
+    ('㸿', "dú"),
+    ('㹀', "bó"),
+    ('㹁', "liáng"),
+    ('㹂', "qiǎn"),
+    ('㹃', "fèi"),
+    ('㹄', "jì"),
+    ('㹅', "zǒng"),
+    ('㹆', "huī"),
+    ('㹇', "hé"),
+    ('㹈', "lí"),
+    ('㹉', "yuán"),
+    ('㹊', "yuè"),
+    ('㹋', "xiū"),
+    ('㹌', "chǎn"),
+    ('㹍', "dí"),
+    ('㹎', "léi"),
+    ('㹏', "jǐn"),
+    ('㹐', "chóng"),
+    ('㹑', "sì"),
+    ('㹒', "pǔ"),
+    ('㹓', "yǎo"),
+    ('㹔', "jiāng"),
+    ('㹕', "huān"),
+    ('㹖', "huàn"),
+    ('㹗', "tāo"),
+    ('㹘', "rù"),
+    ('㹙', "wěng"),
+    ('㹚', "yíng"),
+    ('㹛', "ráo"),
+    ('㹜', "yín"),
+    ('㹝', "shì"),
+    ('㹞', "yín"),
+    ('㹟', "jué"),
+    ('㹠', "tún"),
+    ('㹡', "xuán"),
+    ('㹢', "jiā"),
+    ('㹣', "zhōng"),
+    ('㹤', "qiè"),
+    ('㹥', "zhù"),
+    ('㹦', "diāo"),
+    ('㹨', "yòu"),
+    ('㹫', "yí"),
+    ('㹬', "shǐ"),
+    ('㹭', "yì"),
+    ('㹮', "mò"),
+    ('㹱', "què"),
+    ('㹲', "xiāo"),
+    ('㹳', "wú"),
+    ('㹴', "gēng"),
+    ('㹵', "yǐng"),
+    ('㹶', "tíng"),
+    ('㹷', "shǐ"),
+    ('㹸', "ní"),
+    ('㹹', "gēng"),
+    ('㹺', "tà"),
+    ('㹻', "wō"),
+    ('㹼', "jú"),
+    ('㹽', "chǎn"),
+    ('㹾', "piǎo"),
+    ('㹿', "zhuó"),
+    ('㺀', "hū"),
+    ('㺁', "nǎo"),
+    ('㺂', "yán"),
+    ('㺃', "gǒu"),
+    ('㺄', "yǔ"),
+    ('㺅', "hóu"),
+    ('㺇', "sī"),
+    ('㺈', "chī"),
+    ('㺉', "hù"),
+    ('㺊', "yàng"),
+    ('㺋', "wēng"),
+    ('㺌', "xiàn"),
+    ('㺍', "pín"),
+    ('㺎', "róng"),
+    ('㺏', "lóu"),
+    ('㺐', "lǎo"),
+    ('㺑', "shān"),
+    ('㺒', "xiāo"),
+    ('㺓', "zé"),
+    ('㺔', "hài"),
+    ('㺕', "fán"),
+    ('㺖', "hǎn"),
+    ('㺗', "chān"),
+    ('㺘', "zhàn"),
+    ('㺚', "tǎ"),
+    ('㺛', "zhù"),
+    ('㺜', "nóng"),
+    ('㺝', "hàn"),
+    ('㺞', "yú"),
+    ('㺟', "zhuó"),
+    ('㺠', "yòu"),
+    ('㺡', "lì"),
+    ('㺢', "huò"),
+    ('㺣', "xī"),
+    ('㺤', "xiān"),
+    ('㺥', "chán"),
+    ('㺦', "lián"),
+    ('㺨', "sī"),
+    ('㺩', "jiù"),
+    ('㺪', "pú"),
+    ('㺫', "qiú"),
+    ('㺬', "gǒng"),
+    ('㺭', "zǐ"),
+    ('㺮', "yú"),
+    ('㺱', "réng"),
+    ('㺲', "niǔ"),
+    ('㺳', "méi"),
+    ('㺴', "bā"),
+    ('㺵', "jiú"),
+    ('㺷', "xù"),
+    ('㺸', "píng"),
+    ('㺹', "biàn"),
+    ('㺺', "mào"),
+    ('㺿', "yí"),
+    ('㻀', "yú"),
+    ('㻂', "píng"),
+    ('㻃', "qū"),
+    ('㻄', "bǎo"),
+    ('㻅', "huì"),
+    ('㻉', "bù"),
+    ('㻊', "máng"),
+    ('㻋', "là"),
+    ('㻌', "tú"),
+    ('㻍', "wú"),
+    ('㻎', "lì"),
+    ('㻏', "líng"),
+    ('㻑', "jì"),
+    ('㻒', "jùn"),
+    ('㻓', "zōu"),
+    ('㻔', "duǒ"),
+    ('㻕', "jué"),
+    ('㻖', "dài"),
+    ('㻗', "bèi"),
+    ('㻝', "là"),
+    ('㻞', "bīn"),
+    ('㻟', "suí"),
+    ('㻠', "tú"),
+    ('㻡', "xuē"),
+    ('㻧', "duò"),
+    ('㻪', "suì"),
+    ('㻫', "bì"),
+    ('㻬', "tū"),
+    ('㻭', "sè"),
+    ('㻮', "càn"),
+    ('㻯', "tú"),
+    ('㻰', "miǎn"),
+    ('㻱', "jīn"),
+    ('㻲', "lǚ"),
+    ('㻵', "zhàn"),
+    ('㻶', "bǐ"),
+    ('㻷', "jí"),
+    ('㻸', "zēn"),
+    ('㻹', "xuān"),
+    ('㻺', "lì"),
+    ('㻽', "suì"),
+    ('㻾', "yōng"),
+    ('㻿', "shǔ"),
+    ('㼂', "é"),
+    ('㼇', "qióng"),
+    ('㼈', "luó"),
+    ('㼉', "zhèn"),
+    ('㼊', "tún"),
+    ('㼋', "gū"),
+    ('㼌', "yǔ"),
+    ('㼍', "lěi"),
+    ('㼎', "bó"),
+    ('㼏', "něi"),
+    ('㼐', "pián"),
+    ('㼑', "liàn"),
+    ('㼒', "tǎng"),
+    ('㼓', "lián"),
+    ('㼔', "wēn"),
+    ('㼕', "dāng"),
+    ('㼖', "lì"),
+    ('㼗', "tíng"),
+    ('㼘', "wǎ"),
+    ('㼙', "zhòu"),
+    ('㼚', "gāng"),
+    ('㼛', "xíng"),
+    ('㼜', "àng"),
+    ('㼝', "fàn"),
+    ('㼞', "pèng"),
+    ('㼟', "bó"),
+    ('㼠', "tuó"),
+    ('㼡', "shū"),
+    ('㼢', "yí"),
+    ('㼣', "bó"),
+    ('㼤', "qiè"),
+    ('㼥', "tǒu"),
+    ('㼦', "gǒng"),
+    ('㼧', "tóng"),
+    ('㼨', "hán"),
+    ('㼩', "chéng"),
+    ('㼪', "jié"),
+    ('㼫', "huàn"),
+    ('㼬', "xìng"),
+    ('㼭', "diàn"),
+    ('㼮', "chāi"),
+    ('㼯', "dòng"),
+    ('㼰', "pí"),
+    ('㼱', "ruǎn"),
+    ('㼲', "liè"),
+    ('㼳', "shěng"),
+    ('㼴', "ǒu"),
+    ('㼵', "dì"),
+    ('㼶', "yú"),
+    ('㼷', "chuán"),
+    ('㼸', "róng"),
+    ('㼹', "kāng"),
+    ('㼺', "táng"),
+    ('㼻', "cóng"),
+    ('㼼', "piáo"),
+    ('㼽', "chuǎng"),
+    ('㼾', "lù"),
+    ('㼿', "tóng"),
+    ('㽀', "zhèng"),
+    ('㽁', "lì"),
+    ('㽂', "sà"),
+    ('㽃', "pān"),
+    ('㽄', "sī"),
+    ('㽆', "dāng"),
+    ('㽇', "hú"),
+    ('㽈', "yì"),
+    ('㽉', "xiàn"),
+    ('㽊', "xiè"),
+    ('㽋', "luó"),
+    ('㽌', "liù"),
+    ('㽎', "tán"),
+    ('㽏', "gàn"),
+    ('㽑', "tán"),
+    ('㽕', "yóu"),
+    ('㽖', "nán"),
+    ('㽘', "gǎng"),
+    ('㽙', "jùn"),
+    ('㽚', "chì"),
+    ('㽛', "gōu"),
+    ('㽜', "wǎn"),
+    ('㽝', "lì"),
+    ('㽞', "liú"),
+    ('㽟', "liè"),
+    ('㽠', "xiá"),
+    ('㽡', "bēi"),
+    ('㽢', "ǎn"),
+    ('㽣', "yù"),
+    ('㽤', "jú"),
+    ('㽥', "róu"),
+    ('㽦', "xún"),
+    ('㽧', "zī"),
+    ('㽨', "cuó"),
+    ('㽩', "càn"),
+    ('㽪', "zěng"),
+    ('㽫', "yōng"),
+    ('㽬', "fù"),
+    ('㽭', "ruǎn"),
+    ('㽯', "xí"),
+    ('㽰', "shù"),
+    ('㽱', "jiǎo"),
+    ('㽲', "jiǎo"),
+    ('㽳', "xū"),
+    ('㽴', "zhàng"),
+    ('㽷', "shuì"),
+    ('㽸', "chén"),
+    ('㽹', "fǎn"),
+    ('㽺', "jí"),
+    ('㽻', "zhī"),
+    ('㽽', "gù"),
+    ('㽾', "wù"),
+    ('㾀', "qiè"),
+    ('㾁', "shù"),
+    ('㾂', "hāi"),
+    ('㾃', "tuó"),
+    ('㾄', "dú"),
+    ('㾅', "zǐ"),
+    ('㾆', "rán"),
+    ('㾇', "mù"),
+    ('㾈', "fù"),
+    ('㾉', "líng"),
+    ('㾊', "jí"),
+    ('㾋', "xiū"),
+    ('㾌', "xuǎn"),
+    ('㾍', "nái"),
+    ('㾎', "yā"),
+    ('㾏', "jiè"),
+    ('㾐', "lì"),
+    ('㾑', "dá"),
+    ('㾒', "rú"),
+    ('㾓', "yuān"),
+    ('㾔', "lǚ"),
+    ('㾕', "shěn"),
+    ('㾖', "lǐ"),
+    ('㾗', "liàng"),
+    ('㾘', "gěng"),
+    ('㾙', "xìn"),
+    ('㾚', "xiē"),
+    ('㾛', "qǐn"),
+    ('㾜', "qiè"),
+    ('㾝', "chè"),
+    ('㾞', "yóu"),
+    ('㾟', "bù"),
+    ('㾠', "kuáng"),
+    ('㾡', "què"),
+    ('㾢', "ài"),
+    ('㾣', "qīn"),
+    ('㾤', "qiāng"),
+    ('㾥', "chù"),
+    ('㾦', "pèi"),
+    ('㾧', "kuò"),
+    ('㾨', "yī"),
+    ('㾩', "guāi"),
+    ('㾪', "shěng"),
+    ('㾫', "piān"),
+    ('㾭', "zhòu"),
+    ('㾮', "huáng"),
+    ('㾯', "huī"),
+    ('㾰', "hú"),
+    ('㾱', "bèi"),
+    ('㾴', "zhā"),
+    ('㾵', "jì"),
+    ('㾶', "gǔ"),
+    ('㾷', "xī"),
+    ('㾸', "gǎo"),
+    ('㾹', "chái"),
+    ('㾺', "mà"),
+    ('㾻', "zhù"),
+    ('㾼', "tuǐ"),
+    ('㾽', "zhuì"),
+    ('㾾', "xiān"),
+    ('㾿', "láng"),
+    ('㿃', "zhì"),
+    ('㿄', "ài"),
+    ('㿅', "xiǎn"),
+    ('㿆', "guō"),
+    ('㿇', "xí"),
+    ('㿉', "tuǐ"),
+    ('㿊', "cǎn"),
+    ('㿋', "sào"),
+    ('㿌', "xiān"),
+    ('㿍', "jiè"),
+    ('㿎', "fèn"),
+    ('㿏', "qún"),
+    ('㿑', "yào"),
+    ('㿒', "dǎo"),
+    ('㿓', "jiá"),
+    ('㿔', "lěi"),
+    ('㿕', "yán"),
+    ('㿖', "lú"),
+    ('㿗', "tuí"),
+    ('㿘', "yíng"),
+    ('㿙', "pì"),
+    ('㿚', "luò"),
+    ('㿛', "lì"),
+    ('㿜', "biě"),
+    ('㿞', "mào"),
+    ('㿟', "bái"),
+    ('㿠', "huàng"),
+    ('㿢', "yào"),
+    ('㿣', "hē"),
+    ('㿤', "chǔn"),
+    ('㿥', "hé"),
+    ('㿦', "nìng"),
+    ('㿧', "chóu"),
+    ('㿨', "lì"),
+    ('㿩', "tǎng"),
+    ('㿪', "huán"),
+    ('㿫', "bì"),
+    ('㿬', "bā"),
+    ('㿭', "chè"),
+    ('㿮', "yàng"),
+    ('㿯', "dá"),
+    ('㿰', "áo"),
+    ('㿱', "xué"),
+    ('㿳', "zī"),
+    ('㿴', "dā"),
+    ('㿵', "rǎn"),
+    ('㿶', "bāng"),
+    ('㿷', "cuó"),
+    ('㿸', "wǎn"),
+    ('㿹', "tà"),
+    ('㿺', "báo"),
+    ('㿻', "gān"),
+    ('㿼', "yán"),
+    ('㿽', "xī"),
+    ('㿾', "zhù"),
+    ('㿿', "yǎ"),
+    ('䀀', "fàn"),
+    ('䀁', "yòu"),
+    ('䀂', "ān"),
+    ('䀃', "tuí"),
+    ('䀄', "méng"),
+    ('䀅', "shè"),
+    ('䀆', "jìn"),
+    ('䀇', "gǔ"),
+    ('䀈', "jì"),
+    ('䀉', "qiáo"),
+    ('䀊', "jiǎo"),
+    ('䀋', "yán"),
+    ('䀌', "xì"),
+    ('䀍', "kàn"),
+    ('䀎', "miǎn"),
+    ('䀏', "xuàn"),
+    ('䀐', "shān"),
+    ('䀑', "wò"),
+    ('䀒', "qiān"),
+    ('䀓', "huàn"),
+    ('䀔', "rèn"),
+    ('䀕', "zhèn"),
+    ('䀖', "tiān"),
+    ('䀗', "jué"),
+    ('䀘', "xié"),
+    ('䀙', "qì"),
+    ('䀚', "áng"),
+    ('䀛', "mèi"),
+    ('䀜', "gǔ"),
+    ('䀞', "tāo"),
+    ('䀟', "fán"),
+    ('䀠', "jù"),
+    ('䀡', "chàn"),
+    ('䀢', "shùn"),
+    ('䀣', "bì"),
+    ('䀤', "mào"),
+    ('䀥', "shuò"),
+    ('䀦', "gǔ"),
+    ('䀧', "hǒng"),
+    ('䀨', "huà"),
+    ('䀩', "luò"),
+    ('䀪', "háng"),
+    ('䀫', "jiá"),
+    ('䀬', "quán"),
+    ('䀭', "gāi"),
+    ('䀮', "huāng"),
+    ('䀯', "bǔ"),
+    ('䀰', "gǔ"),
+    ('䀱', "fēng"),
+    ('䀲', "mù"),
+    ('䀳', "ài"),
+    ('䀴', "yǐng"),
+    ('䀵', "shùn"),
+    ('䀶', "liàng"),
+    ('䀷', "jié"),
+    ('䀸', "chì"),
+    ('䀹', "jié"),
+    ('䀺', "chōu"),
+    ('䀻', "pìng"),
+    ('䀼', "chēn"),
+    ('䀽', "yán"),
+    ('䀾', "dǔ"),
+    ('䀿', "dì"),
+    ('䁁', "liàng"),
+    ('䁂', "xiàn"),
+    ('䁃', "biāo"),
+    ('䁄', "xìng"),
+    ('䁅', "měng"),
+    ('䁆', "yè"),
+    ('䁇', "mì"),
+    ('䁈', "qì"),
+    ('䁉', "qì"),
+    ('䁊', "wò"),
+    ('䁋', "xiè"),
+    ('䁌', "yù"),
+    ('䁍', "qià"),
+    ('䁎', "chéng"),
+    ('䁏', "yǎo"),
+    ('䁐', "yīng"),
+    ('䁑', "yáng"),
+    ('䁒', "jí"),
+    ('䁓', "zōng"),
+    ('䁔', "xuān"),
+    ('䁕', "mín"),
+    ('䁖', "lōu"),
+    ('䁗', "kǎi"),
+    ('䁘', "yǎo"),
+    ('䁙', "yǎn"),
+    ('䁚', "sǔn"),
+    ('䁛', "guì"),
+    ('䁜', "huàng"),
+    ('䁝', "yíng"),
+    ('䁞', "shěng"),
+    ('䁟', "chá"),
+    ('䁠', "lián"),
+    ('䁢', "xuán"),
+    ('䁣', "chuán"),
+    ('䁤', "chè"),
+    ('䁥', "nì"),
+    ('䁦', "qù"),
+    ('䁧', "miáo"),
+    ('䁨', "huò"),
+    ('䁩', "yú"),
+    ('䁪', "zhǎn"),
+    ('䁫', "hú"),
+    ('䁬', "céng"),
+    ('䁭', "biāo"),
+    ('䁮', "qián"),
+    ('䁯', "xī"),
+    ('䁰', "jiǎng"),
+    ('䁱', "kōu"),
+    ('䁲', "mái"),
+    ('䁳', "mǎng"),
+    ('䁴', "zhǎn"),
+    ('䁵', "biǎn"),
+    ('䁶', "jī"),
+    ('䁷', "jué"),
+    ('䁸', "náng"),
+    ('䁹', "bì"),
+    ('䁺', "shì"),
+    ('䁻', "shuò"),
+    ('䁼', "mò"),
+    ('䁽', "liè"),
+    ('䁾', "miè"),
+    ('䁿', "mò"),
+    ('䂀', "xī"),
+    ('䂁', "chán"),
+    ('䂂', "qú"),
+    ('䂃', "jiào"),
+    ('䂄', "huò"),
+    ('䂅', "xiān"),
+    ('䂆', "xù"),
+    ('䂇', "niǔ"),
+    ('䂈', "tóng"),
+    ('䂉', "hóu"),
+    ('䂊', "yù"),
+    ('䂌', "chōng"),
+    ('䂍', "bó"),
+    ('䂎', "zuǎn"),
+    ('䂏', "diāo"),
+    ('䂐', "zhuō"),
+    ('䂑', "jī"),
+    ('䂒', "qià"),
+    ('䂔', "xìng"),
+    ('䂕', "huì"),
+    ('䂖', "shí"),
+    ('䂗', "kū"),
+    ('䂙', "duī"),
+    ('䂚', "yáo"),
+    ('䂛', "yú"),
+    ('䂜', "bàng"),
+    ('䂝', "jié"),
+    ('䂞', "zhè"),
+    ('䂟', "jiā"),
+    ('䂠', "shǐ"),
+    ('䂡', "dǐ"),
+    ('䂢', "dǒng"),
+    ('䂣', "cí"),
+    ('䂤', "fù"),
+    ('䂥', "mín"),
+    ('䂦', "zhēn"),
+    ('䂧', "zhěn"),
+    ('䂩', "yàn"),
+    ('䂪', "qiǎo"),
+    ('䂫', "hāng"),
+    ('䂬', "gǒng"),
+    ('䂭', "qiāo"),
+    ('䂮', "lüè"),
+    ('䂯', "guài"),
+    ('䂰', "là"),
+    ('䂱', "ruì"),
+    ('䂲', "fǎ"),
+    ('䂳', "cuǒ"),
+    ('䂴', "yán"),
+    ('䂵', "gōng"),
+    ('䂶', "jié"),
+    ('䂷', "guāi"),
+    ('䂸', "guó"),
+    ('䂹', "suǒ"),
+    ('䂺', "wǒ"),
+    ('䂻', "zhèng"),
+    ('䂼', "niè"),
+    ('䂽', "diào"),
+    ('䂾', "lǎi"),
+    ('䂿', "tà"),
+    ('䃀', "cuì"),
+    ('䃁', "yā"),
+    ('䃂', "gǔn"),
+    ('䃅', "dī"),
+    ('䃇', "mián"),
+    ('䃈', "jiē"),
+    ('䃉', "mín"),
+    ('䃊', "jǔ"),
+    ('䃋', "yú"),
+    ('䃌', "zhēn"),
+    ('䃍', "zhào"),
+    ('䃎', "zhà"),
+    ('䃏', "xīng"),
+    ('䃑', "bān"),
+    ('䃒', "hé"),
+    ('䃓', "gòu"),
+    ('䃔', "hóng"),
+    ('䃕', "láo"),
+    ('䃖', "wù"),
+    ('䃗', "bō"),
+    ('䃘', "kēng"),
+    ('䃙', "lù"),
+    ('䃚', "cù"),
+    ('䃛', "lián"),
+    ('䃜', "yī"),
+    ('䃝', "qiào"),
+    ('䃞', "shú"),
+    ('䃠', "xuàn"),
+    ('䃡', "jīn"),
+    ('䃢', "qīn"),
+    ('䃣', "huǐ"),
+    ('䃤', "sù"),
+    ('䃥', "chuáng"),
+    ('䃦', "dūn"),
+    ('䃧', "lóng"),
+    ('䃩', "náo"),
+    ('䃪', "tán"),
+    ('䃫', "dǎn"),
+    ('䃬', "wěi"),
+    ('䃭', "gǎn"),
+    ('䃮', "dá"),
+    ('䃯', "lì"),
+    ('䃰', "cā"),
+    ('䃱', "xiàn"),
+    ('䃲', "pán"),
+    ('䃳', "là"),
+    ('䃴', "zhū"),
+    ('䃵', "niǎo"),
+    ('䃶', "huái"),
+    ('䃷', "yíng"),
+    ('䃸', "xiàn"),
+    ('䃹', "làn"),
+    ('䃺', "mó"),
+    ('䃻', "bà"),
+    ('䃽', "guǐ"),
+    ('䃾', "bǐ"),
+    ('䃿', "fū"),
+    ('䄀', "huò"),
+    ('䄁', "yì"),
+    ('䄂', "liù"),
+    ('䄃', "yāng"),
+    ('䄄', "yīn"),
+    ('䄅', "juàn"),
+    ('䄆', "huó"),
+    ('䄇', "chéng"),
+    ('䄈', "dòu"),
+    ('䄉', "é"),
+    ('䄋', "yǎn"),
+    ('䄌', "zhuì"),
+    ('䄍', "zhà"),
+    ('䄎', "qǐ"),
+    ('䄏', "yú"),
+    ('䄐', "quàn"),
+    ('䄑', "huó"),
+    ('䄒', "niè"),
+    ('䄓', "huáng"),
+    ('䄔', "jǔ"),
+    ('䄕', "shè"),
+    ('䄘', "péng"),
+    ('䄙', "míng"),
+    ('䄚', "cáo"),
+    ('䄛', "lóu"),
+    ('䄜', "lí"),
+    ('䄝', "chuāng"),
+    ('䄟', "cuī"),
+    ('䄠', "shàn"),
+    ('䄡', "dān"),
+    ('䄢', "qí"),
+    ('䄤', "lài"),
+    ('䄥', "líng"),
+    ('䄦', "liǎo"),
+    ('䄧', "réng"),
+    ('䄨', "yú"),
+    ('䄩', "yì"),
+    ('䄪', "diǎo"),
+    ('䄫', "qǐ"),
+    ('䄬', "yí"),
+    ('䄭', "nián"),
+    ('䄮', "fū"),
+    ('䄯', "jiǎn"),
+    ('䄰', "yá"),
+    ('䄱', "fāng"),
+    ('䄲', "ruì"),
+    ('䄳', "xiān"),
+    ('䄶', "bì"),
+    ('䄷', "shí"),
+    ('䄸', "pò"),
+    ('䄹', "nián"),
+    ('䄺', "zhì"),
+    ('䄻', "táo"),
+    ('䄼', "tiǎn"),
+    ('䄽', "tiǎn"),
+    ('䄾', "rù"),
+    ('䄿', "yì"),
+    ('䅀', "liè"),
+    ('䅁', "àn"),
+    ('䅂', "hé"),
+    ('䅃', "qióng"),
+    ('䅄', "lì"),
+    ('䅅', "guī"),
+    ('䅆', "zì"),
+    ('䅇', "sù"),
+    ('䅈', "yuàn"),
+    ('䅉', "yà"),
+    ('䅊', "chá"),
+    ('䅋', "wǎn"),
+    ('䅌', "juān"),
+    ('䅍', "tǐng"),
+    ('䅎', "yǒu"),
+    ('䅏', "huì"),
+    ('䅐', "jiǎn"),
+    ('䅑', "ruí"),
+    ('䅒', "máng"),
+    ('䅓', "jǔ"),
+    ('䅔', "zī"),
+    ('䅕', "jū"),
+    ('䅖', "ān"),
+    ('䅗', "suì"),
+    ('䅘', "lái"),
+    ('䅙', "hùn"),
+    ('䅚', "quǎn"),
+    ('䅛', "chāng"),
+    ('䅜', "duò"),
+    ('䅝', "kōng"),
+    ('䅞', "nè"),
+    ('䅟', "cǎn"),
+    ('䅠', "tí"),
+    ('䅡', "xǔ"),
+    ('䅢', "jiù"),
+    ('䅣', "huáng"),
+    ('䅤', "qì"),
+    ('䅥', "jié"),
+    ('䅦', "máo"),
+    ('䅧', "yān"),
+    ('䅩', "zhǐ"),
+    ('䅪', "tuí"),
+    ('䅬', "ài"),
+    ('䅭', "páng"),
+    ('䅮', "càng"),
+    ('䅯', "táng"),
+    ('䅰', "ěn"),
+    ('䅱', "hùn"),
+    ('䅲', "qí"),
+    ('䅳', "chú"),
+    ('䅴', "suǒ"),
+    ('䅵', "zhuó"),
+    ('䅶', "nòu"),
+    ('䅷', "tú"),
+    ('䅸', "shēn"),
+    ('䅹', "lǒu"),
+    ('䅺', "biāo"),
+    ('䅻', "lí"),
+    ('䅼', "mán"),
+    ('䅽', "xīn"),
+    ('䅾', "cén"),
+    ('䅿', "huáng"),
+    ('䆀', "měi"),
+    ('䆁', "gāo"),
+    ('䆂', "lián"),
+    ('䆃', "dào"),
+    ('䆄', "zhǎn"),
+    ('䆅', "zī"),
+    ('䆈', "zhì"),
+    ('䆉', "bà"),
+    ('䆊', "cuì"),
+    ('䆋', "qiū"),
+    ('䆍', "lóng"),
+    ('䆎', "xiān"),
+    ('䆏', "fèi"),
+    ('䆐', "guó"),
+    ('䆑', "chéng"),
+    ('䆒', "jiù"),
+    ('䆓', "è"),
+    ('䆔', "chōng"),
+    ('䆕', "yuè"),
+    ('䆖', "hóng"),
+    ('䆗', "yǎo"),
+    ('䆘', "yā"),
+    ('䆙', "yáo"),
+    ('䆚', "tóng"),
+    ('䆛', "zhà"),
+    ('䆜', "yòu"),
+    ('䆝', "xuè"),
+    ('䆞', "yǎo"),
+    ('䆟', "kè"),
+    ('䆠', "huàn"),
+    ('䆡', "láng"),
+    ('䆢', "yuè"),
+    ('䆣', "chén"),
+    ('䆦', "shèn"),
+    ('䆨', "níng"),
+    ('䆩', "míng"),
+    ('䆪', "hōng"),
+    ('䆫', "chuāng"),
+    ('䆬', "yǔn"),
+    ('䆭', "xuān"),
+    ('䆮', "jìn"),
+    ('䆯', "zhuó"),
+    ('䆰', "yū"),
+    ('䆱', "tān"),
+    ('䆲', "kāng"),
+    ('䆳', "qióng"),
+    ('䆵', "chéng"),
+    ('䆶', "jiū"),
+    ('䆷', "xuè"),
+    ('䆸', "zhēng"),
+    ('䆹', "chōng"),
+    ('䆺', "pān"),
+    ('䆻', "qiào"),
+    ('䆽', "qú"),
+    ('䆾', "lán"),
+    ('䆿', "yì"),
+    ('䇀', "róng"),
+    ('䇁', "sī"),
+    ('䇂', "qiān"),
+    ('䇃', "sì"),
+    ('䇅', "fá"),
+    ('䇇', "méng"),
+    ('䇈', "huà"),
+    ('䇋', "hài"),
+    ('䇌', "qiào"),
+    ('䇍', "chù"),
+    ('䇎', "què"),
+    ('䇏', "duì"),
+    ('䇐', "lì"),
+    ('䇑', "bà"),
+    ('䇒', "jiè"),
+    ('䇓', "xū"),
+    ('䇔', "luò"),
+    ('䇖', "yǔn"),
+    ('䇗', "zhōng"),
+    ('䇘', "hù"),
+    ('䇙', "yǐn"),
+    ('䇚', "pò"),
+    ('䇛', "zhǐ"),
+    ('䇜', "qiǎn"),
+    ('䇞', "gān"),
+    ('䇟', "jiàn"),
+    ('䇠', "zhù"),
+    ('䇡', "zhù"),
+    ('䇢', "kǔ"),
+    ('䇣', "niè"),
+    ('䇤', "ruì"),
+    ('䇥', "zé"),
+    ('䇦', "ǎng"),
+    ('䇧', "zhì"),
+    ('䇨', "gòng"),
+    ('䇩', "yì"),
+    ('䇪', "chī"),
+    ('䇫', "jī"),
+    ('䇬', "zhū"),
+    ('䇭', "lǎo"),
+    ('䇮', "rèn"),
+    ('䇯', "róng"),
+    ('䇰', "zhēng"),
+    ('䇱', "nà"),
+    ('䇲', "cè"),
+    ('䇵', "yí"),
+    ('䇶', "jué"),
+    ('䇷', "bié"),
+    ('䇸', "chéng"),
+    ('䇹', "jùn"),
+    ('䇺', "dòu"),
+    ('䇻', "wěi"),
+    ('䇼', "yì"),
+    ('䇽', "zhé"),
+    ('䇾', "yán"),
+    ('䈀', "sān"),
+    ('䈁', "lún"),
+    ('䈂', "píng"),
+    ('䈃', "zhǎo"),
+    ('䈄', "hán"),
+    ('䈅', "yù"),
+    ('䈆', "dài"),
+    ('䈇', "zhào"),
+    ('䈈', "féi"),
+    ('䈉', "shà"),
+    ('䈊', "líng"),
+    ('䈋', "tà"),
+    ('䈌', "qū"),
+    ('䈍', "máng"),
+    ('䈎', "yè"),
+    ('䈏', "báo"),
+    ('䈐', "guì"),
+    ('䈑', "guǎ"),
+    ('䈒', "nǎn"),
+    ('䈓', "gé"),
+    ('䈕', "shí"),
+    ('䈖', "kē"),
+    ('䈗', "suǒ"),
+    ('䈘', "cí"),
+    ('䈙', "zhòu"),
+    ('䈚', "tái"),
+    ('䈛', "kuài"),
+    ('䈜', "qìn"),
+    ('䈝', "xū"),
+    ('䈞', "dǔ"),
+    ('䈟', "cè"),
+    ('䈠', "huǎn"),
+    ('䈡', "cōng"),
+    ('䈢', "sǎi"),
+    ('䈣', "zhèng"),
+    ('䈤', "qián"),
+    ('䈥', "jīn"),
+    ('䈦', "zōng"),
+    ('䈧', "wěi"),
+    ('䈪', "xì"),
+    ('䈫', "nà"),
+    ('䈬', "pú"),
+    ('䈭', "sōu"),
+    ('䈮', "jù"),
+    ('䈯', "zhēn"),
+    ('䈰', "shāo"),
+    ('䈱', "tāo"),
+    ('䈲', "bān"),
+    ('䈳', "tà"),
+    ('䈴', "qiàn"),
+    ('䈵', "wēng"),
+    ('䈶', "róng"),
+    ('䈷', "luò"),
+    ('䈸', "hú"),
+    ('䈹', "sǒu"),
+    ('䈺', "zhōng"),
+    ('䈻', "pú"),
+    ('䈼', "miè"),
+    ('䈽', "jīn"),
+    ('䈾', "shāo"),
+    ('䈿', "mì"),
+    ('䉀', "shù"),
+    ('䉁', "líng"),
+    ('䉂', "lěi"),
+    ('䉃', "jiǎng"),
+    ('䉄', "léng"),
+    ('䉅', "zhì"),
+    ('䉆', "diǎo"),
+    ('䉈', "sǎn"),
+    ('䉉', "gū"),
+    ('䉊', "fàn"),
+    ('䉋', "mèi"),
+    ('䉌', "suì"),
+    ('䉍', "jiǎn"),
+    ('䉎', "táng"),
+    ('䉏', "xiè"),
+    ('䉐', "kū"),
+    ('䉑', "wú"),
+    ('䉒', "fán"),
+    ('䉓', "luò"),
+    ('䉔', "cān"),
+    ('䉕', "céng"),
+    ('䉖', "líng"),
+    ('䉗', "yī"),
+    ('䉘', "cóng"),
+    ('䉙', "yún"),
+    ('䉚', "méng"),
+    ('䉛', "yù"),
+    ('䉜', "zhì"),
+    ('䉝', "yǐ"),
+    ('䉞', "dǎn"),
+    ('䉟', "huò"),
+    ('䉠', "wéi"),
+    ('䉡', "tán"),
+    ('䉢', "sè"),
+    ('䉣', "xiè"),
+    ('䉤', "sǒu"),
+    ('䉥', "sǒng"),
+    ('䉦', "qiān"),
+    ('䉧', "liú"),
+    ('䉨', "yì"),
+    ('䉪', "lèi"),
+    ('䉫', "lí"),
+    ('䉬', "fèi"),
+    ('䉭', "liè"),
+    ('䉮', "lìn"),
+    ('䉯', "xiàn"),
+    ('䉰', "xiào"),
+    ('䉱', "ōu"),
+    ('䉲', "mí"),
+    ('䉳', "xiān"),
+    ('䉴', "ráng"),
+    ('䉵', "zhuàn"),
+    ('䉶', "shuāng"),
+    ('䉷', "yán"),
+    ('䉸', "biàn"),
+    ('䉹', "líng"),
+    ('䉺', "hóng"),
+    ('䉻', "qí"),
+    ('䉼', "liào"),
+    ('䉽', "bǎn"),
+    ('䉾', "bì"),
+    ('䉿', "hú"),
+    ('䊀', "hú"),
+    ('䊂', "cè"),
+    ('䊃', "pèi"),
+    ('䊄', "qióng"),
+    ('䊅', "míng"),
+    ('䊆', "jiù"),
+    ('䊇', "bù"),
+    ('䊈', "méi"),
+    ('䊉', "sǎn"),
+    ('䊊', "wèi"),
+    ('䊍', "lí"),
+    ('䊎', "quǎn"),
+    ('䊐', "hún"),
+    ('䊑', "xiǎng"),
+    ('䊓', "shì"),
+    ('䊔', "yíng"),
+    ('䊖', "nǎn"),
+    ('䊗', "huáng"),
+    ('䊘', "jiù"),
+    ('䊙', "yān"),
+    ('䊛', "sà"),
+    ('䊜', "tuán"),
+    ('䊝', "xiè"),
+    ('䊞', "zhé"),
+    ('䊟', "mén"),
+    ('䊠', "xì"),
+    ('䊡', "mán"),
+    ('䊣', "huáng"),
+    ('䊤', "tán"),
+    ('䊥', "xiào"),
+    ('䊦', "yè"),
+    ('䊧', "bì"),
+    ('䊨', "luó"),
+    ('䊩', "fán"),
+    ('䊪', "lì"),
+    ('䊫', "cuǐ"),
+    ('䊬', "chuā"),
+    ('䊭', "dào"),
+    ('䊮', "dí"),
+    ('䊯', "kuàng"),
+    ('䊰', "chú"),
+    ('䊱', "xiān"),
+    ('䊲', "chàn"),
+    ('䊳', "mí"),
+    ('䊴', "qiàn"),
+    ('䊵', "qiú"),
+    ('䊶', "zhèn"),
+    ('䊺', "hù"),
+    ('䊻', "gān"),
+    ('䊼', "chǐ"),
+    ('䊽', "guài"),
+    ('䊾', "mù"),
+    ('䊿', "bó"),
+    ('䋀', "huà"),
+    ('䋁', "gěng"),
+    ('䋂', "yáo"),
+    ('䋃', "mào"),
+    ('䋄', "wǎng"),
+    ('䋈', "rú"),
+    ('䋉', "xué"),
+    ('䋊', "zhēng"),
+    ('䋋', "mín"),
+    ('䋌', "jiǎng"),
+    ('䋎', "zhàn"),
+    ('䋏', "zuó"),
+    ('䋐', "yuè"),
+    ('䋑', "liè"),
+    ('䋓', "zhòu"),
+    ('䋔', "bì"),
+    ('䋕', "rèn"),
+    ('䋖', "yù"),
+    ('䋘', "chuò"),
+    ('䋙', "ěr"),
+    ('䋚', "yì"),
+    ('䋛', "mǐ"),
+    ('䋜', "qìng"),
+    ('䋞', "wǎng"),
+    ('䋟', "jì"),
+    ('䋠', "bǔ"),
+    ('䋢', "biē"),
+    ('䋣', "fán"),
+    ('䋤', "yuè"),
+    ('䋥', "lí"),
+    ('䋦', "fán"),
+    ('䋧', "qú"),
+    ('䋨', "fǔ"),
+    ('䋩', "ér"),
+    ('䋪', "ē"),
+    ('䋫', "zhēng"),
+    ('䋬', "tiān"),
+    ('䋭', "yù"),
+    ('䋮', "jìn"),
+    ('䋯', "qǐ"),
+    ('䋰', "jú"),
+    ('䋱', "lái"),
+    ('䋲', "chě"),
+    ('䋳', "běi"),
+    ('䋴', "niù"),
+    ('䋵', "yì"),
+    ('䋶', "xǔ"),
+    ('䋷', "móu"),
+    ('䋸', "xún"),
+    ('䋹', "fú"),
+    ('䋻', "nín"),
+    ('䋼', "tīng"),
+    ('䋽', "běng"),
+    ('䋾', "zhǎ"),
+    ('䋿', "wēi"),
+    ('䌀', "kē"),
+    ('䌁', "yāo"),
+    ('䌂', "òu"),
+    ('䌃', "xiāo"),
+    ('䌄', "gěng"),
+    ('䌅', "táng"),
+    ('䌆', "guì"),
+    ('䌇', "huì"),
+    ('䌈', "tā"),
+    ('䌊', "yáo"),
+    ('䌋', "dā"),
+    ('䌌', "qì"),
+    ('䌍', "jǐn"),
+    ('䌎', "lüè"),
+    ('䌏', "mì"),
+    ('䌐', "mì"),
+    ('䌑', "jiān"),
+    ('䌒', "lù"),
+    ('䌓', "fán"),
+    ('䌔', "ōu"),
+    ('䌕', "mí"),
+    ('䌖', "jié"),
+    ('䌗', "fǔ"),
+    ('䌘', "biè"),
+    ('䌙', "huàng"),
+    ('䌚', "sū"),
+    ('䌛', "yáo"),
+    ('䌜', "niè"),
+    ('䌝', "jīn"),
+    ('䌞', "liǎn"),
+    ('䌟', "bó"),
+    ('䌠', "jiān"),
+    ('䌡', "tǐ"),
+    ('䌢', "líng"),
+    ('䌣', "zuǎn"),
+    ('䌤', "shī"),
+    ('䌥', "yǐn"),
+    ('䌦', "dào"),
+    ('䌧', "chóu"),
+    ('䌨', "cā"),
+    ('䌩', "miè"),
+    ('䌪', "yǎn"),
+    ('䌫', "lǎn"),
+    ('䌬', "chóng"),
+    ('䌭', "jiāo"),
+    ('䌮', "shuāng"),
+    ('䌯', "quān"),
+    ('䌰', "niè"),
+    ('䌱', "luò"),
+    ('䌳', "shī"),
+    ('䌴', "luò"),
+    ('䌵', "zhú"),
+    ('䌷', "chōu"),
+    ('䌸', "juàn"),
+    ('䌹', "jiǒng"),
+    ('䌺', "ěr"),
+    ('䌻', "yì"),
+    ('䌼', "ruì"),
+    ('䌽', "cǎi"),
+    ('䌾', "rén"),
+    ('䌿', "fú"),
+    ('䍀', "lán"),
+    ('䍁', "suì"),
+    ('䍂', "yú"),
+    ('䍃', "yóu"),
+    ('䍄', "diǎn"),
+    ('䍅', "líng"),
+    ('䍆', "zhù"),
+    ('䍇', "tà"),
+    ('䍈', "píng"),
+    ('䍉', "zhǎi"),
+    ('䍊', "jiāo"),
+    ('䍋', "chuí"),
+    ('䍌', "bù"),
+    ('䍍', "kòu"),
+    ('䍎', "cùn"),
+    ('䍐', "hǎn"),
+    ('䍑', "hǎn"),
+    ('䍒', "mǒu"),
+    ('䍓', "hù"),
+    ('䍔', "gōng"),
+    ('䍕', "dī"),
+    ('䍖', "fú"),
+    ('䍗', "xuàn"),
+    ('䍘', "mí"),
+    ('䍙', "méi"),
+    ('䍚', "làng"),
+    ('䍛', "gù"),
+    ('䍜', "zhào"),
+    ('䍝', "tà"),
+    ('䍞', "yù"),
+    ('䍟', "zòng"),
+    ('䍠', "lí"),
+    ('䍡', "lù"),
+    ('䍢', "wú"),
+    ('䍣', "léi"),
+    ('䍤', "jǐ"),
+    ('䍥', "lì"),
+    ('䍦', "lí"),
+    ('䍨', "pō"),
+    ('䍩', "yǎng"),
+    ('䍪', "wà"),
+    ('䍫', "tuó"),
+    ('䍬', "pēng"),
+    ('䍮', "zhào"),
+    ('䍯', "guǐ"),
+    ('䍱', "xú"),
+    ('䍲', "nái"),
+    ('䍳', "què"),
+    ('䍴', "wěi"),
+    ('䍵', "zhēng"),
+    ('䍶', "dōng"),
+    ('䍷', "wěi"),
+    ('䍸', "bó"),
+    ('䍺', "huàn"),
+    ('䍻', "xuàn"),
+    ('䍼', "zān"),
+    ('䍽', "lì"),
+    ('䍾', "yǎn"),
+    ('䍿', "huáng"),
+    ('䎀', "xuè"),
+    ('䎁', "hú"),
+    ('䎂', "bǎo"),
+    ('䎃', "rǎn"),
+    ('䎄', "xiāo"),
+    ('䎅', "pò"),
+    ('䎆', "liào"),
+    ('䎇', "zhōu"),
+    ('䎈', "yì"),
+    ('䎉', "xù"),
+    ('䎊', "luò"),
+    ('䎋', "kào"),
+    ('䎌', "chù"),
+    ('䎎', "nà"),
+    ('䎏', "hán"),
+    ('䎐', "chǎo"),
+    ('䎑', "lù"),
+    ('䎒', "zhǎn"),
+    ('䎓', "tà"),
+    ('䎔', "fū"),
+    ('䎕', "hōng"),
+    ('䎖', "zēng"),
+    ('䎗', "qiáo"),
+    ('䎘', "sù"),
+    ('䎙', "pīn"),
+    ('䎚', "guàn"),
+    ('䎜', "hūn"),
+    ('䎝', "chú"),
+    ('䎟', "ér"),
+    ('䎠', "ér"),
+    ('䎡', "ruǎn"),
+    ('䎢', "qǐ"),
+    ('䎣', "sì"),
+    ('䎤', "jú"),
+    ('䎦', "yǎn"),
+    ('䎧', "bàng"),
+    ('䎨', "yè"),
+    ('䎩', "zī"),
+    ('䎪', "nè"),
+    ('䎫', "chuàng"),
+    ('䎬', "bà"),
+    ('䎭', "cāo"),
+    ('䎮', "tì"),
+    ('䎯', "hàn"),
+    ('䎰', "zuó"),
+    ('䎱', "bà"),
+    ('䎲', "zhé"),
+    ('䎳', "wà"),
+    ('䎴', "gēng"),
+    ('䎵', "bì"),
+    ('䎶', "èr"),
+    ('䎷', "zhù"),
+    ('䎸', "wù"),
+    ('䎹', "wén"),
+    ('䎺', "zhì"),
+    ('䎻', "zhòu"),
+    ('䎼', "lù"),
+    ('䎽', "wén"),
+    ('䎾', "gǔn"),
+    ('䎿', "qiú"),
+    ('䏀', "là"),
+    ('䏁', "zǎi"),
+    ('䏂', "sǒu"),
+    ('䏃', "mián"),
+    ('䏄', "dǐ"),
+    ('䏅', "qì"),
+    ('䏆', "cáo"),
+    ('䏇', "piào"),
+    ('䏈', "lián"),
+    ('䏉', "shī"),
+    ('䏊', "lóng"),
+    ('䏋', "sù"),
+    ('䏌', "qì"),
+    ('䏍', "yuàn"),
+    ('䏎', "féng"),
+    ('䏏', "xū"),
+    ('䏐', "jué"),
+    ('䏑', "dì"),
+    ('䏒', "piàn"),
+    ('䏓', "guǎn"),
+    ('䏔', "niǔ"),
+    ('䏕', "rèn"),
+    ('䏖', "zhèn"),
+    ('䏗', "gài"),
+    ('䏘', "pì"),
+    ('䏙', "tǎn"),
+    ('䏚', "chǎo"),
+    ('䏛', "chǔn"),
+    ('䏜', "hē"),
+    ('䏝', "zhuān"),
+    ('䏞', "mò"),
+    ('䏟', "bié"),
+    ('䏠', "qì"),
+    ('䏡', "shì"),
+    ('䏢', "bǐ"),
+    ('䏣', "jué"),
+    ('䏤', "sì"),
+    ('䏦', "guā"),
+    ('䏧', "nà"),
+    ('䏨', "huǐ"),
+    ('䏩', "xī"),
+    ('䏪', "èr"),
+    ('䏫', "xiū"),
+    ('䏬', "móu"),
+    ('䏮', "xí"),
+    ('䏯', "zhì"),
+    ('䏰', "rùn"),
+    ('䏱', "jú"),
+    ('䏲', "dié"),
+    ('䏳', "zhè"),
+    ('䏴', "shào"),
+    ('䏵', "měng"),
+    ('䏶', "bì"),
+    ('䏷', "hàn"),
+    ('䏸', "yú"),
+    ('䏹', "xiàn"),
+    ('䏺', "pāng"),
+    ('䏻', "néng"),
+    ('䏼', "cán"),
+    ('䏽', "bù"),
+    ('䏿', "qǐ"),
+    ('䐀', "jì"),
+    ('䐁', "zhuó"),
+    ('䐂', "lù"),
+    ('䐃', "jùn"),
+    ('䐄', "xiàn"),
+    ('䐅', "xī"),
+    ('䐆', "cǎi"),
+    ('䐇', "wěn"),
+    ('䐈', "zhí"),
+    ('䐉', "zì"),
+    ('䐊', "kūn"),
+    ('䐋', "cōng"),
+    ('䐌', "tiǎn"),
+    ('䐍', "chù"),
+    ('䐎', "dī"),
+    ('䐏', "chǔn"),
+    ('䐐', "qiū"),
+    ('䐑', "zhé"),
+    ('䐒', "zhā"),
+    ('䐓', "róu"),
+    ('䐔', "bǐn"),
+    ('䐕', "jí"),
+    ('䐖', "xī"),
+    ('䐗', "zhū"),
+    ('䐘', "jué"),
+    ('䐙', "gé"),
+    ('䐚', "jī"),
+    ('䐛', "dā"),
+    ('䐜', "chēn"),
+    ('䐝', "suò"),
+    ('䐞', "ruò"),
+    ('䐟', "xiǎng"),
+    ('䐠', "huǎng"),
+    ('䐡', "qí"),
+    ('䐢', "zhù"),
+    ('䐣', "sǔn"),
+    ('䐤', "chāi"),
+    ('䐥', "wěng"),
+    ('䐦', "kē"),
+    ('䐧', "kào"),
+    ('䐨', "gǔ"),
+    ('䐩', "gāi"),
+    ('䐪', "fàn"),
+    ('䐫', "cōng"),
+    ('䐬', "cáo"),
+    ('䐭', "zhì"),
+    ('䐮', "chǎn"),
+    ('䐯', "léi"),
+    ('䐰', "xiū"),
+    ('䐱', "zhài"),
+    ('䐲', "zhé"),
+    ('䐳', "yú"),
+    ('䐴', "guì"),
+    ('䐵', "gōng"),
+    ('䐶', "zān"),
+    ('䐷', "dān"),
+    ('䐸', "huò"),
+    ('䐹', "sōu"),
+    ('䐺', "tàn"),
+    ('䐻', "gū"),
+    ('䐼', "xì"),
+    ('䐽', "mán"),
+    ('䐾', "duó"),
+    ('䐿', "ào"),
+    ('䑀', "pì"),
+    ('䑁', "wù"),
+    ('䑂', "ǎi"),
+    ('䑃', "méng"),
+    ('䑄', "pì"),
+    ('䑅', "méng"),
+    ('䑆', "yǎng"),
+    ('䑇', "zhì"),
+    ('䑈', "bó"),
+    ('䑉', "yíng"),
+    ('䑊', "wéi"),
+    ('䑋', "rǎng"),
+    ('䑌', "lán"),
+    ('䑍', "yān"),
+    ('䑎', "chǎn"),
+    ('䑏', "quán"),
+    ('䑐', "zhěn"),
+    ('䑑', "pú"),
+    ('䑓', "tái"),
+    ('䑔', "fèi"),
+    ('䑕', "shǔ"),
+    ('䑗', "dàng"),
+    ('䑘', "cuó"),
+    ('䑙', "tān"),
+    ('䑚', "tián"),
+    ('䑛', "chǐ"),
+    ('䑜', "tà"),
+    ('䑝', "jiǎ"),
+    ('䑞', "shùn"),
+    ('䑟', "huáng"),
+    ('䑠', "liǎo"),
+    ('䑣', "chēn"),
+    ('䑤', "jìn"),
+    ('䑥', "è"),
+    ('䑦', "gōu"),
+    ('䑧', "fú"),
+    ('䑨', "duò"),
+    ('䑪', "è"),
+    ('䑫', "bēng"),
+    ('䑬', "tāo"),
+    ('䑭', "dì"),
+    ('䑯', "dì"),
+    ('䑰', "bù"),
+    ('䑱', "wǎn"),
+    ('䑲', "zhào"),
+    ('䑳', "lún"),
+    ('䑴', "qí"),
+    ('䑵', "mù"),
+    ('䑶', "qiàn"),
+    ('䑸', "zōng"),
+    ('䑹', "sōu"),
+    ('䑻', "yóu"),
+    ('䑼', "zhōu"),
+    ('䑽', "tà"),
+    ('䑿', "sù"),
+    ('䒀', "bù"),
+    ('䒁', "xí"),
+    ('䒂', "jiǎng"),
+    ('䒃', "cào"),
+    ('䒄', "fù"),
+    ('䒅', "téng"),
+    ('䒆', "chè"),
+    ('䒇', "fù"),
+    ('䒈', "fèi"),
+    ('䒉', "wǔ"),
+    ('䒊', "xī"),
+    ('䒋', "yǎng"),
+    ('䒌', "mìng"),
+    ('䒍', "pǎng"),
+    ('䒎', "mǎng"),
+    ('䒏', "sēng"),
+    ('䒐', "méng"),
+    ('䒑', "cǎo"),
+    ('䒒', "tiáo"),
+    ('䒓', "kǎi"),
+    ('䒔', "bài"),
+    ('䒕', "xiǎo"),
+    ('䒖', "xìn"),
+    ('䒗', "qì"),
+    ('䒚', "shǎo"),
+    ('䒛', "huàn"),
+    ('䒜', "niú"),
+    ('䒝', "xiáo"),
+    ('䒞', "chén"),
+    ('䒟', "dān"),
+    ('䒠', "fēng"),
+    ('䒡', "yǐn"),
+    ('䒢', "áng"),
+    ('䒣', "rǎn"),
+    ('䒤', "rì"),
+    ('䒥', "mán"),
+    ('䒦', "fàn"),
+    ('䒧', "qū"),
+    ('䒨', "shǐ"),
+    ('䒩', "hé"),
+    ('䒪', "biàn"),
+    ('䒫', "dài"),
+    ('䒬', "mò"),
+    ('䒭', "děng"),
+    ('䒰', "kuāng"),
+    ('䒲', "chà"),
+    ('䒳', "duǒ"),
+    ('䒴', "yǒu"),
+    ('䒵', "hào"),
+    ('䒷', "guā"),
+    ('䒸', "xuè"),
+    ('䒹', "lèi"),
+    ('䒺', "jǐn"),
+    ('䒻', "qǐ"),
+    ('䒼', "qū"),
+    ('䒽', "wǎng"),
+    ('䒾', "yī"),
+    ('䒿', "liáo"),
+    ('䓂', "yán"),
+    ('䓃', "yì"),
+    ('䓄', "yín"),
+    ('䓅', "qí"),
+    ('䓆', "zhé"),
+    ('䓇', "xì"),
+    ('䓈', "yì"),
+    ('䓉', "yé"),
+    ('䓊', "wú"),
+    ('䓋', "zhī"),
+    ('䓌', "zhì"),
+    ('䓍', "hǎn"),
+    ('䓎', "chuò"),
+    ('䓏', "fū"),
+    ('䓐', "chún"),
+    ('䓑', "píng"),
+    ('䓒', "kuǎi"),
+    ('䓓', "chóu"),
+    ('䓕', "tuǒ"),
+    ('䓖', "qióng"),
+    ('䓗', "cōng"),
+    ('䓘', "gāo"),
+    ('䓙', "kuā"),
+    ('䓚', "qū"),
+    ('䓛', "qū"),
+    ('䓜', "zhī"),
+    ('䓝', "mèng"),
+    ('䓞', "lì"),
+    ('䓟', "zhōu"),
+    ('䓠', "tà"),
+    ('䓡', "zhī"),
+    ('䓢', "gù"),
+    ('䓣', "liǎng"),
+    ('䓤', "hū"),
+    ('䓥', "là"),
+    ('䓦', "diǎn"),
+    ('䓧', "cì"),
+    ('䓨', "yīng"),
+    ('䓫', "qí"),
+    ('䓬', "zhuó"),
+    ('䓭', "chà"),
+    ('䓮', "mào"),
+    ('䓯', "dú"),
+    ('䓰', "yīn"),
+    ('䓱', "chái"),
+    ('䓲', "ruì"),
+    ('䓳', "hěn"),
+    ('䓴', "ruǎn"),
+    ('䓵', "fū"),
+    ('䓶', "lài"),
+    ('䓷', "xìng"),
+    ('䓸', "jiān"),
+    ('䓹', "yì"),
+    ('䓺', "měi"),
+    ('䓼', "máng"),
+    ('䓽', "jì"),
+    ('䓾', "suō"),
+    ('䓿', "hàn"),
+    ('䔁', "lì"),
+    ('䔂', "zǐ"),
+    ('䔃', "zǔ"),
+    ('䔄', "yáo"),
+    ('䔅', "gē"),
+    ('䔆', "lí"),
+    ('䔇', "qǐ"),
+    ('䔈', "gòng"),
+    ('䔉', "lì"),
+    ('䔊', "bīng"),
+    ('䔋', "suō"),
+    ('䔎', "sù"),
+    ('䔏', "chòu"),
+    ('䔐', "jiān"),
+    ('䔑', "xié"),
+    ('䔒', "bèi"),
+    ('䔓', "xǔ"),
+    ('䔔', "jìng"),
+    ('䔕', "pú"),
+    ('䔖', "líng"),
+    ('䔗', "xiáng"),
+    ('䔘', "zuò"),
+    ('䔙', "diào"),
+    ('䔚', "chún"),
+    ('䔛', "qǐng"),
+    ('䔜', "nán"),
+    ('䔝', "zhāi"),
+    ('䔞', "lǜ"),
+    ('䔟', "yí"),
+    ('䔠', "shǎo"),
+    ('䔡', "yú"),
+    ('䔢', "huá"),
+    ('䔣', "lí"),
+    ('䔤', "pā"),
+    ('䔧', "lí"),
+    ('䔪', "shuǎng"),
+    ('䔬', "yì"),
+    ('䔭', "nìng"),
+    ('䔮', "sī"),
+    ('䔯', "kù"),
+    ('䔰', "fù"),
+    ('䔱', "yī"),
+    ('䔲', "dēng"),
+    ('䔳', "rán"),
+    ('䔴', "cè"),
+    ('䔶', "tí"),
+    ('䔷', "qín"),
+    ('䔸', "biǎo"),
+    ('䔹', "suì"),
+    ('䔺', "wéi"),
+    ('䔻', "dūn"),
+    ('䔼', "sè"),
+    ('䔽', "ài"),
+    ('䔾', "qì"),
+    ('䔿', "zǔn"),
+    ('䕀', "kuǎn"),
+    ('䕁', "fěi"),
+    ('䕃', "yìn"),
+    ('䕅', "sǎo"),
+    ('䕆', "dòu"),
+    ('䕇', "huì"),
+    ('䕈', "xiè"),
+    ('䕉', "zé"),
+    ('䕊', "tán"),
+    ('䕋', "táng"),
+    ('䕌', "zhì"),
+    ('䕍', "yì"),
+    ('䕎', "fú"),
+    ('䕏', "é"),
+    ('䕑', "jùn"),
+    ('䕒', "jiā"),
+    ('䕓', "chá"),
+    ('䕔', "xián"),
+    ('䕕', "màn"),
+    ('䕗', "bì"),
+    ('䕘', "líng"),
+    ('䕙', "jié"),
+    ('䕚', "kuì"),
+    ('䕛', "jiá"),
+    ('䕝', "chēng"),
+    ('䕞', "làng"),
+    ('䕟', "xīng"),
+    ('䕠', "fèi"),
+    ('䕡', "lǘ"),
+    ('䕢', "zhǎ"),
+    ('䕣', "hé"),
+    ('䕤', "jī"),
+    ('䕥', "nǐ"),
+    ('䕦', "yíng"),
+    ('䕧', "xiào"),
+    ('䕨', "téng"),
+    ('䕩', "lǎo"),
+    ('䕪', "zé"),
+    ('䕫', "kuí"),
+    ('䕭', "qián"),
+    ('䕮', "jú"),
+    ('䕯', "piáo"),
+    ('䕰', "fán"),
+    ('䕱', "tóu"),
+    ('䕲', "lǐn"),
+    ('䕳', "mí"),
+    ('䕴', "zhuó"),
+    ('䕵', "xié"),
+    ('䕶', "hù"),
+    ('䕷', "mí"),
+    ('䕸', "jiē"),
+    ('䕹', "zá"),
+    ('䕺', "cóng"),
+    ('䕻', "lì"),
+    ('䕼', "rán"),
+    ('䕽', "zhú"),
+    ('䕾', "yín"),
+    ('䕿', "hàn"),
+    ('䖁', "yì"),
+    ('䖂', "luán"),
+    ('䖃', "yuè"),
+    ('䖄', "rán"),
+    ('䖅', "líng"),
+    ('䖆', "niàng"),
+    ('䖇', "yù"),
+    ('䖈', "nüè"),
+    ('䖊', "yì"),
+    ('䖋', "nüè"),
+    ('䖌', "yì"),
+    ('䖍', "qián"),
+    ('䖎', "xiá"),
+    ('䖏', "chǔ"),
+    ('䖐', "yín"),
+    ('䖑', "mì"),
+    ('䖒', "xī"),
+    ('䖓', "nà"),
+    ('䖔', "kǎn"),
+    ('䖕', "zǔ"),
+    ('䖖', "xiá"),
+    ('䖗', "yán"),
+    ('䖘', "tú"),
+    ('䖙', "tī"),
+    ('䖚', "wū"),
+    ('䖛', "suǒ"),
+    ('䖜', "yín"),
+    ('䖝', "chóng"),
+    ('䖞', "zhǒu"),
+    ('䖟', "mǎng"),
+    ('䖠', "yuán"),
+    ('䖡', "nǜ"),
+    ('䖢', "miáo"),
+    ('䖣', "zǎo"),
+    ('䖤', "wǎn"),
+    ('䖥', "lí"),
+    ('䖦', "qū"),
+    ('䖧', "nà"),
+    ('䖨', "shí"),
+    ('䖩', "bì"),
+    ('䖪', "zī"),
+    ('䖫', "bàng"),
+    ('䖭', "juàn"),
+    ('䖮', "xiǎng"),
+    ('䖯', "kuí"),
+    ('䖰', "pài"),
+    ('䖱', "kuāng"),
+    ('䖲', "xún"),
+    ('䖳', "zhà"),
+    ('䖴', "yáo"),
+    ('䖵', "kūn"),
+    ('䖶', "huī"),
+    ('䖷', "xī"),
+    ('䖸', "é"),
+    ('䖹', "yáng"),
+    ('䖺', "tiáo"),
+    ('䖻', "yóu"),
+    ('䖼', "jué"),
+    ('䖽', "lí"),
+    ('䖿', "lí"),
+    ('䗀', "chēng"),
+    ('䗁', "jì"),
+    ('䗂', "hǔ"),
+    ('䗃', "zhàn"),
+    ('䗄', "fǔ"),
+    ('䗅', "cháng"),
+    ('䗆', "guǎn"),
+    ('䗇', "jú"),
+    ('䗈', "méng"),
+    ('䗉', "chāng"),
+    ('䗊', "tàn"),
+    ('䗋', "móu"),
+    ('䗌', "xīng"),
+    ('䗍', "lǐ"),
+    ('䗎', "yān"),
+    ('䗏', "sōu"),
+    ('䗐', "shī"),
+    ('䗑', "yì"),
+    ('䗒', "bìng"),
+    ('䗓', "cōng"),
+    ('䗔', "hóu"),
+    ('䗕', "wǎn"),
+    ('䗖', "dì"),
+    ('䗗', "jī"),
+    ('䗘', "gé"),
+    ('䗙', "hán"),
+    ('䗚', "bó"),
+    ('䗛', "xiū"),
+    ('䗜', "liú"),
+    ('䗝', "cán"),
+    ('䗞', "cán"),
+    ('䗟', "yì"),
+    ('䗠', "xuán"),
+    ('䗡', "yán"),
+    ('䗢', "zǎo"),
+    ('䗣', "hàn"),
+    ('䗤', "yóng"),
+    ('䗥', "zōng"),
+    ('䗧', "kāng"),
+    ('䗨', "yú"),
+    ('䗩', "qī"),
+    ('䗪', "zhè"),
+    ('䗫', "má"),
+    ('䗮', "shuǎng"),
+    ('䗯', "jìn"),
+    ('䗰', "guàn"),
+    ('䗱', "pú"),
+    ('䗲', "lìn"),
+    ('䗴', "tíng"),
+    ('䗵', "jiāng"),
+    ('䗶', "là"),
+    ('䗷', "yì"),
+    ('䗸', "yōng"),
+    ('䗹', "cì"),
+    ('䗺', "yǎn"),
+    ('䗻', "jié"),
+    ('䗼', "xūn"),
+    ('䗽', "wèi"),
+    ('䗾', "xiǎn"),
+    ('䗿', "níng"),
+    ('䘀', "fù"),
+    ('䘁', "gé"),
+    ('䘃', "mò"),
+    ('䘄', "zhù"),
+    ('䘅', "nái"),
+    ('䘆', "xiǎn"),
+    ('䘇', "wén"),
+    ('䘈', "lì"),
+    ('䘉', "cán"),
+    ('䘊', "miè"),
+    ('䘋', "jiān"),
+    ('䘌', "nì"),
+    ('䘍', "chài"),
+    ('䘎', "wān"),
+    ('䘏', "xù"),
+    ('䘐', "nǜ"),
+    ('䘑', "mài"),
+    ('䘒', "zuī"),
+    ('䘓', "kàn"),
+    ('䘔', "kā"),
+    ('䘕', "háng"),
+    ('䘘', "yù"),
+    ('䘙', "wèi"),
+    ('䘚', "zhú"),
+    ('䘝', "yì"),
+    ('䘟', "diāo"),
+    ('䘠', "fú"),
+    ('䘡', "bǐ"),
+    ('䘢', "zhǔ"),
+    ('䘣', "zǐ"),
+    ('䘤', "shù"),
+    ('䘥', "xiá"),
+    ('䘦', "ní"),
+    ('䘨', "jiǎo"),
+    ('䘩', "xún"),
+    ('䘪', "chōng"),
+    ('䘫', "nòu"),
+    ('䘬', "róng"),
+    ('䘭', "zhì"),
+    ('䘮', "sāng"),
+    ('䘰', "shān"),
+    ('䘱', "yù"),
+    ('䘳', "jīn"),
+    ('䘵', "lù"),
+    ('䘶', "hān"),
+    ('䘷', "biē"),
+    ('䘸', "yì"),
+    ('䘹', "zuì"),
+    ('䘺', "zhàn"),
+    ('䘻', "yù"),
+    ('䘼', "wǎn"),
+    ('䘽', "ní"),
+    ('䘾', "guǎn"),
+    ('䘿', "jué"),
+    ('䙀', "běng"),
+    ('䙁', "cán"),
+    ('䙃', "duò"),
+    ('䙄', "qì"),
+    ('䙅', "yāo"),
+    ('䙆', "kuì"),
+    ('䙇', "ruán"),
+    ('䙈', "hóu"),
+    ('䙉', "xún"),
+    ('䙊', "xiè"),
+    ('䙌', "kuì"),
+    ('䙎', "xié"),
+    ('䙏', "bó"),
+    ('䙐', "kè"),
+    ('䙑', "cuī"),
+    ('䙒', "xù"),
+    ('䙓', "bǎi"),
+    ('䙔', "ōu"),
+    ('䙕', "zǒng"),
+    ('䙗', "tì"),
+    ('䙘', "chǔ"),
+    ('䙙', "chí"),
+    ('䙚', "niǎo"),
+    ('䙛', "guàn"),
+    ('䙜', "féng"),
+    ('䙝', "xiè"),
+    ('䙞', "dēng"),
+    ('䙟', "wéi"),
+    ('䙠', "jué"),
+    ('䙡', "kuì"),
+    ('䙢', "zèng"),
+    ('䙣', "sà"),
+    ('䙤', "duǒ"),
+    ('䙥', "líng"),
+    ('䙦', "méng"),
+    ('䙨', "guǒ"),
+    ('䙩', "méng"),
+    ('䙪', "lóng"),
+    ('䙬', "yìng"),
+    ('䙮', "guàn"),
+    ('䙯', "cù"),
+    ('䙰', "lí"),
+    ('䙱', "dú"),
+    ('䙳', "biāo"),
+    ('䙴', "qiān"),
+    ('䙵', "xī"),
+    ('䙷', "dé"),
+    ('䙸', "dé"),
+    ('䙹', "xiàn"),
+    ('䙺', "lián"),
+    ('䙼', "shào"),
+    ('䙽', "xié"),
+    ('䙾', "shī"),
+    ('䙿', "wèi"),
+    ('䚂', "hè"),
+    ('䚃', "yóu"),
+    ('䚄', "lù"),
+    ('䚅', "lài"),
+    ('䚆', "yǐng"),
+    ('䚇', "shěng"),
+    ('䚈', "juàn"),
+    ('䚉', "qì"),
+    ('䚊', "jiǎn"),
+    ('䚋', "yùn"),
+    ('䚍', "qì"),
+    ('䚏', "lìn"),
+    ('䚐', "jí"),
+    ('䚑', "mái"),
+    ('䚒', "chuáng"),
+    ('䚓', "niǎn"),
+    ('䚔', "bīn"),
+    ('䚕', "lì"),
+    ('䚖', "líng"),
+    ('䚗', "gāng"),
+    ('䚘', "chéng"),
+    ('䚙', "xuān"),
+    ('䚚', "xiǎn"),
+    ('䚛', "hú"),
+    ('䚜', "bī"),
+    ('䚝', "zú"),
+    ('䚞', "dǎi"),
+    ('䚟', "dǎi"),
+    ('䚠', "hùn"),
+    ('䚡', "sāi"),
+    ('䚢', "chè"),
+    ('䚣', "tí"),
+    ('䚥', "nuò"),
+    ('䚦', "zhì"),
+    ('䚧', "liú"),
+    ('䚨', "fèi"),
+    ('䚩', "jiǎo"),
+    ('䚪', "guān"),
+    ('䚫', "xí"),
+    ('䚬', "lín"),
+    ('䚭', "xuān"),
+    ('䚮', "réng"),
+    ('䚯', "tǎo"),
+    ('䚰', "pǐ"),
+    ('䚱', "xìn"),
+    ('䚲', "shàn"),
+    ('䚳', "zhì"),
+    ('䚴', "wà"),
+    ('䚵', "tǒu"),
+    ('䚶', "tiān"),
+    ('䚷', "yī"),
+    ('䚸', "xiè"),
+    ('䚹', "pǐ"),
+    ('䚺', "yáo"),
+    ('䚻', "yáo"),
+    ('䚼', "nǜ"),
+    ('䚽', "hào"),
+    ('䚾', "nín"),
+    ('䚿', "yìn"),
+    ('䛀', "fǎn"),
+    ('䛁', "nán"),
+    ('䛂', "yāo"),
+    ('䛃', "wàn"),
+    ('䛄', "yuǎn"),
+    ('䛅', "xiá"),
+    ('䛆', "zhòu"),
+    ('䛇', "yuǎn"),
+    ('䛈', "shì"),
+    ('䛉', "miàn"),
+    ('䛊', "xī"),
+    ('䛋', "jì"),
+    ('䛌', "táo"),
+    ('䛍', "fèi"),
+    ('䛎', "xuè"),
+    ('䛏', "ní"),
+    ('䛐', "cí"),
+    ('䛑', "mì"),
+    ('䛒', "biàn"),
+    ('䛓', "jiàn"),
+    ('䛔', "ná"),
+    ('䛕', "yù"),
+    ('䛖', "è"),
+    ('䛗', "zhǐ"),
+    ('䛘', "rén"),
+    ('䛙', "xù"),
+    ('䛚', "lüè"),
+    ('䛛', "huì"),
+    ('䛜', "xùn"),
+    ('䛝', "náo"),
+    ('䛞', "hàn"),
+    ('䛟', "jiá"),
+    ('䛠', "dòu"),
+    ('䛡', "huà"),
+    ('䛢', "tū"),
+    ('䛣', "pīng"),
+    ('䛤', "cù"),
+    ('䛥', "xī"),
+    ('䛦', "sòng"),
+    ('䛧', "mí"),
+    ('䛨', "xìn"),
+    ('䛩', "wù"),
+    ('䛪', "qióng"),
+    ('䛫', "zhāng"),
+    ('䛬', "táo"),
+    ('䛭', "xìng"),
+    ('䛮', "jiù"),
+    ('䛯', "jù"),
+    ('䛰', "hùn"),
+    ('䛱', "tí"),
+    ('䛲', "mán"),
+    ('䛳', "yàn"),
+    ('䛴', "jī"),
+    ('䛵', "shòu"),
+    ('䛶', "lěi"),
+    ('䛷', "wǎn"),
+    ('䛸', "chè"),
+    ('䛹', "càn"),
+    ('䛺', "jiè"),
+    ('䛻', "yòu"),
+    ('䛼', "huǐ"),
+    ('䛽', "zhǎ"),
+    ('䛾', "sù"),
+    ('䛿', "gé"),
+    ('䜀', "nǎo"),
+    ('䜁', "xì"),
+    ('䜃', "duī"),
+    ('䜄', "chí"),
+    ('䜅', "wéi"),
+    ('䜆', "zhé"),
+    ('䜇', "gǔn"),
+    ('䜈', "chāo"),
+    ('䜉', "chī"),
+    ('䜊', "zāo"),
+    ('䜋', "huì"),
+    ('䜌', "luán"),
+    ('䜍', "liáo"),
+    ('䜎', "láo"),
+    ('䜏', "tuō"),
+    ('䜐', "huī"),
+    ('䜑', "wù"),
+    ('䜒', "ào"),
+    ('䜓', "shè"),
+    ('䜔', "suí"),
+    ('䜕', "mài"),
+    ('䜖', "tàn"),
+    ('䜗', "xìn"),
+    ('䜘', "jǐng"),
+    ('䜙', "án"),
+    ('䜚', "tà"),
+    ('䜛', "chán"),
+    ('䜜', "wèi"),
+    ('䜝', "tuǎn"),
+    ('䜞', "jì"),
+    ('䜟', "chén"),
+    ('䜠', "chè"),
+    ('䜡', "yù"),
+    ('䜢', "xiǎn"),
+    ('䜣', "xīn"),
+    ('䜧', "nǎo"),
+    ('䜩', "yàn"),
+    ('䜪', "qiú"),
+    ('䜫', "jiāng"),
+    ('䜬', "sǒng"),
+    ('䜭', "jùn"),
+    ('䜮', "liáo"),
+    ('䜯', "jú"),
+    ('䜱', "mǎn"),
+    ('䜲', "liè"),
+    ('䜴', "chù"),
+    ('䜵', "chǐ"),
+    ('䜶', "xiáng"),
+    ('䜷', "qīn"),
+    ('䜸', "měi"),
+    ('䜹', "shù"),
+    ('䜺', "chǎi"),
+    ('䜻', "chǐ"),
+    ('䜼', "gú"),
+    ('䜽', "yú"),
+    ('䜾', "yīn"),
+    ('䝀', "liú"),
+    ('䝁', "láo"),
+    ('䝂', "shù"),
+    ('䝃', "zhé"),
+    ('䝄', "shuāng"),
+    ('䝅', "huī"),
+    ('䝈', "è"),
+    ('䝊', "shà"),
+    ('䝋', "zòng"),
+    ('䝌', "jué"),
+    ('䝍', "jùn"),
+    ('䝎', "tuān"),
+    ('䝏', "lóu"),
+    ('䝐', "wéi"),
+    ('䝑', "chōng"),
+    ('䝒', "zhù"),
+    ('䝓', "liè"),
+    ('䝕', "zhé"),
+    ('䝖', "zhǎo"),
+    ('䝘', "yì"),
+    ('䝙', "chū"),
+    ('䝚', "ní"),
+    ('䝛', "bō"),
+    ('䝜', "suān"),
+    ('䝝', "yǐ"),
+    ('䝞', "hào"),
+    ('䝟', "yà"),
+    ('䝠', "huán"),
+    ('䝡', "màn"),
+    ('䝢', "màn"),
+    ('䝣', "qú"),
+    ('䝤', "lǎo"),
+    ('䝥', "háo"),
+    ('䝦', "zhōng"),
+    ('䝧', "mín"),
+    ('䝨', "xián"),
+    ('䝩', "zhèn"),
+    ('䝪', "shǔ"),
+    ('䝫', "zuó"),
+    ('䝬', "zhù"),
+    ('䝭', "gòu"),
+    ('䝮', "xuàn"),
+    ('䝯', "yì"),
+    ('䝰', "zhì"),
+    ('䝱', "xié"),
+    ('䝲', "jìn"),
+    ('䝳', "cán"),
+    ('䝵', "bù"),
+    ('䝶', "liáng"),
+    ('䝷', "zhī"),
+    ('䝸', "jì"),
+    ('䝹', "wǎn"),
+    ('䝺', "guàn"),
+    ('䝻', "jū"),
+    ('䝼', "jìng"),
+    ('䝽', "ài"),
+    ('䝾', "fù"),
+    ('䝿', "guì"),
+    ('䞀', "hòu"),
+    ('䞁', "yàn"),
+    ('䞂', "ruǎn"),
+    ('䞃', "zhì"),
+    ('䞄', "biào"),
+    ('䞅', "yí"),
+    ('䞆', "suǒ"),
+    ('䞇', "dié"),
+    ('䞈', "guì"),
+    ('䞉', "shèng"),
+    ('䞊', "xùn"),
+    ('䞋', "chèn"),
+    ('䞌', "shé"),
+    ('䞍', "qíng"),
+    ('䞐', "chǔn"),
+    ('䞑', "hóng"),
+    ('䞒', "dòng"),
+    ('䞓', "chēng"),
+    ('䞔', "wěi"),
+    ('䞕', "rú"),
+    ('䞖', "shǔ"),
+    ('䞗', "cāi"),
+    ('䞘', "jí"),
+    ('䞙', "zá"),
+    ('䞚', "qí"),
+    ('䞛', "yān"),
+    ('䞜', "fù"),
+    ('䞝', "yù"),
+    ('䞞', "fú"),
+    ('䞟', "pò"),
+    ('䞠', "zhī"),
+    ('䞡', "tǎn"),
+    ('䞢', "zuó"),
+    ('䞣', "chě"),
+    ('䞤', "qú"),
+    ('䞥', "yòu"),
+    ('䞦', "hé"),
+    ('䞧', "hòu"),
+    ('䞨', "guǐ"),
+    ('䞩', "è"),
+    ('䞪', "jiàng"),
+    ('䞫', "yǔn"),
+    ('䞬', "tòu"),
+    ('䞭', "cūn"),
+    ('䞮', "tū"),
+    ('䞯', "fù"),
+    ('䞰', "zuó"),
+    ('䞱', "hú"),
+    ('䞳', "bó"),
+    ('䞴', "zhāo"),
+    ('䞵', "juě"),
+    ('䞶', "tāng"),
+    ('䞷', "jué"),
+    ('䞸', "fù"),
+    ('䞹', "huáng"),
+    ('䞺', "chūn"),
+    ('䞻', "yǒng"),
+    ('䞼', "chuǐ"),
+    ('䞽', "suǒ"),
+    ('䞾', "chí"),
+    ('䞿', "qiān"),
+    ('䟀', "cāi"),
+    ('䟁', "xiáo"),
+    ('䟂', "mán"),
+    ('䟃', "cān"),
+    ('䟄', "qì"),
+    ('䟅', "jiàn"),
+    ('䟆', "bì"),
+    ('䟇', "jī"),
+    ('䟈', "zhí"),
+    ('䟉', "zhú"),
+    ('䟊', "qú"),
+    ('䟋', "zhǎn"),
+    ('䟌', "jí"),
+    ('䟍', "biān"),
+    ('䟏', "lì"),
+    ('䟐', "lì"),
+    ('䟑', "yuè"),
+    ('䟒', "quán"),
+    ('䟓', "chēng"),
+    ('䟔', "fù"),
+    ('䟕', "chà"),
+    ('䟖', "tàng"),
+    ('䟗', "shì"),
+    ('䟘', "hàng"),
+    ('䟙', "qiè"),
+    ('䟚', "qí"),
+    ('䟛', "bó"),
+    ('䟜', "nà"),
+    ('䟝', "tòu"),
+    ('䟞', "chú"),
+    ('䟟', "cù"),
+    ('䟠', "yuè"),
+    ('䟡', "zhī"),
+    ('䟢', "chén"),
+    ('䟣', "chù"),
+    ('䟤', "bì"),
+    ('䟥', "méng"),
+    ('䟦', "bá"),
+    ('䟧', "tián"),
+    ('䟨', "mín"),
+    ('䟩', "liě"),
+    ('䟪', "fěng"),
+    ('䟫', "chēng"),
+    ('䟬', "qiù"),
+    ('䟭', "tiáo"),
+    ('䟮', "fú"),
+    ('䟯', "kuò"),
+    ('䟰', "jiǎn"),
+    ('䟴', "zhèn"),
+    ('䟵', "qiú"),
+    ('䟶', "zuò"),
+    ('䟷', "chì"),
+    ('䟸', "kuí"),
+    ('䟹', "liè"),
+    ('䟺', "bèi"),
+    ('䟻', "dù"),
+    ('䟼', "wǔ"),
+    ('䟾', "zhuó"),
+    ('䟿', "lù"),
+    ('䠀', "tāng"),
+    ('䠂', "chú"),
+    ('䠃', "liǎng"),
+    ('䠄', "tiǎn"),
+    ('䠅', "kǔn"),
+    ('䠆', "cháng"),
+    ('䠇', "jué"),
+    ('䠈', "tú"),
+    ('䠉', "huàn"),
+    ('䠊', "fèi"),
+    ('䠋', "bì"),
+    ('䠍', "xiā"),
+    ('䠎', "wò"),
+    ('䠏', "jì"),
+    ('䠐', "qù"),
+    ('䠑', "kuǐ"),
+    ('䠒', "hú"),
+    ('䠓', "qiū"),
+    ('䠔', "suì"),
+    ('䠕', "cāi"),
+    ('䠗', "qiù"),
+    ('䠘', "pì"),
+    ('䠙', "páng"),
+    ('䠚', "wà"),
+    ('䠛', "yáo"),
+    ('䠜', "róng"),
+    ('䠝', "xūn"),
+    ('䠞', "cù"),
+    ('䠟', "dié"),
+    ('䠠', "chì"),
+    ('䠡', "cuó"),
+    ('䠢', "mèng"),
+    ('䠣', "xuǎn"),
+    ('䠤', "duǒ"),
+    ('䠥', "bié"),
+    ('䠦', "zhè"),
+    ('䠧', "chú"),
+    ('䠨', "chàn"),
+    ('䠩', "guì"),
+    ('䠪', "duàn"),
+    ('䠫', "zòu"),
+    ('䠬', "dèng"),
+    ('䠭', "lái"),
+    ('䠮', "téng"),
+    ('䠯', "yuè"),
+    ('䠰', "quán"),
+    ('䠱', "zhú"),
+    ('䠲', "líng"),
+    ('䠳', "chēn"),
+    ('䠴', "zhěn"),
+    ('䠵', "fù"),
+    ('䠶', "shè"),
+    ('䠷', "tiǎo"),
+    ('䠸', "kuā"),
+    ('䠹', "ái"),
+    ('䠻', "qióng"),
+    ('䠼', "shù"),
+    ('䠽', "hái"),
+    ('䠾', "shǎn"),
+    ('䠿', "wài"),
+    ('䡀', "zhǎn"),
+    ('䡁', "lǒng"),
+    ('䡂', "jiū"),
+    ('䡃', "lì"),
+    ('䡅', "chūn"),
+    ('䡆', "róng"),
+    ('䡇', "yuè"),
+    ('䡈', "jué"),
+    ('䡉', "kǎng"),
+    ('䡊', "fǎn"),
+    ('䡋', "qí"),
+    ('䡌', "hóng"),
+    ('䡍', "fú"),
+    ('䡎', "lú"),
+    ('䡏', "hóng"),
+    ('䡐', "tuó"),
+    ('䡑', "mín"),
+    ('䡒', "tián"),
+    ('䡓', "juàn"),
+    ('䡔', "qǐ"),
+    ('䡕', "zhěng"),
+    ('䡖', "qìng"),
+    ('䡗', "gǒng"),
+    ('䡘', "tián"),
+    ('䡙', "láng"),
+    ('䡚', "mào"),
+    ('䡛', "yìn"),
+    ('䡜', "lù"),
+    ('䡝', "yuān"),
+    ('䡞', "jú"),
+    ('䡟', "pì"),
+    ('䡡', "xié"),
+    ('䡢', "biàn"),
+    ('䡣', "hūn"),
+    ('䡤', "zhū"),
+    ('䡥', "róng"),
+    ('䡦', "sǎng"),
+    ('䡧', "wū"),
+    ('䡨', "chà"),
+    ('䡩', "kēng"),
+    ('䡪', "shàn"),
+    ('䡫', "péng"),
+    ('䡬', "màn"),
+    ('䡭', "xiū"),
+    ('䡯', "cōng"),
+    ('䡰', "kēng"),
+    ('䡱', "zhuǎn"),
+    ('䡲', "chán"),
+    ('䡳', "sī"),
+    ('䡴', "chōng"),
+    ('䡵', "suì"),
+    ('䡶', "bèi"),
+    ('䡷', "kài"),
+    ('䡹', "zhì"),
+    ('䡺', "wèi"),
+    ('䡻', "mín"),
+    ('䡼', "líng"),
+    ('䡽', "zuān"),
+    ('䡾', "niè"),
+    ('䡿', "líng"),
+    ('䢀', "qì"),
+    ('䢁', "yuè"),
+    ('䢃', "yì"),
+    ('䢄', "xǐ"),
+    ('䢅', "chén"),
+    ('䢇', "rǒng"),
+    ('䢈', "chén"),
+    ('䢉', "nóng"),
+    ('䢊', "yóu"),
+    ('䢋', "jì"),
+    ('䢌', "bó"),
+    ('䢍', "fǎng"),
+    ('䢐', "cú"),
+    ('䢑', "dǐ"),
+    ('䢒', "jiāo"),
+    ('䢓', "yú"),
+    ('䢔', "hé"),
+    ('䢕', "xù"),
+    ('䢖', "yù"),
+    ('䢗', "qū"),
+    ('䢙', "bài"),
+    ('䢚', "gēng"),
+    ('䢛', "jiǒng"),
+    ('䢝', "yà"),
+    ('䢞', "shù"),
+    ('䢟', "yóu"),
+    ('䢠', "sòng"),
+    ('䢡', "yè"),
+    ('䢢', "càng"),
+    ('䢣', "yáo"),
+    ('䢤', "shù"),
+    ('䢥', "yán"),
+    ('䢦', "shuài"),
+    ('䢧', "liào"),
+    ('䢨', "cōng"),
+    ('䢩', "yù"),
+    ('䢪', "bó"),
+    ('䢫', "suí"),
+    ('䢭', "yàn"),
+    ('䢮', "lèi"),
+    ('䢯', "lín"),
+    ('䢰', "tī"),
+    ('䢱', "dú"),
+    ('䢲', "yuè"),
+    ('䢳', "jǐ"),
+    ('䢵', "yún"),
+    ('䢸', "jū"),
+    ('䢹', "jǔ"),
+    ('䢺', "chū"),
+    ('䢻', "chén"),
+    ('䢼', "gōng"),
+    ('䢽', "xiàng"),
+    ('䢾', "xiǎn"),
+    ('䢿', "ān"),
+    ('䣀', "guǐ"),
+    ('䣁', "yǔ"),
+    ('䣂', "lěi"),
+    ('䣄', "tú"),
+    ('䣅', "chén"),
+    ('䣆', "xíng"),
+    ('䣇', "qiú"),
+    ('䣈', "hàng"),
+    ('䣊', "dǎng"),
+    ('䣋', "cǎi"),
+    ('䣌', "dǐ"),
+    ('䣍', "yǎn"),
+    ('䣎', "zī"),
+    ('䣐', "yīng"),
+    ('䣑', "chán"),
+    ('䣓', "lí"),
+    ('䣔', "suǒ"),
+    ('䣕', "mǎ"),
+    ('䣖', "mǎ"),
+    ('䣘', "táng"),
+    ('䣙', "péi"),
+    ('䣚', "lóu"),
+    ('䣛', "qī"),
+    ('䣜', "cuó"),
+    ('䣝', "tú"),
+    ('䣞', "è"),
+    ('䣟', "cán"),
+    ('䣠', "jié"),
+    ('䣡', "yí"),
+    ('䣢', "jí"),
+    ('䣣', "dǎng"),
+    ('䣤', "jué"),
+    ('䣥', "bǐ"),
+    ('䣦', "lèi"),
+    ('䣧', "yì"),
+    ('䣨', "chún"),
+    ('䣩', "chún"),
+    ('䣪', "pò"),
+    ('䣫', "lí"),
+    ('䣬', "zǎi"),
+    ('䣭', "tài"),
+    ('䣮', "pò"),
+    ('䣯', "cú"),
+    ('䣰', "jù"),
+    ('䣱', "xù"),
+    ('䣲', "fàn"),
+    ('䣴', "xù"),
+    ('䣵', "èr"),
+    ('䣶', "huó"),
+    ('䣷', "zhū"),
+    ('䣸', "rǎn"),
+    ('䣹', "fá"),
+    ('䣺', "juān"),
+    ('䣻', "hān"),
+    ('䣼', "liáng"),
+    ('䣽', "zhī"),
+    ('䣾', "mì"),
+    ('䣿', "yū"),
+    ('䤁', "cén"),
+    ('䤂', "méi"),
+    ('䤃', "yīn"),
+    ('䤄', "miǎn"),
+    ('䤅', "tú"),
+    ('䤆', "kuí"),
+    ('䤉', "mì"),
+    ('䤊', "róng"),
+    ('䤋', "yù"),
+    ('䤌', "qiāng"),
+    ('䤍', "mí"),
+    ('䤎', "jú"),
+    ('䤏', "pǐ"),
+    ('䤐', "jǐn"),
+    ('䤑', "wàng"),
+    ('䤒', "jì"),
+    ('䤓', "méng"),
+    ('䤔', "jiàn"),
+    ('䤕', "xuè"),
+    ('䤖', "bào"),
+    ('䤗', "gǎn"),
+    ('䤘', "chǎn"),
+    ('䤙', "lì"),
+    ('䤚', "lǐ"),
+    ('䤛', "qiú"),
+    ('䤜', "dùn"),
+    ('䤝', "yìng"),
+    ('䤞', "yǔn"),
+    ('䤟', "chén"),
+    ('䤠', "zhǐ"),
+    ('䤡', "rǎn"),
+    ('䤣', "lüè"),
+    ('䤤', "kāi"),
+    ('䤥', "guǐ"),
+    ('䤦', "yuè"),
+    ('䤧', "huì"),
+    ('䤨', "pì"),
+    ('䤩', "chá"),
+    ('䤪', "duǒ"),
+    ('䤫', "chán"),
+    ('䤬', "shā"),
+    ('䤭', "shì"),
+    ('䤮', "shè"),
+    ('䤯', "xíng"),
+    ('䤰', "yíng"),
+    ('䤱', "shì"),
+    ('䤲', "chì"),
+    ('䤳', "yè"),
+    ('䤴', "hán"),
+    ('䤵', "fèi"),
+    ('䤶', "yè"),
+    ('䤷', "yǎn"),
+    ('䤸', "zuàn"),
+    ('䤹', "sōu"),
+    ('䤺', "jīn"),
+    ('䤻', "duò"),
+    ('䤼', "xiàn"),
+    ('䤽', "guān"),
+    ('䤾', "tāo"),
+    ('䤿', "qiè"),
+    ('䥀', "chǎn"),
+    ('䥁', "hán"),
+    ('䥂', "mèng"),
+    ('䥃', "yuè"),
+    ('䥄', "cù"),
+    ('䥅', "qiàn"),
+    ('䥆', "jǐn"),
+    ('䥇', "shàn"),
+    ('䥈', "mǔ"),
+    ('䥉', "yuān"),
+    ('䥋', "pēng"),
+    ('䥌', "zhèng"),
+    ('䥍', "zhì"),
+    ('䥎', "chún"),
+    ('䥏', "yǔ"),
+    ('䥐', "móu"),
+    ('䥑', "wàn"),
+    ('䥒', "jiàng"),
+    ('䥓', "qī"),
+    ('䥔', "sù"),
+    ('䥕', "piě"),
+    ('䥖', "tián"),
+    ('䥗', "kuǎn"),
+    ('䥘', "cù"),
+    ('䥙', "suì"),
+    ('䥛', "jiē"),
+    ('䥜', "jiàn"),
+    ('䥝', "áo"),
+    ('䥞', "jiǎo"),
+    ('䥟', "yè"),
+    ('䥡', "yè"),
+    ('䥢', "lóng"),
+    ('䥣', "záo"),
+    ('䥤', "báo"),
+    ('䥥', "lián"),
+    ('䥧', "huán"),
+    ('䥨', "lǜ"),
+    ('䥩', "wéi"),
+    ('䥪', "xiǎn"),
+    ('䥫', "tiě"),
+    ('䥬', "bó"),
+    ('䥭', "zhèng"),
+    ('䥮', "zhú"),
+    ('䥯', "bēi"),
+    ('䥰', "méng"),
+    ('䥱', "xiě"),
+    ('䥲', "ōu"),
+    ('䥳', "yōu"),
+    ('䥵', "xiǎo"),
+    ('䥶', "lì"),
+    ('䥷', "zhá"),
+    ('䥸', "mí"),
+    ('䥺', "yé"),
+    ('䥽', "pō"),
+    ('䥾', "xiě"),
+    ('䦂', "shàn"),
+    ('䦃', "zhuō"),
+    ('䦅', "shàn"),
+    ('䦆', "jué"),
+    ('䦇', "jì"),
+    ('䦈', "jiē"),
+    ('䦊', "niǎo"),
+    ('䦋', "áo"),
+    ('䦌', "chù"),
+    ('䦍', "wù"),
+    ('䦎', "guǎn"),
+    ('䦏', "xiè"),
+    ('䦐', "tǐng"),
+    ('䦑', "xuè"),
+    ('䦒', "dàng"),
+    ('䦓', "zhān"),
+    ('䦔', "tǎn"),
+    ('䦕', "pēng"),
+    ('䦖', "xié"),
+    ('䦗', "xù"),
+    ('䦘', "xiàn"),
+    ('䦙', "sì"),
+    ('䦚', "kuà"),
+    ('䦛', "zhèng"),
+    ('䦜', "wú"),
+    ('䦝', "huō"),
+    ('䦞', "rùn"),
+    ('䦟', "wěn"),
+    ('䦠', "dū"),
+    ('䦡', "huán"),
+    ('䦢', "kuò"),
+    ('䦣', "fù"),
+    ('䦤', "chuài"),
+    ('䦥', "xián"),
+    ('䦦', "qín"),
+    ('䦧', "qié"),
+    ('䦨', "lán"),
+    ('䦪', "yà"),
+    ('䦫', "yīng"),
+    ('䦬', "què"),
+    ('䦭', "hāng"),
+    ('䦮', "chǔn"),
+    ('䦯', "zhì"),
+    ('䦱', "wěi"),
+    ('䦲', "yán"),
+    ('䦳', "xiàng"),
+    ('䦴', "yì"),
+    ('䦵', "nǐ"),
+    ('䦶', "zhèng"),
+    ('䦷', "chuài"),
+    ('䦹', "shí"),
+    ('䦺', "dīng"),
+    ('䦻', "zǐ"),
+    ('䦼', "jué"),
+    ('䦽', "xù"),
+    ('䦾', "yuán"),
+    ('䧁', "xǔ"),
+    ('䧂', "dào"),
+    ('䧃', "tián"),
+    ('䧄', "gè"),
+    ('䧅', "yí"),
+    ('䧆', "hóng"),
+    ('䧇', "yī"),
+    ('䧉', "lǐ"),
+    ('䧊', "kū"),
+    ('䧋', "xiǎn"),
+    ('䧌', "suī"),
+    ('䧍', "xì"),
+    ('䧎', "xuàn"),
+    ('䧑', "dī"),
+    ('䧒', "lái"),
+    ('䧓', "zhōu"),
+    ('䧔', "niàn"),
+    ('䧕', "chéng"),
+    ('䧖', "jiàn"),
+    ('䧗', "bì"),
+    ('䧘', "zhuàn"),
+    ('䧙', "líng"),
+    ('䧚', "hào"),
+    ('䧛', "bàng"),
+    ('䧜', "táng"),
+    ('䧝', "chī"),
+    ('䧞', "mà"),
+    ('䧟', "xiàn"),
+    ('䧠', "shuàn"),
+    ('䧡', "yōng"),
+    ('䧢', "qū"),
+    ('䧤', "pú"),
+    ('䧥', "huì"),
+    ('䧦', "wéi"),
+    ('䧧', "yǐ"),
+    ('䧨', "yè"),
+    ('䧪', "chè"),
+    ('䧫', "háo"),
+    ('䧬', "bīn"),
+    ('䧮', "xiàn"),
+    ('䧯', "chán"),
+    ('䧰', "hùn"),
+    ('䧲', "hàn"),
+    ('䧳', "cí"),
+    ('䧴', "zhī"),
+    ('䧵', "qí"),
+    ('䧶', "kuí"),
+    ('䧷', "róu"),
+    ('䧹', "yīng"),
+    ('䧺', "xióng"),
+    ('䧼', "hú"),
+    ('䧽', "cuǐ"),
+    ('䧿', "què"),
+    ('䨀', "dí"),
+    ('䨁', "wù"),
+    ('䨂', "qiū"),
+    ('䨄', "yàn"),
+    ('䨅', "liáo"),
+    ('䨆', "bí"),
+    ('䨈', "bīn"),
+    ('䨊', "yuān"),
+    ('䨋', "nüè"),
+    ('䨌', "báo"),
+    ('䨍', "yǐng"),
+    ('䨎', "hóng"),
+    ('䨏', "cí"),
+    ('䨐', "qià"),
+    ('䨑', "tí"),
+    ('䨒', "yù"),
+    ('䨓', "léi"),
+    ('䨔', "báo"),
+    ('䨖', "jì"),
+    ('䨗', "fú"),
+    ('䨘', "xiàn"),
+    ('䨙', "cén"),
+    ('䨚', "hū"),
+    ('䨛', "sè"),
+    ('䨜', "bēng"),
+    ('䨝', "qīng"),
+    ('䨞', "yǔ"),
+    ('䨟', "wā"),
+    ('䨠', "ǎi"),
+    ('䨡', "hán"),
+    ('䨢', "dàn"),
+    ('䨣', "gé"),
+    ('䨤', "dí"),
+    ('䨥', "huò"),
+    ('䨦', "pāng"),
+    ('䨨', "zhuī"),
+    ('䨩', "líng"),
+    ('䨪', "mái"),
+    ('䨫', "mài"),
+    ('䨬', "lián"),
+    ('䨭', "xiāo"),
+    ('䨮', "xuě"),
+    ('䨯', "zhèn"),
+    ('䨰', "pò"),
+    ('䨱', "fù"),
+    ('䨲', "nóu"),
+    ('䨳', "xì"),
+    ('䨴', "duì"),
+    ('䨵', "dàn"),
+    ('䨶', "yǔn"),
+    ('䨷', "xiàn"),
+    ('䨸', "yǐn"),
+    ('䨹', "shū"),
+    ('䨺', "duì"),
+    ('䨻', "bèng"),
+    ('䨼', "hù"),
+    ('䨽', "fěi"),
+    ('䨾', "fèi"),
+    ('䨿', "zá"),
+    ('䩀', "bèi"),
+    ('䩁', "fēi"),
+    ('䩂', "xiān"),
+    ('䩃', "shì"),
+    ('䩄', "miǎn"),
+    ('䩅', "zhǎn"),
+    ('䩆', "zhǎn"),
+    ('䩇', "zhān"),
+    ('䩈', "huì"),
+    ('䩉', "fǔ"),
+    ('䩊', "wǎn"),
+    ('䩋', "mǒ"),
+    ('䩌', "qiáo"),
+    ('䩍', "liǎo"),
+    ('䩏', "miè"),
+    ('䩐', "hū"),
+    ('䩑', "hóng"),
+    ('䩒', "yú"),
+    ('䩓', "qí"),
+    ('䩔', "duò"),
+    ('䩕', "áng"),
+    ('䩗', "bà"),
+    ('䩘', "dì"),
+    ('䩙', "xuàn"),
+    ('䩚', "dì"),
+    ('䩛', "bì"),
+    ('䩜', "zhòu"),
+    ('䩝', "páo"),
+    ('䩞', "tié"),
+    ('䩟', "yí"),
+    ('䩡', "jiá"),
+    ('䩢', "zhì"),
+    ('䩣', "tú"),
+    ('䩤', "xié"),
+    ('䩥', "dàn"),
+    ('䩦', "tiáo"),
+    ('䩧', "xiè"),
+    ('䩨', "chàng"),
+    ('䩩', "yuǎn"),
+    ('䩪', "guǎn"),
+    ('䩫', "liǎng"),
+    ('䩬', "běng"),
+    ('䩮', "lù"),
+    ('䩯', "jí"),
+    ('䩰', "xuàn"),
+    ('䩱', "shù"),
+    ('䩲', "dū"),
+    ('䩳', "sōu"),
+    ('䩴', "hú"),
+    ('䩵', "yùn"),
+    ('䩶', "chǎn"),
+    ('䩷', "bāng"),
+    ('䩸', "róng"),
+    ('䩹', "é"),
+    ('䩺', "wēng"),
+    ('䩻', "bà"),
+    ('䩼', "féng"),
+    ('䩽', "yū"),
+    ('䩾', "zhè"),
+    ('䩿', "fén"),
+    ('䪀', "guǎn"),
+    ('䪁', "bǔ"),
+    ('䪂', "gé"),
+    ('䪃', "dūn"),
+    ('䪄', "huáng"),
+    ('䪅', "dú"),
+    ('䪆', "tǐ"),
+    ('䪇', "bó"),
+    ('䪈', "qiàn"),
+    ('䪉', "liè"),
+    ('䪊', "lóng"),
+    ('䪋', "wèi"),
+    ('䪌', "zhàn"),
+    ('䪍', "lán"),
+    ('䪎', "suī"),
+    ('䪏', "nà"),
+    ('䪐', "bì"),
+    ('䪑', "tuó"),
+    ('䪒', "zhù"),
+    ('䪓', "diē"),
+    ('䪔', "bǔ"),
+    ('䪕', "jú"),
+    ('䪖', "pò"),
+    ('䪗', "xiá"),
+    ('䪘', "wěi"),
+    ('䪙', "pò"),
+    ('䪚', "dā"),
+    ('䪛', "fān"),
+    ('䪜', "chān"),
+    ('䪝', "hù"),
+    ('䪞', "zá"),
+    ('䪤', "fán"),
+    ('䪥', "xiè"),
+    ('䪦', "hóng"),
+    ('䪧', "chí"),
+    ('䪨', "báo"),
+    ('䪩', "yín"),
+    ('䪫', "jīng"),
+    ('䪬', "bó"),
+    ('䪭', "ruǎn"),
+    ('䪮', "chǒu"),
+    ('䪯', "yīng"),
+    ('䪰', "yī"),
+    ('䪱', "gǎi"),
+    ('䪲', "kūn"),
+    ('䪳', "yǔn"),
+    ('䪴', "zhěn"),
+    ('䪵', "yǎ"),
+    ('䪶', "jū"),
+    ('䪷', "hòu"),
+    ('䪸', "mín"),
+    ('䪹', "bāi"),
+    ('䪺', "gé"),
+    ('䪻', "biàn"),
+    ('䪼', "zhuō"),
+    ('䪽', "hào"),
+    ('䪾', "zhěn"),
+    ('䪿', "shěng"),
+    ('䫀', "gěn"),
+    ('䫁', "bì"),
+    ('䫂', "duǒ"),
+    ('䫃', "chún"),
+    ('䫄', "chuà"),
+    ('䫅', "sàn"),
+    ('䫆', "chéng"),
+    ('䫇', "rán"),
+    ('䫈', "chěn"),
+    ('䫉', "mào"),
+    ('䫊', "péi"),
+    ('䫋', "wēi"),
+    ('䫌', "pǐ"),
+    ('䫍', "fǔ"),
+    ('䫎', "zhuō"),
+    ('䫏', "qī"),
+    ('䫐', "lín"),
+    ('䫑', "yī"),
+    ('䫒', "mén"),
+    ('䫓', "wú"),
+    ('䫔', "qì"),
+    ('䫕', "dié"),
+    ('䫖', "chěn"),
+    ('䫗', "xiá"),
+    ('䫘', "hé"),
+    ('䫙', "sǎng"),
+    ('䫚', "guā"),
+    ('䫛', "hóu"),
+    ('䫜', "āo"),
+    ('䫝', "fǔ"),
+    ('䫞', "qiāo"),
+    ('䫟', "hùn"),
+    ('䫠', "pī"),
+    ('䫡', "yán"),
+    ('䫢', "sī"),
+    ('䫣', "xí"),
+    ('䫤', "míng"),
+    ('䫥', "kuǐ"),
+    ('䫦', "gé"),
+    ('䫨', "ào"),
+    ('䫩', "sǎn"),
+    ('䫪', "shuǎng"),
+    ('䫫', "lóu"),
+    ('䫬', "zhěn"),
+    ('䫭', "huì"),
+    ('䫮', "chán"),
+    ('䫰', "lìn"),
+    ('䫱', "ná"),
+    ('䫲', "hàn"),
+    ('䫳', "dú"),
+    ('䫴', "jìn"),
+    ('䫵', "mián"),
+    ('䫶', "fán"),
+    ('䫷', "è"),
+    ('䫸', "chāo"),
+    ('䫹', "hóng"),
+    ('䫺', "hóng"),
+    ('䫻', "yù"),
+    ('䫼', "xuè"),
+    ('䫽', "pāo"),
+    ('䫾', "bī"),
+    ('䫿', "chāo"),
+    ('䬀', "yǒu"),
+    ('䬁', "yí"),
+    ('䬂', "xuè"),
+    ('䬃', "sà"),
+    ('䬄', "xù"),
+    ('䬅', "lì"),
+    ('䬆', "lì"),
+    ('䬇', "yuàn"),
+    ('䬈', "duì"),
+    ('䬉', "huò"),
+    ('䬊', "shà"),
+    ('䬋', "léng"),
+    ('䬌', "pōu"),
+    ('䬍', "hū"),
+    ('䬎', "guó"),
+    ('䬏', "bù"),
+    ('䬐', "ruí"),
+    ('䬑', "wèi"),
+    ('䬒', "sōu"),
+    ('䬓', "àn"),
+    ('䬔', "yú"),
+    ('䬕', "xiāng"),
+    ('䬖', "héng"),
+    ('䬗', "yáng"),
+    ('䬘', "xiāo"),
+    ('䬙', "yáo"),
+    ('䬛', "bì"),
+    ('䬝', "héng"),
+    ('䬞', "táo"),
+    ('䬟', "liú"),
+    ('䬡', "zhù"),
+    ('䬣', "xì"),
+    ('䬤', "zàn"),
+    ('䬥', "yì"),
+    ('䬦', "dòu"),
+    ('䬧', "yuán"),
+    ('䬨', "jiù"),
+    ('䬪', "bó"),
+    ('䬫', "tí"),
+    ('䬬', "yǐng"),
+    ('䬮', "yí"),
+    ('䬯', "nián"),
+    ('䬰', "shào"),
+    ('䬱', "bèn"),
+    ('䬲', "gōu"),
+    ('䬳', "bǎn"),
+    ('䬴', "mò"),
+    ('䬵', "gāi"),
+    ('䬶', "èn"),
+    ('䬷', "shě"),
+    ('䬹', "zhì"),
+    ('䬺', "yàng"),
+    ('䬻', "jiàn"),
+    ('䬼', "yuàn"),
+    ('䬽', "shuì"),
+    ('䬾', "tí"),
+    ('䬿', "wěi"),
+    ('䭀', "xùn"),
+    ('䭁', "zhì"),
+    ('䭂', "yì"),
+    ('䭃', "rěn"),
+    ('䭄', "shì"),
+    ('䭅', "hú"),
+    ('䭆', "nè"),
+    ('䭇', "yē"),
+    ('䭈', "jiàn"),
+    ('䭉', "suǐ"),
+    ('䭊', "yǐng"),
+    ('䭋', "bǎo"),
+    ('䭌', "hú"),
+    ('䭍', "hú"),
+    ('䭎', "yè"),
+    ('䭐', "yàng"),
+    ('䭑', "lián"),
+    ('䭒', "xī"),
+    ('䭓', "èn"),
+    ('䭔', "duī"),
+    ('䭕', "zǎn"),
+    ('䭖', "zhù"),
+    ('䭗', "yǐng"),
+    ('䭘', "yǐng"),
+    ('䭙', "jǐn"),
+    ('䭚', "chuáng"),
+    ('䭛', "dàn"),
+    ('䭝', "kuài"),
+    ('䭞', "yì"),
+    ('䭟', "yè"),
+    ('䭠', "jiǎn"),
+    ('䭡', "èn"),
+    ('䭢', "níng"),
+    ('䭣', "cí"),
+    ('䭤', "qiǎn"),
+    ('䭥', "xuè"),
+    ('䭦', "bō"),
+    ('䭧', "mǐ"),
+    ('䭨', "shuì"),
+    ('䭩', "mó"),
+    ('䭪', "liáng"),
+    ('䭫', "qǐ"),
+    ('䭬', "qǐ"),
+    ('䭭', "shǒu"),
+    ('䭮', "fú"),
+    ('䭯', "bó"),
+    ('䭰', "bèng"),
+    ('䭱', "bié"),
+    ('䭲', "yǐ"),
+    ('䭳', "wèi"),
+    ('䭴', "huán"),
+    ('䭵', "fán"),
+    ('䭶', "qí"),
+    ('䭷', "máo"),
+    ('䭸', "fù"),
+    ('䭹', "áng"),
+    ('䭺', "ǎng"),
+    ('䭻', "fù"),
+    ('䭼', "qí"),
+    ('䭽', "qún"),
+    ('䭾', "tuó"),
+    ('䭿', "yì"),
+    ('䮀', "bó"),
+    ('䮁', "pián"),
+    ('䮂', "bá"),
+    ('䮄', "xuán"),
+    ('䮇', "yù"),
+    ('䮈', "chí"),
+    ('䮉', "lú"),
+    ('䮊', "yí"),
+    ('䮋', "lì"),
+    ('䮍', "niǎo"),
+    ('䮎', "xì"),
+    ('䮏', "wú"),
+    ('䮑', "lèi"),
+    ('䮒', "pū"),
+    ('䮓', "zhuō"),
+    ('䮔', "zuī"),
+    ('䮕', "zhuó"),
+    ('䮖', "chāng"),
+    ('䮗', "àn"),
+    ('䮘', "ér"),
+    ('䮙', "yù"),
+    ('䮚', "lèng"),
+    ('䮛', "fù"),
+    ('䮜', "zhá"),
+    ('䮝', "hún"),
+    ('䮞', "chǔn"),
+    ('䮟', "sōu"),
+    ('䮠', "bī"),
+    ('䮡', "bì"),
+    ('䮢', "zhá"),
+    ('䮤', "hé"),
+    ('䮥', "lì"),
+    ('䮧', "hàn"),
+    ('䮨', "zǎi"),
+    ('䮩', "gú"),
+    ('䮪', "chéng"),
+    ('䮫', "lóu"),
+    ('䮬', "mò"),
+    ('䮭', "mì"),
+    ('䮮', "mài"),
+    ('䮯', "ào"),
+    ('䮰', "zhé"),
+    ('䮱', "zhú"),
+    ('䮲', "huáng"),
+    ('䮳', "fán"),
+    ('䮴', "dèng"),
+    ('䮵', "tóng"),
+    ('䮷', "dú"),
+    ('䮸', "wò"),
+    ('䮹', "wèi"),
+    ('䮺', "jì"),
+    ('䮻', "chì"),
+    ('䮼', "lín"),
+    ('䮽', "biāo"),
+    ('䮾', "lóng"),
+    ('䮿', "jiǎn"),
+    ('䯀', "niè"),
+    ('䯁', "luó"),
+    ('䯂', "shēn"),
+    ('䯄', "guā"),
+    ('䯅', "niè"),
+    ('䯆', "yì"),
+    ('䯇', "kū"),
+    ('䯈', "wán"),
+    ('䯉', "wā"),
+    ('䯊', "qià"),
+    ('䯋', "bó"),
+    ('䯌', "kāo"),
+    ('䯍', "líng"),
+    ('䯎', "gàn"),
+    ('䯏', "guā"),
+    ('䯐', "hái"),
+    ('䯑', "kuāng"),
+    ('䯒', "héng"),
+    ('䯓', "kuī"),
+    ('䯔', "zé"),
+    ('䯕', "tīng"),
+    ('䯖', "láng"),
+    ('䯗', "bì"),
+    ('䯘', "huàn"),
+    ('䯙', "pò"),
+    ('䯚', "yǎo"),
+    ('䯛', "wàn"),
+    ('䯜', "tì"),
+    ('䯝', "suǐ"),
+    ('䯞', "kuā"),
+    ('䯟', "duì"),
+    ('䯠', "ǎo"),
+    ('䯡', "jiàn"),
+    ('䯢', "mó"),
+    ('䯣', "kuì"),
+    ('䯤', "kuài"),
+    ('䯥', "àn"),
+    ('䯦', "mà"),
+    ('䯧', "qǐng"),
+    ('䯨', "qiāo"),
+    ('䯪', "kǎo"),
+    ('䯫', "hào"),
+    ('䯬', "duǒ"),
+    ('䯭', "xiān"),
+    ('䯮', "nái"),
+    ('䯯', "suō"),
+    ('䯰', "jiè"),
+    ('䯱', "pī"),
+    ('䯲', "pā"),
+    ('䯳', "sōng"),
+    ('䯴', "cháng"),
+    ('䯵', "niè"),
+    ('䯶', "mán"),
+    ('䯷', "sōng"),
+    ('䯸', "cì"),
+    ('䯹', "xiān"),
+    ('䯺', "kuò"),
+    ('䯼', "dí"),
+    ('䯽', "póu"),
+    ('䯾', "tiáo"),
+    ('䯿', "zú"),
+    ('䰀', "wǒ"),
+    ('䰁', "fèi"),
+    ('䰂', "cài"),
+    ('䰃', "péng"),
+    ('䰄', "sāi"),
+    ('䰆', "róu"),
+    ('䰇', "qí"),
+    ('䰈', "cuó"),
+    ('䰉', "pán"),
+    ('䰊', "bó"),
+    ('䰋', "mán"),
+    ('䰌', "zǒng"),
+    ('䰍', "cì"),
+    ('䰎', "kuì"),
+    ('䰏', "jì"),
+    ('䰐', "lán"),
+    ('䰒', "méng"),
+    ('䰓', "mián"),
+    ('䰔', "pán"),
+    ('䰕', "lú"),
+    ('䰖', "zuǎn"),
+    ('䰗', "jiū"),
+    ('䰘', "liú"),
+    ('䰙', "yǐ"),
+    ('䰚', "wén"),
+    ('䰛', "lì"),
+    ('䰜', "lì"),
+    ('䰝', "zèng"),
+    ('䰞', "zhǔ"),
+    ('䰟', "hún"),
+    ('䰠', "shén"),
+    ('䰡', "chì"),
+    ('䰢', "xìng"),
+    ('䰣', "wǎng"),
+    ('䰤', "dōng"),
+    ('䰥', "huò"),
+    ('䰦', "pǐ"),
+    ('䰧', "hū"),
+    ('䰨', "mèi"),
+    ('䰩', "chě"),
+    ('䰪', "mèi"),
+    ('䰫', "chāo"),
+    ('䰬', "jú"),
+    ('䰭', "nòu"),
+    ('䰯', "yì"),
+    ('䰰', "rú"),
+    ('䰱', "líng"),
+    ('䰲', "yà"),
+    ('䰴', "qì"),
+    ('䰵', "zī"),
+    ('䰷', "bàng"),
+    ('䰸', "gōng"),
+    ('䰹', "zé"),
+    ('䰺', "jiè"),
+    ('䰻', "yú"),
+    ('䰼', "qín"),
+    ('䰽', "bèi"),
+    ('䰾', "bā"),
+    ('䰿', "tuó"),
+    ('䱀', "yāng"),
+    ('䱁', "qiáo"),
+    ('䱂', "yǒu"),
+    ('䱃', "zhì"),
+    ('䱄', "jiè"),
+    ('䱅', "mò"),
+    ('䱆', "shéng"),
+    ('䱇', "shàn"),
+    ('䱈', "qí"),
+    ('䱉', "shàn"),
+    ('䱊', "mǐ"),
+    ('䱋', "gǒng"),
+    ('䱌', "yí"),
+    ('䱍', "gèng"),
+    ('䱎', "gèng"),
+    ('䱏', "tǒu"),
+    ('䱐', "fū"),
+    ('䱑', "xué"),
+    ('䱒', "yè"),
+    ('䱓', "tíng"),
+    ('䱔', "tiáo"),
+    ('䱕', "móu"),
+    ('䱖', "liú"),
+    ('䱗', "cān"),
+    ('䱘', "lí"),
+    ('䱙', "shū"),
+    ('䱚', "lù"),
+    ('䱛', "huò"),
+    ('䱜', "cuò"),
+    ('䱝', "pái"),
+    ('䱞', "liú"),
+    ('䱟', "jù"),
+    ('䱠', "zhàn"),
+    ('䱡', "jú"),
+    ('䱢', "zhēng"),
+    ('䱣', "zú"),
+    ('䱤', "xiàn"),
+    ('䱥', "zhì"),
+    ('䱨', "là"),
+    ('䱫', "là"),
+    ('䱬', "xū"),
+    ('䱭', "gèng"),
+    ('䱮', "é"),
+    ('䱯', "mú"),
+    ('䱰', "zhòng"),
+    ('䱱', "tí"),
+    ('䱲', "yuán"),
+    ('䱳', "zhān"),
+    ('䱴', "gèng"),
+    ('䱵', "wēng"),
+    ('䱶', "láng"),
+    ('䱷', "yú"),
+    ('䱸', "sōu"),
+    ('䱹', "zhǎ"),
+    ('䱺', "hái"),
+    ('䱻', "huá"),
+    ('䱼', "zhǎn"),
+    ('䱽', "chāng"),
+    ('䱾', "lóu"),
+    ('䱿', "chàn"),
+    ('䲀', "zhì"),
+    ('䲁', "wèi"),
+    ('䲂', "xuán"),
+    ('䲃', "zǎo"),
+    ('䲄', "mín"),
+    ('䲅', "guī"),
+    ('䲆', "sū"),
+    ('䲉', "sī"),
+    ('䲊', "duò"),
+    ('䲋', "cén"),
+    ('䲌', "kuǎn"),
+    ('䲍', "téng"),
+    ('䲎', "něi"),
+    ('䲏', "láo"),
+    ('䲐', "lǔ"),
+    ('䲑', "yí"),
+    ('䲒', "xiè"),
+    ('䲓', "yǎn"),
+    ('䲔', "qíng"),
+    ('䲕', "pū"),
+    ('䲖', "chóu"),
+    ('䲗', "xián"),
+    ('䲘', "guǎn"),
+    ('䲙', "jié"),
+    ('䲚', "lài"),
+    ('䲛', "méng"),
+    ('䲜', "yè"),
+    ('䲝', "chāng"),
+    ('䲞', "lì"),
+    ('䲟', "yìn"),
+    ('䲠', "chūn"),
+    ('䲡', "qiū"),
+    ('䲢', "téng"),
+    ('䲣', "yú"),
+    ('䲦', "dài"),
+    ('䲧', "dù"),
+    ('䲨', "hóng"),
+    ('䲪', "xì"),
+    ('䲬', "qí"),
+    ('䲮', "yuán"),
+    ('䲯', "jí"),
+    ('䲰', "yùn"),
+    ('䲱', "fǎng"),
+    ('䲲', "gōng"),
+    ('䲳', "háng"),
+    ('䲴', "zhèn"),
+    ('䲵', "què"),
+    ('䲸', "jiè"),
+    ('䲹', "pí"),
+    ('䲺', "gàn"),
+    ('䲻', "xuán"),
+    ('䲼', "shēng"),
+    ('䲽', "shí"),
+    ('䲾', "qiǎo"),
+    ('䲿', "cí"),
+    ('䳀', "dié"),
+    ('䳁', "bó"),
+    ('䳂', "diāo"),
+    ('䳃', "wǎn"),
+    ('䳄', "cí"),
+    ('䳅', "zhǐ"),
+    ('䳆', "bái"),
+    ('䳇', "wǔ"),
+    ('䳈', "bǎo"),
+    ('䳉', "dàn"),
+    ('䳊', "bá"),
+    ('䳋', "tóng"),
+    ('䳍', "gōng"),
+    ('䳎', "jiù"),
+    ('䳏', "guì"),
+    ('䳐', "cì"),
+    ('䳑', "yǒu"),
+    ('䳒', "yuán"),
+    ('䳓', "lǎo"),
+    ('䳔', "jú"),
+    ('䳕', "fú"),
+    ('䳖', "niè"),
+    ('䳗', "é"),
+    ('䳘', "é"),
+    ('䳙', "xǐng"),
+    ('䳚', "kàn"),
+    ('䳛', "yàn"),
+    ('䳜', "tú"),
+    ('䳝', "pǒu"),
+    ('䳞', "běng"),
+    ('䳟', "míng"),
+    ('䳠', "shuì"),
+    ('䳡', "yàn"),
+    ('䳢', "qí"),
+    ('䳣', "yuán"),
+    ('䳤', "biē"),
+    ('䳦', "xuān"),
+    ('䳧', "hóu"),
+    ('䳨', "huáng"),
+    ('䳩', "yāo"),
+    ('䳪', "juàn"),
+    ('䳫', "kuí"),
+    ('䳬', "è"),
+    ('䳭', "jí"),
+    ('䳮', "mò"),
+    ('䳯', "chóng"),
+    ('䳰', "bǎo"),
+    ('䳱', "wù"),
+    ('䳲', "zhèn"),
+    ('䳳', "xù"),
+    ('䳴', "tà"),
+    ('䳵', "chì"),
+    ('䳶', "xī"),
+    ('䳷', "cóng"),
+    ('䳸', "má"),
+    ('䳹', "kòu"),
+    ('䳺', "yàn"),
+    ('䳻', "cán"),
+    ('䳽', "hè"),
+    ('䳾', "dēng"),
+    ('䳿', "rán"),
+    ('䴀', "tóng"),
+    ('䴁', "yù"),
+    ('䴂', "xiàng"),
+    ('䴃', "náo"),
+    ('䴄', "shùn"),
+    ('䴅', "fén"),
+    ('䴆', "pú"),
+    ('䴇', "líng"),
+    ('䴈', "ǎo"),
+    ('䴉', "huán"),
+    ('䴊', "yí"),
+    ('䴋', "huán"),
+    ('䴌', "méng"),
+    ('䴍', "yīng"),
+    ('䴎', "lěi"),
+    ('䴏', "yàn"),
+    ('䴐', "bǎo"),
+    ('䴑', "dié"),
+    ('䴒', "líng"),
+    ('䴓', "shī"),
+    ('䴔', "jiāo"),
+    ('䴕', "liè"),
+    ('䴖', "jīng"),
+    ('䴗', "jú"),
+    ('䴘', "tī"),
+    ('䴙', "pì"),
+    ('䴚', "gǎng"),
+    ('䴛', "xiāo"),
+    ('䴜', "wāi"),
+    ('䴝', "chuài"),
+    ('䴞', "dí"),
+    ('䴟', "huán"),
+    ('䴠', "yǎo"),
+    ('䴡', "lì"),
+    ('䴢', "mí"),
+    ('䴣', "hū"),
+    ('䴤', "shēng"),
+    ('䴥', "jiā"),
+    ('䴦', "yín"),
+    ('䴧', "wēi"),
+    ('䴩', "piáo"),
+    ('䴪', "lù"),
+    ('䴫', "líng"),
+    ('䴬', "yì"),
+    ('䴭', "cái"),
+    ('䴮', "shàn"),
+    ('䴯', "hū"),
+    ('䴰', "shú"),
+    ('䴱', "tuō"),
+    ('䴲', "mò"),
+    ('䴳', "huá"),
+    ('䴴', "tiè"),
+    ('䴵', "bǐng"),
+    ('䴶', "péng"),
+    ('䴷', "hún"),
+    ('䴸', "fū"),
+    ('䴹', "guǒ"),
+    ('䴺', "bù"),
+    ('䴻', "lí"),
+    ('䴼', "chàn"),
+    ('䴽', "pí"),
+    ('䴾', "cuó"),
+    ('䴿', "méng"),
+    ('䵀', "suǒ"),
+    ('䵁', "qiàng"),
+    ('䵂', "zhí"),
+    ('䵃', "kuàng"),
+    ('䵄', "bí"),
+    ('䵅', "áo"),
+    ('䵆', "méng"),
+    ('䵇', "xiàn"),
+    ('䵈', "kù"),
+    ('䵉', "tóu"),
+    ('䵊', "tuān"),
+    ('䵋', "wěi"),
+    ('䵌', "xiān"),
+    ('䵎', "tuān"),
+    ('䵏', "lǎo"),
+    ('䵐', "chǎn"),
+    ('䵑', "nì"),
+    ('䵒', "nì"),
+    ('䵓', "lí"),
+    ('䵔', "dǒng"),
+    ('䵕', "jù"),
+    ('䵖', "qiàn"),
+    ('䵗', "bó"),
+    ('䵘', "shài"),
+    ('䵙', "zhā"),
+    ('䵚', "tǎo"),
+    ('䵛', "qiàn"),
+    ('䵜', "nǒng"),
+    ('䵝', "yì"),
+    ('䵞', "jìng"),
+    ('䵟', "gǎn"),
+    ('䵠', "dí"),
+    ('䵡', "jiǎn"),
+    ('䵢', "mèi"),
+    ('䵣', "dá"),
+    ('䵤', "jiǎn"),
+    ('䵥', "yù"),
+    ('䵦', "xiè"),
+    ('䵧', "zài"),
+    ('䵨', "máng"),
+    ('䵩', "lí"),
+    ('䵪', "gùn"),
+    ('䵫', "xūn"),
+    ('䵬', "tà"),
+    ('䵭', "zhè"),
+    ('䵮', "yàng"),
+    ('䵯', "tuǎn"),
+    ('䵰', "shāng"),
+    ('䵱', "xì"),
+    ('䵲', "qiāo"),
+    ('䵳', "wèi"),
+    ('䵴', "yìng"),
+    ('䵵', "chuā"),
+    ('䵶', "qú"),
+    ('䵷', "wā"),
+    ('䵹', "zhī"),
+    ('䵺', "tǐng"),
+    ('䵻', "gǔ"),
+    ('䵼', "shāng"),
+    ('䵽', "cà"),
+    ('䵾', "fú"),
+    ('䵿', "tiè"),
+    ('䶀', "tà"),
+    ('䶁', "tà"),
+    ('䶂', "zhuó"),
+    ('䶃', "hán"),
+    ('䶄', "píng"),
+    ('䶅', "hé"),
+    ('䶆', "zhuī"),
+    ('䶇', "zhòu"),
+    ('䶈', "bó"),
+    ('䶉', "liú"),
+    ('䶊', "nǜ"),
+    ('䶋', "xī"),
+    ('䶌', "pào"),
+    ('䶍', "dì"),
+    ('䶎', "hē"),
+    ('䶏', "tì"),
+    ('䶐', "wài"),
+    ('䶑', "tì"),
+    ('䶒', "qí"),
+    ('䶓', "jì"),
+    ('䶔', "chí"),
+    ('䶕', "bà"),
+    ('䶖', "jìn"),
+    ('䶗', "kè"),
+    ('䶘', "lì"),
+    ('䶙', "jù"),
+    ('䶚', "qǔ"),
+    ('䶛', "là"),
+    ('䶜', "gǔ"),
+    ('䶝', "qià"),
+    ('䶞', "qí"),
+    ('䶟', "xiàn"),
+    ('䶠', "jiǎn"),
+    ('䶡', "shí"),
+    ('䶢', "jiān"),
+    ('䶣', "ái"),
+    ('䶤', "huá"),
+    ('䶥', "zhā"),
+    ('䶦', "zé"),
+    ('䶧', "yǎo"),
+    ('䶨', "zhān"),
+    ('䶩', "jì"),
+    ('䶪', "chà"),
+    ('䶫', "yàn"),
+    ('䶬', "jiān"),
+    ('䶮', "yǎn"),
+    ('䶰', "jiāo"),
+    ('䶱', "tóng"),
+    ('䶲', "nán"),
+    ('䶳', "yuè"),
+    ('䶵', "chí"),
+    ('一', "yī"),
+    ('丁', "dīng"),
+    ('丂', "kǎo"),
+    ('七', "qī"),
+    ('丄', "shàng"),
+    ('丅', "xià"),
+    ('丆', "hǎn"),
+    ('万', "wàn"),
+    ('丈', "zhàng"),
+    ('三', "sān"),
+    ('上', "shàng,shǎng"),
+    ('下', "xià"),
+    ('丌', "jī"),
+    ('不', "bù"),
+    ('与', "yǔ,yù"),
+    ('丏', "miǎn"),
+    ('丐', "gài"),
+    ('丑', "chǒu"),
+    ('丒', "chǒu"),
+    ('专', "zhuān"),
+    ('且', "qiě"),
+    ('丕', "pī"),
+    ('世', "shì"),
+    ('丗', "shì"),
+    ('丘', "qiū"),
+    ('丙', "bǐng"),
+    ('业', "yè"),
+    ('丛', "cóng"),
+    ('东', "dōng"),
+    ('丝', "sī"),
+    ('丞', "chéng"),
+    ('丟', "diū"),
+    ('丠', "qiū"),
+    ('両', "liǎng"),
+    ('丢', "diū"),
+    ('丣', "yǒu"),
+    ('两', "liǎng"),
+    ('严', "yán"),
+    ('並', "bìng"),
+    ('丧', "sàng,sāng"),
+    ('丨', "gǔn"),
+    ('丩', "jiū"),
+    ('个', "gè,gě"),
+    ('丫', "yā"),
+    ('丬', "qiáng"),
+    ('中', "zhōng,zhòng"),
+    ('丮', "jǐ"),
+    ('丯', "jiè"),
+    ('丰', "fēng"),
+    ('丱', "guàn"),
+    ('串', "chuàn"),
+    ('丳', "chǎn"),
+    ('临', "lín"),
+    ('丵', "zhuó"),
+    ('丶', "zhǔ"),
+    ('丷', "bā"),
+    ('丸', "wán"),
+    ('丹', "dān"),
+    ('为', "wéi,wèi"),
+    ('主', "zhǔ"),
+    ('丼', "jǐng"),
+    ('丽', "lì"),
+    ('举', "jǔ"),
+    ('丿', "piě"),
+    ('乀', "fú"),
+    ('乁', "yí"),
+    ('乂', "yì"),
+    ('乃', "nǎi"),
+    ('乄', "wǔ"),
+    ('久', "jiǔ"),
+    ('乆', "jiǔ"),
+    ('乇', "tuō"),
+    ('么', "me,mó,ma"),
+    ('义', "yì"),
+    ('乊', "yī"),
+    ('之', "zhī"),
+    ('乌', "wū,wù"),
+    ('乍', "zhà"),
+    ('乎', "hū"),
+    ('乏', "fá"),
+    ('乐', "lè,yuè"),
+    ('乑', "yín"),
+    ('乒', "pīng"),
+    ('乓', "pāng"),
+    ('乔', "qiáo"),
+    ('乕', "hǔ"),
+    ('乖', "guāi"),
+    ('乗', "chéng"),
+    ('乘', "chéng,shèng"),
+    ('乙', "yǐ"),
+    ('乚', "yǐn"),
+    ('乛', "ya"),
+    ('乜', "miē"),
+    ('九', "jiǔ"),
+    ('乞', "qǐ"),
+    ('也', "yě"),
+    ('习', "xí"),
+    ('乡', "xiāng"),
+    ('乢', "gài"),
+    ('乣', "jiǔ"),
+    ('乤', "xià"),
+    ('乥', "hù"),
+    ('书', "shū"),
+    ('乧', "dǒu"),
+    ('乨', "shǐ"),
+    ('乩', "jī"),
+    ('乪', "náng"),
+    ('乫', "jiā"),
+    ('乬', "jù"),
+    ('乭', "shí"),
+    ('乮', "mǎo"),
+    ('乯', "hū"),
+    ('买', "mǎi"),
+    ('乱', "luàn"),
+    ('乲', "zī"),
+    ('乳', "rǔ"),
+    ('乴', "xué"),
+    ('乵', "yǎn"),
+    ('乶', "fǔ"),
+    ('乷', "shā"),
+    ('乸', "nǎ"),
+    ('乹', "gān"),
+    ('乺', "suǒ"),
+    ('乻', "yú"),
+    ('乼', "cui"),
+    ('乽', "zhě"),
+    ('乾', "qián"),
+    ('乿', "zhì"),
+    ('亀', "guī"),
+    ('亁', "gān"),
+    ('亂', "luàn"),
+    ('亃', "lǐn"),
+    ('亄', "yì"),
+    ('亅', "jué"),
+    ('了', "le,liǎo"),
+    ('亇', "ma"),
+    ('予', "yǔ,yú"),
+    ('争', "zhēng"),
+    ('亊', "shì"),
+    ('事', "shì"),
+    ('二', "èr"),
+    ('亍', "chù"),
+    ('于', "yú"),
+    ('亏', "kuī"),
+    ('亐', "yú"),
+    ('云', "yún"),
+    ('互', "hù"),
+    ('亓', "qí"),
+    ('五', "wǔ"),
+    ('井', "jǐng"),
+    ('亖', "sì"),
+    ('亗', "suì"),
+    ('亘', "gèn"),
+    ('亙', "gèn"),
+    ('亚', "yà"),
+    ('些', "xiē"),
+    ('亜', "yà"),
+    ('亝', "qí"),
+    ('亞', "yà"),
+    ('亟', "jí"),
+    ('亠', "tóu"),
+    ('亡', "wáng"),
+    ('亢', "kàng"),
+    ('亣', "dà"),
+    ('交', "jiāo"),
+    ('亥', "hài"),
+    ('亦', "yì"),
+    ('产', "chǎn"),
+    ('亨', "hēng"),
+    ('亩', "mǔ"),
+    ('亪', "ye"),
+    ('享', "xiǎng"),
+    ('京', "jīng"),
+    ('亭', "tíng"),
+    ('亮', "liàng"),
+    ('亯', "xiǎng"),
+    ('亰', "jīng"),
+    ('亱', "yè"),
+    ('亲', "qīn,qìng"),
+    ('亳', "bó"),
+    ('亴', "yòu"),
+    ('亵', "xiè"),
+    ('亶', "dǎn"),
+    ('亷', "lián"),
+    ('亸', "duǒ"),
+    ('亹', "mén"),
+    ('人', "rén"),
+    ('亻', "rén"),
+    ('亼', "jí"),
+    ('亽', "jí"),
+    ('亾', "wáng"),
+    ('亿', "yì"),
+    ('什', "shén,shí"),
+    ('仁', "rén"),
+    ('仂', "lè"),
+    ('仃', "dīng"),
+    ('仄', "zè"),
+    ('仅', "jǐn"),
+    ('仆', "pū"),
+    ('仇', "chóu,qiú"),
+    ('仈', "bā"),
+    ('仉', "zhǎng"),
+    ('今', "jīn"),
+    ('介', "jiè"),
+    ('仌', "bīng"),
+    ('仍', "réng"),
+    ('从', "cóng"),
+    ('仏', "fó"),
+    ('仐', "sǎn"),
+    ('仑', "lún"),
+    ('仒', "bīng"),
+    ('仓', "cāng"),
+    ('仔', "zǎi,zǐ,zī"),
+    ('仕', "shì"),
+    ('他', "tā"),
+    ('仗', "zhàng"),
+    ('付', "fù"),
+    ('仙', "xiān"),
+    ('仚', "xiān"),
+    ('仛', "tuō"),
+    ('仜', "hóng"),
+    ('仝', "tóng"),
+    ('仞', "rèn"),
+    ('仟', "qiān"),
+    ('仠', "gǎn"),
+    ('仡', "gē"),
+    ('仢', "bó"),
+    ('代', "dài"),
+    ('令', "lìng"),
+    ('以', "yǐ"),
+    ('仦', "chào"),
+    ('仧', "cháng"),
+    ('仨', "sā"),
+    ('仩', "cháng"),
+    ('仪', "yí"),
+    ('仫', "mù"),
+    ('们', "men"),
+    ('仭', "rèn"),
+    ('仮', "fǎn"),
+    ('仯', "chào"),
+    ('仰', "yǎng"),
+    ('仱', "qián"),
+    ('仲', "zhòng"),
+    ('仳', "pǐ"),
+    ('仴', "wò"),
+    ('仵', "wǔ"),
+    ('件', "jiàn"),
+    ('价', "jià"),
+    ('仸', "yǎo"),
+    ('仹', "fēng"),
+    ('仺', "cāng"),
+    ('任', "rèn,rén"),
+    ('仼', "wáng"),
+    ('份', "fèn"),
+    ('仾', "dī"),
+    ('仿', "fǎng"),
+    ('伀', "zhōng"),
+    ('企', "qǐ"),
+    ('伂', "pèi"),
+    ('伃', "yú"),
+    ('伄', "diào"),
+    ('伅', "dùn"),
+    ('伆', "wù"),
+    ('伇', "yì"),
+    ('伈', "xǐn"),
+    ('伉', "kàng"),
+    ('伊', "yī"),
+    ('伋', "jí"),
+    ('伌', "ài"),
+    ('伍', "wǔ"),
+    ('伎', "jì"),
+    ('伏', "fú"),
+    ('伐', "fá"),
+    ('休', "xiū"),
+    ('伒', "jìn"),
+    ('伓', "pī"),
+    ('伔', "dǎn"),
+    ('伕', "fū"),
+    ('伖', "tǎng"),
+    ('众', "zhòng"),
+    ('优', "yōu"),
+    ('伙', "huǒ"),
+    ('会', "huì,kuài"),
+    ('伛', "yǔ"),
+    ('伜', "cuì"),
+    ('伝', "yún"),
+    ('伞', "sǎn"),
+    ('伟', "wěi"),
+    ('传', "chuán,zhuàn"),
+    ('伡', "chē"),
+    ('伢', "yá"),
+    ('伣', "xiàn"),
+    ('伤', "shāng"),
+    ('伥', "chāng"),
+    ('伦', "lún"),
+    ('伧', "cāng"),
+    ('伨', "xùn"),
+    ('伩', "xìn"),
+    ('伪', "wěi"),
+    ('伫', "zhù"),
+    ('伬', "ze"),
+    ('伭', "xián"),
+    ('伮', "nǔ"),
+    ('伯', "bó,bǎi"),
+    ('估', "gū"),
+    ('伱', "nǐ"),
+    ('伲', "nì"),
+    ('伳', "xiè"),
+    ('伴', "bàn"),
+    ('伵', "xù"),
+    ('伶', "líng"),
+    ('伷', "zhòu"),
+    ('伸', "shēn"),
+    ('伹', "qū"),
+    ('伺', "cì,sì"),
+    ('伻', "bēng"),
+    ('似', "sì,shì"),
+    ('伽', "jiā"),
+    ('伾', "pī"),
+    ('伿', "yì"),
+    ('佀', "sì"),
+    ('佁', "yǐ"),
+    ('佂', "zhēng"),
+    ('佃', "diàn"),
+    ('佄', "hān"),
+    ('佅', "mài"),
+    ('但', "dàn"),
+    ('佇', "zhù"),
+    ('佈', "bù"),
+    ('佉', "qū"),
+    ('佊', "bǐ"),
+    ('佋', "zhāo"),
+    ('佌', "cǐ"),
+    ('位', "wèi"),
+    ('低', "dī"),
+    ('住', "zhù"),
+    ('佐', "zuǒ"),
+    ('佑', "yòu"),
+    ('佒', "yǎng"),
+    ('体', "tǐ"),
+    ('佔', "zhàn"),
+    ('何', "hé"),
+    ('佖', "bì"),
+    ('佗', "tuó"),
+    ('佘', "shé"),
+    ('余', "yú"),
+    ('佚', "yì"),
+    ('佛', "fó,fú"),
+    ('作', "zuò,zuō"),
+    ('佝', "gōu"),
+    ('佞', "nìng"),
+    ('佟', "tóng"),
+    ('你', "nǐ"),
+    ('佡', "xiān"),
+    ('佢', "qú"),
+    ('佣', "yōng,yòng"),
+    ('佤', "wǎ"),
+    ('佥', "qiān"),
+    ('佦', "shi"),
+    ('佧', "kǎ"),
+    ('佨', "bāo"),
+    ('佩', "pèi"),
+    ('佪', "huí"),
+    ('佫', "hè"),
+    ('佬', "lǎo"),
+    ('佭', "xiáng"),
+    ('佮', "gé"),
+    ('佯', "yáng"),
+    ('佰', "bǎi"),
+    ('佱', "fǎ"),
+    ('佲', "mǐng"),
+    ('佳', "jiā"),
+    ('佴', "èr"),
+    ('併', "bìng"),
+    ('佶', "jí"),
+    ('佷', "hěn"),
+    ('佸', "huó"),
+    ('佹', "guǐ"),
+    ('佺', "quán"),
+    ('佻', "tiāo"),
+    ('佼', "jiǎo"),
+    ('佽', "cì"),
+    ('佾', "yì"),
+    ('使', "shǐ"),
+    ('侀', "xíng"),
+    ('侁', "shēn"),
+    ('侂', "tuō"),
+    ('侃', "kǎn"),
+    ('侄', "zhí"),
+    ('侅', "gāi"),
+    ('來', "lái"),
+    ('侇', "yí"),
+    ('侈', "chǐ"),
+    ('侉', "kuǎ"),
+    ('侊', "guāng"),
+    ('例', "lì"),
+    ('侌', "yīn"),
+    ('侍', "shì"),
+    ('侎', "mǐ"),
+    ('侏', "zhū"),
+    ('侐', "xù"),
+    ('侑', "yòu"),
+    ('侒', "ān"),
+    ('侓', "lù"),
+    ('侔', "móu"),
+    ('侕', "ér"),
+    ('侖', "lún"),
+    ('侗', "dòng"),
+    ('侘', "chà"),
+    ('侙', "chī"),
+    ('侚', "xùn"),
+    ('供', "gōng,gòng"),
+    ('侜', "zhōu"),
+    ('依', "yī"),
+    ('侞', "rú"),
+    ('侟', "cún"),
+    ('侠', "xiá"),
+    ('価', "sì"),
+    ('侢', "dài"),
+    ('侣', "lǚ"),
+    ('侤', "ta"),
+    ('侥', "jiǎo,yáo"),
+    ('侦', "zhēn"),
+    ('侧', "cè,zè,zhāi"),
+    ('侨', "qiáo"),
+    ('侩', "kuài"),
+    ('侪', "chái"),
+    ('侫', "nìng"),
+    ('侬', "nóng"),
+    ('侭', "jǐn"),
+    ('侮', "wǔ"),
+    ('侯', "hóu"),
+    ('侰', "jiǒng"),
+    ('侱', "chěng"),
+    ('侲', "zhèn"),
+    ('侳', "zuò"),
+    ('侴', "chǒu"),
+    ('侵', "qīn"),
+    ('侶', "lǚ"),
+    ('侷', "jú"),
+    ('侸', "shù"),
+    ('侹', "tǐng"),
+    ('侺', "shèn"),
+    ('侻', "tuì"),
+    ('侼', "bó"),
+    ('侽', "nán"),
+    ('侾', "xiāo"),
+    ('便', "biàn,pián"),
+    ('俀', "tuǐ"),
+    ('俁', "yǔ"),
+    ('係', "xì"),
+    ('促', "cù"),
+    ('俄', "é"),
+    ('俅', "qiú"),
+    ('俆', "xú"),
+    ('俇', "guàng"),
+    ('俈', "kù"),
+    ('俉', "wǔ"),
+    ('俊', "jùn"),
+    ('俋', "yì"),
+    ('俌', "fǔ"),
+    ('俍', "liáng"),
+    ('俎', "zǔ"),
+    ('俏', "qiào"),
+    ('俐', "lì"),
+    ('俑', "yǒng"),
+    ('俒', "hùn"),
+    ('俓', "jìng"),
+    ('俔', "qiàn"),
+    ('俕', "sàn"),
+    ('俖', "pěi"),
+    ('俗', "sú"),
+    ('俘', "fú"),
+    ('俙', "xī"),
+    ('俚', "lǐ"),
+    ('俛', "fǔ"),
+    ('俜', "pīng"),
+    ('保', "bǎo"),
+    ('俞', "yú"),
+    ('俟', "qí"),
+    ('俠', "xiá"),
+    ('信', "xìn"),
+    ('俢', "xiū"),
+    ('俣', "yǔ"),
+    ('俤', "dì"),
+    ('俥', "chē"),
+    ('俦', "chóu"),
+    ('俧', "zhì"),
+    ('俨', "yǎn"),
+    ('俩', "liǎ,liǎng"),
+    ('俪', "lì"),
+    ('俫', "lái"),
+    ('俬', "sī"),
+    ('俭', "jiǎn"),
+    ('修', "xiū"),
+    ('俯', "fǔ"),
+    ('俰', "huò"),
+    ('俱', "jù"),
+    ('俲', "xiào"),
+    ('俳', "pái"),
+    ('俴', "jiàn"),
+    ('俵', "biào"),
+    ('俶', "chù"),
+    ('俷', "fèi"),
+    ('俸', "fèng"),
+    ('俹', "yà"),
+    ('俺', "ǎn"),
+    ('俻', "bèi"),
+    ('俼', "yù"),
+    ('俽', "xīn"),
+    ('俾', "bǐ"),
+    ('俿', "hǔ"),
+    ('倀', "chāng"),
+    ('倁', "zhī"),
+    ('倂', "bìng"),
+    ('倃', "jiù"),
+    ('倄', "yáo"),
+    ('倅', "cuì"),
+    ('倆', "liǎ"),
+    ('倇', "wǎn"),
+    ('倈', "lái"),
+    ('倉', "cāng"),
+    ('倊', "zòng"),
+    ('個', "gè"),
+    ('倌', "guān"),
+    ('倍', "bèi"),
+    ('倎', "tiǎn"),
+    ('倏', "shū"),
+    ('倐', "shū"),
+    ('們', "men"),
+    ('倒', "dǎo,dào"),
+    ('倓', "tán"),
+    ('倔', "jué"),
+    ('倕', "chuí"),
+    ('倖', "xìng"),
+    ('倗', "péng"),
+    ('倘', "tǎng"),
+    ('候', "hòu"),
+    ('倚', "yǐ"),
+    ('倛', "qī"),
+    ('倜', "tì"),
+    ('倝', "gàn"),
+    ('倞', "jìng"),
+    ('借', "jiè"),
+    ('倠', "suī"),
+    ('倡', "chàng"),
+    ('倢', "jié"),
+    ('倣', "fǎng"),
+    ('値', "zhí"),
+    ('倥', "kōng"),
+    ('倦', "juàn"),
+    ('倧', "zōng"),
+    ('倨', "jù"),
+    ('倩', "qiàn"),
+    ('倪', "ní"),
+    ('倫', "lún"),
+    ('倬', "zhuō"),
+    ('倭', "wō"),
+    ('倮', "luǒ"),
+    ('倯', "sōng"),
+    ('倰', "lèng"),
+    ('倱', "hùn"),
+    ('倲', "dōng"),
+    ('倳', "zì"),
+    ('倴', "bèn"),
+    ('倵', "wǔ"),
+    ('倶', "jù"),
+    ('倷', "nǎi"),
+    ('倸', "cǎi"),
+    ('倹', "jiǎn"),
+    ('债', "zhài"),
+    ('倻', "yē"),
+    ('值', "zhí"),
+    ('倽', "shà"),
+    ('倾', "qīng"),
+    ('倿', "nìng"),
+    ('偀', "yīng"),
+    ('偁', "chēng"),
+    ('偂', "qián"),
+    ('偃', "yǎn"),
+    ('偄', "ruǎn"),
+    ('偅', "zhòng"),
+    ('偆', "chǔn"),
+    ('假', "jiǎ,jià"),
+    ('偈', "jì"),
+    ('偉', "wěi"),
+    ('偊', "yǔ"),
+    ('偋', "bìng"),
+    ('偌', "ruò"),
+    ('偍', "tí"),
+    ('偎', "wēi"),
+    ('偏', "piān"),
+    ('偐', "yàn"),
+    ('偑', "fēng"),
+    ('偒', "tǎng"),
+    ('偓', "wò"),
+    ('偔', "è"),
+    ('偕', "xié"),
+    ('偖', "chě"),
+    ('偗', "shěng"),
+    ('偘', "kǎn"),
+    ('偙', "dì"),
+    ('做', "zuò"),
+    ('偛', "chā"),
+    ('停', "tíng"),
+    ('偝', "bèi"),
+    ('偞', "xiè"),
+    ('偟', "huáng"),
+    ('偠', "yǎo"),
+    ('偡', "zhàn"),
+    ('偢', "chǒu"),
+    ('偣', "yān"),
+    ('偤', "yóu"),
+    ('健', "jiàn"),
+    ('偦', "xǔ"),
+    ('偧', "zhā"),
+    ('偨', "cī"),
+    ('偩', "fù"),
+    ('偪', "bī"),
+    ('偫', "zhì"),
+    ('偬', "zǒng"),
+    ('偭', "miǎn"),
+    ('偮', "jí"),
+    ('偯', "yǐ"),
+    ('偰', "xiè"),
+    ('偱', "xún"),
+    ('偲', "cāi"),
+    ('偳', "duān"),
+    ('側', "cè"),
+    ('偵', "zhēn"),
+    ('偶', "ǒu"),
+    ('偷', "tōu"),
+    ('偸', "tōu"),
+    ('偹', "bèi"),
+    ('偺', "zá"),
+    ('偻', "lóu"),
+    ('偼', "jié"),
+    ('偽', "wěi"),
+    ('偾', "fèn"),
+    ('偿', "cháng"),
+    ('傀', "guī"),
+    ('傁', "sǒu"),
+    ('傂', "zhì"),
+    ('傃', "sù"),
+    ('傄', "xiā"),
+    ('傅', "fù"),
+    ('傆', "yuàn"),
+    ('傇', "rǒng"),
+    ('傈', "lì"),
+    ('傉', "nù"),
+    ('傊', "yùn"),
+    ('傋', "jiǎng"),
+    ('傌', "mà"),
+    ('傍', "bàng"),
+    ('傎', "diān"),
+    ('傏', "táng"),
+    ('傐', "hào"),
+    ('傑', "jié"),
+    ('傒', "xī"),
+    ('傓', "shàn"),
+    ('傔', "qiàn"),
+    ('傕', "jué"),
+    ('傖', "cāng"),
+    ('傗', "chù"),
+    ('傘', "sǎn"),
+    ('備', "bèi"),
+    ('傚', "xiào"),
+    ('傛', "yǒng"),
+    ('傜', "yáo"),
+    ('傝', "tàn"),
+    ('傞', "suō"),
+    ('傟', "yǎng"),
+    ('傠', "fá"),
+    ('傡', "bìng"),
+    ('傢', "jiā"),
+    ('傣', "dǎi"),
+    ('傤', "zài"),
+    ('傥', "tǎng"),
+    ('傦', "gǔ"),
+    ('傧', "bīn"),
+    ('储', "chǔ"),
+    ('傩', "nuó"),
+    ('傪', "cān"),
+    ('傫', "lěi"),
+    ('催', "cuī"),
+    ('傭', "yōng"),
+    ('傮', "zāo"),
+    ('傯', "zǒng"),
+    ('傰', "bēng"),
+    ('傱', "sǒng"),
+    ('傲', "ào"),
+    ('傳', "chuán"),
+    ('傴', "yǔ"),
+    ('債', "zhài"),
+    ('傶', "zú"),
+    ('傷', "shāng"),
+    ('傸', "chuǎng"),
+    ('傹', "jìng"),
+    ('傺', "chì"),
+    ('傻', "shǎ"),
+    ('傼', "hàn"),
+    ('傽', "zhāng"),
+    ('傾', "qīng"),
+    ('傿', "yàn"),
+    ('僀', "dì"),
+    ('僁', "xiè"),
+    ('僂', "lóu"),
+    ('僃', "bèi"),
+    ('僄', "piào"),
+    ('僅', "jǐn"),
+    ('僆', "liàn"),
+    ('僇', "lù"),
+    ('僈', "mán"),
+    ('僉', "qiān"),
+    ('僊', "xiān"),
+    ('僋', "tàn"),
+    ('僌', "yíng"),
+    ('働', "dòng"),
+    ('僎', "zhuàn"),
+    ('像', "xiàng"),
+    ('僐', "shàn"),
+    ('僑', "qiáo"),
+    ('僒', "jiǒng"),
+    ('僓', "tuǐ"),
+    ('僔', "zǔn"),
+    ('僕', "pú"),
+    ('僖', "xī"),
+    ('僗', "láo"),
+    ('僘', "chǎng"),
+    ('僙', "guāng"),
+    ('僚', "liáo"),
+    ('僛', "qī"),
+    ('僜', "chēng"),
+    ('僝', "chán"),
+    ('僞', "wěi"),
+    ('僟', "jī"),
+    ('僠', "bō"),
+    ('僡', "huì"),
+    ('僢', "chuǎn"),
+    ('僣', "tiě"),
+    ('僤', "dàn"),
+    ('僥', "jiǎo"),
+    ('僦', "jiù"),
+    ('僧', "sēng"),
+    ('僨', "fèn"),
+    ('僩', "xiàn"),
+    ('僪', "jú"),
+    ('僫', "è"),
+    ('僬', "jiāo"),
+    ('僭', "jiàn"),
+    ('僮', "tóng"),
+    ('僯', "lìn"),
+    ('僰', "bó"),
+    ('僱', "gù"),
+    ('僲', "xiān"),
+    ('僳', "sù"),
+    ('僴', "xiàn"),
+    ('僵', "jiāng"),
+    ('僶', "mǐn"),
+    ('僷', "yè"),
+    ('僸', "jìn"),
+    ('價', "jià"),
+    ('僺', "qiào"),
+    ('僻', "pì"),
+    ('僼', "fēng"),
+    ('僽', "zhòu"),
+    ('僾', "ài"),
+    ('僿', "sài"),
+    ('儀', "yí"),
+    ('儁', "jùn"),
+    ('儂', "nóng"),
+    ('儃', "chán"),
+    ('億', "yì"),
+    ('儅', "dàng"),
+    ('儆', "jǐng"),
+    ('儇', "xuān"),
+    ('儈', "kuài"),
+    ('儉', "jiǎn"),
+    ('儊', "chù"),
+    ('儋', "dān"),
+    ('儌', "jiǎo"),
+    ('儍', "shǎ"),
+    ('儎', "zài"),
+    ('儏', "càn"),
+    ('儐', "bīn"),
+    ('儑', "án"),
+    ('儒', "rú"),
+    ('儓', "tái"),
+    ('儔', "chóu"),
+    ('儕', "chái"),
+    ('儖', "lán"),
+    ('儗', "nǐ"),
+    ('儘', "jǐn"),
+    ('儙', "qiàn"),
+    ('儚', "méng"),
+    ('儛', "wǔ"),
+    ('儜', "níng"),
+    ('儝', "qióng"),
+    ('儞', "nǐ"),
+    ('償', "cháng"),
+    ('儠', "liè"),
+    ('儡', "lěi"),
+    ('儢', "lǚ"),
+    ('儣', "kuǎng"),
+    ('儤', "bào"),
+    ('儥', "yù"),
+    ('儦', "biāo"),
+    ('儧', "zǎn"),
+    ('儨', "zhì"),
+    ('儩', "sì"),
+    ('優', "yōu"),
+    ('儫', "háo"),
+    ('儬', "qìng"),
+    ('儭', "chèn"),
+    ('儮', "lì"),
+    ('儯', "téng"),
+    ('儰', "wěi"),
+    ('儱', "lǒng"),
+    ('儲', "chǔ"),
+    ('儳', "chán"),
+    ('儴', "ráng"),
+    ('儵', "shū"),
+    ('儶', "huì"),
+    ('儷', "lì"),
+    ('儸', "luó"),
+    ('儹', "zǎn"),
+    ('儺', "nuó"),
+    ('儻', "tǎng"),
+    ('儼', "yǎn"),
+    ('儽', "léi"),
+    ('儾', "nàng"),
+    ('儿', "ér"),
+    ('兀', "wù"),
+    ('允', "yǔn"),
+    ('兂', "zān"),
+    ('元', "yuán"),
+    ('兄', "xiōng"),
+    ('充', "chōng"),
+    ('兆', "zhào"),
+    ('兇', "xiōng"),
+    ('先', "xiān"),
+    ('光', "guāng"),
+    ('兊', "duì"),
+    ('克', "kè"),
+    ('兌', "duì"),
+    ('免', "miǎn"),
+    ('兎', "tù"),
+    ('兏', "cháng"),
+    ('児', "ér"),
+    ('兑', "duì"),
+    ('兒', "ér"),
+    ('兓', "jīn"),
+    ('兔', "tù"),
+    ('兕', "sì"),
+    ('兖', "yǎn"),
+    ('兗', "yǎn"),
+    ('兘', "shǐ"),
+    ('党', "dǎng"),
+    ('兛', "qiān"),
+    ('兜', "dōu"),
+    ('兝', "fēn"),
+    ('兞', "máo"),
+    ('兟', "shēn"),
+    ('兠', "dōu"),
+    ('兢', "jīng"),
+    ('兣', "lǐ"),
+    ('兤', "huǎng"),
+    ('入', "rù"),
+    ('兦', "wáng"),
+    ('內', "nèi"),
+    ('全', "quán"),
+    ('兩', "liǎng"),
+    ('兪', "yú"),
+    ('八', "bā"),
+    ('公', "gōng"),
+    ('六', "liù"),
+    ('兮', "xī"),
+    ('兯', "han"),
+    ('兰', "lán"),
+    ('共', "gòng"),
+    ('兲', "tiān"),
+    ('关', "guān"),
+    ('兴', "xīng,xìng"),
+    ('兵', "bīng"),
+    ('其', "qí"),
+    ('具', "jù"),
+    ('典', "diǎn"),
+    ('兹', "zī"),
+    ('兺', "fēn"),
+    ('养', "yǎng"),
+    ('兼', "jiān"),
+    ('兽', "shòu"),
+    ('兾', "jì"),
+    ('兿', "yì"),
+    ('冀', "jì"),
+    ('冁', "chǎn"),
+    ('冂', "jiōng"),
+    ('冃', "mào"),
+    ('冄', "rǎn"),
+    ('内', "nèi"),
+    ('円', "yuán"),
+    ('冇', "mǎo"),
+    ('冈', "gāng"),
+    ('冉', "rǎn"),
+    ('冊', "cè"),
+    ('冋', "jiōng"),
+    ('册', "cè"),
+    ('再', "zài"),
+    ('冎', "guǎ"),
+    ('冏', "jiǒng"),
+    ('冐', "mào"),
+    ('冑', "zhòu"),
+    ('冒', "mào,mò"),
+    ('冓', "gòu"),
+    ('冔', "xǔ"),
+    ('冕', "miǎn"),
+    ('冖', "mì"),
+    ('冗', "rǒng"),
+    ('冘', "yín"),
+    ('写', "xiě"),
+    ('冚', "kǎn"),
+    ('军', "jūn"),
+    ('农', "nóng"),
+    ('冝', "yí"),
+    ('冞', "mí"),
+    ('冟', "shì"),
+    ('冠', "guān,guàn"),
+    ('冡', "méng"),
+    ('冢', "zhǒng"),
+    ('冣', "jù"),
+    ('冤', "yuān"),
+    ('冥', "míng"),
+    ('冦', "kòu"),
+    ('冧', "lín"),
+    ('冨', "fù"),
+    ('冩', "xiě"),
+    ('冪', "mì"),
+    ('冫', "bīng"),
+    ('冬', "dōng"),
+    ('冭', "tài"),
+    ('冮', "gāng"),
+    ('冯', "féng"),
+    ('冰', "bīng"),
+    ('冱', "hù"),
+    ('冲', "chōng,chòng"),
+    ('决', "jué"),
+    ('冴', "hù"),
+    ('况', "kuàng"),
+    ('冶', "yě"),
+    ('冷', "lěng"),
+    ('冸', "pàn"),
+    ('冹', "fú"),
+    ('冺', "mǐn"),
+    ('冻', "dòng"),
+    ('冼', "xiǎn"),
+    ('冽', "liè"),
+    ('冾', "qià"),
+    ('冿', "jiān"),
+    ('净', "jìng"),
+    ('凁', "sōu"),
+    ('凂', "měi"),
+    ('凃', "tú"),
+    ('凄', "qī"),
+    ('凅', "gù"),
+    ('准', "zhǔn"),
+    ('凇', "sōng"),
+    ('凈', "jìng"),
+    ('凉', "liáng,liàng"),
+    ('凊', "qìng"),
+    ('凋', "diāo"),
+    ('凌', "líng"),
+    ('凍', "dòng"),
+    ('凎', "gàn"),
+    ('减', "jiǎn"),
+    ('凐', "yīn"),
+    ('凑', "còu"),
+    ('凒', "ái"),
+    ('凓', "lì"),
+    ('凔', "chuàng"),
+    ('凕', "mǐng"),
+    ('凖', "zhǔn"),
+    ('凗', "cuī"),
+    ('凘', "sī"),
+    ('凙', "duó"),
+    ('凚', "jìn"),
+    ('凛', "lǐn"),
+    ('凜', "lǐn"),
+    ('凝', "níng"),
+    ('凞', "xī"),
+    ('凟', "dú"),
+    ('几', "jǐ,jī"),
+    ('凡', "fán"),
+    ('凢', "fán"),
+    ('凣', "fán"),
+    ('凤', "fèng"),
+    ('凥', "jū"),
+    ('処', "chǔ"),
+    ('凧', "zhēng"),
+    ('凨', "fēng"),
+    ('凩', "mù"),
+    ('凪', "zhǐ"),
+    ('凫', "fú"),
+    ('凬', "fēng"),
+    ('凭', "píng"),
+    ('凮', "fēng"),
+    ('凯', "kǎi"),
+    ('凰', "huáng"),
+    ('凱', "kǎi"),
+    ('凲', "gān"),
+    ('凳', "dèng"),
+    ('凴', "píng"),
+    ('凵', "qiǎn"),
+    ('凶', "xiōng"),
+    ('凷', "kuài"),
+    ('凸', "tū"),
+    ('凹', "āo"),
+    ('出', "chū"),
+    ('击', "jī"),
+    ('凼', "dàng"),
+    ('函', "hán"),
+    ('凾', "hán"),
+    ('凿', "záo"),
+    ('刀', "dāo"),
+    ('刁', "diāo"),
+    ('刂', "dāo"),
+    ('刃', "rèn"),
+    ('刄', "rèn"),
+    ('刅', "chuāng"),
+    ('分', "fēn,fèn"),
+    ('切', "qiē,qiè"),
+    ('刈', "yì"),
+    ('刉', "jī"),
+    ('刊', "kān"),
+    ('刋', "qiàn"),
+    ('刌', "cǔn"),
+    ('刍', "chú"),
+    ('刎', "wěn"),
+    ('刏', "jī"),
+    ('刐', "dǎn"),
+    ('刑', "xíng"),
+    ('划', "huà,huá"),
+    ('刓', "wán"),
+    ('刔', "jué"),
+    ('刕', "lí"),
+    ('刖', "yuè"),
+    ('列', "liè"),
+    ('刘', "liú"),
+    ('则', "zé"),
+    ('刚', "gāng"),
+    ('创', "chuàng,chuāng"),
+    ('刜', "fú"),
+    ('初', "chū"),
+    ('刞', "qù"),
+    ('刟', "diāo"),
+    ('删', "shān"),
+    ('刡', "mǐn"),
+    ('刢', "líng"),
+    ('刣', "zhōng"),
+    ('判', "pàn"),
+    ('別', "bié"),
+    ('刦', "jié"),
+    ('刧', "jié"),
+    ('刨', "páo,bào"),
+    ('利', "lì"),
+    ('刪', "shān"),
+    ('别', "bié,biè"),
+    ('刬', "chǎn"),
+    ('刭', "jǐng"),
+    ('刮', "guā"),
+    ('刯', "gēng"),
+    ('到', "dào"),
+    ('刱', "chuàng"),
+    ('刲', "kuī"),
+    ('刳', "kū"),
+    ('刴', "duò"),
+    ('刵', "èr"),
+    ('制', "zhì"),
+    ('刷', "shuā,shuà"),
+    ('券', "quàn"),
+    ('刹', "chà,shā"),
+    ('刺', "cì,cī"),
+    ('刻', "kè"),
+    ('刼', "jié"),
+    ('刽', "guì"),
+    ('刾', "cì"),
+    ('刿', "guì"),
+    ('剀', "kǎi"),
+    ('剁', "duò"),
+    ('剂', "jì"),
+    ('剃', "tì"),
+    ('剄', "jǐng"),
+    ('剅', "lóu"),
+    ('剆', "luǒ"),
+    ('則', "zé"),
+    ('剈', "yuān"),
+    ('剉', "cuò"),
+    ('削', "xiāo,xuē"),
+    ('剋', "kēi"),
+    ('剌', "lá"),
+    ('前', "qián"),
+    ('剎', "shā"),
+    ('剏', "chuàng"),
+    ('剐', "guǎ"),
+    ('剑', "jiàn"),
+    ('剒', "cuò"),
+    ('剓', "lí"),
+    ('剔', "tī"),
+    ('剕', "fèi"),
+    ('剖', "pōu"),
+    ('剗', "chǎn"),
+    ('剘', "qí"),
+    ('剙', "chuàng"),
+    ('剚', "zì"),
+    ('剛', "gāng"),
+    ('剜', "wān"),
+    ('剝', "bō"),
+    ('剞', "jī"),
+    ('剟', "duō"),
+    ('剠', "qíng"),
+    ('剡', "shàn"),
+    ('剢', "dū"),
+    ('剣', "jiàn"),
+    ('剤', "jì"),
+    ('剥', "bō,bāo"),
+    ('剦', "yān"),
+    ('剧', "jù"),
+    ('剨', "huō"),
+    ('剩', "shèng"),
+    ('剪', "jiǎn"),
+    ('剫', "duó"),
+    ('剬', "duān"),
+    ('剭', "wū"),
+    ('剮', "guǎ"),
+    ('副', "fù"),
+    ('剰', "shèng"),
+    ('剱', "jiàn"),
+    ('割', "gē"),
+    ('剳', "dá"),
+    ('剴', "kǎi"),
+    ('創', "chuàng"),
+    ('剶', "chuān"),
+    ('剷', "chǎn"),
+    ('剸', "tuán"),
+    ('剹', "lù"),
+    ('剺', "lí"),
+    ('剻', "pěng"),
+    ('剼', "shān"),
+    ('剽', "piāo"),
+    ('剾', "kōu"),
+    ('剿', "jiǎo"),
+    ('劀', "guā"),
+    ('劁', "qiāo"),
+    ('劂', "jué"),
+    ('劃', "huà"),
+    ('劄', "zhā"),
+    ('劅', "zhuó"),
+    ('劆', "lián"),
+    ('劇', "jù"),
+    ('劈', "pī,pǐ"),
+    ('劉', "liú"),
+    ('劊', "guì"),
+    ('劋', "jiǎo"),
+    ('劌', "guì"),
+    ('劍', "jiàn"),
+    ('劎', "jiàn"),
+    ('劏', "tāng"),
+    ('劐', "huō"),
+    ('劑', "jì"),
+    ('劒', "jiàn"),
+    ('劓', "yì"),
+    ('劔', "jiàn"),
+    ('劕', "zhì"),
+    ('劖', "chán"),
+    ('劗', "jiǎn"),
+    ('劘', "mó"),
+    ('劙', "lí"),
+    ('劚', "zhǔ"),
+    ('力', "lì"),
+    ('劜', "yà"),
+    ('劝', "quàn"),
+    ('办', "bàn"),
+    ('功', "gōng"),
+    ('加', "jiā"),
+    ('务', "wù"),
+    ('劢', "mài"),
+    ('劣', "liè"),
+    ('劤', "jìn"),
+    ('劥', "kēng"),
+    ('劦', "xié"),
+    ('劧', "zhǐ"),
+    ('动', "dòng"),
+    ('助', "zhù"),
+    ('努', "nǔ"),
+    ('劫', "jié"),
+    ('劬', "qú"),
+    ('劭', "shào"),
+    ('劮', "yì"),
+    ('劯', "zhū"),
+    ('劰', "mò"),
+    ('励', "lì"),
+    ('劲', "jìn,jìng"),
+    ('劳', "láo"),
+    ('労', "láo"),
+    ('劵', "juàn"),
+    ('劶', "kǒu"),
+    ('劷', "yáng"),
+    ('劸', "wā"),
+    ('効', "xiào"),
+    ('劺', "móu"),
+    ('劻', "kuāng"),
+    ('劼', "jié"),
+    ('劽', "liè"),
+    ('劾', "hé"),
+    ('势', "shì"),
+    ('勀', "kè"),
+    ('勁', "jìn"),
+    ('勂', "gào"),
+    ('勃', "bó"),
+    ('勄', "mǐn"),
+    ('勅', "chì"),
+    ('勆', "láng"),
+    ('勇', "yǒng"),
+    ('勈', "yǒng"),
+    ('勉', "miǎn"),
+    ('勊', "kè"),
+    ('勋', "xūn"),
+    ('勌', "juàn"),
+    ('勍', "qíng"),
+    ('勎', "lù"),
+    ('勏', "bù"),
+    ('勐', "měng"),
+    ('勑', "chì"),
+    ('勒', "lè,lēi"),
+    ('勓', "kài"),
+    ('勔', "miǎn"),
+    ('動', "dòng"),
+    ('勖', "xù"),
+    ('勗', "xù"),
+    ('勘', "kān"),
+    ('務', "wù"),
+    ('勚', "yì"),
+    ('勛', "xūn"),
+    ('勜', "wěng"),
+    ('勝', "shèng"),
+    ('勞', "láo"),
+    ('募', "mù"),
+    ('勠', "lù"),
+    ('勡', "piào"),
+    ('勢', "shì"),
+    ('勣', "jī"),
+    ('勤', "qín"),
+    ('勥', "jiàng"),
+    ('勦', "chāo"),
+    ('勧', "quàn"),
+    ('勨', "xiàng"),
+    ('勩', "yì"),
+    ('勪', "jué"),
+    ('勫', "fān"),
+    ('勬', "juān"),
+    ('勭', "tóng"),
+    ('勮', "jù"),
+    ('勯', "dān"),
+    ('勰', "xié"),
+    ('勱', "mài"),
+    ('勲', "xūn"),
+    ('勳', "xūn"),
+    ('勴', "lǜ"),
+    ('勵', "lì"),
+    ('勶', "chè"),
+    ('勷', "ráng"),
+    ('勸', "quàn"),
+    ('勹', "bāo"),
+    ('勺', "sháo"),
+    ('勻', "yún"),
+    ('勼', "jiū"),
+    ('勽', "bào"),
+    ('勾', "gōu,gòu"),
+    ('勿', "wù"),
+    ('匀', "yún"),
+    ('匁', "wén"),
+    ('匂', "xiōng"),
+    ('匃', "gài"),
+    ('匄', "gài"),
+    ('包', "bāo"),
+    ('匆', "cōng"),
+    ('匇', "yì"),
+    ('匈', "xiōng"),
+    ('匉', "pēng"),
+    ('匊', "jū"),
+    ('匋', "táo"),
+    ('匌', "gé"),
+    ('匍', "pú"),
+    ('匎', "è"),
+    ('匏', "páo"),
+    ('匐', "fú"),
+    ('匑', "gōng"),
+    ('匒', "dá"),
+    ('匓', "jiù"),
+    ('匔', "gōng"),
+    ('匕', "bǐ"),
+    ('化', "huà"),
+    ('北', "běi"),
+    ('匘', "nǎo"),
+    ('匙', "chí,shi"),
+    ('匚', "fāng"),
+    ('匛', "jiù"),
+    ('匜', "yí"),
+    ('匝', "zā"),
+    ('匞', "jiàng"),
+    ('匟', "kàng"),
+    ('匠', "jiàng"),
+    ('匡', "kuāng"),
+    ('匢', "hū"),
+    ('匣', "xiá"),
+    ('匤', "qū"),
+    ('匥', "fán"),
+    ('匦', "guǐ"),
+    ('匧', "qiè"),
+    ('匨', "zāng"),
+    ('匩', "kuāng"),
+    ('匪', "fěi"),
+    ('匫', "hū"),
+    ('匬', "yǔ"),
+    ('匭', "guǐ"),
+    ('匮', "kuì"),
+    ('匯', "huì"),
+    ('匰', "dān"),
+    ('匱', "guì"),
+    ('匲', "lián"),
+    ('匳', "lián"),
+    ('匴', "suǎn"),
+    ('匵', "dú"),
+    ('匶', "jiù"),
+    ('匷', "jué"),
+    ('匸', "xì"),
+    ('匹', "pǐ"),
+    ('区', "qū,ōu"),
+    ('医', "yī"),
+    ('匼', "kē"),
+    ('匽', "yǎn"),
+    ('匾', "biǎn"),
+    ('匿', "nì"),
+    ('區', "qū"),
+    ('十', "shí"),
+    ('卂', "xùn"),
+    ('千', "qiān"),
+    ('卄', "niàn"),
+    ('卅', "sà"),
+    ('卆', "zú"),
+    ('升', "shēng"),
+    ('午', "wǔ"),
+    ('卉', "huì"),
+    ('半', "bàn"),
+    ('卋', "shì"),
+    ('卌', "xì"),
+    ('卍', "wàn"),
+    ('华', "huá,huà"),
+    ('协', "xié"),
+    ('卐', "wàn"),
+    ('卑', "bēi"),
+    ('卒', "zú"),
+    ('卓', "zhuō"),
+    ('協', "xié"),
+    ('单', "dān,shàn,chán"),
+    ('卖', "mài"),
+    ('南', "nán"),
+    ('単', "dān"),
+    ('卙', "jí"),
+    ('博', "bó"),
+    ('卛', "shuài"),
+    ('卜', "bǔ,bo"),
+    ('卝', "kuàng"),
+    ('卞', "biàn"),
+    ('卟', "bǔ"),
+    ('占', "zhàn,zhān"),
+    ('卡', "kǎ,qiǎ"),
+    ('卢', "lú"),
+    ('卣', "yǒu"),
+    ('卤', "lǔ"),
+    ('卥', "xī"),
+    ('卦', "guà"),
+    ('卧', "wò"),
+    ('卨', "xiè"),
+    ('卩', "jié"),
+    ('卪', "jié"),
+    ('卫', "wèi"),
+    ('卬', "áng"),
+    ('卭', "qióng"),
+    ('卮', "zhī"),
+    ('卯', "mǎo"),
+    ('印', "yìn"),
+    ('危', "wēi"),
+    ('卲', "shào"),
+    ('即', "jí"),
+    ('却', "què"),
+    ('卵', "luǎn"),
+    ('卶', "chǐ"),
+    ('卷', "juǎn,juàn"),
+    ('卸', "xiè"),
+    ('卹', "xù"),
+    ('卺', "jǐn"),
+    ('卻', "què"),
+    ('卼', "wù"),
+    ('卽', "jí"),
+    ('卾', "è"),
+    ('卿', "qīng"),
+    ('厀', "xī"),
+    ('厁', "sān"),
+    ('厂', "chǎng"),
+    ('厃', "wěi"),
+    ('厄', "è"),
+    ('厅', "tīng"),
+    ('历', "lì"),
+    ('厇', "zhé"),
+    ('厈', "hǎn"),
+    ('厉', "lì"),
+    ('厊', "yǎ"),
+    ('压', "yā,yà"),
+    ('厌', "yàn"),
+    ('厍', "shè"),
+    ('厎', "dǐ"),
+    ('厏', "zhǎ"),
+    ('厐', "páng"),
+    ('厑', "yá"),
+    ('厒', "qiè"),
+    ('厓', "yá"),
+    ('厔', "zhì"),
+    ('厕', "cè"),
+    ('厖', "páng"),
+    ('厗', "tí"),
+    ('厘', "lí"),
+    ('厙', "shè"),
+    ('厚', "hòu"),
+    ('厛', "tīng"),
+    ('厜', "zuī"),
+    ('厝', "cuò"),
+    ('厞', "fèi"),
+    ('原', "yuán"),
+    ('厠', "cè"),
+    ('厡', "yuán"),
+    ('厢', "xiāng"),
+    ('厣', "yǎn"),
+    ('厤', "lì"),
+    ('厥', "jué"),
+    ('厦', "shà,xià"),
+    ('厧', "diān"),
+    ('厨', "chú"),
+    ('厩', "jiù"),
+    ('厪', "jǐn"),
+    ('厫', "áo"),
+    ('厬', "guǐ"),
+    ('厭', "yàn"),
+    ('厮', "sī"),
+    ('厯', "lì"),
+    ('厰', "chǎng"),
+    ('厱', "lán"),
+    ('厲', "lì"),
+    ('厳', "yán"),
+    ('厴', "yǎn"),
+    ('厵', "yuán"),
+    ('厶', "sī"),
+    ('厷', "gōng"),
+    ('厸', "lín"),
+    ('厹', "róu"),
+    ('厺', "qù"),
+    ('去', "qù"),
+    ('厼', "ěr"),
+    ('厽', "lěi"),
+    ('厾', "dū"),
+    ('县', "xiàn"),
+    ('叀', "zhuān"),
+    ('叁', "sān"),
+    ('参', "cān,shēn,cēn"),
+    ('參', "cān"),
+    ('叄', "cān"),
+    ('叅', "cān"),
+    ('叆', "ài"),
+    ('叇', "dài"),
+    ('又', "yòu"),
+    ('叉', "chā,chá,chǎ"),
+    ('及', "jí"),
+    ('友', "yǒu"),
+    ('双', "shuāng"),
+    ('反', "fǎn"),
+    ('収', "shōu"),
+    ('叏', "guài"),
+    ('叐', "bá"),
+    ('发', "fā,fà"),
+    ('叒', "ruò"),
+    ('叓', "shì"),
+    ('叔', "shū"),
+    ('叕', "zhuó"),
+    ('取', "qǔ"),
+    ('受', "shòu"),
+    ('变', "biàn"),
+    ('叙', "xù"),
+    ('叚', "xiá"),
+    ('叛', "pàn"),
+    ('叜', "sǒu"),
+    ('叝', "jí"),
+    ('叞', "wèi"),
+    ('叟', "sǒu"),
+    ('叠', "dié"),
+    ('叡', "ruì"),
+    ('叢', "cóng"),
+    ('口', "kǒu"),
+    ('古', "gǔ"),
+    ('句', "jù"),
+    ('另', "lìng"),
+    ('叧', "guǎ"),
+    ('叨', "dāo,tāo"),
+    ('叩', "kòu"),
+    ('只', "zhǐ,zhī"),
+    ('叫', "jiào"),
+    ('召', "zhào,shào"),
+    ('叭', "bā"),
+    ('叮', "dīng"),
+    ('可', "kě,kè"),
+    ('台', "tái"),
+    ('叱', "chì"),
+    ('史', "shǐ"),
+    ('右', "yòu"),
+    ('叴', "qiú"),
+    ('叵', "pǒ"),
+    ('叶', "yè,xié"),
+    ('号', "hào,háo"),
+    ('司', "sī"),
+    ('叹', "tàn"),
+    ('叺', "chǐ"),
+    ('叻', "lè"),
+    ('叼', "diāo"),
+    ('叽', "jī"),
+    ('叾', "liǎo"),
+    ('叿', "hōng"),
+    ('吀', "miē"),
+    ('吁', "xū,yù,yū"),
+    ('吂', "máng"),
+    ('吃', "chī"),
+    ('各', "gè"),
+    ('吅', "xuān"),
+    ('吆', "yāo"),
+    ('吇', "zǐ"),
+    ('合', "hé,gě"),
+    ('吉', "jí"),
+    ('吊', "diào"),
+    ('吋', "cùn"),
+    ('同', "tóng,tòng"),
+    ('名', "míng"),
+    ('后', "hòu"),
+    ('吏', "lì"),
+    ('吐', "tǔ,tù"),
+    ('向', "xiàng"),
+    ('吒', "zhā"),
+    ('吓', "xià,hè"),
+    ('吔', "yě"),
+    ('吕', "lǚ"),
+    ('吖', "yā"),
+    ('吗', "ma,má,mǎ"),
+    ('吘', "ǒu"),
+    ('吙', "huō"),
+    ('吚', "yī"),
+    ('君', "jūn"),
+    ('吜', "chǒu"),
+    ('吝', "lìn"),
+    ('吞', "tūn"),
+    ('吟', "yín"),
+    ('吠', "fèi"),
+    ('吡', "bǐ"),
+    ('吢', "qìn"),
+    ('吣', "qìn"),
+    ('吤', "jiè"),
+    ('吥', "bù"),
+    ('否', "fǒu,pǐ"),
+    ('吧', "ba,bā"),
+    ('吨', "dūn"),
+    ('吩', "fēn"),
+    ('吪', "é"),
+    ('含', "hán"),
+    ('听', "tīng"),
+    ('吭', "kēng"),
+    ('吮', "shǔn"),
+    ('启', "qǐ"),
+    ('吰', "hóng"),
+    ('吱', "zhī"),
+    ('吲', "yǐn"),
+    ('吳', "wú"),
+    ('吴', "wú"),
+    ('吵', "chǎo"),
+    ('吶', "nà"),
+    ('吷', "xuè"),
+    ('吸', "xī"),
+    ('吹', "chuī"),
+    ('吺', "dōu"),
+    ('吻', "wěn"),
+    ('吼', "hǒu"),
+    ('吽', "hōng"),
+    ('吾', "wú"),
+    ('吿', "gào"),
+    ('呀', "yā,ya"),
+    ('呁', "jùn"),
+    ('呂', "lǚ"),
+    ('呃', "è"),
+    ('呄', "gé"),
+    ('呅', "méi"),
+    ('呆', "dāi"),
+    ('呇', "qǐ"),
+    ('呈', "chéng"),
+    ('呉', "wú"),
+    ('告', "gào"),
+    ('呋', "fū"),
+    ('呌', "jiào"),
+    ('呍', "hōng"),
+    ('呎', "chǐ"),
+    ('呏', "shēng"),
+    ('呐', "nà"),
+    ('呑', "tūn"),
+    ('呒', "fǔ"),
+    ('呓', "yì"),
+    ('呔', "dāi"),
+    ('呕', "ǒu"),
+    ('呖', "lì"),
+    ('呗', "bei"),
+    ('员', "yuán,yún,yùn"),
+    ('呙', "guō"),
+    ('呚', "wen"),
+    ('呛', "qiāng,qiàng"),
+    ('呜', "wū"),
+    ('呝', "è"),
+    ('呞', "shī"),
+    ('呟', "juǎn"),
+    ('呠', "pěn"),
+    ('呡', "wěn"),
+    ('呢', "ne,ní"),
+    ('呣', "ḿ,m̀"),
+    ('呤', "lìng"),
+    ('呥', "rán"),
+    ('呦', "yōu"),
+    ('呧', "dǐ"),
+    ('周', "zhōu"),
+    ('呩', "shì"),
+    ('呪', "zhòu"),
+    ('呫', "tiè"),
+    ('呬', "xì"),
+    ('呭', "yì"),
+    ('呮', "qì"),
+    ('呯', "píng"),
+    ('呰', "zǐ"),
+    ('呱', "gū"),
+    ('呲', "cī"),
+    ('味', "wèi"),
+    ('呴', "xǔ"),
+    ('呵', "hē"),
+    ('呶', "náo"),
+    ('呷', "gā"),
+    ('呸', "pēi"),
+    ('呹', "yì"),
+    ('呺', "xiāo"),
+    ('呻', "shēn"),
+    ('呼', "hū"),
+    ('命', "mìng"),
+    ('呾', "dá"),
+    ('呿', "qù"),
+    ('咀', "jǔ"),
+    ('咁', "hán"),
+    ('咂', "zā"),
+    ('咃', "tuō"),
+    ('咄', "duō"),
+    ('咅', "pǒu"),
+    ('咆', "páo"),
+    ('咇', "bié"),
+    ('咈', "fú"),
+    ('咉', "yāng"),
+    ('咊', "hé"),
+    ('咋', "zǎ,zhā,zé"),
+    ('和', "hé,hè,huó,huò,hú"),
+    ('咍', "hāi"),
+    ('咎', "jiù"),
+    ('咏', "yǒng"),
+    ('咐', "fù"),
+    ('咑', "dā"),
+    ('咒', "zhòu"),
+    ('咓', "wǎ"),
+    ('咔', "kā"),
+    ('咕', "gū"),
+    ('咖', "kā"),
+    ('咗', "zuo"),
+    ('咘', "bù"),
+    ('咙', "lóng"),
+    ('咚', "dōng"),
+    ('咛', "níng"),
+    ('咜', "ta"),
+    ('咝', "sī"),
+    ('咞', "xiàn"),
+    ('咟', "huò"),
+    ('咠', "qì"),
+    ('咡', "èr"),
+    ('咢', "è"),
+    ('咣', "guāng"),
+    ('咤', "zhà"),
+    ('咥', "xì"),
+    ('咦', "yí"),
+    ('咧', "liě"),
+    ('咨', "zī"),
+    ('咩', "miē"),
+    ('咪', "mī"),
+    ('咫', "zhǐ"),
+    ('咬', "yǎo"),
+    ('咭', "jī"),
+    ('咮', "zhòu"),
+    ('咯', "gē"),
+    ('咰', "shù"),
+    ('咱', "zán"),
+    ('咲', "xiào"),
+    ('咳', "ké,hāi"),
+    ('咴', "huī"),
+    ('咵', "kuǎ"),
+    ('咶', "huài"),
+    ('咷', "táo"),
+    ('咸', "xián"),
+    ('咹', "è"),
+    ('咺', "xuǎn"),
+    ('咻', "xiū"),
+    ('咼', "guō"),
+    ('咽', "yān,yàn,yè"),
+    ('咾', "lǎo"),
+    ('咿', "yī"),
+    ('哀', "āi"),
+    ('品', "pǐn"),
+    ('哂', "shěn"),
+    ('哃', "tóng"),
+    ('哄', "hǒng,hōng,hòng"),
+    ('哅', "xiōng"),
+    ('哆', "duō"),
+    ('哇', "wa"),
+    ('哈', "hā,hǎ"),
+    ('哉', "zāi"),
+    ('哊', "yòu"),
+    ('哋', "diè"),
+    ('哌', "pài"),
+    ('响', "xiǎng"),
+    ('哎', "āi"),
+    ('哏', "gén"),
+    ('哐', "kuāng"),
+    ('哑', "yǎ,yā"),
+    ('哒', "dá"),
+    ('哓', "xiāo"),
+    ('哔', "bì"),
+    ('哕', "huì"),
+    ('哖', "nián"),
+    ('哗', "huā"),
+    ('哘', "xing"),
+    ('哙', "kuài"),
+    ('哚', "duǒ"),
+    ('哛', "fēn"),
+    ('哜', "jì"),
+    ('哝', "nóng"),
+    ('哞', "mōu"),
+    ('哠', "hào"),
+    ('員', "yuán"),
+    ('哢', "lòng"),
+    ('哣', "pǒu"),
+    ('哤', "máng"),
+    ('哥', "gē"),
+    ('哦', "ó,ò,é"),
+    ('哧', "chī"),
+    ('哨', "shào"),
+    ('哩', "lī"),
+    ('哪', "nǎ,na,né"),
+    ('哫', "zú"),
+    ('哬', "hé"),
+    ('哭', "kū"),
+    ('哮', "xiāo"),
+    ('哯', "xiàn"),
+    ('哰', "láo"),
+    ('哱', "bō"),
+    ('哲', "zhé"),
+    ('哳', "zhā"),
+    ('哴', "liàng"),
+    ('哵', "bā"),
+    ('哶', "miē"),
+    ('哷', "liè"),
+    ('哸', "suī"),
+    ('哹', "fú"),
+    ('哺', "bǔ"),
+    ('哻', "hān"),
+    ('哼', "hēng"),
+    ('哽', "gěng"),
+    ('哾', "shuō"),
+    ('哿', "gě"),
+    ('唀', "yòu"),
+    ('唁', "yàn"),
+    ('唂', "gū"),
+    ('唃', "gǔ"),
+    ('唄', "bei"),
+    ('唅', "hán"),
+    ('唆', "suō"),
+    ('唇', "chún"),
+    ('唈', "yì"),
+    ('唉', "āi"),
+    ('唊', "jiá"),
+    ('唋', "tū"),
+    ('唌', "xián"),
+    ('唍', "wǎn"),
+    ('唎', "lì"),
+    ('唏', "xī"),
+    ('唐', "táng"),
+    ('唑', "zuò"),
+    ('唒', "qiú"),
+    ('唓', "chē"),
+    ('唔', "wú"),
+    ('唕', "zào"),
+    ('唖', "yǎ"),
+    ('唗', "dōu"),
+    ('唘', "qǐ"),
+    ('唙', "dí"),
+    ('唚', "qìn"),
+    ('唛', "mà"),
+    ('唜', "mò"),
+    ('唝', "gòng"),
+    ('唞', "dǒu"),
+    ('唟', "qù"),
+    ('唠', "láo"),
+    ('唡', "liǎng"),
+    ('唢', "suǒ"),
+    ('唣', "zào"),
+    ('唤', "huàn"),
+    ('唥', "lang"),
+    ('唦', "shā"),
+    ('唧', "jī"),
+    ('唨', "zǔ"),
+    ('唩', "wō"),
+    ('唪', "fěng"),
+    ('唫', "jìn"),
+    ('唬', "hǔ"),
+    ('唭', "qì"),
+    ('售', "shòu"),
+    ('唯', "wéi"),
+    ('唰', "shuā"),
+    ('唱', "chàng"),
+    ('唲', "ér"),
+    ('唳', "lì"),
+    ('唴', "qiàng"),
+    ('唵', "ǎn"),
+    ('唶', "zé"),
+    ('唸', "niàn"),
+    ('唹', "yū"),
+    ('唺', "tiǎn"),
+    ('唻', "lài"),
+    ('唼', "shà"),
+    ('唽', "xī"),
+    ('唾', "tuò"),
+    ('唿', "hū"),
+    ('啀', "ái"),
+    ('啁', "zhāo"),
+    ('啂', "nǒu"),
+    ('啃', "kěn"),
+    ('啄', "zhuó"),
+    ('啅', "zhuó"),
+    ('商', "shāng"),
+    ('啇', "dì"),
+    ('啈', "hēng"),
+    ('啉', "lín"),
+    ('啊', "a,ā,á,ǎ,à"),
+    ('啋', "cǎi"),
+    ('啌', "xiāng"),
+    ('啍', "tūn"),
+    ('啎', "wǔ"),
+    ('問', "wèn"),
+    ('啐', "cuì"),
+    ('啑', "shà"),
+    ('啒', "gǔ"),
+    ('啓', "qǐ"),
+    ('啔', "qǐ"),
+    ('啕', "táo"),
+    ('啖', "dàn"),
+    ('啗', "dàn"),
+    ('啘', "yè"),
+    ('啙', "zǐ"),
+    ('啚', "bǐ"),
+    ('啛', "cuì"),
+    ('啜', "chuài"),
+    ('啝', "hé"),
+    ('啞', "yǎ"),
+    ('啟', "qǐ"),
+    ('啠', "zhé"),
+    ('啡', "fēi"),
+    ('啢', "liǎng"),
+    ('啣', "xián"),
+    ('啤', "pí"),
+    ('啥', "shà"),
+    ('啦', "la"),
+    ('啧', "zé"),
+    ('啨', "yīng"),
+    ('啩', "guà"),
+    ('啪', "pā"),
+    ('啫', "zhě"),
+    ('啬', "sè"),
+    ('啭', "zhuàn"),
+    ('啮', "niè"),
+    ('啯', "guō"),
+    ('啰', "luō"),
+    ('啱', "yán"),
+    ('啲', "dī"),
+    ('啳', "quán"),
+    ('啴', "chǎn"),
+    ('啵', "bō"),
+    ('啶', "dìng"),
+    ('啷', "lāng"),
+    ('啸', "xiào"),
+    ('啹', "jú"),
+    ('啺', "táng"),
+    ('啻', "chì"),
+    ('啼', "tí"),
+    ('啽', "án"),
+    ('啾', "jiū"),
+    ('啿', "dàn"),
+    ('喀', "kā"),
+    ('喁', "yóng"),
+    ('喂', "wèi"),
+    ('喃', "nán"),
+    ('善', "shàn"),
+    ('喅', "yù"),
+    ('喆', "zhé"),
+    ('喇', "lǎ"),
+    ('喈', "jiē"),
+    ('喉', "hóu"),
+    ('喊', "hǎn"),
+    ('喋', "dié"),
+    ('喌', "zhōu"),
+    ('喍', "chái"),
+    ('喎', "wāi"),
+    ('喏', "nuò"),
+    ('喐', "yù"),
+    ('喑', "yīn"),
+    ('喒', "zá"),
+    ('喓', "yāo"),
+    ('喔', "ō"),
+    ('喕', "miǎn"),
+    ('喖', "hú"),
+    ('喗', "yǔn"),
+    ('喘', "chuǎn"),
+    ('喙', "huì"),
+    ('喚', "huàn"),
+    ('喛', "huàn"),
+    ('喜', "xǐ"),
+    ('喝', "hē,hè"),
+    ('喞', "jī"),
+    ('喟', "kuì"),
+    ('喠', "zhǒng"),
+    ('喡', "wéi"),
+    ('喢', "shà"),
+    ('喣', "xù"),
+    ('喤', "huáng"),
+    ('喥', "duó"),
+    ('喦', "niè"),
+    ('喧', "xuān"),
+    ('喨', "liàng"),
+    ('喩', "yù"),
+    ('喪', "sàng"),
+    ('喫', "chī"),
+    ('喬', "qiáo"),
+    ('喭', "yàn"),
+    ('單', "dān"),
+    ('喯', "pèn"),
+    ('喰', "cān"),
+    ('喱', "lí"),
+    ('喳', "zhā"),
+    ('喴', "wēi"),
+    ('喵', "miāo"),
+    ('営', "yíng"),
+    ('喷', "pēn,pèn"),
+    ('喸', "bǔ"),
+    ('喹', "kuí"),
+    ('喺', "xí"),
+    ('喻', "yù"),
+    ('喼', "jiē"),
+    ('喽', "lóu"),
+    ('喾', "kù"),
+    ('喿', "zào"),
+    ('嗀', "hù"),
+    ('嗁', "tí"),
+    ('嗂', "yáo"),
+    ('嗃', "hè"),
+    ('嗄', "á"),
+    ('嗅', "xiù"),
+    ('嗆', "qiāng"),
+    ('嗇', "sè"),
+    ('嗈', "yōng"),
+    ('嗉', "sù"),
+    ('嗊', "hǒng"),
+    ('嗋', "xié"),
+    ('嗌', "ài"),
+    ('嗍', "suō"),
+    ('嗎', "ma"),
+    ('嗏', "chā"),
+    ('嗐', "hài"),
+    ('嗑', "kē"),
+    ('嗒', "dā"),
+    ('嗓', "sǎng"),
+    ('嗔', "chēn"),
+    ('嗕', "rù"),
+    ('嗖', "sōu"),
+    ('嗗', "wā"),
+    ('嗘', "jī"),
+    ('嗙', "pǎng"),
+    ('嗚', "wū"),
+    ('嗛', "qiǎn"),
+    ('嗜', "shì"),
+    ('嗝', "gé"),
+    ('嗞', "zī"),
+    ('嗟', "jiē"),
+    ('嗠', "lào"),
+    ('嗡', "wēng"),
+    ('嗢', "wà"),
+    ('嗣', "sì"),
+    ('嗤', "chī"),
+    ('嗥', "háo"),
+    ('嗦', "suo"),
+    ('嗨', "hāi"),
+    ('嗩', "suǒ"),
+    ('嗪', "qín"),
+    ('嗫', "niè"),
+    ('嗬', "hē"),
+    ('嗭', "zhí"),
+    ('嗮', "sài"),
+    ('嗯', "ń,ň,ǹ"),
+    ('嗰', "gě"),
+    ('嗱', "ná"),
+    ('嗲', "diē"),
+    ('嗳', "āi"),
+    ('嗴', "qiāng"),
+    ('嗵', "tōng"),
+    ('嗶', "bì"),
+    ('嗷', "áo"),
+    ('嗸', "áo"),
+    ('嗹', "lián"),
+    ('嗺', "zuī"),
+    ('嗻', "zhē"),
+    ('嗼', "mò"),
+    ('嗽', "sòu"),
+    ('嗾', "sǒu"),
+    ('嗿', "tǎn"),
+    ('嘀', "dí"),
+    ('嘁', "qī"),
+    ('嘂', "jiào"),
+    ('嘃', "chōng"),
+    ('嘄', "jiāo"),
+    ('嘅', "kǎi"),
+    ('嘆', "tàn"),
+    ('嘇', "shān"),
+    ('嘈', "cáo"),
+    ('嘉', "jiā"),
+    ('嘊', "ái"),
+    ('嘋', "xiào"),
+    ('嘌', "piào"),
+    ('嘍', "lóu"),
+    ('嘎', "gā"),
+    ('嘏', "gǔ"),
+    ('嘐', "xiāo"),
+    ('嘑', "hū"),
+    ('嘒', "huì"),
+    ('嘓', "guō"),
+    ('嘔', "ǒu"),
+    ('嘕', "xiān"),
+    ('嘖', "zé"),
+    ('嘗', "cháng"),
+    ('嘘', "xū"),
+    ('嘙', "pó"),
+    ('嘚', "dē"),
+    ('嘛', "ma"),
+    ('嘜', "mà"),
+    ('嘝', "hú"),
+    ('嘞', "lei"),
+    ('嘟', "dū"),
+    ('嘠', "gā"),
+    ('嘡', "tāng"),
+    ('嘢', "yě"),
+    ('嘣', "bēng"),
+    ('嘤', "yīng"),
+    ('嘥', "sāi"),
+    ('嘦', "jiào"),
+    ('嘧', "mì"),
+    ('嘨', "xiào"),
+    ('嘩', "huā"),
+    ('嘪', "mǎi"),
+    ('嘫', "rán"),
+    ('嘬', "chuài"),
+    ('嘭', "pēng"),
+    ('嘮', "láo"),
+    ('嘯', "xiào"),
+    ('嘰', "jī"),
+    ('嘱', "zhǔ"),
+    ('嘲', "cháo,zhāo"),
+    ('嘳', "kuì"),
+    ('嘴', "zuǐ"),
+    ('嘵', "xiāo"),
+    ('嘶', "sī"),
+    ('嘷', "háo"),
+    ('嘸', "fǔ"),
+    ('嘹', "liáo"),
+    ('嘺', "qiáo"),
+    ('嘻', "xī"),
+    ('嘼', "chù"),
+    ('嘽', "chǎn"),
+    ('嘾', "dàn"),
+    ('嘿', "hēi"),
+    ('噀', "xùn"),
+    ('噁', "ě"),
+    ('噂', "zǔn"),
+    ('噃', "fān"),
+    ('噄', "chī"),
+    ('噅', "huī"),
+    ('噆', "zǎn"),
+    ('噇', "chuáng"),
+    ('噈', "cù"),
+    ('噉', "dàn"),
+    ('噊', "yù"),
+    ('噋', "tūn"),
+    ('噌', "cēng"),
+    ('噍', "jiào"),
+    ('噎', "yē"),
+    ('噏', "xī"),
+    ('噐', "qì"),
+    ('噑', "háo"),
+    ('噒', "lián"),
+    ('噓', "xū"),
+    ('噔', "dēng"),
+    ('噕', "huī"),
+    ('噖', "yín"),
+    ('噗', "pū"),
+    ('噘', "juē"),
+    ('噙', "qín"),
+    ('噚', "xún"),
+    ('噛', "niè"),
+    ('噜', "lū"),
+    ('噝', "sī"),
+    ('噞', "yǎn"),
+    ('噟', "yìng"),
+    ('噠', "dā"),
+    ('噡', "zhān"),
+    ('噢', "ō"),
+    ('噣', "zhòu"),
+    ('噤', "jìn"),
+    ('噥', "nóng"),
+    ('噦', "huì"),
+    ('噧', "xiè"),
+    ('器', "qì"),
+    ('噩', "è"),
+    ('噪', "zào"),
+    ('噫', "yī"),
+    ('噬', "shì"),
+    ('噭', "jiào"),
+    ('噮', "yuàn"),
+    ('噯', "āi"),
+    ('噰', "yōng"),
+    ('噱', "jué"),
+    ('噲', "kuài"),
+    ('噳', "yǔ"),
+    ('噴', "pēn"),
+    ('噵', "dào"),
+    ('噶', "gá"),
+    ('噸', "dūn"),
+    ('噹', "dāng"),
+    ('噺', "xīn"),
+    ('噻', "sāi"),
+    ('噼', "pī"),
+    ('噽', "pǐ"),
+    ('噾', "yīn"),
+    ('噿', "zuǐ"),
+    ('嚀', "níng"),
+    ('嚁', "dí"),
+    ('嚂', "làn"),
+    ('嚃', "tā"),
+    ('嚄', "huō"),
+    ('嚅', "rú"),
+    ('嚆', "hāo"),
+    ('嚇', "xià"),
+    ('嚈', "yè"),
+    ('嚉', "duō"),
+    ('嚊', "pì"),
+    ('嚋', "chóu"),
+    ('嚌', "jì"),
+    ('嚍', "jìn"),
+    ('嚎', "háo"),
+    ('嚏', "tì"),
+    ('嚐', "cháng"),
+    ('嚑', "xūn"),
+    ('嚒', "mē"),
+    ('嚓', "cā"),
+    ('嚔', "tì"),
+    ('嚕', "lǔ"),
+    ('嚖', "huì"),
+    ('嚗', "bó"),
+    ('嚘', "yōu"),
+    ('嚙', "niè"),
+    ('嚚', "yín"),
+    ('嚛', "hù"),
+    ('嚜', "me"),
+    ('嚝', "hōng"),
+    ('嚞', "zhé"),
+    ('嚟', "lí"),
+    ('嚠', "liú"),
+    ('嚡', "hai"),
+    ('嚢', "náng"),
+    ('嚣', "xiāo"),
+    ('嚤', "mó"),
+    ('嚥', "yàn"),
+    ('嚦', "lì"),
+    ('嚧', "lú"),
+    ('嚨', "lóng"),
+    ('嚩', "mó"),
+    ('嚪', "dàn"),
+    ('嚫', "chèn"),
+    ('嚬', "pín"),
+    ('嚭', "pǐ"),
+    ('嚮', "xiàng"),
+    ('嚯', "huò"),
+    ('嚰', "mó"),
+    ('嚱', "xì"),
+    ('嚲', "duǒ"),
+    ('嚳', "kù"),
+    ('嚴', "yán"),
+    ('嚵', "chán"),
+    ('嚶', "yīng"),
+    ('嚷', "rǎng,rāng"),
+    ('嚸', "diǎn"),
+    ('嚹', "lá"),
+    ('嚺', "tà"),
+    ('嚻', "xiāo"),
+    ('嚼', "jiáo,jué,jiào"),
+    ('嚽', "chuò"),
+    ('嚾', "huān"),
+    ('嚿', "huò"),
+    ('囀', "zhuàn"),
+    ('囁', "niè"),
+    ('囂', "xiāo"),
+    ('囃', "cà"),
+    ('囄', "lí"),
+    ('囅', "chǎn"),
+    ('囆', "chài"),
+    ('囇', "lì"),
+    ('囈', "yì"),
+    ('囉', "luō"),
+    ('囊', "náng"),
+    ('囋', "zá"),
+    ('囌', "sū"),
+    ('囍', "xǐ"),
+    ('囎', "zen"),
+    ('囏', "jiān"),
+    ('囐', "zá"),
+    ('囑', "zhǔ"),
+    ('囒', "lán"),
+    ('囓', "niè"),
+    ('囔', "nāng"),
+    ('囕', "lǎn"),
+    ('囖', "lo"),
+    ('囗', "wéi"),
+    ('囘', "huí"),
+    ('囙', "yīn"),
+    ('囚', "qiú"),
+    ('四', "sì"),
+    ('囜', "nín"),
+    ('囝', "jiǎn"),
+    ('回', "huí"),
+    ('囟', "xìn"),
+    ('因', "yīn"),
+    ('囡', "nān"),
+    ('团', "tuán"),
+    ('団', "tuán"),
+    ('囤', "dùn,tún"),
+    ('囥', "kàng"),
+    ('囦', "yuān"),
+    ('囧', "jiǒng"),
+    ('囨', "piān"),
+    ('囩', "yún"),
+    ('囪', "cōng"),
+    ('囫', "hú"),
+    ('囬', "huí"),
+    ('园', "yuán"),
+    ('囮', "é"),
+    ('囯', "guó"),
+    ('困', "kùn"),
+    ('囱', "cōng"),
+    ('囲', "tōng"),
+    ('図', "tú"),
+    ('围', "wéi"),
+    ('囵', "lún"),
+    ('囶', "guó"),
+    ('囷', "qūn"),
+    ('囸', "rì"),
+    ('囹', "líng"),
+    ('固', "gù"),
+    ('囻', "guó"),
+    ('囼', "tāi"),
+    ('国', "guó"),
+    ('图', "tú"),
+    ('囿', "yòu"),
+    ('圀', "guó"),
+    ('圁', "yín"),
+    ('圂', "hùn"),
+    ('圃', "pǔ"),
+    ('圄', "yǔ"),
+    ('圅', "hán"),
+    ('圆', "yuán"),
+    ('圇', "lún"),
+    ('圈', "quān,juàn,juān"),
+    ('圉', "yǔ"),
+    ('圊', "qīng"),
+    ('國', "guó"),
+    ('圌', "chuán"),
+    ('圍', "wéi"),
+    ('圎', "yuán"),
+    ('圏', "quān"),
+    ('圐', "kū"),
+    ('圑', "pǔ"),
+    ('園', "yuán"),
+    ('圓', "yuán"),
+    ('圔', "yà"),
+    ('圕', "tú"),
+    ('圖', "tú"),
+    ('圗', "tú"),
+    ('團', "tuán"),
+    ('圙', "lüè"),
+    ('圚', "huì"),
+    ('圛', "yì"),
+    ('圜', "huán"),
+    ('圝', "luán"),
+    ('圞', "luán"),
+    ('土', "tǔ"),
+    ('圠', "yà"),
+    ('圡', "tǔ"),
+    ('圢', "tǐng"),
+    ('圣', "shèng"),
+    ('圤', "pú"),
+    ('圥', "lù"),
+    ('圦', "kuài"),
+    ('圧', "yā"),
+    ('在', "zài"),
+    ('圩', "wéi,xū"),
+    ('圪', "gē"),
+    ('圫', "yù"),
+    ('圬', "wū"),
+    ('圭', "guī"),
+    ('圮', "pǐ"),
+    ('圯', "yí"),
+    ('地', "dì,de"),
+    ('圱', "qiān"),
+    ('圲', "qiān"),
+    ('圳', "zhèn"),
+    ('圴', "zhuó"),
+    ('圵', "dàng"),
+    ('圶', "qià"),
+    ('圷', "xià"),
+    ('圸', "shān"),
+    ('圹', "kuàng"),
+    ('场', "chǎng,cháng"),
+    ('圻', "qí"),
+    ('圼', "niè"),
+    ('圽', "mò"),
+    ('圾', "jī"),
+    ('圿', "jiá"),
+    ('址', "zhǐ"),
+    ('坁', "zhǐ"),
+    ('坂', "bǎn"),
+    ('坃', "xūn"),
+    ('坄', "yì"),
+    ('坅', "qǐn"),
+    ('坆', "méi"),
+    ('均', "jūn"),
+    ('坈', "rǒng"),
+    ('坉', "tún"),
+    ('坊', "fāng,fáng"),
+    ('坋', "bèn"),
+    ('坌', "bèn"),
+    ('坍', "tān"),
+    ('坎', "kǎn"),
+    ('坏', "huài"),
+    ('坐', "zuò"),
+    ('坑', "kēng"),
+    ('坒', "bì"),
+    ('坓', "jǐng"),
+    ('坔', "dì"),
+    ('坕', "jīng"),
+    ('坖', "jì"),
+    ('块', "kuài"),
+    ('坘', "dǐ"),
+    ('坙', "jīng"),
+    ('坚', "jiān"),
+    ('坛', "tán"),
+    ('坜', "lì"),
+    ('坝', "bà"),
+    ('坞', "wù"),
+    ('坟', "fén"),
+    ('坠', "zhuì"),
+    ('坡', "pō"),
+    ('坢', "bàn"),
+    ('坣', "táng"),
+    ('坤', "kūn"),
+    ('坥', "qū"),
+    ('坦', "tǎn"),
+    ('坧', "zhī"),
+    ('坨', "tuó"),
+    ('坩', "gān"),
+    ('坪', "píng"),
+    ('坫', "diàn"),
+    ('坬', "guà"),
+    ('坭', "ní"),
+    ('坮', "tái"),
+    ('坯', "pī"),
+    ('坰', "jiōng"),
+    ('坱', "yǎng"),
+    ('坲', "fó"),
+    ('坳', "ào"),
+    ('坴', "lù"),
+    ('坵', "qiū"),
+    ('坶', "mǔ"),
+    ('坷', "kě"),
+    ('坸', "gòu"),
+    ('坹', "xuè"),
+    ('坺', "bá"),
+    ('坻', "chí"),
+    ('坼', "chè"),
+    ('坽', "líng"),
+    ('坾', "zhù"),
+    ('坿', "fù"),
+    ('垀', "hū"),
+    ('垁', "zhì"),
+    ('垂', "chuí"),
+    ('垃', "lā"),
+    ('垄', "lǒng"),
+    ('垅', "lǒng"),
+    ('垆', "lú"),
+    ('垇', "ào"),
+    ('垈', "dài"),
+    ('垉', "páo"),
+    ('垊', "min"),
+    ('型', "xíng"),
+    ('垌', "dòng"),
+    ('垍', "jì"),
+    ('垎', "hè"),
+    ('垏', "lǜ"),
+    ('垐', "cí"),
+    ('垑', "chǐ"),
+    ('垒', "lěi"),
+    ('垓', "gāi"),
+    ('垔', "yīn"),
+    ('垕', "hòu"),
+    ('垖', "duī"),
+    ('垗', "zhào"),
+    ('垘', "fú"),
+    ('垙', "guāng"),
+    ('垚', "yáo"),
+    ('垛', "duǒ"),
+    ('垜', "duǒ"),
+    ('垝', "guǐ"),
+    ('垞', "chá"),
+    ('垟', "yáng"),
+    ('垠', "yín"),
+    ('垡', "fá"),
+    ('垢', "gòu"),
+    ('垣', "yuán"),
+    ('垤', "dié"),
+    ('垥', "xié"),
+    ('垦', "kěn"),
+    ('垧', "shǎng"),
+    ('垨', "shǒu"),
+    ('垩', "è"),
+    ('垪', "bìng"),
+    ('垫', "diàn"),
+    ('垬', "hóng"),
+    ('垭', "yā"),
+    ('垮', "kuǎ"),
+    ('垯', "da"),
+    ('垰', "kǎ"),
+    ('垱', "dàng"),
+    ('垲', "kǎi"),
+    ('垳', "háng"),
+    ('垴', "nǎo"),
+    ('垵', "ǎn"),
+    ('垶', "xīng"),
+    ('垷', "xiàn"),
+    ('垸', "yuàn"),
+    ('垹', "bāng"),
+    ('垺', "fū"),
+    ('垻', "bà"),
+    ('垼', "yì"),
+    ('垽', "yìn"),
+    ('垾', "hàn"),
+    ('垿', "xù"),
+    ('埀', "chuí"),
+    ('埁', "qín"),
+    ('埂', "gěng"),
+    ('埃', "āi"),
+    ('埄', "běng"),
+    ('埅', "fáng"),
+    ('埆', "què"),
+    ('埇', "yǒng"),
+    ('埈', "jùn"),
+    ('埉', "jiā"),
+    ('埊', "dì"),
+    ('埋', "mái,mán"),
+    ('埌', "làng"),
+    ('埍', "juǎn"),
+    ('城', "chéng"),
+    ('埏', "shān"),
+    ('埐', "jīn"),
+    ('埑', "zhé"),
+    ('埒', "liè"),
+    ('埓', "liè"),
+    ('埔', "bù"),
+    ('埕', "chéng"),
+    ('埖', "huā"),
+    ('埗', "bù"),
+    ('埘', "shí"),
+    ('埙', "xūn"),
+    ('埚', "guō"),
+    ('埛', "jiōng"),
+    ('埜', "yě"),
+    ('埝', "niàn"),
+    ('埞', "dī"),
+    ('域', "yù"),
+    ('埠', "bù"),
+    ('埡', "yā"),
+    ('埢', "quán"),
+    ('埣', "suì"),
+    ('埤', "pí"),
+    ('埥', "qīng"),
+    ('埦', "wǎn"),
+    ('埧', "jù"),
+    ('埨', "lǔn"),
+    ('埩', "zhēng"),
+    ('埪', "kōng"),
+    ('埫', "chǒng"),
+    ('埬', "dōng"),
+    ('埭', "dài"),
+    ('埮', "tàn"),
+    ('埯', "ǎn"),
+    ('埰', "cài"),
+    ('埱', "chù"),
+    ('埲', "běng"),
+    ('埳', "kǎn"),
+    ('埴', "zhí"),
+    ('埵', "duǒ"),
+    ('埶', "yì"),
+    ('執', "zhí"),
+    ('埸', "yì"),
+    ('培', "péi"),
+    ('基', "jī"),
+    ('埻', "zhǔn"),
+    ('埼', "qí"),
+    ('埽', "sào"),
+    ('埾', "jù"),
+    ('埿', "ní"),
+    ('堀', "kū"),
+    ('堁', "kè"),
+    ('堂', "táng"),
+    ('堃', "kūn"),
+    ('堄', "nì"),
+    ('堅', "jiān"),
+    ('堆', "duī"),
+    ('堇', "jǐn"),
+    ('堈', "gāng"),
+    ('堉', "yù"),
+    ('堊', "è"),
+    ('堋', "péng"),
+    ('堌', "gù"),
+    ('堍', "tù"),
+    ('堎', "lèng"),
+    ('堏', "fang"),
+    ('堐', "yá"),
+    ('堑', "qiàn"),
+    ('堒', "kūn"),
+    ('堓', "àn"),
+    ('堔', "shēn"),
+    ('堕', "duò"),
+    ('堖', "nǎo"),
+    ('堗', "tū"),
+    ('堘', "chéng"),
+    ('堙', "yīn"),
+    ('堚', "hún"),
+    ('堛', "bì"),
+    ('堜', "liàn"),
+    ('堝', "guō"),
+    ('堞', "dié"),
+    ('堟', "zhuàn"),
+    ('堠', "hòu"),
+    ('堡', "bǎo,bǔ,pù"),
+    ('堢', "bǎo"),
+    ('堣', "yú"),
+    ('堤', "dī"),
+    ('堥', "máo"),
+    ('堦', "jiē"),
+    ('堧', "ruán"),
+    ('堨', "yè"),
+    ('堩', "gèng"),
+    ('堪', "kān"),
+    ('堫', "zōng"),
+    ('堬', "yú"),
+    ('堭', "huáng"),
+    ('堮', "è"),
+    ('堯', "yáo"),
+    ('堰', "yàn"),
+    ('報', "bào"),
+    ('堲', "cí"),
+    ('堳', "méi"),
+    ('場', "chǎng"),
+    ('堵', "dǔ"),
+    ('堶', "tuó"),
+    ('堷', "yìn"),
+    ('堸', "féng"),
+    ('堹', "zhòng"),
+    ('堺', "jiè"),
+    ('堻', "jīn"),
+    ('堼', "hèng"),
+    ('堽', "gāng"),
+    ('堾', "chūn"),
+    ('堿', "jiǎn"),
+    ('塀', "píng"),
+    ('塁', "lěi"),
+    ('塂', "xiàng"),
+    ('塃', "huāng"),
+    ('塄', "léng"),
+    ('塅', "duàn"),
+    ('塆', "wān"),
+    ('塇', "xuān"),
+    ('塈', "jì"),
+    ('塉', "jí"),
+    ('塊', "kuài"),
+    ('塋', "yíng"),
+    ('塌', "tā"),
+    ('塍', "chéng"),
+    ('塎', "yǒng"),
+    ('塏', "kǎi"),
+    ('塐', "sù"),
+    ('塑', "sù"),
+    ('塒', "shí"),
+    ('塓', "mì"),
+    ('塔', "tǎ"),
+    ('塕', "wěng"),
+    ('塖', "chéng"),
+    ('塗', "tú"),
+    ('塘', "táng"),
+    ('塙', "què"),
+    ('塚', "zhǒng"),
+    ('塛', "lì"),
+    ('塜', "zhǒng"),
+    ('塝', "bàng"),
+    ('塞', "sāi,sè,sài"),
+    ('塟', "zàng"),
+    ('塠', "duī"),
+    ('塡', "tián"),
+    ('塢', "wù"),
+    ('塣', "zhèng"),
+    ('塤', "xūn"),
+    ('塥', "gé"),
+    ('塦', "zhèn"),
+    ('塧', "ài"),
+    ('塨', "gōng"),
+    ('塩', "yán"),
+    ('塪', "kǎn"),
+    ('填', "tián"),
+    ('塬', "yuán"),
+    ('塭', "wēn"),
+    ('塮', "xiè"),
+    ('塯', "liù"),
+    ('塰', "hǎi"),
+    ('塱', "lǎng"),
+    ('塲', "cháng"),
+    ('塳', "péng"),
+    ('塴', "bèng"),
+    ('塵', "chén"),
+    ('塶', "lù"),
+    ('塷', "lǔ"),
+    ('塸', "ōu"),
+    ('塹', "qiàn"),
+    ('塺', "méi"),
+    ('塻', "mò"),
+    ('塼', "zhuān"),
+    ('塽', "shuǎng"),
+    ('塾', "shú"),
+    ('塿', "lǒu"),
+    ('墀', "chí"),
+    ('墁', "màn"),
+    ('墂', "biāo"),
+    ('境', "jìng"),
+    ('墄', "cè"),
+    ('墅', "shù"),
+    ('墆', "zhì"),
+    ('墇', "zhàng"),
+    ('墈', "kàn"),
+    ('墉', "yōng"),
+    ('墊', "diàn"),
+    ('墋', "chěn"),
+    ('墌', "zhí"),
+    ('墍', "xì"),
+    ('墎', "guō"),
+    ('墏', "qiǎng"),
+    ('墐', "jìn"),
+    ('墑', "dì"),
+    ('墒', "shāng"),
+    ('墓', "mù"),
+    ('墔', "cuī"),
+    ('墕', "yàn"),
+    ('墖', "tǎ"),
+    ('増', "zēng"),
+    ('墘', "qián"),
+    ('墙', "qiáng"),
+    ('墚', "liáng"),
+    ('墛', "wèi"),
+    ('墜', "zhuì"),
+    ('墝', "qiāo"),
+    ('增', "zēng"),
+    ('墟', "xū"),
+    ('墠', "shàn"),
+    ('墡', "shàn"),
+    ('墢', "bá"),
+    ('墣', "pú"),
+    ('墤', "kuài"),
+    ('墥', "dǒng"),
+    ('墦', "fán"),
+    ('墧', "què"),
+    ('墨', "mò"),
+    ('墩', "dūn"),
+    ('墪', "dūn"),
+    ('墫', "zūn"),
+    ('墬', "dì"),
+    ('墭', "shèng"),
+    ('墮', "duò"),
+    ('墯', "duò"),
+    ('墰', "tán"),
+    ('墱', "dèng"),
+    ('墲', "mú"),
+    ('墳', "fén"),
+    ('墴', "huáng"),
+    ('墵', "tán"),
+    ('墶', "da"),
+    ('墷', "yè"),
+    ('墸', "zhù"),
+    ('墹', "jiàn"),
+    ('墺', "ào"),
+    ('墻', "qiáng"),
+    ('墼', "jī"),
+    ('墽', "qiāo"),
+    ('墾', "kěn"),
+    ('墿', "yì"),
+    ('壀', "pí"),
+    ('壁', "bì"),
+    ('壂', "diàn"),
+    ('壃', "jiāng"),
+    ('壄', "yě"),
+    ('壅', "yōng"),
+    ('壆', "xué"),
+    ('壇', "tán"),
+    ('壈', "lǎn"),
+    ('壉', "jù"),
+    ('壊', "huài"),
+    ('壋', "dàng"),
+    ('壌', "rǎng"),
+    ('壍', "qiàn"),
+    ('壎', "xūn"),
+    ('壏', "xiàn"),
+    ('壐', "xǐ"),
+    ('壑', "hè"),
+    ('壒', "ài"),
+    ('壓', "yā"),
+    ('壔', "dǎo"),
+    ('壕', "háo"),
+    ('壖', "ruán"),
+    ('壗', "jìn"),
+    ('壘', "lěi"),
+    ('壙', "kuàng"),
+    ('壚', "lú"),
+    ('壛', "yán"),
+    ('壜', "tán"),
+    ('壝', "wěi"),
+    ('壞', "huài"),
+    ('壟', "lǒng"),
+    ('壠', "lǒng"),
+    ('壡', "ruì"),
+    ('壢', "lì"),
+    ('壣', "lín"),
+    ('壤', "rǎng"),
+    ('壥', "chán"),
+    ('壦', "xūn"),
+    ('壧', "yán"),
+    ('壨', "léi"),
+    ('壩', "bà"),
+    ('壪', "wān"),
+    ('士', "shì"),
+    ('壬', "rén"),
+    ('壭', "san"),
+    ('壮', "zhuàng"),
+    ('壯', "zhuàng"),
+    ('声', "shēng"),
+    ('壱', "yī"),
+    ('売', "mài"),
+    ('壳', "ké,qiào"),
+    ('壴', "zhù"),
+    ('壵', "zhuàng"),
+    ('壶', "hú"),
+    ('壷', "hú"),
+    ('壸', "kǔn"),
+    ('壹', "yī"),
+    ('壺', "hú"),
+    ('壻', "xù"),
+    ('壼', "kǔn"),
+    ('壽', "shòu"),
+    ('壾', "mǎng"),
+    ('壿', "zūn"),
+    ('夀', "shòu"),
+    ('夁', "yī"),
+    ('夂', "zhǐ"),
+    ('夃', "gǔ"),
+    ('处', "chù,chǔ"),
+    ('夅', "jiàng"),
+    ('夆', "féng"),
+    ('备', "bèi"),
+    ('夈', "zhāi"),
+    ('変', "biàn"),
+    ('夊', "suī"),
+    ('夋', "qūn"),
+    ('夌', "líng"),
+    ('复', "fù"),
+    ('夎', "cuò"),
+    ('夏', "xià"),
+    ('夐', "xiòng"),
+    ('夑', "xiè"),
+    ('夒', "náo"),
+    ('夓', "xià"),
+    ('夔', "kuí"),
+    ('夕', "xī"),
+    ('外', "wài"),
+    ('夗', "yuàn"),
+    ('夘', "mǎo"),
+    ('夙', "sù"),
+    ('多', "duō"),
+    ('夛', "duō"),
+    ('夜', "yè"),
+    ('夝', "qíng"),
+    ('夞', "wài"),
+    ('够', "gòu"),
+    ('夠', "gòu"),
+    ('夡', "qì"),
+    ('夢', "mèng"),
+    ('夣', "mèng"),
+    ('夤', "yín"),
+    ('夥', "huǒ"),
+    ('夦', "chěn"),
+    ('大', "dà,dài"),
+    ('夨', "zè"),
+    ('天', "tiān"),
+    ('太', "tài"),
+    ('夫', "fū"),
+    ('夬', "guài"),
+    ('夭', "yāo"),
+    ('央', "yāng"),
+    ('夯', "hāng"),
+    ('夰', "gǎo"),
+    ('失', "shī"),
+    ('夲', "tāo"),
+    ('夳', "tài"),
+    ('头', "tóu"),
+    ('夵', "yǎn"),
+    ('夶', "bǐ"),
+    ('夷', "yí"),
+    ('夸', "kuā"),
+    ('夹', "jiā,jiá,gā"),
+    ('夺', "duó"),
+    ('夻', "huà"),
+    ('夼', "kuǎng"),
+    ('夽', "yǔn"),
+    ('夾', "jiā"),
+    ('夿', "bā"),
+    ('奀', "ēn"),
+    ('奁', "lián"),
+    ('奂', "huàn"),
+    ('奃', "dī"),
+    ('奄', "yǎn"),
+    ('奅', "pào"),
+    ('奆', "juàn"),
+    ('奇', "qí,jī"),
+    ('奈', "nài"),
+    ('奉', "fèng"),
+    ('奊', "xié"),
+    ('奋', "fèn"),
+    ('奌', "diǎn"),
+    ('奍', "quān"),
+    ('奎', "kuí"),
+    ('奏', "zòu"),
+    ('奐', "huàn"),
+    ('契', "qì"),
+    ('奒', "kāi"),
+    ('奓', "zhā"),
+    ('奔', "bēn,bèn"),
+    ('奕', "yì"),
+    ('奖', "jiǎng"),
+    ('套', "tào"),
+    ('奘', "zàng"),
+    ('奙', "běn"),
+    ('奚', "xī"),
+    ('奛', "huǎng"),
+    ('奜', "fěi"),
+    ('奝', "diāo"),
+    ('奞', "xùn"),
+    ('奟', "bēng"),
+    ('奠', "diàn"),
+    ('奡', "ào"),
+    ('奢', "shē"),
+    ('奣', "wěng"),
+    ('奤', "hǎ"),
+    ('奥', "ào"),
+    ('奦', "wù"),
+    ('奧', "ào"),
+    ('奨', "jiǎng"),
+    ('奩', "lián"),
+    ('奪', "duó"),
+    ('奫', "yūn"),
+    ('奬', "jiǎng"),
+    ('奭', "shì"),
+    ('奮', "fèn"),
+    ('奯', "huò"),
+    ('奰', "bì"),
+    ('奱', "luán"),
+    ('奲', "duǒ"),
+    ('女', "nǚ"),
+    ('奴', "nú"),
+    ('奵', "dǐng"),
+    ('奶', "nǎi"),
+    ('奷', "qiān"),
+    ('奸', "jiān"),
+    ('她', "tā"),
+    ('奺', "jiǔ"),
+    ('奻', "nuán"),
+    ('奼', "chà"),
+    ('好', "hǎo,hào"),
+    ('奾', "xiān"),
+    ('奿', "fàn"),
+    ('妀', "jǐ"),
+    ('妁', "shuò"),
+    ('如', "rú"),
+    ('妃', "fēi"),
+    ('妄', "wàng"),
+    ('妅', "hóng"),
+    ('妆', "zhuāng"),
+    ('妇', "fù"),
+    ('妈', "mā"),
+    ('妉', "dān"),
+    ('妊', "rèn"),
+    ('妋', "fū"),
+    ('妌', "jìng"),
+    ('妍', "yán"),
+    ('妎', "hài"),
+    ('妏', "wèn"),
+    ('妐', "zhōng"),
+    ('妑', "pā"),
+    ('妒', "dù"),
+    ('妓', "jì"),
+    ('妔', "kēng"),
+    ('妕', "zhòng"),
+    ('妖', "yāo"),
+    ('妗', "jìn"),
+    ('妘', "yún"),
+    ('妙', "miào"),
+    ('妚', "fǒu"),
+    ('妛', "chī"),
+    ('妜', "yuè"),
+    ('妝', "zhuāng"),
+    ('妞', "niū"),
+    ('妟', "yàn"),
+    ('妠', "nà"),
+    ('妡', "xīn"),
+    ('妢', "fén"),
+    ('妣', "bǐ"),
+    ('妤', "yú"),
+    ('妥', "tuǒ"),
+    ('妦', "fēng"),
+    ('妧', "wàn"),
+    ('妨', "fáng"),
+    ('妩', "wǔ"),
+    ('妪', "yù"),
+    ('妫', "guī"),
+    ('妬', "dù"),
+    ('妭', "bá"),
+    ('妮', "nī"),
+    ('妯', "zhóu"),
+    ('妰', "zhuó"),
+    ('妱', "zhāo"),
+    ('妲', "dá"),
+    ('妳', "nǎi"),
+    ('妴', "yuàn"),
+    ('妵', "tǒu"),
+    ('妶', "xián"),
+    ('妷', "zhí"),
+    ('妸', "ē"),
+    ('妹', "mèi"),
+    ('妺', "mò"),
+    ('妻', "qī"),
+    ('妼', "bì"),
+    ('妽', "shēn"),
+    ('妾', "qiè"),
+    ('妿', "ē"),
+    ('姀', "hé"),
+    ('姁', "xǔ"),
+    ('姂', "fá"),
+    ('姃', "zhēng"),
+    ('姄', "mín"),
+    ('姅', "bàn"),
+    ('姆', "mǔ"),
+    ('姇', "fū"),
+    ('姈', "líng"),
+    ('姉', "zǐ"),
+    ('姊', "zǐ"),
+    ('始', "shǐ"),
+    ('姌', "rǎn"),
+    ('姍', "shān"),
+    ('姎', "yāng"),
+    ('姏', "mán"),
+    ('姐', "jiě"),
+    ('姑', "gū"),
+    ('姒', "sì"),
+    ('姓', "xìng"),
+    ('委', "wěi,wēi"),
+    ('姕', "zī"),
+    ('姖', "jù"),
+    ('姗', "shān"),
+    ('姘', "pīn"),
+    ('姙', "rèn"),
+    ('姚', "yáo"),
+    ('姛', "dòng"),
+    ('姜', "jiāng"),
+    ('姝', "shū"),
+    ('姞', "jí"),
+    ('姟', "gāi"),
+    ('姠', "xiàng"),
+    ('姡', "huá"),
+    ('姢', "juān"),
+    ('姣', "jiāo"),
+    ('姤', "gòu"),
+    ('姥', "lǎo"),
+    ('姦', "jiān"),
+    ('姧', "jiān"),
+    ('姨', "yí"),
+    ('姩', "niàn"),
+    ('姪', "zhí"),
+    ('姫', "jī"),
+    ('姬', "jī"),
+    ('姭', "xiàn"),
+    ('姮', "héng"),
+    ('姯', "guāng"),
+    ('姰', "jūn"),
+    ('姱', "kuā"),
+    ('姲', "yàn"),
+    ('姳', "mǐng"),
+    ('姴', "liè"),
+    ('姵', "pèi"),
+    ('姶', "è"),
+    ('姷', "yòu"),
+    ('姸', "yán"),
+    ('姹', "chà"),
+    ('姺', "shēn"),
+    ('姻', "yīn"),
+    ('姼', "shí"),
+    ('姽', "guǐ"),
+    ('姾', "quán"),
+    ('姿', "zī"),
+    ('娀', "sōng"),
+    ('威', "wēi"),
+    ('娂', "hóng"),
+    ('娃', "wá"),
+    ('娄', "lóu"),
+    ('娅', "yà"),
+    ('娆', "ráo"),
+    ('娇', "jiāo"),
+    ('娈', "luán"),
+    ('娉', "pīng"),
+    ('娊', "xiàn"),
+    ('娋', "shào"),
+    ('娌', "lǐ"),
+    ('娍', "chéng"),
+    ('娎', "xiè"),
+    ('娏', "máng"),
+    ('娐', "fū"),
+    ('娑', "suō"),
+    ('娒', "méi"),
+    ('娓', "wěi"),
+    ('娔', "kè"),
+    ('娕', "chuò"),
+    ('娖', "chuò"),
+    ('娗', "tǐng"),
+    ('娘', "niáng"),
+    ('娙', "xíng"),
+    ('娚', "nán"),
+    ('娛', "yú"),
+    ('娜', "nà,nuó"),
+    ('娝', "pōu"),
+    ('娞', "něi"),
+    ('娟', "juān"),
+    ('娠', "shēn"),
+    ('娡', "zhì"),
+    ('娢', "hán"),
+    ('娣', "dì"),
+    ('娤', "zhuāng"),
+    ('娥', "é"),
+    ('娦', "pín"),
+    ('娧', "tuì"),
+    ('娨', "xiàn"),
+    ('娩', "miǎn"),
+    ('娪', "wú"),
+    ('娫', "yán"),
+    ('娬', "wǔ"),
+    ('娭', "āi"),
+    ('娮', "yán"),
+    ('娯', "yú"),
+    ('娰', "sì"),
+    ('娱', "yú"),
+    ('娲', "wā"),
+    ('娳', "lì"),
+    ('娴', "xián"),
+    ('娵', "jū"),
+    ('娶', "qǔ"),
+    ('娷', "zhuì"),
+    ('娸', "qī"),
+    ('娹', "xián"),
+    ('娺', "zhuó"),
+    ('娻', "dōng"),
+    ('娼', "chāng"),
+    ('娽', "lù"),
+    ('娾', "ǎi"),
+    ('娿', "ē"),
+    ('婀', "ē"),
+    ('婁', "lóu"),
+    ('婂', "mián"),
+    ('婃', "cóng"),
+    ('婄', "pǒu"),
+    ('婅', "jú"),
+    ('婆', "pó"),
+    ('婇', "cǎi"),
+    ('婈', "líng"),
+    ('婉', "wǎn"),
+    ('婊', "biǎo"),
+    ('婋', "xiāo"),
+    ('婌', "shú"),
+    ('婍', "qǐ"),
+    ('婎', "huī"),
+    ('婏', "fàn"),
+    ('婐', "wǒ"),
+    ('婑', "ruí"),
+    ('婒', "tán"),
+    ('婓', "fēi"),
+    ('婔', "fēi"),
+    ('婕', "jié"),
+    ('婖', "tiān"),
+    ('婗', "ní"),
+    ('婘', "quán"),
+    ('婙', "jìng"),
+    ('婚', "hūn"),
+    ('婛', "jīng"),
+    ('婜', "qiān"),
+    ('婝', "diàn"),
+    ('婞', "xìng"),
+    ('婟', "hù"),
+    ('婠', "wān"),
+    ('婡', "lái"),
+    ('婢', "bì"),
+    ('婣', "yīn"),
+    ('婤', "chōu"),
+    ('婥', "nào"),
+    ('婦', "fù"),
+    ('婧', "jìng"),
+    ('婨', "lún"),
+    ('婩', "àn"),
+    ('婪', "lán"),
+    ('婫', "kūn"),
+    ('婬', "yín"),
+    ('婭', "yà"),
+    ('婮', "jū"),
+    ('婯', "lì"),
+    ('婰', "diǎn"),
+    ('婱', "xián"),
+    ('婲', "huā"),
+    ('婳', "huà"),
+    ('婴', "yīng"),
+    ('婵', "chán"),
+    ('婶', "shěn"),
+    ('婷', "tíng"),
+    ('婸', "dàng"),
+    ('婹', "yǎo"),
+    ('婺', "wù"),
+    ('婻', "nàn"),
+    ('婼', "chuò"),
+    ('婽', "jiǎ"),
+    ('婾', "tōu"),
+    ('婿', "xù"),
+    ('媀', "yù"),
+    ('媁', "wéi"),
+    ('媂', "dì"),
+    ('媃', "róu"),
+    ('媄', "měi"),
+    ('媅', "dān"),
+    ('媆', "ruǎn"),
+    ('媇', "qīn"),
+    ('媈', "huī"),
+    ('媉', "wò"),
+    ('媊', "qián"),
+    ('媋', "chūn"),
+    ('媌', "miáo"),
+    ('媍', "fù"),
+    ('媎', "jiě"),
+    ('媏', "duān"),
+    ('媐', "yí"),
+    ('媑', "zhòng"),
+    ('媒', "méi"),
+    ('媓', "huáng"),
+    ('媔', "mián"),
+    ('媕', "ān"),
+    ('媖', "yīng"),
+    ('媗', "xuān"),
+    ('媘', "jiē"),
+    ('媙', "wēi"),
+    ('媚', "mèi"),
+    ('媛', "yuàn"),
+    ('媜', "zhēng"),
+    ('媝', "qiū"),
+    ('媞', "shì"),
+    ('媟', "xiè"),
+    ('媠', "tuǒ"),
+    ('媡', "liàn"),
+    ('媢', "mào"),
+    ('媣', "rǎn"),
+    ('媤', "sī"),
+    ('媥', "piān"),
+    ('媦', "wèi"),
+    ('媧', "wā"),
+    ('媨', "cù"),
+    ('媩', "hú"),
+    ('媪', "ǎo"),
+    ('媫', "jié"),
+    ('媬', "bǎo"),
+    ('媭', "xū"),
+    ('媮', "tōu"),
+    ('媯', "guī"),
+    ('媰', "chú"),
+    ('媱', "yáo"),
+    ('媲', "pì"),
+    ('媳', "xí"),
+    ('媴', "yuán"),
+    ('媵', "yìng"),
+    ('媶', "róng"),
+    ('媷', "rù"),
+    ('媸', "chī"),
+    ('媹', "liú"),
+    ('媺', "měi"),
+    ('媻', "pán"),
+    ('媼', "ǎo"),
+    ('媽', "mā"),
+    ('媾', "gòu"),
+    ('媿', "kuì"),
+    ('嫀', "qín"),
+    ('嫁', "jià"),
+    ('嫂', "sǎo"),
+    ('嫃', "zhēn"),
+    ('嫄', "yuán"),
+    ('嫅', "jiē"),
+    ('嫆', "róng"),
+    ('嫇', "míng"),
+    ('嫈', "yīng"),
+    ('嫉', "jí"),
+    ('嫊', "sù"),
+    ('嫋', "niǎo"),
+    ('嫌', "xián"),
+    ('嫍', "tāo"),
+    ('嫎', "páng"),
+    ('嫏', "láng"),
+    ('嫐', "nǎo"),
+    ('嫑', "báo"),
+    ('嫒', "ài"),
+    ('嫓', "pì"),
+    ('嫔', "pín"),
+    ('嫕', "yì"),
+    ('嫖', "piáo"),
+    ('嫗', "yù"),
+    ('嫘', "léi"),
+    ('嫙', "xuán"),
+    ('嫚', "mān"),
+    ('嫛', "yī"),
+    ('嫜', "zhāng"),
+    ('嫝', "kāng"),
+    ('嫞', "yōng"),
+    ('嫟', "nì"),
+    ('嫠', "lí"),
+    ('嫡', "dí"),
+    ('嫢', "guī"),
+    ('嫣', "yān"),
+    ('嫤', "jǐn"),
+    ('嫥', "zhuān"),
+    ('嫦', "cháng"),
+    ('嫧', "zé"),
+    ('嫨', "hān"),
+    ('嫩', "nèn"),
+    ('嫪', "lào"),
+    ('嫫', "mó"),
+    ('嫬', "zhē"),
+    ('嫭', "hù"),
+    ('嫮', "hù"),
+    ('嫯', "ào"),
+    ('嫰', "nèn"),
+    ('嫱', "qiáng"),
+    ('嫲', "ma"),
+    ('嫳', "piè"),
+    ('嫴', "gū"),
+    ('嫵', "wǔ"),
+    ('嫶', "qiáo"),
+    ('嫷', "tuǒ"),
+    ('嫸', "zhǎn"),
+    ('嫹', "miáo"),
+    ('嫺', "xián"),
+    ('嫻', "xián"),
+    ('嫼', "mò"),
+    ('嫽', "liáo"),
+    ('嫾', "lián"),
+    ('嫿', "huà"),
+    ('嬀', "guī"),
+    ('嬁', "dēng"),
+    ('嬂', "zhí"),
+    ('嬃', "xū"),
+    ('嬄', "yī"),
+    ('嬅', "huà"),
+    ('嬆', "xī"),
+    ('嬇', "kuì"),
+    ('嬈', "ráo"),
+    ('嬉', "xī"),
+    ('嬊', "yàn"),
+    ('嬋', "chán"),
+    ('嬌', "jiāo"),
+    ('嬍', "měi"),
+    ('嬎', "fàn"),
+    ('嬏', "fān"),
+    ('嬐', "xiān"),
+    ('嬑', "yì"),
+    ('嬒', "huì"),
+    ('嬓', "jiào"),
+    ('嬔', "fù"),
+    ('嬕', "shì"),
+    ('嬖', "bì"),
+    ('嬗', "shàn"),
+    ('嬘', "suì"),
+    ('嬙', "qiáng"),
+    ('嬚', "liǎn"),
+    ('嬛', "huán"),
+    ('嬜', "xīn"),
+    ('嬝', "niǎo"),
+    ('嬞', "dǒng"),
+    ('嬟', "yì"),
+    ('嬠', "cān"),
+    ('嬡', "ài"),
+    ('嬢', "niáng"),
+    ('嬣', "níng"),
+    ('嬤', "mā"),
+    ('嬥', "tiǎo"),
+    ('嬦', "chóu"),
+    ('嬧', "jìn"),
+    ('嬨', "cí"),
+    ('嬩', "yú"),
+    ('嬪', "pín"),
+    ('嬫', "róng"),
+    ('嬬', "rú"),
+    ('嬭', "nǎi"),
+    ('嬮', "yān"),
+    ('嬯', "tái"),
+    ('嬰', "yīng"),
+    ('嬱', "qiàn"),
+    ('嬲', "niǎo"),
+    ('嬳', "yuè"),
+    ('嬴', "yíng"),
+    ('嬵', "mián"),
+    ('嬶', "bí"),
+    ('嬷', "mā"),
+    ('嬸', "shěn"),
+    ('嬹', "xìng"),
+    ('嬺', "nì"),
+    ('嬻', "dú"),
+    ('嬼', "liǔ"),
+    ('嬽', "yuān"),
+    ('嬾', "lǎn"),
+    ('嬿', "yàn"),
+    ('孀', "shuāng"),
+    ('孁', "líng"),
+    ('孂', "jiǎo"),
+    ('孃', "niáng"),
+    ('孄', "lǎn"),
+    ('孅', "qiān"),
+    ('孆', "yīng"),
+    ('孇', "shuāng"),
+    ('孈', "huì"),
+    ('孉', "quán"),
+    ('孊', "mǐ"),
+    ('孋', "lí"),
+    ('孌', "luán"),
+    ('孍', "yán"),
+    ('孎', "zhú"),
+    ('孏', "lǎn"),
+    ('子', "zǐ,zi"),
+    ('孑', "jié"),
+    ('孒', "jué"),
+    ('孓', "jué"),
+    ('孔', "kǒng"),
+    ('孕', "yùn"),
+    ('孖', "mā"),
+    ('字', "zì"),
+    ('存', "cún"),
+    ('孙', "sūn"),
+    ('孚', "fú"),
+    ('孛', "bèi"),
+    ('孜', "zī"),
+    ('孝', "xiào"),
+    ('孞', "xìn"),
+    ('孟', "mèng"),
+    ('孠', "sì"),
+    ('孡', "tāi"),
+    ('孢', "bāo"),
+    ('季', "jì"),
+    ('孤', "gū"),
+    ('孥', "nú"),
+    ('学', "xué"),
+    ('孧', "yòu"),
+    ('孨', "zhuǎn"),
+    ('孩', "hái"),
+    ('孪', "luán"),
+    ('孫', "sūn"),
+    ('孬', "nāo"),
+    ('孭', "miē"),
+    ('孮', "cóng"),
+    ('孯', "qiān"),
+    ('孰', "shú"),
+    ('孱', "càn"),
+    ('孲', "yā"),
+    ('孳', "zī"),
+    ('孴', "nǐ"),
+    ('孵', "fū"),
+    ('孶', "zī"),
+    ('孷', "lí"),
+    ('學', "xué"),
+    ('孹', "bò"),
+    ('孺', "rú"),
+    ('孻', "nái"),
+    ('孼', "niè"),
+    ('孽', "niè"),
+    ('孾', "yīng"),
+    ('孿', "luán"),
+    ('宀', "mián"),
+    ('宁', "níng,nìng"),
+    ('宂', "rǒng"),
+    ('它', "tā"),
+    ('宄', "guǐ"),
+    ('宅', "zhái"),
+    ('宆', "qióng"),
+    ('宇', "yǔ"),
+    ('守', "shǒu"),
+    ('安', "ān"),
+    ('宊', "tū"),
+    ('宋', "sòng"),
+    ('完', "wán"),
+    ('宍', "ròu"),
+    ('宎', "yǎo"),
+    ('宏', "hóng"),
+    ('宐', "yí"),
+    ('宑', "jǐng"),
+    ('宒', "zhūn"),
+    ('宓', "mì"),
+    ('宔', "zhǔ"),
+    ('宕', "dàng"),
+    ('宖', "hóng"),
+    ('宗', "zōng"),
+    ('官', "guān"),
+    ('宙', "zhòu"),
+    ('定', "dìng"),
+    ('宛', "wǎn"),
+    ('宜', "yí"),
+    ('宝', "bǎo"),
+    ('实', "shí"),
+    ('実', "shí"),
+    ('宠', "chǒng"),
+    ('审', "shěn"),
+    ('客', "kè"),
+    ('宣', "xuān"),
+    ('室', "shì"),
+    ('宥', "yòu"),
+    ('宦', "huàn"),
+    ('宧', "yí"),
+    ('宨', "tiǎo"),
+    ('宩', "shǐ"),
+    ('宪', "xiàn"),
+    ('宫', "gōng"),
+    ('宬', "chéng"),
+    ('宭', "qún"),
+    ('宮', "gōng"),
+    ('宯', "xiāo"),
+    ('宰', "zǎi"),
+    ('宱', "zhà"),
+    ('宲', "bǎo"),
+    ('害', "hài"),
+    ('宴', "yàn"),
+    ('宵', "xiāo"),
+    ('家', "jiā"),
+    ('宷', "shěn"),
+    ('宸', "chén"),
+    ('容', "róng"),
+    ('宺', "huǎng"),
+    ('宻', "mì"),
+    ('宼', "kòu"),
+    ('宽', "kuān"),
+    ('宾', "bīn"),
+    ('宿', "sù,xiǔ,xiù"),
+    ('寀', "cǎi"),
+    ('寁', "zǎn"),
+    ('寂', "jì"),
+    ('寃', "yuān"),
+    ('寄', "jì"),
+    ('寅', "yín"),
+    ('密', "mì"),
+    ('寇', "kòu"),
+    ('寈', "qīng"),
+    ('寉', "hè"),
+    ('寊', "zhēn"),
+    ('寋', "jiàn"),
+    ('富', "fù"),
+    ('寍', "níng"),
+    ('寎', "bìng"),
+    ('寏', "huán"),
+    ('寐', "mèi"),
+    ('寑', "qǐn"),
+    ('寒', "hán"),
+    ('寓', "yù"),
+    ('寔', "shí"),
+    ('寕', "níng"),
+    ('寖', "jìn"),
+    ('寗', "níng"),
+    ('寘', "zhì"),
+    ('寙', "yǔ"),
+    ('寚', "bǎo"),
+    ('寛', "kuān"),
+    ('寜', "níng"),
+    ('寝', "qǐn"),
+    ('寞', "mò"),
+    ('察', "chá"),
+    ('寠', "jù"),
+    ('寡', "guǎ"),
+    ('寢', "qǐn"),
+    ('寣', "hū"),
+    ('寤', "wù"),
+    ('寥', "liáo"),
+    ('實', "shí"),
+    ('寧', "níng"),
+    ('寨', "zhài"),
+    ('審', "shěn"),
+    ('寪', "wěi"),
+    ('寫', "xiě"),
+    ('寬', "kuān"),
+    ('寭', "huì"),
+    ('寮', "liáo"),
+    ('寯', "jùn"),
+    ('寰', "huán"),
+    ('寱', "yì"),
+    ('寲', "yí"),
+    ('寳', "bǎo"),
+    ('寴', "qīn"),
+    ('寵', "chǒng"),
+    ('寶', "bǎo"),
+    ('寷', "fēng"),
+    ('寸', "cùn"),
+    ('对', "duì"),
+    ('寺', "sì"),
+    ('寻', "xún"),
+    ('导', "dǎo"),
+    ('寽', "lǜ"),
+    ('対', "duì"),
+    ('寿', "shòu"),
+    ('尀', "pǒ"),
+    ('封', "fēng"),
+    ('専', "zhuān"),
+    ('尃', "fū"),
+    ('射', "shè"),
+    ('尅', "kè"),
+    ('将', "jiāng,jiàng"),
+    ('將', "jiāng"),
+    ('專', "zhuān"),
+    ('尉', "wèi"),
+    ('尊', "zūn"),
+    ('尋', "xún"),
+    ('尌', "shù"),
+    ('對', "duì"),
+    ('導', "dǎo"),
+    ('小', "xiǎo"),
+    ('尐', "jié"),
+    ('少', "shǎo,shào"),
+    ('尒', "ěr"),
+    ('尓', "ěr"),
+    ('尔', "ěr"),
+    ('尕', "gǎ"),
+    ('尖', "jiān"),
+    ('尗', "shū"),
+    ('尘', "chén"),
+    ('尙', "shàng"),
+    ('尚', "shàng"),
+    ('尛', "mó"),
+    ('尜', "gá"),
+    ('尝', "cháng"),
+    ('尞', "liào"),
+    ('尟', "xiǎn"),
+    ('尠', "xiǎn"),
+    ('尡', "kun"),
+    ('尢', "yóu"),
+    ('尣', "wāng"),
+    ('尤', "yóu"),
+    ('尥', "liào"),
+    ('尦', "liào"),
+    ('尧', "yáo"),
+    ('尨', "máng"),
+    ('尩', "wāng"),
+    ('尪', "wāng"),
+    ('尫', "wāng"),
+    ('尬', "gà"),
+    ('尭', "yáo"),
+    ('尮', "duò"),
+    ('尯', "kuì"),
+    ('尰', "zhǒng"),
+    ('就', "jiù"),
+    ('尲', "gān"),
+    ('尳', "gǔ"),
+    ('尴', "gān"),
+    ('尵', "tuí"),
+    ('尶', "gān"),
+    ('尷', "gān"),
+    ('尸', "shī"),
+    ('尹', "yǐn"),
+    ('尺', "chǐ,chě"),
+    ('尻', "kāo"),
+    ('尼', "ní"),
+    ('尽', "jǐn,jìn"),
+    ('尾', "wěi,yǐ"),
+    ('尿', "niào"),
+    ('局', "jú"),
+    ('屁', "pì"),
+    ('层', "céng"),
+    ('屃', "xì"),
+    ('屄', "bī"),
+    ('居', "jū"),
+    ('屆', "jiè"),
+    ('屇', "tián"),
+    ('屈', "qū"),
+    ('屉', "tì"),
+    ('届', "jiè"),
+    ('屋', "wū"),
+    ('屌', "diǎo"),
+    ('屍', "shī"),
+    ('屎', "shǐ"),
+    ('屏', "píng,bǐng"),
+    ('屐', "jī"),
+    ('屑', "xiè"),
+    ('屒', "zhěn"),
+    ('屓', "xiè"),
+    ('屔', "ní"),
+    ('展', "zhǎn"),
+    ('屖', "xī"),
+    ('屗', "wěi"),
+    ('屘', "mǎn"),
+    ('屙', "ē"),
+    ('屚', "lòu"),
+    ('屛', "píng"),
+    ('屜', "tì"),
+    ('屝', "fèi"),
+    ('属', "shǔ,zhǔ"),
+    ('屟', "xiè"),
+    ('屠', "tú"),
+    ('屡', "lǚ"),
+    ('屢', "lǚ"),
+    ('屣', "xǐ"),
+    ('層', "céng"),
+    ('履', "lǚ"),
+    ('屦', "jù"),
+    ('屧', "xiè"),
+    ('屨', "jù"),
+    ('屩', "juē"),
+    ('屪', "liáo"),
+    ('屫', "jué"),
+    ('屬', "shǔ"),
+    ('屭', "xì"),
+    ('屮', "chè"),
+    ('屯', "tún"),
+    ('屰', "nì"),
+    ('山', "shān"),
+    ('屲', "wā"),
+    ('屳', "xiān"),
+    ('屴', "lì"),
+    ('屵', "è"),
+    ('屶', "huì"),
+    ('屷', "huì"),
+    ('屸', "lóng"),
+    ('屹', "yì"),
+    ('屺', "qǐ"),
+    ('屻', "rèn"),
+    ('屼', "wù"),
+    ('屽', "hàn"),
+    ('屾', "shēn"),
+    ('屿', "yǔ"),
+    ('岀', "chū"),
+    ('岁', "suì"),
+    ('岂', "qǐ"),
+    ('岃', "rèn"),
+    ('岄', "yuè"),
+    ('岅', "bǎn"),
+    ('岆', "yǎo"),
+    ('岇', "áng"),
+    ('岈', "yá"),
+    ('岉', "wù"),
+    ('岊', "jié"),
+    ('岋', "è"),
+    ('岌', "jí"),
+    ('岍', "qiān"),
+    ('岎', "fén"),
+    ('岏', "wán"),
+    ('岐', "qí"),
+    ('岑', "cén"),
+    ('岒', "qián"),
+    ('岓', "qí"),
+    ('岔', "chà"),
+    ('岕', "jiè"),
+    ('岖', "qū"),
+    ('岗', "gǎng,gāng"),
+    ('岘', "xiàn"),
+    ('岙', "ào"),
+    ('岚', "lán"),
+    ('岛', "dǎo"),
+    ('岜', "bā"),
+    ('岝', "zuò"),
+    ('岞', "zuò"),
+    ('岟', "yǎng"),
+    ('岠', "jù"),
+    ('岡', "gāng"),
+    ('岢', "kě"),
+    ('岣', "gǒu"),
+    ('岤', "xué"),
+    ('岥', "pō"),
+    ('岦', "lì"),
+    ('岧', "tiáo"),
+    ('岨', "qū"),
+    ('岩', "yán"),
+    ('岪', "fú"),
+    ('岫', "xiù"),
+    ('岬', "jiǎ"),
+    ('岭', "lǐng"),
+    ('岮', "tuó"),
+    ('岯', "pí"),
+    ('岰', "ào"),
+    ('岱', "dài"),
+    ('岲', "kuàng"),
+    ('岳', "yuè"),
+    ('岴', "qū"),
+    ('岵', "hù"),
+    ('岶', "pò"),
+    ('岷', "mín"),
+    ('岸', "àn"),
+    ('岹', "tiáo"),
+    ('岺', "líng"),
+    ('岻', "chí"),
+    ('岼', "píng"),
+    ('岽', "dōng"),
+    ('岾', "hàn"),
+    ('岿', "kuī"),
+    ('峀', "xiù"),
+    ('峁', "mǎo"),
+    ('峂', "tóng"),
+    ('峃', "xué"),
+    ('峄', "yì"),
+    ('峅', "biàn"),
+    ('峆', "hé"),
+    ('峇', "bā"),
+    ('峈', "luò"),
+    ('峉', "è"),
+    ('峊', "fù"),
+    ('峋', "xún"),
+    ('峌', "dié"),
+    ('峍', "lù"),
+    ('峎', "ěn"),
+    ('峏', "ér"),
+    ('峐', "gāi"),
+    ('峑', "quān"),
+    ('峒', "dòng"),
+    ('峓', "yí"),
+    ('峔', "mǔ"),
+    ('峕', "shí"),
+    ('峖', "ān"),
+    ('峗', "wéi"),
+    ('峘', "huán"),
+    ('峙', "zhì"),
+    ('峚', "mì"),
+    ('峛', "lǐ"),
+    ('峜', "jì"),
+    ('峝', "tóng"),
+    ('峞', "wéi"),
+    ('峟', "yòu"),
+    ('峠', "qiǎ"),
+    ('峡', "xiá"),
+    ('峢', "lǐ"),
+    ('峣', "yáo"),
+    ('峤', "jiào"),
+    ('峥', "zhēng"),
+    ('峦', "luán"),
+    ('峧', "jiāo"),
+    ('峨', "é"),
+    ('峩', "é"),
+    ('峪', "yù"),
+    ('峫', "xié"),
+    ('峬', "bū"),
+    ('峭', "qiào"),
+    ('峮', "qūn"),
+    ('峯', "fēng"),
+    ('峰', "fēng"),
+    ('峱', "náo"),
+    ('峲', "lǐ"),
+    ('峳', "yóu"),
+    ('峴', "xiàn"),
+    ('峵', "róng"),
+    ('島', "dǎo"),
+    ('峷', "shēn"),
+    ('峸', "chéng"),
+    ('峹', "tú"),
+    ('峺', "gěng"),
+    ('峻', "jùn"),
+    ('峼', "gào"),
+    ('峽', "xiá"),
+    ('峾', "yín"),
+    ('峿', "yǔ"),
+    ('崀', "làng"),
+    ('崁', "kàn"),
+    ('崂', "láo"),
+    ('崃', "lái"),
+    ('崄', "xiǎn"),
+    ('崅', "què"),
+    ('崆', "kōng"),
+    ('崇', "chóng"),
+    ('崈', "chóng"),
+    ('崉', "tà"),
+    ('崊', "lín"),
+    ('崋', "huà"),
+    ('崌', "jū"),
+    ('崍', "lái"),
+    ('崎', "qí"),
+    ('崏', "mín"),
+    ('崐', "kūn"),
+    ('崑', "kūn"),
+    ('崒', "zú"),
+    ('崓', "gù"),
+    ('崔', "cuī"),
+    ('崕', "yá"),
+    ('崖', "yá"),
+    ('崗', "gǎng"),
+    ('崘', "lún"),
+    ('崙', "lún"),
+    ('崚', "léng"),
+    ('崛', "jué"),
+    ('崜', "duō"),
+    ('崝', "zhēng"),
+    ('崞', "guō"),
+    ('崟', "yín"),
+    ('崠', "dōng"),
+    ('崡', "hán"),
+    ('崢', "zhēng"),
+    ('崣', "wěi"),
+    ('崤', "xiáo"),
+    ('崥', "pí"),
+    ('崦', "yān"),
+    ('崧', "sōng"),
+    ('崨', "jié"),
+    ('崩', "bēng"),
+    ('崪', "zú"),
+    ('崫', "kū"),
+    ('崬', "dōng"),
+    ('崭', "zhǎn"),
+    ('崮', "gù"),
+    ('崯', "yín"),
+    ('崰', "zī"),
+    ('崱', "zè"),
+    ('崲', "huáng"),
+    ('崳', "yú"),
+    ('崴', "wǎi"),
+    ('崵', "yáng"),
+    ('崶', "fēng"),
+    ('崷', "qiú"),
+    ('崸', "yáng"),
+    ('崹', "tí"),
+    ('崺', "yǐ"),
+    ('崻', "zhì"),
+    ('崼', "shì"),
+    ('崽', "zǎi"),
+    ('崾', "yǎo"),
+    ('崿', "è"),
+    ('嵀', "zhù"),
+    ('嵁', "kān"),
+    ('嵂', "lǜ"),
+    ('嵃', "yǎn"),
+    ('嵄', "měi"),
+    ('嵅', "hán"),
+    ('嵆', "jī"),
+    ('嵇', "jī"),
+    ('嵈', "huàn"),
+    ('嵉', "tíng"),
+    ('嵊', "shèng"),
+    ('嵋', "méi"),
+    ('嵌', "qiàn"),
+    ('嵍', "wù"),
+    ('嵎', "yú"),
+    ('嵏', "zōng"),
+    ('嵐', "lán"),
+    ('嵑', "kě"),
+    ('嵒', "yán"),
+    ('嵓', "yán"),
+    ('嵔', "wěi"),
+    ('嵕', "zōng"),
+    ('嵖', "chá"),
+    ('嵗', "suì"),
+    ('嵘', "róng"),
+    ('嵙', "kē"),
+    ('嵚', "qīn"),
+    ('嵛', "yú"),
+    ('嵜', "qí"),
+    ('嵝', "lǒu"),
+    ('嵞', "tú"),
+    ('嵟', "duī"),
+    ('嵠', "xī"),
+    ('嵡', "wěng"),
+    ('嵢', "cāng"),
+    ('嵣', "dàng"),
+    ('嵤', "róng"),
+    ('嵥', "jié"),
+    ('嵦', "kǎi"),
+    ('嵧', "liú"),
+    ('嵨', "wù"),
+    ('嵩', "sōng"),
+    ('嵪', "qiāo"),
+    ('嵫', "zī"),
+    ('嵬', "wéi"),
+    ('嵭', "bēng"),
+    ('嵮', "diān"),
+    ('嵯', "cuó"),
+    ('嵰', "qiǎn"),
+    ('嵱', "yǒng"),
+    ('嵲', "niè"),
+    ('嵳', "cuó"),
+    ('嵴', "jǐ"),
+    ('嵵', "shí"),
+    ('嵶', "ruò"),
+    ('嵷', "sǒng"),
+    ('嵸', "zōng"),
+    ('嵹', "jiàng"),
+    ('嵺', "liáo"),
+    ('嵻', "kāng"),
+    ('嵼', "chǎn"),
+    ('嵽', "dié"),
+    ('嵾', "cēn"),
+    ('嵿', "dǐng"),
+    ('嶀', "tū"),
+    ('嶁', "lǒu"),
+    ('嶂', "zhàng"),
+    ('嶃', "zhǎn"),
+    ('嶄', "zhǎn"),
+    ('嶅', "áo"),
+    ('嶆', "cáo"),
+    ('嶇', "qū"),
+    ('嶈', "qiāng"),
+    ('嶉', "cuī"),
+    ('嶊', "zuǐ"),
+    ('嶋', "dǎo"),
+    ('嶌', "dǎo"),
+    ('嶍', "xí"),
+    ('嶎', "yù"),
+    ('嶏', "pèi"),
+    ('嶐', "lóng"),
+    ('嶑', "xiàng"),
+    ('嶒', "céng"),
+    ('嶓', "bō"),
+    ('嶔', "qīn"),
+    ('嶕', "jiāo"),
+    ('嶖', "yān"),
+    ('嶗', "láo"),
+    ('嶘', "zhàn"),
+    ('嶙', "lín"),
+    ('嶚', "liáo"),
+    ('嶛', "liáo"),
+    ('嶜', "jīn"),
+    ('嶝', "dèng"),
+    ('嶞', "duò"),
+    ('嶟', "zūn"),
+    ('嶠', "jiào"),
+    ('嶡', "guì"),
+    ('嶢', "yáo"),
+    ('嶣', "jiāo"),
+    ('嶤', "yáo"),
+    ('嶥', "jué"),
+    ('嶦', "zhān"),
+    ('嶧', "yì"),
+    ('嶨', "xué"),
+    ('嶩', "náo"),
+    ('嶪', "yè"),
+    ('嶫', "yè"),
+    ('嶬', "yí"),
+    ('嶭', "niè"),
+    ('嶮', "xiǎn"),
+    ('嶯', "jí"),
+    ('嶰', "xiè"),
+    ('嶱', "kě"),
+    ('嶲', "xī"),
+    ('嶳', "dì"),
+    ('嶴', "ào"),
+    ('嶵', "zuǐ"),
+    ('嶶', "wēi"),
+    ('嶷', "yí"),
+    ('嶸', "róng"),
+    ('嶹', "dǎo"),
+    ('嶺', "lǐng"),
+    ('嶻', "jié"),
+    ('嶼', "yǔ"),
+    ('嶽', "yuè"),
+    ('嶾', "yǐn"),
+    ('嶿', "ru"),
+    ('巀', "jié"),
+    ('巁', "lì"),
+    ('巂', "guī"),
+    ('巃', "lóng"),
+    ('巄', "lóng"),
+    ('巅', "diān"),
+    ('巆', "róng"),
+    ('巇', "xī"),
+    ('巈', "jú"),
+    ('巉', "chán"),
+    ('巊', "yǐng"),
+    ('巋', "kuī"),
+    ('巌', "yán"),
+    ('巍', "wēi"),
+    ('巎', "náo"),
+    ('巏', "quán"),
+    ('巐', "chǎo"),
+    ('巑', "cuán"),
+    ('巒', "luán"),
+    ('巓', "diān"),
+    ('巔', "diān"),
+    ('巕', "niè"),
+    ('巖', "yán"),
+    ('巗', "yán"),
+    ('巘', "yǎn"),
+    ('巙', "kuí"),
+    ('巚', "yǎn"),
+    ('巛', "chuān"),
+    ('巜', "kuài"),
+    ('川', "chuān"),
+    ('州', "zhōu"),
+    ('巟', "huāng"),
+    ('巠', "jīng"),
+    ('巡', "xún"),
+    ('巢', "cháo"),
+    ('巣', "cháo"),
+    ('巤', "liè"),
+    ('工', "gōng"),
+    ('左', "zuǒ"),
+    ('巧', "qiǎo"),
+    ('巨', "jù"),
+    ('巩', "gǒng"),
+    ('巪', "jù"),
+    ('巫', "wū"),
+    ('巬', "pu"),
+    ('巭', "pu"),
+    ('差', "chà,chā,chāi,cī"),
+    ('巯', "qiú"),
+    ('巰', "qiú"),
+    ('己', "jǐ"),
+    ('已', "yǐ"),
+    ('巳', "sì"),
+    ('巴', "bā"),
+    ('巵', "zhī"),
+    ('巶', "zhāo"),
+    ('巷', "xiàng,hàng"),
+    ('巸', "yí"),
+    ('巹', "jǐn"),
+    ('巺', "xùn"),
+    ('巻', "juàn"),
+    ('巼', "bā"),
+    ('巽', "xùn"),
+    ('巾', "jīn"),
+    ('巿', "fú"),
+    ('帀', "zā"),
+    ('币', "bì"),
+    ('市', "shì"),
+    ('布', "bù"),
+    ('帄', "dīng"),
+    ('帅', "shuài"),
+    ('帆', "fān"),
+    ('帇', "niè"),
+    ('师', "shī"),
+    ('帉', "fēn"),
+    ('帊', "pà"),
+    ('帋', "zhǐ"),
+    ('希', "xī"),
+    ('帍', "hù"),
+    ('帎', "dàn"),
+    ('帏', "wéi"),
+    ('帐', "zhàng"),
+    ('帑', "tǎng"),
+    ('帒', "dài"),
+    ('帓', "mò"),
+    ('帔', "pèi"),
+    ('帕', "pà"),
+    ('帖', "tiē,tiě,tiè"),
+    ('帗', "bō"),
+    ('帘', "lián"),
+    ('帙', "zhì"),
+    ('帚', "zhǒu"),
+    ('帛', "bó"),
+    ('帜', "zhì"),
+    ('帝', "dì"),
+    ('帞', "mò"),
+    ('帟', "yì"),
+    ('帠', "yì"),
+    ('帡', "píng"),
+    ('帢', "qià"),
+    ('帣', "juǎn"),
+    ('帤', "rú"),
+    ('帥', "shuài"),
+    ('带', "dài"),
+    ('帧', "zhèng"),
+    ('帨', "shuì"),
+    ('帩', "qiào"),
+    ('帪', "zhēn"),
+    ('師', "shī"),
+    ('帬', "qún"),
+    ('席', "xí"),
+    ('帮', "bāng"),
+    ('帯', "dài"),
+    ('帰', "guī"),
+    ('帱', "chóu"),
+    ('帲', "píng"),
+    ('帳', "zhàng"),
+    ('帴', "sàn"),
+    ('帵', "wān"),
+    ('帶', "dài"),
+    ('帷', "wéi"),
+    ('常', "cháng"),
+    ('帹', "shà"),
+    ('帺', "qí"),
+    ('帻', "zé"),
+    ('帼', "guó"),
+    ('帽', "mào"),
+    ('帾', "dǔ"),
+    ('帿', "hóu"),
+    ('幀', "zhèng"),
+    ('幁', "xū"),
+    ('幂', "mì"),
+    ('幃', "wéi"),
+    ('幄', "wò"),
+    ('幅', "fú"),
+    ('幆', "yì"),
+    ('幇', "bāng"),
+    ('幈', "píng"),
+    ('幉', "dié"),
+    ('幊', "gōng"),
+    ('幋', "pán"),
+    ('幌', "huǎng"),
+    ('幍', "tāo"),
+    ('幎', "mì"),
+    ('幏', "jià"),
+    ('幐', "téng"),
+    ('幑', "huī"),
+    ('幒', "zhōng"),
+    ('幓', "shān"),
+    ('幔', "màn"),
+    ('幕', "mù"),
+    ('幖', "biāo"),
+    ('幗', "guó"),
+    ('幘', "zé"),
+    ('幙', "mù"),
+    ('幚', "bāng"),
+    ('幛', "zhàng"),
+    ('幜', "jǐng"),
+    ('幝', "chǎn"),
+    ('幞', "fú"),
+    ('幟', "zhì"),
+    ('幠', "hū"),
+    ('幡', "fān"),
+    ('幢', "zhuàng,chuáng"),
+    ('幣', "bì"),
+    ('幤', "bì"),
+    ('幥', "zhǎng"),
+    ('幦', "mì"),
+    ('幧', "qiāo"),
+    ('幨', "chān"),
+    ('幩', "fén"),
+    ('幪', "méng"),
+    ('幫', "bāng"),
+    ('幬', "chóu"),
+    ('幭', "miè"),
+    ('幮', "chú"),
+    ('幯', "jié"),
+    ('幰', "xiǎn"),
+    ('幱', "lán"),
+    ('干', "gàn,gān"),
+    ('平', "píng"),
+    ('年', "nián"),
+    ('幵', "jiān"),
+    ('并', "bìng,bīng"),
+    ('幷', "bìng"),
+    ('幸', "xìng"),
+    ('幹', "gàn"),
+    ('幺', "yāo"),
+    ('幻', "huàn"),
+    ('幼', "yòu"),
+    ('幽', "yōu"),
+    ('幾', "jǐ"),
+    ('广', "guǎng"),
+    ('庀', "pǐ"),
+    ('庁', "tīng"),
+    ('庂', "zè"),
+    ('広', "guǎng"),
+    ('庄', "zhuāng"),
+    ('庅', "mó"),
+    ('庆', "qìng"),
+    ('庇', "bì"),
+    ('庈', "qín"),
+    ('庉', "dùn"),
+    ('床', "chuáng"),
+    ('庋', "guǐ"),
+    ('庌', "yǎ"),
+    ('庍', "bài"),
+    ('庎', "jiè"),
+    ('序', "xù"),
+    ('庐', "lú"),
+    ('庑', "wǔ"),
+    ('庒', "zhuāng"),
+    ('库', "kù"),
+    ('应', "yìng,yīng"),
+    ('底', "dǐ"),
+    ('庖', "páo"),
+    ('店', "diàn"),
+    ('庘', "yā"),
+    ('庙', "miào"),
+    ('庚', "gēng"),
+    ('庛', "cì"),
+    ('府', "fǔ"),
+    ('庝', "tóng"),
+    ('庞', "páng"),
+    ('废', "fèi"),
+    ('庠', "xiáng"),
+    ('庡', "yǐ"),
+    ('庢', "zhì"),
+    ('庣', "tiāo"),
+    ('庤', "zhì"),
+    ('庥', "xiū"),
+    ('度', "dù,duó"),
+    ('座', "zuò"),
+    ('庨', "xiāo"),
+    ('庩', "tú"),
+    ('庪', "guǐ"),
+    ('庫', "kù"),
+    ('庬', "máng"),
+    ('庭', "tíng"),
+    ('庮', "yǒu"),
+    ('庯', "bū"),
+    ('庰', "bìng"),
+    ('庱', "chěng"),
+    ('庲', "lái"),
+    ('庳', "bì"),
+    ('庴', "jí"),
+    ('庵', "ān"),
+    ('庶', "shù"),
+    ('康', "kāng"),
+    ('庸', "yōng"),
+    ('庹', "tuǒ"),
+    ('庺', "sōng"),
+    ('庻', "shù"),
+    ('庼', "qǐng"),
+    ('庽', "yù"),
+    ('庾', "yǔ"),
+    ('庿', "miào"),
+    ('廀', "sōu"),
+    ('廁', "cè"),
+    ('廂', "xiāng"),
+    ('廃', "fèi"),
+    ('廄', "jiù"),
+    ('廅', "è"),
+    ('廆', "guī"),
+    ('廇', "liù"),
+    ('廈', "shà"),
+    ('廉', "lián"),
+    ('廊', "láng"),
+    ('廋', "sōu"),
+    ('廌', "zhì"),
+    ('廍', "bù"),
+    ('廎', "qǐng"),
+    ('廏', "jiù"),
+    ('廐', "jiù"),
+    ('廑', "jǐn"),
+    ('廒', "áo"),
+    ('廓', "kuò"),
+    ('廔', "lóu"),
+    ('廕', "yìn"),
+    ('廖', "liào"),
+    ('廗', "dài"),
+    ('廘', "lù"),
+    ('廙', "yì"),
+    ('廚', "chú"),
+    ('廛', "chán"),
+    ('廜', "tú"),
+    ('廝', "sī"),
+    ('廞', "xīn"),
+    ('廟', "miào"),
+    ('廠', "chǎng"),
+    ('廡', "wǔ"),
+    ('廢', "fèi"),
+    ('廣', "guǎng"),
+    ('廤', "kù"),
+    ('廥', "kuài"),
+    ('廦', "bì"),
+    ('廧', "qiáng"),
+    ('廨', "xiè"),
+    ('廩', "lǐn"),
+    ('廪', "lǐn"),
+    ('廫', "liáo"),
+    ('廬', "lú"),
+    ('廭', "jì"),
+    ('廮', "yǐng"),
+    ('廯', "xiān"),
+    ('廰', "tīng"),
+    ('廱', "yōng"),
+    ('廲', "lí"),
+    ('廳', "tīng"),
+    ('廴', "yǐn"),
+    ('廵', "xún"),
+    ('延', "yán"),
+    ('廷', "tíng"),
+    ('廸', "dí"),
+    ('廹', "pǎi"),
+    ('建', "jiàn"),
+    ('廻', "huí"),
+    ('廼', "nǎi"),
+    ('廽', "huí"),
+    ('廾', "gǒng"),
+    ('廿', "niàn"),
+    ('开', "kāi"),
+    ('弁', "biàn"),
+    ('异', "yì"),
+    ('弃', "qì"),
+    ('弄', "nòng,lòng"),
+    ('弅', "fèn"),
+    ('弆', "jǔ"),
+    ('弇', "yǎn"),
+    ('弈', "yì"),
+    ('弉', "zàng"),
+    ('弊', "bì"),
+    ('弋', "yì"),
+    ('弌', "yī"),
+    ('弍', "èr"),
+    ('弎', "sān"),
+    ('式', "shì"),
+    ('弐', "èr"),
+    ('弑', "shì"),
+    ('弒', "shì"),
+    ('弓', "gōng"),
+    ('弔', "diào"),
+    ('引', "yǐn"),
+    ('弖', "hù"),
+    ('弗', "fú"),
+    ('弘', "hóng"),
+    ('弙', "wū"),
+    ('弚', "tuí"),
+    ('弛', "chí"),
+    ('弜', "jiàng"),
+    ('弝', "bà"),
+    ('弞', "shěn"),
+    ('弟', "dì"),
+    ('张', "zhāng"),
+    ('弡', "jué"),
+    ('弢', "tāo"),
+    ('弣', "fǔ"),
+    ('弤', "dǐ"),
+    ('弥', "mí"),
+    ('弦', "xián"),
+    ('弧', "hú"),
+    ('弨', "chāo"),
+    ('弩', "nǔ"),
+    ('弪', "jìng"),
+    ('弫', "zhěn"),
+    ('弬', "yí"),
+    ('弭', "mǐ"),
+    ('弮', "quān"),
+    ('弯', "wān"),
+    ('弰', "shāo"),
+    ('弱', "ruò"),
+    ('弲', "xuān"),
+    ('弳', "jìng"),
+    ('弴', "diāo"),
+    ('張', "zhāng"),
+    ('弶', "jiàng"),
+    ('強', "qiáng"),
+    ('弸', "péng"),
+    ('弹', "tán,dàn"),
+    ('强', "qiáng,qiǎng,jiàng"),
+    ('弻', "bì"),
+    ('弼', "bì"),
+    ('弽', "shè"),
+    ('弾', "dàn"),
+    ('弿', "jiǎn"),
+    ('彀', "gòu"),
+    ('彁', "gē"),
+    ('彂', "fā"),
+    ('彃', "bì"),
+    ('彄', "kōu"),
+    ('彅', "jiǎn"),
+    ('彆', "biè"),
+    ('彇', "xiāo"),
+    ('彈', "dàn"),
+    ('彉', "guō"),
+    ('彊', "jiàng"),
+    ('彋', "hóng"),
+    ('彌', "mí"),
+    ('彍', "guō"),
+    ('彎', "wān"),
+    ('彏', "jué"),
+    ('彐', "jì"),
+    ('彑', "jì"),
+    ('归', "guī"),
+    ('当', "dāng,dàng"),
+    ('彔', "lù"),
+    ('录', "lù"),
+    ('彖', "tuàn"),
+    ('彗', "huì"),
+    ('彘', "zhì"),
+    ('彙', "huì"),
+    ('彚', "huì"),
+    ('彛', "yí"),
+    ('彜', "yí"),
+    ('彝', "yí"),
+    ('彞', "yí"),
+    ('彟', "yuē"),
+    ('彠', "yuē"),
+    ('彡', "shān"),
+    ('形', "xíng"),
+    ('彣', "wén"),
+    ('彤', "tóng"),
+    ('彥', "yàn"),
+    ('彦', "yàn"),
+    ('彧', "yù"),
+    ('彨', "chī"),
+    ('彩', "cǎi"),
+    ('彪', "biāo"),
+    ('彫', "diāo"),
+    ('彬', "bīn"),
+    ('彭', "péng"),
+    ('彮', "yǒng"),
+    ('彯', "piāo"),
+    ('彰', "zhāng"),
+    ('影', "yǐng"),
+    ('彲', "chī"),
+    ('彳', "chì"),
+    ('彴', "zhuó"),
+    ('彵', "tuǒ"),
+    ('彶', "jí"),
+    ('彷', "fǎng"),
+    ('彸', "zhōng"),
+    ('役', "yì"),
+    ('彺', "wáng"),
+    ('彻', "chè"),
+    ('彼', "bǐ"),
+    ('彽', "dī"),
+    ('彾', "líng"),
+    ('彿', "fú"),
+    ('往', "wǎng"),
+    ('征', "zhēng"),
+    ('徂', "cú"),
+    ('徃', "wǎng"),
+    ('径', "jìng"),
+    ('待', "dài,dāi"),
+    ('徆', "xī"),
+    ('徇', "xùn"),
+    ('很', "hěn"),
+    ('徉', "yáng"),
+    ('徊', "huái"),
+    ('律', "lǜ"),
+    ('後', "hòu"),
+    ('徍', "wǎng"),
+    ('徎', "chěng"),
+    ('徏', "zhì"),
+    ('徐', "xú"),
+    ('徑', "jìng"),
+    ('徒', "tú"),
+    ('従', "cóng"),
+    ('徔', "zhi"),
+    ('徕', "lái"),
+    ('徖', "cóng"),
+    ('得', "dé,de,děi"),
+    ('徘', "pái"),
+    ('徙', "xǐ"),
+    ('徚', "dōng"),
+    ('徛', "jì"),
+    ('徜', "cháng"),
+    ('徝', "zhì"),
+    ('從', "cóng"),
+    ('徟', "zhōu"),
+    ('徠', "lái"),
+    ('御', "yù"),
+    ('徢', "xiè"),
+    ('徣', "jiè"),
+    ('徤', "jiàn"),
+    ('徥', "shì"),
+    ('徦', "jiǎ"),
+    ('徧', "biàn"),
+    ('徨', "huáng"),
+    ('復', "fù"),
+    ('循', "xún"),
+    ('徫', "wěi"),
+    ('徬', "páng"),
+    ('徭', "yáo"),
+    ('微', "wēi"),
+    ('徯', "xī"),
+    ('徰', "zhēng"),
+    ('徱', "piào"),
+    ('徲', "tí"),
+    ('徳', "dé"),
+    ('徴', "zhēng"),
+    ('徵', "zhǐ"),
+    ('徶', "bié"),
+    ('德', "dé"),
+    ('徸', "chōng"),
+    ('徹', "chè"),
+    ('徺', "jiǎo"),
+    ('徻', "huì"),
+    ('徼', "jiǎo"),
+    ('徽', "huī"),
+    ('徾', "méi"),
+    ('徿', "lòng"),
+    ('忀', "xiāng"),
+    ('忁', "bào"),
+    ('忂', "qú"),
+    ('心', "xīn"),
+    ('忄', "xin"),
+    ('必', "bì"),
+    ('忆', "yì"),
+    ('忇', "lè"),
+    ('忈', "rén"),
+    ('忉', "dāo"),
+    ('忊', "dìng"),
+    ('忋', "gǎi"),
+    ('忌', "jì"),
+    ('忍', "rěn"),
+    ('忎', "rén"),
+    ('忏', "chàn"),
+    ('忐', "tǎn"),
+    ('忑', "tè"),
+    ('忒', "tè"),
+    ('忓', "gān"),
+    ('忔', "qì"),
+    ('忕', "shì"),
+    ('忖', "cǔn"),
+    ('志', "zhì"),
+    ('忘', "wàng"),
+    ('忙', "máng"),
+    ('忚', "xī"),
+    ('忛', "fān"),
+    ('応', "yīng"),
+    ('忝', "tiǎn"),
+    ('忞', "mín"),
+    ('忟', "wěn"),
+    ('忠', "zhōng"),
+    ('忡', "chōng"),
+    ('忢', "wù"),
+    ('忣', "jí"),
+    ('忤', "wǔ"),
+    ('忥', "xì"),
+    ('忦', "jiá"),
+    ('忧', "yōu"),
+    ('忨', "wàn"),
+    ('忩', "cōng"),
+    ('忪', "sōng"),
+    ('快', "kuài"),
+    ('忬', "yù"),
+    ('忭', "biàn"),
+    ('忮', "zhì"),
+    ('忯', "qí"),
+    ('忰', "cuì"),
+    ('忱', "chén"),
+    ('忲', "tài"),
+    ('忳', "tún"),
+    ('忴', "qián"),
+    ('念', "niàn"),
+    ('忶', "hún"),
+    ('忷', "xiōng"),
+    ('忸', "niǔ"),
+    ('忹', "kuáng"),
+    ('忺', "xiān"),
+    ('忻', "xīn"),
+    ('忼', "kāng"),
+    ('忽', "hū"),
+    ('忾', "kài"),
+    ('忿', "fèn"),
+    ('怀', "huái"),
+    ('态', "tài"),
+    ('怂', "sǒng"),
+    ('怃', "wǔ"),
+    ('怄', "òu"),
+    ('怅', "chàng"),
+    ('怆', "chuàng"),
+    ('怇', "jù"),
+    ('怈', "yì"),
+    ('怉', "bǎo"),
+    ('怊', "chāo"),
+    ('怋', "mín"),
+    ('怌', "pēi"),
+    ('怍', "zuò"),
+    ('怎', "zěn"),
+    ('怏', "yàng"),
+    ('怐', "jù"),
+    ('怑', "bàn"),
+    ('怒', "nù"),
+    ('怓', "náo"),
+    ('怔', "zhēng"),
+    ('怕', "pà"),
+    ('怖', "bù"),
+    ('怗', "tiē"),
+    ('怘', "hù"),
+    ('怙', "hù"),
+    ('怚', "jù"),
+    ('怛', "dá"),
+    ('怜', "lián"),
+    ('思', "sī"),
+    ('怞', "chóu"),
+    ('怟', "dì"),
+    ('怠', "dài"),
+    ('怡', "yí"),
+    ('怢', "tū"),
+    ('怣', "yóu"),
+    ('怤', "fū"),
+    ('急', "jí"),
+    ('怦', "pēng"),
+    ('性', "xìng"),
+    ('怨', "yuàn"),
+    ('怩', "ní"),
+    ('怪', "guài"),
+    ('怫', "fú"),
+    ('怬', "xì"),
+    ('怭', "bì"),
+    ('怮', "yōu"),
+    ('怯', "qiè"),
+    ('怰', "xuàn"),
+    ('怱', "cōng"),
+    ('怲', "bǐng"),
+    ('怳', "huǎng"),
+    ('怴', "xù"),
+    ('怵', "chù"),
+    ('怶', "bì"),
+    ('怷', "shù"),
+    ('怸', "xī"),
+    ('怹', "tān"),
+    ('怺', "yǒng"),
+    ('总', "zǒng"),
+    ('怼', "duì"),
+    ('怽', "mo"),
+    ('怾', "zhǐ"),
+    ('怿', "yì"),
+    ('恀', "shì"),
+    ('恁', "nèn"),
+    ('恂', "xún"),
+    ('恃', "shì"),
+    ('恄', "xì"),
+    ('恅', "lǎo"),
+    ('恆', "héng"),
+    ('恇', "kuāng"),
+    ('恈', "móu"),
+    ('恉', "zhǐ"),
+    ('恊', "xié"),
+    ('恋', "liàn"),
+    ('恌', "tiāo"),
+    ('恍', "huǎng"),
+    ('恎', "dié"),
+    ('恏', "hào"),
+    ('恐', "kǒng"),
+    ('恑', "guǐ"),
+    ('恒', "héng"),
+    ('恓', "xī"),
+    ('恔', "jiǎo"),
+    ('恕', "shù"),
+    ('恖', "sī"),
+    ('恗', "hū"),
+    ('恘', "qiū"),
+    ('恙', "yàng"),
+    ('恚', "huì"),
+    ('恛', "huí"),
+    ('恜', "chì"),
+    ('恝', "jiá"),
+    ('恞', "yí"),
+    ('恟', "xiōng"),
+    ('恠', "guài"),
+    ('恡', "lìn"),
+    ('恢', "huī"),
+    ('恣', "zì"),
+    ('恤', "xù"),
+    ('恥', "chǐ"),
+    ('恦', "shàng"),
+    ('恧', "nǜ"),
+    ('恨', "hèn"),
+    ('恩', "ēn"),
+    ('恪', "kè"),
+    ('恫', "dòng"),
+    ('恬', "tián"),
+    ('恭', "gōng"),
+    ('恮', "quān"),
+    ('息', "xī"),
+    ('恰', "qià"),
+    ('恱', "yuè"),
+    ('恲', "pēng"),
+    ('恳', "kěn"),
+    ('恴', "dé"),
+    ('恵', "huì"),
+    ('恶', "è,wù,ě,wū"),
+    ('恷', "xiao"),
+    ('恸', "tòng"),
+    ('恹', "yān"),
+    ('恺', "kǎi"),
+    ('恻', "cè"),
+    ('恼', "nǎo"),
+    ('恽', "yùn"),
+    ('恾', "máng"),
+    ('恿', "yǒng"),
+    ('悀', "yǒng"),
+    ('悁', "yuān"),
+    ('悂', "pī"),
+    ('悃', "kǔn"),
+    ('悄', "qiāo,qiǎo"),
+    ('悅', "yuè"),
+    ('悆', "yù"),
+    ('悇', "tú"),
+    ('悈', "jiè"),
+    ('悉', "xī"),
+    ('悊', "zhé"),
+    ('悋', "lìn"),
+    ('悌', "tì"),
+    ('悍', "hàn"),
+    ('悎', "hào"),
+    ('悏', "qiè"),
+    ('悐', "tì"),
+    ('悑', "bù"),
+    ('悒', "yì"),
+    ('悓', "qiàn"),
+    ('悔', "huǐ"),
+    ('悕', "xī"),
+    ('悖', "bèi"),
+    ('悗', "mán"),
+    ('悘', "yī"),
+    ('悙', "hēng"),
+    ('悚', "sǒng"),
+    ('悛', "quān"),
+    ('悜', "chěng"),
+    ('悝', "kuī"),
+    ('悞', "wù"),
+    ('悟', "wù"),
+    ('悠', "yōu"),
+    ('悡', "lí"),
+    ('悢', "liàng"),
+    ('患', "huàn"),
+    ('悤', "cōng"),
+    ('悥', "yì"),
+    ('悦', "yuè"),
+    ('悧', "lì"),
+    ('您', "nín"),
+    ('悩', "nǎo"),
+    ('悪', "è"),
+    ('悫', "què"),
+    ('悬', "xuán"),
+    ('悭', "qiān"),
+    ('悮', "wù"),
+    ('悯', "mǐn"),
+    ('悰', "cóng"),
+    ('悱', "fěi"),
+    ('悲', "bēi"),
+    ('悳', "dé"),
+    ('悴', "cuì"),
+    ('悵', "chàng"),
+    ('悶', "mèn"),
+    ('悷', "lì"),
+    ('悸', "jì"),
+    ('悹', "guàn"),
+    ('悺', "guàn"),
+    ('悻', "xìng"),
+    ('悼', "dào"),
+    ('悽', "qī"),
+    ('悾', "kōng"),
+    ('悿', "tiǎn"),
+    ('惀', "lún"),
+    ('惁', "xī"),
+    ('惂', "kǎn"),
+    ('惃', "gǔn"),
+    ('惄', "nì"),
+    ('情', "qíng"),
+    ('惆', "chóu"),
+    ('惇', "dūn"),
+    ('惈', "guǒ"),
+    ('惉', "zhān"),
+    ('惊', "jīng"),
+    ('惋', "wǎn"),
+    ('惌', "yuān"),
+    ('惍', "jīn"),
+    ('惎', "jì"),
+    ('惏', "lán"),
+    ('惐', "yù"),
+    ('惑', "huò"),
+    ('惒', "hé"),
+    ('惓', "quán"),
+    ('惔', "tán"),
+    ('惕', "tì"),
+    ('惖', "tì"),
+    ('惗', "niè"),
+    ('惘', "wǎng"),
+    ('惙', "chuò"),
+    ('惚', "hū"),
+    ('惛', "hūn"),
+    ('惜', "xī"),
+    ('惝', "chǎng"),
+    ('惞', "xīn"),
+    ('惟', "wéi"),
+    ('惠', "huì"),
+    ('惡', "è"),
+    ('惢', "suǒ"),
+    ('惣', "zǒng"),
+    ('惤', "jiān"),
+    ('惥', "yǒng"),
+    ('惦', "diàn"),
+    ('惧', "jù"),
+    ('惨', "cǎn"),
+    ('惩', "chéng"),
+    ('惪', "dé"),
+    ('惫', "bèi"),
+    ('惬', "qiè"),
+    ('惭', "cán"),
+    ('惮', "dàn"),
+    ('惯', "guàn"),
+    ('惰', "duò"),
+    ('惱', "nǎo"),
+    ('惲', "yùn"),
+    ('想', "xiǎng"),
+    ('惴', "zhuì"),
+    ('惵', "dié"),
+    ('惶', "huáng"),
+    ('惷', "chǔn"),
+    ('惸', "qióng"),
+    ('惹', "rě"),
+    ('惺', "xīng"),
+    ('惻', "cè"),
+    ('惼', "biǎn"),
+    ('惽', "mǐn"),
+    ('惾', "zōng"),
+    ('惿', "tí"),
+    ('愀', "qiǎo"),
+    ('愁', "chóu"),
+    ('愂', "bèi"),
+    ('愃', "xuān"),
+    ('愄', "wēi"),
+    ('愅', "gé"),
+    ('愆', "qiān"),
+    ('愇', "wěi"),
+    ('愈', "yù"),
+    ('愉', "yú"),
+    ('愊', "bì"),
+    ('愋', "xuān"),
+    ('愌', "huàn"),
+    ('愍', "mǐn"),
+    ('愎', "bì"),
+    ('意', "yì"),
+    ('愐', "miǎn"),
+    ('愑', "yǒng"),
+    ('愒', "kài"),
+    ('愓', "dàng"),
+    ('愔', "yīn"),
+    ('愕', "è"),
+    ('愖', "chén"),
+    ('愗', "mào"),
+    ('愘', "qià"),
+    ('愙', "kè"),
+    ('愚', "yú"),
+    ('愛', "ài"),
+    ('愜', "qiè"),
+    ('愝', "yǎn"),
+    ('愞', "nuò"),
+    ('感', "gǎn"),
+    ('愠', "yùn"),
+    ('愡', "zǒng"),
+    ('愢', "sāi"),
+    ('愣', "lèng"),
+    ('愤', "fèn"),
+    ('愥', "yīng"),
+    ('愦', "kuì"),
+    ('愧', "kuì"),
+    ('愨', "què"),
+    ('愩', "gōng"),
+    ('愪', "yún"),
+    ('愫', "sù"),
+    ('愬', "sù"),
+    ('愭', "qí"),
+    ('愮', "yáo"),
+    ('愯', "sǒng"),
+    ('愰', "huàng"),
+    ('愱', "jí"),
+    ('愲', "gǔ"),
+    ('愳', "jù"),
+    ('愴', "chuàng"),
+    ('愵', "nì"),
+    ('愶', "xié"),
+    ('愷', "kǎi"),
+    ('愸', "zhěng"),
+    ('愹', "yǒng"),
+    ('愺', "cǎo"),
+    ('愻', "xùn"),
+    ('愼', "shèn"),
+    ('愽', "bó"),
+    ('愾', "kài"),
+    ('愿', "yuàn"),
+    ('慀', "xì"),
+    ('慁', "hùn"),
+    ('慂', "yǒng"),
+    ('慃', "yǎng"),
+    ('慄', "lì"),
+    ('慅', "sāo"),
+    ('慆', "tāo"),
+    ('慇', "yīn"),
+    ('慈', "cí"),
+    ('慉', "xù"),
+    ('慊', "qiàn"),
+    ('態', "tài"),
+    ('慌', "huāng"),
+    ('慍', "yùn"),
+    ('慎', "shèn"),
+    ('慏', "mǐng"),
+    ('慐', "gong"),
+    ('慑', "shè"),
+    ('慒', "cóng"),
+    ('慓', "piāo"),
+    ('慔', "mù"),
+    ('慕', "mù"),
+    ('慖', "guó"),
+    ('慗', "chì"),
+    ('慘', "cǎn"),
+    ('慙', "cán"),
+    ('慚', "cán"),
+    ('慛', "cuī"),
+    ('慜', "mǐn"),
+    ('慝', "tè"),
+    ('慞', "zhāng"),
+    ('慟', "tòng"),
+    ('慠', "ào"),
+    ('慡', "shuǎng"),
+    ('慢', "màn"),
+    ('慣', "guàn"),
+    ('慤', "què"),
+    ('慥', "zào"),
+    ('慦', "jiù"),
+    ('慧', "huì"),
+    ('慨', "kǎi"),
+    ('慩', "lián"),
+    ('慪', "òu"),
+    ('慫', "sǒng"),
+    ('慬', "qín"),
+    ('慭', "yìn"),
+    ('慮', "lǜ"),
+    ('慯', "shāng"),
+    ('慰', "wèi"),
+    ('慱', "tuán"),
+    ('慲', "mán"),
+    ('慳', "qiān"),
+    ('慴', "shè"),
+    ('慵', "yōng"),
+    ('慶', "qìng"),
+    ('慷', "kāng"),
+    ('慸', "dì"),
+    ('慹', "zhí"),
+    ('慺', "lóu"),
+    ('慻', "juàn"),
+    ('慼', "qī"),
+    ('慽', "qī"),
+    ('慾', "yù"),
+    ('慿', "píng"),
+    ('憀', "liáo"),
+    ('憁', "còng"),
+    ('憂', "yōu"),
+    ('憃', "chōng"),
+    ('憄', "zhì"),
+    ('憅', "tòng"),
+    ('憆', "chēng"),
+    ('憇', "qì"),
+    ('憈', "qū"),
+    ('憉', "péng"),
+    ('憊', "bèi"),
+    ('憋', "biē"),
+    ('憌', "qióng"),
+    ('憍', "jiāo"),
+    ('憎', "zēng"),
+    ('憏', "chì"),
+    ('憐', "lián"),
+    ('憑', "píng"),
+    ('憒', "kuì"),
+    ('憓', "huì"),
+    ('憔', "qiáo"),
+    ('憕', "chéng"),
+    ('憖', "yìn"),
+    ('憗', "yìn"),
+    ('憘', "xǐ"),
+    ('憙', "xī"),
+    ('憚', "dàn"),
+    ('憛', "tán"),
+    ('憜', "duò"),
+    ('憝', "duì"),
+    ('憞', "duì"),
+    ('憟', "sù"),
+    ('憠', "jué"),
+    ('憡', "cè"),
+    ('憢', "xiāo"),
+    ('憣', "fān"),
+    ('憤', "fèn"),
+    ('憥', "láo"),
+    ('憦', "lào"),
+    ('憧', "chōng"),
+    ('憨', "hān"),
+    ('憩', "qì"),
+    ('憪', "xián"),
+    ('憫', "mǐn"),
+    ('憬', "jǐng"),
+    ('憭', "liǎo"),
+    ('憮', "wǔ"),
+    ('憯', "cǎn"),
+    ('憰', "jué"),
+    ('憱', "cù"),
+    ('憲', "xiàn"),
+    ('憳', "tǎn"),
+    ('憴', "shéng"),
+    ('憵', "pī"),
+    ('憶', "yì"),
+    ('憷', "chù"),
+    ('憸', "xiān"),
+    ('憹', "náo"),
+    ('憺', "dàn"),
+    ('憻', "tǎn"),
+    ('憼', "jǐng"),
+    ('憽', "sōng"),
+    ('憾', "hàn"),
+    ('憿', "jiǎo"),
+    ('懀', "wèi"),
+    ('懁', "xuān"),
+    ('懂', "dǒng"),
+    ('懃', "qín"),
+    ('懄', "qín"),
+    ('懅', "jù"),
+    ('懆', "cǎo"),
+    ('懇', "kěn"),
+    ('懈', "xiè"),
+    ('應', "yīng"),
+    ('懊', "ào"),
+    ('懋', "mào"),
+    ('懌', "yì"),
+    ('懍', "lǐn"),
+    ('懎', "sè"),
+    ('懏', "jùn"),
+    ('懐', "huái"),
+    ('懑', "mèn"),
+    ('懒', "lǎn"),
+    ('懓', "ài"),
+    ('懔', "lǐn"),
+    ('懕', "yān"),
+    ('懖', "kuò"),
+    ('懗', "xià"),
+    ('懘', "chì"),
+    ('懙', "yǔ"),
+    ('懚', "yìn"),
+    ('懛', "dāi"),
+    ('懜', "měng"),
+    ('懝', "ài"),
+    ('懞', "méng"),
+    ('懟', "duì"),
+    ('懠', "qí"),
+    ('懡', "mǒ"),
+    ('懢', "lán"),
+    ('懣', "mèn"),
+    ('懤', "chóu"),
+    ('懥', "zhì"),
+    ('懦', "nuò"),
+    ('懧', "nuò"),
+    ('懨', "yān"),
+    ('懩', "yǎng"),
+    ('懪', "bó"),
+    ('懫', "zhì"),
+    ('懬', "kuàng"),
+    ('懭', "kuǎng"),
+    ('懮', "yǒu"),
+    ('懯', "fū"),
+    ('懰', "liú"),
+    ('懱', "miè"),
+    ('懲', "chéng"),
+    ('懳', "hui"),
+    ('懴', "chàn"),
+    ('懵', "měng"),
+    ('懶', "lǎn"),
+    ('懷', "huái"),
+    ('懸', "xuán"),
+    ('懹', "ràng"),
+    ('懺', "chàn"),
+    ('懻', "jì"),
+    ('懼', "jù"),
+    ('懽', "huān"),
+    ('懾', "shè"),
+    ('懿', "yì"),
+    ('戀', "liàn"),
+    ('戁', "nǎn"),
+    ('戂', "mí"),
+    ('戃', "tǎng"),
+    ('戄', "jué"),
+    ('戅', "gàng"),
+    ('戆', "gàng"),
+    ('戇', "zhuàng"),
+    ('戈', "gē"),
+    ('戉', "yuè"),
+    ('戊', "wù"),
+    ('戋', "jiān"),
+    ('戌', "xū"),
+    ('戍', "shù"),
+    ('戎', "róng"),
+    ('戏', "xì"),
+    ('成', "chéng"),
+    ('我', "wǒ"),
+    ('戒', "jiè"),
+    ('戓', "gē"),
+    ('戔', "jiān"),
+    ('戕', "qiāng"),
+    ('或', "huò"),
+    ('戗', "qiāng"),
+    ('战', "zhàn"),
+    ('戙', "dòng"),
+    ('戚', "qī"),
+    ('戛', "jiá"),
+    ('戜', "dié"),
+    ('戝', "zéi"),
+    ('戞', "jiá"),
+    ('戟', "jǐ"),
+    ('戠', "zhī"),
+    ('戡', "kān"),
+    ('戢', "jí"),
+    ('戣', "kuí"),
+    ('戤', "gài"),
+    ('戥', "děng"),
+    ('戦', "zhàn"),
+    ('戧', "qiāng"),
+    ('戨', "gē"),
+    ('戩', "jiǎn"),
+    ('截', "jié"),
+    ('戫', "yù"),
+    ('戬', "jiǎn"),
+    ('戭', "yǎn"),
+    ('戮', "lù"),
+    ('戯', "hū"),
+    ('戰', "zhàn"),
+    ('戱', "xì"),
+    ('戲', "xì"),
+    ('戳', "chuō"),
+    ('戴', "dài"),
+    ('戵', "qú"),
+    ('戶', "hù"),
+    ('户', "hù"),
+    ('戸', "hù"),
+    ('戹', "è"),
+    ('戺', "shì"),
+    ('戻', "tì"),
+    ('戼', "mǎo"),
+    ('戽', "hù"),
+    ('戾', "lì"),
+    ('房', "fáng"),
+    ('所', "suǒ"),
+    ('扁', "biǎn,piān"),
+    ('扂', "diàn"),
+    ('扃', "jiōng"),
+    ('扄', "shǎng"),
+    ('扅', "yí"),
+    ('扆', "yǐ"),
+    ('扇', "shàn,shān"),
+    ('扈', "hù"),
+    ('扉', "fēi"),
+    ('扊', "yǎn"),
+    ('手', "shǒu"),
+    ('扌', "shou"),
+    ('才', "cái"),
+    ('扎', "zhā,zā,zhá"),
+    ('扏', "qiú"),
+    ('扐', "lè"),
+    ('扑', "pū"),
+    ('扒', "bā"),
+    ('打', "dǎ,dá"),
+    ('扔', "rēng"),
+    ('払', "fǎn"),
+    ('扖', "rù"),
+    ('扗', "zài"),
+    ('托', "tuō"),
+    ('扙', "zhàng"),
+    ('扚', "diǎo"),
+    ('扛', "káng"),
+    ('扜', "yū"),
+    ('扝', "kū"),
+    ('扞', "gǎn"),
+    ('扟', "shēn"),
+    ('扠', "chā"),
+    ('扡', "tuō"),
+    ('扢', "gǔ"),
+    ('扣', "kòu"),
+    ('扤', "wù"),
+    ('扥', "dèn"),
+    ('扦', "qiān"),
+    ('执', "zhí"),
+    ('扨', "rèn"),
+    ('扩', "kuò"),
+    ('扪', "mén"),
+    ('扫', "sǎo,sào"),
+    ('扬', "yáng"),
+    ('扭', "niǔ"),
+    ('扮', "bàn"),
+    ('扯', "chě"),
+    ('扰', "rǎo"),
+    ('扱', "xī"),
+    ('扲', "qián"),
+    ('扳', "bān"),
+    ('扴', "jiá"),
+    ('扵', "yú"),
+    ('扶', "fú"),
+    ('扷', "ào"),
+    ('扸', "xī"),
+    ('批', "pī"),
+    ('扺', "zhǐ"),
+    ('扻', "zhì"),
+    ('扼', "è"),
+    ('扽', "dèn"),
+    ('找', "zhǎo"),
+    ('承', "chéng"),
+    ('技', "jì"),
+    ('抁', "yǎn"),
+    ('抂', "kuáng"),
+    ('抃', "biàn"),
+    ('抄', "chāo"),
+    ('抅', "jū"),
+    ('抆', "wěn"),
+    ('抇', "hú"),
+    ('抈', "yuè"),
+    ('抉', "jué"),
+    ('把', "bǎ,bà"),
+    ('抋', "qìn"),
+    ('抌', "dǎn"),
+    ('抍', "zhěng"),
+    ('抎', "yǔn"),
+    ('抏', "wán"),
+    ('抐', "nè"),
+    ('抑', "yì"),
+    ('抒', "shū"),
+    ('抓', "zhuā"),
+    ('抔', "póu"),
+    ('投', "tóu"),
+    ('抖', "dǒu"),
+    ('抗', "kàng"),
+    ('折', "zhé,shé,zhē"),
+    ('抙', "póu"),
+    ('抚', "fǔ"),
+    ('抛', "pāo"),
+    ('抜', "bá"),
+    ('抝', "ǎo"),
+    ('択', "zé"),
+    ('抟', "tuán"),
+    ('抠', "kōu"),
+    ('抡', "lūn"),
+    ('抢', "qiǎng"),
+    ('抣', "yun"),
+    ('护', "hù"),
+    ('报', "bào"),
+    ('抦', "bǐng"),
+    ('抧', "zhǐ"),
+    ('抨', "pēng"),
+    ('抩', "nán"),
+    ('抪', "bù"),
+    ('披', "pī"),
+    ('抬', "tái"),
+    ('抭', "yǎo"),
+    ('抮', "zhěn"),
+    ('抯', "zhā"),
+    ('抰', "yāng"),
+    ('抱', "bào"),
+    ('抲', "hē"),
+    ('抳', "nǐ"),
+    ('抴', "yè"),
+    ('抵', "dǐ"),
+    ('抶', "chì"),
+    ('抷', "pī"),
+    ('抸', "jiā"),
+    ('抹', "mǒ,mò,mā"),
+    ('抺', "mèi"),
+    ('抻', "chēn"),
+    ('押', "yā"),
+    ('抽', "chōu"),
+    ('抾', "qū"),
+    ('抿', "mǐn"),
+    ('拀', "chù"),
+    ('拁', "jiā"),
+    ('拂', "fú"),
+    ('拃', "zhǎ"),
+    ('拄', "zhǔ"),
+    ('担', "dān,dàn"),
+    ('拆', "chāi"),
+    ('拇', "mǔ"),
+    ('拈', "niān"),
+    ('拉', "lā,lá"),
+    ('拊', "fǔ"),
+    ('拋', "pāo"),
+    ('拌', "bàn"),
+    ('拍', "pāi"),
+    ('拎', "līn"),
+    ('拏', "ná"),
+    ('拐', "guǎi"),
+    ('拑', "qián"),
+    ('拒', "jù"),
+    ('拓', "tuò,tà"),
+    ('拔', "bá"),
+    ('拕', "tuō"),
+    ('拖', "tuō"),
+    ('拗', "ào,niù,ǎo"),
+    ('拘', "jū"),
+    ('拙', "zhuō"),
+    ('拚', "pàn"),
+    ('招', "zhāo"),
+    ('拜', "bài"),
+    ('拝', "bài"),
+    ('拞', "dǐ"),
+    ('拟', "nǐ"),
+    ('拠', "jù"),
+    ('拡', "kuò"),
+    ('拢', "lǒng"),
+    ('拣', "jiǎn"),
+    ('拤', "qiá"),
+    ('拥', "yōng"),
+    ('拦', "lán"),
+    ('拧', "níng"),
+    ('拨', "bō"),
+    ('择', "zé,zhái"),
+    ('拪', "qiān"),
+    ('拫', "hén"),
+    ('括', "kuò,guā"),
+    ('拭', "shì"),
+    ('拮', "jié"),
+    ('拯', "zhěng"),
+    ('拰', "nǐn"),
+    ('拱', "gǒng"),
+    ('拲', "gǒng"),
+    ('拳', "quán"),
+    ('拴', "shuān"),
+    ('拵', "cún"),
+    ('拶', "zā"),
+    ('拷', "kǎo"),
+    ('拸', "yí"),
+    ('拹', "xié"),
+    ('拺', "cè"),
+    ('拻', "huī"),
+    ('拼', "pīn"),
+    ('拽', "zhuāi"),
+    ('拾', "shí,shè"),
+    ('拿', "ná"),
+    ('挀', "bāi"),
+    ('持', "chí"),
+    ('挂', "guà"),
+    ('挃', "zhì"),
+    ('挄', "kuò"),
+    ('挅', "duǒ"),
+    ('挆', "duǒ"),
+    ('指', "zhǐ"),
+    ('挈', "qiè"),
+    ('按', "àn"),
+    ('挊', "nòng"),
+    ('挋', "zhèn"),
+    ('挌', "gé"),
+    ('挍', "jiào"),
+    ('挎', "kuà"),
+    ('挏', "dòng"),
+    ('挐', "ná"),
+    ('挑', "tiāo,tiǎo"),
+    ('挒', "liè"),
+    ('挓', "zhā"),
+    ('挔', "lǚ"),
+    ('挕', "dié"),
+    ('挖', "wā"),
+    ('挗', "jué"),
+    ('挘', "liě"),
+    ('挙', "jǔ"),
+    ('挚', "zhì"),
+    ('挛', "luán"),
+    ('挜', "yà"),
+    ('挝', "wō"),
+    ('挞', "tà"),
+    ('挟', "xié"),
+    ('挠', "náo"),
+    ('挡', "dǎng"),
+    ('挢', "jiǎo"),
+    ('挣', "zhèng,zhēng"),
+    ('挤', "jǐ"),
+    ('挥', "huī"),
+    ('挦', "xián"),
+    ('挧', "yǔ"),
+    ('挨', "āi"),
+    ('挩', "tuō"),
+    ('挪', "nuó"),
+    ('挫', "cuò"),
+    ('挬', "bó"),
+    ('挭', "gěng"),
+    ('挮', "tǐ"),
+    ('振', "zhèn"),
+    ('挰', "chéng"),
+    ('挱', "sā"),
+    ('挲', "sā"),
+    ('挳', "kēng"),
+    ('挴', "měi"),
+    ('挵', "nòng"),
+    ('挶', "jū"),
+    ('挷', "péng"),
+    ('挸', "jiǎn"),
+    ('挹', "yì"),
+    ('挺', "tǐng"),
+    ('挻', "shān"),
+    ('挼', "ruá"),
+    ('挽', "wǎn"),
+    ('挾', "xié"),
+    ('挿', "chā"),
+    ('捀', "féng"),
+    ('捁', "jiǎo"),
+    ('捂', "wǔ"),
+    ('捃', "jùn"),
+    ('捄', "jiù"),
+    ('捅', "tǒng"),
+    ('捆', "kǔn"),
+    ('捇', "huò"),
+    ('捈', "tú"),
+    ('捉', "zhuō"),
+    ('捊', "póu"),
+    ('捋', "lǚ"),
+    ('捌', "bā"),
+    ('捍', "hàn"),
+    ('捎', "shāo"),
+    ('捏', "niē"),
+    ('捐', "juān"),
+    ('捑', "zè"),
+    ('捒', "shù"),
+    ('捓', "yé"),
+    ('捔', "jué"),
+    ('捕', "bǔ"),
+    ('捖', "wán"),
+    ('捗', "bù"),
+    ('捘', "zùn"),
+    ('捙', "yè"),
+    ('捚', "zhāi"),
+    ('捛', "lǚ"),
+    ('捜', "sōu"),
+    ('捝', "tuō"),
+    ('捞', "lāo"),
+    ('损', "sǔn"),
+    ('捠', "bāng"),
+    ('捡', "jiǎn"),
+    ('换', "huàn"),
+    ('捣', "dǎo"),
+    ('捤', "wěi"),
+    ('捥', "wàn"),
+    ('捦', "qín"),
+    ('捧', "pěng"),
+    ('捨', "shě"),
+    ('捩', "liè"),
+    ('捪', "mín"),
+    ('捫', "mén"),
+    ('捬', "fǔ"),
+    ('捭', "bǎi"),
+    ('据', "jù,jū"),
+    ('捯', "dáo"),
+    ('捰', "wǒ"),
+    ('捱', "ái"),
+    ('捲', "juǎn"),
+    ('捳', "yuè"),
+    ('捴', "zǒng"),
+    ('捵', "chēn"),
+    ('捶', "chuí"),
+    ('捷', "jié"),
+    ('捸', "tū"),
+    ('捹', "bèn"),
+    ('捺', "nà"),
+    ('捻', "niǎn"),
+    ('捼', "ruó"),
+    ('捽', "zuó"),
+    ('捾', "wò"),
+    ('捿', "qī"),
+    ('掀', "xiān"),
+    ('掁', "chéng"),
+    ('掂', "diān"),
+    ('掃', "sǎo"),
+    ('掄', "lūn"),
+    ('掅', "qìng"),
+    ('掆', "gāng"),
+    ('掇', "duō"),
+    ('授', "shòu"),
+    ('掉', "diào"),
+    ('掊', "póu"),
+    ('掋', "dǐ"),
+    ('掌', "zhǎng"),
+    ('掍', "hùn"),
+    ('掎', "jǐ"),
+    ('掏', "tāo"),
+    ('掐', "qiā"),
+    ('掑', "qí"),
+    ('排', "pái,pǎi"),
+    ('掓', "shū"),
+    ('掔', "qiān"),
+    ('掕', "líng"),
+    ('掖', "yē"),
+    ('掗', "yà"),
+    ('掘', "jué"),
+    ('掙', "zhēng"),
+    ('掚', "liǎng"),
+    ('掛', "guà"),
+    ('掜', "yì"),
+    ('掝', "huò"),
+    ('掞', "shàn"),
+    ('掟', "zhěng"),
+    ('掠', "lüè"),
+    ('採', "cǎi"),
+    ('探', "tàn"),
+    ('掣', "chè"),
+    ('掤', "bīng"),
+    ('接', "jiē"),
+    ('掦', "tì"),
+    ('控', "kòng"),
+    ('推', "tuī"),
+    ('掩', "yǎn"),
+    ('措', "cuò"),
+    ('掫', "zhōu"),
+    ('掬', "jū"),
+    ('掭', "tiàn"),
+    ('掮', "qián"),
+    ('掯', "kèn"),
+    ('掰', "bāi"),
+    ('掱', "pá"),
+    ('掲', "jiē"),
+    ('掳', "lǔ"),
+    ('掴', "guāi"),
+    ('掵', "ming"),
+    ('掶', "jié"),
+    ('掷', "zhì"),
+    ('掸', "dǎn"),
+    ('掹', "meng"),
+    ('掺', "càn"),
+    ('掻', "sāo"),
+    ('掼', "guàn"),
+    ('掽', "pèng"),
+    ('掾', "yuàn"),
+    ('掿', "nuò"),
+    ('揀', "jiǎn"),
+    ('揁', "zhēng"),
+    ('揂', "jiū"),
+    ('揃', "jiǎn"),
+    ('揄', "yú"),
+    ('揅', "yán"),
+    ('揆', "kuí"),
+    ('揇', "nǎn"),
+    ('揈', "hōng"),
+    ('揉', "róu"),
+    ('揊', "pì"),
+    ('揋', "wēi"),
+    ('揌', "sāi"),
+    ('揍', "zòu"),
+    ('揎', "xuān"),
+    ('描', "miáo"),
+    ('提', "tí,dī"),
+    ('揑', "niē"),
+    ('插', "chā"),
+    ('揓', "shì"),
+    ('揔', "zǒng"),
+    ('揕', "zhèn"),
+    ('揖', "yī"),
+    ('揗', "xún"),
+    ('揘', "yóng"),
+    ('揙', "biān"),
+    ('揚', "yáng"),
+    ('換', "huàn"),
+    ('揜', "yǎn"),
+    ('揝', "zǎn"),
+    ('揞', "ǎn"),
+    ('揟', "xū"),
+    ('揠', "yà"),
+    ('握', "wò"),
+    ('揢', "ké"),
+    ('揣', "chuāi"),
+    ('揤', "jí"),
+    ('揥', "tì"),
+    ('揦', "lá"),
+    ('揧', "là"),
+    ('揨', "chén"),
+    ('揩', "kāi"),
+    ('揪', "jiū"),
+    ('揫', "jiū"),
+    ('揬', "tú"),
+    ('揭', "jiē"),
+    ('揮', "huī"),
+    ('揯', "gèn"),
+    ('揰', "chòng"),
+    ('揱', "xiāo"),
+    ('揲', "dié"),
+    ('揳', "xiē"),
+    ('援', "yuán"),
+    ('揵', "qián"),
+    ('揶', "yé"),
+    ('揷', "chā"),
+    ('揸', "zhā"),
+    ('揹', "bēi"),
+    ('揺', "yáo"),
+    ('揻', "wēi"),
+    ('揼', "beng"),
+    ('揽', "lǎn"),
+    ('揾', "wèn"),
+    ('揿', "qìn"),
+    ('搀', "chān"),
+    ('搁', "gē,gé"),
+    ('搂', "lǒu"),
+    ('搃', "zǒng"),
+    ('搄', "gèn"),
+    ('搅', "jiǎo"),
+    ('搆', "gòu"),
+    ('搇', "qìn"),
+    ('搈', "róng"),
+    ('搉', "què"),
+    ('搊', "chōu"),
+    ('搋', "chuāi"),
+    ('搌', "zhǎn"),
+    ('損', "sǔn"),
+    ('搎', "sūn"),
+    ('搏', "bó"),
+    ('搐', "chù"),
+    ('搑', "róng"),
+    ('搒', "bàng"),
+    ('搓', "cuō"),
+    ('搔', "sāo"),
+    ('搕', "kē"),
+    ('搖', "yáo"),
+    ('搗', "dǎo"),
+    ('搘', "zhī"),
+    ('搙', "nù"),
+    ('搚', "lā"),
+    ('搛', "jiān"),
+    ('搜', "sōu"),
+    ('搝', "qiǔ"),
+    ('搞', "gǎo"),
+    ('搟', "xiǎn"),
+    ('搠', "shuò"),
+    ('搡', "sǎng"),
+    ('搢', "jìn"),
+    ('搣', "miè"),
+    ('搤', "è"),
+    ('搥', "chuí"),
+    ('搦', "nuò"),
+    ('搧', "shān"),
+    ('搨', "tà"),
+    ('搩', "zhǎ"),
+    ('搪', "táng"),
+    ('搫', "pán"),
+    ('搬', "bān"),
+    ('搭', "dā"),
+    ('搮', "lì"),
+    ('搯', "tāo"),
+    ('搰', "hú"),
+    ('搱', "zhì"),
+    ('搲', "wā"),
+    ('搳', "huá"),
+    ('搴', "qiān"),
+    ('搵', "wèn"),
+    ('搶', "qiǎng"),
+    ('搷', "tián"),
+    ('搸', "zhēn"),
+    ('搹', "è"),
+    ('携', "xié"),
+    ('搻', "nuò"),
+    ('搼', "quán"),
+    ('搽', "chá"),
+    ('搾', "zhà"),
+    ('搿', "gé"),
+    ('摀', "wǔ"),
+    ('摁', "èn"),
+    ('摂', "shè"),
+    ('摃', "káng"),
+    ('摄', "shè"),
+    ('摅', "shū"),
+    ('摆', "bǎi"),
+    ('摇', "yáo"),
+    ('摈', "bìn"),
+    ('摉', "sōu"),
+    ('摊', "tān"),
+    ('摋', "sà"),
+    ('摌', "chǎn"),
+    ('摍', "suō"),
+    ('摎', "jiū"),
+    ('摏', "chōng"),
+    ('摐', "chuāng"),
+    ('摑', "guāi"),
+    ('摒', "bǐng"),
+    ('摓', "féng"),
+    ('摔', "shuāi"),
+    ('摕', "dì"),
+    ('摖', "qì"),
+    ('摗', "sōu"),
+    ('摘', "zhāi"),
+    ('摙', "liǎn"),
+    ('摚', "chēng"),
+    ('摛', "chī"),
+    ('摜', "guàn"),
+    ('摝', "lù"),
+    ('摞', "luò"),
+    ('摟', "lǒu"),
+    ('摠', "zǒng"),
+    ('摡', "gài"),
+    ('摢', "hù"),
+    ('摣', "zhā"),
+    ('摤', "chuǎng"),
+    ('摥', "tàng"),
+    ('摦', "huà"),
+    ('摧', "cuī"),
+    ('摨', "nái"),
+    ('摩', "mó,mā"),
+    ('摪', "jiāng"),
+    ('摫', "guī"),
+    ('摬', "yǐng"),
+    ('摭', "zhí"),
+    ('摮', "áo"),
+    ('摯', "zhì"),
+    ('摰', "niè"),
+    ('摱', "màn"),
+    ('摲', "chàn"),
+    ('摳', "kōu"),
+    ('摴', "chū"),
+    ('摵', "shè"),
+    ('摶', "tuán"),
+    ('摷', "jiǎo"),
+    ('摸', "mō"),
+    ('摹', "mó"),
+    ('摺', "zhé"),
+    ('摻', "càn"),
+    ('摼', "kēng"),
+    ('摽', "biāo"),
+    ('摾', "jiàng"),
+    ('摿', "yáo"),
+    ('撀', "gòu"),
+    ('撁', "qiān"),
+    ('撂', "liào"),
+    ('撃', "jī"),
+    ('撄', "yīng"),
+    ('撅', "juē"),
+    ('撆', "piē"),
+    ('撇', "piē"),
+    ('撈', "lāo"),
+    ('撉', "dūn"),
+    ('撊', "xiàn"),
+    ('撋', "ruán"),
+    ('撌', "guì"),
+    ('撍', "zǎn"),
+    ('撎', "yì"),
+    ('撏', "xián"),
+    ('撐', "chēng"),
+    ('撑', "chēng"),
+    ('撒', "sā,sǎ"),
+    ('撓', "náo"),
+    ('撔', "hòng"),
+    ('撕', "sī"),
+    ('撖', "hàn"),
+    ('撗', "guàng"),
+    ('撘', "dā"),
+    ('撙', "zǔn"),
+    ('撚', "niǎn"),
+    ('撛', "lǐn"),
+    ('撜', "zhěng"),
+    ('撝', "huī"),
+    ('撞', "zhuàng"),
+    ('撟', "jiǎo"),
+    ('撠', "jǐ"),
+    ('撡', "cāo"),
+    ('撢', "dǎn"),
+    ('撣', "dǎn"),
+    ('撤', "chè"),
+    ('撥', "bō"),
+    ('撦', "chě"),
+    ('撧', "juē"),
+    ('撨', "fǔ"),
+    ('撩', "liāo,liáo"),
+    ('撪', "bèn"),
+    ('撫', "fǔ"),
+    ('撬', "qiào"),
+    ('播', "bō"),
+    ('撮', "cuō,zuǒ"),
+    ('撯', "zhuó"),
+    ('撰', "zhuàn"),
+    ('撱', "wěi"),
+    ('撲', "pū"),
+    ('撳', "qìn"),
+    ('撴', "dūn"),
+    ('撵', "niǎn"),
+    ('撶', "huá"),
+    ('撷', "xié"),
+    ('撸', "lū"),
+    ('撹', "jiǎo"),
+    ('撺', "cuān"),
+    ('撻', "tà"),
+    ('撼', "hàn"),
+    ('撽', "qiào"),
+    ('撾', "wō"),
+    ('撿', "jiǎn"),
+    ('擀', "gǎn"),
+    ('擁', "yōng"),
+    ('擂', "léi"),
+    ('擃', "nǎng"),
+    ('擄', "lǔ"),
+    ('擅', "shàn"),
+    ('擆', "zhuó"),
+    ('擇', "zé"),
+    ('擈', "pū"),
+    ('擉', "chuò"),
+    ('擊', "jī"),
+    ('擋', "dǎng"),
+    ('擌', "sè"),
+    ('操', "cāo"),
+    ('擎', "qíng"),
+    ('擏', "qíng"),
+    ('擐', "huàn"),
+    ('擑', "jiē"),
+    ('擒', "qín"),
+    ('擓', "kuǎi"),
+    ('擔', "dān"),
+    ('擕', "xié"),
+    ('擖', "kā"),
+    ('擗', "pǐ"),
+    ('擘', "bāi"),
+    ('擙', "ào"),
+    ('據', "jù"),
+    ('擛', "yè"),
+    ('擜', "è"),
+    ('擝', "mēng"),
+    ('擞', "sǒu"),
+    ('擟', "mí"),
+    ('擠', "jǐ"),
+    ('擡', "tái"),
+    ('擢', "zhuó"),
+    ('擣', "dǎo"),
+    ('擤', "xǐng"),
+    ('擥', "lǎn"),
+    ('擦', "cā"),
+    ('擧', "jǔ"),
+    ('擨', "yé"),
+    ('擩', "rǔ"),
+    ('擪', "yè"),
+    ('擫', "yè"),
+    ('擬', "nǐ"),
+    ('擭', "wò"),
+    ('擮', "jié"),
+    ('擯', "bìn"),
+    ('擰', "níng"),
+    ('擱', "gē"),
+    ('擲', "zhì"),
+    ('擳', "zhì"),
+    ('擴', "kuò"),
+    ('擵', "mó"),
+    ('擶', "jiàn"),
+    ('擷', "xié"),
+    ('擸', "liè"),
+    ('擹', "tān"),
+    ('擺', "bǎi"),
+    ('擻', "sǒu"),
+    ('擼', "lǔ"),
+    ('擽', "lüè"),
+    ('擾', "rǎo"),
+    ('擿', "tī"),
+    ('攀', "pān"),
+    ('攁', "yǎng"),
+    ('攂', "lèi"),
+    ('攃', "cā"),
+    ('攄', "shū"),
+    ('攅', "zǎn"),
+    ('攆', "niǎn"),
+    ('攇', "xiǎn"),
+    ('攈', "jùn"),
+    ('攉', "huō"),
+    ('攊', "lì"),
+    ('攋', "là"),
+    ('攌', "huǎn"),
+    ('攍', "yíng"),
+    ('攎', "lú"),
+    ('攏', "lǒng"),
+    ('攐', "qiān"),
+    ('攑', "qiān"),
+    ('攒', "zǎn,cuán"),
+    ('攓', "qiān"),
+    ('攔', "lán"),
+    ('攕', "xiān"),
+    ('攖', "yīng"),
+    ('攗', "méi"),
+    ('攘', "rǎng"),
+    ('攙', "chān"),
+    ('攚', "wěng"),
+    ('攛', "cuān"),
+    ('攜', "xié"),
+    ('攝', "shè"),
+    ('攞', "luó"),
+    ('攟', "jùn"),
+    ('攠', "mí"),
+    ('攡', "chī"),
+    ('攢', "zǎn"),
+    ('攣', "luán"),
+    ('攤', "tān"),
+    ('攥', "zuàn"),
+    ('攦', "lì"),
+    ('攧', "diān"),
+    ('攨', "wā"),
+    ('攩', "dǎng"),
+    ('攪', "jiǎo"),
+    ('攫', "jué"),
+    ('攬', "lǎn"),
+    ('攭', "lì"),
+    ('攮', "nǎng"),
+    ('支', "zhī"),
+    ('攰', "guì"),
+    ('攱', "guǐ"),
+    ('攲', "qī"),
+    ('攳', "xún"),
+    ('攴', "pū"),
+    ('攵', "pū"),
+    ('收', "shōu"),
+    ('攷', "kǎo"),
+    ('攸', "yōu"),
+    ('改', "gǎi"),
+    ('攺', "yǐ"),
+    ('攻', "gōng"),
+    ('攼', "gān"),
+    ('攽', "bān"),
+    ('放', "fàng"),
+    ('政', "zhèng"),
+    ('敀', "pò"),
+    ('敁', "diān"),
+    ('敂', "kòu"),
+    ('敃', "mǐn"),
+    ('敄', "wù"),
+    ('故', "gù"),
+    ('敆', "hé"),
+    ('敇', "cè"),
+    ('效', "xiào"),
+    ('敉', "mǐ"),
+    ('敊', "chù"),
+    ('敋', "gé"),
+    ('敌', "dí"),
+    ('敍', "xù"),
+    ('敎', "jiào"),
+    ('敏', "mǐn"),
+    ('敐', "chén"),
+    ('救', "jiù"),
+    ('敒', "shēn"),
+    ('敓', "duó"),
+    ('敔', "yǔ"),
+    ('敕', "chì"),
+    ('敖', "áo"),
+    ('敗', "bài"),
+    ('敘', "xù"),
+    ('教', "jiào,jiāo"),
+    ('敚', "duó"),
+    ('敛', "liǎn"),
+    ('敜', "niè"),
+    ('敝', "bì"),
+    ('敞', "chǎng"),
+    ('敟', "diǎn"),
+    ('敠', "duō"),
+    ('敡', "yì"),
+    ('敢', "gǎn"),
+    ('散', "sàn,sǎn"),
+    ('敤', "kě"),
+    ('敥', "yàn"),
+    ('敦', "dūn"),
+    ('敧', "jī"),
+    ('敨', "tǒu"),
+    ('敩', "xiào"),
+    ('敪', "duō"),
+    ('敫', "jiǎo"),
+    ('敬', "jìng"),
+    ('敭', "yáng"),
+    ('敮', "xiá"),
+    ('敯', "mǐn"),
+    ('数', "shù,shǔ,shuò"),
+    ('敱', "ái"),
+    ('敲', "qiāo"),
+    ('敳', "ái"),
+    ('整', "zhěng"),
+    ('敵', "dí"),
+    ('敶', "zhèn"),
+    ('敷', "fū"),
+    ('數', "shù"),
+    ('敹', "liáo"),
+    ('敺', "qū"),
+    ('敻', "xiòng"),
+    ('敼', "yǐ"),
+    ('敽', "jiǎo"),
+    ('敾', "shàn"),
+    ('敿', "jiǎo"),
+    ('斀', "zhuó"),
+    ('斁', "yì"),
+    ('斂', "liǎn"),
+    ('斃', "bì"),
+    ('斄', "lí"),
+    ('斅', "xiào"),
+    ('斆', "xiào"),
+    ('文', "wén"),
+    ('斈', "xué"),
+    ('斉', "qí"),
+    ('斊', "qí"),
+    ('斋', "zhāi"),
+    ('斌', "bīn"),
+    ('斍', "jué"),
+    ('斎', "zhāi"),
+    ('斏', "láng"),
+    ('斐', "fěi"),
+    ('斑', "bān"),
+    ('斒', "bān"),
+    ('斓', "lán"),
+    ('斔', "yǔ"),
+    ('斕', "lán"),
+    ('斖', "wěi"),
+    ('斗', "dǒu,dòu"),
+    ('斘', "shēng"),
+    ('料', "liào"),
+    ('斚', "jiǎ"),
+    ('斛', "hú"),
+    ('斜', "xié"),
+    ('斝', "jiǎ"),
+    ('斞', "yǔ"),
+    ('斟', "zhēn"),
+    ('斠', "jiào"),
+    ('斡', "wò"),
+    ('斢', "tiǎo"),
+    ('斣', "dòu"),
+    ('斤', "jīn"),
+    ('斥', "chì"),
+    ('斦', "yín"),
+    ('斧', "fǔ"),
+    ('斨', "qiāng"),
+    ('斩', "zhǎn"),
+    ('斪', "qú"),
+    ('斫', "zhuó"),
+    ('斬', "zhǎn"),
+    ('断', "duàn"),
+    ('斮', "cuò"),
+    ('斯', "sī"),
+    ('新', "xīn"),
+    ('斱', "zhuó"),
+    ('斲', "zhuó"),
+    ('斳', "qín"),
+    ('斴', "lín"),
+    ('斵', "zhuó"),
+    ('斶', "chù"),
+    ('斷', "duàn"),
+    ('斸', "zhǔ"),
+    ('方', "fāng"),
+    ('斺', "chǎn"),
+    ('斻', "háng"),
+    ('於', "yú"),
+    ('施', "shī"),
+    ('斾', "pèi"),
+    ('斿', "yóu"),
+    ('旀', "mèi"),
+    ('旁', "páng"),
+    ('旂', "qí"),
+    ('旃', "zhān"),
+    ('旄', "máo"),
+    ('旅', "lǚ"),
+    ('旆', "pèi"),
+    ('旇', "pī"),
+    ('旈', "liú"),
+    ('旉', "fū"),
+    ('旊', "fǎng"),
+    ('旋', "xuán,xuàn"),
+    ('旌', "jīng"),
+    ('旍', "jīng"),
+    ('旎', "nǐ"),
+    ('族', "zú"),
+    ('旐', "zhào"),
+    ('旑', "yǐ"),
+    ('旒', "liú"),
+    ('旓', "shāo"),
+    ('旔', "jiàn"),
+    ('旕', "yú"),
+    ('旖', "yǐ"),
+    ('旗', "qí"),
+    ('旘', "zhì"),
+    ('旙', "fān"),
+    ('旚', "piāo"),
+    ('旛', "fān"),
+    ('旜', "zhān"),
+    ('旝', "kuài"),
+    ('旞', "suì"),
+    ('旟', "yú"),
+    ('无', "wú"),
+    ('旡', "jì"),
+    ('既', "jì"),
+    ('旣', "jì"),
+    ('旤', "huò"),
+    ('日', "rì"),
+    ('旦', "dàn"),
+    ('旧', "jiù"),
+    ('旨', "zhǐ"),
+    ('早', "zǎo"),
+    ('旪', "xié"),
+    ('旫', "tiāo"),
+    ('旬', "xún"),
+    ('旭', "xù"),
+    ('旮', "gā"),
+    ('旯', "lá"),
+    ('旰', "gàn"),
+    ('旱', "hàn"),
+    ('旲', "tái"),
+    ('旳', "dì"),
+    ('旴', "xū"),
+    ('旵', "chǎn"),
+    ('时', "shí"),
+    ('旷', "kuàng"),
+    ('旸', "yáng"),
+    ('旹', "shí"),
+    ('旺', "wàng"),
+    ('旻', "mín"),
+    ('旼', "mín"),
+    ('旽', "tūn"),
+    ('旾', "chūn"),
+    ('旿', "wǔ"),
+    ('昀', "yún"),
+    ('昁', "bèi"),
+    ('昂', "áng"),
+    ('昃', "zè"),
+    ('昄', "bǎn"),
+    ('昅', "jié"),
+    ('昆', "kūn"),
+    ('昇', "shēng"),
+    ('昈', "hù"),
+    ('昉', "fǎng"),
+    ('昊', "hào"),
+    ('昋', "guì"),
+    ('昌', "chāng"),
+    ('昍', "xuān"),
+    ('明', "míng"),
+    ('昏', "hūn"),
+    ('昐', "fēn"),
+    ('昑', "qǐn"),
+    ('昒', "hū"),
+    ('易', "yì"),
+    ('昔', "xī"),
+    ('昕', "xīn"),
+    ('昖', "yán"),
+    ('昗', "zè"),
+    ('昘', "fǎng"),
+    ('昙', "tán"),
+    ('昚', "shèn"),
+    ('昛', "jù"),
+    ('昜', "yáng"),
+    ('昝', "zǎn"),
+    ('昞', "bǐng"),
+    ('星', "xīng"),
+    ('映', "yìng"),
+    ('昡', "xuàn"),
+    ('昢', "pò"),
+    ('昣', "zhěn"),
+    ('昤', "líng"),
+    ('春', "chūn"),
+    ('昦', "hào"),
+    ('昧', "mèi"),
+    ('昨', "zuó"),
+    ('昩', "mò"),
+    ('昪', "biàn"),
+    ('昫', "xù"),
+    ('昬', "hūn"),
+    ('昭', "zhāo"),
+    ('昮', "zòng"),
+    ('是', "shì"),
+    ('昰', "shì"),
+    ('昱', "yù"),
+    ('昲', "fèi"),
+    ('昳', "dié"),
+    ('昴', "mǎo"),
+    ('昵', "nì"),
+    ('昶', "chǎng"),
+    ('昷', "wēn"),
+    ('昸', "dōng"),
+    ('昹', "ǎi"),
+    ('昺', "bǐng"),
+    ('昻', "áng"),
+    ('昼', "zhòu"),
+    ('昽', "lóng"),
+    ('显', "xiǎn"),
+    ('昿', "kuàng"),
+    ('晀', "tiǎo"),
+    ('晁', "cháo"),
+    ('時', "shí"),
+    ('晃', "huǎng,huàng"),
+    ('晄', "huǎng"),
+    ('晅', "xuǎn"),
+    ('晆', "kuí"),
+    ('晇', "xū"),
+    ('晈', "jiǎo"),
+    ('晉', "jìn"),
+    ('晊', "zhì"),
+    ('晋', "jìn"),
+    ('晌', "shǎng"),
+    ('晍', "tóng"),
+    ('晎', "hǒng"),
+    ('晏', "yàn"),
+    ('晐', "gāi"),
+    ('晑', "xiǎng"),
+    ('晒', "shài"),
+    ('晓', "xiǎo"),
+    ('晔', "yè"),
+    ('晕', "yūn,yùn"),
+    ('晖', "huī"),
+    ('晗', "hán"),
+    ('晘', "hàn"),
+    ('晙', "jùn"),
+    ('晚', "wǎn"),
+    ('晛', "xiàn"),
+    ('晜', "kūn"),
+    ('晝', "zhòu"),
+    ('晞', "xī"),
+    ('晟', "chéng"),
+    ('晠', "shèng"),
+    ('晡', "bū"),
+    ('晢', "zhé"),
+    ('晣', "zhé"),
+    ('晤', "wù"),
+    ('晥', "wǎn"),
+    ('晦', "huì"),
+    ('晧', "hào"),
+    ('晨', "chén"),
+    ('晩', "wǎn"),
+    ('晪', "tiǎn"),
+    ('晫', "zhuó"),
+    ('晬', "zuì"),
+    ('晭', "zhǒu"),
+    ('普', "pǔ"),
+    ('景', "jǐng"),
+    ('晰', "xī"),
+    ('晱', "shǎn"),
+    ('晲', "nǐ"),
+    ('晳', "xī"),
+    ('晴', "qíng"),
+    ('晵', "qǐ"),
+    ('晶', "jīng"),
+    ('晷', "guǐ"),
+    ('晸', "zhěng"),
+    ('晹', "yì"),
+    ('智', "zhì"),
+    ('晻', "àn"),
+    ('晼', "wǎn"),
+    ('晽', "lín"),
+    ('晾', "liàng"),
+    ('晿', "chāng"),
+    ('暀', "wǎng"),
+    ('暁', "xiǎo"),
+    ('暂', "zàn"),
+    ('暃', "fēi"),
+    ('暄', "xuān"),
+    ('暅', "gèng"),
+    ('暆', "yí"),
+    ('暇', "xiá"),
+    ('暈', "yūn"),
+    ('暉', "huī"),
+    ('暊', "xǔ"),
+    ('暋', "mǐn"),
+    ('暌', "kuí"),
+    ('暍', "yē"),
+    ('暎', "yìng"),
+    ('暏', "shǔ"),
+    ('暐', "wěi"),
+    ('暑', "shǔ"),
+    ('暒', "qíng"),
+    ('暓', "mào"),
+    ('暔', "nán"),
+    ('暕', "jiǎn"),
+    ('暖', "nuǎn"),
+    ('暗', "àn"),
+    ('暘', "yáng"),
+    ('暙', "chūn"),
+    ('暚', "yáo"),
+    ('暛', "suǒ"),
+    ('暜', "pǔ"),
+    ('暝', "míng"),
+    ('暞', "jiǎo"),
+    ('暟', "kǎi"),
+    ('暠', "gǎo"),
+    ('暡', "wěng"),
+    ('暢', "chàng"),
+    ('暣', "qì"),
+    ('暤', "hào"),
+    ('暥', "yàn"),
+    ('暦', "lì"),
+    ('暧', "ài"),
+    ('暨', "jì"),
+    ('暩', "jì"),
+    ('暪', "mèn"),
+    ('暫', "zàn"),
+    ('暬', "xiè"),
+    ('暭', "hào"),
+    ('暮', "mù"),
+    ('暯', "mò"),
+    ('暰', "cōng"),
+    ('暱', "nì"),
+    ('暲', "zhāng"),
+    ('暳', "huì"),
+    ('暴', "bào,pù"),
+    ('暵', "hàn"),
+    ('暶', "xuán"),
+    ('暷', "chuán"),
+    ('暸', "liáo"),
+    ('暹', "xiān"),
+    ('暺', "tǎn"),
+    ('暻', "jǐng"),
+    ('暼', "piē"),
+    ('暽', "lín"),
+    ('暾', "tūn"),
+    ('暿', "xǐ"),
+    ('曀', "yì"),
+    ('曁', "jì"),
+    ('曂', "huàng"),
+    ('曃', "dài"),
+    ('曄', "yè"),
+    ('曅', "yè"),
+    ('曆', "lì"),
+    ('曇', "tán"),
+    ('曈', "tóng"),
+    ('曉', "xiǎo"),
+    ('曊', "fèi"),
+    ('曋', "shěn"),
+    ('曌', "zhào"),
+    ('曍', "hào"),
+    ('曎', "yì"),
+    ('曏', "xiǎng"),
+    ('曐', "xīng"),
+    ('曑', "shēn"),
+    ('曒', "jiǎo"),
+    ('曓', "bào"),
+    ('曔', "jìng"),
+    ('曕', "yàn"),
+    ('曖', "ài"),
+    ('曗', "yè"),
+    ('曘', "rú"),
+    ('曙', "shǔ"),
+    ('曚', "méng"),
+    ('曛', "xūn"),
+    ('曜', "yào"),
+    ('曝', "pù,bào"),
+    ('曞', "lì"),
+    ('曟', "chén"),
+    ('曠', "kuàng"),
+    ('曡', "dié"),
+    ('曢', "liǎo"),
+    ('曣', "yàn"),
+    ('曤', "huò"),
+    ('曥', "lú"),
+    ('曦', "xī"),
+    ('曧', "róng"),
+    ('曨', "lóng"),
+    ('曩', "nǎng"),
+    ('曪', "luǒ"),
+    ('曫', "luán"),
+    ('曬', "shài"),
+    ('曭', "tǎng"),
+    ('曮', "yǎn"),
+    ('曯', "zhú"),
+    ('曰', "yuē"),
+    ('曱', "yuē"),
+    ('曲', "qū,qǔ"),
+    ('曳', "yè"),
+    ('更', "gèng,gēng"),
+    ('曵', "yè"),
+    ('曶', "hū"),
+    ('曷', "hé"),
+    ('書', "shū"),
+    ('曹', "cáo"),
+    ('曺', "cáo"),
+    ('曻', "shēng"),
+    ('曼', "màn"),
+    ('曽', "cēng"),
+    ('曾', "céng,zēng"),
+    ('替', "tì"),
+    ('最', "zuì"),
+    ('朁', "cǎn"),
+    ('朂', "xù"),
+    ('會', "huì"),
+    ('朄', "yǐn"),
+    ('朅', "qiè"),
+    ('朆', "fēn"),
+    ('朇', "pí"),
+    ('月', "yuè"),
+    ('有', "yǒu,yòu"),
+    ('朊', "ruǎn"),
+    ('朋', "péng"),
+    ('朌', "fén"),
+    ('服', "fú,fù"),
+    ('朎', "líng"),
+    ('朏', "fěi"),
+    ('朐', "qú"),
+    ('朑', "tì"),
+    ('朒', "nǜ"),
+    ('朓', "tiǎo"),
+    ('朔', "shuò"),
+    ('朕', "zhèn"),
+    ('朖', "lǎng"),
+    ('朗', "lǎng"),
+    ('朘', "zuī"),
+    ('朙', "míng"),
+    ('朚', "huāng"),
+    ('望', "wàng"),
+    ('朜', "tūn"),
+    ('朝', "cháo,zhāo"),
+    ('朞', "jī"),
+    ('期', "qī,jī"),
+    ('朠', "yīng"),
+    ('朡', "zōng"),
+    ('朢', "wàng"),
+    ('朣', "tóng"),
+    ('朤', "lǎng"),
+    ('朥', "láo"),
+    ('朦', "méng"),
+    ('朧', "lóng"),
+    ('木', "mù"),
+    ('朩', "děng"),
+    ('未', "wèi"),
+    ('末', "mò"),
+    ('本', "běn"),
+    ('札', "zhá"),
+    ('朮', "shù"),
+    ('术', "shù,zhú"),
+    ('朰', "mù"),
+    ('朱', "zhū"),
+    ('朲', "rén"),
+    ('朳', "bā"),
+    ('朴', "pǔ,pò,pō,piáo"),
+    ('朵', "duǒ"),
+    ('朶', "duǒ"),
+    ('朷', "dāo"),
+    ('朸', "lì"),
+    ('朹', "guǐ"),
+    ('机', "jī"),
+    ('朻', "jiū"),
+    ('朼', "bǐ"),
+    ('朽', "xiǔ"),
+    ('朾', "chéng"),
+    ('朿', "cì"),
+    ('杀', "shā"),
+    ('杁', "rù"),
+    ('杂', "zá"),
+    ('权', "quán"),
+    ('杄', "qiān"),
+    ('杅', "yú"),
+    ('杆', "gǎn,gān"),
+    ('杇', "wū"),
+    ('杈', "chā"),
+    ('杉', "shān,shā"),
+    ('杊', "xún"),
+    ('杋', "fán"),
+    ('杌', "wù"),
+    ('杍', "zǐ"),
+    ('李', "lǐ"),
+    ('杏', "xìng"),
+    ('材', "cái"),
+    ('村', "cūn"),
+    ('杒', "rèn"),
+    ('杓', "biāo"),
+    ('杔', "tuō"),
+    ('杕', "dì"),
+    ('杖', "zhàng"),
+    ('杗', "máng"),
+    ('杘', "chì"),
+    ('杙', "yì"),
+    ('杚', "gài"),
+    ('杛', "gōng"),
+    ('杜', "dù"),
+    ('杝', "lí"),
+    ('杞', "qǐ"),
+    ('束', "shù"),
+    ('杠', "gāng"),
+    ('条', "tiáo"),
+    ('杢', "jiang"),
+    ('杣', "mián"),
+    ('杤', "wàn"),
+    ('来', "lái"),
+    ('杦', "jiǔ"),
+    ('杧', "máng"),
+    ('杨', "yáng"),
+    ('杩', "mà"),
+    ('杪', "miǎo"),
+    ('杫', "sì"),
+    ('杬', "yuán"),
+    ('杭', "háng"),
+    ('杮', "fèi"),
+    ('杯', "bēi"),
+    ('杰', "jié"),
+    ('東', "dōng"),
+    ('杲', "gǎo"),
+    ('杳', "yǎo"),
+    ('杴', "xiān"),
+    ('杵', "chǔ"),
+    ('杶', "chūn"),
+    ('杷', "pá"),
+    ('杸', "shū"),
+    ('杹', "huà"),
+    ('杺', "xīn"),
+    ('杻', "chǒu"),
+    ('杼', "zhù"),
+    ('杽', "chǒu"),
+    ('松', "sōng"),
+    ('板', "bǎn"),
+    ('枀', "sōng"),
+    ('极', "jí"),
+    ('枂', "wò"),
+    ('枃', "jìn"),
+    ('构', "gòu"),
+    ('枅', "jī"),
+    ('枆', "máo"),
+    ('枇', "pí"),
+    ('枈', "bì"),
+    ('枉', "wǎng"),
+    ('枊', "àng"),
+    ('枋', "fāng"),
+    ('枌', "fén"),
+    ('枍', "yì"),
+    ('枎', "fú"),
+    ('枏', "nán"),
+    ('析', "xī"),
+    ('枑', "hù"),
+    ('枒', "yā"),
+    ('枓', "dǒu"),
+    ('枔', "xín"),
+    ('枕', "zhěn"),
+    ('枖', "yāo"),
+    ('林', "lín"),
+    ('枘', "ruì"),
+    ('枙', "ě"),
+    ('枚', "méi"),
+    ('枛', "zhào"),
+    ('果', "guǒ"),
+    ('枝', "zhī"),
+    ('枞', "cōng"),
+    ('枟', "yùn"),
+    ('枠', "zui"),
+    ('枡', "shēng"),
+    ('枢', "shū"),
+    ('枣', "zǎo"),
+    ('枤', "dì"),
+    ('枥', "lì"),
+    ('枦', "lú"),
+    ('枧', "jiǎn"),
+    ('枨', "chéng"),
+    ('枩', "sōng"),
+    ('枪', "qiāng"),
+    ('枫', "fēng"),
+    ('枬', "zhān"),
+    ('枭', "xiāo"),
+    ('枮', "xiān"),
+    ('枯', "kū"),
+    ('枰', "píng"),
+    ('枱', "tái"),
+    ('枲', "xǐ"),
+    ('枳', "zhǐ"),
+    ('枴', "guǎi"),
+    ('枵', "xiāo"),
+    ('架', "jià"),
+    ('枷', "jiā"),
+    ('枸', "gǒu,gōu,jǔ"),
+    ('枹', "bāo"),
+    ('枺', "mò"),
+    ('枻', "yì"),
+    ('枼', "yè"),
+    ('枽', "yè"),
+    ('枾', "shì"),
+    ('枿', "niè"),
+    ('柀', "bǐ"),
+    ('柁', "duò"),
+    ('柂', "yí"),
+    ('柃', "líng"),
+    ('柄', "bǐng"),
+    ('柅', "nǐ"),
+    ('柆', "lā"),
+    ('柇', "hé"),
+    ('柈', "bàn"),
+    ('柉', "fán"),
+    ('柊', "zhōng"),
+    ('柋', "dài"),
+    ('柌', "cí"),
+    ('柍', "yǎng"),
+    ('柎', "fū"),
+    ('柏', "bǎi,bó,bò"),
+    ('某', "mǒu"),
+    ('柑', "gān"),
+    ('柒', "qī"),
+    ('染', "rǎn"),
+    ('柔', "róu"),
+    ('柕', "mào"),
+    ('柖', "sháo"),
+    ('柗', "sōng"),
+    ('柘', "zhè"),
+    ('柙', "xiá"),
+    ('柚', "yòu"),
+    ('柛', "shēn"),
+    ('柜', "guì"),
+    ('柝', "tuò"),
+    ('柞', "zuò,zhà"),
+    ('柟', "nán"),
+    ('柠', "níng"),
+    ('柡', "yǒng"),
+    ('柢', "dǐ"),
+    ('柣', "zhì"),
+    ('柤', "zhā"),
+    ('查', "chá,zhā"),
+    ('柦', "dàn"),
+    ('柧', "gū"),
+    ('柨', "bù"),
+    ('柩', "jiù"),
+    ('柪', "āo"),
+    ('柫', "fú"),
+    ('柬', "jiǎn"),
+    ('柭', "bā"),
+    ('柮', "duò"),
+    ('柯', "kē"),
+    ('柰', "nài"),
+    ('柱', "zhù"),
+    ('柲', "bì"),
+    ('柳', "liǔ"),
+    ('柴', "chái"),
+    ('柵', "shān"),
+    ('柶', "sì"),
+    ('柷', "chù"),
+    ('柸', "pēi"),
+    ('柹', "shì"),
+    ('柺', "guǎi"),
+    ('査', "zhā"),
+    ('柼', "yǎo"),
+    ('柽', "chēng"),
+    ('柾', "jiù"),
+    ('柿', "shì"),
+    ('栀', "zhī"),
+    ('栁', "liǔ"),
+    ('栂', "méi"),
+    ('栃', "lì"),
+    ('栄', "róng"),
+    ('栅', "zhà,shān"),
+    ('栆', "zǎo"),
+    ('标', "biāo"),
+    ('栈', "zhàn"),
+    ('栉', "zhì"),
+    ('栊', "lóng"),
+    ('栋', "dòng"),
+    ('栌', "lú"),
+    ('栍', "shēng"),
+    ('栎', "lì"),
+    ('栏', "lán"),
+    ('栐', "yǒng"),
+    ('树', "shù"),
+    ('栒', "xún"),
+    ('栓', "shuān"),
+    ('栔', "qì"),
+    ('栕', "zhēn"),
+    ('栖', "qī,xī"),
+    ('栗', "lì"),
+    ('栘', "yí"),
+    ('栙', "xiáng"),
+    ('栚', "zhèn"),
+    ('栛', "lì"),
+    ('栜', "sè"),
+    ('栝', "guā"),
+    ('栞', "kān"),
+    ('栟', "bēn"),
+    ('栠', "rěn"),
+    ('校', "xiào,jiào"),
+    ('栢', "bǎi"),
+    ('栣', "rěn"),
+    ('栤', "bìng"),
+    ('栥', "zī"),
+    ('栦', "chóu"),
+    ('栧', "yì"),
+    ('栨', "cì"),
+    ('栩', "xǔ"),
+    ('株', "zhū"),
+    ('栫', "jiàn"),
+    ('栬', "zuì"),
+    ('栭', "ér"),
+    ('栮', "ěr"),
+    ('栯', "yǒu"),
+    ('栰', "fá"),
+    ('栱', "gǒng"),
+    ('栲', "kǎo"),
+    ('栳', "lǎo"),
+    ('栴', "zhān"),
+    ('栵', "liè"),
+    ('栶', "yīn"),
+    ('样', "yàng"),
+    ('核', "hé,hú"),
+    ('根', "gēn"),
+    ('栺', "yì"),
+    ('栻', "shì"),
+    ('格', "gé"),
+    ('栽', "zāi"),
+    ('栾', "luán"),
+    ('栿', "fú"),
+    ('桀', "jié"),
+    ('桁', "héng"),
+    ('桂', "guì"),
+    ('桃', "táo"),
+    ('桄', "guāng"),
+    ('桅', "wéi"),
+    ('框', "kuàng,kuāng"),
+    ('桇', "rú"),
+    ('案', "àn"),
+    ('桉', "ān"),
+    ('桊', "juàn"),
+    ('桋', "yí"),
+    ('桌', "zhuō"),
+    ('桍', "kū"),
+    ('桎', "zhì"),
+    ('桏', "qióng"),
+    ('桐', "tóng"),
+    ('桑', "sāng"),
+    ('桒', "sāng"),
+    ('桓', "huán"),
+    ('桔', "jú"),
+    ('桕', "jiù"),
+    ('桖', "xuè"),
+    ('桗', "duò"),
+    ('桘', "zhuì"),
+    ('桙', "yú"),
+    ('桚', "zǎn"),
+    ('桜', "yīng"),
+    ('桝', "jié"),
+    ('桞', "liǔ"),
+    ('桟', "zhàn"),
+    ('桠', "yā"),
+    ('桡', "ráo"),
+    ('桢', "zhēn"),
+    ('档', "dàng"),
+    ('桤', "qī"),
+    ('桥', "qiáo"),
+    ('桦', "huà"),
+    ('桧', "guì,huì"),
+    ('桨', "jiǎng"),
+    ('桩', "zhuāng"),
+    ('桪', "xún"),
+    ('桫', "suō"),
+    ('桬', "shā"),
+    ('桭', "zhēn"),
+    ('桮', "bēi"),
+    ('桯', "tīng"),
+    ('桰', "kuò"),
+    ('桱', "jìng"),
+    ('桲', "po"),
+    ('桳', "bèn"),
+    ('桴', "fú"),
+    ('桵', "ruí"),
+    ('桶', "tǒng"),
+    ('桷', "jué"),
+    ('桸', "xī"),
+    ('桹', "láng"),
+    ('桺', "liǔ"),
+    ('桻', "fēng"),
+    ('桼', "qī"),
+    ('桽', "wěn"),
+    ('桾', "jūn"),
+    ('桿', "gǎn"),
+    ('梀', "sù"),
+    ('梁', "liáng"),
+    ('梂', "qiú"),
+    ('梃', "tǐng"),
+    ('梄', "yǒu"),
+    ('梅', "méi"),
+    ('梆', "bāng"),
+    ('梇', "lòng"),
+    ('梈', "pēng"),
+    ('梉', "zhuāng"),
+    ('梊', "dì"),
+    ('梋', "xuān"),
+    ('梌', "tú"),
+    ('梍', "zào"),
+    ('梎', "āo"),
+    ('梏', "gù"),
+    ('梐', "bì"),
+    ('梑', "dí"),
+    ('梒', "hán"),
+    ('梓', "zǐ"),
+    ('梔', "zhī"),
+    ('梕', "rèn"),
+    ('梖', "bèi"),
+    ('梗', "gěng"),
+    ('梘', "jiǎn"),
+    ('梙', "huàn"),
+    ('梚', "wǎn"),
+    ('梛', "nuó"),
+    ('梜', "jiā"),
+    ('條', "tiáo"),
+    ('梞', "jì"),
+    ('梟', "xiāo"),
+    ('梠', "lǚ"),
+    ('梡', "hún"),
+    ('梢', "shāo"),
+    ('梣', "cén"),
+    ('梤', "fén"),
+    ('梥', "sōng"),
+    ('梦', "mèng"),
+    ('梧', "wú"),
+    ('梨', "lí"),
+    ('梩', "lí"),
+    ('梪', "dòu"),
+    ('梫', "qǐn"),
+    ('梬', "yǐng"),
+    ('梭', "suō"),
+    ('梮', "jū"),
+    ('梯', "tī"),
+    ('械', "xiè"),
+    ('梱', "kǔn"),
+    ('梲', "zhuó"),
+    ('梳', "shū"),
+    ('梴', "chān"),
+    ('梵', "fàn"),
+    ('梶', "wěi"),
+    ('梷', "jìng"),
+    ('梸', "lí"),
+    ('梹', "bīn"),
+    ('梺', "xià"),
+    ('梻', "fó"),
+    ('梼', "táo"),
+    ('梽', "zhì"),
+    ('梾', "lái"),
+    ('梿', "lián"),
+    ('检', "jiǎn"),
+    ('棁', "zhuō"),
+    ('棂', "líng"),
+    ('棃', "lí"),
+    ('棄', "qì"),
+    ('棅', "bǐng"),
+    ('棆', "lún"),
+    ('棇', "cōng"),
+    ('棈', "qiàn"),
+    ('棉', "mián"),
+    ('棊', "qí"),
+    ('棋', "qí"),
+    ('棌', "cài"),
+    ('棍', "gùn"),
+    ('棎', "chán"),
+    ('棏', "dé"),
+    ('棐', "fěi"),
+    ('棑', "pái"),
+    ('棒', "bàng"),
+    ('棓', "bàng"),
+    ('棔', "hūn"),
+    ('棕', "zōng"),
+    ('棖', "chéng"),
+    ('棗', "zǎo"),
+    ('棘', "jí"),
+    ('棙', "lì"),
+    ('棚', "péng"),
+    ('棛', "yù"),
+    ('棜', "yù"),
+    ('棝', "gù"),
+    ('棞', "jùn"),
+    ('棟', "dòng"),
+    ('棠', "táng"),
+    ('棡', "gāng"),
+    ('棢', "wǎng"),
+    ('棣', "dì"),
+    ('棤', "cuò"),
+    ('棥', "fán"),
+    ('棦', "chēng"),
+    ('棧', "zhàn"),
+    ('棨', "qǐ"),
+    ('棩', "yuān"),
+    ('棪', "yǎn"),
+    ('棫', "yù"),
+    ('棬', "quān"),
+    ('棭', "yì"),
+    ('森', "sēn"),
+    ('棯', "rěn"),
+    ('棰', "chuí"),
+    ('棱', "léng,lēng,líng"),
+    ('棲', "qī"),
+    ('棳', "zhuō"),
+    ('棴', "fú"),
+    ('棵', "kē"),
+    ('棶', "lái"),
+    ('棷', "zōu"),
+    ('棸', "zōu"),
+    ('棹', "zhào"),
+    ('棺', "guān"),
+    ('棻', "fēn"),
+    ('棼', "fén"),
+    ('棽', "shēn"),
+    ('棾', "qíng"),
+    ('棿', "ní"),
+    ('椀', "wǎn"),
+    ('椁', "guǒ"),
+    ('椂', "lù"),
+    ('椃', "háo"),
+    ('椄', "jiē"),
+    ('椅', "yǐ"),
+    ('椆', "chóu"),
+    ('椇', "jǔ"),
+    ('椈', "jú"),
+    ('椉', "chéng"),
+    ('椊', "zuó"),
+    ('椋', "liáng"),
+    ('椌', "qiāng"),
+    ('植', "zhí"),
+    ('椎', "chuí"),
+    ('椏', "yā"),
+    ('椐', "jū"),
+    ('椑', "bēi"),
+    ('椒', "jiāo"),
+    ('椓', "zhuó"),
+    ('椔', "zī"),
+    ('椕', "bīn"),
+    ('椖', "péng"),
+    ('椗', "dìng"),
+    ('椘', "chǔ"),
+    ('椙', "chāng"),
+    ('椚', "mēn"),
+    ('椛', "huā"),
+    ('検', "jiǎn"),
+    ('椝', "guī"),
+    ('椞', "xì"),
+    ('椟', "dú"),
+    ('椠', "qiàn"),
+    ('椡', "dào"),
+    ('椢', "guì"),
+    ('椣', "diǎn"),
+    ('椤', "luó"),
+    ('椥', "zhī"),
+    ('椦', "quan"),
+    ('椧', "mìng"),
+    ('椨', "fǔ"),
+    ('椩', "gēng"),
+    ('椪', "pèng"),
+    ('椫', "shàn"),
+    ('椬', "yí"),
+    ('椭', "tuǒ"),
+    ('椮', "sēn"),
+    ('椯', "duǒ"),
+    ('椰', "yē"),
+    ('椱', "fù"),
+    ('椲', "wěi"),
+    ('椳', "wēi"),
+    ('椴', "duàn"),
+    ('椵', "jiǎ"),
+    ('椶', "zōng"),
+    ('椷', "jiān"),
+    ('椸', "yí"),
+    ('椹', "shèn"),
+    ('椺', "xí"),
+    ('椻', "yàn"),
+    ('椼', "yǎn"),
+    ('椽', "chuán"),
+    ('椾', "jiān"),
+    ('椿', "chūn"),
+    ('楀', "yǔ"),
+    ('楁', "hé"),
+    ('楂', "zhā"),
+    ('楃', "wò"),
+    ('楄', "pián"),
+    ('楅', "bī"),
+    ('楆', "yāo"),
+    ('楇', "huò"),
+    ('楈', "xū"),
+    ('楉', "ruò"),
+    ('楊', "yáng"),
+    ('楋', "là"),
+    ('楌', "yán"),
+    ('楍', "běn"),
+    ('楎', "huī"),
+    ('楏', "kuí"),
+    ('楐', "jiè"),
+    ('楑', "kuí"),
+    ('楒', "sī"),
+    ('楓', "fēng"),
+    ('楔', "xiē"),
+    ('楕', "tuǒ"),
+    ('楖', "zhì"),
+    ('楗', "jiàn"),
+    ('楘', "mù"),
+    ('楙', "mào"),
+    ('楚', "chǔ"),
+    ('楛', "hù"),
+    ('楜', "hú"),
+    ('楝', "liàn"),
+    ('楞', "léng"),
+    ('楟', "tíng"),
+    ('楠', "nán"),
+    ('楡', "yú"),
+    ('楢', "yóu"),
+    ('楣', "méi"),
+    ('楤', "sǒng"),
+    ('楥', "xuàn"),
+    ('楦', "xuàn"),
+    ('楧', "yǎng"),
+    ('楨', "zhēn"),
+    ('楩', "pián"),
+    ('楪', "yè"),
+    ('楫', "jí"),
+    ('楬', "jié"),
+    ('業', "yè"),
+    ('楮', "chǔ"),
+    ('楯', "dùn"),
+    ('楰', "yú"),
+    ('楱', "zòu"),
+    ('楲', "wēi"),
+    ('楳', "méi"),
+    ('楴', "tì"),
+    ('極', "jí"),
+    ('楶', "jié"),
+    ('楷', "kǎi"),
+    ('楸', "qiū"),
+    ('楹', "yíng"),
+    ('楺', "rǒu"),
+    ('楻', "huáng"),
+    ('楼', "lóu"),
+    ('楽', "lè"),
+    ('楾', "quán"),
+    ('楿', "xiāng"),
+    ('榀', "pǐn"),
+    ('榁', "shǐ"),
+    ('概', "gài"),
+    ('榃', "tán"),
+    ('榄', "lǎn"),
+    ('榅', "wēn"),
+    ('榆', "yú"),
+    ('榇', "chèn"),
+    ('榈', "lǘ"),
+    ('榉', "jǔ"),
+    ('榊', "shén"),
+    ('榋', "chu"),
+    ('榌', "bī"),
+    ('榍', "xiè"),
+    ('榎', "jiǎ"),
+    ('榏', "yì"),
+    ('榐', "zhǎn"),
+    ('榑', "fú"),
+    ('榒', "nuò"),
+    ('榓', "mì"),
+    ('榔', "láng"),
+    ('榕', "róng"),
+    ('榖', "gǔ"),
+    ('榗', "jiàn"),
+    ('榘', "jǔ"),
+    ('榙', "tā"),
+    ('榚', "yǎo"),
+    ('榛', "zhēn"),
+    ('榜', "bǎng"),
+    ('榝', "shā"),
+    ('榞', "yuán"),
+    ('榟', "zǐ"),
+    ('榠', "míng"),
+    ('榡', "sù"),
+    ('榢', "jià"),
+    ('榣', "yáo"),
+    ('榤', "jié"),
+    ('榥', "huàng"),
+    ('榦', "gàn"),
+    ('榧', "fěi"),
+    ('榨', "zhà"),
+    ('榩', "qián"),
+    ('榪', "mà"),
+    ('榫', "sǔn"),
+    ('榬', "yuán"),
+    ('榭', "xiè"),
+    ('榮', "róng"),
+    ('榯', "shí"),
+    ('榰', "zhī"),
+    ('榱', "cuī"),
+    ('榲', "wēn"),
+    ('榳', "tíng"),
+    ('榴', "liú"),
+    ('榵', "róng"),
+    ('榶', "táng"),
+    ('榷', "què"),
+    ('榸', "zhāi"),
+    ('榹', "sī"),
+    ('榺', "shèng"),
+    ('榻', "tà"),
+    ('榼', "kē"),
+    ('榽', "xī"),
+    ('榾', "gǔ"),
+    ('榿', "qī"),
+    ('槀', "gǎo"),
+    ('槁', "gǎo"),
+    ('槂', "sūn"),
+    ('槃', "pán"),
+    ('槄', "tāo"),
+    ('槅', "gé"),
+    ('槆', "chūn"),
+    ('槇', "diān"),
+    ('槈', "nòu"),
+    ('槉', "jí"),
+    ('槊', "shuò"),
+    ('構', "gòu"),
+    ('槌', "chuí"),
+    ('槍', "qiāng"),
+    ('槎', "chá"),
+    ('槏', "qiǎn"),
+    ('槐', "huái"),
+    ('槑', "méi"),
+    ('槒', "xù"),
+    ('槓', "gàng"),
+    ('槔', "gāo"),
+    ('槕', "zhuō"),
+    ('槖', "tuó"),
+    ('槗', "qiáo"),
+    ('様', "yàng"),
+    ('槙', "diān"),
+    ('槚', "jiǎ"),
+    ('槛', "kǎn"),
+    ('槜', "zuì"),
+    ('槝', "dǎo"),
+    ('槞', "lóng"),
+    ('槟', "bīng,bīn"),
+    ('槠', "zhū"),
+    ('槡', "sāng"),
+    ('槢', "xí"),
+    ('槣', "jī"),
+    ('槤', "lián"),
+    ('槥', "huì"),
+    ('槦', "yōng"),
+    ('槧', "qiàn"),
+    ('槨', "guǒ"),
+    ('槩', "gài"),
+    ('槪', "gài"),
+    ('槫', "tuán"),
+    ('槬', "huà"),
+    ('槭', "qī"),
+    ('槮', "sēn"),
+    ('槯', "cuī"),
+    ('槰', "péng"),
+    ('槱', "yǒu"),
+    ('槲', "hú"),
+    ('槳', "jiǎng"),
+    ('槴', "hù"),
+    ('槵', "huàn"),
+    ('槶', "guì"),
+    ('槷', "niè"),
+    ('槸', "yì"),
+    ('槹', "gāo"),
+    ('槺', "kāng"),
+    ('槻', "guī"),
+    ('槼', "guī"),
+    ('槽', "cáo"),
+    ('槾', "màn"),
+    ('槿', "jǐn"),
+    ('樀', "dí"),
+    ('樁', "zhuāng"),
+    ('樂', "lè"),
+    ('樃', "lǎng"),
+    ('樄', "chén"),
+    ('樅', "cōng"),
+    ('樆', "lí"),
+    ('樇', "xiū"),
+    ('樈', "qíng"),
+    ('樉', "shuǎng"),
+    ('樊', "fán"),
+    ('樋', "tōng"),
+    ('樌', "guàn"),
+    ('樍', "zé"),
+    ('樎', "sù"),
+    ('樏', "lěi"),
+    ('樐', "lǔ"),
+    ('樑', "liáng"),
+    ('樒', "mì"),
+    ('樓', "lóu"),
+    ('樔', "cháo"),
+    ('樕', "sù"),
+    ('樖', "kē"),
+    ('樗', "chū"),
+    ('樘', "táng"),
+    ('標', "biāo"),
+    ('樚', "lù"),
+    ('樛', "jiū"),
+    ('樜', "zhè"),
+    ('樝', "zhā"),
+    ('樞', "shū"),
+    ('樟', "zhāng"),
+    ('樠', "mán"),
+    ('模', "mó,mú"),
+    ('樢', "niǎo"),
+    ('樣', "yàng"),
+    ('樤', "tiáo"),
+    ('樥', "péng"),
+    ('樦', "zhù"),
+    ('樧', "shā"),
+    ('樨', "xī"),
+    ('権', "quán"),
+    ('横', "héng,hèng"),
+    ('樫', "jiān"),
+    ('樬', "cōng"),
+    ('樭', "jī"),
+    ('樮', "yān"),
+    ('樯', "qiáng"),
+    ('樰', "xuě"),
+    ('樱', "yīng"),
+    ('樲', "èr"),
+    ('樳', "xún"),
+    ('樴', "zhí"),
+    ('樵', "qiáo"),
+    ('樶', "zuī"),
+    ('樷', "cóng"),
+    ('樸', "pǔ"),
+    ('樹', "shù"),
+    ('樺', "huà"),
+    ('樻', "kuì"),
+    ('樼', "zhēn"),
+    ('樽', "zūn"),
+    ('樾', "yuè"),
+    ('樿', "shàn"),
+    ('橀', "xī"),
+    ('橁', "chūn"),
+    ('橂', "diàn"),
+    ('橃', "fá"),
+    ('橄', "gǎn"),
+    ('橅', "mó"),
+    ('橆', "wǔ"),
+    ('橇', "qiāo"),
+    ('橈', "ráo"),
+    ('橉', "lìn"),
+    ('橊', "liú"),
+    ('橋', "qiáo"),
+    ('橌', "xiàn"),
+    ('橍', "rùn"),
+    ('橎', "fán"),
+    ('橏', "zhǎn"),
+    ('橐', "tuó"),
+    ('橑', "lǎo"),
+    ('橒', "yún"),
+    ('橓', "shùn"),
+    ('橔', "dūn"),
+    ('橕', "chēng"),
+    ('橖', "táng"),
+    ('橗', "méng"),
+    ('橘', "jú"),
+    ('橙', "chéng"),
+    ('橚', "sù"),
+    ('橛', "jué"),
+    ('橜', "jué"),
+    ('橝', "diàn"),
+    ('橞', "huì"),
+    ('機', "jī"),
+    ('橠', "nuǒ"),
+    ('橡', "xiàng"),
+    ('橢', "tuǒ"),
+    ('橣', "nǐng"),
+    ('橤', "ruǐ"),
+    ('橥', "zhū"),
+    ('橦', "tóng"),
+    ('橧', "zēng"),
+    ('橨', "fén"),
+    ('橩', "qióng"),
+    ('橪', "rǎn"),
+    ('橫', "héng"),
+    ('橬', "qián"),
+    ('橭', "gū"),
+    ('橮', "liǔ"),
+    ('橯', "lào"),
+    ('橰', "gāo"),
+    ('橱', "chú"),
+    ('橲', "xǐ"),
+    ('橳', "shèng"),
+    ('橴', "zǐ"),
+    ('橵', "san"),
+    ('橶', "jí"),
+    ('橷', "dōu"),
+    ('橸', "jīng"),
+    ('橹', "lǔ"),
+    ('橺', "jian"),
+    ('橻', "chu"),
+    ('橼', "yuán"),
+    ('橽', "tà"),
+    ('橾', "shū"),
+    ('橿', "jiāng"),
+    ('檀', "tán"),
+    ('檁', "lǐn"),
+    ('檂', "nóng"),
+    ('檃', "yǐn"),
+    ('檄', "xí"),
+    ('檅', "huì"),
+    ('檆', "shān"),
+    ('檇', "zuì"),
+    ('檈', "xuán"),
+    ('檉', "chēng"),
+    ('檊', "gàn"),
+    ('檋', "jú"),
+    ('檌', "zuì"),
+    ('檍', "yì"),
+    ('檎', "qín"),
+    ('檏', "pǔ"),
+    ('檐', "yán"),
+    ('檑', "léi"),
+    ('檒', "fēng"),
+    ('檓', "huǐ"),
+    ('檔', "dàng"),
+    ('檕', "jì"),
+    ('檖', "suì"),
+    ('檗', "bò"),
+    ('檘', "píng"),
+    ('檙', "chéng"),
+    ('檚', "chǔ"),
+    ('檛', "zhuā"),
+    ('檜', "guì"),
+    ('檝', "jí"),
+    ('檞', "jiě"),
+    ('檟', "jiǎ"),
+    ('檠', "qíng"),
+    ('檡', "zhái"),
+    ('檢', "jiǎn"),
+    ('檣', "qiáng"),
+    ('檤', "dào"),
+    ('檥', "yǐ"),
+    ('檦', "biǎo"),
+    ('檧', "sōng"),
+    ('檨', "shē"),
+    ('檩', "lǐn"),
+    ('檪', "lì"),
+    ('檫', "chá"),
+    ('檬', "méng"),
+    ('檭', "yín"),
+    ('檮', "táo"),
+    ('檯', "tái"),
+    ('檰', "mián"),
+    ('檱', "qí"),
+    ('檲', "tuán"),
+    ('檳', "bīn"),
+    ('檴', "huò"),
+    ('檵', "jì"),
+    ('檶', "qiān"),
+    ('檷', "nǐ"),
+    ('檸', "níng"),
+    ('檹', "yī"),
+    ('檺', "gǎo"),
+    ('檻', "kǎn"),
+    ('檼', "yìn"),
+    ('檽', "nòu"),
+    ('檾', "qǐng"),
+    ('檿', "yǎn"),
+    ('櫀', "qí"),
+    ('櫁', "mì"),
+    ('櫂', "zhào"),
+    ('櫃', "guì"),
+    ('櫄', "chūn"),
+    ('櫅', "jī"),
+    ('櫆', "kuí"),
+    ('櫇', "pó"),
+    ('櫈', "dèng"),
+    ('櫉', "chú"),
+    ('櫊', "gé"),
+    ('櫋', "mián"),
+    ('櫌', "yōu"),
+    ('櫍', "zhì"),
+    ('櫎', "huǎng"),
+    ('櫏', "qiān"),
+    ('櫐', "lěi"),
+    ('櫑', "léi"),
+    ('櫒', "sà"),
+    ('櫓', "lǔ"),
+    ('櫔', "lì"),
+    ('櫕', "cuán"),
+    ('櫖', "lǜ"),
+    ('櫗', "miè"),
+    ('櫘', "huì"),
+    ('櫙', "ōu"),
+    ('櫚', "lǘ"),
+    ('櫛', "zhì"),
+    ('櫜', "gāo"),
+    ('櫝', "dú"),
+    ('櫞', "yuán"),
+    ('櫟', "lì"),
+    ('櫠', "fèi"),
+    ('櫡', "zhuó"),
+    ('櫢', "sǒu"),
+    ('櫣', "lián"),
+    ('櫤', "jiàng"),
+    ('櫥', "chú"),
+    ('櫦', "qìng"),
+    ('櫧', "zhū"),
+    ('櫨', "lú"),
+    ('櫩', "yán"),
+    ('櫪', "lì"),
+    ('櫫', "zhū"),
+    ('櫬', "chèn"),
+    ('櫭', "jié"),
+    ('櫮', "è"),
+    ('櫯', "sū"),
+    ('櫰', "huái"),
+    ('櫱', "niè"),
+    ('櫲', "yù"),
+    ('櫳', "lóng"),
+    ('櫴', "lài"),
+    ('櫵', "jiao"),
+    ('櫶', "xiǎn"),
+    ('櫷', "guī"),
+    ('櫸', "jǔ"),
+    ('櫹', "xiāo"),
+    ('櫺', "líng"),
+    ('櫻', "yīng"),
+    ('櫼', "jiān"),
+    ('櫽', "yǐn"),
+    ('櫾', "yóu"),
+    ('櫿', "yíng"),
+    ('欀', "xiāng"),
+    ('欁', "nóng"),
+    ('欂', "bó"),
+    ('欃', "chán"),
+    ('欄', "lán"),
+    ('欅', "jǔ"),
+    ('欆', "shuāng"),
+    ('欇', "shè"),
+    ('欈', "wéi"),
+    ('欉', "cóng"),
+    ('權', "quán"),
+    ('欋', "qú"),
+    ('欌', "cáng"),
+    ('欍', "jiù"),
+    ('欎', "yù"),
+    ('欏', "luó"),
+    ('欐', "lì"),
+    ('欑', "cuán"),
+    ('欒', "luán"),
+    ('欓', "dǎng"),
+    ('欔', "jué"),
+    ('欕', "yán"),
+    ('欖', "lǎn"),
+    ('欗', "lán"),
+    ('欘', "zhú"),
+    ('欙', "léi"),
+    ('欚', "lǐ"),
+    ('欛', "bà"),
+    ('欜', "náng"),
+    ('欝', "yù"),
+    ('欞', "líng"),
+    ('欟', "guang"),
+    ('欠', "qiàn"),
+    ('次', "cì"),
+    ('欢', "huān"),
+    ('欣', "xīn"),
+    ('欤', "yú"),
+    ('欥', "yì"),
+    ('欦', "qiān"),
+    ('欧', "ōu"),
+    ('欨', "xū"),
+    ('欩', "chāo"),
+    ('欪', "chù"),
+    ('欫', "qì"),
+    ('欬', "kài"),
+    ('欭', "yì"),
+    ('欮', "jué"),
+    ('欯', "xì"),
+    ('欰', "xù"),
+    ('欱', "hē"),
+    ('欲', "yù"),
+    ('欳', "kuì"),
+    ('欴', "láng"),
+    ('欵', "kuǎn"),
+    ('欶', "shuò"),
+    ('欷', "xī"),
+    ('欸', "ê,āi"),
+    ('欹', "yī"),
+    ('欺', "qī"),
+    ('欻', "chuā"),
+    ('欼', "chǐ"),
+    ('欽', "qīn"),
+    ('款', "kuǎn"),
+    ('欿', "kǎn"),
+    ('歀', "kuǎn"),
+    ('歁', "kǎn"),
+    ('歂', "chuǎn"),
+    ('歃', "shà"),
+    ('歄', "guā"),
+    ('歅', "yīn"),
+    ('歆', "xīn"),
+    ('歇', "xiē"),
+    ('歈', "yú"),
+    ('歉', "qiàn"),
+    ('歊', "xiāo"),
+    ('歋', "yè"),
+    ('歌', "gē"),
+    ('歍', "wū"),
+    ('歎', "tàn"),
+    ('歏', "jìn"),
+    ('歐', "ōu"),
+    ('歑', "hū"),
+    ('歒', "tì"),
+    ('歓', "huān"),
+    ('歔', "xū"),
+    ('歕', "pēn"),
+    ('歖', "xǐ"),
+    ('歗', "xiào"),
+    ('歘', "chuā"),
+    ('歙', "shè"),
+    ('歚', "shàn"),
+    ('歛', "hān"),
+    ('歜', "chù"),
+    ('歝', "yì"),
+    ('歞', "è"),
+    ('歟', "yú"),
+    ('歠', "chuò"),
+    ('歡', "huān"),
+    ('止', "zhǐ"),
+    ('正', "zhèng,zhēng"),
+    ('此', "cǐ"),
+    ('步', "bù"),
+    ('武', "wǔ"),
+    ('歧', "qí"),
+    ('歨', "bù"),
+    ('歩', "bù"),
+    ('歪', "wāi"),
+    ('歫', "jù"),
+    ('歬', "qián"),
+    ('歭', "chí"),
+    ('歮', "sè"),
+    ('歯', "chǐ"),
+    ('歰', "sè"),
+    ('歱', "zhǒng"),
+    ('歲', "suì"),
+    ('歳', "suì"),
+    ('歴', "lì"),
+    ('歵', "zé"),
+    ('歶', "yú"),
+    ('歷', "lì"),
+    ('歸', "guī"),
+    ('歹', "dǎi"),
+    ('歺', "è"),
+    ('死', "sǐ"),
+    ('歼', "jiān"),
+    ('歽', "zhé"),
+    ('歾', "mò"),
+    ('歿', "mò"),
+    ('殀', "yāo"),
+    ('殁', "mò"),
+    ('殂', "cú"),
+    ('殃', "yāng"),
+    ('殄', "tiǎn"),
+    ('殅', "shēng"),
+    ('殆', "dài"),
+    ('殇', "shāng"),
+    ('殈', "xù"),
+    ('殉', "xùn"),
+    ('殊', "shū"),
+    ('残', "cán"),
+    ('殌', "jué"),
+    ('殍', "piǎo"),
+    ('殎', "qià"),
+    ('殏', "qiú"),
+    ('殐', "sù"),
+    ('殑', "qíng"),
+    ('殒', "yǔn"),
+    ('殓', "liàn"),
+    ('殔', "yì"),
+    ('殕', "fǒu"),
+    ('殖', "zhí,shi"),
+    ('殗', "yè"),
+    ('殘', "cán"),
+    ('殙', "hūn"),
+    ('殚', "dān"),
+    ('殛', "jí"),
+    ('殜', "dié"),
+    ('殝', "zhēn"),
+    ('殞', "yǔn"),
+    ('殟', "wēn"),
+    ('殠', "chòu"),
+    ('殡', "bìn"),
+    ('殢', "tì"),
+    ('殣', "jìn"),
+    ('殤', "shāng"),
+    ('殥', "yín"),
+    ('殦', "diāo"),
+    ('殧', "jiù"),
+    ('殨', "huì"),
+    ('殩', "cuàn"),
+    ('殪', "yì"),
+    ('殫', "dān"),
+    ('殬', "dù"),
+    ('殭', "jiāng"),
+    ('殮', "liàn"),
+    ('殯', "bìn"),
+    ('殰', "dú"),
+    ('殱', "jiān"),
+    ('殲', "jiān"),
+    ('殳', "shū"),
+    ('殴', "ōu"),
+    ('段', "duàn"),
+    ('殶', "zhù"),
+    ('殷', "yīn,yān,yǐn"),
+    ('殸', "qìng"),
+    ('殹', "yì"),
+    ('殺', "shā"),
+    ('殻', "qiào"),
+    ('殼', "ké"),
+    ('殽', "xiáo"),
+    ('殾', "xùn"),
+    ('殿', "diàn"),
+    ('毀', "huǐ"),
+    ('毁', "huǐ"),
+    ('毂', "gǔ"),
+    ('毃', "qiāo"),
+    ('毄', "jī"),
+    ('毅', "yì"),
+    ('毆', "ōu"),
+    ('毇', "huǐ"),
+    ('毈', "duàn"),
+    ('毉', "yī"),
+    ('毊', "xiāo"),
+    ('毋', "wú"),
+    ('毌', "guàn"),
+    ('母', "mǔ"),
+    ('毎', "měi"),
+    ('每', "měi"),
+    ('毐', "ǎi"),
+    ('毑', "jiě"),
+    ('毒', "dú"),
+    ('毓', "yù"),
+    ('比', "bǐ"),
+    ('毕', "bì"),
+    ('毖', "bì"),
+    ('毗', "pí"),
+    ('毘', "pí"),
+    ('毙', "bì"),
+    ('毚', "chán"),
+    ('毛', "máo"),
+    ('毜', "háo"),
+    ('毝', "cǎi"),
+    ('毞', "pí"),
+    ('毟', "liě"),
+    ('毠', "jiā"),
+    ('毡', "zhān"),
+    ('毢', "sāi"),
+    ('毣', "mù"),
+    ('毤', "tuò"),
+    ('毥', "xún"),
+    ('毦', "ěr"),
+    ('毧', "róng"),
+    ('毨', "xiǎn"),
+    ('毩', "jú"),
+    ('毪', "mú"),
+    ('毫', "háo"),
+    ('毬', "qiú"),
+    ('毭', "dòu"),
+    ('毮', "shā"),
+    ('毯', "tǎn"),
+    ('毰', "péi"),
+    ('毱', "jú"),
+    ('毲', "duō"),
+    ('毳', "cuì"),
+    ('毴', "bī"),
+    ('毵', "sān"),
+    ('毶', "sān"),
+    ('毷', "mào"),
+    ('毸', "sāi"),
+    ('毹', "shū"),
+    ('毺', "shū"),
+    ('毻', "tuò"),
+    ('毼', "hé"),
+    ('毽', "jiàn"),
+    ('毾', "tà"),
+    ('毿', "sān"),
+    ('氀', "lǘ"),
+    ('氁', "mú"),
+    ('氂', "máo"),
+    ('氃', "tóng"),
+    ('氄', "rǒng"),
+    ('氅', "chǎng"),
+    ('氆', "pǔ"),
+    ('氇', "lu"),
+    ('氈', "zhān"),
+    ('氉', "sào"),
+    ('氊', "zhān"),
+    ('氋', "méng"),
+    ('氌', "lǔ"),
+    ('氍', "qú"),
+    ('氎', "dié"),
+    ('氏', "shì"),
+    ('氐', "dī"),
+    ('民', "mín"),
+    ('氒', "jué"),
+    ('氓', "máng,méng"),
+    ('气', "qì"),
+    ('氕', "piē"),
+    ('氖', "nǎi"),
+    ('気', "qì"),
+    ('氘', "dāo"),
+    ('氙', "xiān"),
+    ('氚', "chuān"),
+    ('氛', "fēn"),
+    ('氜', "yáng"),
+    ('氝', "nèi"),
+    ('氞', "bin"),
+    ('氟', "fú"),
+    ('氠', "shēn"),
+    ('氡', "dōng"),
+    ('氢', "qīng"),
+    ('氣', "qì"),
+    ('氤', "yīn"),
+    ('氥', "xī"),
+    ('氦', "hài"),
+    ('氧', "yǎng"),
+    ('氨', "ān"),
+    ('氩', "yà"),
+    ('氪', "kè"),
+    ('氫', "qīng"),
+    ('氬', "yà"),
+    ('氭', "dōng"),
+    ('氮', "dàn"),
+    ('氯', "lǜ"),
+    ('氰', "qíng"),
+    ('氱', "yǎng"),
+    ('氲', "yūn"),
+    ('氳', "yūn"),
+    ('水', "shuǐ"),
+    ('氵', "shui"),
+    ('氶', "zhěng"),
+    ('氷', "bīng"),
+    ('永', "yǒng"),
+    ('氹', "dàng"),
+    ('氺', "shuǐ"),
+    ('氻', "lè"),
+    ('氼', "nì"),
+    ('氽', "tǔn"),
+    ('氾', "fán"),
+    ('氿', "guǐ"),
+    ('汀', "tīng"),
+    ('汁', "zhī"),
+    ('求', "qiú"),
+    ('汃', "bīn"),
+    ('汄', "zè"),
+    ('汅', "miǎn"),
+    ('汆', "cuān"),
+    ('汇', "huì"),
+    ('汈', "diāo"),
+    ('汉', "hàn"),
+    ('汊', "chà"),
+    ('汋', "zhuó"),
+    ('汌', "chuàn"),
+    ('汍', "wán"),
+    ('汎', "fàn"),
+    ('汏', "dà"),
+    ('汐', "xī"),
+    ('汑', "tuō"),
+    ('汒', "máng"),
+    ('汓', "qiú"),
+    ('汔', "qì"),
+    ('汕', "shàn"),
+    ('汖', "pìn"),
+    ('汗', "hàn,hán"),
+    ('汘', "qiān"),
+    ('汙', "wū"),
+    ('汚', "wū"),
+    ('汛', "xùn"),
+    ('汜', "sì"),
+    ('汝', "rǔ"),
+    ('汞', "gǒng"),
+    ('江', "jiāng"),
+    ('池', "chí"),
+    ('污', "wū"),
+    ('汢', "tu"),
+    ('汣', "jiǔ"),
+    ('汤', "tāng"),
+    ('汥', "zhī"),
+    ('汦', "zhǐ"),
+    ('汧', "qiān"),
+    ('汨', "mì"),
+    ('汩', "gǔ"),
+    ('汪', "wāng"),
+    ('汫', "jǐng"),
+    ('汬', "jǐng"),
+    ('汭', "ruì"),
+    ('汮', "jūn"),
+    ('汯', "hóng"),
+    ('汰', "tài"),
+    ('汱', "quǎn"),
+    ('汲', "jí"),
+    ('汳', "biàn"),
+    ('汴', "biàn"),
+    ('汵', "gàn"),
+    ('汶', "wèn"),
+    ('汷', "zhōng"),
+    ('汸', "fāng"),
+    ('汹', "xiōng"),
+    ('決', "jué"),
+    ('汻', "hǔ"),
+    ('汼', "niú"),
+    ('汽', "qì"),
+    ('汾', "fén"),
+    ('汿', "xù"),
+    ('沀', "xù"),
+    ('沁', "qìn"),
+    ('沂', "yí"),
+    ('沃', "wò"),
+    ('沄', "yún"),
+    ('沅', "yuán"),
+    ('沆', "hàng"),
+    ('沇', "yǎn"),
+    ('沈', "shěn,chén"),
+    ('沉', "chén"),
+    ('沊', "dàn"),
+    ('沋', "yóu"),
+    ('沌', "dùn"),
+    ('沍', "hù"),
+    ('沎', "huò"),
+    ('沏', "qī"),
+    ('沐', "mù"),
+    ('沑', "nǜ"),
+    ('沒', "méi"),
+    ('沓', "dá"),
+    ('沔', "miǎn"),
+    ('沕', "mì"),
+    ('沖', "chōng"),
+    ('沗', "pāng"),
+    ('沘', "bǐ"),
+    ('沙', "shā,shà"),
+    ('沚', "zhǐ"),
+    ('沛', "pèi"),
+    ('沜', "pàn"),
+    ('沝', "zhuǐ"),
+    ('沞', "zā"),
+    ('沟', "gōu"),
+    ('沠', "liú"),
+    ('没', "méi,mò"),
+    ('沢', "zé"),
+    ('沣', "fēng"),
+    ('沤', "ōu"),
+    ('沥', "lì"),
+    ('沦', "lún"),
+    ('沧', "cāng"),
+    ('沨', "fēng"),
+    ('沩', "wéi"),
+    ('沪', "hù"),
+    ('沫', "mò"),
+    ('沬', "mèi"),
+    ('沭', "shù"),
+    ('沮', "jǔ"),
+    ('沯', "zá"),
+    ('沰', "tuō"),
+    ('沱', "tuó"),
+    ('沲', "tuó"),
+    ('河', "hé"),
+    ('沴', "lì"),
+    ('沵', "mǐ"),
+    ('沶', "yí"),
+    ('沷', "fā"),
+    ('沸', "fèi"),
+    ('油', "yóu"),
+    ('沺', "tián"),
+    ('治', "zhì"),
+    ('沼', "zhǎo"),
+    ('沽', "gū"),
+    ('沾', "zhān"),
+    ('沿', "yán"),
+    ('泀', "sī"),
+    ('況', "kuàng"),
+    ('泂', "jiǒng"),
+    ('泃', "jū"),
+    ('泄', "xiè"),
+    ('泅', "qiú"),
+    ('泆', "yì"),
+    ('泇', "jiā"),
+    ('泈', "zhōng"),
+    ('泉', "quán"),
+    ('泊', "bó,pō"),
+    ('泋', "huì"),
+    ('泌', "mì,bì"),
+    ('泍', "bēn"),
+    ('泎', "zé"),
+    ('泏', "zhú"),
+    ('泐', "lè"),
+    ('泑', "yōu"),
+    ('泒', "gū"),
+    ('泓', "hóng"),
+    ('泔', "gān"),
+    ('法', "fǎ"),
+    ('泖', "mǎo"),
+    ('泗', "sì"),
+    ('泘', "hū"),
+    ('泙', "píng"),
+    ('泚', "cǐ"),
+    ('泛', "fàn"),
+    ('泜', "zhī"),
+    ('泝', "sù"),
+    ('泞', "nìng"),
+    ('泟', "chēng"),
+    ('泠', "líng"),
+    ('泡', "pào"),
+    ('波', "bō"),
+    ('泣', "qì"),
+    ('泤', "sì"),
+    ('泥', "ní,nì"),
+    ('泦', "jú"),
+    ('泧', "sà"),
+    ('注', "zhù"),
+    ('泩', "shēng"),
+    ('泪', "lèi"),
+    ('泫', "xuàn"),
+    ('泬', "jué"),
+    ('泭', "fú"),
+    ('泮', "pàn"),
+    ('泯', "mǐn"),
+    ('泰', "tài"),
+    ('泱', "yāng"),
+    ('泲', "jǐ"),
+    ('泳', "yǒng"),
+    ('泴', "guàn"),
+    ('泵', "bèng"),
+    ('泶', "xué"),
+    ('泷', "lóng"),
+    ('泸', "lú"),
+    ('泹', "dàn"),
+    ('泺', "luò"),
+    ('泻', "xiè"),
+    ('泼', "pō"),
+    ('泽', "zé"),
+    ('泾', "jīng"),
+    ('泿', "yín"),
+    ('洀', "pán"),
+    ('洁', "jié"),
+    ('洂', "yè"),
+    ('洃', "huī"),
+    ('洄', "huí"),
+    ('洅', "zài"),
+    ('洆', "chéng"),
+    ('洇', "yīn"),
+    ('洈', "wéi"),
+    ('洉', "hòu"),
+    ('洊', "jiàn"),
+    ('洋', "yáng"),
+    ('洌', "liè"),
+    ('洍', "sì"),
+    ('洎', "jì"),
+    ('洏', "ér"),
+    ('洐', "xíng"),
+    ('洑', "fú"),
+    ('洒', "sǎ"),
+    ('洓', "sè"),
+    ('洔', "zhǐ"),
+    ('洕', "yìn"),
+    ('洖', "wú"),
+    ('洗', "xǐ"),
+    ('洘', "kǎo"),
+    ('洙', "zhū"),
+    ('洚', "jiàng"),
+    ('洛', "luò"),
+    ('洜', "luò"),
+    ('洝', "àn"),
+    ('洞', "dòng"),
+    ('洟', "tì"),
+    ('洠', "móu"),
+    ('洡', "lèi"),
+    ('洢', "yī"),
+    ('洣', "mǐ"),
+    ('洤', "quán"),
+    ('津', "jīn"),
+    ('洦', "pò"),
+    ('洧', "wěi"),
+    ('洨', "xiáo"),
+    ('洩', "xiè"),
+    ('洪', "hóng"),
+    ('洫', "xù"),
+    ('洬', "sù"),
+    ('洭', "kuāng"),
+    ('洮', "táo"),
+    ('洯', "qiè"),
+    ('洰', "jù"),
+    ('洱', "ěr"),
+    ('洲', "zhōu"),
+    ('洳', "rù"),
+    ('洴', "píng"),
+    ('洵', "xún"),
+    ('洶', "xiōng"),
+    ('洷', "zhì"),
+    ('洸', "guāng"),
+    ('洹', "huán"),
+    ('洺', "míng"),
+    ('活', "huó"),
+    ('洼', "wā"),
+    ('洽', "qià"),
+    ('派', "pài"),
+    ('洿', "wū"),
+    ('浀', "qū"),
+    ('流', "liú"),
+    ('浂', "yì"),
+    ('浃', "jiā"),
+    ('浄', "jìng"),
+    ('浅', "qiǎn,jiān"),
+    ('浆', "jiāng"),
+    ('浇', "jiāo"),
+    ('浈', "zhēn"),
+    ('浉', "shī"),
+    ('浊', "zhuó"),
+    ('测', "cè"),
+    ('浌', "fá"),
+    ('浍', "huì"),
+    ('济', "jì,jǐ"),
+    ('浏', "liú"),
+    ('浐', "chǎn"),
+    ('浑', "hún"),
+    ('浒', "hǔ"),
+    ('浓', "nóng"),
+    ('浔', "xún"),
+    ('浕', "jìn"),
+    ('浖', "liè"),
+    ('浗', "qiú"),
+    ('浘', "wěi"),
+    ('浙', "zhè"),
+    ('浚', "jùn"),
+    ('浛', "hán"),
+    ('浜', "bāng"),
+    ('浝', "máng"),
+    ('浞', "zhuó"),
+    ('浟', "yóu"),
+    ('浠', "xī"),
+    ('浡', "bó"),
+    ('浢', "dòu"),
+    ('浣', "huàn"),
+    ('浤', "hóng"),
+    ('浥', "yì"),
+    ('浦', "pǔ"),
+    ('浧', "yǐng"),
+    ('浨', "lǎn"),
+    ('浩', "hào"),
+    ('浪', "làng"),
+    ('浫', "hǎn"),
+    ('浬', "lǐ"),
+    ('浭', "gēng"),
+    ('浮', "fú"),
+    ('浯', "wú"),
+    ('浰', "liàn"),
+    ('浱', "chún"),
+    ('浲', "féng"),
+    ('浳', "yì"),
+    ('浴', "yù"),
+    ('浵', "tóng"),
+    ('浶', "láo"),
+    ('海', "hǎi"),
+    ('浸', "jìn"),
+    ('浹', "jiā"),
+    ('浺', "chōng"),
+    ('浻', "jiǒng"),
+    ('浼', "měi"),
+    ('浽', "suī"),
+    ('浾', "chēng"),
+    ('浿', "pèi"),
+    ('涀', "xiàn"),
+    ('涁', "shèn"),
+    ('涂', "tú"),
+    ('涃', "kùn"),
+    ('涄', "pīng"),
+    ('涅', "niè"),
+    ('涆', "hàn"),
+    ('涇', "jīng"),
+    ('消', "xiāo"),
+    ('涉', "shè"),
+    ('涊', "niǎn"),
+    ('涋', "tū"),
+    ('涌', "yǒng"),
+    ('涍', "xiào"),
+    ('涎', "xián"),
+    ('涏', "tǐng"),
+    ('涐', "é"),
+    ('涑', "sù"),
+    ('涒', "tūn"),
+    ('涓', "juān"),
+    ('涔', "cén"),
+    ('涕', "tì"),
+    ('涖', "lì"),
+    ('涗', "shuì"),
+    ('涘', "sì"),
+    ('涙', "lèi"),
+    ('涚', "shuì"),
+    ('涛', "tāo"),
+    ('涜', "dú"),
+    ('涝', "lào"),
+    ('涞', "lái"),
+    ('涟', "lián"),
+    ('涠', "wéi"),
+    ('涡', "wō"),
+    ('涢', "yún"),
+    ('涣', "huàn"),
+    ('涤', "dí"),
+    ('涥', "hēng"),
+    ('润', "rùn"),
+    ('涧', "jiàn"),
+    ('涨', "zhǎng,zhàng"),
+    ('涩', "sè"),
+    ('涪', "fú"),
+    ('涫', "guàn"),
+    ('涬', "xìng"),
+    ('涭', "shòu"),
+    ('涮', "shuàn"),
+    ('涯', "yá"),
+    ('涰', "chuò"),
+    ('涱', "zhàng"),
+    ('液', "yè"),
+    ('涳', "kōng"),
+    ('涴', "wò"),
+    ('涵', "hán"),
+    ('涶', "tuō"),
+    ('涷', "dōng"),
+    ('涸', "hé"),
+    ('涹', "wō"),
+    ('涺', "jū"),
+    ('涻', "shè"),
+    ('涼', "liáng"),
+    ('涽', "hūn"),
+    ('涾', "tà"),
+    ('涿', "zhuō"),
+    ('淀', "diàn"),
+    ('淁', "qiè"),
+    ('淂', "dé"),
+    ('淃', "juàn"),
+    ('淄', "zī"),
+    ('淅', "xī"),
+    ('淆', "xiáo"),
+    ('淇', "qí"),
+    ('淈', "gǔ"),
+    ('淉', "guǒ"),
+    ('淊', "yān"),
+    ('淋', "lín,lìn"),
+    ('淌', "tǎng"),
+    ('淍', "zhōu"),
+    ('淎', "pěng"),
+    ('淏', "hào"),
+    ('淐', "chāng"),
+    ('淑', "shū"),
+    ('淒', "qī"),
+    ('淓', "fāng"),
+    ('淔', "zhí"),
+    ('淕', "lù"),
+    ('淖', "nào"),
+    ('淗', "jú"),
+    ('淘', "táo"),
+    ('淙', "cóng"),
+    ('淚', "lèi"),
+    ('淛', "zhè"),
+    ('淜', "píng"),
+    ('淝', "féi"),
+    ('淞', "sōng"),
+    ('淟', "tiǎn"),
+    ('淠', "pì"),
+    ('淡', "dàn"),
+    ('淢', "yù"),
+    ('淣', "ní"),
+    ('淤', "yū"),
+    ('淥', "lù"),
+    ('淦', "gàn"),
+    ('淧', "mì"),
+    ('淨', "jìng"),
+    ('淩', "líng"),
+    ('淪', "lún"),
+    ('淫', "yín"),
+    ('淬', "cuì"),
+    ('淭', "qú"),
+    ('淮', "huái"),
+    ('淯', "yù"),
+    ('淰', "niǎn"),
+    ('深', "shēn"),
+    ('淲', "biāo"),
+    ('淳', "chún"),
+    ('淴', "hū"),
+    ('淵', "yuān"),
+    ('淶', "lái"),
+    ('混', "hùn,hún"),
+    ('淸', "qīng"),
+    ('淹', "yān"),
+    ('淺', "qiǎn"),
+    ('添', "tiān"),
+    ('淼', "miǎo"),
+    ('淽', "zhǐ"),
+    ('淾', "yǐn"),
+    ('淿', "bó"),
+    ('渀', "bèn"),
+    ('渁', "yuān"),
+    ('渂', "wèn"),
+    ('渃', "ruò"),
+    ('渄', "fēi"),
+    ('清', "qīng"),
+    ('渆', "yuān"),
+    ('渇', "kě"),
+    ('済', "jì"),
+    ('渉', "shè"),
+    ('渊', "yuān"),
+    ('渋', "sè"),
+    ('渌', "lù"),
+    ('渍', "zì"),
+    ('渎', "dú"),
+    ('渏', "yī"),
+    ('渐', "jiàn,jiān"),
+    ('渑', "miǎn"),
+    ('渒', "pài"),
+    ('渓', "xī"),
+    ('渔', "yú"),
+    ('渕', "yuān"),
+    ('渖', "shěn"),
+    ('渗', "shèn"),
+    ('渘', "róu"),
+    ('渙', "huàn"),
+    ('渚', "zhǔ"),
+    ('減', "jiǎn"),
+    ('渜', "nuǎn"),
+    ('渝', "yú"),
+    ('渞', "qiú"),
+    ('渟', "tíng"),
+    ('渠', "qú"),
+    ('渡', "dù"),
+    ('渢', "fán"),
+    ('渣', "zhā"),
+    ('渤', "bó"),
+    ('渥', "wò"),
+    ('渦', "wō"),
+    ('渧', "dì"),
+    ('渨', "wēi"),
+    ('温', "wēn"),
+    ('渪', "rú"),
+    ('渫', "xiè"),
+    ('測', "cè"),
+    ('渭', "wèi"),
+    ('渮', "hé"),
+    ('港', "gǎng"),
+    ('渰', "yǎn"),
+    ('渱', "hóng"),
+    ('渲', "xuàn"),
+    ('渳', "mǐ"),
+    ('渴', "kě"),
+    ('渵', "máo"),
+    ('渶', "yīng"),
+    ('渷', "yǎn"),
+    ('游', "yóu"),
+    ('渹', "hōng"),
+    ('渺', "miǎo"),
+    ('渻', "shěng"),
+    ('渼', "měi"),
+    ('渽', "zāi"),
+    ('渾', "hún"),
+    ('渿', "nài"),
+    ('湀', "guǐ"),
+    ('湁', "chì"),
+    ('湂', "è"),
+    ('湃', "pài"),
+    ('湄', "méi"),
+    ('湅', "liàn"),
+    ('湆', "qì"),
+    ('湇', "qì"),
+    ('湈', "méi"),
+    ('湉', "tián"),
+    ('湊', "còu"),
+    ('湋', "wéi"),
+    ('湌', "cān"),
+    ('湍', "tuān"),
+    ('湎', "miǎn"),
+    ('湏', "huì"),
+    ('湐', "mò"),
+    ('湑', "xū"),
+    ('湒', "jí"),
+    ('湓', "pén"),
+    ('湔', "jiān"),
+    ('湕', "jiǎn"),
+    ('湖', "hú"),
+    ('湗', "fèng"),
+    ('湘', "xiāng"),
+    ('湙', "yì"),
+    ('湚', "yìn"),
+    ('湛', "zhàn"),
+    ('湜', "shí"),
+    ('湝', "jiē"),
+    ('湞', "chēng"),
+    ('湟', "huáng"),
+    ('湠', "tàn"),
+    ('湡', "yú"),
+    ('湢', "bì"),
+    ('湣', "mǐn"),
+    ('湤', "shī"),
+    ('湥', "tū"),
+    ('湦', "shēng"),
+    ('湧', "yǒng"),
+    ('湨', "jú"),
+    ('湩', "dòng"),
+    ('湪', "tuàn"),
+    ('湫', "jiǎo"),
+    ('湬', "jiǎo"),
+    ('湭', "qiú"),
+    ('湮', "yān"),
+    ('湯', "tāng"),
+    ('湰', "lóng"),
+    ('湱', "huò"),
+    ('湲', "yuán"),
+    ('湳', "nǎn"),
+    ('湴', "bàn"),
+    ('湵', "yǒu"),
+    ('湶', "quán"),
+    ('湷', "zhuāng"),
+    ('湸', "liàng"),
+    ('湹', "chán"),
+    ('湺', "xián"),
+    ('湻', "chún"),
+    ('湼', "niè"),
+    ('湽', "zī"),
+    ('湾', "wān"),
+    ('湿', "shī"),
+    ('満', "mǎn"),
+    ('溁', "yíng"),
+    ('溂', "là"),
+    ('溃', "kuì,huì"),
+    ('溄', "féng"),
+    ('溅', "jiàn"),
+    ('溆', "xù"),
+    ('溇', "lóu"),
+    ('溈', "wéi"),
+    ('溉', "gài"),
+    ('溊', "bō"),
+    ('溋', "yíng"),
+    ('溌', "pō"),
+    ('溍', "jìn"),
+    ('溎', "yàn"),
+    ('溏', "táng"),
+    ('源', "yuán"),
+    ('溑', "suǒ"),
+    ('溒', "yuán"),
+    ('溓', "lián"),
+    ('溔', "yǎo"),
+    ('溕', "méng"),
+    ('準', "zhǔn"),
+    ('溗', "chéng"),
+    ('溘', "kè"),
+    ('溙', "tài"),
+    ('溚', "tǎ"),
+    ('溛', "wā"),
+    ('溜', "liū,liù"),
+    ('溝', "gōu"),
+    ('溞', "sāo"),
+    ('溟', "míng"),
+    ('溠', "zhà"),
+    ('溡', "shí"),
+    ('溢', "yì"),
+    ('溣', "lùn"),
+    ('溤', "mǎ"),
+    ('溥', "pǔ"),
+    ('溦', "wēi"),
+    ('溧', "lì"),
+    ('溨', "zāi"),
+    ('溩', "wù"),
+    ('溪', "xī"),
+    ('溫', "wēn"),
+    ('溬', "qiāng"),
+    ('溭', "zé"),
+    ('溮', "shī"),
+    ('溯', "sù"),
+    ('溰', "ái"),
+    ('溱', "qín"),
+    ('溲', "sōu"),
+    ('溳', "yún"),
+    ('溴', "xiù"),
+    ('溵', "yīn"),
+    ('溶', "róng"),
+    ('溷', "hùn"),
+    ('溸', "sù"),
+    ('溹', "suò"),
+    ('溺', "nì"),
+    ('溻', "tā"),
+    ('溼', "shī"),
+    ('溽', "rù"),
+    ('溾', "āi"),
+    ('溿', "pàn"),
+    ('滀', "chù"),
+    ('滁', "chú"),
+    ('滂', "pāng"),
+    ('滃', "wēng"),
+    ('滄', "cāng"),
+    ('滅', "miè"),
+    ('滆', "gé"),
+    ('滇', "diān"),
+    ('滈', "hào"),
+    ('滉', "huàng"),
+    ('滊', "xì"),
+    ('滋', "zī"),
+    ('滌', "dí"),
+    ('滍', "zhì"),
+    ('滎', "xíng"),
+    ('滏', "fǔ"),
+    ('滐', "jié"),
+    ('滑', "huá"),
+    ('滒', "gē"),
+    ('滓', "zǐ"),
+    ('滔', "tāo"),
+    ('滕', "téng"),
+    ('滖', "suī"),
+    ('滗', "bì"),
+    ('滘', "jiào"),
+    ('滙', "huì"),
+    ('滚', "gǔn"),
+    ('滛', "yín"),
+    ('滜', "gāo"),
+    ('滝', "lóng"),
+    ('滞', "zhì"),
+    ('滟', "yàn"),
+    ('滠', "shè"),
+    ('满', "mǎn"),
+    ('滢', "yíng"),
+    ('滣', "chún"),
+    ('滤', "lǜ"),
+    ('滥', "làn"),
+    ('滦', "luán"),
+    ('滧', "yáo"),
+    ('滨', "bīn"),
+    ('滩', "tān"),
+    ('滪', "yù"),
+    ('滫', "xiǔ"),
+    ('滬', "hù"),
+    ('滭', "bì"),
+    ('滮', "biāo"),
+    ('滯', "zhì"),
+    ('滰', "jiàng"),
+    ('滱', "kòu"),
+    ('滲', "shèn"),
+    ('滳', "shāng"),
+    ('滴', "dī"),
+    ('滵', "mì"),
+    ('滶', "áo"),
+    ('滷', "lǔ"),
+    ('滸', "hǔ"),
+    ('滹', "hū"),
+    ('滺', "yōu"),
+    ('滻', "chǎn"),
+    ('滼', "fàn"),
+    ('滽', "yōng"),
+    ('滾', "gǔn"),
+    ('滿', "mǎn"),
+    ('漀', "qǐng"),
+    ('漁', "yú"),
+    ('漂', "piāo,piǎo,piào"),
+    ('漃', "jì"),
+    ('漄', "yá"),
+    ('漅', "cháo"),
+    ('漆', "qī"),
+    ('漇', "xǐ"),
+    ('漈', "jì"),
+    ('漉', "lù"),
+    ('漊', "lóu"),
+    ('漋', "lóng"),
+    ('漌', "jǐn"),
+    ('漍', "guó"),
+    ('漎', "cóng"),
+    ('漏', "lòu"),
+    ('漐', "zhí"),
+    ('漑', "gài"),
+    ('漒', "qiáng"),
+    ('漓', "lí"),
+    ('演', "yǎn"),
+    ('漕', "cáo"),
+    ('漖', "jiào"),
+    ('漗', "cōng"),
+    ('漘', "chún"),
+    ('漙', "tuán"),
+    ('漚', "ōu"),
+    ('漛', "téng"),
+    ('漜', "yě"),
+    ('漝', "xí"),
+    ('漞', "mì"),
+    ('漟', "táng"),
+    ('漠', "mò"),
+    ('漡', "shāng"),
+    ('漢', "hàn"),
+    ('漣', "lián"),
+    ('漤', "lǎn"),
+    ('漥', "wā"),
+    ('漦', "chí"),
+    ('漧', "gān"),
+    ('漨', "féng"),
+    ('漩', "xuán"),
+    ('漪', "yī"),
+    ('漫', "màn"),
+    ('漬', "zì"),
+    ('漭', "mǎng"),
+    ('漮', "kāng"),
+    ('漯', "luò"),
+    ('漰', "pēng"),
+    ('漱', "shù"),
+    ('漲', "zhǎng"),
+    ('漳', "zhāng"),
+    ('漴', "zhuàng"),
+    ('漵', "xù"),
+    ('漶', "huàn"),
+    ('漷', "huǒ"),
+    ('漸', "jiàn"),
+    ('漹', "yān"),
+    ('漺', "shuǎng"),
+    ('漻', "liáo"),
+    ('漼', "cuǐ"),
+    ('漽', "tí"),
+    ('漾', "yàng"),
+    ('漿', "jiāng"),
+    ('潀', "cóng"),
+    ('潁', "yǐng"),
+    ('潂', "hóng"),
+    ('潃', "xiǔ"),
+    ('潄', "shù"),
+    ('潅', "guàn"),
+    ('潆', "yíng"),
+    ('潇', "xiāo"),
+    ('潈', "zong"),
+    ('潉', "kūn"),
+    ('潊', "xù"),
+    ('潋', "liàn"),
+    ('潌', "zhì"),
+    ('潍', "wéi"),
+    ('潎', "pì"),
+    ('潏', "yù"),
+    ('潐', "jiào"),
+    ('潑', "pō"),
+    ('潒', "dàng"),
+    ('潓', "huì"),
+    ('潔', "jié"),
+    ('潕', "wǔ"),
+    ('潖', "pá"),
+    ('潗', "jí"),
+    ('潘', "pān"),
+    ('潙', "wéi"),
+    ('潚', "sù"),
+    ('潛', "qián"),
+    ('潜', "qián"),
+    ('潝', "xī"),
+    ('潞', "lù"),
+    ('潟', "xì"),
+    ('潠', "xùn"),
+    ('潡', "dùn"),
+    ('潢', "huáng"),
+    ('潣', "mǐn"),
+    ('潤', "rùn"),
+    ('潥', "sù"),
+    ('潦', "lǎo"),
+    ('潧', "zhēn"),
+    ('潨', "cóng"),
+    ('潩', "yì"),
+    ('潪', "zhè"),
+    ('潫', "wān"),
+    ('潬', "shàn"),
+    ('潭', "tán"),
+    ('潮', "cháo"),
+    ('潯', "xún"),
+    ('潰', "kuì"),
+    ('潱', "yē"),
+    ('潲', "shào"),
+    ('潳', "tú"),
+    ('潴', "zhū"),
+    ('潵', "sǎ"),
+    ('潶', "hēi"),
+    ('潷', "bì"),
+    ('潸', "shān"),
+    ('潹', "chán"),
+    ('潺', "chán"),
+    ('潻', "shǔ"),
+    ('潼', "tóng"),
+    ('潽', "pū"),
+    ('潾', "lín"),
+    ('潿', "wéi"),
+    ('澀', "sè"),
+    ('澁', "sè"),
+    ('澂', "chéng"),
+    ('澃', "jiǒng"),
+    ('澄', "chéng,dèng"),
+    ('澅', "huà"),
+    ('澆', "jiāo"),
+    ('澇', "lào"),
+    ('澈', "chè"),
+    ('澉', "gǎn"),
+    ('澊', "cūn"),
+    ('澋', "hòng"),
+    ('澌', "sī"),
+    ('澍', "shù"),
+    ('澎', "pēng"),
+    ('澏', "hán"),
+    ('澐', "yún"),
+    ('澑', "liù"),
+    ('澒', "hòng"),
+    ('澓', "fú"),
+    ('澔', "hào"),
+    ('澕', "hé"),
+    ('澖', "xián"),
+    ('澗', "jiàn"),
+    ('澘', "shān"),
+    ('澙', "xì"),
+    ('澚', "yu"),
+    ('澛', "lǔ"),
+    ('澜', "lán"),
+    ('澝', "nìng"),
+    ('澞', "yú"),
+    ('澟', "lǐn"),
+    ('澠', "miǎn"),
+    ('澡', "zǎo"),
+    ('澢', "dāng"),
+    ('澣', "huàn"),
+    ('澤', "zé"),
+    ('澥', "xiè"),
+    ('澦', "yù"),
+    ('澧', "lǐ"),
+    ('澨', "shì"),
+    ('澩', "xué"),
+    ('澪', "líng"),
+    ('澫', "wàn"),
+    ('澬', "zī"),
+    ('澭', "yōng"),
+    ('澮', "huì"),
+    ('澯', "càn"),
+    ('澰', "liàn"),
+    ('澱', "diàn"),
+    ('澲', "yè"),
+    ('澳', "ào"),
+    ('澴', "huán"),
+    ('澵', "zhēn"),
+    ('澶', "chán"),
+    ('澷', "màn"),
+    ('澸', "dǎn"),
+    ('澹', "dàn"),
+    ('澺', "yì"),
+    ('澻', "suì"),
+    ('澼', "pì"),
+    ('澽', "jù"),
+    ('澾', "tà"),
+    ('澿', "qín"),
+    ('激', "jī"),
+    ('濁', "zhuó"),
+    ('濂', "lián"),
+    ('濃', "nóng"),
+    ('濄', "guō"),
+    ('濅', "jìn"),
+    ('濆', "fén"),
+    ('濇', "sè"),
+    ('濈', "jí"),
+    ('濉', "suī"),
+    ('濊', "huì"),
+    ('濋', "chǔ"),
+    ('濌', "tà"),
+    ('濍', "sōng"),
+    ('濎', "dǐng"),
+    ('濏', "sè"),
+    ('濐', "zhǔ"),
+    ('濑', "lài"),
+    ('濒', "bīn"),
+    ('濓', "lián"),
+    ('濔', "mǐ"),
+    ('濕', "shī"),
+    ('濖', "shù"),
+    ('濗', "mì"),
+    ('濘', "nìng"),
+    ('濙', "yíng"),
+    ('濚', "yíng"),
+    ('濛', "méng"),
+    ('濜', "jìn"),
+    ('濝', "qí"),
+    ('濞', "bì"),
+    ('濟', "jì"),
+    ('濠', "háo"),
+    ('濡', "rú"),
+    ('濢', "cuì"),
+    ('濣', "wò"),
+    ('濤', "tāo"),
+    ('濥', "yǐn"),
+    ('濦', "yǐn"),
+    ('濧', "duì"),
+    ('濨', "cí"),
+    ('濩', "huò"),
+    ('濪', "qìng"),
+    ('濫', "làn"),
+    ('濬', "jùn"),
+    ('濭', "ǎi"),
+    ('濮', "pú"),
+    ('濯', "zhuó"),
+    ('濰', "wéi"),
+    ('濱', "bīn"),
+    ('濲', "gǔ"),
+    ('濳', "qián"),
+    ('濴', "yíng"),
+    ('濵', "bīn"),
+    ('濶', "kuò"),
+    ('濷', "fèi"),
+    ('濸', "cāng"),
+    ('濹', "me"),
+    ('濺', "jiàn"),
+    ('濻', "wěi"),
+    ('濼', "luò"),
+    ('濽', "zàn"),
+    ('濾', "lǜ"),
+    ('濿', "lì"),
+    ('瀀', "yōu"),
+    ('瀁', "yàng"),
+    ('瀂', "lǔ"),
+    ('瀃', "sì"),
+    ('瀄', "zhì"),
+    ('瀅', "yíng"),
+    ('瀆', "dú"),
+    ('瀇', "wǎng"),
+    ('瀈', "huī"),
+    ('瀉', "xiè"),
+    ('瀊', "pán"),
+    ('瀋', "shěn"),
+    ('瀌', "biāo"),
+    ('瀍', "chán"),
+    ('瀎', "mò"),
+    ('瀏', "liú"),
+    ('瀐', "jiān"),
+    ('瀑', "pù"),
+    ('瀒', "sè"),
+    ('瀓', "chéng"),
+    ('瀔', "gǔ"),
+    ('瀕', "bīn"),
+    ('瀖', "huò"),
+    ('瀗', "xiàn"),
+    ('瀘', "lú"),
+    ('瀙', "qìn"),
+    ('瀚', "hàn"),
+    ('瀛', "yíng"),
+    ('瀜', "róng"),
+    ('瀝', "lì"),
+    ('瀞', "jìng"),
+    ('瀟', "xiāo"),
+    ('瀠', "yíng"),
+    ('瀡', "suǐ"),
+    ('瀢', "wěi"),
+    ('瀣', "xiè"),
+    ('瀤', "huái"),
+    ('瀥', "xuè"),
+    ('瀦', "zhū"),
+    ('瀧', "lóng"),
+    ('瀨', "lài"),
+    ('瀩', "duì"),
+    ('瀪', "fán"),
+    ('瀫', "hú"),
+    ('瀬', "lài"),
+    ('瀭', "shū"),
+    ('瀮', "ling"),
+    ('瀯', "yíng"),
+    ('瀰', "mí"),
+    ('瀱', "jì"),
+    ('瀲', "liàn"),
+    ('瀳', "jiàn"),
+    ('瀴', "yíng"),
+    ('瀵', "fèn"),
+    ('瀶', "lín"),
+    ('瀷', "yì"),
+    ('瀸', "jiān"),
+    ('瀹', "yuè"),
+    ('瀺', "chán"),
+    ('瀻', "dài"),
+    ('瀼', "ráng"),
+    ('瀽', "jiǎn"),
+    ('瀾', "lán"),
+    ('瀿', "fán"),
+    ('灀', "shuàng"),
+    ('灁', "yuān"),
+    ('灂', "zhuó"),
+    ('灃', "fēng"),
+    ('灄', "shè"),
+    ('灅', "lěi"),
+    ('灆', "lán"),
+    ('灇', "cóng"),
+    ('灈', "qú"),
+    ('灉', "yōng"),
+    ('灊', "qián"),
+    ('灋', "fǎ"),
+    ('灌', "guàn"),
+    ('灍', "jué"),
+    ('灎', "yàn"),
+    ('灏', "hào"),
+    ('灐', "yíng"),
+    ('灑', "sǎ"),
+    ('灒', "zàn"),
+    ('灓', "luán"),
+    ('灔', "yàn"),
+    ('灕', "lí"),
+    ('灖', "mǐ"),
+    ('灗', "shàn"),
+    ('灘', "tān"),
+    ('灙', "dǎng"),
+    ('灚', "jiǎo"),
+    ('灛', "chǎn"),
+    ('灜', "yíng"),
+    ('灝', "hào"),
+    ('灞', "bà"),
+    ('灟', "zhú"),
+    ('灠', "lǎn"),
+    ('灡', "lán"),
+    ('灢', "nǎng"),
+    ('灣', "wān"),
+    ('灤', "luán"),
+    ('灥', "xún"),
+    ('灦', "xiǎn"),
+    ('灧', "yàn"),
+    ('灨', "gàn"),
+    ('灩', "yàn"),
+    ('灪', "yù"),
+    ('火', "huǒ"),
+    ('灬', "biāo"),
+    ('灭', "miè"),
+    ('灮', "guāng"),
+    ('灯', "dēng"),
+    ('灰', "huī"),
+    ('灱', "xiāo"),
+    ('灲', "xiāo"),
+    ('灳', "huī"),
+    ('灴', "hōng"),
+    ('灵', "líng"),
+    ('灶', "zào"),
+    ('灷', "zhuàn"),
+    ('灸', "jiǔ"),
+    ('灹', "zhà"),
+    ('灺', "xiè"),
+    ('灻', "chì"),
+    ('灼', "zhuó"),
+    ('災', "zāi"),
+    ('灾', "zāi"),
+    ('灿', "càn"),
+    ('炀', "yáng"),
+    ('炁', "qì"),
+    ('炂', "zhōng"),
+    ('炃', "fén"),
+    ('炄', "niǔ"),
+    ('炅', "jiǒng"),
+    ('炆', "wén"),
+    ('炇', "pū"),
+    ('炈', "yì"),
+    ('炉', "lú"),
+    ('炊', "chuī"),
+    ('炋', "pī"),
+    ('炌', "kài"),
+    ('炍', "pàn"),
+    ('炎', "yán"),
+    ('炏', "kài"),
+    ('炐', "pàng"),
+    ('炑', "mù"),
+    ('炒', "chǎo"),
+    ('炓', "liào"),
+    ('炔', "guì"),
+    ('炕', "kàng"),
+    ('炖', "dùn"),
+    ('炗', "guāng"),
+    ('炘', "xīn"),
+    ('炙', "zhì"),
+    ('炚', "guāng"),
+    ('炛', "guāng"),
+    ('炜', "wěi"),
+    ('炝', "qiàng"),
+    ('炞', "bian"),
+    ('炟', "dá"),
+    ('炠', "xiá"),
+    ('炡', "zhēng"),
+    ('炢', "zhú"),
+    ('炣', "kě"),
+    ('炤', "zhào"),
+    ('炥', "fú"),
+    ('炦', "bá"),
+    ('炧', "xiè"),
+    ('炨', "xiè"),
+    ('炩', "lìng"),
+    ('炪', "zhuō"),
+    ('炫', "xuàn"),
+    ('炬', "jù"),
+    ('炭', "tàn"),
+    ('炮', "pào,páo,bāo"),
+    ('炯', "jiǒng"),
+    ('炰', "páo"),
+    ('炱', "tái"),
+    ('炲', "tái"),
+    ('炳', "bǐng"),
+    ('炴', "yǎng"),
+    ('炵', "tōng"),
+    ('炶', "shǎn"),
+    ('炷', "zhù"),
+    ('炸', "zhà,zhá"),
+    ('点', "diǎn"),
+    ('為', "wèi"),
+    ('炻', "shí"),
+    ('炼', "liàn"),
+    ('炽', "chì"),
+    ('炾', "huǎng"),
+    ('炿', "zhōu"),
+    ('烀', "hū"),
+    ('烁', "shuò"),
+    ('烂', "làn"),
+    ('烃', "tīng"),
+    ('烄', "jiǎo"),
+    ('烅', "xù"),
+    ('烆', "héng"),
+    ('烇', "quǎn"),
+    ('烈', "liè"),
+    ('烉', "huàn"),
+    ('烊', "yáng"),
+    ('烋', "xiū"),
+    ('烌', "xiū"),
+    ('烍', "xiǎn"),
+    ('烎', "yín"),
+    ('烏', "wū"),
+    ('烐', "zhōu"),
+    ('烑', "yáo"),
+    ('烒', "shì"),
+    ('烓', "wēi"),
+    ('烔', "tóng"),
+    ('烕', "miè"),
+    ('烖', "zāi"),
+    ('烗', "kài"),
+    ('烘', "hōng"),
+    ('烙', "lào,luò"),
+    ('烚', "xiá"),
+    ('烛', "zhú"),
+    ('烜', "xuǎn"),
+    ('烝', "zhēng"),
+    ('烞', "pò"),
+    ('烟', "yān"),
+    ('烠', "huí"),
+    ('烡', "guāng"),
+    ('烢', "chè"),
+    ('烣', "huī"),
+    ('烤', "kǎo"),
+    ('烥', "jù"),
+    ('烦', "fán"),
+    ('烧', "shāo"),
+    ('烨', "yè"),
+    ('烩', "huì"),
+    ('烫', "tàng"),
+    ('烬', "jìn"),
+    ('热', "rè"),
+    ('烮', "liè"),
+    ('烯', "xī"),
+    ('烰', "fú"),
+    ('烱', "jiǒng"),
+    ('烲', "xiè"),
+    ('烳', "pǔ"),
+    ('烴', "tīng"),
+    ('烵', "zhuó"),
+    ('烶', "tǐng"),
+    ('烷', "wán"),
+    ('烸', "hǎi"),
+    ('烹', "pēng"),
+    ('烺', "lǎng"),
+    ('烻', "yàn"),
+    ('烼', "xù"),
+    ('烽', "fēng"),
+    ('烾', "chì"),
+    ('烿', "róng"),
+    ('焀', "hú"),
+    ('焁', "xī"),
+    ('焂', "shū"),
+    ('焃', "hè"),
+    ('焄', "xūn"),
+    ('焅', "kù"),
+    ('焆', "juān"),
+    ('焇', "xiāo"),
+    ('焈', "xī"),
+    ('焉', "yān"),
+    ('焊', "hàn"),
+    ('焋', "zhuàng"),
+    ('焌', "jùn"),
+    ('焍', "dì"),
+    ('焎', "xiè"),
+    ('焏', "jí"),
+    ('焐', "wù"),
+    ('焑', "yān"),
+    ('焒', "lǚ"),
+    ('焓', "hán"),
+    ('焔', "yàn"),
+    ('焕', "huàn"),
+    ('焖', "mèn"),
+    ('焗', "jú"),
+    ('焘', "dào"),
+    ('焙', "bèi"),
+    ('焚', "fén"),
+    ('焛', "lìn"),
+    ('焜', "kūn"),
+    ('焝', "hùn"),
+    ('焞', "tūn"),
+    ('焟', "xī"),
+    ('焠', "cuì"),
+    ('無', "wú"),
+    ('焢', "hōng"),
+    ('焣', "chǎo"),
+    ('焤', "fǔ"),
+    ('焥', "wò"),
+    ('焦', "jiāo"),
+    ('焧', "cōng"),
+    ('焨', "fèng"),
+    ('焩', "píng"),
+    ('焪', "qióng"),
+    ('焫', "ruò"),
+    ('焬', "xī"),
+    ('焭', "qióng"),
+    ('焮', "xìn"),
+    ('焯', "chāo"),
+    ('焰', "yàn"),
+    ('焱', "yàn"),
+    ('焲', "yì"),
+    ('焳', "jué"),
+    ('焴', "yù"),
+    ('焵', "gàng"),
+    ('然', "rán"),
+    ('焷', "pí"),
+    ('焸', "xiòng"),
+    ('焹', "gàng"),
+    ('焺', "shēng"),
+    ('焻', "chàng"),
+    ('焼', "shāo"),
+    ('焽', "xiǒng"),
+    ('焾', "niǎn"),
+    ('焿', "gēng"),
+    ('煀', "wei"),
+    ('煁', "chén"),
+    ('煂', "hè"),
+    ('煃', "kuǐ"),
+    ('煄', "zhǒng"),
+    ('煅', "duàn"),
+    ('煆', "xiā"),
+    ('煇', "huī"),
+    ('煈', "fèng"),
+    ('煉', "liàn"),
+    ('煊', "xuān"),
+    ('煋', "xīng"),
+    ('煌', "huáng"),
+    ('煍', "jiǎo"),
+    ('煎', "jiān"),
+    ('煏', "bì"),
+    ('煐', "yīng"),
+    ('煑', "zhǔ"),
+    ('煒', "wěi"),
+    ('煓', "tuān"),
+    ('煔', "shǎn"),
+    ('煕', "xī"),
+    ('煖', "nuǎn"),
+    ('煗', "nuǎn"),
+    ('煘', "chán"),
+    ('煙', "yān"),
+    ('煚', "jiǒng"),
+    ('煛', "jiǒng"),
+    ('煜', "yù"),
+    ('煝', "mèi"),
+    ('煞', "shā"),
+    ('煟', "wèi"),
+    ('煠', "zhá"),
+    ('煡', "jìn"),
+    ('煢', "qióng"),
+    ('煣', "róu"),
+    ('煤', "méi"),
+    ('煥', "huàn"),
+    ('煦', "xù"),
+    ('照', "zhào"),
+    ('煨', "wēi"),
+    ('煩', "fán"),
+    ('煪', "qiú"),
+    ('煫', "suì"),
+    ('煬', "yáng"),
+    ('煭', "liè"),
+    ('煮', "zhǔ"),
+    ('煯', "jiē"),
+    ('煰', "zào"),
+    ('煱', "guā"),
+    ('煲', "bāo"),
+    ('煳', "hú"),
+    ('煴', "yūn"),
+    ('煵', "nǎn"),
+    ('煶', "shì"),
+    ('煷', "liang"),
+    ('煸', "biān"),
+    ('煹', "gòu"),
+    ('煺', "tuì"),
+    ('煻', "táng"),
+    ('煼', "chǎo"),
+    ('煽', "shān"),
+    ('煾', "ēn"),
+    ('煿', "bó"),
+    ('熀', "huǎng"),
+    ('熁', "xié"),
+    ('熂', "xì"),
+    ('熃', "wù"),
+    ('熄', "xī"),
+    ('熅', "yùn"),
+    ('熆', "hé"),
+    ('熇', "hè"),
+    ('熈', "xī"),
+    ('熉', "yún"),
+    ('熊', "xióng"),
+    ('熋', "nái"),
+    ('熌', "shǎn"),
+    ('熍', "qióng"),
+    ('熎', "yào"),
+    ('熏', "xūn,xùn"),
+    ('熐', "mì"),
+    ('熑', "lián"),
+    ('熒', "yíng"),
+    ('熓', "wǔ"),
+    ('熔', "róng"),
+    ('熕', "gōng"),
+    ('熖', "yàn"),
+    ('熗', "qiàng"),
+    ('熘', "liū"),
+    ('熙', "xī"),
+    ('熚', "bì"),
+    ('熛', "biāo"),
+    ('熜', "cōng"),
+    ('熝', "lù"),
+    ('熞', "jiān"),
+    ('熟', "shú,shóu"),
+    ('熠', "yì"),
+    ('熡', "lóu"),
+    ('熢', "péng"),
+    ('熣', "suī"),
+    ('熤', "yì"),
+    ('熥', "tēng"),
+    ('熦', "jué"),
+    ('熧', "zōng"),
+    ('熨', "yùn,yù"),
+    ('熩', "hù"),
+    ('熪', "yí"),
+    ('熫', "zhì"),
+    ('熬', "áo"),
+    ('熭', "wèi"),
+    ('熮', "liǔ"),
+    ('熯', "hàn"),
+    ('熰', "ōu"),
+    ('熱', "rè"),
+    ('熲', "jiǒng"),
+    ('熳', "màn"),
+    ('熴', "kūn"),
+    ('熵', "shāng"),
+    ('熶', "cuàn"),
+    ('熷', "zēng"),
+    ('熸', "jiān"),
+    ('熹', "xī"),
+    ('熺', "xī"),
+    ('熻', "xī"),
+    ('熼', "yì"),
+    ('熽', "xiào"),
+    ('熾', "chì"),
+    ('熿', "huáng"),
+    ('燀', "chǎn"),
+    ('燁', "yè"),
+    ('燂', "tán"),
+    ('燃', "rán"),
+    ('燄', "yàn"),
+    ('燅', "xún"),
+    ('燆', "qiāo"),
+    ('燇', "jùn"),
+    ('燈', "dēng"),
+    ('燉', "dùn"),
+    ('燊', "shēn"),
+    ('燋', "jiāo"),
+    ('燌', "fén"),
+    ('燍', "sī"),
+    ('燎', "liáo,liǎo"),
+    ('燏', "yù"),
+    ('燐', "lín"),
+    ('燑', "tóng"),
+    ('燒', "shāo"),
+    ('燓', "fén"),
+    ('燔', "fán"),
+    ('燕', "yàn,yān"),
+    ('燖', "xún"),
+    ('燗', "làn"),
+    ('燘', "měi"),
+    ('燙', "tàng"),
+    ('燚', "yì"),
+    ('燛', "jiǒng"),
+    ('燜', "mèn"),
+    ('燝', "jing"),
+    ('燞', "jiǎo"),
+    ('營', "yíng"),
+    ('燠', "yù"),
+    ('燡', "yì"),
+    ('燢', "xué"),
+    ('燣', "lán"),
+    ('燤', "tài"),
+    ('燥', "zào"),
+    ('燦', "càn"),
+    ('燧', "suì"),
+    ('燨', "xī"),
+    ('燩', "què"),
+    ('燪', "zǒng"),
+    ('燫', "lián"),
+    ('燬', "huǐ"),
+    ('燭', "zhú"),
+    ('燮', "xiè"),
+    ('燯', "líng"),
+    ('燰', "wēi"),
+    ('燱', "yì"),
+    ('燲', "xié"),
+    ('燳', "zhào"),
+    ('燴', "huì"),
+    ('燵', "dá"),
+    ('燶', "nóng"),
+    ('燷', "lán"),
+    ('燸', "rú"),
+    ('燹', "xiǎn"),
+    ('燺', "hè"),
+    ('燻', "xūn"),
+    ('燼', "jìn"),
+    ('燽', "chóu"),
+    ('燾', "dào"),
+    ('燿', "yào"),
+    ('爀', "hè"),
+    ('爁', "làn"),
+    ('爂', "biāo"),
+    ('爃', "róng"),
+    ('爄', "lì"),
+    ('爅', "mò"),
+    ('爆', "bào"),
+    ('爇', "ruò"),
+    ('爈', "lǜ"),
+    ('爉', "là"),
+    ('爊', "āo"),
+    ('爋', "xūn"),
+    ('爌', "kuàng"),
+    ('爍', "shuò"),
+    ('爎', "liáo"),
+    ('爏', "lì"),
+    ('爐', "lú"),
+    ('爑', "jué"),
+    ('爒', "liǎo"),
+    ('爓', "yàn"),
+    ('爔', "xī"),
+    ('爕', "xiè"),
+    ('爖', "lóng"),
+    ('爗', "yè"),
+    ('爘', "cān"),
+    ('爙', "rǎng"),
+    ('爚', "yuè"),
+    ('爛', "làn"),
+    ('爜', "cóng"),
+    ('爝', "jué"),
+    ('爞', "chóng"),
+    ('爟', "guàn"),
+    ('爠', "ju"),
+    ('爡', "chè"),
+    ('爢', "mí"),
+    ('爣', "tǎng"),
+    ('爤', "làn"),
+    ('爥', "zhú"),
+    ('爦', "lǎn"),
+    ('爧', "líng"),
+    ('爨', "cuàn"),
+    ('爩', "yù"),
+    ('爪', "zhuǎ,zhǎo"),
+    ('爫', "zhǎo"),
+    ('爬', "pá"),
+    ('爭', "zhēng"),
+    ('爮', "páo"),
+    ('爯', "chēng"),
+    ('爰', "yuán"),
+    ('爱', "ài"),
+    ('爲', "wèi"),
+    ('爳', "han"),
+    ('爴', "jué"),
+    ('爵', "jué"),
+    ('父', "fù"),
+    ('爷', "yé"),
+    ('爸', "bà"),
+    ('爹', "diē"),
+    ('爺', "yé"),
+    ('爻', "yáo"),
+    ('爼', "zǔ"),
+    ('爽', "shuǎng"),
+    ('爾', "ěr"),
+    ('爿', "pán"),
+    ('牀', "chuáng"),
+    ('牁', "kē"),
+    ('牂', "zāng"),
+    ('牃', "dié"),
+    ('牄', "qiāng"),
+    ('牅', "yōng"),
+    ('牆', "qiáng"),
+    ('片', "piàn,piān"),
+    ('版', "bǎn"),
+    ('牉', "pàn"),
+    ('牊', "cháo"),
+    ('牋', "jiān"),
+    ('牌', "pái"),
+    ('牍', "dú"),
+    ('牎', "chuāng"),
+    ('牏', "yú"),
+    ('牐', "zhá"),
+    ('牑', "biān"),
+    ('牒', "dié"),
+    ('牓', "bǎng"),
+    ('牔', "bó"),
+    ('牕', "chuāng"),
+    ('牖', "yǒu"),
+    ('牗', "yǒu"),
+    ('牘', "dú"),
+    ('牙', "yá"),
+    ('牚', "chēng"),
+    ('牛', "niú"),
+    ('牜', "niú"),
+    ('牝', "pìn"),
+    ('牞', "jiū"),
+    ('牟', "móu"),
+    ('牠', "tā"),
+    ('牡', "mǔ"),
+    ('牢', "láo"),
+    ('牣', "rèn"),
+    ('牤', "māng"),
+    ('牥', "fāng"),
+    ('牦', "máo"),
+    ('牧', "mù"),
+    ('牨', "gāng"),
+    ('物', "wù"),
+    ('牪', "yàn"),
+    ('牫', "gē"),
+    ('牬', "bèi"),
+    ('牭', "sì"),
+    ('牮', "jiàn"),
+    ('牯', "gǔ"),
+    ('牰', "yòu"),
+    ('牱', "gē"),
+    ('牲', "shēng"),
+    ('牳', "mǔ"),
+    ('牴', "dǐ"),
+    ('牵', "qiān"),
+    ('牶', "quàn"),
+    ('牷', "quán"),
+    ('牸', "zì"),
+    ('特', "tè"),
+    ('牺', "xī"),
+    ('牻', "máng"),
+    ('牼', "kēng"),
+    ('牽', "qiān"),
+    ('牾', "wǔ"),
+    ('牿', "gù"),
+    ('犀', "xī"),
+    ('犁', "lí"),
+    ('犂', "lí"),
+    ('犃', "pǒu"),
+    ('犄', "jī"),
+    ('犅', "gāng"),
+    ('犆', "zhí"),
+    ('犇', "bēn"),
+    ('犈', "quán"),
+    ('犉', "chún"),
+    ('犊', "dú"),
+    ('犋', "jù"),
+    ('犌', "jiā"),
+    ('犍', "jiān"),
+    ('犎', "fēng"),
+    ('犏', "piān"),
+    ('犐', "kē"),
+    ('犑', "jú"),
+    ('犒', "kào"),
+    ('犓', "chú"),
+    ('犔', "xì"),
+    ('犕', "bèi"),
+    ('犖', "luò"),
+    ('犗', "jiè"),
+    ('犘', "má"),
+    ('犙', "sān"),
+    ('犚', "wèi"),
+    ('犛', "máo"),
+    ('犜', "dūn"),
+    ('犝', "tóng"),
+    ('犞', "qiáo"),
+    ('犟', "jiàng"),
+    ('犠', "xī"),
+    ('犡', "lì"),
+    ('犢', "dú"),
+    ('犣', "liè"),
+    ('犤', "pái"),
+    ('犥', "piāo"),
+    ('犦', "bó"),
+    ('犧', "xī"),
+    ('犨', "chōu"),
+    ('犩', "wéi"),
+    ('犪', "kuí"),
+    ('犫', "chōu"),
+    ('犬', "quǎn"),
+    ('犭', "quǎn"),
+    ('犮', "bá"),
+    ('犯', "fàn"),
+    ('犰', "qiú"),
+    ('犱', "jǐ"),
+    ('犲', "chái"),
+    ('犳', "zhuó"),
+    ('犴', "àn"),
+    ('犵', "gē"),
+    ('状', "zhuàng"),
+    ('犷', "guǎng"),
+    ('犸', "mà"),
+    ('犹', "yóu"),
+    ('犺', "kàng"),
+    ('犻', "bó"),
+    ('犼', "hǒu"),
+    ('犽', "yà"),
+    ('犾', "yín"),
+    ('犿', "huān"),
+    ('狀', "zhuàng"),
+    ('狁', "yǔn"),
+    ('狂', "kuáng"),
+    ('狃', "niǔ"),
+    ('狄', "dí"),
+    ('狅', "kuáng"),
+    ('狆', "zhòng"),
+    ('狇', "mù"),
+    ('狈', "bèi"),
+    ('狉', "pī"),
+    ('狊', "jú"),
+    ('狋', "yí"),
+    ('狌', "shēng"),
+    ('狍', "páo"),
+    ('狎', "xiá"),
+    ('狏', "tuó"),
+    ('狐', "hú"),
+    ('狑', "líng"),
+    ('狒', "fèi"),
+    ('狓', "pí"),
+    ('狔', "nǐ"),
+    ('狕', "yǎo"),
+    ('狖', "yòu"),
+    ('狗', "gǒu"),
+    ('狘', "xuè"),
+    ('狙', "jū"),
+    ('狚', "dàn"),
+    ('狛', "bó"),
+    ('狜', "kǔ"),
+    ('狝', "xiǎn"),
+    ('狞', "níng"),
+    ('狟', "huán"),
+    ('狠', "hěn"),
+    ('狡', "jiǎo"),
+    ('狢', "hé"),
+    ('狣', "zhào"),
+    ('狤', "jí"),
+    ('狥', "xùn"),
+    ('狦', "shān"),
+    ('狧', "tà"),
+    ('狨', "róng"),
+    ('狩', "shòu"),
+    ('狪', "tóng"),
+    ('狫', "lǎo"),
+    ('独', "dú"),
+    ('狭', "xiá"),
+    ('狮', "shī"),
+    ('狯', "kuài"),
+    ('狰', "zhēng"),
+    ('狱', "yù"),
+    ('狲', "sūn"),
+    ('狳', "yú"),
+    ('狴', "bì"),
+    ('狵', "máng"),
+    ('狶', "xī"),
+    ('狷', "juàn"),
+    ('狸', "lí"),
+    ('狹', "xiá"),
+    ('狺', "yín"),
+    ('狻', "suān"),
+    ('狼', "láng"),
+    ('狽', "bèi"),
+    ('狾', "zhì"),
+    ('狿', "yán"),
+    ('猀', "shā"),
+    ('猁', "lì"),
+    ('猂', "hàn"),
+    ('猃', "xiǎn"),
+    ('猄', "jīng"),
+    ('猅', "pái"),
+    ('猆', "fēi"),
+    ('猇', "xiāo"),
+    ('猈', "bài"),
+    ('猉', "qí"),
+    ('猊', "ní"),
+    ('猋', "biāo"),
+    ('猌', "yìn"),
+    ('猍', "lái"),
+    ('猎', "liè"),
+    ('猏', "jiān"),
+    ('猐', "qiāng"),
+    ('猑', "kūn"),
+    ('猒', "yàn"),
+    ('猓', "guǒ"),
+    ('猔', "zòng"),
+    ('猕', "mí"),
+    ('猖', "chāng"),
+    ('猗', "yī"),
+    ('猘', "zhì"),
+    ('猙', "zhēng"),
+    ('猚', "yá"),
+    ('猛', "měng"),
+    ('猜', "cāi"),
+    ('猝', "cù"),
+    ('猞', "shē"),
+    ('猟', "liè"),
+    ('猠', "diǎn"),
+    ('猡', "luó"),
+    ('猢', "hú"),
+    ('猣', "zōng"),
+    ('猤', "guì"),
+    ('猥', "wěi"),
+    ('猦', "fēng"),
+    ('猧', "wō"),
+    ('猨', "yuán"),
+    ('猩', "xīng"),
+    ('猪', "zhū"),
+    ('猫', "māo"),
+    ('猬', "wèi"),
+    ('猭', "chuān"),
+    ('献', "xiàn"),
+    ('猯', "tuān"),
+    ('猰', "yà"),
+    ('猱', "náo"),
+    ('猲', "xiē"),
+    ('猳', "jiā"),
+    ('猴', "hóu"),
+    ('猵', "biān"),
+    ('猶', "yóu"),
+    ('猷', "yóu"),
+    ('猸', "méi"),
+    ('猹', "chá"),
+    ('猺', "yáo"),
+    ('猻', "sūn"),
+    ('猼', "bó"),
+    ('猽', "míng"),
+    ('猾', "huá"),
+    ('猿', "yuán"),
+    ('獀', "sōu"),
+    ('獁', "mà"),
+    ('獂', "yuán"),
+    ('獃', "dāi"),
+    ('獄', "yù"),
+    ('獅', "shī"),
+    ('獆', "háo"),
+    ('獇', "qiāng"),
+    ('獈', "yì"),
+    ('獉', "zhēn"),
+    ('獊', "cāng"),
+    ('獋', "háo"),
+    ('獌', "màn"),
+    ('獍', "jìng"),
+    ('獎', "jiǎng"),
+    ('獏', "mò"),
+    ('獐', "zhāng"),
+    ('獑', "chán"),
+    ('獒', "áo"),
+    ('獓', "áo"),
+    ('獔', "háo"),
+    ('獕', "cuī"),
+    ('獖', "bèn"),
+    ('獗', "jué"),
+    ('獘', "bì"),
+    ('獙', "bì"),
+    ('獚', "huáng"),
+    ('獛', "pú"),
+    ('獜', "lín"),
+    ('獝', "xù"),
+    ('獞', "tóng"),
+    ('獟', "yào"),
+    ('獠', "liáo"),
+    ('獡', "shuò"),
+    ('獢', "xiāo"),
+    ('獣', "shòu"),
+    ('獤', "dūn"),
+    ('獥', "jiào"),
+    ('獦', "gé"),
+    ('獧', "juàn"),
+    ('獨', "dú"),
+    ('獩', "huì"),
+    ('獪', "kuài"),
+    ('獫', "xiǎn"),
+    ('獬', "xiè"),
+    ('獭', "tǎ"),
+    ('獮', "xiǎn"),
+    ('獯', "xūn"),
+    ('獰', "níng"),
+    ('獱', "biān"),
+    ('獲', "huò"),
+    ('獳', "nòu"),
+    ('獴', "měng"),
+    ('獵', "liè"),
+    ('獶', "nǎo"),
+    ('獷', "guǎng"),
+    ('獸', "shòu"),
+    ('獹', "lú"),
+    ('獺', "tǎ"),
+    ('獻', "xiàn"),
+    ('獼', "mí"),
+    ('獽', "ráng"),
+    ('獾', "huān"),
+    ('獿', "nǎo"),
+    ('玀', "luó"),
+    ('玁', "xiǎn"),
+    ('玂', "qí"),
+    ('玃', "jué"),
+    ('玄', "xuán"),
+    ('玅', "miào"),
+    ('玆', "zī"),
+    ('率', "lǜ,shuài"),
+    ('玈', "lú"),
+    ('玉', "yù"),
+    ('玊', "sù"),
+    ('王', "wáng"),
+    ('玌', "qiú"),
+    ('玍', "gǎ"),
+    ('玎', "dīng"),
+    ('玏', "lè"),
+    ('玐', "bā"),
+    ('玑', "jī"),
+    ('玒', "hóng"),
+    ('玓', "dì"),
+    ('玔', "chuàn"),
+    ('玕', "gān"),
+    ('玖', "jiǔ"),
+    ('玗', "yú"),
+    ('玘', "qǐ"),
+    ('玙', "yú"),
+    ('玚', "chàng"),
+    ('玛', "mǎ"),
+    ('玜', "hóng"),
+    ('玝', "wǔ"),
+    ('玞', "fū"),
+    ('玟', "wén"),
+    ('玠', "jiè"),
+    ('玡', "yá"),
+    ('玢', "bīn"),
+    ('玣', "biàn"),
+    ('玤', "bàng"),
+    ('玥', "yuè"),
+    ('玦', "jué"),
+    ('玧', "mén"),
+    ('玨', "jué"),
+    ('玩', "wán"),
+    ('玪', "jiān"),
+    ('玫', "méi"),
+    ('玬', "dǎn"),
+    ('玭', "pín"),
+    ('玮', "wěi"),
+    ('环', "huán"),
+    ('现', "xiàn"),
+    ('玱', "qiāng"),
+    ('玲', "líng"),
+    ('玳', "dài"),
+    ('玴', "yì"),
+    ('玵', "án"),
+    ('玶', "píng"),
+    ('玷', "diàn"),
+    ('玸', "fú"),
+    ('玹', "xuán"),
+    ('玺', "xǐ"),
+    ('玻', "bō"),
+    ('玼', "cǐ"),
+    ('玽', "gǒu"),
+    ('玾', "jiǎ"),
+    ('玿', "sháo"),
+    ('珀', "pò"),
+    ('珁', "cí"),
+    ('珂', "kē"),
+    ('珃', "rǎn"),
+    ('珄', "shēng"),
+    ('珅', "shēn"),
+    ('珆', "yí"),
+    ('珇', "zǔ"),
+    ('珈', "jiā"),
+    ('珉', "mín"),
+    ('珊', "shān"),
+    ('珋', "liǔ"),
+    ('珌', "bì"),
+    ('珍', "zhēn"),
+    ('珎', "zhēn"),
+    ('珏', "jué"),
+    ('珐', "fà"),
+    ('珑', "lóng"),
+    ('珒', "jīn"),
+    ('珓', "jiào"),
+    ('珔', "jiàn"),
+    ('珕', "lì"),
+    ('珖', "guāng"),
+    ('珗', "xiān"),
+    ('珘', "zhōu"),
+    ('珙', "gǒng"),
+    ('珚', "yān"),
+    ('珛', "xiù"),
+    ('珜', "yáng"),
+    ('珝', "xǔ"),
+    ('珞', "luò"),
+    ('珟', "sù"),
+    ('珠', "zhū"),
+    ('珡', "qín"),
+    ('珢', "yín"),
+    ('珣', "xún"),
+    ('珤', "bǎo"),
+    ('珥', "ěr"),
+    ('珦', "xiàng"),
+    ('珧', "yáo"),
+    ('珨', "xiá"),
+    ('珩', "háng"),
+    ('珪', "guī"),
+    ('珫', "chōng"),
+    ('珬', "xù"),
+    ('班', "bān"),
+    ('珮', "pèi"),
+    ('珯', "lǎo"),
+    ('珰', "dāng"),
+    ('珱', "yīng"),
+    ('珲', "huī"),
+    ('珳', "wén"),
+    ('珴', "é"),
+    ('珵', "chéng"),
+    ('珶', "dì"),
+    ('珷', "wǔ"),
+    ('珸', "wú"),
+    ('珹', "chéng"),
+    ('珺', "jùn"),
+    ('珻', "méi"),
+    ('珼', "bèi"),
+    ('珽', "tǐng"),
+    ('現', "xiàn"),
+    ('珿', "chù"),
+    ('琀', "hán"),
+    ('琁', "xuán"),
+    ('琂', "yán"),
+    ('球', "qiú"),
+    ('琄', "xuàn"),
+    ('琅', "láng"),
+    ('理', "lǐ"),
+    ('琇', "xiù"),
+    ('琈', "fú"),
+    ('琉', "liú"),
+    ('琊', "yá"),
+    ('琋', "xī"),
+    ('琌', "líng"),
+    ('琍', "lí"),
+    ('琎', "jìn"),
+    ('琏', "liǎn"),
+    ('琐', "suǒ"),
+    ('琑', "suǒ"),
+    ('琒', "fēng"),
+    ('琓', "wán"),
+    ('琔', "diàn"),
+    ('琕', "pín"),
+    ('琖', "zhǎn"),
+    ('琗', "sè"),
+    ('琘', "mín"),
+    ('琙', "yù"),
+    ('琚', "jū"),
+    ('琛', "chēn"),
+    ('琜', "lái"),
+    ('琝', "mín"),
+    ('琞', "shèng"),
+    ('琟', "wéi"),
+    ('琠', "tiǎn"),
+    ('琡', "chù"),
+    ('琢', "zhuó,zuó"),
+    ('琣', "běng"),
+    ('琤', "chēng"),
+    ('琥', "hǔ"),
+    ('琦', "qí"),
+    ('琧', "è"),
+    ('琨', "kūn"),
+    ('琩', "chāng"),
+    ('琪', "qí"),
+    ('琫', "běng"),
+    ('琬', "wǎn"),
+    ('琭', "lù"),
+    ('琮', "cóng"),
+    ('琯', "guǎn"),
+    ('琰', "yǎn"),
+    ('琱', "diāo"),
+    ('琲', "bèi"),
+    ('琳', "lín"),
+    ('琴', "qín"),
+    ('琵', "pí"),
+    ('琶', "pá"),
+    ('琷', "què"),
+    ('琸', "zhuó"),
+    ('琹', "qín"),
+    ('琺', "fà"),
+    ('琻', "jīn"),
+    ('琼', "qióng"),
+    ('琽', "dǔ"),
+    ('琾', "jiè"),
+    ('琿', "hún"),
+    ('瑀', "yǔ"),
+    ('瑁', "mào"),
+    ('瑂', "méi"),
+    ('瑃', "chūn"),
+    ('瑄', "xuān"),
+    ('瑅', "tí"),
+    ('瑆', "xīng"),
+    ('瑇', "dài"),
+    ('瑈', "róu"),
+    ('瑉', "mín"),
+    ('瑊', "jiān"),
+    ('瑋', "wěi"),
+    ('瑌', "ruǎn"),
+    ('瑍', "huàn"),
+    ('瑎', "xié"),
+    ('瑏', "chuān"),
+    ('瑐', "jiǎn"),
+    ('瑑', "zhuàn"),
+    ('瑒', "chàng"),
+    ('瑓', "liàn"),
+    ('瑔', "quán"),
+    ('瑕', "xiá"),
+    ('瑖', "duàn"),
+    ('瑗', "yuàn"),
+    ('瑘', "yá"),
+    ('瑙', "nǎo"),
+    ('瑚', "hú"),
+    ('瑛', "yīng"),
+    ('瑜', "yú"),
+    ('瑝', "huáng"),
+    ('瑞', "ruì"),
+    ('瑟', "sè"),
+    ('瑠', "liú"),
+    ('瑡', "shī"),
+    ('瑢', "róng"),
+    ('瑣', "suǒ"),
+    ('瑤', "yáo"),
+    ('瑥', "wēn"),
+    ('瑦', "wǔ"),
+    ('瑧', "zhēn"),
+    ('瑨', "jìn"),
+    ('瑩', "yíng"),
+    ('瑪', "mǎ"),
+    ('瑫', "tāo"),
+    ('瑬', "liú"),
+    ('瑭', "táng"),
+    ('瑮', "lì"),
+    ('瑯', "láng"),
+    ('瑰', "guī"),
+    ('瑱', "zhèn"),
+    ('瑲', "qiāng"),
+    ('瑳', "cuō"),
+    ('瑴', "jué"),
+    ('瑵', "zhǎo"),
+    ('瑶', "yáo"),
+    ('瑷', "ài"),
+    ('瑸', "bīn"),
+    ('瑹', "shū"),
+    ('瑺', "cháng"),
+    ('瑻', "kūn"),
+    ('瑼', "zhuān"),
+    ('瑽', "cōng"),
+    ('瑾', "jǐn"),
+    ('瑿', "yī"),
+    ('璀', "cuǐ"),
+    ('璁', "cōng"),
+    ('璂', "qí"),
+    ('璃', "lí"),
+    ('璄', "jǐng"),
+    ('璅', "suǒ"),
+    ('璆', "qiú"),
+    ('璇', "xuán"),
+    ('璈', "áo"),
+    ('璉', "liǎn"),
+    ('璊', "mén"),
+    ('璋', "zhāng"),
+    ('璌', "yín"),
+    ('璍', "yè"),
+    ('璎', "yīng"),
+    ('璏', "wèi"),
+    ('璐', "lù"),
+    ('璑', "wú"),
+    ('璒', "dēng"),
+    ('璓', "xiù"),
+    ('璔', "zēng"),
+    ('璕', "xún"),
+    ('璖', "qú"),
+    ('璗', "dàng"),
+    ('璘', "lín"),
+    ('璙', "liáo"),
+    ('璚', "qióng"),
+    ('璛', "sù"),
+    ('璜', "huáng"),
+    ('璝', "guī"),
+    ('璞', "pú"),
+    ('璟', "jǐng"),
+    ('璠', "fán"),
+    ('璡', "jìn"),
+    ('璢', "liú"),
+    ('璣', "jī"),
+    ('璤', "huì"),
+    ('璥', "jǐng"),
+    ('璦', "ài"),
+    ('璧', "bì"),
+    ('璨', "càn"),
+    ('璩', "qú"),
+    ('璪', "zǎo"),
+    ('璫', "dāng"),
+    ('璬', "jiǎo"),
+    ('璭', "gùn"),
+    ('璮', "tǎn"),
+    ('璯', "huì"),
+    ('環', "huán"),
+    ('璱', "sè"),
+    ('璲', "suì"),
+    ('璳', "tián"),
+    ('璴', "chǔ"),
+    ('璵', "yú"),
+    ('璶', "jìn"),
+    ('璷', "lú"),
+    ('璸', "bīn"),
+    ('璹', "shú"),
+    ('璺', "wèn"),
+    ('璻', "zuǐ"),
+    ('璼', "lán"),
+    ('璽', "xǐ"),
+    ('璾', "zī"),
+    ('璿', "xuán"),
+    ('瓀', "ruǎn"),
+    ('瓁', "wò"),
+    ('瓂', "gài"),
+    ('瓃', "léi"),
+    ('瓄', "dú"),
+    ('瓅', "lì"),
+    ('瓆', "zhì"),
+    ('瓇', "róu"),
+    ('瓈', "lí"),
+    ('瓉', "zàn"),
+    ('瓊', "qióng"),
+    ('瓋', "tì"),
+    ('瓌', "guī"),
+    ('瓍', "suí"),
+    ('瓎', "là"),
+    ('瓏', "lóng"),
+    ('瓐', "lú"),
+    ('瓑', "lì"),
+    ('瓒', "zàn"),
+    ('瓓', "làn"),
+    ('瓔', "yīng"),
+    ('瓕', "mí"),
+    ('瓖', "xiāng"),
+    ('瓗', "qióng"),
+    ('瓘', "guàn"),
+    ('瓙', "dào"),
+    ('瓚', "zàn"),
+    ('瓛', "huán"),
+    ('瓜', "guā"),
+    ('瓝', "bó"),
+    ('瓞', "dié"),
+    ('瓟', "bó"),
+    ('瓠', "hù"),
+    ('瓡', "zhí"),
+    ('瓢', "piáo"),
+    ('瓣', "bàn"),
+    ('瓤', "ráng"),
+    ('瓥', "lì"),
+    ('瓦', "wǎ,wà"),
+    ('瓨', "xiáng"),
+    ('瓩', "qiān"),
+    ('瓪', "bǎn"),
+    ('瓫', "pén"),
+    ('瓬', "fǎng"),
+    ('瓭', "dǎn"),
+    ('瓮', "wèng"),
+    ('瓯', "ōu"),
+    ('瓲', "wa"),
+    ('瓳', "hú"),
+    ('瓴', "líng"),
+    ('瓵', "yí"),
+    ('瓶', "píng"),
+    ('瓷', "cí"),
+    ('瓸', "bǎi"),
+    ('瓹', "juān"),
+    ('瓺', "cháng"),
+    ('瓻', "chī"),
+    ('瓽', "dàng"),
+    ('瓾', "měng"),
+    ('瓿', "bù"),
+    ('甀', "zhuì"),
+    ('甁', "píng"),
+    ('甂', "biān"),
+    ('甃', "zhòu"),
+    ('甄', "zhēn"),
+    ('甆', "cí"),
+    ('甇', "yīng"),
+    ('甈', "qì"),
+    ('甉', "xián"),
+    ('甊', "lǒu"),
+    ('甋', "dì"),
+    ('甌', "ōu"),
+    ('甍', "méng"),
+    ('甎', "zhuān"),
+    ('甏', "bèng"),
+    ('甐', "lìn"),
+    ('甑', "zèng"),
+    ('甒', "wǔ"),
+    ('甓', "pì"),
+    ('甔', "dān"),
+    ('甕', "wèng"),
+    ('甖', "yīng"),
+    ('甗', "yǎn"),
+    ('甘', "gān"),
+    ('甙', "dài"),
+    ('甚', "shèn,shén"),
+    ('甛', "tián"),
+    ('甜', "tián"),
+    ('甝', "hán"),
+    ('甞', "cháng"),
+    ('生', "shēng"),
+    ('甠', "qíng"),
+    ('甡', "shēn"),
+    ('產', "chǎn"),
+    ('産', "chǎn"),
+    ('甤', "ruí"),
+    ('甥', "shēng"),
+    ('甦', "sū"),
+    ('甧', "shēn"),
+    ('用', "yòng"),
+    ('甩', "shuǎi"),
+    ('甪', "lù"),
+    ('甫', "fǔ"),
+    ('甬', "yǒng"),
+    ('甭', "béng"),
+    ('甮', "fèng"),
+    ('甯', "níng"),
+    ('田', "tián"),
+    ('由', "yóu"),
+    ('甲', "jiǎ"),
+    ('申', "shēn"),
+    ('甴', "zhá"),
+    ('电', "diàn"),
+    ('甶', "fú"),
+    ('男', "nán"),
+    ('甸', "diān"),
+    ('甹', "pīng"),
+    ('町', "tīng"),
+    ('画', "huà"),
+    ('甼', "tǐng"),
+    ('甽', "zhèn"),
+    ('甾', "zāi"),
+    ('甿', "méng"),
+    ('畀', "bì"),
+    ('畁', "bì"),
+    ('畂', "liù"),
+    ('畃', "xún"),
+    ('畄', "liú"),
+    ('畅', "chàng"),
+    ('畆', "mǔ"),
+    ('畇', "yún"),
+    ('畈', "fàn"),
+    ('畉', "fú"),
+    ('畊', "gēng"),
+    ('畋', "tián"),
+    ('界', "jiè"),
+    ('畍', "jiè"),
+    ('畎', "quǎn"),
+    ('畏', "wèi"),
+    ('畐', "fú"),
+    ('畑', "tián"),
+    ('畒', "mǔ"),
+    ('畓', "duō"),
+    ('畔', "pàn"),
+    ('畕', "jiāng"),
+    ('畖', "wā"),
+    ('畗', "dá"),
+    ('畘', "nán"),
+    ('留', "liú"),
+    ('畚', "běn"),
+    ('畛', "zhěn"),
+    ('畜', "chù,xù"),
+    ('畝', "mǔ"),
+    ('畞', "mǔ"),
+    ('畟', "cè"),
+    ('畠', "tián"),
+    ('畡', "gāi"),
+    ('畢', "bì"),
+    ('畣', "dá"),
+    ('畤', "zhì"),
+    ('略', "lüè"),
+    ('畦', "qí"),
+    ('畧', "lüè"),
+    ('畨', "pān"),
+    ('畩', "yī"),
+    ('番', "fān"),
+    ('畫', "huà"),
+    ('畬', "shē"),
+    ('畭', "yú"),
+    ('畮', "mǔ"),
+    ('畯', "jùn"),
+    ('異', "yì"),
+    ('畱', "liú"),
+    ('畲', "shē"),
+    ('畳', "dié"),
+    ('畴', "chóu"),
+    ('畵', "huà"),
+    ('當', "dāng"),
+    ('畷', "zhuì"),
+    ('畸', "jī"),
+    ('畹', "wǎn"),
+    ('畺', "jiāng"),
+    ('畻', "chéng"),
+    ('畼', "chàng"),
+    ('畽', "tǔn"),
+    ('畾', "léi"),
+    ('畿', "jī"),
+    ('疀', "chā"),
+    ('疁', "liú"),
+    ('疂', "dié"),
+    ('疃', "tuǎn"),
+    ('疄', "lìn"),
+    ('疅', "jiāng"),
+    ('疆', "jiāng"),
+    ('疇', "chóu"),
+    ('疈', "pì"),
+    ('疉', "dié"),
+    ('疊', "dié"),
+    ('疋', "pǐ"),
+    ('疌', "jié"),
+    ('疍', "dàn"),
+    ('疎', "shū"),
+    ('疏', "shū"),
+    ('疐', "zhì"),
+    ('疑', "yí"),
+    ('疒', "nè"),
+    ('疓', "nǎi"),
+    ('疔', "dīng"),
+    ('疕', "bǐ"),
+    ('疖', "jiē"),
+    ('疗', "liáo"),
+    ('疘', "gāng"),
+    ('疙', "gē"),
+    ('疚', "jiù"),
+    ('疛', "zhǒu"),
+    ('疜', "xià"),
+    ('疝', "shàn"),
+    ('疞', "xū"),
+    ('疟', "nüè,yào"),
+    ('疠', "lì"),
+    ('疡', "yáng"),
+    ('疢', "chèn"),
+    ('疣', "yóu"),
+    ('疤', "bā"),
+    ('疥', "jiè"),
+    ('疦', "jué"),
+    ('疧', "qí"),
+    ('疨', "xiā"),
+    ('疩', "cuì"),
+    ('疪', "bì"),
+    ('疫', "yì"),
+    ('疬', "lì"),
+    ('疭', "zòng"),
+    ('疮', "chuāng"),
+    ('疯', "fēng"),
+    ('疰', "zhù"),
+    ('疱', "pào"),
+    ('疲', "pí"),
+    ('疳', "gān"),
+    ('疴', "kē"),
+    ('疵', "cī"),
+    ('疶', "xuē"),
+    ('疷', "zhī"),
+    ('疸', "dǎn"),
+    ('疹', "zhěn"),
+    ('疺', "fá"),
+    ('疻', "zhǐ"),
+    ('疼', "téng"),
+    ('疽', "jū"),
+    ('疾', "jí"),
+    ('疿', "fèi"),
+    ('痀', "jū"),
+    ('痁', "shān"),
+    ('痂', "jiā"),
+    ('痃', "xuán"),
+    ('痄', "zhà"),
+    ('病', "bìng"),
+    ('痆', "niè"),
+    ('症', "zhèng,zhēng"),
+    ('痈', "yōng"),
+    ('痉', "jìng"),
+    ('痊', "quán"),
+    ('痋', "téng"),
+    ('痌', "tōng"),
+    ('痍', "yí"),
+    ('痎', "jiē"),
+    ('痏', "wěi"),
+    ('痐', "huí"),
+    ('痑', "tān"),
+    ('痒', "yǎng"),
+    ('痓', "chì"),
+    ('痔', "zhì"),
+    ('痕', "hén"),
+    ('痖', "yǎ"),
+    ('痗', "mèi"),
+    ('痘', "dòu"),
+    ('痙', "jìng"),
+    ('痚', "xiāo"),
+    ('痛', "tòng"),
+    ('痜', "tū"),
+    ('痝', "máng"),
+    ('痞', "pǐ"),
+    ('痟', "xiāo"),
+    ('痠', "suān"),
+    ('痡', "fū"),
+    ('痢', "lì"),
+    ('痣', "zhì"),
+    ('痤', "cuó"),
+    ('痥', "duó"),
+    ('痦', "wù"),
+    ('痧', "shā"),
+    ('痨', "láo"),
+    ('痩', "shòu"),
+    ('痪', "huàn"),
+    ('痫', "xián"),
+    ('痬', "yì"),
+    ('痭', "bēng"),
+    ('痮', "zhàng"),
+    ('痯', "guǎn"),
+    ('痰', "tán"),
+    ('痱', "fèi"),
+    ('痲', "má"),
+    ('痳', "lín"),
+    ('痴', "chī"),
+    ('痵', "jì"),
+    ('痶', "tiǎn"),
+    ('痷', "ān"),
+    ('痸', "chì"),
+    ('痹', "bì"),
+    ('痺', "bì"),
+    ('痻', "mín"),
+    ('痼', "gù"),
+    ('痽', "duī"),
+    ('痾', "ē"),
+    ('痿', "wěi"),
+    ('瘀', "yū"),
+    ('瘁', "cuì"),
+    ('瘂', "yǎ"),
+    ('瘃', "zhú"),
+    ('瘄', "cù"),
+    ('瘅', "dān"),
+    ('瘆', "shèn"),
+    ('瘇', "zhǒng"),
+    ('瘈', "chì"),
+    ('瘉', "yù"),
+    ('瘊', "hóu"),
+    ('瘋', "fēng"),
+    ('瘌', "là"),
+    ('瘍', "yáng"),
+    ('瘎', "chén"),
+    ('瘏', "tú"),
+    ('瘐', "yǔ"),
+    ('瘑', "guō"),
+    ('瘒', "wén"),
+    ('瘓', "huàn"),
+    ('瘔', "kù"),
+    ('瘕', "jiǎ"),
+    ('瘖', "yīn"),
+    ('瘗', "yì"),
+    ('瘘', "lòu"),
+    ('瘙', "sào"),
+    ('瘚', "jué"),
+    ('瘛', "chì"),
+    ('瘜', "xī"),
+    ('瘝', "guān"),
+    ('瘞', "yì"),
+    ('瘟', "wēn"),
+    ('瘠', "jí"),
+    ('瘡', "chuāng"),
+    ('瘢', "bān"),
+    ('瘣', "huì"),
+    ('瘤', "liú"),
+    ('瘥', "chài"),
+    ('瘦', "shòu"),
+    ('瘧', "nüè"),
+    ('瘨', "diān"),
+    ('瘩', "da"),
+    ('瘪', "biě"),
+    ('瘫', "tān"),
+    ('瘬', "zhàng"),
+    ('瘭', "biāo"),
+    ('瘮', "shèn"),
+    ('瘯', "cù"),
+    ('瘰', "luǒ"),
+    ('瘱', "yì"),
+    ('瘲', "zòng"),
+    ('瘳', "chōu"),
+    ('瘴', "zhàng"),
+    ('瘵', "zhài"),
+    ('瘶', "sòu"),
+    ('瘷', "sè"),
+    ('瘸', "qué"),
+    ('瘹', "diào"),
+    ('瘺', "lòu"),
+    ('瘻', "lòu"),
+    ('瘼', "mò"),
+    ('瘽', "qín"),
+    ('瘾', "yǐn"),
+    ('瘿', "yǐng"),
+    ('癀', "huáng"),
+    ('癁', "fú"),
+    ('療', "liáo"),
+    ('癃', "lóng"),
+    ('癄', "qiáo"),
+    ('癅', "liú"),
+    ('癆', "láo"),
+    ('癇', "xián"),
+    ('癈', "fèi"),
+    ('癉', "dān"),
+    ('癊', "yìn"),
+    ('癋', "hè"),
+    ('癌', "ái"),
+    ('癍', "bān"),
+    ('癎', "xián"),
+    ('癏', "guān"),
+    ('癐', "guì"),
+    ('癑', "nòng"),
+    ('癒', "yù"),
+    ('癓', "wéi"),
+    ('癔', "yì"),
+    ('癕', "yōng"),
+    ('癖', "pǐ"),
+    ('癗', "lěi"),
+    ('癘', "lì"),
+    ('癙', "shǔ"),
+    ('癚', "dàn"),
+    ('癛', "lǐn"),
+    ('癜', "diàn"),
+    ('癝', "lǐn"),
+    ('癞', "lài"),
+    ('癟', "biě"),
+    ('癠', "jì"),
+    ('癡', "chī"),
+    ('癢', "yǎng"),
+    ('癣', "xuǎn"),
+    ('癤', "jiē"),
+    ('癥', "zhēng"),
+    ('癦', "me"),
+    ('癧', "lì"),
+    ('癨', "huò"),
+    ('癩', "lài"),
+    ('癪', "jī"),
+    ('癫', "diān"),
+    ('癬', "xuǎn"),
+    ('癭', "yǐng"),
+    ('癮', "yǐn"),
+    ('癯', "qú"),
+    ('癰', "yōng"),
+    ('癱', "tān"),
+    ('癲', "diān"),
+    ('癳', "luǒ"),
+    ('癴', "luán"),
+    ('癵', "luán"),
+    ('癶', "bō"),
+    ('癷', "bō"),
+    ('癸', "guǐ"),
+    ('癹', "bá"),
+    ('発', "fā"),
+    ('登', "dēng"),
+    ('發', "fā"),
+    ('白', "bái"),
+    ('百', "bǎi"),
+    ('癿', "qié"),
+    ('皀', "jí"),
+    ('皁', "zào"),
+    ('皂', "zào"),
+    ('皃', "mào"),
+    ('的', "de,dí,dì"),
+    ('皅', "pā"),
+    ('皆', "jiē"),
+    ('皇', "huáng"),
+    ('皈', "guī"),
+    ('皉', "cǐ"),
+    ('皊', "líng"),
+    ('皋', "gāo"),
+    ('皌', "mò"),
+    ('皍', "jí"),
+    ('皎', "jiǎo"),
+    ('皏', "pěng"),
+    ('皐', "gāo"),
+    ('皑', "ái"),
+    ('皒', "é"),
+    ('皓', "hào"),
+    ('皔', "hàn"),
+    ('皕', "bì"),
+    ('皖', "wǎn"),
+    ('皗', "chóu"),
+    ('皘', "qiàn"),
+    ('皙', "xī"),
+    ('皚', "ái"),
+    ('皛', "xiǎo"),
+    ('皜', "hào"),
+    ('皝', "huàng"),
+    ('皞', "hào"),
+    ('皟', "zé"),
+    ('皠', "cuǐ"),
+    ('皡', "hào"),
+    ('皢', "xiǎo"),
+    ('皣', "yè"),
+    ('皤', "pó"),
+    ('皥', "hào"),
+    ('皦', "jiǎo"),
+    ('皧', "ài"),
+    ('皨', "xīng"),
+    ('皩', "huàng"),
+    ('皪', "lì"),
+    ('皫', "piǎo"),
+    ('皬', "hé"),
+    ('皭', "jiào"),
+    ('皮', "pí"),
+    ('皯', "gǎn"),
+    ('皰', "pào"),
+    ('皱', "zhòu"),
+    ('皲', "jūn"),
+    ('皳', "qiú"),
+    ('皴', "cūn"),
+    ('皵', "què"),
+    ('皶', "zhā"),
+    ('皷', "gǔ"),
+    ('皸', "jūn"),
+    ('皹', "jūn"),
+    ('皺', "zhòu"),
+    ('皻', "zhā"),
+    ('皼', "gǔ"),
+    ('皽', "zhāo"),
+    ('皾', "dú"),
+    ('皿', "mǐn"),
+    ('盀', "qǐ"),
+    ('盁', "yíng"),
+    ('盂', "yú"),
+    ('盃', "bēi"),
+    ('盄', "zhāo"),
+    ('盅', "zhōng"),
+    ('盆', "pén"),
+    ('盇', "hé"),
+    ('盈', "yíng"),
+    ('盉', "hé"),
+    ('益', "yì"),
+    ('盋', "bō"),
+    ('盌', "wǎn"),
+    ('盍', "hé"),
+    ('盎', "àng"),
+    ('盏', "zhǎn"),
+    ('盐', "yán"),
+    ('监', "jiān,jiàn"),
+    ('盒', "hé"),
+    ('盓', "yū"),
+    ('盔', "kuī"),
+    ('盕', "fàn"),
+    ('盖', "gài"),
+    ('盗', "dào"),
+    ('盘', "pán"),
+    ('盙', "fǔ"),
+    ('盚', "qiú"),
+    ('盛', "shèng,chéng"),
+    ('盜', "dào"),
+    ('盝', "lù"),
+    ('盞', "zhǎn"),
+    ('盟', "méng"),
+    ('盠', "lí"),
+    ('盡', "jǐn"),
+    ('盢', "xù"),
+    ('監', "jiān"),
+    ('盤', "pán"),
+    ('盥', "guàn"),
+    ('盦', "ān"),
+    ('盧', "lú"),
+    ('盨', "xǔ"),
+    ('盩', "zhōu"),
+    ('盪', "dàng"),
+    ('盫', "ān"),
+    ('盬', "gǔ"),
+    ('盭', "lì"),
+    ('目', "mù"),
+    ('盯', "dīng"),
+    ('盰', "gàn"),
+    ('盱', "xū"),
+    ('盲', "máng"),
+    ('盳', "wàng"),
+    ('直', "zhí"),
+    ('盵', "qì"),
+    ('盶', "yuǎn"),
+    ('盷', "tián"),
+    ('相', "xiāng,xiàng"),
+    ('盹', "dǔn"),
+    ('盺', "xīn"),
+    ('盻', "xì"),
+    ('盼', "pàn"),
+    ('盽', "fēng"),
+    ('盾', "dùn"),
+    ('盿', "mín"),
+    ('眀', "míng"),
+    ('省', "shěng,xǐng"),
+    ('眂', "shì"),
+    ('眃', "yún"),
+    ('眄', "miǎn"),
+    ('眅', "pān"),
+    ('眆', "fǎng"),
+    ('眇', "miǎo"),
+    ('眈', "dān"),
+    ('眉', "méi"),
+    ('眊', "mào"),
+    ('看', "kàn,kān"),
+    ('県', "xiàn"),
+    ('眍', "kōu"),
+    ('眎', "shì"),
+    ('眏', "yāng"),
+    ('眐', "zhēng"),
+    ('眑', "yǎo"),
+    ('眒', "shēn"),
+    ('眓', "huò"),
+    ('眔', "dà"),
+    ('眕', "zhěn"),
+    ('眖', "kuàng"),
+    ('眗', "jū"),
+    ('眘', "shèn"),
+    ('眙', "yí"),
+    ('眚', "shěng"),
+    ('眛', "mèi"),
+    ('眜', "mò"),
+    ('眝', "zhù"),
+    ('眞', "zhēn"),
+    ('真', "zhēn"),
+    ('眠', "mián"),
+    ('眡', "shì"),
+    ('眢', "yuān"),
+    ('眣', "dié"),
+    ('眤', "nì"),
+    ('眥', "zì"),
+    ('眦', "zì"),
+    ('眧', "chǎo"),
+    ('眨', "zhǎ"),
+    ('眩', "xuàn"),
+    ('眪', "bǐng"),
+    ('眫', "mǐ"),
+    ('眬', "lóng"),
+    ('眭', "suī"),
+    ('眮', "tóng"),
+    ('眯', "mī,mí"),
+    ('眰', "diè"),
+    ('眱', "dì"),
+    ('眲', "nè"),
+    ('眳', "míng"),
+    ('眴', "xuàn"),
+    ('眵', "chī"),
+    ('眶', "kuàng"),
+    ('眷', "juàn"),
+    ('眸', "móu"),
+    ('眹', "zhèn"),
+    ('眺', "tiào"),
+    ('眻', "yáng"),
+    ('眼', "yǎn"),
+    ('眽', "mò"),
+    ('眾', "zhòng"),
+    ('眿', "mò"),
+    ('着', "zhe,zháo,zhuó,zhāo"),
+    ('睁', "zhēng"),
+    ('睂', "méi"),
+    ('睃', "suō"),
+    ('睄', "shào"),
+    ('睅', "hàn"),
+    ('睆', "huàn"),
+    ('睇', "dì"),
+    ('睈', "chěng"),
+    ('睉', "cuó"),
+    ('睊', "juàn"),
+    ('睋', "é"),
+    ('睌', "mǎn"),
+    ('睍', "xiàn"),
+    ('睎', "xī"),
+    ('睏', "kùn"),
+    ('睐', "lài"),
+    ('睑', "jiǎn"),
+    ('睒', "shǎn"),
+    ('睓', "tiǎn"),
+    ('睔', "gùn"),
+    ('睕', "wǎn"),
+    ('睖', "lèng"),
+    ('睗', "shì"),
+    ('睘', "qióng"),
+    ('睙', "liè"),
+    ('睚', "yá"),
+    ('睛', "jīng"),
+    ('睜', "zhēng"),
+    ('睝', "lí"),
+    ('睞', "lài"),
+    ('睟', "suì"),
+    ('睠', "juàn"),
+    ('睡', "shuì"),
+    ('睢', "suī"),
+    ('督', "dū"),
+    ('睤', "bì"),
+    ('睥', "pì"),
+    ('睦', "mù"),
+    ('睧', "hūn"),
+    ('睨', "nì"),
+    ('睩', "lù"),
+    ('睪', "yì"),
+    ('睫', "jié"),
+    ('睬', "cǎi"),
+    ('睭', "zhǒu"),
+    ('睮', "yú"),
+    ('睯', "hūn"),
+    ('睰', "mà"),
+    ('睱', "xià"),
+    ('睲', "xǐng"),
+    ('睳', "huī"),
+    ('睴', "gùn"),
+    ('睵', "zāi"),
+    ('睶', "chǔn"),
+    ('睷', "jiān"),
+    ('睸', "mèi"),
+    ('睹', "dǔ"),
+    ('睺', "hóu"),
+    ('睻', "xuān"),
+    ('睼', "tiàn"),
+    ('睽', "kuí"),
+    ('睾', "gāo"),
+    ('睿', "ruì"),
+    ('瞀', "mào"),
+    ('瞁', "xù"),
+    ('瞂', "fá"),
+    ('瞃', "wò"),
+    ('瞄', "miáo"),
+    ('瞅', "chǒu"),
+    ('瞆', "kuì"),
+    ('瞇', "mī"),
+    ('瞈', "wěng"),
+    ('瞉', "kòu"),
+    ('瞊', "dàng"),
+    ('瞋', "chēn"),
+    ('瞌', "kē"),
+    ('瞍', "sǒu"),
+    ('瞎', "xiā"),
+    ('瞏', "qióng"),
+    ('瞐', "mò"),
+    ('瞑', "míng"),
+    ('瞒', "mán"),
+    ('瞓', "shuì"),
+    ('瞔', "zé"),
+    ('瞕', "zhàng"),
+    ('瞖', "yì"),
+    ('瞗', "diāo"),
+    ('瞘', "kōu"),
+    ('瞙', "mò"),
+    ('瞚', "shùn"),
+    ('瞛', "cōng"),
+    ('瞜', "lōu"),
+    ('瞝', "chī"),
+    ('瞞', "mán"),
+    ('瞟', "piǎo"),
+    ('瞠', "chēng"),
+    ('瞡', "guī"),
+    ('瞢', "méng"),
+    ('瞣', "wàn"),
+    ('瞤', "rún"),
+    ('瞥', "piē"),
+    ('瞦', "xī"),
+    ('瞧', "qiáo"),
+    ('瞨', "pú"),
+    ('瞩', "zhǔ"),
+    ('瞪', "dèng"),
+    ('瞫', "shěn"),
+    ('瞬', "shùn"),
+    ('瞭', "liào"),
+    ('瞮', "chè"),
+    ('瞯', "xián"),
+    ('瞰', "kàn"),
+    ('瞱', "yè"),
+    ('瞲', "xù"),
+    ('瞳', "tóng"),
+    ('瞴', "móu"),
+    ('瞵', "lín"),
+    ('瞶', "guì"),
+    ('瞷', "jiàn"),
+    ('瞸', "yè"),
+    ('瞹', "ài"),
+    ('瞺', "huì"),
+    ('瞻', "zhān"),
+    ('瞼', "jiǎn"),
+    ('瞽', "gǔ"),
+    ('瞾', "zhào"),
+    ('瞿', "qú"),
+    ('矀', "méi"),
+    ('矁', "chǒu"),
+    ('矂', "sào"),
+    ('矃', "nǐng"),
+    ('矄', "xūn"),
+    ('矅', "yào"),
+    ('矆', "huò"),
+    ('矇', "méng"),
+    ('矈', "mián"),
+    ('矉', "pín"),
+    ('矊', "mián"),
+    ('矋', "lěi"),
+    ('矌', "kuàng"),
+    ('矍', "jué"),
+    ('矎', "xuān"),
+    ('矏', "mián"),
+    ('矐', "huò"),
+    ('矑', "lú"),
+    ('矒', "méng"),
+    ('矓', "lóng"),
+    ('矔', "guàn"),
+    ('矕', "mǎn"),
+    ('矖', "xǐ"),
+    ('矗', "chù"),
+    ('矘', "tǎng"),
+    ('矙', "kàn"),
+    ('矚', "zhǔ"),
+    ('矛', "máo"),
+    ('矜', "jīn,qín,guān"),
+    ('矝', "jīn"),
+    ('矞', "yù"),
+    ('矟', "shuò"),
+    ('矠', "zé"),
+    ('矡', "jué"),
+    ('矢', "shǐ"),
+    ('矣', "yǐ"),
+    ('矤', "shěn"),
+    ('知', "zhī"),
+    ('矦', "hóu"),
+    ('矧', "shěn"),
+    ('矨', "yǐng"),
+    ('矩', "jǔ"),
+    ('矪', "zhōu"),
+    ('矫', "jiǎo,jiáo"),
+    ('矬', "cuó"),
+    ('短', "duǎn"),
+    ('矮', "ǎi"),
+    ('矯', "jiǎo"),
+    ('矰', "zēng"),
+    ('矱', "yuē"),
+    ('矲', "bà"),
+    ('石', "shí,dàn"),
+    ('矴', "dìng"),
+    ('矵', "qì"),
+    ('矶', "jī"),
+    ('矷', "zǐ"),
+    ('矸', "gān"),
+    ('矹', "wù"),
+    ('矺', "zhé"),
+    ('矻', "kū"),
+    ('矼', "gāng"),
+    ('矽', "xì"),
+    ('矾', "fán"),
+    ('矿', "kuàng"),
+    ('砀', "dàng"),
+    ('码', "mǎ"),
+    ('砂', "shā"),
+    ('砃', "dān"),
+    ('砄', "jué"),
+    ('砅', "lì"),
+    ('砆', "fū"),
+    ('砇', "mín"),
+    ('砈', "ě"),
+    ('砉', "huò"),
+    ('砊', "kāng"),
+    ('砋', "zhǐ"),
+    ('砌', "qì"),
+    ('砍', "kǎn"),
+    ('砎', "jiè"),
+    ('砏', "bīn"),
+    ('砐', "è"),
+    ('砑', "yà"),
+    ('砒', "pī"),
+    ('砓', "zhé"),
+    ('研', "yán"),
+    ('砕', "suì"),
+    ('砖', "zhuān"),
+    ('砗', "chē"),
+    ('砘', "dùn"),
+    ('砙', "wǎ"),
+    ('砚', "yàn"),
+    ('砛', "jīn"),
+    ('砜', "fēng"),
+    ('砝', "fá"),
+    ('砞', "mò"),
+    ('砟', "zhǎ"),
+    ('砠', "jū"),
+    ('砡', "yù"),
+    ('砢', "kē"),
+    ('砣', "tuó"),
+    ('砤', "tuó"),
+    ('砥', "dǐ"),
+    ('砦', "zhài"),
+    ('砧', "zhēn"),
+    ('砨', "è"),
+    ('砩', "fú"),
+    ('砪', "mǔ"),
+    ('砫', "zhù"),
+    ('砬', "lá"),
+    ('砭', "biān"),
+    ('砮', "nǔ"),
+    ('砯', "pīng"),
+    ('砰', "pēng"),
+    ('砱', "líng"),
+    ('砲', "pào"),
+    ('砳', "lè"),
+    ('破', "pò"),
+    ('砵', "bō"),
+    ('砶', "pò"),
+    ('砷', "shēn"),
+    ('砸', "zá"),
+    ('砹', "ài"),
+    ('砺', "lì"),
+    ('砻', "lóng"),
+    ('砼', "tóng"),
+    ('砽', "yòng"),
+    ('砾', "lì"),
+    ('砿', "kuàng"),
+    ('础', "chǔ"),
+    ('硁', "kēng"),
+    ('硂', "quán"),
+    ('硃', "zhū"),
+    ('硄', "kuāng"),
+    ('硅', "guī"),
+    ('硆', "è"),
+    ('硇', "náo"),
+    ('硈', "qià"),
+    ('硉', "lù"),
+    ('硊', "wěi"),
+    ('硋', "ài"),
+    ('硌', "gè"),
+    ('硍', "xiàn"),
+    ('硎', "xíng"),
+    ('硏', "yán"),
+    ('硐', "dòng"),
+    ('硑', "pēng"),
+    ('硒', "xī"),
+    ('硓', "lǎo"),
+    ('硔', "hóng"),
+    ('硕', "shuò"),
+    ('硖', "xiá"),
+    ('硗', "qiāo"),
+    ('硘', "qing"),
+    ('硙', "wéi"),
+    ('硚', "qiáo"),
+    ('硛', "yì"),
+    ('硜', "kēng"),
+    ('硝', "xiāo"),
+    ('硞', "què"),
+    ('硟', "chàn"),
+    ('硠', "láng"),
+    ('硡', "hōng"),
+    ('硢', "yú"),
+    ('硣', "xiāo"),
+    ('硤', "xiá"),
+    ('硥', "mǎng"),
+    ('硦', "luò"),
+    ('硧', "yǒng"),
+    ('硨', "chē"),
+    ('硩', "chè"),
+    ('硪', "wò"),
+    ('硫', "liú"),
+    ('硬', "yìng"),
+    ('硭', "máng"),
+    ('确', "què"),
+    ('硯', "yàn"),
+    ('硰', "shā"),
+    ('硱', "kǔn"),
+    ('硲', "yù"),
+    ('硳', "chì"),
+    ('硴', "huā"),
+    ('硵', "lǔ"),
+    ('硶', "chěn"),
+    ('硷', "jiǎn"),
+    ('硸', "nüè"),
+    ('硹', "sōng"),
+    ('硺', "zhuó"),
+    ('硻', "kēng"),
+    ('硼', "péng"),
+    ('硽', "yān"),
+    ('硾', "zhuì"),
+    ('硿', "kōng"),
+    ('碀', "chéng"),
+    ('碁', "qí"),
+    ('碂', "zòng"),
+    ('碃', "qìng"),
+    ('碄', "lín"),
+    ('碅', "jūn"),
+    ('碆', "bō"),
+    ('碇', "dìng"),
+    ('碈', "mín"),
+    ('碉', "diāo"),
+    ('碊', "jiān"),
+    ('碋', "hè"),
+    ('碌', "lù,liù"),
+    ('碍', "ài"),
+    ('碎', "suì"),
+    ('碏', "què"),
+    ('碐', "léng"),
+    ('碑', "bēi"),
+    ('碒', "yín"),
+    ('碓', "duì"),
+    ('碔', "wǔ"),
+    ('碕', "qí"),
+    ('碖', "lǔn"),
+    ('碗', "wǎn"),
+    ('碘', "diǎn"),
+    ('碙', "náo"),
+    ('碚', "bèi"),
+    ('碛', "qì"),
+    ('碜', "chěn"),
+    ('碝', "ruǎn"),
+    ('碞', "yán"),
+    ('碟', "dié"),
+    ('碠', "dìng"),
+    ('碡', "dú"),
+    ('碢', "tuó"),
+    ('碣', "jié"),
+    ('碤', "yīng"),
+    ('碥', "biǎn"),
+    ('碦', "kè"),
+    ('碧', "bì"),
+    ('碨', "wèi"),
+    ('碩', "shuò"),
+    ('碪', "zhēn"),
+    ('碫', "duàn"),
+    ('碬', "xiá"),
+    ('碭', "dàng"),
+    ('碮', "tí"),
+    ('碯', "nǎo"),
+    ('碰', "pèng"),
+    ('碱', "jiǎn"),
+    ('碲', "dì"),
+    ('碳', "tàn"),
+    ('碴', "chá"),
+    ('碵', "tián"),
+    ('碶', "qì"),
+    ('碷', "dùn"),
+    ('碸', "fēng"),
+    ('碹', "xuàn"),
+    ('確', "què"),
+    ('碻', "què"),
+    ('碼', "mǎ"),
+    ('碽', "gōng"),
+    ('碾', "niǎn"),
+    ('碿', "sù"),
+    ('磀', "é"),
+    ('磁', "cí"),
+    ('磂', "liú"),
+    ('磃', "sī"),
+    ('磄', "táng"),
+    ('磅', "bàng,páng"),
+    ('磆', "huá"),
+    ('磇', "pī"),
+    ('磈', "wěi"),
+    ('磉', "sǎng"),
+    ('磊', "lěi"),
+    ('磋', "cuō"),
+    ('磌', "tián"),
+    ('磍', "xiá"),
+    ('磎', "xī"),
+    ('磏', "lián"),
+    ('磐', "pán"),
+    ('磑', "wéi"),
+    ('磒', "yǔn"),
+    ('磓', "duī"),
+    ('磔', "zhé"),
+    ('磕', "kē"),
+    ('磖', "lá"),
+    ('磗', "zhuān"),
+    ('磘', "yáo"),
+    ('磙', "gǔn"),
+    ('磚', "zhuān"),
+    ('磛', "chán"),
+    ('磜', "qì"),
+    ('磝', "áo"),
+    ('磞', "pēng"),
+    ('磟', "liù"),
+    ('磠', "lǔ"),
+    ('磡', "kàn"),
+    ('磢', "chuǎng"),
+    ('磣', "chěn"),
+    ('磤', "yǐn"),
+    ('磥', "lěi"),
+    ('磦', "biāo"),
+    ('磧', "qì"),
+    ('磨', "mó,mò"),
+    ('磩', "qì"),
+    ('磪', "cuī"),
+    ('磫', "zōng"),
+    ('磬', "qìng"),
+    ('磭', "chuò"),
+    ('磮', "lún"),
+    ('磯', "jī"),
+    ('磰', "shàn"),
+    ('磱', "láo"),
+    ('磲', "qú"),
+    ('磳', "zēng"),
+    ('磴', "dèng"),
+    ('磵', "jiàn"),
+    ('磶', "xì"),
+    ('磷', "lín"),
+    ('磸', "dìng"),
+    ('磹', "tán"),
+    ('磺', "huáng"),
+    ('磻', "pán"),
+    ('磼', "zá"),
+    ('磽', "qiāo"),
+    ('磾', "dī"),
+    ('磿', "lì"),
+    ('礀', "jiàn"),
+    ('礁', "jiāo"),
+    ('礂', "xī"),
+    ('礃', "zhǎng"),
+    ('礄', "qiáo"),
+    ('礅', "dūn"),
+    ('礆', "jiǎn"),
+    ('礇', "yù"),
+    ('礈', "zhuì"),
+    ('礉', "hé"),
+    ('礊', "kè"),
+    ('礋', "zé"),
+    ('礌', "léi"),
+    ('礍', "jié"),
+    ('礎', "chǔ"),
+    ('礏', "yè"),
+    ('礐', "què"),
+    ('礑', "dàng"),
+    ('礒', "yǐ"),
+    ('礓', "jiāng"),
+    ('礔', "pī"),
+    ('礕', "pī"),
+    ('礖', "yù"),
+    ('礗', "pīn"),
+    ('礘', "è"),
+    ('礙', "ài"),
+    ('礚', "kē"),
+    ('礛', "jiān"),
+    ('礜', "yù"),
+    ('礝', "ruǎn"),
+    ('礞', "méng"),
+    ('礟', "pào"),
+    ('礠', "cí"),
+    ('礡', "bó"),
+    ('礢', "yǎng"),
+    ('礣', "mà"),
+    ('礤', "cǎ"),
+    ('礥', "xián"),
+    ('礦', "kuàng"),
+    ('礧', "léi"),
+    ('礨', "lěi"),
+    ('礩', "zhì"),
+    ('礪', "lì"),
+    ('礫', "lì"),
+    ('礬', "fán"),
+    ('礭', "què"),
+    ('礮', "pào"),
+    ('礯', "yīng"),
+    ('礰', "lì"),
+    ('礱', "lóng"),
+    ('礲', "lóng"),
+    ('礳', "mò"),
+    ('礴', "bó"),
+    ('礵', "shuāng"),
+    ('礶', "guàn"),
+    ('礷', "lán"),
+    ('礸', "cǎ"),
+    ('礹', "yán"),
+    ('示', "shì"),
+    ('礻', "shì"),
+    ('礼', "lǐ"),
+    ('礽', "réng"),
+    ('社', "shè"),
+    ('礿', "yuè"),
+    ('祀', "sì"),
+    ('祁', "qí"),
+    ('祂', "tā"),
+    ('祃', "mà"),
+    ('祄', "xiè"),
+    ('祅', "yāo"),
+    ('祆', "xiān"),
+    ('祇', "qí"),
+    ('祈', "qí"),
+    ('祉', "zhǐ"),
+    ('祊', "bēng"),
+    ('祋', "duì"),
+    ('祌', "zhòng"),
+    ('祍', "rèn"),
+    ('祎', "yī"),
+    ('祏', "shí"),
+    ('祐', "yòu"),
+    ('祑', "zhì"),
+    ('祒', "tiáo"),
+    ('祓', "fú"),
+    ('祔', "fù"),
+    ('祕', "mì"),
+    ('祖', "zǔ"),
+    ('祗', "zhī"),
+    ('祘', "suàn"),
+    ('祙', "mèi"),
+    ('祚', "zuò"),
+    ('祛', "qū"),
+    ('祜', "hù"),
+    ('祝', "zhù"),
+    ('神', "shén"),
+    ('祟', "suì"),
+    ('祠', "cí"),
+    ('祡', "chái"),
+    ('祢', "mí"),
+    ('祣', "lǚ"),
+    ('祤', "yǔ"),
+    ('祥', "xiáng"),
+    ('祦', "wú"),
+    ('祧', "tiāo"),
+    ('票', "piào"),
+    ('祩', "zhù"),
+    ('祪', "guǐ"),
+    ('祫', "xiá"),
+    ('祬', "zhī"),
+    ('祭', "jì"),
+    ('祮', "gào"),
+    ('祯', "zhēn"),
+    ('祰', "gào"),
+    ('祱', "shuì"),
+    ('祲', "jìn"),
+    ('祳', "shèn"),
+    ('祴', "gāi"),
+    ('祵', "kǔn"),
+    ('祶', "dì"),
+    ('祷', "dǎo"),
+    ('祸', "huò"),
+    ('祹', "táo"),
+    ('祺', "qí"),
+    ('祻', "gù"),
+    ('祼', "guàn"),
+    ('祽', "zuì"),
+    ('祾', "líng"),
+    ('祿', "lù"),
+    ('禀', "bǐng"),
+    ('禁', "jìn,jīn"),
+    ('禂', "dǎo"),
+    ('禃', "zhí"),
+    ('禄', "lù"),
+    ('禅', "chán"),
+    ('禆', "bì"),
+    ('禇', "zhě"),
+    ('禈', "huī"),
+    ('禉', "yǒu"),
+    ('禊', "xì"),
+    ('禋', "yīn"),
+    ('禌', "zī"),
+    ('禍', "huò"),
+    ('禎', "zhēn"),
+    ('福', "fú"),
+    ('禐', "yuàn"),
+    ('禑', "wú"),
+    ('禒', "xiǎn"),
+    ('禓', "yáng"),
+    ('禔', "zhī"),
+    ('禕', "yī"),
+    ('禖', "méi"),
+    ('禗', "sī"),
+    ('禘', "dì"),
+    ('禙', "bèi"),
+    ('禚', "zhuó"),
+    ('禛', "zhēn"),
+    ('禜', "yǒng"),
+    ('禝', "jì"),
+    ('禞', "gào"),
+    ('禟', "táng"),
+    ('禠', "sī"),
+    ('禡', "mà"),
+    ('禢', "tà"),
+    ('禣', "fù"),
+    ('禤', "xuān"),
+    ('禥', "qí"),
+    ('禦', "yù"),
+    ('禧', "xǐ"),
+    ('禨', "jī"),
+    ('禩', "sì"),
+    ('禪', "chán"),
+    ('禫', "dàn"),
+    ('禬', "guì"),
+    ('禭', "suì"),
+    ('禮', "lǐ"),
+    ('禯', "nóng"),
+    ('禰', "mí"),
+    ('禱', "dǎo"),
+    ('禲', "lì"),
+    ('禳', "ráng"),
+    ('禴', "yuè"),
+    ('禵', "tí"),
+    ('禶', "zàn"),
+    ('禷', "lèi"),
+    ('禸', "róu"),
+    ('禹', "yǔ"),
+    ('禺', "yú"),
+    ('离', "lí"),
+    ('禼', "xiè"),
+    ('禽', "qín"),
+    ('禾', "hé"),
+    ('禿', "tū"),
+    ('秀', "xiù"),
+    ('私', "sī"),
+    ('秂', "rén"),
+    ('秃', "tū"),
+    ('秄', "zǐ"),
+    ('秅', "chá"),
+    ('秆', "gǎn"),
+    ('秇', "yì"),
+    ('秈', "xiān"),
+    ('秉', "bǐng"),
+    ('秊', "nián"),
+    ('秋', "qiū"),
+    ('秌', "qiū"),
+    ('种', "zhǒng,zhòng"),
+    ('秎', "fèn"),
+    ('秏', "hào"),
+    ('秐', "yún"),
+    ('科', "kē"),
+    ('秒', "miǎo"),
+    ('秓', "zhī"),
+    ('秔', "jīng"),
+    ('秕', "bǐ"),
+    ('秖', "zhī"),
+    ('秗', "yù"),
+    ('秘', "mì,bì"),
+    ('秙', "kù"),
+    ('秚', "bàn"),
+    ('秛', "pī"),
+    ('秜', "ní"),
+    ('秝', "lì"),
+    ('秞', "yóu"),
+    ('租', "zū"),
+    ('秠', "pī"),
+    ('秡', "bó"),
+    ('秢', "líng"),
+    ('秣', "mò"),
+    ('秤', "chèng"),
+    ('秥', "nián"),
+    ('秦', "qín"),
+    ('秧', "yāng"),
+    ('秨', "zuó"),
+    ('秩', "zhì"),
+    ('秪', "zhī"),
+    ('秫', "shú"),
+    ('秬', "jù"),
+    ('秭', "zǐ"),
+    ('秮', "huó"),
+    ('积', "jī"),
+    ('称', "chēng,chèn"),
+    ('秱', "tóng"),
+    ('秲', "zhì"),
+    ('秳', "huó"),
+    ('秴', "hé"),
+    ('秵', "yīn"),
+    ('秶', "zī"),
+    ('秷', "zhì"),
+    ('秸', "jiē"),
+    ('秹', "rěn"),
+    ('秺', "dù"),
+    ('移', "yí"),
+    ('秼', "zhū"),
+    ('秽', "huì"),
+    ('秾', "nóng"),
+    ('秿', "fù"),
+    ('稀', "xī"),
+    ('稁', "gǎo"),
+    ('稂', "láng"),
+    ('稃', "fū"),
+    ('稄', "xùn"),
+    ('稅', "shuì"),
+    ('稆', "lǚ"),
+    ('稇', "kǔn"),
+    ('稈', "gǎn"),
+    ('稉', "jīng"),
+    ('稊', "tí"),
+    ('程', "chéng"),
+    ('稌', "tú"),
+    ('稍', "shāo,shào"),
+    ('税', "shuì"),
+    ('稏', "yà"),
+    ('稐', "lǔn"),
+    ('稑', "lù"),
+    ('稒', "gù"),
+    ('稓', "zuó"),
+    ('稔', "rěn"),
+    ('稕', "zhùn"),
+    ('稖', "bàng"),
+    ('稗', "bài"),
+    ('稘', "jī"),
+    ('稙', "zhī"),
+    ('稚', "zhì"),
+    ('稛', "kǔn"),
+    ('稜', "léng"),
+    ('稝', "péng"),
+    ('稞', "kē"),
+    ('稟', "bǐng"),
+    ('稠', "chóu"),
+    ('稡', "zuì"),
+    ('稢', "yù"),
+    ('稣', "sū"),
+    ('稤', "lüè"),
+    ('稥', "xiāng"),
+    ('稦', "yī"),
+    ('稧', "xì"),
+    ('稨', "biǎn"),
+    ('稩', "jì"),
+    ('稪', "fú"),
+    ('稫', "pì"),
+    ('稬', "nuò"),
+    ('稭', "jiē"),
+    ('種', "zhǒng"),
+    ('稯', "zōng"),
+    ('稰', "xǔ"),
+    ('稱', "chēng"),
+    ('稲', "dào"),
+    ('稳', "wěn"),
+    ('稴', "xián"),
+    ('稵', "zī"),
+    ('稶', "yù"),
+    ('稷', "jì"),
+    ('稸', "xù"),
+    ('稹', "zhěn"),
+    ('稺', "zhì"),
+    ('稻', "dào"),
+    ('稼', "jià"),
+    ('稽', "jī,qǐ"),
+    ('稾', "gǎo"),
+    ('稿', "gǎo"),
+    ('穀', "gǔ"),
+    ('穁', "róng"),
+    ('穂', "suì"),
+    ('穃', "rong"),
+    ('穄', "jì"),
+    ('穅', "kāng"),
+    ('穆', "mù"),
+    ('穇', "cǎn"),
+    ('穈', "méi"),
+    ('穉', "zhì"),
+    ('穊', "jì"),
+    ('穋', "lù"),
+    ('穌', "sū"),
+    ('積', "jī"),
+    ('穎', "yǐng"),
+    ('穏', "wěn"),
+    ('穐', "qiū"),
+    ('穑', "sè"),
+    ('穒', "hè"),
+    ('穓', "yì"),
+    ('穔', "huáng"),
+    ('穕', "qiè"),
+    ('穖', "jǐ"),
+    ('穗', "suì"),
+    ('穘', "xiāo"),
+    ('穙', "pú"),
+    ('穚', "jiāo"),
+    ('穛', "zhuō"),
+    ('穜', "zhǒng"),
+    ('穝', "zui"),
+    ('穞', "lǚ"),
+    ('穟', "suì"),
+    ('穠', "nóng"),
+    ('穡', "sè"),
+    ('穢', "huì"),
+    ('穣', "ráng"),
+    ('穤', "nuò"),
+    ('穥', "yù"),
+    ('穦', "pīn"),
+    ('穧', "jì"),
+    ('穨', "tuí"),
+    ('穩', "wěn"),
+    ('穪', "chēng"),
+    ('穫', "huò"),
+    ('穬', "kuàng"),
+    ('穭', "lǚ"),
+    ('穮', "biāo"),
+    ('穯', "sè"),
+    ('穰', "ráng"),
+    ('穱', "zhuō"),
+    ('穲', "lí"),
+    ('穳', "cuán"),
+    ('穴', "xué"),
+    ('穵', "wā"),
+    ('究', "jiū"),
+    ('穷', "qióng"),
+    ('穸', "xī"),
+    ('穹', "qióng"),
+    ('空', "kōng,kòng"),
+    ('穻', "yū"),
+    ('穼', "shēn"),
+    ('穽', "jǐng"),
+    ('穾', "yào"),
+    ('穿', "chuān"),
+    ('窀', "zhūn"),
+    ('突', "tū"),
+    ('窂', "láo"),
+    ('窃', "qiè"),
+    ('窄', "zhǎi"),
+    ('窅', "yǎo"),
+    ('窆', "biǎn"),
+    ('窇', "báo"),
+    ('窈', "yǎo"),
+    ('窉', "bǐng"),
+    ('窊', "wā"),
+    ('窋', "zhú"),
+    ('窌', "jiào"),
+    ('窍', "qiào"),
+    ('窎', "diào"),
+    ('窏', "wū"),
+    ('窐', "guī"),
+    ('窑', "yáo"),
+    ('窒', "zhì"),
+    ('窓', "chuāng"),
+    ('窔', "yào"),
+    ('窕', "tiǎo"),
+    ('窖', "jiào"),
+    ('窗', "chuāng"),
+    ('窘', "jiǒng"),
+    ('窙', "xiāo"),
+    ('窚', "chéng"),
+    ('窛', "kòu"),
+    ('窜', "cuàn"),
+    ('窝', "wō"),
+    ('窞', "dàn"),
+    ('窟', "kū"),
+    ('窠', "kē"),
+    ('窡', "zhuó"),
+    ('窢', "xū"),
+    ('窣', "sū"),
+    ('窤', "guān"),
+    ('窥', "kuī"),
+    ('窦', "dòu"),
+    ('窧', "zhuo"),
+    ('窨', "xūn"),
+    ('窩', "wō"),
+    ('窪', "wā"),
+    ('窫', "yà"),
+    ('窬', "yú"),
+    ('窭', "jù"),
+    ('窮', "qióng"),
+    ('窯', "yáo"),
+    ('窰', "yáo"),
+    ('窱', "tiǎo"),
+    ('窲', "cháo"),
+    ('窳', "yǔ"),
+    ('窴', "tián"),
+    ('窵', "diào"),
+    ('窶', "jù"),
+    ('窷', "liào"),
+    ('窸', "xī"),
+    ('窹', "wù"),
+    ('窺', "kuī"),
+    ('窻', "chuāng"),
+    ('窼', "zhāo"),
+    ('窽', "kuǎn"),
+    ('窾', "kuǎn"),
+    ('窿', "lóng"),
+    ('竀', "chēng"),
+    ('竁', "cuì"),
+    ('竂', "liáo"),
+    ('竃', "zào"),
+    ('竄', "cuàn"),
+    ('竅', "qiào"),
+    ('竆', "qióng"),
+    ('竇', "dòu"),
+    ('竈', "zào"),
+    ('竉', "lǒng"),
+    ('竊', "qiè"),
+    ('立', "lì"),
+    ('竌', "chù"),
+    ('竍', "shí"),
+    ('竎', "fù"),
+    ('竏', "qiān"),
+    ('竐', "chù"),
+    ('竑', "hóng"),
+    ('竒', "qí"),
+    ('竓', "háo"),
+    ('竔', "shēng"),
+    ('竕', "fēn"),
+    ('竖', "shù"),
+    ('竗', "miào"),
+    ('竘', "qǔ"),
+    ('站', "zhàn"),
+    ('竚', "zhù"),
+    ('竛', "líng"),
+    ('竜', "lóng"),
+    ('竝', "bìng"),
+    ('竞', "jìng"),
+    ('竟', "jìng"),
+    ('章', "zhāng"),
+    ('竡', "bǎi"),
+    ('竢', "sì"),
+    ('竣', "jùn"),
+    ('竤', "hóng"),
+    ('童', "tóng"),
+    ('竦', "sǒng"),
+    ('竧', "jìng"),
+    ('竨', "diào"),
+    ('竩', "yì"),
+    ('竪', "shù"),
+    ('竫', "jìng"),
+    ('竬', "qǔ"),
+    ('竭', "jié"),
+    ('竮', "pīng"),
+    ('端', "duān"),
+    ('竰', "lí"),
+    ('竱', "zhuǎn"),
+    ('竲', "céng"),
+    ('竳', "dēng"),
+    ('竴', "cūn"),
+    ('竵', "wāi"),
+    ('競', "jìng"),
+    ('竷', "kǎn"),
+    ('竸', "jìng"),
+    ('竹', "zhú"),
+    ('竺', "zhú"),
+    ('竻', "lè"),
+    ('竼', "péng"),
+    ('竽', "yú"),
+    ('竾', "chí"),
+    ('竿', "gān"),
+    ('笀', "máng"),
+    ('笁', "zhú"),
+    ('笂', "wán"),
+    ('笃', "dǔ"),
+    ('笄', "jī"),
+    ('笅', "jiǎo"),
+    ('笆', "bā"),
+    ('笇', "suàn"),
+    ('笈', "jí"),
+    ('笉', "qǐn"),
+    ('笊', "zhào"),
+    ('笋', "sǔn"),
+    ('笌', "yá"),
+    ('笍', "zhuì"),
+    ('笎', "yuán"),
+    ('笏', "hù"),
+    ('笐', "háng"),
+    ('笑', "xiào"),
+    ('笒', "cén"),
+    ('笓', "bì"),
+    ('笔', "bǐ"),
+    ('笕', "jiǎn"),
+    ('笖', "yǐ"),
+    ('笗', "dōng"),
+    ('笘', "shān"),
+    ('笙', "shēng"),
+    ('笚', "dā"),
+    ('笛', "dí"),
+    ('笜', "zhú"),
+    ('笝', "nà"),
+    ('笞', "chī"),
+    ('笟', "gū"),
+    ('笠', "lì"),
+    ('笡', "qiè"),
+    ('笢', "mǐn"),
+    ('笣', "bāo"),
+    ('笤', "tiáo"),
+    ('笥', "sì"),
+    ('符', "fú"),
+    ('笧', "cè"),
+    ('笨', "bèn"),
+    ('笩', "fá"),
+    ('笪', "dá"),
+    ('笫', "zǐ"),
+    ('第', "dì"),
+    ('笭', "líng"),
+    ('笮', "zé"),
+    ('笯', "nú"),
+    ('笰', "fú"),
+    ('笱', "gǒu"),
+    ('笲', "fán"),
+    ('笳', "jiā"),
+    ('笴', "gǎn"),
+    ('笵', "fàn"),
+    ('笶', "shǐ"),
+    ('笷', "mǎo"),
+    ('笸', "pǒ"),
+    ('笹', "ti"),
+    ('笺', "jiān"),
+    ('笻', "qióng"),
+    ('笼', "lóng"),
+    ('笽', "mǐn"),
+    ('笾', "biān"),
+    ('笿', "luò"),
+    ('筀', "guì"),
+    ('筁', "qū"),
+    ('筂', "chí"),
+    ('筃', "yīn"),
+    ('筄', "yào"),
+    ('筅', "xiǎn"),
+    ('筆', "bǐ"),
+    ('筇', "qióng"),
+    ('筈', "kuò"),
+    ('等', "děng"),
+    ('筊', "xiáo"),
+    ('筋', "jīn"),
+    ('筌', "quán"),
+    ('筍', "sǔn"),
+    ('筎', "rú"),
+    ('筏', "fá"),
+    ('筐', "kuāng"),
+    ('筑', "zhù"),
+    ('筒', "tǒng"),
+    ('筓', "jī"),
+    ('答', "dá,dā"),
+    ('筕', "háng"),
+    ('策', "cè"),
+    ('筗', "zhòng"),
+    ('筘', "kòu"),
+    ('筙', "lái"),
+    ('筚', "bì"),
+    ('筛', "shāi"),
+    ('筜', "dāng"),
+    ('筝', "zhēng"),
+    ('筞', "cè"),
+    ('筟', "fū"),
+    ('筠', "yún"),
+    ('筡', "tú"),
+    ('筢', "pá"),
+    ('筣', "lí"),
+    ('筤', "láng"),
+    ('筥', "jǔ"),
+    ('筦', "guǎn"),
+    ('筧', "jiǎn"),
+    ('筨', "hán"),
+    ('筩', "tóng"),
+    ('筪', "xiá"),
+    ('筫', "zhì"),
+    ('筬', "chéng"),
+    ('筭', "suàn"),
+    ('筮', "shì"),
+    ('筯', "zhù"),
+    ('筰', "zuó"),
+    ('筱', "xiǎo"),
+    ('筲', "shāo"),
+    ('筳', "tíng"),
+    ('筴', "cè"),
+    ('筵', "yán"),
+    ('筶', "gào"),
+    ('筷', "kuài"),
+    ('筸', "gān"),
+    ('筹', "chóu"),
+    ('筺', "kuāng"),
+    ('筻', "gàng"),
+    ('筼', "yún"),
+    ('筽', "ōu"),
+    ('签', "qiān"),
+    ('筿', "xiǎo"),
+    ('简', "jiǎn"),
+    ('箁', "póu"),
+    ('箂', "lái"),
+    ('箃', "zōu"),
+    ('箄', "bǐ"),
+    ('箅', "bì"),
+    ('箆', "bì"),
+    ('箇', "gè"),
+    ('箈', "tái"),
+    ('箉', "guǎi"),
+    ('箊', "yū"),
+    ('箋', "jiān"),
+    ('箌', "dào"),
+    ('箍', "gū"),
+    ('箎', "chí"),
+    ('箏', "zhēng"),
+    ('箐', "qìng"),
+    ('箑', "shà"),
+    ('箒', "zhǒu"),
+    ('箓', "lù"),
+    ('箔', "bó"),
+    ('箕', "jī"),
+    ('箖', "lín"),
+    ('算', "suàn"),
+    ('箘', "jùn"),
+    ('箙', "fú"),
+    ('箚', "zhá"),
+    ('箛', "gū"),
+    ('箜', "kōng"),
+    ('箝', "qián"),
+    ('箞', "qiān"),
+    ('箟', "jùn"),
+    ('箠', "chuí"),
+    ('管', "guǎn"),
+    ('箢', "yuān"),
+    ('箣', "cè"),
+    ('箤', "zú"),
+    ('箥', "bǒ"),
+    ('箦', "zé"),
+    ('箧', "qiè"),
+    ('箨', "tuò"),
+    ('箩', "luó"),
+    ('箪', "dān"),
+    ('箫', "xiāo"),
+    ('箬', "ruò"),
+    ('箭', "jiàn"),
+    ('箮', "xuān"),
+    ('箯', "biān"),
+    ('箰', "sǔn"),
+    ('箱', "xiāng"),
+    ('箲', "xiǎn"),
+    ('箳', "píng"),
+    ('箴', "zhēn"),
+    ('箵', "xīng"),
+    ('箶', "hú"),
+    ('箷', "yí"),
+    ('箸', "zhù"),
+    ('箹', "yuē"),
+    ('箺', "chūn"),
+    ('箻', "lǜ"),
+    ('箼', "wū"),
+    ('箽', "dǒng"),
+    ('箾', "shuò"),
+    ('箿', "jí"),
+    ('節', "jié"),
+    ('篁', "huáng"),
+    ('篂', "xīng"),
+    ('篃', "mèi"),
+    ('範', "fàn"),
+    ('篅', "chuán"),
+    ('篆', "zhuàn"),
+    ('篇', "piān"),
+    ('篈', "fēng"),
+    ('築', "zhú"),
+    ('篊', "huáng"),
+    ('篋', "qiè"),
+    ('篌', "hóu"),
+    ('篍', "qiū"),
+    ('篎', "miǎo"),
+    ('篏', "qiàn"),
+    ('篐', "gū"),
+    ('篑', "kuì"),
+    ('篒', "shi"),
+    ('篓', "lǒu"),
+    ('篔', "yún"),
+    ('篕', "hé"),
+    ('篖', "táng"),
+    ('篗', "yuè"),
+    ('篘', "chōu"),
+    ('篙', "gāo"),
+    ('篚', "fěi"),
+    ('篛', "ruò"),
+    ('篜', "zhēng"),
+    ('篝', "gōu"),
+    ('篞', "niè"),
+    ('篟', "qiàn"),
+    ('篠', "xiǎo"),
+    ('篡', "cuàn"),
+    ('篢', "lǒng"),
+    ('篣', "péng"),
+    ('篤', "dǔ"),
+    ('篥', "lì"),
+    ('篦', "bì"),
+    ('篧', "zhuó"),
+    ('篨', "chú"),
+    ('篩', "shāi"),
+    ('篪', "chí"),
+    ('篫', "zhù"),
+    ('篬', "qiāng"),
+    ('篭', "lóng"),
+    ('篮', "lán"),
+    ('篯', "jiān"),
+    ('篰', "bù"),
+    ('篱', "lí"),
+    ('篲', "huì"),
+    ('篳', "bì"),
+    ('篴', "dí"),
+    ('篵', "cōng"),
+    ('篶', "yān"),
+    ('篷', "péng"),
+    ('篸', "cǎn"),
+    ('篹', "zhuàn"),
+    ('篺', "pí"),
+    ('篻', "piǎo"),
+    ('篼', "dōu"),
+    ('篽', "yù"),
+    ('篾', "miè"),
+    ('篿', "tuán"),
+    ('簀', "zé"),
+    ('簁', "shāi"),
+    ('簂', "guì"),
+    ('簃', "yí"),
+    ('簄', "hù"),
+    ('簅', "chǎn"),
+    ('簆', "kòu"),
+    ('簇', "cù"),
+    ('簈', "píng"),
+    ('簉', "zào"),
+    ('簊', "jī"),
+    ('簋', "guǐ"),
+    ('簌', "sù"),
+    ('簍', "lǒu"),
+    ('簎', "cè"),
+    ('簏', "lù"),
+    ('簐', "niǎn"),
+    ('簑', "suō"),
+    ('簒', "cuàn"),
+    ('簓', "diāo"),
+    ('簔', "suō"),
+    ('簕', "lè"),
+    ('簖', "duàn"),
+    ('簗', "liang"),
+    ('簘', "xiāo"),
+    ('簙', "bó"),
+    ('簚', "mì"),
+    ('簛', "shāi"),
+    ('簜', "dàng"),
+    ('簝', "liáo"),
+    ('簞', "dān"),
+    ('簟', "diàn"),
+    ('簠', "fǔ"),
+    ('簡', "jiǎn"),
+    ('簢', "mǐn"),
+    ('簣', "kuì"),
+    ('簤', "dài"),
+    ('簥', "jiāo"),
+    ('簦', "dēng"),
+    ('簧', "huáng"),
+    ('簨', "sǔn"),
+    ('簩', "láo"),
+    ('簪', "zān"),
+    ('簫', "xiāo"),
+    ('簬', "lù"),
+    ('簭', "shì"),
+    ('簮', "zān"),
+    ('簯', "qi"),
+    ('簰', "pái"),
+    ('簱', "qí"),
+    ('簲', "pái"),
+    ('簳', "gǎn"),
+    ('簴', "jù"),
+    ('簵', "lù"),
+    ('簶', "lù"),
+    ('簷', "yán"),
+    ('簸', "bǒ"),
+    ('簹', "dāng"),
+    ('簺', "sài"),
+    ('簻', "zhuā"),
+    ('簼', "gōu"),
+    ('簽', "qiān"),
+    ('簾', "lián"),
+    ('簿', "bù"),
+    ('籀', "zhòu"),
+    ('籁', "lài"),
+    ('籂', "shi"),
+    ('籃', "lán"),
+    ('籄', "kuì"),
+    ('籅', "yú"),
+    ('籆', "yuè"),
+    ('籇', "háo"),
+    ('籈', "zhēn"),
+    ('籉', "tái"),
+    ('籊', "tì"),
+    ('籋', "niè"),
+    ('籌', "chóu"),
+    ('籍', "jí"),
+    ('籎', "yí"),
+    ('籏', "qí"),
+    ('籐', "téng"),
+    ('籑', "zhuàn"),
+    ('籒', "zhòu"),
+    ('籓', "fān"),
+    ('籔', "sǒu"),
+    ('籕', "zhòu"),
+    ('籖', "qian"),
+    ('籗', "zhuó"),
+    ('籘', "téng"),
+    ('籙', "lù"),
+    ('籚', "lú"),
+    ('籛', "jiǎn"),
+    ('籜', "tuò"),
+    ('籝', "yíng"),
+    ('籞', "yù"),
+    ('籟', "lài"),
+    ('籠', "lóng"),
+    ('籡', "qiè"),
+    ('籢', "lián"),
+    ('籣', "lán"),
+    ('籤', "qiān"),
+    ('籥', "yuè"),
+    ('籦', "zhōng"),
+    ('籧', "qú"),
+    ('籨', "lián"),
+    ('籩', "biān"),
+    ('籪', "duàn"),
+    ('籫', "zuǎn"),
+    ('籬', "lí"),
+    ('籭', "sī"),
+    ('籮', "luó"),
+    ('籯', "yíng"),
+    ('籰', "yuè"),
+    ('籱', "zhuó"),
+    ('籲', "yù"),
+    ('米', "mǐ"),
+    ('籴', "dí"),
+    ('籵', "fán"),
+    ('籶', "shēn"),
+    ('籷', "zhé"),
+    ('籸', "shēn"),
+    ('籹', "nǚ"),
+    ('籺', "hé"),
+    ('类', "lèi"),
+    ('籼', "xiān"),
+    ('籽', "zǐ"),
+    ('籾', "ní"),
+    ('籿', "cùn"),
+    ('粀', "zhàng"),
+    ('粁', "qiān"),
+    ('粂', "zhāi"),
+    ('粃', "bǐ"),
+    ('粄', "bǎn"),
+    ('粅', "wù"),
+    ('粆', "shā"),
+    ('粇', "kāng"),
+    ('粈', "róu"),
+    ('粉', "fěn"),
+    ('粊', "bì"),
+    ('粋', "cuì"),
+    ('粌', "yin"),
+    ('粍', "zhé"),
+    ('粎', "mǐ"),
+    ('粏', "tai"),
+    ('粐', "hù"),
+    ('粑', "bā"),
+    ('粒', "lì"),
+    ('粓', "gān"),
+    ('粔', "jù"),
+    ('粕', "pò"),
+    ('粖', "mò"),
+    ('粗', "cū"),
+    ('粘', "zhān,nián"),
+    ('粙', "zhòu"),
+    ('粚', "chī"),
+    ('粛', "sù"),
+    ('粜', "tiào"),
+    ('粝', "lì"),
+    ('粞', "xī"),
+    ('粟', "sù"),
+    ('粠', "hóng"),
+    ('粡', "tóng"),
+    ('粢', "zī"),
+    ('粣', "cè"),
+    ('粤', "yuè"),
+    ('粥', "zhōu"),
+    ('粦', "lín"),
+    ('粧', "zhuāng"),
+    ('粨', "bǎi"),
+    ('粩', "lāo"),
+    ('粪', "fèn"),
+    ('粫', "ér"),
+    ('粬', "qū"),
+    ('粭', "hé"),
+    ('粮', "liáng"),
+    ('粯', "xiàn"),
+    ('粰', "fú"),
+    ('粱', "liáng"),
+    ('粲', "càn"),
+    ('粳', "jīng"),
+    ('粴', "lǐ"),
+    ('粵', "yuè"),
+    ('粶', "lù"),
+    ('粷', "jú"),
+    ('粸', "qí"),
+    ('粹', "cuì"),
+    ('粺', "bài"),
+    ('粻', "zhāng"),
+    ('粼', "lín"),
+    ('粽', "zòng"),
+    ('精', "jīng"),
+    ('粿', "guǒ"),
+    ('糀', "huā"),
+    ('糁', "sǎn"),
+    ('糂', "sǎn"),
+    ('糃', "táng"),
+    ('糄', "biǎn"),
+    ('糅', "róu"),
+    ('糆', "miàn"),
+    ('糇', "hóu"),
+    ('糈', "xǔ"),
+    ('糉', "zòng"),
+    ('糊', "hú,hū,hù"),
+    ('糋', "jiàn"),
+    ('糌', "zān"),
+    ('糍', "cí"),
+    ('糎', "lí"),
+    ('糏', "xiè"),
+    ('糐', "fū"),
+    ('糑', "nuò"),
+    ('糒', "bèi"),
+    ('糓', "gǔ"),
+    ('糔', "xiǔ"),
+    ('糕', "gāo"),
+    ('糖', "táng"),
+    ('糗', "qiǔ"),
+    ('糘', "jiā"),
+    ('糙', "cāo"),
+    ('糚', "zhuāng"),
+    ('糛', "táng"),
+    ('糜', "mí"),
+    ('糝', "sǎn"),
+    ('糞', "fèn"),
+    ('糟', "zāo"),
+    ('糠', "kāng"),
+    ('糡', "jiàng"),
+    ('糢', "mó"),
+    ('糣', "sǎn"),
+    ('糤', "sǎn"),
+    ('糥', "nuò"),
+    ('糦', "xī"),
+    ('糧', "liáng"),
+    ('糨', "jiàng"),
+    ('糩', "kuài"),
+    ('糪', "bò"),
+    ('糫', "huán"),
+    ('糬', "shǔ"),
+    ('糭', "zòng"),
+    ('糮', "xiàn"),
+    ('糯', "nuò"),
+    ('糰', "tuán"),
+    ('糱', "niè"),
+    ('糲', "lì"),
+    ('糳', "zuò"),
+    ('糴', "dí"),
+    ('糵', "niè"),
+    ('糶', "tiào"),
+    ('糷', "làn"),
+    ('糸', "mì"),
+    ('糹', "sī"),
+    ('糺', "jiū"),
+    ('系', "xì,jì"),
+    ('糼', "gōng"),
+    ('糽', "zhěng"),
+    ('糾', "jiū"),
+    ('糿', "yòu"),
+    ('紀', "jì"),
+    ('紁', "chà"),
+    ('紂', "zhòu"),
+    ('紃', "xún"),
+    ('約', "yuē"),
+    ('紅', "hóng"),
+    ('紆', "yū"),
+    ('紇', "hé"),
+    ('紈', "wán"),
+    ('紉', "rèn"),
+    ('紊', "wěn"),
+    ('紋', "wén"),
+    ('紌', "qiú"),
+    ('納', "nà"),
+    ('紎', "zī"),
+    ('紏', "tǒu"),
+    ('紐', "niǔ"),
+    ('紑', "fóu"),
+    ('紒', "jì"),
+    ('紓', "shū"),
+    ('純', "chún"),
+    ('紕', "pī"),
+    ('紖', "zhèn"),
+    ('紗', "shā"),
+    ('紘', "hóng"),
+    ('紙', "zhǐ"),
+    ('級', "jí"),
+    ('紛', "fēn"),
+    ('紜', "yún"),
+    ('紝', "rèn"),
+    ('紞', "dǎn"),
+    ('紟', "jīn"),
+    ('素', "sù"),
+    ('紡', "fǎng"),
+    ('索', "suǒ"),
+    ('紣', "cuì"),
+    ('紤', "jiǔ"),
+    ('紥', "zā"),
+    ('紦', "ba"),
+    ('紧', "jǐn"),
+    ('紨', "fū"),
+    ('紩', "zhì"),
+    ('紪', "qī"),
+    ('紫', "zǐ"),
+    ('紬', "chóu"),
+    ('紭', "hóng"),
+    ('紮', "zā"),
+    ('累', "lèi,lěi,léi"),
+    ('細', "xì"),
+    ('紱', "fú"),
+    ('紲', "xiè"),
+    ('紳', "shēn"),
+    ('紴', "bō"),
+    ('紵', "zhù"),
+    ('紶', "qū"),
+    ('紷', "líng"),
+    ('紸', "zhù"),
+    ('紹', "shào"),
+    ('紺', "gàn"),
+    ('紻', "yǎng"),
+    ('紼', "fú"),
+    ('紽', "tuó"),
+    ('紾', "zhěn"),
+    ('紿', "dài"),
+    ('絀', "chù"),
+    ('絁', "shī"),
+    ('終', "zhōng"),
+    ('絃', "xián"),
+    ('組', "zǔ"),
+    ('絅', "jiōng"),
+    ('絆', "bàn"),
+    ('絇', "qú"),
+    ('絈', "mò"),
+    ('絉', "shù"),
+    ('絊', "zuì"),
+    ('絋', "kuàng"),
+    ('経', "jīng"),
+    ('絍', "rèn"),
+    ('絎', "háng"),
+    ('絏', "xiè"),
+    ('結', "jié"),
+    ('絑', "zhū"),
+    ('絒', "chóu"),
+    ('絓', "guà"),
+    ('絔', "bǎi"),
+    ('絕', "jué"),
+    ('絖', "kuàng"),
+    ('絗', "hú"),
+    ('絘', "cì"),
+    ('絙', "huán"),
+    ('絚', "gēng"),
+    ('絛', "tāo"),
+    ('絜', "jié"),
+    ('絝', "kù"),
+    ('絞', "jiǎo"),
+    ('絟', "quán"),
+    ('絠', "gǎi"),
+    ('絡', "luò"),
+    ('絢', "xuàn"),
+    ('絣', "bēng"),
+    ('絤', "xiàn"),
+    ('絥', "fú"),
+    ('給', "gěi"),
+    ('絧', "dòng"),
+    ('絨', "róng"),
+    ('絩', "tiào"),
+    ('絪', "yīn"),
+    ('絫', "lěi"),
+    ('絬', "xiè"),
+    ('絭', "juàn"),
+    ('絮', "xù"),
+    ('絯', "gāi"),
+    ('絰', "dié"),
+    ('統', "tǒng"),
+    ('絲', "sī"),
+    ('絳', "jiàng"),
+    ('絴', "xiáng"),
+    ('絵', "huì"),
+    ('絶', "jué"),
+    ('絷', "zhí"),
+    ('絸', "jiǎn"),
+    ('絹', "juàn"),
+    ('絺', "chī"),
+    ('絻', "miǎn"),
+    ('絼', "zhèn"),
+    ('絽', "lǚ"),
+    ('絾', "chéng"),
+    ('絿', "qiú"),
+    ('綀', "shū"),
+    ('綁', "bǎng"),
+    ('綂', "tǒng"),
+    ('綃', "xiāo"),
+    ('綄', "huán"),
+    ('綅', "qīn"),
+    ('綆', "gěng"),
+    ('綇', "xiǔ"),
+    ('綈', "tí"),
+    ('綉', "tòu"),
+    ('綊', "xié"),
+    ('綋', "hóng"),
+    ('綌', "xì"),
+    ('綍', "fú"),
+    ('綎', "tīng"),
+    ('綏', "suī"),
+    ('綐', "duì"),
+    ('綑', "kǔn"),
+    ('綒', "fū"),
+    ('經', "jīng"),
+    ('綔', "hù"),
+    ('綕', "zhī"),
+    ('綖', "yán"),
+    ('綗', "jiǒng"),
+    ('綘', "féng"),
+    ('継', "jì"),
+    ('続', "xù"),
+    ('綛', "rěn"),
+    ('綜', "zōng"),
+    ('綝', "chēn"),
+    ('綞', "duǒ"),
+    ('綟', "lì"),
+    ('綠', "lǜ"),
+    ('綡', "liáng"),
+    ('綢', "chóu"),
+    ('綣', "quǎn"),
+    ('綤', "shào"),
+    ('綥', "qí"),
+    ('綦', "qí"),
+    ('綧', "zhǔn"),
+    ('綨', "qí"),
+    ('綩', "wǎn"),
+    ('綪', "qiàn"),
+    ('綫', "xiàn"),
+    ('綬', "shòu"),
+    ('維', "wéi"),
+    ('綮', "qǐ"),
+    ('綯', "táo"),
+    ('綰', "wǎn"),
+    ('綱', "gāng"),
+    ('網', "wǎng"),
+    ('綳', "bēng"),
+    ('綴', "zhuì"),
+    ('綵', "cǎi"),
+    ('綶', "guǒ"),
+    ('綷', "cuì"),
+    ('綸', "lún"),
+    ('綹', "liǔ"),
+    ('綺', "qǐ"),
+    ('綻', "zhàn"),
+    ('綼', "bì"),
+    ('綽', "chuò"),
+    ('綾', "líng"),
+    ('綿', "mián"),
+    ('緀', "qī"),
+    ('緁', "qiè"),
+    ('緂', "tián"),
+    ('緃', "zōng"),
+    ('緄', "gǔn"),
+    ('緅', "zōu"),
+    ('緆', "xī"),
+    ('緇', "zī"),
+    ('緈', "xìng"),
+    ('緉', "liǎng"),
+    ('緊', "jǐn"),
+    ('緋', "fēi"),
+    ('緌', "ruí"),
+    ('緍', "mín"),
+    ('緎', "yù"),
+    ('総', "zǒng"),
+    ('緐', "fán"),
+    ('緑', "lǜ"),
+    ('緒', "xù"),
+    ('緓', "yīng"),
+    ('緔', "shàng"),
+    ('緕', "qi"),
+    ('緖', "xù"),
+    ('緗', "xiāng"),
+    ('緘', "jiān"),
+    ('緙', "kè"),
+    ('線', "xiàn"),
+    ('緛', "ruǎn"),
+    ('緜', "mián"),
+    ('緝', "jī"),
+    ('緞', "duàn"),
+    ('緟', "chóng"),
+    ('締', "dì"),
+    ('緡', "mín"),
+    ('緢', "miáo"),
+    ('緣', "yuán"),
+    ('緤', "xiè"),
+    ('緥', "bǎo"),
+    ('緦', "sī"),
+    ('緧', "qiū"),
+    ('編', "biān"),
+    ('緩', "huǎn"),
+    ('緪', "gēng"),
+    ('緫', "cōng"),
+    ('緬', "miǎn"),
+    ('緭', "wèi"),
+    ('緮', "fù"),
+    ('緯', "wěi"),
+    ('緰', "tóu"),
+    ('緱', "gōu"),
+    ('緲', "miǎo"),
+    ('緳', "xié"),
+    ('練', "liàn"),
+    ('緵', "zōng"),
+    ('緶', "biàn"),
+    ('緷', "yùn"),
+    ('緸', "yīn"),
+    ('緹', "tí"),
+    ('緺', "guā"),
+    ('緻', "zhì"),
+    ('緼', "yùn"),
+    ('緽', "chēng"),
+    ('緾', "chán"),
+    ('緿', "dài"),
+    ('縀', "xiá"),
+    ('縁', "yuán"),
+    ('縂', "zǒng"),
+    ('縃', "xū"),
+    ('縄', "shéng"),
+    ('縅', "wēi"),
+    ('縆', "gēng"),
+    ('縇', "xuān"),
+    ('縈', "yíng"),
+    ('縉', "jìn"),
+    ('縊', "yì"),
+    ('縋', "zhuì"),
+    ('縌', "nì"),
+    ('縍', "bāng"),
+    ('縎', "gǔ"),
+    ('縏', "pán"),
+    ('縐', "zhòu"),
+    ('縑', "jiān"),
+    ('縒', "cī"),
+    ('縓', "quán"),
+    ('縔', "shuǎng"),
+    ('縕', "yùn"),
+    ('縖', "xiá"),
+    ('縗', "cuī"),
+    ('縘', "xī"),
+    ('縙', "róng"),
+    ('縚', "tāo"),
+    ('縛', "fù"),
+    ('縜', "yún"),
+    ('縝', "chēn"),
+    ('縞', "gǎo"),
+    ('縟', "rù"),
+    ('縠', "hú"),
+    ('縡', "zài"),
+    ('縢', "téng"),
+    ('縣', "xiàn"),
+    ('縤', "sù"),
+    ('縥', "zhěn"),
+    ('縦', "zòng"),
+    ('縧', "tāo"),
+    ('縨', "huǎng"),
+    ('縩', "cài"),
+    ('縪', "bì"),
+    ('縫', "fèng"),
+    ('縬', "cù"),
+    ('縭', "lí"),
+    ('縮', "suō"),
+    ('縯', "yǎn"),
+    ('縰', "xǐ"),
+    ('縱', "zòng"),
+    ('縲', "léi"),
+    ('縳', "juàn"),
+    ('縴', "qiàn"),
+    ('縵', "màn"),
+    ('縶', "zhí"),
+    ('縷', "lǚ"),
+    ('縸', "mù"),
+    ('縹', "piǎo"),
+    ('縺', "lián"),
+    ('縻', "mí"),
+    ('縼', "xuàn"),
+    ('總', "zǒng"),
+    ('績', "jī"),
+    ('縿', "shān"),
+    ('繀', "suì"),
+    ('繁', "fán"),
+    ('繂', "lǜ"),
+    ('繃', "běng"),
+    ('繄', "yī"),
+    ('繅', "sāo"),
+    ('繆', "móu"),
+    ('繇', "yáo"),
+    ('繈', "qiǎng"),
+    ('繉', "hún"),
+    ('繊', "xiān"),
+    ('繋', "jì"),
+    ('繌', "sha"),
+    ('繍', "xiù"),
+    ('繎', "rán"),
+    ('繏', "xuàn"),
+    ('繐', "suì"),
+    ('繑', "qiāo"),
+    ('繒', "zēng"),
+    ('繓', "zuǒ"),
+    ('織', "zhī"),
+    ('繕', "shàn"),
+    ('繖', "sǎn"),
+    ('繗', "lín"),
+    ('繘', "yù"),
+    ('繙', "fān"),
+    ('繚', "liáo"),
+    ('繛', "chuò"),
+    ('繜', "zūn"),
+    ('繝', "jiàn"),
+    ('繞', "rào"),
+    ('繟', "chǎn"),
+    ('繠', "ruǐ"),
+    ('繡', "xiù"),
+    ('繢', "huì"),
+    ('繣', "huà"),
+    ('繤', "zuǎn"),
+    ('繥', "xī"),
+    ('繦', "qiǎng"),
+    ('繧', "yun"),
+    ('繨', "da"),
+    ('繩', "shéng"),
+    ('繪', "huì"),
+    ('繫', "xì"),
+    ('繬', "sè"),
+    ('繭', "jiǎn"),
+    ('繮', "jiāng"),
+    ('繯', "huán"),
+    ('繰', "zǎo"),
+    ('繱', "cōng"),
+    ('繲', "xiè"),
+    ('繳', "jiǎo"),
+    ('繴', "bì"),
+    ('繵', "dàn"),
+    ('繶', "yì"),
+    ('繷', "nǒng"),
+    ('繸', "suì"),
+    ('繹', "yì"),
+    ('繺', "shǎi"),
+    ('繻', "xū"),
+    ('繼', "jì"),
+    ('繽', "bīn"),
+    ('繾', "qiǎn"),
+    ('繿', "lán"),
+    ('纀', "pú"),
+    ('纁', "xūn"),
+    ('纂', "zuǎn"),
+    ('纃', "qí"),
+    ('纄', "péng"),
+    ('纅', "yào"),
+    ('纆', "mò"),
+    ('纇', "lèi"),
+    ('纈', "xié"),
+    ('纉', "zuǎn"),
+    ('纊', "kuàng"),
+    ('纋', "yōu"),
+    ('續', "xù"),
+    ('纍', "léi"),
+    ('纎', "xiān"),
+    ('纏', "chán"),
+    ('纐', "jiǎo"),
+    ('纑', "lú"),
+    ('纒', "chán"),
+    ('纓', "yīng"),
+    ('纔', "cái"),
+    ('纕', "rǎng"),
+    ('纖', "xiān"),
+    ('纗', "zuī"),
+    ('纘', "zuǎn"),
+    ('纙', "luò"),
+    ('纚', "lí"),
+    ('纛', "dào"),
+    ('纜', "lǎn"),
+    ('纝', "léi"),
+    ('纞', "liàn"),
+    ('纟', "sī"),
+    ('纠', "jiū"),
+    ('纡', "yū"),
+    ('红', "hóng"),
+    ('纣', "zhòu"),
+    ('纤', "xiān,qiàn"),
+    ('纥', "gē"),
+    ('约', "yuē,yāo"),
+    ('级', "jí"),
+    ('纨', "wán"),
+    ('纩', "kuàng"),
+    ('纪', "jì,jǐ"),
+    ('纫', "rèn"),
+    ('纬', "wěi"),
+    ('纭', "yún"),
+    ('纮', "hóng"),
+    ('纯', "chún"),
+    ('纰', "pī"),
+    ('纱', "shā"),
+    ('纲', "gāng"),
+    ('纳', "nà"),
+    ('纴', "rèn"),
+    ('纵', "zòng"),
+    ('纶', "lún"),
+    ('纷', "fēn"),
+    ('纸', "zhǐ"),
+    ('纹', "wén"),
+    ('纺', "fǎng"),
+    ('纻', "zhù"),
+    ('纼', "zhèn"),
+    ('纽', "niǔ"),
+    ('纾', "shū"),
+    ('线', "xiàn"),
+    ('绀', "gàn"),
+    ('绁', "xiè"),
+    ('绂', "fú"),
+    ('练', "liàn"),
+    ('组', "zǔ"),
+    ('绅', "shēn"),
+    ('细', "xì"),
+    ('织', "zhī"),
+    ('终', "zhōng"),
+    ('绉', "zhòu"),
+    ('绊', "bàn"),
+    ('绋', "fú"),
+    ('绌', "chù"),
+    ('绍', "shào"),
+    ('绎', "yì"),
+    ('经', "jīng"),
+    ('绐', "dài"),
+    ('绑', "bǎng"),
+    ('绒', "róng"),
+    ('结', "jié,jiē"),
+    ('绔', "kù"),
+    ('绕', "rào"),
+    ('绖', "dié"),
+    ('绗', "háng"),
+    ('绘', "huì"),
+    ('给', "gěi,jǐ"),
+    ('绚', "xuàn"),
+    ('绛', "jiàng"),
+    ('络', "luò,lào"),
+    ('绝', "jué"),
+    ('绞', "jiǎo"),
+    ('统', "tǒng"),
+    ('绠', "gěng"),
+    ('绡', "xiāo"),
+    ('绢', "juàn"),
+    ('绣', "xiù"),
+    ('绤', "xì"),
+    ('绥', "suí"),
+    ('绦', "tāo"),
+    ('继', "jì"),
+    ('绨', "tí"),
+    ('绩', "jī"),
+    ('绪', "xù"),
+    ('绫', "líng"),
+    ('绬', "yīng"),
+    ('续', "xù"),
+    ('绮', "qǐ"),
+    ('绯', "fēi"),
+    ('绰', "chuò,chāo"),
+    ('绱', "shàng"),
+    ('绲', "gǔn"),
+    ('绳', "shéng"),
+    ('维', "wéi"),
+    ('绵', "mián"),
+    ('绶', "shòu"),
+    ('绷', "bēng"),
+    ('绸', "chóu"),
+    ('绹', "táo"),
+    ('绺', "liǔ"),
+    ('绻', "quǎn"),
+    ('综', "zōng,zèng"),
+    ('绽', "zhàn"),
+    ('绾', "wǎn"),
+    ('绿', "lǜ,lù"),
+    ('缀', "zhuì"),
+    ('缁', "zī"),
+    ('缂', "kè"),
+    ('缃', "xiāng"),
+    ('缄', "jiān"),
+    ('缅', "miǎn"),
+    ('缆', "lǎn"),
+    ('缇', "tí"),
+    ('缈', "miǎo"),
+    ('缉', "jī"),
+    ('缊', "yūn"),
+    ('缋', "huì"),
+    ('缌', "sī"),
+    ('缍', "duǒ"),
+    ('缎', "duàn"),
+    ('缏', "biàn"),
+    ('缐', "xiàn"),
+    ('缑', "gōu"),
+    ('缒', "zhuì"),
+    ('缓', "huǎn"),
+    ('缔', "dì"),
+    ('缕', "lǚ"),
+    ('编', "biān"),
+    ('缗', "mín"),
+    ('缘', "yuán"),
+    ('缙', "jìn"),
+    ('缚', "fù"),
+    ('缛', "rù"),
+    ('缜', "zhěn"),
+    ('缝', "féng,fèng"),
+    ('缞', "cuī"),
+    ('缟', "gǎo"),
+    ('缠', "chán"),
+    ('缡', "lí"),
+    ('缢', "yì"),
+    ('缣', "jiān"),
+    ('缤', "bīn"),
+    ('缥', "piāo"),
+    ('缦', "màn"),
+    ('缧', "léi"),
+    ('缨', "yīng"),
+    ('缩', "suō"),
+    ('缪', "miù,móu,miào"),
+    ('缫', "sāo"),
+    ('缬', "xié"),
+    ('缭', "liáo"),
+    ('缮', "shàn"),
+    ('缯', "zēng"),
+    ('缰', "jiāng"),
+    ('缱', "qiǎn"),
+    ('缲', "qiāo"),
+    ('缳', "huán"),
+    ('缴', "jiǎo,zhuó"),
+    ('缵', "zuǎn"),
+    ('缶', "fǒu"),
+    ('缷', "xiè"),
+    ('缸', "gāng"),
+    ('缹', "fǒu"),
+    ('缺', "quē"),
+    ('缻', "fǒu"),
+    ('缼', "qi"),
+    ('缽', "bō"),
+    ('缾', "píng"),
+    ('缿', "xiàng"),
+    ('罀', "zhao"),
+    ('罁', "gāng"),
+    ('罂', "yīng"),
+    ('罃', "yīng"),
+    ('罄', "qìng"),
+    ('罅', "xià"),
+    ('罆', "guàn"),
+    ('罇', "zūn"),
+    ('罈', "tán"),
+    ('罉', "chēng"),
+    ('罊', "qì"),
+    ('罋', "wèng"),
+    ('罌', "yīng"),
+    ('罍', "léi"),
+    ('罎', "tán"),
+    ('罏', "lú"),
+    ('罐', "guàn"),
+    ('网', "wǎng"),
+    ('罒', "wǎng"),
+    ('罓', "gāng"),
+    ('罔', "wǎng"),
+    ('罕', "hǎn"),
+    ('罖', "luó"),
+    ('罗', "luó,luō"),
+    ('罘', "fú"),
+    ('罙', "shēn"),
+    ('罚', "fá"),
+    ('罛', "gū"),
+    ('罜', "zhǔ"),
+    ('罝', "jū"),
+    ('罞', "máo"),
+    ('罟', "gǔ"),
+    ('罠', "mín"),
+    ('罡', "gāng"),
+    ('罢', "bà"),
+    ('罣', "guà"),
+    ('罤', "tí"),
+    ('罥', "juàn"),
+    ('罦', "fú"),
+    ('罧', "shèn"),
+    ('罨', "yǎn"),
+    ('罩', "zhào"),
+    ('罪', "zuì"),
+    ('罫', "guà"),
+    ('罬', "zhuó"),
+    ('罭', "yù"),
+    ('置', "zhì"),
+    ('罯', "ǎn"),
+    ('罰', "fá"),
+    ('罱', "lǎn"),
+    ('署', "shǔ"),
+    ('罳', "sī"),
+    ('罴', "pí"),
+    ('罵', "mà"),
+    ('罶', "liǔ"),
+    ('罷', "bà"),
+    ('罸', "fá"),
+    ('罹', "lí"),
+    ('罺', "cháo"),
+    ('罻', "wèi"),
+    ('罼', "bì"),
+    ('罽', "jì"),
+    ('罾', "zēng"),
+    ('罿', "chōng"),
+    ('羀', "liǔ"),
+    ('羁', "jī"),
+    ('羂', "juàn"),
+    ('羃', "mì"),
+    ('羄', "zhào"),
+    ('羅', "luó"),
+    ('羆', "pí"),
+    ('羇', "jī"),
+    ('羈', "jī"),
+    ('羉', "luán"),
+    ('羊', "yáng"),
+    ('羋', "mǐ"),
+    ('羌', "qiāng"),
+    ('羍', "dá"),
+    ('美', "měi"),
+    ('羏', "yáng"),
+    ('羐', "yǒu"),
+    ('羑', "yǒu"),
+    ('羒', "fén"),
+    ('羓', "bā"),
+    ('羔', "gāo"),
+    ('羕', "yàng"),
+    ('羖', "gǔ"),
+    ('羗', "qiāng"),
+    ('羘', "zāng"),
+    ('羙', "gāo"),
+    ('羚', "líng"),
+    ('羛', "yì"),
+    ('羜', "zhù"),
+    ('羝', "dī"),
+    ('羞', "xiū"),
+    ('羟', "qiǎng"),
+    ('羠', "yí"),
+    ('羡', "xiàn"),
+    ('羢', "róng"),
+    ('羣', "qún"),
+    ('群', "qún"),
+    ('羥', "qiǎng"),
+    ('羦', "huán"),
+    ('羧', "suō"),
+    ('羨', "xiàn"),
+    ('義', "yì"),
+    ('羪', "yang"),
+    ('羫', "qiāng"),
+    ('羬', "qián"),
+    ('羭', "yú"),
+    ('羮', "gēng"),
+    ('羯', "jié"),
+    ('羰', "tāng"),
+    ('羱', "yuán"),
+    ('羲', "xī"),
+    ('羳', "fán"),
+    ('羴', "shān"),
+    ('羵', "fén"),
+    ('羶', "shān"),
+    ('羷', "liǎn"),
+    ('羸', "léi"),
+    ('羹', "gēng"),
+    ('羺', "nóu"),
+    ('羻', "qiàng"),
+    ('羼', "chàn"),
+    ('羽', "yǔ"),
+    ('羾', "gòng"),
+    ('羿', "yì"),
+    ('翀', "chōng"),
+    ('翁', "wēng"),
+    ('翂', "fēn"),
+    ('翃', "hóng"),
+    ('翄', "chì"),
+    ('翅', "chì"),
+    ('翆', "cuì"),
+    ('翇', "fú"),
+    ('翈', "xiá"),
+    ('翉', "běn"),
+    ('翊', "yì"),
+    ('翋', "lā"),
+    ('翌', "yì"),
+    ('翍', "pī"),
+    ('翎', "líng"),
+    ('翏', "liù"),
+    ('翐', "zhì"),
+    ('翑', "qú"),
+    ('習', "xí"),
+    ('翓', "xié"),
+    ('翔', "xiáng"),
+    ('翕', "xī"),
+    ('翖', "xī"),
+    ('翗', "ké"),
+    ('翘', "qiào,qiáo"),
+    ('翙', "huì"),
+    ('翚', "huī"),
+    ('翛', "xiāo"),
+    ('翜', "shà"),
+    ('翝', "hóng"),
+    ('翞', "jiāng"),
+    ('翟', "dí"),
+    ('翠', "cuì"),
+    ('翡', "fěi"),
+    ('翢', "dào"),
+    ('翣', "shà"),
+    ('翤', "chì"),
+    ('翥', "zhù"),
+    ('翦', "jiǎn"),
+    ('翧', "xuān"),
+    ('翨', "chì"),
+    ('翩', "piān"),
+    ('翪', "zōng"),
+    ('翫', "wán"),
+    ('翬', "huī"),
+    ('翭', "hóu"),
+    ('翮', "hé"),
+    ('翯', "hè"),
+    ('翰', "hàn"),
+    ('翱', "áo"),
+    ('翲', "piāo"),
+    ('翳', "yì"),
+    ('翴', "lián"),
+    ('翵', "hóu"),
+    ('翶', "áo"),
+    ('翷', "lín"),
+    ('翸', "pěn"),
+    ('翹', "qiào"),
+    ('翺', "áo"),
+    ('翻', "fān"),
+    ('翼', "yì"),
+    ('翽', "huì"),
+    ('翾', "xuān"),
+    ('翿', "dào"),
+    ('耀', "yào"),
+    ('老', "lǎo"),
+    ('耂', "lǎo"),
+    ('考', "kǎo"),
+    ('耄', "mào"),
+    ('者', "zhě"),
+    ('耆', "qí"),
+    ('耇', "gǒu"),
+    ('耈', "gǒu"),
+    ('耉', "gǒu"),
+    ('耊', "dié"),
+    ('耋', "dié"),
+    ('而', "ér"),
+    ('耍', "shuǎ"),
+    ('耎', "ruǎn"),
+    ('耏', "nài"),
+    ('耐', "nài"),
+    ('耑', "duān"),
+    ('耒', "lěi"),
+    ('耓', "tīng"),
+    ('耔', "zǐ"),
+    ('耕', "gēng"),
+    ('耖', "chào"),
+    ('耗', "hào"),
+    ('耘', "yún"),
+    ('耙', "bà"),
+    ('耚', "pī"),
+    ('耛', "yí"),
+    ('耜', "sì"),
+    ('耝', "qù"),
+    ('耞', "jiā"),
+    ('耟', "jù"),
+    ('耠', "huō"),
+    ('耡', "chú"),
+    ('耢', "lào"),
+    ('耣', "lǔn"),
+    ('耤', "jí"),
+    ('耥', "tāng"),
+    ('耦', "ǒu"),
+    ('耧', "lóu"),
+    ('耨', "nòu"),
+    ('耩', "jiǎng"),
+    ('耪', "pǎng"),
+    ('耫', "zhá"),
+    ('耬', "lóu"),
+    ('耭', "jī"),
+    ('耮', "lào"),
+    ('耯', "huò"),
+    ('耰', "yōu"),
+    ('耱', "mò"),
+    ('耲', "huái"),
+    ('耳', "ěr"),
+    ('耴', "yì"),
+    ('耵', "dīng"),
+    ('耶', "yé"),
+    ('耷', "dā"),
+    ('耸', "sǒng"),
+    ('耹', "qín"),
+    ('耺', "yún"),
+    ('耻', "chǐ"),
+    ('耼', "dān"),
+    ('耽', "dān"),
+    ('耾', "hóng"),
+    ('耿', "gěng"),
+    ('聀', "zhí"),
+    ('聁', "pàn"),
+    ('聂', "niè"),
+    ('聃', "dān"),
+    ('聄', "zhěn"),
+    ('聅', "chè"),
+    ('聆', "líng"),
+    ('聇', "zhēng"),
+    ('聈', "yǒu"),
+    ('聉', "wà"),
+    ('聊', "liáo"),
+    ('聋', "lóng"),
+    ('职', "zhí"),
+    ('聍', "níng"),
+    ('聎', "tiāo"),
+    ('聏', "ér"),
+    ('聐', "yà"),
+    ('聑', "tiē"),
+    ('聒', "guā"),
+    ('聓', "xù"),
+    ('联', "lián"),
+    ('聕', "hào"),
+    ('聖', "shèng"),
+    ('聗', "liè"),
+    ('聘', "pìn"),
+    ('聙', "jīng"),
+    ('聚', "jù"),
+    ('聛', "bǐ"),
+    ('聜', "dǐ"),
+    ('聝', "guó"),
+    ('聞', "wén"),
+    ('聟', "xù"),
+    ('聠', "pīng"),
+    ('聡', "cōng"),
+    ('聢', "dìng"),
+    ('聣', "ní"),
+    ('聤', "tíng"),
+    ('聥', "jǔ"),
+    ('聦', "cōng"),
+    ('聧', "kuī"),
+    ('聨', "lián"),
+    ('聩', "kuì"),
+    ('聪', "cōng"),
+    ('聫', "lián"),
+    ('聬', "wěng"),
+    ('聭', "kuì"),
+    ('聮', "lián"),
+    ('聯', "lián"),
+    ('聰', "cōng"),
+    ('聱', "áo"),
+    ('聲', "shēng"),
+    ('聳', "sǒng"),
+    ('聴', "tīng"),
+    ('聵', "kuì"),
+    ('聶', "niè"),
+    ('職', "zhí"),
+    ('聸', "dān"),
+    ('聹', "níng"),
+    ('聺', "qié"),
+    ('聻', "nǐ"),
+    ('聼', "tīng"),
+    ('聽', "tīng"),
+    ('聾', "lóng"),
+    ('聿', "yù"),
+    ('肀', "yù"),
+    ('肁', "zhào"),
+    ('肂', "sì"),
+    ('肃', "sù"),
+    ('肄', "yì"),
+    ('肅', "sù"),
+    ('肆', "sì"),
+    ('肇', "zhào"),
+    ('肈', "zhào"),
+    ('肉', "ròu"),
+    ('肊', "yì"),
+    ('肋', "lèi,lē"),
+    ('肌', "jī"),
+    ('肍', "qiú"),
+    ('肎', "kěn"),
+    ('肏', "cào"),
+    ('肐', "gē"),
+    ('肑', "bó"),
+    ('肒', "huàn"),
+    ('肓', "huāng"),
+    ('肔', "chǐ"),
+    ('肕', "rèn"),
+    ('肖', "xiào,xiāo"),
+    ('肗', "rǔ"),
+    ('肘', "zhǒu"),
+    ('肙', "yuàn"),
+    ('肚', "dù,dǔ"),
+    ('肛', "gāng"),
+    ('肜', "róng"),
+    ('肝', "gān"),
+    ('肞', "chā"),
+    ('肟', "wò"),
+    ('肠', "cháng"),
+    ('股', "gǔ"),
+    ('肢', "zhī"),
+    ('肣', "hán"),
+    ('肤', "fū"),
+    ('肥', "féi"),
+    ('肦', "fén"),
+    ('肧', "pēi"),
+    ('肨', "pàng"),
+    ('肩', "jiān"),
+    ('肪', "fáng"),
+    ('肫', "zhūn"),
+    ('肬', "yóu"),
+    ('肭', "nà"),
+    ('肮', "āng"),
+    ('肯', "kěn"),
+    ('肰', "rán"),
+    ('肱', "gōng"),
+    ('育', "yù"),
+    ('肳', "wěn"),
+    ('肴', "yáo"),
+    ('肵', "qí"),
+    ('肶', "pí"),
+    ('肷', "qiǎn"),
+    ('肸', "xī"),
+    ('肹', "xī"),
+    ('肺', "fèi"),
+    ('肻', "kěn"),
+    ('肼', "jǐng"),
+    ('肽', "tài"),
+    ('肾', "shèn"),
+    ('肿', "zhǒng"),
+    ('胀', "zhàng"),
+    ('胁', "xié"),
+    ('胂', "shèn"),
+    ('胃', "wèi"),
+    ('胄', "zhòu"),
+    ('胅', "dié"),
+    ('胆', "dǎn"),
+    ('胇', "fèi"),
+    ('胈', "bá"),
+    ('胉', "bó"),
+    ('胊', "qú"),
+    ('胋', "tián"),
+    ('背', "bèi,bēi"),
+    ('胍', "guā"),
+    ('胎', "tāi"),
+    ('胏', "zǐ"),
+    ('胐', "fěi"),
+    ('胑', "zhī"),
+    ('胒', "nì"),
+    ('胓', "píng"),
+    ('胔', "zì"),
+    ('胕', "fǔ"),
+    ('胖', "pàng,pán"),
+    ('胗', "zhēn"),
+    ('胘', "xián"),
+    ('胙', "zuò"),
+    ('胚', "pēi"),
+    ('胛', "jiǎ"),
+    ('胜', "shèng"),
+    ('胝', "zhī"),
+    ('胞', "bāo"),
+    ('胟', "mǔ"),
+    ('胠', "qū"),
+    ('胡', "hú"),
+    ('胢', "kē"),
+    ('胣', "chǐ"),
+    ('胤', "yìn"),
+    ('胥', "xū"),
+    ('胦', "yāng"),
+    ('胧', "lóng"),
+    ('胨', "dòng"),
+    ('胩', "kǎ"),
+    ('胪', "lú"),
+    ('胫', "jìng"),
+    ('胬', "nǔ"),
+    ('胭', "yān"),
+    ('胮', "pāng"),
+    ('胯', "kuà"),
+    ('胰', "yí"),
+    ('胱', "guāng"),
+    ('胲', "hǎi"),
+    ('胳', "gē"),
+    ('胴', "dòng"),
+    ('胵', "chī"),
+    ('胶', "jiāo"),
+    ('胷', "xiōng"),
+    ('胸', "xiōng"),
+    ('胹', "ér"),
+    ('胺', "àn"),
+    ('胻', "héng"),
+    ('胼', "pián"),
+    ('能', "néng"),
+    ('胾', "zì"),
+    ('胿', "guī"),
+    ('脀', "chéng"),
+    ('脁', "tiǎo"),
+    ('脂', "zhī"),
+    ('脃', "cuì"),
+    ('脄', "méi"),
+    ('脅', "xié"),
+    ('脆', "cuì"),
+    ('脇', "xié"),
+    ('脈', "mài"),
+    ('脉', "mài"),
+    ('脊', "jí"),
+    ('脋', "xié"),
+    ('脌', "nin"),
+    ('脍', "kuài"),
+    ('脎', "sà"),
+    ('脏', "zāng,zàng"),
+    ('脐', "qí"),
+    ('脑', "nǎo"),
+    ('脒', "mǐ"),
+    ('脓', "nóng"),
+    ('脔', "luán"),
+    ('脕', "wàn"),
+    ('脖', "bó"),
+    ('脗', "wěn"),
+    ('脘', "wǎn"),
+    ('脙', "xiū"),
+    ('脚', "jiǎo,jué"),
+    ('脛', "jìng"),
+    ('脜', "yǒu"),
+    ('脝', "hēng"),
+    ('脞', "cuǒ"),
+    ('脟', "liè"),
+    ('脠', "shān"),
+    ('脡', "tǐng"),
+    ('脢', "méi"),
+    ('脣', "chún"),
+    ('脤', "shèn"),
+    ('脥', "qiǎn"),
+    ('脦', "de"),
+    ('脧', "juān"),
+    ('脨', "cù"),
+    ('脩', "xiū"),
+    ('脪', "xìn"),
+    ('脫', "tuō"),
+    ('脬', "pāo"),
+    ('脭', "chéng"),
+    ('脮', "něi"),
+    ('脯', "fǔ,pú"),
+    ('脰', "dòu"),
+    ('脱', "tuō"),
+    ('脲', "niào"),
+    ('脳', "nǎo"),
+    ('脴', "pǐ"),
+    ('脵', "gǔ"),
+    ('脶', "luó"),
+    ('脷', "lì"),
+    ('脸', "liǎn"),
+    ('脹', "zhàng"),
+    ('脺', "cuì"),
+    ('脻', "jiē"),
+    ('脼', "liǎng"),
+    ('脽', "shuí"),
+    ('脾', "pí"),
+    ('脿', "biāo"),
+    ('腀', "lún"),
+    ('腁', "pián"),
+    ('腂', "lěi"),
+    ('腃', "kuì"),
+    ('腄', "chuí"),
+    ('腅', "dàn"),
+    ('腆', "tiǎn"),
+    ('腇', "něi"),
+    ('腈', "jīng"),
+    ('腉', "nái"),
+    ('腊', "là,xī"),
+    ('腋', "yè"),
+    ('腌', "yān"),
+    ('腍', "rèn"),
+    ('腎', "shèn"),
+    ('腏', "chuò"),
+    ('腐', "fǔ"),
+    ('腑', "fǔ"),
+    ('腒', "jū"),
+    ('腓', "féi"),
+    ('腔', "qiāng"),
+    ('腕', "wàn"),
+    ('腖', "dòng"),
+    ('腗', "pí"),
+    ('腘', "guó"),
+    ('腙', "zōng"),
+    ('腚', "dìng"),
+    ('腛', "wò"),
+    ('腜', "méi"),
+    ('腝', "ní"),
+    ('腞', "zhuàn"),
+    ('腟', "chì"),
+    ('腠', "còu"),
+    ('腡', "luó"),
+    ('腢', "ǒu"),
+    ('腣', "dì"),
+    ('腤', "ān"),
+    ('腥', "xīng"),
+    ('腦', "nǎo"),
+    ('腧', "shù"),
+    ('腨', "shuàn"),
+    ('腩', "nǎn"),
+    ('腪', "yùn"),
+    ('腫', "zhǒng"),
+    ('腬', "róu"),
+    ('腭', "è"),
+    ('腮', "sāi"),
+    ('腯', "tú"),
+    ('腰', "yāo"),
+    ('腱', "jiàn"),
+    ('腲', "wěi"),
+    ('腳', "jiǎo"),
+    ('腴', "yú"),
+    ('腵', "jiā"),
+    ('腶', "duàn"),
+    ('腷', "bì"),
+    ('腸', "cháng"),
+    ('腹', "fù"),
+    ('腺', "xiàn"),
+    ('腻', "nì"),
+    ('腼', "miǎn"),
+    ('腽', "wà"),
+    ('腾', "téng"),
+    ('腿', "tuǐ"),
+    ('膀', "bǎng,páng,pāng"),
+    ('膁', "qiǎn"),
+    ('膂', "lǚ"),
+    ('膃', "wà"),
+    ('膄', "shòu"),
+    ('膅', "táng"),
+    ('膆', "sù"),
+    ('膇', "zhuì"),
+    ('膈', "gé"),
+    ('膉', "yì"),
+    ('膊', "bó"),
+    ('膋', "liáo"),
+    ('膌', "jí"),
+    ('膍', "pí"),
+    ('膎', "xié"),
+    ('膏', "gāo,gào"),
+    ('膐', "lǚ"),
+    ('膑', "bìn"),
+    ('膒', "ōu"),
+    ('膓', "cháng"),
+    ('膔', "lù"),
+    ('膕', "guó"),
+    ('膖', "pāng"),
+    ('膗', "chuái"),
+    ('膘', "biāo"),
+    ('膙', "jiǎng"),
+    ('膚', "fū"),
+    ('膛', "táng"),
+    ('膜', "mó"),
+    ('膝', "xī"),
+    ('膞', "zhuān"),
+    ('膟', "lǜ"),
+    ('膠', "jiāo"),
+    ('膡', "yìng"),
+    ('膢', "lǘ"),
+    ('膣', "zhì"),
+    ('膤', "xuě"),
+    ('膥', "cūn"),
+    ('膦', "lìn"),
+    ('膧', "tóng"),
+    ('膨', "péng"),
+    ('膩', "nì"),
+    ('膪', "chuài"),
+    ('膫', "liáo"),
+    ('膬', "cuì"),
+    ('膭', "guī"),
+    ('膮', "xiāo"),
+    ('膯', "tēng"),
+    ('膰', "fán"),
+    ('膱', "zhí"),
+    ('膲', "jiāo"),
+    ('膳', "shàn"),
+    ('膴', "hū"),
+    ('膵', "cuì"),
+    ('膶', "rùn"),
+    ('膷', "xiāng"),
+    ('膸', "suǐ"),
+    ('膹', "fèn"),
+    ('膺', "yīng"),
+    ('膻', "shān"),
+    ('膼', "zhuā"),
+    ('膽', "dǎn"),
+    ('膾', "kuài"),
+    ('膿', "nóng"),
+    ('臀', "tún"),
+    ('臁', "lián"),
+    ('臂', "bì,bei"),
+    ('臃', "yōng"),
+    ('臄', "jué"),
+    ('臅', "chù"),
+    ('臆', "yì"),
+    ('臇', "juǎn"),
+    ('臈', "là"),
+    ('臉', "liǎn"),
+    ('臊', "sāo"),
+    ('臋', "tún"),
+    ('臌', "gǔ"),
+    ('臍', "qí"),
+    ('臎', "cuì"),
+    ('臏', "bìn"),
+    ('臐', "xūn"),
+    ('臑', "nào"),
+    ('臒', "wò"),
+    ('臓', "zàng"),
+    ('臔', "xiàn"),
+    ('臕', "biāo"),
+    ('臖', "xìng"),
+    ('臗', "kuān"),
+    ('臘', "là"),
+    ('臙', "yān"),
+    ('臚', "lú"),
+    ('臛', "huò"),
+    ('臜', "zā"),
+    ('臝', "luǒ"),
+    ('臞', "qú"),
+    ('臟', "zàng"),
+    ('臠', "luán"),
+    ('臡', "ní"),
+    ('臢', "zā"),
+    ('臣', "chén"),
+    ('臤', "qiān"),
+    ('臥', "wò"),
+    ('臦', "guàng"),
+    ('臧', "zāng"),
+    ('臨', "lín"),
+    ('臩', "guǎng"),
+    ('自', "zì"),
+    ('臫', "jiǎo"),
+    ('臬', "niè"),
+    ('臭', "chòu,xiù"),
+    ('臮', "jì"),
+    ('臯', "gāo"),
+    ('臰', "chòu"),
+    ('臱', "mián"),
+    ('臲', "niè"),
+    ('至', "zhì"),
+    ('致', "zhì"),
+    ('臵', "gé"),
+    ('臶', "jiàn"),
+    ('臷', "dié"),
+    ('臸', "zhī"),
+    ('臹', "xiū"),
+    ('臺', "tái"),
+    ('臻', "zhēn"),
+    ('臼', "jiù"),
+    ('臽', "xiàn"),
+    ('臾', "yú"),
+    ('臿', "chā"),
+    ('舀', "yǎo"),
+    ('舁', "yú"),
+    ('舂', "chōng"),
+    ('舃', "xì"),
+    ('舄', "xì"),
+    ('舅', "jiù"),
+    ('舆', "yú"),
+    ('與', "yǔ"),
+    ('興', "xìng"),
+    ('舉', "jǔ"),
+    ('舊', "jiù"),
+    ('舋', "xìn"),
+    ('舌', "shé"),
+    ('舍', "shě,shè"),
+    ('舎', "shè"),
+    ('舏', "jiǔ"),
+    ('舐', "shì"),
+    ('舑', "tān"),
+    ('舒', "shū"),
+    ('舓', "shì"),
+    ('舔', "tiǎn"),
+    ('舕', "tàn"),
+    ('舖', "pù"),
+    ('舗', "pù"),
+    ('舘', "guǎn"),
+    ('舙', "huà"),
+    ('舚', "tiàn"),
+    ('舛', "chuǎn"),
+    ('舜', "shùn"),
+    ('舝', "xiá"),
+    ('舞', "wǔ"),
+    ('舟', "zhōu"),
+    ('舠', "dāo"),
+    ('舡', "chuán"),
+    ('舢', "shān"),
+    ('舣', "yǐ"),
+    ('舤', "fán"),
+    ('舥', "pā"),
+    ('舦', "tài"),
+    ('舧', "fán"),
+    ('舨', "bǎn"),
+    ('舩', "chuán"),
+    ('航', "háng"),
+    ('舫', "fǎng"),
+    ('般', "bān"),
+    ('舭', "bǐ"),
+    ('舮', "lú"),
+    ('舯', "zhōng"),
+    ('舰', "jiàn"),
+    ('舱', "cāng"),
+    ('舲', "líng"),
+    ('舳', "zhú"),
+    ('舴', "zé"),
+    ('舵', "duò"),
+    ('舶', "bó"),
+    ('舷', "xián"),
+    ('舸', "gě"),
+    ('船', "chuán"),
+    ('舺', "xiá"),
+    ('舻', "lú"),
+    ('舼', "qióng"),
+    ('舽', "páng"),
+    ('舾', "xī"),
+    ('舿', "kuā"),
+    ('艀', "fú"),
+    ('艁', "zào"),
+    ('艂', "féng"),
+    ('艃', "lí"),
+    ('艄', "shāo"),
+    ('艅', "yú"),
+    ('艆', "láng"),
+    ('艇', "tǐng"),
+    ('艈', "yù"),
+    ('艉', "wěi"),
+    ('艊', "bó"),
+    ('艋', "měng"),
+    ('艌', "niàn"),
+    ('艍', "jū"),
+    ('艎', "huáng"),
+    ('艏', "shǒu"),
+    ('艐', "kè"),
+    ('艑', "biàn"),
+    ('艒', "mù"),
+    ('艓', "dié"),
+    ('艔', "dào"),
+    ('艕', "bàng"),
+    ('艖', "chā"),
+    ('艗', "yì"),
+    ('艘', "sōu"),
+    ('艙', "cāng"),
+    ('艚', "cáo"),
+    ('艛', "lóu"),
+    ('艜', "dài"),
+    ('艝', "xuě"),
+    ('艞', "yào"),
+    ('艟', "chōng"),
+    ('艠', "dēng"),
+    ('艡', "dāng"),
+    ('艢', "qiáng"),
+    ('艣', "lǔ"),
+    ('艤', "yǐ"),
+    ('艥', "jí"),
+    ('艦', "jiàn"),
+    ('艧', "huò"),
+    ('艨', "méng"),
+    ('艩', "qí"),
+    ('艪', "lǔ"),
+    ('艫', "lú"),
+    ('艬', "chán"),
+    ('艭', "shuāng"),
+    ('艮', "gěn"),
+    ('良', "liáng"),
+    ('艰', "jiān"),
+    ('艱', "jiān"),
+    ('色', "sè,shǎi"),
+    ('艳', "yàn"),
+    ('艴', "fú"),
+    ('艵', "pīng"),
+    ('艶', "yàn"),
+    ('艷', "yàn"),
+    ('艸', "cǎo"),
+    ('艹', "cao"),
+    ('艺', "yì"),
+    ('艻', "lè"),
+    ('艼', "tīng"),
+    ('艽', "jiāo"),
+    ('艾', "ài"),
+    ('艿', "nǎi"),
+    ('芀', "tiáo"),
+    ('芁', "jiāo"),
+    ('节', "jié"),
+    ('芃', "péng"),
+    ('芄', "wán"),
+    ('芅', "yì"),
+    ('芆', "chāi"),
+    ('芇', "mián"),
+    ('芈', "mǐ"),
+    ('芉', "gān"),
+    ('芊', "qiān"),
+    ('芋', "yù"),
+    ('芌', "yù"),
+    ('芍', "sháo"),
+    ('芎', "qiōng"),
+    ('芏', "dù"),
+    ('芐', "hù"),
+    ('芑', "qǐ"),
+    ('芒', "máng"),
+    ('芓', "zì"),
+    ('芔', "huì"),
+    ('芕', "suī"),
+    ('芖', "zhì"),
+    ('芗', "xiāng"),
+    ('芘', "pí"),
+    ('芙', "fú"),
+    ('芚', "tún"),
+    ('芛', "wěi"),
+    ('芜', "wú"),
+    ('芝', "zhī"),
+    ('芞', "qì"),
+    ('芟', "shān"),
+    ('芠', "wén"),
+    ('芡', "qiàn"),
+    ('芢', "rén"),
+    ('芣', "fú"),
+    ('芤', "kōu"),
+    ('芥', "jiè,gài"),
+    ('芦', "lú"),
+    ('芧', "xù"),
+    ('芨', "jī"),
+    ('芩', "qín"),
+    ('芪', "qí"),
+    ('芫', "yán"),
+    ('芬', "fēn"),
+    ('芭', "bā"),
+    ('芮', "ruì"),
+    ('芯', "xīn"),
+    ('芰', "jì"),
+    ('花', "huā"),
+    ('芲', "huā"),
+    ('芳', "fāng"),
+    ('芴', "wù"),
+    ('芵', "jué"),
+    ('芶', "gǒu"),
+    ('芷', "zhǐ"),
+    ('芸', "yún"),
+    ('芹', "qín"),
+    ('芺', "ǎo"),
+    ('芻', "chú"),
+    ('芼', "mào"),
+    ('芽', "yá"),
+    ('芾', "fèi"),
+    ('芿', "rèng"),
+    ('苀', "háng"),
+    ('苁', "cōng"),
+    ('苂', "yín"),
+    ('苃', "yǒu"),
+    ('苄', "biàn"),
+    ('苅', "yì"),
+    ('苆', "qiē"),
+    ('苇', "wěi"),
+    ('苈', "lì"),
+    ('苉', "pǐ"),
+    ('苊', "è"),
+    ('苋', "xiàn"),
+    ('苌', "cháng"),
+    ('苍', "cāng"),
+    ('苎', "zhù"),
+    ('苏', "sū"),
+    ('苐', "tí"),
+    ('苑', "yuàn"),
+    ('苒', "rǎn"),
+    ('苓', "líng"),
+    ('苔', "tái,tāi"),
+    ('苕', "sháo"),
+    ('苖', "dí"),
+    ('苗', "miáo"),
+    ('苘', "qǐng"),
+    ('苙', "lì"),
+    ('苚', "yòng"),
+    ('苛', "kē"),
+    ('苜', "mù"),
+    ('苝', "bèi"),
+    ('苞', "bāo"),
+    ('苟', "gǒu"),
+    ('苠', "mín"),
+    ('苡', "yǐ"),
+    ('苢', "yǐ"),
+    ('苣', "jù"),
+    ('苤', "piě"),
+    ('若', "ruò,rě"),
+    ('苦', "kǔ"),
+    ('苧', "níng"),
+    ('苨', "nǐ"),
+    ('苩', "bó"),
+    ('苪', "bǐng"),
+    ('苫', "shàn,shān"),
+    ('苬', "xiú"),
+    ('苭', "yǎo"),
+    ('苮', "xiān"),
+    ('苯', "běn"),
+    ('苰', "hóng"),
+    ('英', "yīng"),
+    ('苲', "zhǎ"),
+    ('苳', "dōng"),
+    ('苴', "jū"),
+    ('苵', "dié"),
+    ('苶', "nié"),
+    ('苷', "gān"),
+    ('苸', "hū"),
+    ('苹', "píng"),
+    ('苺', "méi"),
+    ('苻', "fú"),
+    ('苼', "shēng"),
+    ('苽', "gū"),
+    ('苾', "bì"),
+    ('苿', "wèi"),
+    ('茀', "fú"),
+    ('茁', "zhuó"),
+    ('茂', "mào"),
+    ('范', "fàn"),
+    ('茄', "qié,jiā"),
+    ('茅', "máo"),
+    ('茆', "máo"),
+    ('茇', "bá"),
+    ('茈', "cí"),
+    ('茉', "mò"),
+    ('茊', "zī"),
+    ('茋', "zhǐ"),
+    ('茌', "chí"),
+    ('茍', "jì"),
+    ('茎', "jīng"),
+    ('茏', "lóng"),
+    ('茐', "cōng"),
+    ('茑', "niǎo"),
+    ('茒', "yuán"),
+    ('茓', "xué"),
+    ('茔', "yíng"),
+    ('茕', "qióng"),
+    ('茖', "gé"),
+    ('茗', "míng"),
+    ('茘', "lì"),
+    ('茙', "róng"),
+    ('茚', "yìn"),
+    ('茛', "gèn"),
+    ('茜', "qiàn"),
+    ('茝', "chǎi"),
+    ('茞', "chén"),
+    ('茟', "yù"),
+    ('茠', "hāo"),
+    ('茡', "zì"),
+    ('茢', "liè"),
+    ('茣', "wú"),
+    ('茤', "jì"),
+    ('茥', "guī"),
+    ('茦', "cì"),
+    ('茧', "jiǎn"),
+    ('茨', "cí"),
+    ('茩', "gòu"),
+    ('茪', "guāng"),
+    ('茫', "máng"),
+    ('茬', "chá"),
+    ('茭', "jiāo"),
+    ('茮', "jiāo"),
+    ('茯', "fú"),
+    ('茰', "yú"),
+    ('茱', "zhū"),
+    ('茲', "zī"),
+    ('茳', "jiāng"),
+    ('茴', "huí"),
+    ('茵', "yīn"),
+    ('茶', "chá"),
+    ('茷', "fá"),
+    ('茸', "rōng"),
+    ('茹', "rú"),
+    ('茺', "chōng"),
+    ('茻', "mǎng"),
+    ('茼', "tóng"),
+    ('茽', "zhòng"),
+    ('茾', "qiān"),
+    ('茿', "zhú"),
+    ('荀', "xún"),
+    ('荁', "huán"),
+    ('荂', "fū"),
+    ('荃', "quán"),
+    ('荄', "gāi"),
+    ('荅', "dā"),
+    ('荆', "jīng"),
+    ('荇', "xìng"),
+    ('荈', "chuǎn"),
+    ('草', "cǎo"),
+    ('荊', "jīng"),
+    ('荋', "ér"),
+    ('荌', "àn"),
+    ('荍', "qiáo"),
+    ('荎', "chí"),
+    ('荏', "rěn"),
+    ('荐', "jiàn"),
+    ('荑', "tí"),
+    ('荒', "huāng"),
+    ('荓', "píng"),
+    ('荔', "lì"),
+    ('荕', "jīn"),
+    ('荖', "lǎo"),
+    ('荗', "shù"),
+    ('荘', "zhuāng"),
+    ('荙', "dá"),
+    ('荚', "jiá"),
+    ('荛', "ráo"),
+    ('荜', "bì"),
+    ('荝', "cè"),
+    ('荞', "qiáo"),
+    ('荟', "huì"),
+    ('荠', "jì"),
+    ('荡', "dàng"),
+    ('荢', "zì"),
+    ('荣', "róng"),
+    ('荤', "hūn"),
+    ('荥', "xíng"),
+    ('荦', "luò"),
+    ('荧', "yíng"),
+    ('荨', "xún"),
+    ('荩', "jìn"),
+    ('荪', "sūn"),
+    ('荫', "yīn"),
+    ('荬', "mǎi"),
+    ('荭', "hóng"),
+    ('荮', "zhòu"),
+    ('药', "yào"),
+    ('荰', "dù"),
+    ('荱', "wěi"),
+    ('荲', "lí"),
+    ('荳', "dòu"),
+    ('荴', "fū"),
+    ('荵', "rěn"),
+    ('荶', "yín"),
+    ('荷', "hé,hè"),
+    ('荸', "bí"),
+    ('荹', "bù"),
+    ('荺', "yǔn"),
+    ('荻', "dí"),
+    ('荼', "tú"),
+    ('荽', "suī"),
+    ('荾', "suī"),
+    ('荿', "chéng"),
+    ('莀', "chén"),
+    ('莁', "wú"),
+    ('莂', "bié"),
+    ('莃', "xī"),
+    ('莄', "gěng"),
+    ('莅', "lì"),
+    ('莆', "pú"),
+    ('莇', "zhù"),
+    ('莈', "mò"),
+    ('莉', "lì"),
+    ('莊', "zhuāng"),
+    ('莋', "zuó"),
+    ('莌', "tuō"),
+    ('莍', "qiú"),
+    ('莎', "shā"),
+    ('莏', "suō"),
+    ('莐', "chén"),
+    ('莑', "péng"),
+    ('莒', "jǔ"),
+    ('莓', "méi"),
+    ('莔', "méng"),
+    ('莕', "xìng"),
+    ('莖', "jīng"),
+    ('莗', "chē"),
+    ('莘', "shēn"),
+    ('莙', "jūn"),
+    ('莚', "yán"),
+    ('莛', "tíng"),
+    ('莜', "yóu"),
+    ('莝', "cuò"),
+    ('莞', "guǎn,wǎn,guān"),
+    ('莟', "hàn"),
+    ('莠', "yǒu"),
+    ('莡', "cuò"),
+    ('莢', "jiá"),
+    ('莣', "wáng"),
+    ('莤', "sù"),
+    ('莥', "niǔ"),
+    ('莦', "shāo"),
+    ('莧', "xiàn"),
+    ('莨', "làng"),
+    ('莩', "fú"),
+    ('莪', "é"),
+    ('莫', "mò"),
+    ('莬', "wèn"),
+    ('莭', "jié"),
+    ('莮', "nán"),
+    ('莯', "mù"),
+    ('莰', "kǎn"),
+    ('莱', "lái"),
+    ('莲', "lián"),
+    ('莳', "shí"),
+    ('莴', "wō"),
+    ('莵', "tù"),
+    ('莶', "xiān"),
+    ('获', "huò"),
+    ('莸', "yóu"),
+    ('莹', "yíng"),
+    ('莺', "yīng"),
+    ('莻', "gòng"),
+    ('莼', "chún"),
+    ('莽', "mǎng"),
+    ('莾', "mǎng"),
+    ('莿', "cì"),
+    ('菀', "wǎn"),
+    ('菁', "jīng"),
+    ('菂', "dì"),
+    ('菃', "qú"),
+    ('菄', "dōng"),
+    ('菅', "jiān"),
+    ('菆', "zōu"),
+    ('菇', "gū"),
+    ('菈', "lā"),
+    ('菉', "lù"),
+    ('菊', "jú"),
+    ('菋', "wèi"),
+    ('菌', "jūn,jùn"),
+    ('菍', "niè"),
+    ('菎', "kūn"),
+    ('菏', "hé"),
+    ('菐', "pú"),
+    ('菑', "zāi"),
+    ('菒', "gǎo"),
+    ('菓', "guǒ"),
+    ('菔', "fú"),
+    ('菕', "lún"),
+    ('菖', "chāng"),
+    ('菗', "chóu"),
+    ('菘', "sōng"),
+    ('菙', "chuí"),
+    ('菚', "zhàn"),
+    ('菛', "mén"),
+    ('菜', "cài"),
+    ('菝', "bá"),
+    ('菞', "lí"),
+    ('菟', "tú"),
+    ('菠', "bō"),
+    ('菡', "hàn"),
+    ('菢', "bào"),
+    ('菣', "qìn"),
+    ('菤', "juǎn"),
+    ('菥', "xī"),
+    ('菦', "qín"),
+    ('菧', "dǐ"),
+    ('菨', "jiē"),
+    ('菩', "pú"),
+    ('菪', "dàng"),
+    ('菫', "jǐn"),
+    ('菬', "qiáo"),
+    ('菭', "tái"),
+    ('菮', "gēng"),
+    ('華', "huá"),
+    ('菰', "gū"),
+    ('菱', "líng"),
+    ('菲', "fēi,fěi"),
+    ('菳', "qín"),
+    ('菴', "ān"),
+    ('菵', "wǎng"),
+    ('菶', "běng"),
+    ('菷', "zhǒu"),
+    ('菸', "yān"),
+    ('菹', "jū"),
+    ('菺', "jiān"),
+    ('菻', "lǐn"),
+    ('菼', "tǎn"),
+    ('菽', "shū"),
+    ('菾', "tián"),
+    ('菿', "dào"),
+    ('萀', "hǔ"),
+    ('萁', "qí"),
+    ('萂', "hé"),
+    ('萃', "cuì"),
+    ('萄', "táo"),
+    ('萅', "chūn"),
+    ('萆', "bì"),
+    ('萇', "cháng"),
+    ('萈', "huán"),
+    ('萉', "fèi"),
+    ('萊', "lái"),
+    ('萋', "qī"),
+    ('萌', "méng"),
+    ('萍', "píng"),
+    ('萎', "wēi"),
+    ('萏', "dàn"),
+    ('萐', "shà"),
+    ('萑', "huán"),
+    ('萒', "yǎn"),
+    ('萓', "yí"),
+    ('萔', "tiáo"),
+    ('萕', "qí"),
+    ('萖', "wǎn"),
+    ('萗', "cè"),
+    ('萘', "nài"),
+    ('萙', "zhěn"),
+    ('萚', "tuò"),
+    ('萛', "jiū"),
+    ('萜', "tiē"),
+    ('萝', "luó"),
+    ('萞', "bì"),
+    ('萟', "yì"),
+    ('萠', "pān"),
+    ('萡', "bo"),
+    ('萢', "pāo"),
+    ('萣', "dìng"),
+    ('萤', "yíng"),
+    ('营', "yíng"),
+    ('萦', "yíng"),
+    ('萧', "xiāo"),
+    ('萨', "sà"),
+    ('萩', "qiū"),
+    ('萪', "kē"),
+    ('萫', "xiàng"),
+    ('萬', "wàn"),
+    ('萭', "yǔ"),
+    ('萮', "yú"),
+    ('萯', "fù"),
+    ('萰', "liàn"),
+    ('萱', "xuān"),
+    ('萲', "xuān"),
+    ('萳', "nǎn"),
+    ('萴', "cè"),
+    ('萵', "wō"),
+    ('萶', "chǔn"),
+    ('萷', "xiāo"),
+    ('萸', "yú"),
+    ('萹', "biǎn"),
+    ('萺', "mào"),
+    ('萻', "ān"),
+    ('萼', "è"),
+    ('落', "luò,là,lào"),
+    ('萾', "yíng"),
+    ('萿', "kuò"),
+    ('葀', "kuò"),
+    ('葁', "jiāng"),
+    ('葂', "miǎn"),
+    ('葃', "zuò"),
+    ('葄', "zuò"),
+    ('葅', "zū"),
+    ('葆', "bǎo"),
+    ('葇', "róu"),
+    ('葈', "xǐ"),
+    ('葉', "yè"),
+    ('葊', "ān"),
+    ('葋', "qú"),
+    ('葌', "jiān"),
+    ('葍', "fú"),
+    ('葎', "lǜ"),
+    ('葏', "jīng"),
+    ('葐', "pén"),
+    ('葑', "fēng"),
+    ('葒', "hóng"),
+    ('葓', "hóng"),
+    ('葔', "hóu"),
+    ('葕', "yàn"),
+    ('葖', "tū"),
+    ('著', "zhù,zhe"),
+    ('葘', "zī"),
+    ('葙', "xiāng"),
+    ('葚', "rèn"),
+    ('葛', "gé"),
+    ('葜', "qiā"),
+    ('葝', "qíng"),
+    ('葞', "mǐ"),
+    ('葟', "huáng"),
+    ('葠', "shēn"),
+    ('葡', "pú"),
+    ('葢', "gài"),
+    ('董', "dǒng"),
+    ('葤', "zhòu"),
+    ('葥', "jiàn"),
+    ('葦', "wěi"),
+    ('葧', "bó"),
+    ('葨', "wēi"),
+    ('葩', "pā"),
+    ('葪', "jì"),
+    ('葫', "hú"),
+    ('葬', "zàng"),
+    ('葭', "jiā"),
+    ('葮', "duàn"),
+    ('葯', "yào"),
+    ('葰', "suī"),
+    ('葱', "cōng"),
+    ('葲', "quán"),
+    ('葳', "wēi"),
+    ('葴', "zhēn"),
+    ('葵', "kuí"),
+    ('葶', "tíng"),
+    ('葷', "hūn"),
+    ('葸', "xǐ"),
+    ('葹', "shī"),
+    ('葺', "qì"),
+    ('葻', "lán"),
+    ('葼', "zōng"),
+    ('葽', "yāo"),
+    ('葾', "yuān"),
+    ('葿', "méi"),
+    ('蒀', "yūn"),
+    ('蒁', "shù"),
+    ('蒂', "dì"),
+    ('蒃', "zhuàn"),
+    ('蒄', "guān"),
+    ('蒅', "rǎn"),
+    ('蒆', "xuē"),
+    ('蒇', "chǎn"),
+    ('蒈', "kǎi"),
+    ('蒉', "kuì"),
+    ('蒊', "huā"),
+    ('蒋', "jiǎng"),
+    ('蒌', "lóu"),
+    ('蒍', "wěi"),
+    ('蒎', "pài"),
+    ('蒏', "you"),
+    ('蒐', "sōu"),
+    ('蒑', "yīn"),
+    ('蒒', "shī"),
+    ('蒓', "chún"),
+    ('蒔', "shí"),
+    ('蒕', "yūn"),
+    ('蒖', "zhēn"),
+    ('蒗', "làng"),
+    ('蒘', "rú"),
+    ('蒙', "méng,mēng,měng"),
+    ('蒚', "lì"),
+    ('蒛', "quē"),
+    ('蒜', "suàn"),
+    ('蒝', "yuán"),
+    ('蒞', "lì"),
+    ('蒟', "jǔ"),
+    ('蒠', "xī"),
+    ('蒡', "bàng"),
+    ('蒢', "chú"),
+    ('蒣', "xú"),
+    ('蒤', "tú"),
+    ('蒥', "liú"),
+    ('蒦', "huò"),
+    ('蒧', "diǎn"),
+    ('蒨', "qiàn"),
+    ('蒩', "zū"),
+    ('蒪', "pò"),
+    ('蒫', "cuó"),
+    ('蒬', "yuān"),
+    ('蒭', "chú"),
+    ('蒮', "yù"),
+    ('蒯', "kuǎi"),
+    ('蒰', "pán"),
+    ('蒱', "pú"),
+    ('蒲', "pú"),
+    ('蒳', "nà"),
+    ('蒴', "shuò"),
+    ('蒵', "xí"),
+    ('蒶', "fén"),
+    ('蒷', "yún"),
+    ('蒸', "zhēng"),
+    ('蒹', "jiān"),
+    ('蒺', "jí"),
+    ('蒻', "ruò"),
+    ('蒼', "cāng"),
+    ('蒽', "ēn"),
+    ('蒾', "mí"),
+    ('蒿', "hāo"),
+    ('蓀', "sūn"),
+    ('蓁', "zhēn"),
+    ('蓂', "míng"),
+    ('蓃', "sōu"),
+    ('蓄', "xù"),
+    ('蓅', "liú"),
+    ('蓆', "xí"),
+    ('蓇', "gǔ"),
+    ('蓈', "láng"),
+    ('蓉', "róng"),
+    ('蓊', "wěng"),
+    ('蓋', "gài"),
+    ('蓌', "cuò"),
+    ('蓍', "shī"),
+    ('蓎', "táng"),
+    ('蓏', "luǒ"),
+    ('蓐', "rù"),
+    ('蓑', "suō"),
+    ('蓒', "xuān"),
+    ('蓓', "bèi"),
+    ('蓔', "yǎo"),
+    ('蓕', "guì"),
+    ('蓖', "bì"),
+    ('蓗', "zǒng"),
+    ('蓘', "gǔn"),
+    ('蓙', "zuò"),
+    ('蓚', "tiáo"),
+    ('蓛', "cè"),
+    ('蓜', "pèi"),
+    ('蓝', "lán"),
+    ('蓞', "dàn"),
+    ('蓟', "jì"),
+    ('蓠', "lí"),
+    ('蓡', "shēn"),
+    ('蓢', "lǎng"),
+    ('蓣', "yù"),
+    ('蓤', "líng"),
+    ('蓥', "yíng"),
+    ('蓦', "mò"),
+    ('蓧', "diào"),
+    ('蓨', "tiáo"),
+    ('蓩', "mǎo"),
+    ('蓪', "tōng"),
+    ('蓫', "chù"),
+    ('蓬', "péng"),
+    ('蓭', "ān"),
+    ('蓮', "lián"),
+    ('蓯', "cōng"),
+    ('蓰', "xǐ"),
+    ('蓱', "píng"),
+    ('蓲', "qiū"),
+    ('蓳', "jǐn"),
+    ('蓴', "chún"),
+    ('蓵', "jié"),
+    ('蓶', "wéi"),
+    ('蓷', "tuī"),
+    ('蓸', "cáo"),
+    ('蓹', "yù"),
+    ('蓺', "yì"),
+    ('蓻', "zí"),
+    ('蓼', "liǎo"),
+    ('蓽', "bì"),
+    ('蓾', "lǔ"),
+    ('蓿', "xu"),
+    ('蔀', "bù"),
+    ('蔁', "zhāng"),
+    ('蔂', "léi"),
+    ('蔃', "qiáng"),
+    ('蔄', "màn"),
+    ('蔅', "yán"),
+    ('蔆', "líng"),
+    ('蔇', "jì"),
+    ('蔈', "biāo"),
+    ('蔉', "gǔn"),
+    ('蔊', "hǎn"),
+    ('蔋', "dí"),
+    ('蔌', "sù"),
+    ('蔍', "lù"),
+    ('蔎', "shè"),
+    ('蔏', "shāng"),
+    ('蔐', "dí"),
+    ('蔑', "miè"),
+    ('蔒', "xūn"),
+    ('蔓', "màn,wàn,mán"),
+    ('蔔', "bó"),
+    ('蔕', "dì"),
+    ('蔖', "cuó"),
+    ('蔗', "zhè"),
+    ('蔘', "shēn"),
+    ('蔙', "xuàn"),
+    ('蔚', "wèi"),
+    ('蔛', "hú"),
+    ('蔜', "áo"),
+    ('蔝', "mǐ"),
+    ('蔞', "lóu"),
+    ('蔟', "cù"),
+    ('蔠', "zhōng"),
+    ('蔡', "cài"),
+    ('蔢', "pó"),
+    ('蔣', "jiǎng"),
+    ('蔤', "mì"),
+    ('蔥', "cōng"),
+    ('蔦', "niǎo"),
+    ('蔧', "huì"),
+    ('蔨', "juàn"),
+    ('蔩', "yín"),
+    ('蔪', "jiàn"),
+    ('蔫', "niān"),
+    ('蔬', "shū"),
+    ('蔭', "yīn"),
+    ('蔮', "guó"),
+    ('蔯', "chén"),
+    ('蔰', "hù"),
+    ('蔱', "shā"),
+    ('蔲', "kòu"),
+    ('蔳', "qiàn"),
+    ('蔴', "má"),
+    ('蔵', "zāng"),
+    ('蔶', "zé"),
+    ('蔷', "qiáng"),
+    ('蔸', "dōu"),
+    ('蔹', "liǎn"),
+    ('蔺', "lìn"),
+    ('蔻', "kòu"),
+    ('蔼', "ǎi"),
+    ('蔽', "bì"),
+    ('蔾', "lí"),
+    ('蔿', "wěi"),
+    ('蕀', "jí"),
+    ('蕁', "qián"),
+    ('蕂', "shèng"),
+    ('蕃', "fān,fán,bō"),
+    ('蕄', "méng"),
+    ('蕅', "ǒu"),
+    ('蕆', "chǎn"),
+    ('蕇', "diǎn"),
+    ('蕈', "xùn"),
+    ('蕉', "jiāo"),
+    ('蕊', "ruǐ"),
+    ('蕋', "ruǐ"),
+    ('蕌', "lěi"),
+    ('蕍', "yú"),
+    ('蕎', "qiáo"),
+    ('蕏', "chú"),
+    ('蕐', "huá"),
+    ('蕑', "jiān"),
+    ('蕒', "mǎi"),
+    ('蕓', "yún"),
+    ('蕔', "bāo"),
+    ('蕕', "yóu"),
+    ('蕖', "qú"),
+    ('蕗', "lù"),
+    ('蕘', "ráo"),
+    ('蕙', "huì"),
+    ('蕚', "è"),
+    ('蕛', "tí"),
+    ('蕜', "fěi"),
+    ('蕝', "jué"),
+    ('蕞', "zuì"),
+    ('蕟', "fà"),
+    ('蕠', "rú"),
+    ('蕡', "fén"),
+    ('蕢', "kuì"),
+    ('蕣', "shùn"),
+    ('蕤', "ruí"),
+    ('蕥', "yǎ"),
+    ('蕦', "xū"),
+    ('蕧', "fù"),
+    ('蕨', "jué"),
+    ('蕩', "dàng"),
+    ('蕪', "wú"),
+    ('蕫', "dǒng"),
+    ('蕬', "sī"),
+    ('蕭', "xiāo"),
+    ('蕮', "xì"),
+    ('蕯', "lóng"),
+    ('蕰', "wēn"),
+    ('蕱', "shāo"),
+    ('蕲', "qí"),
+    ('蕳', "jiān"),
+    ('蕴', "yùn"),
+    ('蕵', "sūn"),
+    ('蕶', "líng"),
+    ('蕷', "yù"),
+    ('蕸', "xiá"),
+    ('蕹', "wèng"),
+    ('蕺', "jí"),
+    ('蕻', "hóng"),
+    ('蕼', "sì"),
+    ('蕽', "nóng"),
+    ('蕾', "lěi"),
+    ('蕿', "xuān"),
+    ('薀', "yùn"),
+    ('薁', "yù"),
+    ('薂', "xí"),
+    ('薃', "hào"),
+    ('薄', "báo,bó,bò"),
+    ('薅', "hāo"),
+    ('薆', "ài"),
+    ('薇', "wēi"),
+    ('薈', "huì"),
+    ('薉', "huì"),
+    ('薊', "jì"),
+    ('薋', "cí"),
+    ('薌', "xiāng"),
+    ('薍', "wàn"),
+    ('薎', "miè"),
+    ('薏', "yì"),
+    ('薐', "léng"),
+    ('薑', "jiāng"),
+    ('薒', "càn"),
+    ('薓', "shēn"),
+    ('薔', "qiáng"),
+    ('薕', "lián"),
+    ('薖', "kē"),
+    ('薗', "yuán"),
+    ('薘', "dá"),
+    ('薙', "tì"),
+    ('薚', "tāng"),
+    ('薛', "xuē"),
+    ('薜', "bì"),
+    ('薝', "zhān"),
+    ('薞', "sūn"),
+    ('薟', "xiān"),
+    ('薠', "fán"),
+    ('薡', "dǐng"),
+    ('薢', "xiè"),
+    ('薣', "gǔ"),
+    ('薤', "xiè"),
+    ('薥', "shǔ"),
+    ('薦', "jiàn"),
+    ('薧', "hāo"),
+    ('薨', "hōng"),
+    ('薩', "sà"),
+    ('薪', "xīn"),
+    ('薫', "xūn"),
+    ('薬', "yào"),
+    ('薭', "bài"),
+    ('薮', "sǒu"),
+    ('薯', "shǔ"),
+    ('薰', "xūn"),
+    ('薱', "duì"),
+    ('薲', "pín"),
+    ('薳', "wěi"),
+    ('薴', "níng"),
+    ('薵', "chóu"),
+    ('薶', "mái"),
+    ('薷', "rú"),
+    ('薸', "piáo"),
+    ('薹', "tái"),
+    ('薺', "jì"),
+    ('薻', "zǎo"),
+    ('薼', "chén"),
+    ('薽', "zhēn"),
+    ('薾', "ěr"),
+    ('薿', "nǐ"),
+    ('藀', "yíng"),
+    ('藁', "gǎo"),
+    ('藂', "cóng"),
+    ('藃', "xiāo"),
+    ('藄', "qí"),
+    ('藅', "fá"),
+    ('藆', "jiǎn"),
+    ('藇', "xù"),
+    ('藈', "kuí"),
+    ('藉', "jí,jiè"),
+    ('藊', "biǎn"),
+    ('藋', "diào"),
+    ('藌', "mì"),
+    ('藍', "lán"),
+    ('藎', "jìn"),
+    ('藏', "cáng,zàng"),
+    ('藐', "miǎo"),
+    ('藑', "qióng"),
+    ('藒', "qiè"),
+    ('藓', "xiǎn"),
+    ('藔', "liáo"),
+    ('藕', "ǒu"),
+    ('藖', "xián"),
+    ('藗', "sù"),
+    ('藘', "lǘ"),
+    ('藙', "yì"),
+    ('藚', "xù"),
+    ('藛', "xiě"),
+    ('藜', "lí"),
+    ('藝', "yì"),
+    ('藞', "lǎ"),
+    ('藟', "lěi"),
+    ('藠', "jiào"),
+    ('藡', "dí"),
+    ('藢', "zhǐ"),
+    ('藣', "bēi"),
+    ('藤', "téng"),
+    ('藥', "yào"),
+    ('藦', "mò"),
+    ('藧', "huàn"),
+    ('藨', "biāo"),
+    ('藩', "fān"),
+    ('藪', "sǒu"),
+    ('藫', "tán"),
+    ('藬', "tuī"),
+    ('藭', "qióng"),
+    ('藮', "qiáo"),
+    ('藯', "wèi"),
+    ('藰', "liú"),
+    ('藱', "huì"),
+    ('藲', "ōu"),
+    ('藳', "gǎo"),
+    ('藴', "yùn"),
+    ('藵', "bǎo"),
+    ('藶', "lì"),
+    ('藷', "shǔ"),
+    ('藸', "chú"),
+    ('藹', "ǎi"),
+    ('藺', "lìn"),
+    ('藻', "zǎo"),
+    ('藼', "xuān"),
+    ('藽', "qìn"),
+    ('藾', "lài"),
+    ('藿', "huò"),
+    ('蘀', "tuò"),
+    ('蘁', "wù"),
+    ('蘂', "ruǐ"),
+    ('蘃', "ruǐ"),
+    ('蘄', "qí"),
+    ('蘅', "héng"),
+    ('蘆', "lú"),
+    ('蘇', "sū"),
+    ('蘈', "tuí"),
+    ('蘉', "méng"),
+    ('蘊', "yùn"),
+    ('蘋', "píng"),
+    ('蘌', "yǔ"),
+    ('蘍', "xūn"),
+    ('蘎', "jì"),
+    ('蘏', "jiōng"),
+    ('蘐', "xuān"),
+    ('蘑', "mó"),
+    ('蘒', "qiū"),
+    ('蘓', "sū"),
+    ('蘔', "jiōng"),
+    ('蘕', "péng"),
+    ('蘖', "niè"),
+    ('蘗', "bò"),
+    ('蘘', "ráng"),
+    ('蘙', "yì"),
+    ('蘚', "xiǎn"),
+    ('蘛', "yú"),
+    ('蘜', "jú"),
+    ('蘝', "liǎn"),
+    ('蘞', "liǎn"),
+    ('蘟', "yǐn"),
+    ('蘠', "qiáng"),
+    ('蘡', "yīng"),
+    ('蘢', "lóng"),
+    ('蘣', "tǒu"),
+    ('蘤', "huā"),
+    ('蘥', "yuè"),
+    ('蘦', "líng"),
+    ('蘧', "qú"),
+    ('蘨', "yáo"),
+    ('蘩', "fán"),
+    ('蘪', "méi"),
+    ('蘫', "hàn"),
+    ('蘬', "kuī"),
+    ('蘭', "lán"),
+    ('蘮', "jì"),
+    ('蘯', "dàng"),
+    ('蘰', "màn"),
+    ('蘱', "lèi"),
+    ('蘲', "léi"),
+    ('蘳', "huī"),
+    ('蘴', "fēng"),
+    ('蘵', "zhī"),
+    ('蘶', "wèi"),
+    ('蘷', "kuí"),
+    ('蘸', "zhàn"),
+    ('蘹', "huái"),
+    ('蘺', "lí"),
+    ('蘻', "jì"),
+    ('蘼', "mí"),
+    ('蘽', "lěi"),
+    ('蘾', "huài"),
+    ('蘿', "luó"),
+    ('虀', "jī"),
+    ('虁', "kuí"),
+    ('虂', "lù"),
+    ('虃', "jiān"),
+    ('虄', "sà"),
+    ('虅', "téng"),
+    ('虆', "léi"),
+    ('虇', "quǎn"),
+    ('虈', "xiāo"),
+    ('虉', "yì"),
+    ('虊', "luán"),
+    ('虋', "mén"),
+    ('虌', "biē"),
+    ('虍', "hū"),
+    ('虎', "hǔ"),
+    ('虏', "lǔ"),
+    ('虐', "nüè"),
+    ('虑', "lǜ"),
+    ('虒', "sī"),
+    ('虓', "xiāo"),
+    ('虔', "qián"),
+    ('處', "chù"),
+    ('虖', "hū"),
+    ('虗', "xū"),
+    ('虘', "cuó"),
+    ('虙', "fú"),
+    ('虚', "xū"),
+    ('虛', "xū"),
+    ('虜', "lǔ"),
+    ('虝', "hǔ"),
+    ('虞', "yú"),
+    ('號', "hào"),
+    ('虠', "jiāo"),
+    ('虡', "jù"),
+    ('虢', "guó"),
+    ('虣', "bào"),
+    ('虤', "yán"),
+    ('虥', "zhàn"),
+    ('虦', "zhàn"),
+    ('虧', "kuī"),
+    ('虨', "bīn"),
+    ('虩', "xì"),
+    ('虪', "shù"),
+    ('虫', "chóng"),
+    ('虬', "qiú"),
+    ('虭', "diāo"),
+    ('虮', "jǐ"),
+    ('虯', "qiú"),
+    ('虰', "dīng"),
+    ('虱', "shī"),
+    ('虲', "xiā"),
+    ('虳', "jué"),
+    ('虴', "zhé"),
+    ('虵', "shé"),
+    ('虶', "yū"),
+    ('虷', "hán"),
+    ('虸', "zǐ"),
+    ('虹', "hóng,jiàng"),
+    ('虺', "huī"),
+    ('虻', "méng"),
+    ('虼', "gè"),
+    ('虽', "suī"),
+    ('虾', "xiā,há"),
+    ('虿', "chài"),
+    ('蚀', "shí"),
+    ('蚁', "yǐ"),
+    ('蚂', "mǎ"),
+    ('蚃', "xiǎng"),
+    ('蚄', "fāng"),
+    ('蚅', "è"),
+    ('蚆', "bā"),
+    ('蚇', "chǐ"),
+    ('蚈', "qiān"),
+    ('蚉', "wén"),
+    ('蚊', "wén"),
+    ('蚋', "ruì"),
+    ('蚌', "bàng,bèng"),
+    ('蚍', "pí"),
+    ('蚎', "yuè"),
+    ('蚏', "yuè"),
+    ('蚐', "jūn"),
+    ('蚑', "qí"),
+    ('蚒', "tóng"),
+    ('蚓', "yǐn"),
+    ('蚔', "qí"),
+    ('蚕', "cán"),
+    ('蚖', "yuán"),
+    ('蚗', "jué"),
+    ('蚘', "huí"),
+    ('蚙', "qín"),
+    ('蚚', "qí"),
+    ('蚛', "zhòng"),
+    ('蚜', "yá"),
+    ('蚝', "háo"),
+    ('蚞', "mù"),
+    ('蚟', "wáng"),
+    ('蚠', "fén"),
+    ('蚡', "fén"),
+    ('蚢', "háng"),
+    ('蚣', "gōng"),
+    ('蚤', "zǎo"),
+    ('蚥', "fù"),
+    ('蚦', "rán"),
+    ('蚧', "jiè"),
+    ('蚨', "fú"),
+    ('蚩', "chī"),
+    ('蚪', "dǒu"),
+    ('蚫', "bào"),
+    ('蚬', "xiǎn"),
+    ('蚭', "ní"),
+    ('蚮', "dài"),
+    ('蚯', "qiū"),
+    ('蚰', "yóu"),
+    ('蚱', "zhà"),
+    ('蚲', "píng"),
+    ('蚳', "chí"),
+    ('蚴', "yòu"),
+    ('蚵', "hé"),
+    ('蚶', "hān"),
+    ('蚷', "jù"),
+    ('蚸', "lì"),
+    ('蚹', "fù"),
+    ('蚺', "rán"),
+    ('蚻', "zhá"),
+    ('蚼', "gǒu"),
+    ('蚽', "pí"),
+    ('蚾', "pí"),
+    ('蚿', "xián"),
+    ('蛀', "zhù"),
+    ('蛁', "diāo"),
+    ('蛂', "bié"),
+    ('蛃', "bǐng"),
+    ('蛄', "gū"),
+    ('蛅', "zhān"),
+    ('蛆', "qū"),
+    ('蛇', "shé,yí"),
+    ('蛈', "tiě"),
+    ('蛉', "líng"),
+    ('蛊', "gǔ"),
+    ('蛋', "dàn"),
+    ('蛌', "gǔ"),
+    ('蛍', "yíng"),
+    ('蛎', "lì"),
+    ('蛏', "chēng"),
+    ('蛐', "qū"),
+    ('蛑', "móu"),
+    ('蛒', "gé"),
+    ('蛓', "cì"),
+    ('蛔', "huí"),
+    ('蛕', "huí"),
+    ('蛖', "máng"),
+    ('蛗', "fù"),
+    ('蛘', "yáng"),
+    ('蛙', "wā"),
+    ('蛚', "liè"),
+    ('蛛', "zhū"),
+    ('蛜', "yī"),
+    ('蛝', "xián"),
+    ('蛞', "kuò"),
+    ('蛟', "jiāo"),
+    ('蛠', "lì"),
+    ('蛡', "yì"),
+    ('蛢', "píng"),
+    ('蛣', "qī"),
+    ('蛤', "gé,há"),
+    ('蛥', "shé"),
+    ('蛦', "yí"),
+    ('蛧', "wǎng"),
+    ('蛨', "mò"),
+    ('蛩', "qióng"),
+    ('蛪', "qiè"),
+    ('蛫', "guǐ"),
+    ('蛬', "qióng"),
+    ('蛭', "zhì"),
+    ('蛮', "mán"),
+    ('蛯', "lǎo"),
+    ('蛰', "zhé"),
+    ('蛱', "jiá"),
+    ('蛲', "náo"),
+    ('蛳', "sī"),
+    ('蛴', "qí"),
+    ('蛵', "xīng"),
+    ('蛶', "jiè"),
+    ('蛷', "qiú"),
+    ('蛸', "shāo"),
+    ('蛹', "yǒng"),
+    ('蛺', "jiá"),
+    ('蛻', "tuì"),
+    ('蛼', "chē"),
+    ('蛽', "bèi"),
+    ('蛾', "é"),
+    ('蛿', "hàn"),
+    ('蜀', "shǔ"),
+    ('蜁', "xuán"),
+    ('蜂', "fēng"),
+    ('蜃', "shèn"),
+    ('蜄', "shèn"),
+    ('蜅', "fǔ"),
+    ('蜆', "xiàn"),
+    ('蜇', "zhē"),
+    ('蜈', "wú"),
+    ('蜉', "fú"),
+    ('蜊', "lí"),
+    ('蜋', "láng"),
+    ('蜌', "bì"),
+    ('蜍', "chú"),
+    ('蜎', "yuān"),
+    ('蜏', "yǒu"),
+    ('蜐', "jié"),
+    ('蜑', "dàn"),
+    ('蜒', "yán"),
+    ('蜓', "tíng"),
+    ('蜔', "diàn"),
+    ('蜕', "tuì"),
+    ('蜖', "huí"),
+    ('蜗', "wō"),
+    ('蜘', "zhī"),
+    ('蜙', "sōng"),
+    ('蜚', "fēi"),
+    ('蜛', "jū"),
+    ('蜜', "mì"),
+    ('蜝', "qí"),
+    ('蜞', "qí"),
+    ('蜟', "yù"),
+    ('蜠', "jùn"),
+    ('蜡', "là"),
+    ('蜢', "měng"),
+    ('蜣', "qiāng"),
+    ('蜤', "sī"),
+    ('蜥', "xī"),
+    ('蜦', "lún"),
+    ('蜧', "lì"),
+    ('蜨', "dié"),
+    ('蜩', "tiáo"),
+    ('蜪', "táo"),
+    ('蜫', "kūn"),
+    ('蜬', "hán"),
+    ('蜭', "hàn"),
+    ('蜮', "yù"),
+    ('蜯', "bàng"),
+    ('蜰', "féi"),
+    ('蜱', "pí"),
+    ('蜲', "wēi"),
+    ('蜳', "dūn"),
+    ('蜴', "yì"),
+    ('蜵', "yuān"),
+    ('蜶', "suò"),
+    ('蜷', "quán"),
+    ('蜸', "qiǎn"),
+    ('蜹', "ruì"),
+    ('蜺', "ní"),
+    ('蜻', "qīng"),
+    ('蜼', "wèi"),
+    ('蜽', "liǎng"),
+    ('蜾', "guǒ"),
+    ('蜿', "wān"),
+    ('蝀', "dōng"),
+    ('蝁', "è"),
+    ('蝂', "bǎn"),
+    ('蝃', "dì"),
+    ('蝄', "wǎng"),
+    ('蝅', "cán"),
+    ('蝆', "yǎng"),
+    ('蝇', "yíng"),
+    ('蝈', "guō"),
+    ('蝉', "chán"),
+    ('蝊', "dìng"),
+    ('蝋', "là"),
+    ('蝌', "kē"),
+    ('蝍', "jié"),
+    ('蝎', "xiē"),
+    ('蝏', "tíng"),
+    ('蝐', "mào"),
+    ('蝑', "xū"),
+    ('蝒', "mián"),
+    ('蝓', "yú"),
+    ('蝔', "jiē"),
+    ('蝕', "shí"),
+    ('蝖', "xuān"),
+    ('蝗', "huáng"),
+    ('蝘', "yǎn"),
+    ('蝙', "biān"),
+    ('蝚', "róu"),
+    ('蝛', "wēi"),
+    ('蝜', "fù"),
+    ('蝝', "yuán"),
+    ('蝞', "mèi"),
+    ('蝟', "wèi"),
+    ('蝠', "fú"),
+    ('蝡', "rú"),
+    ('蝢', "xié"),
+    ('蝣', "yóu"),
+    ('蝤', "qiú"),
+    ('蝥', "máo"),
+    ('蝦', "xiā"),
+    ('蝧', "yīng"),
+    ('蝨', "shī"),
+    ('蝩', "chóng"),
+    ('蝪', "tāng"),
+    ('蝫', "zhū"),
+    ('蝬', "zōng"),
+    ('蝭', "tí"),
+    ('蝮', "fù"),
+    ('蝯', "yuán"),
+    ('蝰', "kuí"),
+    ('蝱', "méng"),
+    ('蝲', "là"),
+    ('蝳', "dú"),
+    ('蝴', "hú"),
+    ('蝵', "qiū"),
+    ('蝶', "dié"),
+    ('蝷', "lì"),
+    ('蝸', "wō"),
+    ('蝹', "yūn"),
+    ('蝺', "qǔ"),
+    ('蝻', "nǎn"),
+    ('蝼', "lóu"),
+    ('蝽', "chūn"),
+    ('蝾', "róng"),
+    ('蝿', "yíng"),
+    ('螀', "jiāng"),
+    ('螁', "ban"),
+    ('螂', "láng"),
+    ('螃', "páng"),
+    ('螄', "sī"),
+    ('螅', "xī"),
+    ('螆', "cì"),
+    ('螇', "xī"),
+    ('螈', "yuán"),
+    ('螉', "wēng"),
+    ('螊', "lián"),
+    ('螋', "sōu"),
+    ('螌', "bān"),
+    ('融', "róng"),
+    ('螎', "róng"),
+    ('螏', "jí"),
+    ('螐', "wū"),
+    ('螑', "xiù"),
+    ('螒', "hàn"),
+    ('螓', "qín"),
+    ('螔', "yí"),
+    ('螕', "bī"),
+    ('螖', "huá"),
+    ('螗', "táng"),
+    ('螘', "yǐ"),
+    ('螙', "dù"),
+    ('螚', "nài"),
+    ('螛', "hé"),
+    ('螜', "hú"),
+    ('螝', "guī"),
+    ('螞', "mǎ"),
+    ('螟', "míng"),
+    ('螠', "yì"),
+    ('螡', "wén"),
+    ('螢', "yíng"),
+    ('螣', "tè"),
+    ('螤', "zhōng"),
+    ('螥', "cāng"),
+    ('螦', "sāo"),
+    ('螧', "qí"),
+    ('螨', "mǎn"),
+    ('螩', "tiao"),
+    ('螪', "shāng"),
+    ('螫', "shì,zhē"),
+    ('螬', "cáo"),
+    ('螭', "chī"),
+    ('螮', "dì"),
+    ('螯', "áo"),
+    ('螰', "lù"),
+    ('螱', "wèi"),
+    ('螲', "zhì"),
+    ('螳', "táng"),
+    ('螴', "chén"),
+    ('螵', "piāo"),
+    ('螶', "qú"),
+    ('螷', "pí"),
+    ('螸', "yú"),
+    ('螹', "jiàn"),
+    ('螺', "luó"),
+    ('螻', "lóu"),
+    ('螼', "qǐn"),
+    ('螽', "zhōng"),
+    ('螾', "yǐn"),
+    ('螿', "jiāng"),
+    ('蟀', "shuài"),
+    ('蟁', "wén"),
+    ('蟂', "xiāo"),
+    ('蟃', "wàn"),
+    ('蟄', "zhé"),
+    ('蟅', "zhè"),
+    ('蟆', "má"),
+    ('蟇', "má"),
+    ('蟈', "guō"),
+    ('蟉', "liú"),
+    ('蟊', "máo"),
+    ('蟋', "xī"),
+    ('蟌', "cōng"),
+    ('蟍', "lí"),
+    ('蟎', "mǎn"),
+    ('蟏', "xiāo"),
+    ('蟐', "chang"),
+    ('蟑', "zhāng"),
+    ('蟒', "mǎng"),
+    ('蟓', "xiàng"),
+    ('蟔', "mò"),
+    ('蟕', "zuī"),
+    ('蟖', "sī"),
+    ('蟗', "qiū"),
+    ('蟘', "tè"),
+    ('蟙', "zhí"),
+    ('蟚', "péng"),
+    ('蟛', "péng"),
+    ('蟜', "jiǎo"),
+    ('蟝', "qú"),
+    ('蟞', "biē"),
+    ('蟟', "liáo"),
+    ('蟠', "pán"),
+    ('蟡', "guǐ"),
+    ('蟢', "xǐ"),
+    ('蟣', "jǐ"),
+    ('蟤', "zhuān"),
+    ('蟥', "huáng"),
+    ('蟦', "féi"),
+    ('蟧', "láo"),
+    ('蟨', "jué"),
+    ('蟩', "jué"),
+    ('蟪', "huì"),
+    ('蟫', "yín"),
+    ('蟬', "chán"),
+    ('蟭', "jiāo"),
+    ('蟮', "shàn"),
+    ('蟯', "náo"),
+    ('蟰', "xiāo"),
+    ('蟱', "wú"),
+    ('蟲', "chóng"),
+    ('蟳', "xún"),
+    ('蟴', "sī"),
+    ('蟵', "chú"),
+    ('蟶', "chēng"),
+    ('蟷', "dāng"),
+    ('蟸', "lǐ"),
+    ('蟹', "xiè"),
+    ('蟺', "shàn"),
+    ('蟻', "yǐ"),
+    ('蟼', "jǐng"),
+    ('蟽', "dá"),
+    ('蟾', "chán"),
+    ('蟿', "qì"),
+    ('蠀', "cī"),
+    ('蠁', "xiǎng"),
+    ('蠂', "shè"),
+    ('蠃', "luǒ"),
+    ('蠄', "qín"),
+    ('蠅', "yíng"),
+    ('蠆', "chài"),
+    ('蠇', "lì"),
+    ('蠈', "zéi"),
+    ('蠉', "xuān"),
+    ('蠊', "lián"),
+    ('蠋', "zhú"),
+    ('蠌', "zé"),
+    ('蠍', "xiē"),
+    ('蠎', "mǎng"),
+    ('蠏', "xiè"),
+    ('蠐', "qí"),
+    ('蠑', "róng"),
+    ('蠒', "jiǎn"),
+    ('蠓', "měng"),
+    ('蠔', "háo"),
+    ('蠕', "rú"),
+    ('蠖', "huò"),
+    ('蠗', "zhuó"),
+    ('蠘', "jié"),
+    ('蠙', "pín"),
+    ('蠚', "hē"),
+    ('蠛', "miè"),
+    ('蠜', "fán"),
+    ('蠝', "lěi"),
+    ('蠞', "jié"),
+    ('蠟', "là"),
+    ('蠠', "mǐn"),
+    ('蠡', "lí"),
+    ('蠢', "chǔn"),
+    ('蠣', "lì"),
+    ('蠤', "qiū"),
+    ('蠥', "niè"),
+    ('蠦', "lú"),
+    ('蠧', "dù"),
+    ('蠨', "xiāo"),
+    ('蠩', "zhū"),
+    ('蠪', "lóng"),
+    ('蠫', "lí"),
+    ('蠬', "lóng"),
+    ('蠭', "fēng"),
+    ('蠮', "yē"),
+    ('蠯', "pí"),
+    ('蠰', "náng"),
+    ('蠱', "gǔ"),
+    ('蠲', "juān"),
+    ('蠳', "yīng"),
+    ('蠴', "shǔ"),
+    ('蠵', "xī"),
+    ('蠶', "cán"),
+    ('蠷', "qú"),
+    ('蠸', "quán"),
+    ('蠹', "dù"),
+    ('蠺', "cán"),
+    ('蠻', "mán"),
+    ('蠼', "qú"),
+    ('蠽', "jié"),
+    ('蠾', "zhú"),
+    ('蠿', "zhuō"),
+    ('血', "xuè,xiě"),
+    ('衁', "huāng"),
+    ('衂', "nǜ"),
+    ('衃', "pēi"),
+    ('衄', "nǜ"),
+    ('衅', "xìn"),
+    ('衆', "zhòng"),
+    ('衇', "mài"),
+    ('衈', "èr"),
+    ('衉', "kā"),
+    ('衊', "miè"),
+    ('衋', "xì"),
+    ('行', "xíng,háng,hàng,héng"),
+    ('衍', "yǎn"),
+    ('衎', "kàn"),
+    ('衏', "yuàn"),
+    ('衐', "qú"),
+    ('衑', "líng"),
+    ('衒', "xuàn"),
+    ('術', "shù"),
+    ('衔', "xián"),
+    ('衕', "tòng"),
+    ('衖', "xiàng"),
+    ('街', "jiē"),
+    ('衘', "xián"),
+    ('衙', "yá"),
+    ('衚', "hú"),
+    ('衛', "wèi"),
+    ('衜', "dào"),
+    ('衝', "chōng"),
+    ('衞', "wèi"),
+    ('衟', "dào"),
+    ('衠', "zhūn"),
+    ('衡', "héng"),
+    ('衢', "qú"),
+    ('衣', "yī"),
+    ('衤', "yī"),
+    ('补', "bǔ"),
+    ('衦', "gǎn"),
+    ('衧', "yú"),
+    ('表', "biǎo"),
+    ('衩', "chǎ"),
+    ('衪', "yí"),
+    ('衫', "shān"),
+    ('衬', "chèn"),
+    ('衭', "fū"),
+    ('衮', "gǔn"),
+    ('衯', "fēn"),
+    ('衰', "shuāi"),
+    ('衱', "jié"),
+    ('衲', "nà"),
+    ('衳', "zhōng"),
+    ('衴', "dǎn"),
+    ('衵', "yì"),
+    ('衶', "zhòng"),
+    ('衷', "zhōng"),
+    ('衸', "jiè"),
+    ('衹', "zhǐ"),
+    ('衺', "xié"),
+    ('衻', "rán"),
+    ('衼', "zhī"),
+    ('衽', "rèn"),
+    ('衾', "qīn"),
+    ('衿', "jīn"),
+    ('袀', "jūn"),
+    ('袁', "yuán"),
+    ('袂', "mèi"),
+    ('袃', "chài"),
+    ('袄', "ǎo"),
+    ('袅', "niǎo"),
+    ('袆', "huī"),
+    ('袇', "rán"),
+    ('袈', "jiā"),
+    ('袉', "tuó"),
+    ('袊', "lǐng"),
+    ('袋', "dài"),
+    ('袌', "bào"),
+    ('袍', "páo"),
+    ('袎', "yào"),
+    ('袏', "zuò"),
+    ('袐', "bì"),
+    ('袑', "shào"),
+    ('袒', "tǎn"),
+    ('袓', "jù"),
+    ('袔', "hè"),
+    ('袕', "xué"),
+    ('袖', "xiù"),
+    ('袗', "zhěn"),
+    ('袘', "yí"),
+    ('袙', "pà"),
+    ('袚', "bō"),
+    ('袛', "dī"),
+    ('袜', "wà"),
+    ('袝', "fù"),
+    ('袞', "gǔn"),
+    ('袟', "zhì"),
+    ('袠', "zhì"),
+    ('袡', "rán"),
+    ('袢', "pàn"),
+    ('袣', "yì"),
+    ('袤', "mào"),
+    ('袥', "tuō"),
+    ('袦', "nà"),
+    ('袧', "gōu"),
+    ('袨', "xuàn"),
+    ('袩', "zhé"),
+    ('袪', "qū"),
+    ('被', "bèi"),
+    ('袬', "yù"),
+    ('袭', "xí"),
+    ('袮', "mí"),
+    ('袯', "bó"),
+    ('袰', "bō"),
+    ('袱', "fú"),
+    ('袲', "chǐ"),
+    ('袳', "chǐ"),
+    ('袴', "kù"),
+    ('袵', "rèn"),
+    ('袶', "jiàng"),
+    ('袷', "qiā"),
+    ('袸', "jiàn"),
+    ('袹', "bó"),
+    ('袺', "jié"),
+    ('袻', "ér"),
+    ('袼', "gē"),
+    ('袽', "rú"),
+    ('袾', "zhū"),
+    ('袿', "guī"),
+    ('裀', "yīn"),
+    ('裁', "cái"),
+    ('裂', "liè"),
+    ('裃', "kǎ"),
+    ('裄', "xing"),
+    ('装', "zhuāng"),
+    ('裆', "dāng"),
+    ('裇', "xū"),
+    ('裈', "kūn"),
+    ('裉', "kèn"),
+    ('裊', "niǎo"),
+    ('裋', "shù"),
+    ('裌', "jiá"),
+    ('裍', "kǔn"),
+    ('裎', "chéng"),
+    ('裏', "lǐ"),
+    ('裐', "juān"),
+    ('裑', "shēn"),
+    ('裒', "póu"),
+    ('裓', "gé"),
+    ('裔', "yì"),
+    ('裕', "yù"),
+    ('裖', "zhěn"),
+    ('裗', "liú"),
+    ('裘', "qiú"),
+    ('裙', "qún"),
+    ('裚', "jì"),
+    ('裛', "yì"),
+    ('補', "bǔ"),
+    ('裝', "zhuāng"),
+    ('裞', "shuì"),
+    ('裟', "shā"),
+    ('裠', "qún"),
+    ('裡', "lǐ"),
+    ('裢', "lián"),
+    ('裣', "liǎn"),
+    ('裤', "kù"),
+    ('裥', "jiǎn"),
+    ('裦', "fóu"),
+    ('裧', "chān"),
+    ('裨', "bì"),
+    ('裩', "kūn"),
+    ('裪', "táo"),
+    ('裫', "yuàn"),
+    ('裬', "líng"),
+    ('裭', "chǐ"),
+    ('裮', "chāng"),
+    ('裯', "chóu"),
+    ('裰', "duō"),
+    ('裱', "biǎo"),
+    ('裲', "liǎng"),
+    ('裳', "shang,cháng"),
+    ('裴', "péi"),
+    ('裵', "péi"),
+    ('裶', "fēi"),
+    ('裷', "yuān"),
+    ('裸', "luǒ"),
+    ('裹', "guǒ"),
+    ('裺', "yǎn"),
+    ('裻', "dú"),
+    ('裼', "tì"),
+    ('製', "zhì"),
+    ('裾', "jū"),
+    ('裿', "yǐ"),
+    ('褀', "qí"),
+    ('褁', "guǒ"),
+    ('褂', "guà"),
+    ('褃', "kèn"),
+    ('褄', "qī"),
+    ('褅', "tì"),
+    ('褆', "tí"),
+    ('複', "fù"),
+    ('褈', "chóng"),
+    ('褉', "xiè"),
+    ('褊', "biǎn"),
+    ('褋', "dié"),
+    ('褌', "kūn"),
+    ('褍', "duān"),
+    ('褎', "xiù"),
+    ('褏', "xiù"),
+    ('褐', "hè"),
+    ('褑', "yuàn"),
+    ('褒', "bāo"),
+    ('褓', "bǎo"),
+    ('褔', "fù"),
+    ('褕', "yú"),
+    ('褖', "tuàn"),
+    ('褗', "yǎn"),
+    ('褘', "huī"),
+    ('褙', "bèi"),
+    ('褚', "chǔ"),
+    ('褛', "lǚ"),
+    ('褜', "páo"),
+    ('褝', "dān"),
+    ('褞', "yǔn"),
+    ('褟', "tā"),
+    ('褠', "gōu"),
+    ('褡', "dā"),
+    ('褢', "huái"),
+    ('褣', "róng"),
+    ('褤', "yuàn"),
+    ('褥', "rù"),
+    ('褦', "nài"),
+    ('褧', "jiǒng"),
+    ('褨', "suǒ"),
+    ('褩', "bān"),
+    ('褪', "tuì,tùn"),
+    ('褫', "chǐ"),
+    ('褬', "sǎng"),
+    ('褭', "niǎo"),
+    ('褮', "yīng"),
+    ('褯', "jiè"),
+    ('褰', "qiān"),
+    ('褱', "huái"),
+    ('褲', "kù"),
+    ('褳', "lián"),
+    ('褴', "lán"),
+    ('褵', "lí"),
+    ('褶', "zhě"),
+    ('褷', "shī"),
+    ('褸', "lǚ"),
+    ('褹', "yì"),
+    ('褺', "diē"),
+    ('褻', "xiè"),
+    ('褼', "xiān"),
+    ('褽', "wèi"),
+    ('褾', "biǎo"),
+    ('褿', "cáo"),
+    ('襀', "jī"),
+    ('襁', "qiǎng"),
+    ('襂', "sēn"),
+    ('襃', "bāo"),
+    ('襄', "xiāng"),
+    ('襅', "bì"),
+    ('襆', "fú"),
+    ('襇', "jiǎn"),
+    ('襈', "zhuàn"),
+    ('襉', "jiǎn"),
+    ('襊', "cuì"),
+    ('襋', "jí"),
+    ('襌', "dān"),
+    ('襍', "zá"),
+    ('襎', "fán"),
+    ('襏', "bó"),
+    ('襐', "xiàng"),
+    ('襑', "xín"),
+    ('襒', "bié"),
+    ('襓', "ráo"),
+    ('襔', "mǎn"),
+    ('襕', "lán"),
+    ('襖', "ǎo"),
+    ('襗', "zé"),
+    ('襘', "guì"),
+    ('襙', "cào"),
+    ('襚', "suì"),
+    ('襛', "nóng"),
+    ('襜', "chān"),
+    ('襝', "liǎn"),
+    ('襞', "bì"),
+    ('襟', "jīn"),
+    ('襠', "dāng"),
+    ('襡', "shǔ"),
+    ('襢', "tǎn"),
+    ('襣', "bì"),
+    ('襤', "lán"),
+    ('襥', "fú"),
+    ('襦', "rú"),
+    ('襧', "zhǐ"),
+    ('襨', "duì"),
+    ('襩', "shǔ"),
+    ('襪', "wà"),
+    ('襫', "shì"),
+    ('襬', "bǎi"),
+    ('襭', "xié"),
+    ('襮', "bó"),
+    ('襯', "chèn"),
+    ('襰', "lài"),
+    ('襱', "lóng"),
+    ('襲', "xí"),
+    ('襳', "xiān"),
+    ('襴', "lán"),
+    ('襵', "zhě"),
+    ('襶', "dài"),
+    ('襷', "jǔ"),
+    ('襸', "zàn"),
+    ('襹', "shī"),
+    ('襺', "jiǎn"),
+    ('襻', "pàn"),
+    ('襼', "yì"),
+    ('襽', "lán"),
+    ('襾', "yà"),
+    ('西', "xī"),
+    ('覀', "xī"),
+    ('要', "yào,yāo"),
+    ('覂', "fěng"),
+    ('覃', "tán"),
+    ('覄', "fù"),
+    ('覆', "fù"),
+    ('覇', "bà"),
+    ('覈', "hé"),
+    ('覉', "jī"),
+    ('覊', "jī"),
+    ('見', "jiàn"),
+    ('覌', "guān"),
+    ('覍', "biàn"),
+    ('覎', "yàn"),
+    ('規', "guī"),
+    ('覐', "jué"),
+    ('覑', "piǎn"),
+    ('覒', "mào"),
+    ('覓', "mì"),
+    ('覔', "mì"),
+    ('覕', "miè"),
+    ('視', "shì"),
+    ('覗', "sì"),
+    ('覘', "chān"),
+    ('覙', "luó"),
+    ('覚', "jué"),
+    ('覛', "mì"),
+    ('覜', "tiào"),
+    ('覝', "lián"),
+    ('覞', "yào"),
+    ('覟', "zhì"),
+    ('覠', "jūn"),
+    ('覡', "xí"),
+    ('覢', "shǎn"),
+    ('覣', "wēi"),
+    ('覤', "xì"),
+    ('覥', "tiǎn"),
+    ('覦', "yú"),
+    ('覧', "lǎn"),
+    ('覨', "è"),
+    ('覩', "dǔ"),
+    ('親', "qīn"),
+    ('覫', "pǎng"),
+    ('覬', "jì"),
+    ('覭', "míng"),
+    ('覮', "yíng"),
+    ('覯', "gòu"),
+    ('覰', "qū"),
+    ('覱', "zhàn"),
+    ('覲', "jìn"),
+    ('観', "guān"),
+    ('覴', "dēng"),
+    ('覵', "jiàn"),
+    ('覶', "luó"),
+    ('覷', "qù"),
+    ('覸', "jiān"),
+    ('覹', "wéi"),
+    ('覺', "jué"),
+    ('覻', "qū"),
+    ('覼', "luó"),
+    ('覽', "lǎn"),
+    ('覾', "shěn"),
+    ('覿', "dí"),
+    ('觀', "guān"),
+    ('见', "jiàn"),
+    ('观', "guān,guàn"),
+    ('觃', "yàn"),
+    ('规', "guī"),
+    ('觅', "mì"),
+    ('视', "shì"),
+    ('觇', "chān"),
+    ('览', "lǎn"),
+    ('觉', "jué,jiào"),
+    ('觊', "jì"),
+    ('觋', "xí"),
+    ('觌', "dí"),
+    ('觍', "tiǎn"),
+    ('觎', "yú"),
+    ('觏', "gòu"),
+    ('觐', "jìn"),
+    ('觑', "qù"),
+    ('角', "jiǎo,jué"),
+    ('觓', "qiú"),
+    ('觔', "jīn"),
+    ('觕', "cū"),
+    ('觖', "jué"),
+    ('觗', "zhì"),
+    ('觘', "chào"),
+    ('觙', "jí"),
+    ('觚', "gū"),
+    ('觛', "dàn"),
+    ('觜', "zī"),
+    ('觝', "dǐ"),
+    ('觞', "shāng"),
+    ('觟', "huà"),
+    ('觠', "quán"),
+    ('觡', "gé"),
+    ('觢', "shì"),
+    ('解', "jiě,jiè,xiè"),
+    ('觤', "guǐ"),
+    ('觥', "gōng"),
+    ('触', "chù"),
+    ('觧', "jiě"),
+    ('觨', "hùn"),
+    ('觩', "qiú"),
+    ('觪', "xīng"),
+    ('觫', "sù"),
+    ('觬', "ní"),
+    ('觭', "jī"),
+    ('觮', "lù"),
+    ('觯', "zhì"),
+    ('觰', "zhā"),
+    ('觱', "bì"),
+    ('觲', "xīng"),
+    ('觳', "hú"),
+    ('觴', "shāng"),
+    ('觵', "gōng"),
+    ('觶', "zhì"),
+    ('觷', "xué"),
+    ('觸', "chù"),
+    ('觹', "xī"),
+    ('觺', "yí"),
+    ('觻', "lì"),
+    ('觼', "jué"),
+    ('觽', "xī"),
+    ('觾', "yàn"),
+    ('觿', "xī"),
+    ('言', "yán"),
+    ('訁', "yán"),
+    ('訂', "dìng"),
+    ('訃', "fù"),
+    ('訄', "qiú"),
+    ('訅', "qiú"),
+    ('訆', "jiào"),
+    ('訇', "hōng"),
+    ('計', "jì"),
+    ('訉', "fàn"),
+    ('訊', "xùn"),
+    ('訋', "diào"),
+    ('訌', "hòng"),
+    ('訍', "chài"),
+    ('討', "tǎo"),
+    ('訏', "xū"),
+    ('訐', "jié"),
+    ('訑', "yí"),
+    ('訒', "rèn"),
+    ('訓', "xùn"),
+    ('訔', "yín"),
+    ('訕', "shàn"),
+    ('訖', "qì"),
+    ('託', "tuō"),
+    ('記', "jì"),
+    ('訙', "xùn"),
+    ('訚', "yín"),
+    ('訛', "é"),
+    ('訜', "fēn"),
+    ('訝', "yà"),
+    ('訞', "yāo"),
+    ('訟', "sòng"),
+    ('訠', "shěn"),
+    ('訡', "yín"),
+    ('訢', "xīn"),
+    ('訣', "jué"),
+    ('訤', "xiáo"),
+    ('訥', "nè"),
+    ('訦', "chén"),
+    ('訧', "yóu"),
+    ('訨', "zhǐ"),
+    ('訩', "xiōng"),
+    ('訪', "fǎng"),
+    ('訫', "xìn"),
+    ('訬', "chāo"),
+    ('設', "shè"),
+    ('訮', "yán"),
+    ('訯', "sǎ"),
+    ('訰', "zhùn"),
+    ('許', "xǔ"),
+    ('訲', "yì"),
+    ('訳', "yì"),
+    ('訴', "sù"),
+    ('訵', "chī"),
+    ('訶', "hē"),
+    ('訷', "shēn"),
+    ('訸', "hé"),
+    ('訹', "xù"),
+    ('診', "zhěn"),
+    ('註', "zhù"),
+    ('証', "zhèng"),
+    ('訽', "gòu"),
+    ('訾', "zī"),
+    ('訿', "zǐ"),
+    ('詀', "zhān"),
+    ('詁', "gǔ"),
+    ('詂', "fù"),
+    ('詃', "jiǎn"),
+    ('詄', "dié"),
+    ('詅', "líng"),
+    ('詆', "dǐ"),
+    ('詇', "yàng"),
+    ('詈', "lì"),
+    ('詉', "náo"),
+    ('詊', "pàn"),
+    ('詋', "zhòu"),
+    ('詌', "gàn"),
+    ('詍', "yì"),
+    ('詎', "jù"),
+    ('詏', "yào"),
+    ('詐', "zhà"),
+    ('詑', "yí"),
+    ('詒', "yí"),
+    ('詓', "qǔ"),
+    ('詔', "zhào"),
+    ('評', "píng"),
+    ('詖', "bì"),
+    ('詗', "xiòng"),
+    ('詘', "qū"),
+    ('詙', "bá"),
+    ('詚', "dá"),
+    ('詛', "zǔ"),
+    ('詜', "tāo"),
+    ('詝', "zhǔ"),
+    ('詞', "cí"),
+    ('詟', "zhé"),
+    ('詠', "yǒng"),
+    ('詡', "xǔ"),
+    ('詢', "xún"),
+    ('詣', "yì"),
+    ('詤', "huǎng"),
+    ('詥', "hé"),
+    ('試', "shì"),
+    ('詧', "chá"),
+    ('詨', "xiào"),
+    ('詩', "shī"),
+    ('詪', "hěn"),
+    ('詫', "chà"),
+    ('詬', "gòu"),
+    ('詭', "guǐ"),
+    ('詮', "quán"),
+    ('詯', "huì"),
+    ('詰', "jié"),
+    ('話', "huà"),
+    ('該', "gāi"),
+    ('詳', "xiáng"),
+    ('詴', "wēi"),
+    ('詵', "shēn"),
+    ('詶', "zhòu"),
+    ('詷', "tóng"),
+    ('詸', "mí"),
+    ('詹', "zhān"),
+    ('詺', "mìng"),
+    ('詻', "è"),
+    ('詼', "huī"),
+    ('詽', "yán"),
+    ('詾', "xiōng"),
+    ('詿', "guà"),
+    ('誀', "èr"),
+    ('誁', "bìng"),
+    ('誂', "tiǎo"),
+    ('誃', "yí"),
+    ('誄', "lěi"),
+    ('誅', "zhū"),
+    ('誆', "kuāng"),
+    ('誇', "kuā"),
+    ('誈', "wū"),
+    ('誉', "yù"),
+    ('誊', "téng"),
+    ('誋', "jì"),
+    ('誌', "zhì"),
+    ('認', "rèn"),
+    ('誎', "cù"),
+    ('誏', "lǎng"),
+    ('誐', "é"),
+    ('誑', "kuáng"),
+    ('誒', "éi"),
+    ('誓', "shì"),
+    ('誔', "tǐng"),
+    ('誕', "dàn"),
+    ('誖', "bèi"),
+    ('誗', "chán"),
+    ('誘', "yòu"),
+    ('誙', "kēng"),
+    ('誚', "qiào"),
+    ('誛', "qīn"),
+    ('誜', "shuà"),
+    ('誝', "ān"),
+    ('語', "yǔ"),
+    ('誟', "xiào"),
+    ('誠', "chéng"),
+    ('誡', "jiè"),
+    ('誢', "xiàn"),
+    ('誣', "wū"),
+    ('誤', "wù"),
+    ('誥', "gào"),
+    ('誦', "sòng"),
+    ('誧', "bū"),
+    ('誨', "huì"),
+    ('誩', "jìng"),
+    ('說', "shuō"),
+    ('誫', "zhèn"),
+    ('説', "shuō"),
+    ('読', "dú"),
+    ('誮', "huā"),
+    ('誯', "chàng"),
+    ('誰', "shuí"),
+    ('誱', "jié"),
+    ('課', "kè"),
+    ('誳', "qū"),
+    ('誴', "cóng"),
+    ('誵', "xiáo"),
+    ('誶', "suì"),
+    ('誷', "wǎng"),
+    ('誸', "xián"),
+    ('誹', "fěi"),
+    ('誺', "chī"),
+    ('誻', "tà"),
+    ('誼', "yì"),
+    ('誽', "nì"),
+    ('誾', "yín"),
+    ('調', "diào"),
+    ('諀', "pǐ"),
+    ('諁', "zhuó"),
+    ('諂', "chǎn"),
+    ('諃', "chēn"),
+    ('諄', "zhūn"),
+    ('諅', "jì"),
+    ('諆', "qī"),
+    ('談', "tán"),
+    ('諈', "zhuì"),
+    ('諉', "wěi"),
+    ('諊', "jū"),
+    ('請', "qǐng"),
+    ('諌', "dǒng"),
+    ('諍', "zhèng"),
+    ('諎', "zé"),
+    ('諏', "zōu"),
+    ('諐', "qiān"),
+    ('諑', "zhuó"),
+    ('諒', "liàng"),
+    ('諓', "jiàn"),
+    ('諔', "chù"),
+    ('諕', "háo"),
+    ('論', "lùn"),
+    ('諗', "shěn"),
+    ('諘', "biǎo"),
+    ('諙', "huà"),
+    ('諚', "pián"),
+    ('諛', "yú"),
+    ('諜', "dié"),
+    ('諝', "xū"),
+    ('諞', "piǎn"),
+    ('諟', "shì"),
+    ('諠', "xuān"),
+    ('諡', "shì"),
+    ('諢', "hùn"),
+    ('諣', "huà"),
+    ('諤', "è"),
+    ('諥', "zhòng"),
+    ('諦', "dì"),
+    ('諧', "xié"),
+    ('諨', "fú"),
+    ('諩', "pǔ"),
+    ('諪', "tíng"),
+    ('諫', "jiàn"),
+    ('諬', "qǐ"),
+    ('諭', "yù"),
+    ('諮', "zī"),
+    ('諯', "zhuān"),
+    ('諰', "xǐ"),
+    ('諱', "huì"),
+    ('諲', "yīn"),
+    ('諳', "ān"),
+    ('諴', "xián"),
+    ('諵', "nán"),
+    ('諶', "chén"),
+    ('諷', "fěng"),
+    ('諸', "zhū"),
+    ('諹', "yáng"),
+    ('諺', "yàn"),
+    ('諻', "huáng"),
+    ('諼', "xuān"),
+    ('諽', "gé"),
+    ('諾', "nuò"),
+    ('諿', "qī"),
+    ('謀', "móu"),
+    ('謁', "yè"),
+    ('謂', "wèi"),
+    ('謃', "xīng"),
+    ('謄', "téng"),
+    ('謅', "zhōu"),
+    ('謆', "shàn"),
+    ('謇', "jiǎn"),
+    ('謈', "pó"),
+    ('謉', "kuì"),
+    ('謊', "huǎng"),
+    ('謋', "huò"),
+    ('謌', "gē"),
+    ('謍', "yíng"),
+    ('謎', "mí"),
+    ('謏', "xiǎo"),
+    ('謐', "mì"),
+    ('謑', "xǐ"),
+    ('謒', "qiāng"),
+    ('謓', "chēn"),
+    ('謔', "xuè"),
+    ('謕', "tí"),
+    ('謖', "sù"),
+    ('謗', "bàng"),
+    ('謘', "chí"),
+    ('謙', "qiān"),
+    ('謚', "shì"),
+    ('講', "jiǎng"),
+    ('謜', "yuán"),
+    ('謝', "xiè"),
+    ('謞', "hè"),
+    ('謟', "tāo"),
+    ('謠', "yáo"),
+    ('謡', "yáo"),
+    ('謢', "lū"),
+    ('謣', "yú"),
+    ('謤', "biāo"),
+    ('謥', "còng"),
+    ('謦', "qìng"),
+    ('謧', "lí"),
+    ('謨', "mó"),
+    ('謩', "mó"),
+    ('謪', "shāng"),
+    ('謫', "zhé"),
+    ('謬', "miù"),
+    ('謭', "jiǎn"),
+    ('謮', "zé"),
+    ('謯', "jiē"),
+    ('謰', "lián"),
+    ('謱', "lóu"),
+    ('謲', "càn"),
+    ('謳', "ōu"),
+    ('謴', "gùn"),
+    ('謵', "xí"),
+    ('謶', "zhuó"),
+    ('謷', "áo"),
+    ('謸', "áo"),
+    ('謹', "jǐn"),
+    ('謺', "zhé"),
+    ('謻', "yí"),
+    ('謼', "hū"),
+    ('謽', "jiàng"),
+    ('謾', "mán"),
+    ('謿', "cháo"),
+    ('譀', "hàn"),
+    ('譁', "huá"),
+    ('譂', "chǎn"),
+    ('譃', "xū"),
+    ('譄', "zēng"),
+    ('譅', "sè"),
+    ('譆', "xī"),
+    ('譇', "zhā"),
+    ('譈', "duì"),
+    ('證', "zhèng"),
+    ('譊', "náo"),
+    ('譋', "lán"),
+    ('譌', "é"),
+    ('譍', "yīng"),
+    ('譎', "jué"),
+    ('譏', "jī"),
+    ('譐', "zǔn"),
+    ('譑', "jiǎo"),
+    ('譒', "bò"),
+    ('譓', "huì"),
+    ('譔', "zhuàn"),
+    ('譕', "wú"),
+    ('譖', "zèn"),
+    ('譗', "zhá"),
+    ('識', "shí"),
+    ('譙', "qiào"),
+    ('譚', "tán"),
+    ('譛', "zèn"),
+    ('譜', "pǔ"),
+    ('譝', "shéng"),
+    ('譞', "xuān"),
+    ('譟', "zào"),
+    ('譠', "tán"),
+    ('譡', "dǎng"),
+    ('譢', "suì"),
+    ('譣', "xiǎn"),
+    ('譤', "jī"),
+    ('譥', "jiào"),
+    ('警', "jǐng"),
+    ('譧', "zhàn"),
+    ('譨', "náng"),
+    ('譩', "yī"),
+    ('譪', "ǎi"),
+    ('譫', "zhān"),
+    ('譬', "pì"),
+    ('譭', "huǐ"),
+    ('譮', "huà"),
+    ('譯', "yì"),
+    ('議', "yì"),
+    ('譱', "shàn"),
+    ('譲', "ràng"),
+    ('譳', "nòu"),
+    ('譴', "qiǎn"),
+    ('譵', "duì"),
+    ('譶', "tà"),
+    ('護', "hù"),
+    ('譸', "zhōu"),
+    ('譹', "háo"),
+    ('譺', "ài"),
+    ('譻', "yīng"),
+    ('譼', "jiàn"),
+    ('譽', "yù"),
+    ('譾', "jiǎn"),
+    ('譿', "huì"),
+    ('讀', "dú"),
+    ('讁', "zhé"),
+    ('讂', "xuàn"),
+    ('讃', "zàn"),
+    ('讄', "lěi"),
+    ('讅', "shěn"),
+    ('讆', "wèi"),
+    ('讇', "chǎn"),
+    ('讈', "lì"),
+    ('讉', "yí"),
+    ('變', "biàn"),
+    ('讋', "zhé"),
+    ('讌', "yàn"),
+    ('讍', "è"),
+    ('讎', "chóu"),
+    ('讏', "wèi"),
+    ('讐', "chóu"),
+    ('讑', "yào"),
+    ('讒', "chán"),
+    ('讓', "ràng"),
+    ('讔', "yǐn"),
+    ('讕', "lán"),
+    ('讖', "chèn"),
+    ('讗', "xié"),
+    ('讘', "niè"),
+    ('讙', "huān"),
+    ('讚', "zàn"),
+    ('讛', "yì"),
+    ('讜', "dǎng"),
+    ('讝', "zhān"),
+    ('讞', "yàn"),
+    ('讟', "dú"),
+    ('讠', "yán"),
+    ('计', "jì"),
+    ('订', "dìng"),
+    ('讣', "fù"),
+    ('认', "rèn"),
+    ('讥', "jī"),
+    ('讦', "jié"),
+    ('讧', "hòng"),
+    ('讨', "tǎo"),
+    ('让', "ràng"),
+    ('讪', "shàn"),
+    ('讫', "qì"),
+    ('讬', "tuō"),
+    ('训', "xùn"),
+    ('议', "yì"),
+    ('讯', "xùn"),
+    ('记', "jì"),
+    ('讱', "rèn"),
+    ('讲', "jiǎng"),
+    ('讳', "huì"),
+    ('讴', "ōu"),
+    ('讵', "jù"),
+    ('讶', "yà"),
+    ('讷', "nè"),
+    ('许', "xǔ"),
+    ('讹', "é"),
+    ('论', "lùn,lún"),
+    ('讻', "xiōng"),
+    ('讼', "sòng"),
+    ('讽', "fěng"),
+    ('设', "shè"),
+    ('访', "fǎng"),
+    ('诀', "jué"),
+    ('证', "zhèng"),
+    ('诂', "gǔ"),
+    ('诃', "hē"),
+    ('评', "píng"),
+    ('诅', "zǔ"),
+    ('识', "shí,zhì"),
+    ('诇', "xiòng"),
+    ('诈', "zhà"),
+    ('诉', "sù"),
+    ('诊', "zhěn"),
+    ('诋', "dǐ"),
+    ('诌', "zhōu"),
+    ('词', "cí"),
+    ('诎', "qū"),
+    ('诏', "zhào"),
+    ('诐', "bì"),
+    ('译', "yì"),
+    ('诒', "yí"),
+    ('诓', "kuāng"),
+    ('诔', "lěi"),
+    ('试', "shì"),
+    ('诖', "guà"),
+    ('诗', "shī"),
+    ('诘', "jí"),
+    ('诙', "huī"),
+    ('诚', "chéng"),
+    ('诛', "zhū"),
+    ('诜', "shēn"),
+    ('话', "huà"),
+    ('诞', "dàn"),
+    ('诟', "gòu"),
+    ('诠', "quán"),
+    ('诡', "guǐ"),
+    ('询', "xún"),
+    ('诣', "yì"),
+    ('诤', "zhēng"),
+    ('该', "gāi"),
+    ('详', "xiáng"),
+    ('诧', "chà"),
+    ('诨', "hùn"),
+    ('诩', "xǔ"),
+    ('诪', "zhōu"),
+    ('诫', "jiè"),
+    ('诬', "wū"),
+    ('语', "yǔ"),
+    ('诮', "qiào"),
+    ('误', "wù"),
+    ('诰', "gào"),
+    ('诱', "yòu"),
+    ('诲', "huì"),
+    ('诳', "kuáng"),
+    ('说', "shuō,shuì,yuè"),
+    ('诵', "sòng"),
+    ('诶', "éi"),
+    ('请', "qǐng"),
+    ('诸', "zhū"),
+    ('诹', "zōu"),
+    ('诺', "nuò"),
+    ('读', "dú,dòu"),
+    ('诼', "zhuó"),
+    ('诽', "fěi"),
+    ('课', "kè"),
+    ('诿', "wěi"),
+    ('谀', "yú"),
+    ('谁', "shéi"),
+    ('谂', "shěn"),
+    ('调', "diào,tiáo"),
+    ('谄', "chǎn"),
+    ('谅', "liàng"),
+    ('谆', "zhūn"),
+    ('谇', "suì"),
+    ('谈', "tán"),
+    ('谉', "shěn"),
+    ('谊', "yì"),
+    ('谋', "móu"),
+    ('谌', "chén"),
+    ('谍', "dié"),
+    ('谎', "huǎng"),
+    ('谏', "jiàn"),
+    ('谐', "xié"),
+    ('谑', "xuè"),
+    ('谒', "yè"),
+    ('谓', "wèi"),
+    ('谔', "è"),
+    ('谕', "yù"),
+    ('谖', "xuān"),
+    ('谗', "chán"),
+    ('谘', "zī"),
+    ('谙', "ān"),
+    ('谚', "yàn"),
+    ('谛', "dì"),
+    ('谜', "mí"),
+    ('谝', "pián"),
+    ('谞', "xū"),
+    ('谟', "mó"),
+    ('谠', "dǎng"),
+    ('谡', "sù"),
+    ('谢', "xiè"),
+    ('谣', "yáo"),
+    ('谤', "bàng"),
+    ('谥', "shì"),
+    ('谦', "qiān"),
+    ('谧', "mì"),
+    ('谨', "jǐn"),
+    ('谩', "mán"),
+    ('谪', "zhé"),
+    ('谫', "jiǎn"),
+    ('谬', "miù"),
+    ('谭', "tán"),
+    ('谮', "zèn"),
+    ('谯', "qiáo"),
+    ('谰', "lán"),
+    ('谱', "pǔ"),
+    ('谲', "jué"),
+    ('谳', "yàn"),
+    ('谴', "qiǎn"),
+    ('谵', "zhān"),
+    ('谶', "chèn"),
+    ('谷', "gǔ,yù"),
+    ('谸', "qiān"),
+    ('谹', "hóng"),
+    ('谺', "xiā"),
+    ('谻', "jí"),
+    ('谼', "hóng"),
+    ('谽', "hān"),
+    ('谾', "hōng"),
+    ('谿', "xī"),
+    ('豀', "xī"),
+    ('豁', "huō,huò,huá"),
+    ('豂', "liáo"),
+    ('豃', "hǎn"),
+    ('豄', "dú"),
+    ('豅', "lóng"),
+    ('豆', "dòu"),
+    ('豇', "jiāng"),
+    ('豈', "qǐ"),
+    ('豉', "shì"),
+    ('豊', "lǐ"),
+    ('豋', "dēng"),
+    ('豌', "wān"),
+    ('豍', "bī"),
+    ('豎', "shù"),
+    ('豏', "xiàn"),
+    ('豐', "fēng"),
+    ('豑', "zhì"),
+    ('豒', "zhì"),
+    ('豓', "yàn"),
+    ('豔', "yàn"),
+    ('豕', "shǐ"),
+    ('豖', "chù"),
+    ('豗', "huī"),
+    ('豘', "tún"),
+    ('豙', "yì"),
+    ('豚', "tún"),
+    ('豛', "yì"),
+    ('豜', "jiān"),
+    ('豝', "bā"),
+    ('豞', "hòu"),
+    ('豟', "è"),
+    ('豠', "chú"),
+    ('象', "xiàng"),
+    ('豢', "huàn"),
+    ('豣', "jiān"),
+    ('豤', "kěn"),
+    ('豥', "gāi"),
+    ('豦', "jù"),
+    ('豧', "fū"),
+    ('豨', "xī"),
+    ('豩', "bīn"),
+    ('豪', "háo"),
+    ('豫', "yù"),
+    ('豬', "zhū"),
+    ('豭', "jiā"),
+    ('豮', "fén"),
+    ('豯', "xī"),
+    ('豰', "bó"),
+    ('豱', "wēn"),
+    ('豲', "huán"),
+    ('豳', "bīn"),
+    ('豴', "dí"),
+    ('豵', "zōng"),
+    ('豶', "fén"),
+    ('豷', "yì"),
+    ('豸', "zhì"),
+    ('豹', "bào"),
+    ('豺', "chái"),
+    ('豻', "àn"),
+    ('豼', "pí"),
+    ('豽', "nà"),
+    ('豾', "pī"),
+    ('豿', "gǒu"),
+    ('貀', "nà"),
+    ('貁', "yòu"),
+    ('貂', "diāo"),
+    ('貃', "mò"),
+    ('貄', "sì"),
+    ('貅', "xiū"),
+    ('貆', "huán"),
+    ('貇', "kūn"),
+    ('貈', "hé"),
+    ('貉', "háo"),
+    ('貊', "mò"),
+    ('貋', "àn"),
+    ('貌', "mào"),
+    ('貍', "lí"),
+    ('貎', "ní"),
+    ('貏', "bǐ"),
+    ('貐', "yǔ"),
+    ('貑', "jiā"),
+    ('貒', "tuān"),
+    ('貓', "māo"),
+    ('貔', "pí"),
+    ('貕', "xī"),
+    ('貖', "yì"),
+    ('貗', "jù"),
+    ('貘', "mò"),
+    ('貙', "chū"),
+    ('貚', "tán"),
+    ('貛', "huān"),
+    ('貜', "jué"),
+    ('貝', "bèi"),
+    ('貞', "zhēn"),
+    ('貟', "yuán"),
+    ('負', "fù"),
+    ('財', "cái"),
+    ('貢', "gòng"),
+    ('貣', "tè"),
+    ('貤', "yí"),
+    ('貥', "háng"),
+    ('貦', "wán"),
+    ('貧', "pín"),
+    ('貨', "huò"),
+    ('販', "fàn"),
+    ('貪', "tān"),
+    ('貫', "guàn"),
+    ('責', "zé"),
+    ('貭', "zhì"),
+    ('貮', "èr"),
+    ('貯', "zhù"),
+    ('貰', "shì"),
+    ('貱', "bì"),
+    ('貲', "zī"),
+    ('貳', "èr"),
+    ('貴', "guì"),
+    ('貵', "piǎn"),
+    ('貶', "biǎn"),
+    ('買', "mǎi"),
+    ('貸', "dài"),
+    ('貹', "shèng"),
+    ('貺', "kuàng"),
+    ('費', "fèi"),
+    ('貼', "tiē"),
+    ('貽', "yí"),
+    ('貾', "chí"),
+    ('貿', "mào"),
+    ('賀', "hè"),
+    ('賁', "bì"),
+    ('賂', "lù"),
+    ('賃', "lìn"),
+    ('賄', "huì"),
+    ('賅', "gāi"),
+    ('賆', "pián"),
+    ('資', "zī"),
+    ('賈', "jiǎ"),
+    ('賉', "xù"),
+    ('賊', "zéi"),
+    ('賋', "jiǎo"),
+    ('賌', "gāi"),
+    ('賍', "zāng"),
+    ('賎', "jiàn"),
+    ('賏', "yīng"),
+    ('賐', "xùn"),
+    ('賑', "zhèn"),
+    ('賒', "shē"),
+    ('賓', "bīn"),
+    ('賔', "bīn"),
+    ('賕', "qiú"),
+    ('賖', "shē"),
+    ('賗', "chuàn"),
+    ('賘', "zāng"),
+    ('賙', "zhōu"),
+    ('賚', "lài"),
+    ('賛', "zàn"),
+    ('賜', "cì"),
+    ('賝', "chēn"),
+    ('賞', "shǎng"),
+    ('賟', "tiǎn"),
+    ('賠', "péi"),
+    ('賡', "gēng"),
+    ('賢', "xián"),
+    ('賣', "mài"),
+    ('賤', "jiàn"),
+    ('賥', "suì"),
+    ('賦', "fù"),
+    ('賧', "tàn"),
+    ('賨', "cóng"),
+    ('賩', "cóng"),
+    ('質', "zhì"),
+    ('賫', "jī"),
+    ('賬', "zhàng"),
+    ('賭', "dǔ"),
+    ('賮', "jìn"),
+    ('賯', "xiōng"),
+    ('賰', "chǔn"),
+    ('賱', "yǔn"),
+    ('賲', "bǎo"),
+    ('賳', "zāi"),
+    ('賴', "lài"),
+    ('賵', "fèng"),
+    ('賶', "càng"),
+    ('賷', "jī"),
+    ('賸', "shèng"),
+    ('賹', "yì"),
+    ('賺', "zhuàn"),
+    ('賻', "fù"),
+    ('購', "gòu"),
+    ('賽', "sài"),
+    ('賾', "zé"),
+    ('賿', "liáo"),
+    ('贀', "yì"),
+    ('贁', "bài"),
+    ('贂', "chěn"),
+    ('贃', "wàn"),
+    ('贄', "zhì"),
+    ('贅', "zhuì"),
+    ('贆', "biāo"),
+    ('贇', "yūn"),
+    ('贈', "zèng"),
+    ('贉', "dàn"),
+    ('贊', "zàn"),
+    ('贋', "yàn"),
+    ('贌', "pú"),
+    ('贍', "shàn"),
+    ('贎', "wàn"),
+    ('贏', "yíng"),
+    ('贐', "jìn"),
+    ('贑', "gàn"),
+    ('贒', "xián"),
+    ('贓', "zāng"),
+    ('贔', "bì"),
+    ('贕', "dú"),
+    ('贖', "shú"),
+    ('贗', "yàn"),
+    ('贘', "shǎng"),
+    ('贙', "xuàn"),
+    ('贚', "lòng"),
+    ('贛', "gàn"),
+    ('贜', "zāng"),
+    ('贝', "bèi"),
+    ('贞', "zhēn"),
+    ('负', "fù"),
+    ('贠', "yuán"),
+    ('贡', "gòng"),
+    ('财', "cái"),
+    ('责', "zé"),
+    ('贤', "xián"),
+    ('败', "bài"),
+    ('账', "zhàng"),
+    ('货', "huò"),
+    ('质', "zhì"),
+    ('贩', "fàn"),
+    ('贪', "tān"),
+    ('贫', "pín"),
+    ('贬', "biǎn"),
+    ('购', "gòu"),
+    ('贮', "zhù"),
+    ('贯', "guàn"),
+    ('贰', "èr"),
+    ('贱', "jiàn"),
+    ('贲', "bēn"),
+    ('贳', "shì"),
+    ('贴', "tiē"),
+    ('贵', "guì"),
+    ('贶', "kuàng"),
+    ('贷', "dài"),
+    ('贸', "mào"),
+    ('费', "fèi"),
+    ('贺', "hè"),
+    ('贻', "yí"),
+    ('贼', "zéi"),
+    ('贽', "zhì"),
+    ('贾', "jiǎ"),
+    ('贿', "huì"),
+    ('赀', "zī"),
+    ('赁', "lìn"),
+    ('赂', "lù"),
+    ('赃', "zāng"),
+    ('资', "zī"),
+    ('赅', "gāi"),
+    ('赆', "jìn"),
+    ('赇', "qiú"),
+    ('赈', "zhèn"),
+    ('赉', "lài"),
+    ('赊', "shē"),
+    ('赋', "fù"),
+    ('赌', "dǔ"),
+    ('赍', "jī"),
+    ('赎', "shú"),
+    ('赏', "shǎng"),
+    ('赐', "cì"),
+    ('赑', "bì"),
+    ('赒', "zhōu"),
+    ('赓', "gēng"),
+    ('赔', "péi"),
+    ('赕', "dǎn"),
+    ('赖', "lài"),
+    ('赗', "fèng"),
+    ('赘', "zhuì"),
+    ('赙', "fù"),
+    ('赚', "zhuàn"),
+    ('赛', "sài"),
+    ('赜', "zé"),
+    ('赝', "yàn"),
+    ('赞', "zàn"),
+    ('赟', "yūn"),
+    ('赠', "zèng"),
+    ('赡', "shàn"),
+    ('赢', "yíng"),
+    ('赣', "gàn"),
+    ('赤', "chì"),
+    ('赥', "xī"),
+    ('赦', "shè"),
+    ('赧', "nǎn"),
+    ('赨', "tóng"),
+    ('赩', "xì"),
+    ('赪', "chēng"),
+    ('赫', "hè"),
+    ('赬', "chēng"),
+    ('赭', "zhě"),
+    ('赮', "xiá"),
+    ('赯', "táng"),
+    ('走', "zǒu"),
+    ('赱', "zǒu"),
+    ('赲', "lì"),
+    ('赳', "jiū"),
+    ('赴', "fù"),
+    ('赵', "zhào"),
+    ('赶', "gǎn"),
+    ('起', "qǐ"),
+    ('赸', "shàn"),
+    ('赹', "qióng"),
+    ('赺', "yǐn"),
+    ('赻', "xiǎn"),
+    ('赼', "zī"),
+    ('赽', "jué"),
+    ('赾', "qǐn"),
+    ('赿', "chí"),
+    ('趀', "cī"),
+    ('趁', "chèn"),
+    ('趂', "chèn"),
+    ('趃', "dié"),
+    ('趄', "jū"),
+    ('超', "chāo"),
+    ('趆', "dī"),
+    ('趇', "xì"),
+    ('趈', "zhān"),
+    ('趉', "jué"),
+    ('越', "yuè"),
+    ('趋', "qū"),
+    ('趌', "jí"),
+    ('趍', "chí"),
+    ('趎', "chú"),
+    ('趏', "guā"),
+    ('趐', "xuè"),
+    ('趑', "zī"),
+    ('趒', "tiáo"),
+    ('趓', "duǒ"),
+    ('趔', "liè"),
+    ('趕', "gǎn"),
+    ('趖', "suō"),
+    ('趗', "cù"),
+    ('趘', "xí"),
+    ('趙', "zhào"),
+    ('趚', "sù"),
+    ('趛', "yǐn"),
+    ('趜', "jú"),
+    ('趝', "jiàn"),
+    ('趞', "què"),
+    ('趟', "tàng"),
+    ('趠', "chuò"),
+    ('趡', "cuǐ"),
+    ('趢', "lù"),
+    ('趣', "qù"),
+    ('趤', "dàng"),
+    ('趥', "qiū"),
+    ('趦', "zī"),
+    ('趧', "tí"),
+    ('趨', "qū"),
+    ('趩', "chì"),
+    ('趪', "huáng"),
+    ('趫', "qiáo"),
+    ('趬', "qiāo"),
+    ('趭', "jiào"),
+    ('趮', "zào"),
+    ('趯', "tì"),
+    ('趰', "ěr"),
+    ('趱', "zǎn"),
+    ('趲', "zǎn"),
+    ('足', "zú"),
+    ('趴', "pā"),
+    ('趵', "bào"),
+    ('趶', "kù"),
+    ('趷', "kē"),
+    ('趸', "dǔn"),
+    ('趹', "jué"),
+    ('趺', "fū"),
+    ('趻', "chěn"),
+    ('趼', "jiǎn"),
+    ('趽', "fàng"),
+    ('趾', "zhǐ"),
+    ('趿', "tā"),
+    ('跀', "yuè"),
+    ('跁', "bà"),
+    ('跂', "qí"),
+    ('跃', "yuè"),
+    ('跄', "qiāng"),
+    ('跅', "tuò"),
+    ('跆', "tái"),
+    ('跇', "yì"),
+    ('跈', "niǎn"),
+    ('跉', "líng"),
+    ('跊', "mèi"),
+    ('跋', "bá"),
+    ('跌', "diē"),
+    ('跍', "kū"),
+    ('跎', "tuó"),
+    ('跏', "jiā"),
+    ('跐', "cī"),
+    ('跑', "pǎo"),
+    ('跒', "qiǎ"),
+    ('跓', "zhù"),
+    ('跔', "jū"),
+    ('跕', "diǎn"),
+    ('跖', "zhí"),
+    ('跗', "fū"),
+    ('跘', "pán"),
+    ('跙', "jù"),
+    ('跚', "shān"),
+    ('跛', "bǒ"),
+    ('跜', "ní"),
+    ('距', "jù"),
+    ('跞', "lì"),
+    ('跟', "gēn"),
+    ('跠', "yí"),
+    ('跡', "jī"),
+    ('跢', "duò"),
+    ('跣', "xiǎn"),
+    ('跤', "jiāo"),
+    ('跥', "duò"),
+    ('跦', "zhū"),
+    ('跧', "quán"),
+    ('跨', "kuà"),
+    ('跩', "zhuǎi"),
+    ('跪', "guì"),
+    ('跫', "qióng"),
+    ('跬', "kuǐ"),
+    ('跭', "xiáng"),
+    ('跮', "chì"),
+    ('路', "lù"),
+    ('跰', "pián"),
+    ('跱', "zhì"),
+    ('跲', "jiá"),
+    ('跳', "tiào"),
+    ('跴', "cǎi"),
+    ('践', "jiàn"),
+    ('跶', "dá"),
+    ('跷', "qiāo"),
+    ('跸', "bì"),
+    ('跹', "xiān"),
+    ('跺', "duò"),
+    ('跻', "jī"),
+    ('跼', "jú"),
+    ('跽', "jì"),
+    ('跾', "shū"),
+    ('跿', "tú"),
+    ('踀', "chù"),
+    ('踁', "jìng"),
+    ('踂', "niè"),
+    ('踃', "xiāo"),
+    ('踄', "bù"),
+    ('踅', "xué"),
+    ('踆', "cūn"),
+    ('踇', "mǔ"),
+    ('踈', "shū"),
+    ('踉', "liáng"),
+    ('踊', "yǒng"),
+    ('踋', "jiǎo"),
+    ('踌', "chóu"),
+    ('踍', "qiāo"),
+    ('踎', "móu"),
+    ('踏', "tà,tā"),
+    ('踐', "jiàn"),
+    ('踑', "qí"),
+    ('踒', "wō"),
+    ('踓', "wěi"),
+    ('踔', "chuō"),
+    ('踕', "jié"),
+    ('踖', "jí"),
+    ('踗', "niè"),
+    ('踘', "jū"),
+    ('踙', "niè"),
+    ('踚', "lún"),
+    ('踛', "lù"),
+    ('踜', "lèng"),
+    ('踝', "huái"),
+    ('踞', "jù"),
+    ('踟', "chí"),
+    ('踠', "wǎn"),
+    ('踡', "quán"),
+    ('踢', "tī"),
+    ('踣', "bó"),
+    ('踤', "zú"),
+    ('踥', "qiè"),
+    ('踦', "yǐ"),
+    ('踧', "cù"),
+    ('踨', "zōng"),
+    ('踩', "cǎi"),
+    ('踪', "zōng"),
+    ('踫', "pèng"),
+    ('踬', "zhì"),
+    ('踭', "zhēng"),
+    ('踮', "diǎn"),
+    ('踯', "zhí"),
+    ('踰', "yú"),
+    ('踱', "duó"),
+    ('踲', "dùn"),
+    ('踳', "chuǎn"),
+    ('踴', "yǒng"),
+    ('踵', "zhǒng"),
+    ('踶', "dì"),
+    ('踷', "zhǎ"),
+    ('踸', "chěn"),
+    ('踹', "chuài"),
+    ('踺', "jiàn"),
+    ('踻', "guā"),
+    ('踼', "táng"),
+    ('踽', "jǔ"),
+    ('踾', "fú"),
+    ('踿', "zú"),
+    ('蹀', "dié"),
+    ('蹁', "pián"),
+    ('蹂', "róu"),
+    ('蹃', "nuò"),
+    ('蹄', "tí"),
+    ('蹅', "chǎ"),
+    ('蹆', "tuǐ"),
+    ('蹇', "jiǎn"),
+    ('蹈', "dǎo"),
+    ('蹉', "cuō"),
+    ('蹊', "qī"),
+    ('蹋', "tà"),
+    ('蹌', "qiāng"),
+    ('蹍', "niǎn"),
+    ('蹎', "diān"),
+    ('蹏', "tí"),
+    ('蹐', "jí"),
+    ('蹑', "niè"),
+    ('蹒', "mán"),
+    ('蹓', "liū"),
+    ('蹔', "zàn"),
+    ('蹕', "bì"),
+    ('蹖', "chōng"),
+    ('蹗', "lù"),
+    ('蹘', "liáo"),
+    ('蹙', "cù"),
+    ('蹚', "tāng"),
+    ('蹛', "dài"),
+    ('蹜', "sù"),
+    ('蹝', "xǐ"),
+    ('蹞', "kuǐ"),
+    ('蹟', "jī"),
+    ('蹠', "zhí"),
+    ('蹡', "qiāng"),
+    ('蹢', "dí"),
+    ('蹣', "pán"),
+    ('蹤', "zōng"),
+    ('蹥', "lián"),
+    ('蹦', "bèng"),
+    ('蹧', "zāo"),
+    ('蹨', "niǎn"),
+    ('蹩', "bié"),
+    ('蹪', "tuí"),
+    ('蹫', "jú"),
+    ('蹬', "dēng"),
+    ('蹭', "cèng"),
+    ('蹮', "xiān"),
+    ('蹯', "fán"),
+    ('蹰', "chú"),
+    ('蹱', "zhōng"),
+    ('蹲', "dūn"),
+    ('蹳', "bō"),
+    ('蹴', "cù"),
+    ('蹵', "cù"),
+    ('蹶', "jué"),
+    ('蹷', "jué"),
+    ('蹸', "lìn"),
+    ('蹹', "tá"),
+    ('蹺', "qiāo"),
+    ('蹻', "juē"),
+    ('蹼', "pǔ"),
+    ('蹽', "liāo"),
+    ('蹾', "dūn"),
+    ('蹿', "cuān"),
+    ('躀', "guàn"),
+    ('躁', "zào"),
+    ('躂', "dá"),
+    ('躃', "bì"),
+    ('躄', "bì"),
+    ('躅', "zhú"),
+    ('躆', "jù"),
+    ('躇', "chú"),
+    ('躈', "qiào"),
+    ('躉', "dǔn"),
+    ('躊', "chóu"),
+    ('躋', "jī"),
+    ('躌', "wǔ"),
+    ('躍', "yuè"),
+    ('躎', "niǎn"),
+    ('躏', "lìn"),
+    ('躐', "liè"),
+    ('躑', "zhí"),
+    ('躒', "lì"),
+    ('躓', "zhì"),
+    ('躔', "chán"),
+    ('躕', "chú"),
+    ('躖', "duàn"),
+    ('躗', "wèi"),
+    ('躘', "lóng"),
+    ('躙', "lìn"),
+    ('躚', "xiān"),
+    ('躛', "wèi"),
+    ('躜', "zuān"),
+    ('躝', "lán"),
+    ('躞', "xiè"),
+    ('躟', "ráng"),
+    ('躠', "sǎ"),
+    ('躡', "niè"),
+    ('躢', "tà"),
+    ('躣', "qú"),
+    ('躤', "jí"),
+    ('躥', "cuān"),
+    ('躦', "cuó"),
+    ('躧', "xǐ"),
+    ('躨', "kuí"),
+    ('躩', "jué"),
+    ('躪', "lìn"),
+    ('身', "shēn"),
+    ('躬', "gōng"),
+    ('躭', "dān"),
+    ('躮', "fēn"),
+    ('躯', "qū"),
+    ('躰', "tǐ"),
+    ('躱', "duǒ"),
+    ('躲', "duǒ"),
+    ('躳', "gōng"),
+    ('躴', "láng"),
+    ('躵', "rěn"),
+    ('躶', "luǒ"),
+    ('躷', "ǎi"),
+    ('躸', "jī"),
+    ('躹', "jú"),
+    ('躺', "tǎng"),
+    ('躻', "kōng"),
+    ('躼', "lào"),
+    ('躽', "yǎn"),
+    ('躾', "měi"),
+    ('躿', "kāng"),
+    ('軀', "qū"),
+    ('軁', "lóu"),
+    ('軂', "lào"),
+    ('軃', "duǒ"),
+    ('軄', "zhí"),
+    ('軅', "yàn"),
+    ('軆', "tǐ"),
+    ('軇', "dào"),
+    ('軈', "yīng"),
+    ('軉', "yù"),
+    ('車', "chē"),
+    ('軋', "yà"),
+    ('軌', "guǐ"),
+    ('軍', "jūn"),
+    ('軎', "wèi"),
+    ('軏', "yuè"),
+    ('軐', "xìn"),
+    ('軑', "dài"),
+    ('軒', "xuān"),
+    ('軓', "fàn"),
+    ('軔', "rèn"),
+    ('軕', "shān"),
+    ('軖', "kuáng"),
+    ('軗', "shū"),
+    ('軘', "tún"),
+    ('軙', "chén"),
+    ('軚', "dài"),
+    ('軛', "è"),
+    ('軜', "nà"),
+    ('軝', "qí"),
+    ('軞', "máo"),
+    ('軟', "ruǎn"),
+    ('軠', "kuáng"),
+    ('軡', "qián"),
+    ('転', "zhuǎn"),
+    ('軣', "hōng"),
+    ('軤', "hū"),
+    ('軥', "qú"),
+    ('軦', "kuàng"),
+    ('軧', "dǐ"),
+    ('軨', "líng"),
+    ('軩', "dài"),
+    ('軪', "āo"),
+    ('軫', "zhěn"),
+    ('軬', "fàn"),
+    ('軭', "kuāng"),
+    ('軮', "yǎng"),
+    ('軯', "pēng"),
+    ('軰', "bèi"),
+    ('軱', "gū"),
+    ('軲', "gū"),
+    ('軳', "páo"),
+    ('軴', "zhù"),
+    ('軵', "rǒng"),
+    ('軶', "è"),
+    ('軷', "bá"),
+    ('軸', "zhóu"),
+    ('軹', "zhǐ"),
+    ('軺', "yáo"),
+    ('軻', "kē"),
+    ('軼', "yì"),
+    ('軽', "zhì"),
+    ('軾', "shì"),
+    ('軿', "píng"),
+    ('輀', "ér"),
+    ('輁', "gǒng"),
+    ('輂', "jú"),
+    ('較', "jiào"),
+    ('輄', "guāng"),
+    ('輅', "hé"),
+    ('輆', "kǎi"),
+    ('輇', "quán"),
+    ('輈', "zhōu"),
+    ('載', "zài"),
+    ('輊', "zhì"),
+    ('輋', "shē"),
+    ('輌', "liàng"),
+    ('輍', "yù"),
+    ('輎', "shāo"),
+    ('輏', "yóu"),
+    ('輐', "wàn"),
+    ('輑', "yǐn"),
+    ('輒', "zhé"),
+    ('輓', "wǎn"),
+    ('輔', "fǔ"),
+    ('輕', "qīng"),
+    ('輖', "zhōu"),
+    ('輗', "ní"),
+    ('輘', "léng"),
+    ('輙', "zhé"),
+    ('輚', "zhàn"),
+    ('輛', "liàng"),
+    ('輜', "zī"),
+    ('輝', "huī"),
+    ('輞', "wǎng"),
+    ('輟', "chuò"),
+    ('輠', "guǒ"),
+    ('輡', "kǎn"),
+    ('輢', "yǐ"),
+    ('輣', "péng"),
+    ('輤', "qiàn"),
+    ('輥', "gǔn"),
+    ('輦', "niǎn"),
+    ('輧', "píng"),
+    ('輨', "guǎn"),
+    ('輩', "bèi"),
+    ('輪', "lún"),
+    ('輫', "pái"),
+    ('輬', "liáng"),
+    ('輭', "ruǎn"),
+    ('輮', "róu"),
+    ('輯', "jí"),
+    ('輰', "yáng"),
+    ('輱', "xián"),
+    ('輲', "chuán"),
+    ('輳', "còu"),
+    ('輴', "chūn"),
+    ('輵', "gé"),
+    ('輶', "yóu"),
+    ('輷', "hōng"),
+    ('輸', "shū"),
+    ('輹', "fù"),
+    ('輺', "zī"),
+    ('輻', "fú"),
+    ('輼', "wēn"),
+    ('輽', "bèn"),
+    ('輾', "zhǎn"),
+    ('輿', "yú"),
+    ('轀', "wēn"),
+    ('轁', "tāo"),
+    ('轂', "gǔ"),
+    ('轃', "zhēn"),
+    ('轄', "xiá"),
+    ('轅', "yuán"),
+    ('轆', "lù"),
+    ('轇', "jiāo"),
+    ('轈', "cháo"),
+    ('轉', "zhuǎn"),
+    ('轊', "wèi"),
+    ('轋', "hún"),
+    ('轌', "xuě"),
+    ('轍', "zhé"),
+    ('轎', "jiào"),
+    ('轏', "zhàn"),
+    ('轐', "bú"),
+    ('轑', "lǎo"),
+    ('轒', "fén"),
+    ('轓', "fān"),
+    ('轔', "lín"),
+    ('轕', "gé"),
+    ('轖', "sè"),
+    ('轗', "kǎn"),
+    ('轘', "huán"),
+    ('轙', "yǐ"),
+    ('轚', "jí"),
+    ('轛', "zhuì"),
+    ('轜', "ér"),
+    ('轝', "yù"),
+    ('轞', "jiàn"),
+    ('轟', "hōng"),
+    ('轠', "léi"),
+    ('轡', "pèi"),
+    ('轢', "lì"),
+    ('轣', "lì"),
+    ('轤', "lú"),
+    ('轥', "lìn"),
+    ('车', "chē,jū"),
+    ('轧', "yà"),
+    ('轨', "guǐ"),
+    ('轩', "xuān"),
+    ('轪', "dài"),
+    ('轫', "rèn"),
+    ('转', "zhuǎn,zhuàn"),
+    ('轭', "è"),
+    ('轮', "lún"),
+    ('软', "ruǎn"),
+    ('轰', "hōng"),
+    ('轱', "gū"),
+    ('轲', "kē"),
+    ('轳', "lú"),
+    ('轴', "zhóu,zhòu"),
+    ('轵', "zhǐ"),
+    ('轶', "yì"),
+    ('轷', "hū"),
+    ('轸', "zhěn"),
+    ('轹', "lì"),
+    ('轺', "yáo"),
+    ('轻', "qīng"),
+    ('轼', "shì"),
+    ('载', "zài,zǎi"),
+    ('轾', "zhì"),
+    ('轿', "jiào"),
+    ('辀', "zhōu"),
+    ('辁', "quán"),
+    ('辂', "lù"),
+    ('较', "jiào"),
+    ('辄', "zhé"),
+    ('辅', "fǔ"),
+    ('辆', "liàng"),
+    ('辇', "niǎn"),
+    ('辈', "bèi"),
+    ('辉', "huī"),
+    ('辊', "gǔn"),
+    ('辋', "wǎng"),
+    ('辌', "liáng"),
+    ('辍', "chuò"),
+    ('辎', "zī"),
+    ('辏', "còu"),
+    ('辐', "fú"),
+    ('辑', "jí"),
+    ('辒', "wēn"),
+    ('输', "shū"),
+    ('辔', "pèi"),
+    ('辕', "yuán"),
+    ('辖', "xiá"),
+    ('辗', "niǎn"),
+    ('辘', "lù"),
+    ('辙', "zhé"),
+    ('辚', "lín"),
+    ('辛', "xīn"),
+    ('辜', "gū"),
+    ('辝', "cí"),
+    ('辞', "cí"),
+    ('辟', "pì,bì"),
+    ('辠', "zuì"),
+    ('辡', "biàn"),
+    ('辢', "là"),
+    ('辣', "là"),
+    ('辤', "cí"),
+    ('辥', "xuē"),
+    ('辦', "bàn"),
+    ('辧', "biàn"),
+    ('辨', "biàn"),
+    ('辩', "biàn"),
+    ('辪', "xuē"),
+    ('辫', "biàn"),
+    ('辬', "bān"),
+    ('辭', "cí"),
+    ('辮', "biàn"),
+    ('辯', "biàn"),
+    ('辰', "chén"),
+    ('辱', "rǔ"),
+    ('農', "nóng"),
+    ('辳', "nóng"),
+    ('辴', "chǎn"),
+    ('辵', "chuò"),
+    ('辶', "chuò"),
+    ('辷', "yī"),
+    ('辸', "réng"),
+    ('边', "biān"),
+    ('辺', "biān"),
+    ('辻', "shí"),
+    ('込', "yū"),
+    ('辽', "liáo"),
+    ('达', "dá"),
+    ('辿', "chān"),
+    ('迀', "gān"),
+    ('迁', "qiān"),
+    ('迂', "yū"),
+    ('迃', "yū"),
+    ('迄', "qì"),
+    ('迅', "xùn"),
+    ('迆', "yí"),
+    ('过', "guò,guo,guō"),
+    ('迈', "mài"),
+    ('迉', "qī"),
+    ('迊', "zā"),
+    ('迋', "wàng"),
+    ('迌', "tù"),
+    ('迍', "zhūn"),
+    ('迎', "yíng"),
+    ('迏', "dá"),
+    ('运', "yùn"),
+    ('近', "jìn"),
+    ('迒', "háng"),
+    ('迓', "yà"),
+    ('返', "fǎn"),
+    ('迕', "wù"),
+    ('迖', "dá"),
+    ('迗', "é"),
+    ('还', "hái,huán"),
+    ('这', "zhè,zhèi"),
+    ('迚', "dá"),
+    ('进', "jìn"),
+    ('远', "yuǎn"),
+    ('违', "wéi"),
+    ('连', "lián"),
+    ('迟', "chí"),
+    ('迠', "chè"),
+    ('迡', "nì"),
+    ('迢', "tiáo"),
+    ('迣', "zhì"),
+    ('迤', "yí"),
+    ('迥', "jiǒng"),
+    ('迦', "jiā"),
+    ('迧', "chén"),
+    ('迨', "dài"),
+    ('迩', "ěr"),
+    ('迪', "dí"),
+    ('迫', "pò,pǎi"),
+    ('迬', "zhù"),
+    ('迭', "dié"),
+    ('迮', "zé"),
+    ('迯', "táo"),
+    ('述', "shù"),
+    ('迱', "tuó"),
+    ('迲', "qu"),
+    ('迳', "jìng"),
+    ('迴', "huí"),
+    ('迵', "dòng"),
+    ('迶', "yòu"),
+    ('迷', "mí"),
+    ('迸', "bèng"),
+    ('迹', "jī"),
+    ('迺', "nǎi"),
+    ('迻', "yí"),
+    ('迼', "jié"),
+    ('追', "zhuī"),
+    ('迾', "liè"),
+    ('迿', "xùn"),
+    ('退', "tuì"),
+    ('送', "sòng"),
+    ('适', "shì"),
+    ('逃', "táo"),
+    ('逄', "páng"),
+    ('逅', "hòu"),
+    ('逆', "nì"),
+    ('逇', "dùn"),
+    ('逈', "jiǒng"),
+    ('选', "xuǎn"),
+    ('逊', "xùn"),
+    ('逋', "bū"),
+    ('逌', "yōu"),
+    ('逍', "xiāo"),
+    ('逎', "qiú"),
+    ('透', "tòu"),
+    ('逐', "zhú"),
+    ('逑', "qiú"),
+    ('递', "dì"),
+    ('逓', "dì"),
+    ('途', "tú"),
+    ('逕', "jìng"),
+    ('逖', "tì"),
+    ('逗', "dòu"),
+    ('逘', "yǐ"),
+    ('這', "zhè"),
+    ('通', "tōng,tòng"),
+    ('逛', "guàng"),
+    ('逜', "wù"),
+    ('逝', "shì"),
+    ('逞', "chěng"),
+    ('速', "sù"),
+    ('造', "zào"),
+    ('逡', "qūn"),
+    ('逢', "féng"),
+    ('連', "lián"),
+    ('逤', "suò"),
+    ('逥', "huí"),
+    ('逦', "lǐ"),
+    ('逧', "gǔ"),
+    ('逨', "lái"),
+    ('逩', "bèn"),
+    ('逪', "cuò"),
+    ('逫', "jué"),
+    ('逬', "bèng"),
+    ('逭', "huàn"),
+    ('逮', "dǎi"),
+    ('逯', "lù"),
+    ('逰', "yóu"),
+    ('週', "zhōu"),
+    ('進', "jìn"),
+    ('逳', "yù"),
+    ('逴', "chuō"),
+    ('逵', "kuí"),
+    ('逶', "wēi"),
+    ('逷', "tì"),
+    ('逸', "yì"),
+    ('逹', "dá"),
+    ('逺', "yuǎn"),
+    ('逻', "luó"),
+    ('逼', "bī"),
+    ('逽', "nuò"),
+    ('逾', "yú"),
+    ('逿', "dàng"),
+    ('遀', "suí"),
+    ('遁', "dùn"),
+    ('遂', "suì,suí"),
+    ('遃', "yǎn"),
+    ('遄', "chuán"),
+    ('遅', "chí"),
+    ('遆', "tí"),
+    ('遇', "yù"),
+    ('遈', "shí"),
+    ('遉', "zhēn"),
+    ('遊', "yóu"),
+    ('運', "yùn"),
+    ('遌', "è"),
+    ('遍', "biàn"),
+    ('過', "guò"),
+    ('遏', "è"),
+    ('遐', "xiá"),
+    ('遑', "huáng"),
+    ('遒', "qiú"),
+    ('道', "dào"),
+    ('達', "dá"),
+    ('違', "wéi"),
+    ('遖', "nán"),
+    ('遗', "yí"),
+    ('遘', "gòu"),
+    ('遙', "yáo"),
+    ('遚', "chòu"),
+    ('遛', "liú"),
+    ('遜', "xùn"),
+    ('遝', "tà"),
+    ('遞', "dì"),
+    ('遟', "chí"),
+    ('遠', "yuǎn"),
+    ('遡', "sù"),
+    ('遢', "tà"),
+    ('遣', "qiǎn"),
+    ('遤', "mǎ"),
+    ('遥', "yáo"),
+    ('遦', "guàn"),
+    ('遧', "zhāng"),
+    ('遨', "áo"),
+    ('適', "shì"),
+    ('遪', "cà"),
+    ('遫', "chì"),
+    ('遬', "sù"),
+    ('遭', "zāo"),
+    ('遮', "zhē"),
+    ('遯', "dùn"),
+    ('遰', "dì"),
+    ('遱', "lóu"),
+    ('遲', "chí"),
+    ('遳', "cuō"),
+    ('遴', "lín"),
+    ('遵', "zūn"),
+    ('遶', "rào"),
+    ('遷', "qiān"),
+    ('選', "xuǎn"),
+    ('遹', "yù"),
+    ('遺', "yí"),
+    ('遻', "è"),
+    ('遼', "liáo"),
+    ('遽', "jù"),
+    ('遾', "shì"),
+    ('避', "bì"),
+    ('邀', "yāo"),
+    ('邁', "mài"),
+    ('邂', "xiè"),
+    ('邃', "suì"),
+    ('還', "hái"),
+    ('邅', "zhān"),
+    ('邆', "téng"),
+    ('邇', "ěr"),
+    ('邈', "miǎo"),
+    ('邉', "biān"),
+    ('邊', "biān"),
+    ('邋', "lā"),
+    ('邌', "lí"),
+    ('邍', "yuán"),
+    ('邎', "yáo"),
+    ('邏', "luó"),
+    ('邐', "lǐ"),
+    ('邑', "yì"),
+    ('邒', "tíng"),
+    ('邓', "dèng"),
+    ('邔', "qǐ"),
+    ('邕', "yōng"),
+    ('邖', "shān"),
+    ('邗', "hán"),
+    ('邘', "yú"),
+    ('邙', "máng"),
+    ('邚', "rú"),
+    ('邛', "qióng"),
+    ('邜', "xī"),
+    ('邝', "kuàng"),
+    ('邞', "fū"),
+    ('邟', "kàng"),
+    ('邠', "bīn"),
+    ('邡', "fāng"),
+    ('邢', "xíng"),
+    ('那', "nà,nèi,nā"),
+    ('邤', "xīn"),
+    ('邥', "shěn"),
+    ('邦', "bāng"),
+    ('邧', "yuán"),
+    ('邨', "cūn"),
+    ('邩', "huǒ"),
+    ('邪', "xié"),
+    ('邫', "bāng"),
+    ('邬', "wū"),
+    ('邭', "jù"),
+    ('邮', "yóu"),
+    ('邯', "hán"),
+    ('邰', "tái"),
+    ('邱', "qiū"),
+    ('邲', "bì"),
+    ('邳', "pī"),
+    ('邴', "bǐng"),
+    ('邵', "shào"),
+    ('邶', "bèi"),
+    ('邷', "wǎ"),
+    ('邸', "dǐ"),
+    ('邹', "zōu"),
+    ('邺', "yè"),
+    ('邻', "lín"),
+    ('邼', "kuāng"),
+    ('邽', "guī"),
+    ('邾', "zhū"),
+    ('邿', "shī"),
+    ('郀', "kū"),
+    ('郁', "yù"),
+    ('郂', "gāi"),
+    ('郃', "hé"),
+    ('郄', "qiè"),
+    ('郅', "zhì"),
+    ('郆', "jí"),
+    ('郇', "huán"),
+    ('郈', "hòu"),
+    ('郉', "xíng"),
+    ('郊', "jiāo"),
+    ('郋', "xí"),
+    ('郌', "guī"),
+    ('郍', "nuó"),
+    ('郎', "láng"),
+    ('郏', "jiá"),
+    ('郐', "kuài"),
+    ('郑', "zhèng"),
+    ('郒', "láng"),
+    ('郓', "yùn"),
+    ('郔', "yán"),
+    ('郕', "chéng"),
+    ('郖', "dòu"),
+    ('郗', "xī"),
+    ('郘', "lǚ"),
+    ('郙', "fǔ"),
+    ('郚', "wú"),
+    ('郛', "fú"),
+    ('郜', "gào"),
+    ('郝', "hǎo"),
+    ('郞', "láng"),
+    ('郟', "jiá"),
+    ('郠', "gěng"),
+    ('郡', "jùn"),
+    ('郢', "yǐng"),
+    ('郣', "bó"),
+    ('郤', "xì"),
+    ('郥', "bèi"),
+    ('郦', "lì"),
+    ('郧', "yún"),
+    ('部', "bù"),
+    ('郩', "xiáo"),
+    ('郪', "qī"),
+    ('郫', "pí"),
+    ('郬', "qīng"),
+    ('郭', "guō"),
+    ('郮', "zhōu"),
+    ('郯', "tán"),
+    ('郰', "zōu"),
+    ('郱', "píng"),
+    ('郲', "lái"),
+    ('郳', "ní"),
+    ('郴', "chēn"),
+    ('郵', "yóu"),
+    ('郶', "bù"),
+    ('郷', "xiāng"),
+    ('郸', "dān"),
+    ('郹', "jú"),
+    ('郺', "yōng"),
+    ('郻', "qiāo"),
+    ('郼', "yī"),
+    ('都', "dōu,dū"),
+    ('郾', "yǎn"),
+    ('郿', "méi"),
+    ('鄀', "ruò"),
+    ('鄁', "bèi"),
+    ('鄂', "è"),
+    ('鄃', "shū"),
+    ('鄄', "juàn"),
+    ('鄅', "yǔ"),
+    ('鄆', "yùn"),
+    ('鄇', "hóu"),
+    ('鄈', "kuí"),
+    ('鄉', "xiāng"),
+    ('鄊', "xiāng"),
+    ('鄋', "sōu"),
+    ('鄌', "táng"),
+    ('鄍', "míng"),
+    ('鄎', "xī"),
+    ('鄏', "rǔ"),
+    ('鄐', "chù"),
+    ('鄑', "zī"),
+    ('鄒', "zōu"),
+    ('鄓', "yè"),
+    ('鄔', "wū"),
+    ('鄕', "xiāng"),
+    ('鄖', "yún"),
+    ('鄗', "hào"),
+    ('鄘', "yōng"),
+    ('鄙', "bǐ"),
+    ('鄚', "mào"),
+    ('鄛', "cháo"),
+    ('鄜', "fū"),
+    ('鄝', "liǎo"),
+    ('鄞', "yín"),
+    ('鄟', "zhuān"),
+    ('鄠', "hù"),
+    ('鄡', "qiāo"),
+    ('鄢', "yān"),
+    ('鄣', "zhāng"),
+    ('鄤', "màn"),
+    ('鄥', "qiāo"),
+    ('鄦', "xǔ"),
+    ('鄧', "dèng"),
+    ('鄨', "bì"),
+    ('鄩', "xún"),
+    ('鄪', "bì"),
+    ('鄫', "zēng"),
+    ('鄬', "wéi"),
+    ('鄭', "zhèng"),
+    ('鄮', "mào"),
+    ('鄯', "shàn"),
+    ('鄰', "lín"),
+    ('鄱', "pó"),
+    ('鄲', "dān"),
+    ('鄳', "méng"),
+    ('鄴', "yè"),
+    ('鄵', "cào"),
+    ('鄶', "kuài"),
+    ('鄷', "fēng"),
+    ('鄸', "méng"),
+    ('鄹', "zōu"),
+    ('鄺', "kuàng"),
+    ('鄻', "liǎn"),
+    ('鄼', "zàn"),
+    ('鄽', "chán"),
+    ('鄾', "yōu"),
+    ('鄿', "jī"),
+    ('酀', "yàn"),
+    ('酁', "chán"),
+    ('酂', "cuó"),
+    ('酃', "líng"),
+    ('酄', "huān"),
+    ('酅', "xī"),
+    ('酆', "fēng"),
+    ('酇', "zàn"),
+    ('酈', "lì"),
+    ('酉', "yǒu"),
+    ('酊', "dīng"),
+    ('酋', "qiú"),
+    ('酌', "zhuó"),
+    ('配', "pèi"),
+    ('酎', "zhòu"),
+    ('酏', "yǐ"),
+    ('酐', "gān"),
+    ('酑', "yú"),
+    ('酒', "jiǔ"),
+    ('酓', "yǎn"),
+    ('酔', "zuì"),
+    ('酕', "máo"),
+    ('酖', "zhèn"),
+    ('酗', "xù"),
+    ('酘', "dòu"),
+    ('酙', "zhēn"),
+    ('酚', "fēn"),
+    ('酛', "yuán"),
+    ('酜', "fu"),
+    ('酝', "yùn"),
+    ('酞', "tài"),
+    ('酟', "tiān"),
+    ('酠', "qiǎ"),
+    ('酡', "tuó"),
+    ('酢', "cù"),
+    ('酣', "hān"),
+    ('酤', "gū"),
+    ('酥', "sū"),
+    ('酦', "pò"),
+    ('酧', "chóu"),
+    ('酨', "zài"),
+    ('酩', "mǐng"),
+    ('酪', "lào"),
+    ('酫', "chuò"),
+    ('酬', "chóu"),
+    ('酭', "yòu"),
+    ('酮', "tóng"),
+    ('酯', "zhǐ"),
+    ('酰', "xiān"),
+    ('酱', "jiàng"),
+    ('酲', "chéng"),
+    ('酳', "yìn"),
+    ('酴', "tú"),
+    ('酵', "jiào"),
+    ('酶', "méi"),
+    ('酷', "kù"),
+    ('酸', "suān"),
+    ('酹', "lèi"),
+    ('酺', "pú"),
+    ('酻', "zuì"),
+    ('酼', "hǎi"),
+    ('酽', "yàn"),
+    ('酾', "shāi"),
+    ('酿', "niàng"),
+    ('醀', "wéi"),
+    ('醁', "lù"),
+    ('醂', "lǎn"),
+    ('醃', "yān"),
+    ('醄', "táo"),
+    ('醅', "pēi"),
+    ('醆', "zhǎn"),
+    ('醇', "chún"),
+    ('醈', "tán"),
+    ('醉', "zuì"),
+    ('醊', "zhuì"),
+    ('醋', "cù"),
+    ('醌', "kūn"),
+    ('醍', "tí"),
+    ('醎', "xián"),
+    ('醏', "dū"),
+    ('醐', "hú"),
+    ('醑', "xǔ"),
+    ('醒', "xǐng"),
+    ('醓', "tǎn"),
+    ('醔', "qiú"),
+    ('醕', "chún"),
+    ('醖', "yùn"),
+    ('醗', "pò"),
+    ('醘', "kē"),
+    ('醙', "sōu"),
+    ('醚', "mí"),
+    ('醛', "quán"),
+    ('醜', "chǒu"),
+    ('醝', "cuō"),
+    ('醞', "yùn"),
+    ('醟', "yòng"),
+    ('醠', "àng"),
+    ('醡', "zhà"),
+    ('醢', "hǎi"),
+    ('醣', "táng"),
+    ('醤', "jiàng"),
+    ('醥', "piǎo"),
+    ('醦', "chěn"),
+    ('醧', "yù"),
+    ('醨', "lí"),
+    ('醩', "zāo"),
+    ('醪', "láo"),
+    ('醫', "yī"),
+    ('醬', "jiàng"),
+    ('醭', "bú"),
+    ('醮', "jiào"),
+    ('醯', "xī"),
+    ('醰', "tán"),
+    ('醱', "fā"),
+    ('醲', "nóng"),
+    ('醳', "yì"),
+    ('醴', "lǐ"),
+    ('醵', "jù"),
+    ('醶', "yàn"),
+    ('醷', "yì"),
+    ('醸', "niàng"),
+    ('醹', "rú"),
+    ('醺', "xūn"),
+    ('醻', "chóu"),
+    ('醼', "yàn"),
+    ('醽', "líng"),
+    ('醾', "mí"),
+    ('醿', "mí"),
+    ('釀', "niàng"),
+    ('釁', "xìn"),
+    ('釂', "jiào"),
+    ('釃', "shāi"),
+    ('釄', "mí"),
+    ('釅', "yàn"),
+    ('釆', "biàn"),
+    ('采', "cǎi"),
+    ('釈', "shì"),
+    ('釉', "yòu"),
+    ('释', "shì"),
+    ('釋', "shì"),
+    ('里', "lǐ"),
+    ('重', "zhòng,chóng"),
+    ('野', "yě"),
+    ('量', "liàng,liáng"),
+    ('釐', "xī"),
+    ('金', "jīn"),
+    ('釒', "jīn"),
+    ('釓', "qiú"),
+    ('釔', "yǐ"),
+    ('釕', "liǎo"),
+    ('釖', "dāo"),
+    ('釗', "zhāo"),
+    ('釘', "dīng"),
+    ('釙', "pò"),
+    ('釚', "qiú"),
+    ('釛', "bā"),
+    ('釜', "fǔ"),
+    ('針', "zhēn"),
+    ('釞', "zhí"),
+    ('釟', "bā"),
+    ('釠', "luàn"),
+    ('釡', "fǔ"),
+    ('釢', "nǎi"),
+    ('釣', "diào"),
+    ('釤', "shàn"),
+    ('釥', "qiǎo"),
+    ('釦', "kòu"),
+    ('釧', "chuàn"),
+    ('釨', "zǐ"),
+    ('釩', "fǎn"),
+    ('釪', "huá"),
+    ('釫', "huá"),
+    ('釬', "hàn"),
+    ('釭', "gāng"),
+    ('釮', "qí"),
+    ('釯', "máng"),
+    ('釰', "rì"),
+    ('釱', "dì"),
+    ('釲', "sì"),
+    ('釳', "xì"),
+    ('釴', "yì"),
+    ('釵', "chāi"),
+    ('釶', "shī"),
+    ('釷', "tǔ"),
+    ('釸', "xī"),
+    ('釹', "nǚ"),
+    ('釺', "qiān"),
+    ('釻', "qiú"),
+    ('釼', "jiàn"),
+    ('釽', "pì"),
+    ('釾', "yé"),
+    ('釿', "jīn"),
+    ('鈀', "bǎ"),
+    ('鈁', "fāng"),
+    ('鈂', "chén"),
+    ('鈃', "xíng"),
+    ('鈄', "dǒu"),
+    ('鈅', "yuè"),
+    ('鈆', "qiān"),
+    ('鈇', "fū"),
+    ('鈈', "pī"),
+    ('鈉', "nà"),
+    ('鈊', "xīn"),
+    ('鈋', "é"),
+    ('鈌', "jué"),
+    ('鈍', "dùn"),
+    ('鈎', "gōu"),
+    ('鈏', "yǐn"),
+    ('鈐', "qián"),
+    ('鈑', "bǎn"),
+    ('鈒', "sà"),
+    ('鈓', "rén"),
+    ('鈔', "chāo"),
+    ('鈕', "niǔ"),
+    ('鈖', "fēn"),
+    ('鈗', "yǔn"),
+    ('鈘', "yǐ"),
+    ('鈙', "qín"),
+    ('鈚', "pī"),
+    ('鈛', "guō"),
+    ('鈜', "hóng"),
+    ('鈝', "yín"),
+    ('鈞', "jūn"),
+    ('鈟', "diào"),
+    ('鈠', "yì"),
+    ('鈡', "zhōng"),
+    ('鈢', "xǐ"),
+    ('鈣', "gài"),
+    ('鈤', "rì"),
+    ('鈥', "huǒ"),
+    ('鈦', "tài"),
+    ('鈧', "kàng"),
+    ('鈨', "yuán"),
+    ('鈩', "lú"),
+    ('鈪', "è"),
+    ('鈫', "qín"),
+    ('鈬', "duó"),
+    ('鈭', "zī"),
+    ('鈮', "nǐ"),
+    ('鈯', "tú"),
+    ('鈰', "shì"),
+    ('鈱', "mín"),
+    ('鈲', "gū"),
+    ('鈳', "kē"),
+    ('鈴', "líng"),
+    ('鈵', "bǐng"),
+    ('鈶', "sì"),
+    ('鈷', "gǔ"),
+    ('鈸', "bó"),
+    ('鈹', "pī"),
+    ('鈺', "yù"),
+    ('鈻', "sì"),
+    ('鈼', "zuó"),
+    ('鈽', "bū"),
+    ('鈾', "yóu"),
+    ('鈿', "tián"),
+    ('鉀', "jiǎ"),
+    ('鉁', "zhēn"),
+    ('鉂', "shǐ"),
+    ('鉃', "shì"),
+    ('鉄', "zhí"),
+    ('鉅', "jù"),
+    ('鉆', "chān"),
+    ('鉇', "shī"),
+    ('鉈', "shī"),
+    ('鉉', "xuàn"),
+    ('鉊', "zhāo"),
+    ('鉋', "bào"),
+    ('鉌', "hé"),
+    ('鉍', "bì"),
+    ('鉎', "shēng"),
+    ('鉏', "chú"),
+    ('鉐', "shí"),
+    ('鉑', "bó"),
+    ('鉒', "zhù"),
+    ('鉓', "chì"),
+    ('鉔', "zā"),
+    ('鉕', "pō"),
+    ('鉖', "tóng"),
+    ('鉗', "qián"),
+    ('鉘', "fú"),
+    ('鉙', "zhǎi"),
+    ('鉚', "liǔ"),
+    ('鉛', "qiān"),
+    ('鉜', "fú"),
+    ('鉝', "lì"),
+    ('鉞', "yuè"),
+    ('鉟', "pī"),
+    ('鉠', "yāng"),
+    ('鉡', "bàn"),
+    ('鉢', "bō"),
+    ('鉣', "jié"),
+    ('鉤', "gōu"),
+    ('鉥', "shù"),
+    ('鉦', "zhēng"),
+    ('鉧', "mǔ"),
+    ('鉨', "xǐ"),
+    ('鉩', "xǐ"),
+    ('鉪', "dì"),
+    ('鉫', "jiā"),
+    ('鉬', "mù"),
+    ('鉭', "tǎn"),
+    ('鉮', "huán"),
+    ('鉯', "yǐ"),
+    ('鉰', "sī"),
+    ('鉱', "kuàng"),
+    ('鉲', "kǎ"),
+    ('鉳', "běi"),
+    ('鉴', "jiàn"),
+    ('鉵', "tóng"),
+    ('鉶', "xíng"),
+    ('鉷', "hóng"),
+    ('鉸', "jiǎo"),
+    ('鉹', "chǐ"),
+    ('鉺', "èr"),
+    ('鉻', "luò"),
+    ('鉼', "bǐng"),
+    ('鉽', "shì"),
+    ('鉾', "móu"),
+    ('鉿', "jiā"),
+    ('銀', "yín"),
+    ('銁', "jūn"),
+    ('銂', "zhōu"),
+    ('銃', "chòng"),
+    ('銄', "xiǎng"),
+    ('銅', "tóng"),
+    ('銆', "mò"),
+    ('銇', "lèi"),
+    ('銈', "jī"),
+    ('銉', "yù"),
+    ('銊', "xù"),
+    ('銋', "rén"),
+    ('銌', "zùn"),
+    ('銍', "zhì"),
+    ('銎', "qióng"),
+    ('銏', "shàn"),
+    ('銐', "chì"),
+    ('銑', "xiǎn"),
+    ('銒', "xíng"),
+    ('銓', "quán"),
+    ('銔', "pī"),
+    ('銕', "tiě"),
+    ('銖', "zhū"),
+    ('銗', "xiàng"),
+    ('銘', "míng"),
+    ('銙', "kuǎ"),
+    ('銚', "yáo"),
+    ('銛', "xiān"),
+    ('銜', "xián"),
+    ('銝', "xiū"),
+    ('銞', "jūn"),
+    ('銟', "chā"),
+    ('銠', "lǎo"),
+    ('銡', "jí"),
+    ('銢', "pǐ"),
+    ('銣', "rú"),
+    ('銤', "mǐ"),
+    ('銥', "yī"),
+    ('銦', "yīn"),
+    ('銧', "guāng"),
+    ('銨', "ǎn"),
+    ('銩', "diū"),
+    ('銪', "yǒu"),
+    ('銫', "sè"),
+    ('銬', "kào"),
+    ('銭', "qián"),
+    ('銮', "luán"),
+    ('銯', "sī"),
+    ('銰', "āi"),
+    ('銱', "diào"),
+    ('銲', "hàn"),
+    ('銳', "ruì"),
+    ('銴', "shì"),
+    ('銵', "kēng"),
+    ('銶', "qiú"),
+    ('銷', "xiāo"),
+    ('銸', "zhé"),
+    ('銹', "xiù"),
+    ('銺', "zàng"),
+    ('銻', "tí"),
+    ('銼', "cuò"),
+    ('銽', "guā"),
+    ('銾', "hòng"),
+    ('銿', "zhōng"),
+    ('鋀', "tōu"),
+    ('鋁', "lǚ"),
+    ('鋂', "méi"),
+    ('鋃', "láng"),
+    ('鋄', "wàn"),
+    ('鋅', "xīn"),
+    ('鋆', "yún"),
+    ('鋇', "bèi"),
+    ('鋈', "wù"),
+    ('鋉', "sù"),
+    ('鋊', "yù"),
+    ('鋋', "chán"),
+    ('鋌', "dìng"),
+    ('鋍', "bó"),
+    ('鋎', "hàn"),
+    ('鋏', "jiá"),
+    ('鋐', "hóng"),
+    ('鋑', "cuān"),
+    ('鋒', "fēng"),
+    ('鋓', "chān"),
+    ('鋔', "wǎn"),
+    ('鋕', "zhì"),
+    ('鋖', "sī"),
+    ('鋗', "xuān"),
+    ('鋘', "huá"),
+    ('鋙', "yǔ"),
+    ('鋚', "tiáo"),
+    ('鋛', "kuàng"),
+    ('鋜', "zhuó"),
+    ('鋝', "lüè"),
+    ('鋞', "xíng"),
+    ('鋟', "qǐn"),
+    ('鋠', "shèn"),
+    ('鋡', "hán"),
+    ('鋢', "lüè"),
+    ('鋣', "yé"),
+    ('鋤', "chú"),
+    ('鋥', "zèng"),
+    ('鋦', "jū"),
+    ('鋧', "xiàn"),
+    ('鋨', "tiě"),
+    ('鋩', "máng"),
+    ('鋪', "pù"),
+    ('鋫', "lí"),
+    ('鋬', "pàn"),
+    ('鋭', "ruì"),
+    ('鋮', "chéng"),
+    ('鋯', "gào"),
+    ('鋰', "lǐ"),
+    ('鋱', "tè"),
+    ('鋲', "bīng"),
+    ('鋳', "zhù"),
+    ('鋴', "zhèn"),
+    ('鋵', "tū"),
+    ('鋶', "liǔ"),
+    ('鋷', "zuì"),
+    ('鋸', "jù"),
+    ('鋹', "chǎng"),
+    ('鋺', "yuǎn"),
+    ('鋻', "jiàn"),
+    ('鋼', "gāng"),
+    ('鋽', "diào"),
+    ('鋾', "táo"),
+    ('鋿', "cháng"),
+    ('錀', "lún"),
+    ('錁', "guǒ"),
+    ('錂', "líng"),
+    ('錃', "pī"),
+    ('錄', "lù"),
+    ('錅', "lí"),
+    ('錆', "qiāng"),
+    ('錇', "póu"),
+    ('錈', "juǎn"),
+    ('錉', "mín"),
+    ('錊', "zuì"),
+    ('錋', "péng"),
+    ('錌', "àn"),
+    ('錍', "pī"),
+    ('錎', "xiàn"),
+    ('錏', "yā"),
+    ('錐', "zhuī"),
+    ('錑', "lèi"),
+    ('錒', "kē"),
+    ('錓', "kōng"),
+    ('錔', "tà"),
+    ('錕', "kūn"),
+    ('錖', "dú"),
+    ('錗', "nèi"),
+    ('錘', "chuí"),
+    ('錙', "zī"),
+    ('錚', "zhēng"),
+    ('錛', "bēn"),
+    ('錜', "niè"),
+    ('錝', "zòng"),
+    ('錞', "chún"),
+    ('錟', "tán"),
+    ('錠', "dìng"),
+    ('錡', "qí"),
+    ('錢', "qián"),
+    ('錣', "zhuì"),
+    ('錤', "jī"),
+    ('錥', "yù"),
+    ('錦', "jǐn"),
+    ('錧', "guǎn"),
+    ('錨', "máo"),
+    ('錩', "chāng"),
+    ('錪', "tiǎn"),
+    ('錫', "xī"),
+    ('錬', "liàn"),
+    ('錭', "táo"),
+    ('錮', "gù"),
+    ('錯', "cuò"),
+    ('錰', "shù"),
+    ('錱', "zhēn"),
+    ('録', "lù"),
+    ('錳', "měng"),
+    ('錴', "lù"),
+    ('錵', "huā"),
+    ('錶', "biǎo"),
+    ('錷', "gá"),
+    ('錸', "lái"),
+    ('錹', "kěn"),
+    ('錺', "fāng"),
+    ('錻', "wu"),
+    ('錼', "nài"),
+    ('錽', "wàn"),
+    ('錾', "zàn"),
+    ('錿', "hǔ"),
+    ('鍀', "dé"),
+    ('鍁', "xiān"),
+    ('鍂', "piān"),
+    ('鍃', "huō"),
+    ('鍄', "liàng"),
+    ('鍅', "fǎ"),
+    ('鍆', "mén"),
+    ('鍇', "kǎi"),
+    ('鍈', "yīng"),
+    ('鍉', "dī"),
+    ('鍊', "liàn"),
+    ('鍋', "guō"),
+    ('鍌', "xiǎn"),
+    ('鍍', "dù"),
+    ('鍎', "tú"),
+    ('鍏', "wéi"),
+    ('鍐', "zōng"),
+    ('鍑', "fù"),
+    ('鍒', "róu"),
+    ('鍓', "jí"),
+    ('鍔', "è"),
+    ('鍕', "jūn"),
+    ('鍖', "chěn"),
+    ('鍗', "tí"),
+    ('鍘', "zhá"),
+    ('鍙', "hù"),
+    ('鍚', "yáng"),
+    ('鍛', "duàn"),
+    ('鍜', "xiá"),
+    ('鍝', "yú"),
+    ('鍞', "kēng"),
+    ('鍟', "shēng"),
+    ('鍠', "huáng"),
+    ('鍡', "wěi"),
+    ('鍢', "fù"),
+    ('鍣', "zhāo"),
+    ('鍤', "chā"),
+    ('鍥', "qiè"),
+    ('鍦', "shī"),
+    ('鍧', "hōng"),
+    ('鍨', "kuí"),
+    ('鍩', "tiǎn"),
+    ('鍪', "móu"),
+    ('鍫', "qiāo"),
+    ('鍬', "qiāo"),
+    ('鍭', "hóu"),
+    ('鍮', "tōu"),
+    ('鍯', "cōng"),
+    ('鍰', "huán"),
+    ('鍱', "yè"),
+    ('鍲', "mín"),
+    ('鍳', "jiàn"),
+    ('鍴', "duān"),
+    ('鍵', "jiàn"),
+    ('鍶', "sōng"),
+    ('鍷', "kuí"),
+    ('鍸', "hú"),
+    ('鍹', "xuān"),
+    ('鍺', "duǒ"),
+    ('鍻', "jié"),
+    ('鍼', "zhēn"),
+    ('鍽', "biān"),
+    ('鍾', "zhōng"),
+    ('鍿', "zī"),
+    ('鎀', "xiū"),
+    ('鎁', "yé"),
+    ('鎂', "měi"),
+    ('鎃', "pài"),
+    ('鎄', "āi"),
+    ('鎅', "jiè"),
+    ('鎆', "qian"),
+    ('鎇', "méi"),
+    ('鎈', "suǒ"),
+    ('鎉', "dá"),
+    ('鎊', "bàng"),
+    ('鎋', "xiá"),
+    ('鎌', "lián"),
+    ('鎍', "suǒ"),
+    ('鎎', "kài"),
+    ('鎏', "liú"),
+    ('鎐', "yáo"),
+    ('鎑', "yè"),
+    ('鎒', "nòu"),
+    ('鎓', "wēng"),
+    ('鎔', "róng"),
+    ('鎕', "táng"),
+    ('鎖', "suǒ"),
+    ('鎗', "qiāng"),
+    ('鎘', "lì"),
+    ('鎙', "shuò"),
+    ('鎚', "chuí"),
+    ('鎛', "bó"),
+    ('鎜', "pán"),
+    ('鎝', "dā"),
+    ('鎞', "bī"),
+    ('鎟', "sǎng"),
+    ('鎠', "gāng"),
+    ('鎡', "zī"),
+    ('鎢', "wū"),
+    ('鎣', "yíng"),
+    ('鎤', "huàng"),
+    ('鎥', "tiáo"),
+    ('鎦', "liú"),
+    ('鎧', "kǎi"),
+    ('鎨', "sǔn"),
+    ('鎩', "shā"),
+    ('鎪', "sōu"),
+    ('鎫', "wàn"),
+    ('鎬', "hào"),
+    ('鎭', "zhèn"),
+    ('鎮', "zhèn"),
+    ('鎯', "láng"),
+    ('鎰', "yì"),
+    ('鎱', "yuán"),
+    ('鎲', "tǎng"),
+    ('鎳', "niè"),
+    ('鎴', "xí"),
+    ('鎵', "jiā"),
+    ('鎶', "gē"),
+    ('鎷', "mǎ"),
+    ('鎸', "juān"),
+    ('鎹', "sòng"),
+    ('鎺', "zǔ"),
+    ('鎻', "suǒ"),
+    ('鎼', "xià"),
+    ('鎽', "fēng"),
+    ('鎾', "wēn"),
+    ('鎿', "ná"),
+    ('鏀', "lǔ"),
+    ('鏁', "suǒ"),
+    ('鏂', "ōu"),
+    ('鏃', "zú"),
+    ('鏄', "tuán"),
+    ('鏅', "xiū"),
+    ('鏆', "guàn"),
+    ('鏇', "xuàn"),
+    ('鏈', "liàn"),
+    ('鏉', "shòu"),
+    ('鏊', "ào"),
+    ('鏋', "mǎn"),
+    ('鏌', "mò"),
+    ('鏍', "luó"),
+    ('鏎', "bì"),
+    ('鏏', "wèi"),
+    ('鏐', "liú"),
+    ('鏑', "dí"),
+    ('鏒', "sǎn"),
+    ('鏓', "zǒng"),
+    ('鏔', "yí"),
+    ('鏕', "lù"),
+    ('鏖', "áo"),
+    ('鏗', "kēng"),
+    ('鏘', "qiāng"),
+    ('鏙', "cuī"),
+    ('鏚', "qī"),
+    ('鏛', "cháng"),
+    ('鏜', "tāng"),
+    ('鏝', "màn"),
+    ('鏞', "yōng"),
+    ('鏟', "chǎn"),
+    ('鏠', "fēng"),
+    ('鏡', "jìng"),
+    ('鏢', "biāo"),
+    ('鏣', "shù"),
+    ('鏤', "lòu"),
+    ('鏥', "xiù"),
+    ('鏦', "cōng"),
+    ('鏧', "lóng"),
+    ('鏨', "zàn"),
+    ('鏩', "jiàn"),
+    ('鏪', "cáo"),
+    ('鏫', "lí"),
+    ('鏬', "xià"),
+    ('鏭', "xī"),
+    ('鏮', "kāng"),
+    ('鏯', "shuǎng"),
+    ('鏰', "bèng"),
+    ('鏱', "zhang"),
+    ('鏲', "qian"),
+    ('鏳', "chēng"),
+    ('鏴', "lù"),
+    ('鏵', "huá"),
+    ('鏶', "jí"),
+    ('鏷', "pú"),
+    ('鏸', "huì"),
+    ('鏹', "qiǎng"),
+    ('鏺', "pō"),
+    ('鏻', "lín"),
+    ('鏼', "sè"),
+    ('鏽', "xiù"),
+    ('鏾', "sǎn"),
+    ('鏿', "chēng"),
+    ('鐀', "kuì"),
+    ('鐁', "sī"),
+    ('鐂', "liú"),
+    ('鐃', "náo"),
+    ('鐄', "huáng"),
+    ('鐅', "piě"),
+    ('鐆', "suì"),
+    ('鐇', "fán"),
+    ('鐈', "qiáo"),
+    ('鐉', "quān"),
+    ('鐊', "yáng"),
+    ('鐋', "tāng"),
+    ('鐌', "xiàng"),
+    ('鐍', "jué"),
+    ('鐎', "jiāo"),
+    ('鐏', "zūn"),
+    ('鐐', "liáo"),
+    ('鐑', "qiè"),
+    ('鐒', "láo"),
+    ('鐓', "duì"),
+    ('鐔', "xín"),
+    ('鐕', "zān"),
+    ('鐖', "jī"),
+    ('鐗', "jiǎn"),
+    ('鐘', "zhōng"),
+    ('鐙', "dèng"),
+    ('鐚', "yā"),
+    ('鐛', "yǐng"),
+    ('鐜', "duī"),
+    ('鐝', "jué"),
+    ('鐞', "nòu"),
+    ('鐟', "zān"),
+    ('鐠', "pǔ"),
+    ('鐡', "tiě"),
+    ('鐢', "fán"),
+    ('鐣', "chēng"),
+    ('鐤', "dǐng"),
+    ('鐥', "shàn"),
+    ('鐦', "kāi"),
+    ('鐧', "jiān"),
+    ('鐨', "fèi"),
+    ('鐩', "suì"),
+    ('鐪', "lǔ"),
+    ('鐫', "juān"),
+    ('鐬', "huì"),
+    ('鐭', "yù"),
+    ('鐮', "lián"),
+    ('鐯', "zhuó"),
+    ('鐰', "qiāo"),
+    ('鐱', "jiàn"),
+    ('鐲', "zhuó"),
+    ('鐳', "léi"),
+    ('鐴', "bì"),
+    ('鐵', "tiě"),
+    ('鐶', "huán"),
+    ('鐷', "yè"),
+    ('鐸', "duó"),
+    ('鐹', "guǒ"),
+    ('鐺', "dāng"),
+    ('鐻', "jù"),
+    ('鐼', "fén"),
+    ('鐽', "dá"),
+    ('鐾', "bèi"),
+    ('鐿', "yì"),
+    ('鑀', "ài"),
+    ('鑁', "zōng"),
+    ('鑂', "xùn"),
+    ('鑃', "diào"),
+    ('鑄', "zhù"),
+    ('鑅', "héng"),
+    ('鑆', "zhuì"),
+    ('鑇', "jī"),
+    ('鑈', "niè"),
+    ('鑉', "hé"),
+    ('鑊', "huò"),
+    ('鑋', "qīng"),
+    ('鑌', "bīn"),
+    ('鑍', "yīng"),
+    ('鑎', "kuì"),
+    ('鑏', "níng"),
+    ('鑐', "xū"),
+    ('鑑', "jiàn"),
+    ('鑒', "jiàn"),
+    ('鑓', "qiǎn"),
+    ('鑔', "chǎ"),
+    ('鑕', "zhì"),
+    ('鑖', "miè"),
+    ('鑗', "lí"),
+    ('鑘', "léi"),
+    ('鑙', "jī"),
+    ('鑚', "zuàn"),
+    ('鑛', "kuàng"),
+    ('鑜', "shǎng"),
+    ('鑝', "péng"),
+    ('鑞', "là"),
+    ('鑟', "dú"),
+    ('鑠', "shuò"),
+    ('鑡', "chuò"),
+    ('鑢', "lǜ"),
+    ('鑣', "biāo"),
+    ('鑤', "bào"),
+    ('鑥', "lǔ"),
+    ('鑦', "xian"),
+    ('鑧', "kuān"),
+    ('鑨', "lóng"),
+    ('鑩', "è"),
+    ('鑪', "lú"),
+    ('鑫', "xīn"),
+    ('鑬', "jiàn"),
+    ('鑭', "làn"),
+    ('鑮', "bó"),
+    ('鑯', "jiān"),
+    ('鑰', "yào"),
+    ('鑱', "chán"),
+    ('鑲', "xiāng"),
+    ('鑳', "jiàn"),
+    ('鑴', "xī"),
+    ('鑵', "guàn"),
+    ('鑶', "cáng"),
+    ('鑷', "niè"),
+    ('鑸', "lěi"),
+    ('鑹', "cuān"),
+    ('鑺', "qú"),
+    ('鑻', "pàn"),
+    ('鑼', "luó"),
+    ('鑽', "zuān"),
+    ('鑾', "luán"),
+    ('鑿', "záo"),
+    ('钀', "niè"),
+    ('钁', "jué"),
+    ('钂', "tǎng"),
+    ('钃', "zhú"),
+    ('钄', "lán"),
+    ('钅', "jīn"),
+    ('钆', "gá"),
+    ('钇', "yǐ"),
+    ('针', "zhēn"),
+    ('钉', "dīng,dìng"),
+    ('钊', "zhāo"),
+    ('钋', "pō"),
+    ('钌', "liǎo"),
+    ('钍', "tǔ"),
+    ('钎', "qiān"),
+    ('钏', "chuàn"),
+    ('钐', "shān"),
+    ('钑', "sà"),
+    ('钒', "fán"),
+    ('钓', "diào"),
+    ('钔', "mén"),
+    ('钕', "nǚ"),
+    ('钖', "yáng"),
+    ('钗', "chāi"),
+    ('钘', "xíng"),
+    ('钙', "gài"),
+    ('钚', "bù"),
+    ('钛', "tài"),
+    ('钜', "jù"),
+    ('钝', "dùn"),
+    ('钞', "chāo"),
+    ('钟', "zhōng"),
+    ('钠', "nà"),
+    ('钡', "bèi"),
+    ('钢', "gāng"),
+    ('钣', "bǎn"),
+    ('钤', "qián"),
+    ('钥', "yào,yuè"),
+    ('钦', "qīn"),
+    ('钧', "jūn"),
+    ('钨', "wū"),
+    ('钩', "gōu"),
+    ('钪', "kàng"),
+    ('钫', "fāng"),
+    ('钬', "huǒ"),
+    ('钭', "tǒu"),
+    ('钮', "niǔ"),
+    ('钯', "bǎ"),
+    ('钰', "yù"),
+    ('钱', "qián"),
+    ('钲', "zhēng"),
+    ('钳', "qián"),
+    ('钴', "gǔ"),
+    ('钵', "bō"),
+    ('钶', "kē"),
+    ('钷', "pǒ"),
+    ('钸', "bū"),
+    ('钹', "bó"),
+    ('钺', "yuè"),
+    ('钻', "zuān,zuàn"),
+    ('钼', "mù"),
+    ('钽', "tǎn"),
+    ('钾', "jiǎ"),
+    ('钿', "diàn"),
+    ('铀', "yóu"),
+    ('铁', "tiě"),
+    ('铂', "bó"),
+    ('铃', "líng"),
+    ('铄', "shuò"),
+    ('铅', "qiān"),
+    ('铆', "mǎo"),
+    ('铇', "bào"),
+    ('铈', "shì"),
+    ('铉', "xuàn"),
+    ('铊', "tā"),
+    ('铋', "bì"),
+    ('铌', "ní"),
+    ('铍', "pī"),
+    ('铎', "duó"),
+    ('铏', "xíng"),
+    ('铐', "kào"),
+    ('铑', "lǎo"),
+    ('铒', "ěr"),
+    ('铓', "máng"),
+    ('铔', "yā"),
+    ('铕', "yǒu"),
+    ('铖', "chéng"),
+    ('铗', "jiá"),
+    ('铘', "yé"),
+    ('铙', "náo"),
+    ('铚', "zhì"),
+    ('铛', "dāng"),
+    ('铜', "tóng"),
+    ('铝', "lǚ"),
+    ('铞', "diào"),
+    ('铟', "yīn"),
+    ('铠', "kǎi"),
+    ('铡', "zhá"),
+    ('铢', "zhū"),
+    ('铣', "xǐ"),
+    ('铤', "dìng"),
+    ('铥', "diū"),
+    ('铦', "xiān"),
+    ('铧', "huá"),
+    ('铨', "quán"),
+    ('铩', "shā"),
+    ('铪', "hā"),
+    ('铫', "diào"),
+    ('铬', "gè"),
+    ('铭', "míng"),
+    ('铮', "zhēng"),
+    ('铯', "sè"),
+    ('铰', "jiǎo"),
+    ('铱', "yī"),
+    ('铲', "chǎn"),
+    ('铳', "chòng"),
+    ('铴', "tāng"),
+    ('铵', "ǎn"),
+    ('银', "yín"),
+    ('铷', "rú"),
+    ('铸', "zhù"),
+    ('铹', "láo"),
+    ('铺', "pū,pù"),
+    ('铻', "wú"),
+    ('铼', "lái"),
+    ('铽', "tè"),
+    ('链', "liàn"),
+    ('铿', "kēng"),
+    ('销', "xiāo"),
+    ('锁', "suǒ"),
+    ('锂', "lǐ"),
+    ('锃', "zèng"),
+    ('锄', "chú"),
+    ('锅', "guō"),
+    ('锆', "gào"),
+    ('锇', "é"),
+    ('锈', "xiù"),
+    ('锉', "cuò"),
+    ('锊', "lüè"),
+    ('锋', "fēng"),
+    ('锌', "xīn"),
+    ('锍', "liǔ"),
+    ('锎', "kāi"),
+    ('锏', "jiǎn"),
+    ('锐', "ruì"),
+    ('锑', "tī"),
+    ('锒', "láng"),
+    ('锓', "qǐn"),
+    ('锔', "jū"),
+    ('锕', "ā"),
+    ('锖', "qiāng"),
+    ('锗', "zhě"),
+    ('锘', "nuò"),
+    ('错', "cuò"),
+    ('锚', "máo"),
+    ('锛', "bēn"),
+    ('锜', "qí"),
+    ('锝', "dé"),
+    ('锞', "kè"),
+    ('锟', "kūn"),
+    ('锠', "chāng"),
+    ('锡', "xī"),
+    ('锢', "gù"),
+    ('锣', "luó"),
+    ('锤', "chuí"),
+    ('锥', "zhuī"),
+    ('锦', "jǐn"),
+    ('锧', "zhì"),
+    ('锨', "xiān"),
+    ('锩', "juǎn"),
+    ('锪', "huō"),
+    ('锫', "péi"),
+    ('锬', "tán"),
+    ('锭', "dìng"),
+    ('键', "jiàn"),
+    ('锯', "jù"),
+    ('锰', "měng"),
+    ('锱', "zī"),
+    ('锲', "qiè"),
+    ('锳', "yīng"),
+    ('锴', "kǎi"),
+    ('锵', "qiāng"),
+    ('锶', "sī"),
+    ('锷', "è"),
+    ('锸', "chā"),
+    ('锹', "qiāo"),
+    ('锺', "zhōng"),
+    ('锻', "duàn"),
+    ('锼', "sōu"),
+    ('锽', "huáng"),
+    ('锾', "huán"),
+    ('锿', "āi"),
+    ('镀', "dù"),
+    ('镁', "měi"),
+    ('镂', "lòu"),
+    ('镃', "zī"),
+    ('镄', "fèi"),
+    ('镅', "méi"),
+    ('镆', "mò"),
+    ('镇', "zhèn"),
+    ('镈', "bó"),
+    ('镉', "gé"),
+    ('镊', "niè"),
+    ('镋', "tǎng"),
+    ('镌', "juān"),
+    ('镍', "niè"),
+    ('镎', "ná"),
+    ('镏', "liú"),
+    ('镐', "gǎo"),
+    ('镑', "bàng"),
+    ('镒', "yì"),
+    ('镓', "jiā"),
+    ('镔', "bīn"),
+    ('镕', "róng"),
+    ('镖', "biāo"),
+    ('镗', "tāng"),
+    ('镘', "màn"),
+    ('镙', "luó"),
+    ('镚', "bèng"),
+    ('镛', "yōng"),
+    ('镜', "jìng"),
+    ('镝', "dī"),
+    ('镞', "zú"),
+    ('镟', "xuàn"),
+    ('镠', "liú"),
+    ('镡', "chán"),
+    ('镢', "jué"),
+    ('镣', "liào"),
+    ('镤', "pú"),
+    ('镥', "lǔ"),
+    ('镦', "duì"),
+    ('镧', "lán"),
+    ('镨', "pǔ"),
+    ('镩', "cuān"),
+    ('镪', "qiāng"),
+    ('镫', "dèng"),
+    ('镬', "huò"),
+    ('镭', "léi"),
+    ('镮', "huán"),
+    ('镯', "zhuó"),
+    ('镰', "lián"),
+    ('镱', "yì"),
+    ('镲', "chǎ"),
+    ('镳', "biāo"),
+    ('镴', "là"),
+    ('镵', "chán"),
+    ('镶', "xiāng"),
+    ('長', "zhǎng"),
+    ('镸', "cháng"),
+    ('镹', "jiǔ"),
+    ('镺', "ǎo"),
+    ('镻', "dié"),
+    ('镼', "qū"),
+    ('镽', "liǎo"),
+    ('镾', "mí"),
+    ('长', "cháng,zhǎng"),
+    ('門', "mén"),
+    ('閁', "mà"),
+    ('閂', "shuān"),
+    ('閃', "shǎn"),
+    ('閄', "huò"),
+    ('閅', "mén"),
+    ('閆', "yán"),
+    ('閇', "bì"),
+    ('閈', "hàn"),
+    ('閉', "bì"),
+    ('閊', "shān"),
+    ('開', "kāi"),
+    ('閌', "kàng"),
+    ('閍', "bēng"),
+    ('閎', "hóng"),
+    ('閏', "rùn"),
+    ('閐', "sàn"),
+    ('閑', "xián"),
+    ('閒', "xián"),
+    ('間', "jiān"),
+    ('閔', "mǐn"),
+    ('閕', "xiā"),
+    ('閖', "shui"),
+    ('閗', "dòu"),
+    ('閘', "zhá"),
+    ('閙', "nào"),
+    ('閚', "zhān"),
+    ('閛', "pēng"),
+    ('閜', "xiǎ"),
+    ('閝', "líng"),
+    ('閞', "biàn"),
+    ('閟', "bì"),
+    ('閠', "rùn"),
+    ('閡', "ài"),
+    ('関', "guān"),
+    ('閣', "gé"),
+    ('閤', "gé"),
+    ('閥', "fá"),
+    ('閦', "chù"),
+    ('閧', "hòng"),
+    ('閨', "guī"),
+    ('閩', "mǐn"),
+    ('閪', "sē"),
+    ('閫', "kǔn"),
+    ('閬', "làng"),
+    ('閭', "lǘ"),
+    ('閮', "tíng"),
+    ('閯', "shà"),
+    ('閰', "jú"),
+    ('閱', "yuè"),
+    ('閲', "yuè"),
+    ('閳', "chǎn"),
+    ('閴', "qù"),
+    ('閵', "lìn"),
+    ('閶', "chāng"),
+    ('閷', "shài"),
+    ('閸', "kǔn"),
+    ('閹', "yān"),
+    ('閺', "wén"),
+    ('閻', "yán"),
+    ('閼', "è"),
+    ('閽', "hūn"),
+    ('閾', "yù"),
+    ('閿', "wén"),
+    ('闀', "hòng"),
+    ('闁', "bāo"),
+    ('闂', "hòng"),
+    ('闃', "qù"),
+    ('闄', "yǎo"),
+    ('闅', "wén"),
+    ('闆', "bǎn"),
+    ('闇', "àn"),
+    ('闈', "wéi"),
+    ('闉', "yīn"),
+    ('闊', "kuò"),
+    ('闋', "què"),
+    ('闌', "lán"),
+    ('闍', "dū"),
+    ('闎', "quán"),
+    ('闏', "fēng"),
+    ('闐', "tián"),
+    ('闑', "niè"),
+    ('闒', "tà"),
+    ('闓', "kǎi"),
+    ('闔', "hé"),
+    ('闕', "què"),
+    ('闖', "chuǎng"),
+    ('闗', "guān"),
+    ('闘', "dòu"),
+    ('闙', "qǐ"),
+    ('闚', "kuī"),
+    ('闛', "táng"),
+    ('關', "guān"),
+    ('闝', "piáo"),
+    ('闞', "kàn"),
+    ('闟', "xì"),
+    ('闠', "huì"),
+    ('闡', "chǎn"),
+    ('闢', "pì"),
+    ('闣', "dàng"),
+    ('闤', "huán"),
+    ('闥', "tà"),
+    ('闦', "wén"),
+    ('闧', "tā"),
+    ('门', "mén"),
+    ('闩', "shuān"),
+    ('闪', "shǎn"),
+    ('闫', "yán"),
+    ('闬', "hàn"),
+    ('闭', "bì"),
+    ('问', "wèn"),
+    ('闯', "chuǎng"),
+    ('闰', "rùn"),
+    ('闱', "wéi"),
+    ('闲', "xián"),
+    ('闳', "hóng"),
+    ('间', "jiān,jiàn"),
+    ('闵', "mǐn"),
+    ('闶', "kāng"),
+    ('闷', "mēn,mèn"),
+    ('闸', "zhá"),
+    ('闹', "nào"),
+    ('闺', "guī"),
+    ('闻', "wén"),
+    ('闼', "tà"),
+    ('闽', "mǐn"),
+    ('闾', "lǘ"),
+    ('闿', "kǎi"),
+    ('阀', "fá"),
+    ('阁', "gé"),
+    ('阂', "hé"),
+    ('阃', "kǔn"),
+    ('阄', "jiū"),
+    ('阅', "yuè"),
+    ('阆', "láng"),
+    ('阇', "dū"),
+    ('阈', "yù"),
+    ('阉', "yān"),
+    ('阊', "chāng"),
+    ('阋', "xì"),
+    ('阌', "wén"),
+    ('阍', "hūn"),
+    ('阎', "yán"),
+    ('阏', "è"),
+    ('阐', "chǎn"),
+    ('阑', "lán"),
+    ('阒', "qù"),
+    ('阓', "huì"),
+    ('阔', "kuò"),
+    ('阕', "què"),
+    ('阖', "hé"),
+    ('阗', "tián"),
+    ('阘', "dá"),
+    ('阙', "quē"),
+    ('阚', "hǎn"),
+    ('阛', "huán"),
+    ('阜', "fù"),
+    ('阝', "fù"),
+    ('阞', "lè"),
+    ('队', "duì"),
+    ('阠', "xìn"),
+    ('阡', "qiān"),
+    ('阢', "wù"),
+    ('阣', "gài"),
+    ('阤', "zhì"),
+    ('阥', "yīn"),
+    ('阦', "yáng"),
+    ('阧', "dǒu"),
+    ('阨', "è"),
+    ('阩', "shēng"),
+    ('阪', "bǎn"),
+    ('阫', "péi"),
+    ('阬', "kēng"),
+    ('阭', "yǔn"),
+    ('阮', "ruǎn"),
+    ('阯', "zhǐ"),
+    ('阰', "pí"),
+    ('阱', "jǐng"),
+    ('防', "fáng"),
+    ('阳', "yáng"),
+    ('阴', "yīn"),
+    ('阵', "zhèn"),
+    ('阶', "jiē"),
+    ('阷', "chēng"),
+    ('阸', "è"),
+    ('阹', "qū"),
+    ('阺', "dǐ"),
+    ('阻', "zǔ"),
+    ('阼', "zuò"),
+    ('阽', "diàn"),
+    ('阾', "lǐng"),
+    ('阿', "ā"),
+    ('陀', "tuó"),
+    ('陁', "tuó"),
+    ('陂', "bēi"),
+    ('陃', "bǐng"),
+    ('附', "fù"),
+    ('际', "jì"),
+    ('陆', "lù"),
+    ('陇', "lǒng"),
+    ('陈', "chén"),
+    ('陉', "xíng"),
+    ('陊', "duò"),
+    ('陋', "lòu"),
+    ('陌', "mò"),
+    ('降', "jiàng,xiáng"),
+    ('陎', "shū"),
+    ('陏', "duò"),
+    ('限', "xiàn"),
+    ('陑', "ér"),
+    ('陒', "guǐ"),
+    ('陓', "yū"),
+    ('陔', "gāi"),
+    ('陕', "shǎn"),
+    ('陖', "jùn"),
+    ('陗', "qiào"),
+    ('陘', "xíng"),
+    ('陙', "chún"),
+    ('陚', "fù"),
+    ('陛', "bì"),
+    ('陜', "xiá"),
+    ('陝', "shǎn"),
+    ('陞', "shēng"),
+    ('陟', "zhì"),
+    ('陠', "pū"),
+    ('陡', "dǒu"),
+    ('院', "yuàn"),
+    ('陣', "zhèn"),
+    ('除', "chú"),
+    ('陥', "xiàn"),
+    ('陦', "dǎo"),
+    ('陧', "niè"),
+    ('陨', "yǔn"),
+    ('险', "xiǎn"),
+    ('陪', "péi"),
+    ('陫', "fèi"),
+    ('陬', "zōu"),
+    ('陭', "yì"),
+    ('陮', "duì"),
+    ('陯', "lún"),
+    ('陰', "yīn"),
+    ('陱', "jū"),
+    ('陲', "chuí"),
+    ('陳', "chén"),
+    ('陴', "pí"),
+    ('陵', "líng"),
+    ('陶', "táo"),
+    ('陷', "xiàn"),
+    ('陸', "lù"),
+    ('陹', "shēng"),
+    ('険', "xiǎn"),
+    ('陻', "yīn"),
+    ('陼', "zhǔ"),
+    ('陽', "yáng"),
+    ('陾', "réng"),
+    ('陿', "xiá"),
+    ('隀', "chóng"),
+    ('隁', "yàn"),
+    ('隂', "yīn"),
+    ('隃', "shù"),
+    ('隄', "dī"),
+    ('隅', "yú"),
+    ('隆', "lóng"),
+    ('隇', "wēi"),
+    ('隈', "wēi"),
+    ('隉', "niè"),
+    ('隊', "duì"),
+    ('隋', "suí"),
+    ('隌', "ǎn"),
+    ('隍', "huáng"),
+    ('階', "jiē"),
+    ('随', "suí"),
+    ('隐', "yǐn"),
+    ('隑', "gài"),
+    ('隒', "yǎn"),
+    ('隓', "huī"),
+    ('隔', "gé"),
+    ('隕', "yǔn"),
+    ('隖', "wù"),
+    ('隗', "kuí"),
+    ('隘', "ài"),
+    ('隙', "xì"),
+    ('隚', "táng"),
+    ('際', "jì"),
+    ('障', "zhàng"),
+    ('隝', "dǎo"),
+    ('隞', "áo"),
+    ('隟', "xì"),
+    ('隠', "yǐn"),
+    ('隡', "sà"),
+    ('隢', "rǎo"),
+    ('隣', "lín"),
+    ('隤', "tuí"),
+    ('隥', "dèng"),
+    ('隦', "jiǎo"),
+    ('隧', "suì"),
+    ('隨', "suí"),
+    ('隩', "ào"),
+    ('險', "xiǎn"),
+    ('隫', "fén"),
+    ('隬', "nǐ"),
+    ('隭', "ér"),
+    ('隮', "jī"),
+    ('隯', "dǎo"),
+    ('隰', "xí"),
+    ('隱', "yǐn"),
+    ('隲', "zhì"),
+    ('隳', "huī"),
+    ('隴', "lǒng"),
+    ('隵', "xī"),
+    ('隶', "lì"),
+    ('隷', "lì"),
+    ('隸', "lì"),
+    ('隹', "zhuī"),
+    ('隺', "hú"),
+    ('隻', "zhī"),
+    ('隼', "sǔn"),
+    ('隽', "juàn"),
+    ('难', "nán,nàn"),
+    ('隿', "yì"),
+    ('雀', "què,qiāo,qiǎo"),
+    ('雁', "yàn"),
+    ('雂', "qín"),
+    ('雃', "qiān"),
+    ('雄', "xióng"),
+    ('雅', "yǎ"),
+    ('集', "jí"),
+    ('雇', "gù"),
+    ('雈', "huán"),
+    ('雉', "zhì"),
+    ('雊', "gòu"),
+    ('雋', "juàn"),
+    ('雌', "cí"),
+    ('雍', "yōng"),
+    ('雎', "jū"),
+    ('雏', "chú"),
+    ('雐', "hū"),
+    ('雑', "zá"),
+    ('雒', "luò"),
+    ('雓', "yú"),
+    ('雔', "chóu"),
+    ('雕', "diāo"),
+    ('雖', "suī"),
+    ('雗', "hàn"),
+    ('雘', "wò"),
+    ('雙', "shuāng"),
+    ('雚', "guàn"),
+    ('雛', "chú"),
+    ('雜', "zá"),
+    ('雝', "yōng"),
+    ('雞', "jī"),
+    ('雟', "xī"),
+    ('雠', "chóu"),
+    ('雡', "liù"),
+    ('離', "lí"),
+    ('難', "nán"),
+    ('雤', "xué"),
+    ('雥', "zá"),
+    ('雦', "jí"),
+    ('雧', "jí"),
+    ('雨', "yǔ"),
+    ('雩', "yú"),
+    ('雪', "xuě"),
+    ('雫', "nǎ"),
+    ('雬', "fǒu"),
+    ('雭', "sè"),
+    ('雮', "mù"),
+    ('雯', "wén"),
+    ('雰', "fēn"),
+    ('雱', "pāng"),
+    ('雲', "yún"),
+    ('雳', "lì"),
+    ('雴', "chì"),
+    ('雵', "yāng"),
+    ('零', "líng"),
+    ('雷', "léi"),
+    ('雸', "án"),
+    ('雹', "báo"),
+    ('雺', "wù"),
+    ('電', "diàn"),
+    ('雼', "dàng"),
+    ('雽', "hù"),
+    ('雾', "wù"),
+    ('雿', "diào"),
+    ('需', "xū"),
+    ('霁', "jì"),
+    ('霂', "mù"),
+    ('霃', "chén"),
+    ('霄', "xiāo"),
+    ('霅', "zhà"),
+    ('霆', "tíng"),
+    ('震', "zhèn"),
+    ('霈', "pèi"),
+    ('霉', "méi"),
+    ('霊', "líng"),
+    ('霋', "qī"),
+    ('霌', "zhōu"),
+    ('霍', "huò"),
+    ('霎', "shà"),
+    ('霏', "fēi"),
+    ('霐', "hóng"),
+    ('霑', "zhān"),
+    ('霒', "yīn"),
+    ('霓', "ní"),
+    ('霔', "zhù"),
+    ('霕', "tún"),
+    ('霖', "lín"),
+    ('霗', "líng"),
+    ('霘', "dòng"),
+    ('霙', "yīng"),
+    ('霚', "wù"),
+    ('霛', "líng"),
+    ('霜', "shuāng"),
+    ('霝', "líng"),
+    ('霞', "xiá"),
+    ('霟', "hóng"),
+    ('霠', "yīn"),
+    ('霡', "mài"),
+    ('霢', "mài"),
+    ('霣', "yǔn"),
+    ('霤', "liù"),
+    ('霥', "mèng"),
+    ('霦', "bīn"),
+    ('霧', "wù"),
+    ('霨', "wèi"),
+    ('霩', "kuò"),
+    ('霪', "yín"),
+    ('霫', "xí"),
+    ('霬', "yì"),
+    ('霭', "ǎi"),
+    ('霮', "dàn"),
+    ('霯', "tèng"),
+    ('霰', "xiàn"),
+    ('霱', "yù"),
+    ('露', "lù,lòu"),
+    ('霳', "lóng"),
+    ('霴', "dài"),
+    ('霵', "jí"),
+    ('霶', "pāng"),
+    ('霷', "yáng"),
+    ('霸', "bà"),
+    ('霹', "pī"),
+    ('霺', "wéi"),
+    ('霻', "fēng"),
+    ('霼', "xì"),
+    ('霽', "jì"),
+    ('霾', "mái"),
+    ('霿', "méng"),
+    ('靀', "méng"),
+    ('靁', "léi"),
+    ('靂', "lì"),
+    ('靃', "huò"),
+    ('靄', "ǎi"),
+    ('靅', "fèi"),
+    ('靆', "dài"),
+    ('靇', "lóng"),
+    ('靈', "líng"),
+    ('靉', "ài"),
+    ('靊', "fēng"),
+    ('靋', "lì"),
+    ('靌', "bǎo"),
+    ('靍', "hè"),
+    ('靎', "hè"),
+    ('靏', "hè"),
+    ('靐', "bìng"),
+    ('靑', "qīng"),
+    ('青', "qīng"),
+    ('靓', "jìng"),
+    ('靔', "tiān"),
+    ('靕', "zhēn"),
+    ('靖', "jìng"),
+    ('靗', "chēng"),
+    ('靘', "qìng"),
+    ('静', "jìng"),
+    ('靚', "jìng"),
+    ('靛', "diàn"),
+    ('靜', "jìng"),
+    ('靝', "tiān"),
+    ('非', "fēi"),
+    ('靟', "fēi"),
+    ('靠', "kào"),
+    ('靡', "mí"),
+    ('面', "miàn"),
+    ('靣', "miàn"),
+    ('靤', "bào"),
+    ('靥', "yè"),
+    ('靦', "tiǎn"),
+    ('靧', "huì"),
+    ('靨', "yè"),
+    ('革', "gé"),
+    ('靪', "dīng"),
+    ('靫', "chá"),
+    ('靬', "qián"),
+    ('靭', "rèn"),
+    ('靮', "dí"),
+    ('靯', "dù"),
+    ('靰', "wù"),
+    ('靱', "rèn"),
+    ('靲', "qín"),
+    ('靳', "jìn"),
+    ('靴', "xuē"),
+    ('靵', "niǔ"),
+    ('靶', "bǎ"),
+    ('靷', "yǐn"),
+    ('靸', "sǎ"),
+    ('靹', "nà"),
+    ('靺', "mò"),
+    ('靻', "zǔ"),
+    ('靼', "dá"),
+    ('靽', "bàn"),
+    ('靾', "yì"),
+    ('靿', "yào"),
+    ('鞀', "táo"),
+    ('鞁', "bèi"),
+    ('鞂', "jiē"),
+    ('鞃', "hóng"),
+    ('鞄', "páo"),
+    ('鞅', "yāng"),
+    ('鞆', "bǐng"),
+    ('鞇', "yīn"),
+    ('鞈', "gé"),
+    ('鞉', "táo"),
+    ('鞊', "jié"),
+    ('鞋', "xié"),
+    ('鞌', "ān"),
+    ('鞍', "ān"),
+    ('鞎', "hén"),
+    ('鞏', "gǒng"),
+    ('鞐', "qiǎ"),
+    ('鞑', "dá"),
+    ('鞒', "qiáo"),
+    ('鞓', "tīng"),
+    ('鞔', "mán"),
+    ('鞕', "yìng"),
+    ('鞖', "suī"),
+    ('鞗', "tiáo"),
+    ('鞘', "qiào"),
+    ('鞙', "xuàn"),
+    ('鞚', "kòng"),
+    ('鞛', "běng"),
+    ('鞜', "tà"),
+    ('鞝', "shàng"),
+    ('鞞', "bǐng"),
+    ('鞟', "kuò"),
+    ('鞠', "jū"),
+    ('鞡', "la"),
+    ('鞢', "xiè"),
+    ('鞣', "róu"),
+    ('鞤', "bāng"),
+    ('鞥', "ēng"),
+    ('鞦', "qiū"),
+    ('鞧', "qiū"),
+    ('鞨', "hé"),
+    ('鞩', "qiào"),
+    ('鞪', "mù"),
+    ('鞫', "jū"),
+    ('鞬', "jiān"),
+    ('鞭', "biān"),
+    ('鞮', "dī"),
+    ('鞯', "jiān"),
+    ('鞰', "wēn"),
+    ('鞱', "tāo"),
+    ('鞲', "gōu"),
+    ('鞳', "tà"),
+    ('鞴', "bèi"),
+    ('鞵', "xié"),
+    ('鞶', "pán"),
+    ('鞷', "gé"),
+    ('鞸', "bì"),
+    ('鞹', "kuò"),
+    ('鞺', "tāng"),
+    ('鞻', "lóu"),
+    ('鞼', "guì"),
+    ('鞽', "qiáo"),
+    ('鞾', "xuē"),
+    ('鞿', "jī"),
+    ('韀', "jiān"),
+    ('韁', "jiāng"),
+    ('韂', "chàn"),
+    ('韃', "dá"),
+    ('韄', "hù"),
+    ('韅', "xiǎn"),
+    ('韆', "qiān"),
+    ('韇', "dú"),
+    ('韈', "wà"),
+    ('韉', "jiān"),
+    ('韊', "lán"),
+    ('韋', "wéi"),
+    ('韌', "rèn"),
+    ('韍', "fú"),
+    ('韎', "mèi"),
+    ('韏', "quàn"),
+    ('韐', "gé"),
+    ('韑', "wěi"),
+    ('韒', "qiào"),
+    ('韓', "hán"),
+    ('韔', "chàng"),
+    ('韕', "kuò"),
+    ('韖', "rǒu"),
+    ('韗', "yùn"),
+    ('韘', "shè"),
+    ('韙', "wěi"),
+    ('韚', "gé"),
+    ('韛', "bài"),
+    ('韜', "tāo"),
+    ('韝', "gōu"),
+    ('韞', "yùn"),
+    ('韟', "gāo"),
+    ('韠', "bì"),
+    ('韡', "wěi"),
+    ('韢', "suì"),
+    ('韣', "dú"),
+    ('韤', "wà"),
+    ('韥', "dú"),
+    ('韦', "wéi"),
+    ('韧', "rèn"),
+    ('韨', "fú"),
+    ('韩', "hán"),
+    ('韪', "wěi"),
+    ('韫', "yùn"),
+    ('韬', "tāo"),
+    ('韭', "jiǔ"),
+    ('韮', "jiǔ"),
+    ('韯', "xiān"),
+    ('韰', "xiè"),
+    ('韱', "xiān"),
+    ('韲', "jī"),
+    ('音', "yīn"),
+    ('韴', "zá"),
+    ('韵', "yùn"),
+    ('韶', "sháo"),
+    ('韷', "lè"),
+    ('韸', "péng"),
+    ('韹', "huáng"),
+    ('韺', "yīng"),
+    ('韻', "yùn"),
+    ('韼', "péng"),
+    ('韽', "ān"),
+    ('韾', "yīn"),
+    ('響', "xiǎng"),
+    ('頀', "hù"),
+    ('頁', "yè"),
+    ('頂', "dǐng"),
+    ('頃', "qǐng"),
+    ('頄', "kuí"),
+    ('項', "xiàng"),
+    ('順', "shùn"),
+    ('頇', "hān"),
+    ('須', "xū"),
+    ('頉', "yí"),
+    ('頊', "xū"),
+    ('頋', "ě"),
+    ('頌', "sòng"),
+    ('頍', "kuǐ"),
+    ('頎', "qí"),
+    ('頏', "háng"),
+    ('預', "yù"),
+    ('頑', "wán"),
+    ('頒', "bān"),
+    ('頓', "dùn"),
+    ('頔', "dí"),
+    ('頕', "dān"),
+    ('頖', "pàn"),
+    ('頗', "pō"),
+    ('領', "lǐng"),
+    ('頙', "chè"),
+    ('頚', "jǐng"),
+    ('頛', "lèi"),
+    ('頜', "hé"),
+    ('頝', "qiāo"),
+    ('頞', "è"),
+    ('頟', "é"),
+    ('頠', "wěi"),
+    ('頡', "xié"),
+    ('頢', "kuò"),
+    ('頣', "shěn"),
+    ('頤', "yí"),
+    ('頥', "yí"),
+    ('頦', "hái"),
+    ('頧', "duǐ"),
+    ('頨', "yǔ"),
+    ('頩', "pīng"),
+    ('頪', "lèi"),
+    ('頫', "fǔ"),
+    ('頬', "jiá"),
+    ('頭', "tóu"),
+    ('頮', "huì"),
+    ('頯', "kuí"),
+    ('頰', "jiá"),
+    ('頱', "luō"),
+    ('頲', "tǐng"),
+    ('頳', "chēng"),
+    ('頴', "yǐng"),
+    ('頵', "yūn"),
+    ('頶', "hú"),
+    ('頷', "hàn"),
+    ('頸', "jǐng"),
+    ('頹', "tuí"),
+    ('頺', "tuí"),
+    ('頻', "pín"),
+    ('頼', "lài"),
+    ('頽', "tuí"),
+    ('頾', "zī"),
+    ('頿', "zī"),
+    ('顀', "chuí"),
+    ('顁', "dìng"),
+    ('顂', "lài"),
+    ('顃', "tán"),
+    ('顄', "hàn"),
+    ('顅', "qiān"),
+    ('顆', "kē"),
+    ('顇', "cuì"),
+    ('顈', "xuǎn"),
+    ('顉', "qīn"),
+    ('顊', "yí"),
+    ('顋', "sāi"),
+    ('題', "tí"),
+    ('額', "é"),
+    ('顎', "è"),
+    ('顏', "yán"),
+    ('顐', "wèn"),
+    ('顑', "kǎn"),
+    ('顒', "yóng"),
+    ('顓', "zhuān"),
+    ('顔', "yán"),
+    ('顕', "xiǎn"),
+    ('顖', "xìn"),
+    ('顗', "yǐ"),
+    ('願', "yuàn"),
+    ('顙', "sǎng"),
+    ('顚', "diān"),
+    ('顛', "diān"),
+    ('顜', "jiǎng"),
+    ('顝', "kuī"),
+    ('類', "lèi"),
+    ('顟', "láo"),
+    ('顠', "piǎo"),
+    ('顡', "wài"),
+    ('顢', "mán"),
+    ('顣', "cù"),
+    ('顤', "yáo"),
+    ('顥', "hào"),
+    ('顦', "qiáo"),
+    ('顧', "gù"),
+    ('顨', "xùn"),
+    ('顩', "yǎn"),
+    ('顪', "huì"),
+    ('顫', "chàn"),
+    ('顬', "rú"),
+    ('顭', "méng"),
+    ('顮', "bīn"),
+    ('顯', "xiǎn"),
+    ('顰', "pín"),
+    ('顱', "lú"),
+    ('顲', "lǎn"),
+    ('顳', "niè"),
+    ('顴', "quán"),
+    ('页', "yè"),
+    ('顶', "dǐng"),
+    ('顷', "qǐng"),
+    ('顸', "hān"),
+    ('项', "xiàng"),
+    ('顺', "shùn"),
+    ('须', "xū"),
+    ('顼', "xū"),
+    ('顽', "wán"),
+    ('顾', "gù"),
+    ('顿', "dùn"),
+    ('颀', "qí"),
+    ('颁', "bān"),
+    ('颂', "sòng"),
+    ('颃', "háng"),
+    ('预', "yù"),
+    ('颅', "lú"),
+    ('领', "lǐng"),
+    ('颇', "pǒ"),
+    ('颈', "jǐng,gěng"),
+    ('颉', "jié"),
+    ('颊', "jiá"),
+    ('颋', "tǐng"),
+    ('颌', "hé"),
+    ('颍', "yǐng"),
+    ('颎', "jiǒng"),
+    ('颏', "kē"),
+    ('颐', "yí"),
+    ('频', "pín"),
+    ('颒', "huì"),
+    ('颓', "tuí"),
+    ('颔', "hàn"),
+    ('颕', "yǐng"),
+    ('颖', "yǐng"),
+    ('颗', "kē"),
+    ('题', "tí"),
+    ('颙', "yóng"),
+    ('颚', "è"),
+    ('颛', "zhuān"),
+    ('颜', "yán"),
+    ('额', "é"),
+    ('颞', "niè"),
+    ('颟', "mān"),
+    ('颠', "diān"),
+    ('颡', "sǎng"),
+    ('颢', "hào"),
+    ('颣', "lèi"),
+    ('颤', "chàn,zhàn"),
+    ('颥', "rú"),
+    ('颦', "pín"),
+    ('颧', "quán"),
+    ('風', "fēng"),
+    ('颩', "biāo"),
+    ('颪', "guā"),
+    ('颫', "fú"),
+    ('颬', "xiā"),
+    ('颭', "zhǎn"),
+    ('颮', "biāo"),
+    ('颯', "sà"),
+    ('颰', "bá"),
+    ('颱', "tái"),
+    ('颲', "liè"),
+    ('颳', "guā"),
+    ('颴', "xuàn"),
+    ('颵', "shāo"),
+    ('颶', "jù"),
+    ('颷', "biāo"),
+    ('颸', "sī"),
+    ('颹', "wěi"),
+    ('颺', "yáng"),
+    ('颻', "yáo"),
+    ('颼', "sōu"),
+    ('颽', "kǎi"),
+    ('颾', "sōu"),
+    ('颿', "fān"),
+    ('飀', "liú"),
+    ('飁', "xí"),
+    ('飂', "liù"),
+    ('飃', "piāo"),
+    ('飄', "piāo"),
+    ('飅', "liú"),
+    ('飆', "biāo"),
+    ('飇', "biāo"),
+    ('飈', "biāo"),
+    ('飉', "liáo"),
+    ('飊', "biāo"),
+    ('飋', "sè"),
+    ('飌', "fēng"),
+    ('飍', "xiū"),
+    ('风', "fēng"),
+    ('飏', "yáng"),
+    ('飐', "zhǎn"),
+    ('飑', "biāo"),
+    ('飒', "sà"),
+    ('飓', "jù"),
+    ('飔', "sī"),
+    ('飕', "sōu"),
+    ('飖', "yáo"),
+    ('飗', "liú"),
+    ('飘', "piāo"),
+    ('飙', "biāo"),
+    ('飚', "biāo"),
+    ('飛', "fēi"),
+    ('飜', "fān"),
+    ('飝', "fēi"),
+    ('飞', "fēi"),
+    ('食', "shí"),
+    ('飠', "shí"),
+    ('飡', "cān"),
+    ('飢', "jī"),
+    ('飣', "dìng"),
+    ('飤', "sì"),
+    ('飥', "tuō"),
+    ('飦', "zhān"),
+    ('飧', "sūn"),
+    ('飨', "xiǎng"),
+    ('飩', "tún"),
+    ('飪', "rèn"),
+    ('飫', "yù"),
+    ('飬', "juàn"),
+    ('飭', "chì"),
+    ('飮', "yǐn"),
+    ('飯', "fàn"),
+    ('飰', "fàn"),
+    ('飱', "sūn"),
+    ('飲', "yǐn"),
+    ('飳', "tǒu"),
+    ('飴', "yí"),
+    ('飵', "zuò"),
+    ('飶', "bì"),
+    ('飷', "jiě"),
+    ('飸', "tāo"),
+    ('飹', "bǎo"),
+    ('飺', "cí"),
+    ('飻', "tiè"),
+    ('飼', "sì"),
+    ('飽', "bǎo"),
+    ('飾', "shì"),
+    ('飿', "duò"),
+    ('餀', "hài"),
+    ('餁', "rèn"),
+    ('餂', "tiǎn"),
+    ('餃', "jiǎo"),
+    ('餄', "jiá"),
+    ('餅', "bǐng"),
+    ('餆', "yáo"),
+    ('餇', "tóng"),
+    ('餈', "cí"),
+    ('餉', "xiǎng"),
+    ('養', "yǎng"),
+    ('餋', "juàn"),
+    ('餌', "ěr"),
+    ('餍', "yàn"),
+    ('餎', "le"),
+    ('餏', "xī"),
+    ('餐', "cān"),
+    ('餑', "bō"),
+    ('餒', "něi"),
+    ('餓', "è"),
+    ('餔', "bù"),
+    ('餕', "jùn"),
+    ('餖', "dòu"),
+    ('餗', "sù"),
+    ('餘', "yú"),
+    ('餙', "shì"),
+    ('餚', "yáo"),
+    ('餛', "hún"),
+    ('餜', "guǒ"),
+    ('餝', "shì"),
+    ('餞', "jiàn"),
+    ('餟', "zhuì"),
+    ('餠', "bǐng"),
+    ('餡', "xiàn"),
+    ('餢', "bù"),
+    ('餣', "yè"),
+    ('餤', "tán"),
+    ('餥', "fēi"),
+    ('餦', "zhāng"),
+    ('餧', "wèi"),
+    ('館', "guǎn"),
+    ('餩', "è"),
+    ('餪', "nuǎn"),
+    ('餫', "yùn"),
+    ('餬', "hú"),
+    ('餭', "huáng"),
+    ('餮', "tiè"),
+    ('餯', "huì"),
+    ('餰', "jiān"),
+    ('餱', "hóu"),
+    ('餲', "ài"),
+    ('餳', "táng"),
+    ('餴', "fēn"),
+    ('餵', "wèi"),
+    ('餶', "gǔ"),
+    ('餷', "chā"),
+    ('餸', "sòng"),
+    ('餹', "táng"),
+    ('餺', "bó"),
+    ('餻', "gāo"),
+    ('餼', "xì"),
+    ('餽', "kuì"),
+    ('餾', "liù"),
+    ('餿', "sōu"),
+    ('饀', "táo"),
+    ('饁', "yè"),
+    ('饂', "wēn"),
+    ('饃', "mó"),
+    ('饄', "táng"),
+    ('饅', "mán"),
+    ('饆', "bì"),
+    ('饇', "yù"),
+    ('饈', "xiū"),
+    ('饉', "jǐn"),
+    ('饊', "sǎn"),
+    ('饋', "kuì"),
+    ('饌', "zhuàn"),
+    ('饍', "shàn"),
+    ('饎', "chì"),
+    ('饏', "dàn"),
+    ('饐', "yì"),
+    ('饑', "jī"),
+    ('饒', "ráo"),
+    ('饓', "chēng"),
+    ('饔', "yōng"),
+    ('饕', "tāo"),
+    ('饖', "wèi"),
+    ('饗', "xiǎng"),
+    ('饘', "zhān"),
+    ('饙', "fēn"),
+    ('饚', "hài"),
+    ('饛', "méng"),
+    ('饜', "yàn"),
+    ('饝', "mó"),
+    ('饞', "chán"),
+    ('饟', "xiǎng"),
+    ('饠', "luó"),
+    ('饡', "zàn"),
+    ('饢', "náng"),
+    ('饣', "shí"),
+    ('饤', "dìng"),
+    ('饥', "jī"),
+    ('饦', "tuō"),
+    ('饧', "táng"),
+    ('饨', "tún"),
+    ('饩', "xì"),
+    ('饪', "rèn"),
+    ('饫', "yù"),
+    ('饬', "chì"),
+    ('饭', "fàn"),
+    ('饮', "yǐn,yìn"),
+    ('饯', "jiàn"),
+    ('饰', "shì"),
+    ('饱', "bǎo"),
+    ('饲', "sì"),
+    ('饳', "duò"),
+    ('饴', "yí"),
+    ('饵', "ěr"),
+    ('饶', "ráo"),
+    ('饷', "xiǎng"),
+    ('饸', "hé"),
+    ('饹', "le"),
+    ('饺', "jiǎo"),
+    ('饻', "xī"),
+    ('饼', "bǐng"),
+    ('饽', "bō"),
+    ('饾', "dòu"),
+    ('饿', "è"),
+    ('馀', "yú"),
+    ('馁', "něi"),
+    ('馂', "jùn"),
+    ('馃', "guǒ"),
+    ('馄', "hún"),
+    ('馅', "xiàn"),
+    ('馆', "guǎn"),
+    ('馇', "chā"),
+    ('馈', "kuì"),
+    ('馉', "gǔ"),
+    ('馊', "sōu"),
+    ('馋', "chán"),
+    ('馌', "yè"),
+    ('馍', "mó"),
+    ('馎', "bó"),
+    ('馏', "liú"),
+    ('馐', "xiū"),
+    ('馑', "jǐn"),
+    ('馒', "mán"),
+    ('馓', "sǎn"),
+    ('馔', "zhuàn"),
+    ('馕', "náng"),
+    ('首', "shǒu"),
+    ('馗', "kuí"),
+    ('馘', "guó"),
+    ('香', "xiāng"),
+    ('馚', "fén"),
+    ('馛', "bó"),
+    ('馜', "nǐ"),
+    ('馝', "bì"),
+    ('馞', "bó"),
+    ('馟', "tú"),
+    ('馠', "hān"),
+    ('馡', "fēi"),
+    ('馢', "jiān"),
+    ('馣', "ān"),
+    ('馤', "ài"),
+    ('馥', "fù"),
+    ('馦', "xiān"),
+    ('馧', "yūn"),
+    ('馨', "xīn"),
+    ('馩', "fén"),
+    ('馪', "pīn"),
+    ('馫', "xīn"),
+    ('馬', "mǎ"),
+    ('馭', "yù"),
+    ('馮', "féng"),
+    ('馯', "hàn"),
+    ('馰', "dí"),
+    ('馱', "tuó"),
+    ('馲', "zhé"),
+    ('馳', "chí"),
+    ('馴', "xún"),
+    ('馵', "zhù"),
+    ('馶', "zhī"),
+    ('馷', "pèi"),
+    ('馸', "xìn"),
+    ('馹', "rì"),
+    ('馺', "sà"),
+    ('馻', "yǔn"),
+    ('馼', "wén"),
+    ('馽', "zhí"),
+    ('馾', "dàn"),
+    ('馿', "lǘ"),
+    ('駀', "yóu"),
+    ('駁', "bó"),
+    ('駂', "bǎo"),
+    ('駃', "jué"),
+    ('駄', "tuó"),
+    ('駅', "yì"),
+    ('駆', "qū"),
+    ('駇', "wén"),
+    ('駈', "qū"),
+    ('駉', "jiōng"),
+    ('駊', "pǒ"),
+    ('駋', "zhāo"),
+    ('駌', "yuān"),
+    ('駍', "péi"),
+    ('駎', "zhòu"),
+    ('駏', "jù"),
+    ('駐', "zhù"),
+    ('駑', "nú"),
+    ('駒', "jū"),
+    ('駓', "pī"),
+    ('駔', "zǎng"),
+    ('駕', "jià"),
+    ('駖', "líng"),
+    ('駗', "zhěn"),
+    ('駘', "tái"),
+    ('駙', "fù"),
+    ('駚', "yǎng"),
+    ('駛', "shǐ"),
+    ('駜', "bì"),
+    ('駝', "tuó"),
+    ('駞', "tuó"),
+    ('駟', "sì"),
+    ('駠', "liú"),
+    ('駡', "mà"),
+    ('駢', "pián"),
+    ('駣', "táo"),
+    ('駤', "zhì"),
+    ('駥', "róng"),
+    ('駦', "téng"),
+    ('駧', "dòng"),
+    ('駨', "xūn"),
+    ('駩', "quān"),
+    ('駪', "shēn"),
+    ('駫', "jiōng"),
+    ('駬', "ěr"),
+    ('駭', "hài"),
+    ('駮', "bó"),
+    ('駯', "zhū"),
+    ('駰', "yīn"),
+    ('駱', "luò"),
+    ('駲', "zhōu"),
+    ('駳', "dàn"),
+    ('駴', "hài"),
+    ('駵', "liú"),
+    ('駶', "jú"),
+    ('駷', "sǒng"),
+    ('駸', "qīn"),
+    ('駹', "máng"),
+    ('駺', "láng"),
+    ('駻', "hàn"),
+    ('駼', "tú"),
+    ('駽', "xuān"),
+    ('駾', "tuì"),
+    ('駿', "jùn"),
+    ('騀', "ě"),
+    ('騁', "chěng"),
+    ('騂', "xīng"),
+    ('騃', "ái"),
+    ('騄', "lù"),
+    ('騅', "zhuī"),
+    ('騆', "zhōu"),
+    ('騇', "shè"),
+    ('騈', "pián"),
+    ('騉', "kūn"),
+    ('騊', "táo"),
+    ('騋', "lái"),
+    ('騌', "zōng"),
+    ('騍', "kè"),
+    ('騎', "qí"),
+    ('騏', "qí"),
+    ('騐', "yàn"),
+    ('騑', "fēi"),
+    ('騒', "sāo"),
+    ('験', "yàn"),
+    ('騔', "gé"),
+    ('騕', "yǎo"),
+    ('騖', "wù"),
+    ('騗', "piàn"),
+    ('騘', "cōng"),
+    ('騙', "piàn"),
+    ('騚', "qián"),
+    ('騛', "fēi"),
+    ('騜', "huáng"),
+    ('騝', "qián"),
+    ('騞', "huō"),
+    ('騟', "yú"),
+    ('騠', "tí"),
+    ('騡', "quán"),
+    ('騢', "xiá"),
+    ('騣', "zōng"),
+    ('騤', "kuí"),
+    ('騥', "róu"),
+    ('騦', "sī"),
+    ('騧', "guā"),
+    ('騨', "tuó"),
+    ('騩', "guī"),
+    ('騪', "sōu"),
+    ('騫', "qiān"),
+    ('騬', "chéng"),
+    ('騭', "zhì"),
+    ('騮', "liú"),
+    ('騯', "péng"),
+    ('騰', "téng"),
+    ('騱', "xí"),
+    ('騲', "cǎo"),
+    ('騳', "dú"),
+    ('騴', "yàn"),
+    ('騵', "yuán"),
+    ('騶', "zōu"),
+    ('騷', "sāo"),
+    ('騸', "shàn"),
+    ('騹', "qí"),
+    ('騺', "zhì"),
+    ('騻', "shuāng"),
+    ('騼', "lù"),
+    ('騽', "xí"),
+    ('騾', "luó"),
+    ('騿', "zhāng"),
+    ('驀', "mò"),
+    ('驁', "ào"),
+    ('驂', "cān"),
+    ('驃', "biāo"),
+    ('驄', "cōng"),
+    ('驅', "qū"),
+    ('驆', "bì"),
+    ('驇', "zhì"),
+    ('驈', "yù"),
+    ('驉', "xū"),
+    ('驊', "huá"),
+    ('驋', "bō"),
+    ('驌', "sù"),
+    ('驍', "xiāo"),
+    ('驎', "lín"),
+    ('驏', "zhàn"),
+    ('驐', "dūn"),
+    ('驑', "liú"),
+    ('驒', "tuó"),
+    ('驓', "céng"),
+    ('驔', "diàn"),
+    ('驕', "jiāo"),
+    ('驖', "tiě"),
+    ('驗', "yàn"),
+    ('驘', "luó"),
+    ('驙', "zhān"),
+    ('驚', "jīng"),
+    ('驛', "yì"),
+    ('驜', "yè"),
+    ('驝', "tuō"),
+    ('驞', "pīn"),
+    ('驟', "zhòu"),
+    ('驠', "yàn"),
+    ('驡', "lóng"),
+    ('驢', "lǘ"),
+    ('驣', "téng"),
+    ('驤', "xiāng"),
+    ('驥', "jì"),
+    ('驦', "shuāng"),
+    ('驧', "jú"),
+    ('驨', "xí"),
+    ('驩', "huān"),
+    ('驪', "lí"),
+    ('驫', "biāo"),
+    ('马', "mǎ"),
+    ('驭', "yù"),
+    ('驮', "tuó,duò"),
+    ('驯', "xún"),
+    ('驰', "chí"),
+    ('驱', "qū"),
+    ('驲', "rì"),
+    ('驳', "bó"),
+    ('驴', "lǘ"),
+    ('驵', "zǎng"),
+    ('驶', "shǐ"),
+    ('驷', "sì"),
+    ('驸', "fù"),
+    ('驹', "jū"),
+    ('驺', "zōu"),
+    ('驻', "zhù"),
+    ('驼', "tuó"),
+    ('驽', "nú"),
+    ('驾', "jià"),
+    ('驿', "yì"),
+    ('骀', "dài"),
+    ('骁', "xiāo"),
+    ('骂', "mà"),
+    ('骃', "yīn"),
+    ('骄', "jiāo"),
+    ('骅', "huá"),
+    ('骆', "luò"),
+    ('骇', "hài"),
+    ('骈', "pián"),
+    ('骉', "biāo"),
+    ('骊', "lí"),
+    ('骋', "chěng"),
+    ('验', "yàn"),
+    ('骍', "xīng"),
+    ('骎', "qīn"),
+    ('骏', "jùn"),
+    ('骐', "qí"),
+    ('骑', "qí"),
+    ('骒', "kè"),
+    ('骓', "zhuī"),
+    ('骔', "zōng"),
+    ('骕', "sù"),
+    ('骖', "cān"),
+    ('骗', "piàn"),
+    ('骘', "zhì"),
+    ('骙', "kuí"),
+    ('骚', "sāo"),
+    ('骛', "wù"),
+    ('骜', "ào"),
+    ('骝', "liú"),
+    ('骞', "qiān"),
+    ('骟', "shàn"),
+    ('骠', "biào,piào,biāo"),
+    ('骡', "luó"),
+    ('骢', "cōng"),
+    ('骣', "chǎn"),
+    ('骤', "zhòu"),
+    ('骥', "jì"),
+    ('骦', "shuāng"),
+    ('骧', "xiāng"),
+    ('骨', "gǔ,gū"),
+    ('骩', "wěi"),
+    ('骪', "wěi"),
+    ('骫', "wěi"),
+    ('骬', "yú"),
+    ('骭', "gàn"),
+    ('骮', "yì"),
+    ('骯', "āng"),
+    ('骰', "tóu"),
+    ('骱', "jiè"),
+    ('骲', "bào"),
+    ('骳', "bèi"),
+    ('骴', "cī"),
+    ('骵', "tǐ"),
+    ('骶', "dǐ"),
+    ('骷', "kū"),
+    ('骸', "hái"),
+    ('骹', "qiāo"),
+    ('骺', "hóu"),
+    ('骻', "kuà"),
+    ('骼', "gé"),
+    ('骽', "tuǐ"),
+    ('骾', "gěng"),
+    ('骿', "pián"),
+    ('髀', "bì"),
+    ('髁', "kē"),
+    ('髂', "qià"),
+    ('髃', "yú"),
+    ('髄', "suǐ"),
+    ('髅', "lóu"),
+    ('髆', "bó"),
+    ('髇', "xiāo"),
+    ('髈', "bǎng"),
+    ('髉', "bó"),
+    ('髊', "cī"),
+    ('髋', "kuān"),
+    ('髌', "bìn"),
+    ('髍', "mó"),
+    ('髎', "liáo"),
+    ('髏', "lóu"),
+    ('髐', "xiāo"),
+    ('髑', "dú"),
+    ('髒', "zāng"),
+    ('髓', "suǐ"),
+    ('體', "tǐ"),
+    ('髕', "bìn"),
+    ('髖', "kuān"),
+    ('髗', "lú"),
+    ('高', "gāo"),
+    ('髙', "gāo"),
+    ('髚', "qiào"),
+    ('髛', "kāo"),
+    ('髜', "qiǎo"),
+    ('髝', "láo"),
+    ('髞', "sào"),
+    ('髟', "biāo"),
+    ('髠', "kūn"),
+    ('髡', "kūn"),
+    ('髢', "dí"),
+    ('髣', "fǎng"),
+    ('髤', "xiū"),
+    ('髥', "rán"),
+    ('髦', "máo"),
+    ('髧', "dàn"),
+    ('髨', "kūn"),
+    ('髩', "bìn"),
+    ('髪', "fà"),
+    ('髫', "tiáo"),
+    ('髬', "pī"),
+    ('髭', "zī"),
+    ('髮', "fà"),
+    ('髯', "rán"),
+    ('髰', "tì"),
+    ('髱', "bào"),
+    ('髲', "bì"),
+    ('髳', "máo"),
+    ('髴', "fú"),
+    ('髵', "ér"),
+    ('髶', "róng"),
+    ('髷', "qū"),
+    ('髸', "gōng"),
+    ('髹', "xiū"),
+    ('髺', "kuò"),
+    ('髻', "jì"),
+    ('髼', "péng"),
+    ('髽', "zhuā"),
+    ('髾', "shāo"),
+    ('髿', "suō"),
+    ('鬀', "tì"),
+    ('鬁', "lì"),
+    ('鬂', "bìn"),
+    ('鬃', "zōng"),
+    ('鬄', "dí"),
+    ('鬅', "péng"),
+    ('鬆', "sōng"),
+    ('鬇', "zhēng"),
+    ('鬈', "quán"),
+    ('鬉', "zōng"),
+    ('鬊', "shùn"),
+    ('鬋', "jiǎn"),
+    ('鬌', "tuǒ"),
+    ('鬍', "hú"),
+    ('鬎', "là"),
+    ('鬏', "jiū"),
+    ('鬐', "qí"),
+    ('鬑', "lián"),
+    ('鬒', "zhěn"),
+    ('鬓', "bìn"),
+    ('鬔', "péng"),
+    ('鬕', "mà"),
+    ('鬖', "sān"),
+    ('鬗', "mán"),
+    ('鬘', "mán"),
+    ('鬙', "sēng"),
+    ('鬚', "xū"),
+    ('鬛', "liè"),
+    ('鬜', "qiān"),
+    ('鬝', "qiān"),
+    ('鬞', "náng"),
+    ('鬟', "huán"),
+    ('鬠', "kuò"),
+    ('鬡', "níng"),
+    ('鬢', "bìn"),
+    ('鬣', "liè"),
+    ('鬤', "ráng"),
+    ('鬥', "dòu"),
+    ('鬦', "dòu"),
+    ('鬧', "nào"),
+    ('鬨', "hòng"),
+    ('鬩', "xì"),
+    ('鬪', "dòu"),
+    ('鬫', "hǎn"),
+    ('鬬', "dòu"),
+    ('鬭', "dòu"),
+    ('鬮', "jiū"),
+    ('鬯', "chàng"),
+    ('鬰', "yù"),
+    ('鬱', "yù"),
+    ('鬲', "gé"),
+    ('鬳', "yàn"),
+    ('鬴', "fǔ"),
+    ('鬵', "qín"),
+    ('鬶', "guī"),
+    ('鬷', "zōng"),
+    ('鬸', "liù"),
+    ('鬹', "guī"),
+    ('鬺', "shāng"),
+    ('鬻', "yù"),
+    ('鬼', "guǐ"),
+    ('鬽', "mèi"),
+    ('鬾', "jì"),
+    ('鬿', "qí"),
+    ('魀', "gà"),
+    ('魁', "kuí"),
+    ('魂', "hún"),
+    ('魃', "bá"),
+    ('魄', "pò"),
+    ('魅', "mèi"),
+    ('魆', "xū"),
+    ('魇', "yǎn"),
+    ('魈', "xiāo"),
+    ('魉', "liǎng"),
+    ('魊', "yù"),
+    ('魋', "tuí"),
+    ('魌', "qī"),
+    ('魍', "wǎng"),
+    ('魎', "liǎng"),
+    ('魏', "wèi"),
+    ('魐', "gān"),
+    ('魑', "chī"),
+    ('魒', "piāo"),
+    ('魓', "bì"),
+    ('魔', "mó"),
+    ('魕', "jǐ"),
+    ('魖', "xū"),
+    ('魗', "chǒu"),
+    ('魘', "yǎn"),
+    ('魙', "zhān"),
+    ('魚', "yú"),
+    ('魛', "dāo"),
+    ('魜', "rén"),
+    ('魝', "jié"),
+    ('魞', "bā"),
+    ('魟', "hóng"),
+    ('魠', "tuō"),
+    ('魡', "diào"),
+    ('魢', "jǐ"),
+    ('魣', "xù"),
+    ('魤', "é"),
+    ('魥', "è"),
+    ('魦', "shā"),
+    ('魧', "háng"),
+    ('魨', "tún"),
+    ('魩', "mò"),
+    ('魪', "jiè"),
+    ('魫', "shěn"),
+    ('魬', "bǎn"),
+    ('魭', "yuán"),
+    ('魮', "pí"),
+    ('魯', "lǔ"),
+    ('魰', "wén"),
+    ('魱', "hú"),
+    ('魲', "lú"),
+    ('魳', "zā"),
+    ('魴', "fáng"),
+    ('魵', "fén"),
+    ('魶', "nà"),
+    ('魷', "yóu"),
+    ('魸', "piàn"),
+    ('魹', "mó"),
+    ('魺', "hé"),
+    ('魻', "xiá"),
+    ('魼', "qū"),
+    ('魽', "hán"),
+    ('魾', "pī"),
+    ('魿', "líng"),
+    ('鮀', "tuó"),
+    ('鮁', "bō"),
+    ('鮂', "qiú"),
+    ('鮃', "píng"),
+    ('鮄', "fú"),
+    ('鮅', "bì"),
+    ('鮆', "cǐ"),
+    ('鮇', "wèi"),
+    ('鮈', "jū"),
+    ('鮉', "diāo"),
+    ('鮊', "bà"),
+    ('鮋', "yóu"),
+    ('鮌', "gǔn"),
+    ('鮍', "pī"),
+    ('鮎', "nián"),
+    ('鮏', "xīng"),
+    ('鮐', "tái"),
+    ('鮑', "bào"),
+    ('鮒', "fù"),
+    ('鮓', "zhǎ"),
+    ('鮔', "jù"),
+    ('鮕', "gū"),
+    ('鮖', "shí"),
+    ('鮗', "dōng"),
+    ('鮘', "dai"),
+    ('鮙', "tà"),
+    ('鮚', "jié"),
+    ('鮛', "shū"),
+    ('鮜', "hòu"),
+    ('鮝', "xiǎng"),
+    ('鮞', "ér"),
+    ('鮟', "àn"),
+    ('鮠', "wéi"),
+    ('鮡', "zhào"),
+    ('鮢', "zhū"),
+    ('鮣', "yìn"),
+    ('鮤', "liè"),
+    ('鮥', "luò"),
+    ('鮦', "tóng"),
+    ('鮧', "tǐ"),
+    ('鮨', "yì"),
+    ('鮩', "bìng"),
+    ('鮪', "wěi"),
+    ('鮫', "jiāo"),
+    ('鮬', "kū"),
+    ('鮭', "guī"),
+    ('鮮', "xiān"),
+    ('鮯', "gé"),
+    ('鮰', "huí"),
+    ('鮱', "lǎo"),
+    ('鮲', "fú"),
+    ('鮳', "kào"),
+    ('鮴', "xiū"),
+    ('鮵', "duó"),
+    ('鮶', "jūn"),
+    ('鮷', "tí"),
+    ('鮸', "miǎn"),
+    ('鮹', "shāo"),
+    ('鮺', "zhǎ"),
+    ('鮻', "suō"),
+    ('鮼', "qīn"),
+    ('鮽', "yú"),
+    ('鮾', "něi"),
+    ('鮿', "zhé"),
+    ('鯀', "gǔn"),
+    ('鯁', "gěng"),
+    ('鯂', "sū"),
+    ('鯃', "wú"),
+    ('鯄', "qiú"),
+    ('鯅', "shān"),
+    ('鯆', "pū"),
+    ('鯇', "huàn"),
+    ('鯈', "tiáo"),
+    ('鯉', "lǐ"),
+    ('鯊', "shā"),
+    ('鯋', "shā"),
+    ('鯌', "kào"),
+    ('鯍', "méng"),
+    ('鯎', "chéng"),
+    ('鯏', "lí"),
+    ('鯐', "zǒu"),
+    ('鯑', "xī"),
+    ('鯒', "yǒng"),
+    ('鯓', "shēn"),
+    ('鯔', "zī"),
+    ('鯕', "qí"),
+    ('鯖', "zhēng"),
+    ('鯗', "xiǎng"),
+    ('鯘', "něi"),
+    ('鯙', "chún"),
+    ('鯚', "jì"),
+    ('鯛', "diāo"),
+    ('鯜', "qiè"),
+    ('鯝', "gù"),
+    ('鯞', "zhǒu"),
+    ('鯟', "dōng"),
+    ('鯠', "lái"),
+    ('鯡', "fèi"),
+    ('鯢', "ní"),
+    ('鯣', "yì"),
+    ('鯤', "kūn"),
+    ('鯥', "lù"),
+    ('鯦', "jiù"),
+    ('鯧', "chāng"),
+    ('鯨', "jīng"),
+    ('鯩', "lún"),
+    ('鯪', "líng"),
+    ('鯫', "zōu"),
+    ('鯬', "lí"),
+    ('鯭', "měng"),
+    ('鯮', "zōng"),
+    ('鯯', "zhì"),
+    ('鯰', "nián"),
+    ('鯱', "hǔ"),
+    ('鯲', "yú"),
+    ('鯳', "dǐ"),
+    ('鯴', "shī"),
+    ('鯵', "shēn"),
+    ('鯶', "huàn"),
+    ('鯷', "tí"),
+    ('鯸', "hóu"),
+    ('鯹', "xīng"),
+    ('鯺', "zhū"),
+    ('鯻', "là"),
+    ('鯼', "zōng"),
+    ('鯽', "zéi"),
+    ('鯾', "biān"),
+    ('鯿', "biān"),
+    ('鰀', "huàn"),
+    ('鰁', "quán"),
+    ('鰂', "zéi"),
+    ('鰃', "wēi"),
+    ('鰄', "wēi"),
+    ('鰅', "yú"),
+    ('鰆', "chūn"),
+    ('鰇', "róu"),
+    ('鰈', "dié"),
+    ('鰉', "huáng"),
+    ('鰊', "liàn"),
+    ('鰋', "yǎn"),
+    ('鰌', "qiū"),
+    ('鰍', "qiū"),
+    ('鰎', "jiǎn"),
+    ('鰏', "bī"),
+    ('鰐', "è"),
+    ('鰑', "yáng"),
+    ('鰒', "fù"),
+    ('鰓', "sāi"),
+    ('鰔', "gǎn"),
+    ('鰕', "xiā"),
+    ('鰖', "tuǒ"),
+    ('鰗', "hú"),
+    ('鰘', "shì"),
+    ('鰙', "ruò"),
+    ('鰚', "xuān"),
+    ('鰛', "wēn"),
+    ('鰜', "qiàn"),
+    ('鰝', "hào"),
+    ('鰞', "wū"),
+    ('鰟', "fáng"),
+    ('鰠', "sāo"),
+    ('鰡', "liú"),
+    ('鰢', "mǎ"),
+    ('鰣', "shí"),
+    ('鰤', "shī"),
+    ('鰥', "guān"),
+    ('鰦', "zī"),
+    ('鰧', "téng"),
+    ('鰨', "tǎ"),
+    ('鰩', "yáo"),
+    ('鰪', "é"),
+    ('鰫', "yóng"),
+    ('鰬', "qián"),
+    ('鰭', "qí"),
+    ('鰮', "wēn"),
+    ('鰯', "ruò"),
+    ('鰰', "shén"),
+    ('鰱', "lián"),
+    ('鰲', "áo"),
+    ('鰳', "lè"),
+    ('鰴', "huī"),
+    ('鰵', "mǐn"),
+    ('鰶', "jì"),
+    ('鰷', "tiáo"),
+    ('鰸', "qū"),
+    ('鰹', "jiān"),
+    ('鰺', "shēn"),
+    ('鰻', "mán"),
+    ('鰼', "xí"),
+    ('鰽', "qiú"),
+    ('鰾', "biào"),
+    ('鰿', "jì"),
+    ('鱀', "jì"),
+    ('鱁', "zhú"),
+    ('鱂', "jiāng"),
+    ('鱃', "xiū"),
+    ('鱄', "zhuān"),
+    ('鱅', "yōng"),
+    ('鱆', "zhāng"),
+    ('鱇', "kāng"),
+    ('鱈', "xuě"),
+    ('鱉', "biē"),
+    ('鱊', "yù"),
+    ('鱋', "qū"),
+    ('鱌', "xiàng"),
+    ('鱍', "bō"),
+    ('鱎', "jiǎo"),
+    ('鱏', "xún"),
+    ('鱐', "sù"),
+    ('鱑', "huáng"),
+    ('鱒', "zūn"),
+    ('鱓', "shàn"),
+    ('鱔', "shàn"),
+    ('鱕', "fān"),
+    ('鱖', "guì"),
+    ('鱗', "lín"),
+    ('鱘', "xún"),
+    ('鱙', "miáo"),
+    ('鱚', "xǐ"),
+    ('鱛', "zēng"),
+    ('鱜', "xiāng"),
+    ('鱝', "fèn"),
+    ('鱞', "guān"),
+    ('鱟', "hòu"),
+    ('鱠', "kuài"),
+    ('鱡', "zéi"),
+    ('鱢', "sāo"),
+    ('鱣', "zhān"),
+    ('鱤', "gǎn"),
+    ('鱥', "guì"),
+    ('鱦', "yìng"),
+    ('鱧', "lǐ"),
+    ('鱨', "cháng"),
+    ('鱩', "léi"),
+    ('鱪', "shǔ"),
+    ('鱫', "ài"),
+    ('鱬', "rú"),
+    ('鱭', "jì"),
+    ('鱮', "xù"),
+    ('鱯', "hù"),
+    ('鱰', "shǔ"),
+    ('鱱', "lì"),
+    ('鱲', "liè"),
+    ('鱳', "lì"),
+    ('鱴', "miè"),
+    ('鱵', "zhēn"),
+    ('鱶', "xiǎng"),
+    ('鱷', "è"),
+    ('鱸', "lú"),
+    ('鱹', "guàn"),
+    ('鱺', "lí"),
+    ('鱻', "xiān"),
+    ('鱼', "yú"),
+    ('鱽', "dāo"),
+    ('鱾', "jǐ"),
+    ('鱿', "yóu"),
+    ('鲀', "tún"),
+    ('鲁', "lǔ"),
+    ('鲂', "fáng"),
+    ('鲃', "bā"),
+    ('鲄', "hé"),
+    ('鲅', "bà"),
+    ('鲆', "píng"),
+    ('鲇', "nián"),
+    ('鲈', "lú"),
+    ('鲉', "yóu"),
+    ('鲊', "zhǎ"),
+    ('鲋', "fù"),
+    ('鲌', "bà"),
+    ('鲍', "bào"),
+    ('鲎', "hòu"),
+    ('鲏', "pí"),
+    ('鲐', "tái"),
+    ('鲑', "guī"),
+    ('鲒', "jié"),
+    ('鲓', "kào"),
+    ('鲔', "wěi"),
+    ('鲕', "ér"),
+    ('鲖', "tóng"),
+    ('鲗', "zéi"),
+    ('鲘', "hòu"),
+    ('鲙', "kuài"),
+    ('鲚', "jì"),
+    ('鲛', "jiāo"),
+    ('鲜', "xiān,xiǎn"),
+    ('鲝', "zhǎ"),
+    ('鲞', "xiǎng"),
+    ('鲟', "xún"),
+    ('鲠', "gěng"),
+    ('鲡', "lí"),
+    ('鲢', "lián"),
+    ('鲣', "jiān"),
+    ('鲤', "lǐ"),
+    ('鲥', "shí"),
+    ('鲦', "tiáo"),
+    ('鲧', "gǔn"),
+    ('鲨', "shā"),
+    ('鲩', "huàn"),
+    ('鲪', "jūn"),
+    ('鲫', "jì"),
+    ('鲬', "yǒng"),
+    ('鲭', "qīng"),
+    ('鲮', "líng"),
+    ('鲯', "qí"),
+    ('鲰', "zōu"),
+    ('鲱', "fēi"),
+    ('鲲', "kūn"),
+    ('鲳', "chāng"),
+    ('鲴', "gù"),
+    ('鲵', "ní"),
+    ('鲶', "nián"),
+    ('鲷', "diāo"),
+    ('鲸', "jīng"),
+    ('鲹', "shēn"),
+    ('鲺', "shī"),
+    ('鲻', "zī"),
+    ('鲼', "fèn"),
+    ('鲽', "dié"),
+    ('鲾', "bī"),
+    ('鲿', "cháng"),
+    ('鳀', "tí"),
+    ('鳁', "wēn"),
+    ('鳂', "wēi"),
+    ('鳃', "sāi"),
+    ('鳄', "è"),
+    ('鳅', "qiū"),
+    ('鳆', "fù"),
+    ('鳇', "huáng"),
+    ('鳈', "quán"),
+    ('鳉', "jiāng"),
+    ('鳊', "biān"),
+    ('鳋', "sāo"),
+    ('鳌', "áo"),
+    ('鳍', "qí"),
+    ('鳎', "tǎ"),
+    ('鳏', "guān"),
+    ('鳐', "yáo"),
+    ('鳑', "páng"),
+    ('鳒', "jiān"),
+    ('鳓', "lè"),
+    ('鳔', "biào"),
+    ('鳕', "xuě"),
+    ('鳖', "biē"),
+    ('鳗', "mán"),
+    ('鳘', "mǐn"),
+    ('鳙', "yōng"),
+    ('鳚', "wèi"),
+    ('鳛', "xí"),
+    ('鳜', "guì"),
+    ('鳝', "shàn"),
+    ('鳞', "lín"),
+    ('鳟', "zūn"),
+    ('鳠', "hù"),
+    ('鳡', "gǎn"),
+    ('鳢', "lǐ"),
+    ('鳣', "zhān"),
+    ('鳤', "guǎn"),
+    ('鳥', "niǎo"),
+    ('鳦', "yǐ"),
+    ('鳧', "fú"),
+    ('鳨', "lì"),
+    ('鳩', "jiū"),
+    ('鳪', "bú"),
+    ('鳫', "yàn"),
+    ('鳬', "fǔ"),
+    ('鳭', "diāo"),
+    ('鳮', "jī"),
+    ('鳯', "fèng"),
+    ('鳰', "rù"),
+    ('鳱', "gān"),
+    ('鳲', "shī"),
+    ('鳳', "fèng"),
+    ('鳴', "míng"),
+    ('鳵', "bǎo"),
+    ('鳶', "yuān"),
+    ('鳷', "zhī"),
+    ('鳸', "hù"),
+    ('鳹', "qín"),
+    ('鳺', "fū"),
+    ('鳻', "bān"),
+    ('鳼', "wén"),
+    ('鳽', "jiān"),
+    ('鳾', "shī"),
+    ('鳿', "yù"),
+    ('鴀', "fǒu"),
+    ('鴁', "yāo"),
+    ('鴂', "jué"),
+    ('鴃', "jué"),
+    ('鴄', "pǐ"),
+    ('鴅', "huān"),
+    ('鴆', "zhèn"),
+    ('鴇', "bǎo"),
+    ('鴈', "yàn"),
+    ('鴉', "yā"),
+    ('鴊', "zhèng"),
+    ('鴋', "fāng"),
+    ('鴌', "fèng"),
+    ('鴍', "wén"),
+    ('鴎', "ōu"),
+    ('鴏', "dài"),
+    ('鴐', "gē"),
+    ('鴑', "rú"),
+    ('鴒', "líng"),
+    ('鴓', "miè"),
+    ('鴔', "fú"),
+    ('鴕', "tuó"),
+    ('鴖', "mín"),
+    ('鴗', "lì"),
+    ('鴘', "biǎn"),
+    ('鴙', "zhì"),
+    ('鴚', "gē"),
+    ('鴛', "yuān"),
+    ('鴜', "cí"),
+    ('鴝', "qú"),
+    ('鴞', "xiāo"),
+    ('鴟', "chī"),
+    ('鴠', "dàn"),
+    ('鴡', "jū"),
+    ('鴢', "yǎo"),
+    ('鴣', "gū"),
+    ('鴤', "zhōng"),
+    ('鴥', "yù"),
+    ('鴦', "yāng"),
+    ('鴧', "yù"),
+    ('鴨', "yā"),
+    ('鴩', "tiě"),
+    ('鴪', "yù"),
+    ('鴫', "tián"),
+    ('鴬', "yīng"),
+    ('鴭', "duī"),
+    ('鴮', "wū"),
+    ('鴯', "ér"),
+    ('鴰', "guā"),
+    ('鴱', "ài"),
+    ('鴲', "zhī"),
+    ('鴳', "yàn"),
+    ('鴴', "héng"),
+    ('鴵', "xiāo"),
+    ('鴶', "jiá"),
+    ('鴷', "liè"),
+    ('鴸', "zhū"),
+    ('鴹', "yáng"),
+    ('鴺', "tí"),
+    ('鴻', "hóng"),
+    ('鴼', "luò"),
+    ('鴽', "rú"),
+    ('鴾', "móu"),
+    ('鴿', "gē"),
+    ('鵀', "rén"),
+    ('鵁', "jiāo"),
+    ('鵂', "xiū"),
+    ('鵃', "zhōu"),
+    ('鵄', "chī"),
+    ('鵅', "luò"),
+    ('鵆', "héng"),
+    ('鵇', "nián"),
+    ('鵈', "ě"),
+    ('鵉', "luán"),
+    ('鵊', "jiá"),
+    ('鵋', "jì"),
+    ('鵌', "tú"),
+    ('鵍', "huān"),
+    ('鵎', "tuǒ"),
+    ('鵏', "bǔ"),
+    ('鵐', "wú"),
+    ('鵑', "juān"),
+    ('鵒', "yù"),
+    ('鵓', "bó"),
+    ('鵔', "jùn"),
+    ('鵕', "jùn"),
+    ('鵖', "bī"),
+    ('鵗', "xī"),
+    ('鵘', "jùn"),
+    ('鵙', "jú"),
+    ('鵚', "tū"),
+    ('鵛', "jīng"),
+    ('鵜', "tí"),
+    ('鵝', "é"),
+    ('鵞', "é"),
+    ('鵟', "kuáng"),
+    ('鵠', "hú"),
+    ('鵡', "wǔ"),
+    ('鵢', "shēn"),
+    ('鵣', "lài"),
+    ('鵤', "jiao"),
+    ('鵥', "pàn"),
+    ('鵦', "lù"),
+    ('鵧', "pí"),
+    ('鵨', "shū"),
+    ('鵩', "fú"),
+    ('鵪', "ān"),
+    ('鵫', "zhuó"),
+    ('鵬', "péng"),
+    ('鵭', "qín"),
+    ('鵮', "qiān"),
+    ('鵯', "bēi"),
+    ('鵰', "diāo"),
+    ('鵱', "lù"),
+    ('鵲', "què"),
+    ('鵳', "jiān"),
+    ('鵴', "jú"),
+    ('鵵', "tù"),
+    ('鵶', "yā"),
+    ('鵷', "yuān"),
+    ('鵸', "qí"),
+    ('鵹', "lí"),
+    ('鵺', "yè"),
+    ('鵻', "zhuī"),
+    ('鵼', "kōng"),
+    ('鵽', "duò"),
+    ('鵾', "kūn"),
+    ('鵿', "shēng"),
+    ('鶀', "qí"),
+    ('鶁', "jīng"),
+    ('鶂', "yì"),
+    ('鶃', "yì"),
+    ('鶄', "jīng"),
+    ('鶅', "zī"),
+    ('鶆', "lái"),
+    ('鶇', "dōng"),
+    ('鶈', "qī"),
+    ('鶉', "chún"),
+    ('鶊', "gēng"),
+    ('鶋', "jū"),
+    ('鶌', "jué"),
+    ('鶍', "yì"),
+    ('鶎', "zūn"),
+    ('鶏', "jī"),
+    ('鶐', "shù"),
+    ('鶑', "yīng"),
+    ('鶒', "chì"),
+    ('鶓', "miáo"),
+    ('鶔', "róu"),
+    ('鶕', "ān"),
+    ('鶖', "qiū"),
+    ('鶗', "tí"),
+    ('鶘', "hú"),
+    ('鶙', "tí"),
+    ('鶚', "è"),
+    ('鶛', "jiē"),
+    ('鶜', "máo"),
+    ('鶝', "fú"),
+    ('鶞', "chūn"),
+    ('鶟', "tú"),
+    ('鶠', "yǎn"),
+    ('鶡', "hé"),
+    ('鶢', "yuán"),
+    ('鶣', "piān"),
+    ('鶤', "kūn"),
+    ('鶥', "méi"),
+    ('鶦', "hú"),
+    ('鶧', "yīng"),
+    ('鶨', "chuàn"),
+    ('鶩', "wù"),
+    ('鶪', "jú"),
+    ('鶫', "dōng"),
+    ('鶬', "cāng"),
+    ('鶭', "fǎng"),
+    ('鶮', "hè"),
+    ('鶯', "yīng"),
+    ('鶰', "yuán"),
+    ('鶱', "xiān"),
+    ('鶲', "wēng"),
+    ('鶳', "shī"),
+    ('鶴', "hè"),
+    ('鶵', "chú"),
+    ('鶶', "táng"),
+    ('鶷', "xiá"),
+    ('鶸', "ruò"),
+    ('鶹', "liú"),
+    ('鶺', "jí"),
+    ('鶻', "gú"),
+    ('鶼', "jiān"),
+    ('鶽', "sǔn"),
+    ('鶾', "hàn"),
+    ('鶿', "cí"),
+    ('鷀', "cí"),
+    ('鷁', "yì"),
+    ('鷂', "yào"),
+    ('鷃', "yàn"),
+    ('鷄', "jī"),
+    ('鷅', "lì"),
+    ('鷆', "tián"),
+    ('鷇', "kòu"),
+    ('鷈', "tī"),
+    ('鷉', "tī"),
+    ('鷊', "yì"),
+    ('鷋', "tú"),
+    ('鷌', "mǎ"),
+    ('鷍', "xiāo"),
+    ('鷎', "gāo"),
+    ('鷏', "tián"),
+    ('鷐', "chén"),
+    ('鷑', "jí"),
+    ('鷒', "tuán"),
+    ('鷓', "zhè"),
+    ('鷔', "áo"),
+    ('鷕', "yǎo"),
+    ('鷖', "yī"),
+    ('鷗', "ōu"),
+    ('鷘', "chì"),
+    ('鷙', "zhì"),
+    ('鷚', "liù"),
+    ('鷛', "yōng"),
+    ('鷜', "lǘ"),
+    ('鷝', "bì"),
+    ('鷞', "shuāng"),
+    ('鷟', "zhuó"),
+    ('鷠', "yú"),
+    ('鷡', "wú"),
+    ('鷢', "jué"),
+    ('鷣', "yín"),
+    ('鷤', "tí"),
+    ('鷥', "sī"),
+    ('鷦', "jiāo"),
+    ('鷧', "yì"),
+    ('鷨', "huá"),
+    ('鷩', "bì"),
+    ('鷪', "yīng"),
+    ('鷫', "sù"),
+    ('鷬', "huáng"),
+    ('鷭', "fán"),
+    ('鷮', "jiāo"),
+    ('鷯', "liáo"),
+    ('鷰', "yàn"),
+    ('鷱', "gāo"),
+    ('鷲', "jiù"),
+    ('鷳', "xián"),
+    ('鷴', "xián"),
+    ('鷵', "tú"),
+    ('鷶', "mǎi"),
+    ('鷷', "zūn"),
+    ('鷸', "yù"),
+    ('鷹', "yīng"),
+    ('鷺', "lù"),
+    ('鷻', "tuán"),
+    ('鷼', "xián"),
+    ('鷽', "xué"),
+    ('鷾', "yì"),
+    ('鷿', "pì"),
+    ('鸀', "shǔ"),
+    ('鸁', "luó"),
+    ('鸂', "xī"),
+    ('鸃', "yí"),
+    ('鸄', "jī"),
+    ('鸅', "zé"),
+    ('鸆', "yú"),
+    ('鸇', "zhān"),
+    ('鸈', "yè"),
+    ('鸉', "yáng"),
+    ('鸊', "pì"),
+    ('鸋', "níng"),
+    ('鸌', "hù"),
+    ('鸍', "mí"),
+    ('鸎', "yīng"),
+    ('鸏', "méng"),
+    ('鸐', "dí"),
+    ('鸑', "yuè"),
+    ('鸒', "yù"),
+    ('鸓', "lěi"),
+    ('鸔', "bǔ"),
+    ('鸕', "lú"),
+    ('鸖', "hè"),
+    ('鸗', "lóng"),
+    ('鸘', "shuāng"),
+    ('鸙', "yuè"),
+    ('鸚', "yīng"),
+    ('鸛', "guàn"),
+    ('鸜', "qú"),
+    ('鸝', "lí"),
+    ('鸞', "luán"),
+    ('鸟', "niǎo,diǎo"),
+    ('鸠', "jiū"),
+    ('鸡', "jī"),
+    ('鸢', "yuān"),
+    ('鸣', "míng"),
+    ('鸤', "shī"),
+    ('鸥', "ōu"),
+    ('鸦', "yā"),
+    ('鸧', "cāng"),
+    ('鸨', "bǎo"),
+    ('鸩', "zhèn"),
+    ('鸪', "gū"),
+    ('鸫', "dōng"),
+    ('鸬', "lú"),
+    ('鸭', "yā"),
+    ('鸮', "xiāo"),
+    ('鸯', "yāng"),
+    ('鸰', "líng"),
+    ('鸱', "chī"),
+    ('鸲', "qú"),
+    ('鸳', "yuān"),
+    ('鸴', "xué"),
+    ('鸵', "tuó"),
+    ('鸶', "sī"),
+    ('鸷', "zhì"),
+    ('鸸', "ér"),
+    ('鸹', "guā"),
+    ('鸺', "xiū"),
+    ('鸻', "héng"),
+    ('鸼', "zhōu"),
+    ('鸽', "gē"),
+    ('鸾', "luán"),
+    ('鸿', "hóng"),
+    ('鹀', "wú"),
+    ('鹁', "bó"),
+    ('鹂', "lí"),
+    ('鹃', "juān"),
+    ('鹄', "gǔ"),
+    ('鹅', "é"),
+    ('鹆', "yù"),
+    ('鹇', "xián"),
+    ('鹈', "tí"),
+    ('鹉', "wǔ"),
+    ('鹊', "què"),
+    ('鹋', "miáo"),
+    ('鹌', "ān"),
+    ('鹍', "kūn"),
+    ('鹎', "bēi"),
+    ('鹏', "péng"),
+    ('鹐', "qiān"),
+    ('鹑', "chún"),
+    ('鹒', "gēng"),
+    ('鹓', "yuān"),
+    ('鹔', "sù"),
+    ('鹕', "hú"),
+    ('鹖', "hé"),
+    ('鹗', "è"),
+    ('鹘', "gǔ"),
+    ('鹙', "qiū"),
+    ('鹚', "cí"),
+    ('鹛', "méi"),
+    ('鹜', "wù"),
+    ('鹝', "yì"),
+    ('鹞', "yào"),
+    ('鹟', "wēng"),
+    ('鹠', "liú"),
+    ('鹡', "jí"),
+    ('鹢', "yì"),
+    ('鹣', "jiān"),
+    ('鹤', "hè"),
+    ('鹥', "yī"),
+    ('鹦', "yīng"),
+    ('鹧', "zhè"),
+    ('鹨', "liù"),
+    ('鹩', "liáo"),
+    ('鹪', "jiāo"),
+    ('鹫', "jiù"),
+    ('鹬', "yù"),
+    ('鹭', "lù"),
+    ('鹮', "huán"),
+    ('鹯', "zhān"),
+    ('鹰', "yīng"),
+    ('鹱', "hù"),
+    ('鹲', "méng"),
+    ('鹳', "guàn"),
+    ('鹴', "shuāng"),
+    ('鹵', "lǔ"),
+    ('鹶', "jīn"),
+    ('鹷', "líng"),
+    ('鹸', "jiǎn"),
+    ('鹹', "xián"),
+    ('鹺', "cuó"),
+    ('鹻', "jiǎn"),
+    ('鹼', "jiǎn"),
+    ('鹽', "yán"),
+    ('鹾', "cuó"),
+    ('鹿', "lù"),
+    ('麀', "yōu"),
+    ('麁', "cū"),
+    ('麂', "jǐ"),
+    ('麃', "páo"),
+    ('麄', "cū"),
+    ('麅', "páo"),
+    ('麆', "zhù"),
+    ('麇', "jūn"),
+    ('麈', "zhǔ"),
+    ('麉', "jiān"),
+    ('麊', "mí"),
+    ('麋', "mí"),
+    ('麌', "yǔ"),
+    ('麍', "liú"),
+    ('麎', "chén"),
+    ('麏', "jūn"),
+    ('麐', "lín"),
+    ('麑', "ní"),
+    ('麒', "qí"),
+    ('麓', "lù"),
+    ('麔', "jiù"),
+    ('麕', "jūn"),
+    ('麖', "jīng"),
+    ('麗', "lì"),
+    ('麘', "xiāng"),
+    ('麙', "xián"),
+    ('麚', "jiā"),
+    ('麛', "mí"),
+    ('麜', "lì"),
+    ('麝', "shè"),
+    ('麞', "zhāng"),
+    ('麟', "lín"),
+    ('麠', "jīng"),
+    ('麡', "qí"),
+    ('麢', "líng"),
+    ('麣', "yán"),
+    ('麤', "cū"),
+    ('麥', "mài"),
+    ('麦', "mài"),
+    ('麧', "hé"),
+    ('麨', "chǎo"),
+    ('麩', "fū"),
+    ('麪', "miàn"),
+    ('麫', "miàn"),
+    ('麬', "fū"),
+    ('麭', "pào"),
+    ('麮', "qù"),
+    ('麯', "qū"),
+    ('麰', "móu"),
+    ('麱', "fū"),
+    ('麲', "xiàn"),
+    ('麳', "lái"),
+    ('麴', "qū"),
+    ('麵', "miàn"),
+    ('麶', "chi"),
+    ('麷', "fēng"),
+    ('麸', "fū"),
+    ('麹', "qū"),
+    ('麺', "miàn"),
+    ('麻', "má"),
+    ('麼', "me"),
+    ('麽', "mó"),
+    ('麾', "huī"),
+    ('麿', "mo"),
+    ('黀', "zōu"),
+    ('黂', "fén"),
+    ('黃', "huáng"),
+    ('黄', "huáng"),
+    ('黅', "jīn"),
+    ('黆', "guāng"),
+    ('黇', "tiān"),
+    ('黈', "tǒu"),
+    ('黉', "hóng"),
+    ('黊', "huà"),
+    ('黋', "kuàng"),
+    ('黌', "hóng"),
+    ('黍', "shǔ"),
+    ('黎', "lí"),
+    ('黏', "nián"),
+    ('黐', "chī"),
+    ('黑', "hēi"),
+    ('黒', "hēi"),
+    ('黓', "yì"),
+    ('黔', "qián"),
+    ('黕', "dǎn"),
+    ('黖', "xì"),
+    ('黗', "tūn"),
+    ('默', "mò"),
+    ('黙', "mò"),
+    ('黚', "qián"),
+    ('黛', "dài"),
+    ('黜', "chù"),
+    ('黝', "yǒu"),
+    ('點', "diǎn"),
+    ('黟', "yī"),
+    ('黠', "xiá"),
+    ('黡', "yǎn"),
+    ('黢', "qū"),
+    ('黣', "měi"),
+    ('黤', "yǎn"),
+    ('黥', "qíng"),
+    ('黦', "yuè"),
+    ('黧', "lí"),
+    ('黨', "dǎng"),
+    ('黩', "dú"),
+    ('黪', "cǎn"),
+    ('黫', "yān"),
+    ('黬', "yán"),
+    ('黭', "yǎn"),
+    ('黮', "dǎn"),
+    ('黯', "àn"),
+    ('黰', "zhěn"),
+    ('黱', "dài"),
+    ('黲', "cǎn"),
+    ('黳', "yī"),
+    ('黴', "méi"),
+    ('黵', "zhǎn"),
+    ('黶', "yǎn"),
+    ('黷', "dú"),
+    ('黸', "lú"),
+    ('黹', "zhǐ"),
+    ('黺', "fěn"),
+    ('黻', "fú"),
+    ('黼', "fǔ"),
+    ('黽', "miǎn"),
+    ('黾', "miǎn"),
+    ('黿', "yuán"),
+    ('鼀', "cù"),
+    ('鼁', "qù"),
+    ('鼂', "cháo"),
+    ('鼃', "wā"),
+    ('鼄', "zhū"),
+    ('鼅', "zhī"),
+    ('鼆', "méng"),
+    ('鼇', "áo"),
+    ('鼈', "biē"),
+    ('鼉', "tuó"),
+    ('鼊', "bì"),
+    ('鼋', "yuán"),
+    ('鼌', "cháo"),
+    ('鼍', "tuó"),
+    ('鼎', "dǐng"),
+    ('鼏', "mì"),
+    ('鼐', "nài"),
+    ('鼑', "dǐng"),
+    ('鼒', "zī"),
+    ('鼓', "gǔ"),
+    ('鼔', "gǔ"),
+    ('鼕', "dōng"),
+    ('鼖', "fén"),
+    ('鼗', "táo"),
+    ('鼘', "yuān"),
+    ('鼙', "pí"),
+    ('鼚', "chāng"),
+    ('鼛', "gāo"),
+    ('鼜', "qì"),
+    ('鼝', "yuān"),
+    ('鼞', "tāng"),
+    ('鼟', "tēng"),
+    ('鼠', "shǔ"),
+    ('鼡', "shǔ"),
+    ('鼢', "fén"),
+    ('鼣', "fèi"),
+    ('鼤', "wén"),
+    ('鼥', "bá"),
+    ('鼦', "diāo"),
+    ('鼧', "tuó"),
+    ('鼨', "zhōng"),
+    ('鼩', "qú"),
+    ('鼪', "shēng"),
+    ('鼫', "shí"),
+    ('鼬', "yòu"),
+    ('鼭', "shí"),
+    ('鼮', "tíng"),
+    ('鼯', "wú"),
+    ('鼰', "jú"),
+    ('鼱', "jīng"),
+    ('鼲', "hún"),
+    ('鼳', "jú"),
+    ('鼴', "yǎn"),
+    ('鼵', "tū"),
+    ('鼶', "sī"),
+    ('鼷', "xī"),
+    ('鼸', "xiàn"),
+    ('鼹', "yǎn"),
+    ('鼺', "léi"),
+    ('鼻', "bí"),
+    ('鼼', "yào"),
+    ('鼽', "qiú"),
+    ('鼾', "hān"),
+    ('鼿', "wù"),
+    ('齀', "wù"),
+    ('齁', "hōu"),
+    ('齂', "xiè"),
+    ('齃', "è"),
+    ('齄', "zhā"),
+    ('齅', "xiù"),
+    ('齆', "wèng"),
+    ('齇', "zhā"),
+    ('齈', "nòng"),
+    ('齉', "nàng"),
+    ('齊', "qí"),
+    ('齋', "zhāi"),
+    ('齌', "jì"),
+    ('齍', "zī"),
+    ('齎', "jī"),
+    ('齏', "jī"),
+    ('齐', "qí"),
+    ('齑', "jī"),
+    ('齒', "chǐ"),
+    ('齓', "chèn"),
+    ('齔', "chèn"),
+    ('齕', "hé"),
+    ('齖', "yá"),
+    ('齗', "yín"),
+    ('齘', "xiè"),
+    ('齙', "bāo"),
+    ('齚', "zé"),
+    ('齛', "xiè"),
+    ('齜', "chái"),
+    ('齝', "chī"),
+    ('齞', "yǎn"),
+    ('齟', "jǔ"),
+    ('齠', "tiáo"),
+    ('齡', "líng"),
+    ('齢', "líng"),
+    ('齣', "chū"),
+    ('齤', "quán"),
+    ('齥', "xiè"),
+    ('齦', "kěn"),
+    ('齧', "niè"),
+    ('齨', "jiù"),
+    ('齩', "yǎo"),
+    ('齪', "chuò"),
+    ('齫', "yǔn"),
+    ('齬', "yǔ"),
+    ('齭', "chǔ"),
+    ('齮', "yǐ"),
+    ('齯', "ní"),
+    ('齰', "zé"),
+    ('齱', "zōu"),
+    ('齲', "qǔ"),
+    ('齳', "yǔn"),
+    ('齴', "yǎn"),
+    ('齵', "óu"),
+    ('齶', "è"),
+    ('齷', "wò"),
+    ('齸', "yì"),
+    ('齹', "cī"),
+    ('齺', "zōu"),
+    ('齻', "diān"),
+    ('齼', "chǔ"),
+    ('齽', "jìn"),
+    ('齾', "yà"),
+    ('齿', "chǐ"),
+    ('龀', "chèn"),
+    ('龁', "hé"),
+    ('龂', "yín"),
+    ('龃', "jǔ"),
+    ('龄', "líng"),
+    ('龅', "bāo"),
+    ('龆', "tiáo"),
+    ('龇', "zī"),
+    ('龈', "kěn"),
+    ('龉', "yǔ"),
+    ('龊', "chuò"),
+    ('龋', "qǔ"),
+    ('龌', "wò"),
+    ('龍', "lóng"),
+    ('龎', "páng"),
+    ('龏', "gōng"),
+    ('龐', "páng"),
+    ('龑', "yǎn"),
+    ('龒', "lóng"),
+    ('龓', "lǒng"),
+    ('龔', "gōng"),
+    ('龕', "kān"),
+    ('龖', "dá"),
+    ('龗', "líng"),
+    ('龘', "dá"),
+    ('龙', "lóng"),
+    ('龚', "gōng"),
+    ('龛', "kān"),
+    ('龜', "guī"),
+    ('龝', "qiū"),
+    ('龞', "biē"),
+    ('龟', "guī,jūn,qiū"),
+    ('龠', "yuè"),
+    ('龡', "chuī"),
+    ('龢', "hé"),
+    ('龣', "jué"),
+    ('龤', "xié"),
+    ('龥', "yù"),
+    ('鿃', "shǎn"),
+    ('鿍', "gàng"),
+    ('鿎', "tǎ"),
+    ('鿏', "mài"),
+    ('鿔', "gē"),
+    ('鿕', "dān"),
+    ('鿫', "ào"),
+    ('鿬', "tián"),
+    ('鿭', "nǐ"),
+    ('鿴', "dōng"),
+    ('鿵', "zhì"),
+    ('鿶', "láng"),
+    ('鿷', "àn"),
+    ('鿺', "mài"),
+    ('豈', "qǐ"),
+    ('更', "gèng"),
+    ('車', "chē"),
+    ('賈', "jiǎ"),
+    ('滑', "huá"),
+    ('串', "chuàn"),
+    ('句', "jù"),
+    ('龜', "guī"),
+    ('龜', "guī"),
+    ('契', "qì"),
+    ('金', "jīn"),
+    ('喇', "lǎ"),
+    ('奈', "nài"),
+    ('懶', "lǎn"),
+    ('癩', "lài"),
+    ('羅', "luó"),
+    ('蘿', "luó"),
+    ('螺', "luó"),
+    ('裸', "luǒ"),
+    ('邏', "luó"),
+    ('樂', "lè"),
+    ('洛', "luò"),
+    ('烙', "lào"),
+    ('珞', "luò"),
+    ('落', "luò"),
+    ('酪', "lào"),
+    ('駱', "luò"),
+    ('亂', "luàn"),
+    ('卵', "luǎn"),
+    ('欄', "lán"),
+    ('爛', "làn"),
+    ('蘭', "lán"),
+    ('鸞', "luán"),
+    ('嵐', "lán"),
+    ('濫', "làn"),
+    ('藍', "lán"),
+    ('襤', "lán"),
+    ('拉', "lā"),
+    ('臘', "là"),
+    ('蠟', "là"),
+    ('廊', "láng"),
+    ('朗', "lǎng"),
+    ('浪', "làng"),
+    ('狼', "láng"),
+    ('郎', "láng"),
+    ('來', "lái"),
+    ('冷', "lěng"),
+    ('勞', "láo"),
+    ('擄', "lǔ"),
+    ('櫓', "lǔ"),
+    ('爐', "lú"),
+    ('盧', "lú"),
+    ('老', "lǎo"),
+    ('蘆', "lú"),
+    ('虜', "lǔ"),
+    ('路', "lù"),
+    ('露', "lù"),
+    ('魯', "lǔ"),
+    ('鷺', "lù"),
+    ('碌', "lù"),
+    ('祿', "lù"),
+    ('綠', "lǜ"),
+    ('菉', "lù"),
+    ('錄', "lù"),
+    ('鹿', "lù"),
+    ('論', "lùn"),
+    ('壟', "lǒng"),
+    ('弄', "nòng"),
+    ('籠', "lóng"),
+    ('聾', "lóng"),
+    ('牢', "láo"),
+    ('磊', "lěi"),
+    ('賂', "lù"),
+    ('雷', "léi"),
+    ('壘', "lěi"),
+    ('屢', "lǚ"),
+    ('樓', "lóu"),
+    ('淚', "lèi"),
+    ('漏', "lòu"),
+    ('累', "lèi"),
+    ('縷', "lǚ"),
+    ('陋', "lòu"),
+    ('勒', "lēi"),
+    ('肋', "lē"),
+    ('凜', "lǐn"),
+    ('凌', "líng"),
+    ('稜', "léng"),
+    ('綾', "líng"),
+    ('菱', "líng"),
+    ('陵', "líng"),
+    ('讀', "dú"),
+    ('拏', "ná"),
+    ('樂', "lè"),
+    ('諾', "nuò"),
+    ('丹', "dān"),
+    ('寧', "níng"),
+    ('怒', "nù"),
+    ('率', "lǜ"),
+    ('異', "yì"),
+    ('北', "běi"),
+    ('磻', "pán"),
+    ('便', "biàn"),
+    ('復', "fù"),
+    ('不', "bù"),
+    ('泌', "mì"),
+    ('數', "shù"),
+    ('索', "suǒ"),
+    ('參', "cān"),
+    ('塞', "sāi"),
+    ('省', "shěng"),
+    ('葉', "yè"),
+    ('說', "shuō"),
+    ('殺', "shā"),
+    ('辰', "chén"),
+    ('沈', "shěn"),
+    ('拾', "shí"),
+    ('若', "ruò"),
+    ('掠', "lüè"),
+    ('略', "lüè"),
+    ('亮', "liàng"),
+    ('兩', "liǎng"),
+    ('凉', "liáng"),
+    ('梁', "liáng"),
+    ('糧', "liáng"),
+    ('良', "liáng"),
+    ('諒', "liàng"),
+    ('量', "liàng"),
+    ('勵', "lì"),
+    ('呂', "lǚ"),
+    ('女', "nǚ"),
+    ('廬', "lú"),
+    ('旅', "lǚ"),
+    ('濾', "lǜ"),
+    ('礪', "lì"),
+    ('閭', "lǘ"),
+    ('驪', "lí"),
+    ('麗', "lì"),
+    ('黎', "lí"),
+    ('力', "lì"),
+    ('曆', "lì"),
+    ('歷', "lì"),
+    ('轢', "lì"),
+    ('年', "nián"),
+    ('憐', "lián"),
+    ('戀', "liàn"),
+    ('撚', "niǎn"),
+    ('漣', "lián"),
+    ('煉', "liàn"),
+    ('璉', "liǎn"),
+    ('秊', "nián"),
+    ('練', "liàn"),
+    ('聯', "lián"),
+    ('輦', "niǎn"),
+    ('蓮', "lián"),
+    ('連', "lián"),
+    ('鍊', "liàn"),
+    ('列', "liè"),
+    ('劣', "liè"),
+    ('咽', "yàn"),
+    ('烈', "liè"),
+    ('裂', "liè"),
+    ('說', "shuō"),
+    ('廉', "lián"),
+    ('念', "niàn"),
+    ('捻', "niǎn"),
+    ('殮', "liàn"),
+    ('簾', "lián"),
+    ('獵', "liè"),
+    ('令', "lìng"),
+    ('囹', "líng"),
+    ('寧', "níng"),
+    ('嶺', "lǐng"),
+    ('怜', "lián"),
+    ('玲', "líng"),
+    ('瑩', "yíng"),
+    ('羚', "líng"),
+    ('聆', "líng"),
+    ('鈴', "líng"),
+    ('零', "líng"),
+    ('靈', "líng"),
+    ('領', "lǐng"),
+    ('例', "lì"),
+    ('禮', "lǐ"),
+    ('醴', "lǐ"),
+    ('隸', "lì"),
+    ('惡', "è"),
+    ('了', "le"),
+    ('僚', "liáo"),
+    ('寮', "liáo"),
+    ('尿', "niào"),
+    ('料', "liào"),
+    ('樂', "lè"),
+    ('燎', "liáo"),
+    ('療', "liáo"),
+    ('蓼', "liǎo"),
+    ('遼', "liáo"),
+    ('龍', "lóng"),
+    ('暈', "yūn"),
+    ('阮', "ruǎn"),
+    ('劉', "liú"),
+    ('杻', "chǒu"),
+    ('柳', "liǔ"),
+    ('流', "liú"),
+    ('溜', "liū"),
+    ('琉', "liú"),
+    ('留', "liú"),
+    ('硫', "liú"),
+    ('紐', "niǔ"),
+    ('類', "lèi"),
+    ('六', "liù"),
+    ('戮', "lù"),
+    ('陸', "lù"),
+    ('倫', "lún"),
+    ('崙', "lún"),
+    ('淪', "lún"),
+    ('輪', "lún"),
+    ('律', "lǜ"),
+    ('慄', "lì"),
+    ('栗', "lì"),
+    ('率', "lǜ"),
+    ('隆', "lóng"),
+    ('利', "lì"),
+    ('吏', "lì"),
+    ('履', "lǚ"),
+    ('易', "yì"),
+    ('李', "lǐ"),
+    ('梨', "lí"),
+    ('泥', "ní"),
+    ('理', "lǐ"),
+    ('痢', "lì"),
+    ('罹', "lí"),
+    ('裏', "lǐ"),
+    ('裡', "lǐ"),
+    ('里', "lǐ"),
+    ('離', "lí"),
+    ('匿', "nì"),
+    ('溺', "nì"),
+    ('吝', "lìn"),
+    ('燐', "lín"),
+    ('璘', "lín"),
+    ('藺', "lìn"),
+    ('隣', "lín"),
+    ('鱗', "lín"),
+    ('麟', "lín"),
+    ('林', "lín"),
+    ('淋', "lín"),
+    ('臨', "lín"),
+    ('立', "lì"),
+    ('笠', "lì"),
+    ('粒', "lì"),
+    ('狀', "zhuàng"),
+    ('炙', "zhì"),
+    ('識', "shí"),
+    ('什', "shén"),
+    ('茶', "chá"),
+    ('刺', "cì"),
+    ('切', "qiè"),
+    ('度', "dù"),
+    ('拓', "tà"),
+    ('糖', "táng"),
+    ('宅', "zhái"),
+    ('洞', "dòng"),
+    ('暴', "bào"),
+    ('輻', "fú"),
+    ('行', "xíng"),
+    ('降', "jiàng"),
+    ('見', "jiàn"),
+    ('廓', "kuò"),
+    ('兀', "wù"),
+    ('嗀', "hù"),
+    ('塚', "zhǒng"),
+    ('晴', "qíng"),
+    ('凞', "xī"),
+    ('猪', "zhū"),
+    ('益', "yì"),
+    ('礼', "lǐ"),
+    ('神', "shén"),
+    ('祥', "xiáng"),
+    ('福', "fú"),
+    ('靖', "jìng"),
+    ('精', "jīng"),
+    ('羽', "yǔ"),
+    ('蘒', "qiū"),
+    ('諸', "zhū"),
+    ('逸', "yì"),
+    ('都', "dōu"),
+    ('飯', "fàn"),
+    ('飼', "sì"),
+    ('館', "guǎn"),
+    ('鶴', "hè"),
+    ('郞', "láng"),
+    ('隷', "lì"),
+    ('侮', "wǔ"),
+    ('僧', "sēng"),
+    ('免', "miǎn"),
+    ('勉', "miǎn"),
+    ('勤', "qín"),
+    ('卑', "bēi"),
+    ('喝', "hē"),
+    ('嘆', "tàn"),
+    ('器', "qì"),
+    ('塀', "píng"),
+    ('墨', "mò"),
+    ('層', "céng"),
+    ('屮', "chè"),
+    ('悔', "huǐ"),
+    ('慨', "kǎi"),
+    ('憎', "zēng"),
+    ('懲', "chéng"),
+    ('敏', "mǐn"),
+    ('既', "jì"),
+    ('暑', "shǔ"),
+    ('梅', "méi"),
+    ('海', "hǎi"),
+    ('渚', "zhǔ"),
+    ('漢', "hàn"),
+    ('煮', "zhǔ"),
+    ('爫', "zhǎo"),
+    ('琢', "zuó"),
+    ('碑', "bēi"),
+    ('社', "shè"),
+    ('祉', "zhǐ"),
+    ('祈', "qí"),
+    ('祐', "yòu"),
+    ('祖', "zǔ"),
+    ('祝', "zhù"),
+    ('禍', "huò"),
+    ('禎', "zhēn"),
+    ('穀', "gǔ"),
+    ('突', "tū"),
+    ('節', "jié"),
+    ('練', "liàn"),
+    ('縉', "jìn"),
+    ('繁', "fán"),
+    ('署', "shǔ"),
+    ('者', "zhě"),
+    ('臭', "chòu"),
+    ('艹', "cao"),
+    ('艹', "cao"),
+    ('著', "zhe"),
+    ('褐', "hè"),
+    ('視', "shì"),
+    ('謁', "yè"),
+    ('謹', "jǐn"),
+    ('賓', "bīn"),
+    ('贈', "zèng"),
+    ('辶', "chuò"),
+    ('逸', "yì"),
+    ('難', "nán"),
+    ('響', "xiǎng"),
+    ('頻', "pín"),
+    ('恵', "huì"),
+    ('舘', "guǎn"),
+    ('並', "bìng"),
+    ('况', "kuàng"),
+    ('全', "quán"),
+    ('侀', "xíng"),
+    ('充', "chōng"),
+    ('冀', "jì"),
+    ('勇', "yǒng"),
+    ('勺', "sháo"),
+    ('喝', "hē"),
+    ('啕', "táo"),
+    ('喙', "huì"),
+    ('嗢', "wà"),
+    ('塚', "zhǒng"),
+    ('墳', "fén"),
+    ('奄', "yǎn"),
+    ('奔', "bēn"),
+    ('婢', "bì"),
+    ('嬨', "cí"),
+    ('廒', "áo"),
+    ('廙', "yì"),
+    ('彩', "cǎi"),
+    ('徭', "yáo"),
+    ('惘', "wǎng"),
+    ('慎', "shèn"),
+    ('愈', "yù"),
+    ('憎', "zēng"),
+    ('慠', "ào"),
+    ('懲', "chéng"),
+    ('戴', "dài"),
+    ('揄', "yú"),
+    ('搜', "sōu"),
+    ('摒', "bǐng"),
+    ('敖', "áo"),
+    ('晴', "qíng"),
+    ('朗', "lǎng"),
+    ('望', "wàng"),
+    ('杖', "zhàng"),
+    ('歹', "dǎi"),
+    ('殺', "shā"),
+    ('流', "liú"),
+    ('滛', "yín"),
+    ('滋', "zī"),
+    ('漢', "hàn"),
+    ('瀞', "jìng"),
+    ('煮', "zhǔ"),
+    ('瞧', "qiáo"),
+    ('爵', "jué"),
+    ('犯', "fàn"),
+    ('猪', "zhū"),
+    ('瑱', "zhèn"),
+    ('甆', "cí"),
+    ('画', "huà"),
+    ('瘝', "guān"),
+    ('瘟', "wēn"),
+    ('益', "yì"),
+    ('盛', "shèng"),
+    ('直', "zhí"),
+    ('睊', "juàn"),
+    ('着', "zhe"),
+    ('磌', "tián"),
+    ('窱', "tiǎo"),
+    ('節', "jié"),
+    ('类', "lèi"),
+    ('絛', "tāo"),
+    ('練', "liàn"),
+    ('缾', "píng"),
+    ('者', "zhě"),
+    ('荒', "huāng"),
+    ('華', "huá"),
+    ('蝹', "yūn"),
+    ('襁', "qiǎng"),
+    ('覆', "fù"),
+    ('視', "shì"),
+    ('調', "diào"),
+    ('諸', "zhū"),
+    ('請', "qǐng"),
+    ('謁', "yè"),
+    ('諾', "nuò"),
+    ('諭', "yù"),
+    ('謹', "jǐn"),
+    ('變', "biàn"),
+    ('贈', "zèng"),
+    ('輸', "shū"),
+    ('遲', "chí"),
+    ('醙', "sōu"),
+    ('鉶', "xíng"),
+    ('陼', "zhǔ"),
+    ('難', "nán"),
+    ('靖', "jìng"),
+    ('韛', "bài"),
+    ('響', "xiǎng"),
+    ('頋', "ě"),
+    ('頻', "pín"),
+    ('鬒', "zhěn"),
+    ('龜', "guī"),
+    ('㮝', "hé"),
+    ('䀘', "xié"),
+    ('䀹', "jié"),
+    ('𥳐', "qiǎn"),
+    ('𧻓', "bèng"),
+    ('齃', "è"),
+    ('龎', "páng"),
+    ('𠀀', "hē"),
+    ('𠀁', "qī"),
+    ('𠀃', "qiě"),
+    ('𠀅', "hài"),
+    ('𠀉', "qiū"),
+    ('𠀊', "cāo"),
+    ('𠀍', "shì"),
+    ('𠀓', "sī"),
+    ('𠀔', "jué"),
+    ('𠀛', "yù"),
+    ('𠀝', "kōng"),
+    ('𠀢', "zī"),
+    ('𠀦', "xíng"),
+    ('𠀱', "mǒu"),
+    ('𠀷', "jī"),
+    ('𠀸', "yè"),
+    ('𠀹', "jūn"),
+    ('𠀼', "qián"),
+    ('𠀽', "lù"),
+    ('𠁁', "dòu"),
+    ('𠁉', "chū"),
+    ('𠁗', "shì"),
+    ('𠁠', "qiè"),
+    ('𠁥', "gǎ"),
+    ('𠁭', "qí"),
+    ('𠁷', "chǎn"),
+    ('𠂄', "huān"),
+    ('𠂆', "yì"),
+    ('𠂇', "zuǒ"),
+    ('𠂈', "jié"),
+    ('𠂑', "zōu"),
+    ('𠂔', "zǐ"),
+    ('𠂝', "zā"),
+    ('𠂟', "jīn"),
+    ('𠂢', "pài"),
+    ('𠂤', "duī"),
+    ('𠂥', "cóng"),
+    ('𠂧', "shèn"),
+    ('𠂸', "huáng"),
+    ('𠃊', "yǐn"),
+    ('𠃌', "gǔn"),
+    ('𠃓', "yáng"),
+    ('𠃖', "jiū"),
+    ('𠃫', "shēn"),
+    ('𠃺', "jiù"),
+    ('𠄅', "yè"),
+    ('𠄉', "dòng"),
+    ('𠄌', "jué"),
+    ('𠄍', "jié"),
+    ('𠄏', "diǎo"),
+    ('𠄑', "jué"),
+    ('𠄒', "chuí"),
+    ('𠄖', "líng"),
+    ('𠄚', "tīng"),
+    ('𠄣', "gèn"),
+    ('𠄮', "yà"),
+    ('𠄱', "yí"),
+    ('𠄿', "wéi"),
+    ('𠅂', "jié"),
+    ('𠅌', "yí"),
+    ('𠅗', "diè"),
+    ('𠅚', "qí"),
+    ('𠅤', "xí"),
+    ('𠅬', "bāo"),
+    ('𠅱', "xiè"),
+    ('𠅹', "zhàng"),
+    ('𠆌', "yōng"),
+    ('𠆐', "xù"),
+    ('𠆙', "diè"),
+    ('𠆛', "dān"),
+    ('𠆟', "wěi"),
+    ('𠆣', "guǎ"),
+    ('𠆩', "fàn"),
+    ('𠆮', "mò"),
+    ('𠆱', "xī"),
+    ('𠆲', "yǎn"),
+    ('𠆵', "ní"),
+    ('𠆶', "dàn"),
+    ('𠇋', "dǎn"),
+    ('𠇏', "tāo"),
+    ('𠇐', "yǔ"),
+    ('𠇒', "gōng"),
+    ('𠇗', "kuā"),
+    ('𠇘', "chù"),
+    ('𠇯', "qù"),
+    ('𠇱', "mò"),
+    ('𠇳', "shī"),
+    ('𠇵', "gǎn"),
+    ('𠇷', "shēng"),
+    ('𠇹', "jìng"),
+    ('𠈁', "tuō"),
+    ('𠈅', "shōu"),
+    ('𠈊', "niě"),
+    ('𠈤', "yùn"),
+    ('𠈥', "guǎ"),
+    ('𠈬', "xiāo"),
+    ('𠈭', "láo"),
+    ('𠈰', "dàn"),
+    ('𠈱', "suō"),
+    ('𠈵', "mǎng"),
+    ('𠈶', "yí"),
+    ('𠈸', "tè"),
+    ('𠈺', "bì"),
+    ('𠉂', "tà"),
+    ('𠉗', "luò"),
+    ('𠉢', "xǐ"),
+    ('𠉣', "hūn"),
+    ('𠉤', "dá"),
+    ('𠉧', "jù"),
+    ('𠉩', "dú"),
+    ('𠉬', "ǎn"),
+    ('𠊉', "mèi"),
+    ('𠊌', "rán"),
+    ('𠊎', "ái"),
+    ('𠊏', "yù"),
+    ('𠊒', "jiàn"),
+    ('𠊔', "qì"),
+    ('𠊟', "mǐn"),
+    ('𠊣', "zhòu"),
+    ('𠊤', "zhì"),
+    ('𠊥', "zhǒng"),
+    ('𠊦', "nǎo"),
+    ('𠊧', "bìng"),
+    ('𠊩', "zhuàn"),
+    ('𠊪', "shù"),
+    ('𠊫', "xùn"),
+    ('𠊬', "jué"),
+    ('𠊭', "qiǎn"),
+    ('𠊰', "guǎ"),
+    ('𠊲', "tū"),
+    ('𠊶', "yìng"),
+    ('𠊷', "zhì"),
+    ('𠊾', "kuí"),
+    ('𠋆', "chèn"),
+    ('𠋖', "liàn"),
+    ('𠋗', "yā"),
+    ('𠋜', "guò"),
+    ('𠋝', "miǎo"),
+    ('𠋞', "shé"),
+    ('𠋟', "yǔ"),
+    ('𠋡', "sì"),
+    ('𠋢', "sǒu"),
+    ('𠋤', "zhì"),
+    ('𠋧', "qiē"),
+    ('𠋩', "fù"),
+    ('𠋬', "jú"),
+    ('𠋭', "bèi"),
+    ('𠋯', "bì"),
+    ('𠋲', "suǒ"),
+    ('𠋵', "qiǎn"),
+    ('𠋶', "mǐng"),
+    ('𠋷', "chǎn"),
+    ('𠋺', "sāo"),
+    ('𠋻', "jī"),
+    ('𠌕', "gòng"),
+    ('𠌖', "qióng"),
+    ('𠌚', "ròng"),
+    ('𠌞', "sǒu"),
+    ('𠌟', "sǒu"),
+    ('𠌠', "yáo"),
+    ('𠌪', "chōu"),
+    ('𠌭', "shuài"),
+    ('𠌮', "zhē"),
+    ('𠌯', "lì"),
+    ('𠌰', "gài"),
+    ('𠌱', "suī"),
+    ('𠌲', "zhān"),
+    ('𠌴', "zhuàng"),
+    ('𠌽', "fù"),
+    ('𠍃', "jī"),
+    ('𠍄', "dōu"),
+    ('𠍗', "huì"),
+    ('𠍚', "jiǎn"),
+    ('𠍛', "yǎn"),
+    ('𠍜', "zhì"),
+    ('𠍨', "měi"),
+    ('𠍩', "yào"),
+    ('𠍪', "dī"),
+    ('𠍫', "yí"),
+    ('𠍯', "bié"),
+    ('𠍲', "qú"),
+    ('𠍳', "yì"),
+    ('𠍵', "yàng"),
+    ('𠍹', "zhá"),
+    ('𠍽', "shà"),
+    ('𠎙', "lái"),
+    ('𠎮', "jué"),
+    ('𠎰', "qī"),
+    ('𠎳', "yú"),
+    ('𠎶', "zǎi"),
+    ('𠎷', "sà"),
+    ('𠎸', "sè"),
+    ('𠎻', "dùn"),
+    ('𠎿', "jiě"),
+    ('𠏀', "kē"),
+    ('𠏃', "yuē"),
+    ('𠏇', "jiǎn"),
+    ('𠏈', "yáo"),
+    ('𠏓', "xiān"),
+    ('𠏕', "xiào"),
+    ('𠏖', "qiāo"),
+    ('𠏚', "yù"),
+    ('𠏛', "qú"),
+    ('𠏡', "xiān"),
+    ('𠏢', "luò"),
+    ('𠏤', "guǎng"),
+    ('𠏧', "chēng"),
+    ('𠏨', "chuǎng"),
+    ('𠏩', "yí"),
+    ('𠏫', "zhěng"),
+    ('𠏭', "zòng"),
+    ('𠏮', "duì"),
+    ('𠏰', "zhǎi"),
+    ('𠏿', "fěi"),
+    ('𠐀', "yí"),
+    ('𠐁', "méng"),
+    ('𠐈', "biān"),
+    ('𠐉', "jié"),
+    ('𠐊', "shù"),
+    ('𠐋', "liáo"),
+    ('𠐌', "bǐ"),
+    ('𠐍', "sú"),
+    ('𠐑', "dì"),
+    ('𠐡', "bèi"),
+    ('𠐢', "wèn"),
+    ('𠐧', "méng"),
+    ('𠐩', "chǎn"),
+    ('𠐵', "dǎo"),
+    ('𠐺', "pín"),
+    ('𠐻', "jiǎn"),
+    ('𠐼', "lìn"),
+    ('𠐽', "guì"),
+    ('𠐾', "qī"),
+    ('𠐿', "hōng"),
+    ('𠑃', "jí"),
+    ('𠑄', "xiè"),
+    ('𠑅', "zhēng"),
+    ('𠑆', "chǎn"),
+    ('𠑐', "yáo"),
+    ('𠑑', "chǎn"),
+    ('𠑘', "diān"),
+    ('𠑙', "chòng"),
+    ('𠑚', "néi"),
+    ('𠑛', "néi"),
+    ('𠑞', "zhài"),
+    ('𠑟', "biān"),
+    ('𠑡', "chǎn"),
+    ('𠑪', "xiāo"),
+    ('𠑯', "cù"),
+    ('𠑰', "xīn"),
+    ('𠑱', "jǐng"),
+    ('𠑲', "qiān"),
+    ('𠑴', "qīng"),
+    ('𠑹', "gǔ"),
+    ('𠒄', "wù"),
+    ('𠒜', "yuǎn"),
+    ('𠒝', "bǐng"),
+    ('𠒢', "wán"),
+    ('𠒰', "niǎo"),
+    ('𠒵', "liàn"),
+    ('𠒸', "rǎo"),
+    ('𠒾', "fàn"),
+    ('𠒿', "dí"),
+    ('𠓊', "huī"),
+    ('𠓋', "yì"),
+    ('𠓌', "xián"),
+    ('𠓖', "lán"),
+    ('𠓗', "fù"),
+    ('𠓙', "xiòng"),
+    ('𠓜', "liǎng"),
+    ('𠓝', "tāo"),
+    ('𠓞', "jí"),
+    ('𠓢', "jiè"),
+    ('𠓣', "zhá"),
+    ('𠓤', "shī"),
+    ('𠓪', "qí"),
+    ('𠓫', "biǎn"),
+    ('𠓭', "lǎn"),
+    ('𠓮', "lǐn"),
+    ('𠓶', "zhì"),
+    ('𠓷', "bì"),
+    ('𠓸', "shèng"),
+    ('𠓽', "shèng"),
+    ('𠓿', "qín"),
+    ('𠔂', "biāo"),
+    ('𠔃', "xī"),
+    ('𠔉', "juàn"),
+    ('𠔋', "jī"),
+    ('𠔍', "xī"),
+    ('𠔎', "qǐn"),
+    ('𠔑', "hài"),
+    ('𠔕', "lún"),
+    ('𠔠', "yuè"),
+    ('𠔨', "lián"),
+    ('𠔯', "bān"),
+    ('𠔲', "héng"),
+    ('𠔶', "qī"),
+    ('𠔺', "qiān"),
+    ('𠔻', "zhèng"),
+    ('𠔼', "mǎo"),
+    ('𠕁', "cóng"),
+    ('𠕄', "nà"),
+    ('𠕊', "tǐng"),
+    ('𠕌', "zōng"),
+    ('𠕕', "jiōng"),
+    ('𠕖', "zhǎo"),
+    ('𠕟', "niǎn"),
+    ('𠕠', "chéng"),
+    ('𠕣', "qià"),
+    ('𠕦', "yù"),
+    ('𠕧', "jiǎo"),
+    ('𠕭', "zhào"),
+    ('𠕳', "dí"),
+    ('𠕴', "jiū"),
+    ('𠕸', "suǐ"),
+    ('𠕻', "yāo"),
+    ('𠕿', "wāng"),
+    ('𠖂', "liáo"),
+    ('𠖄', "tóng"),
+    ('𠖆', "mèng"),
+    ('𠖋', "yǒu"),
+    ('𠖓', "sī"),
+    ('𠖛', "lòu"),
+    ('𠖟', "yīn"),
+    ('𠖥', "chǒng"),
+    ('𠖫', "gǎn"),
+    ('𠖬', "jiū"),
+    ('𠖶', "qìn"),
+    ('𠖷', "jiǒng"),
+    ('𠖹', "xié"),
+    ('𠗂', "hè"),
+    ('𠗆', "tāo"),
+    ('𠗈', "qiú"),
+    ('𠗉', "xié"),
+    ('𠗊', "jìng"),
+    ('𠗋', "niǎn"),
+    ('𠗌', "jìng"),
+    ('𠗏', "jí"),
+    ('𠗘', "tiǎn"),
+    ('𠗚', "cuì"),
+    ('𠗛', "dié"),
+    ('𠗝', "qǐng"),
+    ('𠗥', "pìng"),
+    ('𠗦', "píng"),
+    ('𠗨', "dié"),
+    ('𠗩', "lòu"),
+    ('𠗳', "liǎn"),
+    ('𠗴', "hán"),
+    ('𠗵', "pāng"),
+    ('𠗶', "táng"),
+    ('𠗺', "yí"),
+    ('𠗻', "xuán"),
+    ('𠗼', "suò"),
+    ('𠗽', "liú"),
+    ('𠗾', "shuǎng"),
+    ('𠗿', "shèn"),
+    ('𠘁', "bù"),
+    ('𠘂', "sōu"),
+    ('𠘅', "qín"),
+    ('𠘆', "shěn"),
+    ('𠘊', "nòng"),
+    ('𠘋', "tǐng"),
+    ('𠘌', "jiāng"),
+    ('𠘕', "xī"),
+    ('𠘖', "zhì"),
+    ('𠘝', "lài"),
+    ('𠘞', "lì"),
+    ('𠘟', "lì"),
+    ('𠘢', "hé"),
+    ('𠘣', "jiào"),
+    ('𠘥', "yán"),
+    ('𠘧', "shū"),
+    ('𠘪', "shǐ"),
+    ('𠘱', "zhěn"),
+    ('𠘳', "yōu"),
+    ('𠘺', "suò"),
+    ('𠘻', "wú"),
+    ('𠙁', "cháng"),
+    ('𠙂', "cóng"),
+    ('𠙆', "jù"),
+    ('𠙎', "shū"),
+    ('𠙔', "jiù"),
+    ('𠙕', "wéi"),
+    ('𠙞', "huò"),
+    ('𠙤', "jiē"),
+    ('𠙬', "zǎo"),
+    ('𠙶', "ǒu"),
+    ('𠙼', "guǎ"),
+    ('𠚃', "háo"),
+    ('𠚄', "lǐ"),
+    ('𠚅', "zhì"),
+    ('𠚆', "xiàn"),
+    ('𠚉', "bū"),
+    ('𠚊', "chàng"),
+    ('𠚓', "yūn"),
+    ('𠚔', "hé"),
+    ('𠚜', "tāo"),
+    ('𠚠', "biāo"),
+    ('𠚥', "diāo"),
+    ('𠚧', "èr"),
+    ('𠚨', "jiū"),
+    ('𠚭', "dì"),
+    ('𠚮', "yì"),
+    ('𠚯', "kūn"),
+    ('𠚱', "zhé"),
+    ('𠚳', "kuò"),
+    ('𠚴', "zhōu"),
+    ('𠚵', "jù"),
+    ('𠚹', "shàn"),
+    ('𠚺', "shà"),
+    ('𠚻', "diāo"),
+    ('𠚼', "bān"),
+    ('𠚽', "jī"),
+    ('𠛀', "zhōng"),
+    ('𠛃', "yí"),
+    ('𠛅', "kōu"),
+    ('𠛆', "wū"),
+    ('𠛊', "gē"),
+    ('𠛋', "bā"),
+    ('𠛎', "gōu"),
+    ('𠛑', "xián"),
+    ('𠛒', "guā"),
+    ('𠛓', "liǔ"),
+    ('𠛔', "chǐ"),
+    ('𠛕', "guāi"),
+    ('𠛖', "chuān"),
+    ('𠛘', "lí"),
+    ('𠛙', "cù"),
+    ('𠛚', "shuā"),
+    ('𠛡', "bǐ"),
+    ('𠛥', "bǐng"),
+    ('𠛦', "lì"),
+    ('𠛩', "jiǔ"),
+    ('𠛪', "tiāo"),
+    ('𠛫', "duǒ"),
+    ('𠛭', "yān"),
+    ('𠛮', "quān"),
+    ('𠛱', "liè"),
+    ('𠛳', "kè"),
+    ('𠛵', "gēn"),
+    ('𠛶', "zhēn"),
+    ('𠛸', "fén"),
+    ('𠜁', "yí"),
+    ('𠜃', "jiù"),
+    ('𠜄', "xù"),
+    ('𠜅', "jiǎo"),
+    ('𠜈', "lǜ"),
+    ('𠜉', "jiǔ"),
+    ('𠜋', "chǒu"),
+    ('𠜎', "xiàn"),
+    ('𠜐', "kuài"),
+    ('𠜑', "duì"),
+    ('𠜖', "luō"),
+    ('𠜗', "xī"),
+    ('𠜘', "qìn"),
+    ('𠜙', "bù"),
+    ('𠜤', "qià"),
+    ('𠜱', "pī"),
+    ('𠜲', "yā"),
+    ('𠜳', "bēng"),
+    ('𠜴', "guǒ"),
+    ('𠜵', "guā"),
+    ('𠜹', "jú"),
+    ('𠜼', "qiā"),
+    ('𠜾', "jué"),
+    ('𠝄', "lì"),
+    ('𠝐', "huā"),
+    ('𠝑', "jiāo"),
+    ('𠝘', "qià"),
+    ('𠝚', "zhá"),
+    ('𠝛', "qiā"),
+    ('𠝝', "zhé"),
+    ('𠝞', "chā"),
+    ('𠝟', "yǐng"),
+    ('𠝢', "yān"),
+    ('𠝤', "chōng"),
+    ('𠝨', "chǐ"),
+    ('𠝪', "wān"),
+    ('𠝬', "sōu"),
+    ('𠝲', "kǎn"),
+    ('𠝳', "yuán"),
+    ('𠝽', "chóu"),
+    ('𠝿', "suǒ"),
+    ('𠞀', "tū"),
+    ('𠞃', "zhé"),
+    ('𠞄', "tī"),
+    ('𠞆', "wū"),
+    ('𠞈', "dā"),
+    ('𠞉', "lì"),
+    ('𠞊', "chā"),
+    ('𠞕', "róng"),
+    ('𠞖', "gòng"),
+    ('𠞗', "què"),
+    ('𠞙', "lí"),
+    ('𠞞', "tāo"),
+    ('𠞤', "lì"),
+    ('𠞧', "mí"),
+    ('𠞩', "chì"),
+    ('𠞬', "gùn"),
+    ('𠞭', "lóu"),
+    ('𠞮', "chuǎng"),
+    ('𠞯', "suǒ"),
+    ('𠞰', "jiǎo"),
+    ('𠞱', "jìn"),
+    ('𠞵', "fá"),
+    ('𠞶', "zhāi"),
+    ('𠞾', "jìn"),
+    ('𠞿', "cuì"),
+    ('𠟂', "cèng"),
+    ('𠟃', "zǔn"),
+    ('𠟅', "zhào"),
+    ('𠟈', "piē"),
+    ('𠟉', "zhǎn"),
+    ('𠟊', "xī"),
+    ('𠟋', "yào"),
+    ('𠟌', "fǔ"),
+    ('𠟍', "chōng"),
+    ('𠟓', "cuì"),
+    ('𠟗', "guā"),
+    ('𠟣', "jī"),
+    ('𠟦', "sè"),
+    ('𠟧', "zhān"),
+    ('𠟨', "lìng"),
+    ('𠟩', "sè"),
+    ('𠟪', "yè"),
+    ('𠟰', "jū"),
+    ('𠟶', "tū"),
+    ('𠟺', "rú"),
+    ('𠟻', "zé"),
+    ('𠟼', "huán"),
+    ('𠠁', "xiǎn"),
+    ('𠠃', "qiān"),
+    ('𠠄', "zhào"),
+    ('𠠋', "cán"),
+    ('𠠎', "kuò"),
+    ('𠠏', "lì"),
+    ('𠠐', "róu"),
+    ('𠠔', "dú"),
+    ('𠠗', "liè"),
+    ('𠠜', "yīng"),
+    ('𠠝', "lì"),
+    ('𠠠', "dú"),
+    ('𠠢', "líng"),
+    ('𠠪', "wān"),
+    ('𠠯', "dié"),
+    ('𠠳', "jiū"),
+    ('𠠵', "lì"),
+    ('𠠶', "kū"),
+    ('𠠷', "kēng"),
+    ('𠠹', "zhěn"),
+    ('𠡀', "hè"),
+    ('𠡂', "bì"),
+    ('𠡄', "pī"),
+    ('𠡊', "hāng"),
+    ('𠡑', "zhuó"),
+    ('𠡒', "duǐ"),
+    ('𠡔', "yì"),
+    ('𠡜', "kè"),
+    ('𠡝', "yì"),
+    ('𠡞', "mò"),
+    ('𠡠', "chì"),
+    ('𠡡', "cán"),
+    ('𠡣', "gěng"),
+    ('𠡤', "kè"),
+    ('𠡥', "shì"),
+    ('𠡭', "líng"),
+    ('𠡮', "bēng"),
+    ('𠡱', "duàn"),
+    ('𠡶', "juān"),
+    ('𠡷', "nǎo"),
+    ('𠡸', "zǐ"),
+    ('𠡻', "zòng"),
+    ('𠢃', "táng"),
+    ('𠢆', "xiá"),
+    ('𠢇', "hàn"),
+    ('𠢌', "lüè"),
+    ('𠢍', "qián"),
+    ('𠢓', "mò"),
+    ('𠢔', "ōu"),
+    ('𠢕', "háo"),
+    ('𠢙', "zhá"),
+    ('𠢚', "juàn"),
+    ('𠢛', "cóng"),
+    ('𠢠', "lì"),
+    ('𠢡', "zhá"),
+    ('𠢢', "yǒu"),
+    ('𠢣', "diàn"),
+    ('𠢤', "jué"),
+    ('𠢥', "bèi"),
+    ('𠢩', "yǎo"),
+    ('𠢪', "piē"),
+    ('𠢱', "jìn"),
+    ('𠢲', "kǎi"),
+    ('𠢳', "sè"),
+    ('𠢴', "yǎng"),
+    ('𠢵', "jìn"),
+    ('𠢹', "kè"),
+    ('𠣄', "chān"),
+    ('𠣇', "niǎn"),
+    ('𠣉', "wàn"),
+    ('𠣊', "lǜ"),
+    ('𠣐', "yún"),
+    ('𠣑', "yāo"),
+    ('𠣒', "bāo"),
+    ('𠣕', "jūn"),
+    ('𠣖', "xuán"),
+    ('𠣘', "zhōu"),
+    ('𠣠', "kuì"),
+    ('𠣪', "qú"),
+    ('𠣫', "shào"),
+    ('𠣬', "sǔn"),
+    ('𠣰', "dū"),
+    ('𠣲', "kuǎi"),
+    ('𠣳', "pào"),
+    ('𠣺', "bào"),
+    ('𠣾', "fù"),
+    ('𠣿', "jiù"),
+    ('𠤀', "rán"),
+    ('𠤄', "jū"),
+    ('𠤊', "qióng"),
+    ('𠤍', "zhōu"),
+    ('𠤎', "huà"),
+    ('𠤏', "bǎo"),
+    ('𠤕', "yí"),
+    ('𠤗', "yí"),
+    ('𠤘', "yí"),
+    ('𠤝', "mào"),
+    ('𠤦', "ruǎn"),
+    ('𠤫', "cí"),
+    ('𠤮', "hán"),
+    ('𠤰', "cóng"),
+    ('𠤴', "xì"),
+    ('𠤹', "quán"),
+    ('𠤺', "tiáo"),
+    ('𠤼', "diào"),
+    ('𠤾', "hán"),
+    ('𠥇', "yě"),
+    ('𠥍', "ē"),
+    ('𠥎', "wéi"),
+    ('𠥐', "cāng"),
+    ('𠥑', "diào"),
+    ('𠥕', "è"),
+    ('𠥖', "dì"),
+    ('𠥘', "suǎn"),
+    ('𠥙', "quán"),
+    ('𠥜', "è"),
+    ('𠥝', "ōu"),
+    ('𠥞', "xuán"),
+    ('𠥢', "wǔ"),
+    ('𠥦', "yì"),
+    ('𠥨', "móu"),
+    ('𠥰', "hū"),
+    ('𠥴', "hán"),
+    ('𠥿', "shí"),
+    ('𠦃', "sà"),
+    ('𠦈', "bì"),
+    ('𠦊', "hán"),
+    ('𠦋', "jìng"),
+    ('𠦌', "xì"),
+    ('𠦎', "qìn"),
+    ('𠦏', "cuó"),
+    ('𠦐', "cì"),
+    ('𠦒', "bān"),
+    ('𠦗', "duī"),
+    ('𠦜', "xì"),
+    ('𠦧', "zhī"),
+    ('𠦨', "luàn"),
+    ('𠦪', "hū"),
+    ('𠦫', "jí"),
+    ('𠦬', "guāi"),
+    ('𠦲', "pāng"),
+    ('𠧀', "zhū"),
+    ('𠧅', "bǐ"),
+    ('𠧇', "yú"),
+    ('𠧒', "qǐ"),
+    ('𠧕', "hé"),
+    ('𠧖', "chǔ"),
+    ('𠧙', "shào"),
+    ('𠧚', "chì"),
+    ('𠧛', "bó"),
+    ('𠧟', "réng"),
+    ('𠧠', "yóu"),
+    ('𠧤', "nǎi"),
+    ('𠧩', "huì"),
+    ('𠧪', "tiáo"),
+    ('𠧫', "bǎn"),
+    ('𠧰', "xū"),
+    ('𠧴', "yóu"),
+    ('𠧵', "chì"),
+    ('𠧿', "héng"),
+    ('𠨃', "wài"),
+    ('𠨆', "xiè"),
+    ('𠨊', "jué"),
+    ('𠨌', "suī"),
+    ('𠨍', "qīng"),
+    ('𠨎', "zhuàn"),
+    ('𠨕', "jì"),
+    ('𠨘', "bì"),
+    ('𠨚', "xī"),
+    ('𠨠', "jí"),
+    ('𠨢', "jùn"),
+    ('𠨥', "liáo"),
+    ('𠨦', "yōu"),
+    ('𠨭', "jú"),
+    ('𠨲', "yuè"),
+    ('𠨵', "bàng"),
+    ('𠨸', "pí"),
+    ('𠨻', "zè"),
+    ('𠨾', "yì"),
+    ('𠨿', "dǐ"),
+    ('𠩂', "qiè"),
+    ('𠩄', "suǒ"),
+    ('𠩆', "cì"),
+    ('𠩈', "zhù"),
+    ('𠩉', "yuè"),
+    ('𠩏', "jiāo"),
+    ('𠩔', "shí"),
+    ('𠩗', "yí"),
+    ('𠩘', "xiá"),
+    ('𠩠', "yuán"),
+    ('𠩥', "guó"),
+    ('𠩧', "kè"),
+    ('𠩪', "cuì"),
+    ('𠩫', "yì"),
+    ('𠩵', "lì"),
+    ('𠩷', "diǎn"),
+    ('𠩺', "xī"),
+    ('𠩿', "bì"),
+    ('𠪂', "biǎn"),
+    ('𠪃', "méi"),
+    ('𠪄', "lì"),
+    ('𠪇', "sǒu"),
+    ('𠪐', "liú"),
+    ('𠪑', "guì"),
+    ('𠪒', "kè"),
+    ('𠪗', "yí"),
+    ('𠪙', "xǐ"),
+    ('𠪚', "yín"),
+    ('𠪟', "kè"),
+    ('𠪣', "shè"),
+    ('𠪧', "wǒ"),
+    ('𠪮', "pì"),
+    ('𠪶', "yuè"),
+    ('𠪷', "hóng"),
+    ('𠪺', "lì"),
+    ('𠪻', "fù"),
+    ('𠫃', "jué"),
+    ('𠫄', "xiān"),
+    ('𠫉', "diān"),
+    ('𠫌', "lì"),
+    ('𠫓', "tū"),
+    ('𠫘', "jiān"),
+    ('𠫛', "bǎi"),
+    ('𠫜', "dì"),
+    ('𠫝', "zhǎng"),
+    ('𠫣', "yù"),
+    ('𠫨', "duì"),
+    ('𠫭', "cān"),
+    ('𠫮', "tú"),
+    ('𠫶', "tān"),
+    ('𠫷', "jí"),
+    ('𠫸', "qí"),
+    ('𠫹', "shàn"),
+    ('𠫺', "nián"),
+    ('𠬆', "guàn"),
+    ('𠬈', "bǐ"),
+    ('𠬋', "xīng"),
+    ('𠬓', "zhěn"),
+    ('𠬙', "sā"),
+    ('𠬛', "mò"),
+    ('𠬝', "fú"),
+    ('𠬢', "tāo"),
+    ('𠬣', "bàng"),
+    ('𠬤', "yì"),
+    ('𠬪', "biào"),
+    ('𠬬', "xī"),
+    ('𠬮', "jié"),
+    ('𠬶', "jìn"),
+    ('𠬾', "qiān"),
+    ('𠭈', "sì"),
+    ('𠭉', "jǐng"),
+    ('𠭋', "chǐ"),
+    ('𠭗', "jǐng"),
+    ('𠭥', "suì"),
+    ('𠭯', "zhā"),
+    ('𠭰', "lí"),
+    ('𠭴', "zhuō"),
+    ('𠭹', "biàn"),
+    ('𠭿', "tún"),
+    ('𠮃', "bì"),
+    ('𠮆', "fèi"),
+    ('𠮊', "dé"),
+    ('𠮌', "zhú"),
+    ('𠮑', "jū"),
+    ('𠮙', "yǐ"),
+    ('𠮜', "yà"),
+    ('𠮟', "chì"),
+    ('𠮠', "guǎ"),
+    ('𠮡', "zhǐ"),
+    ('𠮨', "réng"),
+    ('𠮫', "yōu"),
+    ('𠮭', "bó"),
+    ('𠮯', "jǐ"),
+    ('𠮰', "pǐn"),
+    ('𠮳', "yīng"),
+    ('𠮴', "yāng"),
+    ('𠮵', "màng"),
+    ('𠮽', "lòng"),
+    ('𠮾', "ǹ"),
+    ('𠮿', "sa"),
+    ('𠯀', "chuān"),
+    ('𠯂', "cí"),
+    ('𠯃', "wǔ"),
+    ('𠯄', "rèn"),
+    ('𠯈', "dài"),
+    ('𠯉', "jí"),
+    ('𠯋', "yǐ"),
+    ('𠯍', "rán"),
+    ('𠯐', "huò"),
+    ('𠯑', "guā"),
+    ('𠯓', "zhé"),
+    ('𠯔', "pì"),
+    ('𠯗', "zā"),
+    ('𠯘', "bàn"),
+    ('𠯙', "jié"),
+    ('𠯜', "hōu"),
+    ('𠯟', "xiàn"),
+    ('𠯠', "huī"),
+    ('𠯩', "zhā"),
+    ('𠯪', "dāi"),
+    ('𠯫', "gē"),
+    ('𠯭', "pì"),
+    ('𠯯', "piàn"),
+    ('𠯰', "shí"),
+    ('𠯱', "liǎng"),
+    ('𠯲', "yuè"),
+    ('𠯳', "hù"),
+    ('𠯴', "biàn"),
+    ('𠯷', "réng"),
+    ('𠯹', "réng"),
+    ('𠰄', "yī"),
+    ('𠰅', "zhī"),
+    ('𠰇', "jīn"),
+    ('𠰈', "wēng"),
+    ('𠰉', "chāo"),
+    ('𠰋', "qiū"),
+    ('𠰍', "zhǔ"),
+    ('𠰏', "zhá"),
+    ('𠰐', "pǒ"),
+    ('𠰑', "àn"),
+    ('𠰓', "hé"),
+    ('𠰕', "chū"),
+    ('𠰖', "yán"),
+    ('𠰚', "shì"),
+    ('𠰛', "hù"),
+    ('𠰜', "è"),
+    ('𠰴', "shí"),
+    ('𠰷', "lú"),
+    ('𠰹', "tuō"),
+    ('𠰺', "dài"),
+    ('𠰻', "wài"),
+    ('𠰼', "pō"),
+    ('𠰽', "rǒng"),
+    ('𠰾', "jū"),
+    ('𠱀', "bō"),
+    ('𠱐', "yǔ"),
+    ('𠱑', "dōu"),
+    ('𠱓', "guǐ"),
+    ('𠱔', "shòu"),
+    ('𠱗', "suō"),
+    ('𠱘', "nì"),
+    ('𠱙', "zhōu"),
+    ('𠱚', "lòng"),
+    ('𠱛', "bǐng"),
+    ('𠱜', "zùn"),
+    ('𠱝', "yè"),
+    ('𠱞', "rǎn"),
+    ('𠱠', "líng"),
+    ('𠱡', "sà"),
+    ('𠱤', "lěi"),
+    ('𠱥', "è"),
+    ('𠱧', "zhòng"),
+    ('𠱨', "jǐ"),
+    ('𠱫', "è"),
+    ('𠱯', "zuò"),
+    ('𠱲', "nà"),
+    ('𠱳', "yǔn"),
+    ('𠲊', "xiè"),
+    ('𠲋', "zuǐ"),
+    ('𠲌', "shù"),
+    ('𠲍', "diū"),
+    ('𠲎', "fa"),
+    ('𠲏', "rěn"),
+    ('𠲑', "bāng"),
+    ('𠲒', "hán"),
+    ('𠲓', "hóng"),
+    ('𠲔', "yī"),
+    ('𠲖', "yī"),
+    ('𠲙', "kē"),
+    ('𠲚', "yì"),
+    ('𠲛', "huí"),
+    ('𠲜', "zhēng"),
+    ('𠲮', "jìng"),
+    ('𠲱', "gé"),
+    ('𠲴', "nóu"),
+    ('𠲵', "qiè"),
+    ('𠲷', "dié"),
+    ('𠲹', "jì"),
+    ('𠲺', "yì"),
+    ('𠲻', "yí"),
+    ('𠲽', "fú"),
+    ('𠲾', "shuò"),
+    ('𠲿', "shuò"),
+    ('𠳀', "yǒng"),
+    ('𠳁', "kěn"),
+    ('𠳂', "huá"),
+    ('𠳃', "hòng"),
+    ('𠳇', "hé"),
+    ('𠳊', "hē"),
+    ('𠳋', "qiǎn"),
+    ('𠳌', "qià"),
+    ('𠳎', "sì"),
+    ('𠳐', "bāng"),
+    ('𠳞', "jué"),
+    ('𠳬', "jīng"),
+    ('𠳭', "kè"),
+    ('𠳳', "āi"),
+    ('𠳴', "lóu"),
+    ('𠳶', "tū"),
+    ('𠳹', "chuáng"),
+    ('𠳼', "sòng"),
+    ('𠳽', "chéng"),
+    ('𠳿', "wēi"),
+    ('𠴂', "nǔ"),
+    ('𠴄', "jiǔ"),
+    ('𠴇', "bīn"),
+    ('𠴡', "xiào"),
+    ('𠴢', "shēng"),
+    ('𠴣', "hǒu"),
+    ('𠴦', "zhù"),
+    ('𠴨', "guān"),
+    ('𠴩', "jī"),
+    ('𠴫', "jì"),
+    ('𠴭', "xī"),
+    ('𠴯', "shè"),
+    ('𠴰', "ǒu"),
+    ('𠴱', "hú"),
+    ('𠴲', "tà"),
+    ('𠴳', "xiáo"),
+    ('𠴵', "zào"),
+    ('𠴸', "bò"),
+    ('𠴹', "qì"),
+    ('𠴺', "wā"),
+    ('𠴻', "tuō"),
+    ('𠴼', "dào"),
+    ('𠴾', "nà"),
+    ('𠵠', "zhāi"),
+    ('𠵣', "yà"),
+    ('𠵦', "wǔ"),
+    ('𠵧', "zhén"),
+    ('𠵨', "de"),
+    ('𠵩', "hē"),
+    ('𠵫', "āng"),
+    ('𠵬', "pí"),
+    ('𠵭', "sè"),
+    ('𠵮', "fěn"),
+    ('𠵯', "guā"),
+    ('𠵳', "pǒ"),
+    ('𠵷', "xuàn"),
+    ('𠵸', "hān"),
+    ('𠵹', "gāng"),
+    ('𠵺', "bā"),
+    ('𠵻', "zōng"),
+    ('𠵼', "mèng"),
+    ('𠵾', "huò"),
+    ('𠶧', "diān"),
+    ('𠶨', "xī"),
+    ('𠶫', "dà"),
+    ('𠶬', "nàng"),
+    ('𠶰', "diāo"),
+    ('𠶱', "luò"),
+    ('𠶲', "kè"),
+    ('𠶷', "yì"),
+    ('𠶸', "jué"),
+    ('𠶹', "hé"),
+    ('𠶻', "jí"),
+    ('𠶾', "hè"),
+    ('𠶿', "niè"),
+    ('𠷀', "rǔn"),
+    ('𠷁', "qián"),
+    ('𠷂', "dài"),
+    ('𠷃', "shāo"),
+    ('𠷄', "kè"),
+    ('𠷅', "zhú"),
+    ('𠷇', "shī"),
+    ('𠷈', "lǜ"),
+    ('𠷉', "jiā"),
+    ('𠷊', "pián"),
+    ('𠷋', "hòu"),
+    ('𠷌', "jī"),
+    ('𠷍', "tà"),
+    ('𠷎', "chóu"),
+    ('𠷏', "wō"),
+    ('𠷐', "jìng"),
+    ('𠷑', "pō"),
+    ('𠷒', "zhāi"),
+    ('𠷓', "xīn"),
+    ('𠷖', "biàn"),
+    ('𠷙', "xù"),
+    ('𠷞', "gū"),
+    ('𠷟', "jiè"),
+    ('𠷢', "xián"),
+    ('𠷸', "é"),
+    ('𠷺', "bó"),
+    ('𠷻', "piāo"),
+    ('𠷿', "zǎ"),
+    ('𠸁', "pài"),
+    ('𠸂', "tū"),
+    ('𠸄', "yīng"),
+    ('𠸮', "xiǎng"),
+    ('𠸱', "nuò"),
+    ('𠸲', "gē"),
+    ('𠸳', "bó"),
+    ('𠸴', "xiè"),
+    ('𠸸', "zhēn"),
+    ('𠸹', "yú"),
+    ('𠸺', "nì"),
+    ('𠹀', "xùn"),
+    ('𠹁', "wà"),
+    ('𠹃', "àng"),
+    ('𠹄', "hàn"),
+    ('𠹅', "hōng"),
+    ('𠹆', "dān"),
+    ('𠹈', "nuó"),
+    ('𠹊', "cǎo"),
+    ('𠹋', "jí"),
+    ('𠹌', "něng"),
+    ('𠹍', "yǒng"),
+    ('𠹎', "xiāo"),
+    ('𠹐', "chuǎ"),
+    ('𠹑', "yào"),
+    ('𠹓', "gé"),
+    ('𠹔', "táng"),
+    ('𠹕', "bào"),
+    ('𠹖', "chǎn"),
+    ('𠹘', "xù"),
+    ('𠹛', "hái"),
+    ('𠹝', "chóu"),
+    ('𠹟', "jiǎn"),
+    ('𠹠', "zuō"),
+    ('𠹤', "wèi"),
+    ('𠹥', "dā"),
+    ('𠹦', "pī"),
+    ('𠺐', "huàn"),
+    ('𠺒', "xī"),
+    ('𠺔', "pèn"),
+    ('𠺕', "liū"),
+    ('𠺖', "mǔ"),
+    ('𠺗', "miē"),
+    ('𠺘', "làng"),
+    ('𠺙', "tuì"),
+    ('𠺚', "bān"),
+    ('𠺝', "gē"),
+    ('𠺟', "kù"),
+    ('𠺢', "jiā"),
+    ('𠺣', "bō"),
+    ('𠻍', "huàn"),
+    ('𠻏', "zú"),
+    ('𠻐', "luò"),
+    ('𠻗', "lí"),
+    ('𠻙', "hé"),
+    ('𠻚', "mó"),
+    ('𠻜', "shuì"),
+    ('𠻝', "shēn"),
+    ('𠻞', "kǎng"),
+    ('𠻟', "chì"),
+    ('𠻠', "líng"),
+    ('𠻡', "luǒ"),
+    ('𠻤', "yǎn"),
+    ('𠻥', "zhào"),
+    ('𠻦', "chuǎ"),
+    ('𠻧', "gǔ"),
+    ('𠻨', "qǐn"),
+    ('𠻪', "tán"),
+    ('𠻫', "fèn"),
+    ('𠻬', "tú"),
+    ('𠻱', "líng"),
+    ('𠻴', "lǎng"),
+    ('𠼖', "lán"),
+    ('𠼗', "zàn"),
+    ('𠼘', "wù"),
+    ('𠼝', "lí"),
+    ('𠼞', "ā"),
+    ('𠼟', "lüè"),
+    ('𠼠', "zhǐ"),
+    ('𠼡', "chóu"),
+    ('𠼢', "jiàng"),
+    ('𠼤', "jiān"),
+    ('𠼩', "lún"),
+    ('𠼪', "yí"),
+    ('𠼬', "shāng"),
+    ('𠼻', "jī"),
+    ('𠽜', "yì"),
+    ('𠽝', "nín"),
+    ('𠽡', "huì"),
+    ('𠽣', "zhā"),
+    ('𠽦', "hǎn"),
+    ('𠽨', "yǐn"),
+    ('𠽩', "bì"),
+    ('𠽪', "ān"),
+    ('𠽫', "xiā"),
+    ('𠽬', "ní"),
+    ('𠽰', "dī"),
+    ('𠽱', "jiǎn"),
+    ('𠽲', "pán"),
+    ('𠽵', "yù"),
+    ('𠽶', "chuài"),
+    ('𠽷', "zā"),
+    ('𠽹', "chá"),
+    ('𠽻', "zhé"),
+    ('𠽼', "sè"),
+    ('𠽾', "pēn"),
+    ('𠽿', "gū"),
+    ('𠾀', "zhé"),
+    ('𠾆', "lí"),
+    ('𠾇', "dōu"),
+    ('𠾉', "chóu"),
+    ('𠾋', "zuǐ"),
+    ('𠾌', "pò"),
+    ('𠾏', "shē"),
+    ('𠾐', "lóng"),
+    ('𠾢', "shù"),
+    ('𠾤', "jìn"),
+    ('𠾥', "líng"),
+    ('𠾨', "kāng"),
+    ('𠾩', "là"),
+    ('𠾫', "xū"),
+    ('𠾬', "jìn"),
+    ('𠾮', "chuān"),
+    ('𠾲', "yuè"),
+    ('𠿆', "mǎi"),
+    ('𠿇', "xiè"),
+    ('𠿈', "jiū"),
+    ('𠿉', "jì"),
+    ('𠿋', "yuè"),
+    ('𠿏', "jiān"),
+    ('𠿑', "hán"),
+    ('𠿓', "sà"),
+    ('𠿔', "huì"),
+    ('𠿕', "qiào"),
+    ('𠿗', "sè"),
+    ('𠿘', "zuǐ"),
+    ('𠿛', "lǔ"),
+    ('𠿜', "huà"),
+    ('𠿝', "chū"),
+    ('𠿞', "shǎn"),
+    ('𠿟', "wò"),
+    ('𠿠', "jí"),
+    ('𠿡', "zhuó"),
+    ('𠿢', "xián"),
+    ('𠿣', "yī"),
+    ('𠿤', "guó"),
+    ('𠿥', "kuì"),
+    ('𡀑', "zhōu"),
+    ('𡀔', "lù"),
+    ('𡀖', "bō"),
+    ('𡀗', "shí"),
+    ('𡀘', "yìng"),
+    ('𡀙', "kū"),
+    ('𡀹', "zhì"),
+    ('𡀺', "xié"),
+    ('𡀽', "yè"),
+    ('𡀾', "è"),
+    ('𡀿', "lǜ"),
+    ('𡁀', "hàn"),
+    ('𡁁', "yè"),
+    ('𡁆', "luò"),
+    ('𡁇', "chuò"),
+    ('𡁈', "fàn"),
+    ('𡁉', "zhí"),
+    ('𡁊', "yìng"),
+    ('𡁋', "wěn"),
+    ('𡁌', "wā"),
+    ('𡁍', "ài"),
+    ('𡁎', "yú"),
+    ('𡁑', "huā"),
+    ('𡁓', "liè"),
+    ('𡁔', "jīng"),
+    ('𡁕', "zá"),
+    ('𡁧', "zāng"),
+    ('𡁨', "duì"),
+    ('𡁪', "jì"),
+    ('𡁮', "wō"),
+    ('𡁰', "jí"),
+    ('𡁱', "xī"),
+    ('𡁳', "zhàn"),
+    ('𡁴', "tuán"),
+    ('𡂊', "yú"),
+    ('𡂏', "liè"),
+    ('𡂒', "zhì"),
+    ('𡂓', "shī"),
+    ('𡂕', "lǎo"),
+    ('𡂖', "lài"),
+    ('𡂗', "wěi"),
+    ('𡂘', "páo"),
+    ('𡂙', "chí"),
+    ('𡂚', "yǐng"),
+    ('𡂛', "dòu"),
+    ('𡂝', "dòu"),
+    ('𡂟', "bào"),
+    ('𡂠', "qiè"),
+    ('𡂡', "shù"),
+    ('𡂣', "zhí"),
+    ('𡂩', "liè"),
+    ('𡂫', "péng"),
+    ('𡂭', "zhē"),
+    ('𡂿', "ōu"),
+    ('𡃂', "xiè"),
+    ('𡃃', "jí"),
+    ('𡃄', "lài"),
+    ('𡃅', "yíng"),
+    ('𡃆', "cēng"),
+    ('𡃖', "lē"),
+    ('𡃝', "lùn"),
+    ('𡃡', "lóng"),
+    ('𡃢', "xì"),
+    ('𡃦', "lìn"),
+    ('𡃩', "guī"),
+    ('𡃳', "xīng"),
+    ('𡃷', "lí"),
+    ('𡃸', "cī"),
+    ('𡄇', "qǐng"),
+    ('𡄑', "jiān"),
+    ('𡄒', "dào"),
+    ('𡄓', "jiǎn"),
+    ('𡄔', "qìng"),
+    ('𡄕', "xiè"),
+    ('𡄖', "yìng"),
+    ('𡄟', "há"),
+    ('𡄡', "zhe"),
+    ('𡄢', "shē"),
+    ('𡄣', "mí"),
+    ('𡄤', "huán"),
+    ('𡄱', "cù"),
+    ('𡄲', "rú"),
+    ('𡄳', "sǎ"),
+    ('𡄴', "huò"),
+    ('𡄵', "yī"),
+    ('𡄷', "dī"),
+    ('𡄹', "luàn"),
+    ('𡄻', "yì"),
+    ('𡅂', "bò"),
+    ('𡅃', "páng"),
+    ('𡅄', "tán"),
+    ('𡅅', "é"),
+    ('𡅆', "zāng"),
+    ('𡅇', "cóng"),
+    ('𡅓', "zhāi"),
+    ('𡅕', "xǐ"),
+    ('𡅖', "mǎng"),
+    ('𡅘', "là"),
+    ('𡅙', "yùn"),
+    ('𡅡', "è"),
+    ('𡅥', "dié"),
+    ('𡅭', "guān"),
+    ('𡅱', "huàn"),
+    ('𡅵', "shì"),
+    ('𡅶', "jiǎn"),
+    ('𡅹', "zhān"),
+    ('𡅺', "jí"),
+    ('𡅻', "huàn"),
+    ('𡆅', "wàn"),
+    ('𡆆', "luǒ"),
+    ('𡆏', "dòu"),
+    ('𡆕', "liàn"),
+    ('𡆣', "niè"),
+    ('𡆤', "nǎn"),
+    ('𡆥', "jiù"),
+    ('𡆦', "yuè"),
+    ('𡆩', "yāo"),
+    ('𡆪', "chuāng"),
+    ('𡆮', "cǎn"),
+    ('𡆯', "lǐ"),
+    ('𡆰', "dùn"),
+    ('𡆱', "nǎn"),
+    ('𡆲', "nǎn"),
+    ('𡆸', "rì"),
+    ('𡆽', "yuè"),
+    ('𡇀', "yóu"),
+    ('𡇂', "yīn"),
+    ('𡇄', "guó"),
+    ('𡇈', "dàng"),
+    ('𡇑', "zhēn"),
+    ('𡇒', "mí"),
+    ('𡇓', "dié"),
+    ('𡇖', "zhēn"),
+    ('𡇚', "kuā"),
+    ('𡇜', "hán"),
+    ('𡇝', "sòng"),
+    ('𡇞', "hé"),
+    ('𡇟', "jī"),
+    ('𡇠', "zhé"),
+    ('𡇤', "bǐng"),
+    ('𡇦', "wéi"),
+    ('𡇧', "tōu"),
+    ('𡇩', "tú"),
+    ('𡇬', "gāng"),
+    ('𡇭', "lóu"),
+    ('𡇮', "quán"),
+    ('𡇯', "hùn"),
+    ('𡇰', "zhuǎn"),
+    ('𡇱', "què"),
+    ('𡇳', "hóng"),
+    ('𡇵', "dàng"),
+    ('𡇶', "hé"),
+    ('𡇷', "tài"),
+    ('𡇸', "guāi"),
+    ('𡇺', "yù"),
+    ('𡇼', "yà"),
+    ('𡇿', "wān"),
+    ('𡈀', "qūn"),
+    ('𡈅', "jué"),
+    ('𡈆', "ōu"),
+    ('𡈉', "quān"),
+    ('𡈊', "zhí"),
+    ('𡈍', "líng"),
+    ('𡈎', "wū"),
+    ('𡈏', "xìn"),
+    ('𡈐', "dá"),
+    ('𡈒', "yuān"),
+    ('𡈓', "yuàn"),
+    ('𡈗', "mò"),
+    ('𡈙', "yóu"),
+    ('𡈛', "wān"),
+    ('𡈞', "wǔ"),
+    ('𡈠', "zhāng"),
+    ('𡈣', "xuān"),
+    ('𡈦', "rǎo"),
+    ('𡈧', "gǔn"),
+    ('𡈨', "yù"),
+    ('𡈮', "xiá"),
+    ('𡈯', "biǎn"),
+    ('𡈰', "yóu"),
+    ('𡈲', "yīn"),
+    ('𡈴', "xuán"),
+    ('𡈵', "yóu"),
+    ('𡈶', "léi"),
+    ('𡈼', "tǐng"),
+    ('𡈿', "zhēn"),
+    ('𡉄', "zài"),
+    ('𡉅', "gā"),
+    ('𡉆', "lá"),
+    ('𡉉', "què"),
+    ('𡉎', "jú"),
+    ('𡉐', "chūn"),
+    ('𡉑', "dā"),
+    ('𡉒', "tún"),
+    ('𡉓', "āi"),
+    ('𡉗', "zǐ"),
+    ('𡉚', "huáng"),
+    ('𡉛', "yì"),
+    ('𡉩', "bào"),
+    ('𡉪', "chí"),
+    ('𡉭', "rì"),
+    ('𡉴', "lú"),
+    ('𡉷', "jié"),
+    ('𡉸', "shì"),
+    ('𡉺', "zuān"),
+    ('𡊁', "yì"),
+    ('𡊄', "fèn"),
+    ('𡊅', "fèn"),
+    ('𡊉', "mò"),
+    ('𡊍', "shù"),
+    ('𡊑', "xǐ"),
+    ('𡊛', "áo"),
+    ('𡊝', "pǐ"),
+    ('𡊞', "píng"),
+    ('𡊟', "pō"),
+    ('𡊠', "jiá"),
+    ('𡊡', "zhóu"),
+    ('𡊣', "qiū"),
+    ('𡊧', "yǒu"),
+    ('𡊨', "tán"),
+    ('𡊫', "rǒng"),
+    ('𡊭', "mì"),
+    ('𡊶', "yì"),
+    ('𡊸', "rǒng"),
+    ('𡊻', "liè"),
+    ('𡊼', "qióng"),
+    ('𡋙', "huí"),
+    ('𡋚', "jì"),
+    ('𡋟', "gào"),
+    ('𡋤', "jìn"),
+    ('𡋧', "yóu"),
+    ('𡋨', "chā"),
+    ('𡋩', "dé"),
+    ('𡋪', "yīn"),
+    ('𡋬', "yù"),
+    ('𡋭', "bèi"),
+    ('𡋯', "bó"),
+    ('𡌔', "qiāo"),
+    ('𡌚', "chǎ"),
+    ('𡌜', "xīn"),
+    ('𡌞', "chí"),
+    ('𡌣', "zào"),
+    ('𡌤', "kuí"),
+    ('𡌦', "fèi"),
+    ('𡌩', "tā"),
+    ('𡌪', "guài"),
+    ('𡌭', "duō"),
+    ('𡌲', "guī"),
+    ('𡌴', "zhí"),
+    ('𡍌', "chǎn"),
+    ('𡍍', "nǎo"),
+    ('𡍐', "hú"),
+    ('𡍒', "táo"),
+    ('𡍡', "yì"),
+    ('𡍤', "niè"),
+    ('𡍥', "zhài"),
+    ('𡍦', "huán"),
+    ('𡍨', "dù"),
+    ('𡍪', "qì"),
+    ('𡍫', "cè"),
+    ('𡍮', "chuí"),
+    ('𡍲', "dā"),
+    ('𡍶', "zhì"),
+    ('𡍷', "gèng"),
+    ('𡍻', "wèng"),
+    ('𡎉', "dù"),
+    ('𡎍', "chí"),
+    ('𡎑', "àn"),
+    ('𡎒', "kuò"),
+    ('𡎔', "wò"),
+    ('𡎘', "yīng"),
+    ('𡎚', "piǎn"),
+    ('𡎫', "zhá"),
+    ('𡎬', "zhuǎ"),
+    ('𡎮', "sù"),
+    ('𡎳', "nì"),
+    ('𡎺', "zhú"),
+    ('𡎻', "chán"),
+    ('𡎾', "bèng"),
+    ('𡎿', "ní"),
+    ('𡏀', "zhí"),
+    ('𡏁', "huì"),
+    ('𡏘', "xià"),
+    ('𡏚', "zhì"),
+    ('𡏛', "xī"),
+    ('𡏞', "jiǎng"),
+    ('𡏩', "duī"),
+    ('𡏪', "fū"),
+    ('𡏭', "jiāo"),
+    ('𡏮', "cháo"),
+    ('𡏯', "bài"),
+    ('𡏵', "liè"),
+    ('𡏼', "áo"),
+    ('𡐋', "zāo"),
+    ('𡐌', "chù"),
+    ('𡐏', "tuǒ"),
+    ('𡐒', "háo"),
+    ('𡐓', "kāng"),
+    ('𡐔', "yín"),
+    ('𡐖', "xiàn"),
+    ('𡐝', "fù"),
+    ('𡐞', "biē"),
+    ('𡐠', "kuī"),
+    ('𡐤', "qiè"),
+    ('𡐥', "sà"),
+    ('𡐿', "dā"),
+    ('𡑀', "yě"),
+    ('𡑄', "zhǎng"),
+    ('𡑆', "liáng"),
+    ('𡑈', "duǐ"),
+    ('𡑍', "láo"),
+    ('𡑎', "xūn"),
+    ('𡑘', "zhì"),
+    ('𡑚', "kū"),
+    ('𡑞', "suì"),
+    ('𡑟', "wō"),
+    ('𡑣', "kū"),
+    ('𡑯', "jiǎn"),
+    ('𡑶', "jiǎng"),
+    ('𡑻', "zhuì"),
+    ('𡑽', "shuǎng"),
+    ('𡑾', "yú"),
+    ('𡒁', "sà"),
+    ('𡒃', "yù"),
+    ('𡒄', "lǎn"),
+    ('𡒊', "yù"),
+    ('𡒌', "qiǎn"),
+    ('𡒍', "jù"),
+    ('𡒏', "liè"),
+    ('𡒒', "shú"),
+    ('𡒓', "xiàn"),
+    ('𡒖', "gài"),
+    ('𡒢', "tái"),
+    ('𡒧', "tiǎn"),
+    ('𡒯', "mèng"),
+    ('𡒱', "dí"),
+    ('𡒳', "mián"),
+    ('𡒾', "huī"),
+    ('𡓉', "duò"),
+    ('𡓍', "liè"),
+    ('𡓒', "lài"),
+    ('𡓓', "yín"),
+    ('𡓔', "lǎn"),
+    ('𡓖', "jiāo"),
+    ('𡓘', "huò"),
+    ('𡓣', "guō"),
+    ('𡓦', "zhàn"),
+    ('𡓭', "mǐ"),
+    ('𡓰', "kuī"),
+    ('𡓷', "duò"),
+    ('𡓿', "yín"),
+    ('𡔇', "lèi"),
+    ('𡔕', "gòng"),
+    ('𡔛', "tǐng"),
+    ('𡔜', "yáo"),
+    ('𡔞', "wǎng"),
+    ('𡔣', "jié"),
+    ('𡔨', "xiū"),
+    ('𡔪', "shù"),
+    ('𡔱', "wèi"),
+    ('𡔴', "yù"),
+    ('𡕁', "zhān"),
+    ('𡕉', "āng"),
+    ('𡕏', "sǎng"),
+    ('𡕐', "chóu"),
+    ('𡕒', "kuà"),
+    ('𡕖', "jǔ"),
+    ('𡕗', "hài"),
+    ('𡕢', "miǎn"),
+    ('𡕧', "hàng"),
+    ('𡕪', "chóu"),
+    ('𡕮', "líng"),
+    ('𡕰', "zōng"),
+    ('𡖉', "kūn"),
+    ('𡖌', "zhōng"),
+    ('𡖎', "zhāo"),
+    ('𡖐', "diě"),
+    ('𡖑', "gǒu"),
+    ('𡖒', "yún"),
+    ('𡖓', "dān"),
+    ('𡖔', "nuǒ"),
+    ('𡖛', "bǐng"),
+    ('𡖝', "rán"),
+    ('𡖞', "chān"),
+    ('𡖢', "rǒng"),
+    ('𡖣', "yīn"),
+    ('𡖤', "chān"),
+    ('𡖧', "zhì"),
+    ('𡖪', "guài"),
+    ('𡖫', "nuó"),
+    ('𡖬', "shēn"),
+    ('𡖯', "sù"),
+    ('𡖲', "wǒ"),
+    ('𡖳', "chǐ"),
+    ('𡖺', "miè"),
+    ('𡖻', "zhí"),
+    ('𡖾', "qī"),
+    ('𡗁', "gōu"),
+    ('𡗆', "lǒu"),
+    ('𡗈', "zī"),
+    ('𡗍', "dǎng"),
+    ('𡗏', "xiǎn"),
+    ('𡗑', "rǒu"),
+    ('𡗗', "pěng"),
+    ('𡗞', "xī"),
+    ('𡗢', "kuā"),
+    ('𡗤', "guì"),
+    ('𡗥', "chún"),
+    ('𡗦', "jiè"),
+    ('𡗲', "jiè"),
+    ('𡗳', "xī"),
+    ('𡗵', "kū"),
+    ('𡗷', "gū"),
+    ('𡗸', "zhà"),
+    ('𡗹', "fàn"),
+    ('𡗼', "xiè"),
+    ('𡘍', "huán"),
+    ('𡘏', "niǎo"),
+    ('𡘐', "xì"),
+    ('𡘛', "cū"),
+    ('𡘝', "gǔn"),
+    ('𡘡', "xī"),
+    ('𡘧', "qiá"),
+    ('𡘪', "māng"),
+    ('𡘭', "zhé"),
+    ('𡘰', "juàn"),
+    ('𡘴', "biē"),
+    ('𡙀', "biē"),
+    ('𡙅', "quán"),
+    ('𡙋', "xì"),
+    ('𡙎', "jiǎo"),
+    ('𡙐', "quán"),
+    ('𡙑', "zhǐ"),
+    ('𡙒', "tiān"),
+    ('𡙓', "kāi"),
+    ('𡙘', "sǎn"),
+    ('𡙛', "zī"),
+    ('𡙣', "jié"),
+    ('𡙪', "bié"),
+    ('𡙬', "dòu"),
+    ('𡙭', "zuī"),
+    ('𡙶', "yǎn"),
+    ('𡚁', "bì"),
+    ('𡚅', "kuǎi"),
+    ('𡚇', "yàn"),
+    ('𡚈', "wéi"),
+    ('𡚊', "huān"),
+    ('𡚌', "hào"),
+    ('𡚑', "gōng"),
+    ('𡚔', "méng"),
+    ('𡚗', "lěi"),
+    ('𡚙', "dì"),
+    ('𡚛', "bǐng"),
+    ('𡚜', "huān"),
+    ('𡚟', "wā"),
+    ('𡚠', "jué"),
+    ('𡚨', "chì"),
+    ('𡚭', "bā"),
+    ('𡚮', "jiǔ"),
+    ('𡚷', "dì"),
+    ('𡚹', "zhàng"),
+    ('𡚻', "dà"),
+    ('𡚼', "shí"),
+    ('𡚽', "hào"),
+    ('𡛌', "yè"),
+    ('𡛗', "bì"),
+    ('𡛘', "pǐ"),
+    ('𡛙', "yǎo"),
+    ('𡛜', "dī"),
+    ('𡛝', "càn"),
+    ('𡛞', "pín"),
+    ('𡛟', "yuè"),
+    ('𡛠', "qiē"),
+    ('𡛡', "pī"),
+    ('𡛵', "tuǒ"),
+    ('𡛶', "xiè"),
+    ('𡛽', "yè"),
+    ('𡜀', "fàn"),
+    ('𡜁', "guā"),
+    ('𡜂', "hù"),
+    ('𡜃', "rǔ"),
+    ('𡜉', "rǎn"),
+    ('𡜊', "fǒu"),
+    ('𡜋', "huāng"),
+    ('𡜚', "rú"),
+    ('𡜢', "mǎo"),
+    ('𡜥', "duī"),
+    ('𡜦', "huì"),
+    ('𡜧', "xì"),
+    ('𡜨', "xiū"),
+    ('𡜫', "rǎn"),
+    ('𡜬', "yī"),
+    ('𡜯', "zhé"),
+    ('𡜱', "jì"),
+    ('𡜲', "gào"),
+    ('𡜳', "yòu"),
+    ('𡜵', "pū"),
+    ('𡝈', "chù"),
+    ('𡝉', "cū"),
+    ('𡝊', "zhé"),
+    ('𡝋', "niǎo"),
+    ('𡝍', "qiè"),
+    ('𡝐', "chá"),
+    ('𡝒', "niǎo"),
+    ('𡝓', "suī"),
+    ('𡝙', "chá"),
+    ('𡝚', "chéng"),
+    ('𡝛', "yáo"),
+    ('𡝜', "dù"),
+    ('𡝝', "wāng"),
+    ('𡝟', "niàn"),
+    ('𡝠', "mí"),
+    ('𡝦', "nǒu"),
+    ('𡝧', "xì"),
+    ('𡝩', "yāo"),
+    ('𡝫', "chān"),
+    ('𡞋', "cān"),
+    ('𡞘', "xiè"),
+    ('𡞙', "miè"),
+    ('𡞚', "kěng"),
+    ('𡞜', "cù"),
+    ('𡞞', "shěng"),
+    ('𡞟', "pàn"),
+    ('𡞠', "hù"),
+    ('𡞢', "kè"),
+    ('𡞣', "xiàn"),
+    ('𡞥', "hóu"),
+    ('𡞦', "qióng"),
+    ('𡞧', "zōng"),
+    ('𡞪', "fú"),
+    ('𡞫', "nài"),
+    ('𡞭', "nì"),
+    ('𡞯', "kǔ"),
+    ('𡞾', "nèn"),
+    ('𡟍', "gē"),
+    ('𡟑', "hóu"),
+    ('𡟓', "āi"),
+    ('𡟕', "shī"),
+    ('𡟞', "xiū"),
+    ('𡟟', "cōng"),
+    ('𡟠', "jiāo"),
+    ('𡟢', "zhá"),
+    ('𡟣', "xiāo"),
+    ('𡟤', "liàn"),
+    ('𡟥', "qǔ"),
+    ('𡟨', "shǎn"),
+    ('𡟩', "xiè"),
+    ('𡟫', "gòng"),
+    ('𡟬', "miè"),
+    ('𡟭', "chái"),
+    ('𡟯', "ēn"),
+    ('𡟳', "dòu"),
+    ('𡠆', "kòu"),
+    ('𡠊', "tiáo"),
+    ('𡠋', "shī"),
+    ('𡠏', "sāng"),
+    ('𡠒', "guān"),
+    ('𡠖', "hào"),
+    ('𡠗', "zhì"),
+    ('𡠘', "yàng"),
+    ('𡠙', "tōng"),
+    ('𡠚', "bì"),
+    ('𡠜', "mó"),
+    ('𡠞', "fú"),
+    ('𡠟', "zhú"),
+    ('𡠥', "qiáng"),
+    ('𡠹', "zhì"),
+    ('𡠼', "sōu"),
+    ('𡠿', "niǎo"),
+    ('𡡀', "juàn"),
+    ('𡡂', "yàng"),
+    ('𡡄', "huāng"),
+    ('𡡈', "bēng"),
+    ('𡡉', "mó"),
+    ('𡡊', "cháo"),
+    ('𡡎', "lǚ"),
+    ('𡡏', "shāo"),
+    ('𡡐', "bǔ"),
+    ('𡡑', "zēng"),
+    ('𡡒', "sī"),
+    ('𡡔', "zuì"),
+    ('𡡕', "yuē"),
+    ('𡡖', "zān"),
+    ('𡡗', "luǎn"),
+    ('𡡥', "qú"),
+    ('𡡺', "miǎo"),
+    ('𡢀', "zhuàn"),
+    ('𡢈', "dàng"),
+    ('𡢊', "yuān"),
+    ('𡢒', "jǔ"),
+    ('𡢕', "huǐ"),
+    ('𡢖', "qì"),
+    ('𡢘', "yùn"),
+    ('𡢚', "màn"),
+    ('𡢜', "mǒ"),
+    ('𡢱', "piāo"),
+    ('𡢳', "jìn"),
+    ('𡢹', "yāo"),
+    ('𡣀', "chì"),
+    ('𡣁', "nì"),
+    ('𡣂', "sōu"),
+    ('𡣈', "shù"),
+    ('𡣋', "piāo"),
+    ('𡣔', "hàn"),
+    ('𡣠', "yāo"),
+    ('𡣢', "néi"),
+    ('𡣪', "shì"),
+    ('𡣬', "yuān"),
+    ('𡣮', "cài"),
+    ('𡣯', "jié"),
+    ('𡣹', "xiè"),
+    ('𡣽', "yán"),
+    ('𡣾', "xiāo"),
+    ('𡤋', "xiè"),
+    ('𡤌', "lì"),
+    ('𡤎', "fàn"),
+    ('𡤗', "zhù"),
+    ('𡤙', "nà"),
+    ('𡤛', "zhuǎn"),
+    ('𡤞', "kuī"),
+    ('𡤢', "luó"),
+    ('𡤫', "qiā"),
+    ('𡤶', "wān"),
+    ('𡤽', "shǔ"),
+    ('𡤿', "chèng"),
+    ('𡥁', "yì"),
+    ('𡥆', "hǎo"),
+    ('𡥈', "jiào"),
+    ('𡥋', "huì"),
+    ('𡥍', "xiào"),
+    ('𡥎', "cí"),
+    ('𡥞', "jì"),
+    ('𡥦', "nǐ"),
+    ('𡥧', "nái"),
+    ('𡥨', "nǐ"),
+    ('𡥩', "tǐ"),
+    ('𡥶', "jù"),
+    ('𡥸', "mìng"),
+    ('𡥽', "lí"),
+    ('𡥿', "zhòng"),
+    ('𡦁', "xù"),
+    ('𡦃', "qióng"),
+    ('𡦄', "fú"),
+    ('𡦆', "bìn"),
+    ('𡦊', "jì"),
+    ('𡦍', "qí"),
+    ('𡦎', "xì"),
+    ('𡦔', "dèng"),
+    ('𡦕', "ér"),
+    ('𡦛', "shú"),
+    ('𡦜', "tóng"),
+    ('𡦝', "xiáo"),
+    ('𡦟', "pí"),
+    ('𡦨', "dǎn"),
+    ('𡦪', "jí"),
+    ('𡦳', "xiào"),
+    ('𡦷', "cóng"),
+    ('𡦻', "bīn"),
+    ('𡦼', "rǒng"),
+    ('𡧍', "miàn"),
+    ('𡧒', "miàn"),
+    ('𡧔', "shū"),
+    ('𡧕', "xiáo"),
+    ('𡧖', "bǎo"),
+    ('𡧗', "wà"),
+    ('𡧙', "pào"),
+    ('𡧣', "gǎi"),
+    ('𡧥', "hū"),
+    ('𡧦', "héng"),
+    ('𡧨', "zhú"),
+    ('𡧩', "guāi"),
+    ('𡧭', "guì"),
+    ('𡧹', "dài"),
+    ('𡧼', "bīn"),
+    ('𡧽', "huǎng"),
+    ('𡨀', "chá"),
+    ('𡨄', "xià"),
+    ('𡨅', "jú"),
+    ('𡨇', "yǎo"),
+    ('𡨖', "fěn"),
+    ('𡨗', "zào"),
+    ('𡨛', "fēng"),
+    ('𡨢', "jū"),
+    ('𡨣', "yù"),
+    ('𡨩', "hūn"),
+    ('𡨲', "jié"),
+    ('𡨳', "xiòng"),
+    ('𡨵', "nài"),
+    ('𡨻', "nǒu"),
+    ('𡨽', "shěng"),
+    ('𡨿', "yù"),
+    ('𡩂', "huán"),
+    ('𡩃', "gěng"),
+    ('𡩄', "wǎn"),
+    ('𡩆', "tuó"),
+    ('𡩇', "qiāo"),
+    ('𡩘', "yìn"),
+    ('𡩚', "jiā"),
+    ('𡩡', "suǒ"),
+    ('𡩣', "jié"),
+    ('𡩤', "xī"),
+    ('𡩥', "wěng"),
+    ('𡩩', "máng"),
+    ('𡩶', "yáng"),
+    ('𡩸', "yáo"),
+    ('𡩽', "máng"),
+    ('𡩾', "ōu"),
+    ('𡪁', "án"),
+    ('𡪅', "lòu"),
+    ('𡪑', "è"),
+    ('𡪒', "zǐ"),
+    ('𡪗', "è"),
+    ('𡪙', "àn"),
+    ('𡪞', "huò"),
+    ('𡪠', "céng"),
+    ('𡪰', "xiòng"),
+    ('𡪱', "jì"),
+    ('𡪳', "zuó"),
+    ('𡪵', "qí"),
+    ('𡪺', "zhēng"),
+    ('𡫀', "jī"),
+    ('𡫁', "qī"),
+    ('𡫂', "juǎn"),
+    ('𡫃', "níng"),
+    ('𡫟', "sè"),
+    ('𡫥', "hè"),
+    ('𡫦', "rǒng"),
+    ('𡫧', "qǐn"),
+    ('𡫬', "jū"),
+    ('𡫯', "lì"),
+    ('𡫵', "shí"),
+    ('𡫸', "nì"),
+    ('𡫹', "xián"),
+    ('𡫺', "fū"),
+    ('𡫽', "rǔ"),
+    ('𡬁', "xiòng"),
+    ('𡬂', "guì"),
+    ('𡬄', "jì"),
+    ('𡬆', "měng"),
+    ('𡬇', "fū"),
+    ('𡬉', "sài"),
+    ('𡬊', "yù"),
+    ('𡬋', "jiào"),
+    ('𡬌', "mèng"),
+    ('𡬍', "lóng"),
+    ('𡬎', "qiāng"),
+    ('𡬐', "mí"),
+    ('𡬓', "yí"),
+    ('𡬕', "lóng"),
+    ('𡬖', "hān"),
+    ('𡬗', "nì"),
+    ('𡬘', "lào"),
+    ('𡬙', "sèng"),
+    ('𡬜', "lǐn"),
+    ('𡬞', "yù"),
+    ('𡬥', "nuó"),
+    ('𡬫', "wù"),
+    ('𡬯', "biǎn"),
+    ('𡬲', "biǎn"),
+    ('𡬳', "xuān"),
+    ('𡬵', "jiān"),
+    ('𡬸', "biǎn"),
+    ('𡭂', "dé"),
+    ('𡭇', "zhuān"),
+    ('𡭋', "rǒng"),
+    ('𡭐', "shuàn"),
+    ('𡭘', "jiā"),
+    ('𡭛', "huǐ"),
+    ('𡭞', "zhān"),
+    ('𡭢', "bài"),
+    ('𡭣', "liè"),
+    ('𡭥', "xiē"),
+    ('𡭭', "jiǎn"),
+    ('𡭮', "shǒu"),
+    ('𡭳', "kào"),
+    ('𡭷', "guān"),
+    ('𡭸', "luàn"),
+    ('𡭾', "nǒu"),
+    ('𡭿', "chǎng"),
+    ('𡮎', "liáng"),
+    ('𡮙', "nài"),
+    ('𡮚', "rǔ"),
+    ('𡮞', "zhì"),
+    ('𡮦', "cáo"),
+    ('𡮰', "lì"),
+    ('𡮻', "lán"),
+    ('𡮿', "chān"),
+    ('𡯁', "wāng"),
+    ('𡯄', "lì"),
+    ('𡯇', "wù"),
+    ('𡯈', "páo"),
+    ('𡯉', "yòu"),
+    ('𡯋', "gān"),
+    ('𡯏', "ān"),
+    ('𡯐', "xiū"),
+    ('𡯑', "shuǐ"),
+    ('𡯒', "ruǐ"),
+    ('𡯘', "bǎn"),
+    ('𡯙', "yóu"),
+    ('𡯢', "huó"),
+    ('𡯥', "huī"),
+    ('𡯨', "zuò"),
+    ('𡯩', "xiāo"),
+    ('𡯫', "mián"),
+    ('𡯰', "gà"),
+    ('𡯱', "yuǎn"),
+    ('𡯳', "bò"),
+    ('𡯴', "chào"),
+    ('𡯵', "tuǐ"),
+    ('𡯷', "bò"),
+    ('𡯽', "gà"),
+    ('𡯿', "tiāo"),
+    ('𡰀', "ná"),
+    ('𡰅', "hú"),
+    ('𡰆', "niè"),
+    ('𡰋', "huí"),
+    ('𡰌', "lǒu"),
+    ('𡰎', "tí"),
+    ('𡰐', "qiào"),
+    ('𡰑', "qiáo"),
+    ('𡰒', "zhǒng"),
+    ('𡰖', "dī"),
+    ('𡰚', "lín"),
+    ('𡰝', "quán"),
+    ('𡰞', "zhuān"),
+    ('𡰠', "léi"),
+    ('𡰢', "xié"),
+    ('𡰥', "rén"),
+    ('𡰨', "dāng"),
+    ('𡰪', "dū"),
+    ('𡰫', "niǎn"),
+    ('𡰯', "shǐ"),
+    ('𡰲', "xián"),
+    ('𡰹', "zhí"),
+    ('𡰽', "ài"),
+    ('𡰾', "cī"),
+    ('𡰿', "pú"),
+    ('𡱁', "shǐ"),
+    ('𡱅', "qū"),
+    ('𡱆', "shǔ"),
+    ('𡱇', "diān"),
+    ('𡱉', "xiǎo"),
+    ('𡱊', "shuǐ"),
+    ('𡱌', "huán"),
+    ('𡱐', "yí"),
+    ('𡱑', "juān"),
+    ('𡱔', "zhǐ"),
+    ('𡱜', "zhào"),
+    ('𡱣', "xù"),
+    ('𡱯', "lòng"),
+    ('𡱱', "zhù"),
+    ('𡱳', "suǒ"),
+    ('𡱷', "dié"),
+    ('𡱺', "qú"),
+    ('𡱼', "kè"),
+    ('𡱽', "hū"),
+    ('𡱾', "jū"),
+    ('𡲀', "qǐng"),
+    ('𡲍', "bīng"),
+    ('𡲕', "tì"),
+    ('𡲗', "jué"),
+    ('𡲚', "qiú"),
+    ('𡲣', "jiàng"),
+    ('𡲪', "yùn"),
+    ('𡲭', "mèi"),
+    ('𡲮', "pī"),
+    ('𡲰', "qú"),
+    ('𡲼', "mì"),
+    ('𡲿', "tì"),
+    ('𡳂', "kài"),
+    ('𡳄', "bǐ"),
+    ('𡳆', "qū"),
+    ('𡳏', "tiāo"),
+    ('𡳑', "chù"),
+    ('𡳘', "jú"),
+    ('𡳚', "xī"),
+    ('𡳞', "lìn"),
+    ('𡳭', "chǐ"),
+    ('𡳮', "jī"),
+    ('𡳴', "lú"),
+    ('𡳸', "lì"),
+    ('𡳾', "jué"),
+    ('𡴅', "zhū"),
+    ('𡴆', "lù"),
+    ('𡴎', "niè"),
+    ('𡴔', "quán"),
+    ('𡴭', "yà"),
+    ('𡴯', "è"),
+    ('𡴱', "hù"),
+    ('𡵀', "máng"),
+    ('𡵉', "wù"),
+    ('𡵌', "chā"),
+    ('𡵑', "qīn"),
+    ('𡵒', "jié"),
+    ('𡵓', "hóng"),
+    ('𡵕', "dān"),
+    ('𡵖', "ěn"),
+    ('𡵗', "zè"),
+    ('𡵘', "hù"),
+    ('𡵙', "àng"),
+    ('𡵚', "jiè"),
+    ('𡵛', "fù"),
+    ('𡵜', "yòng"),
+    ('𡵝', "zōng"),
+    ('𡵞', "fēng"),
+    ('𡵬', "mù"),
+    ('𡵶', "sè"),
+    ('𡵷', "cóng"),
+    ('𡵻', "kāng"),
+    ('𡶂', "yào"),
+    ('𡶃', "ài"),
+    ('𡶄', "bāo"),
+    ('𡶆', "pǒ"),
+    ('𡶈', "shǐ"),
+    ('𡶉', "fàn"),
+    ('𡶋', "jú"),
+    ('𡶌', "pí"),
+    ('𡶎', "wèi"),
+    ('𡶏', "kū"),
+    ('𡶐', "qié"),
+    ('𡶑', "gān"),
+    ('𡶢', "kuàng"),
+    ('𡶣', "suì"),
+    ('𡶤', "bēng"),
+    ('𡶥', "jiā"),
+    ('𡶦', "yà"),
+    ('𡶪', "kàn"),
+    ('𡶫', "niè"),
+    ('𡶭', "xíng"),
+    ('𡶯', "xì"),
+    ('𡶱', "lìn"),
+    ('𡶲', "duǒ"),
+    ('𡶴', "chǎn"),
+    ('𡷈', "shì"),
+    ('𡷋', "duì"),
+    ('𡷍', "jiāng"),
+    ('𡷎', "yǔ"),
+    ('𡷏', "lù"),
+    ('𡷐', "ěn"),
+    ('𡷓', "gǔ"),
+    ('𡷕', "wěi"),
+    ('𡷖', "chē"),
+    ('𡷗', "huàn"),
+    ('𡷘', "bié"),
+    ('𡷛', "hàn"),
+    ('𡷜', "tuí"),
+    ('𡷝', "nà"),
+    ('𡷞', "qǐ"),
+    ('𡷠', "tóu"),
+    ('𡷡', "yuān"),
+    ('𡷢', "wáng"),
+    ('𡷤', "wú"),
+    ('𡷥', "gào"),
+    ('𡷨', "kēng"),
+    ('𡷪', "yí"),
+    ('𡷸', "xiāo"),
+    ('𡷺', "guǐ"),
+    ('𡷻', "yà"),
+    ('𡷼', "suì"),
+    ('𡷽', "sǒng"),
+    ('𡷿', "zhuó"),
+    ('𡸂', "tū"),
+    ('𡸃', "xiǎn"),
+    ('𡸈', "zè"),
+    ('𡸉', "lì"),
+    ('𡸌', "zhù"),
+    ('𡸎', "jié"),
+    ('𡸑', "tì"),
+    ('𡸔', "xié"),
+    ('𡸕', "qióng"),
+    ('𡸗', "yà"),
+    ('𡸘', "jū"),
+    ('𡸛', "yín"),
+    ('𡸜', "zhí"),
+    ('𡸞', "kǎn"),
+    ('𡸟', "zī"),
+    ('𡸡', "kē"),
+    ('𡸣', "niè"),
+    ('𡸤', "qiáng"),
+    ('𡸥', "wǎn"),
+    ('𡸦', "zé"),
+    ('𡸨', "jū"),
+    ('𡸪', "zì"),
+    ('𡹄', "yà"),
+    ('𡹇', "lín"),
+    ('𡹉', "qí"),
+    ('𡹎', "huí"),
+    ('𡹓', "qì"),
+    ('𡹕', "yáng"),
+    ('𡹖', "suì"),
+    ('𡹘', "qǐ"),
+    ('𡹙', "guī"),
+    ('𡹢', "qìn"),
+    ('𡹣', "ē"),
+    ('𡹥', "zuò"),
+    ('𡹨', "zè"),
+    ('𡹩', "qì"),
+    ('𡹪', "jí"),
+    ('𡹬', "tuó"),
+    ('𡹭', "dié"),
+    ('𡹯', "huì"),
+    ('𡹰', "máo"),
+    ('𡹲', "xǔ"),
+    ('𡹵', "hóu"),
+    ('𡹶', "yǎn"),
+    ('𡹷', "xiáng"),
+    ('𡹸', "cōng"),
+    ('𡹹', "hú"),
+    ('𡹼', "àn"),
+    ('𡹾', "bǐng"),
+    ('𡺃', "qiāng"),
+    ('𡺇', "duǒ"),
+    ('𡺐', "zhǔ"),
+    ('𡺑', "dié"),
+    ('𡺒', "yōu"),
+    ('𡺓', "qǐ"),
+    ('𡺔', "shí"),
+    ('𡺕', "xūn"),
+    ('𡺖', "yōu"),
+    ('𡺗', "kān"),
+    ('𡺘', "qiǎo"),
+    ('𡺛', "qiāng"),
+    ('𡺜', "pén"),
+    ('𡺟', "quán"),
+    ('𡺡', "yíng"),
+    ('𡺧', "shā"),
+    ('𡺫', "tāo"),
+    ('𡺭', "hòng"),
+    ('𡺮', "pǐ"),
+    ('𡺯', "yáo"),
+    ('𡺴', "tú"),
+    ('𡺵', "chái"),
+    ('𡺷', "xià"),
+    ('𡺸', "qí"),
+    ('𡺺', "qióng"),
+    ('𡺽', "jìn"),
+    ('𡻈', "zhēn"),
+    ('𡻌', "zhū"),
+    ('𡻎', "xī"),
+    ('𡻐', "wēng"),
+    ('𡻑', "zhǒng"),
+    ('𡻕', "suì"),
+    ('𡻘', "kē"),
+    ('𡻙', "kuò"),
+    ('𡻚', "kǎng"),
+    ('𡻝', "cháo"),
+    ('𡻞', "bì"),
+    ('𡻟', "mò"),
+    ('𡻠', "zhù"),
+    ('𡻡', "hàn"),
+    ('𡻢', "yǔ"),
+    ('𡻣', "yí"),
+    ('𡻤', "má"),
+    ('𡻧', "qì"),
+    ('𡻨', "gùn"),
+    ('𡻩', "màn"),
+    ('𡻪', "liáo"),
+    ('𡻫', "lín"),
+    ('𡻬', "zú"),
+    ('𡻭', "lěi"),
+    ('𡻮', "hù"),
+    ('𡻯', "chuǎng"),
+    ('𡻰', "qì"),
+    ('𡻱', "léi"),
+    ('𡼁', "chī"),
+    ('𡼃', "pó"),
+    ('𡼄', "dié"),
+    ('𡼊', "lěi"),
+    ('𡼎', "yǐ"),
+    ('𡼓', "diàn"),
+    ('𡼖', "dūn"),
+    ('𡼗', "gāo"),
+    ('𡼘', "hū"),
+    ('𡼚', "xiāo"),
+    ('𡼛', "gá"),
+    ('𡼜', "pēng"),
+    ('𡼬', "shěn"),
+    ('𡼱', "wéi"),
+    ('𡼻', "duì"),
+    ('𡼼', "cháo"),
+    ('𡼽', "yǐn"),
+    ('𡼾', "kuài"),
+    ('𡼿', "kū"),
+    ('𡽁', "zuì"),
+    ('𡽂', "gǔ"),
+    ('𡽅', "yùn"),
+    ('𡽆', "zhì"),
+    ('𡽉', "jì"),
+    ('𡽊', "chēng"),
+    ('𡽖', "xiè"),
+    ('𡽗', "xiǎn"),
+    ('𡽛', "zuǐ"),
+    ('𡽜', "án"),
+    ('𡽝', "hāo"),
+    ('𡽠', "pǒ"),
+    ('𡽢', "dí"),
+    ('𡽣', "yè"),
+    ('𡽧', "náo"),
+    ('𡽱', "jié"),
+    ('𡽲', "bàng"),
+    ('𡽳', "lǎn"),
+    ('𡽴', "cáng"),
+    ('𡽶', "bì"),
+    ('𡽻', "zhǎn"),
+    ('𡽼', "qì"),
+    ('𡾂', "náo"),
+    ('𡾅', "lǜ"),
+    ('𡾇', "kuàng"),
+    ('𡾉', "mó"),
+    ('𡾋', "lěi"),
+    ('𡾌', "páo"),
+    ('𡾒', "lì"),
+    ('𡾓', "céng"),
+    ('𡾕', "dàng"),
+    ('𡾖', "lěi"),
+    ('𡾙', "è"),
+    ('𡾛', "bèng"),
+    ('𡾜', "jué"),
+    ('𡾥', "xuán"),
+    ('𡾦', "niè"),
+    ('𡾨', "hài"),
+    ('𡾮', "xiǎn"),
+    ('𡾰', "jiǎn"),
+    ('𡾱', "mí"),
+    ('𡾲', "niè"),
+    ('𡾻', "cáng"),
+    ('𡾼', "sǒng"),
+    ('𡾽', "zēng"),
+    ('𡾾', "yì"),
+    ('𡿂', "chóng"),
+    ('𡿄', "cáng"),
+    ('𡿉', "lěi"),
+    ('𡿊', "nuó"),
+    ('𡿋', "lì"),
+    ('𡿎', "lí"),
+    ('𡿏', "luó"),
+    ('𡿓', "tǎng"),
+    ('𡿖', "niè"),
+    ('𡿗', "niè"),
+    ('𡿙', "jī"),
+    ('𡿛', "lěi"),
+    ('𡿝', "nàng"),
+    ('𡿠', "lín"),
+    ('𡿡', "líng"),
+    ('𡿤', "xián"),
+    ('𡿥', "yù"),
+    ('𡿧', "zāi"),
+    ('𡿨', "quǎn"),
+    ('𡿩', "liè"),
+    ('𡿯', "yù"),
+    ('𡿰', "huāng"),
+    ('𡿺', "nǎo"),
+    ('𡿼', "xùn"),
+    ('𡿾', "jú"),
+    ('𡿿', "huò"),
+    ('𢀁', "yì"),
+    ('𢀊', "xī"),
+    ('𢀋', "sè"),
+    ('𢀌', "jiǎo"),
+    ('𢀍', "yōng"),
+    ('𢀕', "shī"),
+    ('𢀖', "jīng"),
+    ('𢀗', "wàn"),
+    ('𢀘', "yě"),
+    ('𢀙', "jiū"),
+    ('𢀜', "gǒng"),
+    ('𢀡', "huī"),
+    ('𢀪', "ěr"),
+    ('𢀵', "hàn"),
+    ('𢀼', "fú"),
+    ('𢁀', "fú"),
+    ('𢁁', "zhuó"),
+    ('𢁂', "jī"),
+    ('𢁏', "bāng"),
+    ('𢁒', "qí"),
+    ('𢁓', "shǐ"),
+    ('𢁕', "diǎo"),
+    ('𢁖', "pèi"),
+    ('𢁗', "xiǎn"),
+    ('𢁘', "sān"),
+    ('𢁝', "cháng"),
+    ('𢁞', "yuē"),
+    ('𢁠', "gōng"),
+    ('𢁢', "wū"),
+    ('𢁤', "fēn"),
+    ('𢁧', "chǎn"),
+    ('𢁩', "nèi"),
+    ('𢁪', "jué"),
+    ('𢁬', "zhǎo"),
+    ('𢁮', "qián"),
+    ('𢁱', "ǎo"),
+    ('𢁶', "wǎng"),
+    ('𢁷', "zhōng"),
+    ('𢁹', "huāng"),
+    ('𢁻', "bù"),
+    ('𢁼', "zhǔ"),
+    ('𢁽', "bì"),
+    ('𢁾', "chāo"),
+    ('𢁿', "zhēng"),
+    ('𢂀', "fú"),
+    ('𢂁', "kōu"),
+    ('𢂃', "zuó"),
+    ('𢂄', "xuàn"),
+    ('𢂆', "fù"),
+    ('𢂊', "yǎo"),
+    ('𢂍', "bō"),
+    ('𢂏', "bèi"),
+    ('𢂐', "xié"),
+    ('𢂑', "shì"),
+    ('𢂒', "yí"),
+    ('𢂔', "hóng"),
+    ('𢂕', "cuì"),
+    ('𢂗', "yì"),
+    ('𢂘', "zhuān"),
+    ('𢂝', "chì"),
+    ('𢂤', "pō"),
+    ('𢂨', "yín"),
+    ('𢂱', "yuàn"),
+    ('𢂶', "jiōng"),
+    ('𢂹', "mào"),
+    ('𢂺', "qiàn"),
+    ('𢂼', "yì"),
+    ('𢃀', "wú"),
+    ('𢃍', "bēi"),
+    ('𢃎', "huò"),
+    ('𢃏', "cóng"),
+    ('𢃐', "kōng"),
+    ('𢃕', "tà"),
+    ('𢃗', "hàn"),
+    ('𢃘', "qiàn"),
+    ('𢃜', "zhí"),
+    ('𢃢', "sè"),
+    ('𢃥', "qiān"),
+    ('𢃦', "guǒ"),
+    ('𢃩', "gǔn"),
+    ('𢃬', "jiān"),
+    ('𢃭', "zhōng"),
+    ('𢃮', "miǎn"),
+    ('𢃯', "guǐ"),
+    ('𢃰', "shì"),
+    ('𢃱', "móu"),
+    ('𢃲', "è"),
+    ('𢃳', "bǎ"),
+    ('𢃴', "là"),
+    ('𢃸', "zhòu"),
+    ('𢃺', "jí"),
+    ('𢄀', "zǎo"),
+    ('𢄄', "zhā"),
+    ('𢄅', "yì"),
+    ('𢄇', "gǒu"),
+    ('𢄊', "guī"),
+    ('𢄋', "yīng"),
+    ('𢄌', "shǎi"),
+    ('𢄍', "hé"),
+    ('𢄎', "bàng"),
+    ('𢄏', "mò"),
+    ('𢄐', "méng"),
+    ('𢄓', "wù"),
+    ('𢄔', "dài"),
+    ('𢄗', "jiǒng"),
+    ('𢄜', "hàn"),
+    ('𢄟', "tōng"),
+    ('𢄠', "kōu"),
+    ('𢄡', "lí"),
+    ('𢄢', "zhì"),
+    ('𢄣', "huì"),
+    ('𢄤', "zǎn"),
+    ('𢄦', "diǎo"),
+    ('𢄧', "cù"),
+    ('𢄱', "zhì"),
+    ('𢄳', "kuǎ"),
+    ('𢄵', "xiàng"),
+    ('𢄶', "huà"),
+    ('𢄷', "liáo"),
+    ('𢄸', "cuì"),
+    ('𢄹', "qiāo"),
+    ('𢄺', "jiǎo"),
+    ('𢄼', "xū"),
+    ('𢄽', "èr"),
+    ('𢄿', "tuō"),
+    ('𢅀', "tán"),
+    ('𢅁', "zhì"),
+    ('𢅈', "nǎo"),
+    ('𢅉', "mào"),
+    ('𢅊', "dì"),
+    ('𢅋', "céng"),
+    ('𢅎', "jiǎo"),
+    ('𢅏', "lián"),
+    ('𢅑', "shā"),
+    ('𢅒', "dàn"),
+    ('𢅕', "suì"),
+    ('𢅖', "lián"),
+    ('𢅗', "guò"),
+    ('𢅚', "biǎo"),
+    ('𢅜', "cì"),
+    ('𢅝', "diàn"),
+    ('𢅞', "lǜ"),
+    ('𢅟', "nǐ"),
+    ('𢅠', "yǎn"),
+    ('𢅡', "lán"),
+    ('𢅤', "gài"),
+    ('𢅥', "chú"),
+    ('𢅩', "bì"),
+    ('𢅪', "zú"),
+    ('𢅫', "huì"),
+    ('𢅭', "lǎi"),
+    ('𢅮', "xián"),
+    ('𢅯', "fèn"),
+    ('𢅰', "hè"),
+    ('𢅹', "yào"),
+    ('𢅺', "zhǎn"),
+    ('𢅼', "néi"),
+    ('𢅾', "luǒ"),
+    ('𢆀', "yuán"),
+    ('𢆂', "néng"),
+    ('𢆉', "rěn"),
+    ('𢆜', "gé"),
+    ('𢆞', "jiǎn"),
+    ('𢆟', "píng"),
+    ('𢆣', "biè"),
+    ('𢆦', "jiàn"),
+    ('𢆩', "bìng"),
+    ('𢆯', "mì"),
+    ('𢆰', "hù"),
+    ('𢆴', "diǎo"),
+    ('𢆶', "yōu"),
+    ('𢆷', "yāo"),
+    ('𢆸', "bēng"),
+    ('𢆺', "chén"),
+    ('𢆻', "jī"),
+    ('𢆽', "yāo"),
+    ('𢇇', "guān"),
+    ('𢇈', "yàn"),
+    ('𢇕', "chǐ"),
+    ('𢇗', "shà"),
+    ('𢇘', "yǎn"),
+    ('𢇙', "yì"),
+    ('𢇚', "yì"),
+    ('𢇛', "chè"),
+    ('𢇞', "hàn"),
+    ('𢇟', "huāng"),
+    ('𢇤', "shuì"),
+    ('𢇥', "suì"),
+    ('𢇦', "rén"),
+    ('𢇧', "tán"),
+    ('𢇨', "zhǐ"),
+    ('𢇪', "fàn"),
+    ('𢇫', "fěng"),
+    ('𢇰', "tán"),
+    ('𢇲', "mí"),
+    ('𢇳', "pí"),
+    ('𢇴', "bù"),
+    ('𢇵', "nà"),
+    ('𢇶', "tián"),
+    ('𢇷', "bá"),
+    ('𢇸', "yì"),
+    ('𢈂', "yǎn"),
+    ('𢈄', "tiāo"),
+    ('𢈆', "yáo"),
+    ('𢈇', "shěn"),
+    ('𢈈', "kē"),
+    ('𢈉', "tóng"),
+    ('𢈋', "xuǎn"),
+    ('𢈓', "yòu"),
+    ('𢈕', "bài"),
+    ('𢈙', "xiá"),
+    ('𢈚', "lǚ"),
+    ('𢈛', "kùn"),
+    ('𢈜', "zāng"),
+    ('𢈝', "qiú"),
+    ('𢈠', "cù"),
+    ('𢈡', "zuī"),
+    ('𢈢', "lǒu"),
+    ('𢈤', "xiá"),
+    ('𢈯', "shēn"),
+    ('𢈲', "pú"),
+    ('𢈴', "jīng"),
+    ('𢈵', "qiāng"),
+    ('𢈶', "yì"),
+    ('𢈸', "niè"),
+    ('𢈹', "duī"),
+    ('𢈻', "jié"),
+    ('𢈼', "suì"),
+    ('𢈽', "zhàn"),
+    ('𢈾', "cōu"),
+    ('𢉁', "bēng"),
+    ('𢉂', "guān"),
+    ('𢉃', "shě"),
+    ('𢉅', "jìn"),
+    ('𢉆', "dì"),
+    ('𢉑', "dān"),
+    ('𢉓', "nǎi"),
+    ('𢉕', "nóu"),
+    ('𢉗', "jí"),
+    ('𢉘', "yán"),
+    ('𢉚', "nòu"),
+    ('𢉜', "dù"),
+    ('𢉝', "wèi"),
+    ('𢉞', "piān"),
+    ('𢉢', "hú"),
+    ('𢉤', "jià"),
+    ('𢉥', "yè"),
+    ('𢉦', "jǔn"),
+    ('𢉧', "lán"),
+    ('𢉨', "là"),
+    ('𢉩', "yīn"),
+    ('𢉭', "tuí"),
+    ('𢉵', "nǎo"),
+    ('𢉺', "zǔ"),
+    ('𢉿', "mà"),
+    ('𢊀', "sī"),
+    ('𢊁', "zhì"),
+    ('𢊄', "huī"),
+    ('𢊅', "zhuì"),
+    ('𢊇', "huì"),
+    ('𢊍', "chú"),
+    ('𢊏', "chè"),
+    ('𢊒', "xiū"),
+    ('𢊓', "lán"),
+    ('𢊕', "cōng"),
+    ('𢊖', "shèn"),
+    ('𢊗', "mò"),
+    ('𢊘', "yī"),
+    ('𢊙', "yáo"),
+    ('𢊚', "xǐ"),
+    ('𢊛', "zuǐ"),
+    ('𢊜', "bìng"),
+    ('𢊧', "yú"),
+    ('𢊩', "lù"),
+    ('𢊮', "tuí"),
+    ('𢊯', "wěi"),
+    ('𢊱', "fén"),
+    ('𢊲', "shěn"),
+    ('𢊻', "liáo"),
+    ('𢋂', "shǔ"),
+    ('𢋃', "dǎn"),
+    ('𢋄', "juǎn"),
+    ('𢋅', "yú"),
+    ('𢋆', "xìn"),
+    ('𢋇', "yáo"),
+    ('𢋈', "sū"),
+    ('𢋒', "huó"),
+    ('𢋔', "qiān"),
+    ('𢋚', "má"),
+    ('𢋝', "kǎi"),
+    ('𢋡', "lǔ"),
+    ('𢋣', "yōu"),
+    ('𢋮', "xiàn"),
+    ('𢋹', "wú"),
+    ('𢋻', "yǐn"),
+    ('𢋼', "xī"),
+    ('𢋿', "zhāi"),
+    ('𢌀', "xiè"),
+    ('𢌄', "qú"),
+    ('𢌈', "lí"),
+    ('𢌍', "qiān"),
+    ('𢌔', "líng"),
+    ('𢌕', "luán"),
+    ('𢌚', "chān"),
+    ('𢌦', "zhèng"),
+    ('𢌨', "yán"),
+    ('𢌲', "yìn"),
+    ('𢌳', "kuí"),
+    ('𢌷', "qū"),
+    ('𢌹', "fú"),
+    ('𢌻', "yù"),
+    ('𢍁', "qí"),
+    ('𢍆', "qì"),
+    ('𢍇', "jì"),
+    ('𢍈', "yuān"),
+    ('𢍎', "gào"),
+    ('𢍏', "juàn"),
+    ('𢍑', "qí"),
+    ('𢍓', "gǎi"),
+    ('𢍕', "quàn"),
+    ('𢍚', "wèi"),
+    ('𢍧', "zhì"),
+    ('𢍫', "jiǎn"),
+    ('𢍭', "sì"),
+    ('𢍰', "yì"),
+    ('𢍱', "qiān"),
+    ('𢍼', "lì"),
+    ('𢍿', "zāng"),
+    ('𢎀', "yì"),
+    ('𢎂', "cái"),
+    ('𢎃', "yì"),
+    ('𢎄', "gē"),
+    ('𢎆', "dié"),
+    ('𢎈', "zhī"),
+    ('𢎉', "yì"),
+    ('𢎋', "zāi"),
+    ('𢎌', "dài"),
+    ('𢎎', "sù"),
+    ('𢎔', "jié"),
+    ('𢎕', "chèn"),
+    ('𢎖', "qú"),
+    ('𢎘', "hàn"),
+    ('𢎙', "xián"),
+    ('𢎠', "quán"),
+    ('𢎡', "jié"),
+    ('𢎥', "juàn"),
+    ('𢎪', "dàn"),
+    ('𢎭', "jīn"),
+    ('𢎴', "bīng"),
+    ('𢎵', "hú"),
+    ('𢎹', "jué"),
+    ('𢎻', "yú"),
+    ('𢏃', "lǐ"),
+    ('𢏄', "qiáng"),
+    ('𢏅', "shuǐ"),
+    ('𢏆', "kū"),
+    ('𢏈', "zhěn"),
+    ('𢏍', "fú"),
+    ('𢏎', "shēn"),
+    ('𢏒', "chuí"),
+    ('𢏕', "tóng"),
+    ('𢏗', "yì"),
+    ('𢏙', "yáng"),
+    ('𢏜', "tuó"),
+    ('𢏝', "zhōu"),
+    ('𢏞', "jí"),
+    ('𢏤', "xùn"),
+    ('𢏦', "shěn"),
+    ('𢏧', "xuān"),
+    ('𢏭', "liú"),
+    ('𢏮', "yuān"),
+    ('𢏯', "hú"),
+    ('𢏰', "zhèng"),
+    ('𢏳', "pēng"),
+    ('𢏷', "jué"),
+    ('𢐂', "zhì"),
+    ('𢐃', "piān"),
+    ('𢐄', "yuàn"),
+    ('𢐆', "jiān"),
+    ('𢐊', "páng"),
+    ('𢐎', "zhuàn"),
+    ('𢐐', "xián"),
+    ('𢐒', "bēng"),
+    ('𢐔', "cōng"),
+    ('𢐖', "mò"),
+    ('𢐚', "guó"),
+    ('𢐞', "chéng"),
+    ('𢐟', "qiāo"),
+    ('𢐦', "bì"),
+    ('𢐩', "qiǎng"),
+    ('𢐫', "zhōu"),
+    ('𢐲', "fán"),
+    ('𢐳', "biē"),
+    ('𢐾', "bó"),
+    ('𢐿', "rǒng"),
+    ('𢑅', "dǐng"),
+    ('𢑆', "quán"),
+    ('𢑇', "jiù"),
+    ('𢑈', "yáo"),
+    ('𢑓', "xiá"),
+    ('𢑖', "zǎo"),
+    ('𢑝', "dān"),
+    ('𢑟', "wǔ"),
+    ('𢑠', "tuó"),
+    ('𢑢', "hū"),
+    ('𢑧', "xī"),
+    ('𢑬', "lái"),
+    ('𢑮', "fēi"),
+    ('𢑹', "hú"),
+    ('𢒆', "xiān"),
+    ('𢒉', "shǎn"),
+    ('𢒍', "fèi"),
+    ('𢒐', "cuò"),
+    ('𢒒', "fú"),
+    ('𢒔', "chù"),
+    ('𢒝', "diū"),
+    ('𢒞', "làn"),
+    ('𢒩', "xǐ"),
+    ('𢒯', "biāo"),
+    ('𢒰', "yù"),
+    ('𢒱', "suì"),
+    ('𢒲', "xǐ"),
+    ('𢒷', "póu"),
+    ('𢒹', "shǎn"),
+    ('𢒾', "jiào"),
+    ('𢓀', "yì"),
+    ('𢓃', "wán"),
+    ('𢓄', "jǐ"),
+    ('𢓆', "wán"),
+    ('𢓇', "tuì"),
+    ('𢓋', "àng"),
+    ('𢓍', "tiān"),
+    ('𢓎', "chí"),
+    ('𢓒', "rán"),
+    ('𢓔', "sà"),
+    ('𢓕', "yín"),
+    ('𢓖', "pī"),
+    ('𢓗', "cǐ"),
+    ('𢓘', "tóng"),
+    ('𢓙', "yǐn"),
+    ('𢓜', "gé"),
+    ('𢓝', "tiāo"),
+    ('𢓞', "zhēng"),
+    ('𢓟', "zhòu"),
+    ('𢓡', "yí"),
+    ('𢓢', "kuà"),
+    ('𢓣', "sōng"),
+    ('𢓧', "dì"),
+    ('𢓬', "xié"),
+    ('𢓮', "xiāo"),
+    ('𢓯', "guàng"),
+    ('𢓰', "tuǒ"),
+    ('𢓱', "fēng"),
+    ('𢓲', "wú"),
+    ('𢓵', "xiù"),
+    ('𢓿', "yóu"),
+    ('𢔁', "líng"),
+    ('𢔂', "yàn"),
+    ('𢔅', "dōng"),
+    ('𢔆', "qì"),
+    ('𢔇', "táo"),
+    ('𢔈', "hán"),
+    ('𢔊', "chí"),
+    ('𢔋', "sōng"),
+    ('𢔑', "quǎn"),
+    ('𢔔', "hàn"),
+    ('𢔟', "rǒu"),
+    ('𢔠', "qì"),
+    ('𢔡', "kāi"),
+    ('𢔢', "yú"),
+    ('𢔣', "chā"),
+    ('𢔤', "chèng"),
+    ('𢔥', "yù"),
+    ('𢔧', "bìng"),
+    ('𢔩', "cōng"),
+    ('𢔪', "zhū"),
+    ('𢔬', "yù"),
+    ('𢔱', "jué"),
+    ('𢔲', "liù"),
+    ('𢔳', "sāo"),
+    ('𢔴', "yù"),
+    ('𢕅', "shuài"),
+    ('𢕋', "yuàn"),
+    ('𢕎', "zhāng"),
+    ('𢕑', "shuài"),
+    ('𢕓', "chǔ"),
+    ('𢕔', "zhāng"),
+    ('𢕕', "sǎn"),
+    ('𢕖', "xiān"),
+    ('𢕘', "cuī"),
+    ('𢕙', "měng"),
+    ('𢕚', "dí"),
+    ('𢕞', "zhì"),
+    ('𢕟', "ào"),
+    ('𢕦', "xiū"),
+    ('𢕨', "pián"),
+    ('𢕪', "jiào"),
+    ('𢕫', "kuǎn"),
+    ('𢕬', "sà"),
+    ('𢕭', "xiàn"),
+    ('𢕮', "zhà"),
+    ('𢕯', "diàn"),
+    ('𢕷', "yí"),
+    ('𢕺', "huì"),
+    ('𢕻', "shàn"),
+    ('𢖄', "chóng"),
+    ('𢖅', "yí"),
+    ('𢖆', "xiè"),
+    ('𢖇', "zhì"),
+    ('𢖈', "tiào"),
+    ('𢖊', "pīng"),
+    ('𢖋', "xián"),
+    ('𢖎', "xiān"),
+    ('𢖏', "sù"),
+    ('𢖑', "cuán"),
+    ('𢖗', "sǒng"),
+    ('𢖛', "hēi"),
+    ('𢖝', "xiàn"),
+    ('𢖟', "yóu"),
+    ('𢖡', "yù"),
+    ('𢖤', "tái"),
+    ('𢖦', "jué"),
+    ('𢖧', "nàng"),
+    ('𢖩', "diān"),
+    ('𢖫', "yì"),
+    ('𢖬', "bì"),
+    ('𢖳', "xū"),
+    ('𢖴', "yì"),
+    ('𢖵', "rù"),
+    ('𢖷', "gōng"),
+    ('𢖺', "yì"),
+    ('𢖿', "zhì"),
+    ('𢗀', "xīn"),
+    ('𢗂', "jì"),
+    ('𢗄', "xià"),
+    ('𢗈', "zhāo"),
+    ('𢗉', "nè"),
+    ('𢗊', "xiè"),
+    ('𢗎', "yì"),
+    ('𢗓', "yǔ"),
+    ('𢗫', "fǔ"),
+    ('𢗭', "shè"),
+    ('𢗯', "yuán"),
+    ('𢗰', "fǎn"),
+    ('𢗲', "fū"),
+    ('𢗳', "wù"),
+    ('𢗴', "xī"),
+    ('𢗵', "hǒng"),
+    ('𢗹', "jì"),
+    ('𢗺', "chàng"),
+    ('𢗿', "mò"),
+    ('𢘀', "pèi"),
+    ('𢘃', "mú"),
+    ('𢘄', "qiú"),
+    ('𢘅', "mào"),
+    ('𢘇', "dá"),
+    ('𢘉', "xiá"),
+    ('𢘊', "shēn"),
+    ('𢘋', "tè"),
+    ('𢘌', "hóng"),
+    ('𢘍', "bì"),
+    ('𢘙', "lǒng"),
+    ('𢘝', "nǐ"),
+    ('𢘟', "qiáo"),
+    ('𢘧', "ruǎn"),
+    ('𢘸', "jiàng"),
+    ('𢘹', "chā"),
+    ('𢘺', "mǐ"),
+    ('𢘽', "yì"),
+    ('𢘿', "suō"),
+    ('𢙁', "wù"),
+    ('𢙂', "xuān"),
+    ('𢙅', "xí"),
+    ('𢙇', "yǐ"),
+    ('𢙐', "náo"),
+    ('𢙑', "mái"),
+    ('𢙒', "xiāo"),
+    ('𢙓', "wèi"),
+    ('𢙮', "kàn"),
+    ('𢙱', "lòng"),
+    ('𢙲', "lǚ"),
+    ('𢙳', "zhuǎng"),
+    ('𢙺', "zhì"),
+    ('𢙼', "xìng"),
+    ('𢙾', "gěng"),
+    ('𢙿', "jìn"),
+    ('𢚀', "xiàn"),
+    ('𢚁', "jì"),
+    ('𢚂', "cuò"),
+    ('𢚄', "láo"),
+    ('𢚅', "fěn"),
+    ('𢚆', "jù"),
+    ('𢚋', "miào"),
+    ('𢚌', "xiá"),
+    ('𢚑', "sù"),
+    ('𢚨', "zhì"),
+    ('𢚪', "hù"),
+    ('𢚫', "kòu"),
+    ('𢚭', "suǒ"),
+    ('𢚮', "nì"),
+    ('𢚺', "tēng"),
+    ('𢚻', "zhù"),
+    ('𢛁', "dá"),
+    ('𢛃', "qiú"),
+    ('𢛄', "yà"),
+    ('𢛆', "xián"),
+    ('𢛉', "nèi"),
+    ('𢛍', "zhǐ"),
+    ('𢛎', "bié"),
+    ('𢛒', "chǒng"),
+    ('𢛓', "lán"),
+    ('𢛔', "dōng"),
+    ('𢛕', "qūn"),
+    ('𢛖', "xiàng"),
+    ('𢛘', "xiáo"),
+    ('𢛙', "wǎn"),
+    ('𢛚', "rù"),
+    ('𢛛', "wàng"),
+    ('𢛜', "nì"),
+    ('𢛞', "bāi"),
+    ('𢛟', "yà"),
+    ('𢛥', "sī"),
+    ('𢛦', "yǐn"),
+    ('𢛨', "yù"),
+    ('𢛮', "lí"),
+    ('𢛯', "huò"),
+    ('𢜗', "bàng"),
+    ('𢜣', "xī"),
+    ('𢜥', "jiū"),
+    ('𢜨', "xiè"),
+    ('𢜩', "qiān"),
+    ('𢜪', "nuò"),
+    ('𢜫', "xǐng"),
+    ('𢜬', "duó"),
+    ('𢜭', "jǐ"),
+    ('𢜮', "wǔ"),
+    ('𢜯', "mú"),
+    ('𢜰', "yàn"),
+    ('𢜱', "qì"),
+    ('𢜲', "ná"),
+    ('𢜳', "chì"),
+    ('𢜴', "hóu"),
+    ('𢜶', "sào"),
+    ('𢜸', "náo"),
+    ('𢜻', "chěng"),
+    ('𢜼', "chěng"),
+    ('𢜽', "kuǐ"),
+    ('𢜿', "jià"),
+    ('𢝀', "tú"),
+    ('𢝁', "hōng"),
+    ('𢝂', "dú"),
+    ('𢝅', "xiá"),
+    ('𢝆', "zhòng"),
+    ('𢝇', "huò"),
+    ('𢝈', "chóng"),
+    ('𢝉', "dá"),
+    ('𢝌', "mào"),
+    ('𢝍', "yào"),
+    ('𢝓', "juān"),
+    ('𢝬', "shì"),
+    ('𢝯', "yín"),
+    ('𢝳', "gǔ"),
+    ('𢝴', "wù"),
+    ('𢝸', "guò"),
+    ('𢝹', "tì"),
+    ('𢝻', "hōng"),
+    ('𢞇', "rě"),
+    ('𢞉', "yí"),
+    ('𢞋', "tǔn"),
+    ('𢞏', "qióng"),
+    ('𢞐', "hài"),
+    ('𢞒', "qì"),
+    ('𢞕', "huò"),
+    ('𢞖', "tì"),
+    ('𢞗', "pī"),
+    ('𢞚', "gěng"),
+    ('𢞜', "xiè"),
+    ('𢞞', "mì"),
+    ('𢞟', "gào"),
+    ('𢞠', "tā"),
+    ('𢞡', "xiǎng"),
+    ('𢞣', "shū"),
+    ('𢞦', "fú"),
+    ('𢞬', "zhuān"),
+    ('𢞭', "liù"),
+    ('𢟅', "yóu"),
+    ('𢟊', "chěng"),
+    ('𢟋', "duī"),
+    ('𢟢', "lí"),
+    ('𢟣', "yàng"),
+    ('𢟤', "lí"),
+    ('𢟧', "lǔ"),
+    ('𢟨', "mǔ"),
+    ('𢟩', "suì"),
+    ('𢟪', "ài"),
+    ('𢟭', "kòu"),
+    ('𢟯', "zhé"),
+    ('𢟰', "ài"),
+    ('𢟱', "téng"),
+    ('𢟳', "lǜ"),
+    ('𢟴', "tuí"),
+    ('𢟵', "bī"),
+    ('𢟼', "měng"),
+    ('𢟾', "huì"),
+    ('𢟿', "huán"),
+    ('𢠛', "kuò"),
+    ('𢠝', "xīn"),
+    ('𢠡', "sào"),
+    ('𢠫', "shù"),
+    ('𢠬', "què"),
+    ('𢠭', "bā"),
+    ('𢠮', "tuì"),
+    ('𢠲', "fù"),
+    ('𢠳', "biē"),
+    ('𢠵', "tǎng"),
+    ('𢠷', "xiàng"),
+    ('𢠹', "sī"),
+    ('𢠺', "bó"),
+    ('𢠼', "mái"),
+    ('𢠽', "dàng"),
+    ('𢠿', "guì"),
+    ('𢡀', "hēi"),
+    ('𢡁', "xī"),
+    ('𢡂', "dàng"),
+    ('𢡃', "yì"),
+    ('𢡅', "bī"),
+    ('𢡇', "gū"),
+    ('𢡈', "cuì"),
+    ('𢡉', "sè"),
+    ('𢡍', "gé"),
+    ('𢡎', "yù"),
+    ('𢡏', "nǎ"),
+    ('𢡑', "lì"),
+    ('𢡒', "zhì"),
+    ('𢡰', "zhào"),
+    ('𢡴', "jī"),
+    ('𢡵', "ruǎn"),
+    ('𢡹', "chòng"),
+    ('𢢂', "jié"),
+    ('𢢌', "chàng"),
+    ('𢢍', "zhé"),
+    ('𢢒', "sù"),
+    ('𢢓', "yōng"),
+    ('𢢖', "qì"),
+    ('𢢗', "zhuó"),
+    ('𢢚', "kài"),
+    ('𢢜', "yè"),
+    ('𢢞', "qì"),
+    ('𢢹', "xiòng"),
+    ('𢣉', "yī"),
+    ('𢣊', "chǒu"),
+    ('𢣎', "tuǎn"),
+    ('𢣏', "ài"),
+    ('𢣐', "pīn"),
+    ('𢣓', "liè"),
+    ('𢣔', "mián"),
+    ('𢣕', "ài"),
+    ('𢣗', "mǒ"),
+    ('𢣘', "wèi"),
+    ('𢣙', "yìng"),
+    ('𢣚', "nǐ"),
+    ('𢣞', "bó"),
+    ('𢣠', "liù"),
+    ('𢣳', "ruì"),
+    ('𢣵', "chú"),
+    ('𢣻', "lǘ"),
+    ('𢣼', "chá"),
+    ('𢣿', "chù"),
+    ('𢤁', "sào"),
+    ('𢤂', "lí"),
+    ('𢤄', "sōng"),
+    ('𢤆', "lì"),
+    ('𢤋', "xì"),
+    ('𢤍', "yān"),
+    ('𢤎', "cuō"),
+    ('𢤐', "liú"),
+    ('𢤘', "méng"),
+    ('𢤚', "zhàn"),
+    ('𢤤', "zhuàng"),
+    ('𢤧', "miǎo"),
+    ('𢤩', "lì"),
+    ('𢤫', "jǔ"),
+    ('𢤯', "xiè"),
+    ('𢤰', "xiè"),
+    ('𢤱', "lǒng"),
+    ('𢤲', "lóng"),
+    ('𢥂', "téng"),
+    ('𢥃', "zhù"),
+    ('𢥋', "chán"),
+    ('𢥌', "xiǎn"),
+    ('𢥏', "yíng"),
+    ('𢥐', "pèi"),
+    ('𢥘', "xié"),
+    ('𢥚', "jiào"),
+    ('𢥞', "chōng"),
+    ('𢥳', "hē"),
+    ('𢥽', "tǔn"),
+    ('𢦅', "hǒng"),
+    ('𢦈', "mán"),
+    ('𢦊', "jīn"),
+    ('𢦌', "qú"),
+    ('𢦍', "dǒu"),
+    ('𢦎', "qiú"),
+    ('𢦏', "zāi"),
+    ('𢦑', "shēng"),
+    ('𢦒', "zāi"),
+    ('𢦕', "yǐ"),
+    ('𢦚', "huà"),
+    ('𢦟', "kān"),
+    ('𢦰', "yuè"),
+    ('𢦱', "nì"),
+    ('𢦲', "sī"),
+    ('𢦴', "wǒ"),
+    ('𢦸', "cán"),
+    ('𢦺', "jiān"),
+    ('𢦼', "miè"),
+    ('𢦽', "sháo"),
+    ('𢦿', "rǒng"),
+    ('𢧀', "gān"),
+    ('𢧅', "qiáng"),
+    ('𢧇', "shú"),
+    ('𢧈', "zhuó"),
+    ('𢧏', "shī"),
+    ('𢧐', "zhàn"),
+    ('𢧑', "tì"),
+    ('𢧖', "zhá"),
+    ('𢧗', "zhān"),
+    ('𢧝', "fèn"),
+    ('𢧞', "miè"),
+    ('𢧠', "zè"),
+    ('𢧤', "zhì"),
+    ('𢧥', "qiān"),
+    ('𢧦', "hàn"),
+    ('𢧧', "gé"),
+    ('𢧮', "cán"),
+    ('𢧰', "guó"),
+    ('𢧱', "jiāo"),
+    ('𢧳', "yōng"),
+    ('𢧴', "áo"),
+    ('𢧻', "zhá"),
+    ('𢧽', "xì"),
+    ('𢨁', "xū"),
+    ('𢨂', "wǔ"),
+    ('𢨏', "jué"),
+    ('𢨐', "jī"),
+    ('𢨒', "chì"),
+    ('𢨔', "wǎn"),
+    ('𢨖', "miè"),
+    ('𢨗', "zéi"),
+    ('𢨜', "jié"),
+    ('𢨝', "shí"),
+    ('𢨟', "xī"),
+    ('𢨡', "è"),
+    ('𢨥', "hù"),
+    ('𢨦', "hù"),
+    ('𢨨', "lì"),
+    ('𢨫', "chù"),
+    ('𢨮', "yī"),
+    ('𢨯', "mǎo"),
+    ('𢨰', "xū"),
+    ('𢨱', "zhōng"),
+    ('𢨳', "yì"),
+    ('𢨺', "liáo"),
+    ('𢨿', "jiān"),
+    ('𢩀', "jiǎn"),
+    ('𢩁', "jú"),
+    ('𢩄', "zhù"),
+    ('𢩈', "wǔ"),
+    ('𢩏', "kè"),
+    ('𢩐', "kě"),
+    ('𢩑', "lì"),
+    ('𢩒', "bǐ"),
+    ('𢩓', "gé"),
+    ('𢩕', "xū"),
+    ('𢩖', "shā"),
+    ('𢩗', "líng"),
+    ('𢩘', "kē"),
+    ('𢩞', "bó"),
+    ('𢩟', "biān"),
+    ('𢩠', "shuān"),
+    ('𢩡', "qí"),
+    ('𢩢', "shàn"),
+    ('𢩦', "jī"),
+    ('𢩨', "qiǎo"),
+    ('𢩮', "yì"),
+    ('𢩯', "jué"),
+    ('𢩰', "zhǎng"),
+    ('𢩲', "xìn"),
+    ('𢩷', "tuō"),
+    ('𢩸', "hài"),
+    ('𢩹', "xià"),
+    ('𢩻', "tuó"),
+    ('𢩼', "yí"),
+    ('𢪃', "cù"),
+    ('𢪇', "jiāng"),
+    ('𢪈', "nán"),
+    ('𢪋', "pěng"),
+    ('𢪍', "jié"),
+    ('𢪎', "xuē"),
+    ('𢪏', "hú"),
+    ('𢪓', "jǔ"),
+    ('𢪥', "yǒu"),
+    ('𢪦', "nǔ"),
+    ('𢪧', "yè"),
+    ('𢪪', "yìn"),
+    ('𢪬', "kǒng"),
+    ('𢪶', "xiāo"),
+    ('𢪷', "xiāng"),
+    ('𢪼', "náo"),
+    ('𢪾', "zhàng"),
+    ('𢫐', "jié"),
+    ('𢫓', "nǔ"),
+    ('𢫔', "shàn"),
+    ('𢫘', "lú"),
+    ('𢫢', "jiá"),
+    ('𢫧', "zhǒu"),
+    ('𢫨', "rǒng"),
+    ('𢫫', "lù"),
+    ('𢫬', "sà"),
+    ('𢫭', "nù"),
+    ('𢫯', "bó"),
+    ('𢫰', "zhé"),
+    ('𢫲', "qǐn"),
+    ('𢫴', "cī"),
+    ('𢫵', "zú"),
+    ('𢫷', "wǒ"),
+    ('𢫸', "wǔ"),
+    ('𢫻', "nié"),
+    ('𢫿', "xiān"),
+    ('𢬀', "hóng"),
+    ('𢬍', "yè"),
+    ('𢬫', "tìng"),
+    ('𢬬', "jǐn"),
+    ('𢬱', "jié"),
+    ('𢬲', "hè"),
+    ('𢬳', "tū"),
+    ('𢬴', "zhé"),
+    ('𢬵', "pīn"),
+    ('𢬶', "jìn"),
+    ('𢬷', "nàn"),
+    ('𢬼', "dùn"),
+    ('𢬾', "xī"),
+    ('𢬿', "xiè"),
+    ('𢭁', "xì"),
+    ('𢭂', "láo"),
+    ('𢭃', "duǎn"),
+    ('𢭄', "jì"),
+    ('𢭅', "chā"),
+    ('𢭆', "chōu"),
+    ('𢭈', "gāng"),
+    ('𢭎', "xiáng"),
+    ('𢭏', "dǎo"),
+    ('𢭥', "biàn"),
+    ('𢭦', "xiāo"),
+    ('𢭧', "xīn"),
+    ('𢮁', "yǔ"),
+    ('𢮂', "xián"),
+    ('𢮃', "lí"),
+    ('𢮄', "qiǎn"),
+    ('𢮇', "měi"),
+    ('𢮉', "qiāo"),
+    ('𢮊', "yà"),
+    ('𢮌', "qiā"),
+    ('𢮍', "qiòng"),
+    ('𢮏', "bàng"),
+    ('𢮐', "zhēng"),
+    ('𢮚', "zè"),
+    ('𢮛', "shuàn"),
+    ('𢮞', "sào"),
+    ('𢯅', "lù"),
+    ('𢯉', "xié"),
+    ('𢯋', "fǔ"),
+    ('𢯌', "zhài"),
+    ('𢯩', "zè"),
+    ('𢯫', "duàn"),
+    ('𢯭', "dèng"),
+    ('𢯮', "yù"),
+    ('𢯰', "lǜ"),
+    ('𢯲', "wàn"),
+    ('𢯳', "xué"),
+    ('𢯴', "jiǎo"),
+    ('𢯵', "yuě"),
+    ('𢯶', "zhì"),
+    ('𢯷', "wěi"),
+    ('𢯹', "gé"),
+    ('𢯺', "jǔ"),
+    ('𢯼', "yǎn"),
+    ('𢯽', "cuò"),
+    ('𢯾', "mào"),
+    ('𢰆', "fú"),
+    ('𢰇', "āi"),
+    ('𢰊', "xuān"),
+    ('𢰌', "gāng"),
+    ('𢰍', "ān"),
+    ('𢰒', "jí"),
+    ('𢰘', "pí"),
+    ('𢰙', "zhǐ"),
+    ('𢰜', "nuó"),
+    ('𢰿', "pàn"),
+    ('𢱁', "yí"),
+    ('𢱄', "jié"),
+    ('𢱆', "zī"),
+    ('𢱈', "jià"),
+    ('𢱉', "wǎi"),
+    ('𢱌', "jià"),
+    ('𢱟', "chǎn"),
+    ('𢱡', "suǒ"),
+    ('𢱢', "suǒ"),
+    ('𢱣', "jí"),
+    ('𢱤', "sǒng"),
+    ('𢱦', "tī"),
+    ('𢱧', "pī"),
+    ('𢱨', "pó"),
+    ('𢱮', "mì"),
+    ('𢱴', "yè"),
+    ('𢱶', "qìn"),
+    ('𢱷', "jìn"),
+    ('𢱺', "juē"),
+    ('𢱽', "yuān"),
+    ('𢱾', "ruán"),
+    ('𢲔', "bàn"),
+    ('𢲰', "bīn"),
+    ('𢲴', "wèi"),
+    ('𢲵', "zào"),
+    ('𢲶', "qiè"),
+    ('𢲷', "sōu"),
+    ('𢲸', "lǔ"),
+    ('𢲼', "dié"),
+    ('𢲽', "chuāi"),
+    ('𢲾', "bì"),
+    ('𢲿', "zhú"),
+    ('𢳀', "mā"),
+    ('𢳁', "fèi"),
+    ('𢳂', "piē"),
+    ('𢳃', "yìn"),
+    ('𢳄', "xuàn"),
+    ('𢳆', "ào"),
+    ('𢳇', "zhuó"),
+    ('𢳈', "zú"),
+    ('𢳋', "bǐ"),
+    ('𢳑', "làng"),
+    ('𢳓', "tì"),
+    ('𢳙', "tiǎo"),
+    ('𢳚', "jiān"),
+    ('𢳟', "tǒng"),
+    ('𢳽', "duō"),
+    ('𢳾', "dòng"),
+    ('𢴂', "biǎn"),
+    ('𢴠', "zhì"),
+    ('𢴢', "fén"),
+    ('𢴦', "káng"),
+    ('𢴧', "zhì"),
+    ('𢴨', "zhāi"),
+    ('𢴩', "bì"),
+    ('𢴪', "kuǎn"),
+    ('𢴬', "bàn"),
+    ('𢴭', "juē"),
+    ('𢴮', "qū"),
+    ('𢴰', "qī"),
+    ('𢴱', "léi"),
+    ('𢴲', "xié"),
+    ('𢴳', "tāng"),
+    ('𢴼', "sōu"),
+    ('𢴾', "bèi"),
+    ('𢵇', "yàng"),
+    ('𢵈', "jiǎn"),
+    ('𢵥', "zào"),
+    ('𢶀', "zhuài"),
+    ('𢶃', "fán"),
+    ('𢶅', "shé"),
+    ('𢶇', "qióng"),
+    ('𢶉', "pò"),
+    ('𢶋', "tiě"),
+    ('𢶌', "shā"),
+    ('𢶍', "zá"),
+    ('𢶑', "niǎo"),
+    ('𢶒', "guài"),
+    ('𢶓', "cuǐ"),
+    ('𢶡', "qiào"),
+    ('𢶣', "dié"),
+    ('𢶳', "pīn"),
+    ('𢶴', "cí"),
+    ('𢶶', "bàng"),
+    ('𢷍', "yìn"),
+    ('𢷑', "xiǎn"),
+    ('𢷔', "yǐ"),
+    ('𢷕', "miǎo"),
+    ('𢷖', "duǎn"),
+    ('𢷗', "zhòu"),
+    ('𢷙', "kōng"),
+    ('𢷢', "zhāng"),
+    ('𢷶', "liú"),
+    ('𢷸', "zhǐ"),
+    ('𢷹', "chǎn"),
+    ('𢷺', "dú"),
+    ('𢷻', "yuán"),
+    ('𢷾', "suò"),
+    ('𢷿', "jié"),
+    ('𢸀', "lì"),
+    ('𢸁', "gǒng"),
+    ('𢸌', "bāng"),
+    ('𢸗', "guó"),
+    ('𢸘', "liáo"),
+    ('𢸙', "shěn"),
+    ('𢸣', "niǎo"),
+    ('𢸥', "cuàn"),
+    ('𢸦', "wěi"),
+    ('𢸨', "tuō"),
+    ('𢸫', "sū"),
+    ('𢸭', "lóng"),
+    ('𢸳', "xiāo"),
+    ('𢸴', "yǎn"),
+    ('𢹃', "qǐng"),
+    ('𢹍', "xī"),
+    ('𢹏', "yú"),
+    ('𢹑', "zhèng"),
+    ('𢹒', "xiè"),
+    ('𢹓', "chāi"),
+    ('𢹔', "fèn"),
+    ('𢹖', "guó"),
+    ('𢹘', "jǐng"),
+    ('𢹙', "làn"),
+    ('𢹚', "xiān"),
+    ('𢹝', "líng"),
+    ('𢹮', "lěi"),
+    ('𢹲', "jùn"),
+    ('𢹳', "xiào"),
+    ('𢹼', "zá"),
+    ('𢺄', "guān"),
+    ('𢺅', "qiè"),
+    ('𢺆', "luò"),
+    ('𢺇', "yào"),
+    ('𢺈', "luán"),
+    ('𢺉', "tà"),
+    ('𢺑', "luò"),
+    ('𢺞', "bǎ"),
+    ('𢺟', "chàn"),
+    ('𢺡', "zhuó"),
+    ('𢺫', "tiǎo"),
+    ('𢺯', "wān"),
+    ('𢺰', "líng"),
+    ('𢺴', "yù"),
+    ('𢺵', "qì"),
+    ('𢺷', "qí"),
+    ('𢺼', "jì"),
+    ('𢺽', "bó"),
+    ('𢺿', "shī"),
+    ('𢻀', "fǔ"),
+    ('𢻂', "guī"),
+    ('𢻅', "diǎn"),
+    ('𢻇', "hāo"),
+    ('𢻉', "gǎi"),
+    ('𢻋', "qí"),
+    ('𢻓', "chéng"),
+    ('𢻔', "huì"),
+    ('𢻗', "xiá"),
+    ('𢻘', "shí"),
+    ('𢻙', "zhì"),
+    ('𢻚', "qí"),
+    ('𢻜', "hài"),
+    ('𢻟', "jiǎo"),
+    ('𢻠', "lì"),
+    ('𢻢', "liǎo"),
+    ('𢻤', "qiāo"),
+    ('𢻨', "sà"),
+    ('𢻪', "qī"),
+    ('𢻫', "shī"),
+    ('𢻮', "jié"),
+    ('𢻵', "bèi"),
+    ('𢻶', "biān"),
+    ('𢻷', "bā"),
+    ('𢻸', "jūn"),
+    ('𢻹', "pī"),
+    ('𢻼', "dǎn"),
+    ('𢻿', "táng"),
+    ('𢼀', "kuǐ"),
+    ('𢼁', "kū"),
+    ('𢼃', "kǒu"),
+    ('𢼉', "shī"),
+    ('𢼊', "shī"),
+    ('𢼋', "jī"),
+    ('𢼌', "bào"),
+    ('𢼐', "kě"),
+    ('𢼑', "kuāng"),
+    ('𢼖', "mǐn"),
+    ('𢼙', "liáo"),
+    ('𢼚', "è"),
+    ('𢼛', "gé"),
+    ('𢼟', "wǎng"),
+    ('𢼠', "duó"),
+    ('𢼣', "qià"),
+    ('𢼤', "huá"),
+    ('𢼦', "hǒng"),
+    ('𢼩', "pēng"),
+    ('𢼫', "jiào"),
+    ('𢼰', "qū"),
+    ('𢼱', "zì"),
+    ('𢼲', "zhòu"),
+    ('𢼳', "kuāng"),
+    ('𢼵', "shā"),
+    ('𢼷', "jì"),
+    ('𢼸', "wēi"),
+    ('𢼹', "pū"),
+    ('𢼺', "xué"),
+    ('𢼼', "shāo"),
+    ('𢽂', "láng"),
+    ('𢽃', "zhǐ"),
+    ('𢽄', "tǐng"),
+    ('𢽇', "dà"),
+    ('𢽕', "yáng"),
+    ('𢽖', "jìn"),
+    ('𢽗', "zhǐ"),
+    ('𢽚', "zhuó"),
+    ('𢽜', "zá"),
+    ('𢽝', "chán"),
+    ('𢽢', "mào"),
+    ('𢽦', "kōng"),
+    ('𢽧', "zhōu"),
+    ('𢽨', "hū"),
+    ('𢽩', "pēng"),
+    ('𢽭', "jiù"),
+    ('𢽸', "chuò"),
+    ('𢽹', "mǐn"),
+    ('𢽾', "xiào"),
+    ('𢾀', "dǔ"),
+    ('𢾁', "wéi"),
+    ('𢾃', "cán"),
+    ('𢾄', "yú"),
+    ('𢾅', "dù"),
+    ('𢾆', "kāi"),
+    ('𢾇', "pì"),
+    ('𢾊', "chéng"),
+    ('𢾎', "chǔn"),
+    ('𢾐', "shǎo"),
+    ('𢾑', "yǎn"),
+    ('𢾒', "kuài"),
+    ('𢾔', "yuē"),
+    ('𢾦', "qí"),
+    ('𢾧', "zhēng"),
+    ('𢾩', "kè"),
+    ('𢾪', "qí"),
+    ('𢾫', "zhǐ"),
+    ('𢾬', "lù"),
+    ('𢾱', "pī"),
+    ('𢾲', "nuò"),
+    ('𢾳', "pǎo"),
+    ('𢾺', "fěi"),
+    ('𢾿', "wén"),
+    ('𢿂', "méng"),
+    ('𢿈', "shǎn"),
+    ('𢿌', "xiòng"),
+    ('𢿎', "duò"),
+    ('𢿏', "biào"),
+    ('𢿚', "yōu"),
+    ('𢿜', "màn"),
+    ('𢿞', "liǎo"),
+    ('𢿡', "xié"),
+    ('𢿢', "luàn"),
+    ('𢿣', "qiāo"),
+    ('𢿤', "dèng"),
+    ('𢿦', "chéng"),
+    ('𢿧', "chéng"),
+    ('𢿭', "chuò"),
+    ('𢿸', "cè"),
+    ('𣀀', "léi"),
+    ('𣀁', "zhǎn"),
+    ('𣀂', "lǐ"),
+    ('𣀃', "lián"),
+    ('𣀄', "qún"),
+    ('𣀍', "chén"),
+    ('𣀏', "chéng"),
+    ('𣀐', "gū"),
+    ('𣀒', "zòng"),
+    ('𣀓', "chóu"),
+    ('𣀔', "chuàn"),
+    ('𣀜', "lèi"),
+    ('𣀝', "shuò"),
+    ('𣀞', "lǜ"),
+    ('𣀣', "fú"),
+    ('𣀥', "lì"),
+    ('𣀧', "sàn"),
+    ('𣀫', "sān"),
+    ('𣀯', "sà"),
+    ('𣀳', "niè"),
+    ('𣀶', "zuān"),
+    ('𣀷', "lǐ"),
+    ('𣀻', "shǔ"),
+    ('𣀾', "fú"),
+    ('𣁉', "bì"),
+    ('𣁍', "dào"),
+    ('𣁒', "shī"),
+    ('𣁖', "gàn"),
+    ('𣁗', "tàn"),
+    ('𣁜', "màn"),
+    ('𣁟', "lí"),
+    ('𣁢', "bì"),
+    ('𣁦', "pán"),
+    ('𣁨', "yōu"),
+    ('𣁭', "jiū"),
+    ('𣁯', "guō"),
+    ('𣁰', "liáo"),
+    ('𣁳', "wò"),
+    ('𣁴', "qià"),
+    ('𣁵', "dǒu"),
+    ('𣁷', "liè"),
+    ('𣁹', "jiǎo"),
+    ('𣁻', "liè"),
+    ('𣂁', "tiāo"),
+    ('𣂄', "guō"),
+    ('𣂆', "pāng"),
+    ('𣂇', "qiāo"),
+    ('𣂉', "dí"),
+    ('𣂊', "yùn"),
+    ('𣂒', "lè"),
+    ('𣂖', "sī"),
+    ('𣂗', "xīn"),
+    ('𣂜', "xīn"),
+    ('𣂝', "xiàng"),
+    ('𣂞', "luǒ"),
+    ('𣂤', "bēng"),
+    ('𣂥', "tiāo"),
+    ('𣂬', "xiào"),
+    ('𣂮', "dōu"),
+    ('𣂳', "dàng"),
+    ('𣂴', "tíng"),
+    ('𣂵', "zhuàn"),
+    ('𣂻', "ōu"),
+    ('𣂽', "wò"),
+    ('𣃁', "zhǔ"),
+    ('𣃄', "xīn"),
+    ('𣃅', "ruǎn"),
+    ('𣃈', "zhuó"),
+    ('𣃉', "dàng"),
+    ('𣃍', "cuì"),
+    ('𣃑', "zhuó"),
+    ('𣃗', "cóng"),
+    ('𣃘', "chǎn"),
+    ('𣃝', "yǎng"),
+    ('𣃧', "yǎn"),
+    ('𣃳', "yǎn"),
+    ('𣃵', "zhèn"),
+    ('𣃽', "nuǒ"),
+    ('𣃾', "yàn"),
+    ('𣄅', "fǎng"),
+    ('𣄉', "yǎn"),
+    ('𣄊', "yú"),
+    ('𣄍', "tí"),
+    ('𣄎', "fù"),
+    ('𣄏', "běn"),
+    ('𣄑', "yǎn"),
+    ('𣄓', "huī"),
+    ('𣄙', "huǎng"),
+    ('𣄜', "guì"),
+    ('𣄝', "yàn"),
+    ('𣄟', "hú"),
+    ('𣄠', "biāo"),
+    ('𣄧', "suì"),
+    ('𣄮', "zì"),
+    ('𣄯', "jì"),
+    ('𣄰', "ě"),
+    ('𣄱', "jì"),
+    ('𣄲', "kuǐ"),
+    ('𣄴', "liàng"),
+    ('𣄸', "huò"),
+    ('𣄺', "wéi"),
+    ('𣄻', "zhuō"),
+    ('𣄿', "tǐng"),
+    ('𣅃', "zǎi"),
+    ('𣅄', "yòu"),
+    ('𣅉', "rèn"),
+    ('𣅍', "miàn"),
+    ('𣅚', "nà"),
+    ('𣅝', "tū"),
+    ('𣅟', "dān"),
+    ('𣅡', "jué"),
+    ('𣅤', "xū"),
+    ('𣅥', "dī"),
+    ('𣅰', "xiàng"),
+    ('𣅷', "xiòng"),
+    ('𣅺', "yǒu"),
+    ('𣅻', "guǎ"),
+    ('𣅾', "xī"),
+    ('𣆈', "hè"),
+    ('𣆍', "dǐng"),
+    ('𣆐', "lú"),
+    ('𣆒', "xú"),
+    ('𣆔', "zhòu"),
+    ('𣆕', "xiàn"),
+    ('𣆖', "huāng"),
+    ('𣆗', "chā"),
+    ('𣆘', "shǐ"),
+    ('𣆙', "gàn"),
+    ('𣆚', "nuǒ"),
+    ('𣆛', "àn"),
+    ('𣆟', "xiē"),
+    ('𣆧', "hào"),
+    ('𣆲', "qīn"),
+    ('𣆳', "gěng"),
+    ('𣆴', "shān"),
+    ('𣆵', "fú"),
+    ('𣆽', "zè"),
+    ('𣇇', "dàn"),
+    ('𣇖', "diǎn"),
+    ('𣇗', "shēn"),
+    ('𣇙', "zǔ"),
+    ('𣇢', "biē"),
+    ('𣇦', "chuí"),
+    ('𣇧', "zhè"),
+    ('𣇨', "dài"),
+    ('𣇫', "wǒ"),
+    ('𣇬', "qióng"),
+    ('𣇰', "lín"),
+    ('𣇲', "hūn"),
+    ('𣇳', "jī"),
+    ('𣈅', "cáo"),
+    ('𣈊', "mù"),
+    ('𣈍', "dié"),
+    ('𣈎', "wèi"),
+    ('𣈠', "biàn"),
+    ('𣈡', "tǐ"),
+    ('𣈣', "yìng"),
+    ('𣈥', "tú"),
+    ('𣈶', "gèng"),
+    ('𣉄', "chí"),
+    ('𣉅', "còu"),
+    ('𣉆', "tǐ"),
+    ('𣉒', "huò"),
+    ('𣉓', "qī"),
+    ('𣉔', "sāo"),
+    ('𣉕', "sàng"),
+    ('𣉖', "xuǎn"),
+    ('𣉗', "àng"),
+    ('𣉘', "nài"),
+    ('𣉚', "yáng"),
+    ('𣉛', "shū"),
+    ('𣉜', "shā"),
+    ('𣉡', "tǐng"),
+    ('𣉩', "yà"),
+    ('𣉪', "huǎng"),
+    ('𣉮', "bīn"),
+    ('𣉼', "ài"),
+    ('𣉾', "òu"),
+    ('𣉿', "cáo"),
+    ('𣊁', "áo"),
+    ('𣊃', "mào"),
+    ('𣊔', "méng"),
+    ('𣊖', "tiān"),
+    ('𣊝', "sàng"),
+    ('𣊞', "xù"),
+    ('𣊟', "kàn"),
+    ('𣊧', "lǎng"),
+    ('𣊶', "biē"),
+    ('𣊷', "cóng"),
+    ('𣊺', "xián"),
+    ('𣋄', "tūn"),
+    ('𣋉', "yù"),
+    ('𣋊', "dàn"),
+    ('𣋋', "yìng"),
+    ('𣋍', "zhāo"),
+    ('𣋏', "pù"),
+    ('𣋘', "huì"),
+    ('𣋞', "ài"),
+    ('𣋟', "mǒ"),
+    ('𣋢', "jīng"),
+    ('𣋣', "lán"),
+    ('𣋲', "liè"),
+    ('𣋳', "piǎo"),
+    ('𣋵', "bó"),
+    ('𣋶', "qióng"),
+    ('𣋹', "bì"),
+    ('𣋿', "yōng"),
+    ('𣌅', "lì"),
+    ('𣌍', "niè"),
+    ('𣌏', "dé"),
+    ('𣌓', "huān"),
+    ('𣌗', "yuè"),
+    ('𣌚', "chūn"),
+    ('𣌜', "lì"),
+    ('𣌞', "zhāng"),
+    ('𣌟', "líng"),
+    ('𣌠', "chún"),
+    ('𣌧', "cè"),
+    ('𣌨', "xún"),
+    ('𣌬', "jǔ"),
+    ('𣌭', "hui"),
+    ('𣌾', "tōng"),
+    ('𣍆', "níng"),
+    ('𣍇', "jù"),
+    ('𣍏', "chà"),
+    ('𣍖', "zāo"),
+    ('𣍛', "yù"),
+    ('𣍟', "kěn"),
+    ('𣍦', "kuàng"),
+    ('𣍧', "fěi"),
+    ('𣍯', "yùn"),
+    ('𣍰', "qiǎn"),
+    ('𣍴', "quán"),
+    ('𣍸', "pò"),
+    ('𣍺', "pěi"),
+    ('𣎄', "gèng"),
+    ('𣎅', "yì"),
+    ('𣎆', "luò"),
+    ('𣎑', "kuān"),
+    ('𣎓', "xuǎn"),
+    ('𣎔', "niàn"),
+    ('𣎚', "hú"),
+    ('𣎛', "jú"),
+    ('𣎩', "yè"),
+    ('𣎮', "xī"),
+    ('𣎱', "yuè"),
+    ('𣎲', "tǎng"),
+    ('𣎳', "pìn"),
+    ('𣎴', "dǔn"),
+    ('𣎵', "bèi"),
+    ('𣎸', "liǎo"),
+    ('𣏀', "yǒng"),
+    ('𣏎', "yā"),
+    ('𣏑', "jiǎo"),
+    ('𣏔', "kùn"),
+    ('𣏖', "zhèn"),
+    ('𣏗', "shù"),
+    ('𣏚', "shí"),
+    ('𣏞', "yóu"),
+    ('𣏟', "pài"),
+    ('𣏠', "xiáo"),
+    ('𣏡', "jí"),
+    ('𣏢', "tuán"),
+    ('𣏶', "qī"),
+    ('𣏷', "hé"),
+    ('𣏺', "kǒng"),
+    ('𣐂', "yè"),
+    ('𣐃', "chì"),
+    ('𣐊', "kǎo"),
+    ('𣐋', "yuè"),
+    ('𣐎', "wǎ"),
+    ('𣐏', "niǎn"),
+    ('𣐑', "cí"),
+    ('𣐓', "yí"),
+    ('𣐕', "jìng"),
+    ('𣐤', "jiu"),
+    ('𣐫', "yāng"),
+    ('𣐬', "lí"),
+    ('𣐮', "dāi"),
+    ('𣐯', "chóng"),
+    ('𣐵', "yí"),
+    ('𣐺', "hàn"),
+    ('𣐿', "yī"),
+    ('𣑁', "chòng"),
+    ('𣑂', "hù"),
+    ('𣑃', "zhuǎ"),
+    ('𣑝', "tuán"),
+    ('𣑦', "qióng"),
+    ('𣑧', "duò"),
+    ('𣑸', "tóng"),
+    ('𣑹', "xiān"),
+    ('𣑿', "fú"),
+    ('𣒂', "diàn"),
+    ('𣒃', "xí"),
+    ('𣒄', "xiē"),
+    ('𣒅', "zhèn"),
+    ('𣒆', "qiào"),
+    ('𣒇', "tū"),
+    ('𣒌', "huò"),
+    ('𣒗', "hé"),
+    ('𣒷', "hàn"),
+    ('𣒸', "kuàng"),
+    ('𣒹', "suō"),
+    ('𣒻', "shòu"),
+    ('𣒼', "tiáo"),
+    ('𣓀', "zhēn"),
+    ('𣓃', "nèi"),
+    ('𣓅', "qiǎn"),
+    ('𣓆', "yín"),
+    ('𣓈', "liǎng"),
+    ('𣓉', "shà"),
+    ('𣓊', "zì"),
+    ('𣓋', "pí"),
+    ('𣓌', "gāo"),
+    ('𣓏', "jìn"),
+    ('𣓐', "yóu"),
+    ('𣓒', "shàn"),
+    ('𣓔', "mì"),
+    ('𣓕', "òu"),
+    ('𣓗', "hū"),
+    ('𣓛', "yòu"),
+    ('𣓝', "měng"),
+    ('𣓿', "lào"),
+    ('𣔐', "zhǐ"),
+    ('𣔓', "bǐ"),
+    ('𣔗', "shēn"),
+    ('𣔘', "qì"),
+    ('𣔙', "xiān"),
+    ('𣔚', "pán"),
+    ('𣔛', "kǎng"),
+    ('𣔫', "shuān"),
+    ('𣔬', "pí"),
+    ('𣔮', "zāi"),
+    ('𣔯', "zhǔ"),
+    ('𣔱', "sōu"),
+    ('𣔲', "jiǒng"),
+    ('𣔵', "chán"),
+    ('𣔶', "fán"),
+    ('𣔷', "xiáo"),
+    ('𣔸', "yǐn"),
+    ('𣔹', "hóu"),
+    ('𣔺', "mào"),
+    ('𣔻', "tú"),
+    ('𣔼', "gān"),
+    ('𣔽', "jì"),
+    ('𣕁', "yí"),
+    ('𣕃', "yù"),
+    ('𣕄', "jiōng"),
+    ('𣕅', "pào"),
+    ('𣕇', "xiāo"),
+    ('𣕉', "gǒu"),
+    ('𣕌', "gōu"),
+    ('𣕍', "sǔn"),
+    ('𣕎', "xiǎn"),
+    ('𣕏', "zhuǎn"),
+    ('𣕲', "gèn"),
+    ('𣕾', "chóu"),
+    ('𣖄', "qiāo"),
+    ('𣖅', "tí"),
+    ('𣖆', "yún"),
+    ('𣖉', "shān"),
+    ('𣖊', "liè"),
+    ('𣖌', "zhǐ"),
+    ('𣖐', "pāi"),
+    ('𣖣', "jú"),
+    ('𣖤', "lái"),
+    ('𣖨', "zǐ"),
+    ('𣖪', "qú"),
+    ('𣖫', "gǔ"),
+    ('𣖬', "jué"),
+    ('𣖭', "zhí"),
+    ('𣖮', "àng"),
+    ('𣖯', "qìn"),
+    ('𣖰', "pí"),
+    ('𣖱', "zuī"),
+    ('𣖳', "qián"),
+    ('𣖵', "cuó"),
+    ('𣖷', "jí"),
+    ('𣖸', "tí"),
+    ('𣖹', "rú"),
+    ('𣖻', "hǎi"),
+    ('𣖼', "xún"),
+    ('𣖾', "bèi"),
+    ('𣖿', "zhí"),
+    ('𣗁', "dùn"),
+    ('𣗊', "mán"),
+    ('𣗋', "dǎng"),
+    ('𣗐', "réng"),
+    ('𣗲', "gān"),
+    ('𣗵', "gàng"),
+    ('𣗶', "tà"),
+    ('𣗸', "tuò"),
+    ('𣗹', "yàng"),
+    ('𣗺', "kū"),
+    ('𣗻', "zhì"),
+    ('𣘐', "lì"),
+    ('𣘖', "jiān"),
+    ('𣘗', "nì"),
+    ('𣘘', "shēn"),
+    ('𣘙', "bàng"),
+    ('𣘚', "shuài"),
+    ('𣘛', "dōu"),
+    ('𣘝', "qiān"),
+    ('𣘞', "hán"),
+    ('𣘟', "qiā"),
+    ('𣘠', "gǎn"),
+    ('𣘣', "chún"),
+    ('𣘤', "chá"),
+    ('𣘥', "bì"),
+    ('𣘦', "yī"),
+    ('𣘧', "fū"),
+    ('𣘨', "ě"),
+    ('𣘪', "láo"),
+    ('𣘫', "háo"),
+    ('𣘬', "lí"),
+    ('𣘱', "tè"),
+    ('𣘲', "shēn"),
+    ('𣘴', "yín"),
+    ('𣘷', "jiān"),
+    ('𣘻', "chá"),
+    ('𣙗', "niè"),
+    ('𣙘', "còu"),
+    ('𣙛', "yí"),
+    ('𣙟', "táng"),
+    ('𣙢', "juàn"),
+    ('𣙰', "chì"),
+    ('𣙱', "gǒu"),
+    ('𣙴', "jié"),
+    ('𣙵', "zhé"),
+    ('𣙶', "hú"),
+    ('𣙷', "máng"),
+    ('𣙻', "zōu"),
+    ('𣙼', "sì"),
+    ('𣙿', "fèi"),
+    ('𣚀', "zī"),
+    ('𣚁', "zī"),
+    ('𣚃', "jié"),
+    ('𣚄', "sī"),
+    ('𣚆', "chūn"),
+    ('𣚇', "pào"),
+    ('𣚋', "yé"),
+    ('𣚌', "dī"),
+    ('𣚎', "léi"),
+    ('𣚏', "xū"),
+    ('𣚐', "rú"),
+    ('𣚒', "pá"),
+    ('𣚓', "juàn"),
+    ('𣚔', "xì"),
+    ('𣚕', "yè"),
+    ('𣚖', "ān"),
+    ('𣚘', "yì"),
+    ('𣚙', "jiān"),
+    ('𣚚', "zhú"),
+    ('𣚜', "sōng"),
+    ('𣚝', "wǒ"),
+    ('𣚟', "sè"),
+    ('𣚠', "zhǐ"),
+    ('𣚡', "bī"),
+    ('𣚢', "zhuàn"),
+    ('𣚦', "jiàng"),
+    ('𣚧', "hào"),
+    ('𣚩', "chì"),
+    ('𣚪', "dùn"),
+    ('𣛓', "bó"),
+    ('𣛔', "jí"),
+    ('𣛕', "chuǎ"),
+    ('𣛗', "luò"),
+    ('𣛚', "ruǐ"),
+    ('𣛫', "hú"),
+    ('𣛱', "dàn"),
+    ('𣛴', "hǎn"),
+    ('𣛵', "què"),
+    ('𣛶', "shā"),
+    ('𣛷', "zhǎn"),
+    ('𣛸', "zé"),
+    ('𣛹', "chuán"),
+    ('𣛺', "qī"),
+    ('𣛻', "dié"),
+    ('𣛽', "zhà"),
+    ('𣛾', "tòu"),
+    ('𣜁', "cī"),
+    ('𣜂', "sà"),
+    ('𣜄', "luó"),
+    ('𣜇', "jí"),
+    ('𣜢', "luǒ"),
+    ('𣜣', "qín"),
+    ('𣜧', "qióng"),
+    ('𣜨', "juàn"),
+    ('𣜬', "ài"),
+    ('𣜭', "jiǎn"),
+    ('𣜹', "tì"),
+    ('𣜺', "wén"),
+    ('𣜽', "qiāo"),
+    ('𣝁', "pái"),
+    ('𣝂', "hún"),
+    ('𣝅', "ài"),
+    ('𣝇', "shuò"),
+    ('𣝈', "lián"),
+    ('𣝉', "duì"),
+    ('𣝋', "tà"),
+    ('𣝌', "jǐn"),
+    ('𣝍', "bì"),
+    ('𣝎', "yǎn"),
+    ('𣝏', "gào"),
+    ('𣝐', "piáo"),
+    ('𣝑', "yù"),
+    ('𣝒', "shè"),
+    ('𣝕', "jiān"),
+    ('𣝗', "hú"),
+    ('𣝚', "liè"),
+    ('𣝜', "biàn"),
+    ('𣝝', "sù"),
+    ('𣝞', "jiāo"),
+    ('𣝸', "zhuì"),
+    ('𣝽', "hān"),
+    ('𣞇', "dùn"),
+    ('𣞐', "xiě"),
+    ('𣞑', "méng"),
+    ('𣞒', "fū"),
+    ('𣞓', "lù"),
+    ('𣞔', "tàn"),
+    ('𣞗', "liú"),
+    ('𣞘', "xiān"),
+    ('𣞙', "sǎng"),
+    ('𣞜', "còu"),
+    ('𣞝', "zhuāng"),
+    ('𣞟', "chēn"),
+    ('𣞰', "liàn"),
+    ('𣞴', "lí"),
+    ('𣟀', "pèng"),
+    ('𣟁', "tuǒ"),
+    ('𣟄', "tuò"),
+    ('𣟆', "liáo"),
+    ('𣟇', "xiào"),
+    ('𣟈', "chuì"),
+    ('𣟉', "huài"),
+    ('𣟊', "niǎo"),
+    ('𣟋', "qiān"),
+    ('𣟌', "lì"),
+    ('𣟏', "pāo"),
+    ('𣟐', "tiáo"),
+    ('𣟑', "liú"),
+    ('𣟒', "wú"),
+    ('𣟤', "yǐng"),
+    ('𣟦', "zhá"),
+    ('𣟰', "yú"),
+    ('𣟲', "xiǎn"),
+    ('𣟳', "xuán"),
+    ('𣟴', "shuān"),
+    ('𣟵', "xī"),
+    ('𣟸', "méi"),
+    ('𣟹', "sēn"),
+    ('𣟺', "liàn"),
+    ('𣟼', "jiū"),
+    ('𣟽', "lào"),
+    ('𣠎', "xiāo"),
+    ('𣠏', "zōu"),
+    ('𣠚', "liú"),
+    ('𣠜', "zhào"),
+    ('𣠞', "zhé"),
+    ('𣠠', "lěi"),
+    ('𣠭', "duǎn"),
+    ('𣠷', "jiǎn"),
+    ('𣠸', "shuān"),
+    ('𣠹', "zuó"),
+    ('𣠺', "qiè"),
+    ('𣠼', "lǎo"),
+    ('𣡉', "yù"),
+    ('𣡊', "yì"),
+    ('𣡋', "nǐ"),
+    ('𣡎', "cén"),
+    ('𣡕', "yàn"),
+    ('𣡗', "ruǎn"),
+    ('𣡞', "yán"),
+    ('𣡟', "dié"),
+    ('𣡠', "mián"),
+    ('𣡧', "léi"),
+    ('𣡩', "wān"),
+    ('𣡰', "nǎ"),
+    ('𣡶', "yán"),
+    ('𣡺', "lěi"),
+    ('𣡽', "shā"),
+    ('𣡾', "hū"),
+    ('𣢁', "xī"),
+    ('𣢂', "xī"),
+    ('𣢄', "yǒu"),
+    ('𣢅', "hān"),
+    ('𣢇', "hāi"),
+    ('𣢉', "wā"),
+    ('𣢊', "xù"),
+    ('𣢋', "pī"),
+    ('𣢌', "tān"),
+    ('𣢍', "xī"),
+    ('𣢎', "xī"),
+    ('𣢏', "bīn"),
+    ('𣢐', "qīn"),
+    ('𣢑', "xī"),
+    ('𣢒', "yú"),
+    ('𣢓', "xì"),
+    ('𣢕', "cì"),
+    ('𣢖', "qiàn"),
+    ('𣢗', "xiā"),
+    ('𣢚', "wá"),
+    ('𣢛', "è"),
+    ('𣢜', "yǒu"),
+    ('𣢝', "xìng"),
+    ('𣢞', "ní"),
+    ('𣢟', "hán"),
+    ('𣢠', "bì"),
+    ('𣢡', "shēng"),
+    ('𣢤', "zhān"),
+    ('𣢥', "diàn"),
+    ('𣢦', "yǔ"),
+    ('𣢨', "ǒu"),
+    ('𣢪', "guǐ"),
+    ('𣢫', "wǎng"),
+    ('𣢬', "qiān"),
+    ('𣢭', "yí"),
+    ('𣢰', "zú"),
+    ('𣢲', "qiān"),
+    ('𣢳', "dìng"),
+    ('𣢴', "kēng"),
+    ('𣢶', "chù"),
+    ('𣢷', "yī"),
+    ('𣢺', "hān"),
+    ('𣢻', "kuǎn"),
+    ('𣣈', "diàn"),
+    ('𣣉', "xì"),
+    ('𣣊', "zī"),
+    ('𣣋', "líng"),
+    ('𣣌', "zì"),
+    ('𣣎', "yù"),
+    ('𣣏', "hūn"),
+    ('𣣑', "sǐ"),
+    ('𣣒', "kǎn"),
+    ('𣣚', "àn"),
+    ('𣣜', "yǒu"),
+    ('𣣝', "jí"),
+    ('𣣞', "hùn"),
+    ('𣣟', "qiā"),
+    ('𣣠', "hóu"),
+    ('𣣡', "hóu"),
+    ('𣣣', "diàn"),
+    ('𣣩', "xiē"),
+    ('𣣭', "shè"),
+    ('𣣮', "shà"),
+    ('𣣲', "xié"),
+    ('𣣳', "yáo"),
+    ('𣣴', "dà"),
+    ('𣣶', "xiè"),
+    ('𣣷', "chī"),
+    ('𣣸', "yǒu"),
+    ('𣣹', "hē"),
+    ('𣣺', "shà"),
+    ('𣣿', "tái"),
+    ('𣤁', "zhú"),
+    ('𣤃', "ǎi"),
+    ('𣤇', "què"),
+    ('𣤈', "zé"),
+    ('𣤊', "lā"),
+    ('𣤋', "lòu"),
+    ('𣤌', "chuài"),
+    ('𣤎', "yǒu"),
+    ('𣤖', "tì"),
+    ('𣤘', "shī"),
+    ('𣤡', "xiào"),
+    ('𣤢', "xì"),
+    ('𣤨', "huò"),
+    ('𣤩', "chì"),
+    ('𣤪', "yì"),
+    ('𣤯', "shú"),
+    ('𣤰', "yuè"),
+    ('𣤱', "chán"),
+    ('𣤲', "è"),
+    ('𣤳', "xī"),
+    ('𣤴', "xī"),
+    ('𣤵', "yǐng"),
+    ('𣤶', "zú"),
+    ('𣤷', "zā"),
+    ('𣤺', "zā"),
+    ('𣥂', "tà"),
+    ('𣥃', "wàn"),
+    ('𣥇', "xìn"),
+    ('𣥊', "wàng"),
+    ('𣥋', "fǔ"),
+    ('𣥐', "lǔ"),
+    ('𣥞', "jiǎn"),
+    ('𣥡', "yán"),
+    ('𣥣', "bì"),
+    ('𣥤', "kěn"),
+    ('𣥥', "guàn"),
+    ('𣥨', "zī"),
+    ('𣥮', "kuǐ"),
+    ('𣥯', "zhǒu"),
+    ('𣥰', "zhì"),
+    ('𣥳', "tú"),
+    ('𣥷', "tà"),
+    ('𣥹', "chù"),
+    ('𣥺', "chēng"),
+    ('𣥻', "chěng"),
+    ('𣥼', "zhù"),
+    ('𣥾', "dà"),
+    ('𣦇', "bì"),
+    ('𣦉', "jiǎ"),
+    ('𣦌', "yì"),
+    ('𣦏', "yuè"),
+    ('𣦐', "gāng"),
+    ('𣦖', "gān"),
+    ('𣦜', "qiāo"),
+    ('𣦠', "chú"),
+    ('𣦡', "chú"),
+    ('𣦢', "bì"),
+    ('𣦦', "guì"),
+    ('𣦩', "gǔ"),
+    ('𣦪', "bǐng"),
+    ('𣦫', "yìn"),
+    ('𣦬', "zhuì"),
+    ('𣦭', "gǔ"),
+    ('𣦯', "lì"),
+    ('𣦵', "è"),
+    ('𣦶', "dǎi"),
+    ('𣦼', "cán"),
+    ('𣧂', "tì"),
+    ('𣧃', "dù"),
+    ('𣧄', "yì"),
+    ('𣧈', "dié"),
+    ('𣧊', "niǔ"),
+    ('𣧌', "xuè"),
+    ('𣧍', "nè"),
+    ('𣧎', "guì"),
+    ('𣧏', "kǎo"),
+    ('𣧒', "chuǎn"),
+    ('𣧖', "zhá"),
+    ('𣧗', "yóu"),
+    ('𣧙', "bài"),
+    ('𣧚', "shí"),
+    ('𣧛', "diàn"),
+    ('𣧜', "pā"),
+    ('𣧝', "qiú"),
+    ('𣧡', "xuè"),
+    ('𣧣', "mò"),
+    ('𣧤', "kē"),
+    ('𣧥', "yǒu"),
+    ('𣧦', "jiǎo"),
+    ('𣧧', "bó"),
+    ('𣧬', "xiǔ"),
+    ('𣧲', "mǐ"),
+    ('𣧳', "luò"),
+    ('𣧵', "xuè"),
+    ('𣧷', "duò"),
+    ('𣧹', "èr"),
+    ('𣧺', "shān"),
+    ('𣧼', "kuì"),
+    ('𣧽', "nào"),
+    ('𣧾', "miǎn"),
+    ('𣧿', "lì"),
+    ('𣨀', "luàn"),
+    ('𣨂', "dié"),
+    ('𣨄', "qià"),
+    ('𣨅', "lèi"),
+    ('𣨇', "mào"),
+    ('𣨉', "hēng"),
+    ('𣨊', "chè"),
+    ('𣨋', "zhì"),
+    ('𣨍', "gǔ"),
+    ('𣨎', "cuō"),
+    ('𣨓', "wù"),
+    ('𣨔', "tào"),
+    ('𣨗', "xī"),
+    ('𣨘', "yāo"),
+    ('𣨙', "wěi"),
+    ('𣨛', "zú"),
+    ('𣨜', "mà"),
+    ('𣨝', "yǔ"),
+    ('𣨞', "pěng"),
+    ('𣨟', "yì"),
+    ('𣨠', "qìn"),
+    ('𣨡', "yuè"),
+    ('𣨢', "juè"),
+    ('𣨣', "jiàng"),
+    ('𣨤', "xù"),
+    ('𣨥', "bēng"),
+    ('𣨪', "luǒ"),
+    ('𣨫', "zhuī"),
+    ('𣨲', "dù"),
+    ('𣨳', "xiàng"),
+    ('𣨶', "huì"),
+    ('𣨺', "gǔ"),
+    ('𣨻', "kǎo"),
+    ('𣨼', "tì"),
+    ('𣨾', "xīng"),
+    ('𣨿', "hún"),
+    ('𣩀', "biān"),
+    ('𣩄', "kè"),
+    ('𣩅', "kǎo"),
+    ('𣩈', "cuó"),
+    ('𣩏', "lù"),
+    ('𣩑', "zuì"),
+    ('𣩒', "zāo"),
+    ('𣩓', "jiǎo"),
+    ('𣩔', "guàn"),
+    ('𣩙', "yān"),
+    ('𣩚', "ér"),
+    ('𣩜', "qíng"),
+    ('𣩟', "dèng"),
+    ('𣩠', "sì"),
+    ('𣩡', "suì"),
+    ('𣩢', "liào"),
+    ('𣩧', "shàn"),
+    ('𣩩', "bì"),
+    ('𣩪', "wèi"),
+    ('𣩫', "yè"),
+    ('𣩭', "zhài"),
+    ('𣩯', "yé"),
+    ('𣩰', "diào"),
+    ('𣩱', "ài"),
+    ('𣩴', "jiàng"),
+    ('𣩷', "sū"),
+    ('𣩹', "huài"),
+    ('𣩺', "yù"),
+    ('𣩽', "rǎng"),
+    ('𣪀', "diān"),
+    ('𣪁', "zuān"),
+    ('𣪂', "bān"),
+    ('𣪄', "qín"),
+    ('𣪇', "jiā"),
+    ('𣪉', "pí"),
+    ('𣪌', "tóu"),
+    ('𣪐', "chóu"),
+    ('𣪕', "guǐ"),
+    ('𣪠', "jī"),
+    ('𣪨', "xuè"),
+    ('𣪪', "diàn"),
+    ('𣪭', "biàn"),
+    ('𣪮', "zǎi"),
+    ('𣪯', "tóng"),
+    ('𣪶', "shǎn"),
+    ('𣪸', "gù"),
+    ('𣪹', "què"),
+    ('𣫀', "gǔ"),
+    ('𣫈', "hú"),
+    ('𣫉', "kuǎi"),
+    ('𣫌', "gòu"),
+    ('𣫎', "sù"),
+    ('𣫐', "chóu"),
+    ('𣫒', "kēng"),
+    ('𣫔', "dū"),
+    ('𣫙', "yì"),
+    ('𣫜', "dào"),
+    ('𣫝', "qiāng"),
+    ('𣫣', "lóng"),
+    ('𣫥', "lí"),
+    ('𣫧', "lì"),
+    ('𣫨', "qīng"),
+    ('𣫪', "wēi"),
+    ('𣫬', "móu"),
+    ('𣫱', "qì"),
+    ('𣫳', "jiǎng"),
+    ('𣫴', "xié"),
+    ('𣫹', "dài"),
+    ('𣫻', "lóu"),
+    ('𣬂', "guàn"),
+    ('𣬆', "péi"),
+    ('𣬉', "pí"),
+    ('𣬋', "juàn"),
+    ('𣬍', "bēi"),
+    ('𣬎', "jué"),
+    ('𣬏', "juàn"),
+    ('𣬐', "shì"),
+    ('𣬕', "xiě"),
+    ('𣬘', "ruí"),
+    ('𣬙', "jìng"),
+    ('𣬚', "pò"),
+    ('𣬛', "sān"),
+    ('𣬠', "jī"),
+    ('𣬩', "fēn"),
+    ('𣬪', "bèi"),
+    ('𣬫', "jiè"),
+    ('𣬬', "sā"),
+    ('𣬮', "pī"),
+    ('𣬴', "dì"),
+    ('𣬵', "máo"),
+    ('𣬶', "ba"),
+    ('𣬷', "ba"),
+    ('𣬸', "tiáo"),
+    ('𣬹', "líng"),
+    ('𣬺', "shēng"),
+    ('𣬻', "zhěn"),
+    ('𣬼', "pī"),
+    ('𣬽', "wù"),
+    ('𣬿', "zè"),
+    ('𣭀', "bào"),
+    ('𣭇', "lǚ"),
+    ('𣭖', "hāo"),
+    ('𣭗', "dǒu"),
+    ('𣭘', "fú"),
+    ('𣭙', "ní"),
+    ('𣭝', "gé"),
+    ('𣭠', "rú"),
+    ('𣭡', "xiǎn"),
+    ('𣭤', "bì"),
+    ('𣭮', "máo"),
+    ('𣭲', "rǒng"),
+    ('𣭳', "qiú"),
+    ('𣭷', "bó"),
+    ('𣭹', "hāo"),
+    ('𣭺', "nǎo"),
+    ('𣭻', "yán"),
+    ('𣮃', "páo"),
+    ('𣮄', "suī"),
+    ('𣮆', "tuò"),
+    ('𣮈', "qū"),
+    ('𣮉', "lí"),
+    ('𣮊', "dé"),
+    ('𣮌', "jié"),
+    ('𣮍', "jié"),
+    ('𣮎', "gǔn"),
+    ('𣮏', "jiān"),
+    ('𣮐', "bì"),
+    ('𣮠', "sàn"),
+    ('𣮡', "bāng"),
+    ('𣮢', "chún"),
+    ('𣮦', "nài"),
+    ('𣮧', "bǎng"),
+    ('𣮪', "róng"),
+    ('𣮫', "jiā"),
+    ('𣮬', "sōu"),
+    ('𣮰', "dé"),
+    ('𣮾', "xiān"),
+    ('𣮿', "zhān"),
+    ('𣯀', "mào"),
+    ('𣯃', "zī"),
+    ('𣯅', "jì"),
+    ('𣯆', "qí"),
+    ('𣯋', "rù"),
+    ('𣯌', "suō"),
+    ('𣯍', "rǒng"),
+    ('𣯎', "wù"),
+    ('𣯏', "róng"),
+    ('𣯐', "róng"),
+    ('𣯚', "tà"),
+    ('𣯜', "sōu"),
+    ('𣯣', "mén"),
+    ('𣯤', "lí"),
+    ('𣯧', "cuǐ"),
+    ('𣯨', "zōng"),
+    ('𣯩', "mén"),
+    ('𣯪', "xǐ"),
+    ('𣯬', "mǎng"),
+    ('𣯭', "niè"),
+    ('𣯯', "suī"),
+    ('𣯱', "péi"),
+    ('𣯴', "bì"),
+    ('𣯵', "dì"),
+    ('𣯶', "sān"),
+    ('𣯸', "qú"),
+    ('𣯹', "qiáo"),
+    ('𣯻', "fēn"),
+    ('𣯼', "sù"),
+    ('𣰃', "xū"),
+    ('𣰇', "rǒng"),
+    ('𣰈', "jī"),
+    ('𣰋', "qú"),
+    ('𣰌', "liè"),
+    ('𣰕', "sào"),
+    ('𣰘', "kùn"),
+    ('𣰚', "cuì"),
+    ('𣰛', "yè"),
+    ('𣰜', "bìng"),
+    ('𣰞', "jié"),
+    ('𣰠', "qú"),
+    ('𣰡', "qú"),
+    ('𣰥', "méng"),
+    ('𣰦', "rán"),
+    ('𣰨', "bīn"),
+    ('𣰩', "cháo"),
+    ('𣰬', "dú"),
+    ('𣰶', "ráng"),
+    ('𣰷', "xiān"),
+    ('𣰺', "táo"),
+    ('𣰻', "qú"),
+    ('𣰼', "niè"),
+    ('𣰿', "shū"),
+    ('𣱀', "lǔ"),
+    ('𣱂', "kùn"),
+    ('𣱈', "mín"),
+    ('𣱉', "mǐn"),
+    ('𣱍', "dàn"),
+    ('𣱐', "yìn"),
+    ('𣱓', "xiào"),
+    ('𣱗', "jì"),
+    ('𣱜', "yīn"),
+    ('𣱝', "dōng"),
+    ('𣱦', "fēn"),
+    ('𣱧', "zhòng"),
+    ('𣱫', "gǔ"),
+    ('𣱱', "chá"),
+    ('𣱳', "liú"),
+    ('𣱶', "bǔ"),
+    ('𣱺', "pā"),
+    ('𣱻', "sì"),
+    ('𣱼', "dāo"),
+    ('𣱽', "zhěn"),
+    ('𣲀', "shān"),
+    ('𣲂', "chuǎi"),
+    ('𣲄', "jiǔ"),
+    ('𣲊', "kè"),
+    ('𣲋', "chí"),
+    ('𣲑', "hù"),
+    ('𣲒', "lì"),
+    ('𣲓', "shā"),
+    ('𣲖', "pài"),
+    ('𣲗', "wéi"),
+    ('𣲘', "wǔ"),
+    ('𣲜', "yíng"),
+    ('𣲡', "shā"),
+    ('𣲢', "dī"),
+    ('𣲥', "dān"),
+    ('𣲱', "tū"),
+    ('𣲲', "hé"),
+    ('𣲳', "pǒ"),
+    ('𣲵', "zhǐ"),
+    ('𣲶', "niǔ"),
+    ('𣲷', "nì"),
+    ('𣲽', "rǒng"),
+    ('𣲾', "guài"),
+    ('𣳀', "zhí"),
+    ('𣳃', "jí"),
+    ('𣳆', "píng"),
+    ('𣳜', "fàn"),
+    ('𣳟', "jié"),
+    ('𣳠', "hǎi"),
+    ('𣳤', "zhàn"),
+    ('𣳦', "xì"),
+    ('𣳩', "zī"),
+    ('𣳬', "xí"),
+    ('𣳭', "piào"),
+    ('𣳰', "bēn"),
+    ('𣳲', "jiǎn"),
+    ('𣴓', "jiàn"),
+    ('𣴖', "zá"),
+    ('𣴞', "bèn"),
+    ('𣴟', "mào"),
+    ('𣴢', "zào"),
+    ('𣴣', "zhuàng"),
+    ('𣴥', "kuáng"),
+    ('𣴨', "bí"),
+    ('𣴪', "pài"),
+    ('𣴼', "mào"),
+    ('𣴽', "tàn"),
+    ('𣵞', "tǔn"),
+    ('𣵟', "luǒ"),
+    ('𣵢', "tān"),
+    ('𣵱', "án"),
+    ('𣵷', "hán"),
+    ('𣵸', "zhú"),
+    ('𣵺', "duò"),
+    ('𣵻', "duò"),
+    ('𣵼', "gàn"),
+    ('𣶆', "qiòng"),
+    ('𣶈', "wǎng"),
+    ('𣶊', "mò"),
+    ('𣶋', "zhè"),
+    ('𣶌', "wěn"),
+    ('𣶍', "zhuàng"),
+    ('𣶏', "jiē"),
+    ('𣶐', "pào"),
+    ('𣶘', "sù"),
+    ('𣶝', "jù"),
+    ('𣶠', "qī"),
+    ('𣶡', "càn"),
+    ('𣶣', "tuán"),
+    ('𣶤', "shā"),
+    ('𣶦', "tuó"),
+    ('𣶩', "huà"),
+    ('𣶫', "yì"),
+    ('𣷠', "mín"),
+    ('𣷡', "zhōng"),
+    ('𣷥', "shuò"),
+    ('𣷩', "yì"),
+    ('𣷪', "wǎng"),
+    ('𣷫', "áo"),
+    ('𣷶', "sǔ"),
+    ('𣷾', "guǐ"),
+    ('𣷿', "tuǒ"),
+    ('𣸀', "huǐ"),
+    ('𣸃', "xù"),
+    ('𣸄', "zǎn"),
+    ('𣸆', "zǐ"),
+    ('𣸇', "biàn"),
+    ('𣸉', "dá"),
+    ('𣸊', "yīn"),
+    ('𣸋', "quǎn"),
+    ('𣸎', "huài"),
+    ('𣸏', "ná"),
+    ('𣸐', "zá"),
+    ('𣸒', "tí"),
+    ('𣸘', "yí"),
+    ('𣸙', "tān"),
+    ('𣸚', "shé"),
+    ('𣸛', "shuò"),
+    ('𣸝', "xíng"),
+    ('𣸠', "yǒu"),
+    ('𣸣', "fén"),
+    ('𣹇', "kè"),
+    ('𣹋', "fú"),
+    ('𣹒', "mǐn"),
+    ('𣹚', "pì"),
+    ('𣹜', "jí"),
+    ('𣹝', "qiào"),
+    ('𣹞', "zhǒng"),
+    ('𣹟', "gàn"),
+    ('𣹠', "yuān"),
+    ('𣹡', "chí"),
+    ('𣹥', "qiàn"),
+    ('𣹧', "zuó"),
+    ('𣹩', "xié"),
+    ('𣹪', "máo"),
+    ('𣹬', "hú"),
+    ('𣹮', "pì"),
+    ('𣹯', "xùn"),
+    ('𣹱', "xiá"),
+    ('𣹲', "tí"),
+    ('𣹵', "nà"),
+    ('𣹶', "chuǎ"),
+    ('𣺀', "wǔ"),
+    ('𣺬', "huāng"),
+    ('𣺭', "xuè"),
+    ('𣺮', "tào"),
+    ('𣺰', "qiào"),
+    ('𣺳', "jiāo"),
+    ('𣺼', "dǎng"),
+    ('𣺽', "bài"),
+    ('𣻍', "dàng"),
+    ('𣻎', "kòu"),
+    ('𣻐', "jū"),
+    ('𣻑', "shā"),
+    ('𣻒', "jīng"),
+    ('𣻕', "mó"),
+    ('𣻖', "nóu"),
+    ('𣻘', "shuò"),
+    ('𣻚', "shù"),
+    ('𣻛', "zhuāng"),
+    ('𣻜', "fú"),
+    ('𣻟', "zāng"),
+    ('𣻠', "xié"),
+    ('𣻡', "làng"),
+    ('𣻢', "tōng"),
+    ('𣻩', "zhé"),
+    ('𣻬', "càn"),
+    ('𣻮', "yuè"),
+    ('𣻱', "zhòu"),
+    ('𣼚', "tān"),
+    ('𣼞', "yán"),
+    ('𣼟', "lù"),
+    ('𣼠', "yǎn"),
+    ('𣼦', "zé"),
+    ('𣼧', "shuài"),
+    ('𣽅', "guō"),
+    ('𣽆', "zhú"),
+    ('𣽈', "rú"),
+    ('𣽉', "rú"),
+    ('𣽌', "kǎn"),
+    ('𣽍', "jì"),
+    ('𣽎', "gāo"),
+    ('𣽒', "xiè"),
+    ('𣽕', "òu"),
+    ('𣽖', "jiān"),
+    ('𣽚', "zhí"),
+    ('𣽛', "zhá"),
+    ('𣽝', "hǒng"),
+    ('𣽟', "kuǎn"),
+    ('𣽡', "bó"),
+    ('𣽤', "sè"),
+    ('𣽥', "àn"),
+    ('𣽦', "jiàn"),
+    ('𣽨', "téng"),
+    ('𣽫', "sōng"),
+    ('𣽭', "mèng"),
+    ('𣽮', "yín"),
+    ('𣽯', "tān"),
+    ('𣽰', "guō"),
+    ('𣽳', "ruán"),
+    ('𣽴', "wèi"),
+    ('𣽷', "sì"),
+    ('𣾍', "lián"),
+    ('𣾤', "qì"),
+    ('𣾦', "zhǎng"),
+    ('𣿅', "dǒng"),
+    ('𣿆', "fú"),
+    ('𣿇', "shěn"),
+    ('𣿈', "sù"),
+    ('𣿉', "yì"),
+    ('𣿊', "liàn"),
+    ('𣿌', "hé"),
+    ('𣿎', "zhēn"),
+    ('𣿐', "zé"),
+    ('𣿒', "cuǐ"),
+    ('𣿓', "cuǐ"),
+    ('𣿝', "fèng"),
+    ('𣿞', "lǐ"),
+    ('𣿟', "kòu"),
+    ('𣿣', "xiào"),
+    ('𣿤', "yǒu"),
+    ('𤀃', "háo"),
+    ('𤀉', "hàn"),
+    ('𤀊', "kěn"),
+    ('𤀝', "yù"),
+    ('𤀣', "huǎn"),
+    ('𤀤', "suō"),
+    ('𤀦', "là"),
+    ('𤀨', "dòu"),
+    ('𤀩', "jiàn"),
+    ('𤀪', "pō"),
+    ('𤀫', "biǎn"),
+    ('𤀰', "xuè"),
+    ('𤀲', "biàn"),
+    ('𤀷', "wèi"),
+    ('𤁡', "dàn"),
+    ('𤁢', "jié"),
+    ('𤁣', "bài"),
+    ('𤁥', "niǎn"),
+    ('𤁦', "xiàn"),
+    ('𤁧', "sè"),
+    ('𤁪', "huá"),
+    ('𤁫', "chuā"),
+    ('𤁮', "òu"),
+    ('𤁯', "liè"),
+    ('𤁰', "dí"),
+    ('𤁱', "cài"),
+    ('𤁳', "zhá"),
+    ('𤁵', "lǘ"),
+    ('𤁹', "huò"),
+    ('𤁼', "lì"),
+    ('𤁽', "yǐng"),
+    ('𤁿', "wěi"),
+    ('𤂀', "bì"),
+    ('𤂁', "guó"),
+    ('𤂃', "pì"),
+    ('𤂆', "biāo"),
+    ('𤂠', "yǎn"),
+    ('𤂤', "zhuàn"),
+    ('𤂲', "hóng"),
+    ('𤂶', "lìn"),
+    ('𤂷', "è"),
+    ('𤂹', "yǐn"),
+    ('𤂺', "làn"),
+    ('𤂼', "yào"),
+    ('𤂿', "xuàn"),
+    ('𤃀', "lì"),
+    ('𤃨', "làn"),
+    ('𤃩', "líng"),
+    ('𤃪', "xī"),
+    ('𤃫', "hōng"),
+    ('𤃭', "jiǎo"),
+    ('𤃮', "zhuó"),
+    ('𤃲', "zhí"),
+    ('𤃵', "bó"),
+    ('𤃶', "tēng"),
+    ('𤃷', "ǎn"),
+    ('𤃺', "xún"),
+    ('𤃻', "lěi"),
+    ('𤃼', "zāng"),
+    ('𤃽', "huǐ"),
+    ('𤄎', "xì"),
+    ('𤄏', "hóng"),
+    ('𤄑', "fàn"),
+    ('𤄒', "jiǎn"),
+    ('𤄓', "cóng"),
+    ('𤄔', "zá"),
+    ('𤄖', "cā"),
+    ('𤄘', "yōu"),
+    ('𤄛', "duì"),
+    ('𤄜', "pān"),
+    ('𤄥', "tà"),
+    ('𤄧', "pàn"),
+    ('𤄫', "fān"),
+    ('𤄬', "xī"),
+    ('𤄶', "yào"),
+    ('𤄷', "luó"),
+    ('𤄺', "biān"),
+    ('𤄼', "jìn"),
+    ('𤄽', "lì"),
+    ('𤅊', "yàn"),
+    ('𤅋', "dòu"),
+    ('𤅎', "màn"),
+    ('𤅐', "gōng"),
+    ('𤅑', "rǎng"),
+    ('𤅒', "càn"),
+    ('𤅣', "mén"),
+    ('𤅱', "gǔ"),
+    ('𤅲', "shuàn"),
+    ('𤅸', "yán"),
+    ('𤅹', "bì"),
+    ('𤆀', "biāo"),
+    ('𤆁', "chéng"),
+    ('𤆂', "kuì"),
+    ('𤆄', "huǒ"),
+    ('𤆍', "chì"),
+    ('𤆏', "wò"),
+    ('𤆑', "còu"),
+    ('𤆒', "zhì"),
+    ('𤆙', "shuǐ"),
+    ('𤆜', "guà"),
+    ('𤆝', "pū"),
+    ('𤆞', "xù"),
+    ('𤆟', "sī"),
+    ('𤆡', "wǔ"),
+    ('𤆢', "lún"),
+    ('𤆮', "fū"),
+    ('𤆰', "shì"),
+    ('𤆳', "huì"),
+    ('𤆴', "huāng"),
+    ('𤆵', "pā"),
+    ('𤆼', "zhǔ"),
+    ('𤆾', "yí"),
+    ('𤇃', "lì"),
+    ('𤇄', "shǎn"),
+    ('𤇜', "mín"),
+    ('𤇞', "gē"),
+    ('𤇠', "hū"),
+    ('𤇭', "lóng"),
+    ('𤇯', "ēn"),
+    ('𤇰', "fá"),
+    ('𤇳', "xù"),
+    ('𤇴', "yí"),
+    ('𤇾', "yìng"),
+    ('𤈔', "chí"),
+    ('𤈙', "yí"),
+    ('𤈥', "dí"),
+    ('𤈦', "huǐ"),
+    ('𤈧', "hé"),
+    ('𤈩', "zhǎ"),
+    ('𤈶', "yún"),
+    ('𤈷', "xiān"),
+    ('𤉌', "xián"),
+    ('𤉍', "lào"),
+    ('𤉎', "shào"),
+    ('𤉏', "shì"),
+    ('𤉐', "zhuó"),
+    ('𤉤', "biē"),
+    ('𤉥', "jiǔ"),
+    ('𤉦', "wō"),
+    ('𤉧', "jiǎo"),
+    ('𤉨', "fú"),
+    ('𤉪', "xiāng"),
+    ('𤉫', "kài"),
+    ('𤊲', "nǎo"),
+    ('𤊴', "huò"),
+    ('𤊵', "jí"),
+    ('𤊶', "là"),
+    ('𤊻', "fōu"),
+    ('𤊼', "shǎn"),
+    ('𤊽', "liào"),
+    ('𤊾', "miè"),
+    ('𤊿', "chè"),
+    ('𤋂', "mó"),
+    ('𤋏', "lóu"),
+    ('𤋨', "duò"),
+    ('𤋫', "nǎo"),
+    ('𤋭', "jī"),
+    ('𤋰', "zhù"),
+    ('𤌂', "sù"),
+    ('𤌃', "duò"),
+    ('𤌇', "jiǒng"),
+    ('𤌊', "zǎi"),
+    ('𤌋', "huǐ"),
+    ('𤌌', "yǐng"),
+    ('𤌍', "hú"),
+    ('𤌎', "lìn"),
+    ('𤌏', "wěng"),
+    ('𤌐', "hàn"),
+    ('𤌔', "nán"),
+    ('𤌷', "xì"),
+    ('𤌹', "gàn"),
+    ('𤌾', "hè"),
+    ('𤌿', "jī"),
+    ('𤍀', "xiǎng"),
+    ('𤍁', "shā"),
+    ('𤍐', "tuì"),
+    ('𤍒', "zhāo"),
+    ('𤍓', "shù"),
+    ('𤍕', "yǒu"),
+    ('𤍖', "jiān"),
+    ('𤍜', "zào"),
+    ('𤍠', "rè"),
+    ('𤍤', "zhāng"),
+    ('𤍽', "ruò"),
+    ('𤎄', "yān"),
+    ('𤎋', "cuì"),
+    ('𤎗', "jí"),
+    ('𤎘', "shāng"),
+    ('𤎣', "è"),
+    ('𤎤', "láo"),
+    ('𤎥', "tǎn"),
+    ('𤎧', "zhù"),
+    ('𤎭', "lǐn"),
+    ('𤎯', "zēng"),
+    ('𤎱', "juǎn"),
+    ('𤎲', "hū"),
+    ('𤎻', "xiāo"),
+    ('𤏗', "shěn"),
+    ('𤏘', "huò"),
+    ('𤏜', "kuì"),
+    ('𤏱', "chù"),
+    ('𤏲', "zhòu"),
+    ('𤏶', "āo"),
+    ('𤏸', "zhuó"),
+    ('𤏽', "xīng"),
+    ('𤏿', "miè"),
+    ('𤐀', "hū"),
+    ('𤐔', "tán"),
+    ('𤐙', "bì"),
+    ('𤐣', "dǐng"),
+    ('𤐩', "kài"),
+    ('𤐫', "biāo"),
+    ('𤐰', "huò"),
+    ('𤐱', "liè"),
+    ('𤐲', "cuàn"),
+    ('𤑃', "xiàn"),
+    ('𤑄', "rè"),
+    ('𤑓', "yuè"),
+    ('𤑕', "xūn"),
+    ('𤑗', "liǎo"),
+    ('𤑣', "shā"),
+    ('𤑦', "shì"),
+    ('𤑪', "xiè"),
+    ('𤑳', "xiāo"),
+    ('𤑷', "yé"),
+    ('𤑸', "lǎn"),
+    ('𤑹', "yì"),
+    ('𤑿', "liǎn"),
+    ('𤒔', "bó"),
+    ('𤒕', "cāo"),
+    ('𤒝', "yào"),
+    ('𤒦', "liàn"),
+    ('𤒻', "tà"),
+    ('𤓑', "jì"),
+    ('𤓔', "xī"),
+    ('𤓕', "zhì"),
+    ('𤓚', "xī"),
+    ('𤓝', "yuè"),
+    ('𤓤', "xiǎn"),
+    ('𤓦', "zhuò"),
+    ('𤓯', "zhǎng"),
+    ('𤓵', "zǔ"),
+    ('𤓷', "ná"),
+    ('𤓾', "dào"),
+    ('𤓿', "liè"),
+    ('𤔀', "ná"),
+    ('𤔉', "páo"),
+    ('𤔋', "jù"),
+    ('𤔔', "luàn"),
+    ('𤔖', "luǒ"),
+    ('𤔙', "shuǎ"),
+    ('𤔚', "shàng"),
+    ('𤔝', "luǒ"),
+    ('𤔟', "fēn"),
+    ('𤔣', "bào"),
+    ('𤔨', "lì"),
+    ('𤔫', "xiòng"),
+    ('𤔶', "dāng"),
+    ('𤕀', "chèng"),
+    ('𤕄', "zhǎng"),
+    ('𤕇', "sǒu"),
+    ('𤕊', "shén"),
+    ('𤕒', "gě"),
+    ('𤕘', "yū"),
+    ('𤕚', "huī"),
+    ('𤕛', "chè"),
+    ('𤕝', "jiào"),
+    ('𤕞', "zhù"),
+    ('𤕟', "shū"),
+    ('𤕢', "xiáo"),
+    ('𤕦', "níng"),
+    ('𤕭', "jiāng"),
+    ('𤕯', "jiāng"),
+    ('𤕷', "diào"),
+    ('𤕽', "qiáng"),
+    ('𤕾', "qiú"),
+    ('𤖀', "fēng"),
+    ('𤖆', "zhàn"),
+    ('𤖇', "kē"),
+    ('𤖒', "dié"),
+    ('𤖓', "zé"),
+    ('𤖖', "guāng"),
+    ('𤖗', "sè"),
+    ('𤖘', "fèn"),
+    ('𤖛', "jiǎng"),
+    ('𤖝', "yán"),
+    ('𤖞', "zhì"),
+    ('𤖢', "lì"),
+    ('𤖦', "líng"),
+    ('𤖪', "yí"),
+    ('𤖬', "qǔ"),
+    ('𤖭', "pán"),
+    ('𤖮', "gōu"),
+    ('𤖰', "jiǎ"),
+    ('𤖱', "hé"),
+    ('𤖳', "pèng"),
+    ('𤖵', "jù"),
+    ('𤖷', "chè"),
+    ('𤖺', "liè"),
+    ('𤖻', "shì"),
+    ('𤖼', "pò"),
+    ('𤖽', "xiàng"),
+    ('𤖿', "pì"),
+    ('𤗀', "luǒ"),
+    ('𤗁', "cù"),
+    ('𤗃', "yǔ"),
+    ('𤗇', "kòng"),
+    ('𤗈', "xiè"),
+    ('𤗍', "wǎn"),
+    ('𤗎', "yǎn"),
+    ('𤗏', "péi"),
+    ('𤗓', "chéng"),
+    ('𤗘', "tí"),
+    ('𤗙', "chè"),
+    ('𤗚', "bì"),
+    ('𤗛', "liàn"),
+    ('𤗜', "jiǎ"),
+    ('𤗞', "tíng"),
+    ('𤗢', "tī"),
+    ('𤗨', "dié"),
+    ('𤗪', "shù"),
+    ('𤗫', "lí"),
+    ('𤗬', "lǘ"),
+    ('𤗭', "xiā"),
+    ('𤗯', "cuī"),
+    ('𤗳', "bō"),
+    ('𤗴', "tuí"),
+    ('𤗵', "pú"),
+    ('𤗷', "lìn"),
+    ('𤗸', "fèn"),
+    ('𤗺', "bó"),
+    ('𤗻', "chàn"),
+    ('𤗾', "dāng"),
+    ('𤗿', "tǎi"),
+    ('𤘀', "dào"),
+    ('𤘃', "lì"),
+    ('𤘅', "yá"),
+    ('𤘆', "yá"),
+    ('𤘇', "zhān"),
+    ('𤘊', "yí"),
+    ('𤘌', "qī"),
+    ('𤘔', "hù"),
+    ('𤘖', "tīng"),
+    ('𤘘', "kǒu"),
+    ('𤘛', "chún"),
+    ('𤘜', "yóu"),
+    ('𤘝', "fèn"),
+    ('𤘟', "nuó"),
+    ('𤘠', "tiàn"),
+    ('𤘡', "jìn"),
+    ('𤘢', "pí"),
+    ('𤘣', "chén"),
+    ('𤘤', "pì"),
+    ('𤘦', "jiè"),
+    ('𤘧', "guǐ"),
+    ('𤘲', "zhuàng"),
+    ('𤘵', "hú"),
+    ('𤘶', "chǒu"),
+    ('𤘷', "shù"),
+    ('𤘸', "tāo"),
+    ('𤘹', "pí"),
+    ('𤘺', "rǒng"),
+    ('𤘻', "rǒng"),
+    ('𤘽', "hǒu"),
+    ('𤘾', "pēng"),
+    ('𤙅', "bài"),
+    ('𤙇', "xiá"),
+    ('𤙋', "qǐn"),
+    ('𤙌', "nǐ"),
+    ('𤙎', "tāo"),
+    ('𤙏', "qù"),
+    ('𤙒', "xié"),
+    ('𤙔', "zhào"),
+    ('𤙕', "huā"),
+    ('𤙖', "xīn"),
+    ('𤙘', "shōu"),
+    ('𤙛', "tú"),
+    ('𤙝', "liáng"),
+    ('𤙞', "bì"),
+    ('𤙟', "chū"),
+    ('𤙡', "xīng"),
+    ('𤙣', "xīn"),
+    ('𤙤', "fū"),
+    ('𤙩', "jiè"),
+    ('𤙭', "fǔ"),
+    ('𤙯', "láo"),
+    ('𤙰', "tè"),
+    ('𤙱', "shè"),
+    ('𤙴', "chāo"),
+    ('𤙵', "chuī"),
+    ('𤙼', "rán"),
+    ('𤙽', "hǒu"),
+    ('𤙾', "bēng"),
+    ('𤚀', "cǎi"),
+    ('𤚅', "mú"),
+    ('𤚉', "xū"),
+    ('𤚊', "dié"),
+    ('𤚍', "chǎn"),
+    ('𤚎', "yú"),
+    ('𤚏', "zhòng"),
+    ('𤚓', "lí"),
+    ('𤚔', "shōu"),
+    ('𤚚', "dú"),
+    ('𤚜', "māo"),
+    ('𤚝', "huáng"),
+    ('𤚟', "táo"),
+    ('𤚡', "dù"),
+    ('𤚢', "tí"),
+    ('𤚣', "shēng"),
+    ('𤚤', "méi"),
+    ('𤚨', "zhēn"),
+    ('𤚩', "qín"),
+    ('𤚪', "pì"),
+    ('𤚫', "táng"),
+    ('𤚬', "cāng"),
+    ('𤚭', "yáo"),
+    ('𤚯', "xiù"),
+    ('𤚰', "bāng"),
+    ('𤚱', "gǔ"),
+    ('𤚵', "bù"),
+    ('𤚼', "gòu"),
+    ('𤚽', "bó"),
+    ('𤛁', "wèn"),
+    ('𤛄', "jì"),
+    ('𤛊', "lā"),
+    ('𤛍', "cuī"),
+    ('𤛎', "mǐn"),
+    ('𤛏', "cǔ"),
+    ('𤛐', "ōu"),
+    ('𤛑', "yōng"),
+    ('𤛖', "máo"),
+    ('𤛗', "kè"),
+    ('𤛘', "māng"),
+    ('𤛙', "dǐng"),
+    ('𤛚', "huān"),
+    ('𤛛', "duǒ"),
+    ('𤛜', "jiāng"),
+    ('𤛝', "sù"),
+    ('𤛢', "céng"),
+    ('𤛣', "tà"),
+    ('𤛥', "huáng"),
+    ('𤛦', "jué"),
+    ('𤛧', "xún"),
+    ('𤛪', "xiòng"),
+    ('𤛬', "mì"),
+    ('𤛭', "qún"),
+    ('𤛮', "láo"),
+    ('𤛱', "zhì"),
+    ('𤛲', "wěi"),
+    ('𤛷', "sè"),
+    ('𤛻', "zāng"),
+    ('𤜁', "ǎn"),
+    ('𤜂', "wèi"),
+    ('𤜄', "huài"),
+    ('𤜇', "zhàn"),
+    ('𤜉', "yīng"),
+    ('𤜊', "gē"),
+    ('𤜋', "huì"),
+    ('𤜍', "quán"),
+    ('𤜓', "liè"),
+    ('𤜔', "jú"),
+    ('𤜕', "bà"),
+    ('𤜖', "léi"),
+    ('𤜘', "mán"),
+    ('𤜙', "líng"),
+    ('𤜜', "lì"),
+    ('𤜝', "jǐ"),
+    ('𤜡', "huí"),
+    ('𤜢', "xìn"),
+    ('𤜣', "shì"),
+    ('𤜤', "zhé"),
+    ('𤜧', "bō"),
+    ('𤜫', "chā"),
+    ('𤜯', "chā"),
+    ('𤜰', "jīng"),
+    ('𤜱', "bā"),
+    ('𤜲', "bèi"),
+    ('𤜵', "yàn"),
+    ('𤜷', "hù"),
+    ('𤜹', "yú"),
+    ('𤜻', "bì"),
+    ('𤜼', "chuán"),
+    ('𤜾', "jǐ"),
+    ('𤝂', "mù"),
+    ('𤝄', "máo"),
+    ('𤝅', "zhōng"),
+    ('𤝇', "yè"),
+    ('𤝈', "dōu"),
+    ('𤝉', "yě"),
+    ('𤝍', "rì"),
+    ('𤝎', "yīn"),
+    ('𤝐', "hào"),
+    ('𤝒', "nà"),
+    ('𤝓', "tiè"),
+    ('𤝔', "fù"),
+    ('𤝕', "mǔ"),
+    ('𤝖', "zǎi"),
+    ('𤝘', "hú"),
+    ('𤝚', "chēn"),
+    ('𤝛', "tuó"),
+    ('𤝞', "chù"),
+    ('𤝟', "fú"),
+    ('𤝢', "zé"),
+    ('𤝧', "bào"),
+    ('𤝬', "dǐ"),
+    ('𤝭', "cǎi"),
+    ('𤝮', "lù"),
+    ('𤝯', "pǒ"),
+    ('𤝰', "dá"),
+    ('𤝱', "yè"),
+    ('𤝳', "yǐ"),
+    ('𤝷', "xiáng"),
+    ('𤝸', "bī"),
+    ('𤝹', "zhū"),
+    ('𤝻', "yí"),
+    ('𤝽', "lǜ"),
+    ('𤝿', "kuāng"),
+    ('𤞂', "zhì"),
+    ('𤞃', "huì"),
+    ('𤞇', "wá"),
+    ('𤞈', "dī"),
+    ('𤞉', "shù"),
+    ('𤞊', "liè"),
+    ('𤞋', "zǎo"),
+    ('𤞌', "zhì"),
+    ('𤞍', "náo"),
+    ('𤞗', "chái"),
+    ('𤞚', "xiāo"),
+    ('𤞛', "zàng"),
+    ('𤞞', "yù"),
+    ('𤞟', "dòu"),
+    ('𤞠', "chà"),
+    ('𤞡', "xié"),
+    ('𤞢', "yáng"),
+    ('𤞤', "xiǎn"),
+    ('𤞥', "bǎo"),
+    ('𤞮', "zhāi"),
+    ('𤞰', "qiú"),
+    ('𤞲', "hú"),
+    ('𤞳', "zài"),
+    ('𤞴', "jué"),
+    ('𤞶', "hān"),
+    ('𤞿', "àn"),
+    ('𤟀', "zào"),
+    ('𤟃', "shà"),
+    ('𤟅', "xiàn"),
+    ('𤟆', "chǐ"),
+    ('𤟇', "yǎn"),
+    ('𤟉', "àn"),
+    ('𤟍', "zhé"),
+    ('𤟎', "jué"),
+    ('𤟑', "lì"),
+    ('𤟓', "lè"),
+    ('𤟖', "cǎi"),
+    ('𤟘', "lù"),
+    ('𤟚', "jiā"),
+    ('𤟝', "xià"),
+    ('𤟞', "xiào"),
+    ('𤟟', "yān"),
+    ('𤟠', "xū"),
+    ('𤟢', "dùn"),
+    ('𤟣', "yíng"),
+    ('𤟤', "huī"),
+    ('𤟥', "tí"),
+    ('𤟦', "nóu"),
+    ('𤟧', "xǐ"),
+    ('𤟪', "tú"),
+    ('𤟷', "wāi"),
+    ('𤟸', "chēn"),
+    ('𤟼', "hōng"),
+    ('𤟾', "tí"),
+    ('𤟿', "xuān"),
+    ('𤠀', "zá"),
+    ('𤠇', "gé"),
+    ('𤠋', "lóu"),
+    ('𤠌', "chái"),
+    ('𤠍', "pán"),
+    ('𤠎', "jí"),
+    ('𤠐', "tà"),
+    ('𤠓', "xī"),
+    ('𤠖', "xiāo"),
+    ('𤠘', "sāo"),
+    ('𤠙', "jiā"),
+    ('𤠚', "sù"),
+    ('𤠛', "huāng"),
+    ('𤠝', "cuō"),
+    ('𤠟', "tà"),
+    ('𤠠', "shuāi"),
+    ('𤠪', "fú"),
+    ('𤠫', "lì"),
+    ('𤠭', "shè"),
+    ('𤠯', "táng"),
+    ('𤠶', "diān"),
+    ('𤠺', "bì"),
+    ('𤠼', "gòu"),
+    ('𤠽', "cù"),
+    ('𤠿', "qiān"),
+    ('𤡂', "léi"),
+    ('𤡃', "sù"),
+    ('𤡆', "zòng"),
+    ('𤡇', "hāo"),
+    ('𤡏', "chì"),
+    ('𤡐', "cáo"),
+    ('𤡓', "wò"),
+    ('𤡔', "xiāo"),
+    ('𤡕', "liè"),
+    ('𤡖', "yān"),
+    ('𤡝', "bì"),
+    ('𤡟', "huàn"),
+    ('𤡡', "xī"),
+    ('𤡢', "chī"),
+    ('𤡣', "xū"),
+    ('𤡤', "náo"),
+    ('𤡥', "yán"),
+    ('𤡧', "xiè"),
+    ('𤡨', "zhá"),
+    ('𤡪', "suì"),
+    ('𤡬', "xì"),
+    ('𤡭', "bēng"),
+    ('𤡮', "rán"),
+    ('𤡯', "shuò"),
+    ('𤡰', "bān"),
+    ('𤡱', "guì"),
+    ('𤡲', "kāi"),
+    ('𤡳', "chēn"),
+    ('𤡶', "xù"),
+    ('𤡾', "è"),
+    ('𤡿', "lì"),
+    ('𤢀', "xī"),
+    ('𤢁', "huàn"),
+    ('𤢂', "sù"),
+    ('𤢄', "chǎng"),
+    ('𤢊', "lù"),
+    ('𤢋', "yán"),
+    ('𤢎', "dāng"),
+    ('𤢏', "dǎn"),
+    ('𤢐', "yāng"),
+    ('𤢒', "zhǎi"),
+    ('𤢓', "jù"),
+    ('𤢕', "duó"),
+    ('𤢖', "sāo"),
+    ('𤢗', "lái"),
+    ('𤢘', "sù"),
+    ('𤢟', "zé"),
+    ('𤢣', "bì"),
+    ('𤢦', "yìn"),
+    ('𤢨', "hāo"),
+    ('𤢪', "liè"),
+    ('𤢭', "háo"),
+    ('𤢮', "yáng"),
+    ('𤢴', "shuò"),
+    ('𤢵', "ài"),
+    ('𤢶', "qióng"),
+    ('𤢹', "lěi"),
+    ('𤢺', "xié"),
+    ('𤢼', "shì"),
+    ('𤣃', "lǔ"),
+    ('𤣅', "què"),
+    ('𤣆', "lián"),
+    ('𤣌', "xiào"),
+    ('𤣎', "yīng"),
+    ('𤣑', "xié"),
+    ('𤣘', "líng"),
+    ('𤣙', "yōu"),
+    ('𤣞', "dǎng"),
+    ('𤣟', "lǎn"),
+    ('𤣠', "xiāo"),
+    ('𤣨', "yì"),
+    ('𤣬', "wū"),
+    ('𤣮', "yì"),
+    ('𤣯', "tuō"),
+    ('𤣰', "bǔ"),
+    ('𤣲', "xìn"),
+    ('𤣵', "sī"),
+    ('𤣶', "jīn"),
+    ('𤣸', "bā"),
+    ('𤣹', "fǎ"),
+    ('𤣻', "mò"),
+    ('𤣼', "ruò"),
+    ('𤤊', "dà"),
+    ('𤤋', "jì"),
+    ('𤤐', "sù"),
+    ('𤤑', "qióng"),
+    ('𤤒', "bā"),
+    ('𤤦', "tián"),
+    ('𤤧', "yóu"),
+    ('𤤩', "tuó"),
+    ('𤤫', "wài"),
+    ('𤤬', "yòu"),
+    ('𤤮', "dōng"),
+    ('𤤱', "xǐ"),
+    ('𤤲', "kǒng"),
+    ('𤤶', "qióng"),
+    ('𤤷', "duī"),
+    ('𤤸', "duò"),
+    ('𤤺', "yì"),
+    ('𤥒', "xī"),
+    ('𤥓', "qīn"),
+    ('𤥔', "sù"),
+    ('𤥗', "liú"),
+    ('𤥙', "wán"),
+    ('𤥭', "chē"),
+    ('𤥮', "zhū"),
+    ('𤥰', "mào"),
+    ('𤥷', "quán"),
+    ('𤥽', "yū"),
+    ('𤥿', "yì"),
+    ('𤦀', "mí"),
+    ('𤦃', "lái"),
+    ('𤦄', "zhì"),
+    ('𤦤', "ní"),
+    ('𤦦', "bān"),
+    ('𤦪', "dōng"),
+    ('𤦮', "zhì"),
+    ('𤧕', "yì"),
+    ('𤧘', "líng"),
+    ('𤧙', "yú"),
+    ('𤧚', "cōng"),
+    ('𤧛', "dì"),
+    ('𤧜', "zhì"),
+    ('𤧠', "ruǎn"),
+    ('𤧣', "jiàn"),
+    ('𤧩', "wàn"),
+    ('𤧫', "jìn"),
+    ('𤧭', "páng"),
+    ('𤨍', "lù"),
+    ('𤨎', "qú"),
+    ('𤨐', "xǐ"),
+    ('𤨑', "dá"),
+    ('𤨖', "hù"),
+    ('𤨗', "luǒ"),
+    ('𤨙', "lè"),
+    ('𤨶', "gǒng"),
+    ('𤨻', "lìng"),
+    ('𤩂', "láo"),
+    ('𤩄', "zhuàn"),
+    ('𤩨', "zǎo"),
+    ('𤩩', "hào"),
+    ('𤩪', "xiàng"),
+    ('𤩭', "hào"),
+    ('𤩮', "lì"),
+    ('𤩱', "diàn"),
+    ('𤩲', "gé"),
+    ('𤩽', "huán"),
+    ('𤪄', "è"),
+    ('𤪆', "xiá"),
+    ('𤪋', "jiān"),
+    ('𤪌', "qí"),
+    ('𤪍', "xiá"),
+    ('𤪎', "yǒu"),
+    ('𤪡', "zhēng"),
+    ('𤪪', "zhuàn"),
+    ('𤪮', "chàn"),
+    ('𤫉', "xiè"),
+    ('𤫕', "náo"),
+    ('𤫝', "jì"),
+    ('𤫞', "tián"),
+    ('𤫣', "yǎn"),
+    ('𤫧', "hǎo"),
+    ('𤫨', "xín"),
+    ('𤫩', "líng"),
+    ('𤫫', "bān"),
+    ('𤫬', "běng"),
+    ('𤫱', "gōu"),
+    ('𤫲', "líng"),
+    ('𤫵', "kuò"),
+    ('𤫶', "qià"),
+    ('𤫷', "jiào"),
+    ('𤫹', "ēn"),
+    ('𤫺', "yáo"),
+    ('𤫻', "dū"),
+    ('𤬁', "huǒ"),
+    ('𤬂', "dǔ"),
+    ('𤬃', "pēi"),
+    ('𤬌', "yuán"),
+    ('𤬏', "lóu"),
+    ('𤬐', "xíng"),
+    ('𤬓', "lián"),
+    ('𤬔', "yáo"),
+    ('𤬕', "xī"),
+    ('𤬖', "yáo"),
+    ('𤬘', "xī"),
+    ('𤬛', "lú"),
+    ('𤬝', "yàn"),
+    ('𤬠', "quán"),
+    ('𤬥', "ráng"),
+    ('𤬦', "wà"),
+    ('𤬧', "zú"),
+    ('𤬨', "fàn"),
+    ('𤬩', "yì"),
+    ('𤬪', "dù"),
+    ('𤬫', "suì"),
+    ('𤬭', "pī"),
+    ('𤬯', "hán"),
+    ('𤬱', "xù"),
+    ('𤬳', "gǒng"),
+    ('𤬵', "dì"),
+    ('𤬷', "nà"),
+    ('𤬾', "duò"),
+    ('𤬿', "wā"),
+    ('𤭂', "niè"),
+    ('𤭈', "diào"),
+    ('𤭉', "huāng"),
+    ('𤭌', "tí"),
+    ('𤭍', "fàn"),
+    ('𤭑', "wú"),
+    ('𤭒', "áng"),
+    ('𤭔', "píng"),
+    ('𤭙', "hán"),
+    ('𤭛', "gāng"),
+    ('𤭜', "lí"),
+    ('𤭞', "dūn"),
+    ('𤭟', "fù"),
+    ('𤭠', "nà"),
+    ('𤭧', "jiē"),
+    ('𤭩', "qìng"),
+    ('𤭫', "yīng"),
+    ('𤭬', "xiáng"),
+    ('𤭱', "hú"),
+    ('𤭴', "sù"),
+    ('𤭻', "gē"),
+    ('𤭼', "è"),
+    ('𤭽', "xù"),
+    ('𤮆', "xī"),
+    ('𤮊', "kāng"),
+    ('𤮋', "guó"),
+    ('𤮌', "jiē"),
+    ('𤮍', "chuán"),
+    ('𤮎', "léi"),
+    ('𤮏', "héng"),
+    ('𤮐', "zūn"),
+    ('𤮕', "piè"),
+    ('𤮘', "dēng"),
+    ('𤮙', "xī"),
+    ('𤮚', "léi"),
+    ('𤮜', "shàn"),
+    ('𤮧', "lú"),
+    ('𤮩', "duì"),
+    ('𤮪', "jùn"),
+    ('𤮭', "chàn"),
+    ('𤮯', "xié"),
+    ('𤮰', "wā"),
+    ('𤮱', "zhé"),
+    ('𤮳', "zhuān"),
+    ('𤮷', "liù"),
+    ('𤮸', "léi"),
+    ('𤮼', "dài"),
+    ('𤮽', "gān"),
+    ('𤯄', "shì"),
+    ('𤯇', "yǎn"),
+    ('𤯌', "gān"),
+    ('𤯐', "yán"),
+    ('𤯖', "suī"),
+    ('𤯚', "zhōng"),
+    ('𤯜', "shì"),
+    ('𤯡', "shèng"),
+    ('𤯥', "chǎn"),
+    ('𤯷', "huáng"),
+    ('𤯸', "yìn"),
+    ('𤯻', "měng"),
+    ('𤰂', "ráng"),
+    ('𤰅', "xiáng"),
+    ('𤰈', "bèi"),
+    ('𤰌', "chuán"),
+    ('𤰑', "pú"),
+    ('𤰙', "kē"),
+    ('𤰚', "lā"),
+    ('𤰝', "quǎn"),
+    ('𤰟', "hàng"),
+    ('𤰠', "chì"),
+    ('𤰡', "máng"),
+    ('𤰦', "zhà"),
+    ('𤰪', "fèn"),
+    ('𤰬', "chào"),
+    ('𤰳', "jǐng"),
+    ('𤱃', "liè"),
+    ('𤱅', "nà"),
+    ('𤱆', "nà"),
+    ('𤱇', "tóng"),
+    ('𤱋', "rán"),
+    ('𤱌', "zǔ"),
+    ('𤱍', "pī"),
+    ('𤱎', "yǒu"),
+    ('𤱐', "shū"),
+    ('𤱛', "liè"),
+    ('𤱜', "shōu"),
+    ('𤱝', "tuǎn"),
+    ('𤱟', "gǎo"),
+    ('𤱠', "sháo"),
+    ('𤱡', "tuó"),
+    ('𤱣', "nán"),
+    ('𤱧', "tuǒ"),
+    ('𤱨', "gōng"),
+    ('𤱩', "diào"),
+    ('𤱴', "měng"),
+    ('𤱵', "bāng"),
+    ('𤱷', "xié"),
+    ('𤱸', "sì"),
+    ('𤱹', "tǐng"),
+    ('𤱺', "guì"),
+    ('𤱽', "fú"),
+    ('𤱾', "guì"),
+    ('𤲉', "guì"),
+    ('𤲑', "zhǔ"),
+    ('𤲓', "lái"),
+    ('𤲕', "lǔn"),
+    ('𤲖', "tiǎn"),
+    ('𤲗', "rǎn"),
+    ('𤲚', "dōng"),
+    ('𤲨', "juàn"),
+    ('𤲩', "yán"),
+    ('𤲬', "ruán"),
+    ('𤲭', "dǎn"),
+    ('𤲰', "mào"),
+    ('𤲶', "luán"),
+    ('𤲸', "xù"),
+    ('𤲺', "xī"),
+    ('𤳂', "má"),
+    ('𤳃', "qī"),
+    ('𤳅', "chà"),
+    ('𤳈', "shāng"),
+    ('𤳉', "hàn"),
+    ('𤳊', "píng"),
+    ('𤳎', "jī"),
+    ('𤳓', "lì"),
+    ('𤳕', "yù"),
+    ('𤳖', "bān"),
+    ('𤳘', "tēng"),
+    ('𤳝', "chóu"),
+    ('𤳠', "chóu"),
+    ('𤳤', "qī"),
+    ('𤳥', "xī"),
+    ('𤳦', "bèi"),
+    ('𤳪', "yè"),
+    ('𤳭', "guǎng"),
+    ('𤳯', "zhù"),
+    ('𤳳', "léi"),
+    ('𤳴', "léi"),
+    ('𤳵', "chā"),
+    ('𤴀', "guǎng"),
+    ('𤴍', "dié"),
+    ('𤴓', "yǎ"),
+    ('𤴘', "niè"),
+    ('𤴙', "shū"),
+    ('𤴛', "zhì"),
+    ('𤴟', "zhì"),
+    ('𤴢', "zhì"),
+    ('𤴣', "pǐ"),
+    ('𤴥', "jiū"),
+    ('𤴦', "jiū"),
+    ('𤴧', "yì"),
+    ('𤴨', "yòu"),
+    ('𤴪', "jiū"),
+    ('𤴯', "huàn"),
+    ('𤴱', "dù"),
+    ('𤴻', "táo"),
+    ('𤴼', "qiè"),
+    ('𤴽', "qín"),
+    ('𤴾', "xìn"),
+    ('𤴿', "chān"),
+    ('𤵀', "jì"),
+    ('𤵂', "qìn"),
+    ('𤵊', "dù"),
+    ('𤵋', "zhī"),
+    ('𤵎', "ǒu"),
+    ('𤵐', "wù"),
+    ('𤵒', "wén"),
+    ('𤵘', "bì"),
+    ('𤵛', "bēi"),
+    ('𤵝', "mǔ"),
+    ('𤵞', "jìn"),
+    ('𤵟', "táo"),
+    ('𤵠', "liáo"),
+    ('𤵥', "cáo"),
+    ('𤵦', "zhá"),
+    ('𤵬', "chǐ"),
+    ('𤵭', "yā"),
+    ('𤵮', "kuí"),
+    ('𤵯', "yìn"),
+    ('𤵸', "lóng"),
+    ('𤵹', "qià"),
+    ('𤵻', "hāng"),
+    ('𤵼', "shàng"),
+    ('𤵽', "hài"),
+    ('𤵾', "chā"),
+    ('𤶀', "jiǎo"),
+    ('𤶁', "lǎo"),
+    ('𤶈', "xī"),
+    ('𤶊', "guì"),
+    ('𤶋', "bó"),
+    ('𤶓', "zhǐ"),
+    ('𤶕', "tùn"),
+    ('𤶖', "fú"),
+    ('𤶘', "hū"),
+    ('𤶚', "niè"),
+    ('𤶛', "yì"),
+    ('𤶜', "zhuàng"),
+    ('𤶠', "chá"),
+    ('𤶤', "suān"),
+    ('𤶧', "yùn"),
+    ('𤶮', "dù"),
+    ('𤶰', "xī"),
+    ('𤶱', "chuàn"),
+    ('𤶲', "xíng"),
+    ('𤶳', "jiǎo"),
+    ('𤶴', "shēn"),
+    ('𤷀', "wāng"),
+    ('𤷁', "bēi"),
+    ('𤷂', "féi"),
+    ('𤷃', "jiàn"),
+    ('𤷄', "quán"),
+    ('𤷅', "yì"),
+    ('𤷆', "dōng"),
+    ('𤷇', "xù"),
+    ('𤷈', "nà"),
+    ('𤷉', "jí"),
+    ('𤷌', "zhěn"),
+    ('𤷍', "qí"),
+    ('𤷎', "duī"),
+    ('𤷏', "yín"),
+    ('𤷑', "jiù"),
+    ('𤷒', "pí"),
+    ('𤷓', "xìn"),
+    ('𤷔', "lún"),
+    ('𤷕', "cǎi"),
+    ('𤷖', "lìng"),
+    ('𤷗', "biē"),
+    ('𤷘', "dào"),
+    ('𤷙', "dé"),
+    ('𤷟', "la"),
+    ('𤷡', "xī"),
+    ('𤷢', "jù"),
+    ('𤷤', "xiáo"),
+    ('𤷦', "jīng"),
+    ('𤷹', "wài"),
+    ('𤷻', "nǎo"),
+    ('𤷼', "xiāng"),
+    ('𤷽', "què"),
+    ('𤷾', "qiè"),
+    ('𤷿', "tū"),
+    ('𤸀', "xǔ"),
+    ('𤸁', "huì"),
+    ('𤸅', "mín"),
+    ('𤸆', "wěi"),
+    ('𤸈', "yóu"),
+    ('𤸉', "tuí"),
+    ('𤸊', "dài"),
+    ('𤸎', "kě"),
+    ('𤸏', "nà"),
+    ('𤸑', "fù"),
+    ('𤸒', "yù"),
+    ('𤸓', "zhǐ"),
+    ('𤸕', "hān"),
+    ('𤸖', "āi"),
+    ('𤸗', "fù"),
+    ('𤸡', "yāng"),
+    ('𤸤', "shí"),
+    ('𤸦', "chán"),
+    ('𤸪', "chì"),
+    ('𤸫', "yùn"),
+    ('𤸬', "shuāi"),
+    ('𤸮', "sù"),
+    ('𤸯', "sǎng"),
+    ('𤸱', "è"),
+    ('𤸲', "zhěng"),
+    ('𤸳', "ái"),
+    ('𤸴', "suǒ"),
+    ('𤸵', "bù"),
+    ('𤸷', "qún"),
+    ('𤸸', "yì"),
+    ('𤸹', "yǎn"),
+    ('𤸻', "nà"),
+    ('𤸼', "wǔ"),
+    ('𤹇', "lì"),
+    ('𤹈', "lì"),
+    ('𤹊', "xī"),
+    ('𤹋', "jué"),
+    ('𤹌', "shī"),
+    ('𤹎', "yǎ"),
+    ('𤹛', "chén"),
+    ('𤹜', "yíng"),
+    ('𤹝', "bì"),
+    ('𤹞', "chè"),
+    ('𤹡', "zhā"),
+    ('𤹢', "tuǒ"),
+    ('𤹣', "hù"),
+    ('𤹤', "téng"),
+    ('𤹥', "yìng"),
+    ('𤹦', "bǐ"),
+    ('𤹧', "níng"),
+    ('𤹨', "liàn"),
+    ('𤹩', "xìn"),
+    ('𤹪', "yǔ"),
+    ('𤹲', "bèi"),
+    ('𤹴', "mó"),
+    ('𤹵', "duī"),
+    ('𤹷', "dǎo"),
+    ('𤹸', "qí"),
+    ('𤹺', "kài"),
+    ('𤺀', "shuāi"),
+    ('𤺃', "xiāo"),
+    ('𤺄', "zhǒng"),
+    ('𤺅', "zhuì"),
+    ('𤺇', "biàn"),
+    ('𤺉', "wěi"),
+    ('𤺊', "xī"),
+    ('𤺌', "dēng"),
+    ('𤺎', "xiē"),
+    ('𤺏', "pān"),
+    ('𤺐', "niè"),
+    ('𤺓', "bié"),
+    ('𤺔', "shè"),
+    ('𤺕', "fèi"),
+    ('𤺖', "mǐn"),
+    ('𤺗', "qì"),
+    ('𤺪', "shàn"),
+    ('𤺫', "suǒ"),
+    ('𤺷', "jí"),
+    ('𤺺', "dǎn"),
+    ('𤺻', "juàn"),
+    ('𤺼', "lù"),
+    ('𤺾', "ào"),
+    ('𤻂', "yì"),
+    ('𤻃', "shǔ"),
+    ('𤻄', "suì"),
+    ('𤻅', "wèi"),
+    ('𤻆', "wán"),
+    ('𤻇', "chǔ"),
+    ('𤻊', "tuí"),
+    ('𤻌', "wò"),
+    ('𤻖', "bì"),
+    ('𤻘', "yǐn"),
+    ('𤻙', "huó"),
+    ('𤻜', "kài"),
+    ('𤻝', "níng"),
+    ('𤻢', "ài"),
+    ('𤻤', "lì"),
+    ('𤻦', "zhāi"),
+    ('𤻱', "lù"),
+    ('𤻶', "biàn"),
+    ('𤻷', "pán"),
+    ('𤻿', "guì"),
+    ('𤼀', "sū"),
+    ('𤼁', "méng"),
+    ('𤼂', "xiǎn"),
+    ('𤼃', "lòng"),
+    ('𤼅', "qì"),
+    ('𤼋', "chàn"),
+    ('𤼌', "yì"),
+    ('𤼍', "háng"),
+    ('𤼏', "liǎn"),
+    ('𤼐', "guàn"),
+    ('𤼒', "wěi"),
+    ('𤼗', "jué"),
+    ('𤼘', "léi"),
+    ('𤼙', "luán"),
+    ('𤼚', "lì"),
+    ('𤼜', "pí"),
+    ('𤼢', "huǎn"),
+    ('𤼮', "guī"),
+    ('𤼳', "jú"),
+    ('𤼶', "dēng"),
+    ('𤼺', "fèi"),
+    ('𤽁', "zhī"),
+    ('𤽃', "mèi"),
+    ('𤽅', "huàn"),
+    ('𤽉', "pā"),
+    ('𤽊', "bǐ"),
+    ('𤽌', "pō"),
+    ('𤽓', "ér"),
+    ('𤽕', "huàn"),
+    ('𤽣', "chàng"),
+    ('𤽥', "luò"),
+    ('𤽦', "fǒu"),
+    ('𤽯', "chóu"),
+    ('𤽱', "zú"),
+    ('𤽲', "nán"),
+    ('𤽳', "xiǎo"),
+    ('𤽹', "bài"),
+    ('𤽺', "lù"),
+    ('𤽼', "luò"),
+    ('𤽿', "niàn"),
+    ('𤾀', "zé"),
+    ('𤾄', "zhù"),
+    ('𤾅', "hú"),
+    ('𤾈', "huī"),
+    ('𤾉', "tǎng"),
+    ('𤾊', "chóu"),
+    ('𤾑', "huáng"),
+    ('𤾒', "dōu"),
+    ('𤾛', "miào"),
+    ('𤾝', "bó"),
+    ('𤾠', "dì"),
+    ('𤾢', "děng"),
+    ('𤾣', "pū"),
+    ('𤾥', "sōng"),
+    ('𤾦', "chóu"),
+    ('𤾫', "yào"),
+    ('𤾬', "měng"),
+    ('𤾭', "lóng"),
+    ('𤾲', "lián"),
+    ('𤾵', "bié"),
+    ('𤾺', "lǚ"),
+    ('𤾿', "sè"),
+    ('𤿀', "zuó"),
+    ('𤿄', "cún"),
+    ('𤿅', "líng"),
+    ('𤿆', "zhěng"),
+    ('𤿇', "pǐ"),
+    ('𤿈', "báo"),
+    ('𤿋', "què"),
+    ('𤿎', "pī"),
+    ('𤿏', "nàn"),
+    ('𤿐', "pī"),
+    ('𤿑', "bǒ"),
+    ('𤿒', "bèi"),
+    ('𤿓', "fā"),
+    ('𤿕', "mǐn"),
+    ('𤿖', "mò"),
+    ('𤿗', "wà"),
+    ('𤿘', "zhāo"),
+    ('𤿙', "zhì"),
+    ('𤿚', "cū"),
+    ('𤿟', "xún"),
+    ('𤿠', "jí"),
+    ('𤿡', "guì"),
+    ('𤿣', "chéng"),
+    ('𤿧', "hàn"),
+    ('𤿨', "xiào"),
+    ('𤿩', "què"),
+    ('𤿫', "chuò"),
+    ('𤿭', "fǔ"),
+    ('𤿲', "fū"),
+    ('𤿳', "qǐn"),
+    ('𤿴', "lù"),
+    ('𤿵', "què"),
+    ('𤿶', "diǎn"),
+    ('𤿷', "qiān"),
+    ('𤿼', "chǎng"),
+    ('𤿽', "tà"),
+    ('𤿾', "bēi"),
+    ('𥀁', "dù"),
+    ('𥀂', "běng"),
+    ('𥀃', "hòu"),
+    ('𥀈', "zhǎ"),
+    ('𥀉', "zhǎ"),
+    ('𥀎', "què"),
+    ('𥀏', "má"),
+    ('𥀐', "hán"),
+    ('𥀓', "liú"),
+    ('𥀔', "lù"),
+    ('𥀖', "zī"),
+    ('𥀘', "pǐ"),
+    ('𥀙', "zhòu"),
+    ('𥀛', "zāo"),
+    ('𥀝', "niǔ"),
+    ('𥀠', "huì"),
+    ('𥀣', "xué"),
+    ('𥀥', "là"),
+    ('𥀫', "nóu"),
+    ('𥀬', "yǎn"),
+    ('𥀭', "rǎn"),
+    ('𥀮', "nǎo"),
+    ('𥀰', "là"),
+    ('𥀱', "guǎng"),
+    ('𥀲', "dú"),
+    ('𥀵', "lú"),
+    ('𥀹', "jiǎn"),
+    ('𥀺', "xiè"),
+    ('𥀻', "qì"),
+    ('𥀾', "xiàng"),
+    ('𥁁', "guǒ"),
+    ('𥁂', "jié"),
+    ('𥁃', "màng"),
+    ('𥁆', "xiā"),
+    ('𥁇', "kuī"),
+    ('𥁎', "yòng"),
+    ('𥁐', "hǎi"),
+    ('𥁑', "mì"),
+    ('𥁒', "yào"),
+    ('𥁕', "wēn"),
+    ('𥁟', "lì"),
+    ('𥁠', "juàn"),
+    ('𥁡', "wū"),
+    ('𥁢', "qiáo"),
+    ('𥁮', "diào"),
+    ('𥁯', "chù"),
+    ('𥁲', "suō"),
+    ('𥁵', "chōng"),
+    ('𥁸', "quān"),
+    ('𥁹', "shè"),
+    ('𥂁', "yán"),
+    ('𥂂', "měng"),
+    ('𥂃', "jù"),
+    ('𥂋', "tú"),
+    ('𥂒', "nóng"),
+    ('𥂓', "mó"),
+    ('𥂙', "fèn"),
+    ('𥂢', "áo"),
+    ('𥂣', "guō"),
+    ('𥂤', "hú"),
+    ('𥂥', "cán"),
+    ('𥂦', "dūn"),
+    ('𥂧', "hǎi"),
+    ('𥂨', "jiǎo"),
+    ('𥂰', "gū"),
+    ('𥂵', "jīn"),
+    ('𥂸', "yáng"),
+    ('𥃀', "chà"),
+    ('𥃌', "huī"),
+    ('𥃔', "qú"),
+    ('𥃕', "kē"),
+    ('𥃟', "qīng"),
+    ('𥃠', "yì"),
+    ('𥃣', "kǎi"),
+    ('𥃤', "jiǎo"),
+    ('𥃧', "chōu"),
+    ('𥃨', "bǔ"),
+    ('𥃩', "gèn"),
+    ('𥃪', "jiāo"),
+    ('𥃫', "zhī"),
+    ('𥃮', "wèn"),
+    ('𥃰', "bīn"),
+    ('𥃴', "xiòng"),
+    ('𥃵', "fàn"),
+    ('𥃸', "yí"),
+    ('𥃹', "chuàn"),
+    ('𥃺', "yào"),
+    ('𥃽', "yāng"),
+    ('𥃾', "dù"),
+    ('𥃿', "yǎn"),
+    ('𥄁', "méng"),
+    ('𥄇', "chī"),
+    ('𥄈', "mù"),
+    ('𥄉', "jiāo"),
+    ('𥄋', "nǜ"),
+    ('𥄍', "guó"),
+    ('𥄎', "xuè"),
+    ('𥄑', "fú"),
+    ('𥄒', "xuē"),
+    ('𥄓', "fū"),
+    ('𥄔', "pèi"),
+    ('𥄕', "mò"),
+    ('𥄖', "xī"),
+    ('𥄗', "wò"),
+    ('𥄘', "shǎn"),
+    ('𥄛', "xī"),
+    ('𥄜', "qì"),
+    ('𥄝', "miàn"),
+    ('𥄦', "dǎn"),
+    ('𥄨', "chǒu"),
+    ('𥄱', "fèi"),
+    ('𥄲', "mié"),
+    ('𥄴', "xuè"),
+    ('𥄵', "xù"),
+    ('𥄶', "sī"),
+    ('𥄷', "jǔ"),
+    ('𥄸', "mǎo"),
+    ('𥄹', "bào"),
+    ('𥄻', "yí"),
+    ('𥄼', "guā"),
+    ('𥄽', "nì"),
+    ('𥄿', "yí"),
+    ('𥅁', "zuò"),
+    ('𥅄', "nǔ"),
+    ('𥅑', "diàn"),
+    ('𥅒', "fàn"),
+    ('𥅓', "yì"),
+    ('𥅔', "shì"),
+    ('𥅗', "cū"),
+    ('𥅘', "zhěn"),
+    ('𥅞', "shì"),
+    ('𥅟', "jiǎo"),
+    ('𥅠', "hòu"),
+    ('𥅡', "ér"),
+    ('𥅦', "lèi"),
+    ('𥅧', "xuè"),
+    ('𥅨', "gèng"),
+    ('𥅪', "shōu"),
+    ('𥅬', "juān"),
+    ('𥅴', "jié"),
+    ('𥅵', "wéi"),
+    ('𥅷', "shǒu"),
+    ('𥅸', "jìng"),
+    ('𥅺', "xú"),
+    ('𥅻', "chòng"),
+    ('𥆅', "jiāng"),
+    ('𥆆', "mòu"),
+    ('𥆉', "yù"),
+    ('𥆌', "jué"),
+    ('𥆑', "tìng"),
+    ('𥆔', "xiāo"),
+    ('𥆖', "dōu"),
+    ('𥆘', "guó"),
+    ('𥆙', "máng"),
+    ('𥆚', "wāng"),
+    ('𥆛', "xù"),
+    ('𥆜', "wàng"),
+    ('𥆝', "suō"),
+    ('𥆞', "juàn"),
+    ('𥆟', "yuè"),
+    ('𥆡', "hán"),
+    ('𥆣', "shēn"),
+    ('𥆥', "xié"),
+    ('𥆦', "liú"),
+    ('𥆧', "rún"),
+    ('𥆯', "bì"),
+    ('𥆲', "nào"),
+    ('𥆶', "wàn"),
+    ('𥆷', "jiù"),
+    ('𥆸', "quē"),
+    ('𥇄', "nì"),
+    ('𥇆', "mí"),
+    ('𥇇', "suō"),
+    ('𥇉', "qiǎng"),
+    ('𥇌', "hàn"),
+    ('𥇍', "zhuó"),
+    ('𥇎', "mí"),
+    ('𥇏', "xù"),
+    ('𥇑', "lǎng"),
+    ('𥇒', "jié"),
+    ('𥇓', "dìng"),
+    ('𥇔', "chàng"),
+    ('𥇕', "zhì"),
+    ('𥇖', "fēi"),
+    ('𥇗', "jiá"),
+    ('𥇘', "jùn"),
+    ('𥇙', "huò"),
+    ('𥇚', "qī"),
+    ('𥇛', "jū"),
+    ('𥇜', "zhūn"),
+    ('𥇞', "diàn"),
+    ('𥇟', "jiǎo"),
+    ('𥇠', "yā"),
+    ('𥇢', "zhǎn"),
+    ('𥇭', "zhī"),
+    ('𥇯', "mài"),
+    ('𥇰', "hū"),
+    ('𥇱', "xiè"),
+    ('𥇲', "shí"),
+    ('𥇳', "guī"),
+    ('𥇿', "xù"),
+    ('𥈂', "jí"),
+    ('𥈄', "chuàng"),
+    ('𥈆', "mào"),
+    ('𥈇', "ruán"),
+    ('𥈈', "xū"),
+    ('𥈉', "huàn"),
+    ('𥈊', "shà"),
+    ('𥈋', "jǔ"),
+    ('𥈏', "kuàng"),
+    ('𥈑', "hóu"),
+    ('𥈒', "guān"),
+    ('𥈓', "guā"),
+    ('𥈕', "mí"),
+    ('𥈖', "dié"),
+    ('𥈗', "bì"),
+    ('𥈘', "liǎng"),
+    ('𥈙', "là"),
+    ('𥈚', "shǎn"),
+    ('𥈛', "lù"),
+    ('𥈜', "xì"),
+    ('𥈟', "sǒu"),
+    ('𥈬', "ōu"),
+    ('𥈮', "léng"),
+    ('𥈷', "kū"),
+    ('𥈸', "guī"),
+    ('𥈻', "xī"),
+    ('𥈼', "pán"),
+    ('𥈽', "sè"),
+    ('𥈾', "juè"),
+    ('𥈿', "hòng"),
+    ('𥉀', "guàn"),
+    ('𥉁', "jù"),
+    ('𥉃', "nài"),
+    ('𥉄', "huá"),
+    ('𥉅', "gé"),
+    ('𥉆', "lì"),
+    ('𥉇', "gòu"),
+    ('𥉈', "tì"),
+    ('𥉊', "mà"),
+    ('𥉋', "téng"),
+    ('𥉌', "dá"),
+    ('𥉐', "qī"),
+    ('𥉑', "yù"),
+    ('𥉒', "jiǎo"),
+    ('𥉓', "miè"),
+    ('𥉔', "gěng"),
+    ('𥉕', "mèng"),
+    ('𥉖', "wèi"),
+    ('𥉘', "tí"),
+    ('𥉙', "qí"),
+    ('𥉜', "chén"),
+    ('𥉝', "dōu"),
+    ('𥉟', "pán"),
+    ('𥉰', "hàn"),
+    ('𥉴', "mì"),
+    ('𥉵', "má"),
+    ('𥉶', "lù"),
+    ('𥉷', "qī"),
+    ('𥉸', "kēng"),
+    ('𥉺', "dié"),
+    ('𥉻', "qì"),
+    ('𥉼', "jiāo"),
+    ('𥉽', "kāng"),
+    ('𥉾', "qiāo"),
+    ('𥉿', "mì"),
+    ('𥊀', "shān"),
+    ('𥊇', "jiān"),
+    ('𥊈', "lí"),
+    ('𥊉', "kè"),
+    ('𥊊', "xù"),
+    ('𥊑', "mán"),
+    ('𥊒', "fèng"),
+    ('𥊓', "chàn"),
+    ('𥊔', "huǐ"),
+    ('𥊧', "kòu"),
+    ('𥊪', "wěi"),
+    ('𥊫', "guàn"),
+    ('𥊬', "jí"),
+    ('𥊭', "zùn"),
+    ('𥊮', "huò"),
+    ('𥊯', "xié"),
+    ('𥊴', "suì"),
+    ('𥊶', "ruǎn"),
+    ('𥊸', "tè"),
+    ('𥊼', "zhèng"),
+    ('𥊽', "kūn"),
+    ('𥊾', "xiǎng"),
+    ('𥊿', "mián"),
+    ('𥋁', "xì"),
+    ('𥋌', "sā"),
+    ('𥋙', "è"),
+    ('𥋚', "miè"),
+    ('𥋛', "zhǔ"),
+    ('𥋜', "zōu"),
+    ('𥋝', "měng"),
+    ('𥋟', "xī"),
+    ('𥋡', "táng"),
+    ('𥋣', "jià"),
+    ('𥋤', "cháng"),
+    ('𥋥', "jí"),
+    ('𥋮', "zhuó"),
+    ('𥋿', "hè"),
+    ('𥌀', "chá"),
+    ('𥌁', "qì"),
+    ('𥌂', "mián"),
+    ('𥌃', "zhěn"),
+    ('𥌄', "kū"),
+    ('𥌅', "yè"),
+    ('𥌆', "zhōu"),
+    ('𥌈', "jiān"),
+    ('𥌊', "pàn"),
+    ('𥌍', "huī"),
+    ('𥌏', "míng"),
+    ('𥌐', "liù"),
+    ('𥌘', "shuì"),
+    ('𥌚', "mài"),
+    ('𥌛', "lí"),
+    ('𥌞', "shuò"),
+    ('𥌟', "yí"),
+    ('𥌤', "lì"),
+    ('𥌨', "xiē"),
+    ('𥌩', "tè"),
+    ('𥌪', "xiū"),
+    ('𥌭', "xuàn"),
+    ('𥌮', "lì"),
+    ('𥌯', "méng"),
+    ('𥌰', "wéi"),
+    ('𥌱', "méng"),
+    ('𥌺', "yào"),
+    ('𥌻', "lán"),
+    ('𥌼', "líng"),
+    ('𥌽', "yīng"),
+    ('𥌾', "yīng"),
+    ('𥌿', "lì"),
+    ('𥍀', "jiǎn"),
+    ('𥍁', "guī"),
+    ('𥍅', "guān"),
+    ('𥍆', "xiè"),
+    ('𥍉', "shè"),
+    ('𥍋', "zuī"),
+    ('𥍓', "kàn"),
+    ('𥍔', "léi"),
+    ('𥍚', "biàn"),
+    ('𥍝', "shǔ"),
+    ('𥍞', "nǜ"),
+    ('𥍟', "xù"),
+    ('𥍣', "hào"),
+    ('𥍨', "guǐ"),
+    ('𥍪', "zhài"),
+    ('𥍫', "láng"),
+    ('𥍬', "cuān"),
+    ('𥍭', "zhì"),
+    ('𥍮', "féng"),
+    ('𥍯', "qīn"),
+    ('𥍱', "zé"),
+    ('𥍲', "nà"),
+    ('𥍳', "niǔ"),
+    ('𥍴', "yì"),
+    ('𥍷', "cōng"),
+    ('𥍸', "shī"),
+    ('𥍹', "jiǎn"),
+    ('𥍺', "zōng"),
+    ('𥍻', "yǎn"),
+    ('𥍼', "yīng"),
+    ('𥎀', "ruǎn"),
+    ('𥎂', "róng"),
+    ('𥎃', "xì"),
+    ('𥎅', "guān"),
+    ('𥎆', "kài"),
+    ('𥎈', "wù"),
+    ('𥎊', "qín"),
+    ('𥎋', "cōng"),
+    ('𥎍', "zé"),
+    ('𥎎', "xiè"),
+    ('𥎐', "yù"),
+    ('𥎑', "zàn"),
+    ('𥎒', "chuāng"),
+    ('𥎓', "lǐ"),
+    ('𥎔', "lǐ"),
+    ('𥎕', "xù"),
+    ('𥎖', "mí"),
+    ('𥎗', "xù"),
+    ('𥎘', "ruǎn"),
+    ('𥎛', "guì"),
+    ('𥎜', "rǒng"),
+    ('𥎝', "zuǎn"),
+    ('𥎟', "máo"),
+    ('𥎡', "qín"),
+    ('𥎢', "cuàn"),
+    ('𥎣', "cuàn"),
+    ('𥎤', "cuàn"),
+    ('𥎮', "wū"),
+    ('𥎰', "fǎ"),
+    ('𥎱', "bá"),
+    ('𥎸', "qià"),
+    ('𥎹', "zhì"),
+    ('𥎺', "tiào"),
+    ('𥏄', "zhì"),
+    ('𥏅', "zhí"),
+    ('𥏇', "huàn"),
+    ('𥏈', "chóu"),
+    ('𥏊', "zhì"),
+    ('𥏎', "yǐng"),
+    ('𥏒', "wù"),
+    ('𥏓', "bēi"),
+    ('𥏕', "hóng"),
+    ('𥏖', "shěn"),
+    ('𥏘', "jué"),
+    ('𥏙', "kuì"),
+    ('𥏜', "yǐ"),
+    ('𥏝', "yà"),
+    ('𥏠', "bī"),
+    ('𥏤', "kuà"),
+    ('𥏥', "qiān"),
+    ('𥏨', "zhāo"),
+    ('𥏪', "kǎi"),
+    ('𥏫', "shāng"),
+    ('𥏮', "àn"),
+    ('𥏯', "zhé"),
+    ('𥏰', "zhì"),
+    ('𥏷', "zhì"),
+    ('𥏹', "jiǎo"),
+    ('𥐀', "sī"),
+    ('𥐁', "pú"),
+    ('𥐂', "ǒu"),
+    ('𥐊', "zhuó"),
+    ('𥐑', "yīng"),
+    ('𥐓', "huān"),
+    ('𥐕', "yà"),
+    ('𥐘', "shí"),
+    ('𥐙', "pā"),
+    ('𥐚', "pǔ"),
+    ('𥐞', "máng"),
+    ('𥐟', "chāi"),
+    ('𥐩', "yún"),
+    ('𥐬', "gǔ"),
+    ('𥐹', "dǎn"),
+    ('𥐻', "náo"),
+    ('𥐽', "zhé"),
+    ('𥐿', "hú"),
+    ('𥑅', "kēng"),
+    ('𥑇', "dié"),
+    ('𥑈', "tīng"),
+    ('𥑋', "guài"),
+    ('𥑎', "qiōng"),
+    ('𥑏', "shǐ"),
+    ('𥑐', "jiǎ"),
+    ('𥑑', "ào"),
+    ('𥑒', "nǎ"),
+    ('𥑓', "pǐn"),
+    ('𥑔', "jiá"),
+    ('𥑡', "zhè"),
+    ('𥑢', "bù"),
+    ('𥑣', "wǒ"),
+    ('𥑥', "chǎ"),
+    ('𥑪', "náo"),
+    ('𥑫', "kǎn"),
+    ('𥑯', "dú"),
+    ('𥑰', "guài"),
+    ('𥑱', "qióng"),
+    ('𥑳', "róng"),
+    ('𥑴', "yǐ"),
+    ('𥑵', "duī"),
+    ('𥑶', "lěi"),
+    ('𥑸', "zhōu"),
+    ('𥑹', "kuā"),
+    ('𥑺', "ē"),
+    ('𥑻', "xiān"),
+    ('𥑼', "diàn"),
+    ('𥑽', "nuò"),
+    ('𥑾', "è"),
+    ('𥑿', "yōng"),
+    ('𥒀', "wù"),
+    ('𥒁', "kēng"),
+    ('𥒓', "zhì"),
+    ('𥒗', "zhǐ"),
+    ('𥒘', "xún"),
+    ('𥒛', "zhèng"),
+    ('𥒞', "yáng"),
+    ('𥒠', "huò"),
+    ('𥒡', "jí"),
+    ('𥒢', "nǎo"),
+    ('𥒧', "yà"),
+    ('𥒨', "lù"),
+    ('𥒫', "fū"),
+    ('𥒬', "sǎn"),
+    ('𥒭', "chù"),
+    ('𥒮', "wěi"),
+    ('𥒰', "fǔ"),
+    ('𥒱', "kēng"),
+    ('𥒲', "sì"),
+    ('𥒳', "kàng"),
+    ('𥒵', "yì"),
+    ('𥒶', "huà"),
+    ('𥒾', "yǔ"),
+    ('𥓃', "lì"),
+    ('𥓆', "lǐn"),
+    ('𥓇', "dǔ"),
+    ('𥓈', "è"),
+    ('𥓌', "qiǎng"),
+    ('𥓍', "dú"),
+    ('𥓐', "jié"),
+    ('𥓑', "chuò"),
+    ('𥓒', "xiàn"),
+    ('𥓖', "gǎo"),
+    ('𥓬', "dào"),
+    ('𥓰', "hōng"),
+    ('𥓻', "zōng"),
+    ('𥓾', "qì"),
+    ('𥓿', "tuó"),
+    ('𥔀', "hōng"),
+    ('𥔁', "pǐ"),
+    ('𥔂', "gèng"),
+    ('𥔄', "niè"),
+    ('𥔇', "kōng"),
+    ('𥔊', "zhǐ"),
+    ('𥔑', "xiǎo"),
+    ('𥔡', "shè"),
+    ('𥔢', "yú"),
+    ('𥔣', "jiāng"),
+    ('𥔩', "qǐ"),
+    ('𥔪', "chěn"),
+    ('𥔫', "sǎng"),
+    ('𥔭', "suǒ"),
+    ('𥔮', "qián"),
+    ('𥔯', "huì"),
+    ('𥔱', "shàn"),
+    ('𥔲', "è"),
+    ('𥔻', "qiū"),
+    ('𥔽', "kè"),
+    ('𥕀', "wēng"),
+    ('𥕁', "zī"),
+    ('𥕂', "jí"),
+    ('𥕇', "dǎ"),
+    ('𥕉', "cuò"),
+    ('𥕍', "lǒu"),
+    ('𥕎', "kāng"),
+    ('𥕏', "kuò"),
+    ('𥕐', "dí"),
+    ('𥕑', "qiē"),
+    ('𥕓', "mò"),
+    ('𥕖', "guǒ"),
+    ('𥕗', "hōng"),
+    ('𥕘', "cháo"),
+    ('𥕙', "hēi"),
+    ('𥕢', "cáo"),
+    ('𥕣', "zhé"),
+    ('𥕤', "kē"),
+    ('𥕦', "gǔn"),
+    ('𥕰', "xū"),
+    ('𥕱', "péng"),
+    ('𥕲', "jué"),
+    ('𥕵', "gǎn"),
+    ('𥕶', "sī"),
+    ('𥕸', "suì"),
+    ('𥕹', "què"),
+    ('𥕻', "wú"),
+    ('𥕼', "yán"),
+    ('𥕽', "pèng"),
+    ('𥕾', "xiǎo"),
+    ('𥕿', "pān"),
+    ('𥖍', "là"),
+    ('𥖗', "bèng"),
+    ('𥖘', "zhěn"),
+    ('𥖙', "jí"),
+    ('𥖜', "jǐn"),
+    ('𥖝', "lián"),
+    ('𥖞', "kěn"),
+    ('𥖠', "zhóu"),
+    ('𥖨', "zào"),
+    ('𥖪', "lè"),
+    ('𥖫', "qī"),
+    ('𥖬', "bìng"),
+    ('𥖵', "yǐn"),
+    ('𥖶', "pīn"),
+    ('𥖻', "sǒu"),
+    ('𥖼', "lǜ"),
+    ('𥖾', "dí"),
+    ('𥖿', "dú"),
+    ('𥗀', "liǎo"),
+    ('𥗁', "zhuó"),
+    ('𥗊', "chǎng"),
+    ('𥗒', "chèn"),
+    ('𥗓', "tà"),
+    ('𥗙', "què"),
+    ('𥗚', "dào"),
+    ('𥗝', "rǎng"),
+    ('𥗟', "pò"),
+    ('𥗦', "zhōng"),
+    ('𥗧', "xiē"),
+    ('𥗪', "jiāng"),
+    ('𥗫', "qú"),
+    ('𥗬', "lěi"),
+    ('𥗭', "cà"),
+    ('𥗮', "quē"),
+    ('𥗵', "xiàng"),
+    ('𥗶', "lèi"),
+    ('𥗺', "làn"),
+    ('𥗽', "lán"),
+    ('𥗿', "lǎ"),
+    ('𥘁', "lǎ"),
+    ('𥘄', "yù"),
+    ('𥘊', "jiào"),
+    ('𥘋', "qín"),
+    ('𥘌', "jī"),
+    ('𥘏', "gǎn"),
+    ('𥘒', "yì"),
+    ('𥘠', "yì"),
+    ('𥘡', "zhī"),
+    ('𥘤', "biǎo"),
+    ('𥘥', "shēng"),
+    ('𥘦', "jiù"),
+    ('𥘫', "hē"),
+    ('𥘬', "fú"),
+    ('𥘮', "jū"),
+    ('𥙀', "zuǒ"),
+    ('𥙁', "yí"),
+    ('𥙆', "xiàn"),
+    ('𥙇', "yí"),
+    ('𥙉', "sì"),
+    ('𥙋', "chuì"),
+    ('𥙎', "mò"),
+    ('𥙡', "zhān"),
+    ('𥙣', "xún"),
+    ('𥙦', "rú"),
+    ('𥙨', "huò"),
+    ('𥙬', "shāo"),
+    ('𥙰', "shòu"),
+    ('𥙾', "yòu"),
+    ('𥙿', "yù"),
+    ('𥚂', "jùn"),
+    ('𥚉', "zī"),
+    ('𥚊', "lù"),
+    ('𥚚', "chǐ"),
+    ('𥚛', "kūn"),
+    ('𥚠', "zhùn"),
+    ('𥚦', "hóu"),
+    ('𥚩', "xǔ"),
+    ('𥚾', "zōng"),
+    ('𥚿', "yìng"),
+    ('𥛂', "zhū"),
+    ('𥛅', "liù"),
+    ('𥛑', "nù"),
+    ('𥛘', "bì"),
+    ('𥛚', "chì"),
+    ('𥛜', "zǔ"),
+    ('𥛝', "féng"),
+    ('𥛞', "lù"),
+    ('𥛟', "pǔ"),
+    ('𥛥', "zhuàn"),
+    ('𥛧', "zhé"),
+    ('𥛨', "shī"),
+    ('𥛩', "yǔ"),
+    ('𥛪', "lù"),
+    ('𥛫', "liáng"),
+    ('𥛯', "jué"),
+    ('𥛰', "liào"),
+    ('𥛱', "bēng"),
+    ('𥜃', "yì"),
+    ('𥜄', "guān"),
+    ('𥜌', "ǎo"),
+    ('𥜏', "guì"),
+    ('𥜐', "mǐn"),
+    ('𥜒', "yǎn"),
+    ('𥜓', "lán"),
+    ('𥜖', "bó"),
+    ('𥜙', "zàn"),
+    ('𥜚', "yǒu"),
+    ('𥜥', "yì"),
+    ('𥜦', "nǐ"),
+    ('𥜬', "nǐ"),
+    ('𥜭', "guǒ"),
+    ('𥜮', "jùn"),
+    ('𥜰', "shī"),
+    ('𥜲', "xiǎn"),
+    ('𥜴', "qiān"),
+    ('𥜵', "què"),
+    ('𥜶', "kuí"),
+    ('𥝀', "shé"),
+    ('𥝂', "huò"),
+    ('𥝄', "wàn"),
+    ('𥝊', "fèi"),
+    ('𥝋', "fèi"),
+    ('𥝌', "jī"),
+    ('𥝍', "yù"),
+    ('𥝑', "zhī"),
+    ('𥝒', "guà"),
+    ('𥝔', "jié"),
+    ('𥝕', "máng"),
+    ('𥝖', "hé"),
+    ('𥝘', "yǒu"),
+    ('𥝟', "dù"),
+    ('𥝠', "sī"),
+    ('𥝢', "lì"),
+    ('𥝥', "jié"),
+    ('𥝦', "niǔ"),
+    ('𥝧', "bà"),
+    ('𥝨', "yú"),
+    ('𥝮', "zhī"),
+    ('𥝸', "hé"),
+    ('𥝹', "kē"),
+    ('𥝾', "dù"),
+    ('𥝿', "jiā"),
+    ('𥞁', "chēn"),
+    ('𥞃', "chuì"),
+    ('𥞄', "hé"),
+    ('𥞅', "zhǎi"),
+    ('𥞊', "mèi"),
+    ('𥞍', "hé"),
+    ('𥞎', "zǐ"),
+    ('𥞏', "zhú"),
+    ('𥞒', "tuó"),
+    ('𥞘', "zùn"),
+    ('𥞚', "rú"),
+    ('𥞛', "duò"),
+    ('𥞜', "jiàng"),
+    ('𥞦', "jiá"),
+    ('𥞧', "héng"),
+    ('𥞩', "bēng"),
+    ('𥞪', "mò"),
+    ('𥞯', "zú"),
+    ('𥞲', "biē"),
+    ('𥞴', "kù"),
+    ('𥞵', "jiá"),
+    ('𥞺', "zhuō"),
+    ('𥞼', "xiū"),
+    ('𥟂', "lái"),
+    ('𥟃', "hé"),
+    ('𥟅', "qiāo"),
+    ('𥟍', "fěi"),
+    ('𥟎', "shēng"),
+    ('𥟒', "zhuì"),
+    ('𥟓', "kuǎn"),
+    ('𥟔', "zè"),
+    ('𥟕', "xiān"),
+    ('𥟗', "bì"),
+    ('𥟘', "yì"),
+    ('𥟚', "chàng"),
+    ('𥟪', "mào"),
+    ('𥟶', "wǎn"),
+    ('𥟽', "wū"),
+    ('𥟾', "kū"),
+    ('𥟿', "wǒ"),
+    ('𥠀', "xīng"),
+    ('𥠁', "kē"),
+    ('𥠃', "jiū"),
+    ('𥠄', "duān"),
+    ('𥠅', "huàn"),
+    ('𥠈', "zhì"),
+    ('𥠉', "cè"),
+    ('𥠊', "róu"),
+    ('𥠋', "jí"),
+    ('𥠍', "yè"),
+    ('𥠛', "jīng"),
+    ('𥠜', "yàng"),
+    ('𥠡', "zǒng"),
+    ('𥠩', "cǎn"),
+    ('𥠱', "sī"),
+    ('𥠲', "lì"),
+    ('𥠳', "gǔ"),
+    ('𥠴', "chàng"),
+    ('𥠶', "fěi"),
+    ('𥠷', "liú"),
+    ('𥠹', "jié"),
+    ('𥠺', "yūn"),
+    ('𥠽', "zhì"),
+    ('𥡀', "chóu"),
+    ('𥡁', "biē"),
+    ('𥡒', "jī"),
+    ('𥡜', "luó"),
+    ('𥡝', "jiān"),
+    ('𥡟', "chuāng"),
+    ('𥡠', "shuǎng"),
+    ('𥡢', "lǜ"),
+    ('𥡣', "jùn"),
+    ('𥡤', "jiào"),
+    ('𥡦', "tì"),
+    ('𥡧', "zhā"),
+    ('𥡪', "yì"),
+    ('𥡬', "cōng"),
+    ('𥡭', "něi"),
+    ('𥡮', "jiā"),
+    ('𥡴', "jì"),
+    ('𥡽', "ài"),
+    ('𥢇', "jiǎn"),
+    ('𥢊', "bèn"),
+    ('𥢌', "fán"),
+    ('𥢍', "suì"),
+    ('𥢎', "zùn"),
+    ('𥢏', "diàn"),
+    ('𥢐', "gāo"),
+    ('𥢑', "gǎo"),
+    ('𥢒', "láo"),
+    ('𥢔', "zhuó"),
+    ('𥢢', "tuí"),
+    ('𥢦', "bì"),
+    ('𥢧', "jú"),
+    ('𥢮', "huá"),
+    ('𥢲', "chéng"),
+    ('𥢶', "kuài"),
+    ('𥢷', "dāng"),
+    ('𥢸', "gé"),
+    ('𥢹', "xié"),
+    ('𥢻', "jié"),
+    ('𥢽', "cān"),
+    ('𥣆', "zú"),
+    ('𥣈', "pú"),
+    ('𥣋', "shǔ"),
+    ('𥣌', "bǔ"),
+    ('𥣗', "níng"),
+    ('𥣘', "yǎn"),
+    ('𥣙', "zhòu"),
+    ('𥣛', "méng"),
+    ('𥣝', "biǎn"),
+    ('𥣟', "xiàng"),
+    ('𥣤', "lù"),
+    ('𥣥', "lí"),
+    ('𥣩', "jì"),
+    ('𥣫', "miè"),
+    ('𥣬', "lèi"),
+    ('𥣮', "zhì"),
+    ('𥣯', "yōu"),
+    ('𥣰', "biǎn"),
+    ('𥣸', "mù"),
+    ('𥣺', "ràn"),
+    ('𥤂', "niǎo"),
+    ('𥤊', "quán"),
+    ('𥤋', "zhé"),
+    ('𥤐', "lèi"),
+    ('𥤗', "dǎng"),
+    ('𥤘', "jué"),
+    ('𥤜', "líng"),
+    ('𥤞', "líng"),
+    ('𥤟', "yán"),
+    ('𥤣', "yǎo"),
+    ('𥤤', "zhèn"),
+    ('𥤥', "qī"),
+    ('𥤦', "ài"),
+    ('𥤨', "nú"),
+    ('𥤩', "mǎng"),
+    ('𥤱', "kǎn"),
+    ('𥤳', "jiū"),
+    ('𥤴', "yǎn"),
+    ('𥤵', "miàn"),
+    ('𥤷', "yín"),
+    ('𥤸', "wán"),
+    ('𥤹', "yào"),
+    ('𥤺', "wā"),
+    ('𥤻', "pí"),
+    ('𥤼', "suì"),
+    ('𥥅', "kǒng"),
+    ('𥥈', "hóng"),
+    ('𥥊', "mǐng"),
+    ('𥥋', "líng"),
+    ('𥥌', "yì"),
+    ('𥥍', "shēn"),
+    ('𥥏', "zuò"),
+    ('𥥛', "tū"),
+    ('𥥝', "yòng"),
+    ('𥥟', "wà"),
+    ('𥥠', "guǐ"),
+    ('𥥡', "hòng"),
+    ('𥥥', "shì"),
+    ('𥥧', "xiòng"),
+    ('𥥩', "ā"),
+    ('𥥱', "chéng"),
+    ('𥥳', "kēng"),
+    ('𥥴', "yì"),
+    ('𥥵', "yàng"),
+    ('𥥶', "tíng"),
+    ('𥥷', "dòu"),
+    ('𥥸', "chá"),
+    ('𥥹', "liù"),
+    ('𥥽', "qiú"),
+    ('𥥾', "xuǎn"),
+    ('𥥿', "shēn"),
+    ('𥦀', "kuān"),
+    ('𥦁', "tòng"),
+    ('𥦃', "qiǎn"),
+    ('𥦅', "chòu"),
+    ('𥦊', "wěn"),
+    ('𥦌', "lòng"),
+    ('𥦍', "ǎn"),
+    ('𥦔', "kǎn"),
+    ('𥦖', "yǎo"),
+    ('𥦘', "fú"),
+    ('𥦜', "bèng"),
+    ('𥦝', "lǎn"),
+    ('𥦞', "qià"),
+    ('𥦟', "diàn"),
+    ('𥦢', "jiào"),
+    ('𥦣', "guī"),
+    ('𥦥', "xiòng"),
+    ('𥦨', "kè"),
+    ('𥦶', "xiàn"),
+    ('𥧂', "gǒng"),
+    ('𥧆', "ǒu"),
+    ('𥧇', "kē"),
+    ('𥧋', "kū"),
+    ('𥧑', "tián"),
+    ('𥧒', "gòu"),
+    ('𥧓', "mǎ"),
+    ('𥧕', "liù"),
+    ('𥧙', "wèi"),
+    ('𥧚', "wěn"),
+    ('𥧡', "gòng"),
+    ('𥧣', "tú"),
+    ('𥧤', "níng"),
+    ('𥧧', "mì"),
+    ('𥧫', "láng"),
+    ('𥧬', "qiǎn"),
+    ('𥧭', "mán"),
+    ('𥧮', "zhé"),
+    ('𥧰', "huà"),
+    ('𥧱', "yōng"),
+    ('𥧲', "jìn"),
+    ('𥧴', "mèi"),
+    ('𥧷', "fú"),
+    ('𥧻', "qú"),
+    ('𥨌', "liù"),
+    ('𥨍', "fù"),
+    ('𥨎', "dàn"),
+    ('𥨐', "gǒng"),
+    ('𥨒', "cuì"),
+    ('𥨕', "xǐng"),
+    ('𥨜', "tū"),
+    ('𥨝', "shòu"),
+    ('𥨪', "qióng"),
+    ('𥨳', "róng"),
+    ('𥨻', "lì"),
+    ('𥨿', "jī"),
+    ('𥩀', "tuò"),
+    ('𥩌', "tóng"),
+    ('𥩒', "tán"),
+    ('𥩔', "líng"),
+    ('𥩖', "yì"),
+    ('𥩗', "ruǎn"),
+    ('𥩙', "pǎ"),
+    ('𥩝', "cà"),
+    ('𥩡', "yuè"),
+    ('𥩢', "què"),
+    ('𥩣', "zhù"),
+    ('𥩤', "hài"),
+    ('𥩱', "fá"),
+    ('𥩲', "hài"),
+    ('𥩺', "liè"),
+    ('𥪀', "bū"),
+    ('𥪁', "pīng"),
+    ('𥪂', "liè"),
+    ('𥪊', "kuǐ"),
+    ('𥪋', "fú"),
+    ('𥪌', "tiǎn"),
+    ('𥪍', "wò"),
+    ('𥪏', "jū"),
+    ('𥪘', "zhēn"),
+    ('𥪚', "fú"),
+    ('𥪢', "lóng"),
+    ('𥪦', "xì"),
+    ('𥪧', "tián"),
+    ('𥪫', "jì"),
+    ('𥪯', "yào"),
+    ('𥪱', "cù"),
+    ('𥪴', "pàng"),
+    ('𥪵', "qiè"),
+    ('𥪻', "lóng"),
+    ('𥪼', "jǐ"),
+    ('𥫂', "tóng"),
+    ('𥫃', "yí"),
+    ('𥫅', "chāng"),
+    ('𥫋', "gōng"),
+    ('𥫎', "dòng"),
+    ('𥫖', "xiāng"),
+    ('𥫙', "tǐng"),
+    ('𥫛', "zhuān"),
+    ('𥫜', "yǐ"),
+    ('𥫝', "yì"),
+    ('𥫞', "zǐ"),
+    ('𥫟', "qǐ"),
+    ('𥫢', "chǎ"),
+    ('𥫬', "dùn"),
+    ('𥫯', "chōng"),
+    ('𥫰', "lù"),
+    ('𥫱', "dùn"),
+    ('𥫳', "fāng"),
+    ('𥫴', "shì"),
+    ('𥫵', "tì"),
+    ('𥫶', "jī"),
+    ('𥫷', "qiū"),
+    ('𥫸', "shuǐ"),
+    ('𥫹', "chén"),
+    ('𥫼', "huàng"),
+    ('𥫽', "shi"),
+    ('𥬀', "yún"),
+    ('𥬆', "lóng"),
+    ('𥬈', "mǎn"),
+    ('𥬉', "gōu"),
+    ('𥬍', "xiān"),
+    ('𥬎', "mò"),
+    ('𥬐', "shěn"),
+    ('𥬒', "pō"),
+    ('𥬓', "yào"),
+    ('𥬔', "qū"),
+    ('𥬕', "rǎn"),
+    ('𥬙', "jù"),
+    ('𥬜', "yǐn"),
+    ('𥬝', "bái"),
+    ('𥬞', "niè"),
+    ('𥬠', "chōu"),
+    ('𥬪', "róng"),
+    ('𥬫', "chuǎn"),
+    ('𥬬', "niè"),
+    ('𥬭', "lì"),
+    ('𥬮', "jiāng"),
+    ('𥬯', "kǎo"),
+    ('𥬰', "cè"),
+    ('𥬱', "chòng"),
+    ('𥬲', "zhuā"),
+    ('𥬳', "zǐ"),
+    ('𥬴', "yáng"),
+    ('𥬼', "wěn"),
+    ('𥭋', "jì"),
+    ('𥭌', "jì"),
+    ('𥭐', "lǜ"),
+    ('𥭑', "qiú"),
+    ('𥭒', "dùn"),
+    ('𥭓', "báo"),
+    ('𥭔', "chān"),
+    ('𥭖', "bó"),
+    ('𥭘', "chī"),
+    ('𥭙', "zhè"),
+    ('𥭚', "màng"),
+    ('𥭜', "jì"),
+    ('𥭝', "miào"),
+    ('𥭞', "yuàn"),
+    ('𥭠', "wú"),
+    ('𥭡', "zhì"),
+    ('𥭢', "pīng"),
+    ('𥭥', "chōng"),
+    ('𥭫', "mí"),
+    ('𥭬', "féi"),
+    ('𥭭', "cuō"),
+    ('𥭮', "méng"),
+    ('𥮍', "yín"),
+    ('𥮎', "mǎng"),
+    ('𥮏', "diǎn"),
+    ('𥮐', "diāo"),
+    ('𥮒', "qián"),
+    ('𥮕', "hàng"),
+    ('𥮖', "zhí"),
+    ('𥮗', "jú"),
+    ('𥮘', "niàn"),
+    ('𥮜', "mí"),
+    ('𥮝', "gǔ"),
+    ('𥮣', "zhuā"),
+    ('𥮤', "niè"),
+    ('𥮥', "zhuó"),
+    ('𥮧', "yè"),
+    ('𥮨', "còng"),
+    ('𥮪', "xū"),
+    ('𥮬', "xì"),
+    ('𥮯', "bō"),
+    ('𥮾', "cǎn"),
+    ('𥯃', "yǎn"),
+    ('𥯑', "jǐn"),
+    ('𥯔', "jǔ"),
+    ('𥯕', "dàng"),
+    ('𥯖', "dù"),
+    ('𥯘', "yé"),
+    ('𥯙', "jìng"),
+    ('𥯚', "kè"),
+    ('𥯛', "luò"),
+    ('𥯜', "wěi"),
+    ('𥯝', "tū"),
+    ('𥯞', "yóu"),
+    ('𥯟', "pài"),
+    ('𥯡', "pí"),
+    ('𥯢', "dìng"),
+    ('𥯤', "wěi"),
+    ('𥯥', "chè"),
+    ('𥯦', "jiàn"),
+    ('𥯨', "sī"),
+    ('𥯩', "zhuó"),
+    ('𥯪', "sòu"),
+    ('𥯬', "ruǎn"),
+    ('𥯮', "yú"),
+    ('𥯳', "è"),
+    ('𥯶', "kǔ"),
+    ('𥯸', "zhù"),
+    ('𥯾', "xiá"),
+    ('𥰛', "fú"),
+    ('𥰜', "táo"),
+    ('𥰝', "xī"),
+    ('𥰞', "chōu"),
+    ('𥰟', "yuán"),
+    ('𥰠', "lǘ"),
+    ('𥰡', "cè"),
+    ('𥰢', "shàn"),
+    ('𥰣', "liú"),
+    ('𥰥', "xì"),
+    ('𥰦', "jī"),
+    ('𥰧', "yǐ"),
+    ('𥰨', "tán"),
+    ('𥰪', "hú"),
+    ('𥰭', "cuō"),
+    ('𥰮', "gě"),
+    ('𥰰', "shì"),
+    ('𥰱', "sāo"),
+    ('𥰲', "hòng"),
+    ('𥰳', "xiàn"),
+    ('𥰶', "xiá"),
+    ('𥰻', "mù"),
+    ('𥰼', "suǒ"),
+    ('𥰾', "zhài"),
+    ('𥱀', "fū"),
+    ('𥱁', "sè"),
+    ('𥱂', "nú"),
+    ('𥱃', "yì"),
+    ('𥱧', "qín"),
+    ('𥱨', "qìng"),
+    ('𥱵', "huì"),
+    ('𥱶', "shuǎng"),
+    ('𥱷', "dǎn"),
+    ('𥱸', "ōu"),
+    ('𥱹', "mò"),
+    ('𥱺', "qiān"),
+    ('𥱻', "chì"),
+    ('𥱼', "pái"),
+    ('𥱽', "juàn"),
+    ('𥲀', "cháo"),
+    ('𥲁', "liè"),
+    ('𥲂', "bīng"),
+    ('𥲃', "kòu"),
+    ('𥲄', "dàn"),
+    ('𥲅', "chóu"),
+    ('𥲆', "tōng"),
+    ('𥲇', "dàn"),
+    ('𥲈', "mǎn"),
+    ('𥲉', "hù"),
+    ('𥲊', "liáo"),
+    ('𥲋', "xián"),
+    ('𥲍', "cáo"),
+    ('𥲎', "lù"),
+    ('𥲏', "chuàn"),
+    ('𥲐', "wú"),
+    ('𥲑', "mán"),
+    ('𥲕', "zǐ"),
+    ('𥲗', "dù"),
+    ('𥲚', "shuàng"),
+    ('𥲛', "fù"),
+    ('𥲜', "jù"),
+    ('𥲝', "zhòu"),
+    ('𥲟', "diào"),
+    ('𥲠', "wàng"),
+    ('𥲡', "chuāng"),
+    ('𥲢', "qiān"),
+    ('𥲣', "tuì"),
+    ('𥲥', "lián"),
+    ('𥲦', "biāo"),
+    ('𥲧', "lí"),
+    ('𥲪', "lí"),
+    ('𥳆', "bì"),
+    ('𥳇', "fù"),
+    ('𥳈', "cuì"),
+    ('𥳉', "dū"),
+    ('𥳋', "zàn"),
+    ('𥳌', "lóng"),
+    ('𥳍', "xún"),
+    ('𥳎', "qióng"),
+    ('𥳏', "jī"),
+    ('𥳐', "qiǎn"),
+    ('𥳒', "jiǎn"),
+    ('𥳓', "shāo"),
+    ('𥳔', "duò"),
+    ('𥳕', "shū"),
+    ('𥳖', "bù"),
+    ('𥳗', "xū"),
+    ('𥳘', "dǒng"),
+    ('𥳚', "rán"),
+    ('𥳜', "yáng"),
+    ('𥳝', "ruǐ"),
+    ('𥳞', "lìn"),
+    ('𥳟', "jiǎn"),
+    ('𥳠', "dì"),
+    ('𥳡', "fén"),
+    ('𥳢', "diàn"),
+    ('𥳣', "zuì"),
+    ('𥳥', "nǐng"),
+    ('𥳪', "suàn"),
+    ('𥳫', "tiǎn"),
+    ('𥳬', "àn"),
+    ('𥳯', "cè"),
+    ('𥳰', "dìng"),
+    ('𥳱', "shēn"),
+    ('𥳲', "dù"),
+    ('𥳳', "tí"),
+    ('𥳴', "jiǎo"),
+    ('𥳵', "zuì"),
+    ('𥳶', "zhǎng"),
+    ('𥳷', "jiǎn"),
+    ('𥳸', "dàn"),
+    ('𥳹', "dǎn"),
+    ('𥳺', "sǒng"),
+    ('𥴐', "zhǎn"),
+    ('𥴑', "tíng"),
+    ('𥴒', "zhì"),
+    ('𥴕', "yóu"),
+    ('𥴖', "pái"),
+    ('𥴡', "lǐ"),
+    ('𥴤', "qián"),
+    ('𥴦', "suì"),
+    ('𥴧', "jǔ"),
+    ('𥴨', "ài"),
+    ('𥴩', "gé"),
+    ('𥴪', "jù"),
+    ('𥴫', "tún"),
+    ('𥴬', "bì"),
+    ('𥴭', "qià"),
+    ('𥴮', "bó"),
+    ('𥴯', "huì"),
+    ('𥴱', "jiàn"),
+    ('𥴴', "gōu"),
+    ('𥴵', "suàn"),
+    ('𥴺', "cí"),
+    ('𥴻', "qiàng"),
+    ('𥴿', "yán"),
+    ('𥵏', "diàn"),
+    ('𥵒', "miè"),
+    ('𥵜', "pò"),
+    ('𥵝', "lǐng"),
+    ('𥵞', "jié"),
+    ('𥵟', "zhù"),
+    ('𥵠', "gǔ"),
+    ('𥵣', "duān"),
+    ('𥵤', "zhào"),
+    ('𥵦', "shǎo"),
+    ('𥵧', "qǐn"),
+    ('𥵨', "mí"),
+    ('𥵪', "píng"),
+    ('𥵫', "cóng"),
+    ('𥵬', "chōu"),
+    ('𥵯', "sà"),
+    ('𥵶', "tiǎn"),
+    ('𥶅', "liú"),
+    ('𥶆', "lǘ"),
+    ('𥶇', "lǔ"),
+    ('𥶈', "zōu"),
+    ('𥶌', "lǜ"),
+    ('𥶍', "huǎn"),
+    ('𥶏', "tiáo"),
+    ('𥶐', "tuí"),
+    ('𥶑', "qiǎng"),
+    ('𥶒', "lìn"),
+    ('𥶓', "bēi"),
+    ('𥶔', "páo"),
+    ('𥶕', "zhān"),
+    ('𥶗', "lì"),
+    ('𥶛', "tí"),
+    ('𥶜', "hú"),
+    ('𥶢', "liè"),
+    ('𥶵', "huǐ"),
+    ('𥶶', "qū"),
+    ('𥶷', "xuǎn"),
+    ('𥶹', "jìng"),
+    ('𥶺', "dié"),
+    ('𥶻', "suí"),
+    ('𥶽', "wèi"),
+    ('𥶿', "yán"),
+    ('𥷀', "yān"),
+    ('𥷁', "bàn"),
+    ('𥷃', "jiǎng"),
+    ('𥷄', "nǐ"),
+    ('𥷅', "lì"),
+    ('𥷆', "hú"),
+    ('𥷇', "qì"),
+    ('𥷈', "zhōng"),
+    ('𥷑', "bì"),
+    ('𥷔', "yú"),
+    ('𥷕', "dié"),
+    ('𥷖', "lìn"),
+    ('𥷗', "lì"),
+    ('𥷘', "zhuó"),
+    ('𥷙', "jì"),
+    ('𥷚', "jū"),
+    ('𥷜', "fēng"),
+    ('𥷞', "yù"),
+    ('𥷨', "liè"),
+    ('𥷩', "zá"),
+    ('𥷪', "qián"),
+    ('𥷫', "jiē"),
+    ('𥷬', "guān"),
+    ('𥷮', "zhuó"),
+    ('𥷱', "fù"),
+    ('𥷹', "sè"),
+    ('𥷼', "cù"),
+    ('𥸃', "huǐ"),
+    ('𥸈', "dàng"),
+    ('𥸉', "lóng"),
+    ('𥸊', "yì"),
+    ('𥸗', "sǎ"),
+    ('𥸘', "yuè"),
+    ('𥸚', "dí"),
+    ('𥸡', "gǎn"),
+    ('𥸢', "zān"),
+    ('𥸣', "shàn"),
+    ('𥸤', "yù"),
+    ('𥸥', "bǒ"),
+    ('𥸧', "dìng"),
+    ('𥸨', "fán"),
+    ('𥸪', "yù"),
+    ('𥸬', "shēn"),
+    ('𥸲', "gōng"),
+    ('𥸴', "miè"),
+    ('𥸵', "tún"),
+    ('𥸸', "liè"),
+    ('𥹁', "zhā"),
+    ('𥹂', "pēi"),
+    ('𥹄', "mí"),
+    ('𥹆', "míng"),
+    ('𥹇', "fàn"),
+    ('𥹉', "nà"),
+    ('𥹊', "sì"),
+    ('𥹋', "yí"),
+    ('𥹌', "jiā"),
+    ('𥹍', "zhù"),
+    ('𥹓', "bān"),
+    ('𥹔', "yù"),
+    ('𥹖', "pǒ"),
+    ('𥹚', "huān"),
+    ('𥹛', "càn"),
+    ('𥹜', "jiāo"),
+    ('𥹠', "tán"),
+    ('𥹩', "zhì"),
+    ('𥹫', "mǐ"),
+    ('𥹬', "kǎo"),
+    ('𥹱', "yāo"),
+    ('𥹲', "duì"),
+    ('𥹳', "quǎn"),
+    ('𥹴', "bù"),
+    ('𥹵', "chù"),
+    ('𥹶', "qiǎo"),
+    ('𥹷', "liú"),
+    ('𥹸', "bó"),
+    ('𥹺', "kāng"),
+    ('𥹻', "fèn"),
+    ('𥺅', "dào"),
+    ('𥺉', "dòu"),
+    ('𥺊', "gé"),
+    ('𥺙', "líng"),
+    ('𥺚', "xí"),
+    ('𥺜', "nì"),
+    ('𥺝', "zhōu"),
+    ('𥺞', "zhōu"),
+    ('𥺣', "chōu"),
+    ('𥺴', "niān"),
+    ('𥺵', "jī"),
+    ('𥺷', "qū"),
+    ('𥻄', "kāi"),
+    ('𥻇', "xiàn"),
+    ('𥻉', "hé"),
+    ('𥻋', "lín"),
+    ('𥻍', "zī"),
+    ('𥻑', "ǒu"),
+    ('𥻒', "cù"),
+    ('𥻗', "chá"),
+    ('𥻝', "zhòng"),
+    ('𥻞', "bú"),
+    ('𥻤', "chōu"),
+    ('𥻥', "xì"),
+    ('𥻦', "sà"),
+    ('𥻧', "xián"),
+    ('𥻨', "sè"),
+    ('𥻩', "miàn"),
+    ('𥻫', "fán"),
+    ('𥻬', "zhī"),
+    ('𥻮', "cuì"),
+    ('𥻴', "xià"),
+    ('𥻾', "nuò"),
+    ('𥻿', "lí"),
+    ('𥼀', "zú"),
+    ('𥼂', "cuī"),
+    ('𥼃', "zé"),
+    ('𥼅', "lí"),
+    ('𥼘', "qí"),
+    ('𥼚', "zhuō"),
+    ('𥼛', "cuì"),
+    ('𥼜', "pū"),
+    ('𥼞', "fán"),
+    ('𥼟', "tán"),
+    ('𥼩', "zī"),
+    ('𥼪', "zǔ"),
+    ('𥼫', "zhōu"),
+    ('𥼬', "róng"),
+    ('𥼭', "lín"),
+    ('𥼮', "tán"),
+    ('𥼶', "shì"),
+    ('𥼺', "cuǐ"),
+    ('𥼻', "zī"),
+    ('𥼼', "fū"),
+    ('𥽁', "xiào"),
+    ('𥽈', "fēng"),
+    ('𥽏', "xiàn"),
+    ('𥽐', "jiàn"),
+    ('𥽒', "fèn"),
+    ('𥽗', "lì"),
+    ('𥽘', "mò"),
+    ('𥽟', "yōu"),
+    ('𥽥', "huò"),
+    ('𥽧', "qū"),
+    ('𥽬', "niàng"),
+    ('𥽰', "mí"),
+    ('𥽳', "qì"),
+    ('𥽶', "hé"),
+    ('𥽸', "liàn"),
+    ('𥽿', "zuò"),
+    ('𥾂', "líng"),
+    ('𥾅', "zhú"),
+    ('𥾇', "niǎo"),
+    ('𥾊', "jǐ"),
+    ('𥾋', "réng"),
+    ('𥾌', "jié"),
+    ('𥾍', "gǎn"),
+    ('𥾐', "yì"),
+    ('𥾓', "zhóu"),
+    ('𥾕', "wù"),
+    ('𥾚', "gěng"),
+    ('𥾛', "cù"),
+    ('𥾝', "miè"),
+    ('𥾡', "xún"),
+    ('𥾣', "zhī"),
+    ('𥾤', "xiáo"),
+    ('𥾧', "fú"),
+    ('𥾨', "hú"),
+    ('𥾬', "dī"),
+    ('𥾮', "jué"),
+    ('𥾯', "diào"),
+    ('𥾹', "shǒu"),
+    ('𥾼', "wǎng"),
+    ('𥿃', "nà"),
+    ('𥿄', "dī"),
+    ('𥿅', "shì"),
+    ('𥿆', "cí"),
+    ('𥿇', "shū"),
+    ('𥿉', "wà"),
+    ('𥿊', "chè"),
+    ('𥿋', "fán"),
+    ('𥿍', "gū"),
+    ('𥿎', "yuān"),
+    ('𥿑', "guān"),
+    ('𥿚', "qiè"),
+    ('𥿜', "zhǎn"),
+    ('𥿝', "dài"),
+    ('𥿞', "shē"),
+    ('𥿦', "zhōu"),
+    ('𥿧', "xiǎng"),
+    ('𥿨', "míng"),
+    ('𥿩', "zì"),
+    ('𥿪', "huāng"),
+    ('𥿫', "mí"),
+    ('𥿭', "xì"),
+    ('𥿮', "zhì"),
+    ('𥿯', "pài"),
+    ('𥿰', "duǒ"),
+    ('𥿴', "cì"),
+    ('𥿵', "móu"),
+    ('𥿷', "chào"),
+    ('𥿹', "yì"),
+    ('𥿺', "gōu"),
+    ('𦀇', "jīng"),
+    ('𦀓', "zēng"),
+    ('𦀔', "pīng"),
+    ('𦀕', "yè"),
+    ('𦀖', "jié"),
+    ('𦀘', "pī"),
+    ('𦀛', "shā"),
+    ('𦀜', "zhuàng"),
+    ('𦀝', "jiǒng"),
+    ('𦀠', "liú"),
+    ('𦀡', "yǔ"),
+    ('𦀣', "jū"),
+    ('𦀨', "nuò"),
+    ('𦀸', "mào"),
+    ('𦁄', "chēn"),
+    ('𦁆', "zhuàn"),
+    ('𦁇', "niàn"),
+    ('𦁈', "kòng"),
+    ('𦁉', "jiē"),
+    ('𦁊', "huà"),
+    ('𦁍', "xīn"),
+    ('𦁎', "zuó"),
+    ('𦁏', "yàn"),
+    ('𦁐', "jué"),
+    ('𦁕', "hū"),
+    ('𦁖', "zhòu"),
+    ('𦁗', "shè"),
+    ('𦁙', "yǎn"),
+    ('𦁛', "xiè"),
+    ('𦁜', "dié"),
+    ('𦁟', "chēn"),
+    ('𦁲', "jiǎn"),
+    ('𦁳', "jì"),
+    ('𦁶', "chuò"),
+    ('𦁷', "hóng"),
+    ('𦂀', "dá"),
+    ('𦂄', "kāi"),
+    ('𦂅', "xīng"),
+    ('𦂆', "huì"),
+    ('𦂇', "jiǎn"),
+    ('𦂈', "zhòu"),
+    ('𦂉', "zhǎ"),
+    ('𦂊', "fù"),
+    ('𦂋', "chì"),
+    ('𦂌', "běng"),
+    ('𦂍', "nuò"),
+    ('𦂑', "jì"),
+    ('𦂒', "qián"),
+    ('𦂔', "wàn"),
+    ('𦂕', "óu"),
+    ('𦂖', "bì"),
+    ('𦂗', "shuò"),
+    ('𦂠', "jīng"),
+    ('𦂡', "yè"),
+    ('𦃄', "fěi"),
+    ('𦃇', "lí"),
+    ('𦃊', "lì"),
+    ('𦃋', "pí"),
+    ('𦃒', "suì"),
+    ('𦃓', "liú"),
+    ('𦃔', "hé"),
+    ('𦃕', "hǔn"),
+    ('𦃖', "tǎn"),
+    ('𦃗', "shuò"),
+    ('𦃘', "zhì"),
+    ('𦃙', "bó"),
+    ('𦃝', "xì"),
+    ('𦃡', "pó"),
+    ('𦃢', "qǔn"),
+    ('𦃤', "mù"),
+    ('𦃽', "yōng"),
+    ('𦄂', "dài"),
+    ('𦄊', "qǐ"),
+    ('𦄋', "diǎo"),
+    ('𦄌', "niè"),
+    ('𦄍', "shuǎng"),
+    ('𦄏', "shāo"),
+    ('𦄐', "kǔn"),
+    ('𦄑', "suì"),
+    ('𦄓', "dōu"),
+    ('𦄔', "dié"),
+    ('𦄜', "gōng"),
+    ('𦄯', "zhuǎn"),
+    ('𦄰', "guó"),
+    ('𦄼', "xū"),
+    ('𦄽', "qú"),
+    ('𦅀', "xún"),
+    ('𦅃', "jiāo"),
+    ('𦅄', "zhé"),
+    ('𦅆', "diàn"),
+    ('𦅇', "sāng"),
+    ('𦅈', "bēng"),
+    ('𦅊', "suǒ"),
+    ('𦅋', "qiǎn"),
+    ('𦅏', "xū"),
+    ('𦅑', "xún"),
+    ('𦅔', "mò"),
+    ('𦅵', "suì"),
+    ('𦅶', "là"),
+    ('𦅷', "zhǔ"),
+    ('𦅸', "zhòu"),
+    ('𦅺', "lì"),
+    ('𦅼', "dān"),
+    ('𦅽', "jú"),
+    ('𦅿', "yùn"),
+    ('𦆀', "chǎn"),
+    ('𦆁', "luó"),
+    ('𦆄', "sè"),
+    ('𦆆', "lián"),
+    ('𦆈', "zuǎn"),
+    ('𦆋', "lài"),
+    ('𦆌', "shuǎng"),
+    ('𦆍', "qiè"),
+    ('𦆘', "dōu"),
+    ('𦆞', "wù"),
+    ('𦆟', "méng"),
+    ('𦆡', "jì"),
+    ('𦆤', "chī"),
+    ('𦆦', "nǐ"),
+    ('𦆸', "yáo"),
+    ('𦆻', "là"),
+    ('𦆾', "lǜ"),
+    ('𦇀', "suì"),
+    ('𦇁', "fū"),
+    ('𦇄', "lěi"),
+    ('𦇅', "wěi"),
+    ('𦇎', "cōng"),
+    ('𦇔', "lì"),
+    ('𦇖', "pín"),
+    ('𦇘', "jūn"),
+    ('𦇙', "jǔ"),
+    ('𦇛', "là"),
+    ('𦇧', "jì"),
+    ('𦇪', "miè"),
+    ('𦇬', "yào"),
+    ('𦇭', "biān"),
+    ('𦇱', "cóng"),
+    ('𦇲', "sī"),
+    ('𦇵', "sī"),
+    ('𦇸', "hé"),
+    ('𦈃', "nàng"),
+    ('𦈅', "dié"),
+    ('𦈈', "chè"),
+    ('𦈉', "yùn"),
+    ('𦈋', "xiǔ"),
+    ('𦈌', "shū"),
+    ('𦈎', "chǎn"),
+    ('𦈏', "mín"),
+    ('𦈐', "lián"),
+    ('𦈑', "yīn"),
+    ('𦈒', "xīng"),
+    ('𦈓', "wēi"),
+    ('𦈔', "gǔ"),
+    ('𦈕', "tóu"),
+    ('𦈖', "tā"),
+    ('𦈗', "fěi"),
+    ('𦈘', "dā"),
+    ('𦈙', "niè"),
+    ('𦈚', "cù"),
+    ('𦈛', "zuǒ"),
+    ('𦈜', "jié"),
+    ('𦈝', "xuàn"),
+    ('𦈞', "bó"),
+    ('𦈟', "jīn"),
+    ('𦈠', "yǐn"),
+    ('𦈡', "xū"),
+    ('𦈣', "yú"),
+    ('𦈤', "xiòng"),
+    ('𦈦', "qì"),
+    ('𦈧', "bēi"),
+    ('𦈨', "xíng"),
+    ('𦈩', "gǒng"),
+    ('𦈬', "zuǐ"),
+    ('𦈰', "jiē"),
+    ('𦈲', "kāi"),
+    ('𦈵', "xíng"),
+    ('𦈶', "bēi"),
+    ('𦈷', "shū"),
+    ('𦈸', "yù"),
+    ('𦈺', "zhǒu"),
+    ('𦈻', "zhǎn"),
+    ('𦉂', "zhōng"),
+    ('𦉆', "chá"),
+    ('𦉈', "chuí"),
+    ('𦉉', "liù"),
+    ('𦉎', "suī"),
+    ('𦉐', "zhǔ"),
+    ('𦉙', "biàn"),
+    ('𦉝', "xìn"),
+    ('𦉟', "yà"),
+    ('𦉢', "líng"),
+    ('𦉧', "yà"),
+    ('𦉬', "tīng"),
+    ('𦉹', "dí"),
+    ('𦊁', "pí"),
+    ('𦊂', "hù"),
+    ('𦊃', "cén"),
+    ('𦊊', "tiān"),
+    ('𦊋', "mǒu"),
+    ('𦊌', "juǎn"),
+    ('𦊎', "mǒu"),
+    ('𦊐', "jù"),
+    ('𦊑', "liǔ"),
+    ('𦊓', "lǐng"),
+    ('𦊗', "liǔ"),
+    ('𦊘', "hù"),
+    ('𦊦', "fú"),
+    ('𦊧', "hú"),
+    ('𦊪', "è"),
+    ('𦊫', "gōng"),
+    ('𦊬', "gū"),
+    ('𦊱', "guà"),
+    ('𦊹', "lüè"),
+    ('𦊻', "fán"),
+    ('𦊼', "lǜ"),
+    ('𦊽', "méng"),
+    ('𦊾', "fú"),
+    ('𦊿', "liú"),
+    ('𦋅', "xié"),
+    ('𦋆', "gū"),
+    ('𦋈', "xiàn"),
+    ('𦋉', "bó"),
+    ('𦋋', "jì"),
+    ('𦋓', "quān"),
+    ('𦋔', "lù"),
+    ('𦋞', "shuò"),
+    ('𦋡', "mǒu"),
+    ('𦋢', "yù"),
+    ('𦋣', "hàn"),
+    ('𦋩', "yuè"),
+    ('𦋪', "dàn"),
+    ('𦋯', "yú"),
+    ('𦋰', "jiān"),
+    ('𦋳', "gāng"),
+    ('𦋿', "cáo"),
+    ('𦌀', "shèn"),
+    ('𦌁', "liǔ"),
+    ('𦌆', "jiāo"),
+    ('𦌉', "sù"),
+    ('𦌊', "sù"),
+    ('𦌋', "zhòng"),
+    ('𦌒', "liào"),
+    ('𦌔', "xuǎn"),
+    ('𦌕', "lù"),
+    ('𦌗', "jì"),
+    ('𦌚', "yán"),
+    ('𦌟', "lù"),
+    ('𦌡', "mǐn"),
+    ('𦌢', "tí"),
+    ('𦌦', "huàn"),
+    ('𦌩', "yì"),
+    ('𦌪', "tǎn"),
+    ('𦌬', "wǔ"),
+    ('𦌰', "jī"),
+    ('𦌷', "dú"),
+    ('𦌸', "kūn"),
+    ('𦌺', "jūn"),
+    ('𦌿', "shī"),
+    ('𦍀', "nàn"),
+    ('𦍁', "pò"),
+    ('𦍄', "shū"),
+    ('𦍅', "quàn"),
+    ('𦍌', "rèn"),
+    ('𦍏', "fén"),
+    ('𦍒', "tà"),
+    ('𦍓', "tún"),
+    ('𦍕', "yáng"),
+    ('𦍠', "lì"),
+    ('𦍦', "duō"),
+    ('𦍧', "cī"),
+    ('𦍩', "gǔ"),
+    ('𦍪', "fén"),
+    ('𦍭', "róu"),
+    ('𦍱', "gāo"),
+    ('𦍲', "xiáng"),
+    ('𦍴', "xiáng"),
+    ('𦍵', "hǒu"),
+    ('𦍷', "tāo"),
+    ('𦍸', "shàn"),
+    ('𦍹', "yáng"),
+    ('𦍺', "zì"),
+    ('𦍼', "yuán"),
+    ('𦎄', "sú"),
+    ('𦎇', "chuàn"),
+    ('𦎈', "xiáng"),
+    ('𦎊', "bān"),
+    ('𦎌', "mǎn"),
+    ('𦎎', "fǔ"),
+    ('𦎏', "lǎ"),
+    ('𦎐', "lǐ"),
+    ('𦎒', "jié"),
+    ('𦎓', "yōu"),
+    ('𦎘', "yù"),
+    ('𦎚', "chì"),
+    ('𦎜', "chuàn"),
+    ('𦎝', "yì"),
+    ('𦎞', "shān"),
+    ('𦎢', "jí"),
+    ('𦎣', "yān"),
+    ('𦎦', "wù"),
+    ('𦎧', "chún"),
+    ('𦎨', "máng"),
+    ('𦎭', "fú"),
+    ('𦎮', "jiā"),
+    ('𦎯', "gòu"),
+    ('𦎰', "gú"),
+    ('𦎱', "jiá"),
+    ('𦎵', "xián"),
+    ('𦎷', "jìn"),
+    ('𦎸', "zì"),
+    ('𦎹', "lóu"),
+    ('𦎼', "gòu"),
+    ('𦏀', "rén"),
+    ('𦏂', "shān"),
+    ('𦏅', "jué"),
+    ('𦏆', "tóng"),
+    ('𦏇', "yǒu"),
+    ('𦏔', "jiān"),
+    ('𦏕', "dú"),
+    ('𦏗', "hú"),
+    ('𦏛', "sāo"),
+    ('𦏜', "yù"),
+    ('𦏢', "mài"),
+    ('𦏤', "zhī"),
+    ('𦏥', "yān"),
+    ('𦏦', "gāo"),
+    ('𦏨', "huài"),
+    ('𦏮', "quán"),
+    ('𦏱', "yǎng"),
+    ('𦏳', "zuǐ"),
+    ('𦏷', "xiāo"),
+    ('𦏸', "yì"),
+    ('𦏹', "yǎn"),
+    ('𦏺', "hóng"),
+    ('𦏻', "yú"),
+    ('𦏿', "chì"),
+    ('𦐁', "chí"),
+    ('𦐄', "háng"),
+    ('𦐅', "sè"),
+    ('𦐆', "pā"),
+    ('𦐇', "tà"),
+    ('𦐈', "fēn"),
+    ('𦐉', "chī"),
+    ('𦐌', "hóng"),
+    ('𦐍', "xuè"),
+    ('𦐖', "zhǐ"),
+    ('𦐛', "qú"),
+    ('𦐠', "xī"),
+    ('𦐡', "fú"),
+    ('𦐣', "shū"),
+    ('𦐤', "hài"),
+    ('𦐦', "pò"),
+    ('𦐨', "cǐ"),
+    ('𦐰', "chài"),
+    ('𦐳', "hōng"),
+    ('𦐸', "pǎo"),
+    ('𦐹', "shēn"),
+    ('𦐺', "xiāo"),
+    ('𦐽', "xuān"),
+    ('𦐾', "cǐ"),
+    ('𦐿', "tíng"),
+    ('𦑀', "pò"),
+    ('𦑇', "tà"),
+    ('𦑈', "chā"),
+    ('𦑋', "zú"),
+    ('𦑌', "huò"),
+    ('𦑍', "xù"),
+    ('𦑎', "yàn"),
+    ('𦑏', "chài"),
+    ('𦑑', "tuó"),
+    ('𦑘', "xián"),
+    ('𦑙', "xuān"),
+    ('𦑚', "hóu"),
+    ('𦑛', "huǎn"),
+    ('𦑜', "gé"),
+    ('𦑝', "chǒng"),
+    ('𦑞', "bì"),
+    ('𦑟', "hōng"),
+    ('𦑠', "hōng"),
+    ('𦑡', "chí"),
+    ('𦑣', "chá"),
+    ('𦑯', "zhǎ"),
+    ('𦑱', "zhái"),
+    ('𦑲', "tà"),
+    ('𦑵', "pò"),
+    ('𦑶', "tà"),
+    ('𦑸', "yóu"),
+    ('𦑹', "fú"),
+    ('𦑺', "cī"),
+    ('𦑻', "dá"),
+    ('𦑼', "tǎ"),
+    ('𦑾', "liú"),
+    ('𦒁', "cī"),
+    ('𦒃', "hōng"),
+    ('𦒅', "hàn"),
+    ('𦒆', "lā"),
+    ('𦒈', "shī"),
+    ('𦒍', "tóng"),
+    ('𦒎', "huì"),
+    ('𦒏', "hé"),
+    ('𦒐', "piē"),
+    ('𦒑', "yù"),
+    ('𦒜', "xiān"),
+    ('𦒝', "hǎn"),
+    ('𦒟', "pò"),
+    ('𦒦', "là"),
+    ('𦒧', "huò"),
+    ('𦒰', "tài"),
+    ('𦒴', "lǎo"),
+    ('𦒶', "shù"),
+    ('𦒺', "dào"),
+    ('𦒻', "diǎn"),
+    ('𦓈', "xiòng"),
+    ('𦓋', "wàng"),
+    ('𦓍', "chě"),
+    ('𦓎', "nài"),
+    ('𦓐', "jué"),
+    ('𦓓', "ér"),
+    ('𦓔', "ér"),
+    ('𦓕', "nǘ"),
+    ('𦓖', "nǜ"),
+    ('𦓝', "zhuǎn"),
+    ('𦓢', "nuò"),
+    ('𦓤', "liè"),
+    ('𦓥', "lěi"),
+    ('𦓧', "bā"),
+    ('𦓬', "chēng"),
+    ('𦓯', "guī"),
+    ('𦓰', "quán"),
+    ('𦓱', "gè"),
+    ('𦓳', "gǒng"),
+    ('𦓴', "shào"),
+    ('𦓹', "lái"),
+    ('𦓺', "zhēng"),
+    ('𦓻', "yì"),
+    ('𦓼', "gǔn"),
+    ('𦓽', "wēi"),
+    ('𦓾', "lǔn"),
+    ('𦔂', "shí"),
+    ('𦔃', "yīng"),
+    ('𦔄', "shěng"),
+    ('𦔅', "tú"),
+    ('𦔆', "bì"),
+    ('𦔈', "zé"),
+    ('𦔉', "zhòng"),
+    ('𦔋', "rǒng"),
+    ('𦔌', "qí"),
+    ('𦔍', "fù"),
+    ('𦔎', "cè"),
+    ('𦔓', "lí"),
+    ('𦔔', "mán"),
+    ('𦔖', "lián"),
+    ('𦔗', "biāo"),
+    ('𦔛', "chuáng"),
+    ('𦔜', "yì"),
+    ('𦔠', "pài"),
+    ('𦔥', "yì"),
+    ('𦔦', "kuài"),
+    ('𦔩', "biāo"),
+    ('𦔫', "chì"),
+    ('𦔬', "qú"),
+    ('𦔭', "mò"),
+    ('𦔮', "zhé"),
+    ('𦔯', "shà"),
+    ('𦔰', "shà"),
+    ('𦔷', "yāo"),
+    ('𦔸', "gōng"),
+    ('𦔹', "nài"),
+    ('𦔼', "xiè"),
+    ('𦔿', "tiàn"),
+    ('𦕆', "yé"),
+    ('𦕉', "shā"),
+    ('𦕏', "sào"),
+    ('𦕒', "diān"),
+    ('𦕓', "xù"),
+    ('𦕙', "qú"),
+    ('𦕠', "hōng"),
+    ('𦕡', "shèng"),
+    ('𦕢', "tìng"),
+    ('𦕰', "duo"),
+    ('𦕵', "liáo"),
+    ('𦕷', "hòng"),
+    ('𦕸', "lǐ"),
+    ('𦕺', "xiǎng"),
+    ('𦕽', "shèn"),
+    ('𦖀', "fū"),
+    ('𦖈', "yǎn"),
+    ('𦖉', "wǎng"),
+    ('𦖊', "qī"),
+    ('𦖋', "duǒ"),
+    ('𦖍', "huà"),
+    ('𦖎', "qiān"),
+    ('𦖐', "xiè"),
+    ('𦖝', "cì"),
+    ('𦖞', "shēng"),
+    ('𦖢', "èr"),
+    ('𦖤', "xīng"),
+    ('𦖦', "tuì"),
+    ('𦖧', "yàn"),
+    ('𦖩', "liè"),
+    ('𦖬', "mí"),
+    ('𦖸', "zòng"),
+    ('𦖺', "zī"),
+    ('𦖼', "hú"),
+    ('𦖽', "yíng"),
+    ('𦖾', "lián"),
+    ('𦖿', "dā"),
+    ('𦗀', "tián"),
+    ('𦗁', "tiàn"),
+    ('𦗋', "róng"),
+    ('𦗍', "ài"),
+    ('𦗐', "ài"),
+    ('𦗑', "zhé"),
+    ('𦗒', "guō"),
+    ('𦗓', "lù"),
+    ('𦗔', "zhāo"),
+    ('𦗕', "mí"),
+    ('𦗖', "liáo"),
+    ('𦗗', "zhé"),
+    ('𦗛', "qǔ"),
+    ('𦗜', "cōng"),
+    ('𦗟', "tīng"),
+    ('𦗡', "tán"),
+    ('𦗢', "zhǎn"),
+    ('𦗣', "hú"),
+    ('𦗥', "piē"),
+    ('𦗧', "dā"),
+    ('𦗨', "róng"),
+    ('𦗮', "nǎo"),
+    ('𦗳', "náng"),
+    ('𦗴', "dāng"),
+    ('𦗵', "jiǎo"),
+    ('𦗻', "jù"),
+    ('𦗼', "ěr"),
+    ('𦘊', "lì"),
+    ('𦘌', "guō"),
+    ('𦘍', "wài"),
+    ('𦘒', "niè"),
+    ('𦘔', "jīn"),
+    ('𦘩', "pǐ"),
+    ('𦘪', "chì"),
+    ('𦘲', "pǐ"),
+    ('𦘳', "yì"),
+    ('𦘴', "dū"),
+    ('𦘵', "wǎ"),
+    ('𦘶', "xūn"),
+    ('𦘸', "qì"),
+    ('𦘹', "shàn"),
+    ('𦘼', "xū"),
+    ('𦘿', "hē"),
+    ('𦙀', "pàn"),
+    ('𦙂', "pēi"),
+    ('𦙄', "xiōng"),
+    ('𦙆', "chǐ"),
+    ('𦙇', "tān"),
+    ('𦙈', "zuì"),
+    ('𦙉', "zuǎn"),
+    ('𦙊', "qì"),
+    ('𦙋', "dū"),
+    ('𦙙', "shuǐ"),
+    ('𦙜', "nǎ"),
+    ('𦙝', "xī"),
+    ('𦙧', "chǎo"),
+    ('𦙨', "yì"),
+    ('𦙫', "zhēng"),
+    ('𦙮', "jú"),
+    ('𦙯', "dài"),
+    ('𦙱', "sān"),
+    ('𦙴', "zhù"),
+    ('𦙵', "wàn"),
+    ('𦙶', "gǔ"),
+    ('𦙸', "sān"),
+    ('𦙹', "bàn"),
+    ('𦙺', "jià"),
+    ('𦙻', "mài"),
+    ('𦚈', "tuò"),
+    ('𦚊', "qì"),
+    ('𦚏', "zhuāng"),
+    ('𦚐', "tuó"),
+    ('𦚓', "píng"),
+    ('𦚝', "pēng"),
+    ('𦚞', "kuāng"),
+    ('𦚟', "yí"),
+    ('𦚡', "xiè"),
+    ('𦚢', "yuē"),
+    ('𦚣', "hén"),
+    ('𦚥', "hóu"),
+    ('𦚦', "zhēng"),
+    ('𦚧', "chǔn"),
+    ('𦚨', "shì"),
+    ('𦚩', "wǎ"),
+    ('𦚫', "xié"),
+    ('𦚸', "gèng"),
+    ('𦛅', "è"),
+    ('𦛏', "kú"),
+    ('𦛐', "nà"),
+    ('𦛓', "jū"),
+    ('𦛔', "xuàn"),
+    ('𦛕', "qū"),
+    ('𦛖', "chè"),
+    ('𦛗', "lǚ"),
+    ('𦛘', "hé"),
+    ('𦛙', "shèng"),
+    ('𦛚', "nàn"),
+    ('𦛜', "hé"),
+    ('𦛝', "chá"),
+    ('𦛞', "yān"),
+    ('𦛟', "gěng"),
+    ('𦛠', "niè"),
+    ('𦛢', "guó"),
+    ('𦛣', "yán"),
+    ('𦛤', "guǎn"),
+    ('𦛧', "zhì"),
+    ('𦛨', "lao"),
+    ('𦛯', "dǔ"),
+    ('𦛰', "qì"),
+    ('𦛱', "qū"),
+    ('𦛲', "jué"),
+    ('𦜁', "fēng"),
+    ('𦜃', "xù"),
+    ('𦜄', "tuì"),
+    ('𦜆', "hán"),
+    ('𦜇', "kū"),
+    ('𦜊', "shēn"),
+    ('𦜋', "zhì"),
+    ('𦜍', "pàng"),
+    ('𦜎', "zhēng"),
+    ('𦜏', "lì"),
+    ('𦜐', "wǎn"),
+    ('𦜒', "fǎn"),
+    ('𦜓', "xìn"),
+    ('𦜖', "yà"),
+    ('𦜛', "jū"),
+    ('𦜜', "shèn"),
+    ('𦜭', "mǎng"),
+    ('𦜯', "tǔn"),
+    ('𦜰', "zhuó"),
+    ('𦜱', "xī"),
+    ('𦜲', "yìn"),
+    ('𦜳', "jīng"),
+    ('𦜴', "tún"),
+    ('𦜷', "gèng"),
+    ('𦜸', "jì"),
+    ('𦝏', "zhuǎn"),
+    ('𦝒', "tiē"),
+    ('𦝔', "zhī"),
+    ('𦝖', "jí"),
+    ('𦝚', "yíng"),
+    ('𦝛', "wèi"),
+    ('𦝝', "huàn"),
+    ('𦝞', "tíng"),
+    ('𦝟', "chán"),
+    ('𦝢', "kuí"),
+    ('𦝣', "qià"),
+    ('𦝤', "bàn"),
+    ('𦝥', "chā"),
+    ('𦝦', "tuǒ"),
+    ('𦝧', "nǎn"),
+    ('𦝨', "jiē"),
+    ('𦝪', "yān"),
+    ('𦝬', "tú"),
+    ('𦝮', "wěn"),
+    ('𦝰', "cōng"),
+    ('𦝳', "xù"),
+    ('𦝴', "yìn"),
+    ('𦝷', "bèng"),
+    ('𦝼', "lǘ"),
+    ('𦞁', "zāi"),
+    ('𦞂', "dā"),
+    ('𦞆', "niè"),
+    ('𦞇', "jǔ"),
+    ('𦞈', "hóu"),
+    ('𦞌', "gèng"),
+    ('𦞕', "hóu"),
+    ('𦞖', "kān"),
+    ('𦞗', "gōng"),
+    ('𦞙', "huǐ"),
+    ('𦞚', "xiè"),
+    ('𦞝', "xì"),
+    ('𦞞', "hán"),
+    ('𦞟', "mí"),
+    ('𦞡', "wěng"),
+    ('𦞢', "hùn"),
+    ('𦞣', "sāo"),
+    ('𦞤', "xìn"),
+    ('𦞥', "zhé"),
+    ('𦞦', "huò"),
+    ('𦞨', "gōng"),
+    ('𦞫', "sài"),
+    ('𦞬', "jīn"),
+    ('𦞭', "wā"),
+    ('𦞱', "duǐ"),
+    ('𦞲', "chī"),
+    ('𦞽', "xī"),
+    ('𦟂', "mí"),
+    ('𦟃', "zāng"),
+    ('𦟄', "sǎng"),
+    ('𦟓', "tún"),
+    ('𦟔', "zhì"),
+    ('𦟕', "wěn"),
+    ('𦟘', "yín"),
+    ('𦟙', "tǔn"),
+    ('𦟛', "chōng"),
+    ('𦟜', "zé"),
+    ('𦟞', "xiāo"),
+    ('𦟟', "mó"),
+    ('𦟠', "cù"),
+    ('𦟣', "biǎn"),
+    ('𦟤', "xiū"),
+    ('𦟧', "yí"),
+    ('𦟮', "huǎng"),
+    ('𦟰', "zhā"),
+    ('𦟱', "suō"),
+    ('𦟲', "hún"),
+    ('𦟳', "jù"),
+    ('𦠁', "cù"),
+    ('𦠄', "jī"),
+    ('𦠅', "xún"),
+    ('𦠆', "sǔn"),
+    ('𦠇', "céng"),
+    ('𦠉', "yì"),
+    ('𦠎', "biāo"),
+    ('𦠒', "jué"),
+    ('𦠓', "lì"),
+    ('𦠖', "pào"),
+    ('𦠛', "zā"),
+    ('𦠜', "yè"),
+    ('𦠞', "bì"),
+    ('𦠟', "zhè"),
+    ('𦠠', "zhè"),
+    ('𦠢', "jiù"),
+    ('𦠣', "zhé"),
+    ('𦠦', "shù"),
+    ('𦠪', "xī"),
+    ('𦠷', "xǔ"),
+    ('𦠸', "nǎi"),
+    ('𦠹', "xián"),
+    ('𦠺', "gǔn"),
+    ('𦠻', "wèi"),
+    ('𦠾', "jí"),
+    ('𦠿', "sà"),
+    ('𦡂', "dǒng"),
+    ('𦡃', "nuó"),
+    ('𦡄', "dù"),
+    ('𦡅', "zhēng"),
+    ('𦡆', "kū"),
+    ('𦡉', "míng"),
+    ('𦡕', "báo"),
+    ('𦡖', "huì"),
+    ('𦡙', "zōng"),
+    ('𦡨', "sàn"),
+    ('𦡪', "tēng"),
+    ('𦡫', "yí"),
+    ('𦡭', "yù"),
+    ('𦡱', "yào"),
+    ('𦡲', "nǐng"),
+    ('𦡴', "chóu"),
+    ('𦡵', "hùn"),
+    ('𦡷', "duì"),
+    ('𦡹', "qì"),
+    ('𦡺', "yǐng"),
+    ('𦡻', "bìng"),
+    ('𦡼', "níng"),
+    ('𦡽', "huáng"),
+    ('𦢆', "yǐng"),
+    ('𦢊', "báo"),
+    ('𦢎', "guàng"),
+    ('𦢏', "lěi"),
+    ('𦢐', "zǔn"),
+    ('𦢙', "chǎn"),
+    ('𦢣', "jiǎn"),
+    ('𦢧', "méng"),
+    ('𦢩', "xiào"),
+    ('𦢯', "xìn"),
+    ('𦢱', "lí"),
+    ('𦢺', "qiǎo"),
+    ('𦢿', "wěi"),
+    ('𦣀', "nà"),
+    ('𦣂', "pāng"),
+    ('𦣄', "léi"),
+    ('𦣇', "luó"),
+    ('𦣋', "luán"),
+    ('𦣍', "gēng"),
+    ('𦣏', "luán"),
+    ('𦣒', "qú"),
+    ('𦣖', "luó"),
+    ('𦣘', "náng"),
+    ('𦣛', "luó"),
+    ('𦣜', "yuè"),
+    ('𦣢', "shuì"),
+    ('𦣥', "mì"),
+    ('𦣦', "wáng"),
+    ('𦣧', "cè"),
+    ('𦣨', "jiān"),
+    ('𦣩', "wǎng"),
+    ('𦣯', "jiā"),
+    ('𦣴', "huán"),
+    ('𦣸', "liàn"),
+    ('𦣹', "zì"),
+    ('𦣺', "bái"),
+    ('𦣻', "shǒu"),
+    ('𦣾', "wǎn"),
+    ('𦤂', "shū"),
+    ('𦤇', "guī"),
+    ('𦤈', "xī"),
+    ('𦤊', "rú"),
+    ('𦤋', "yào"),
+    ('𦤎', "gāo"),
+    ('𦤕', "yuè"),
+    ('𦤘', "yōng"),
+    ('𦤙', "wà"),
+    ('𦤚', "bó"),
+    ('𦤟', "xìn"),
+    ('𦤢', "pì"),
+    ('𦤣', "bó"),
+    ('𦤦', "hài"),
+    ('𦤧', "zhài"),
+    ('𦤨', "wò"),
+    ('𦤪', "yè"),
+    ('𦤫', "bì"),
+    ('𦤬', "hài"),
+    ('𦤸', "chì"),
+    ('𦤻', "zhì"),
+    ('𦤽', "ní"),
+    ('𦥁', "wú"),
+    ('𦥂', "ǎi"),
+    ('𦥈', "ǎi"),
+    ('𦥉', "yǔ"),
+    ('𦥊', "chì"),
+    ('𦥍', "jìng"),
+    ('𦥎', "zhì"),
+    ('𦥏', "zhì"),
+    ('𦥐', "zhì"),
+    ('𦥑', "jú"),
+    ('𦥖', "hán"),
+    ('𦥚', "pīng"),
+    ('𦥝', "yǎo"),
+    ('𦥣', "yóu"),
+    ('𦥤', "pīng"),
+    ('𦥦', "mò"),
+    ('𦥬', "zuò"),
+    ('𦥭', "pò"),
+    ('𦥯', "xué"),
+    ('𦥰', "kuáng"),
+    ('𦥱', "yì"),
+    ('𦥲', "pò"),
+    ('𦥻', "zhuì"),
+    ('𦦃', "ní"),
+    ('𦦄', "qiǔ"),
+    ('𦦅', "còu"),
+    ('𦦌', "yǎo"),
+    ('𦦑', "fén"),
+    ('𦦕', "xiá"),
+    ('𦦗', "jiāng"),
+    ('𦦘', "chā"),
+    ('𦦛', "xiào"),
+    ('𦦜', "chā"),
+    ('𦦢', "chéng"),
+    ('𦦣', "cuì"),
+    ('𦦧', "qióng"),
+    ('𦦩', "yù"),
+    ('𦦫', "yú"),
+    ('𦦯', "wèn"),
+    ('𦦱', "chā"),
+    ('𦦲', "yǔ"),
+    ('𦦹', "zuó"),
+    ('𦦺', "dǎo"),
+    ('𦦽', "juàn"),
+    ('𦦾', "dǎo"),
+    ('𦦿', "yīng"),
+    ('𦧁', "fěng"),
+    ('𦧅', "wèng"),
+    ('𦧈', "jìn"),
+    ('𦧉', "qì"),
+    ('𦧋', "qìn"),
+    ('𦧍', "kuò"),
+    ('𦧏', "tān"),
+    ('𦧐', "xiān"),
+    ('𦧒', "tiān"),
+    ('𦧔', "kuò"),
+    ('𦧖', "tiàn"),
+    ('𦧘', "hú"),
+    ('𦧙', "zhū"),
+    ('𦧚', "zhān"),
+    ('𦧛', "tà"),
+    ('𦧝', "tiān"),
+    ('𦧞', "tà"),
+    ('𦧟', "tà"),
+    ('𦧠', "huá"),
+    ('𦧡', "yǎn"),
+    ('𦧢', "tiè"),
+    ('𦧤', "tiè"),
+    ('𦧥', "tà"),
+    ('𦧬', "huài"),
+    ('𦧮', "jiá"),
+    ('𦧯', "qì"),
+    ('𦧱', "tà"),
+    ('𦧴', "tān"),
+    ('𦧵', "huà"),
+    ('𦧸', "zhuàn"),
+    ('𦧹', "huā"),
+    ('𦧼', "lán"),
+    ('𦨆', "zūn"),
+    ('𦨇', "yì"),
+    ('𦨈', "fú"),
+    ('𦨉', "wù"),
+    ('𦨋', "fú"),
+    ('𦨍', "dīng"),
+    ('𦨎', "tà"),
+    ('𦨖', "chào"),
+    ('𦨙', "rì"),
+    ('𦨚', "quǎn"),
+    ('𦨜', "gē"),
+    ('𦨡', "fú"),
+    ('𦨢', "dì"),
+    ('𦨣', "diāo"),
+    ('𦨤', "yǒng"),
+    ('𦨦', "jià"),
+    ('𦨩', "lóng"),
+    ('𦨬', "yǒng"),
+    ('𦨭', "pí"),
+    ('𦨯', "huó"),
+    ('𦨰', "qióng"),
+    ('𦨲', "fán"),
+    ('𦨳', "wú"),
+    ('𦨴', "tóng"),
+    ('𦨵', "háng"),
+    ('𦨸', "tān"),
+    ('𦨾', "hēng"),
+    ('𦩄', "tiāo"),
+    ('𦩈', "zhōu"),
+    ('𦩋', "bài"),
+    ('𦩌', "xiè"),
+    ('𦩍', "dāo"),
+    ('𦩏', "jīn"),
+    ('𦩕', "hū"),
+    ('𦩖', "bēi"),
+    ('𦩘', "dìng"),
+    ('𦩜', "nuó"),
+    ('𦩝', "wèi"),
+    ('𦩞', "yú"),
+    ('𦩠', "xīng"),
+    ('𦩡', "fú"),
+    ('𦩢', "xiàn"),
+    ('𦩣', "qì"),
+    ('𦩤', "tū"),
+    ('𦩧', "jí"),
+    ('𦩩', "yìng"),
+    ('𦩫', "dèng"),
+    ('𦩬', "wēi"),
+    ('𦩭', "xī"),
+    ('𦩯', "pái"),
+    ('𦩱', "shéng"),
+    ('𦩲', "yǒu"),
+    ('𦩴', "ái"),
+    ('𦩵', "jiàn"),
+    ('𦩷', "gōu"),
+    ('𦩸', "ruò"),
+    ('𦩼', "gòng"),
+    ('𦩿', "shà"),
+    ('𦪀', "táng"),
+    ('𦪇', "lù"),
+    ('𦪈', "áo"),
+    ('𦪊', "qì"),
+    ('𦪋', "xiū"),
+    ('𦪍', "dāi"),
+    ('𦪑', "fá"),
+    ('𦪒', "wèi"),
+    ('𦪔', "dùn"),
+    ('𦪕', "liáo"),
+    ('𦪖', "fān"),
+    ('𦪗', "huáng"),
+    ('𦪘', "jué"),
+    ('𦪙', "tà"),
+    ('𦪚', "zùn"),
+    ('𦪛', "ráo"),
+    ('𦪜', "cān"),
+    ('𦪝', "téng"),
+    ('𦪠', "huà"),
+    ('𦪡', "xū"),
+    ('𦪣', "zhān"),
+    ('𦪧', "gǎn"),
+    ('𦪪', "péng"),
+    ('𦪫', "cān"),
+    ('𦪬', "xiē"),
+    ('𦪭', "dá"),
+    ('𦪱', "jì"),
+    ('𦪶', "lǐ"),
+    ('𦪹', "pán"),
+    ('𦪽', "lóng"),
+    ('𦪾', "lì"),
+    ('𦪿', "xí"),
+    ('𦫀', "téng"),
+    ('𦫃', "líng"),
+    ('𦫈', "lǐ"),
+    ('𦫉', "rán"),
+    ('𦫊', "líng"),
+    ('𦫎', "gǔn"),
+    ('𦫔', "pō"),
+    ('𦫕', "mò"),
+    ('𦫖', "pāi"),
+    ('𦫙', "bà"),
+    ('𦫡', "qí"),
+    ('𦫤', "yán"),
+    ('𦫪', "wà"),
+    ('𦫫', "ǎng"),
+    ('𦫭', "mìng"),
+    ('𦫮', "mǐn"),
+    ('𦫯', "xùn"),
+    ('𦫰', "méng"),
+    ('𦫳', "guǎi"),
+    ('𦫶', "jiāo"),
+    ('𦫻', "gǎi"),
+    ('𦬁', "cái"),
+    ('𦬂', "wù"),
+    ('𦬃', "zhé"),
+    ('𦬄', "rěn"),
+    ('𦬅', "kōu"),
+    ('𦬔', "zhǎo"),
+    ('𦬕', "zhōng"),
+    ('𦬖', "qiú"),
+    ('𦬗', "guō"),
+    ('𦬘', "gōng"),
+    ('𦬙', "pū"),
+    ('𦬚', "hù"),
+    ('𦬛', "miǎn"),
+    ('𦬞', "tiān"),
+    ('𦬣', "wǎng"),
+    ('𦬸', "zhú"),
+    ('𦬹', "dá"),
+    ('𦬺', "xiòng"),
+    ('𦬻', "ná"),
+    ('𦬾', "juān"),
+    ('𦭁', "niǎn"),
+    ('𦭈', "hù"),
+    ('𦭉', "shā"),
+    ('𦭜', "zhī"),
+    ('𦭟', "tā"),
+    ('𦭡', "sī"),
+    ('𦭥', "yì"),
+    ('𦭭', "qióng"),
+    ('𦭮', "zhì"),
+    ('𦭯', "lǚ"),
+    ('𦭰', "rú"),
+    ('𦭲', "qí"),
+    ('𦭳', "yǔ"),
+    ('𦭴', "zhōu"),
+    ('𦭵', "yáng"),
+    ('𦭶', "xiǎn"),
+    ('𦭷', "móu"),
+    ('𦭸', "chóu"),
+    ('𦭹', "huī"),
+    ('𦭺', "jiū"),
+    ('𦭻', "jiù"),
+    ('𦭼', "piǎo"),
+    ('𦮁', "jiào"),
+    ('𦮃', "guāi"),
+    ('𦮅', "mò"),
+    ('𦮐', "xī"),
+    ('𦮑', "pú"),
+    ('𦮯', "jì"),
+    ('𦮶', "wěn"),
+    ('𦮷', "bèi"),
+    ('𦮸', "yǐ"),
+    ('𦮹', "fú"),
+    ('𦮺', "sī"),
+    ('𦮻', "juān"),
+    ('𦮼', "jì"),
+    ('𦮾', "nì"),
+    ('𦯀', "bèn"),
+    ('𦯅', "xù"),
+    ('𦯈', "qǐn"),
+    ('𦯉', "bó"),
+    ('𦯌', "wáng"),
+    ('𦯍', "zhè"),
+    ('𦯏', "wò"),
+    ('𦯐', "sháo"),
+    ('𦯑', "zào"),
+    ('𦯒', "yǎng"),
+    ('𦯕', "sòng"),
+    ('𦯖', "niè"),
+    ('𦯛', "bì"),
+    ('𦯣', "cú"),
+    ('𦯤', "qiāng"),
+    ('𦯪', "xiào"),
+    ('𦯫', "zhī"),
+    ('𦯬', "shé"),
+    ('𦯯', "zhì"),
+    ('𦯰', "pēng"),
+    ('𦰏', "diào"),
+    ('𦰖', "wò"),
+    ('𦰘', "zhǐ"),
+    ('𦰙', "bì"),
+    ('𦰛', "fén"),
+    ('𦰡', "nà"),
+    ('𦰥', "bāng"),
+    ('𦰪', "qiú"),
+    ('𦰫', "nǐ"),
+    ('𦰬', "bó"),
+    ('𦰭', "dùn"),
+    ('𦰯', "shǐ"),
+    ('𦰰', "xū"),
+    ('𦰱', "cháng"),
+    ('𦰲', "xū"),
+    ('𦰳', "yé"),
+    ('𦰴', "mí"),
+    ('𦰸', "xīn"),
+    ('𦰹', "zhuó"),
+    ('𦰺', "fù"),
+    ('𦰽', "pǐ"),
+    ('𦰾', "xuè"),
+    ('𦱀', "yù"),
+    ('𦱁', "xián"),
+    ('𦱂', "yù"),
+    ('𦱃', "yú"),
+    ('𦱅', "jū"),
+    ('𦱆', "tā"),
+    ('𦱇', "kōng"),
+    ('𦱊', "zhēng"),
+    ('𦱋', "méng"),
+    ('𦱌', "gāng"),
+    ('𦱒', "mù"),
+    ('𦱓', "xǐ"),
+    ('𦱔', "bì"),
+    ('𦱖', "fù"),
+    ('𦱜', "xiào"),
+    ('𦱠', "jiū"),
+    ('𦱣', "gǒu"),
+    ('𦱰', "chí"),
+    ('𦱱', "jiū"),
+    ('𦱲', "jiū"),
+    ('𦱵', "shā"),
+    ('𦱷', "fēi"),
+    ('𦲫', "fú"),
+    ('𦲯', "wàn"),
+    ('𦲰', "xū"),
+    ('𦲱', "bō"),
+    ('𦳁', "hào"),
+    ('𦳃', "xié"),
+    ('𦳄', "pián"),
+    ('𦳅', "yǔ"),
+    ('𦳇', "tián"),
+    ('𦳈', "pí"),
+    ('𦳊', "shǐ"),
+    ('𦳋', "kuǎi"),
+    ('𦳌', "jī"),
+    ('𦳏', "zhā"),
+    ('𦳐', "nài"),
+    ('𦳑', "mǒu"),
+    ('𦳓', "fú"),
+    ('𦳔', "dù"),
+    ('𦳗', "shěng"),
+    ('𦳘', "chá"),
+    ('𦳚', "chí"),
+    ('𦳛', "guǐ"),
+    ('𦳜', "mín"),
+    ('𦳝', "tāng"),
+    ('𦳞', "bài"),
+    ('𦳟', "qiāng"),
+    ('𦳡', "zhuó"),
+    ('𦳢', "wèi"),
+    ('𦳣', "xún"),
+    ('𦳥', "miǎo"),
+    ('𦳦', "zāi"),
+    ('𦳧', "yóu"),
+    ('𦳩', "yòu"),
+    ('𦳫', "shān"),
+    ('𦳬', "hé"),
+    ('𦳭', "lǚ"),
+    ('𦳮', "zhí"),
+    ('𦳲', "jìng"),
+    ('𦳳', "zhēn"),
+    ('𦳶', "méng"),
+    ('𦳷', "yóu"),
+    ('𦳹', "wò"),
+    ('𦳺', "bá"),
+    ('𦳽', "juàn"),
+    ('𦳾', "rú"),
+    ('𦳿', "còu"),
+    ('𦴀', "zhī"),
+    ('𦴇', "qióng"),
+    ('𦴉', "hú"),
+    ('𦴊', "yāng"),
+    ('𦴌', "jùn"),
+    ('𦴍', "shé"),
+    ('𦴎', "kòu"),
+    ('𦴑', "qián"),
+    ('𦴔', "méng"),
+    ('𦴚', "tiáo"),
+    ('𦵐', "niè"),
+    ('𦵟', "chí"),
+    ('𦵡', "xiōng"),
+    ('𦵣', "hùn"),
+    ('𦵦', "dí"),
+    ('𦵧', "láng"),
+    ('𦵩', "zāo"),
+    ('𦵪', "cè"),
+    ('𦵫', "suǒ"),
+    ('𦵬', "zù"),
+    ('𦵭', "suī"),
+    ('𦵯', "xiá"),
+    ('𦵱', "xiè"),
+    ('𦵴', "jié"),
+    ('𦵵', "yóu"),
+    ('𦵷', "gòu"),
+    ('𦵸', "gěng"),
+    ('𦵼', "jùn"),
+    ('𦵽', "huǎng"),
+    ('𦵾', "jí"),
+    ('𦵿', "pōu"),
+    ('𦶀', "wū"),
+    ('𦶂', "yì"),
+    ('𦶅', "nǎi"),
+    ('𦶇', "rǒng"),
+    ('𦶈', "nán"),
+    ('𦶊', "píng"),
+    ('𦶋', "shàn"),
+    ('𦶌', "diāo"),
+    ('𦶍', "jí"),
+    ('𦶎', "huā"),
+    ('𦶏', "duì"),
+    ('𦶐', "kǒng"),
+    ('𦶑', "tà"),
+    ('𦶓', "hòng"),
+    ('𦶕', "shū"),
+    ('𦶙', "héng"),
+    ('𦶚', "fěn"),
+    ('𦶲', "kòu"),
+    ('𦷙', "nián"),
+    ('𦷝', "chú"),
+    ('𦷦', "qiàng"),
+    ('𦷲', "xì"),
+    ('𦷳', "hú"),
+    ('𦷴', "sòng"),
+    ('𦷵', "wò"),
+    ('𦷷', "hài"),
+    ('𦷸', "rú"),
+    ('𦷹', "méng"),
+    ('𦷻', "sǎn"),
+    ('𦷽', "wú"),
+    ('𦷿', "yóu"),
+    ('𦸁', "tān"),
+    ('𦸂', "shēn"),
+    ('𦸆', "qǐ"),
+    ('𦸈', "guó"),
+    ('𦸉', "qià"),
+    ('𦸊', "xiān"),
+    ('𦸏', "suī"),
+    ('𦸐', "lù"),
+    ('𦸓', "qī"),
+    ('𦸔', "diāo"),
+    ('𦸗', "qí"),
+    ('𦸘', "jiá"),
+    ('𦸙', "yóu"),
+    ('𦸚', "xí"),
+    ('𦸛', "cháo"),
+    ('𦸡', "mì"),
+    ('𦸢', "lòu"),
+    ('𦸣', "bǐ"),
+    ('𦸪', "péi"),
+    ('𦸮', "zhēn"),
+    ('𦸯', "shēn"),
+    ('𦸰', "chǎn"),
+    ('𦸱', "fù"),
+    ('𦸶', "qū"),
+    ('𦸷', "sī"),
+    ('𦸺', "zuī"),
+    ('𦹫', "zhào"),
+    ('𦹽', "pí"),
+    ('𦺀', "còu"),
+    ('𦺆', "gāo"),
+    ('𦺇', "dú"),
+    ('𦺉', "fū"),
+    ('𦺊', "guān"),
+    ('𦺋', "sǎo"),
+    ('𦺌', "sǒu"),
+    ('𦺍', "jiǎn"),
+    ('𦺎', "póu"),
+    ('𦺐', "cán"),
+    ('𦺑', "bèng"),
+    ('𦺒', "mòu"),
+    ('𦺓', "zhāo"),
+    ('𦺔', "xiáo"),
+    ('𦺖', "jú"),
+    ('𦺗', "shū"),
+    ('𦺘', "jiǎn"),
+    ('𦺙', "lí"),
+    ('𦺛', "chuàn"),
+    ('𦺜', "lào"),
+    ('𦺞', "hè"),
+    ('𦺟', "hú"),
+    ('𦺠', "gū"),
+    ('𦺡', "zhǎng"),
+    ('𦺢', "jié"),
+    ('𦺣', "xiàng"),
+    ('𦺥', "dū"),
+    ('𦺦', "hán"),
+    ('𦺧', "jiá"),
+    ('𦺨', "xiàng"),
+    ('𦺩', "jí"),
+    ('𦺪', "shǔ"),
+    ('𦺫', "làng"),
+    ('𦺬', "jī"),
+    ('𦺭', "shān"),
+    ('𦺰', "tāo"),
+    ('𦺱', "zī"),
+    ('𦺲', "shuàn"),
+    ('𦺴', "jí"),
+    ('𦺵', "chù"),
+    ('𦺶', "jì"),
+    ('𦺷', "shēn"),
+    ('𦺸', "lìn"),
+    ('𦺹', "liáo"),
+    ('𦺻', "sǎn"),
+    ('𦺽', "ǎn"),
+    ('𦺾', "ruǎn"),
+    ('𦻀', "tí"),
+    ('𦻁', "dàn"),
+    ('𦻃', "huán"),
+    ('𦻅', "sà"),
+    ('𦻕', "yǐn"),
+    ('𦼆', "ruí"),
+    ('𦼇', "wū"),
+    ('𦼈', "jù"),
+    ('𦼉', "huán"),
+    ('𦼊', "léng"),
+    ('𦼋', "lù"),
+    ('𦼎', "tān"),
+    ('𦼏', "zēng"),
+    ('𦼓', "qián"),
+    ('𦼗', "xī"),
+    ('𦼡', "cǐ"),
+    ('𦼢', "shé"),
+    ('𦼧', "sà"),
+    ('𦼪', "mào"),
+    ('𦼫', "qú"),
+    ('𦼭', "bó"),
+    ('𦼮', "gǎn"),
+    ('𦼰', "qiè"),
+    ('𦼱', "juàn"),
+    ('𦼲', "dāng"),
+    ('𦼳', "cháng"),
+    ('𦼴', "yáng"),
+    ('𦼵', "hé"),
+    ('𦼷', "jī"),
+    ('𦼹', "bǐng"),
+    ('𦼻', "méi"),
+    ('𦼿', "dūn"),
+    ('𦽀', "ǎo"),
+    ('𦽁', "jīng"),
+    ('𦽂', "lù"),
+    ('𦽃', "miàn"),
+    ('𦽄', "diàn"),
+    ('𦽅', "hè"),
+    ('𦽇', "jiān"),
+    ('𦽊', "huá"),
+    ('𦽋', "gōu"),
+    ('𦽎', "lù"),
+    ('𦽏', "fú"),
+    ('𦽐', "huǐ"),
+    ('𦽒', "zéi"),
+    ('𦽔', "jìn"),
+    ('𦽕', "sī"),
+    ('𦽖', "qūn"),
+    ('𦽜', "dàn"),
+    ('𦽞', "wàn"),
+    ('𦽟', "biǎn"),
+    ('𦽤', "jiá"),
+    ('𦽫', "dǎn"),
+    ('𦽬', "jiū"),
+    ('𦽭', "xián"),
+    ('𦽮', "bó"),
+    ('𦾏', "xiá"),
+    ('𦾑', "biāo"),
+    ('𦾕', "pò"),
+    ('𦾘', "sǎo"),
+    ('𦾙', "bèi"),
+    ('𦾚', "shà"),
+    ('𦾛', "wěi"),
+    ('𦾝', "cāng"),
+    ('𦾞', "lù"),
+    ('𦾩', "dàn"),
+    ('𦾫', "gǔ"),
+    ('𦾬', "zā"),
+    ('𦾭', "bǎng"),
+    ('𦾮', "gàn"),
+    ('𦾱', "chāo"),
+    ('𦾲', "jì"),
+    ('𦾳', "liē"),
+    ('𦾵', "qióng"),
+    ('𦾶', "jiàn"),
+    ('𦾷', "lù"),
+    ('𦾸', "duān"),
+    ('𦾹', "suān"),
+    ('𦾺', "yáo"),
+    ('𦾻', "yǐn"),
+    ('𦾽', "tà"),
+    ('𦾾', "yáo"),
+    ('𦾿', "jīng"),
+    ('𦿀', "chú"),
+    ('𦿁', "fú"),
+    ('𦿂', "yuán"),
+    ('𦿃', "shǎo"),
+    ('𦿅', "bìng"),
+    ('𦿆', "dàng"),
+    ('𦿇', "shì"),
+    ('𦿊', "lú"),
+    ('𦿋', "qiè"),
+    ('𦿌', "luó"),
+    ('𦿍', "pò"),
+    ('𦿏', "méng"),
+    ('𦿐', "jié"),
+    ('𦿓', "jī"),
+    ('𦿖', "lù"),
+    ('𧀄', "chàng"),
+    ('𧀅', "miè"),
+    ('𧀆', "méng"),
+    ('𧀇', "jiǎn"),
+    ('𧀊', "cǎi"),
+    ('𧀌', "sù"),
+    ('𧀔', "hè"),
+    ('𧀕', "sà"),
+    ('𧀗', "zī"),
+    ('𧀘', "kēng"),
+    ('𧀙', "gěng"),
+    ('𧀚', "sī"),
+    ('𧀠', "tí"),
+    ('𧀡', "zhàn"),
+    ('𧀢', "xiè"),
+    ('𧀣', "shuí"),
+    ('𧀤', "chǐ"),
+    ('𧀥', "yōu"),
+    ('𧀦', "lǔ"),
+    ('𧀧', "mèng"),
+    ('𧀨', "liè"),
+    ('𧀩', "sì"),
+    ('𧀬', "xī"),
+    ('𧀭', "fán"),
+    ('𧀮', "fū"),
+    ('𧀯', "shěn"),
+    ('𧀰', "tí"),
+    ('𧀱', "chài"),
+    ('𧀲', "yuè"),
+    ('𧀴', "fū"),
+    ('𧀵', "jiàn"),
+    ('𧀶', "dì"),
+    ('𧀹', "zhè"),
+    ('𧀺', "xié"),
+    ('𧀻', "dān"),
+    ('𧀿', "zhí"),
+    ('𧁃', "xù"),
+    ('𧁈', "niè"),
+    ('𧁉', "fàn"),
+    ('𧁊', "méng"),
+    ('𧁋', "mǐn"),
+    ('𧁾', "lóu"),
+    ('𧁿', "dú"),
+    ('𧂁', "zhàn"),
+    ('𧂂', "jiàn"),
+    ('𧂃', "hàn"),
+    ('𧂄', "dàn"),
+    ('𧂅', "sēn"),
+    ('𧂆', "jiàn"),
+    ('𧂇', "tán"),
+    ('𧂈', "jiǎo"),
+    ('𧂉', "pó"),
+    ('𧂋', "píng"),
+    ('𧂍', "zhuàn"),
+    ('𧂏', "liáo"),
+    ('𧂐', "zì"),
+    ('𧂒', "zhuó"),
+    ('𧂔', "hù"),
+    ('𧂙', "xì"),
+    ('𧂛', "méng"),
+    ('𧂜', "jù"),
+    ('𧂝', "miè"),
+    ('𧂞', "xián"),
+    ('𧂠', "kuì"),
+    ('𧂡', "méng"),
+    ('𧂢', "jiān"),
+    ('𧂦', "nóu"),
+    ('𧂨', "dì"),
+    ('𧂩', "sāo"),
+    ('𧃏', "chù"),
+    ('𧃐', "zhí"),
+    ('𧃑', "qián"),
+    ('𧃒', "lǚ"),
+    ('𧃔', "zhuó"),
+    ('𧃘', "zuò"),
+    ('𧃙', "hán"),
+    ('𧃚', "suǐ"),
+    ('𧃛', "gòu"),
+    ('𧃝', "chǒu"),
+    ('𧃞', "jì"),
+    ('𧃟', "yì"),
+    ('𧃠', "yú"),
+    ('𧃨', "nóu"),
+    ('𧃩', "nǐ"),
+    ('𧃪', "ruò"),
+    ('𧃮', "lín"),
+    ('𧃱', "níng"),
+    ('𧄍', "qiáo"),
+    ('𧄎', "yáo"),
+    ('𧄏', "fù"),
+    ('𧄐', "shuāng"),
+    ('𧄑', "kuì"),
+    ('𧄒', "qú"),
+    ('𧄓', "dǒng"),
+    ('𧄔', "shǔ"),
+    ('𧄚', "lí"),
+    ('𧄛', "jú"),
+    ('𧄜', "ruǐ"),
+    ('𧄠', "zhá"),
+    ('𧄤', "xiāo"),
+    ('𧄸', "mén"),
+    ('𧄹', "shí"),
+    ('𧄺', "diān"),
+    ('𧄻', "lì"),
+    ('𧄼', "dèng"),
+    ('𧄽', "zàn"),
+    ('𧄿', "luó"),
+    ('𧅀', "cán"),
+    ('𧅃', "āo"),
+    ('𧅆', "jiǎn"),
+    ('𧅈', "diào"),
+    ('𧅋', "yíng"),
+    ('𧅖', "yì"),
+    ('𧅗', "dǎng"),
+    ('𧅘', "nóu"),
+    ('𧅚', "yuè"),
+    ('𧅮', "lǐ"),
+    ('𧅯', "lí"),
+    ('𧅰', "hù"),
+    ('𧅲', "yòu"),
+    ('𧅺', "nàng"),
+    ('𧆂', "chèn"),
+    ('𧆉', "fēng"),
+    ('𧆊', "biē"),
+    ('𧆏', "mǎn"),
+    ('𧆐', "gàn"),
+    ('𧆑', "huò"),
+    ('𧆓', "cū"),
+    ('𧆕', "yǒu"),
+    ('𧆘', "yòu"),
+    ('𧆜', "xū"),
+    ('𧆡', "xù"),
+    ('𧆢', "hǔ"),
+    ('𧆣', "lú"),
+    ('𧆥', "xiá"),
+    ('𧆦', "yì"),
+    ('𧆮', "hǔ"),
+    ('𧆯', "hù"),
+    ('𧆰', "zǐ"),
+    ('𧆷', "gōng"),
+    ('𧆸', "tuī"),
+    ('𧆹', "wū"),
+    ('𧆺', "líng"),
+    ('𧆻', "gū"),
+    ('𧆼', "zhōng"),
+    ('𧇄', "lú"),
+    ('𧇈', "zù"),
+    ('𧇌', "tóng"),
+    ('𧇍', "xiā"),
+    ('𧇎', "hé"),
+    ('𧇓', "yuè"),
+    ('𧇙', "nán"),
+    ('𧇚', "bó"),
+    ('𧇛', "hū"),
+    ('𧇜', "qì"),
+    ('𧇝', "shú"),
+    ('𧇞', "qiāng"),
+    ('𧇟', "zhōu"),
+    ('𧇠', "yào"),
+    ('𧇡', "gū"),
+    ('𧇥', "bān"),
+    ('𧇦', "kǎn"),
+    ('𧇮', "hé"),
+    ('𧇯', "jì"),
+    ('𧇰', "hú"),
+    ('𧇱', "yán"),
+    ('𧇶', "chūn"),
+    ('𧇷', "dǐng"),
+    ('𧇸', "qiū"),
+    ('𧇹', "hóu"),
+    ('𧇼', "hào"),
+    ('𧇿', "zù"),
+    ('𧈁', "xián"),
+    ('𧈄', "xià"),
+    ('𧈅', "xì"),
+    ('𧈈', "sè"),
+    ('𧈌', "gé"),
+    ('𧈍', "xì"),
+    ('𧈑', "gé"),
+    ('𧈔', "lǚ"),
+    ('𧈖', "gé"),
+    ('𧈗', "kè"),
+    ('𧈙', "shòu"),
+    ('𧈚', "zhù"),
+    ('𧈜', "téng"),
+    ('𧈝', "yà"),
+    ('𧈞', "nì"),
+    ('𧈦', "luò"),
+    ('𧈧', "suī"),
+    ('𧈪', "chǎn"),
+    ('𧈭', "wù"),
+    ('𧈯', "yū"),
+    ('𧈹', "zǎo"),
+    ('𧈻', "yì"),
+    ('𧈼', "xī"),
+    ('𧈽', "hóng"),
+    ('𧈾', "quán"),
+    ('𧈿', "wǎng"),
+    ('𧉀', "chǐ"),
+    ('𧉁', "xì"),
+    ('𧉂', "tiǎn"),
+    ('𧉃', "yǔn"),
+    ('𧉅', "yī"),
+    ('𧉆', "jí"),
+    ('𧉇', "huī"),
+    ('𧉈', "fóu"),
+    ('𧉊', "fǔ"),
+    ('𧉍', "jí"),
+    ('𧉎', "xuán"),
+    ('𧉐', "shuāng"),
+    ('𧉑', "tài"),
+    ('𧉓', "dù"),
+    ('𧉗', "yuán"),
+    ('𧉛', "dì"),
+    ('𧉞', "zhǔ"),
+    ('𧉟', "tāi"),
+    ('𧉡', "rǒng"),
+    ('𧉢', "xué"),
+    ('𧉣', "yù"),
+    ('𧉤', "fàn"),
+    ('𧉥', "běi"),
+    ('𧉧', "qǔ"),
+    ('𧉩', "bù"),
+    ('𧉪', "jiā"),
+    ('𧉫', "zhá"),
+    ('𧉭', "nǔ"),
+    ('𧉮', "shé"),
+    ('𧉲', "lì"),
+    ('𧊄', "guǐ"),
+    ('𧊅', "guǎi"),
+    ('𧊇', "dài"),
+    ('𧊏', "gāi"),
+    ('𧊒', "cì"),
+    ('𧊔', "yǎn"),
+    ('𧊕', "sōng"),
+    ('𧊖', "shì"),
+    ('𧊘', "kù"),
+    ('𧊙', "zhǐ"),
+    ('𧊚', "tóng"),
+    ('𧊛', "qú"),
+    ('𧊜', "è"),
+    ('𧊞', "xíng"),
+    ('𧊟', "rú"),
+    ('𧊠', "yú"),
+    ('𧊣', "yì"),
+    ('𧊤', "yì"),
+    ('𧊥', "xù"),
+    ('𧊦', "fǒu"),
+    ('𧊧', "gé"),
+    ('𧊬', "hé"),
+    ('𧊭', "yīn"),
+    ('𧊯', "hòng"),
+    ('𧊱', "duǒ"),
+    ('𧊽', "xíng"),
+    ('𧊾', "fán"),
+    ('𧋉', "qī"),
+    ('𧋊', "shā"),
+    ('𧋌', "dù"),
+    ('𧋍', "dì"),
+    ('𧋎', "lí"),
+    ('𧋏', "yì"),
+    ('𧋐', "xí"),
+    ('𧋑', "gěng"),
+    ('𧋒', "tóng"),
+    ('𧋓', "kào"),
+    ('𧋔', "hòng"),
+    ('𧋕', "kùn"),
+    ('𧋖', "niè"),
+    ('𧋗', "chí"),
+    ('𧋘', "tí"),
+    ('𧋚', "tóng"),
+    ('𧋠', "lí"),
+    ('𧋡', "nà"),
+    ('𧋱', "zhān"),
+    ('𧋲', "běi"),
+    ('𧌁', "tiáo"),
+    ('𧌃', "zā"),
+    ('𧌄', "è"),
+    ('𧌅', "shòu"),
+    ('𧌆', "kōng"),
+    ('𧌇', "péng"),
+    ('𧌈', "fù"),
+    ('𧌉', "lù"),
+    ('𧌊', "xiè"),
+    ('𧌋', "xiè"),
+    ('𧌌', "xiū"),
+    ('𧌍', "lù"),
+    ('𧌎', "tiǎn"),
+    ('𧌏', "tà"),
+    ('𧌐', "cì"),
+    ('𧌑', "qū"),
+    ('𧌓', "fù"),
+    ('𧌔', "zhī"),
+    ('𧌖', "xiè"),
+    ('𧌗', "zǒu"),
+    ('𧌘', "fèi"),
+    ('𧌙', "mín"),
+    ('𧌚', "xīng"),
+    ('𧌝', "tóng"),
+    ('𧌞', "qí"),
+    ('𧌠', "piāo"),
+    ('𧌢', "suì"),
+    ('𧌣', "ěr"),
+    ('𧌧', "hǔ"),
+    ('𧌻', "sōng"),
+    ('𧌽', "biē"),
+    ('𧌾', "dīng"),
+    ('𧌿', "bǎn"),
+    ('𧍀', "shī"),
+    ('𧍁', "xiè"),
+    ('𧍂', "xiáo"),
+    ('𧍃', "fěi"),
+    ('𧍒', "chuǎn"),
+    ('𧍓', "shuài"),
+    ('𧍔', "yāo"),
+    ('𧍕', "jué"),
+    ('𧍖', "shěng"),
+    ('𧍘', "yōu"),
+    ('𧍙', "fàn"),
+    ('𧍜', "kuí"),
+    ('𧍝', "dì"),
+    ('𧍟', "máo"),
+    ('𧍠', "jié"),
+    ('𧍢', "yán"),
+    ('𧍥', "wēi"),
+    ('𧍨', "sāng"),
+    ('𧍩', "jié"),
+    ('𧍪', "yú"),
+    ('𧍫', "wèi"),
+    ('𧍬', "è"),
+    ('𧍭', "quán"),
+    ('𧍮', "jiǒng"),
+    ('𧍯', "féng"),
+    ('𧍰', "lóng"),
+    ('𧍱', "dié"),
+    ('𧍲', "pián"),
+    ('𧍴', "liàn"),
+    ('𧍵', "hú"),
+    ('𧍶', "lǜ"),
+    ('𧍿', "diàn"),
+    ('𧎃', "cuì"),
+    ('𧎄', "móu"),
+    ('𧎕', "wáng"),
+    ('𧎖', "juān"),
+    ('𧎗', "kē"),
+    ('𧎘', "yán"),
+    ('𧎙', "jiǎo"),
+    ('𧎡', "gōng"),
+    ('𧎣', "róng"),
+    ('𧎤', "sūn"),
+    ('𧎥', "shàn"),
+    ('𧎨', "chí"),
+    ('𧎪', "qí"),
+    ('𧎫', "suǒ"),
+    ('𧎭', "yè"),
+    ('𧎮', "zǎo"),
+    ('𧎯', "quē"),
+    ('𧎰', "zhǎn"),
+    ('𧎱', "bā"),
+    ('𧎲', "zú"),
+    ('𧎳', "suǒ"),
+    ('𧎴', "zhé"),
+    ('𧎵', "xì"),
+    ('𧎷', "chǔ"),
+    ('𧎸', "jiǎo"),
+    ('𧎹', "zuì"),
+    ('𧎺', "gē"),
+    ('𧎻', "wù"),
+    ('𧎾', "lüè"),
+    ('𧎿', "jí"),
+    ('𧏂', "xié"),
+    ('𧏃', "xié"),
+    ('𧏆', "dǒu"),
+    ('𧏋', "qiū"),
+    ('𧏑', "píng"),
+    ('𧏓', "liú"),
+    ('𧏖', "pín"),
+    ('𧏗', "cī"),
+    ('𧏥', "jié"),
+    ('𧏧', "huì"),
+    ('𧏫', "shà"),
+    ('𧏸', "zhí"),
+    ('𧏹', "ài"),
+    ('𧏺', "xù"),
+    ('𧏻', "bì"),
+    ('𧏽', "yē"),
+    ('𧏾', "nì"),
+    ('𧏿', "zhú"),
+    ('𧐁', "sù"),
+    ('𧐃', "xié"),
+    ('𧐄', "yù"),
+    ('𧐅', "qū"),
+    ('𧐈', "zú"),
+    ('𧐉', "zhī"),
+    ('𧐊', "zhāng"),
+    ('𧐋', "lüè"),
+    ('𧐌', "wěi"),
+    ('𧐍', "chōng"),
+    ('𧐎', "mì"),
+    ('𧐐', "jī"),
+    ('𧐒', "sù"),
+    ('𧐓', "yě"),
+    ('𧐔', "xí"),
+    ('𧐕', "tuán"),
+    ('𧐖', "lián"),
+    ('𧐗', "xuán"),
+    ('𧐙', "wù"),
+    ('𧐟', "máo"),
+    ('𧐬', "hóng"),
+    ('𧐯', "lüè"),
+    ('𧐰', "dú"),
+    ('𧐱', "cóng"),
+    ('𧐲', "chán"),
+    ('𧐳', "lù"),
+    ('𧐴', "sù"),
+    ('𧑀', "lüè"),
+    ('𧑆', "zhōng"),
+    ('𧑇', "lí"),
+    ('𧑈', "fèi"),
+    ('𧑊', "jǐng"),
+    ('𧑋', "kuì"),
+    ('𧑌', "yì"),
+    ('𧑍', "huá"),
+    ('𧑎', "cuì"),
+    ('𧑏', "zhú"),
+    ('𧑐', "yù"),
+    ('𧑑', "běng"),
+    ('𧑒', "tūn"),
+    ('𧑓', "shǔ"),
+    ('𧑔', "dài"),
+    ('𧑕', "wū"),
+    ('𧑖', "cì"),
+    ('𧑗', "nìng"),
+    ('𧑘', "dàng"),
+    ('𧑙', "zú"),
+    ('𧑚', "hán"),
+    ('𧑜', "pí"),
+    ('𧑝', "chuàn"),
+    ('𧑠', "dù"),
+    ('𧑡', "pá"),
+    ('𧑤', "zhū"),
+    ('𧑦', "xié"),
+    ('𧑧', "zhé"),
+    ('𧑨', "qiè"),
+    ('𧑩', "xuān"),
+    ('𧑫', "sào"),
+    ('𧒀', "bì"),
+    ('𧒂', "fù"),
+    ('𧒈', "lì"),
+    ('𧒎', "é"),
+    ('𧒐', "yē"),
+    ('𧒑', "shǔ"),
+    ('𧒓', "sè"),
+    ('𧒕', "qī"),
+    ('𧒖', "guò"),
+    ('𧒗', "sè"),
+    ('𧒙', "fù"),
+    ('𧒚', "máo"),
+    ('𧒜', "léi"),
+    ('𧒝', "zhān"),
+    ('𧒨', "chài"),
+    ('𧒭', "wèi"),
+    ('𧒽', "léi"),
+    ('𧒿', "zéi"),
+    ('𧓀', "yīng"),
+    ('𧓁', "ài"),
+    ('𧓂', "xiē"),
+    ('𧓄', "bì"),
+    ('𧓋', "chán"),
+    ('𧓎', "pí"),
+    ('𧓏', "cóng"),
+    ('𧓐', "liè"),
+    ('𧓑', "qí"),
+    ('𧓓', "jì"),
+    ('𧓔', "jīng"),
+    ('𧓕', "dōng"),
+    ('𧓖', "féi"),
+    ('𧓗', "yí"),
+    ('𧓘', "tuán"),
+    ('𧓨', "měng"),
+    ('𧓩', "cán"),
+    ('𧓪', "yá"),
+    ('𧓲', "yǎng"),
+    ('𧓴', "tíng"),
+    ('𧓸', "zhí"),
+    ('𧓺', "xiè"),
+    ('𧓻', "lǜ"),
+    ('𧓽', "lì"),
+    ('𧓿', "máo"),
+    ('𧔂', "xiá"),
+    ('𧔅', "sòu"),
+    ('𧔖', "sū"),
+    ('𧔗', "xuè"),
+    ('𧔝', "lì"),
+    ('𧔞', "yuán"),
+    ('𧔡', "zhǎn"),
+    ('𧔣', "tà"),
+    ('𧔤', "xuán"),
+    ('𧔥', "wèi"),
+    ('𧔦', "yè"),
+    ('𧔧', "páng"),
+    ('𧔨', "máo"),
+    ('𧔩', "tí"),
+    ('𧔪', "pín"),
+    ('𧔬', "dù"),
+    ('𧔭', "qiú"),
+    ('𧔮', "yǐ"),
+    ('𧔳', "tuó"),
+    ('𧔴', "chài"),
+    ('𧔷', "jìn"),
+    ('𧔼', "é"),
+    ('𧕃', "chán"),
+    ('𧕄', "yīng"),
+    ('𧕅', "líng"),
+    ('𧕇', "xiǎn"),
+    ('𧕉', "qī"),
+    ('𧕋', "yuè"),
+    ('𧕌', "lüè"),
+    ('𧕍', "yíng"),
+    ('𧕎', "qú"),
+    ('𧕒', "fěi"),
+    ('𧕓', "zī"),
+    ('𧕙', "qīng"),
+    ('𧕝', "níng"),
+    ('𧕞', "wèi"),
+    ('𧕟', "shuāng"),
+    ('𧕡', "fù"),
+    ('𧕤', "mò"),
+    ('𧕥', "mò"),
+    ('𧕦', "tuó"),
+    ('𧕧', "chài"),
+    ('𧕨', "zàng"),
+    ('𧕮', "lí"),
+    ('𧕯', "lí"),
+    ('𧕱', "xiá"),
+    ('𧕲', "juǎn"),
+    ('𧕴', "nán"),
+    ('𧕵', "mì"),
+    ('𧕸', "huáng"),
+    ('𧕺', "shuāng"),
+    ('𧕼', "xǔ"),
+    ('𧕿', "fěi"),
+    ('𧖁', "xiè"),
+    ('𧖆', "tà"),
+    ('𧖇', "yǒng"),
+    ('𧖉', "zhǎn"),
+    ('𧖑', "qiáng"),
+    ('𧖒', "náng"),
+    ('𧖔', "lìn"),
+    ('𧖘', "luán"),
+    ('𧖙', "xiǎn"),
+    ('𧖚', "fú"),
+    ('𧖜', "líng"),
+    ('𧖠', "sāo"),
+    ('𧖢', "huì"),
+    ('𧖨', "tíng"),
+    ('𧖪', "qíng"),
+    ('𧖬', "huāng"),
+    ('𧖮', "àn"),
+    ('𧖵', "mǎn"),
+    ('𧖷', "nì"),
+    ('𧖻', "guó"),
+    ('𧖼', "ǒu"),
+    ('𧖿', "xiàng"),
+    ('𧗁', "jīn"),
+    ('𧗆', "zhēng"),
+    ('𧗋', "sàn"),
+    ('𧗌', "hù"),
+    ('𧗎', "zú"),
+    ('𧗏', "huǐ"),
+    ('𧗒', "jī"),
+    ('𧗖', "yè"),
+    ('𧗦', "xíng"),
+    ('𧗩', "là"),
+    ('𧗪', "yù"),
+    ('𧗫', "jué"),
+    ('𧗱', "shù"),
+    ('𧗲', "zhēng"),
+    ('𧗴', "yǒng"),
+    ('𧗶', "gē"),
+    ('𧗸', "jiàn"),
+    ('𧗹', "xìn"),
+    ('𧗼', "huī"),
+    ('𧗿', "shuài"),
+    ('𧘂', "chōng"),
+    ('𧘃', "háng"),
+    ('𧘈', "liǎo"),
+    ('𧘍', "jiāng"),
+    ('𧘏', "gōng"),
+    ('𧘑', "zhuó"),
+    ('𧘗', "qǐ"),
+    ('𧘜', "qiān"),
+    ('𧘞', "dǒu"),
+    ('𧘟', "pō"),
+    ('𧘢', "hù"),
+    ('𧘥', "niǔ"),
+    ('𧘧', "qì"),
+    ('𧘨', "diāo"),
+    ('𧘩', "diāo"),
+    ('𧘫', "lì"),
+    ('𧘮', "xiōng"),
+    ('𧘽', "ná"),
+    ('𧘿', "zhēng"),
+    ('𧙀', "là"),
+    ('𧙁', "zhì"),
+    ('𧙃', "ě"),
+    ('𧙄', "bō"),
+    ('𧙅', "pō"),
+    ('𧙆', "xū"),
+    ('𧙇', "yòng"),
+    ('𧙈', "cí"),
+    ('𧙉', "lì"),
+    ('𧙌', "páo"),
+    ('𧙏', "xiù"),
+    ('𧙛', "pù"),
+    ('𧙝', "ché"),
+    ('𧙞', "qì"),
+    ('𧙡', "yì"),
+    ('𧙣', "tí"),
+    ('𧙤', "duǒ"),
+    ('𧙥', "lóng"),
+    ('𧙧', "jiàn"),
+    ('𧙭', "zhàn"),
+    ('𧙮', "yuàn"),
+    ('𧙶', "yú"),
+    ('𧙸', "gēng"),
+    ('𧙺', "hòu"),
+    ('𧙾', "qǐ"),
+    ('𧚀', "mù"),
+    ('𧚁', "huàn"),
+    ('𧚂', "lòng"),
+    ('𧚃', "xì"),
+    ('𧚄', "é"),
+    ('𧚅', "lǎng"),
+    ('𧚆', "fèi"),
+    ('𧚇', "wǎn"),
+    ('𧚉', "cūn"),
+    ('𧚋', "péng"),
+    ('𧚏', "cuò"),
+    ('𧚐', "wēng"),
+    ('𧚡', "gǎo"),
+    ('𧚥', "cuì"),
+    ('𧚨', "qì"),
+    ('𧚩', "lí"),
+    ('𧚪', "qiè"),
+    ('𧚫', "qiàn"),
+    ('𧚬', "kōng"),
+    ('𧚭', "běng"),
+    ('𧚯', "shòu"),
+    ('𧚷', "wēi"),
+    ('𧛄', "shān"),
+    ('𧛏', "zī"),
+    ('𧛒', "tì"),
+    ('𧛓', "qiān"),
+    ('𧛔', "dú"),
+    ('𧛗', "tú"),
+    ('𧛚', "wēi"),
+    ('𧛞', "hú"),
+    ('𧛟', "xīng"),
+    ('𧛡', "shān"),
+    ('𧛢', "zhǐ"),
+    ('𧛧', "chǐ"),
+    ('𧛸', "zhòu"),
+    ('𧛹', "wēng"),
+    ('𧛺', "chí"),
+    ('𧛻', "suǒ"),
+    ('𧛼', "xiè"),
+    ('𧛾', "kè"),
+    ('𧜁', "shài"),
+    ('𧜂', "shī"),
+    ('𧜃', "shòu"),
+    ('𧜅', "jiè"),
+    ('𧜉', "gǎo"),
+    ('𧜊', "lǚ"),
+    ('𧜔', "xiè"),
+    ('𧜚', "zhǐ"),
+    ('𧜞', "mán"),
+    ('𧜠', "shuài"),
+    ('𧜡', "kè"),
+    ('𧜣', "diǎo"),
+    ('𧜤', "yī"),
+    ('𧜦', "sù"),
+    ('𧜧', "chuāng"),
+    ('𧜭', "dú"),
+    ('𧜱', "cuì"),
+    ('𧜲', "tuò"),
+    ('𧜵', "xiè"),
+    ('𧜽', "xuán"),
+    ('𧝂', "hè"),
+    ('𧝃', "jué"),
+    ('𧝆', "tì"),
+    ('𧝇', "fèi"),
+    ('𧝉', "zhǐ"),
+    ('𧝊', "shì"),
+    ('𧝋', "tuí"),
+    ('𧝎', "chōng"),
+    ('𧝐', "tì"),
+    ('𧝑', "zhàn"),
+    ('𧝒', "héng"),
+    ('𧝔', "qú"),
+    ('𧝕', "wéi"),
+    ('𧝗', "dūn"),
+    ('𧝘', "bào"),
+    ('𧝜', "liáo"),
+    ('𧝝', "lài"),
+    ('𧝤', "sī"),
+    ('𧝪', "biǎo"),
+    ('𧝫', "xiè"),
+    ('𧝬', "bié"),
+    ('𧝮', "cǒng"),
+    ('𧝲', "jù"),
+    ('𧝳', "hé"),
+    ('𧝷', "kuì"),
+    ('𧝸', "yōng"),
+    ('𧞀', "shù"),
+    ('𧞍', "niè"),
+    ('𧞏', "yú"),
+    ('𧞐', "zhuó"),
+    ('𧞑', "méng"),
+    ('𧞒', "hú"),
+    ('𧞔', "kè"),
+    ('𧞕', "liè"),
+    ('𧞝', "jiē"),
+    ('𧞞', "xióng"),
+    ('𧞣', "yǎn"),
+    ('𧞩', "jié"),
+    ('𧞪', "là"),
+    ('𧞫', "shù"),
+    ('𧞬', "jié"),
+    ('𧞭', "léi"),
+    ('𧞰', "zú"),
+    ('𧞲', "shì"),
+    ('𧞸', "wéi"),
+    ('𧞹', "dū"),
+    ('𧞺', "sù"),
+    ('𧟃', "xié"),
+    ('𧟄', "ráng"),
+    ('𧟌', "luò"),
+    ('𧟑', "qiān"),
+    ('𧟘', "nàng"),
+    ('𧟙', "líng"),
+    ('𧟜', "jì"),
+    ('𧟠', "mìng"),
+    ('𧟣', "gǔ"),
+    ('𧟨', "xuán"),
+    ('𧟬', "xū"),
+    ('𧟱', "bó"),
+    ('𧟼', "wēi"),
+    ('𧠂', "kū"),
+    ('𧠆', "wǎn"),
+    ('𧠈', "chà"),
+    ('𧠊', "mào"),
+    ('𧠋', "kè"),
+    ('𧠎', "cì"),
+    ('𧠒', "xiàn"),
+    ('𧠓', "mò"),
+    ('𧠚', "hūn"),
+    ('𧠛', "chàn"),
+    ('𧠜', "shī"),
+    ('𧠝', "zhěn"),
+    ('𧠞', "è"),
+    ('𧠟', "mí"),
+    ('𧠡', "shī"),
+    ('𧠢', "qū"),
+    ('𧠣', "shū"),
+    ('𧠥', "cī"),
+    ('𧠦', "yǎn"),
+    ('𧠩', "hū"),
+    ('𧠪', "qī"),
+    ('𧠫', "zhì"),
+    ('𧠬', "huāng"),
+    ('𧠴', "zhǐ"),
+    ('𧠶', "yǒu"),
+    ('𧠼', "gào"),
+    ('𧠽', "yǎo"),
+    ('𧠾', "pōu"),
+    ('𧡇', "yí"),
+    ('𧡈', "chèng"),
+    ('𧡉', "jì"),
+    ('𧡋', "ǎi"),
+    ('𧡍', "dòng"),
+    ('𧡏', "suì"),
+    ('𧡑', "jiù"),
+    ('𧡘', "qì"),
+    ('𧡙', "lián"),
+    ('𧡚', "xuǎn"),
+    ('𧡜', "liǎo"),
+    ('𧡡', "yùn"),
+    ('𧡢', "xuǎn"),
+    ('𧡣', "cóu"),
+    ('𧡤', "piān"),
+    ('𧡦', "kuí"),
+    ('𧡨', "tí"),
+    ('𧡩', "huǎn"),
+    ('𧡪', "dān"),
+    ('𧡫', "guì"),
+    ('𧡬', "chēn"),
+    ('𧡮', "shǎng"),
+    ('𧡯', "jì"),
+    ('𧡴', "liàn"),
+    ('𧡵', "kān"),
+    ('𧡶', "shèng"),
+    ('𧡸', "dōu"),
+    ('𧡹', "yóu"),
+    ('𧡺', "qí"),
+    ('𧡼', "xiǎo"),
+    ('𧢂', "yì"),
+    ('𧢃', "lóu"),
+    ('𧢆', "chuāng"),
+    ('𧢋', "lào"),
+    ('𧢌', "gāo"),
+    ('𧢐', "zēng"),
+    ('𧢒', "wéi"),
+    ('𧢖', "jiān"),
+    ('𧢛', "yīng"),
+    ('𧢜', "fán"),
+    ('𧢝', "lì"),
+    ('𧢞', "qiān"),
+    ('𧢢', "yào"),
+    ('𧢦', "kuī"),
+    ('𧢧', "wéi"),
+    ('𧢩', "què"),
+    ('𧢬', "xiǎo"),
+    ('𧢭', "què"),
+    ('𧢰', "hū"),
+    ('𧢵', "duō"),
+    ('𧢶', "chù"),
+    ('𧢹', "shēn"),
+    ('𧢼', "zhuó"),
+    ('𧢽', "é"),
+    ('𧢾', "jì"),
+    ('𧣁', "tán"),
+    ('𧣃', "pā"),
+    ('𧣋', "jiè"),
+    ('𧣌', "qiào"),
+    ('𧣑', "qián"),
+    ('𧣒', "jù"),
+    ('𧣕', "qiú"),
+    ('𧣖', "tuó"),
+    ('𧣚', "nuò"),
+    ('𧣛', "sì"),
+    ('𧣟', "yí"),
+    ('𧣡', "gǔ"),
+    ('𧣢', "hùn"),
+    ('𧣣', "pá"),
+    ('𧣤', "zī"),
+    ('𧣦', "jiāo"),
+    ('𧣩', "xǐ"),
+    ('𧣪', "shǎo"),
+    ('𧣬', "yí"),
+    ('𧣭', "zhì"),
+    ('𧣵', "lùn"),
+    ('𧣷', "zhōu"),
+    ('𧣸', "jué"),
+    ('𧣹', "tán"),
+    ('𧣺', "nuò"),
+    ('𧣻', "jù"),
+    ('𧣼', "hú"),
+    ('𧣾', "zhì"),
+    ('𧤃', "bī"),
+    ('𧤍', "chì"),
+    ('𧤎', "xuān"),
+    ('𧤏', "jí"),
+    ('𧤐', "guǎ"),
+    ('𧤑', "jú"),
+    ('𧤒', "wò"),
+    ('𧤓', "tuó"),
+    ('𧤕', "qiú"),
+    ('𧤖', "wēi"),
+    ('𧤗', "duān"),
+    ('𧤙', "shòu"),
+    ('𧤛', "zhěn"),
+    ('𧤜', "nè"),
+    ('𧤟', "xì"),
+    ('𧤠', "zhé"),
+    ('𧤡', "zhì"),
+    ('𧤣', "ná"),
+    ('𧤤', "xī"),
+    ('𧤨', "jiān"),
+    ('𧤮', "yáo"),
+    ('𧤯', "guó"),
+    ('𧤲', "dǐ"),
+    ('𧤴', "huò"),
+    ('𧤵', "jīng"),
+    ('𧤼', "jué"),
+    ('𧤽', "yuè"),
+    ('𧥄', "jí"),
+    ('𧥅', "xī"),
+    ('𧥆', "sù"),
+    ('𧥈', "jiān"),
+    ('𧥊', "kūn"),
+    ('𧥋', "wò"),
+    ('𧥌', "kuàng"),
+    ('𧥍', "biāo"),
+    ('𧥎', "jué"),
+    ('𧥑', "bì"),
+    ('𧥓', "chán"),
+    ('𧥕', "zī"),
+    ('𧥖', "lí"),
+    ('𧥚', "fó"),
+    ('𧥛', "qiǎn"),
+    ('𧥜', "yǎn"),
+    ('𧥞', "tàn"),
+    ('𧥟', "mò"),
+    ('𧥣', "kòu"),
+    ('𧥤', "xī"),
+    ('𧥮', "hù"),
+    ('𧥯', "hù"),
+    ('𧥱', "fú"),
+    ('𧥴', "yàng"),
+    ('𧥵', "guò"),
+    ('𧥷', "rén"),
+    ('𧥸', "yìn"),
+    ('𧥹', "fēng"),
+    ('𧥺', "jùn"),
+    ('𧥼', "yún"),
+    ('𧥿', "xùn"),
+    ('𧦁', "xì"),
+    ('𧦎', "xiā"),
+    ('𧦑', "háng"),
+    ('𧦚', "hù"),
+    ('𧦝', "hū"),
+    ('𧦞', "pù"),
+    ('𧦟', "fān"),
+    ('𧦤', "jiā"),
+    ('𧦧', "yí"),
+    ('𧦭', "tuō"),
+    ('𧦮', "ná"),
+    ('𧦸', "yín"),
+    ('𧦹', "yìn"),
+    ('𧧃', "jì"),
+    ('𧧄', "wàng"),
+    ('𧧅', "shì"),
+    ('𧧆', "duī"),
+    ('𧧇', "duò"),
+    ('𧧉', "tuó"),
+    ('𧧊', "wā"),
+    ('𧧋', "lì"),
+    ('𧧏', "rè"),
+    ('𧧒', "cì"),
+    ('𧧓', "xù"),
+    ('𧧔', "zhōu"),
+    ('𧧕', "zì"),
+    ('𧧜', "wǎng"),
+    ('𧧝', "yǎ"),
+    ('𧧟', "jì"),
+    ('𧧠', "chǎo"),
+    ('𧧩', "jí"),
+    ('𧧵', "shǎn"),
+    ('𧧶', "tú"),
+    ('𧧸', "bié"),
+    ('𧧹', "xì"),
+    ('𧧺', "pī"),
+    ('𧧻', "zhà"),
+    ('𧧾', "huì"),
+    ('𧨀', "suō"),
+    ('𧨂', "hè"),
+    ('𧨄', "yuē"),
+    ('𧨆', "wū"),
+    ('𧨈', "líng"),
+    ('𧨊', "zhà"),
+    ('𧨋', "huá"),
+    ('𧨗', "chán"),
+    ('𧨟', "è"),
+    ('𧨡', "chén"),
+    ('𧨧', "suì"),
+    ('𧨩', "tiǎn"),
+    ('𧨰', "zhì"),
+    ('𧨱', "tì"),
+    ('𧨲', "āo"),
+    ('𧨳', "zhuó"),
+    ('𧨴', "zì"),
+    ('𧨵', "kē"),
+    ('𧨷', "sè"),
+    ('𧨸', "tián"),
+    ('𧨹', "lù"),
+    ('𧨾', "shán"),
+    ('𧨿', "zhǎ"),
+    ('𧩃', "chōng"),
+    ('𧩅', "yàn"),
+    ('𧩒', "mǔ"),
+    ('𧩓', "hū"),
+    ('𧩚', "chī"),
+    ('𧩝', "sù"),
+    ('𧩣', "nǎo"),
+    ('𧩦', "jí"),
+    ('𧩧', "duó"),
+    ('𧩨', "hòu"),
+    ('𧩪', "còng"),
+    ('𧩫', "zhā"),
+    ('𧩬', "yín"),
+    ('𧩮', "xiǎo"),
+    ('𧩰', "biàn"),
+    ('𧩱', "bèng"),
+    ('𧩲', "là"),
+    ('𧩴', "chī"),
+    ('𧩶', "qià"),
+    ('𧩸', "ān"),
+    ('𧩹', "shī"),
+    ('𧩼', "chì"),
+    ('𧪅', "nù"),
+    ('𧪇', "jì"),
+    ('𧪓', "ǒu"),
+    ('𧪕', "xiā"),
+    ('𧪘', "chài"),
+    ('𧪚', "ái"),
+    ('𧪝', "shèng"),
+    ('𧪞', "hé"),
+    ('𧪠', "jí"),
+    ('𧪡', "chī"),
+    ('𧪢', "xì"),
+    ('𧪣', "zhēng"),
+    ('𧪦', "tā"),
+    ('𧪨', "mà"),
+    ('𧪫', "pī"),
+    ('𧪮', "xū"),
+    ('𧪯', "qiǎn"),
+    ('𧪹', "xià"),
+    ('𧫊', "yù"),
+    ('𧫑', "jié"),
+    ('𧫒', "xià"),
+    ('𧫓', "lǔ"),
+    ('𧫕', "qiè"),
+    ('𧫗', "chà"),
+    ('𧫛', "yàng"),
+    ('𧫜', "jì"),
+    ('𧫝', "shǎ"),
+    ('𧫞', "lòu"),
+    ('𧫠', "jī"),
+    ('𧫡', "zhì"),
+    ('𧫢', "wàng"),
+    ('𧫤', "bì"),
+    ('𧫥', "ān"),
+    ('𧫦', "yī"),
+    ('𧫧', "ān"),
+    ('𧫬', "lí"),
+    ('𧫹', "xiān"),
+    ('𧫾', "jiù"),
+    ('𧫿', "tǎn"),
+    ('𧬁', "hào"),
+    ('𧬂', "hè"),
+    ('𧬅', "zhā"),
+    ('𧬆', "zhǎn"),
+    ('𧬇', "yì"),
+    ('𧬈', "xì"),
+    ('𧬊', "xì"),
+    ('𧬋', "fà"),
+    ('𧬌', "yán"),
+    ('𧬏', "mǔ"),
+    ('𧬕', "gū"),
+    ('𧬞', "yún"),
+    ('𧬤', "zhòng"),
+    ('𧬦', "chǎn"),
+    ('𧬧', "chuáng"),
+    ('𧬨', "huì"),
+    ('𧬩', "zá"),
+    ('𧬪', "gùn"),
+    ('𧬫', "jiǎn"),
+    ('𧬬', "yá"),
+    ('𧬰', "xiàng"),
+    ('𧬱', "hè"),
+    ('𧭃', "dàn"),
+    ('𧭇', "mián"),
+    ('𧭈', "níng"),
+    ('𧭊', "méng"),
+    ('𧭌', "liè"),
+    ('𧭍', "zhòu"),
+    ('𧭎', "pū"),
+    ('𧭏', "tāi"),
+    ('𧭓', "yíng"),
+    ('𧭔', "téng"),
+    ('𧭕', "guó"),
+    ('𧭚', "qiáng"),
+    ('𧭜', "lǜ"),
+    ('𧭝', "sà"),
+    ('𧭞', "liè"),
+    ('𧭟', "chí"),
+    ('𧭠', "xiě"),
+    ('𧭣', "guó"),
+    ('𧭤', "bào"),
+    ('𧭥', "luò"),
+    ('𧭦', "juàn"),
+    ('𧭪', "è"),
+    ('𧭳', "hé"),
+    ('𧭵', "mèi"),
+    ('𧭸', "xiè"),
+    ('𧭹', "pín"),
+    ('𧭻', "hān"),
+    ('𧭼', "chèn"),
+    ('𧭽', "shàn"),
+    ('𧭾', "huì"),
+    ('𧮆', "yīng"),
+    ('𧮈', "jiǎn"),
+    ('𧮍', "ān"),
+    ('𧮑', "tà"),
+    ('𧮒', "yī"),
+    ('𧮓', "tuí"),
+    ('𧮗', "liú"),
+    ('𧮙', "zuó"),
+    ('𧮛', "lí"),
+    ('𧮝', "pín"),
+    ('𧮞', "xuè"),
+    ('𧮠', "nèn"),
+    ('𧮡', "dòu"),
+    ('𧮤', "lǎn"),
+    ('𧮪', "zhān"),
+    ('𧮫', "jué"),
+    ('𧮬', "zhēn"),
+    ('𧮭', "jí"),
+    ('𧮮', "qiān"),
+    ('𧮰', "hān"),
+    ('𧮱', "fén"),
+    ('𧮳', "hān"),
+    ('𧮴', "hóng"),
+    ('𧮵', "hé"),
+    ('𧮶', "hóu"),
+    ('𧮺', "zhàn"),
+    ('𧮻', "chóu"),
+    ('𧮼', "tài"),
+    ('𧮽', "qiàn"),
+    ('𧮿', "shè"),
+    ('𧯀', "yīng"),
+    ('𧯃', "qīn"),
+    ('𧯆', "huò"),
+    ('𧯈', "xì"),
+    ('𧯉', "hè"),
+    ('𧯊', "xì"),
+    ('𧯋', "xiā"),
+    ('𧯌', "hāo"),
+    ('𧯍', "lào"),
+    ('𧯏', "lì"),
+    ('𧯒', "chēng"),
+    ('𧯖', "jùn"),
+    ('𧯗', "xī"),
+    ('𧯘', "hǎn"),
+    ('𧯞', "dòu"),
+    ('𧯠', "dōu"),
+    ('𧯡', "wān"),
+    ('𧯤', "dōu"),
+    ('𧯥', "zài"),
+    ('𧯦', "juàn"),
+    ('𧯨', "lǒu"),
+    ('𧯩', "chù"),
+    ('𧯫', "zhēng"),
+    ('𧯯', "qí"),
+    ('𧯰', "kàn"),
+    ('𧯱', "huò"),
+    ('𧯲', "lái"),
+    ('𧯺', "gāi"),
+    ('𧯼', "shòu"),
+    ('𧯾', "dōng"),
+    ('𧰃', "lóu"),
+    ('𧰄', "tuān"),
+    ('𧰇', "yú"),
+    ('𧰈', "wù"),
+    ('𧰊', "tián"),
+    ('𧰒', "guó"),
+    ('𧰘', "tán"),
+    ('𧰙', "qí"),
+    ('𧰠', "liè"),
+    ('𧰡', "lì"),
+    ('𧰣', "xūn"),
+    ('𧰨', "gèng"),
+    ('𧰩', "tīng"),
+    ('𧰪', "hàn"),
+    ('𧰫', "chù"),
+    ('𧰭', "tún"),
+    ('𧰯', "xióng"),
+    ('𧰰', "yóu"),
+    ('𧰱', "mò"),
+    ('𧰲', "chǐ"),
+    ('𧰴', "hǔ"),
+    ('𧰵', "dū"),
+    ('𧰷', "mǔ"),
+    ('𧰹', "nà"),
+    ('𧰻', "líng"),
+    ('𧰿', "ài"),
+    ('𧱀', "xiān"),
+    ('𧱄', "kǎn"),
+    ('𧱅', "sì"),
+    ('𧱆', "sān"),
+    ('𧱊', "yì"),
+    ('𧱏', "yì"),
+    ('𧱐', "xiào"),
+    ('𧱒', "zhī"),
+    ('𧱓', "dòu"),
+    ('𧱘', "mài"),
+    ('𧱜', "lún"),
+    ('𧱝', "jué"),
+    ('𧱡', "qiāng"),
+    ('𧱢', "líng"),
+    ('𧱩', "pián"),
+    ('𧱪', "còu"),
+    ('𧱫', "duò"),
+    ('𧱬', "yǔ"),
+    ('𧱰', "zhuō"),
+    ('𧱲', "xì"),
+    ('𧱳', "huài"),
+    ('𧱴', "míng"),
+    ('𧱵', "táng"),
+    ('𧱹', "pū"),
+    ('𧱻', "mì"),
+    ('𧱼', "mán"),
+    ('𧱾', "guāi"),
+    ('𧲀', "qiān"),
+    ('𧲂', "lín"),
+    ('𧲃', "mǐn"),
+    ('𧲄', "wěi"),
+    ('𧲅', "céng"),
+    ('𧲇', "hù"),
+    ('𧲈', "suí"),
+    ('𧲋', "jù"),
+    ('𧲌', "shà"),
+    ('𧲍', "méng"),
+    ('𧲗', "wéi"),
+    ('𧲘', "xī"),
+    ('𧲙', "lìng"),
+    ('𧲜', "bì"),
+    ('𧲝', "wèi"),
+    ('𧲡', "lì"),
+    ('𧲢', "zhé"),
+    ('𧲤', "yóng"),
+    ('𧲥', "hú"),
+    ('𧲦', "wán"),
+    ('𧲧', "bā"),
+    ('𧲨', "jiān"),
+    ('𧲭', "zuǒ"),
+    ('𧲮', "zhǎn"),
+    ('𧲯', "bō"),
+    ('𧲰', "qiū"),
+    ('𧲱', "yāng"),
+    ('𧲴', "dōng"),
+    ('𧲵', "qú"),
+    ('𧲺', "pí"),
+    ('𧲻', "zhǎi"),
+    ('𧲾', "shān"),
+    ('𧲿', "gòu"),
+    ('𧳀', "biào"),
+    ('𧳁', "yí"),
+    ('𧳂', "fú"),
+    ('𧳄', "xìn"),
+    ('𧳅', "shì"),
+    ('𧳆', "tōng"),
+    ('𧳉', "dīng"),
+    ('𧳌', "tū"),
+    ('𧳍', "xiāo"),
+    ('𧳎', "wú"),
+    ('𧳏', "péi"),
+    ('𧳐', "huī"),
+    ('𧳕', "lái"),
+    ('𧳙', "sì"),
+    ('𧳚', "cuǐ"),
+    ('𧳛', "shà"),
+    ('𧳜', "zhǒu"),
+    ('𧳝', "zhào"),
+    ('𧳞', "wéi"),
+    ('𧳟', "lái"),
+    ('𧳠', "bì"),
+    ('𧳣', "dǒng"),
+    ('𧳦', "nǎo"),
+    ('𧳧', "xiē"),
+    ('𧳨', "rǎo"),
+    ('𧳩', "tuàn"),
+    ('𧳪', "wèi"),
+    ('𧳫', "yóu"),
+    ('𧳬', "méi"),
+    ('𧳭', "yuán"),
+    ('𧳮', "zhòng"),
+    ('𧳶', "sōu"),
+    ('𧳸', "gú"),
+    ('𧳹', "shào"),
+    ('𧳻', "zhǎo"),
+    ('𧳼', "pí"),
+    ('𧳿', "tōng"),
+    ('𧴁', "chī"),
+    ('𧴂', "péng"),
+    ('𧴃', "chán"),
+    ('𧴄', "yōng"),
+    ('𧴅', "shuǎng"),
+    ('𧴇', "wǔ"),
+    ('𧴉', "pí"),
+    ('𧴊', "huàn"),
+    ('𧴌', "fú"),
+    ('𧴎', "biào"),
+    ('𧴓', "náo"),
+    ('𧴕', "biào"),
+    ('𧴖', "wèi"),
+    ('𧴗', "yōng"),
+    ('𧴙', "nǎo"),
+    ('𧴚', "guài"),
+    ('𧴠', "lì"),
+    ('𧴢', "xìn"),
+    ('𧴣', "yán"),
+    ('𧴤', "pò"),
+    ('𧴥', "péi"),
+    ('𧴪', "suǒ"),
+    ('𧴬', "rèn"),
+    ('𧴭', "shǎn"),
+    ('𧴲', "suǒ"),
+    ('𧴸', "dān"),
+    ('𧴺', "mèn"),
+    ('𧵃', "shǒu"),
+    ('𧵈', "gòu"),
+    ('𧵊', "hān"),
+    ('𧵋', "shì"),
+    ('𧵌', "yǎng"),
+    ('𧵎', "gǔ"),
+    ('𧵛', "kē"),
+    ('𧵞', "jū"),
+    ('𧵠', "pài"),
+    ('𧵡', "cè"),
+    ('𧵢', "bāo"),
+    ('𧵣', "xiōng"),
+    ('𧵤', "cái"),
+    ('𧵧', "lǐn"),
+    ('𧵨', "ài"),
+    ('𧵬', "mì"),
+    ('𧵭', "lǎi"),
+    ('𧵱', "xiāo"),
+    ('𧵳', "shé"),
+    ('𧵻', "huó"),
+    ('𧵼', "nì"),
+    ('𧶄', "zhèng"),
+    ('𧶆', "lìn"),
+    ('𧶇', "zhá"),
+    ('𧶊', "yún"),
+    ('𧶍', "xù"),
+    ('𧶔', "chéng"),
+    ('𧶕', "wǒ"),
+    ('𧶖', "xī"),
+    ('𧶙', "bèi"),
+    ('𧶜', "shāng"),
+    ('𧶠', "yù"),
+    ('𧶡', "mì"),
+    ('𧶲', "duǎn"),
+    ('𧶵', "chà"),
+    ('𧶶', "fàn"),
+    ('𧶷', "zé"),
+    ('𧶸', "chèng"),
+    ('𧶺', "tíng"),
+    ('𧷅', "yí"),
+    ('𧷋', "yāo"),
+    ('𧷎', "kū"),
+    ('𧷐', "fén"),
+    ('𧷑', "xié"),
+    ('𧷒', "chèng"),
+    ('𧷛', "kuì"),
+    ('𧷟', "bīn"),
+    ('𧷡', "lóu"),
+    ('𧷥', "yì"),
+    ('𧷦', "mì"),
+    ('𧷧', "xiè"),
+    ('𧷱', "guī"),
+    ('𧷳', "luó"),
+    ('𧷶', "shàn"),
+    ('𧷾', "jú"),
+    ('𧷿', "dū"),
+    ('𧸂', "xiān"),
+    ('𧸅', "zhǐ"),
+    ('𧸈', "bìn"),
+    ('𧸕', "zhǐ"),
+    ('𧸖', "zhuàn"),
+    ('𧸗', "xué"),
+    ('𧸘', "liàn"),
+    ('𧸙', "suì"),
+    ('𧸦', "làn"),
+    ('𧸧', "jù"),
+    ('𧸨', "mián"),
+    ('𧸩', "xùn"),
+    ('𧸪', "zhàn"),
+    ('𧸫', "gùn"),
+    ('𧸲', "zhì"),
+    ('𧸽', "wèi"),
+    ('𧸾', "quǎn"),
+    ('𧸿', "chài"),
+    ('𧹈', "réng"),
+    ('𧹊', "yuè"),
+    ('𧹌', "zī"),
+    ('𧹐', "luò"),
+    ('𧹑', "guì"),
+    ('𧹒', "mǎi"),
+    ('𧹓', "chéng"),
+    ('𧹔', "zhàng"),
+    ('𧹕', "jū"),
+    ('𧹖', "tiǎn"),
+    ('𧹗', "wàn"),
+    ('𧹛', "zhī"),
+    ('𧹞', "nǎn"),
+    ('𧹣', "hān"),
+    ('𧹨', "xī"),
+    ('𧹩', "lín"),
+    ('𧹬', "yān"),
+    ('𧹭', "xù"),
+    ('𧹲', "hù"),
+    ('𧹳', "gàn"),
+    ('𧹴', "xù"),
+    ('𧹶', "xì"),
+    ('𧹺', "cuì"),
+    ('𧹽', "xì"),
+    ('𧹾', "hú"),
+    ('𧺅', "yān"),
+    ('𧺎', "yì"),
+    ('𧺏', "chí"),
+    ('𧺐', "jué"),
+    ('𧺒', "zú"),
+    ('𧺜', "jiào"),
+    ('𧺝', "yì"),
+    ('𧺟', "tǎn"),
+    ('𧺠', "chì"),
+    ('𧺡', "bá"),
+    ('𧺢', "tòu"),
+    ('𧺣', "zōng"),
+    ('𧺤', "qiú"),
+    ('𧺧', "chì"),
+    ('𧺨', "xǐ"),
+    ('𧺰', "nì"),
+    ('𧺲', "cū"),
+    ('𧺴', "wǔ"),
+    ('𧺶', "chù"),
+    ('𧺷', "sū"),
+    ('𧺸', "yóng"),
+    ('𧺹', "jǔ"),
+    ('𧺺', "bá"),
+    ('𧺼', "cǐ"),
+    ('𧺽', "dì"),
+    ('𧺾', "pǎn"),
+    ('𧺿', "chì"),
+    ('𧻁', "qiǔ"),
+    ('𧻃', "yán"),
+    ('𧻍', "zhǎi"),
+    ('𧻒', "xiàn"),
+    ('𧻓', "bèng"),
+    ('𧻔', "kuāng"),
+    ('𧻕', "qì"),
+    ('𧻖', "zhōu"),
+    ('𧻗', "jú"),
+    ('𧻘', "qiè"),
+    ('𧻙', "mò"),
+    ('𧻚', "yuán"),
+    ('𧻜', "guì"),
+    ('𧻝', "zuī"),
+    ('𧻧', "qiè"),
+    ('𧻰', "hú"),
+    ('𧻱', "qiú"),
+    ('𧻲', "hái"),
+    ('𧻳', "fù"),
+    ('𧻴', "làng"),
+    ('𧻵', "shà"),
+    ('𧻶', "xī"),
+    ('𧻷', "bū"),
+    ('𧻸', "shì"),
+    ('𧻹', "yǒng"),
+    ('𧻺', "guāng"),
+    ('𧻼', "niè"),
+    ('𧻿', "hǒu"),
+    ('𧼊', "mì"),
+    ('𧼎', "è"),
+    ('𧼏', "xián"),
+    ('𧼐', "yǔn"),
+    ('𧼑', "xù"),
+    ('𧼒', "qǐn"),
+    ('𧼓', "dōng"),
+    ('𧼔', "léng"),
+    ('𧼕', "qì"),
+    ('𧼖', "lán"),
+    ('𧼗', "fú"),
+    ('𧼘', "qǐ"),
+    ('𧼙', "chǒng"),
+    ('𧼜', "cù"),
+    ('𧼟', "mò"),
+    ('𧼠', "bēi"),
+    ('𧼤', "dào"),
+    ('𧼨', "jié"),
+    ('𧼩', "chòng"),
+    ('𧼪', "chì"),
+    ('𧼫', "yù"),
+    ('𧼬', "cuī"),
+    ('𧼭', "sù"),
+    ('𧼮', "tì"),
+    ('𧼯', "shù"),
+    ('𧼰', "zhá"),
+    ('𧼱', "fú"),
+    ('𧼳', "chè"),
+    ('𧼴', "fó"),
+    ('𧼵', "hóu"),
+    ('𧼶', "zhá"),
+    ('𧽄', "jié"),
+    ('𧽅', "zhá"),
+    ('𧽆', "zhān"),
+    ('𧽉', "yǎn"),
+    ('𧽊', "hái"),
+    ('𧽋', "wǔ"),
+    ('𧽌', "huá"),
+    ('𧽍', "diān"),
+    ('𧽎', "yáo"),
+    ('𧽏', "sōu"),
+    ('𧽐', "qiān"),
+    ('𧽑', "jí"),
+    ('𧽒', "xiòng"),
+    ('𧽓', "qì"),
+    ('𧽔', "jūn"),
+    ('𧽖', "hái"),
+    ('𧽞', "yǎn"),
+    ('𧽟', "jié"),
+    ('𧽠', "cuī"),
+    ('𧽢', "tuán"),
+    ('𧽣', "zhāng"),
+    ('𧽤', "piāo"),
+    ('𧽥', "lù"),
+    ('𧽦', "zhī"),
+    ('𧽧', "chù"),
+    ('𧽨', "mì"),
+    ('𧽩', "qiāng"),
+    ('𧽫', "liàn"),
+    ('𧽲', "lì"),
+    ('𧽵', "zōng"),
+    ('𧽶', "é"),
+    ('𧽷', "sù"),
+    ('𧽸', "jué"),
+    ('𧽻', "jú"),
+    ('𧽼', "tán"),
+    ('𧽽', "liáo"),
+    ('𧽾', "sān"),
+    ('𧽿', "dòng"),
+    ('𧾁', "zá"),
+    ('𧾂', "zhí"),
+    ('𧾆', "xuàn"),
+    ('𧾇', "líng"),
+    ('𧾊', "dēng"),
+    ('𧾍', "zhān"),
+    ('𧾎', "xuān"),
+    ('𧾏', "qǐn"),
+    ('𧾐', "jiào"),
+    ('𧾑', "pì"),
+    ('𧾔', "hǎn"),
+    ('𧾚', "yú"),
+    ('𧾛', "guó"),
+    ('𧾝', "xún"),
+    ('𧾠', "xún"),
+    ('𧾡', "chán"),
+    ('𧾢', "jié"),
+    ('𧾣', "jú"),
+    ('𧾤', "yǎn"),
+    ('𧾥', "dú"),
+    ('𧾧', "hòng"),
+    ('𧾨', "xiàn"),
+    ('𧾩', "xún"),
+    ('𧾮', "líng"),
+    ('𧾯', "jié"),
+    ('𧾰', "yì"),
+    ('𧾱', "qú"),
+    ('𧾲', "gān"),
+    ('𧾳', "fēng"),
+    ('𧾵', "jué"),
+    ('𧾶', "qū"),
+    ('𧾻', "jiù"),
+    ('𧾽', "jì"),
+    ('𧾾', "jǐ"),
+    ('𧿅', "xí"),
+    ('𧿆', "pāng"),
+    ('𧿈', "kuàng"),
+    ('𧿉', "kù"),
+    ('𧿋', "kù"),
+    ('𧿌', "zhà"),
+    ('𧿏', "bà"),
+    ('𧿒', "chěn"),
+    ('𧿓', "hù"),
+    ('𧿔', "nù"),
+    ('𧿕', "é"),
+    ('𧿖', "xiōng"),
+    ('𧿗', "dǔn"),
+    ('𧿘', "shēng"),
+    ('𧿙', "wán"),
+    ('𧿚', "fēn"),
+    ('𧿛', "zōng"),
+    ('𧿝', "xī"),
+    ('𧿞', "zī"),
+    ('𧿠', "hù"),
+    ('𧿥', "bié"),
+    ('𧿧', "tuò"),
+    ('𧿨', "bǎn"),
+    ('𧿩', "gé"),
+    ('𧿫', "kē"),
+    ('𧿲', "zhuì"),
+    ('𧿳', "fú"),
+    ('𧿴', "mò"),
+    ('𧿵', "jiá"),
+    ('𧿶', "tuó"),
+    ('𧿷', "yù"),
+    ('𧿹', "mǔ"),
+    ('𧿺', "jué"),
+    ('𧿻', "jú"),
+    ('𧿼', "guā"),
+    ('𧿽', "pǒ"),
+    ('𨀀', "nǐ"),
+    ('𨀁', "lóng"),
+    ('𨀄', "wǎ"),
+    ('𨀅', "yǎn"),
+    ('𨀔', "chǒu"),
+    ('𨀕', "kuāng"),
+    ('𨀖', "hài"),
+    ('𨀘', "xiáng"),
+    ('𨀙', "xī"),
+    ('𨀛', "cún"),
+    ('𨀜', "tōng"),
+    ('𨀝', "ruò"),
+    ('𨀟', "duó"),
+    ('𨀠', "chè"),
+    ('𨀤', "lèi"),
+    ('𨀥', "zī"),
+    ('𨀧', "zhěng"),
+    ('𨀨', "zuǒ"),
+    ('𨀫', "kāng"),
+    ('𨀬', "zài"),
+    ('𨀮', "yuān"),
+    ('𨀯', "qióng"),
+    ('𨀳', "fá"),
+    ('𨀴', "xún"),
+    ('𨀶', "jì"),
+    ('𨀸', "chā"),
+    ('𨁀', "shū"),
+    ('𨁁', "xuàn"),
+    ('𨁂', "xié"),
+    ('𨁃', "tī"),
+    ('𨁄', "hàn"),
+    ('𨁅', "xiān"),
+    ('𨁆', "shān"),
+    ('𨁇', "tùn"),
+    ('𨁈', "háng"),
+    ('𨁉', "kǔn"),
+    ('𨁊', "cén"),
+    ('𨁋', "dōu"),
+    ('𨁌', "nuó"),
+    ('𨁍', "yàn"),
+    ('𨁎', "chéng"),
+    ('𨁏', "pū"),
+    ('𨁐', "qì"),
+    ('𨁑', "yuè"),
+    ('𨁒', "fū"),
+    ('𨁗', "tǐng"),
+    ('𨁟', "wǒ"),
+    ('𨁠', "shēng"),
+    ('𨁡', "tuǒ"),
+    ('𨁴', "tǎn"),
+    ('𨁶', "yǎ"),
+    ('𨁷', "zhì"),
+    ('𨁸', "lù"),
+    ('𨁹', "yǎn"),
+    ('𨁺', "jū"),
+    ('𨁽', "dé"),
+    ('𨁿', "chù"),
+    ('𨂀', "zǔ"),
+    ('𨂁', "è"),
+    ('𨂂', "zhí"),
+    ('𨂃', "péng"),
+    ('𨂅', "biē"),
+    ('𨂇', "dǐ"),
+    ('𨂐', "lái"),
+    ('𨂒', "yè"),
+    ('𨂜', "háo"),
+    ('𨂝', "pán"),
+    ('𨂞', "tàn"),
+    ('𨂟', "kāng"),
+    ('𨂠', "xū"),
+    ('𨂡', "zòu"),
+    ('𨂢', "jí"),
+    ('𨂣', "wù"),
+    ('𨂦', "chuàn"),
+    ('𨂩', "pò"),
+    ('𨂪', "yǎn"),
+    ('𨂫', "tuò"),
+    ('𨂭', "dú"),
+    ('𨂯', "pián"),
+    ('𨂰', "chì"),
+    ('𨂱', "hùn"),
+    ('𨂲', "pīng"),
+    ('𨂴', "cōng"),
+    ('𨂵', "zhǎ"),
+    ('𨂺', "wān"),
+    ('𨂿', "wǎi"),
+    ('𨃃', "è"),
+    ('𨃄', "wèi"),
+    ('𨃅', "bāi"),
+    ('𨃇', "jiāng"),
+    ('𨃓', "chá"),
+    ('𨃕', "chù"),
+    ('𨃖', "kuà"),
+    ('𨃗', "téng"),
+    ('𨃘', "zōu"),
+    ('𨃙', "lì"),
+    ('𨃚', "tà"),
+    ('𨃛', "sà"),
+    ('𨃞', "pán"),
+    ('𨃟', "pán"),
+    ('𨃣', "sào"),
+    ('𨃤', "qiāo"),
+    ('𨃭', "zú"),
+    ('𨃯', "zhì"),
+    ('𨃰', "yǎn"),
+    ('𨃲', "jié"),
+    ('𨃳', "néng"),
+    ('𨄄', "luán"),
+    ('𨄅', "qū"),
+    ('𨄇', "dèng"),
+    ('𨄈', "liáng"),
+    ('𨄉', "chǎn"),
+    ('𨄊', "qiè"),
+    ('𨄋', "lòu"),
+    ('𨄌', "dié"),
+    ('𨄍', "cuī"),
+    ('𨄐', "jǐ"),
+    ('𨄓', "cháo"),
+    ('𨄔', "shuàn"),
+    ('𨄕', "zú"),
+    ('𨄗', "kāng"),
+    ('𨄚', "qiāng"),
+    ('𨄛', "lí"),
+    ('𨄮', "shuāi"),
+    ('𨄯', "yù"),
+    ('𨄰', "zhāng"),
+    ('𨄱', "lěi"),
+    ('𨅅', "pó"),
+    ('𨅊', "zhé"),
+    ('𨅋', "xiào"),
+    ('𨅍', "tǎn"),
+    ('𨅎', "cuì"),
+    ('𨅏', "lán"),
+    ('𨅑', "xū"),
+    ('𨅒', "shù"),
+    ('𨅓', "zhǎ"),
+    ('𨅔', "cán"),
+    ('𨅗', "bǐ"),
+    ('𨅘', "pèng"),
+    ('𨅛', "zhú"),
+    ('𨅝', "chéng"),
+    ('𨅣', "qiáo"),
+    ('𨅤', "jī"),
+    ('𨅪', "zhāi"),
+    ('𨅬', "lán"),
+    ('𨆁', "tiǎn"),
+    ('𨆂', "sà"),
+    ('𨆃', "jīn"),
+    ('𨆄', "zhù"),
+    ('𨆅', "duò"),
+    ('𨆇', "chà"),
+    ('𨆈', "juàn"),
+    ('𨆉', "táng"),
+    ('𨆊', "bèng"),
+    ('𨆌', "fán"),
+    ('𨆍', "liè"),
+    ('𨆎', "zéi"),
+    ('𨆏', "suì"),
+    ('𨆙', "sè"),
+    ('𨆧', "zhì"),
+    ('𨆨', "tuí"),
+    ('𨆪', "qīng"),
+    ('𨆬', "chuò"),
+    ('𨆰', "tà"),
+    ('𨆱', "bìng"),
+    ('𨆲', "wěn"),
+    ('𨆵', "pǒ"),
+    ('𨆽', "mó"),
+    ('𨆾', "cā"),
+    ('𨇁', "kuàng"),
+    ('𨇃', "cuó"),
+    ('𨇄', "rǎo"),
+    ('𨇅', "bào"),
+    ('𨇆', "lài"),
+    ('𨇍', "niǎn"),
+    ('𨇎', "lí"),
+    ('𨇕', "jiǎo"),
+    ('𨇖', "lú"),
+    ('𨇗', "lì"),
+    ('𨇘', "lóng"),
+    ('𨇙', "guì"),
+    ('𨇝', "chǎn"),
+    ('𨇤', "xiān"),
+    ('𨇦', "chàn"),
+    ('𨇨', "xiè"),
+    ('𨇩', "zhàn"),
+    ('𨇯', "shuāng"),
+    ('𨇻', "mǐ"),
+    ('𨇼', "luán"),
+    ('𨇽', "luò"),
+    ('𨈀', "diān"),
+    ('𨈈', "dié"),
+    ('𨈊', "wān"),
+    ('𨈋', "yuè"),
+    ('𨈌', "luán"),
+    ('𨈎', "luán"),
+    ('𨈓', "léng"),
+    ('𨈕', "wǎi"),
+    ('𨈗', "nèn"),
+    ('𨈘', "shǎo"),
+    ('𨈙', "xiè"),
+    ('𨈚', "pí"),
+    ('𨈥', "máo"),
+    ('𨈧', "yǐn"),
+    ('𨈩', "bó"),
+    ('𨈫', "zhù"),
+    ('𨈮', "chōng"),
+    ('𨈶', "mǔ"),
+    ('𨈷', "tuó"),
+    ('𨈹', "tǒng"),
+    ('𨈺', "yé"),
+    ('𨉁', "huàng"),
+    ('𨉃', "rèn"),
+    ('𨉅', "yè"),
+    ('𨉋', "tuō"),
+    ('𨉖', "zuān"),
+    ('𨉗', "yù"),
+    ('𨉚', "ā"),
+    ('𨉜', "zhōu"),
+    ('𨉝', "wān"),
+    ('𨉡', "duǒ"),
+    ('𨉢', "zhòng"),
+    ('𨉣', "hā"),
+    ('𨉤', "huáng"),
+    ('𨉥', "miàn"),
+    ('𨉩', "chūn"),
+    ('𨉪', "qiè"),
+    ('𨉫', "gōng"),
+    ('𨉬', "tíng"),
+    ('𨉭', "méi"),
+    ('𨉱', "tàng"),
+    ('𨉴', "róng"),
+    ('𨉷', "róng"),
+    ('𨉸', "qí"),
+    ('𨉹', "guó"),
+    ('𨉽', "xiàng"),
+    ('𨉾', "tián"),
+    ('𨊅', "xiāo"),
+    ('𨊈', "zhān"),
+    ('𨊉', "cuì"),
+    ('𨊔', "lán"),
+    ('𨊘', "shēn"),
+    ('𨊚', "lěi"),
+    ('𨊛', "lì"),
+    ('𨊝', "chān"),
+    ('𨊞', "niè"),
+    ('𨊟', "luán"),
+    ('𨊡', "tīng"),
+    ('𨊢', "huì"),
+    ('𨊧', "gōng"),
+    ('𨊰', "qì"),
+    ('𨊱', "yú"),
+    ('𨊳', "xīn"),
+    ('𨊸', "yuè"),
+    ('𨊹', "bā"),
+    ('𨊺', "dài"),
+    ('𨊻', "jī"),
+    ('𨊼', "xuàn"),
+    ('𨊿', "jué"),
+    ('𨋀', "niǔ"),
+    ('𨋈', "dù"),
+    ('𨋉', "jí"),
+    ('𨋐', "pā"),
+    ('𨋑', "gǒng"),
+    ('𨋒', "bèn"),
+    ('𨋔', "kēng"),
+    ('𨋕', "yàng"),
+    ('𨋖', "liǔ"),
+    ('𨋗', "ní"),
+    ('𨋘', "zhà"),
+    ('𨋙', "yìn"),
+    ('𨋚', "niǎn"),
+    ('𨋛', "pào"),
+    ('𨋝', "gōng"),
+    ('𨋞', "bù"),
+    ('𨋟', "hé"),
+    ('𨋠', "rǒng"),
+    ('𨋡', "guì"),
+    ('𨋥', "bì"),
+    ('𨋦', "xī"),
+    ('𨋧', "jú"),
+    ('𨋨', "hún"),
+    ('𨋩', "bì"),
+    ('𨋫', "tiāo"),
+    ('𨋬', "zhěng"),
+    ('𨋮', "hōng"),
+    ('𨋯', "yì"),
+    ('𨋰', "cì"),
+    ('𨋲', "bìng"),
+    ('𨋷', "gōng"),
+    ('𨋺', "fá"),
+    ('𨋽', "yáng"),
+    ('𨋾', "xǔ"),
+    ('𨌁', "hōng"),
+    ('𨌄', "zàng"),
+    ('𨌅', "chái"),
+    ('𨌆', "hóng"),
+    ('𨌈', "tián"),
+    ('𨌌', "zhī"),
+    ('𨌍', "xīng"),
+    ('𨌎', "xú"),
+    ('𨌑', "zhèn"),
+    ('𨌔', "wǎn"),
+    ('𨌘', "jùn"),
+    ('𨌝', "wò"),
+    ('𨌠', "lù"),
+    ('𨌢', "zhēng"),
+    ('𨌣', "rǒng"),
+    ('𨌤', "chéng"),
+    ('𨌥', "fú"),
+    ('𨌧', "è"),
+    ('𨌨', "tāo"),
+    ('𨌩', "táng"),
+    ('𨌫', "juān"),
+    ('𨌬', "chào"),
+    ('𨌭', "tà"),
+    ('𨌮', "dǐ"),
+    ('𨌰', "zōng"),
+    ('𨌳', "kēng"),
+    ('𨌴', "tuī"),
+    ('𨌶', "kēng"),
+    ('𨍅', "rǒng"),
+    ('𨍆', "yūn"),
+    ('𨍇', "hé"),
+    ('𨍈', "zǒng"),
+    ('𨍉', "cōng"),
+    ('𨍊', "qiū"),
+    ('𨍎', "mù"),
+    ('𨍏', "duó"),
+    ('𨍐', "xǔ"),
+    ('𨍑', "kēng"),
+    ('𨍒', "xiàn"),
+    ('𨍛', "dú"),
+    ('𨍜', "kǎn"),
+    ('𨍞', "yīng"),
+    ('𨍢', "zī"),
+    ('𨍧', "huáng"),
+    ('𨍩', "péng"),
+    ('𨍫', "lì"),
+    ('𨍭', "bó"),
+    ('𨍮', "gé"),
+    ('𨍯', "jú"),
+    ('𨍰', "kē"),
+    ('𨍲', "hú"),
+    ('𨍳', "yáo"),
+    ('𨍴', "táng"),
+    ('𨍶', "qióng"),
+    ('𨍷', "rǒng"),
+    ('𨍸', "liǔ"),
+    ('𨍹', "huì"),
+    ('𨍺', "jī"),
+    ('𨎉', "zhì"),
+    ('𨎋', "táng"),
+    ('𨎌', "zhǐ"),
+    ('𨎍', "kāng"),
+    ('𨎔', "yàng"),
+    ('𨎖', "tǎng"),
+    ('𨎗', "hōng"),
+    ('𨎛', "liáng"),
+    ('𨎝', "cáo"),
+    ('𨎡', "nǎi"),
+    ('𨎢', "zǒng"),
+    ('𨎤', "dèng"),
+    ('𨎦', "jiāo"),
+    ('𨎧', "péng"),
+    ('𨎩', "guāng"),
+    ('𨎪', "ér"),
+    ('𨎫', "jiàn"),
+    ('𨎬', "jiào"),
+    ('𨎭', "nuó"),
+    ('𨎮', "zǎo"),
+    ('𨎳', "péng"),
+    ('𨎴', "dāng"),
+    ('𨎶', "qú"),
+    ('𨎷', "lián"),
+    ('𨎸', "mù"),
+    ('𨎹', "lǎn"),
+    ('𨎾', "fén"),
+    ('𨏂', "hún"),
+    ('𨏆', "kuāng"),
+    ('𨏈', "yǐn"),
+    ('𨏉', "shuàn"),
+    ('𨏊', "jiàn"),
+    ('𨏒', "luò"),
+    ('𨏔', "lù"),
+    ('𨏚', "gé"),
+    ('𨏛', "rǎng"),
+    ('𨏞', "pín"),
+    ('𨏠', "lóng"),
+    ('𨏤', "zhěn"),
+    ('𨏥', "xiàn"),
+    ('𨏨', "lìn"),
+    ('𨏩', "lián"),
+    ('𨏪', "shān"),
+    ('𨏫', "bó"),
+    ('𨏬', "lì"),
+    ('𨏳', "xié"),
+    ('𨏴', "gé"),
+    ('𨏵', "mǐn"),
+    ('𨏶', "lián"),
+    ('𨏹', "jué"),
+    ('𨏺', "zhōu"),
+    ('𨏿', "kē"),
+    ('𨐁', "dié"),
+    ('𨐃', "zhé"),
+    ('𨐅', "shū"),
+    ('𨐆', "jī"),
+    ('𨐇', "lóng"),
+    ('𨐈', "guāng"),
+    ('𨐉', "zǎo"),
+    ('𨐊', "xiàn"),
+    ('𨐋', "qiān"),
+    ('𨐍', "shēn"),
+    ('𨐐', "yǐn"),
+    ('𨐑', "jiè"),
+    ('𨐔', "shēn"),
+    ('𨐕', "shēn"),
+    ('𨐖', "sǎ"),
+    ('𨐛', "xì"),
+    ('𨐡', "kù"),
+    ('𨐣', "qú"),
+    ('𨐥', "gé"),
+    ('𨐦', "bàn"),
+    ('𨐨', "bì"),
+    ('𨐩', "qiān"),
+    ('𨐰', "bīn"),
+    ('𨐱', "bàn"),
+    ('𨐳', "zuò"),
+    ('𨐴', "pì"),
+    ('𨐶', "huò"),
+    ('𨐾', "bàn"),
+    ('𨑊', "nóng"),
+    ('𨑌', "chén"),
+    ('𨑎', "pēng"),
+    ('𨑑', "fǔ"),
+    ('𨑒', "tú"),
+    ('𨑜', "pǐ"),
+    ('𨑝', "pò"),
+    ('𨑠', "chǐ"),
+    ('𨑣', "xuè"),
+    ('𨑤', "qì"),
+    ('𨑥', "wù"),
+    ('𨑨', "zhì"),
+    ('𨑩', "dì"),
+    ('𨑪', "cōng"),
+    ('𨑫', "yóu"),
+    ('𨑹', "cōng"),
+    ('𨑼', "dì"),
+    ('𨑽', "zhuó"),
+    ('𨑿', "zǒu"),
+    ('𨒀', "cóng"),
+    ('𨒃', "pàn"),
+    ('𨒄', "yǎn"),
+    ('𨒅', "qì"),
+    ('𨒆', "rǒng"),
+    ('𨒇', "jiá"),
+    ('𨒉', "zhì"),
+    ('𨒊', "qiú"),
+    ('𨒋', "yuè"),
+    ('𨒍', "shì"),
+    ('𨒑', "háo"),
+    ('𨒙', "tuō"),
+    ('𨒜', "bié"),
+    ('𨒞', "kàn"),
+    ('𨒢', "chuò"),
+    ('𨒤', "cǐ"),
+    ('𨒦', "yǐn"),
+    ('𨒧', "shì"),
+    ('𨒨', "hài"),
+    ('𨒩', "ruǎn"),
+    ('𨒫', "yáng"),
+    ('𨒬', "chī"),
+    ('𨒮', "cī"),
+    ('𨒱', "gōng"),
+    ('𨒲', "mí"),
+    ('𨒴', "jǐ"),
+    ('𨒼', "gèn"),
+    ('𨒽', "zào"),
+    ('𨓁', "běng"),
+    ('𨓇', "xǐn"),
+    ('𨓈', "kuò"),
+    ('𨓊', "dié"),
+    ('𨓍', "tíng"),
+    ('𨓚', "shuì"),
+    ('𨓞', "dài"),
+    ('𨓦', "lǐ"),
+    ('𨓨', "yǒng"),
+    ('𨓩', "jiāo"),
+    ('𨓬', "tá"),
+    ('𨓭', "qǔ"),
+    ('𨓮', "yín"),
+    ('𨓯', "yuān"),
+    ('𨓰', "jié"),
+    ('𨓲', "qiān"),
+    ('𨓳', "yāo"),
+    ('𨓴', "yà"),
+    ('𨓷', "qīng"),
+    ('𨓿', "péi"),
+    ('𨔗', "jiā"),
+    ('𨔙', "tòu"),
+    ('𨔛', "tī"),
+    ('𨔡', "dùn"),
+    ('𨔢', "chǎn"),
+    ('𨔣', "jiā"),
+    ('𨔤', "chì"),
+    ('𨔥', "jiān"),
+    ('𨔦', "shù"),
+    ('𨔯', "tà"),
+    ('𨕕', "zhī"),
+    ('𨕗', "yuán"),
+    ('𨕚', "hū"),
+    ('𨕜', "liè"),
+    ('𨕠', "zé"),
+    ('𨕢', "chù"),
+    ('𨕦', "qiù"),
+    ('𨕧', "bēng"),
+    ('𨕹', "huán"),
+    ('𨕺', "kuā"),
+    ('𨕻', "shēng"),
+    ('𨕽', "jié"),
+    ('𨕿', "wǎng"),
+    ('𨖃', "hū"),
+    ('𨖊', "zé"),
+    ('𨖋', "zǎn"),
+    ('𨖌', "yàng"),
+    ('𨖎', "chǐ"),
+    ('𨖏', "jiù"),
+    ('𨖚', "liáo"),
+    ('𨖛', "yū"),
+    ('𨖠', "biǎn"),
+    ('𨖢', "kuáng"),
+    ('𨖬', "chòu"),
+    ('𨖭', "yá"),
+    ('𨖮', "zhuó"),
+    ('𨖰', "qiè"),
+    ('𨖱', "xiàn"),
+    ('𨖳', "yuān"),
+    ('𨖴', "wǔ"),
+    ('𨖵', "jiǎo"),
+    ('𨖶', "xiàng"),
+    ('𨖷', "shà"),
+    ('𨖹', "zhì"),
+    ('𨖼', "chòng"),
+    ('𨖾', "biān"),
+    ('𨖿', "wēi"),
+    ('𨗓', "dào"),
+    ('𨗝', "yù"),
+    ('𨗞', "tuí"),
+    ('𨗡', "chào"),
+    ('𨗥', "huì"),
+    ('𨗦', "qiǎn"),
+    ('𨗨', "wěi"),
+    ('𨗰', "yóu"),
+    ('𨗼', "dì"),
+    ('𨗾', "dà"),
+    ('𨘁', "yóu"),
+    ('𨘂', "jiù"),
+    ('𨘃', "tuí"),
+    ('𨘄', "zǎn"),
+    ('𨘇', "huì"),
+    ('𨘉', "shà"),
+    ('𨘌', "huò"),
+    ('𨘔', "yáo"),
+    ('𨘙', "xiàn"),
+    ('𨘞', "xiàn"),
+    ('𨘬', "dì"),
+    ('𨘮', "jiù"),
+    ('𨘲', "huì"),
+    ('𨘴', "kào"),
+    ('𨘵', "yóu"),
+    ('𨘸', "lì"),
+    ('𨘼', "chuán"),
+    ('𨘾', "chí"),
+    ('𨙀', "huò"),
+    ('𨙂', "yóu"),
+    ('𨙄', "yuè"),
+    ('𨙎', "tà"),
+    ('𨙏', "zàn"),
+    ('𨙓', "niè"),
+    ('𨙔', "zhù"),
+    ('𨙡', "xiǎn"),
+    ('𨙩', "shí"),
+    ('𨙫', "kǒu"),
+    ('𨙬', "qǐ"),
+    ('𨙭', "tǔ"),
+    ('𨙮', "fán"),
+    ('𨙯', "cūn"),
+    ('𨙲', "tún"),
+    ('𨙳', "chā"),
+    ('𨙴', "cái"),
+    ('𨙵', "xiàng"),
+    ('𨙶', "pèi"),
+    ('𨙷', "jǐng"),
+    ('𨙸', "qí"),
+    ('𨙹', "shǎo"),
+    ('𨙺', "niǔ"),
+    ('𨙻', "nà"),
+    ('𨙽', "qín"),
+    ('𨚍', "bì"),
+    ('𨚓', "bì"),
+    ('𨚔', "bāo"),
+    ('𨚕', "biàn"),
+    ('𨚖', "zī"),
+    ('𨚗', "nà"),
+    ('𨚘', "wèi"),
+    ('𨚙', "háo"),
+    ('𨚡', "jǐn"),
+    ('𨚣', "zhèng"),
+    ('𨚧', "qié"),
+    ('𨚮', "hào"),
+    ('𨚯', "tóng"),
+    ('𨚰', "zǎo"),
+    ('𨚱', "shèng"),
+    ('𨚲', "cún"),
+    ('𨚳', "huāng"),
+    ('𨚴', "rú"),
+    ('𨚵', "zài"),
+    ('𨚶', "nián"),
+    ('𨚾', "xiān"),
+    ('𨛈', "quán"),
+    ('𨛉', "jì"),
+    ('𨛊', "yín"),
+    ('𨛋', "lǐ"),
+    ('𨛌', "máng"),
+    ('𨛍', "shào"),
+    ('𨛎', "hàn"),
+    ('𨛏', "cuò"),
+    ('𨛐', "jùn"),
+    ('𨛑', "jì"),
+    ('𨛒', "bù"),
+    ('𨛓', "lòng"),
+    ('𨛔', "fǒu"),
+    ('𨛕', "yóu"),
+    ('𨛖', "kuài"),
+    ('𨛜', "xiàng"),
+    ('𨛡', "yún"),
+    ('𨛣', "qín"),
+    ('𨛤', "huí"),
+    ('𨛥', "pú"),
+    ('𨛫', "lí"),
+    ('𨛬', "péi"),
+    ('𨛭', "shū"),
+    ('𨛮', "jū"),
+    ('𨛯', "yí"),
+    ('𨛰', "zhēng"),
+    ('𨛱', "chóng"),
+    ('𨛳', "xí"),
+    ('𨛵', "hǔ"),
+    ('𨛶', "róu"),
+    ('𨜌', "huàn"),
+    ('𨜍', "qiào"),
+    ('𨜎', "zhī"),
+    ('𨜏', "yíng"),
+    ('𨜐', "xǐ"),
+    ('𨜑', "qiāo"),
+    ('𨜒', "jì"),
+    ('𨜓', "zhēng"),
+    ('𨜔', "huáng"),
+    ('𨜖', "yú"),
+    ('𨜗', "zōu"),
+    ('𨜘', "méi"),
+    ('𨜜', "shěng"),
+    ('𨜩', "quán"),
+    ('𨜰', "jiāng"),
+    ('𨜱', "hé"),
+    ('𨜳', "tóng"),
+    ('𨜴', "hé"),
+    ('𨜵', "wēn"),
+    ('𨜶', "yì"),
+    ('𨜷', "páng"),
+    ('𨜺', "wēng"),
+    ('𨜻', "qián"),
+    ('𨜼', "lì"),
+    ('𨜽', "yí"),
+    ('𨜾', "chuàng"),
+    ('𨜿', "xù"),
+    ('𨝀', "wěi"),
+    ('𨝆', "gē"),
+    ('𨝈', "yǔ"),
+    ('𨝋', "zhài"),
+    ('𨝌', "gān"),
+    ('𨝍', "qiān"),
+    ('𨝎', "kāng"),
+    ('𨝏', "lí"),
+    ('𨝐', "shēn"),
+    ('𨝑', "guàn"),
+    ('𨝓', "piáo"),
+    ('𨝕', "gài"),
+    ('𨝖', "lí"),
+    ('𨝘', "hǔ"),
+    ('𨝛', "tú"),
+    ('𨝜', "shùn"),
+    ('𨝞', "hù"),
+    ('𨝟', "lí"),
+    ('𨝢', "lòu"),
+    ('𨝦', "dàng"),
+    ('𨝨', "zuò"),
+    ('𨝩', "shān"),
+    ('𨝫', "shè"),
+    ('𨝭', "féng"),
+    ('𨝮', "jù"),
+    ('𨝯', "tóng"),
+    ('𨝰', "jiǎo"),
+    ('𨝱', "qiáo"),
+    ('𨝲', "gāo"),
+    ('𨝳', "zī"),
+    ('𨝴', "huáng"),
+    ('𨝵', "shān"),
+    ('𨝸', "tán"),
+    ('𨞌', "tuō"),
+    ('𨞎', "lìng"),
+    ('𨞐', "chéng"),
+    ('𨞑', "wèng"),
+    ('𨞒', "zuó"),
+    ('𨞓', "yù"),
+    ('𨞕', "zhú"),
+    ('𨞗', "qún"),
+    ('𨞘', "xǐ"),
+    ('𨞙', "qú"),
+    ('𨞛', "gé"),
+    ('𨞢', "qī"),
+    ('𨞣', "xū"),
+    ('𨞨', "gài"),
+    ('𨞩', "què"),
+    ('𨞪', "chóu"),
+    ('𨞫', "méng"),
+    ('𨞲', "shēn"),
+    ('𨞶', "qiāo"),
+    ('𨞷', "cán"),
+    ('𨞺', "lì"),
+    ('𨞼', "wàn"),
+    ('𨞽', "léi"),
+    ('𨞾', "xīng"),
+    ('𨞿', "láng"),
+    ('𨟂', "shì"),
+    ('𨟃', "zhēng"),
+    ('𨟄', "fán"),
+    ('𨟊', "zhì"),
+    ('𨟏', "yín"),
+    ('𨟑', "lì"),
+    ('𨟖', "mó"),
+    ('𨟗', "wěi"),
+    ('𨟙', "yīng"),
+    ('𨟚', "ráng"),
+    ('𨟠', "quān"),
+    ('𨟥', "luǒ"),
+    ('𨟲', "dài"),
+    ('𨟴', "yìn"),
+    ('𨟵', "bǐ"),
+    ('𨟶', "gē"),
+    ('𨟸', "wèn"),
+    ('𨟹', "yǎn"),
+    ('𨟺', "miǎn"),
+    ('𨟼', "gǎng"),
+    ('𨟽', "qiú"),
+    ('𨟾', "zhī"),
+    ('𨠋', "gū"),
+    ('𨠌', "tóng"),
+    ('𨠎', "líng"),
+    ('𨠏', "tí"),
+    ('𨠐', "cí"),
+    ('𨠑', "yí"),
+    ('𨠒', "fàn"),
+    ('𨠓', "pō"),
+    ('𨠔', "bì"),
+    ('𨠖', "bào"),
+    ('𨠟', "pēng"),
+    ('𨠡', "suān"),
+    ('𨠤', "sōng"),
+    ('𨠥', "wéi"),
+    ('𨠦', "xiáo"),
+    ('𨠨', "jì"),
+    ('𨠬', "hào"),
+    ('𨠭', "yǎn"),
+    ('𨠶', "yí"),
+    ('𨠷', "zāo"),
+    ('𨠸', "yǐng"),
+    ('𨠹', "nǎn"),
+    ('𨠿', "zā"),
+    ('𨡁', "tiǎn"),
+    ('𨡂', "xī"),
+    ('𨡃', "jiào"),
+    ('𨡄', "yán"),
+    ('𨡌', "néi"),
+    ('𨡍', "tǎn"),
+    ('𨡎', "yàn"),
+    ('𨡏', "tiǎn"),
+    ('𨡐', "zhì"),
+    ('𨡑', "chōu"),
+    ('𨡒', "táo"),
+    ('𨡗', "zhà"),
+    ('𨡙', "dān"),
+    ('𨡞', "miǎn"),
+    ('𨡡', "wǔ"),
+    ('𨡢', "yǐn"),
+    ('𨡣', "yàn"),
+    ('𨡤', "lǎo"),
+    ('𨡩', "pō"),
+    ('𨡫', "hùn"),
+    ('𨡬', "hǎi"),
+    ('𨡭', "mú"),
+    ('𨡮', "cōng"),
+    ('𨡱', "kù"),
+    ('𨡲', "chōu"),
+    ('𨡴', "yǒu"),
+    ('𨡸', "zhuó"),
+    ('𨡺', "kuì"),
+    ('𨡻', "sōu"),
+    ('𨢂', "yìn"),
+    ('𨢅', "zuì"),
+    ('𨢆', "sāng"),
+    ('𨢇', "liù"),
+    ('𨢈', "hàn"),
+    ('𨢉', "wèi"),
+    ('𨢊', "méng"),
+    ('𨢋', "hú"),
+    ('𨢌', "lì"),
+    ('𨢎', "mì"),
+    ('𨢐', "bāng"),
+    ('𨢑', "jiǎn"),
+    ('𨢜', "què"),
+    ('𨢠', "méng"),
+    ('𨢢', "mú"),
+    ('𨢣', "hǒng"),
+    ('𨢤', "hù"),
+    ('𨢥', "mí"),
+    ('𨢦', "shài"),
+    ('𨢩', "shāng"),
+    ('𨢪', "chào"),
+    ('𨢬', "zhuó"),
+    ('𨢮', "zhī"),
+    ('𨢯', "niàn"),
+    ('𨢵', "jì"),
+    ('𨢸', "kē"),
+    ('𨢹', "zhēng"),
+    ('𨢿', "dān"),
+    ('𨣀', "liǎo"),
+    ('𨣁', "zhǎn"),
+    ('𨣂', "gǒng"),
+    ('𨣃', "láo"),
+    ('𨣄', "huā"),
+    ('𨣅', "chuài"),
+    ('𨣇', "jiǎn"),
+    ('𨣈', "kuì"),
+    ('𨣍', "shē"),
+    ('𨣔', "chěn"),
+    ('𨣕', "tǎn"),
+    ('𨣗', "hú"),
+    ('𨣘', "méng"),
+    ('𨣙', "pào"),
+    ('𨣚', "zhǎn"),
+    ('𨣛', "cháng"),
+    ('𨣝', "gǎn"),
+    ('𨣠', "yì"),
+    ('𨣢', "suì"),
+    ('𨣦', "xù"),
+    ('𨣧', "jì"),
+    ('𨣨', "làn"),
+    ('𨣬', "yí"),
+    ('𨣯', "mì"),
+    ('𨣱', "miè"),
+    ('𨣵', "cuán"),
+    ('𨣸', "lǎn"),
+    ('𨣻', "yān"),
+    ('𨣾', "mí"),
+    ('𨤂', "yǒng"),
+    ('𨤃', "cáng"),
+    ('𨤄', "jiǎn"),
+    ('𨤇', "sōu"),
+    ('𨤎', "yán"),
+    ('𨤑', "juàn"),
+    ('𨤕', "è"),
+    ('𨤘', "fèn"),
+    ('𨤚', "fèn"),
+    ('𨤡', "guàng"),
+    ('𨤢', "mái"),
+    ('𨤤', "liě"),
+    ('𨤩', "chōng"),
+    ('𨤫', "lí"),
+    ('𨤱', "zhí"),
+    ('𨤴', "xiè"),
+    ('𨤷', "chóu"),
+    ('𨤹', "jí"),
+    ('𨤽', "pī"),
+    ('𨥂', "jié"),
+    ('𨥇', "zhǒu"),
+    ('𨥍', "xiōng"),
+    ('𨥑', "kuàng"),
+    ('𨥙', "jǐng"),
+    ('𨥛', "hù"),
+    ('𨥞', "qián"),
+    ('𨥣', "cén"),
+    ('𨥦', "qí"),
+    ('𨥧', "wǎn"),
+    ('𨥨', "máo"),
+    ('𨥪', "dǒu"),
+    ('𨥴', "kǒu"),
+    ('𨥶', "dài"),
+    ('𨥸', "náo"),
+    ('𨥺', "hóng"),
+    ('𨦂', "lǎi"),
+    ('𨦃', "duǒ"),
+    ('𨦄', "qiān"),
+    ('𨦆', "yín"),
+    ('𨦖', "lòu"),
+    ('𨦗', "huī"),
+    ('𨦛', "fù"),
+    ('𨦜', "máo"),
+    ('𨦞', "zhōu"),
+    ('𨦡', "yóng"),
+    ('𨦭', "láo"),
+    ('𨦮', "jí"),
+    ('𨦯', "yì"),
+    ('𨦰', "liú"),
+    ('𨦱', "cōng"),
+    ('𨦳', "nǎn"),
+    ('𨧀', "dù"),
+    ('𨧐', "tūn"),
+    ('𨧑', "xiàng"),
+    ('𨧕', "biàn"),
+    ('𨧖', "chuáng"),
+    ('𨧗', "wù"),
+    ('𨧙', "jū"),
+    ('𨧥', "xiē"),
+    ('𨧦', "pī"),
+    ('𨧧', "zhuó"),
+    ('𨧨', "ruì"),
+    ('𨧪', "sào"),
+    ('𨧫', "zì"),
+    ('𨧭', "zhèng"),
+    ('𨧮', "mí"),
+    ('𨧰', "zú"),
+    ('𨧱', "qū"),
+    ('𨧳', "chì"),
+    ('𨧵', "zhì"),
+    ('𨨏', "bō"),
+    ('𨨗', "quàn"),
+    ('𨨘', "qiān"),
+    ('𨨙', "yā"),
+    ('𨨚', "chào"),
+    ('𨨛', "hé"),
+    ('𨨜', "rǔ"),
+    ('𨨠', "jū"),
+    ('𨨡', "wù"),
+    ('𨨬', "chì"),
+    ('𨨭', "kuàng"),
+    ('𨨯', "còu"),
+    ('𨨰', "ruàn"),
+    ('𨨱', "kuò"),
+    ('𨨲', "chí"),
+    ('𨨳', "zú"),
+    ('𨨴', "jiāo"),
+    ('𨨶', "yú"),
+    ('𨨷', "tú"),
+    ('𨨸', "méng"),
+    ('𨨹', "dā"),
+    ('𨨺', "shuò"),
+    ('𨩥', "fēng"),
+    ('𨩦', "gǒu"),
+    ('𨩧', "dōng"),
+    ('𨩨', "chǎ"),
+    ('𨩩', "mào"),
+    ('𨩪', "chǎn"),
+    ('𨩫', "biān"),
+    ('𨩬', "yù"),
+    ('𨩯', "wán"),
+    ('𨩰', "zú"),
+    ('𨩲', "zī"),
+    ('𨩴', "chuān"),
+    ('𨩵', "wǎn"),
+    ('𨩶', "wā"),
+    ('𨩸', "quān"),
+    ('𨩻', "wǎn"),
+    ('𨩽', "xià"),
+    ('𨪄', "yìng"),
+    ('𨪅', "jiàn"),
+    ('𨪈', "wěi"),
+    ('𨪉', "tí"),
+    ('𨪊', "sāo"),
+    ('𨪌', "qí"),
+    ('𨪍', "shā"),
+    ('𨪎', "yù"),
+    ('𨪏', "jí"),
+    ('𨪐', "dòu"),
+    ('𨪑', "chǎn"),
+    ('𨪒', "tuán"),
+    ('𨪕', "liú"),
+    ('𨪗', "zhuì"),
+    ('𨪳', "ruàn"),
+    ('𨪶', "yàn"),
+    ('𨪷', "gǔ"),
+    ('𨪹', "lì"),
+    ('𨪺', "chā"),
+    ('𨪾', "dì"),
+    ('𨪿', "liú"),
+    ('𨫀', "zhǎn"),
+    ('𨫁', "pō"),
+    ('𨫒', "lòu"),
+    ('𨫔', "zhì"),
+    ('𨬁', "lián"),
+    ('𨬅', "luǒ"),
+    ('𨬍', "duò"),
+    ('𨬐', "jué"),
+    ('𨬑', "lì"),
+    ('𨬒', "lán"),
+    ('𨬔', "ruàn"),
+    ('𨬕', "gū"),
+    ('𨬖', "chán"),
+    ('𨬗', "xū"),
+    ('𨬚', "zhǐ"),
+    ('𨭁', "xuè"),
+    ('𨭂', "bō"),
+    ('𨭃', "chēng"),
+    ('𨭅', "zhù"),
+    ('𨭆', "hēi"),
+    ('𨭉', "bān"),
+    ('𨭎', "xǐ"),
+    ('𨭓', "dié"),
+    ('𨭖', "zhǎn"),
+    ('𨭗', "guó"),
+    ('𨭚', "biāo"),
+    ('𨭛', "là"),
+    ('𨭺', "jīn"),
+    ('𨮂', "gǎi"),
+    ('𨮒', "mèng"),
+    ('𨮔', "yù"),
+    ('𨮪', "xǐ"),
+    ('𨮬', "piāo"),
+    ('𨮭', "sī"),
+    ('𨮴', "dèng"),
+    ('𨮸', "chuō"),
+    ('𨮹', "dí"),
+    ('𨮺', "jī"),
+    ('𨮻', "chán"),
+    ('𨮿', "zhuó"),
+    ('𨯓', "cài"),
+    ('𨯞', "jiàng"),
+    ('𨯲', "tóu"),
+    ('𨯽', "lí"),
+    ('𨰂', "qiàn"),
+    ('𨰆', "chuō"),
+    ('𨰏', "tà"),
+    ('𨰑', "diào"),
+    ('𨰓', "jiǎn"),
+    ('𨰛', "zhǐ"),
+    ('𨰜', "jué"),
+    ('𨰞', "mó"),
+    ('𨰠', "luó"),
+    ('𨰦', "bǎo"),
+    ('𨰭', "zuǎn"),
+    ('𨰵', "zhē"),
+    ('𨰸', "yú"),
+    ('𨰻', "bǎo"),
+    ('𨰾', "mǎ"),
+    ('𨰿', "xì"),
+    ('𨱀', "hù"),
+    ('𨱁', "yì"),
+    ('𨱂', "é"),
+    ('𨱃', "gū"),
+    ('𨱄', "tú"),
+    ('𨱅', "zhēn"),
+    ('𨱇', "qiú"),
+    ('𨱈', "sù"),
+    ('𨱉', "liàng"),
+    ('𨱊', "qū"),
+    ('𨱋', "líng"),
+    ('𨱌', "guàn"),
+    ('𨱍', "láng"),
+    ('𨱎', "tōu"),
+    ('𨱏', "dā"),
+    ('𨱐', "lòu"),
+    ('𨱑', "huáng"),
+    ('𨱒', "shòu"),
+    ('𨱓', "jiāo"),
+    ('𨱔', "zūn"),
+    ('𨱕', "gǎi"),
+    ('𨱖', "wéi"),
+    ('𨱙', "kūn"),
+    ('𨱚', "duàn"),
+    ('𨱛', "sōng"),
+    ('𨱜', "qí"),
+    ('𨱝', "yǎng"),
+    ('𨱡', "shì"),
+    ('𨱣', "gǎi"),
+    ('𨱦', "dào"),
+    ('𨱧', "yǎo"),
+    ('𨱫', "qián"),
+    ('𨱭', "shāo"),
+    ('𨱮', "cháng"),
+    ('𨱯', "miǔ"),
+    ('𨱱', "mó"),
+    ('𨱵', "nǎo"),
+    ('𨱸', "cōng"),
+    ('𨱺', "niè"),
+    ('𨱻', "zhāo"),
+    ('𨱼', "cén"),
+    ('𨱿', "sōng"),
+    ('𨲀', "niè"),
+    ('𨲁', "cì"),
+    ('𨲄', "jùn"),
+    ('𨲆', "shāo"),
+    ('𨲈', "zhú"),
+    ('𨲉', "duǒ"),
+    ('𨲊', "àn"),
+    ('𨲋', "bī"),
+    ('𨲎', "tì"),
+    ('𨲐', "pǐ"),
+    ('𨲑', "xiá"),
+    ('𨲒', "qiú"),
+    ('𨲓', "shěng"),
+    ('𨲗', "tāng"),
+    ('𨲛', "mán"),
+    ('𨲜', "piān"),
+    ('𨲞', "tì"),
+    ('𨲟', "róng"),
+    ('𨲧', "cōng"),
+    ('𨲪', "jī"),
+    ('𨲫', "féng"),
+    ('𨲬', "wù"),
+    ('𨲭', "jiào"),
+    ('𨲮', "láo"),
+    ('𨲯', "zēng"),
+    ('𨲰', "péng"),
+    ('𨲱', "cǎn"),
+    ('𨲳', "nóng"),
+    ('𨲵', "chǎn"),
+    ('𨲾', "mán"),
+    ('𨲿', "guì"),
+    ('𨳀', "niào"),
+    ('𨳁', "chōng"),
+    ('𨳂', "chàn"),
+    ('𨳆', "nàng"),
+    ('𨳉', "xiā"),
+    ('𨳊', "jiū"),
+    ('𨳋', "jǐ"),
+    ('𨳌', "zhèn"),
+    ('𨳑', "tǐng"),
+    ('𨳔', "mén"),
+    ('𨳕', "yuè"),
+    ('𨳗', "zhōng"),
+    ('𨳘', "tún"),
+    ('𨳙', "ruì"),
+    ('𨳚', "xiè"),
+    ('𨳛', "xī"),
+    ('𨳝', "tǐng"),
+    ('𨳞', "niǔ"),
+    ('𨳠', "wǎng"),
+    ('𨳡', "jiān"),
+    ('𨳣', "fēn"),
+    ('𨳲', "biàn"),
+    ('𨳷', "yí"),
+    ('𨳺', "dié"),
+    ('𨳻', "jī"),
+    ('𨳼', "gǎn"),
+    ('𨳿', "jiān"),
+    ('𨴀', "jiōng"),
+    ('𨴆', "kāi"),
+    ('𨴊', "què"),
+    ('𨴌', "nán"),
+    ('𨴍', "móu"),
+    ('𨴎', "xù"),
+    ('𨴏', "sǒng"),
+    ('𨴐', "shèn"),
+    ('𨴑', "kuāng"),
+    ('𨴒', "què"),
+    ('𨴓', "wéi"),
+    ('𨴗', "dié"),
+    ('𨴘', "nán"),
+    ('𨴚', "ruò"),
+    ('𨴛', "gōng"),
+    ('𨴜', "dòu"),
+    ('𨴞', "niǎn"),
+    ('𨴡', "chāo"),
+    ('𨴢', "hé"),
+    ('𨴣', "yàn"),
+    ('𨴩', "tú"),
+    ('𨴪', "bǔ"),
+    ('𨴬', "hú"),
+    ('𨴭', "yǒng"),
+    ('𨴯', "shǐ"),
+    ('𨴰', "chù"),
+    ('𨴹', "xiāo"),
+    ('𨴺', "mén"),
+    ('𨴻', "lǐ"),
+    ('𨴼', "tí"),
+    ('𨴾', "jiān"),
+    ('𨵂', "zhǐ"),
+    ('𨵃', "guā"),
+    ('𨵄', "guǎn"),
+    ('𨵆', "qì"),
+    ('𨵈', "fēi"),
+    ('𨵉', "yǔ"),
+    ('𨵊', "zhé"),
+    ('𨵋', "wěi"),
+    ('𨵌', "ě"),
+    ('𨵍', "chān"),
+    ('𨵎', "xī"),
+    ('𨵐', "gǔ"),
+    ('𨵗', "què"),
+    ('𨵘', "huì"),
+    ('𨵚', "xié"),
+    ('𨵛', "yīng"),
+    ('𨵝', "tà"),
+    ('𨵞', "wāi"),
+    ('𨵟', "fú"),
+    ('𨵠', "jiè"),
+    ('𨵡', "pì"),
+    ('𨵥', "shěng"),
+    ('𨵦', "yú"),
+    ('𨵧', "kuā"),
+    ('𨵩', "pì"),
+    ('𨵪', "xié"),
+    ('𨵫', "nüè"),
+    ('𨵬', "xiàn"),
+    ('𨵭', "jiàn"),
+    ('𨵮', "xù"),
+    ('𨵰', "bì"),
+    ('𨵴', "nán"),
+    ('𨵶', "liáng"),
+    ('𨵸', "pián"),
+    ('𨵼', "jìng"),
+    ('𨶀', "tǎ"),
+    ('𨶁', "yàn"),
+    ('𨶂', "ài"),
+    ('𨶅', "xiāo"),
+    ('𨶆', "qiāng"),
+    ('𨶇', "wǔ"),
+    ('𨶈', "táng"),
+    ('𨶊', "jùn"),
+    ('𨶐', "kuò"),
+    ('𨶗', "làng"),
+    ('𨶙', "něng"),
+    ('𨶜', "dòu"),
+    ('𨶝', "shú"),
+    ('𨶟', "jiǎo"),
+    ('𨶠', "niè"),
+    ('𨶢', "yú"),
+    ('𨶨', "cè"),
+    ('𨶪', "jiǎo"),
+    ('𨶬', "huà"),
+    ('𨶭', "wén"),
+    ('𨶮', "yē"),
+    ('𨶯', "é"),
+    ('𨶰', "guāng"),
+    ('𨶱', "huā"),
+    ('𨶲', "jiāo"),
+    ('𨶺', "lèi"),
+    ('𨶼', "shāng"),
+    ('𨶽', "yòng"),
+    ('𨶿', "dēng"),
+    ('𨷀', "guān"),
+    ('𨷁', "niú"),
+    ('𨷃', "suì"),
+    ('𨷄', "xiàng"),
+    ('𨷆', "sà"),
+    ('𨷇', "chāng"),
+    ('𨷎', "rùn"),
+    ('𨷐', "yūn"),
+    ('𨷒', "fēn"),
+    ('𨷓', "jiàn"),
+    ('𨷔', "xù"),
+    ('𨷘', "xì"),
+    ('𨷙', "shú"),
+    ('𨷥', "xié"),
+    ('𨷦', "lì"),
+    ('𨷩', "tóu"),
+    ('𨷬', "mǐ"),
+    ('𨷭', "chán"),
+    ('𨷮', "huō"),
+    ('𨷱', "zhuǎn"),
+    ('𨷲', "yuè"),
+    ('𨷻', "lán"),
+    ('𨷽', "yán"),
+    ('𨷾', "dàng"),
+    ('𨷿', "xiàng"),
+    ('𨸀', "yuè"),
+    ('𨸁', "tǐng"),
+    ('𨸂', "bēng"),
+    ('𨸃', "sàn"),
+    ('𨸄', "xiàn"),
+    ('𨸅', "dié"),
+    ('𨸆', "pì"),
+    ('𨸇', "pián"),
+    ('𨸉', "tǎ"),
+    ('𨸋', "jiāo"),
+    ('𨸌', "yē"),
+    ('𨸎', "yuè"),
+    ('𨸐', "réng"),
+    ('𨸑', "qiǎo"),
+    ('𨸒', "qí"),
+    ('𨸓', "diāo"),
+    ('𨸔', "qí"),
+    ('𨸗', "hàn"),
+    ('𨸘', "yuán"),
+    ('𨸙', "yóu"),
+    ('𨸚', "jí"),
+    ('𨸛', "gài"),
+    ('𨸜', "hāi"),
+    ('𨸝', "shì"),
+    ('𨸟', "qū"),
+    ('𨸩', "wèn"),
+    ('𨸬', "zhèn"),
+    ('𨸭', "pō"),
+    ('𨸮', "yán"),
+    ('𨸯', "gū"),
+    ('𨸰', "jú"),
+    ('𨸱', "tiàn"),
+    ('𨸷', "è"),
+    ('𨸺', "yā"),
+    ('𨸻', "lìn"),
+    ('𨸼', "bì"),
+    ('𨹀', "zǐ"),
+    ('𨹁', "hóng"),
+    ('𨹃', "duǒ"),
+    ('𨹅', "duì"),
+    ('𨹆', "xuàn"),
+    ('𨹈', "shǎn"),
+    ('𨹊', "shǎn"),
+    ('𨹋', "yáo"),
+    ('𨹌', "rǎn"),
+    ('𨹔', "tuó"),
+    ('𨹗', "bīng"),
+    ('𨹘', "xù"),
+    ('𨹙', "tūn"),
+    ('𨹚', "chéng"),
+    ('𨹜', "dòu"),
+    ('𨹝', "yì"),
+    ('𨹡', "chè"),
+    ('𨹵', "juǎn"),
+    ('𨹶', "jī"),
+    ('𨹸', "zhào"),
+    ('𨹹', "bēng"),
+    ('𨹻', "tiǎn"),
+    ('𨺀', "pēng"),
+    ('𨺅', "fù"),
+    ('𨺖', "tuǒ"),
+    ('𨺘', "xián"),
+    ('𨺙', "nì"),
+    ('𨺚', "lóng"),
+    ('𨺝', "zhuó"),
+    ('𨺟', "zhēng"),
+    ('𨺠', "shǔn"),
+    ('𨺡', "zōng"),
+    ('𨺢', "fēng"),
+    ('𨺣', "duàn"),
+    ('𨺤', "pì"),
+    ('𨺥', "yǎn"),
+    ('𨺦', "sǒu"),
+    ('𨺧', "qiú"),
+    ('𨺨', "è"),
+    ('𨺩', "qián"),
+    ('𨺫', "qiǎn"),
+    ('𨺭', "cā"),
+    ('𨺮', "xùn"),
+    ('𨺵', "zhuì"),
+    ('𨺸', "mǎo"),
+    ('𨺹', "jiǎo"),
+    ('𨺿', "zhǎn"),
+    ('𨻀', "pí"),
+    ('𨻁', "xī"),
+    ('𨻂', "yàn"),
+    ('𨻃', "fèi"),
+    ('𨻄', "niè"),
+    ('𨻆', "zhì"),
+    ('𨻈', "suǒ"),
+    ('𨻊', "yì"),
+    ('𨻌', "lěi"),
+    ('𨻍', "xù"),
+    ('𨻏', "yì"),
+    ('𨻒', "wēi"),
+    ('𨻕', "jī"),
+    ('𨻖', "chēn"),
+    ('𨻗', "dié"),
+    ('𨻣', "yuán"),
+    ('𨻥', "xí"),
+    ('𨻧', "liú"),
+    ('𨻨', "suǒ"),
+    ('𨻱', "bēng"),
+    ('𨻲', "xià"),
+    ('𨻳', "yàn"),
+    ('𨻵', "cuī"),
+    ('𨻷', "kāng"),
+    ('𨻺', "qīng"),
+    ('𨻻', "lóu"),
+    ('𨻼', "bī"),
+    ('𨼈', "zhàn"),
+    ('𨼉', "cuàn"),
+    ('𨼊', "wú"),
+    ('𨼋', "xū"),
+    ('𨼌', "chēn"),
+    ('𨼍', "háo"),
+    ('𨼎', "jué"),
+    ('𨼐', "chèn"),
+    ('𨼑', "chá"),
+    ('𨼒', "chǎn"),
+    ('𨼓', "zhí"),
+    ('𨼔', "xún"),
+    ('𨼣', "gé"),
+    ('𨼤', "chén"),
+    ('𨼥', "yè"),
+    ('𨼪', "chǔ"),
+    ('𨼫', "qú"),
+    ('𨼬', "xiè"),
+    ('𨼮', "zhàn"),
+    ('𨼯', "kěn"),
+    ('𨼱', "jué"),
+    ('𨼽', "qú"),
+    ('𨼿', "méng"),
+    ('𨽀', "yè"),
+    ('𨽁', "zōu"),
+    ('𨽂', "pú"),
+    ('𨽄', "shì"),
+    ('𨽉', "shǔ"),
+    ('𨽊', "chán"),
+    ('𨽍', "dú"),
+    ('𨽏', "guō"),
+    ('𨽐', "lù"),
+    ('𨽑', "yān"),
+    ('𨽖', "niǎo"),
+    ('𨽗', "bīn"),
+    ('𨽟', "tuí"),
+    ('𨽦', "nì"),
+    ('𨽧', "huān"),
+    ('𨽨', "qián"),
+    ('𨽯', "xià"),
+    ('𨽲', "líng"),
+    ('𨽷', "lián"),
+    ('𨽹', "yì"),
+    ('𨽻', "lì"),
+    ('𨽼', "sì"),
+    ('𨽿', "dài"),
+    ('𨾂', "wèi"),
+    ('𨾅', "cì"),
+    ('𨾉', "jiǔ"),
+    ('𨾊', "hóng"),
+    ('𨾌', "yú"),
+    ('𨾎', "kuí"),
+    ('𨾒', "háng"),
+    ('𨾓', "gē"),
+    ('𨾔', "fàng"),
+    ('𨾗', "kuí"),
+    ('𨾚', "guī"),
+    ('𨾛', "chǐ"),
+    ('𨾞', "jiǔ"),
+    ('𨾡', "suī"),
+    ('𨾤', "dié"),
+    ('𨾬', "suǐ"),
+    ('𨾰', "qín"),
+    ('𨾴', "guī"),
+    ('𨾻', "zhuī"),
+    ('𨾾', "tiào"),
+    ('𨿁', "yuè"),
+    ('𨿇', "zuǐ"),
+    ('𨿏', "wú"),
+    ('𨿐', "cuǐ"),
+    ('𨿛', "zhì"),
+    ('𨿠', "shuì"),
+    ('𨿢', "dōng"),
+    ('𨿭', "wéi"),
+    ('𨿿', "chǒng"),
+    ('𩀋', "rún"),
+    ('𩀖', "jí"),
+    ('𩀜', "diāo"),
+    ('𩀞', "cāng"),
+    ('𩀠', "kòu"),
+    ('𩀣', "wéi"),
+    ('𩀧', "cán"),
+    ('𩀪', "má"),
+    ('𩀫', "òu"),
+    ('𩀲', "sǎn"),
+    ('𩀶', "wéi"),
+    ('𩀼', "sǎn"),
+    ('𩀿', "jīn"),
+    ('𩁌', "wéi"),
+    ('𩁞', "cài"),
+    ('𩁟', "lí"),
+    ('𩁯', "yuè"),
+    ('𩁴', "yūn"),
+    ('𩁷', "chēng"),
+    ('𩁺', "shān"),
+    ('𩂂', "hū"),
+    ('𩂃', "shài"),
+    ('𩂄', "tún"),
+    ('𩂆', "fǒu"),
+    ('𩂈', "qìn"),
+    ('𩂉', "xū"),
+    ('𩂍', "chuān"),
+    ('𩂎', "fù"),
+    ('𩂒', "yì"),
+    ('𩂓', "dōng"),
+    ('𩂔', "fú"),
+    ('𩂕', "fú"),
+    ('𩂖', "zé"),
+    ('𩂗', "pù"),
+    ('𩂙', "líng"),
+    ('𩂝', "shài"),
+    ('𩂞', "pào"),
+    ('𩂢', "yín"),
+    ('𩂣', "luò"),
+    ('𩂤', "huà"),
+    ('𩂥', "yìn"),
+    ('𩂦', "bèng"),
+    ('𩂧', "yū"),
+    ('𩂨', "shè"),
+    ('𩂪', "xiè"),
+    ('𩂫', "chǔ"),
+    ('𩂴', "shè"),
+    ('𩂵', "diàn"),
+    ('𩂹', "yì"),
+    ('𩂻', "chè"),
+    ('𩂼', "gěng"),
+    ('𩂽', "lóng"),
+    ('𩂾', "píng"),
+    ('𩂿', "yǔn"),
+    ('𩃀', "yàn"),
+    ('𩃁', "mò"),
+    ('𩃃', "suī"),
+    ('𩃋', "jìng"),
+    ('𩃍', "sòng"),
+    ('𩃎', "páng"),
+    ('𩃐', "yá"),
+    ('𩃑', "sè"),
+    ('𩃒', "duǒ"),
+    ('𩃕', "chuáng"),
+    ('𩃖', "xiè"),
+    ('𩃘', "tuán"),
+    ('𩃙', "gōng"),
+    ('𩃚', "xuàn"),
+    ('𩃜', "lā"),
+    ('𩃞', "líng"),
+    ('𩃠', "dài"),
+    ('𩃡', "zhá"),
+    ('𩃬', "yīn"),
+    ('𩃭', "sōng"),
+    ('𩃯', "yǔ"),
+    ('𩃰', "tuó"),
+    ('𩃱', "tuó"),
+    ('𩃴', "bà"),
+    ('𩃵', "rǎn"),
+    ('𩃶', "bó"),
+    ('𩃷', "dài"),
+    ('𩃹', "zhá"),
+    ('𩃺', "hóu"),
+    ('𩃾', "huǐ"),
+    ('𩄅', "lú"),
+    ('𩄊', "lìng"),
+    ('𩄋', "rú"),
+    ('𩄕', "dàn"),
+    ('𩄖', "méng"),
+    ('𩄗', "xià"),
+    ('𩄘', "wěng"),
+    ('𩄙', "hán"),
+    ('𩄚', "zī"),
+    ('𩄛', "zhèn"),
+    ('𩄜', "sè"),
+    ('𩄝', "cuó"),
+    ('𩄞', "lì"),
+    ('𩄠', "diān"),
+    ('𩄡', "lián"),
+    ('𩄢', "gòu"),
+    ('𩄦', "péng"),
+    ('𩄪', "yīng"),
+    ('𩄬', "hòu"),
+    ('𩄮', "duì"),
+    ('𩄯', "wù"),
+    ('𩄷', "piào"),
+    ('𩄸', "hè"),
+    ('𩄺', "lóng"),
+    ('𩄻', "mò"),
+    ('𩄼', "fěi"),
+    ('𩄽', "lǚ"),
+    ('𩄾', "zé"),
+    ('𩄿', "bó"),
+    ('𩅀', "diàn"),
+    ('𩅁', "mǎng"),
+    ('𩅃', "zhuàng"),
+    ('𩅄', "lù"),
+    ('𩅅', "pāng"),
+    ('𩅆', "duì"),
+    ('𩅇', "bù"),
+    ('𩅌', "chēn"),
+    ('𩅍', "màn"),
+    ('𩅖', "xī"),
+    ('𩅝', "ǎn"),
+    ('𩅞', "zhōng"),
+    ('𩅠', "nàn"),
+    ('𩅡', "tuò"),
+    ('𩅢', "hé"),
+    ('𩅥', "duì"),
+    ('𩅦', "wān"),
+    ('𩅧', "zhōng"),
+    ('𩅨', "cén"),
+    ('𩅩', "lì"),
+    ('𩅪', "shuāng"),
+    ('𩅮', "cén"),
+    ('𩅰', "sī"),
+    ('𩅲', "duì"),
+    ('𩅴', "hūn"),
+    ('𩅼', "jiān"),
+    ('𩅽', "nóng"),
+    ('𩅾', "dàn"),
+    ('𩅿', "fù"),
+    ('𩆀', "huò"),
+    ('𩆁', "huì"),
+    ('𩆂', "cí"),
+    ('𩆄', "yǒng"),
+    ('𩆅', "sà"),
+    ('𩆆', "tíng"),
+    ('𩆎', "liù"),
+    ('𩆑', "suān"),
+    ('𩆒', "líng"),
+    ('𩆓', "mán"),
+    ('𩆔', "diàn"),
+    ('𩆘', "pāo"),
+    ('𩆚', "líng"),
+    ('𩆝', "lì"),
+    ('𩆟', "nóu"),
+    ('𩆣', "liè"),
+    ('𩆤', "shǎn"),
+    ('𩆦', "fèi"),
+    ('𩆫', "shǎn"),
+    ('𩆮', "líng"),
+    ('𩆯', "zhàn"),
+    ('𩆱', "bīn"),
+    ('𩆲', "lí"),
+    ('𩆵', "sī"),
+    ('𩆶', "ráng"),
+    ('𩆷', "jiān"),
+    ('𩆸', "zhuó"),
+    ('𩆻', "líng"),
+    ('𩆼', "líng"),
+    ('𩆽', "mèng"),
+    ('𩆿', "shuāng"),
+    ('𩇄', "líng"),
+    ('𩇇', "hùn"),
+    ('𩇎', "líng"),
+    ('𩇏', "jiān"),
+    ('𩇐', "qú"),
+    ('𩇔', "nóng"),
+    ('𩇕', "jìng"),
+    ('𩇖', "chēn"),
+    ('𩇜', "zhēn"),
+    ('𩇝', "qìng"),
+    ('𩇟', "qìng"),
+    ('𩇠', "è"),
+    ('𩇣', "sè"),
+    ('𩇩', "bèi"),
+    ('𩇫', "fēi"),
+    ('𩇮', "fèi"),
+    ('𩇯', "féi"),
+    ('𩇴', "fāng"),
+    ('𩇵', "kǔ"),
+    ('𩇺', "zá"),
+    ('𩇻', "huì"),
+    ('𩇽', "féi"),
+    ('𩈁', "duì"),
+    ('𩈆', "pā"),
+    ('𩈇', "niǔ"),
+    ('𩈈', "pàng"),
+    ('𩈉', "dàn"),
+    ('𩈊', "dān"),
+    ('𩈋', "ài"),
+    ('𩈍', "tiǎn"),
+    ('𩈎', "chǎo"),
+    ('𩈏', "ǎo"),
+    ('𩈐', "mèi"),
+    ('𩈑', "nǎn"),
+    ('𩈔', "bò"),
+    ('𩈕', "yù"),
+    ('𩈖', "xiān"),
+    ('𩈗', "mài"),
+    ('𩈚', "pīng"),
+    ('𩈜', "duī"),
+    ('𩈞', "dào"),
+    ('𩈡', "xìng"),
+    ('𩈢', "nì"),
+    ('𩈣', "hān"),
+    ('𩈤', "chù"),
+    ('𩈥', "shuǎ"),
+    ('𩈦', "mǎn"),
+    ('𩈬', "wàn"),
+    ('𩈭', "yì"),
+    ('𩈮', "diào"),
+    ('𩈯', "yān"),
+    ('𩈱', "wò"),
+    ('𩈲', "suàn"),
+    ('𩈴', "ǎn"),
+    ('𩈵', "lán"),
+    ('𩈶', "nǎn"),
+    ('𩈸', "qiǔ"),
+    ('𩈹', "miàn"),
+    ('𩈺', "nuǒ"),
+    ('𩈻', "cán"),
+    ('𩈼', "cǎn"),
+    ('𩉀', "làn"),
+    ('𩉁', "tiǎn"),
+    ('𩉂', "yè"),
+    ('𩉄', "niǎn"),
+    ('𩉆', "shuǎ"),
+    ('𩉋', "cí"),
+    ('𩉍', "jiǎn"),
+    ('𩉐', "gàn"),
+    ('𩉔', "jiàn"),
+    ('𩉕', "guó"),
+    ('𩉗', "zhān"),
+    ('𩉙', "luǒ"),
+    ('𩉜', "jī"),
+    ('𩉝', "guì"),
+    ('𩉡', "jiá"),
+    ('𩉢', "jǐ"),
+    ('𩉥', "xuàn"),
+    ('𩉧', "fēng"),
+    ('𩉫', "bì"),
+    ('𩉬', "qí"),
+    ('𩉯', "yuán"),
+    ('𩉰', "àng"),
+    ('𩉱', "dī"),
+    ('𩉴', "è"),
+    ('𩉵', "fén"),
+    ('𩉸', "jù"),
+    ('𩉹', "nǐ"),
+    ('𩉺', "tuó"),
+    ('𩉼', "shēn"),
+    ('𩉽', "fú"),
+    ('𩉾', "xiá"),
+    ('𩉿', "qú"),
+    ('𩊀', "pò"),
+    ('𩊁', "wǎn"),
+    ('𩊂', "líng"),
+    ('𩊃', "mà"),
+    ('𩊄', "zhòu"),
+    ('𩊅', "bào"),
+    ('𩊇', "yù"),
+    ('𩊌', "běng"),
+    ('𩊍', "mài"),
+    ('𩊏', "jiā"),
+    ('𩊑', "yǎng"),
+    ('𩊓', "kuǎ"),
+    ('𩊔', "jiào"),
+    ('𩊖', "bǐng"),
+    ('𩊚', "luò"),
+    ('𩊛', "guǐ"),
+    ('𩊜', "duò"),
+    ('𩊝', "zhì"),
+    ('𩊡', "zhèn"),
+    ('𩊢', "è"),
+    ('𩊣', "zhū"),
+    ('𩊤', "bá"),
+    ('𩊨', "zhèn"),
+    ('𩊩', "fēng"),
+    ('𩊪', "dòu"),
+    ('𩊫', "niǎn"),
+    ('𩊬', "bù"),
+    ('𩊭', "duì"),
+    ('𩊮', "shā"),
+    ('𩊯', "sè"),
+    ('𩊰', "bì"),
+    ('𩊴', "zhì"),
+    ('𩊵', "zhé"),
+    ('𩊶', "bù"),
+    ('𩊺', "jué"),
+    ('𩊻', "xùn"),
+    ('𩊿', "xì"),
+    ('𩋁', "zhuó"),
+    ('𩋂', "bài"),
+    ('𩋃', "yáo"),
+    ('𩋄', "chǒu"),
+    ('𩋅', "tà"),
+    ('𩋆', "qiān"),
+    ('𩋈', "nào"),
+    ('𩋉', "yù"),
+    ('𩋊', "è"),
+    ('𩋋', "jiān"),
+    ('𩋌', "yì"),
+    ('𩋍', "xiāo"),
+    ('𩋏', "niè"),
+    ('𩋒', "bīng"),
+    ('𩋗', "guǒ"),
+    ('𩋘', "xié"),
+    ('𩋙', "diào"),
+    ('𩋜', "jū"),
+    ('𩋝', "suǒ"),
+    ('𩋞', "dié"),
+    ('𩋟', "fú"),
+    ('𩋠', "miǎn"),
+    ('𩋡', "shì"),
+    ('𩋢', "xuàn"),
+    ('𩋣', "tí"),
+    ('𩋤', "yù"),
+    ('𩋧', "xié"),
+    ('𩋨', "fú"),
+    ('𩋩', "zhì"),
+    ('𩋪', "nǐ"),
+    ('𩋫', "xuàn"),
+    ('𩋬', "yáng"),
+    ('𩋮', "fěng"),
+    ('𩋯', "zòng"),
+    ('𩋰', "zhòu"),
+    ('𩋱', "xuān"),
+    ('𩋵', "zhū"),
+    ('𩋷', "la"),
+    ('𩋹', "yìng"),
+    ('𩋺', "gào"),
+    ('𩋻', "kuò"),
+    ('𩋽', "é"),
+    ('𩋾', "wéi"),
+    ('𩋿', "méi"),
+    ('𩌃', "huái"),
+    ('𩌄', "chǒu"),
+    ('𩌆', "suǒ"),
+    ('𩌇', "tà"),
+    ('𩌈', "suǒ"),
+    ('𩌉', "tà"),
+    ('𩌊', "xuè"),
+    ('𩌌', "gǒng"),
+    ('𩌍', "jiǎ"),
+    ('𩌏', "bó"),
+    ('𩌐', "tà"),
+    ('𩌑', "yuǎn"),
+    ('𩌘', "tà"),
+    ('𩌝', "chuí"),
+    ('𩌠', "xiōng"),
+    ('𩌡', "hé"),
+    ('𩌢', "suō"),
+    ('𩌧', "mò"),
+    ('𩌨', "chóng"),
+    ('𩌩', "suī"),
+    ('𩌪', "zé"),
+    ('𩌫', "lù"),
+    ('𩌬', "zhāng"),
+    ('𩌭', "luò"),
+    ('𩌮', "xù"),
+    ('𩌯', "jiān"),
+    ('𩌰', "shān"),
+    ('𩌲', "xù"),
+    ('𩌾', "jiǎng"),
+    ('𩍂', "bào"),
+    ('𩍃', "mái"),
+    ('𩍅', "tóng"),
+    ('𩍆', "xì"),
+    ('𩍉', "róng"),
+    ('𩍋', "shéng"),
+    ('𩍌', "zhòu"),
+    ('𩍎', "jiān"),
+    ('𩍏', "fù"),
+    ('𩍐', "dèng"),
+    ('𩍓', "yōng"),
+    ('𩍔', "jū"),
+    ('𩍖', "yì"),
+    ('𩍗', "bāng"),
+    ('𩍙', "sè"),
+    ('𩍚', "suì"),
+    ('𩍜', "duó"),
+    ('𩍝', "xiè"),
+    ('𩍡', "huán"),
+    ('𩍥', "rǔ"),
+    ('𩍦', "nǐ"),
+    ('𩍧', "zhòu"),
+    ('𩍨', "guì"),
+    ('𩍪', "luò"),
+    ('𩍲', "zhī"),
+    ('𩍳', "xù"),
+    ('𩍵', "zhī"),
+    ('𩍷', "jué"),
+    ('𩍸', "jū"),
+    ('𩍻', "yuán"),
+    ('𩍼', "lú"),
+    ('𩍿', "bó"),
+    ('𩎂', "róng"),
+    ('𩎃', "xiè"),
+    ('𩎉', "xǐ"),
+    ('𩎊', "luó"),
+    ('𩎎', "gé"),
+    ('𩎑', "zuān"),
+    ('𩎒', "hàn"),
+    ('𩎔', "jiāo"),
+    ('𩎕', "sǎ"),
+    ('𩎖', "qín"),
+    ('𩎗', "qūn"),
+    ('𩎘', "páo"),
+    ('𩎙', "yuè"),
+    ('𩎚', "chè"),
+    ('𩎛', "fú"),
+    ('𩎜', "pēi"),
+    ('𩎟', "mèi"),
+    ('𩎢', "tāo"),
+    ('𩎤', "kēn"),
+    ('𩎥', "xì"),
+    ('𩎫', "duò"),
+    ('𩎭', "yì"),
+    ('𩎰', "suì"),
+    ('𩎲', "xiá"),
+    ('𩎳', "juān"),
+    ('𩎵', "wéi"),
+    ('𩎷', "yì"),
+    ('𩎹', "yù"),
+    ('𩎻', "bài"),
+    ('𩎼', "tuó"),
+    ('𩎽', "tà"),
+    ('𩎾', "páo"),
+    ('𩏂', "bǐng"),
+    ('𩏅', "yùn"),
+    ('𩏆', "yùn"),
+    ('𩏇', "duàn"),
+    ('𩏈', "ruǎn"),
+    ('𩏉', "wéi"),
+    ('𩏏', "wéi"),
+    ('𩏐', "guì"),
+    ('𩏒', "dá"),
+    ('𩏓', "xiá"),
+    ('𩏖', "hùn"),
+    ('𩏗', "juǎn"),
+    ('𩏘', "suī"),
+    ('𩏚', "suì"),
+    ('𩏝', "lóu"),
+    ('𩏞', "bài"),
+    ('𩏟', "yù"),
+    ('𩏠', "zhèng"),
+    ('𩏡', "guì"),
+    ('𩏣', "kuī"),
+    ('𩏤', "gāo"),
+    ('𩏥', "dān"),
+    ('𩏩', "xiǎn"),
+    ('𩏪', "zhái"),
+    ('𩏫', "sè"),
+    ('𩏭', "kē"),
+    ('𩏮', "bǔ"),
+    ('𩏯', "bó"),
+    ('𩏲', "suì"),
+    ('𩏴', "yù"),
+    ('𩏵', "bǔ"),
+    ('𩏶', "jiū"),
+    ('𩏷', "jiū"),
+    ('𩏹', "juàn"),
+    ('𩏺', "jué"),
+    ('𩏼', "nà"),
+    ('𩏽', "zhái"),
+    ('𩏾', "tāo"),
+    ('𩏿', "wěi"),
+    ('𩐀', "xiá"),
+    ('𩐁', "xiè"),
+    ('𩐅', "sà"),
+    ('𩐆', "jī"),
+    ('𩐉', "xiè"),
+    ('𩐌', "duì"),
+    ('𩐍', "zǐ"),
+    ('𩐘', "yuǎn"),
+    ('𩐙', "qìn"),
+    ('𩐚', "fú"),
+    ('𩐛', "péng"),
+    ('𩐜', "páo"),
+    ('𩐞', "yìn"),
+    ('𩐠', "hōng"),
+    ('𩐡', "zú"),
+    ('𩐣', "gōng"),
+    ('𩐤', "dòng"),
+    ('𩐥', "hē"),
+    ('𩐦', "wò"),
+    ('𩐨', "pāng"),
+    ('𩐫', "sù"),
+    ('𩐬', "kǎn"),
+    ('𩐭', "niè"),
+    ('𩐮', "háo"),
+    ('𩐯', "fèng"),
+    ('𩐰', "è"),
+    ('𩐱', "yè"),
+    ('𩐴', "tíng"),
+    ('𩐵', "dòng"),
+    ('𩐶', "zhé"),
+    ('𩐷', "sāng"),
+    ('𩐻', "mò"),
+    ('𩐼', "sù"),
+    ('𩐾', "lè"),
+    ('𩑀', "pǔ"),
+    ('𩑁', "é"),
+    ('𩑂', "zhuó"),
+    ('𩑃', "yè"),
+    ('𩑇', "xiāng"),
+    ('𩑈', "guàng"),
+    ('𩑉', "rěn"),
+    ('𩑊', "líng"),
+    ('𩑍', "ào"),
+    ('𩑐', "chāi"),
+    ('𩑒', "duó"),
+    ('𩑓', "qióng"),
+    ('𩑔', "kū"),
+    ('𩑕', "xū"),
+    ('𩑖', "huán"),
+    ('𩑗', "yāo"),
+    ('𩑘', "zhèn"),
+    ('𩑙', "tǐng"),
+    ('𩑚', "běng"),
+    ('𩑝', "áng"),
+    ('𩑟', "kān"),
+    ('𩑡', "kū"),
+    ('𩑢', "péi"),
+    ('𩑣', "yòu"),
+    ('𩑤', "ǎo"),
+    ('𩑥', "mén"),
+    ('𩑦', "mò"),
+    ('𩑬', "fǔ"),
+    ('𩑭', "qīng"),
+    ('𩑮', "là"),
+    ('𩑯', "dǒu"),
+    ('𩑰', "tǎn"),
+    ('𩑳', "qiǎn"),
+    ('𩑴', "yào"),
+    ('𩑵', "wèi"),
+    ('𩑶', "hú"),
+    ('𩑷', "mò"),
+    ('𩑸', "hē"),
+    ('𩑹', "xuàn"),
+    ('𩑻', "bì"),
+    ('𩑼', "pō"),
+    ('𩑾', "dī"),
+    ('𩒀', "zhěn"),
+    ('𩒂', "shī"),
+    ('𩒃', "kǎn"),
+    ('𩒄', "cè"),
+    ('𩒇', "xū"),
+    ('𩒈', "zhěn"),
+    ('𩒊', "zhǔ"),
+    ('𩒏', "huì"),
+    ('𩒐', "chǐ"),
+    ('𩒓', "hǒng"),
+    ('𩒔', "nóu"),
+    ('𩒕', "niè"),
+    ('𩒖', "yàn"),
+    ('𩒘', "chǒng"),
+    ('𩒙', "fǔ"),
+    ('𩒚', "guāng"),
+    ('𩒛', "qī"),
+    ('𩒝', "gěn"),
+    ('𩒞', "tǐng"),
+    ('𩒢', "tǎn"),
+    ('𩒣', "qiǎn"),
+    ('𩒦', "jiù"),
+    ('𩒧', "xū"),
+    ('𩒨', "qǐ"),
+    ('𩒪', "zhèn"),
+    ('𩒮', "qiú"),
+    ('𩒰', "ě"),
+    ('𩒳', "huì"),
+    ('𩒴', "hòng"),
+    ('𩒵', "qǐng"),
+    ('𩒷', "chē"),
+    ('𩒺', "fù"),
+    ('𩒼', "hōng"),
+    ('𩒽', "xī"),
+    ('𩒾', "wú"),
+    ('𩒿', "máng"),
+    ('𩓂', "tī"),
+    ('𩓅', "hōng"),
+    ('𩓐', "bó"),
+    ('𩓒', "qǐn"),
+    ('𩓓', "gěn"),
+    ('𩓖', "fú"),
+    ('𩓗', "kuǐ"),
+    ('𩓝', "bié"),
+    ('𩓞', "jìng"),
+    ('𩓟', "kǎn"),
+    ('𩓠', "guī"),
+    ('𩓢', "gǎo"),
+    ('𩓣', "xū"),
+    ('𩓤', "àn"),
+    ('𩓥', "yuè"),
+    ('𩓦', "wù"),
+    ('𩓧', "yí"),
+    ('𩓨', "jīng"),
+    ('𩓪', "lù"),
+    ('𩓫', "quán"),
+    ('𩓬', "tuí"),
+    ('𩓮', "jì"),
+    ('𩓺', "jiǒng"),
+    ('𩓻', "jué"),
+    ('𩓼', "piē"),
+    ('𩓽', "kūn"),
+    ('𩔀', "wài"),
+    ('𩔁', "huì"),
+    ('𩔂', "dùn"),
+    ('𩔃', "yuǎn"),
+    ('𩔄', "jié"),
+    ('𩔆', "guì"),
+    ('𩔇', "gǎo"),
+    ('𩔈', "pò"),
+    ('𩔉', "mén"),
+    ('𩔊', "zhuàn"),
+    ('𩔋', "hàng"),
+    ('𩔔', "yóng"),
+    ('𩔕', "qiú"),
+    ('𩔗', "lèi"),
+    ('𩔘', "áng"),
+    ('𩔙', "pǐ"),
+    ('𩔚', "wēng"),
+    ('𩔝', "qìn"),
+    ('𩔟', "qǐn"),
+    ('𩔠', "miè"),
+    ('𩔡', "dōu"),
+    ('𩔢', "mí"),
+    ('𩔣', "zhān"),
+    ('𩔥', "qǐng"),
+    ('𩔦', "yí"),
+    ('𩔮', "bān"),
+    ('𩔱', "juān"),
+    ('𩔳', "zé"),
+    ('𩔴', "xù"),
+    ('𩔵', "lán"),
+    ('𩔶', "má"),
+    ('𩔷', "má"),
+    ('𩔸', "ōu"),
+    ('𩔹', "bēi"),
+    ('𩔻', "póu"),
+    ('𩔼', "xù"),
+    ('𩕀', "ào"),
+    ('𩕆', "hǒng"),
+    ('𩕉', "hǒng"),
+    ('𩕊', "zhǎn"),
+    ('𩕌', "sěn"),
+    ('𩕍', "gǎo"),
+    ('𩕏', "pó"),
+    ('𩕐', "liào"),
+    ('𩕕', "wài"),
+    ('𩕖', "xuān"),
+    ('𩕜', "kuí"),
+    ('𩕟', "è"),
+    ('𩕠', "hàn"),
+    ('𩕡', "sè"),
+    ('𩕤', "dàn"),
+    ('𩕪', "xuān"),
+    ('𩕬', "è"),
+    ('𩕭', "gài"),
+    ('𩕯', "dāo"),
+    ('𩕱', "měng"),
+    ('𩕲', "yī"),
+    ('𩕳', "nǐng"),
+    ('𩕵', "pín"),
+    ('𩕹', "cāng"),
+    ('𩕾', "yuàn"),
+    ('𩖀', "è"),
+    ('𩖁', "niè"),
+    ('𩖄', "yǐn"),
+    ('𩖇', "qiāo"),
+    ('𩖉', "hōng"),
+    ('𩖊', "líng"),
+    ('𩖌', "chān"),
+    ('𩖍', "yǐng"),
+    ('𩖒', "guān"),
+    ('𩖔', "niǎo"),
+    ('𩖕', "xū"),
+    ('𩖖', "tán"),
+    ('𩖗', "jìn"),
+    ('𩖛', "péng"),
+    ('𩖝', "liáo"),
+    ('𩖠', "bèi"),
+    ('𩖣', "xín"),
+    ('𩖤', "tún"),
+    ('𩖥', "chāo"),
+    ('𩖦', "gān"),
+    ('𩖨', "hū"),
+    ('𩖩', "wǎng"),
+    ('𩖬', "fú"),
+    ('𩖭', "pèi"),
+    ('𩖯', "náo"),
+    ('𩖰', "xún"),
+    ('𩖱', "xuè"),
+    ('𩖴', "liǔ"),
+    ('𩖵', "líng"),
+    ('𩖶', "xuè"),
+    ('𩖷', "qū"),
+    ('𩖸', "háo"),
+    ('𩖹', "yí"),
+    ('𩖺', "hàn"),
+    ('𩖼', "fú"),
+    ('𩖽', "bá"),
+    ('𩖾', "yí"),
+    ('𩗀', "bó"),
+    ('𩗄', "hōng"),
+    ('𩗅', "lì"),
+    ('𩗉', "sà"),
+    ('𩗊', "xī"),
+    ('𩗎', "shì"),
+    ('𩗏', "piāo"),
+    ('𩗐', "huà"),
+    ('𩗑', "yí"),
+    ('𩗒', "bó"),
+    ('𩗓', "bó"),
+    ('𩗔', "něi"),
+    ('𩗕', "qiú"),
+    ('𩗘', "wěi"),
+    ('𩗙', "chè"),
+    ('𩗚', "yóu"),
+    ('𩗜', "wèi"),
+    ('𩗝', "huǐ"),
+    ('𩗞', "sà"),
+    ('𩗡', "táo"),
+    ('𩗢', "hòng"),
+    ('𩗣', "sōu"),
+    ('𩗤', "hàn"),
+    ('𩗥', "páo"),
+    ('𩗧', "fáng"),
+    ('𩗩', "liú"),
+    ('𩗪', "zhòu"),
+    ('𩗫', "pí"),
+    ('𩗭', "lì"),
+    ('𩗰', "chuí"),
+    ('𩗱', "xī"),
+    ('𩗲', "zhēng"),
+    ('𩗴', "bèng"),
+    ('𩗵', "zhēng"),
+    ('𩗶', "suì"),
+    ('𩗷', "yǎn"),
+    ('𩗼', "qīng"),
+    ('𩗽', "wù"),
+    ('𩗾', "liǎng"),
+    ('𩘀', "zhào"),
+    ('𩘁', "liáng"),
+    ('𩘅', "jiē"),
+    ('𩘇', "hōng"),
+    ('𩘈', "yōu"),
+    ('𩘊', "là"),
+    ('𩘋', "hòu"),
+    ('𩘍', "yuàn"),
+    ('𩘎', "hóng"),
+    ('𩘏', "yè"),
+    ('𩘑', "yǐng"),
+    ('𩘒', "xuǎn"),
+    ('𩘓', "yóu"),
+    ('𩘘', "quán"),
+    ('𩘜', "táng"),
+    ('𩘝', "suǒ"),
+    ('𩘟', "lì"),
+    ('𩘠', "sōu"),
+    ('𩘡', "lì"),
+    ('𩘤', "yù"),
+    ('𩘧', "yì"),
+    ('𩘭', "xiū"),
+    ('𩘮', "áo"),
+    ('𩘯', "tuán"),
+    ('𩘰', "sù"),
+    ('𩘱', "shuài"),
+    ('𩘳', "yù"),
+    ('𩘵', "fēng"),
+    ('𩘹', "sù"),
+    ('𩘺', "tuí"),
+    ('𩘻', "yù"),
+    ('𩘼', "zhēng"),
+    ('𩘽', "zhēng"),
+    ('𩘿', "táo"),
+    ('𩙄', "liú"),
+    ('𩙆', "chéng"),
+    ('𩙇', "suí"),
+    ('𩙈', "sāo"),
+    ('𩙏', "gǔ"),
+    ('𩙐', "fēng"),
+    ('𩙑', "liè"),
+    ('𩙒', "piāo"),
+    ('𩙖', "lì"),
+    ('𩙘', "lóng"),
+    ('𩙙', "chū"),
+    ('𩙚', "xiāo"),
+    ('𩙛', "hōng"),
+    ('𩙜', "xiè"),
+    ('𩙝', "shè"),
+    ('𩙠', "lóng"),
+    ('𩙡', "hōu"),
+    ('𩙢', "xuán"),
+    ('𩙣', "fēng"),
+    ('𩙥', "bá"),
+    ('𩙦', "bó"),
+    ('𩙧', "táo"),
+    ('𩙨', "sù"),
+    ('𩙩', "zhào"),
+    ('𩙪', "biāo"),
+    ('𩙫', "sōu"),
+    ('𩙬', "tuí"),
+    ('𩙭', "suǒ"),
+    ('𩙮', "xiāo"),
+    ('𩙯', "héng"),
+    ('𩙰', "sāo"),
+    ('𩙲', "fēi"),
+    ('𩙷', "niù"),
+    ('𩙸', "mǎng"),
+    ('𩙽', "huán"),
+    ('𩙾', "zhī"),
+    ('𩚂', "yì"),
+    ('𩚄', "yù"),
+    ('𩚇', "yí"),
+    ('𩚈', "yuē"),
+    ('𩚉', "chí"),
+    ('𩚕', "yǐn"),
+    ('𩚖', "niù"),
+    ('𩚗', "rǒng"),
+    ('𩚛', "nà"),
+    ('𩚣', "tián"),
+    ('𩚥', "bā"),
+    ('𩚪', "ěr"),
+    ('𩚫', "zhēng"),
+    ('𩚬', "è"),
+    ('𩚭', "póu"),
+    ('𩚮', "jī"),
+    ('𩚯', "ní"),
+    ('𩚱', "jiǒng"),
+    ('𩚲', "jiá"),
+    ('𩚵', "gān"),
+    ('𩚹', "líng"),
+    ('𩚻', "zuì"),
+    ('𩚾', "bèi"),
+    ('𩛅', "shū"),
+    ('𩛆', "yǐ"),
+    ('𩛇', "pāi"),
+    ('𩛋', "nǎo"),
+    ('𩛌', "shì"),
+    ('𩛎', "mǎn"),
+    ('𩛏', "shì"),
+    ('𩛑', "tí"),
+    ('𩛘', "gōng"),
+    ('𩛝', "lèi"),
+    ('𩛞', "bǎo"),
+    ('𩛟', "yuān"),
+    ('𩛠', "zuō"),
+    ('𩛡', "láng"),
+    ('𩛢', "xiū"),
+    ('𩛥', "zài"),
+    ('𩛦', "chèng"),
+    ('𩛧', "jiān"),
+    ('𩛨', "mào"),
+    ('𩛩', "jiá"),
+    ('𩛪', "yù"),
+    ('𩛭', "yù"),
+    ('𩛮', "yí"),
+    ('𩛲', "māng"),
+    ('𩛳', "zài"),
+    ('𩛵', "zhuì"),
+    ('𩛶', "tí"),
+    ('𩛹', "xì"),
+    ('𩛺', "jú"),
+    ('𩛻', "zàn"),
+    ('𩛼', "lù"),
+    ('𩛽', "táo"),
+    ('𩜀', "zhuì"),
+    ('𩜁', "líng"),
+    ('𩜃', "jù"),
+    ('𩜆', "jī"),
+    ('𩜇', "juǎn"),
+    ('𩜊', "zī"),
+    ('𩜌', "yuē"),
+    ('𩜍', "dōng"),
+    ('𩜒', "nǎng"),
+    ('𩜖', "chóng"),
+    ('𩜟', "àng"),
+    ('𩜣', "gēng"),
+    ('𩜥', "bō"),
+    ('𩜦', "dìng"),
+    ('𩜧', "wěi"),
+    ('𩜬', "quán"),
+    ('𩜭', "kē"),
+    ('𩜰', "pì"),
+    ('𩜱', "kǎn"),
+    ('𩜲', "fú"),
+    ('𩜳', "yǒng"),
+    ('𩜵', "tuán"),
+    ('𩜶', "tǒu"),
+    ('𩜷', "yòu"),
+    ('𩜸', "yāo"),
+    ('𩜺', "yē"),
+    ('𩜽', "yàn"),
+    ('𩝈', "xián"),
+    ('𩝊', "tí"),
+    ('𩝌', "suì"),
+    ('𩝐', "cí"),
+    ('𩝔', "xǔ"),
+    ('𩝕', "wù"),
+    ('𩝖', "cān"),
+    ('𩝗', "yù"),
+    ('𩝚', "chǎn"),
+    ('𩝛', "xiá"),
+    ('𩝝', "kào"),
+    ('𩝞', "cāng"),
+    ('𩝟', "chā"),
+    ('𩝠', "qiǔ"),
+    ('𩝣', "dā"),
+    ('𩝥', "sù"),
+    ('𩝨', "huā"),
+    ('𩝷', "wū"),
+    ('𩝸', "yuān"),
+    ('𩝽', "jiàng"),
+    ('𩝾', "xiǎng"),
+    ('𩝿', "zhāi"),
+    ('𩞀', "sǎn"),
+    ('𩞁', "mó"),
+    ('𩞃', "shǎng"),
+    ('𩞄', "cáo"),
+    ('𩞅', "suī"),
+    ('𩞆', "chuáng"),
+    ('𩞇', "mí"),
+    ('𩞈', "zhú"),
+    ('𩞉', "chóng"),
+    ('𩞊', "jì"),
+    ('𩞋', "chóng"),
+    ('𩞙', "lián"),
+    ('𩞞', "hài"),
+    ('𩞤', "dūn"),
+    ('𩞥', "xiǎng"),
+    ('𩞦', "chēng"),
+    ('𩞧', "shǎng"),
+    ('𩞨', "lì"),
+    ('𩞩', "huáng"),
+    ('𩞬', "dèng"),
+    ('𩞯', "liáng"),
+    ('𩞶', "zā"),
+    ('𩞺', "huò"),
+    ('𩞻', "lín"),
+    ('𩞾', "dú"),
+    ('𩞿', "hàn"),
+    ('𩟀', "yōng"),
+    ('𩟁', "yuàn"),
+    ('𩟂', "guò"),
+    ('𩟃', "líng"),
+    ('𩟅', "liǎn"),
+    ('𩟇', "ào"),
+    ('𩟈', "dāng"),
+    ('𩟉', "yì"),
+    ('𩟊', "nóng"),
+    ('𩟋', "shàn"),
+    ('𩟍', "xìn"),
+    ('𩟐', "dá"),
+    ('𩟑', "yù"),
+    ('𩟒', "cān"),
+    ('𩟓', "wò"),
+    ('𩟔', "chá"),
+    ('𩟕', "bó"),
+    ('𩟗', "jiǎn"),
+    ('𩟞', "méng"),
+    ('𩟟', "wěi"),
+    ('𩟠', "mó"),
+    ('𩟥', "shuì"),
+    ('𩟦', "jié"),
+    ('𩟧', "shuò"),
+    ('𩟨', "huò"),
+    ('𩟫', "chuò"),
+    ('𩟭', "lóng"),
+    ('𩟮', "huài"),
+    ('𩟰', "tuō"),
+    ('𩟳', "yú"),
+    ('𩟶', "chàn"),
+    ('𩟷', "yōng"),
+    ('𩟸', "huò"),
+    ('𩟺', "lǎn"),
+    ('𩟿', "nà"),
+    ('𩠀', "bā"),
+    ('𩠁', "gān"),
+    ('𩠂', "yǐ"),
+    ('𩠃', "jiá"),
+    ('𩠅', "dá"),
+    ('𩠆', "dìng"),
+    ('𩠇', "xùn"),
+    ('𩠈', "rěn"),
+    ('𩠉', "juǎn"),
+    ('𩠊', "tuán"),
+    ('𩠋', "xǔ"),
+    ('𩠌', "sòng"),
+    ('𩠎', "cáo"),
+    ('𩠏', "chēng"),
+    ('𩠑', "dǐng"),
+    ('𩠚', "hái"),
+    ('𩠟', "wǔ"),
+    ('𩠦', "qǐ"),
+    ('𩠨', "jī"),
+    ('𩠮', "kuí"),
+    ('𩠯', "wéi"),
+    ('𩠶', "shǒu"),
+    ('𩠷', "fú"),
+    ('𩠹', "tuán"),
+    ('𩠻', "bié"),
+    ('𩠽', "tán"),
+    ('𩠾', "hāng"),
+    ('𩠿', "piē"),
+    ('𩡃', "yú"),
+    ('𩡄', "tán"),
+    ('𩡌', "xiāng"),
+    ('𩡎', "xiū"),
+    ('𩡓', "wěng"),
+    ('𩡔', "hài"),
+    ('𩡕', "péng"),
+    ('𩡖', "yǐ"),
+    ('𩡝', "tán"),
+    ('𩡟', "bié"),
+    ('𩡠', "xiāng"),
+    ('𩡣', "yǐ"),
+    ('𩡦', "piáo"),
+    ('𩡧', "huán"),
+    ('𩡨', "mǔ"),
+    ('𩡩', "bā"),
+    ('𩡫', "fàn"),
+    ('𩡯', "dīng"),
+    ('𩡷', "fēn"),
+    ('𩡺', "jiè"),
+    ('𩡾', "suó"),
+    ('𩢄', "wàn"),
+    ('𩢅', "gē"),
+    ('𩢈', "fēn"),
+    ('𩢊', "tuó"),
+    ('𩢌', "wén"),
+    ('𩢍', "guā"),
+    ('𩢎', "duō"),
+    ('𩢐', "zhé"),
+    ('𩢑', "cǐ"),
+    ('𩢒', "yǎo"),
+    ('𩢔', "bàn"),
+    ('𩢕', "bù"),
+    ('𩢖', "mò"),
+    ('𩢘', "pǒ"),
+    ('𩢛', "gé"),
+    ('𩢞', "liú"),
+    ('𩢡', "rǎn"),
+    ('𩢨', "gān"),
+    ('𩢪', "hú"),
+    ('𩢫', "móu"),
+    ('𩢮', "xiū"),
+    ('𩢯', "huāng"),
+    ('𩢰', "fú"),
+    ('𩢱', "huí"),
+    ('𩢳', "qú"),
+    ('𩢴', "jié"),
+    ('𩢵', "tuō"),
+    ('𩢶', "yú"),
+    ('𩢷', "mò"),
+    ('𩢸', "zhōu"),
+    ('𩢹', "jiù"),
+    ('𩢻', "shú"),
+    ('𩢼', "kuāng"),
+    ('𩢽', "qióng"),
+    ('𩢾', "liè"),
+    ('𩢿', "fù"),
+    ('𩣊', "xù"),
+    ('𩣖', "lìn"),
+    ('𩣘', "niè"),
+    ('𩣚', "pī"),
+    ('𩣜', "fù"),
+    ('𩣝', "bù"),
+    ('𩣞', "yì"),
+    ('𩣡', "bó"),
+    ('𩣣', "é"),
+    ('𩣩', "zhé"),
+    ('𩣫', "lì"),
+    ('𩣮', "tù"),
+    ('𩣯', "dá"),
+    ('𩣱', "lù"),
+    ('𩣲', "yān"),
+    ('𩣳', "dōng"),
+    ('𩣴', "qiè"),
+    ('𩣵', "wǎn"),
+    ('𩣶', "mǐng"),
+    ('𩣷', "zuī"),
+    ('𩣸', "fù"),
+    ('𩣹', "qū"),
+    ('𩣺', "bēn"),
+    ('𩣻', "ǎo"),
+    ('𩣼', "qiāng"),
+    ('𩤁', "qūn"),
+    ('𩤈', "què"),
+    ('𩤉', "huá"),
+    ('𩤊', "xiàn"),
+    ('𩤋', "kùn"),
+    ('𩤏', "cuì"),
+    ('𩤒', "yí"),
+    ('𩤖', "chī"),
+    ('𩤗', "zòng"),
+    ('𩤘', "nǎo"),
+    ('𩤙', "chéng"),
+    ('𩤚', "duān"),
+    ('𩤛', "yóng"),
+    ('𩤜', "zhě"),
+    ('𩤞', "tàn"),
+    ('𩤟', "yáng"),
+    ('𩤠', "xié"),
+    ('𩤡', "xuān"),
+    ('𩤣', "duàn"),
+    ('𩤤', "shuǎ"),
+    ('𩤥', "xián"),
+    ('𩤦', "xián"),
+    ('𩤩', "é"),
+    ('𩤲', "lā"),
+    ('𩤸', "wèi"),
+    ('𩤹', "yōu"),
+    ('𩤺', "yú"),
+    ('𩤽', "tī"),
+    ('𩤿', "jīn"),
+    ('𩥁', "táng"),
+    ('𩥂', "qí"),
+    ('𩥄', "diān"),
+    ('𩥅', "tāo"),
+    ('𩥆', "lǜ"),
+    ('𩥇', "zhàn"),
+    ('𩥈', "wēn"),
+    ('𩥉', "jì"),
+    ('𩥊', "āo"),
+    ('𩥋', "òu"),
+    ('𩥌', "qià"),
+    ('𩥐', "shī"),
+    ('𩥑', "tǎ"),
+    ('𩥔', "mò"),
+    ('𩥘', "yóu"),
+    ('𩥠', "zhá"),
+    ('𩥣', "yáo"),
+    ('𩥫', "chōng"),
+    ('𩥬', "lí"),
+    ('𩥭', "yú"),
+    ('𩥮', "chǎn"),
+    ('𩥯', "yī"),
+    ('𩥲', "chì"),
+    ('𩥴', "lí"),
+    ('𩥽', "tú"),
+    ('𩥿', "zú"),
+    ('𩦂', "xián"),
+    ('𩦇', "xì"),
+    ('𩦉', "bié"),
+    ('𩦊', "hán"),
+    ('𩦋', "qí"),
+    ('𩦌', "sāng"),
+    ('𩦎', "fēi"),
+    ('𩦐', "shàn"),
+    ('𩦘', "huān"),
+    ('𩦠', "bàng"),
+    ('𩦡', "yú"),
+    ('𩦢', "yú"),
+    ('𩦤', "jí"),
+    ('𩦱', "kuǎi"),
+    ('𩦲', "zōng"),
+    ('𩦹', "xiàn"),
+    ('𩦺', "méng"),
+    ('𩧃', "lì"),
+    ('𩧄', "zhì"),
+    ('𩧅', "fán"),
+    ('𩧆', "liè"),
+    ('𩧇', "cài"),
+    ('𩧈', "dú"),
+    ('𩧉', "guāng"),
+    ('𩧊', "xiòng"),
+    ('𩧋', "lí"),
+    ('𩧌', "qì"),
+    ('𩧏', "jué"),
+    ('𩧐', "tuō"),
+    ('𩧒', "jù"),
+    ('𩧓', "xiāo"),
+    ('𩧘', "qú"),
+    ('𩧜', "zhuǎn"),
+    ('𩧡', "jué"),
+    ('𩧦', "jiè"),
+    ('𩧨', "zhòu"),
+    ('𩧩', "xiàn"),
+    ('𩧪', "lóng"),
+    ('𩧫', "yǎng"),
+    ('𩧬', "rǎn"),
+    ('𩧭', "yì"),
+    ('𩧮', "liè"),
+    ('𩧯', "bō"),
+    ('𩧰', "hún"),
+    ('𩧱', "jì"),
+    ('𩧲', "dòng"),
+    ('𩧳', "zhōu"),
+    ('𩧴', "quān"),
+    ('𩧵', "jié"),
+    ('𩧸', "lì"),
+    ('𩧺', "jú"),
+    ('𩧻', "wǎn"),
+    ('𩧼', "bēn"),
+    ('𩧿', "bī"),
+    ('𩨀', "gé"),
+    ('𩨁', "chǔn"),
+    ('𩨃', "qián"),
+    ('𩨄', "sōu"),
+    ('𩨅', "wèi"),
+    ('𩨆', "chéng"),
+    ('𩨇', "lóu"),
+    ('𩨈', "yú"),
+    ('𩨉', "lā"),
+    ('𩨊', "qián"),
+    ('𩨋', "diān"),
+    ('𩨌', "tǎ"),
+    ('𩨍', "zhàn"),
+    ('𩨏', "fán"),
+    ('𩨐', "liè"),
+    ('𩨑', "tīng"),
+    ('𩨒', "jī"),
+    ('𩨓', "qiān"),
+    ('𩨔', "hú"),
+    ('𩨗', "yú"),
+    ('𩨘', "qì"),
+    ('𩨙', "yú"),
+    ('𩨚', "wā"),
+    ('𩨜', "bà"),
+    ('𩨝', "qí"),
+    ('𩨞', "sǎ"),
+    ('𩨟', "qiāo"),
+    ('𩨠', "yà"),
+    ('𩨡', "xiǎn"),
+    ('𩨨', "cī"),
+    ('𩨩', "fàn"),
+    ('𩨫', "kǔn"),
+    ('𩨬', "gǔn"),
+    ('𩨭', "quē"),
+    ('𩨮', "è"),
+    ('𩨯', "qióng"),
+    ('𩨲', "mà"),
+    ('𩨳', "kū"),
+    ('𩨴', "yǎo"),
+    ('𩨷', "quē"),
+    ('𩨸', "chū"),
+    ('𩨹', "jiǎ"),
+    ('𩨻', "zhǔ"),
+    ('𩨽', "duī"),
+    ('𩨾', "wá"),
+    ('𩩀', "nǎo"),
+    ('𩩄', "yán"),
+    ('𩩅', "tóng"),
+    ('𩩈', "kuài"),
+    ('𩩋', "xíng"),
+    ('𩩌', "gǔn"),
+    ('𩩍', "pīng"),
+    ('𩩑', "yǔ"),
+    ('𩩒', "hè"),
+    ('𩩔', "zhuó"),
+    ('𩩗', "shē"),
+    ('𩩘', "yǔ"),
+    ('𩩛', "jì"),
+    ('𩩝', "qiāng"),
+    ('𩩞', "shuì"),
+    ('𩩟', "chuò"),
+    ('𩩠', "zú"),
+    ('𩩡', "léng"),
+    ('𩩢', "ní"),
+    ('𩩤', "wā"),
+    ('𩩥', "zhá"),
+    ('𩩧', "dàn"),
+    ('𩩮', "dù"),
+    ('𩩯', "biàn"),
+    ('𩩰', "jiē"),
+    ('𩩱', "qià"),
+    ('𩩲', "hé"),
+    ('𩩳', "chòng"),
+    ('𩩴', "yán"),
+    ('𩩶', "yàn"),
+    ('𩩺', "sóng"),
+    ('𩩻', "téng"),
+    ('𩩼', "yǎo"),
+    ('𩩾', "kāo"),
+    ('𩪀', "zhuī"),
+    ('𩪁', "guì"),
+    ('𩪂', "ái"),
+    ('𩪃', "hài"),
+    ('𩪈', "suǒ"),
+    ('𩪉', "xù"),
+    ('𩪊', "biāo"),
+    ('𩪌', "fèng"),
+    ('𩪍', "qū"),
+    ('𩪎', "mǎng"),
+    ('𩪐', "guó"),
+    ('𩪖', "bì"),
+    ('𩪗', "jué"),
+    ('𩪘', "chuáng"),
+    ('𩪛', "pú"),
+    ('𩪟', "yì"),
+    ('𩪢', "qiān"),
+    ('𩪣', "yì"),
+    ('𩪤', "è"),
+    ('𩪥', "líng"),
+    ('𩪧', "bì"),
+    ('𩪭', "huò"),
+    ('𩪮', "mǒ"),
+    ('𩪱', "xūn"),
+    ('𩪴', "yàn"),
+    ('𩪸', "lì"),
+    ('𩪺', "tán"),
+    ('𩪾', "luán"),
+    ('𩫀', "kài"),
+    ('𩫁', "mào"),
+    ('𩫂', "xiāo"),
+    ('𩫇', "ǎi"),
+    ('𩫊', "tǎ"),
+    ('𩫍', "mèi"),
+    ('𩫏', "guō"),
+    ('𩫓', "gǎo"),
+    ('𩫔', "náo"),
+    ('𩫕', "háo"),
+    ('𩫠', "quē"),
+    ('𩫥', "cáo"),
+    ('𩫦', "sào"),
+    ('𩫫', "pí"),
+    ('𩫲', "xiē"),
+    ('𩫳', "xiāo"),
+    ('𩫴', "jú"),
+    ('𩫹', "chéng"),
+    ('𩫺', "nǎo"),
+    ('𩬀', "nèi"),
+    ('𩬍', "mǔ"),
+    ('𩬏', "shāo"),
+    ('𩬑', "diān"),
+    ('𩬔', "líng"),
+    ('𩬖', "zhěn"),
+    ('𩬗', "yǎo"),
+    ('𩬙', "fù"),
+    ('𩬚', "qián"),
+    ('𩬛', "qióng"),
+    ('𩬜', "jú"),
+    ('𩬝', "bìng"),
+    ('𩬞', "máo"),
+    ('𩬟', "zhà"),
+    ('𩬠', "tāi"),
+    ('𩬤', "chōng"),
+    ('𩬫', "zhǎi"),
+    ('𩬭', "shī"),
+    ('𩬮', "yòng"),
+    ('𩬰', "qióng"),
+    ('𩬱', "dào"),
+    ('𩬲', "tì"),
+    ('𩬳', "zhuǐ"),
+    ('𩬵', "yìn"),
+    ('𩬷', "nǎo"),
+    ('𩬸', "bō"),
+    ('𩬹', "kuāng"),
+    ('𩬺', "zhǐ"),
+    ('𩬻', "duǒ"),
+    ('𩬼', "cōng"),
+    ('𩬽', "bǎo"),
+    ('𩬾', "yā"),
+    ('𩭇', "lí"),
+    ('𩭊', "jú"),
+    ('𩭋', "wén"),
+    ('𩭌', "liè"),
+    ('𩭏', "wǒ"),
+    ('𩭐', "shǐ"),
+    ('𩭑', "niǎo"),
+    ('𩭒', "máng"),
+    ('𩭓', "jiū"),
+    ('𩭘', "xiū"),
+    ('𩭝', "wǒ"),
+    ('𩭟', "dào"),
+    ('𩭡', "xī"),
+    ('𩭢', "àn"),
+    ('𩭣', "dá"),
+    ('𩭤', "zǒng"),
+    ('𩭥', "hàn"),
+    ('𩭦', "chuí"),
+    ('𩭧', "bī"),
+    ('𩭩', "dòng"),
+    ('𩭫', "zhǎng"),
+    ('𩭯', "yā"),
+    ('𩭲', "dí"),
+    ('𩭳', "huō"),
+    ('𩭷', "mín"),
+    ('𩭹', "sān"),
+    ('𩭺', "fù"),
+    ('𩭼', "bǎo"),
+    ('𩭽', "kè"),
+    ('𩭾', "máo"),
+    ('𩭿', "rè"),
+    ('𩮀', "zōng"),
+    ('𩮁', "qià"),
+    ('𩮂', "xiā"),
+    ('𩮃', "sōu"),
+    ('𩮄', "xiū"),
+    ('𩮅', "nà"),
+    ('𩮉', "mán"),
+    ('𩮎', "zhā"),
+    ('𩮏', "chán"),
+    ('𩮐', "shè"),
+    ('𩮑', "wǒ"),
+    ('𩮖', "ái"),
+    ('𩮗', "bàng"),
+    ('𩮘', "hāo"),
+    ('𩮚', "sāo"),
+    ('𩮛', "suǒ"),
+    ('𩮜', "tì"),
+    ('𩮝', "yà"),
+    ('𩮟', "bìng"),
+    ('𩮠', "róng"),
+    ('𩮫', "shā"),
+    ('𩮬', "wěng"),
+    ('𩮯', "áo"),
+    ('𩮱', "zhuāng"),
+    ('𩮳', "piào"),
+    ('𩮴', "suī"),
+    ('𩮵', "yī"),
+    ('𩮶', "sōu"),
+    ('𩮷', "dōu"),
+    ('𩮸', "sōu"),
+    ('𩮹', "luó"),
+    ('𩯃', "fèi"),
+    ('𩯄', "zùn"),
+    ('𩯆', "nào"),
+    ('𩯇', "dēng"),
+    ('𩯈', "zhí"),
+    ('𩯉', "cuō"),
+    ('𩯊', "liáo"),
+    ('𩯋', "jǐ"),
+    ('𩯌', "bō"),
+    ('𩯍', "cóng"),
+    ('𩯎', "chéng"),
+    ('𩯏', "bǔ"),
+    ('𩯑', "sān"),
+    ('𩯒', "zàn"),
+    ('𩯘', "jiào"),
+    ('𩯛', "yào"),
+    ('𩯜', "lǔ"),
+    ('𩯞', "càn"),
+    ('𩯨', "nǐ"),
+    ('𩯰', "jié"),
+    ('𩯱', "pú"),
+    ('𩯲', "zhuàng"),
+    ('𩯳', "zàn"),
+    ('𩯺', "lì"),
+    ('𩯽', "là"),
+    ('𩰀', "chōng"),
+    ('𩰃', "zhàn"),
+    ('𩰍', "biàn"),
+    ('𩰎', "wēng"),
+    ('𩰓', "hòng"),
+    ('𩰗', "pīn"),
+    ('𩰙', "sè"),
+    ('𩰞', "nǐ"),
+    ('𩰟', "fēn"),
+    ('𩰠', "xǔ"),
+    ('𩰢', "shǐ"),
+    ('𩰤', "jù"),
+    ('𩰨', "jué"),
+    ('𩰪', "yù"),
+    ('𩰬', "guō"),
+    ('𩰭', "guō"),
+    ('𩰯', "hú"),
+    ('𩰰', "jìng"),
+    ('𩰲', "lì"),
+    ('𩰳', "xié"),
+    ('𩰴', "ér"),
+    ('𩰵', "yuán"),
+    ('𩰶', "hái"),
+    ('𩰹', "jìng"),
+    ('𩰻', "kè"),
+    ('𩰽', "zōng"),
+    ('𩰾', "fèi"),
+    ('𩱀', "pēng"),
+    ('𩱁', "gēng"),
+    ('𩱃', "jiān"),
+    ('𩱄', "ní"),
+    ('𩱆', "xián"),
+    ('𩱇', "lì"),
+    ('𩱈', "chǎo"),
+    ('𩱊', "ér"),
+    ('𩱋', "gēng"),
+    ('𩱌', "yù"),
+    ('𩱍', "hú"),
+    ('𩱎', "fèi"),
+    ('𩱏', "áo"),
+    ('𩱓', "ěr"),
+    ('𩱘', "kè"),
+    ('𩱙', "kù"),
+    ('𩱚', "bó"),
+    ('𩱝', "yè"),
+    ('𩱞', "jiào"),
+    ('𩱦', "chǎo"),
+    ('𩱧', "gēng"),
+    ('𩱨', "rù"),
+    ('𩱪', "yuè"),
+    ('𩱬', "lín"),
+    ('𩱱', "yù"),
+    ('𩱲', "yuè"),
+    ('𩱳', "zhāi"),
+    ('𩱴', "xiāo"),
+    ('𩱷', "miè"),
+    ('𩱻', "guǐ"),
+    ('𩱼', "jiū"),
+    ('𩱾', "tuò"),
+    ('𩲁', "xí"),
+    ('𩲂', "wěi"),
+    ('𩲃', "zhuó"),
+    ('𩲄', "wèi"),
+    ('𩲅', "kuí"),
+    ('𩲈', "mèi"),
+    ('𩲊', "hào"),
+    ('𩲋', "hāng"),
+    ('𩲌', "fāng"),
+    ('𩲍', "niú"),
+    ('𩲎', "yòu"),
+    ('𩲏', "huà"),
+    ('𩲒', "làng"),
+    ('𩲠', "zhú"),
+    ('𩲡', "guǐ"),
+    ('𩲢', "bì"),
+    ('𩲣', "jiǎ"),
+    ('𩲤', "tiáo"),
+    ('𩲦', "lǜ"),
+    ('𩲧', "kǒng"),
+    ('𩲨', "zuǐ"),
+    ('𩲩', "líng"),
+    ('𩲪', "qí"),
+    ('𩲬', "zhú"),
+    ('𩲱', "gǔ"),
+    ('𩲲', "zù"),
+    ('𩲴', "yāng"),
+    ('𩲵', "sū"),
+    ('𩲷', "kuí"),
+    ('𩲹', "chāng"),
+    ('𩲻', "yáo"),
+    ('𩲾', "yù"),
+    ('𩳅', "shū"),
+    ('𩳆', "lài"),
+    ('𩳇', "yì"),
+    ('𩳈', "dōu"),
+    ('𩳌', "wú"),
+    ('𩳍', "yǐng"),
+    ('𩳎', "fú"),
+    ('𩳏', "zhuàn"),
+    ('𩳐', "fǔ"),
+    ('𩳒', "sù"),
+    ('𩳓', "lǐ"),
+    ('𩳔', "yào"),
+    ('𩳕', "tuì"),
+    ('𩳝', "guì"),
+    ('𩳡', "lǜ"),
+    ('𩳢', "yàn"),
+    ('𩳣', "qí"),
+    ('𩳤', "làng"),
+    ('𩳥', "zhú"),
+    ('𩳧', "guǐ"),
+    ('𩳨', "hū"),
+    ('𩳯', "jīng"),
+    ('𩳲', "chǐ"),
+    ('𩳵', "jú"),
+    ('𩳶', "zhá"),
+    ('𩳸', "miáo"),
+    ('𩴀', "zhū"),
+    ('𩴁', "gān"),
+    ('𩴂', "xiōng"),
+    ('𩴃', "jí"),
+    ('𩴇', "shài"),
+    ('𩴈', "mèi"),
+    ('𩴉', "yùn"),
+    ('𩴌', "gān"),
+    ('𩴍', "shòu"),
+    ('𩴐', "lǜ"),
+    ('𩴑', "yòu"),
+    ('𩴒', "jiàng"),
+    ('𩴓', "nuó"),
+    ('𩴘', "jù"),
+    ('𩴙', "yòu"),
+    ('𩴜', "yì"),
+    ('𩴝', "téng"),
+    ('𩴞', "wéi"),
+    ('𩴟', "chě"),
+    ('𩴠', "lìn"),
+    ('𩴡', "gù"),
+    ('𩴣', "lì"),
+    ('𩴤', "liào"),
+    ('𩴧', "jiāo"),
+    ('𩴨', "yáng"),
+    ('𩴩', "biāo"),
+    ('𩴪', "qí"),
+    ('𩴮', "yì"),
+    ('𩴱', "bīn"),
+    ('𩴲', "méng"),
+    ('𩴳', "chà"),
+    ('𩴵', "gān"),
+    ('𩴹', "qú"),
+    ('𩴺', "dí"),
+    ('𩴻', "léi"),
+    ('𩵀', "líng"),
+    ('𩵄', "huān"),
+    ('𩵅', "qú"),
+    ('𩵇', "luó"),
+    ('𩵉', "kuí"),
+    ('𩵍', "qiú"),
+    ('𩵎', "yǔ"),
+    ('𩵏', "huà"),
+    ('𩵓', "lèi"),
+    ('𩵕', "rèn"),
+    ('𩵖', "xiǎo"),
+    ('𩵗', "sì"),
+    ('𩵚', "dù"),
+    ('𩵛', "biē"),
+    ('𩵠', "niú"),
+    ('𩵢', "hè"),
+    ('𩵣', "pēi"),
+    ('𩵥', "fèi"),
+    ('𩵦', "mù"),
+    ('𩵩', "fū"),
+    ('𩵬', "hú"),
+    ('𩵭', "wáng"),
+    ('𩵮', "shā"),
+    ('𩵰', "jiāo"),
+    ('𩵱', "wǔ"),
+    ('𩵹', "fù"),
+    ('𩶁', "bǐng"),
+    ('𩶂', "zhù"),
+    ('𩶄', "zhú"),
+    ('𩶅', "chī"),
+    ('𩶇', "shěn"),
+    ('𩶈', "hū"),
+    ('𩶉', "bū"),
+    ('𩶎', "rǎn"),
+    ('𩶖', "mù"),
+    ('𩶘', "lì"),
+    ('𩶛', "jiā"),
+    ('𩶞', "mà"),
+    ('𩶡', "méng"),
+    ('𩶢', "móu"),
+    ('𩶣', "zhōu"),
+    ('𩶤', "xiǎn"),
+    ('𩶥', "huǐ"),
+    ('𩶦', "guài"),
+    ('𩶧', "jiù"),
+    ('𩶩', "mù"),
+    ('𩶫', "rù"),
+    ('𩶭', "wú"),
+    ('𩶯', "rú"),
+    ('𩶱', "zhà"),
+    ('𩷁', "nuǒ"),
+    ('𩷂', "xié"),
+    ('𩷄', "jiàng"),
+    ('𩷋', "lǐ"),
+    ('𩷌', "shū"),
+    ('𩷍', "yì"),
+    ('𩷎', "dí"),
+    ('𩷏', "qíng"),
+    ('𩷐', "jú"),
+    ('𩷓', "zhì"),
+    ('𩷕', "láng"),
+    ('𩷖', "bù"),
+    ('𩷗', "kuáng"),
+    ('𩷘', "yì"),
+    ('𩷚', "bó"),
+    ('𩷧', "chì"),
+    ('𩷭', "jiàng"),
+    ('𩷯', "wò"),
+    ('𩷰', "xùn"),
+    ('𩷵', "tūn"),
+    ('𩷶', "máng"),
+    ('𩷸', "fáng"),
+    ('𩷹', "zhuó"),
+    ('𩷻', "qià"),
+    ('𩷽', "tǎ"),
+    ('𩷾', "qí"),
+    ('𩸀', "pèng"),
+    ('𩸁', "biē"),
+    ('𩸂', "fèn"),
+    ('𩸃', "tù"),
+    ('𩸄', "huà"),
+    ('𩸇', "è"),
+    ('𩸋', "è"),
+    ('𩸎', "dìng"),
+    ('𩸐', "rú"),
+    ('𩸖', "è"),
+    ('𩸞', "yàn"),
+    ('𩸟', "sì"),
+    ('𩸥', "yíng"),
+    ('𩸦', "ní"),
+    ('𩸧', "ní"),
+    ('𩸨', "yí"),
+    ('𩸹', "mí"),
+    ('𩸾', "yé"),
+    ('𩸿', "pō"),
+    ('𩹀', "còu"),
+    ('𩹂', "wèi"),
+    ('𩹄', "hài"),
+    ('𩹅', "yīng"),
+    ('𩹇', "tíng"),
+    ('𩹈', "zhì"),
+    ('𩹉', "fēi"),
+    ('𩹊', "yóu"),
+    ('𩹍', "kuí"),
+    ('𩹎', "àn"),
+    ('𩹏', "bà"),
+    ('𩹑', "hàn"),
+    ('𩹞', "nán"),
+    ('𩹟', "nài"),
+    ('𩹢', "jīng"),
+    ('𩹥', "wēi"),
+    ('𩹱', "chù"),
+    ('𩹳', "suǒ"),
+    ('𩹴', "tāo"),
+    ('𩹵', "qí"),
+    ('𩹶', "táng"),
+    ('𩹷', "wěi"),
+    ('𩹸', "gǎn"),
+    ('𩹺', "gé"),
+    ('𩹼', "hàn"),
+    ('𩹾', "nà"),
+    ('𩹿', "gé"),
+    ('𩺄', "zhēng"),
+    ('𩺗', "tǎ"),
+    ('𩺛', "sī"),
+    ('𩺝', "nì"),
+    ('𩺞', "sǎng"),
+    ('𩺫', "xié"),
+    ('𩺯', "zú"),
+    ('𩺰', "yú"),
+    ('𩺱', "nì"),
+    ('𩺲', "qī"),
+    ('𩺵', "shēn"),
+    ('𩺼', "bū"),
+    ('𩻋', "kūn"),
+    ('𩻌', "lí"),
+    ('𩻎', "guā"),
+    ('𩻖', "yǎn"),
+    ('𩻗', "bù"),
+    ('𩻘', "jiàn"),
+    ('𩻚', "wú"),
+    ('𩻛', "cén"),
+    ('𩻜', "lín"),
+    ('𩻝', "zhuàn"),
+    ('𩻟', "huī"),
+    ('𩻡', "tóng"),
+    ('𩻢', "zhǎ"),
+    ('𩻤', "hēi"),
+    ('𩻧', "guǒ"),
+    ('𩻱', "jǐng"),
+    ('𩻵', "dié"),
+    ('𩻷', "yíng"),
+    ('𩻼', "zhì"),
+    ('𩼂', "wěi"),
+    ('𩼄', "jì"),
+    ('𩼅', "rǒng"),
+    ('𩼈', "ào"),
+    ('𩼉', "dāng"),
+    ('𩼊', "luó"),
+    ('𩼋', "yè"),
+    ('𩼌', "wēi"),
+    ('𩼒', "qiáng"),
+    ('𩼙', "gé"),
+    ('𩼚', "jì"),
+    ('𩼦', "zòu"),
+    ('𩼨', "yí"),
+    ('𩼫', "zhǎ"),
+    ('𩼭', "liè"),
+    ('𩼴', "yè"),
+    ('𩼼', "zhān"),
+    ('𩽀', "chóu"),
+    ('𩽁', "biāo"),
+    ('𩽆', "xù"),
+    ('𩽇', "yōu"),
+    ('𩽍', "xiè"),
+    ('𩽎', "wéi"),
+    ('𩽏', "lì"),
+    ('𩽛', "bó"),
+    ('𩽜', "jiǎn"),
+    ('𩽝', "chán"),
+    ('𩽞', "kūn"),
+    ('𩽡', "qíng"),
+    ('𩽧', "shuāng"),
+    ('𩽨', "xī"),
+    ('𩽩', "qú"),
+    ('𩽰', "luó"),
+    ('𩽳', "dǎng"),
+    ('𩽴', "nián"),
+    ('𩽵', "lǐ"),
+    ('𩽷', "bà"),
+    ('𩽹', "è"),
+    ('𩽺', "fū"),
+    ('𩽻', "fù"),
+    ('𩽼', "hǔn"),
+    ('𩽽', "zhà"),
+    ('𩽾', "ān"),
+    ('𩾁', "qiú"),
+    ('𩾂', "chóu"),
+    ('𩾃', "miǎn"),
+    ('𩾄', "xùn"),
+    ('𩾅', "tù"),
+    ('𩾆', "ní"),
+    ('𩾇', "hu"),
+    ('𩾈', "shū"),
+    ('𩾊', "xū"),
+    ('𩾋', "zhòng"),
+    ('𩾌', "kāng"),
+    ('𩾎', "yōu"),
+    ('𩾒', "xiāo"),
+    ('𩾓', "xiāo"),
+    ('𩾔', "cì"),
+    ('𩾕', "chì"),
+    ('𩾗', "diāo"),
+    ('𩾘', "yì"),
+    ('𩾚', "dīng"),
+    ('𩾝', "hàn"),
+    ('𩾞', "wán"),
+    ('𩾠', "yǐ"),
+    ('𩾡', "bào"),
+    ('𩾢', "yì"),
+    ('𩾧', "xùn"),
+    ('𩾬', "xiáng"),
+    ('𩾳', "bí"),
+    ('𩾶', "jié"),
+    ('𩾷', "gē"),
+    ('𩾸', "zè"),
+    ('𩾺', "zhèn"),
+    ('𩾻', "hú"),
+    ('𩾼', "xī"),
+    ('𩾽', "xīn"),
+    ('𩾾', "xiāo"),
+    ('𩾿', "fù"),
+    ('𩿀', "zhòng"),
+    ('𩿂', "mào"),
+    ('𩿃', "xīn"),
+    ('𩿄', "qiāng"),
+    ('𩿈', "fén"),
+    ('𩿉', "bān"),
+    ('𩿊', "huān"),
+    ('𩿑', "jiāo"),
+    ('𩿓', "bào"),
+    ('𩿔', "yā"),
+    ('𩿕', "yáo"),
+    ('𩿛', "xì"),
+    ('𩿝', "jù"),
+    ('𩿟', "qù"),
+    ('𩿠', "yuè"),
+    ('𩿡', "tái"),
+    ('𩿢', "tǒu"),
+    ('𩿣', "mò"),
+    ('𩿤', "zhá"),
+    ('𩿥', "qú"),
+    ('𩿧', "fū"),
+    ('𩿩', "qú"),
+    ('𩿪', "chì"),
+    ('𩿬', "yóu"),
+    ('𩿷', "tí"),
+    ('𩿺', "wā"),
+    ('𩿽', "tuó"),
+    ('𩿿', "chú"),
+    ('𪀁', "gē"),
+    ('𪀉', "gē"),
+    ('𪀊', "qú"),
+    ('𪀏', "jù"),
+    ('𪀒', "dié"),
+    ('𪀓', "yí"),
+    ('𪀔', "shī"),
+    ('𪀕', "yì"),
+    ('𪀗', "guǐ"),
+    ('𪀘', "jiàng"),
+    ('𪀚', "sōng"),
+    ('𪀛', "qióng"),
+    ('𪀝', "è"),
+    ('𪀞', "huāng"),
+    ('𪀟', "huí"),
+    ('𪀠', "xún"),
+    ('𪀣', "jú"),
+    ('𪀥', "zhái"),
+    ('𪀦', "chì"),
+    ('𪀧', "lǎo"),
+    ('𪀩', "qí"),
+    ('𪀪', "xiū"),
+    ('𪀬', "huī"),
+    ('𪀭', "tóng"),
+    ('𪀺', "fù"),
+    ('𪀽', "xún"),
+    ('𪀾', "jié"),
+    ('𪀿', "mǐ"),
+    ('𪁀', "yù"),
+    ('𪁈', "zhuàng"),
+    ('𪁉', "jiāo"),
+    ('𪁊', "zhì"),
+    ('𪁋', "chéng"),
+    ('𪁍', "jié"),
+    ('𪁎', "xiāo"),
+    ('𪁏', "chén"),
+    ('𪁐', "lí"),
+    ('𪁑', "yuè"),
+    ('𪁓', "zhì"),
+    ('𪁔', "láo"),
+    ('𪁕', "wò"),
+    ('𪁖', "qú"),
+    ('𪁘', "wāng"),
+    ('𪁚', "yī"),
+    ('𪁛', "yì"),
+    ('𪁜', "láng"),
+    ('𪁞', "tóu"),
+    ('𪁟', "ān"),
+    ('𪁠', "jué"),
+    ('𪁡', "yàn"),
+    ('𪁥', "jù"),
+    ('𪁧', "zhèn"),
+    ('𪁩', "zhì"),
+    ('𪁪', "mǎng"),
+    ('𪁮', "xiù"),
+    ('𪁱', "chuáng"),
+    ('𪁲', "chū"),
+    ('𪁸', "qiāng"),
+    ('𪁹', "fēi"),
+    ('𪁺', "cháng"),
+    ('𪁼', "mián"),
+    ('𪁽', "sù"),
+    ('𪁾', "ǎo"),
+    ('𪂀', "fǔ"),
+    ('𪂄', "wèi"),
+    ('𪂅', "zhī"),
+    ('𪂆', "mín"),
+    ('𪂇', "chāng"),
+    ('𪂈', "yán"),
+    ('𪂉', "yù"),
+    ('𪂋', "fù"),
+    ('𪂌', "tà"),
+    ('𪂍', "jǐ"),
+    ('𪂏', "fèi"),
+    ('𪂒', "hú"),
+    ('𪂓', "jū"),
+    ('𪂕', "yǔ"),
+    ('𪂛', "qí"),
+    ('𪂜', "méi"),
+    ('𪂟', "biē"),
+    ('𪂠', "guǒ"),
+    ('𪂤', "mìng"),
+    ('𪂦', "wǎn"),
+    ('𪂧', "wǎn"),
+    ('𪂴', "jīng"),
+    ('𪂵', "yù"),
+    ('𪂶', "xián"),
+    ('𪂹', "chūn"),
+    ('𪂺', "jí"),
+    ('𪂼', "xiāng"),
+    ('𪂽', "pén"),
+    ('𪂾', "fù"),
+    ('𪃂', "liú"),
+    ('𪃄', "sāi"),
+    ('𪃅', "xuē"),
+    ('𪃆', "zòu"),
+    ('𪃈', "jié"),
+    ('𪃋', "zhān"),
+    ('𪃍', "yú"),
+    ('𪃎', "yú"),
+    ('𪃏', "méi"),
+    ('𪃐', "miǎo"),
+    ('𪃑', "mào"),
+    ('𪃒', "duó"),
+    ('𪃓', "fù"),
+    ('𪃛', "jiàn"),
+    ('𪃦', "miáo"),
+    ('𪃨', "āo"),
+    ('𪃭', "kè"),
+    ('𪃶', "hóu"),
+    ('𪃺', "gòu"),
+    ('𪃼', "xī"),
+    ('𪃾', "róng"),
+    ('𪃿', "gē"),
+    ('𪄀', "pán"),
+    ('𪄁', "yuán"),
+    ('𪄂', "xià"),
+    ('𪄅', "shā"),
+    ('𪄆', "pī"),
+    ('𪄈', "qíng"),
+    ('𪄉', "yōng"),
+    ('𪄊', "qú"),
+    ('𪄌', "gòng"),
+    ('𪄎', "gé"),
+    ('𪄏', "xiān"),
+    ('𪄑', "sù"),
+    ('𪄕', "bān"),
+    ('𪄖', "qí"),
+    ('𪄗', "hòu"),
+    ('𪄛', "xī"),
+    ('𪄝', "wū"),
+    ('𪄭', "qī"),
+    ('𪄮', "hù"),
+    ('𪄯', "guī"),
+    ('𪄱', "dí"),
+    ('𪄲', "shāng"),
+    ('𪄳', "mài"),
+    ('𪄴', "mǐn"),
+    ('𪄵', "jì"),
+    ('𪄶', "xí"),
+    ('𪄷', "xiān"),
+    ('𪄸', "jí"),
+    ('𪄹', "cháng"),
+    ('𪄺', "kòu"),
+    ('𪄻', "chōng"),
+    ('𪅂', "zhāng"),
+    ('𪅃', "piǎo"),
+    ('𪅄', "sù"),
+    ('𪅅', "lüè"),
+    ('𪅆', "lí"),
+    ('𪅇', "mèng"),
+    ('𪅈', "chōng"),
+    ('𪅉', "tiān"),
+    ('𪅋', "líng"),
+    ('𪅍', "chì"),
+    ('𪅖', "chōng"),
+    ('𪅙', "chì"),
+    ('𪅝', "niǎo"),
+    ('𪅟', "yóng"),
+    ('𪅮', "mì"),
+    ('𪅰', "shū"),
+    ('𪅲', "xì"),
+    ('𪅴', "è"),
+    ('𪅵', "zī"),
+    ('𪅸', "jié"),
+    ('𪅹', "jī"),
+    ('𪅺', "hōu"),
+    ('𪅻', "shèng"),
+    ('𪅼', "lì"),
+    ('𪅾', "qī"),
+    ('𪆀', "zhōu"),
+    ('𪆁', "sī"),
+    ('𪆂', "qú"),
+    ('𪆋', "xié"),
+    ('𪆗', "sī"),
+    ('𪆛', "xū"),
+    ('𪆠', "fù"),
+    ('𪆯', "nóng"),
+    ('𪆰', "yà"),
+    ('𪆱', "liú"),
+    ('𪆲', "jiǎ"),
+    ('𪆳', "guī"),
+    ('𪆴', "kuí"),
+    ('𪆵', "chì"),
+    ('𪆶', "càn"),
+    ('𪆷', "chú"),
+    ('𪆹', "guō"),
+    ('𪆻', "dǎn"),
+    ('𪆿', "jiàn"),
+    ('𪇁', "dāng"),
+    ('𪇂', "hòu"),
+    ('𪇄', "kòu"),
+    ('𪇆', "chù"),
+    ('𪇇', "qiān"),
+    ('𪇈', "ài"),
+    ('𪇊', "pì"),
+    ('𪇑', "xùn"),
+    ('𪇒', "jīng"),
+    ('𪇓', "mèng"),
+    ('𪇕', "bīn"),
+    ('𪇖', "lán"),
+    ('𪇗', "gǔ"),
+    ('𪇘', "chóu"),
+    ('𪇛', "yōng"),
+    ('𪇜', "guá"),
+    ('𪇝', "yú"),
+    ('𪇞', "zhòu"),
+    ('𪇭', "cài"),
+    ('𪇯', "liú"),
+    ('𪇰', "bǔ"),
+    ('𪇱', "luò"),
+    ('𪇲', "jié"),
+    ('𪇳', "zhēn"),
+    ('𪇴', "miè"),
+    ('𪇵', "guǎng"),
+    ('𪇷', "jiá"),
+    ('𪇹', "là"),
+    ('𪈀', "shòu"),
+    ('𪈃', "guō"),
+    ('𪈆', "mèng"),
+    ('𪈇', "qián"),
+    ('𪈈', "lài"),
+    ('𪈊', "hé"),
+    ('𪈋', "tuán"),
+    ('𪈑', "huī"),
+    ('𪈘', "hōng"),
+    ('𪈜', "lǚ"),
+    ('𪈟', "jiá"),
+    ('𪈥', "guī"),
+    ('𪈨', "yī"),
+    ('𪈩', "huān"),
+    ('𪈰', "luó"),
+    ('𪈴', "jué"),
+    ('𪈸', "guàn"),
+    ('𪈻', "quán"),
+    ('𪈼', "niǎo"),
+    ('𪈿', "mán"),
+    ('𪉂', "yùn"),
+    ('𪉃', "wén"),
+    ('𪉄', "chì"),
+    ('𪉅', "chì"),
+    ('𪉆', "zhī"),
+    ('𪉈', "cí"),
+    ('𪉉', "zhuàng"),
+    ('𪉊', "huá"),
+    ('𪉋', "jié"),
+    ('𪉌', "qú"),
+    ('𪉍', "tū"),
+    ('𪉎', "mín"),
+    ('𪉏', "méi"),
+    ('𪉐', "yú"),
+    ('𪉑', "áo"),
+    ('𪉒', "bān"),
+    ('𪉔', "pī"),
+    ('𪉕', "zhēn"),
+    ('𪉖', "lǔ"),
+    ('𪉗', "chì"),
+    ('𪉘', "tóu"),
+    ('𪉚', "jiē"),
+    ('𪉜', "zhān"),
+    ('𪉢', "jīn"),
+    ('𪉣', "lǔ"),
+    ('𪉦', "jiàn"),
+    ('𪉧', "tàn"),
+    ('𪉨', "chāng"),
+    ('𪉪', "cì"),
+    ('𪉭', "wāi"),
+    ('𪉮', "còu"),
+    ('𪉯', "kàn"),
+    ('𪉱', "biàn"),
+    ('𪉸', "wēn"),
+    ('𪉻', "qiān"),
+    ('𪉿', "gàn"),
+    ('𪊂', "huì"),
+    ('𪊄', "gǎn"),
+    ('𪊆', "jì"),
+    ('𪊇', "gàn"),
+    ('𪊉', "huái"),
+    ('𪊍', "sì"),
+    ('𪊐', "fū"),
+    ('𪊕', "pí"),
+    ('𪊗', "cā"),
+    ('𪊜', "bèn"),
+    ('𪊢', "shǐ"),
+    ('𪊥', "huán"),
+    ('𪊧', "guī"),
+    ('𪊪', "ǒu"),
+    ('𪊳', "páo"),
+    ('𪊵', "yǐng"),
+    ('𪊶', "tǐng"),
+    ('𪊷', "xiào"),
+    ('𪊹', "zhù"),
+    ('𪊻', "yú"),
+    ('𪋁', "jiàn"),
+    ('𪋄', "qǔ"),
+    ('𪋅', "wǎn"),
+    ('𪋆', "kūn"),
+    ('𪋇', "zhuī"),
+    ('𪋉', "yù"),
+    ('𪋊', "guǒ"),
+    ('𪋋', "píng"),
+    ('𪋌', "zuǐ"),
+    ('𪋍', "zú"),
+    ('𪋏', "zhū"),
+    ('𪋐', "nuàn"),
+    ('𪋑', "zhū"),
+    ('𪋖', "piāo"),
+    ('𪋗', "mí"),
+    ('𪋜', "bì"),
+    ('𪋝', "sù"),
+    ('𪋡', "pú"),
+    ('𪋢', "mí"),
+    ('𪋫', "yè"),
+    ('𪋬', "yǔ"),
+    ('𪋮', "yù"),
+    ('𪋰', "zhǔ"),
+    ('𪋳', "líng"),
+    ('𪋺', "nòu"),
+    ('𪋾', "líng"),
+    ('𪌀', "liǎo"),
+    ('𪌂', "tuō"),
+    ('𪌄', "bǐ"),
+    ('𪌅', "nà"),
+    ('𪌆', "qú"),
+    ('𪌈', "pí"),
+    ('𪌉', "dǒu"),
+    ('𪌊', "niè"),
+    ('𪌋', "tún"),
+    ('𪌍', "jī"),
+    ('𪌏', "líng"),
+    ('𪌓', "kù"),
+    ('𪌔', "sù"),
+    ('𪌘', "tǒu"),
+    ('𪌞', "nái"),
+    ('𪌟', "zé"),
+    ('𪌢', "tǒng"),
+    ('𪌣', "gé"),
+    ('𪌤', "duī"),
+    ('𪌧', "jié"),
+    ('𪌩', "tián"),
+    ('𪌪', "tiào"),
+    ('𪌫', "chí"),
+    ('𪌬', "qū"),
+    ('𪌮', "shā"),
+    ('𪌰', "bó"),
+    ('𪌱', "lí"),
+    ('𪌳', "luò"),
+    ('𪌵', "liáo"),
+    ('𪌶', "shù"),
+    ('𪌷', "děng"),
+    ('𪌹', "chī"),
+    ('𪌺', "miè"),
+    ('𪌼', "táo"),
+    ('𪌽', "hún"),
+    ('𪌿', "nié"),
+    ('𪍁', "jùn"),
+    ('𪍂', "hù"),
+    ('𪍄', "lù"),
+    ('𪍅', "yè"),
+    ('𪍇', "mò"),
+    ('𪍈', "chào"),
+    ('𪍌', "suò"),
+    ('𪍎', "kē"),
+    ('𪍏', "fù"),
+    ('𪍑', "chǎo"),
+    ('𪍔', "suǒ"),
+    ('𪍗', "qiū"),
+    ('𪍛', "sù"),
+    ('𪍝', "yùn"),
+    ('𪍟', "suǒ"),
+    ('𪍠', "kū"),
+    ('𪍡', "bó"),
+    ('𪍣', "lǒu"),
+    ('𪍤', "mò"),
+    ('𪍦', "liǎn"),
+    ('𪍧', "xuàn"),
+    ('𪍨', "suǒ"),
+    ('𪍩', "mán"),
+    ('𪍪', "bì"),
+    ('𪍲', "tì"),
+    ('𪍴', "lián"),
+    ('𪍵', "tán"),
+    ('𪍶', "shàn"),
+    ('𪍸', "qú"),
+    ('𪍹', "dú"),
+    ('𪍺', "huán"),
+    ('𪍻', "sào"),
+    ('𪍿', "kuàng"),
+    ('𪎃', "niè"),
+    ('𪎅', "niè"),
+    ('𪎆', "luó"),
+    ('𪎇', "zuó"),
+    ('𪎈', "yì"),
+    ('𪎉', "xiàn"),
+    ('𪎊', "chǎo"),
+    ('𪎋', "tiè"),
+    ('𪎌', "lái"),
+    ('𪎒', "shuò"),
+    ('𪎔', "mǐ"),
+    ('𪎗', "mí"),
+    ('𪎛', "wǎn"),
+    ('𪎝', "bèn"),
+    ('𪎞', "qiāng"),
+    ('𪎠', "mǒ"),
+    ('𪎣', "liú"),
+    ('𪎤', "wò"),
+    ('𪎦', "měi"),
+    ('𪎨', "tóu"),
+    ('𪎫', "mǔ"),
+    ('𪎭', "méi"),
+    ('𪎲', "zuò"),
+    ('𪎴', "tún"),
+    ('𪎵', "kàng"),
+    ('𪎶', "tún"),
+    ('𪎺', "chè"),
+    ('𪎻', "zhèng"),
+    ('𪎽', "chōng"),
+    ('𪎾', "tiān"),
+    ('𪏀', "zhì"),
+    ('𪏁', "chán"),
+    ('𪏂', "chán"),
+    ('𪏅', "qīng"),
+    ('𪏆', "tūn"),
+    ('𪏇', "huǐ"),
+    ('𪏈', "què"),
+    ('𪏉', "zhān"),
+    ('𪏊', "jiān"),
+    ('𪏋', "chán"),
+    ('𪏍', "huáng"),
+    ('𪏏', "huī"),
+    ('𪏐', "chí"),
+    ('𪏒', "huáng"),
+    ('𪏓', "héng"),
+    ('𪏔', "yǔn"),
+    ('𪏖', "tuān"),
+    ('𪏗', "biān"),
+    ('𪏙', "huáng"),
+    ('𪏚', "yǔn"),
+    ('𪏟', "mò"),
+    ('𪏠', "gōng"),
+    ('𪏢', "gōng"),
+    ('𪏤', "guì"),
+    ('𪏦', "chán"),
+    ('𪏨', "què"),
+    ('𪏩', "ruì"),
+    ('𪏪', "kuàng"),
+    ('𪏫', "piào"),
+    ('𪏮', "rǔ"),
+    ('𪏲', "niǔ"),
+    ('𪏳', "hù"),
+    ('𪏴', "jǐn"),
+    ('𪏵', "nì"),
+    ('𪏶', "bào"),
+    ('𪏸', "nǐ"),
+    ('𪏺', "bì"),
+    ('𪏻', "hú"),
+    ('𪏼', "lí"),
+    ('𪏿', "zhū"),
+    ('𪐀', "nǎ"),
+    ('𪐂', "quǎn"),
+    ('𪐃', "fěng"),
+    ('𪐄', "bǐ"),
+    ('𪐅', "lí"),
+    ('𪐆', "bié"),
+    ('𪐇', "nián"),
+    ('𪐈', "dǒng"),
+    ('𪐋', "lián"),
+    ('𪐌', "nì"),
+    ('𪐍', "lián"),
+    ('𪐎', "má"),
+    ('𪐏', "zhé"),
+    ('𪐓', "jiā"),
+    ('𪐔', "yí"),
+    ('𪐖', "lǒng"),
+    ('𪐘', "yì"),
+    ('𪐝', "dài"),
+    ('𪐞', "dù"),
+    ('𪐣', "yǐ"),
+    ('𪐥', "tài"),
+    ('𪐦', "hāng"),
+    ('𪐧', "shù"),
+    ('𪐬', "wán"),
+    ('𪐮', "sù"),
+    ('𪐯', "yǎo"),
+    ('𪐰', "èr"),
+    ('𪐲', "zhèn"),
+    ('𪐺', "dòu"),
+    ('𪐻', "jiān"),
+    ('𪐿', "pāng"),
+    ('𪑀', "huī"),
+    ('𪑂', "chà"),
+    ('𪑃', "shān"),
+    ('𪑄', "lú"),
+    ('𪑅', "wèi"),
+    ('𪑆', "yù"),
+    ('𪑈', "yàn"),
+    ('𪑉', "wǎn"),
+    ('𪑊', "qiào"),
+    ('𪑋', "luō"),
+    ('𪑌', "yù"),
+    ('𪑏', "tú"),
+    ('𪑐', "wèi"),
+    ('𪑒', "tùn"),
+    ('𪑕', "hǔn"),
+    ('𪑖', "bēn"),
+    ('𪑗', "qiè"),
+    ('𪑙', "jīn"),
+    ('𪑚', "lái"),
+    ('𪑜', "zhǐ"),
+    ('𪑝', "yú"),
+    ('𪑟', "cì"),
+    ('𪑦', "yè"),
+    ('𪑧', "dié"),
+    ('𪑨', "chà"),
+    ('𪑩', "diàn"),
+    ('𪑪', "mán"),
+    ('𪑬', "dèng"),
+    ('𪑭', "wēi"),
+    ('𪑮', "niǎn"),
+    ('𪑯', "lèi"),
+    ('𪑰', "bīng"),
+    ('𪑱', "wū"),
+    ('𪑳', "zhěn"),
+    ('𪑶', "róu"),
+    ('𪑷', "wài"),
+    ('𪑸', "mì"),
+    ('𪑹', "jiè"),
+    ('𪑻', "hóu"),
+    ('𪑽', "zhài"),
+    ('𪑾', "rǔ"),
+    ('𪑿', "zī"),
+    ('𪒀', "pán"),
+    ('𪒂', "mò"),
+    ('𪒄', "mì"),
+    ('𪒆', "qī"),
+    ('𪒇', "mò"),
+    ('𪒊', "zhī"),
+    ('𪒋', "bān"),
+    ('𪒍', "miè"),
+    ('𪒏', "lù"),
+    ('𪒑', "qī"),
+    ('𪒒', "chōng"),
+    ('𪒔', "lí"),
+    ('𪒕', "yì"),
+    ('𪒘', "dèng"),
+    ('𪒙', "cuō"),
+    ('𪒛', "duì"),
+    ('𪒜', "mà"),
+    ('𪒝', "yǎn"),
+    ('𪒟', "zèng"),
+    ('𪒠', "yǎn"),
+    ('𪒡', "duì"),
+    ('𪒢', "pū"),
+    ('𪒥', "yuè"),
+    ('𪒩', "huò"),
+    ('𪒪', "mài"),
+    ('𪒫', "jiǎn"),
+    ('𪒬', "nóng"),
+    ('𪒭', "qín"),
+    ('𪒯', "qín"),
+    ('𪒲', "yè"),
+    ('𪒴', "tái"),
+    ('𪒹', "jiān"),
+    ('𪒼', "chá"),
+    ('𪒾', "dàn"),
+    ('𪒿', "téng"),
+    ('𪓀', "lì"),
+    ('𪓃', "niǎng"),
+    ('𪓄', "chán"),
+    ('𪓅', "zāng"),
+    ('𪓊', "yù"),
+    ('𪓌', "zuì"),
+    ('𪓍', "biān"),
+    ('𪓐', "chǔ"),
+    ('𪓘', "rán"),
+    ('𪓚', "rán"),
+    ('𪓛', "yāng"),
+    ('𪓜', "bǒ"),
+    ('𪓡', "cù"),
+    ('𪓬', "mí"),
+    ('𪓮', "kě"),
+    ('𪓰', "cù"),
+    ('𪓷', "xí"),
+    ('𪓹', "má"),
+    ('𪓻', "shī"),
+    ('𪓼', "diān"),
+    ('𪓿', "shī"),
+    ('𪔂', "dǐng"),
+    ('𪔃', "jiōng"),
+    ('𪔅', "yuán"),
+    ('𪔆', "gān"),
+    ('𪔊', "huì"),
+    ('𪔋', "jī"),
+    ('𪔍', "péng"),
+    ('𪔏', "dēng"),
+    ('𪔑', "bèng"),
+    ('𪔔', "pāng"),
+    ('𪔕', "tà"),
+    ('𪔗', "yuān"),
+    ('𪔘', "gāo"),
+    ('𪔙', "yuān"),
+    ('𪔟', "jiā"),
+    ('𪔣', "kōng"),
+    ('𪔦', "dòng"),
+    ('𪔩', "xián"),
+    ('𪔪', "qì"),
+    ('𪔬', "sāng"),
+    ('𪔰', "yìn"),
+    ('𪔳', "lóng"),
+    ('𪔶', "tēng"),
+    ('𪔷', "lóng"),
+    ('𪔺', "rèn"),
+    ('𪔽', "yìn"),
+    ('𪔾', "píng"),
+    ('𪔿', "pū"),
+    ('𪕀', "yuán"),
+    ('𪕁', "rǒng"),
+    ('𪕃', "fāng"),
+    ('𪕇', "hāng"),
+    ('𪕈', "mí"),
+    ('𪕉', "hú"),
+    ('𪕊', "zī"),
+    ('𪕌', "líng"),
+    ('𪕍', "jiōng"),
+    ('𪕎', "rǒng"),
+    ('𪕒', "píng"),
+    ('𪕓', "guāng"),
+    ('𪕔', "ěr"),
+    ('𪕝', "cù"),
+    ('𪕞', "jùn"),
+    ('𪕦', "xiǔ"),
+    ('𪕨', "ér"),
+    ('𪕩', "tì"),
+    ('𪕫', "yáng"),
+    ('𪕭', "ài"),
+    ('𪕮', "hú"),
+    ('𪕯', "xí"),
+    ('𪕱', "hú"),
+    ('𪕳', "sī"),
+    ('𪕴', "lǐ"),
+    ('𪕶', "yì"),
+    ('𪕷', "gǔ"),
+    ('𪕹', "táng"),
+    ('𪖀', "què"),
+    ('𪖁', "zōng"),
+    ('𪖂', "lí"),
+    ('𪖄', "jiào"),
+    ('𪖇', "fán"),
+    ('𪖈', "pú"),
+    ('𪖉', "sī"),
+    ('𪖋', "jié"),
+    ('𪖌', "lú"),
+    ('𪖍', "lì"),
+    ('𪖎', "chán"),
+    ('𪖐', "yào"),
+    ('𪖕', "huī"),
+    ('𪖙', "hōu"),
+    ('𪖚', "diān"),
+    ('𪖛', "qiù"),
+    ('𪖜', "jué"),
+    ('𪖞', "pì"),
+    ('𪖢', "kuī"),
+    ('𪖥', "xǐ"),
+    ('𪖦', "tī"),
+    ('𪖩', "xù"),
+    ('𪖯', "biǎn"),
+    ('𪖲', "hē"),
+    ('𪖳', "lián"),
+    ('𪖶', "sù"),
+    ('𪖷', "liào"),
+    ('𪖼', "jīn"),
+    ('𪗁', "lì"),
+    ('𪗂', "chán"),
+    ('𪗅', "qí"),
+    ('𪗆', "qí"),
+    ('𪗉', "zī"),
+    ('𪗋', "zī"),
+    ('𪗍', "qí"),
+    ('𪗏', "qí"),
+    ('𪗐', "zī"),
+    ('𪗒', "zhāi"),
+    ('𪗓', "zhāi"),
+    ('𪗔', "pà"),
+    ('𪗖', "jū"),
+    ('𪗙', "yǎn"),
+    ('𪗜', "háng"),
+    ('𪗝', "nà"),
+    ('𪗤', "yǎn"),
+    ('𪗦', "zhàn"),
+    ('𪗧', "shǐ"),
+    ('𪗨', "zhí"),
+    ('𪗭', "zhā"),
+    ('𪗴', "rǒng"),
+    ('𪗵', "zhā"),
+    ('𪗷', "yì"),
+    ('𪗸', "míng"),
+    ('𪗹', "yá"),
+    ('𪗻', "zhì"),
+    ('𪗽', "kuò"),
+    ('𪗾', "xiá"),
+    ('𪘀', "pián"),
+    ('𪘁', "tà"),
+    ('𪘃', "yǐ"),
+    ('𪘆', "xiū"),
+    ('𪘇', "zhāi"),
+    ('𪘉', "duǒ"),
+    ('𪘊', "è"),
+    ('𪘎', "yín"),
+    ('𪘐', "è"),
+    ('𪘑', "suān"),
+    ('𪘒', "ān"),
+    ('𪘓', "cuó"),
+    ('𪘕', "tuó"),
+    ('𪘗', "tuó"),
+    ('𪘘', "xiá"),
+    ('𪘛', "chuò"),
+    ('𪘝', "suān"),
+    ('𪘥', "jì"),
+    ('𪘦', "qiǎn"),
+    ('𪘧', "zú"),
+    ('𪘨', "zhāi"),
+    ('𪘩', "yǔn"),
+    ('𪘪', "zhàn"),
+    ('𪘬', "yí"),
+    ('𪘲', "yá"),
+    ('𪘳', "yuē"),
+    ('𪘹', "hé"),
+    ('𪘺', "qià"),
+    ('𪘾', "chā"),
+    ('𪙃', "óu"),
+    ('𪙈', "hú"),
+    ('𪙊', "yàn"),
+    ('𪙌', "qiè"),
+    ('𪙍', "bó"),
+    ('𪙎', "qiāng"),
+    ('𪙏', "jiè"),
+    ('𪙛', "nì"),
+    ('𪙞', "chǎn"),
+    ('𪙟', "qǐn"),
+    ('𪙡', "zāo"),
+    ('𪙤', "yǐn"),
+    ('𪙥', "xiè"),
+    ('𪙧', "qí"),
+    ('𪙨', "jiàn"),
+    ('𪙫', "xū"),
+    ('𪙭', "zèng"),
+    ('𪙯', "è"),
+    ('𪙳', "zū"),
+    ('𪙴', "yǐ"),
+    ('𪙹', "zhí"),
+    ('𪙺', "lì"),
+    ('𪙽', "lì"),
+    ('𪙾', "yín"),
+    ('𪚁', "lián"),
+    ('𪚃', "chán"),
+    ('𪚅', "jué"),
+    ('𪚇', "zá"),
+    ('𪚎', "zhāi"),
+    ('𪚏', "pián"),
+    ('𪚑', "lóng"),
+    ('𪚓', "lóng"),
+    ('𪚘', "lóng"),
+    ('𪚝', "lóng"),
+    ('𪚠', "lóng"),
+    ('𪚢', "mǎng"),
+    ('𪚥', "zhé"),
+    ('𪚬', "gàn"),
+    ('𪚭', "gōu"),
+    ('𪚮', "rán"),
+    ('𪚯', "cù"),
+    ('𪚰', "jiāo"),
+    ('𪚷', "bǒ"),
+    ('𪚹', "zhù"),
+    ('𪚺', "qiū"),
+    ('𪚻', "yāng"),
+    ('𪛀', "xiào"),
+    ('𪛂', "huí"),
+    ('𪛃', "qū"),
+    ('𪛈', "líng"),
+    ('𪛊', "yín"),
+    ('𪛎', "pì"),
+    ('𪛒', "lián"),
+    ('𪜎', "qiào"),
+    ('𪞝', "duó"),
+    ('𪟎', "jiān"),
+    ('𪟝', "jì"),
+    ('𪠀', "kū"),
+    ('𪠏', "yǎn"),
+    ('𪠟', "zhèn"),
+    ('𪠡', "sā"),
+    ('𪠳', "chē"),
+    ('𪠵', "lún"),
+    ('𪠸', "hù"),
+    ('𪠽', "dāng"),
+    ('𪡀', "qiáo"),
+    ('𪡃', "mǎi"),
+    ('𪡈', "bái"),
+    ('𪡋', "yǎn"),
+    ('𪡏', "zhān"),
+    ('𪡛', "hé"),
+    ('𪡞', "kuì"),
+    ('𪡺', "lài"),
+    ('𪢌', "lán"),
+    ('𪢒', "shù"),
+    ('𪢕', "chuò"),
+    ('𪢠', "lán"),
+    ('𪢮', "luán"),
+    ('𪣆', "dōng"),
+    ('𪣒', "hún"),
+    ('𪣻', "lóu"),
+    ('𪤗', "liào"),
+    ('𪤚', "lín"),
+    ('𪥠', "réng"),
+    ('𪥫', "shuāng"),
+    ('𪥰', "níng"),
+    ('𪥿', "dú"),
+    ('𪧀', "yīng"),
+    ('𪧘', "jù"),
+    ('𪨇', "tuí"),
+    ('𪨊', "sóng"),
+    ('𪨗', "juē"),
+    ('𪨧', "lún"),
+    ('𪨩', "yà"),
+    ('𪨰', "qū"),
+    ('𪨶', "shē"),
+    ('𪨷', "yán"),
+    ('𪨹', "tuó"),
+    ('𪩇', "lóu"),
+    ('𪩎', "yǐng"),
+    ('𪩘', "yǎn"),
+    ('𪩛', "niè"),
+    ('𪩸', "fén"),
+    ('𪪑', "qiào"),
+    ('𪪝', "yōng"),
+    ('𪪞', "qiáng"),
+    ('𪪴', "yì"),
+    ('𪫌', "lòng"),
+    ('𪫡', "lì"),
+    ('𪫷', "chóu"),
+    ('𪫸', "jǐ"),
+    ('𪫺', "xiān"),
+    ('𪬚', "pīn"),
+    ('𪭝', "wěi"),
+    ('𪭢', "chuāng"),
+    ('𪭧', "mí"),
+    ('𪭯', "guài"),
+    ('𪭵', "liǎng"),
+    ('𪭾', "xiàn"),
+    ('𪮃', "cán"),
+    ('𪮋', "xiāo"),
+    ('𪮖', "juē"),
+    ('𪮶', "là"),
+    ('𪯋', "shǎn"),
+    ('𪰶', "fèi"),
+    ('𪱥', "fèn"),
+    ('𪱷', "bèi"),
+    ('𪲎', "jī"),
+    ('𪲔', "lì"),
+    ('𪲛', "jì"),
+    ('𪲮', "fèi"),
+    ('𪳍', "shè"),
+    ('𪴙', "cuán"),
+    ('𪴯', "è"),
+    ('𪵑', "xiāo"),
+    ('𪵣', "xì"),
+    ('𪵱', "lì"),
+    ('𪶄', "shí"),
+    ('𪶒', "luó"),
+    ('𪷍', "qìng"),
+    ('𪷽', "zàn"),
+    ('𪸕', "xì"),
+    ('𪸩', "huī"),
+    ('𪹀', "yì"),
+    ('𪹳', "zhú"),
+    ('𪹹', "tà"),
+    ('𪺣', "dào"),
+    ('𪺭', "qiáo"),
+    ('𪺷', "cāng"),
+    ('𪺹', "nǔ"),
+    ('𪺻', "nóng"),
+    ('𪺽', "yìn"),
+    ('𪻐', "cōng"),
+    ('𪻨', "dú"),
+    ('𪻲', "kūn"),
+    ('𪻺', "guī"),
+    ('𪼋', "bǐ"),
+    ('𪽪', "zhàng"),
+    ('𪽭', "jiàn"),
+    ('𪽮', "lú"),
+    ('𪽴', "shè"),
+    ('𪽷', "yì"),
+    ('𪾔', "xǔ"),
+    ('𪾢', "xiàn"),
+    ('𪾦', "lú"),
+    ('𪾸', "pín"),
+    ('𪿊', "yà"),
+    ('𪿫', "pào"),
+    ('𫀌', "shī"),
+    ('𫀓', "mǐn"),
+    ('𫀨', "jiǎn"),
+    ('𫀬', "chú"),
+    ('𫀮', "dāng"),
+    ('𫁂', "bà"),
+    ('𫁟', "zhuǎn"),
+    ('𫁡', "lì"),
+    ('𫁲', "wú"),
+    ('𫁳', "wěi"),
+    ('𫁷', "shuāng"),
+    ('𫂃', "mǐn"),
+    ('𫂆', "guì"),
+    ('𫂈', "fèi"),
+    ('𫂖', "ài"),
+    ('𫂙', "sù"),
+    ('𫂿', "sà"),
+    ('𫃗', "jiū"),
+    ('𫃜', "kòu"),
+    ('𫄙', "jiū"),
+    ('𫄚', "hù"),
+    ('𫄛', "jīn"),
+    ('𫄜', "mào"),
+    ('𫄝', "diào"),
+    ('𫄞', "bì"),
+    ('𫄟', "shī"),
+    ('𫄠', "huán"),
+    ('𫄡', "dòng"),
+    ('𫄢', "fú"),
+    ('𫄣', "nǒng"),
+    ('𫄤', "da"),
+    ('𫄥', "lí"),
+    ('𫄦', "jié"),
+    ('𫄧', "yán"),
+    ('𫄨', "chī"),
+    ('𫄩', "fán"),
+    ('𫄪', "sāng"),
+    ('𫄫', "lì"),
+    ('𫄬', "xiè"),
+    ('𫄭', "fù"),
+    ('𫄮', "tīng"),
+    ('𫄰', "bāng"),
+    ('𫄱', "sè"),
+    ('𫄲', "mù"),
+    ('𫄳', "xǐ"),
+    ('𫄴', "lǜ"),
+    ('𫄵', "bēng"),
+    ('𫄶', "qiǎng"),
+    ('𫄷', "yì"),
+    ('𫄸', "xūn"),
+    ('𫄹', "zuī"),
+    ('𫅅', "jǐ"),
+    ('𫅗', "fén"),
+    ('𫅭', "pīn"),
+    ('𫅼', "lián"),
+    ('𫆏', "nǐ"),
+    ('𫇦', "yìng"),
+    ('𫇪', "gāng"),
+    ('𫇭', "wěi"),
+    ('𫇴', "chú"),
+    ('𫇽', "nóng"),
+    ('𫈉', "jiān"),
+    ('𫈎', "qíng"),
+    ('𫈟', "chén"),
+    ('𫈰', "qià"),
+    ('𫈵', "jué"),
+    ('𫉁', "ài"),
+    ('𫉄', "shǔ"),
+    ('𫊪', "cháng"),
+    ('𫊮', "lú"),
+    ('𫊱', "jiǎn"),
+    ('𫊸', "jiǎo"),
+    ('𫊻', "xún"),
+    ('𫋇', "xiāo"),
+    ('𫋌', "tè"),
+    ('𫋐', "gǒng"),
+    ('𫋲', "ōu"),
+    ('𫋷', "zé"),
+    ('𫋹', "ráo"),
+    ('𫋻', "guì"),
+    ('𫌀', "jī"),
+    ('𫌇', "zhě"),
+    ('𫌋', "shù"),
+    ('𫌨', "luó"),
+    ('𫌪', "mì"),
+    ('𫌫', "liàn"),
+    ('𫌭', "wéi"),
+    ('𫌯', "jiǎo"),
+    ('𫍐', "pín"),
+    ('𫍙', "yì"),
+    ('𫍚', "yāo"),
+    ('𫍛', "fēn"),
+    ('𫍜', "qǔ"),
+    ('𫍞', "hū"),
+    ('𫍟', "yí"),
+    ('𫍠', "yuǎn"),
+    ('𫍡', "yí"),
+    ('𫍢', "náo"),
+    ('𫍣', "tóng"),
+    ('𫍤', "jiǎo"),
+    ('𫍥', "tiǎo"),
+    ('𫍦', "náng"),
+    ('𫍧', "chī"),
+    ('𫍨', "zhèn"),
+    ('𫍩', "huà"),
+    ('𫍪', "jì"),
+    ('𫍫', "yàn"),
+    ('𫍬', "wǎng"),
+    ('𫍮', "qū"),
+    ('𫍯', "xián"),
+    ('𫍰', "xǐ"),
+    ('𫍱', "zhuān"),
+    ('𫍲', "xiǎo"),
+    ('𫍳', "zhòng"),
+    ('𫍴', "lóu"),
+    ('𫍵', "áo"),
+    ('𫍶', "chì"),
+    ('𫍷', "kuì"),
+    ('𫍸', "shàn"),
+    ('𫍹', "jiē"),
+    ('𫍺', "shǎ"),
+    ('𫍻', "xī"),
+    ('𫍼', "zhòng"),
+    ('𫍽', "xuān"),
+    ('𫍾', "níng"),
+    ('𫍿', "jiǎn"),
+    ('𫎆', "zōng"),
+    ('𫎌', "jù"),
+    ('𫎦', "lòng"),
+    ('𫎧', "gòu"),
+    ('𫎨', "liàn"),
+    ('𫎩', "chēn"),
+    ('𫎪', "chèn"),
+    ('𫎫', "dàn"),
+    ('𫎬', "gàn"),
+    ('𫎭', "chēng"),
+    ('𫎱', "lì"),
+    ('𫎳', "bì"),
+    ('𫎺', "cān"),
+    ('𫏃', "cháng"),
+    ('𫏆', "bō"),
+    ('𫏋', "juē"),
+    ('𫏌', "lái"),
+    ('𫏐', "zàn"),
+    ('𫏑', "luò"),
+    ('𫏕', "qīng"),
+    ('𫏨', "xiān"),
+    ('𫐄', "yuè"),
+    ('𫐅', "shān"),
+    ('𫐆', "kuài"),
+    ('𫐇', "nà"),
+    ('𫐈', "bá"),
+    ('𫐉', "líng"),
+    ('𫐊', "fàn"),
+    ('𫐋', "zhǐ"),
+    ('𫐌', "píng"),
+    ('𫐍', "tián"),
+    ('𫐎', "yǐ"),
+    ('𫐏', "zhōu"),
+    ('𫐐', "ní"),
+    ('𫐑', "guǎn"),
+    ('𫐒', "hōng"),
+    ('𫐓', "róu"),
+    ('𫐔', "kē"),
+    ('𫐕', "wèi"),
+    ('𫐖', "jiāo"),
+    ('𫐗', "bú"),
+    ('𫐘', "kǎn"),
+    ('𫐙', "léi"),
+    ('𫐷', "lóu"),
+    ('𫑘', "zhuān"),
+    ('𫑡', "méng"),
+    ('𫑷', "yàn"),
+    ('𫒶', "hán"),
+    ('𫓥', "bā"),
+    ('𫓦', "zǐ"),
+    ('𫓧', "fū"),
+    ('𫓨', "guō"),
+    ('𫓩', "cōng"),
+    ('𫓪', "qiān"),
+    ('𫓬', "zā"),
+    ('𫓭', "yāng"),
+    ('𫓮', "liú"),
+    ('𫓯', "jī"),
+    ('𫓰', "xù"),
+    ('𫓱', "qiáo"),
+    ('𫓲', "jūn"),
+    ('𫓴', "móu"),
+    ('𫓵', "shèn"),
+    ('𫓶', "xuān"),
+    ('𫓸', "wàn"),
+    ('𫓹', "jī"),
+    ('𫓺', "lǔ"),
+    ('𫓻', "niè"),
+    ('𫓼', "hé"),
+    ('𫓽', "zòng"),
+    ('𫓾', "yù"),
+    ('𫔀', "liàn"),
+    ('𫔁', "fén"),
+    ('𫔂', "dī"),
+    ('𫔄', "róu"),
+    ('𫔅', "suǒ"),
+    ('𫔆', "bēi"),
+    ('𫔇', "bī"),
+    ('𫔈', "shuò"),
+    ('𫔊', "xiù"),
+    ('𫔋', "kuǎn"),
+    ('𫔌', "sǎn"),
+    ('𫔍', "fán"),
+    ('𫔎', "jué"),
+    ('𫔏', "chán"),
+    ('𫔑', "zhǎn"),
+    ('𫔔', "xī"),
+    ('𫔖', "nóng"),
+    ('𫔭', "kāi"),
+    ('𫔯', "dòu"),
+    ('𫔰', "biàn"),
+    ('𫔱', "lán"),
+    ('𫔲', "xiāo"),
+    ('𫔴', "lìn"),
+    ('𫔵', "zhì"),
+    ('𫔶', "niè"),
+    ('𫕥', "yǔn"),
+    ('𫖃', "huì"),
+    ('𫖅', "lóng"),
+    ('𫖇', "xuē"),
+    ('𫖑', "qín"),
+    ('𫖒', "bì"),
+    ('𫖓', "bǐng"),
+    ('𫖔', "bài"),
+    ('𫖕', "gōu"),
+    ('𫖖', "zhèng"),
+    ('𫖪', "kū"),
+    ('𫖫', "zhěn"),
+    ('𫖬', "zhěn"),
+    ('𫖮', "yǐ"),
+    ('𫖯', "fǔ"),
+    ('𫖰', "duǒ"),
+    ('𫖱', "gěn"),
+    ('𫖲', "hùn"),
+    ('𫖳', "yūn"),
+    ('𫖴', "zé"),
+    ('𫖵', "yuè"),
+    ('𫖶', "qiān"),
+    ('𫖸', "yuàn"),
+    ('𫖹', "cù"),
+    ('𫖺', "fán"),
+    ('𫗇', "yù"),
+    ('𫗈', "bó"),
+    ('𫗉', "bèng"),
+    ('𫗊', "àn"),
+    ('𫗋', "sè"),
+    ('𫗚', "jiǎn"),
+    ('𫗞', "zhān"),
+    ('𫗟', "yuán"),
+    ('𫗠', "zhāng"),
+    ('𫗢', "zuò"),
+    ('𫗣', "bì"),
+    ('𫗤', "shì"),
+    ('𫗥', "yùn"),
+    ('𫗦', "bù"),
+    ('𫗧', "sù"),
+    ('𫗨', "láng"),
+    ('𫗩', "luó"),
+    ('𫗪', "wèi"),
+    ('𫗫', "hú"),
+    ('𫗬', "nuǎn"),
+    ('𫗭', "wèi"),
+    ('𫗮', "huáng"),
+    ('𫗯', "hóu"),
+    ('𫗰', "duī"),
+    ('𫗱', "lián"),
+    ('𫗳', "jiàng"),
+    ('𫗴', "zhān"),
+    ('𫗵', "xiǎng"),
+    ('𫘛', "hàn"),
+    ('𫘜', "wén"),
+    ('𫘝', "jué"),
+    ('𫘞', "tuó"),
+    ('𫘟', "pǒ"),
+    ('𫘠', "zhì"),
+    ('𫘡', "jiōng"),
+    ('𫘣', "hàn"),
+    ('𫘤', "ái"),
+    ('𫘥', "kūn"),
+    ('𫘦', "táo"),
+    ('𫘧', "lù"),
+    ('𫘨', "tí"),
+    ('𫘩', "huáng"),
+    ('𫘪', "yuán"),
+    ('𫘫', "yàn"),
+    ('𫘬', "xí"),
+    ('𫘭', "shuāng"),
+    ('𫘮', "zhé"),
+    ('𫘯', "céng"),
+    ('𫘰', "zhān"),
+    ('𫘱', "xí"),
+    ('𫘽', "kuò"),
+    ('𫚈', "xù"),
+    ('𫚉', "hóng"),
+    ('𫚊', "yáng"),
+    ('𫚋', "zhuān"),
+    ('𫚌', "shā"),
+    ('𫚍', "fén"),
+    ('𫚎', "bǐng"),
+    ('𫚏', "qiáo"),
+    ('𫚐', "yāng"),
+    ('𫚑', "bì"),
+    ('𫚒', "fú"),
+    ('𫚓', "liè"),
+    ('𫚔', "huí"),
+    ('𫚕', "shī"),
+    ('𫚖', "cǐ"),
+    ('𫚗', "gé"),
+    ('𫚙', "pū"),
+    ('𫚚', "zhé"),
+    ('𫚛', "duó"),
+    ('𫚜', "guī"),
+    ('𫚝', "huà"),
+    ('𫚞', "lí"),
+    ('𫚡', "zhǒu"),
+    ('𫚢', "yǎn"),
+    ('𫚣', "biān"),
+    ('𫚤', "zī"),
+    ('𫚥', "xiā"),
+    ('𫚦', "yóng"),
+    ('𫚧', "qiú"),
+    ('𫚨', "bù"),
+    ('𫚪', "yù"),
+    ('𫚫', "sāo"),
+    ('𫚭', "liè"),
+    ('𫛚', "jiān"),
+    ('𫛛', "zhī"),
+    ('𫛜', "fǒu"),
+    ('𫛝', "huān"),
+    ('𫛞', "jué"),
+    ('𫛟', "lóng"),
+    ('𫛠', "zhá"),
+    ('𫛡', "fú"),
+    ('𫛢', "níng"),
+    ('𫛣', "yù"),
+    ('𫛤', "gē"),
+    ('𫛥', "jiá"),
+    ('𫛦', "wū"),
+    ('𫛨', "pí"),
+    ('𫛩', "yàn"),
+    ('𫛪', "rú"),
+    ('𫛫', "yuán"),
+    ('𫛬', "tú"),
+    ('𫛭', "kuáng"),
+    ('𫛮', "biē"),
+    ('𫛯', "fǎng"),
+    ('𫛰', "qí"),
+    ('𫛱', "zhuó"),
+    ('𫛲', "diāo"),
+    ('𫛳', "fú"),
+    ('𫛴', "tí"),
+    ('𫛵', "jué"),
+    ('𫛶', "chì"),
+    ('𫛷', "hú"),
+    ('𫛸', "tí"),
+    ('𫛺', "hóu"),
+    ('𫛻', "duó"),
+    ('𫛼', "kuí"),
+    ('𫛽', "lì"),
+    ('𫛾', "chú"),
+    ('𫜀', "chén"),
+    ('𫜁', "bì"),
+    ('𫜂', "zhāng"),
+    ('𫜃', "yín"),
+    ('𫜄', "zūn"),
+    ('𫜅', "huán"),
+    ('𫜊', "wēn"),
+    ('𫜑', "fēng"),
+    ('𫜒', "tuō"),
+    ('𫜔', "pí"),
+    ('𫜕', "kū"),
+    ('𫜙', "yìng"),
+    ('𫜟', "cù"),
+    ('𫜨', "bà"),
+    ('𫜩', "niè"),
+    ('𫜪', "yǎo"),
+    ('𫜬', "zé"),
+    ('𫜭', "chǔ"),
+    ('𫜮', "yǎn"),
+    ('𫜯', "jiè"),
+    ('𫜰', "yà"),
+    ('𫜲', "lǒng"),
+    ('𫜳', "nán"),
+    ('𫜷', "shòu"),
+    ('𫜸', "huà"),
+    ('𫝈', "hún"),
+    ('𫝋', "shù"),
+    ('𫝦', "bèi"),
+    ('𫝧', "shù"),
+    ('𫝨', "huī"),
+    ('𫝩', "chóu"),
+    ('𫝪', "gòng"),
+    ('𫝫', "lái"),
+    ('𫝬', "kuì"),
+    ('𫝭', "yīng"),
+    ('𫝮', "lǎn"),
+    ('𫝵', "dǎo"),
+    ('𫞅', "xún"),
+    ('𫞗', "mǐn"),
+    ('𫞚', "zī"),
+    ('𫞛', "zhū"),
+    ('𫞝', "jué"),
+    ('𫞠', "líng"),
+    ('𫞡', "róng"),
+    ('𫞢', "zhì"),
+    ('𫞣', "chǎn"),
+    ('𫞥', "bèi"),
+    ('𫞦', "zī"),
+    ('𫞧', "láo"),
+    ('𫞨', "lán"),
+    ('𫞩', "mén"),
+    ('𫞷', "kuài"),
+    ('𫟃', "rèn"),
+    ('𫟄', "hóng"),
+    ('𫟅', "liáng"),
+    ('𫟆', "chóng"),
+    ('𫟑', "líng"),
+    ('𫟕', "jī"),
+    ('𫟞', "zhǐ"),
+    ('𫟟', "pàn"),
+    ('𫟠', "chǎn"),
+    ('𫟡', "cóng"),
+    ('𫟢', "tàn"),
+    ('𫟤', "tuó"),
+    ('𫟥', "kēng"),
+    ('𫟦', "suì"),
+    ('𫟫', "lì"),
+    ('𫟬', "zhì"),
+    ('𫟲', "qiú"),
+    ('𫟳', "sì"),
+    ('𫟴', "fēn"),
+    ('𫟵', "yǔn"),
+    ('𫟶', "shàn"),
+    ('𫟷', "lì"),
+    ('𫟸', "shì"),
+    ('𫟹', "hóng"),
+    ('𫟺', "kāi"),
+    ('𫟻', "zhōu"),
+    ('𫟼', "dá"),
+    ('𫟽', "zú"),
+    ('𫟾', "zú"),
+    ('𫟿', "suǒ"),
+    ('𫠀', "cù"),
+    ('𫠁', "hé"),
+    ('𫠂', "líng"),
+    ('𫠅', "gé"),
+    ('𫠆', "kuǐ"),
+    ('𫠇', "xún"),
+    ('𫠈', "bī"),
+    ('𫠊', "xuán"),
+    ('𫠋', "lù"),
+    ('𫠌', "bàng"),
+    ('𫠏', "mù"),
+    ('𫠐', "hán"),
+    ('𫠑', "sōu"),
+    ('𫠒', "zhāng"),
+    ('𫠜', "ní"),
+    ('𫠥', "lòu"),
+    ('𫡑', "yīn"),
+    ('𫡬', "wō"),
+    ('𫡶', "dàn"),
+    ('𫢒', "lǒng"),
+    ('𫢔', "guì"),
+    ('𫢘', "duì"),
+    ('𫢙', "dòng"),
+    ('𫢪', "liàn"),
+    ('𫢬', "láo"),
+    ('𫢭', "wěi"),
+    ('𫢸', "dàn"),
+    ('𫢹', "chòng"),
+    ('𫢺', "cān"),
+    ('𫣉', "lán"),
+    ('𫣊', "ài"),
+    ('𫣛', "qiān"),
+    ('𫣫', "sú"),
+    ('𫤸', "máo"),
+    ('𫤽', "gǎn"),
+    ('𫥍', "yán"),
+    ('𫥳', "lì"),
+    ('𫥵', "lì"),
+    ('𫥺', "yè"),
+    ('𫥼', "yā"),
+    ('𫦁', "suǒ"),
+    ('𫦅', "xiào"),
+    ('𫦉', "lóu"),
+    ('𫦌', "jué"),
+    ('𫦩', "liǎng"),
+    ('𫦳', "jué"),
+    ('𫧯', "xiè"),
+    ('𫧿', "dú"),
+    ('𫨆', "xiá"),
+    ('𫩕', "hōng"),
+    ('𫩚', "gāng"),
+    ('𫩛', "cóng"),
+    ('𫩤', "yè"),
+    ('𫩥', "huò"),
+    ('𫩩', "chū"),
+    ('𫩫', "yè"),
+    ('𫩯', "hái"),
+    ('𫩺', "jìn"),
+    ('𫪀', "guō"),
+    ('𫪁', "lài"),
+    ('𫪂', "yán"),
+    ('𫪃', "lì"),
+    ('𫪄', "jiān"),
+    ('𫪘', "ōu"),
+    ('𫪚', "zàn"),
+    ('𫪧', "jiāo"),
+    ('𫪪', "zhì"),
+    ('𫪺', "tì"),
+    ('𫪽', "jìn"),
+    ('𫫇', "è"),
+    ('𫫦', "dàn"),
+    ('𫫵', "lǜ"),
+    ('𫫾', "pín"),
+    ('𫬐', "sǎi"),
+    ('𫬙', "gùn"),
+    ('𫬟', "dié"),
+    ('𫭞', "zhuān"),
+    ('𫭟', "ōu"),
+    ('𫭢', "lǔn"),
+    ('𫭨', "bá"),
+    ('𫭪', "qiāo"),
+    ('𫭮', "cè"),
+    ('𫭯', "xūn"),
+    ('𫭲', "yán"),
+    ('𫭼', "láo"),
+    ('𫮃', "shàn"),
+    ('𫮅', "chěn"),
+    ('𫮜', "yín"),
+    ('𫮬', "jiàng"),
+    ('𫯒', "nóng"),
+    ('𫯥', "huò"),
+    ('𫯶', "yūn"),
+    ('𫰂', "duǒ"),
+    ('𫰍', "wéi"),
+    ('𫰛', "xíng"),
+    ('𫰡', "huà"),
+    ('𫰢', "huì"),
+    ('𫰨', "mái"),
+    ('𫰰', "xiān"),
+    ('𫰹', "guī"),
+    ('𫱕', "làn"),
+    ('𫱿', "qiā"),
+    ('𫲗', "zàn"),
+    ('𫲸', "fēng"),
+    ('𫳃', "yā"),
+    ('𫵵', "yáng"),
+    ('𫵷', "lì"),
+    ('𫵸', "kēng"),
+    ('𫶇', "dié"),
+    ('𫶊', "lǎn"),
+    ('𫶕', "róng"),
+    ('𫶲', "kēng"),
+    ('𫷅', "kūn"),
+    ('𫷈', "xū"),
+    ('𫷉', "xiǎn"),
+    ('𫷌', "lán"),
+    ('𫷬', "lái"),
+    ('𫷮', "yìn"),
+    ('𫷷', "xīn"),
+    ('𫷹', "lóu"),
+    ('𫷾', "yǐng"),
+    ('𫸩', "kōu"),
+    ('𫹮', "yǔ"),
+    ('𫹴', "wěi"),
+    ('𫹼', "dōng"),
+    ('𫹽', "shāng"),
+    ('𫺁', "qiè"),
+    ('𫺂', "qiè"),
+    ('𫺆', "huá"),
+    ('𫺊', "qí"),
+    ('𫺌', "gōng"),
+    ('𫺓', "mèn"),
+    ('𫺘', "lào"),
+    ('𫺪', "yǎng"),
+    ('𫺷', "nǎn"),
+    ('𫻁', "xì"),
+    ('𫼛', "diào"),
+    ('𫼝', "chōu"),
+    ('𫼣', "piē"),
+    ('𫼤', "zè"),
+    ('𫼥', "hāi"),
+    ('𫼧', "huá"),
+    ('𫼫', "bì"),
+    ('𫼮', "nǎng"),
+    ('𫼱', "káng"),
+    ('𫼵', "lǔ"),
+    ('𫼶', "suǒ"),
+    ('𫽀', "nà"),
+    ('𫽁', "liǎn"),
+    ('𫽇', "huò"),
+    ('𫽊', "bó"),
+    ('𫽋', "luó"),
+    ('𫽐', "jiān"),
+    ('𫽙', "káng"),
+    ('𫽣', "jiāng"),
+    ('𫽥', "qiān"),
+    ('𫽧', "fèi"),
+    ('𫽮', "dǎng"),
+    ('𫽲', "niǎo"),
+    ('𫽳', "bì"),
+    ('𫾁', "yǎn"),
+    ('𫾃', "xiāo"),
+    ('𫾲', "lǐ"),
+    ('𫿗', "shǔ"),
+    ('𬀥', "huò"),
+    ('𬀩', "wěi"),
+    ('𬀪', "xiàn"),
+    ('𬀮', "duì"),
+    ('𬀱', "kǎi"),
+    ('𬀷', "shāng"),
+    ('𬁵', "ōu"),
+    ('𬁺', "yà"),
+    ('𬁽', "sǔn"),
+    ('𬂀', "rùn"),
+    ('𬂂', "luó"),
+    ('𬂅', "dān"),
+    ('𬂉', "shèng"),
+    ('𬂠', "mó"),
+    ('𬂩', "jiā"),
+    ('𬂮', "shā"),
+    ('𬂰', "nóng"),
+    ('𬃀', "guī"),
+    ('𬃊', "zhì"),
+    ('𬃘', "èr"),
+    ('𬃦', "jiān"),
+    ('𬃫', "xiǎn"),
+    ('𬃮', "fèi"),
+    ('𬃲', "lín"),
+    ('𬃳', "yán"),
+    ('𬄩', "yǐn"),
+    ('𬅢', "xiā"),
+    ('𬅥', "guā"),
+    ('𬅫', "pēn"),
+    ('𬆛', "nǎo"),
+    ('𬆦', "jī"),
+    ('𬆮', "kòu"),
+    ('𬆾', "mào"),
+    ('𬇃', "jiàn"),
+    ('𬇄', "bīn"),
+    ('𬇇', "dié"),
+    ('𬇕', "wàn"),
+    ('𬇘', "tuán"),
+    ('𬇙', "bèi"),
+    ('𬇰', "mèn"),
+    ('𬇹', "guó"),
+    ('𬈁', "shàn"),
+    ('𬈕', "fèi"),
+    ('𬈧', "sè"),
+    ('𬈱', "pō"),
+    ('𬈾', "huá"),
+    ('𬉂', "fèn"),
+    ('𬉇', "shà"),
+    ('𬉋', "wěi"),
+    ('𬉠', "lán"),
+    ('𬉼', "ǒu"),
+    ('𬊂', "chǎo"),
+    ('𬊈', "xún"),
+    ('𬊉', "dá"),
+    ('𬊍', "chóu"),
+    ('𬊎', "gōng"),
+    ('𬊖', "měi"),
+    ('𬊗', "jiān"),
+    ('𬊤', "chǎn"),
+    ('𬊦', "shǎn"),
+    ('𬊵', "tǎng"),
+    ('𬊶', "làn"),
+    ('𬊺', "wēi"),
+    ('𬊾', "rán"),
+    ('𬋃', "liàn"),
+    ('𬋍', "lài"),
+    ('𬌗', "hé"),
+    ('𬌝', "chú"),
+    ('𬌮', "yào"),
+    ('𬌷', "shān"),
+    ('𬍙', "zhǎn"),
+    ('𬍛', "lì"),
+    ('𬍡', "dàng"),
+    ('𬍤', "xún"),
+    ('𬎑', "làn"),
+    ('𬎧', "cóng"),
+    ('𬏜', "mà"),
+    ('𬏟', "jì"),
+    ('𬏦', "fèi"),
+    ('𬏫', "wén"),
+    ('𬏮', "guō"),
+    ('𬏷', "fèn"),
+    ('𬐚', "hǎi"),
+    ('𬐠', "yáng"),
+    ('𬑆', "gùn"),
+    ('𬑇', "chàng"),
+    ('𬑍', "chǒu"),
+    ('𬑏', "yǐng"),
+    ('𬑒', "kōu"),
+    ('𬑓', "yè"),
+    ('𬑔', "zhòng"),
+    ('𬑕', "gùn"),
+    ('𬑗', "jiàn"),
+    ('𬑙', "mài"),
+    ('𬑡', "chǒu"),
+    ('𬑧', "mián"),
+    ('𬒆', "yè"),
+    ('𬒇', "làn"),
+    ('𬒈', "què"),
+    ('𬒍', "yǔn"),
+    ('𬒎', "kēng"),
+    ('𬒔', "gěng"),
+    ('𬒕', "sù"),
+    ('𬒗', "lán"),
+    ('𬒘', "xìn"),
+    ('𬓠', "jǐ"),
+    ('𬓫', "qiū"),
+    ('𬓱', "bèn"),
+    ('𬓸', "shài"),
+    ('𬓼', "tuí"),
+    ('𬔯', "ōu"),
+    ('𬕂', "lǒng"),
+    ('𬕄', "sī"),
+    ('𬕊', "jiǎn"),
+    ('𬕛', "kū"),
+    ('𬕦', "ōu"),
+    ('𬕬', "lǐng"),
+    ('𬖃', "zuǎn"),
+    ('𬖑', "xiàn"),
+    ('𬖖', "chōu"),
+    ('𬖘', "shì"),
+    ('𬖠', "lǒu"),
+    ('𬖮', "xiàn"),
+    ('𬘓', "xún"),
+    ('𬘔', "miè"),
+    ('𬘕', "qiú"),
+    ('𬘖', "jiǎn"),
+    ('𬘘', "dǎn"),
+    ('𬘙', "yuè"),
+    ('𬘚', "wà"),
+    ('𬘛', "qū"),
+    ('𬘜', "zhàn"),
+    ('𬘝', "zhěn"),
+    ('𬘞', "diǎo"),
+    ('𬘟', "xiàn"),
+    ('𬘠', "gǎi"),
+    ('𬘡', "yīn"),
+    ('𬘢', "kuàng"),
+    ('𬘤', "lǚ"),
+    ('𬘥', "quán"),
+    ('𬘦', "pài"),
+    ('𬘧', "qí"),
+    ('𬘨', "zhī"),
+    ('𬘩', "tīng"),
+    ('𬘪', "liǎn"),
+    ('𬘫', "huán"),
+    ('𬘬', "qiàn"),
+    ('𬘭', "chēn"),
+    ('𬘮', "mì"),
+    ('𬘯', "zhǔn"),
+    ('𬘰', "ruǎn"),
+    ('𬘱', "yāo"),
+    ('𬘲', "zhǎ"),
+    ('𬘳', "xū"),
+    ('𬘵', "gēng"),
+    ('𬘶', "qiū"),
+    ('𬘷', "cī"),
+    ('𬘸', "chì"),
+    ('𬘺', "tāo"),
+    ('𬘻', "xiá"),
+    ('𬘼', "suì"),
+    ('𬘽', "zhì"),
+    ('𬘾', "shuǎng"),
+    ('𬙁', "yǎn"),
+    ('𬙂', "yǎn"),
+    ('𬙃', "qiǎn"),
+    ('𬙅', "zhǔ"),
+    ('𬙆', "fān"),
+    ('𬙇', "rán"),
+    ('𬙈', "lín"),
+    ('𬙉', "dàn"),
+    ('𬙊', "mò"),
+    ('𬙋', "xiāng"),
+    ('𬙎', "lú"),
+    ('𬙝', "bì"),
+    ('𬙭', "wěi"),
+    ('𬚄', "sù"),
+    ('𬛸', "niē"),
+    ('𬛹', "dàng"),
+    ('𬛼', "yù"),
+    ('𬜔', "dá"),
+    ('𬜥', "lán"),
+    ('𬜧', "fà"),
+    ('𬜨', "huì"),
+    ('𬜬', "màn"),
+    ('𬜯', "liǎng"),
+    ('𬜸', "huái"),
+    ('𬜾', "xián"),
+    ('𬜿', "guó"),
+    ('𬝁', "yú"),
+    ('𬝃', "láo"),
+    ('𬝊', "sī"),
+    ('𬝋', "jié"),
+    ('𬝠', "zéi"),
+    ('𬝯', "pín"),
+    ('𬝴', "rán"),
+    ('𬞋', "jiàn"),
+    ('𬞕', "lán"),
+    ('𬞘', "tuī"),
+    ('𬞟', "pín"),
+    ('𬞣', "sēn"),
+    ('𬞫', "hàn"),
+    ('𬟁', "yì"),
+    ('𬟪', "xì"),
+    ('𬟺', "cóng"),
+    ('𬟽', "dōng"),
+    ('𬠃', "bì"),
+    ('𬠅', "dāng"),
+    ('𬠈', "gǒng"),
+    ('𬠐', "wǎng"),
+    ('𬠠', "zéi"),
+    ('𬠷', "tuó"),
+    ('𬡇', "niǎo"),
+    ('𬡍', "diǎo"),
+    ('𬡎', "zhòu"),
+    ('𬡒', "jiá"),
+    ('𬡓', "diē"),
+    ('𬡔', "shī"),
+    ('𬡕', "shài"),
+    ('𬡠', "luò"),
+    ('𬡷', "zàn"),
+    ('𬡻', "chàn"),
+    ('𬢇', "chà"),
+    ('𬢈', "dòng"),
+    ('𬢉', "cī"),
+    ('𬢊', "sì"),
+    ('𬢋', "tiào"),
+    ('𬢌', "zhì"),
+    ('𬢎', "dǔ"),
+    ('𬢏', "dān"),
+    ('𬢐', "qì"),
+    ('𬢑', "yǐng"),
+    ('𬢒', "míng"),
+    ('𬢔', "dēng"),
+    ('𬢪', "zhōu"),
+    ('𬢯', "yīng"),
+    ('𬢳', "càn"),
+    ('𬣀', "wèi"),
+    ('𬣙', "xū"),
+    ('𬣚', "kòu"),
+    ('𬣛', "zhì"),
+    ('𬣜', "hào"),
+    ('𬣝', "jùn"),
+    ('𬣞', "zhǔ"),
+    ('𬣟', "tǒu"),
+    ('𬣠', "gàn"),
+    ('𬣡', "jiàn"),
+    ('𬣢', "tuō"),
+    ('𬣣', "zhù"),
+    ('𬣤', "jiǎn"),
+    ('𬣥', "tāo"),
+    ('𬣦', "yào"),
+    ('𬣧', "fèi"),
+    ('𬣨', "yǎ"),
+    ('𬣩', "wēi"),
+    ('𬣪', "huì"),
+    ('𬣬', "huì"),
+    ('𬣭', "dǎng"),
+    ('𬣮', "mìng"),
+    ('𬣯', "rén"),
+    ('𬣰', "huì"),
+    ('𬣱', "zhòu"),
+    ('𬣲', "pǔ"),
+    ('𬣳', "hěn"),
+    ('𬣶', "zhà"),
+    ('𬣷', "cù"),
+    ('𬣸', "hàn"),
+    ('𬣹', "cù"),
+    ('𬣻', "tǐng"),
+    ('𬣼', "lǎng"),
+    ('𬣽', "lián"),
+    ('𬣾', "zé"),
+    ('𬣿', "láo"),
+    ('𬤀', "háo"),
+    ('𬤁', "táo"),
+    ('𬤂', "shán"),
+    ('𬤄', "càn"),
+    ('𬤅', "jí"),
+    ('𬤆', "gùn"),
+    ('𬤇', "yīn"),
+    ('𬤉', "huì"),
+    ('𬤊', "shì"),
+    ('𬤋', "còng"),
+    ('𬤌', "zhǎ"),
+    ('𬤍', "huáng"),
+    ('𬤎', "xuān"),
+    ('𬤏', "duó"),
+    ('𬤐', "gē"),
+    ('𬤑', "gé"),
+    ('𬤒', "hé"),
+    ('𬤓', "chī"),
+    ('𬤕', "tā"),
+    ('𬤖', "gùn"),
+    ('𬤘', "chī"),
+    ('𬤙', "hū"),
+    ('𬤚', "yīng"),
+    ('𬤛', "chǎn"),
+    ('𬤜', "zhā"),
+    ('𬤝', "huì"),
+    ('𬤞', "yì"),
+    ('𬤟', "liáo"),
+    ('𬤠', "yán"),
+    ('𬤡', "ào"),
+    ('𬤢', "zǔn"),
+    ('𬤣', "duì"),
+    ('𬤤', "zēng"),
+    ('𬤥', "zhuàn"),
+    ('𬤦', "yí"),
+    ('𬤨', "zào"),
+    ('𬤩', "ài"),
+    ('𬤪', "tà"),
+    ('𬤫', "háo"),
+    ('𬤬', "tuǎn"),
+    ('𬤭', "huì"),
+    ('𬤮', "zàn"),
+    ('𬤯', "jiǎn"),
+    ('𬤰', "huān"),
+    ('𬤱', "tuí"),
+    ('𬥄', "zhé"),
+    ('𬥈', "mào"),
+    ('𬥳', "càng"),
+    ('𬥴', "hān"),
+    ('𬥵', "yì"),
+    ('𬥶', "bì"),
+    ('𬥷', "zhèng"),
+    ('𬥸', "chuàn"),
+    ('𬥺', "yàn"),
+    ('𬥻', "ruǎn"),
+    ('𬥼', "duǎn"),
+    ('𬥽', "hòu"),
+    ('𬥾', "làn"),
+    ('𬥿', "zhàn"),
+    ('𬦅', "tāng"),
+    ('𬦆', "tuán"),
+    ('𬦣', "lì"),
+    ('𬦥', "bèi"),
+    ('𬦧', "lún"),
+    ('𬦩', "zōu"),
+    ('𬦫', "duò"),
+    ('𬦯', "xié"),
+    ('𬦴', "bìng"),
+    ('𬦵', "zhāng"),
+    ('𬦻', "guàn"),
+    ('𬧀', "qiāng"),
+    ('𬧃', "téng"),
+    ('𬧑', "niǎn"),
+    ('𬧚', "diān"),
+    ('𬧢', "lǒng"),
+    ('𬧤', "lào"),
+    ('𬧩', "guó"),
+    ('𬨁', "máo"),
+    ('𬨂', "qí"),
+    ('𬨄', "yǎng"),
+    ('𬨅', "niǎn"),
+    ('𬨆', "gǒng"),
+    ('𬨇', "kǎi"),
+    ('𬨈', "wǎn"),
+    ('𬨉', "tián"),
+    ('𬨋', "zàng"),
+    ('𬨌', "pì"),
+    ('𬨍', "gé"),
+    ('𬨎', "yóu"),
+    ('𬨏', "xǔ"),
+    ('𬨐', "huì"),
+    ('𬨑', "sǎng"),
+    ('𬨒', "guāng"),
+    ('𬨓', "cháo"),
+    ('𬨔', "bèi"),
+    ('𬩎', "huò"),
+    ('𬩽', "xún"),
+    ('𬩾', "lái"),
+    ('𬪍', "mào"),
+    ('𬪧', "yù"),
+    ('𬪨', "zhǎn"),
+    ('𬪩', "nóng"),
+    ('𬪺', "guàng"),
+    ('𬬇', "zhē"),
+    ('𬬧', "hàn"),
+    ('𬬨', "huá"),
+    ('𬬩', "yì"),
+    ('𬬪', "kòu"),
+    ('𬬫', "pī"),
+    ('𬬬', "wéi"),
+    ('𬬭', "lún"),
+    ('𬬮', "chǎng"),
+    ('𬬯', "rén"),
+    ('𬬰', "qiāng"),
+    ('𬬱', "jīn"),
+    ('𬬲', "pì"),
+    ('𬬳', "qí"),
+    ('𬬵', "chén"),
+    ('𬬷', "shí"),
+    ('𬬸', "shù"),
+    ('𬬹', "shén"),
+    ('𬬺', "chú"),
+    ('𬬻', "lú"),
+    ('𬬽', "zuó"),
+    ('𬬾', "níng"),
+    ('𬬿', "zhāo"),
+    ('𬭀', "sì"),
+    ('𬭁', "mǔ"),
+    ('𬭂', "hóng"),
+    ('𬭃', "pī"),
+    ('𬭅', "xiàng"),
+    ('𬭆', "duǒ"),
+    ('𬭇', "guó"),
+    ('𬭈', "chá"),
+    ('𬭉', "jī"),
+    ('𬭊', "dù"),
+    ('𬭌', "huá"),
+    ('𬭍', "hàn"),
+    ('𬭎', "hóng"),
+    ('𬭏', "yáng"),
+    ('𬭑', "zì"),
+    ('𬭓', "tiǎn"),
+    ('𬭔', "chuò"),
+    ('𬭕', "táo"),
+    ('𬭖', "péng"),
+    ('𬭗', "nèi"),
+    ('𬭚', "chún"),
+    ('𬭛', "bō"),
+    ('𬭜', "lèi"),
+    ('𬭝', "sǎn"),
+    ('𬭞', "dā"),
+    ('𬭟', "còu"),
+    ('𬭠', "chǎ"),
+    ('𬭡', "zhāo"),
+    ('𬭢', "kuì"),
+    ('𬭣', "xiàn"),
+    ('𬭤', "hóu"),
+    ('𬭥', "cōng"),
+    ('𬭦', "nòu"),
+    ('𬭨', "chuí"),
+    ('𬭩', "wēng"),
+    ('𬭪', "xiá"),
+    ('𬭫', "zhǎn"),
+    ('𬭬', "huì"),
+    ('𬭭', "qī"),
+    ('𬭯', "piě"),
+    ('𬭰', "yí"),
+    ('𬭲', "suǒ"),
+    ('𬭳', "xǐ"),
+    ('𬭴', "jiē"),
+    ('𬭶', "hēi"),
+    ('𬭷', "chēng"),
+    ('𬭸', "lín"),
+    ('𬭺', "biāo"),
+    ('𬭻', "jiǎo"),
+    ('𬭼', "suì"),
+    ('𬭽', "bì"),
+    ('𬭿', "jī"),
+    ('𬮁', "bó"),
+    ('𬮃', "zuǎn"),
+    ('𬮄', "jiào"),
+    ('𬮘', "huò"),
+    ('𬮟', "lìn"),
+    ('𬮠', "xiǎ"),
+    ('𬮡', "jiān"),
+    ('𬮢', "hòng"),
+    ('𬮣', "kuāng"),
+    ('𬮤', "hé"),
+    ('𬮥', "chù"),
+    ('𬮨', "huō"),
+    ('𬮩', "qì"),
+    ('𬮪', "shà"),
+    ('𬮭', "kuī"),
+    ('𬮯', "què"),
+    ('𬮰', "ě"),
+    ('𬮱', "yīn"),
+    ('𬮲', "yǎo"),
+    ('𬮳', "bǎn"),
+    ('𬮴', "àn"),
+    ('𬮵', "xiàn"),
+    ('𬮹', "dēng"),
+    ('𬮺', "mà"),
+    ('𬮻', "wù"),
+    ('𬮿', "gài"),
+    ('𬯀', "jī"),
+    ('𬯅', "xiá"),
+    ('𬯎', "tuí"),
+    ('𬰃', "mù"),
+    ('𬰡', "luǒ"),
+    ('𬰣', "jiǎn"),
+    ('𬰤', "zhòu"),
+    ('𬰥', "liǎng"),
+    ('𬰱', "hàn"),
+    ('𬰳', "diē"),
+    ('𬰶', "suì"),
+    ('𬰷', "chān"),
+    ('𬰸', "yù"),
+    ('𬰺', "yè"),
+    ('𬱓', "kuí"),
+    ('𬱔', "yòu"),
+    ('𬱕', "mò"),
+    ('𬱖', "dí"),
+    ('𬱗', "dān"),
+    ('𬱙', "pàn"),
+    ('𬱛', "zhuàn"),
+    ('𬱜', "lèi"),
+    ('𬱝', "gěn"),
+    ('𬱟', "wěi"),
+    ('𬱠', "kuò"),
+    ('𬱢', "wèn"),
+    ('𬱣', "chěn"),
+    ('𬱥', "hōng"),
+    ('𬱦', "qī"),
+    ('𬱪', "yí"),
+    ('𬱫', "dìng"),
+    ('𬱬', "sǎn"),
+    ('𬱭', "pò"),
+    ('𬱮', "āo"),
+    ('𬱯', "shǒu"),
+    ('𬱰', "pī"),
+    ('𬱱', "zhǎn"),
+    ('𬱳', "yù"),
+    ('𬱵', "wěi"),
+    ('𬱷', "xuè"),
+    ('𬱸', "xuè"),
+    ('𬱼', "kǎi"),
+    ('𬱽', "xuàn"),
+    ('𬱿', "guó"),
+    ('𬲀', "hū"),
+    ('𬲅', "liáo"),
+    ('𬲆', "yù"),
+    ('𬲕', "zǎn"),
+    ('𬲜', "chēng"),
+    ('𬲦', "sì"),
+    ('𬲪', "chuáng"),
+    ('𬲫', "nián"),
+    ('𬲭', "jiě"),
+    ('𬲮', "tí"),
+    ('𬲯', "gōu"),
+    ('𬲰', "shǎng"),
+    ('𬲲', "níng"),
+    ('𬲳', "yì"),
+    ('𬲴', "mǎn"),
+    ('𬲶', "cí"),
+    ('𬲷', "èn"),
+    ('𬲸', "guò"),
+    ('𬲹', "māng"),
+    ('𬲺', "bǎo"),
+    ('𬲻', "tí"),
+    ('𬲼', "yè"),
+    ('𬲾', "hú"),
+    ('𬳀', "yē"),
+    ('𬳁', "yáo"),
+    ('𬳂', "zhuì"),
+    ('𬳃', "pì"),
+    ('𬳅', "suǐ"),
+    ('𬳆', "jiān"),
+    ('𬳉', "dā"),
+    ('𬳊', "táo"),
+    ('𬳋', "xī"),
+    ('𬳌', "qiǔ"),
+    ('𬳍', "táng"),
+    ('𬳐', "chóng"),
+    ('𬳑', "yǐng"),
+    ('𬳒', "dèng"),
+    ('𬳓', "yōng"),
+    ('𬳔', "mó"),
+    ('𬳟', "fén"),
+    ('𬳲', "chǎn"),
+    ('𬳳', "fān"),
+    ('𬳴', "péi"),
+    ('𬳵', "pī"),
+    ('𬳶', "jiōng"),
+    ('𬳷', "guā"),
+    ('𬳸', "wò"),
+    ('𬳻', "kuāng"),
+    ('𬳽', "shēn"),
+    ('𬳾', "chí"),
+    ('𬳿', "tú"),
+    ('𬴀', "láng"),
+    ('𬴁', "àn"),
+    ('𬴂', "fēi"),
+    ('𬴃', "huō"),
+    ('𬴅', "péng"),
+    ('𬴆', "qí"),
+    ('𬴇', "chì"),
+    ('𬴊', "lín"),
+    ('𬴋', "tiě"),
+    ('𬴌', "méng"),
+    ('𬴍', "biāo"),
+    ('𬴎', "tuō"),
+    ('𬴏', "jiǎn"),
+    ('𬴐', "huān"),
+    ('𬴨', "nào"),
+    ('𬴩', "náng"),
+    ('𬶀', "jié"),
+    ('𬶁', "rén"),
+    ('𬶂', "dù"),
+    ('𬶄', "diào"),
+    ('𬶆', "bàng"),
+    ('𬶇', "jiè"),
+    ('𬶉', "wǔ"),
+    ('𬶊', "gèng"),
+    ('𬶋', "jū"),
+    ('𬶌', "dai"),
+    ('𬶍', "tuó"),
+    ('𬶎', "jié"),
+    ('𬶏', "wéi"),
+    ('𬶐', "zhào"),
+    ('𬶓', "tíng"),
+    ('𬶔', "kào"),
+    ('𬶕', "tí"),
+    ('𬶗', "láo"),
+    ('𬶛', "shàn"),
+    ('𬶞', "hú"),
+    ('𬶟', "là"),
+    ('𬶠', "liàn"),
+    ('𬶢', "xīng"),
+    ('𬶣', "zhǎ"),
+    ('𬶤', "tí"),
+    ('𬶦', "yóu"),
+    ('𬶧', "róu"),
+    ('𬶨', "jì"),
+    ('𬶪', "nì"),
+    ('𬶫', "huáng"),
+    ('𬶬', "qū"),
+    ('𬶭', "jì"),
+    ('𬶮', "xǐ"),
+    ('𬶯', "guǒ"),
+    ('𬶱', "jǐng"),
+    ('𬶲', "xiàng"),
+    ('𬶴', "pū"),
+    ('𬶵', "guān"),
+    ('𬶺', "guàn"),
+    ('𬶻', "bà"),
+    ('𬷕', "bǔ"),
+    ('𬷼', "yì"),
+    ('𬷽', "xiāo"),
+    ('𬷾', "hóng"),
+    ('𬸀', "wén"),
+    ('𬸁', "wā"),
+    ('𬸂', "gē"),
+    ('𬸅', "chú"),
+    ('𬸆', "shēng"),
+    ('𬸈', "chī"),
+    ('𬸉', "qióng"),
+    ('𬸊', "rén"),
+    ('𬸌', "shā"),
+    ('𬸍', "chóu"),
+    ('𬸎', "lí"),
+    ('𬸏', "láng"),
+    ('𬸐', "chuáng"),
+    ('𬸑', "yuè"),
+    ('𬸒', "qí"),
+    ('𬸕', "yīng"),
+    ('𬸖', "yán"),
+    ('𬸘', "yǎn"),
+    ('𬸙', "miáo"),
+    ('𬸚', "yuè"),
+    ('𬸛', "huáng"),
+    ('𬸜', "piān"),
+    ('𬸝', "ān"),
+    ('𬸞', "lǘ"),
+    ('𬸠', "gē"),
+    ('𬸡', "lán"),
+    ('𬸢', "gāo"),
+    ('𬸣', "xiān"),
+    ('𬸤', "piǎo"),
+    ('𬸥', "chōng"),
+    ('𬸦', "zhuó"),
+    ('𬸧', "yàn"),
+    ('𬸨', "qī"),
+    ('𬸩', "ǎo"),
+    ('𬸪', "fán"),
+    ('𬸬', "kòu"),
+    ('𬸭', "yà"),
+    ('𬸮', "kuí"),
+    ('𬸯', "pì"),
+    ('𬸰', "hè"),
+    ('𬸱', "qú"),
+    ('𬸵', "zhān"),
+    ('𬸶', "chāng"),
+    ('𬸷', "còu"),
+    ('𬸸', "biàn"),
+    ('𬸹', "gàn"),
+    ('𬸾', "qí"),
+    ('𬹅', "cái"),
+    ('𬹇', "bó"),
+    ('𬹉', "hún"),
+    ('𬹋', "hún"),
+    ('𬹌', "nié"),
+    ('𬹍', "mò"),
+    ('𬹎', "shàn"),
+    ('𬹕', "zhěn"),
+    ('𬹖', "nóng"),
+    ('𬹗', "lái"),
+    ('𬹘', "téng"),
+    ('𬹣', "zhū"),
+    ('𬹺', "yá"),
+    ('𬹻', "nà"),
+    ('𬹼', "xiè"),
+    ('𬹽', "háng"),
+    ('𬺀', "zhā"),
+    ('𬺁', "zhì"),
+    ('𬺂', "niè"),
+    ('𬺃', "ái"),
+    ('𬺄', "kuò"),
+    ('𬺅', "chǎn"),
+    ('𬺇', "cuó"),
+    ('𬺈', "yǐ"),
+    ('𬺉', "zé"),
+    ('𬺊', "yǔn"),
+    ('𬺋', "zú"),
+    ('𬺌', "yá"),
+    ('𬺍', "jiān"),
+    ('𬺎', "cī"),
+    ('𬺏', "bó"),
+    ('𬺒', "yǐn"),
+    ('𬺓', "chǔ"),
+    ('𬺔', "jìn"),
+    ('𬺕', "chà"),
+    ('𬺖', "jué"),
+    ('𬺜', "lóng"),
+    ('𬺟', "yào"),
+    ('𭀖', "lí"),
+    ('𭄛', "jiǎn"),
+    ('𭇜', "pō"),
+    ('𭇯', "liú"),
+    ('𭈈', "mǔ"),
+    ('𭈉', "yìng"),
+    ('𭈮', "huán"),
+    ('𭉨', "zuǐ"),
+    ('𭊸', "là"),
+    ('𭎂', "zōng"),
+    ('𭎜', "dǎo"),
+    ('𭏦', "ài"),
+    ('𭏸', "wěi"),
+    ('𭕆', "suǒ"),
+    ('𭗡', "kē"),
+    ('𭘓', "hū"),
+    ('𭚦', "guō"),
+    ('𭝋', "líng"),
+    ('𭞄', "ài"),
+    ('𭠙', "pū"),
+    ('𭡜', "shěn"),
+    ('𭣇', "diān"),
+    ('𭣧', "yì"),
+    ('𭤎', "lí"),
+    ('𭤰', "yú"),
+    ('𭧋', "tǎng"),
+    ('𭩚', "yǐ"),
+    ('𭩛', "mēn"),
+    ('𭩰', "fá"),
+    ('𭪆', "zhuā"),
+    ('𭫀', "kuì"),
+    ('𭫙', "dí"),
+    ('𭭈', "yà"),
+    ('𭰎', "dāng"),
+    ('𭰒', "shā"),
+    ('𭱊', "hòng"),
+    ('𭲫', "zhú"),
+    ('𭴊', "wú"),
+    ('𭹜', "luó"),
+    ('𭻔', "lái"),
+    ('𮀤', "láo"),
+    ('𮀲', "gèng"),
+    ('𮆏', "lán"),
+    ('𮇤', "líng"),
+    ('𮉠', "qiú"),
+    ('𮉡', "lú"),
+    ('𮉢', "zhì"),
+    ('𮉣', "zuó"),
+    ('𮉤', "guà"),
+    ('𮉧', "liǎng"),
+    ('𮉨', "guā"),
+    ('𮉪', "zōu"),
+    ('𮉫', "ruí"),
+    ('𮉬', "cuì"),
+    ('𮉮', "suì"),
+    ('𮉯', "cài"),
+    ('𮏺', "dú"),
+    ('𮐚', "fán"),
+    ('𮐨', "yīng"),
+    ('𮔂', "jié"),
+    ('𮔅', "fù"),
+    ('𮔊', "liǎng"),
+    ('𮖁', "liǎng"),
+    ('𮖱', "xié"),
+    ('𮙊', "yǐn"),
+    ('𮙋', "dú"),
+    ('𮛗', "táng"),
+    ('𮜶', "dào"),
+    ('𮝴', "gū"),
+    ('𮝵', "ér"),
+    ('𮝷', "fén"),
+    ('𮝸', "chūn"),
+    ('𮝹', "huán"),
+    ('𮝺', "gé"),
+    ('𮠞', "qiāng"),
+    ('𮠳', "chěn"),
+    ('𮡈', "kē"),
+    ('𮣲', "gāng"),
+    ('𮣳', "hóng"),
+    ('𮣴', "chán"),
+    ('𮣵', "zhuì"),
+    ('𮣶', "lǜ"),
+    ('𮤫', "mén"),
+    ('𮤬', "chù"),
+    ('𮤯', "ruì"),
+    ('𮤲', "bì"),
+    ('𮤸', "é"),
+    ('𮧴', "chàng"),
+    ('𮧵', "wěi"),
+    ('𮨴', "fēng"),
+    ('𮨵', "liù"),
+    ('𮩛', "bì"),
+    ('𮩜', "hài"),
+    ('𮩝', "ài"),
+    ('𮩞', "yì"),
+    ('𮪡', "máng"),
+    ('𮪢', "hài"),
+    ('𮪣', "zōng"),
+    ('𮪤', "cǎo"),
+    ('𮪥', "dūn"),
+    ('𮫂', "níng"),
+    ('𮬛', "xù"),
+    ('𮬜', "yì"),
+    ('𮬝', "guì"),
+    ('𮬞', "cān"),
+    ('𮬟', "huò"),
+    ('𮬠', "lù"),
+    ('𮬡', "huá"),
+    ('𮬢', "wēng"),
+    ('𮬣', "xián"),
+    ('𮬤', "zhēn"),
+    ('𮭡', "jiè"),
+    ('𮭢', "yāo"),
+    ('𮭤', "miè"),
+    ('𮭥', "gōng"),
+    ('𮭦', "chén"),
+    ('𮭨', "yàn"),
+    ('𮭪', "shuāng"),
+    ('𮭰', "gǎng"),
+    ('𮮇', "móu"),
+    ('𮯙', "kè"),
+    ('丽', "lì"),
+    ('丸', "wán"),
+    ('乁', "yí"),
+    ('你', "nǐ"),
+    ('侮', "wǔ"),
+    ('侻', "tuì"),
+    ('倂', "bìng"),
+    ('偺', "zá"),
+    ('備', "bèi"),
+    ('僧', "sēng"),
+    ('像', "xiàng"),
+    ('㒞', "jùn"),
+    ('𠘺', "suò"),
+    ('免', "miǎn"),
+    ('兔', "tù"),
+    ('兤', "huǎng"),
+    ('具', "jù"),
+    ('內', "nèi"),
+    ('再', "zài"),
+    ('冗', "rǒng"),
+    ('冤', "yuān"),
+    ('仌', "bīng"),
+    ('冬', "dōng"),
+    ('况', "kuàng"),
+    ('𩇟', "qìng"),
+    ('凵', "qiǎn"),
+    ('刃', "rèn"),
+    ('㓟', "pí"),
+    ('刻', "kè"),
+    ('剆', "luǒ"),
+    ('割', "gē"),
+    ('剷', "chǎn"),
+    ('㔕', "jí"),
+    ('勇', "yǒng"),
+    ('勉', "miǎn"),
+    ('勤', "qín"),
+    ('勺', "sháo"),
+    ('包', "bāo"),
+    ('匆', "cōng"),
+    ('北', "běi"),
+    ('卉', "huì"),
+    ('卑', "bēi"),
+    ('博', "bó"),
+    ('即', "jí"),
+    ('卽', "jí"),
+    ('卿', "qīng"),
+    ('卿', "qīng"),
+    ('卿', "qīng"),
+    ('灰', "huī"),
+    ('及', "jí"),
+    ('叟', "sǒu"),
+    ('叫', "jiào"),
+    ('叱', "chì"),
+    ('吆', "yāo"),
+    ('咞', "xiàn"),
+    ('吸', "xī"),
+    ('呈', "chéng"),
+    ('周', "zhōu"),
+    ('咢', "è"),
+    ('哶', "miē"),
+    ('唐', "táng"),
+    ('啓', "qǐ"),
+    ('啣', "xián"),
+    ('善', "shàn"),
+    ('善', "shàn"),
+    ('喙', "huì"),
+    ('喫', "chī"),
+    ('喳', "zhā"),
+    ('嗂', "yáo"),
+    ('圖', "tú"),
+    ('嘆', "tàn"),
+    ('圗', "tú"),
+    ('噑', "háo"),
+    ('噴', "pēn"),
+    ('切', "qiè"),
+    ('壮', "zhuàng"),
+    ('城', "chéng"),
+    ('埴', "zhí"),
+    ('堍', "tù"),
+    ('型', "xíng"),
+    ('堲', "cí"),
+    ('報', "bào"),
+    ('墬', "dì"),
+    ('売', "mài"),
+    ('壷', "hú"),
+    ('夆', "féng"),
+    ('多', "duō"),
+    ('夢', "mèng"),
+    ('奢', "shē"),
+    ('𡚨', "chì"),
+    ('姬', "jī"),
+    ('娛', "yú"),
+    ('娧', "tuì"),
+    ('姘', "pīn"),
+    ('婦', "fù"),
+    ('㛮', "sǎo"),
+    ('㛼', "chā"),
+    ('嬈', "ráo"),
+    ('嬾', "lǎn"),
+    ('嬾', "lǎn"),
+    ('寃', "yuān"),
+    ('寘', "zhì"),
+    ('寧', "níng"),
+    ('寳', "bǎo"),
+    ('𡬘', "lào"),
+    ('寿', "shòu"),
+    ('将', "jiāng"),
+    ('当', "dāng"),
+    ('尢', "yóu"),
+    ('㞁', "yào"),
+    ('屠', "tú"),
+    ('屮', "chè"),
+    ('峀', "xiù"),
+    ('岍', "qiān"),
+    ('𡷤', "wú"),
+    ('嵃', "yǎn"),
+    ('嵮', "diān"),
+    ('嵫', "zī"),
+    ('嵼', "chǎn"),
+    ('巡', "xún"),
+    ('巢', "cháo"),
+    ('㠯', "yǐ"),
+    ('巽', "xùn"),
+    ('帨', "shuì"),
+    ('帽', "mào"),
+    ('幩', "fén"),
+    ('㡢', "màn"),
+    ('㡼', "yì"),
+    ('庰', "bìng"),
+    ('庳', "bì"),
+    ('庶', "shù"),
+    ('廊', "láng"),
+    ('𪎒', "shuò"),
+    ('廾', "gǒng"),
+    ('舁', "yú"),
+    ('弢', "tāo"),
+    ('弢', "tāo"),
+    ('㣇', "yì"),
+    ('形', "xíng"),
+    ('彫', "diāo"),
+    ('㣣', "shàn"),
+    ('徚', "dōng"),
+    ('忍', "rěn"),
+    ('志', "zhì"),
+    ('忹', "kuáng"),
+    ('悁', "yuān"),
+    ('㤺', "huǎng"),
+    ('㤜', "jué"),
+    ('悔', "huǐ"),
+    ('𢛔', "dōng"),
+    ('惇', "dūn"),
+    ('慈', "cí"),
+    ('慌', "huāng"),
+    ('慎', "shèn"),
+    ('慌', "huāng"),
+    ('慺', "lóu"),
+    ('憎', "zēng"),
+    ('憲', "xiàn"),
+    ('憤', "fèn"),
+    ('憯', "cǎn"),
+    ('懞', "méng"),
+    ('懲', "chéng"),
+    ('懶', "lǎn"),
+    ('成', "chéng"),
+    ('戛', "jiá"),
+    ('扝', "kū"),
+    ('抱', "bào"),
+    ('拔', "bá"),
+    ('捐', "juān"),
+    ('挽', "wǎn"),
+    ('拼', "pīn"),
+    ('捨', "shě"),
+    ('掃', "sǎo"),
+    ('揤', "jí"),
+    ('搢', "jìn"),
+    ('揅', "yán"),
+    ('掩', "yǎn"),
+    ('㨮', "dōu"),
+    ('摩', "mó"),
+    ('摾', "jiàng"),
+    ('撝', "huī"),
+    ('摷', "jiǎo"),
+    ('敏', "mǐn"),
+    ('敬', "jìng"),
+    ('旣', "jì"),
+    ('書', "shū"),
+    ('晉', "jìn"),
+    ('㬙', "wěi"),
+    ('暑', "shǔ"),
+    ('㬈', "wēn"),
+    ('㫤', "chǎng"),
+    ('冒', "mào"),
+    ('冕', "miǎn"),
+    ('最', "zuì"),
+    ('暜', "pǔ"),
+    ('肭', "nà"),
+    ('䏙', "tǎn"),
+    ('朗', "lǎng"),
+    ('望', "wàng"),
+    ('朡', "zōng"),
+    ('杞', "qǐ"),
+    ('杓', "biāo"),
+    ('㭉', "huá"),
+    ('柺', "guǎi"),
+    ('枅', "jī"),
+    ('桒', "sāng"),
+    ('梅', "méi"),
+    ('梎', "āo"),
+    ('栟', "bēn"),
+    ('椔', "zī"),
+    ('㮝', "hé"),
+    ('楂', "zhā"),
+    ('榣', "yáo"),
+    ('槪', "gài"),
+    ('檨', "shē"),
+    ('櫛', "zhì"),
+    ('㰘', "yí"),
+    ('次', "cì"),
+    ('歔', "xū"),
+    ('㱎', "kūn"),
+    ('歲', "suì"),
+    ('殟', "wēn"),
+    ('殺', "shā"),
+    ('殻', "qiào"),
+    ('汎', "fàn"),
+    ('沿', "yán"),
+    ('泍', "bēn"),
+    ('汧', "qiān"),
+    ('洖', "wú"),
+    ('派', "pài"),
+    ('海', "hǎi"),
+    ('流', "liú"),
+    ('浩', "hào"),
+    ('浸', "jìn"),
+    ('涅', "niè"),
+    ('𣴞', "bèn"),
+    ('洴', "píng"),
+    ('港', "gǎng"),
+    ('湮', "yān"),
+    ('㴳', "lǒng"),
+    ('滋', "zī"),
+    ('滇', "diān"),
+    ('𣻑', "shā"),
+    ('淹', "yān"),
+    ('潮', "cháo"),
+    ('濆', "fén"),
+    ('瀹', "yuè"),
+    ('瀞', "jìng"),
+    ('瀛', "yíng"),
+    ('㶖', "shù"),
+    ('灊', "qián"),
+    ('災', "zāi"),
+    ('灷', "zhuàn"),
+    ('炭', "tàn"),
+    ('煅', "duàn"),
+    ('熜', "cōng"),
+    ('爨', "cuàn"),
+    ('爵', "jué"),
+    ('牐', "zhá"),
+    ('犀', "xī"),
+    ('犕', "bèi"),
+    ('𤜵', "yàn"),
+    ('獺', "tǎ"),
+    ('王', "wáng"),
+    ('㺬', "gǒng"),
+    ('玥', "yuè"),
+    ('㺸', "píng"),
+    ('㺸', "píng"),
+    ('瑇', "dài"),
+    ('瑜', "yú"),
+    ('瑱', "zhèn"),
+    ('璅', "suǒ"),
+    ('瓊', "qióng"),
+    ('㼛', "xíng"),
+    ('甤', "ruí"),
+    ('甾', "zāi"),
+    ('異', "yì"),
+    ('𢆟', "píng"),
+    ('瘐', "yǔ"),
+    ('㿼', "yán"),
+    ('䀈', "jì"),
+    ('直', "zhí"),
+    ('眞', "zhēn"),
+    ('真', "zhēn"),
+    ('真', "zhēn"),
+    ('睊', "juàn"),
+    ('䀹', "jié"),
+    ('瞋', "chēn"),
+    ('䁆', "yè"),
+    ('䂖', "shí"),
+    ('硎', "xíng"),
+    ('碌', "lù"),
+    ('磌', "tián"),
+    ('䃣', "huǐ"),
+    ('𥘦', "jiù"),
+    ('祖', "zǔ"),
+    ('𥚚', "chǐ"),
+    ('𥛅', "liù"),
+    ('福', "fú"),
+    ('秫', "shú"),
+    ('䄯', "jiǎn"),
+    ('穀', "gǔ"),
+    ('穊', "jì"),
+    ('穏', "wěn"),
+    ('𥪧', "tián"),
+    ('𥪧', "tián"),
+    ('竮', "pīng"),
+    ('䈂', "píng"),
+    ('篆', "zhuàn"),
+    ('築', "zhú"),
+    ('䈧', "wěi"),
+    ('𥲀', "cháo"),
+    ('糒', "bèi"),
+    ('䊠', "xì"),
+    ('糨', "jiàng"),
+    ('糣', "sǎn"),
+    ('紀', "jì"),
+    ('絣', "bēng"),
+    ('䌁', "yāo"),
+    ('緇', "zī"),
+    ('縂', "zǒng"),
+    ('繅', "sāo"),
+    ('䌴', "luò"),
+    ('𦈨', "xíng"),
+    ('䍙', "méi"),
+    ('罺', "cháo"),
+    ('羕', "yàng"),
+    ('翺', "áo"),
+    ('者', "zhě"),
+    ('聠', "pīng"),
+    ('聰', "cōng"),
+    ('𣍟', "kěn"),
+    ('䏕', "rèn"),
+    ('育', "yù"),
+    ('脃', "cuì"),
+    ('䐋', "cōng"),
+    ('脾', "pí"),
+    ('媵', "yìng"),
+    ('𣎓', "xuǎn"),
+    ('舁', "yú"),
+    ('舄', "xì"),
+    ('辞', "cí"),
+    ('䑫', "bēng"),
+    ('芑', "qǐ"),
+    ('芋', "yù"),
+    ('芝', "zhī"),
+    ('劳', "láo"),
+    ('花', "huā"),
+    ('芳', "fāng"),
+    ('芽', "yá"),
+    ('苦', "kǔ"),
+    ('若', "ruò"),
+    ('茝', "chǎi"),
+    ('荣', "róng"),
+    ('莭', "jié"),
+    ('茣', "wú"),
+    ('莽', "mǎng"),
+    ('菧', "dǐ"),
+    ('著', "zhe"),
+    ('荓', "píng"),
+    ('菊', "jú"),
+    ('菌', "jūn"),
+    ('菜', "cài"),
+    ('𦵫', "suǒ"),
+    ('蓱', "píng"),
+    ('蓳', "jǐn"),
+    ('蔖', "cuó"),
+    ('蕤', "ruí"),
+    ('䕝', "chēng"),
+    ('䕡', "lǘ"),
+    ('𦾱', "chāo"),
+    ('𧃒', "lǚ"),
+    ('䕫', "kuí"),
+    ('虐', "nüè"),
+    ('虜', "lǔ"),
+    ('虧', "kuī"),
+    ('虩', "xì"),
+    ('蚩', "chī"),
+    ('蚈', "qiān"),
+    ('蜎', "yuān"),
+    ('蛢', "píng"),
+    ('蝹', "yūn"),
+    ('蜨', "dié"),
+    ('蝫', "zhū"),
+    ('螆', "cì"),
+    ('䗗', "jī"),
+    ('蟡', "guǐ"),
+    ('蠁', "xiǎng"),
+    ('䗹', "cì"),
+    ('衠', "zhūn"),
+    ('衣', "yī"),
+    ('𧙧', "jiàn"),
+    ('裗', "liú"),
+    ('裞', "shuì"),
+    ('䘵', "lù"),
+    ('裺', "yǎn"),
+    ('㒻', "mào"),
+    ('䚾', "nín"),
+    ('䛇', "yuǎn"),
+    ('誠', "chéng"),
+    ('諭', "yù"),
+    ('變', "biàn"),
+    ('豕', "shǐ"),
+    ('𧲨', "jiān"),
+    ('貫', "guàn"),
+    ('賁', "bì"),
+    ('贛', "gàn"),
+    ('起', "qǐ"),
+    ('𧼯', "shù"),
+    ('𠠄', "zhào"),
+    ('跋', "bá"),
+    ('趼', "jiǎn"),
+    ('跰', "pián"),
+    ('軔', "rèn"),
+    ('輸', "shū"),
+    ('邔', "qǐ"),
+    ('郱', "píng"),
+    ('鄑', "zī"),
+    ('鄛', "cháo"),
+    ('鈸', "bó"),
+    ('鋗', "xuān"),
+    ('鋘', "huá"),
+    ('鉼', "bǐng"),
+    ('鏹', "qiǎng"),
+    ('鐕', "zān"),
+    ('開', "kāi"),
+    ('䦕', "pēng"),
+    ('閷', "shài"),
+    ('䧦', "wéi"),
+    ('雃', "qiān"),
+    ('嶲', "xī"),
+    ('霣', "yǔn"),
+    ('𩅅', "pāng"),
+    ('𩈚', "pīng"),
+    ('䩮', "lù"),
+    ('䩶', "chǎn"),
+    ('韠', "bì"),
+    ('䪲', "kūn"),
+    ('𩒖', "yàn"),
+    ('頋', "ě"),
+    ('頋', "ě"),
+    ('頩', "pīng"),
+    ('𩖶', "xuè"),
+    ('飢', "jī"),
+    ('䬳', "bǎn"),
+    ('餩', "è"),
+    ('馧', "yūn"),
+    ('駂', "bǎo"),
+    ('駾', "tuì"),
+    ('䯎', "gàn"),
+    ('𩬰', "qióng"),
+    ('鬒', "zhěn"),
+    ('鱀', "jì"),
+    ('鳽', "jiān"),
+    ('䳎', "jiù"),
+    ('䳭', "jí"),
+    ('鵧', "pí"),
+    ('𪃎', "yú"),
+    ('䳸', "má"),
+    ('𪄅', "shā"),
+    ('麻', "má"),
+    ('䵖', "qiàn"),
+    ('黹', "zhǐ"),
+    ('黾', "miǎn"),
+    ('鼅', "zhī"),
+    ('鼏', "mì"),
+    ('鼖', "fén"),
+    ('鼻', "bí"),
+    ('𪘀', "pián"),
+    ('𰀡', "qiān"),
+    ('𰁧', "sǒng"),
+    ('𰁸', "dàng"),
+    ('𰁾', "fù"),
+    ('𰂋', "xiàn"),
+    ('𰂎', "xiàn"),
+    ('𰂏', "yù"),
+    ('𰂗', "dì"),
+    ('𰂜', "tuǐ"),
+    ('𰂦', "lǚ"),
+    ('𰂭', "sì"),
+    ('𰃆', "zǎn"),
+    ('𰃮', "xué"),
+    ('𰃷', "chuàng"),
+    ('𰃻', "bì"),
+    ('𰃿', "dú"),
+    ('𰄁', "shèn"),
+    ('𰄞', "tuán"),
+    ('𰅔', "lǜ"),
+    ('𰅥', "dú"),
+    ('𰅦', "dān"),
+    ('𰅻', "xiá"),
+    ('𰆕', "wēi"),
+    ('𰆚', "lán"),
+    ('𰇀', "gài"),
+    ('𰇎', "dǒng"),
+    ('𰇕', "jiá"),
+    ('𰇖', "hóng"),
+    ('𰇘', "jī"),
+    ('𰇡', "hé"),
+    ('𰇣', "xì"),
+    ('𰇲', "tǎn"),
+    ('𰇼', "shān"),
+    ('𰈆', "lǎn"),
+    ('𰈇', "cháng"),
+    ('𰈊', "diǎn"),
+    ('𰈍', "chèn"),
+    ('𰈓', "làn"),
+    ('𰈯', "zá"),
+    ('𰈶', "mó"),
+    ('𰉁', "yán"),
+    ('𰉄', "zá"),
+    ('𰉘', "wěi"),
+    ('𰉙', "gāng"),
+    ('𰉚', "xiàn"),
+    ('𰉣', "yì"),
+    ('𰉥', "jiā"),
+    ('𰉩', "què"),
+    ('𰉪', "yè"),
+    ('𰉱', "jiǎn"),
+    ('𰉽', "xì"),
+    ('𰊂', "zhì"),
+    ('𰊅', "zhàn"),
+    ('𰊈', "qiǎng"),
+    ('𰊑', "xiàn"),
+    ('𰊛', "kuí"),
+    ('𰊟', "mán"),
+    ('𰊡', "yán"),
+    ('𰊢', "qiàn"),
+    ('𰋖', "lǒu"),
+    ('𰋸', "dàng"),
+    ('𰋹', "zhuān"),
+    ('𰋽', "yān"),
+    ('𰋾', "bì"),
+    ('𰌀', "yīng"),
+    ('𰌂', "zhēng"),
+    ('𰌆', "qiàn"),
+    ('𰌇', "zé"),
+    ('𰌉', "yùn"),
+    ('𰌙', "lǎn"),
+    ('𰌦', "yā"),
+    ('𰌷', "wěi"),
+    ('𰎌', "sǒng"),
+    ('𰎎', "lóng"),
+    ('𰎏', "dōng"),
+    ('𰎐', "lú"),
+    ('𰎑', "yè"),
+    ('𰎔', "yáo"),
+    ('𰎖', "zè"),
+    ('𰎛', "kuài"),
+    ('𰎞', "náo"),
+    ('𰎠', "yán"),
+    ('𰎢', "wéi"),
+    ('𰎫', "yuè"),
+    ('𰎹', "yǎn"),
+    ('𰏁', "cuán"),
+    ('𰏓', "wù"),
+    ('𰏕', "sàn"),
+    ('𰏜', "lóu"),
+    ('𰏟', "lán"),
+    ('𰏲', "mà"),
+    ('𰏶', "kuài"),
+    ('𰏼', "dài"),
+    ('𰐾', "kuǎng"),
+    ('𰑁', "tuán"),
+    ('𰑄', "lún"),
+    ('𰑔', "zhí"),
+    ('𰑕', "yān"),
+    ('𰑙', "liú"),
+    ('𰑟', "gong"),
+    ('𰑥', "xián"),
+    ('𰑧', "cán"),
+    ('𰑪', "shéng"),
+    ('𰑫', "luǒ"),
+    ('𰑬', "zhì"),
+    ('𰑵', "dì"),
+    ('𰑸', "yè"),
+    ('𰑿', "tǎng"),
+    ('𰒆', "mán"),
+    ('𰒒', "chì"),
+    ('𰒖', "miǎo"),
+    ('𰓄', "chéng"),
+    ('𰓆', "wǔ"),
+    ('𰓔', "shǎn"),
+    ('𰓙', "yè"),
+    ('𰓜', "zhì"),
+    ('𰓟', "zá"),
+    ('𰓧', "sūn"),
+    ('𰓬', "lì"),
+    ('𰓱', "kēng"),
+    ('𰓷', "ruán"),
+    ('𰓻', "guī"),
+    ('𰓼', "chàn"),
+    ('𰔇', "dì"),
+    ('𰔋', "guì"),
+    ('𰔲', "qiān"),
+    ('𰔶', "yú"),
+    ('𰕁', "xún"),
+    ('𰕅', "qū"),
+    ('𰕈', "jiǎo"),
+    ('𰕐', "xié"),
+    ('𰕭', "kuài"),
+    ('𰖈', "yǎn"),
+    ('𰖠', "nàn"),
+    ('𰖩', "lí"),
+    ('𰗆', "huì"),
+    ('𰗓', "huǎng"),
+    ('𰗖', "lún"),
+    ('𰗙', "chōu"),
+    ('𰗚', "xiě"),
+    ('𰗛', "zhái"),
+    ('𰗜', "yǎn"),
+    ('𰗡', "chǎn"),
+    ('𰗢', "huī"),
+    ('𰗦', "jǐn"),
+    ('𰗨', "shí"),
+    ('𰗬', "qiān"),
+    ('𰗵', "shěn"),
+    ('𰗹', "sù"),
+    ('𰗺', "fén"),
+    ('𰘀', "dài"),
+    ('𰘈', "jú"),
+    ('𰘓', "qǐng"),
+    ('𰘠', "yán"),
+    ('𰘣', "mián"),
+    ('𰘩', "xiāo"),
+    ('𰘳', "lài"),
+    ('𰘶', "sū"),
+    ('𰘸', "sǒu"),
+    ('𰙋', "wū"),
+    ('𰙎', "hān"),
+    ('𰙑', "xiào"),
+    ('𰙕', "lòu"),
+    ('𰚔', "níng"),
+    ('𰚦', "lǘ"),
+    ('𰚪', "rán"),
+    ('𰚱', "yè"),
+    ('𰛊', "mǎ"),
+    ('𰛏', "cóng"),
+    ('𰛒', "dōng"),
+    ('𰛛', "shǎn"),
+    ('𰛡', "bì"),
+    ('𰛣', "zhí"),
+    ('𰛤', "zhì"),
+    ('𰛥', "ái"),
+    ('𰛦', "huì"),
+    ('𰛩', "shǎn"),
+    ('𰛪', "luán"),
+    ('𰛮', "lǔ"),
+    ('𰛲', "liàn"),
+    ('𰛵', "xián"),
+    ('𰛽', "dǐng"),
+    ('𰜐', "lǎn"),
+    ('𰜜', "qìn"),
+    ('𰜝', "yàng"),
+    ('𰜢', "hè"),
+    ('𰜨', "jiàn"),
+    ('𰜳', "yíng"),
+    ('𰝅', "yíng"),
+    ('𰝋', "yīn"),
+    ('𰝍', "huī"),
+    ('𰝗', "jiǎn"),
+    ('𰝟', "xiǎn"),
+    ('𰝤', "xiǎn"),
+    ('𰝾', "chuǐ"),
+    ('𰞇', "yì"),
+    ('𰞍', "jié"),
+    ('𰞤', "jiān"),
+    ('𰞲', "bèi"),
+    ('𰞷', "zào"),
+    ('𰞻', "fén"),
+    ('𰟘', "yàn"),
+    ('𰠛', "jiān"),
+    ('𰠫', "gāng"),
+    ('𰠲', "kēng"),
+    ('𰠴', "yǎo"),
+    ('𰠹', "pái"),
+    ('𰡄', "lú"),
+    ('𰡊', "xiāo"),
+    ('𰡋', "huī"),
+    ('𰡎', "lái"),
+    ('𰡏', "wō"),
+    ('𰡔', "chán"),
+    ('𰡞', "bèn"),
+    ('𰡢', "yīng"),
+    ('𰡩', "qí"),
+    ('𰡰', "chē"),
+    ('𰡵', "lú"),
+    ('𰡻', "nǎo"),
+    ('𰡽', "shú"),
+    ('𰢄', "sù"),
+    ('𰢢', "wǔ"),
+    ('𰢤', "yīng"),
+    ('𰢦', "lǒu"),
+    ('𰣦', "wěi"),
+    ('𰣩', "níng"),
+    ('𰣬', "jì"),
+    ('𰣯', "xián"),
+    ('𰣶', "tuǐ"),
+    ('𰣼', "jī"),
+    ('𰣽', "luán"),
+    ('𰤓', "tǎng"),
+    ('𰤕', "lì"),
+    ('𰤨', "tà"),
+    ('𰤫', "dú"),
+    ('𰤬', "dú"),
+    ('𰥊', "kàn"),
+    ('𰥒', "cōng"),
+    ('𰥛', "shuì"),
+    ('𰥞', "yíng"),
+    ('𰥠', "mǎn"),
+    ('𰥢', "xǐ"),
+    ('𰥣', "kēng"),
+    ('𰥨', "xián"),
+    ('𰥪', "guī"),
+    ('𰥭', "měng"),
+    ('𰥹', "tǎng"),
+    ('𰦜', "bà"),
+    ('𰦦', "lì"),
+    ('𰦨', "huǐ"),
+    ('𰦭', "lóng"),
+    ('𰦰', "zé"),
+    ('𰦴', "yā"),
+    ('𰦷', "láo"),
+    ('𰦾', "yán"),
+    ('𰦿', "tuó"),
+    ('𰧃', "jiàn"),
+    ('𰧇', "xián"),
+    ('𰧉', "zhì"),
+    ('𰧎', "qīn"),
+    ('𰧔', "jiān"),
+    ('𰧘', "zhū"),
+    ('𰧰', "yáng"),
+    ('𰧻', "guì"),
+    ('𰧾', "nóng"),
+    ('𰨖', "tí"),
+    ('𰨜', "kuàng"),
+    ('𰨦', "jì"),
+    ('𰨳', "zī"),
+    ('𰩅', "lǒng"),
+    ('𰩏', "tiǎo"),
+    ('𰩓', "chēng"),
+    ('𰩧', "xū"),
+    ('𰩮', "tuán"),
+    ('𰩲', "lú"),
+    ('𰩸', "jiāo"),
+    ('𰩹', "dàng"),
+    ('𰩺', "yuē"),
+    ('𰩻', "zhuā"),
+    ('𰪊', "zhōng"),
+    ('𰪏', "lù"),
+    ('𰪣', "yíng"),
+    ('𰪫', "tuán"),
+    ('𰪭', "zhāng"),
+    ('𰪶', "tuán"),
+    ('𰫋', "mén"),
+    ('𰫖', "làn"),
+    ('𰫼', "zhěng"),
+    ('𰫽', "fóu"),
+    ('𰬀', "jì"),
+    ('𰬃', "liè"),
+    ('𰬅', "fū"),
+    ('𰬆', "qú"),
+    ('𰬇', "zhù"),
+    ('𰬈', "xián"),
+    ('𰬉', "tuó"),
+    ('𰬋', "hóng"),
+    ('𰬌', "gēng"),
+    ('𰬍', "xié"),
+    ('𰬎', "bì"),
+    ('𰬏', "zhū"),
+    ('𰬐', "qiāo"),
+    ('𰬑', "zhēng"),
+    ('𰬒', "cì"),
+    ('𰬓', "gāi"),
+    ('𰬔', "bēng"),
+    ('𰬖', "chéng"),
+    ('𰬗', "qiú"),
+    ('𰬘', "fú"),
+    ('𰬙', "chēn"),
+    ('𰬚', "yún"),
+    ('𰬛', "zhèn"),
+    ('𰬜', "miǎn"),
+    ('𰬞', "qīn"),
+    ('𰬟', "yù"),
+    ('𰬠', "huà"),
+    ('𰬡', "qiè"),
+    ('𰬢', "qī"),
+    ('𰬣', "xī"),
+    ('𰬤', "bì"),
+    ('𰬥', "zǒng"),
+    ('𰬦', "hū"),
+    ('𰬧', "tián"),
+    ('𰬩', "xiù"),
+    ('𰬪', "shān"),
+    ('𰬫', "zhì"),
+    ('𰬬', "miáo"),
+    ('𰬭', "běng"),
+    ('𰬮', "qiàn"),
+    ('𰬯', "zōng"),
+    ('𰬰', "cōng"),
+    ('𰬱', "huì"),
+    ('𰬲', "quán"),
+    ('𰬳', "nì"),
+    ('𰬴', "zài"),
+    ('𰬵', "xuàn"),
+    ('𰬶', "qì"),
+    ('𰬷', "sǎn"),
+    ('𰬸', "suì"),
+    ('𰬹', "zuǎn"),
+    ('𰬺', "zūn"),
+    ('𰬻', "yù"),
+    ('𰬼', "là"),
+    ('𰬽', "xiè"),
+    ('𰬾', "lián"),
+    ('𰬿', "pú"),
+    ('𰭀', "yōu"),
+    ('𰭁', "cōng"),
+    ('𰭄', "guàn"),
+    ('𰭔', "juàn"),
+    ('𰭚', "lóu"),
+    ('𰭢', "shà"),
+    ('𰭣', "dào"),
+    ('𰭹', "lóng"),
+    ('𰮅', "xiāng"),
+    ('𰮇', "hū"),
+    ('𰮙', "zhù"),
+    ('𰮝', "xiāo"),
+    ('𰮲', "sōu"),
+    ('𰯋', "ní"),
+    ('𰯎', "mán"),
+    ('𰰆', "tān"),
+    ('𰰋', "shuāng"),
+    ('𰰌', "zhōu"),
+    ('𰰏', "dài"),
+    ('𰰑', "lóu"),
+    ('𰰠', "xù"),
+    ('𰰢', "tāng"),
+    ('𰰤', "qiū"),
+    ('𰰨', "lún"),
+    ('𰰮', "kuī"),
+    ('𰰱', "duì"),
+    ('𰰳', "shī"),
+    ('𰰴', "qǐ"),
+    ('𰰵', "shā"),
+    ('𰰶', "zá"),
+    ('𰰷', "fù"),
+    ('𰰹', "liú"),
+    ('𰰺', "shè"),
+    ('𰰾', "kē"),
+    ('𰱀', "gòng"),
+    ('𰱇', "jiān"),
+    ('𰱈', "yǒng"),
+    ('𰱉', "méng"),
+    ('𰱌', "nà"),
+    ('𰱍', "fén"),
+    ('𰱐', "xù"),
+    ('𰱑', "jiàn"),
+    ('𰱛', "zhōng"),
+    ('𰱝', "xiàng"),
+    ('𰱟', "fén"),
+    ('𰱦', "fù"),
+    ('𰱩', "lǘ"),
+    ('𰱮', "lǘ"),
+    ('𰱯', "bēi"),
+    ('𰱱', "cí"),
+    ('𰱲', "zhī"),
+    ('𰱾', "lài"),
+    ('𰲁', "tuí"),
+    ('𰲂', "téng"),
+    ('𰲒', "lèi"),
+    ('𰲟', "wū"),
+    ('𰲠', "zhàn"),
+    ('𰲫', "wú"),
+    ('𰲬', "chē"),
+    ('𰲮', "qiǎn"),
+    ('𰲯', "zōng"),
+    ('𰲰', "lún"),
+    ('𰲲', "guǐ"),
+    ('𰲳', "zhàn"),
+    ('𰲴', "lóng"),
+    ('𰲵', "zé"),
+    ('𰲶', "xīng"),
+    ('𰲸', "è"),
+    ('𰲹', "yǐ"),
+    ('𰲺', "guò"),
+    ('𰲻', "dá"),
+    ('𰳁', "jī"),
+    ('𰳂', "jiàn"),
+    ('𰳄', "chén"),
+    ('𰳊', "féi"),
+    ('𰳗', "yīng"),
+    ('𰳚', "wèi"),
+    ('𰳲', "lóng"),
+    ('𰳵', "yì"),
+    ('𰳸', "duì"),
+    ('𰳹', "yǎn"),
+    ('𰳺', "nóng"),
+    ('𰳼', "shī"),
+    ('𰴂', "sēn"),
+    ('𰴕', "miè"),
+    ('𰴖', "shào"),
+    ('𰴗', "lì"),
+    ('𰴘', "jiān"),
+    ('𰴙', "jūn"),
+    ('𰴚', "lóu"),
+    ('𰴛', "dōu"),
+    ('𰴜', "qū"),
+    ('𰴝', "luó"),
+    ('𰴞', "qū"),
+    ('𰴢', "lì"),
+    ('𰴣', "xué"),
+    ('𰴤', "dǎi"),
+    ('𰴯', "yíng"),
+    ('𰵊', "jiào"),
+    ('𰵌', "yáng"),
+    ('𰵍', "zhùn"),
+    ('𰵎', "yóu"),
+    ('𰵏', "chāo"),
+    ('𰵐', "fǎn"),
+    ('𰵑', "é"),
+    ('𰵒', "chén"),
+    ('𰵓', "xù"),
+    ('𰵔', "yì"),
+    ('𰵖', "yì"),
+    ('𰵗', "yàng"),
+    ('𰵙', "dié"),
+    ('𰵚', "líng"),
+    ('𰵛', "gòu"),
+    ('𰵜', "táo"),
+    ('𰵝', "hé"),
+    ('𰵞', "yǒng"),
+    ('𰵠', "náo"),
+    ('𰵡', "kēng"),
+    ('𰵢', "shǎn"),
+    ('𰵣', "hé"),
+    ('𰵤', "è"),
+    ('𰵥', "yí"),
+    ('𰵦', "xiào"),
+    ('𰵧', "zhì"),
+    ('𰵨', "zhān"),
+    ('𰵩', "bū"),
+    ('𰵫', "dòu"),
+    ('𰵬', "bié"),
+    ('𰵭', "chán"),
+    ('𰵮', "é"),
+    ('𰵯', "shuà"),
+    ('𰵰', "xìng"),
+    ('𰵱', "chēn"),
+    ('𰵲', "qī"),
+    ('𰵳', "zhuó"),
+    ('𰵴', "chù"),
+    ('𰵵', "nì"),
+    ('𰵶', "zhuì"),
+    ('𰵷', "zhuó"),
+    ('𰵸', "tà"),
+    ('𰵹', "niè"),
+    ('𰵺', "yuán"),
+    ('𰵻', "xū"),
+    ('𰵼', "huò"),
+    ('𰵽', "tāo"),
+    ('𰵾', "xǐ"),
+    ('𰵿', "hè"),
+    ('𰶀', "yú"),
+    ('𰶁', "yí"),
+    ('𰶂', "còng"),
+    ('𰶃', "xí"),
+    ('𰶄', "zhā"),
+    ('𰶅', "hào"),
+    ('𰶆', "hàn"),
+    ('𰶇', "tuō"),
+    ('𰶈', "chí"),
+    ('𰶉', "tán"),
+    ('𰶊', "yī"),
+    ('𰶌', "nòu"),
+    ('𰶍', "xuàn"),
+    ('𰶎', "sè"),
+    ('𰶏', "yào"),
+    ('𰶑', "lóng"),
+    ('𰶔', "dú"),
+    ('𰶨', "mì"),
+    ('𰶬', "lóu"),
+    ('𰷞', "tè"),
+    ('𰷠', "yí"),
+    ('𰷡', "wán"),
+    ('𰷢', "chí"),
+    ('𰷤', "suì"),
+    ('𰷥', "cóng"),
+    ('𰷦', "fèi"),
+    ('𰷧', "jìn"),
+    ('𰷨', "kuì"),
+    ('𰷩', "shèng"),
+    ('𰷪', "yì"),
+    ('𰷫', "biāo"),
+    ('𰷬', "zhuàn"),
+    ('𰷭', "zāng"),
+    ('𰷮', "xuàn"),
+    ('𰷴', "lì"),
+    ('𰷵', "qiāo"),
+    ('𰷶', "qiáo"),
+    ('𰷸', "dú"),
+    ('𰸇', "shuāng"),
+    ('𰸈', "dié"),
+    ('𰸊', "guì"),
+    ('𰸎', "chǎn"),
+    ('𰸐', "xǐ"),
+    ('𰸔', "lián"),
+    ('𰸚', "dài"),
+    ('𰸛', "chì"),
+    ('𰸞', "tuí"),
+    ('𰹀', "duǒ"),
+    ('𰹲', "wèi"),
+    ('𰹳', "chūn"),
+    ('𰹴', "fàn"),
+    ('𰹵', "yǐ"),
+    ('𰹶', "kuáng"),
+    ('𰹷', "yuè"),
+    ('𰹸', "tún"),
+    ('𰹺', "fǎn"),
+    ('𰹻', "bā"),
+    ('𰹼', "zhàn"),
+    ('𰹽', "pēng"),
+    ('𰹾', "xiàn"),
+    ('𰹿', "rǒng"),
+    ('𰺀', "dǐ"),
+    ('𰺁', "qú"),
+    ('𰺂', "páo"),
+    ('𰺃', "zhuì"),
+    ('𰺄', "gǒng"),
+    ('𰺅', "jú"),
+    ('𰺆', "hōng"),
+    ('𰺇', "wàn"),
+    ('𰺈', "yǐn"),
+    ('𰺉', "qiàn"),
+    ('𰺊', "léng"),
+    ('𰺋', "zhé"),
+    ('𰺌', "lù"),
+    ('𰺍', "guǒ"),
+    ('𰺎', "pái"),
+    ('𰺏', "péng"),
+    ('𰺐', "kǎn"),
+    ('𰺑', "yuān"),
+    ('𰺒', "chuán"),
+    ('𰺓', "fù"),
+    ('𰺔', "zǒng"),
+    ('𰺕', "duó"),
+    ('𰺖', "zhēn"),
+    ('𰺗', "jiàn"),
+    ('𰺘', "kēng"),
+    ('𰺙', "sè"),
+    ('𰺚', "ér"),
+    ('𰺛', "lǎo"),
+    ('𰺜', "fān"),
+    ('𰺝', "chōng"),
+    ('𰺞', "zhàn"),
+    ('𰺟', "jí"),
+    ('𰺠', "niè"),
+    ('𰺡', "kài"),
+    ('𰺢', "luò"),
+    ('𰺣', "lìn"),
+    ('𰺤', "mín"),
+    ('𰺨', "huò"),
+    ('𰺭', "chén"),
+    ('𰺲', "dàng"),
+    ('𰺷', "rào"),
+    ('𰻆', "dì"),
+    ('𰻡', "xǔ"),
+    ('𰻦', "wéi"),
+    ('𰻮', "qiāo"),
+    ('𰻳', "bì"),
+    ('𰼅', "yì"),
+    ('𰼋', "láo"),
+    ('𰼏', "làn"),
+    ('𰼑', "mí"),
+    ('𰼻', "qīng"),
+    ('𰽕', "jī"),
+    ('𰽖', "bā"),
+    ('𰽗', "huá"),
+    ('𰽘', "dì"),
+    ('𰽚', "kuàng"),
+    ('𰽛', "qiǎo"),
+    ('𰽜', "ōu"),
+    ('𰽝', "lì"),
+    ('𰽞', "è"),
+    ('𰽠', "zhǐ"),
+    ('𰽡', "rì"),
+    ('𰽢', "xiàn"),
+    ('𰽣', "yǐn"),
+    ('𰽥', "bǐng"),
+    ('𰽦', "lóng"),
+    ('𰽧', "pī"),
+    ('𰽨', "zhǎi"),
+    ('𰽩', "kǎ"),
+    ('𰽫', "shēng"),
+    ('𰽬', "hé"),
+    ('𰽭', "gōu"),
+    ('𰽮', "fú"),
+    ('𰽯', "zhù"),
+    ('𰽰', "bàn"),
+    ('𰽱', "fú"),
+    ('𰽲', "jí"),
+    ('𰽳', "qīn"),
+    ('𰽴', "kuǎ"),
+    ('𰽵', "guāng"),
+    ('𰽶', "tóng"),
+    ('𰽷', "huì"),
+    ('𰽸', "pì"),
+    ('𰽹', "chǐ"),
+    ('𰽺', "guǐ"),
+    ('𰽻', "rén"),
+    ('𰽼', "bǐng"),
+    ('𰽽', "yóng"),
+    ('𰽾', "guǒ"),
+    ('𰽿', "zhé"),
+    ('𰾀', "bó"),
+    ('𰾁', "hòng"),
+    ('𰾃', "zhuó"),
+    ('𰾄', "méi"),
+    ('𰾅', "hán"),
+    ('𰾆', "yù"),
+    ('𰾇', "tūn"),
+    ('𰾈', "shā"),
+    ('𰾊', "cōng"),
+    ('𰾌', "jiàn"),
+    ('𰾍', "biǎo"),
+    ('𰾎', "pī"),
+    ('𰾏', "táo"),
+    ('𰾐', "fèi"),
+    ('𰾑', "piān"),
+    ('𰾒', "guǎn"),
+    ('𰾓', "tà"),
+    ('𰾕', "yè"),
+    ('𰾖', "duò"),
+    ('𰾗', "zhēn"),
+    ('𰾘', "chěn"),
+    ('𰾙', "yú"),
+    ('𰾚', "wěi"),
+    ('𰾛', "jiè"),
+    ('𰾜', "duān"),
+    ('𰾝', "shēng"),
+    ('𰾞', "zōng"),
+    ('𰾟', "fù"),
+    ('𰾡', "hōng"),
+    ('𰾢', "shī"),
+    ('𰾤', "xiá"),
+    ('𰾥', "kuí"),
+    ('𰾦', "zuàn"),
+    ('𰾩', "yè"),
+    ('𰾪', "yā"),
+    ('𰾫', "jiàn"),
+    ('𰾬', "dá"),
+    ('𰾭', "ài"),
+    ('𰾮', "lián"),
+    ('𰾯', "táng"),
+    ('𰾰', "cuī"),
+    ('𰾱', "zǒng"),
+    ('𰾲', "lù"),
+    ('𰾳', "lán"),
+    ('𰾴', "sī"),
+    ('𰾶', "huì"),
+    ('𰾷', "zān"),
+    ('𰾸', "dǐng"),
+    ('𰾹', "juān"),
+    ('𰾻', "tián"),
+    ('𰾼', "quān"),
+    ('𰾽', "zhú"),
+    ('𰾾', "niè"),
+    ('𰾿', "là"),
+    ('𰿁', "áo"),
+    ('𰿂', "xū"),
+    ('𰿃', "miè"),
+    ('𰿄', "léi"),
+    ('𰿆', "zuān"),
+    ('𰿇', "zhá"),
+    ('𰿈', "jiān"),
+    ('𰿉', "lěi"),
+    ('𰿊', "luó"),
+    ('𰿦', "zhèn"),
+    ('𰿨', "guǎn"),
+    ('𰿩', "xiā"),
+    ('𰿪', "xiè"),
+    ('𰿫', "wěi"),
+    ('𰿬', "pēng"),
+    ('𰿰', "zuān"),
+    ('𰿳', "shài"),
+    ('𰿴', "yà"),
+    ('𰿵', "yú"),
+    ('𰿹', "guāng"),
+    ('𰿺', "táng"),
+    ('𰿻', "xì"),
+    ('𰿾', "pì"),
+    ('𱀑', "zhì"),
+    ('𱀡', "fén"),
+    ('𱁒', "duì"),
+    ('𱁞', "wān"),
+    ('𱁱', "yì"),
+    ('𱁳', "duó"),
+    ('𱁴', "bì"),
+    ('𱁶', "qiān"),
+    ('𱁷', "dú"),
+    ('𱁹', "guì"),
+    ('𱁺', "lóu"),
+    ('𱁽', "lán"),
+    ('𱁾', "lán"),
+    ('𱂃', "sǎ"),
+    ('𱂄', "mèi"),
+    ('𱂅', "bì"),
+    ('𱂆', "gé"),
+    ('𱂇', "quàn"),
+    ('𱂈', "yùn"),
+    ('𱂉', "qiào"),
+    ('𱂊', "shè"),
+    ('𱂋', "dú"),
+    ('𱂌', "hù"),
+    ('𱂍', "duì"),
+    ('𱂎', "xiè"),
+    ('𱂐', "yùn"),
+    ('𱂠', "duó"),
+    ('𱂡', "kū"),
+    ('𱂢', "zhuō"),
+    ('𱂣', "yáo"),
+    ('𱂤', "huì"),
+    ('𱂥', "é"),
+    ('𱂦', "pīng"),
+    ('𱂧', "lèi"),
+    ('𱂨', "è"),
+    ('𱂩', "fù"),
+    ('𱂫', "yǎn"),
+    ('𱂬', "kuí"),
+    ('𱂭', "chuí"),
+    ('𱂮', "pǐ"),
+    ('𱂯', "cuì"),
+    ('𱂰', "hàn"),
+    ('𱂱', "kǎn"),
+    ('𱂲', "sāi"),
+    ('𱂳', "gǎo"),
+    ('𱂴', "jiǎng"),
+    ('𱂵', "kuī"),
+    ('𱂶', "xìn"),
+    ('𱂷', "zhān"),
+    ('𱂸', "bīn"),
+    ('𱂺', "piǎo"),
+    ('𱂻', "qiáo"),
+    ('𱃔', "biāo"),
+    ('𱃕', "xiā"),
+    ('𱃖', "yǒu"),
+    ('𱃗', "tái"),
+    ('𱃘', "liè"),
+    ('𱃙', "liú"),
+    ('𱃚', "lì"),
+    ('𱃜', "ruí"),
+    ('𱃝', "xiū"),
+    ('𱃞', "yú"),
+    ('𱃟', "xí"),
+    ('𱃠', "biāo"),
+    ('𱃱', "xì"),
+    ('𱃲', "yù"),
+    ('𱃳', "bó"),
+    ('𱃴', "fàn"),
+    ('𱃵', "yǐng"),
+    ('𱃷', "bǎn"),
+    ('𱃸', "zhì"),
+    ('𱃹', "èn"),
+    ('𱃺', "tiǎn"),
+    ('𱃼', "fēn"),
+    ('𱃽', "è"),
+    ('𱃾', "bù"),
+    ('𱃿', "tán"),
+    ('𱄀', "fēn"),
+    ('𱄁', "tǒu"),
+    ('𱄃', "jiàn"),
+    ('𱄄', "huì"),
+    ('𱄆', "chì"),
+    ('𱄇', "shǎng"),
+    ('𱄈', "méng"),
+    ('𱄉', "èn"),
+    ('𱄊', "zàn"),
+    ('𱄼', "zhù"),
+    ('𱄽', "zhé"),
+    ('𱄾', "guāng"),
+    ('𱄿', "dú"),
+    ('𱅀', "bǎo"),
+    ('𱅁', "zhí"),
+    ('𱅂', "sà"),
+    ('𱅃', "jù"),
+    ('𱅄', "bá"),
+    ('𱅅', "lóng"),
+    ('𱅇', "zhěn"),
+    ('𱅈', "bì"),
+    ('𱅉', "róng"),
+    ('𱅊', "zhì"),
+    ('𱅋', "ěr"),
+    ('𱅍', "xù"),
+    ('𱅎', "fú"),
+    ('𱅏', "táo"),
+    ('𱅐', "bó"),
+    ('𱅓', "bó"),
+    ('𱅔', "sǒng"),
+    ('𱅕', "lái"),
+    ('𱅖', "xuān"),
+    ('𱅗', "ě"),
+    ('𱅙', "tuì"),
+    ('𱅚', "shè"),
+    ('𱅛', "tuó"),
+    ('𱅜', "yǎo"),
+    ('𱅝', "piàn"),
+    ('𱅞', "xiá"),
+    ('𱅟', "róu"),
+    ('𱅠', "hàn"),
+    ('𱅡', "guī"),
+    ('𱅢', "chéng"),
+    ('𱅣', "tāo"),
+    ('𱅤', "pīn"),
+    ('𱅦', "huáng"),
+    ('𱅧', "xū"),
+    ('𱅩', "xí"),
+    ('𱅪', "diàn"),
+    ('𱅫', "yù"),
+    ('𱅬', "yàn"),
+    ('𱅮', "xiāo"),
+    ('𱆀', "qiān"),
+    ('𱆁', "qiān"),
+    ('𱆃', "kuì"),
+    ('𱆄', "fèi"),
+    ('𱆅', "lán"),
+    ('𱆆', "mán"),
+    ('𱆈', "zuǎn"),
+    ('𱆌', "shāng"),
+    ('𱆍', "chǎo"),
+    ('𱆙', "chāo"),
+    ('𱆚', "kuǐ"),
+    ('𱆛', "chǒu"),
+    ('𱆥', "dān"),
+    ('𱇍', "yà"),
+    ('𱇏', "tuō"),
+    ('𱇐', "yuán"),
+    ('𱇑', "bèi"),
+    ('𱇒', "pí"),
+    ('𱇓', "hú"),
+    ('𱇔', "nà"),
+    ('𱇕', "yú"),
+    ('𱇖', "bǎn"),
+    ('𱇗', "lún"),
+    ('𱇘', "háng"),
+    ('𱇙', "shěn"),
+    ('𱇚', "mò"),
+    ('𱇛', "wèi"),
+    ('𱇜', "qū"),
+    ('𱇝', "pī"),
+    ('𱇞', "shàn"),
+    ('𱇟', "xiá"),
+    ('𱇠', "qiú"),
+    ('𱇡', "xīng"),
+    ('𱇢', "gǔn"),
+    ('𱇣', "bō"),
+    ('𱇤', "yǒu"),
+    ('𱇥', "gèng"),
+    ('𱇦', "kū"),
+    ('𱇧', "tǐ"),
+    ('𱇨', "shū"),
+    ('𱇩', "jiǎo"),
+    ('𱇪', "luò"),
+    ('𱇫', "rú"),
+    ('𱇬', "yí"),
+    ('𱇭', "lái"),
+    ('𱇮', "máng"),
+    ('𱇯', "shāo"),
+    ('𱇰', "yè"),
+    ('𱇱', "tiáo"),
+    ('𱇲', "fū"),
+    ('𱇳', "suō"),
+    ('𱇴', "nà"),
+    ('𱇵', "jì"),
+    ('𱇶', "lù"),
+    ('𱇷', "cuò"),
+    ('𱇸', "yìng"),
+    ('𱇹', "zhì"),
+    ('𱇺', "jì"),
+    ('𱇻', "xiàn"),
+    ('𱇼', "jiù"),
+    ('𱇽', "jú"),
+    ('𱇾', "zōng"),
+    ('𱇿', "sù"),
+    ('𱈀', "jù"),
+    ('𱈁', "shān"),
+    ('𱈂', "yú"),
+    ('𱈃', "wèi"),
+    ('𱈄', "hóu"),
+    ('𱈅', "zōng"),
+    ('𱈆', "lóu"),
+    ('𱈇', "gèng"),
+    ('𱈈', "gèng"),
+    ('𱈉', "qián"),
+    ('𱈊', "liú"),
+    ('𱈋', "hào"),
+    ('𱈌', "xiū"),
+    ('𱈍', "ruò"),
+    ('𱈎', "sǎng"),
+    ('𱈏', "zhú"),
+    ('𱈐', "zhuān"),
+    ('𱈑', "huī"),
+    ('𱈒', "sī"),
+    ('𱈓', "xún"),
+    ('𱈔', "cén"),
+    ('𱈕', "fān"),
+    ('𱈖', "lài"),
+    ('𱈗', "rú"),
+    ('𱈘', "cháng"),
+    ('𱈙', "miè"),
+    ('𱈚', "è"),
+    ('𱈛', "méng"),
+    ('𱈜', "xiān"),
+    ('𱉇', "yǐ"),
+    ('𱉈', "diāo"),
+    ('𱉊', "gān"),
+    ('𱉋', "hàn"),
+    ('𱉌', "yí"),
+    ('𱉍', "yù"),
+    ('𱉎', "fū"),
+    ('𱉏', "tuán"),
+    ('𱉐', "jú"),
+    ('𱉑', "bān"),
+    ('𱉒', "huān"),
+    ('𱉓', "hù"),
+    ('𱉔', "jué"),
+    ('𱉕', "gē"),
+    ('𱉖', "pí"),
+    ('𱉗', "dàn"),
+    ('𱉘', "jū"),
+    ('𱉙', "zhǐ"),
+    ('𱉚', "tiě"),
+    ('𱉛', "zhì"),
+    ('𱉜', "fū"),
+    ('𱉝', "bī"),
+    ('𱉞', "wǔ"),
+    ('𱉟', "zé"),
+    ('𱉠', "jīng"),
+    ('𱉡', "biǎn"),
+    ('𱉢', "yǎo"),
+    ('𱉣', "sōng"),
+    ('𱉤', "guì"),
+    ('𱉥', "jiá"),
+    ('𱉦', "lǎo"),
+    ('𱉧', "yuán"),
+    ('𱉨', "yā"),
+    ('𱉩', "tí"),
+    ('𱉪', "ài"),
+    ('𱉫', "zhū"),
+    ('𱉬', "jiāo"),
+    ('𱉭', "guǐ"),
+    ('𱉮', "luò"),
+    ('𱉯', "yáng"),
+    ('𱉰', "yù"),
+    ('𱉱', "kūn"),
+    ('𱉲', "móu"),
+    ('𱉳', "mǎi"),
+    ('𱉴', "yáng"),
+    ('𱉵', "lái"),
+    ('𱉶', "kàn"),
+    ('𱉷', "yì"),
+    ('𱉸', "tú"),
+    ('𱉹', "xī"),
+    ('𱉺', "fú"),
+    ('𱉻', "tuǒ"),
+    ('𱉼', "jí"),
+    ('𱉽', "jì"),
+    ('𱉾', "jùn"),
+    ('𱉿', "jùn"),
+    ('𱊀', "lù"),
+    ('𱊁', "qí"),
+    ('𱊂', "míng"),
+    ('𱊃', "lí"),
+    ('𱊄', "yì"),
+    ('𱊅', "zhuī"),
+    ('𱊆', "tù"),
+    ('𱊇', "jú"),
+    ('𱊈', "yì"),
+    ('𱊊', "kōng"),
+    ('𱊋', "jiān"),
+    ('𱊌', "jū"),
+    ('𱊍', "duò"),
+    ('𱊎', "zī"),
+    ('𱊏', "fú"),
+    ('𱊐', "jiē"),
+    ('𱊑', "chūn"),
+    ('𱊒', "yuán"),
+    ('𱊓', "mò"),
+    ('𱊕', "tí"),
+    ('𱊖', "tú"),
+    ('𱊗', "róu"),
+    ('𱊘', "chuàn"),
+    ('𱊙', "zhèn"),
+    ('𱊚', "tián"),
+    ('𱊛', "sǔn"),
+    ('𱊜', "niǎo"),
+    ('𱊝', "táng"),
+    ('𱊟', "xiá"),
+    ('𱊠', "tú"),
+    ('𱊡', "yǎo"),
+    ('𱊢', "jí"),
+    ('𱊣', "yàn"),
+    ('𱊤', "yōng"),
+    ('𱊥', "shāng"),
+    ('𱊦', "yì"),
+    ('𱊧', "jué"),
+    ('𱊩', "tú"),
+    ('𱊪', "líng"),
+    ('𱊫', "yú"),
+    ('𱊬', "shǔ"),
+    ('𱊭', "yù"),
+    ('𱊮', "luó"),
+    ('𱊯', "jī"),
+    ('𱊰', "yì"),
+    ('𱊱', "dí"),
+    ('𱊲', "bǔ"),
+    ('𱊳', "lěi"),
+    ('𱊵', "yuè"),
+    ('𱊺', "lǔ"),
+    ('𱊻', "lǔ"),
+    ('𱊼', "chuài"),
+    ('𱊽', "huái"),
+    ('𱋆', "shàn"),
+    ('𱋇', "hé"),
+    ('𱋈', "kuàng"),
+    ('𱋊', "mò"),
+    ('𱋋', "qù"),
+    ('𱋍', "tǒu"),
+    ('𱋎', "huá"),
+    ('𱋐', "qū"),
+    ('𱋓', "gé"),
+    ('𱋔', "bǐng"),
+    ('𱋕', "qū"),
+    ('𱋖', "fū"),
+    ('𱋗', "fū"),
+    ('𱋘', "shā"),
+    ('𱋙', "guǒ"),
+    ('𱋜', "mò"),
+    ('𱋝', "bù"),
+    ('𱋡', "lǒu"),
+    ('𱋢', "chǎo"),
+    ('𱋪', "zhí"),
+    ('𱋫', "kuàng"),
+    ('𱋬', "lián"),
+    ('𱋮', "méng"),
+    ('𱋱', "fén"),
+    ('𱋴', "chǎn"),
+    ('𱋶', "lú"),
+    ('𱋾', "cù"),
+    ('𱋿', "qù"),
+    ('𱌀', "yāng"),
+    ('𱌁', "qú"),
+    ('𱌃', "wā"),
+    ('𱌄', "zhī"),
+    ('𱌅', "mí"),
+    ('𱌆', "méng"),
+    ('𱌇', "biē"),
+    ('𱌈', "má"),
+    ('𱌉', "bì"),
+    ('𱌊', "chāng"),
+    ('𱌏', "hún"),
+    ('𱌖', "nòng"),
+    ('𱌗', "jì"),
+    ('𱌘', "zī"),
+    ('𱌙', "zī"),
+    ('𱌫', "yǎn"),
+    ('𱌬', "zé"),
+    ('𱌭', "zōu"),
+    ('𱌮', "chū"),
+    ('𱌯', "chī"),
+    ('𱌰', "yǎo"),
+    ('𱌱', "xiè"),
+    ('𱌲', "quán"),
+    ('𱌳', "yǔn"),
+    ('𱌴', "zhāi"),
+    ('𱌵', "zhān"),
+    ('𱌶', "zōu"),
+    ('𱌷', "yí"),
+    ('𱌸', "jì"),
+    ('𱌹', "óu"),
+    ('𱌺', "diān"),
+    ('𱌽', "yì"),
+    ('𱍁', "gōng"),
+    ('𱍂', "dá"),
+    ('𱍄', "rán"),
+    ('𱍅', "gōu"),
+    ('𱍆', "jiāo"),
+    ('𱍇', "tóng"),
+    ('𱍈', "biē"),
+];
+
+
+/// 判断字符是否为汉字 ( CJK 统一表意文字，包括扩展 A 至 I 区以及兼容表意文字 )
+#[cfg(feature = "alloc")]
+pub fn is_hanzi(c: char) -> bool {
+    matches!(c as u32,
+        0x3007
+        | 0x3400 ..= 0x4DBF
+        | 0x4E00 ..= 0x9FFF
+        | 0xF900 ..= 0xFAFF
+        | 0x20000 ..= 0x2A6DF
+        | 0x2A700 ..= 0x2EE5F
+        | 0x2F800 ..= 0x2FA1F
+        | 0x30000 ..= 0x323AF)
+}
+
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{ PINYIN_MAP, is_hanzi };
+    use syllable;
+    use tone::ToneFormat;
+    use format::Show;
+    use PinYin;
+
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn table_is_sorted() {
+        assert!(PINYIN_MAP.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn readings_are_syllables() {
+        for (c, readings) in PINYIN_MAP.iter() {
+            for reading in readings.split(',') {
+                assert!(syllable::from_str(reading).is_ok(), "{}: {}", c, reading);
+            }
+        }
+    }
+
+    #[test]
+    fn covers_extensions() {
+        for c in ['液', '漂', '淹', '霉', '倔', '宰', '鑫', '饕', '〇', '㐀', '䶮', '𠀀', '𪚥', '\u{2F800}'].iter() {
+            assert!(c.pinyin().is_ok(), "{}", c);
+        }
+    }
+
+    #[test]
+    fn polyphones() {
+        let readings = |c: char| c.pinyin().unwrap().map(|syllable| syllable.show(ToneFormat::Index)).collect::<Vec<String>>();
+
+        assert_eq!(readings('降'), ["jiang4", "xiang2"]);
+        assert_eq!(readings('漂'), ["piao1", "piao3", "piao4"]);
+
+        // `yo` 不在音节表当中
+        assert!('哟'.pinyin().is_err());
+    }
+
+    #[test]
+    fn ranges() {
+        // 兼容表意文字补充区、扩展 H 区、扩展 I 区
+        for c in ['\u{2F800}', '\u{2FA1D}', '\u{31350}', '\u{323AF}', '\u{2EBF0}', '\u{2EE5D}'].iter() {
+            assert!(is_hanzi(*c), "U+{:X}", *c as u32);
+        }
+
+        for c in ['a', '。', '\u{2FA20}', '\u{323B0}', '\u{2EE60}'].iter() {
+            assert!(!is_hanzi(*c), "U+{:X}", *c as u32);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use tone::Tone;
+use error::{ Error, ErrorKind };
+use initial::{ Initial, INITIAL_TABLE };
+use rhyme::{ Rhyme, RHYME_TABLE };
+use syllable::{
+    Syllable, PRIMITIVE_SYLLABLE_TABLE,
+    PrimitiveSyllable, NormalSyllable, RhymeSyllable, NasalSyllable,
+};
+
+
+// 音节编号的分段 ( 不含声调 ):
+//     整体认读音节: 按照整体认读音节表的顺序
+//     常规音节: 声母表位置 * 韵母表长度 + 韵母表位置
+//     自成音节: 按照韵母表的顺序
+//     鼻音音节: `m`、`n`
+// 
+// 最终编号为 `分段编号 * 5 + 声调序号` ( 轻声为 0 )。
+// NOTE: 编号只依赖于上述各表的顺序，修改这些表会导致已经保存的编号失效。
+const PRIMITIVE_BASE: usize = 0;
+const NORMAL_BASE: usize = PRIMITIVE_BASE + PRIMITIVE_SYLLABLE_TABLE.len();
+const RHYME_BASE: usize = NORMAL_BASE + INITIAL_TABLE.len() * RHYME_TABLE.len();
+const NASAL_BASE: usize = RHYME_BASE + RHYME_TABLE.len();
+const NASAL_INITIALS: [Initial; 2] = [Initial::M, Initial::N];
+const TONE_COUNT: usize = 5;
+
+/// 音节编号的上限，所有的编号都小于这个值，可以用作查找表的长度
+pub const SYLLABLE_ID_LIMIT: u16 = ((NASAL_BASE + NASAL_INITIALS.len()) * TONE_COUNT) as u16;
+
+
+// 声调序号 ( 和 `u8::from(Tone)` 一致 )
+fn tone_from_index(index: usize) -> Tone {
+    match index {
+        1 => Tone::First,
+        2 => Tone::Second,
+        3 => Tone::Third,
+        4 => Tone::Fourth,
+        _ => Tone::Neutral,
+    }
+}
+
+impl Syllable {
+    /// 音节 ( 包含声调 ) 的编号，可以通过 `Syllable::from_id` 还原
+    pub fn id(&self) -> u16 {
+        let base = match *self {
+            Syllable::Primitive(ref v) => PRIMITIVE_BASE + v.index(),
+            Syllable::Normal(ref v) => {
+                let initial = v.initial().expect("常规音节必须包含声母！");
+                let rhyme = v.finals().expect("常规音节必须包含韵母！");
+                NORMAL_BASE + initial.index() * RHYME_TABLE.len() + rhyme.index()
+            },
+            Syllable::Rhyme(ref v) => {
+                RHYME_BASE + v.finals().expect("自成音节必须包含韵母！").index()
+            },
+            Syllable::Nasal(ref v) => {
+                let initial = v.initial().expect("鼻音音节必须包含声母！");
+                NASAL_BASE + NASAL_INITIALS.iter()
+                                .position(|c| *c == initial)
+                                .expect("鼻音音节的声母只能是 `m` 或 `n` ！")
+            },
+        };
+
+        (base * TONE_COUNT + u8::from(self.tone()) as usize) as u16
+    }
+
+    /// 根据编号还原音节，编号不对应任何合法音节的时候返回错误
+    pub fn from_id(id: u16) -> Result<Syllable, Error> {
+        let invalid = || Error::new(ErrorKind::InvalidId, id, 0);
+
+        if id >= SYLLABLE_ID_LIMIT {
+            return Err(invalid());
+        }
+
+        let base = id as usize / TONE_COUNT;
+        let tone = tone_from_index(id as usize % TONE_COUNT);
+
+        if base < NORMAL_BASE {
+            let (chars, _vowel) = PRIMITIVE_SYLLABLE_TABLE[base - PRIMITIVE_BASE];
+            PrimitiveSyllable::new(chars, tone).map(Syllable::from).map_err(|_| invalid())
+        } else if base < RHYME_BASE {
+            let index = base - NORMAL_BASE;
+            let initial = Initial::new(INITIAL_TABLE[index / RHYME_TABLE.len()])?;
+            let rhyme = Rhyme::new(RHYME_TABLE[index % RHYME_TABLE.len()])?;
+            NormalSyllable::new(initial, rhyme, tone).map(Syllable::from).map_err(|_| invalid())
+        } else if base < NASAL_BASE {
+            let rhyme = Rhyme::new(RHYME_TABLE[base - RHYME_BASE])?;
+            let syllable = RhymeSyllable::new(rhyme, tone).map_err(|_| invalid())?;
+
+            // NOTE: `yi`、`wu`、`yu` 等音节只使用整体认读音节的编号
+            if syllable.is_primitive() {
+                return Err(invalid());
+            }
+
+            Ok(Syllable::from(syllable))
+        } else {
+            NasalSyllable::new(NASAL_INITIALS[base - NASAL_BASE], tone)
+                .map(Syllable::from)
+                .map_err(|_| invalid())
+        }
+    }
+}
+
+impl From<Syllable> for u16 {
+    fn from(syllable: Syllable) -> u16 {
+        syllable.id()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::SYLLABLE_ID_LIMIT;
+    use syllable::{ Syllable, from_str };
+    use tone::TONE_TABLE;
+    use inventory::syllables;
+
+    #[test]
+    fn round_trip() {
+        for syllable in syllables(&TONE_TABLE) {
+            assert!(syllable.id() < SYLLABLE_ID_LIMIT);
+            assert_eq!(Syllable::from_id(syllable.id()), Ok(syllable));
+        }
+
+        // 不在音节表当中的编号都是非法编号
+        let valid = (0..SYLLABLE_ID_LIMIT + 5).filter(|id| Syllable::from_id(*id).is_ok()).count();
+        assert_eq!(valid, syllables(&TONE_TABLE).count());
+    }
+
+    #[test]
+    fn fixed_ids() {
+        // NOTE: 编号会被保存下来，不能随着表格的修改而改变
+        let cases = [
+            (1, "zhī"), (37, "yí"), (48, "yǔ"), (80, "ba"), (81, "bā"),
+            (3846, "zhōng"), (3939, "zhuàng"), (3965, "a"), (3980, "ê"),
+            (4152, "ḿ"), (4159, "n4"),
+        ];
+
+        for (id, s) in cases.iter() {
+            assert_eq!(Syllable::from_id(*id), from_str(s), "{}", s);
+            assert_eq!(from_str(s).map(|syllable| syllable.id()), Ok(*id), "{}", s);
+        }
+    }
+}
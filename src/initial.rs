@@ -1,4 +1,9 @@
-use std::fmt;
+use error::{ Error, ErrorKind };
+use letters::Letters;
+
+use core::fmt;
+use core::cmp;
+use core::str::FromStr;
 
 // 对于 《汉语拼音方案》 当中的声母表的补充说明
 // `y` 和 `w` 在现代学说里面被称为 `零声母` ，
@@ -15,36 +20,90 @@ pub const INITIAL_TABLE: [char; 21] = [
 
 
 /// 声母
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
-pub struct Initial(char);
+// NOTE: 只保存在声母表当中的位置，这样音节只占用几个字节。
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct Initial(u8);
 
 impl Initial {
-    pub const M: Initial = Initial('m');
-    pub const N: Initial = Initial('n');
-    pub const J: Initial = Initial('j');
-    pub const Q: Initial = Initial('q');
-    pub const X: Initial = Initial('x');
+    pub const M: Initial = Initial(10);
+    pub const N: Initial = Initial(11);
+    pub const J: Initial = Initial(7);
+    pub const Q: Initial = Initial(13);
+    pub const X: Initial = Initial(18);
 
-    pub fn new(c: char) -> Result<Self, ()> {
+    pub fn new(c: char) -> Result<Self, Error> {
         // NOTE: `zh/sh/ch` 需要预先自动处理成 `ẑ/ĉ/ŝ` 以方便结构化处理。
-        if INITIAL_TABLE.contains(&c) {
-            Ok(Initial(c))
-        } else {
-            Err(())
+        match INITIAL_TABLE.iter().position(|initial| *initial == c) {
+            Some(index) => Ok(Initial(index as u8)),
+            None => Err(Error::new(ErrorKind::InvalidInitial(c), c, 0)),
         }
     }
+
+    // 声母表当中的字符 ( `zh/ch/sh` 为 `ẑ/ĉ/ŝ` )
+    fn char(&self) -> char {
+        INITIAL_TABLE[self.0 as usize]
+    }
+
+    // 拼写形式的字母 ( `zh/ch/sh` 为两个字母 )
+    pub(crate) fn letters(&self) -> Letters {
+        let mut letters = Letters::new();
+        fmt::Write::write_fmt(&mut letters, format_args!("{}", self)).expect("声母最多只有 2 个字母！");
+        letters
+    }
+
+    // 在声母表当中的位置
+    pub(crate) fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+// 按照声母表当中的字符排序
+impl PartialOrd for Initial {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Initial {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.char().cmp(&other.char())
+    }
+}
+
+impl fmt::Debug for Initial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Initial").field(&self.char()).finish()
+    }
 }
 
 impl fmt::Display for Initial {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.0 == 'ĉ' {
-            write!(f, "ch")
-        } else if self.0 == 'ŝ' {
-            write!(f, "sh")
-        } else if self.0 == 'ẑ' {
-            write!(f, "zh")
-        } else {
-            write!(f, "{}", self.0)
+        match self.char() {
+            'ĉ' => write!(f, "ch"),
+            'ŝ' => write!(f, "sh"),
+            'ẑ' => write!(f, "zh"),
+            c => write!(f, "{}", c),
         }
     }
 }
+
+impl FromStr for Initial {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        let c = match s {
+            "zh" => 'ẑ',
+            "ch" => 'ĉ',
+            "sh" => 'ŝ',
+            _ => match (chars.next(), chars.next()) {
+                (Some(c), None) => c,
+                (Some(_), Some(c)) => return Err(Error::new(ErrorKind::InvalidInitial(c), s, 1)),
+                (None, _) => return Err(Error::new(ErrorKind::InvalidSyllable, s, 0)),
+            },
+        };
+
+        Initial::new(c).map_err(|e| e.with_input(s, 0))
+    }
+}
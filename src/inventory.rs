@@ -0,0 +1,95 @@
+use tone::Tone;
+use initial::{ Initial, INITIAL_TABLE };
+use rhyme::{ Rhyme, RHYME_TABLE };
+use syllable::{
+    Syllable, SyllableKind, PRIMITIVE_SYLLABLE_TABLE,
+    PrimitiveSyllable, NormalSyllable, RhymeSyllable, NasalSyllable,
+};
+
+
+/// 列举全部合法的音节，每个音节按照 `tones` 给出的顺序与每一种声调组合
+/// 
+/// 输出顺序为: 整体认读音节、常规音节、自成音节、鼻音音节。
+/// 传入 `&TONE_TABLE` 得到带声调的完整音节表，传入 `&[Tone::Neutral]` 得到不带声调的音节表
+/// （鼻音音节没有轻声的形式，所以不会出现在后者当中）。
+pub fn syllables<'a>(tones: &'a [Tone]) -> impl Iterator<Item = Syllable> + 'a {
+    let primitive = PRIMITIVE_SYLLABLE_TABLE.iter()
+        .flat_map(move |(chars, _vowel)| {
+            tones.iter().filter_map(move |tone| PrimitiveSyllable::new(*chars, *tone).ok())
+        })
+        .map(Syllable::from);
+
+    let normal = INITIAL_TABLE.iter()
+        .filter_map(|c| Initial::new(*c).ok())
+        .flat_map(|initial| {
+            RHYME_TABLE.iter()
+                .filter_map(|chars| Rhyme::new(*chars).ok())
+                .filter(move |rhyme| NormalSyllable::is_legal(initial, *rhyme))
+                .map(move |rhyme| (initial, rhyme))
+        })
+        .flat_map(move |(initial, rhyme)| {
+            tones.iter().filter_map(move |tone| NormalSyllable::new(initial, rhyme, *tone).ok())
+        })
+        .map(Syllable::from);
+
+    // NOTE: `yi`、`wu`、`yu` 等音节已经作为整体认读音节列出，这里不再重复。
+    let rhyme = RHYME_TABLE.iter()
+        .filter_map(|chars| RhymeSyllable::new(Rhyme::new(*chars).ok()?, Tone::Neutral).ok())
+        .filter(|syllable| !syllable.is_primitive())
+        .filter_map(|syllable| syllable.finals())
+        .flat_map(move |rhyme| {
+            tones.iter().filter_map(move |tone| RhymeSyllable::new(rhyme, *tone).ok())
+        })
+        .map(Syllable::from);
+
+    let nasal = [Initial::M, Initial::N].iter()
+        .flat_map(move |initial| {
+            tones.iter().filter_map(move |tone| NasalSyllable::new(*initial, *tone).ok())
+        })
+        .map(Syllable::from);
+
+    primitive.chain(normal).chain(rhyme).chain(nasal)
+}
+
+// 音节在 `syllables` 音节表当中的顺序: 整体认读音节、常规音节、自成音节、鼻音音节
+pub(crate) fn inventory_order(syllable: &Syllable) -> (SyllableKind, usize, usize) {
+    let (initial, rhyme) = syllable.parts();
+    let (initial, rhyme) = (initial.map_or(0, |v| v.index()), rhyme.map_or(0, |v| v.index()));
+
+    match *syllable {
+        Syllable::Primitive(ref v) => (SyllableKind::Primitive, v.index(), 0),
+        Syllable::Rhyme(_) => (SyllableKind::Rhyme, rhyme, 0),
+        _ => (syllable.kind(), initial, rhyme),
+    }
+}
+
+// 按照结构列举拼写可能与输入相同的音节: 声母的拼写 ( 由 `initial_spelling` 给出 ) 为输入前缀的常规音节、
+// 声母加空韵的音节以及鼻音音节 ( 空韵的拼写不一定以声母开头，例如威妥玛拼音的 `tzŭ` )，以及零声母音节
+pub(crate) fn candidates<'a>(input: &'a [char],
+                             initial_spelling: fn(&Initial) -> &'static str,
+                             tones: &'a [Tone]) -> impl Iterator<Item = Syllable> + 'a {
+    let initials = INITIAL_TABLE.iter().filter_map(|c| Initial::new(*c).ok());
+    let rhymes = || RHYME_TABLE.iter().filter_map(|chars| Rhyme::new(*chars).ok());
+
+    let normal = initials.clone()
+        .filter(move |initial| {
+            let prefix = initial_spelling(initial);
+            let len = prefix.chars().count();
+            input.len() >= len && input[..len].iter().cloned().eq(prefix.chars())
+        })
+        .flat_map(move |initial| rhymes().map(move |rhyme| (Some(initial), Some(rhyme))));
+    let empty = initials.map(|initial| (Some(initial), None));
+    let zero = rhymes().map(|rhyme| (None, Some(rhyme)));
+
+    // NOTE: 构造音节的代价比较高，每种组合只构造一次，再替换声调
+    normal.chain(empty)
+        .chain(zero)
+        .filter(|(initial, rhyme)| match (initial, rhyme) {
+            (Some(initial), Some(rhyme)) => NormalSyllable::is_legal(*initial, *rhyme),
+            _ => true,
+        })
+        .filter_map(move |(initial, rhyme)| {
+            tones.iter().find_map(|tone| Syllable::from_parts(initial, rhyme, *tone).ok())
+        })
+        .flat_map(move |syllable| tones.iter().filter_map(move |tone| syllable.with_tone(*tone).ok()))
+}
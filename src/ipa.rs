@@ -0,0 +1,179 @@
+use initial::Initial;
+use syllable::{ Syllable, SyllableKind };
+use format::{ write_tone_letters, CHAO_TONE_LETTERS };
+
+use core::fmt;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use format::write_to_string;
+
+// 国际音标 ( 宽式 ) 以及 X-SAMPA:
+//     https://en.wikipedia.org/wiki/Help:IPA/Mandarin
+//     https://en.wikipedia.org/wiki/X-SAMPA
+//
+// 韵母按照韵头、韵腹、韵尾三部分转写 ( tɕʰ + j ɛ n )，
+// `zi`、`ci`、`si` 的空韵为舌尖前元音 ɹ̩，`zhi`、`chi`、`shi`、`ri` 的空韵为舌尖后元音 ɻ̩，
+// 鼻音音节写作成音节的 m̩、n̩。
+//
+// 声调按照 `Tone::value` 的调值使用五度标调符号写在音节后面，平调只写一个符号 ( ʈʂʊŋ˥, tɕʰy˧˥, sɹ̩˥˩ )，
+// X-SAMPA 使用 `_B`、`_L`、`_M`、`_H`、`_T` 表示调值 1 至 5 ( ts`UN_T, ts\_hy_M_T )，轻声不标。
+
+
+// 一种音标的转写规则
+struct Notation {
+    // 声母 ( 顺序与 `INITIAL_TABLE` 相同 )
+    initials: [&'static str; 21],
+    // 韵头、韵腹、韵尾 ( 顺序与 `RHYME_TABLE` 相同 )
+    rhymes: [(&'static str, &'static str, &'static str); 37],
+    // 唇音声母 b、p、m、f 后面的 `o` 带有韵头 u ( pwo, mwo )
+    labial_medial: &'static str,
+    // 舌尖前元音、舌尖后元音
+    apical_vowels: (&'static str, &'static str),
+    // 成音节符号
+    syllabic: &'static str,
+    // 调值 1 至 5 的标调符号
+    tone_letters: [&'static str; 5],
+}
+
+// b, c, ch, d, f, g, h, j, k, l, m, n, p, q, r, s, sh, t, x, z, zh
+const IPA: Notation = Notation {
+    initials: [
+        "p", "tsʰ", "ʈʂʰ", "t", "f", "k",
+        "x", "tɕ", "kʰ", "l", "m", "n",
+        "pʰ", "tɕʰ", "ʐ", "s", "ʂ", "tʰ",
+        "ɕ", "ts", "ʈʂ",
+    ],
+    rhymes: [
+        ("", "a", ""), ("", "o", ""), ("", "ɤ", ""), ("", "ɛ", ""), ("", "ɚ", ""),
+        ("", "a", "ɪ"), ("", "e", "ɪ"), ("", "ɑ", "ʊ"), ("", "o", "ʊ"),
+        ("", "a", "n"), ("", "ə", "n"), ("", "ɑ", "ŋ"), ("", "ɤ", "ŋ"), ("", "ʊ", "ŋ"),
+        ("", "i", ""), ("j", "a", ""), ("j", "ɛ", ""), ("j", "ɑ", "ʊ"), ("j", "o", "ʊ"),
+        ("j", "ɛ", "n"), ("", "i", "n"), ("j", "ɑ", "ŋ"), ("", "i", "ŋ"), ("j", "ʊ", "ŋ"),
+        ("", "u", ""), ("w", "a", ""), ("w", "o", ""), ("w", "a", "ɪ"), ("w", "e", "ɪ"),
+        ("w", "a", "n"), ("w", "ə", "n"), ("w", "ɑ", "ŋ"), ("w", "ɤ", "ŋ"),
+        ("", "y", ""), ("ɥ", "ɛ", ""), ("ɥ", "ɛ", "n"), ("", "y", "n"),
+    ],
+    labial_medial: "w",
+    apical_vowels: ("ɹ̩", "ɻ̩"),
+    syllabic: "\u{329}",
+    tone_letters: CHAO_TONE_LETTERS,
+};
+
+// b, c, ch, d, f, g, h, j, k, l, m, n, p, q, r, s, sh, t, x, z, zh
+const X_SAMPA: Notation = Notation {
+    initials: [
+        "p", "ts_h", "ts`_h", "t", "f", "k",
+        "x", "ts\\", "k_h", "l", "m", "n",
+        "p_h", "ts\\_h", "z`", "s", "s`", "t_h",
+        "s\\", "ts", "ts`",
+    ],
+    rhymes: [
+        ("", "a", ""), ("", "o", ""), ("", "7", ""), ("", "E", ""), ("", "@`", ""),
+        ("", "a", "I"), ("", "e", "I"), ("", "A", "U"), ("", "o", "U"),
+        ("", "a", "n"), ("", "@", "n"), ("", "A", "N"), ("", "7", "N"), ("", "U", "N"),
+        ("", "i", ""), ("j", "a", ""), ("j", "E", ""), ("j", "A", "U"), ("j", "o", "U"),
+        ("j", "E", "n"), ("", "i", "n"), ("j", "A", "N"), ("", "i", "N"), ("j", "U", "N"),
+        ("", "u", ""), ("w", "a", ""), ("w", "o", ""), ("w", "a", "I"), ("w", "e", "I"),
+        ("w", "a", "n"), ("w", "@", "n"), ("w", "A", "N"), ("w", "7", "N"),
+        ("", "y", ""), ("H", "E", ""), ("H", "E", "n"), ("", "y", "n"),
+    ],
+    labial_medial: "w",
+    apical_vowels: ("r\\=", "r\\`="),
+    syllabic: "=",
+    tone_letters: ["_B", "_L", "_M", "_H", "_T"],
+};
+
+
+fn write_transcription(w: &mut dyn fmt::Write, syllable: &Syllable, notation: &Notation) -> fmt::Result {
+    let (initial, rhyme) = syllable.parts();
+
+    if let Some(initial) = initial {
+        w.write_str(notation.initials[initial.index()])?;
+    }
+
+    match (initial, rhyme) {
+        (_, Some(rhyme)) => {
+            let (medial, nucleus, coda) = notation.rhymes[rhyme.index()];
+            let is_labial = initial.is_some_and(|initial| matches!(initial.letters()[..], ['b'] | ['p'] | ['m'] | ['f']));
+            let medial = if is_labial && rhyme.letters().eq_str("o") { notation.labial_medial } else { medial };
+
+            w.write_str(medial)?;
+            w.write_str(nucleus)?;
+            w.write_str(coda)?;
+        },
+        (Some(_), None) if syllable.kind() == SyllableKind::Nasal => w.write_str(notation.syllabic)?,
+        (Some(initial), None) => {
+            let (front, back) = notation.apical_vowels;
+
+            match initial.letters()[..] {
+                [_, 'h'] | ['r'] => w.write_str(back)?,
+                _ => w.write_str(front)?,
+            }
+        },
+        (None, None) => return Err(fmt::Error),
+    }
+
+    write_tone_letters(w, syllable.tone().value(), &notation.tone_letters)
+}
+
+
+impl Initial {
+    /// 国际音标 ( p, ʈʂʰ, tɕ )
+    pub fn ipa(&self) -> &'static str {
+        IPA.initials[self.index()]
+    }
+
+    /// X-SAMPA ( p, ts`_h, ts\ )
+    pub fn x_sampa(&self) -> &'static str {
+        X_SAMPA.initials[self.index()]
+    }
+}
+
+impl Syllable {
+    /// 写入宽式国际音标 ( ʈʂʊŋ˥, tɕʰy˧˥, sɹ̩˥˩ )
+    pub fn ipa_to(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        write_transcription(w, self, &IPA)
+    }
+
+    /// 输出宽式国际音标
+    #[cfg(feature = "alloc")]
+    pub fn ipa(&self) -> String {
+        write_to_string(|w| self.ipa_to(w))
+    }
+
+    /// 写入 X-SAMPA 形式 ( ts`UN_T, ts\_hy_M_T, sr\=_T_B )
+    pub fn x_sampa_to(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        write_transcription(w, self, &X_SAMPA)
+    }
+
+    /// 输出 X-SAMPA 形式
+    #[cfg(feature = "alloc")]
+    pub fn x_sampa(&self) -> String {
+        write_to_string(|w| self.x_sampa_to(w))
+    }
+}
+
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use tone::{ ToneFormat, TONE_TABLE };
+    use syllable::Syllable;
+    use format::Show;
+    use inventory::syllables;
+
+    #[test]
+    fn tone_letters() {
+        // `ToneFormat::ToneLetter` 与国际音标使用相同的标调符号
+        for syllable in syllables(&TONE_TABLE) {
+            let shown = syllable.show(ToneFormat::ToneLetter);
+            let letters = shown.trim_start_matches(|c: char| c.is_ascii_lowercase() || c == 'ü' || c == 'ê');
+
+            assert!(syllable.ipa().ends_with(letters), "{} {}", syllable.ipa(), shown);
+        }
+
+        let zhong: Syllable = "zhōng".parse().unwrap();
+        assert_eq!(zhong.show(ToneFormat::ToneLetter), "zhong˥");
+        assert_eq!(zhong.ipa(), "ʈʂʊŋ˥");
+    }
+}
@@ -0,0 +1,89 @@
+use core::fmt;
+use core::ops::Deref;
+
+
+// 单个音节最多包含的字母数量 ( `zhuang` 以及带声调数字的 `zhuang1` 等 )
+const LETTERS_CAPACITY: usize = 8;
+
+
+// 定长的字母缓冲区，用于在不分配堆内存的情况下拼写和解析音节
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub(crate) struct Letters {
+    chars: [char; LETTERS_CAPACITY],
+    len: usize,
+}
+
+impl Letters {
+    pub(crate) fn new() -> Self {
+        Letters { chars: [' '; LETTERS_CAPACITY], len: 0 }
+    }
+
+    // 追加一个字母，超出容量的时候返回 `false`
+    pub(crate) fn push(&mut self, c: char) -> bool {
+        if self.len >= LETTERS_CAPACITY {
+            return false;
+        }
+
+        self.chars[self.len] = c;
+        self.len += 1;
+        true
+    }
+
+    pub(crate) fn from_chars<I: IntoIterator<Item = char>>(chars: I) -> Option<Self> {
+        let mut letters = Letters::new();
+
+        for c in chars {
+            if !letters.push(c) {
+                return None;
+            }
+        }
+
+        Some(letters)
+    }
+
+    // 转换为 `[char; 4]` 形式的表格记录 ( 不足的部分使用空格填充 )
+    pub(crate) fn to_record(self) -> Option<[char; 4]> {
+        let mut record = [' '; 4];
+
+        if self.len > record.len() {
+            return None;
+        }
+
+        record[..self.len].copy_from_slice(&self);
+        Some(record)
+    }
+
+    pub(crate) fn eq_str(&self, s: &str) -> bool {
+        self.iter().cloned().eq(s.chars())
+    }
+}
+
+impl Deref for Letters {
+    type Target = [char];
+
+    fn deref(&self) -> &[char] {
+        &self.chars[..self.len]
+    }
+}
+
+impl fmt::Write for Letters {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if !self.push(c) {
+                return Err(fmt::Error);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Letters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for c in self.iter() {
+            fmt::Write::write_char(f, *c)?;
+        }
+
+        Ok(())
+    }
+}
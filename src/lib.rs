@@ -1,4 +1,16 @@
-#![feature(pattern, try_from)]
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "alloc")]
+#[macro_use]
+extern crate alloc;
+#[cfg(feature = "serde")]
+extern crate serde as serde_crate;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+#[cfg(all(test, feature = "serde"))]
+extern crate bincode;
 
 
 mod tone;
@@ -6,9 +18,30 @@ mod initial;
 mod rhyme;
 mod syllable;
 mod error;
+mod letters;
 mod format;
+mod hanzi;
+#[cfg(feature = "alloc")]
+mod phrase;
+#[cfg(feature = "alloc")]
+mod text;
+#[cfg(feature = "alloc")]
+mod segment;
+mod inventory;
+mod id;
+mod zhuyin;
+mod wade_giles;
+mod romatzyh;
+mod romanization;
+mod yale;
+mod tongyong;
+mod mps2;
+mod ipa;
+mod palladius;
+#[cfg(feature = "serde")]
+pub mod serde;
 
-pub use tone::{ Tone, ToneMark, ToneFormat };
+pub use tone::{ Tone, ToneMark, ToneFormat, TONE_TABLE };
 pub use initial::Initial;
 pub use rhyme::Rhyme;
 pub use syllable::{
@@ -16,25 +49,35 @@ pub use syllable::{
     PrimitiveSyllable, NormalSyllable, RhymeSyllable, NasalSyllable,
     from_str,
 };
-pub use error::Error;
+pub use error::{ Error, ErrorKind, ErrorText };
+pub use format::{ Show, join_to, join_surface_to };
+#[cfg(feature = "alloc")]
+pub use format::{ join, join_surface, convert_tone_format };
+#[cfg(feature = "alloc")]
+pub use segment::split;
+pub use inventory::syllables;
+pub use id::SYLLABLE_ID_LIMIT;
+#[cfg(feature = "alloc")]
+pub use text::{ Span, Fallback, to_pinyin, annotate };
+
+use hanzi::PINYIN_MAP;
 
-use std::str::Split;
-use std::str::pattern::Pattern;
+use core::str::Split;
 
 
 pub const SYLLABLE_DIVIDING_MARK: char = '\'';
 
 
 #[doc(hidden)]
-pub struct PinYinIter<'a, P: Pattern<'a>> {
-    inner: Split<'a, P>,
+pub struct PinYinIter<'a> {
+    inner: Split<'a, char>,
 }
 
-impl<'a, P> Iterator for  PinYinIter<'a, P> where P: Pattern<'a> {
-    type Item = Box<Syllable>;
+impl<'a> Iterator for PinYinIter<'a> {
+    type Item = Syllable;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.inner.next().map(|s| -> Box<Syllable> {
+        self.inner.next().map(|s| {
             syllable::from_str(s).expect("数据库记录有误！")
         })
     }
@@ -49,13 +92,12 @@ pub trait PinYin<'a> {
 }
 
 impl<'a> PinYin<'a> for char {
-    type Item = PinYinIter<'a, char>;
+    type Item = PinYinIter<'a>;
     type Error = ();
 
     fn pinyin(&self) -> Result<Self::Item, Self::Error> {
-        // PINYIN_MAP.binary_search_by_key(self, |&(k, _)| k)
-        //     .map(|index| PinYinIter { inner: PINYIN_MAP[index].1.split(',') })
-        //     .map_err(|_| () )
-        unimplemented!()
+        PINYIN_MAP.binary_search_by_key(self, |&(k, _)| k)
+            .map(|index| PinYinIter { inner: PINYIN_MAP[index].1.split(',') })
+            .map_err(|_| () )
     }
 }
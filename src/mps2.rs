@@ -0,0 +1,74 @@
+use tone::ToneFormat;
+use initial::Initial;
+use syllable::Syllable;
+use error::Error;
+use romanization::{ Scheme, table_finals };
+
+use core::fmt;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use format::write_to_string;
+
+// 国语注音符号第二式:
+//     https://zh.wikipedia.org/wiki/%E5%9C%8B%E8%AA%9E%E6%B3%A8%E9%9F%B3%E7%AC%A6%E8%99%9F%E7%AC%AC%E4%BA%8C%E5%BC%8F
+//
+// 与汉语拼音的主要区别: q、x、zh、z、c 写作 ch、sh、j、tz、ts，
+// 空韵音节写作 jr、chr、shr、r、tz、tsz、sz，ü 写作 iu ( jiu, shiue )，ong、iong 写作 ung、iung，
+// ao、iao 写作 au、iau，iou、uei、uen 不省略 ( jiou, guei, duen )。
+
+
+// b, c, ch, d, f, g, h, j, k, l, m, n, p, q, r, s, sh, t, x, z, zh
+const MPS2: Scheme = Scheme {
+    initials: [
+        "b", "ts", "ch", "d", "f", "g",
+        "h", "j", "k", "l", "m", "n",
+        "p", "ch", "r", "s", "sh", "t",
+        "sh", "tz", "j",
+    ],
+    rhymes: [
+        "a", "o", "e", "e", "er", "ai", "ei", "au", "ou", "an", "en", "ang", "eng", "ung",
+        "i", "ia", "ie", "iau", "iou", "ian", "in", "iang", "ing", "iung",
+        "u", "ua", "uo", "uai", "uei", "uan", "uen", "uang", "ueng",
+        "iu", "iue", "iuan", "iun",
+    ],
+    zero_initial_rhymes: [
+        "a", "o", "e", "e", "er", "ai", "ei", "au", "ou", "an", "en", "ang", "eng", "",
+        "yi", "ya", "ye", "yau", "you", "yan", "yin", "yang", "ying", "yung",
+        "wu", "wa", "wo", "wai", "wei", "wan", "wen", "wang", "weng",
+        "yu", "yue", "yuan", "yun",
+    ],
+    // zhi, chi, shi 写作 jr, chr, shr；ci, si 写作 tsz, sz；zi, ri 只写声母 tz, r
+    empty_rhymes: ["r", "r", "r", "", "", "z", "z"],
+    finals: table_finals,
+    preferred: &[],
+};
+
+
+
+impl Initial {
+    /// 注音二式 ( ch, sh, tz )
+    pub fn mps2(&self) -> &'static str {
+        MPS2.initial(self)
+    }
+}
+
+impl Syllable {
+    /// 按照指定的音调标记方式写入注音二式形式 ( jūng, jung1, tsź, shiué )
+    ///
+    /// 这种拼音方案不以拼写区分声调，`ToneFormat::GwoyeuRomatzyh` 只写入不带声调的拼写 ( jung )，声调会丢失。
+    pub fn mps2_to(&self, w: &mut dyn fmt::Write, tone_format: ToneFormat) -> fmt::Result {
+        MPS2.write_toned(w, self, tone_format)
+    }
+
+    /// 按照指定的音调标记方式输出注音二式形式
+    #[cfg(feature = "alloc")]
+    pub fn mps2(&self, tone_format: ToneFormat) -> String {
+        write_to_string(|w| self.mps2_to(w, tone_format))
+    }
+
+    /// 解析注音二式形式的音节，支持调号以及数字 ( jūng, Jung1, tsz2 )，以及 NFC 形式的合成字母 ( tsź, jř )
+    pub fn from_mps2(s: &str) -> Result<Syllable, Error> {
+        MPS2.parse_toned(s)
+    }
+}
@@ -0,0 +1,136 @@
+use tone::Tone;
+use initial::Initial;
+use syllable::Syllable;
+use error::Error;
+use letters::Letters;
+use romanization::{ Scheme, table_finals };
+
+use core::fmt;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use format::write_to_string;
+
+// 巴拉第 ( 鲍乃迪 ) 系统:
+//     https://zh.wikipedia.org/wiki/%E5%B7%B4%E6%8B%89%E7%AC%AC%E7%B3%BB%E7%BB%9F
+//
+// 不标声调 ( чжун, цин, сюэ, жэнь )，`zi`、`ci`、`si` 写作 цзы、цы、сы，
+// `zhi`、`chi`、`shi`、`ri` 写作 чжи、чи、ши、жи，鼻音音节只写声母。
+
+
+// b, c, ch, d, f, g, h, j, k, l, m, n, p, q, r, s, sh, t, x, z, zh
+const PALLADIUS: Scheme = Scheme {
+    initials: [
+        "б", "ц", "ч", "д", "ф", "г",
+        "х", "цз", "к", "л", "м", "н",
+        "п", "ц", "ж", "с", "ш", "т",
+        "с", "цз", "чж",
+    ],
+    rhymes: [
+        "а", "о", "э", "э", "эр", "ай", "эй", "ао", "оу", "ань", "энь", "ан", "эн", "ун",
+        "и", "я", "е", "яо", "ю", "янь", "инь", "ян", "ин", "юн",
+        "у", "уа", "о", "уай", "уй", "уань", "унь", "уан", "ун",
+        "юй", "юэ", "юань", "юнь",
+    ],
+    zero_initial_rhymes: [
+        "а", "о", "э", "э", "эр", "ай", "эй", "ао", "оу", "ань", "энь", "ан", "эн", "",
+        "и", "я", "е", "яо", "ю", "янь", "инь", "ян", "ин", "юн",
+        "у", "ва", "во", "вай", "вэй", "вань", "вэнь", "ван", "вэн",
+        "юй", "юэ", "юань", "юнь",
+    ],
+    // zhi, chi, shi, ri 写作 чжи, чи, ши, жи；zi, ci, si 写作 цзы, цы, сы
+    empty_rhymes: ["и", "и", "и", "и", "ы", "ы", "ы"],
+    finals: table_finals,
+    // `ло` 落/咯
+    preferred: &[("ло", "luo")],
+};
+
+
+impl Initial {
+    /// 巴拉第系统 ( б, цз, чж )
+    pub fn palladius(&self) -> &'static str {
+        PALLADIUS.initial(self)
+    }
+}
+
+impl Syllable {
+    /// 写入巴拉第系统的西里尔字母形式，不带声调 ( чжун, цин, сюэ, жэнь )
+    pub fn palladius_to(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        PALLADIUS.write_spelling(w, self)
+    }
+
+    /// 输出巴拉第系统的西里尔字母形式
+    #[cfg(feature = "alloc")]
+    pub fn palladius(&self) -> String {
+        write_to_string(|w| self.palladius_to(w))
+    }
+
+    /// 解析巴拉第系统形式的音节 ( чжун, Цин, сюэ )
+    ///
+    /// 巴拉第系统不标声调，解析得到的音节为轻声。
+    /// 鼻音音节没有轻声的形式，不会被解析。
+    pub fn from_palladius(s: &str) -> Result<Syllable, Error> {
+        let input = Letters::from_chars(s.chars().map(|c| c.to_lowercase().next().unwrap_or(c)))
+                        .unwrap_or_else(Letters::new);
+
+        PALLADIUS.parse(s, &input, Tone::Neutral)
+    }
+}
+
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use syllable::Syllable;
+    use tone::Tone;
+    use inventory::syllables;
+
+    fn pinyin(s: &str) -> Syllable {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        // NOTE: 巴拉第系统的拼写相同的音节比较多 ( 例如 `ун` 可以是 `ong` 或者 `ueng` )，这里只比较拼写。
+        for syllable in syllables(&[Tone::Neutral]) {
+            let spelling = syllable.palladius();
+            let parsed = Syllable::from_palladius(&spelling).unwrap();
+
+            assert_eq!(parsed.palladius(), spelling, "{}", syllable);
+        }
+    }
+
+    #[test]
+    fn spellings() {
+        let cases = [
+            ("zhong", "чжун"), ("qing", "цин"), ("xue", "сюэ"), ("ren", "жэнь"), ("zi", "цзы"),
+            ("shi", "ши"), ("wen", "вэнь"), ("yu", "юй"),
+        ];
+
+        for (pinyin_spelling, palladius) in cases.iter() {
+            assert_eq!(pinyin(pinyin_spelling).palladius(), *palladius);
+            assert_eq!(Syllable::from_palladius(palladius), Ok(pinyin(pinyin_spelling)), "{}", palladius);
+        }
+
+        assert_eq!(Syllable::from_palladius("Цин"), Ok(pinyin("qing")));
+    }
+
+    #[test]
+    fn merged() {
+        // `ло` 可以是 `luo` 或者 `lo` ( 咯 )，取常用的 `luo`
+        assert_eq!(pinyin("lo").palladius(), "ло");
+        assert_eq!(Syllable::from_palladius("ло"), Ok(pinyin("luo")));
+
+        // `ê` 和 `e` 都写作 `э`，按照 `e` 解析
+        assert_eq!(pinyin("ê").palladius(), "э");
+        assert_eq!(Syllable::from_palladius("э"), Ok(pinyin("e")));
+    }
+
+    #[test]
+    fn nasal() {
+        // 鼻音音节只写声母，但是没有轻声的形式，不会被解析
+        assert_eq!(pinyin("ḿ").palladius(), "м");
+        assert_eq!(pinyin("ň").palladius(), "н");
+        assert!(Syllable::from_palladius("м").is_err());
+        assert!(Syllable::from_palladius("н").is_err());
+    }
+}
@@ -0,0 +1,361 @@
+// 词组读音表
+//
+// 用于多音字的消歧，读音之间以空格分隔，并且与词组当中的汉字一一对应。
+//
+// NOTE: 该表按照字符串排序，以便使用二分查找。
+pub static PHRASE_MAP: [(&str, &str); 279] = [
+    ("一切", "yī qiè"),
+    ("一只", "yī zhī"),
+    ("一年半载", "yī nián bàn zǎi"),
+    ("一行", "yī háng"),
+    ("上当", "shàng dàng"),
+    ("上海", "shàng hǎi"),
+    ("不行", "bù xíng"),
+    ("东西", "dōng xi"),
+    ("严重", "yán zhòng"),
+    ("中国", "zhōng guó"),
+    ("中奖", "zhòng jiǎng"),
+    ("中毒", "zhòng dú"),
+    ("中间", "zhōng jiān"),
+    ("为了", "wèi le"),
+    ("为什么", "wèi shén me"),
+    ("主角", "zhǔ jué"),
+    ("乐器", "yuè qì"),
+    ("乐意", "lè yì"),
+    ("乐曲", "yuè qǔ"),
+    ("乐观", "lè guān"),
+    ("乐队", "yuè duì"),
+    ("了不起", "liǎo bù qǐ"),
+    ("了解", "liǎo jiě"),
+    ("事情", "shì qing"),
+    ("互相", "hù xiāng"),
+    ("人参", "rén shēn"),
+    ("什么", "shén me"),
+    ("他们", "tā men"),
+    ("休息", "xiū xi"),
+    ("会计", "kuài jì"),
+    ("传记", "zhuàn jì"),
+    ("似的", "shì de"),
+    ("作为", "zuò wéi"),
+    ("你们", "nǐ men"),
+    ("供给", "gōng jǐ"),
+    ("便宜", "pián yi"),
+    ("倒车", "dào chē"),
+    ("倒霉", "dǎo méi"),
+    ("倔强", "jué jiàng"),
+    ("假如", "jiǎ rú"),
+    ("假期", "jià qī"),
+    ("先生", "xiān sheng"),
+    ("关系", "guān xì"),
+    ("兴趣", "xìng qù"),
+    ("内行", "nèi háng"),
+    ("出差", "chū chāi"),
+    ("分子", "fēn zǐ"),
+    ("分散", "fēn sàn"),
+    ("切实", "qiè shí"),
+    ("到处", "dào chù"),
+    ("勉强", "miǎn qiǎng"),
+    ("北京", "běi jīng"),
+    ("午觉", "wǔ jiào"),
+    ("占卜", "zhān bǔ"),
+    ("厦门", "xià mén"),
+    ("参加", "cān jiā"),
+    ("参差", "cēn cī"),
+    ("反省", "fǎn xǐng"),
+    ("发行", "fā xíng"),
+    ("取得", "qǔ dé"),
+    ("变更", "biàn gēng"),
+    ("口供", "kǒu gòng"),
+    ("只是", "zhǐ shì"),
+    ("只有", "zhǐ yǒu"),
+    ("号召", "hào zhào"),
+    ("同行", "tóng háng"),
+    ("告诉", "gào su"),
+    ("和平", "hé píng"),
+    ("哥哥", "gē ge"),
+    ("商量", "shāng liang"),
+    ("喜欢", "xǐ huan"),
+    ("因为", "yīn wèi"),
+    ("地方", "dì fang"),
+    ("增长", "zēng zhǎng"),
+    ("声调", "shēng diào"),
+    ("处理", "chǔ lǐ"),
+    ("外行", "wài háng"),
+    ("多少", "duō shao"),
+    ("大夫", "dài fu"),
+    ("天安门", "tiān ān mén"),
+    ("头发", "tóu fa"),
+    ("好处", "hǎo chù"),
+    ("好奇", "hào qí"),
+    ("好客", "hào kè"),
+    ("妈妈", "mā ma"),
+    ("妹妹", "mèi mei"),
+    ("姐姐", "jiě jie"),
+    ("子弹", "zǐ dàn"),
+    ("孩子", "hái zi"),
+    ("宁可", "nìng kě"),
+    ("宝藏", "bǎo zàng"),
+    ("宰相", "zǎi xiàng"),
+    ("家长", "jiā zhǎng"),
+    ("宿舍", "sù shè"),
+    ("寒假", "hán jià"),
+    ("导弹", "dǎo dàn"),
+    ("将军", "jiāng jūn"),
+    ("少女", "shào nǚ"),
+    ("少年", "shào nián"),
+    ("尽管", "jǐn guǎn"),
+    ("尽量", "jǐn liàng"),
+    ("差不多", "chà bu duō"),
+    ("差别", "chā bié"),
+    ("市长", "shì zhǎng"),
+    ("干净", "gān jìng"),
+    ("干活", "gàn huó"),
+    ("干燥", "gān zào"),
+    ("干部", "gàn bù"),
+    ("应该", "yīng gāi"),
+    ("开会", "kāi huì"),
+    ("弟弟", "dì di"),
+    ("弹琴", "tán qín"),
+    ("归还", "guī huán"),
+    ("当作", "dàng zuò"),
+    ("当然", "dāng rán"),
+    ("得到", "dé dào"),
+    ("必得", "bì děi"),
+    ("快乐", "kuài lè"),
+    ("怎么", "zěn me"),
+    ("意思", "yì si"),
+    ("感觉", "gǎn jué"),
+    ("成为", "chéng wéi"),
+    ("成分", "chéng fèn"),
+    ("成都", "chéng dū"),
+    ("成长", "chéng zhǎng"),
+    ("我们", "wǒ men"),
+    ("房间", "fáng jiān"),
+    ("挑战", "tiǎo zhàn"),
+    ("挣扎", "zhēng zhá"),
+    ("提供", "tí gōng"),
+    ("提防", "dī fang"),
+    ("放假", "fàng jià"),
+    ("效率", "xiào lǜ"),
+    ("教书", "jiāo shū"),
+    ("教育", "jiào yù"),
+    ("散文", "sǎn wén"),
+    ("数一数", "shǔ yi shǔ"),
+    ("数字", "shù zì"),
+    ("数学", "shù xué"),
+    ("数量", "shù liàng"),
+    ("方便", "fāng biàn"),
+    ("旅行", "lǚ xíng"),
+    ("时候", "shí hou"),
+    ("时间", "shí jiān"),
+    ("明白", "míng bai"),
+    ("暖和", "nuǎn huo"),
+    ("更加", "gèng jiā"),
+    ("更新", "gēng xīn"),
+    ("曾经", "céng jīng"),
+    ("有空", "yǒu kòng"),
+    ("朋友", "péng you"),
+    ("朝代", "cháo dài"),
+    ("朝气", "zhāo qì"),
+    ("朝阳", "zhāo yáng"),
+    ("朝鲜", "cháo xiǎn"),
+    ("校长", "xiào zhǎng"),
+    ("桌子", "zhuō zi"),
+    ("椅子", "yǐ zi"),
+    ("歌曲", "gē qǔ"),
+    ("正月", "zhēng yuè"),
+    ("没有", "méi yǒu"),
+    ("流血", "liú xiě"),
+    ("测量", "cè liáng"),
+    ("涨价", "zhǎng jià"),
+    ("淹没", "yān mò"),
+    ("清楚", "qīng chu"),
+    ("漂亮", "piào liang"),
+    ("灾难", "zāi nàn"),
+    ("炸弹", "zhà dàn"),
+    ("照相", "zhào xiàng"),
+    ("爱好", "ài hào"),
+    ("爸爸", "bà ba"),
+    ("率领", "shuài lǐng"),
+    ("班长", "bān zhǎng"),
+    ("理发", "lǐ fà"),
+    ("生长", "shēng zhǎng"),
+    ("的士", "dí shì"),
+    ("的确", "dí què"),
+    ("目的", "mù dì"),
+    ("相信", "xiāng xìn"),
+    ("相声", "xiàng sheng"),
+    ("相处", "xiāng chǔ"),
+    ("省份", "shěng fèn"),
+    ("看中", "kàn zhòng"),
+    ("看守", "kān shǒu"),
+    ("着急", "zháo jí"),
+    ("着想", "zhuó xiǎng"),
+    ("着手", "zhuó shǒu"),
+    ("着火", "zháo huǒ"),
+    ("着重", "zhuó zhòng"),
+    ("睡着", "shuì zháo"),
+    ("睡觉", "shuì jiào"),
+    ("知道", "zhī dao"),
+    ("种地", "zhòng dì"),
+    ("种子", "zhǒng zi"),
+    ("种植", "zhòng zhí"),
+    ("种类", "zhǒng lèi"),
+    ("积累", "jī lěi"),
+    ("空儿", "kòng er"),
+    ("空白", "kòng bái"),
+    ("空调", "kōng tiáo"),
+    ("穿着", "chuān zhuó"),
+    ("答应", "dā ying"),
+    ("系鞋带", "jì xié dài"),
+    ("累计", "lěi jì"),
+    ("结实", "jiē shi"),
+    ("结果", "jié guǒ"),
+    ("给予", "jǐ yǔ"),
+    ("舒服", "shū fu"),
+    ("船只", "chuán zhī"),
+    ("获得", "huò dé"),
+    ("落下", "là xià"),
+    ("血液", "xuè yè"),
+    ("行业", "háng yè"),
+    ("行为", "xíng wéi"),
+    ("行人", "xíng rén"),
+    ("行列", "háng liè"),
+    ("行家", "háng jiā"),
+    ("行情", "háng qíng"),
+    ("行走", "xíng zǒu"),
+    ("行长", "háng zhǎng"),
+    ("衣服", "yī fu"),
+    ("衣着", "yī zhuó"),
+    ("西安", "xī ān"),
+    ("西藏", "xī zàng"),
+    ("要求", "yāo qiú"),
+    ("觉得", "jué de"),
+    ("觉悟", "jué wù"),
+    ("角色", "jué sè"),
+    ("认为", "rèn wéi"),
+    ("认识", "rèn shi"),
+    ("记得", "jì de"),
+    ("记载", "jì zǎi"),
+    ("调整", "tiáo zhěng"),
+    ("调查", "diào chá"),
+    ("调节", "tiáo jié"),
+    ("身份", "shēn fèn"),
+    ("转动", "zhuàn dòng"),
+    ("转身", "zhuǎn shēn"),
+    ("还原", "huán yuán"),
+    ("还是", "hái shì"),
+    ("还有", "hái yǒu"),
+    ("这么", "zhè me"),
+    ("进行", "jìn xíng"),
+    ("道行", "dào héng"),
+    ("那么", "nà me"),
+    ("部分", "bù fen"),
+    ("部长", "bù zhǎng"),
+    ("都会", "dū huì"),
+    ("都市", "dū shì"),
+    ("重叠", "chóng dié"),
+    ("重复", "chóng fù"),
+    ("重庆", "chóng qìng"),
+    ("重新", "chóng xīn"),
+    ("重点", "zhòng diǎn"),
+    ("重要", "zhòng yào"),
+    ("重视", "zhòng shì"),
+    ("重量", "zhòng liàng"),
+    ("重阳", "chóng yáng"),
+    ("钻石", "zuàn shí"),
+    ("银行", "yín háng"),
+    ("长城", "cháng chéng"),
+    ("长大", "zhǎng dà"),
+    ("长官", "zhǎng guān"),
+    ("长度", "cháng dù"),
+    ("长期", "cháng qī"),
+    ("长江", "cháng jiāng"),
+    ("长短", "cháng duǎn"),
+    ("长辈", "zhǎng bèi"),
+    ("间接", "jiàn jiē"),
+    ("间隔", "jiàn gé"),
+    ("队长", "duì zhǎng"),
+    ("随便", "suí biàn"),
+    ("难民", "nàn mín"),
+    ("需要", "xū yào"),
+    ("音乐", "yīn yuè"),
+    ("饼干", "bǐng gān"),
+    ("首都", "shǒu dū"),
+    ("高兴", "gāo xìng"),
+];
+
+// 词组表当中最长词组的字数
+pub const PHRASE_MAX_LEN: usize = 4;
+
+
+#[cfg(test)]
+mod tests {
+    use super::{ PHRASE_MAP, PHRASE_MAX_LEN };
+    use hanzi::PINYIN_MAP;
+    use syllable::{ self, Syllable };
+    use tone::{ Tone, ToneFormat };
+    use format::Show;
+    use text::to_pinyin;
+
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    // 汉字在 `PINYIN_MAP` 当中的读音
+    fn readings(c: char) -> Option<impl Iterator<Item = Syllable>> {
+        PINYIN_MAP.binary_search_by_key(&c, |&(k, _)| k)
+            .ok()
+            .map(|index| PINYIN_MAP[index].1.split(',').map(|s| syllable::from_str(s).unwrap()))
+    }
+
+    #[test]
+    fn table_is_sorted() {
+        assert!(PHRASE_MAP.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(PHRASE_MAP.iter().map(|(phrase, _)| phrase.chars().count()).max(), Some(PHRASE_MAX_LEN));
+    }
+
+    #[test]
+    fn readings_match_characters() {
+        for (phrase, pinyin) in PHRASE_MAP.iter() {
+            assert_eq!(phrase.chars().count(), pinyin.split(' ').count(), "{}", phrase);
+
+            for (c, reading) in phrase.chars().zip(pinyin.split(' ')) {
+                let reading = syllable::from_str(reading).unwrap();
+                let mut candidates = readings(c).unwrap_or_else(|| panic!("{}: `{}` 没有读音", phrase, c));
+
+                // NOTE: 词组当中的轻声 ( 东西 dōng xi ) 只要求拼写与某个读音相同
+                let found = candidates.any(|candidate| {
+                    candidate == reading
+                        || (reading.tone() == Tone::Neutral && candidate.parts() == reading.parts())
+                });
+
+                assert!(found, "{}: `{}` 没有读音 {}", phrase, c, reading);
+            }
+        }
+    }
+
+    fn pinyin(text: &str) -> String {
+        to_pinyin(text).iter()
+            .map(|syllable| syllable.map_or(String::from("?"), |syllable| syllable.show(ToneFormat::Symbol)))
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn longest_match() {
+        // 单独的汉字使用第一个读音
+        assert_eq!(pinyin("行"), "xíng");
+        assert_eq!(pinyin("长"), "cháng");
+        assert_eq!(pinyin("重"), "zhòng");
+
+        assert_eq!(pinyin("银行"), "yín háng");
+        assert_eq!(pinyin("行长"), "háng zhǎng");
+        assert_eq!(pinyin("长大"), "zhǎng dà");
+        assert_eq!(pinyin("重庆"), "chóng qìng");
+
+        // 从左到右依次匹配最长的词组
+        assert_eq!(pinyin("银行行长"), "yín háng háng zhǎng");
+        assert_eq!(pinyin("在重庆长大"), "zài chóng qìng zhǎng dà");
+        assert_eq!(pinyin("一年半载"), "yī nián bàn zǎi");
+    }
+}
@@ -1,5 +1,9 @@
-use std::fmt;
-use std::cmp;
+use error::{ Error, ErrorKind, ErrorText };
+use letters::Letters;
+
+use core::fmt;
+use core::cmp;
+use core::str::FromStr;
 
 
 // 对《汉语拼音》方案当中的韵母表勘误:
@@ -13,6 +17,7 @@ use std::cmp;
 
 
 // ["a", "o", "e", "ê", "er", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "ong"]
+#[allow(dead_code)]
 pub const RHYME_TABLE_COLUMN_A : [[char; 4]; 14] = [
     ['a', ' ', ' ', ' '], ['o', ' ', ' ', ' '], ['e', ' ', ' ', ' '],
     ['ê', ' ', ' ', ' '], ['e', 'r', ' ', ' '], ['a', 'i', ' ', ' '],
@@ -84,28 +89,54 @@ pub const COMPOUND_VOWEL_RHYME_TABLE: [[char; 4]; 8] = [
 
 
 /// 韵母
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
-pub struct Rhyme([char; 4]);
+// NOTE: 只保存在韵母表当中的位置，这样音节只占用几个字节。
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct Rhyme(u8);
 
 impl Rhyme {
-    pub fn new(chars: [char; 4]) -> Result<Self, ()> {
-        if RHYME_TABLE.contains(&chars) {
-            Ok(Rhyme(chars))
-        } else {
-            Err(())
+    pub fn new(chars: [char; 4]) -> Result<Self, Error> {
+        match RHYME_TABLE.iter().position(|rhyme| *rhyme == chars) {
+            Some(index) => Ok(Rhyme(index as u8)),
+            None => {
+                let rhyme = Letters::from_chars(chars.iter().cloned().filter(|c| *c != ' '))
+                                .expect("韵母最多只有 4 个字母！");
+                Err(Error::new(ErrorKind::InvalidRhyme(ErrorText::new(rhyme)), rhyme, 0))
+            },
         }
     }
 
+    // 韵母表当中的记录 ( 使用空格填充 )
+    fn record(&self) -> [char; 4] {
+        RHYME_TABLE[self.0 as usize]
+    }
+
+    // 韵母的字母 ( 不包含填充的空格 )
+    pub(crate) fn letters(&self) -> Letters {
+        Letters::from_chars(self.record().iter().cloned().filter(|c| *c != ' '))
+            .expect("韵母最多只有 4 个字母！")
+    }
+
+    // 在韵母表当中的位置
+    pub(crate) fn index(&self) -> usize {
+        self.0 as usize
+    }
+
     // https://zh.wikipedia.org/wiki/%E6%B1%89%E8%AF%AD%E6%8B%BC%E9%9F%B3#%E5%A3%B0%E8%B0%83
     /// 声调标注规则
-    pub fn tone_mark_rule(rhyme: &[char; 4]) -> Result<char, ()> {
+    pub fn tone_mark_rule(rhyme: &[char; 4]) -> Result<char, Error> {
+        let invalid = || {
+            let text = Letters::from_chars(rhyme.iter().cloned().filter(|c| *c != ' '))
+                            .expect("韵母最多只有 4 个字母！");
+            Error::new(ErrorKind::InvalidRhyme(ErrorText::new(text)), text, 0)
+        };
+
         if rhyme.contains(&'a') {
             return Ok('a');
         }
 
         if rhyme.contains(&'o') && rhyme.contains(&'e') {
             // NOTE: 不能同时出现这两个字母
-            return Err(());
+            return Err(invalid());
         }
 
         if rhyme.contains(&'o') {
@@ -114,106 +145,124 @@ impl Rhyme {
         if rhyme.contains(&'e') {
             return Ok('e');
         }
+        if rhyme.contains(&'ê') {
+            return Ok('ê');
+        }
 
-        let search = |c, rhyme: &[char]| -> Result<usize, ()> {
-            for n in 0..rhyme.len() {
-                if &rhyme[n] == c {
-                    return Ok(n);
-                }
-            }
-            Err(())
+        let search = |c: &char, rhyme: &[char]| -> Option<usize> {
+            rhyme.iter().position(|elem| elem == c)
         };
         
         let pos_u2 = search(&'ü', rhyme);
         let pos_i = search(&'i', rhyme);
         let pos_u = search(&'u', rhyme);
 
-        if pos_u2.is_ok() {
-            if pos_i.is_ok() || pos_u.is_ok() {
+        if pos_u2.is_some() {
+            if pos_i.is_some() || pos_u.is_some() {
                 // `ü` 不可能和 `i` 或 `u` 同时出现
-                return Err(());
+                return Err(invalid());
             }
 
             return Ok('ü');
         }
 
-        if pos_u.is_ok() && pos_i.is_ok() {
+        if let (Some(u_index), Some(i_index)) = (pos_u, pos_i) {
             // 如果 `i` 和 `u` 同时出现，则标在第二个韵母上
-            let u_index = pos_u.unwrap();
-            let i_index = pos_i.unwrap();
             let i = cmp::max(u_index, i_index);
             if i == u_index {
                 return Ok('u');
-            } else if i == i_index {
-                return Ok('i');
             } else {
-                unreachable!();
+                return Ok('i');
             }
         }
 
-        if pos_i.is_ok() {
+        if pos_i.is_some() {
             return Ok('i');
         }
 
-        if pos_u.is_ok() {
+        if pos_u.is_some() {
             return Ok('u');
         }
 
         // NOTE: 不合规范的韵母部分
-        return Err(())
+        Err(invalid())
     }
 
     // 带声调的元音字母
     pub fn vowel(&self) -> char {
-        match Rhyme::tone_mark_rule(&self.0) {
+        match Rhyme::tone_mark_rule(&self.record()) {
             Ok(c) => c,
-            Err(_) => {
-                println!("[DEBUG] 在对 `{}` 计算声调位置时，出现未预料的错误。", self);
-                panic!("声调位置计算出现未预料的错误！");
-            }
+            Err(_) => panic!("在对 `{}` 计算声调位置时，出现未预料的错误！", self),
         }
     }
     
     // 判断是否为 单元音韵母
     pub fn is_simple(&self) -> bool {
-        SIMPLE_VOWEL_RHYME_TABLE.contains(&self.0)
+        SIMPLE_VOWEL_RHYME_TABLE.contains(&self.record())
     }
 
     // 判断是否为 复元音韵母
     pub fn is_compound(&self) -> bool {
-        COMPOUND_VOWEL_RHYME_TABLE.contains(&self.0)
+        COMPOUND_VOWEL_RHYME_TABLE.contains(&self.record())
     }
 
     // 判断是否带鼻音韵母
     pub fn is_nasal(&self) -> bool {
         // an, ian, uan, üan, en, uen, in, ün, ang, iang, uang, eng, ing, ueng, ong, iong
+        let record = self.record();
         let mut i = 3usize;
 
-        while self.0[i] != ' ' {
+        while record[i] != ' ' {
             i -= 1;
         }
 
-        let last_char = self.0[i];
+        let last_char = record[i];
 
-        if last_char == 'n' || last_char == 'g' {
-            true
-        } else {
-            false
-        }
+        last_char == 'n' || last_char == 'g'
+    }
+}
+
+// 按照韵母的字母排序
+impl PartialOrd for Rhyme {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rhyme {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.record().cmp(&other.record())
+    }
+}
+
+impl fmt::Debug for Rhyme {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Rhyme(\"{}\")", self)
     }
 }
 
 impl fmt::Display for Rhyme {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for elem in self.0.iter() {
+        for elem in self.record().iter() {
             if elem == &' ' {
                 break;
             }
 
-            write!(f, "{}", elem);
+            write!(f, "{}", elem)?;
         }
 
         Ok(())
     }
 }
 
+impl FromStr for Rhyme {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars = Letters::from_chars(s.chars().map(|c| if c == 'v' { 'ü' } else { c }))
+                        .and_then(|letters| letters.to_record())
+                        .ok_or_else(|| Error::new(ErrorKind::InvalidRhyme(ErrorText::new(s)), s, 0))?;
+
+        Rhyme::new(chars).map_err(|e| e.with_input(s, 0))
+    }
+}
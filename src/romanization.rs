@@ -0,0 +1,228 @@
+use tone::{ Tone, ToneFormat };
+use initial::{ Initial, INITIAL_TABLE };
+use rhyme::{ Rhyme, RHYME_TABLE };
+use syllable::{ Syllable, SyllableKind, split_tone_chars };
+use inventory::inventory_order;
+use error::{ Error, ErrorKind };
+use format::{ write_toned, tone_mark_position };
+use letters::Letters;
+
+use core::fmt;
+use core::iter;
+use core::str::FromStr;
+
+// 按照表格转写的拼音方案 ( 耶鲁拼音、通用拼音、注音二式、巴拉第系统 ):
+//
+// 常规音节写作 声母 + 韵母，零声母音节使用自成音节的韵母表，整体认读音节 zhi chi shi ri zi ci si 写作 声母 + 空韵，
+// 鼻音音节只写声母。与这些规则不同的拼写由各个方案的 `finals` 处理 ( 例如耶鲁拼音的 bwo )。
+//
+// 解析的时候按照声母、韵母的结构拆分拼写，拼写相同的音节取 `syllables` 音节表当中的第一个，或者由 `preferred` 指定。
+
+
+// 一种拼音方案的转写规则
+pub(crate) struct Scheme {
+    // 声母 ( 顺序与 `INITIAL_TABLE` 相同 )
+    pub initials: [&'static str; 21],
+    // 跟在声母后面的韵母 ( 顺序与 `RHYME_TABLE` 相同 )
+    //
+    // NOTE: 这些方案当中 `ê` 和 `e` 的拼写相同，解析的时候按照 `e` 处理。
+    pub rhymes: [&'static str; 37],
+    // 自成音节的韵母 ( 顺序与 `RHYME_TABLE` 相同，`ong` 没有自成音节的形式 )
+    pub zero_initial_rhymes: [&'static str; 37],
+    // zhi, chi, shi, ri, zi, ci, si 的空韵
+    pub empty_rhymes: [&'static str; 7],
+    // 根据声母调整韵母表当中的拼写
+    pub finals: fn(Initial, Rhyme, &'static str) -> &'static str,
+    // 拼写相同的音节，优先选择的那一个 ( 拼写, 汉语拼音 )
+    pub preferred: &'static [(&'static str, &'static str)],
+}
+
+// 不需要调整的韵母
+pub(crate) fn table_finals(_initial: Initial, _rhyme: Rhyme, finals: &'static str) -> &'static str {
+    finals
+}
+
+impl Scheme {
+    pub fn initial(&self, initial: &Initial) -> &'static str {
+        self.initials[initial.index()]
+    }
+
+    // 写入不带声调的拼写
+    pub fn write_spelling(&self, w: &mut dyn fmt::Write, syllable: &Syllable) -> fmt::Result {
+        match syllable.parts() {
+            (Some(initial), Some(rhyme)) => {
+                w.write_str(self.initial(&initial))?;
+                w.write_str((self.finals)(initial, rhyme, self.rhymes[rhyme.index()]))
+            },
+            (Some(initial), None) => {
+                w.write_str(self.initial(&initial))?;
+
+                let index = match &initial.letters()[..] {
+                    _ if syllable.kind() == SyllableKind::Nasal => return Ok(()),
+                    ['z', 'h'] => 0,
+                    ['c', 'h'] => 1,
+                    ['s', 'h'] => 2,
+                    ['r'] => 3,
+                    ['z'] => 4,
+                    ['c'] => 5,
+                    _ => 6,
+                };
+
+                w.write_str(self.empty_rhymes[index])
+            },
+            (None, Some(rhyme)) => w.write_str(self.zero_initial_rhymes[rhyme.index()]),
+            (None, None) => Err(fmt::Error),
+        }
+    }
+
+    fn spelling(&self, syllable: &Syllable) -> Option<Letters> {
+        let mut letters = Letters::new();
+        self.write_spelling(&mut letters, syllable).ok().map(|_| letters)
+    }
+
+    // 按照音调标记方式写入
+    pub fn write_toned(&self, w: &mut dyn fmt::Write, syllable: &Syllable, tone_format: ToneFormat) -> fmt::Result {
+        let spelling = self.spelling(syllable).ok_or(fmt::Error)?;
+        write_toned(w, &spelling, tone_mark_position(&spelling), syllable.tone(), tone_format)
+    }
+
+    // 解析不带声调的小写拼写
+    //
+    // 依次尝试拼写为输入前缀的声母 ( 以及零声母 )，再比较剩余部分和韵母的拼写。
+    pub fn parse(&self, s: &str, input: &Letters, tone: Tone) -> Result<Syllable, Error> {
+        if input.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidSyllable, s, 0));
+        }
+
+        let preferred = self.preferred.iter()
+                            .find(|(spelling, _pinyin)| input.eq_str(spelling))
+                            .and_then(|(_spelling, pinyin)| Syllable::from_str(pinyin).ok());
+
+        let mut best: Option<Syllable> = None;
+        let mut consider = |initial: Option<Initial>, rhyme: Option<Rhyme>| {
+            let syllable = match Syllable::from_parts(initial, rhyme, tone) {
+                Ok(syllable) => syllable,
+                Err(_) => return,
+            };
+
+            let is_match = self.spelling(&syllable).is_some_and(|spelling| spelling[..] == input[..])
+                && preferred.is_none_or(|preferred| preferred.parts() == syllable.parts());
+
+            if is_match && best.is_none_or(|best| inventory_order(&syllable) < inventory_order(&best)) {
+                best = Some(syllable);
+            }
+        };
+
+        let eq = |chars: &[char], s: &str| chars.iter().cloned().eq(s.chars());
+
+        for (index, c) in INITIAL_TABLE.iter().enumerate() {
+            let prefix = self.initials[index];
+            let len = prefix.chars().count();
+            if input.len() < len || !eq(&input[..len], prefix) {
+                continue;
+            }
+
+            let initial = Initial::new(*c)?;
+            let rest = &input[len..];
+
+            consider(Some(initial), None);
+            for (chars, finals) in RHYME_TABLE.iter().zip(self.rhymes.iter()) {
+                let rhyme = Rhyme::new(*chars)?;
+                if eq(rest, (self.finals)(initial, rhyme, finals)) {
+                    consider(Some(initial), Some(rhyme));
+                }
+            }
+        }
+
+        for (chars, finals) in RHYME_TABLE.iter().zip(self.zero_initial_rhymes.iter()) {
+            if eq(input, finals) {
+                consider(None, Some(Rhyme::new(*chars)?));
+            }
+        }
+
+        best.ok_or_else(|| Error::new(ErrorKind::InvalidSyllable, s, 0))
+    }
+
+    // 解析带调号或者数字的拼写
+    pub fn parse_toned(&self, s: &str) -> Result<Syllable, Error> {
+        let (letters, tone) = split_tone_chars(s, s.chars().flat_map(decompose))?;
+        self.parse(s, &letters, tone)
+    }
+}
+
+// 调号标在辅音字母上的拼写 ( `jr̀`、`dz̀` ) 经过 NFC 规范化以后，有些会合成一个字符 ( `jŕ`、`dž` )，
+// 解析之前拆分成字母以及组合附加符号
+fn decompose(c: char) -> impl Iterator<Item = char> {
+    let (base, mark) = match c {
+        'ŕ' => ('r', Some('\u{301}')),
+        'Ŕ' => ('R', Some('\u{301}')),
+        'ř' => ('r', Some('\u{30C}')),
+        'Ř' => ('R', Some('\u{30C}')),
+        'ź' => ('z', Some('\u{301}')),
+        'Ź' => ('Z', Some('\u{301}')),
+        'ž' => ('z', Some('\u{30C}')),
+        'Ž' => ('Z', Some('\u{30C}')),
+        _ => (c, None),
+    };
+
+    iter::once(base).chain(mark)
+}
+
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use syllable::Syllable;
+    use alloc::string::String;
+    use rhyme::Rhyme;
+    use tone::{ ToneFormat, TONE_TABLE };
+    use inventory::syllables;
+    
+    #[test]
+    fn round_trip() {
+        let e_hat = Rhyme::new(['ê', ' ', ' ', ' ']).ok();
+
+        // NOTE: `ê` 和 `e` 的拼写相同，只检查其它音节。
+        for syllable in syllables(&TONE_TABLE).filter(|syllable| syllable.finals() != e_hat) {
+            for tone_format in [ToneFormat::Symbol, ToneFormat::Index].iter() {
+                let yale = syllable.yale(*tone_format);
+                let tongyong = syllable.tongyong(*tone_format);
+                let mps2 = syllable.mps2(*tone_format);
+
+                assert_eq!(Syllable::from_yale(&yale), Ok(syllable), "{}", yale);
+                assert_eq!(Syllable::from_tongyong(&tongyong), Ok(syllable), "{}", tongyong);
+                assert_eq!(Syllable::from_mps2(&mps2), Ok(syllable), "{}", mps2);
+            }
+        }
+    }
+
+    #[test]
+    fn precomposed() {
+        // NFC 形式: 辅音字母和调号合成一个字符
+        let compose = |s: String| {
+            s.replace("r\u{301}", "ŕ").replace("r\u{30C}", "ř").replace("z\u{301}", "ź").replace("z\u{30C}", "ž")
+        };
+
+        for syllable in syllables(&TONE_TABLE) {
+            let yale = compose(syllable.yale(ToneFormat::Symbol));
+            let mps2 = compose(syllable.mps2(ToneFormat::Symbol));
+
+            assert_eq!(Syllable::from_yale(&yale).map(|s| s.yale(ToneFormat::Index)), Ok(syllable.yale(ToneFormat::Index)), "{}", yale);
+            assert_eq!(Syllable::from_mps2(&mps2).map(|s| s.mps2(ToneFormat::Index)), Ok(syllable.mps2(ToneFormat::Index)), "{}", mps2);
+        }
+
+        let pinyin = |s: &str| s.parse::<Syllable>().unwrap();
+        assert_eq!(Syllable::from_yale("shŕ"), Ok(pinyin("shí")));
+        assert_eq!(Syllable::from_yale("Ř"), Ok(pinyin("rǐ")));
+        assert_eq!(Syllable::from_yale("dź"), Ok(pinyin("zí")));
+        assert_eq!(Syllable::from_mps2("sž"), Ok(pinyin("sǐ")));
+    }
+
+    #[test]
+    fn gwoyeu_romatzyh() {
+        let syllable: Syllable = "zhōng".parse().unwrap();
+
+        assert_eq!(syllable.yale(ToneFormat::GwoyeuRomatzyh), "jung");
+        assert_eq!(syllable.tongyong(ToneFormat::GwoyeuRomatzyh), "jhong");
+        assert_eq!(syllable.mps2(ToneFormat::GwoyeuRomatzyh), "jung");
+    }
+}
@@ -0,0 +1,331 @@
+use tone::Tone;
+use initial::Initial;
+use rhyme::Rhyme;
+use syllable::{ Syllable, SyllableKind };
+use error::{ Error, ErrorKind };
+use letters::Letters;
+use inventory::{ candidates, inventory_order };
+
+use core::fmt;
+
+// 国语罗马字:
+//     https://zh.wikipedia.org/wiki/%E5%9C%8B%E8%AA%9E%E7%BE%85%E9%A6%AC%E5%AD%97
+//
+// 以第一声的拼法为基本形式，其余声调按照规则改变拼写 ( chiu, chyu, cheu, chiuh )，
+// 轻声使用基本形式并在前面加点 ( .de )。
+//
+// 第一声: 基本形式，次浊声母 m、n、l、r 之后加 `h` ( mha, lha )。
+// 第二声: 次浊声母使用基本形式 ( ma )；韵头 i、u 改为 y、w，单独的 i、u 前面加 y、w ( chyan, hwa, chyi, hwu )；
+//         其它韵母在元音字母后面加 `r` ( char, hair, chyr )。
+// 第三声: 单独的 i、u 以及 in、ing 双写 ( nii, guu, jiin )，ie、uo 双写韵腹 ( jiee, duoo )，
+//         其它韵头 i、u、iu 改为 e、o、eu ( jea, goan, sheu )；
+//         没有韵头的 ai、au 改为 ae、ao，其它韵母双写第一个字母 ( maa, meei, hao )。
+// 第四声: 韵尾 i、u 改为 y、w ( ay, daw )，n、ng、l 改为 nn、nq、ll，其它韵母后面加 `h` ( dah, chiuh )。
+//
+// 零声母音节第三声、第四声的韵头 i、u 改为 y、w，没有韵头可改的时候在前面加 y、w ( yee, yii, yeu, woh, wuh )。
+
+
+// 国语罗马字声母表 ( 顺序与 `INITIAL_TABLE` 相同 )
+// b, c, ch, d, f, g, h, j, k, l, m, n, p, q, r, s, sh, t, x, z, zh
+const ROMATZYH_INITIAL_TABLE: [&str; 21] = [
+    "b", "ts", "ch", "d", "f", "g",
+    "h", "j", "k", "l", "m", "n",
+    "p", "ch", "r", "s", "sh", "t",
+    "sh", "tz", "j",
+];
+
+// 国语罗马字韵母基本形式 ( 顺序与 `RHYME_TABLE` 相同 )
+const ROMATZYH_RHYME_TABLE: [&str; 37] = [
+    "a", "o", "e", "è", "el", "ai", "ei", "au", "ou", "an", "en", "ang", "eng", "ong",
+    "i", "ia", "ie", "iau", "iou", "ian", "in", "iang", "ing", "iong",
+    "u", "ua", "uo", "uai", "uei", "uan", "uen", "uang", "ueng",
+    "iu", "iue", "iuan", "iun",
+];
+
+// `zhi`、`chi`、`shi`、`ri`、`zi`、`ci`、`si` 的空韵
+const EMPTY_RHYME: &str = "y";
+
+// 轻声符号
+const NEUTRAL_TONE_MARK: char = '.';
+
+// 声调 ( 不包括轻声 )
+const MARKED_TONES: [Tone; 4] = [Tone::First, Tone::Second, Tone::Third, Tone::Fourth];
+
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'è' | 'i' | 'o' | 'u' | 'y')
+}
+
+// 韵头 ( i、u ) 对应的半元音 ( y、w )
+fn glide(medial: char) -> char {
+    if medial == 'i' { 'y' } else { 'w' }
+}
+
+// 按照声调改变韵母的拼写，`sonorant` 表示声母为次浊声母 m、n、l、r
+fn tonal_rhyme(base: &[char], tone: Tone, sonorant: bool) -> Option<Letters> {
+    let chars = |head: &[char], tail: &[char]| Letters::from_chars(head.iter().chain(tail.iter()).cloned());
+    let has_medial = matches!(base.first(), Some('i') | Some('u'));
+
+    match tone {
+        Tone::First | Tone::Neutral => chars(base, &[]),
+        Tone::Second if sonorant => chars(base, &[]),
+        Tone::Second if has_medial => {
+            if base.len() == 1 {
+                chars(&[glide(base[0])], base)
+            } else {
+                chars(&[glide(base[0])], &base[1..])
+            }
+        },
+        Tone::Second => {
+            let (head, tail) = base.split_at(base.iter().rposition(|c| is_vowel(*c)).map_or(0, |i| i + 1));
+            Letters::from_chars(head.iter().chain(Some('r').iter()).chain(tail.iter()).cloned())
+        },
+        Tone::Third if has_medial => {
+            match *base {
+                [m] => chars(&[m, m], &[]),
+                [m, c, ..] if !is_vowel(c) => chars(&[m, m], &base[1..]),
+                ['i', 'u', ..] => chars(&['e', 'u'], &base[2..]),
+                ['i', 'e'] | ['u', 'o'] => chars(base, &base[1..]),
+                [m, ..] => chars(&[if m == 'i' { 'e' } else { 'o' }], &base[1..]),
+                [] => None,
+            }
+        },
+        Tone::Third => {
+            match *base {
+                ['a', 'i'] => chars(&['a', 'e'], &[]),
+                ['a', 'u'] => chars(&['a', 'o'], &[]),
+                [first, ..] => chars(&[first, first], &base[1..]),
+                [] => None,
+            }
+        },
+        Tone::Fourth => {
+            let (head, tail) = base.split_at(base.len().saturating_sub(1));
+
+            match *base {
+                [.., 'a', 'i'] | [.., 'e', 'i'] => chars(head, &['y']),
+                [.., 'a', 'u'] | [.., 'o', 'u'] => chars(head, &['w']),
+                [.., 'n', 'g'] => chars(head, &['q']),
+                [.., 'n'] | [.., 'l'] => chars(base, tail),
+                _ => chars(base, &['h']),
+            }
+        },
+    }
+}
+
+// 零声母音节第三声、第四声的拼写 ( yee, yii, yeu, woh, wuh )
+fn zero_initial_rhyme(rhyme: Letters, medial: char) -> Option<Letters> {
+    let glide = glide(medial);
+
+    match rhyme[..] {
+        [first, second, ..] if first == medial && second != medial && is_vowel(second) => {
+            Letters::from_chars(Some(glide).into_iter().chain(rhyme[1..].iter().cloned()))
+        },
+        _ => Letters::from_chars(Some(glide).into_iter().chain(rhyme.iter().cloned())),
+    }
+}
+
+// 不带轻声符号的拼写
+fn write_spelling(w: &mut dyn fmt::Write, syllable: &Syllable) -> fmt::Result {
+    let tone = syllable.tone();
+    let (initial, rhyme) = syllable.parts();
+
+    // NOTE: 鼻音音节不在国语罗马字的拼写规则之内，按照基本形式输出。
+    if syllable.kind() == SyllableKind::Nasal {
+        return match initial {
+            Some(initial) => w.write_str(initial.romatzyh()),
+            None => Err(fmt::Error),
+        };
+    }
+
+    let base = match (initial, rhyme) {
+        (_, Some(rhyme)) => rhyme.romatzyh(),
+        (Some(_), None) => EMPTY_RHYME,
+        (None, None) => return Err(fmt::Error),
+    };
+    let base = Letters::from_chars(base.chars()).ok_or(fmt::Error)?;
+
+    let sonorant = initial.is_some_and(|initial| {
+        initial == Initial::M || initial == Initial::N || matches!(initial.letters()[..], ['l'] | ['r'])
+    });
+
+    let mut spelling = tonal_rhyme(&base, tone, sonorant).ok_or(fmt::Error)?;
+
+    match initial {
+        Some(initial) => {
+            w.write_str(initial.romatzyh())?;
+
+            if sonorant && tone == Tone::First {
+                w.write_char('h')?;
+            }
+        },
+        None => {
+            let medial = match rhyme.map(|rhyme| rhyme.letters()[0]) {
+                Some('i') | Some('ü') => Some('i'),
+                Some('u') => Some('u'),
+                _ => None,
+            };
+
+            if let (Some(medial), Tone::Third) | (Some(medial), Tone::Fourth) = (medial, tone) {
+                spelling = zero_initial_rhyme(spelling, medial).ok_or(fmt::Error)?;
+            }
+        },
+    }
+
+    write!(w, "{}", spelling)
+}
+
+// 解析不带轻声符号的小写拼写，`tones` 为可能的声调。拼写相同的音节取音节表当中的第一个。
+fn parse_spelling(input: &[char], tones: &[Tone]) -> Option<Syllable> {
+    // 不包含元音字母的拼写 ( 例如拆分出来的 `sh`、`nq` ) 不可能是音节
+    if !input.iter().any(|c| is_vowel(*c)) {
+        return None;
+    }
+
+    let matches = |syllable: &Syllable| {
+        let mut spelling = Letters::new();
+
+        syllable.kind() != SyllableKind::Nasal
+            && write_spelling(&mut spelling, syllable).is_ok()
+            && spelling[..] == input[..]
+    };
+
+    candidates(input, Initial::romatzyh, tones)
+        .filter(matches)
+        .min_by_key(|syllable| (inventory_order(syllable), syllable.tone()))
+}
+
+// 判断两个音节连写以后能否按照其它方式拆分 ( `shi` + `i` 写成 `shii` 会被读作第三声的 `shii` )，
+// 这时两个音节之间需要使用隔音符号。
+pub(crate) fn is_ambiguous(prev: &Syllable, next: &Syllable) -> bool {
+    // NOTE: 轻声音节前面的点已经隔开了两个音节
+    if next.tone() == Tone::Neutral {
+        return false;
+    }
+
+    let (mut head, mut tail) = (Letters::new(), Letters::new());
+    if write_spelling(&mut head, prev).is_err() || write_spelling(&mut tail, next).is_err() {
+        return false;
+    }
+
+    let mut chars = [' '; 16];
+    let len = head.len() + tail.len();
+    chars[..head.len()].copy_from_slice(&head);
+    chars[head.len()..len].copy_from_slice(&tail);
+    let joined = &chars[..len];
+
+    let head_tones: &[Tone] = if prev.tone() == Tone::Neutral { &[Tone::Neutral] } else { &MARKED_TONES };
+
+    parse_spelling(joined, head_tones).is_some()
+        || (1..len).filter(|at| *at != head.len()).any(|at| {
+            parse_spelling(&joined[..at], head_tones).is_some() && parse_spelling(&joined[at..], &MARKED_TONES).is_some()
+        })
+}
+
+// 写入国语罗马字形式的音节 ( chiu, chyu, cheu, chiuh, .de )
+pub(crate) fn write_syllable(w: &mut dyn fmt::Write, syllable: &Syllable) -> fmt::Result {
+    if syllable.tone() == Tone::Neutral {
+        w.write_char(NEUTRAL_TONE_MARK)?;
+    }
+
+    write_spelling(w, syllable)
+}
+
+
+impl Initial {
+    /// 国语罗马字 ( b, ch, tz )
+    pub fn romatzyh(&self) -> &'static str {
+        ROMATZYH_INITIAL_TABLE[self.index()]
+    }
+}
+
+impl Rhyme {
+    /// 国语罗马字的基本形式 ( 第一声的拼法: iau, iu, uei )
+    pub fn romatzyh(&self) -> &'static str {
+        ROMATZYH_RHYME_TABLE[self.index()]
+    }
+}
+
+impl Syllable {
+    /// 解析国语罗马字形式的音节 ( chiu, chyu, cheu, chiuh, .de )
+    ///
+    /// 轻声音节前面的点后面可以是基本形式，也可以是原来声调的拼法 ( .de, .sheang )。
+    /// 鼻音音节不在国语罗马字的拼写规则之内，不会被解析。
+    pub fn from_gwoyeu_romatzyh(s: &str) -> Result<Syllable, Error> {
+        let invalid = || Error::new(ErrorKind::InvalidSyllable, s, 0);
+
+        let (body, neutral) = match s.strip_prefix(NEUTRAL_TONE_MARK) {
+            Some(body) => (body, true),
+            None => (s, false),
+        };
+
+        let input = Letters::from_chars(body.chars().map(|c| c.to_lowercase().next().unwrap_or(c)))
+                        .filter(|letters| !letters.is_empty())
+                        .ok_or_else(invalid)?;
+
+        let found = if neutral {
+            parse_spelling(&input, &[Tone::Neutral])
+                .or_else(|| {
+                    parse_spelling(&input, &MARKED_TONES)
+                        .and_then(|syllable| syllable.with_tone(Tone::Neutral).ok())
+                })
+        } else {
+            parse_spelling(&input, &MARKED_TONES)
+        };
+
+        found.ok_or_else(invalid)
+    }
+}
+
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use syllable::{ Syllable, SyllableKind };
+    use tone::{ ToneFormat, TONE_TABLE };
+    use format::{ Show, join };
+    use inventory::syllables;
+
+    fn pinyin(s: &str) -> Syllable {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        // NOTE: 拼写相同的音节解析为其中一个，这里只比较拼写
+        for syllable in syllables(&TONE_TABLE).filter(|syllable| syllable.kind() != SyllableKind::Nasal) {
+            let spelling = syllable.show(ToneFormat::GwoyeuRomatzyh);
+            let parsed = Syllable::from_gwoyeu_romatzyh(&spelling).unwrap();
+
+            assert_eq!(parsed.show(ToneFormat::GwoyeuRomatzyh), spelling, "{}", syllable);
+            assert_eq!(parsed.tone(), syllable.tone(), "{}", spelling);
+        }
+    }
+
+    #[test]
+    fn spellings() {
+        let cases = [
+            ("zhōng", "jong"), ("lǜ", "liuh"), ("jué", "jyue"), ("nǚ", "neu"), ("ér", "erl"), ("wèn", "wenn"),
+            ("shì", "shyh"), ("zǐ", "tzyy"), ("guì", "guey"), ("liú", "liou"), ("dūn", "duen"), ("bó", "bor"),
+            ("de", ".de"),
+        ];
+
+        for (pinyin_spelling, romatzyh) in cases.iter() {
+            assert_eq!(pinyin(pinyin_spelling).show(ToneFormat::GwoyeuRomatzyh), *romatzyh);
+            assert_eq!(Syllable::from_gwoyeu_romatzyh(romatzyh), Ok(pinyin(pinyin_spelling)), "{}", romatzyh);
+        }
+
+        // 轻声可以使用原来声调的拼法
+        assert_eq!(Syllable::from_gwoyeu_romatzyh(".sheang"), Ok(pinyin("xiang")));
+        assert!(Syllable::from_gwoyeu_romatzyh("jx").is_err());
+        assert!(Syllable::from_gwoyeu_romatzyh("").is_err());
+    }
+
+    #[test]
+    fn separator() {
+        // `shi` + `i` 连写成 `shii` 会读作第三声的 `xǐ`
+        assert_eq!(join(&[pinyin("xī"), pinyin("yī")], ToneFormat::GwoyeuRomatzyh), "shi'i");
+        assert_eq!(join(&[pinyin("xǐ")], ToneFormat::GwoyeuRomatzyh), "shii");
+        assert_eq!(join(&[pinyin("zhōng"), pinyin("guó")], ToneFormat::GwoyeuRomatzyh), "jonggwo");
+        assert_eq!(join(&[pinyin("xī"), pinyin("ān")], ToneFormat::GwoyeuRomatzyh), "shi'an");
+        assert_eq!(join(&[pinyin("mā"), pinyin("ma")], ToneFormat::GwoyeuRomatzyh), "mha.ma");
+    }
+}
@@ -0,0 +1,202 @@
+use syllable::{ self, Syllable };
+use format::starts_with_vowel;
+use tone::{ combining_tone, split_tone_mark };
+use SYLLABLE_DIVIDING_MARK;
+
+use core::cmp::Reverse;
+use core::ops::Range;
+use alloc::vec::Vec;
+
+
+// 单个音节最多包含的字符数 ( `zhuang` + 声调数字，或者带组合附加符号的 `ê̄` 等 )
+const SYLLABLE_MAX_CHARS: usize = 8;
+
+// 最多返回的切分方式数量
+const SPLIT_MAX_RESULTS: usize = 8;
+
+
+// 音节的字母结构: 辅音 ( 声母，或者鼻音音节 ) + 元音 + 韵尾 ( `n`、`ng`、`r` )，中间可以有一个声调数字
+#[derive(Clone, Copy)]
+enum Shape {
+    Consonants(usize),
+    Vowels,
+    Coda(char),
+}
+
+impl Shape {
+    // 在片段后面追加一个字符，片段 ( 以及更长的片段 ) 不可能构成音节的时候返回 `None`
+    fn push(self, c: char, toned: &mut bool) -> Option<Shape> {
+        if c.is_ascii_digit() || combining_tone(c).is_some() {
+            return if core::mem::replace(toned, true) { None } else { Some(self) };
+        }
+
+        let c = match split_tone_mark(c) {
+            Some((base, _tone)) if !core::mem::replace(toned, true) => base,
+            Some(_) => return None,
+            None => c,
+        };
+        let is_vowel = matches!(c, 'a' | 'o' | 'e' | 'ê' | 'i' | 'u' | 'ü' | 'v');
+
+        match (self, c) {
+            (Shape::Consonants(_), _) | (Shape::Vowels, _) if is_vowel => Some(Shape::Vowels),
+            (Shape::Vowels, '\u{302}') | (Shape::Vowels, '\u{308}') => Some(Shape::Vowels),
+            (Shape::Consonants(count), _) if count < 3 && c.is_alphabetic() => Some(Shape::Consonants(count + 1)),
+            (Shape::Vowels, 'n') | (Shape::Vowels, 'r') => Some(Shape::Coda(c)),
+            (Shape::Coda('n'), 'g') => Some(Shape::Coda('g')),
+            _ => None,
+        }
+    }
+}
+
+// 一种切分方式的第一个音节，剩余部分的切分方式为 `best[start + len][tail]`
+#[derive(Clone, Copy)]
+struct Candidate {
+    // 违反隔音符号规则的次数
+    violations: usize,
+    // 音节数量
+    count: usize,
+    // 第一个音节的字符数
+    len: usize,
+    syllable: Syllable,
+    tail: usize,
+}
+
+impl Candidate {
+    // 可能性大的排在前面: 优先满足隔音符号规则，其次音节数量更少，最后前面的音节更长。
+    // 第一个音节相同的时候，剩余部分已经按照同样的规则排好序，只需比较 `tail`。
+    fn rank(&self) -> (usize, usize, Reverse<usize>, usize) {
+        (self.violations, self.count, Reverse(self.len), self.tail)
+    }
+}
+
+// 一种切分方式: 违反隔音符号规则的次数，以及每个音节在文本当中的字符范围
+pub(crate) struct Split {
+    pub violations: usize,
+    pub pieces: Vec<(Range<usize>, Syllable)>,
+}
+
+// 切分小写的文本，返回可能性最大的若干种切分方式，可能性最大的排在最前面
+pub(crate) fn split_text(text: &str) -> Vec<Split> {
+    if text.is_empty() || text.split(SYLLABLE_DIVIDING_MARK).any(|part| part.is_empty()) {
+        return Vec::new();
+    }
+
+    // 每个字符的起始位置，以及结束位置
+    let mut bounds = text.char_indices().map(|(index, _)| index).collect::<Vec<usize>>();
+    bounds.push(text.len());
+
+    let chars = text.chars().collect::<Vec<char>>();
+    let len = chars.len();
+
+    // `best[i]` 为从第 `i` 个字符开始的剩余部分的最佳切分方式 ( 按照 `rank` 排序 )，`best[len]` 不使用
+    let mut best: Vec<Vec<Candidate>> = vec![Vec::new(); len + 1];
+
+    for start in (0..len).rev() {
+        if chars[start] == SYLLABLE_DIVIDING_MARK {
+            best[start] = best[start + 1].clone();
+            continue;
+        }
+
+        // 音节跟在其它音节后面 ( 而不是隔音符号后面 )
+        let follows_syllable = start > 0 && chars[start - 1] != SYLLABLE_DIVIDING_MARK;
+        let mut candidates = Vec::new();
+        let (mut shape, mut toned) = (Shape::Consonants(0), false);
+
+        for end in (start + 1)..=len.min(start + SYLLABLE_MAX_CHARS) {
+            shape = match shape.push(chars[end - 1], &mut toned) {
+                Some(shape) => shape,
+                None => break,
+            };
+
+            // 剩余部分无法切分
+            if end < len && best[end].is_empty() {
+                continue;
+            }
+
+            let syllable = match syllable::from_str(&text[bounds[start]..bounds[end]]) {
+                Ok(syllable) => syllable,
+                Err(_) => continue,
+            };
+            let violation = (follows_syllable && starts_with_vowel(&syllable)) as usize;
+            let candidate = |violations, count, tail| Candidate {
+                violations: violations + violation, count: count + 1, len: end - start, syllable, tail,
+            };
+
+            if end == len {
+                candidates.push(candidate(0, 0, 0));
+            } else {
+                candidates.extend(best[end].iter().enumerate().map(|(tail, rest)| candidate(rest.violations, rest.count, tail)));
+            }
+        }
+
+        candidates.sort_by_key(Candidate::rank);
+        candidates.truncate(SPLIT_MAX_RESULTS);
+        best[start] = candidates;
+    }
+
+    best[0].iter().enumerate().map(|(index, first)| {
+        let mut pieces = Vec::with_capacity(first.count);
+        let (mut start, mut index) = (0, index);
+
+        while start < len {
+            if chars[start] == SYLLABLE_DIVIDING_MARK {
+                start += 1;
+                continue;
+            }
+
+            let candidate = best[start][index];
+            pieces.push((start..start + candidate.len, candidate.syllable));
+            start += candidate.len;
+            index = candidate.tail;
+        }
+
+        Split { violations: first.violations, pieces }
+    }).collect()
+}
+
+/// 将多个音节组成的拼音单词切分为音节 ( `xī'ān`, `zhongguo`, `Tiān'ānmén` )
+///
+/// 隔音符号 `'` 被视为确定的音节边界。返回可能性最大的若干种切分方式 ( 最多 8 种 )，可能性最大的排在最前面:
+/// 优先满足隔音符号规则（ `a`、`o`、`e` 开头的音节不会直接跟在其它音节后面），其次音节数量更少，
+/// 最后前面的音节更长。无法切分的时候返回空列表。
+pub fn split(s: &str) -> Vec<Vec<Syllable>> {
+    split_text(&s.to_lowercase())
+        .into_iter()
+        .map(|split| split.pieces.into_iter().map(|(_, syllable)| syllable).collect())
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{ split, SPLIT_MAX_RESULTS };
+    use format::join;
+    use tone::ToneFormat;
+
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    fn splits(s: &str) -> Vec<String> {
+        split(s).iter().map(|syllables| join(syllables, ToneFormat::Symbol)).collect()
+    }
+
+    #[test]
+    fn ranking() {
+        assert_eq!(splits("xian"), ["xian", "xi'an"]);
+        assert_eq!(splits("Tiān'ānmén"), ["tiān'ānmén", "ti'ān'ānmén"]);
+        assert_eq!(splits("pí'ǎo"), ["pí'ǎo", "pí'ǎ'o"]);
+        assert!(splits("xi''an").is_empty());
+        assert!(splits("xianq").is_empty());
+    }
+
+    #[test]
+    fn long_input() {
+        let word = "xian".repeat(1_000);
+        let result = split(&word);
+
+        assert_eq!(result.len(), SPLIT_MAX_RESULTS);
+        assert_eq!(result[0].len(), 1_000);
+        assert_eq!(splits(&"zhong'guo".repeat(100))[0], "zhongguo".repeat(100));
+        assert!(split(&(word + "q")).is_empty());
+    }
+}
@@ -0,0 +1,384 @@
+//! `serde` 支持
+//!
+//! 默认情况下，声调序列化为数字 ( 轻声为 `0` )，调号、声母、韵母以及音节序列化为字符串，
+//! 其中音节使用带调号的形式 ( `zhōng` )，反序列化的时候同时接受调号以及数字两种形式。
+//!
+//! 如果需要其它形式，可以在字段上使用 `#[serde(with = "...")]` 选择:
+//!
+//! *   `pinyin::serde::marked`: 带调号的字符串 ( `"zhōng"` )
+//! *   `pinyin::serde::numbered`: 带声调数字的字符串 ( `"zhong1"` )
+//! *   `pinyin::serde::structured`: 结构化的形式 ( `{"initial": "zh", "final": "ong", "tone": 1}` )
+
+use tone::{ Tone, ToneMark, ToneFormat };
+use initial::Initial;
+use rhyme::Rhyme;
+use format::Show;
+use syllable::{ Syllable, SyllableKind, PrimitiveSyllable, NormalSyllable, RhymeSyllable, NasalSyllable };
+
+use serde_crate::{ Serialize, Serializer, Deserialize, Deserializer };
+use serde_crate::de::{ self, Visitor };
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+use alloc::string::ToString;
+
+
+// 通过 `FromStr` 解析字符串的 Visitor
+struct FromStrVisitor<T>(&'static str, PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for FromStrVisitor<T> where T: FromStr, T::Err: fmt::Display {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse::<T>().map_err(E::custom)
+    }
+}
+
+fn deserialize_from_str<'de, D, T>(deserializer: D, expecting: &'static str) -> Result<T, D::Error>
+    where D: Deserializer<'de>, T: FromStr, T::Err: fmt::Display {
+    deserializer.deserialize_str(FromStrVisitor(expecting, PhantomData))
+}
+
+
+impl Serialize for Tone {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(u8::from(self))
+    }
+}
+
+struct ToneVisitor;
+
+impl<'de> Visitor<'de> for ToneVisitor {
+    type Value = Tone;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a tone number between 0 and 5")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Tone, E> {
+        match v {
+            0 | 5 => Ok(Tone::Neutral),
+            1 => Ok(Tone::First),
+            2 => Ok(Tone::Second),
+            3 => Ok(Tone::Third),
+            4 => Ok(Tone::Fourth),
+            _ => Err(E::invalid_value(de::Unexpected::Unsigned(v), &self)),
+        }
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Tone, E> {
+        if v < 0 {
+            return Err(E::invalid_value(de::Unexpected::Signed(v), &self));
+        }
+
+        self.visit_u64(v as u64)
+    }
+}
+
+impl<'de> Deserialize<'de> for Tone {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_u8(ToneVisitor)
+    }
+}
+
+
+impl Serialize for ToneMark {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ToneMark {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_from_str(deserializer, "a letter with a tone mark")
+    }
+}
+
+
+impl Serialize for Initial {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Initial {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_from_str(deserializer, "a pinyin initial")
+    }
+}
+
+
+impl Serialize for Rhyme {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Rhyme {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_from_str(deserializer, "a pinyin final")
+    }
+}
+
+
+impl Serialize for Syllable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        marked::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Syllable {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_from_str(deserializer, "a pinyin syllable")
+    }
+}
+
+impl Serialize for PrimitiveSyllable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.show(ToneFormat::Symbol))
+    }
+}
+
+impl<'de> Deserialize<'de> for PrimitiveSyllable {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_from_str(deserializer, "a whole syllable such as zhi, yi or wu")
+    }
+}
+
+impl Serialize for NormalSyllable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.show(ToneFormat::Symbol))
+    }
+}
+
+impl<'de> Deserialize<'de> for NormalSyllable {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_from_str(deserializer, "a syllable with an initial")
+    }
+}
+
+impl Serialize for RhymeSyllable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.show(ToneFormat::Symbol))
+    }
+}
+
+impl<'de> Deserialize<'de> for RhymeSyllable {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_from_str(deserializer, "a syllable without an initial")
+    }
+}
+
+impl Serialize for NasalSyllable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.show(ToneFormat::Symbol))
+    }
+}
+
+impl<'de> Deserialize<'de> for NasalSyllable {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_from_str(deserializer, "a nasal syllable such as ń or ḿ")
+    }
+}
+
+
+/// 带调号的字符串形式 ( `"zhōng"` )
+pub mod marked {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(syllable: &Syllable, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&syllable.show(ToneFormat::Symbol))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Syllable, D::Error> {
+        deserialize_from_str(deserializer, "a pinyin syllable with tone marks")
+    }
+}
+
+/// 带声调数字的字符串形式 ( `"zhong1"` )
+pub mod numbered {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(syllable: &Syllable, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&syllable.show(ToneFormat::Index))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Syllable, D::Error> {
+        deserialize_from_str(deserializer, "a pinyin syllable with a tone number")
+    }
+}
+
+/// 结构化的形式 ( `{"initial": "zh", "final": "ong", "tone": 1}` )
+///
+/// 零声母音节的 `initial` 为 `null`，鼻音音节的 `final` 为 `null`，
+/// 整体认读音节 `zhi`、`chi`、`shi`、`ri`、`zi`、`ci`、`si` 的 `final` 为 `"i"`。
+pub mod structured {
+    use super::*;
+    use serde_crate::ser::SerializeStruct;
+    use alloc::string::String;
+
+    const FIELDS: &[&str] = &["initial", "final", "tone"];
+
+    pub fn serialize<S: Serializer>(syllable: &Syllable, serializer: S) -> Result<S::Ok, S::Error> {
+        let (initial, finals) = match syllable.parts() {
+            // zhi, chi, shi, ri, zi, ci, si
+            (Some(initial), None) if syllable.kind() == SyllableKind::Primitive => (Some(initial), "i".parse().ok()),
+            parts => parts,
+        };
+
+        let mut state = serializer.serialize_struct("Syllable", FIELDS.len())?;
+        state.serialize_field("initial", &initial)?;
+        state.serialize_field("final", &finals)?;
+        state.serialize_field("tone", &syllable.tone())?;
+        state.end()
+    }
+
+    struct StructuredVisitor;
+
+    impl<'de> Visitor<'de> for StructuredVisitor {
+        type Value = Syllable;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a syllable as {initial, final, tone}")
+        }
+
+        fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Syllable, A::Error> {
+            let mut initial: Option<Option<Initial>> = None;
+            let mut finals: Option<Option<Rhyme>> = None;
+            let mut tone: Option<Tone> = None;
+
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "initial" => initial = Some(map.next_value()?),
+                    "final" => finals = Some(map.next_value()?),
+                    "tone" => tone = Some(map.next_value()?),
+                    _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+                }
+            }
+
+            let tone = tone.ok_or_else(|| de::Error::missing_field("tone"))?;
+
+            build(initial.unwrap_or(None), finals.unwrap_or(None), tone)
+        }
+
+        // 按照字段顺序序列化成序列的格式 ( 例如 `bincode` ) 以及 `["zh", "ong", 1]` 形式的输入
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Syllable, A::Error> {
+            let initial = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            let finals = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+            let tone = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+
+            build(initial, finals, tone)
+        }
+    }
+
+    fn build<E: de::Error>(initial: Option<Initial>, finals: Option<Rhyme>, tone: Tone) -> Result<Syllable, E> {
+        let finals = match (initial, finals) {
+            // NOTE: `zhi` 等音节的 `i` 只是书写形式，实际上是空韵
+            (Some(initial), Some(rhyme)) if rhyme.letters().eq_str("i") && !NormalSyllable::is_legal(initial, rhyme) => None,
+            (_, finals) => finals,
+        };
+
+        Syllable::from_parts(initial, finals, tone).map_err(E::custom)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Syllable, D::Error> {
+        deserializer.deserialize_struct("Syllable", FIELDS, StructuredVisitor)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use tone::{ Tone, ToneMark, TONE_TABLE };
+    use initial::Initial;
+    use rhyme::Rhyme;
+    use syllable::Syllable;
+    use inventory::syllables;
+
+    use serde_crate::{ Serialize, Deserialize };
+    use serde_json;
+    use bincode;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(crate = "serde_crate")]
+    struct Record {
+        syllable: Syllable,
+        #[serde(with = "super::marked")]
+        marked: Syllable,
+        #[serde(with = "super::numbered")]
+        numbered: Syllable,
+        #[serde(with = "super::structured")]
+        structured: Syllable,
+        tone: Tone,
+        tone_mark: ToneMark,
+        initial: Option<Initial>,
+        rhyme: Option<Rhyme>,
+    }
+
+    impl Record {
+        fn new(syllable: Syllable) -> Record {
+            Record {
+                syllable,
+                marked: syllable,
+                numbered: syllable,
+                structured: syllable,
+                tone: syllable.tone(),
+                tone_mark: syllable.tone_mark(),
+                initial: syllable.initial(),
+                rhyme: syllable.finals(),
+            }
+        }
+    }
+
+    fn pinyin(s: &str) -> Syllable {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        for syllable in syllables(&TONE_TABLE) {
+            let record = Record::new(syllable);
+
+            let json = serde_json::to_string(&record).unwrap();
+            assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record, "{}", json);
+
+            let bytes = bincode::serialize(&record).unwrap();
+            assert_eq!(bincode::deserialize::<Record>(&bytes).unwrap(), record, "{}", syllable);
+        }
+    }
+
+    #[test]
+    fn json() {
+        let record = Record::new(pinyin("zhōng"));
+        let json = serde_json::to_value(&record).unwrap();
+
+        assert_eq!(json["syllable"], "zhōng");
+        assert_eq!(json["marked"], "zhōng");
+        assert_eq!(json["numbered"], "zhong1");
+        assert_eq!(json["structured"], serde_json::json!({ "initial": "zh", "final": "ong", "tone": 1 }));
+        assert_eq!(json["tone"], 1);
+        assert_eq!(json["tone_mark"], "ō");
+
+        let structured = |syllable: &str| serde_json::to_value(Record::new(pinyin(syllable))).unwrap()["structured"].clone();
+        assert_eq!(structured("zhi"), serde_json::json!({ "initial": "zh", "final": "i", "tone": 0 }));
+        assert_eq!(structured("ān"), serde_json::json!({ "initial": null, "final": "an", "tone": 1 }));
+        assert_eq!(structured("ń"), serde_json::json!({ "initial": "n", "final": null, "tone": 2 }));
+    }
+
+    #[test]
+    fn structured_input() {
+        let parse = |json: &str| super::structured::deserialize(&mut serde_json::Deserializer::from_str(json)).ok();
+
+        assert_eq!(parse(r#"{"tone": 2, "final": "i", "initial": "sh"}"#), Some(pinyin("shí")));
+        assert_eq!(parse(r#"["x", "ian", 4]"#), Some(pinyin("xiàn")));
+        assert_eq!(parse(r#"[null, "er", 3]"#), Some(pinyin("ěr")));
+        assert!(parse(r#"["x", "ian"]"#).is_none());
+        assert!(parse(r#"{"initial": "b", "final": "ong", "tone": 1}"#).is_none());
+        assert!(parse(r#"{"initial": "b", "final": "a"}"#).is_none());
+    }
+}
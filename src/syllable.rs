@@ -1,10 +1,13 @@
-use tone::{Tone, ToneMark};
+use tone::{ Tone, ToneMark, ToneFormat, TONE_MARK_TABLE, TONE_TABLE };
 use initial::Initial;
 use rhyme::{ Rhyme, RHYME_TABLE_COLUMN_I, RHYME_TABLE_COLUMN_U, RHYME_TABLE_COLUMN_YU };
+use format::{ Show, tone_mark_position, COMBINING_TONE_MARKS };
+use error::{ Error, ErrorKind, ErrorText };
+use letters::Letters;
 
-use std::fmt;
-use std::cmp;
-use std::str::FromStr;
+use core::fmt;
+use core::cmp;
+use core::str::FromStr;
 
 
 // zhi, chi, shi, ri, zi, ci, si, yi, wu, yu, ye, yue, yuan, yin, yun, ying
@@ -20,8 +23,60 @@ pub const PRIMITIVE_SYLLABLE_TABLE: [([char; 4], char); 16] = [
 ];
 
 
+// 《汉语普通话声韵配合表》: 每个声母可以拼合的韵母（韵母使用完整形式，例如 `iou`、`uei`、`uen`）
+// 
+// NOTE: 整体认读音节 ( zhi chi shi ri zi ci si ) 以及零声母音节不在此表当中。
+pub const NORMAL_SYLLABLE_TABLE: [(char, &[&str]); 21] = [
+    ('b', &["a", "o", "ai", "ei", "ao", "an", "en", "ang", "eng",
+            "i", "ie", "iao", "ian", "in", "ing", "u"]),
+    ('p', &["a", "o", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng",
+            "i", "ie", "iao", "ian", "in", "ing", "u"]),
+    ('m', &["a", "o", "e", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng",
+            "i", "ie", "iao", "iou", "ian", "in", "ing", "u"]),
+    ('f', &["a", "o", "ei", "ou", "an", "en", "ang", "eng", "u"]),
+    ('d', &["a", "e", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "ong",
+            "i", "ia", "ie", "iao", "iou", "ian", "ing",
+            "u", "uo", "uei", "uan", "uen"]),
+    ('t', &["a", "e", "ai", "ao", "ou", "an", "ang", "eng", "ong",
+            "i", "ie", "iao", "ian", "ing",
+            "u", "uo", "uei", "uan", "uen"]),
+    ('n', &["a", "e", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "ong",
+            "i", "ie", "iao", "iou", "ian", "in", "iang", "ing",
+            "u", "uo", "uan", "ü", "üe"]),
+    ('l', &["a", "o", "e", "ai", "ei", "ao", "ou", "an", "ang", "eng", "ong",
+            "i", "ia", "ie", "iao", "iou", "ian", "in", "iang", "ing",
+            "u", "uo", "uan", "uen", "ü", "üe"]),
+    ('g', &["a", "e", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "ong",
+            "u", "ua", "uo", "uai", "uei", "uan", "uen", "uang"]),
+    ('k', &["a", "e", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "ong",
+            "u", "ua", "uo", "uai", "uei", "uan", "uen", "uang"]),
+    ('h', &["a", "e", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "ong",
+            "u", "ua", "uo", "uai", "uei", "uan", "uen", "uang"]),
+    ('j', &["i", "ia", "ie", "iao", "iou", "ian", "in", "iang", "ing", "iong",
+            "ü", "üe", "üan", "ün"]),
+    ('q', &["i", "ia", "ie", "iao", "iou", "ian", "in", "iang", "ing", "iong",
+            "ü", "üe", "üan", "ün"]),
+    ('x', &["i", "ia", "ie", "iao", "iou", "ian", "in", "iang", "ing", "iong",
+            "ü", "üe", "üan", "ün"]),
+    ('ẑ', &["a", "e", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "ong",
+            "u", "ua", "uo", "uai", "uei", "uan", "uen", "uang"]),
+    ('ĉ', &["a", "e", "ai", "ao", "ou", "an", "en", "ang", "eng", "ong",
+            "u", "ua", "uo", "uai", "uei", "uan", "uen", "uang"]),
+    ('ŝ', &["a", "e", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng",
+            "u", "ua", "uo", "uai", "uei", "uan", "uen", "uang"]),
+    ('r', &["e", "ao", "ou", "an", "en", "ang", "eng", "ong",
+            "u", "ua", "uo", "uei", "uan", "uen"]),
+    ('z', &["a", "e", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "ong",
+            "u", "uo", "uei", "uan", "uen"]),
+    ('c', &["a", "e", "ai", "ao", "ou", "an", "en", "ang", "eng", "ong",
+            "u", "uo", "uei", "uan", "uen"]),
+    ('s', &["a", "e", "ai", "ao", "ou", "an", "en", "ang", "eng", "ong",
+            "u", "uo", "uei", "uan", "uen"]),
+];
+
+
 /// 音节类型
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
 pub enum SyllableKind {
     /// 整体认读音节
     Primitive,
@@ -33,78 +88,282 @@ pub enum SyllableKind {
     Nasal,
 }
 
-pub trait Syllable: fmt::Display + fmt::Debug {
-    fn kind(&self) -> SyllableKind;
-    fn initial(&self) -> Option<Initial>;
-    fn finals(&self) -> Option<Rhyme>;
-    fn vowel(&self) -> char;
-    fn tone(&self) -> Tone;
-    fn tone_mark(&self) -> ToneMark {
+/// 音节
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub enum Syllable {
+    Primitive(PrimitiveSyllable),
+    Normal(NormalSyllable),
+    Rhyme(RhymeSyllable),
+    Nasal(NasalSyllable),
+}
+
+impl Syllable {
+    pub fn kind(&self) -> SyllableKind {
+        match *self {
+            Syllable::Primitive(_) => SyllableKind::Primitive,
+            Syllable::Normal(_) => SyllableKind::Normal,
+            Syllable::Rhyme(_) => SyllableKind::Rhyme,
+            Syllable::Nasal(_) => SyllableKind::Nasal,
+        }
+    }
+
+    pub fn initial(&self) -> Option<Initial> {
+        match *self {
+            Syllable::Primitive(ref v) => v.initial(),
+            Syllable::Normal(ref v) => v.initial(),
+            Syllable::Rhyme(ref v) => v.initial(),
+            Syllable::Nasal(ref v) => v.initial(),
+        }
+    }
+
+    pub fn finals(&self) -> Option<Rhyme> {
+        match *self {
+            Syllable::Primitive(ref v) => v.finals(),
+            Syllable::Normal(ref v) => v.finals(),
+            Syllable::Rhyme(ref v) => v.finals(),
+            Syllable::Nasal(ref v) => v.finals(),
+        }
+    }
+
+    pub fn vowel(&self) -> char {
+        match *self {
+            Syllable::Primitive(ref v) => v.vowel(),
+            Syllable::Normal(ref v) => v.vowel(),
+            Syllable::Rhyme(ref v) => v.vowel(),
+            Syllable::Nasal(ref v) => v.vowel(),
+        }
+    }
+
+    pub fn tone(&self) -> Tone {
+        match *self {
+            Syllable::Primitive(ref v) => v.tone(),
+            Syllable::Normal(ref v) => v.tone(),
+            Syllable::Rhyme(ref v) => v.tone(),
+            Syllable::Nasal(ref v) => v.tone(),
+        }
+    }
+
+    pub fn tone_mark(&self) -> ToneMark {
         ToneMark::new(self.vowel(), self.tone())
             .expect("错误的音节元音音调！")
     }
+
+    // 不带声调的拼写
+    pub(crate) fn spelling(&self) -> Letters {
+        match *self {
+            Syllable::Primitive(ref v) => v.spelling(),
+            Syllable::Normal(ref v) => v.spelling(),
+            Syllable::Rhyme(ref v) => v.spelling(),
+            Syllable::Nasal(ref v) => v.spelling(),
+        }
+    }
+
+    // 音节的声母、韵母部分 ( 整体认读音节按照实际的读音拆分，`zhi` 等音节的韵母为空韵 `None` )
+    pub(crate) fn parts(&self) -> (Option<Initial>, Option<Rhyme>) {
+        match *self {
+            Syllable::Primitive(ref v) => v.parts(),
+            _ => (self.initial(), self.finals()),
+        }
+    }
+
+    // 替换声调 ( 鼻音音节只有部分声调 )
+    pub(crate) fn with_tone(&self, tone: Tone) -> Result<Syllable, Error> {
+        let mut syllable = *self;
+
+        match syllable {
+            Syllable::Primitive(ref mut v) => v.tone = tone,
+            Syllable::Normal(ref mut v) => v.tone = tone,
+            Syllable::Rhyme(ref mut v) => v.tone = tone,
+            Syllable::Nasal(v) => return NasalSyllable::new(v.initial, tone).map(Syllable::from),
+        }
+
+        Ok(syllable)
+    }
+
+    // 根据声母、韵母以及声调组合音节
+    //
+    // 声母加空韵组成 `zhi`、`chi`、`shi`、`ri`、`zi`、`ci`、`si` 或者鼻音音节 `m`、`n`，
+    // 和整体认读音节拼写相同的零声母音节 ( 例如 `yi`、`wu` ) 会被当成整体认读音节。
+    pub(crate) fn from_parts(initial: Option<Initial>, rhyme: Option<Rhyme>, tone: Tone) -> Result<Syllable, Error> {
+        match (initial, rhyme) {
+            (Some(initial), Some(rhyme)) => NormalSyllable::new(initial, rhyme, tone).map(Syllable::from),
+            (Some(initial), None) => {
+                if initial == Initial::M || initial == Initial::N {
+                    return NasalSyllable::new(initial, tone).map(Syllable::from);
+                }
+
+                let mut spelling = initial.letters();
+                spelling.push('i');
+
+                let record = spelling.to_record()
+                                .ok_or_else(|| Error::new(ErrorKind::InvalidSyllable, spelling, 0))?;
+                PrimitiveSyllable::new(record, tone).map(Syllable::from)
+            },
+            (None, Some(rhyme)) => {
+                let syllable = RhymeSyllable::new(rhyme, tone)?;
+
+                match syllable.spelling().to_record() {
+                    Some(record) if syllable.is_primitive() => {
+                        PrimitiveSyllable::new(record, tone).map(Syllable::from)
+                    },
+                    _ => Ok(Syllable::from(syllable)),
+                }
+            },
+            (None, None) => Err(Error::new(ErrorKind::InvalidSyllable, "", 0)),
+        }
+    }
+}
+
+impl From<PrimitiveSyllable> for Syllable {
+    fn from(v: PrimitiveSyllable) -> Syllable {
+        Syllable::Primitive(v)
+    }
+}
+
+impl From<NormalSyllable> for Syllable {
+    fn from(v: NormalSyllable) -> Syllable {
+        Syllable::Normal(v)
+    }
+}
+
+impl From<RhymeSyllable> for Syllable {
+    fn from(v: RhymeSyllable) -> Syllable {
+        Syllable::Rhyme(v)
+    }
+}
+
+impl From<NasalSyllable> for Syllable {
+    fn from(v: NasalSyllable) -> Syllable {
+        Syllable::Nasal(v)
+    }
+}
+
+impl fmt::Display for Syllable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.show_to(f, ToneFormat::Symbol)
+    }
 }
 
 
 // zhi chi shi ri zi ci si yi wu yu ye yue yuan yin yun ying
 /// 整体认读音节
-#[derive(Debug)]
+// NOTE: 只保存在整体认读音节表当中的位置，这样音节只占用几个字节。
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
 pub struct PrimitiveSyllable {
-    primitive: [char; 4],
-    vowel: char,
+    index: u8,
     tone: Tone,
 }
 
 impl PrimitiveSyllable {
-    pub fn new(chars: [char; 4], tone: Tone) -> Result<Self, ()> {
+    pub fn new(chars: [char; 4], tone: Tone) -> Result<Self, Error> {
         
-        for (primitive, vowel) in PRIMITIVE_SYLLABLE_TABLE.iter() {
-            if primitive == &chars {
-                return Ok(PrimitiveSyllable{ primitive: chars, vowel: *vowel, tone });
-            }
+        if let Some(index) = PRIMITIVE_SYLLABLE_TABLE.iter().position(|(primitive, _vowel)| *primitive == chars) {
+            return Ok(PrimitiveSyllable{ index: index as u8, tone });
         }
 
-        return Err(());
+        let input = Letters::from_chars(chars.iter().cloned().filter(|c| *c != ' '))
+                        .expect("表格记录最多只有 4 个字母！");
+        Err(Error::new(ErrorKind::InvalidSyllable, input, 0))
+    }
+
+    // 在整体认读音节表当中的位置
+    pub(crate) fn index(&self) -> usize {
+        self.index as usize
+    }
+
+    // 整体认读音节表当中的记录 ( 使用空格填充 )
+    fn primitive(&self) -> [char; 4] {
+        PRIMITIVE_SYLLABLE_TABLE[self.index()].0
+    }
+
+    // 不带声调的拼写
+    pub(crate) fn spelling(&self) -> Letters {
+        Letters::from_chars(self.primitive().iter().cloned().filter(|c| *c != ' '))
+            .expect("整体认读音节最多只有 4 个字母！")
+    }
+
+    // 按照实际的读音拆分声母、韵母部分，`zhi`、`chi`、`shi`、`ri`、`zi`、`ci`、`si` 的韵母为空韵
+    pub(crate) fn parts(&self) -> (Option<Initial>, Option<Rhyme>) {
+        let rhyme = |chars: [char; 4]| Rhyme::new(chars).ok();
+
+        match self.primitive() {
+            ['z', 'h', 'i', ' '] => (Initial::new('ẑ').ok(), None),
+            ['c', 'h', 'i', ' '] => (Initial::new('ĉ').ok(), None),
+            ['s', 'h', 'i', ' '] => (Initial::new('ŝ').ok(), None),
+            ['y', 'i', ' ', ' '] => (None, rhyme(['i', ' ', ' ', ' '])),
+            ['w', 'u', ' ', ' '] => (None, rhyme(['u', ' ', ' ', ' '])),
+            ['y', 'u', ' ', ' '] => (None, rhyme(['ü', ' ', ' ', ' '])),
+            ['y', 'e', ' ', ' '] => (None, rhyme(['i', 'e', ' ', ' '])),
+            ['y', 'u', 'e', ' '] => (None, rhyme(['ü', 'e', ' ', ' '])),
+            ['y', 'u', 'a', 'n'] => (None, rhyme(['ü', 'a', 'n', ' '])),
+            ['y', 'i', 'n', ' '] => (None, rhyme(['i', 'n', ' ', ' '])),
+            ['y', 'u', 'n', ' '] => (None, rhyme(['ü', 'n', ' ', ' '])),
+            ['y', 'i', 'n', 'g'] => (None, rhyme(['i', 'n', 'g', ' '])),
+            // ri, zi, ci, si
+            [c, 'i', ' ', ' '] => (Initial::new(c).ok(), None),
+            _ => unreachable!("整体认读音节必须来自整体认读音节表！"),
+        }
     }
 }
 
-impl Syllable for PrimitiveSyllable {
-    fn kind(&self) -> SyllableKind {
+impl PrimitiveSyllable {
+    pub fn kind(&self) -> SyllableKind {
         SyllableKind::Primitive
     }
 
-    fn initial(&self) -> Option<Initial> {
+    pub fn initial(&self) -> Option<Initial> {
         None
     }
     
-    fn finals(&self) -> Option<Rhyme> {
+    pub fn finals(&self) -> Option<Rhyme> {
         None
     }
 
-    fn vowel(&self) -> char {
-        self.vowel
+    pub fn vowel(&self) -> char {
+        PRIMITIVE_SYLLABLE_TABLE[self.index()].1
     }
 
-    fn tone(&self) -> Tone {
+    pub fn tone(&self) -> Tone {
         self.tone
     }
+
+    pub fn tone_mark(&self) -> ToneMark {
+        ToneMark::new(self.vowel(), self.tone())
+            .expect("错误的音节元音音调！")
+    }
 }
 
-impl fmt::Display for PrimitiveSyllable {
+impl fmt::Debug for PrimitiveSyllable {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let tone_mark = self.tone_mark();
+        f.debug_struct("PrimitiveSyllable")
+            .field("primitive", &format_args!("{}", self.spelling()))
+            .field("tone", &self.tone)
+            .finish()
+    }
+}
+
+// 按照拼写排序，拼写相同的按照声调排序
+impl PartialOrd for PrimitiveSyllable {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-        let s = self.primitive.iter().collect::<String>()
-            .replace(self.vowel, tone_mark.to_string().as_ref())
-            .replace(' ', "");
+impl Ord for PrimitiveSyllable {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        (self.primitive(), self.tone).cmp(&(other.primitive(), other.tone))
+    }
+}
 
-        write!(f, "{}", s)
+impl fmt::Display for PrimitiveSyllable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.show_to(f, ToneFormat::Symbol)
     }
 }
 
 
 /// 常规音节
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
 pub struct NormalSyllable {
     initial: Initial,
     rhyme: Rhyme,
@@ -112,164 +371,182 @@ pub struct NormalSyllable {
 }
 
 impl NormalSyllable {
-    pub fn new(initial: Initial, rhyme: Rhyme, tone: Tone) -> Result<Self, ()> {
+    pub fn new(initial: Initial, rhyme: Rhyme, tone: Tone) -> Result<Self, Error> {
+        if !NormalSyllable::is_legal(initial, rhyme) {
+            let input = format_args!("{}{}", initial, rhyme);
+            return Err(Error::new(ErrorKind::IllegalCombination, input, 0));
+        }
+
         Ok(NormalSyllable {
             initial,
             rhyme,
             tone,
         })
     }
+
+    /// 判断声母和韵母能否拼合 ( 参见 `NORMAL_SYLLABLE_TABLE` )
+    pub fn is_legal(initial: Initial, rhyme: Rhyme) -> bool {
+        let rhyme = rhyme.letters();
+
+        NORMAL_SYLLABLE_TABLE.iter()
+            .filter(|(c, _)| Initial::new(*c) == Ok(initial))
+            .any(|(_, finals)| finals.iter().any(|finals| rhyme.eq_str(finals)))
+    }
+
+    // 不带声调的拼写
+    pub(crate) fn spelling(&self) -> Letters {
+        let rhyme = self.rhyme.letters();
+        let is_jqx = self.initial == Initial::J
+            || self.initial == Initial::Q
+            || self.initial == Initial::X;
+
+        let mut spelling = self.initial.letters();
+
+        for c in rhyme.iter() {
+            let c = match (&rhyme[..], *c) {
+                // 省写规则: iou、uei、uen 前面加声母的时候，写成 iu、ui、un，例如 niu（牛）、gui（归）、lun（论）。
+                (['i', 'o', 'u'], 'o') | (['u', 'e', 'i'], 'e') | (['u', 'e', 'n'], 'e') => continue,
+                // ü 行的韵母跟声母 j，q，x 拼的时候，写成 ju（居），qu（取），xu（虚），ü 上两点也省略；
+                // 但是跟声母 l，n 拼的时候，仍然写成 lü（吕），lüe（略），nü（女），nüe（虐）。
+                (_, 'ü') if is_jqx => 'u',
+                (_, c) => c,
+            };
+
+            spelling.push(c);
+        }
+
+        spelling
+    }
 }
 
-impl Syllable for NormalSyllable {
-    fn kind(&self) -> SyllableKind {
-        SyllableKind::Primitive
+impl NormalSyllable {
+    pub fn kind(&self) -> SyllableKind {
+        SyllableKind::Normal
     }
 
-    fn initial(&self) -> Option<Initial> {
+    pub fn initial(&self) -> Option<Initial> {
         Some(self.initial)
     }
     
-    fn finals(&self) -> Option<Rhyme> {
+    pub fn finals(&self) -> Option<Rhyme> {
         Some(self.rhyme)
     }
 
-    fn vowel(&self) -> char {
+    pub fn vowel(&self) -> char {
         self.rhyme.vowel()
     }
 
-    fn tone(&self) -> Tone {
+    pub fn tone(&self) -> Tone {
         self.tone
     }
+
+    pub fn tone_mark(&self) -> ToneMark {
+        ToneMark::new(self.vowel(), self.tone())
+            .expect("错误的音节元音音调！")
+    }
 }
 
 impl fmt::Display for NormalSyllable {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut vowel = self.vowel();
-        let mut tone_mark = self.tone_mark();
-
-        // let u2a = ["ü", "üe", "üan", "ün"];
-        // let u2b = ["yu", "yue", "yuan", "yun"];
-
-        // 替换规则: iou、uei、uen 前面加声母的时候，写成 iu、ui、un，例如 niu（牛）、gui（归）、lun（论）。
-        let mut s = format!("{}", self.rhyme)
-                        .replace("iou", "iu")
-                        .replace("uei", "ui")
-                        .replace("uen", "un");
-
-        if self.initial == Initial::J 
-            || self.initial == Initial::Q 
-            || self.initial == Initial::X {
-            // ü 行的韵母跟声母 j，q，x 拼的时候，写成 ju（居），qu（取），xu（虚），jue（觉），que（缺），xue（学），ü 上两点也省略；
-            // 但是跟声母 l，n 拼的时候，仍然写成 lü（吕），lüe（略），nü（女），nüe（虐）。
-            // ['ü', ' ', ' ', ' '], ['ü', 'e', ' ', ' ']
-            if &s == "ü" || &s == "üe" {
-                // 执行替换规则
-                vowel = 'u';
-                tone_mark = ToneMark::new(vowel, self.tone).unwrap();
-                s = s.replace('ü', "u");
-            }
-        }
-
-        s = s.replace(vowel, tone_mark.to_string().as_ref())
-                    .replace(' ', "");
-        write!(f, "{}{}", self.initial, s)
+        self.show_to(f, ToneFormat::Symbol)
     }
 }
 
 
 /// 自成音节（不包含声母，只有韵母部分）
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
 pub struct RhymeSyllable {
     rhyme: Rhyme,
     tone: Tone,
 }
 
 impl RhymeSyllable {
-    pub fn new(rhyme: Rhyme, tone: Tone) -> Result<Self, ()> {
+    pub fn new(rhyme: Rhyme, tone: Tone) -> Result<Self, Error> {
+        // NOTE: 韵母 `ong` 没有零声母的形式 ( `weng` 的韵母是 `ueng` )
+        if rhyme.letters().eq_str("ong") {
+            return Err(Error::new(ErrorKind::IllegalCombination, "ong", 0));
+        }
+
         Ok(RhymeSyllable {
             rhyme,
             tone,
         })
     }
+
+    // 不带声调的拼写
+    pub(crate) fn spelling(&self) -> Letters {
+        let rhyme = self.rhyme.letters();
+
+        // 执行拼音方案当中的补写规则 ( i => yi, ia => ya, u => wu, ü => yu, ... )
+        let (prefix, skip): (&[char], usize) = match rhyme[..] {
+            ['i'] | ['i', 'n'] | ['i', 'n', 'g'] => (&['y'], 0),
+            ['u'] => (&['w'], 0),
+            ['ü', ..] => (&['y', 'u'], 1),
+            ['i', ..] => (&['y'], 1),
+            ['u', ..] => (&['w'], 1),
+            _ => (&[], 0),
+        };
+
+        Letters::from_chars(prefix.iter().chain(rhyme.iter().skip(skip)).cloned())
+            .expect("自成音节最多只有 5 个字母！")
+    }
+
+    // 是否和整体认读音节的拼写相同 ( 例如 `yi`、`wu`、`yu` )
+    pub(crate) fn is_primitive(&self) -> bool {
+        let spelling = self.spelling();
+
+        PRIMITIVE_SYLLABLE_TABLE.iter()
+            .any(|(primitive, _vowel)| spelling.to_record() == Some(*primitive))
+    }
 }
 
-impl Syllable for RhymeSyllable {
-    fn kind(&self) -> SyllableKind {
+impl RhymeSyllable {
+    pub fn kind(&self) -> SyllableKind {
         SyllableKind::Rhyme
     }
 
-    fn initial(&self) -> Option<Initial> {
+    pub fn initial(&self) -> Option<Initial> {
         None
     }
     
-    fn finals(&self) -> Option<Rhyme> {
+    pub fn finals(&self) -> Option<Rhyme> {
         Some(self.rhyme)
     }
 
-    fn vowel(&self) -> char {
+    pub fn vowel(&self) -> char {
         self.rhyme.vowel()
     }
 
-    fn tone(&self) -> Tone {
+    pub fn tone(&self) -> Tone {
         self.tone
     }
+
+    pub fn tone_mark(&self) -> ToneMark {
+        ToneMark::new(self.vowel(), self.tone())
+            .expect("错误的音节元音音调！")
+    }
 }
 
 impl fmt::Display for RhymeSyllable {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let vowel = self.vowel();
-        let tone_mark = self.tone_mark();
-
-        let mut s = format!("{}", self.rhyme)
-                    .replace(vowel, tone_mark.to_string().as_ref())
-                    .replace(' ', "");
-        
-        // 执行拼音方案当中的补写规则 ( i => yi, ia => ya, u => wu, ... )
-        let ia = ["i", "ia", "ie", "iao", "iou", "ian", "in", "iang", "ing", "iong"];
-        let ib = ["yi", "ya", "ye", "yao", "you", "yan", "yin", "yang", "ying", "yong"];
-
-        let ua = ["u", "ua", "uo", "uai", "uei", "uan", "uen", "uang", "ueng"];
-        let ub = ["wu", "wa", "wo", "wai", "wei", "wan", "wen", "wang", "weng"];
-        
-        let u2a = ["ü", "üe", "üan", "ün"];
-        let u2b = ["yu", "yue", "yuan", "yun"];
-
-        // BUG: 音调需要延迟处理
-        for (a, b) in ia.iter().zip(ib.iter()) {
-            if a == &s {
-                s = s.replace(a, b);
-            }
-        }
-
-        for (a, b) in ua.iter().zip(ub.iter()) {
-            if a == &s {
-                s = s.replace(a, b);
-            }
-        }
-
-        for (a, b) in u2a.iter().zip(u2b.iter()) {
-            if a == &s {
-                s = s.replace(a, b);
-            }
-        }
-
-        write!(f, "{}", s)
+        self.show_to(f, ToneFormat::Symbol)
     }
 }
 
 
 /// 鼻音音节 （不属于汉语拼音规范）
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
 pub struct NasalSyllable {
     initial: Initial,
     tone: Tone,
 }
 
 impl NasalSyllable {
-    pub fn new(initial: Initial, tone: Tone) -> Result<Self, ()> {
+    pub fn new(initial: Initial, tone: Tone) -> Result<Self, Error> {
         //             'ń' | 'ň' | 'ǹ'
         //             'ḿ' |       "m̀"
+        let input = initial.letters();
+
         if initial == Initial::M {
             if tone == Tone::Second || tone == Tone::Fourth {
                 Ok(NasalSyllable {
@@ -277,7 +554,7 @@ impl NasalSyllable {
                     tone,
                 })
             } else {
-                Err(())
+                Err(Error::new(ErrorKind::InvalidTone, input, 0))
             }
         } else if initial == Initial::N {
             if tone == Tone::Second || tone == Tone::Third 
@@ -287,28 +564,33 @@ impl NasalSyllable {
                     tone,
                 })
             } else {
-                Err(())
+                Err(Error::new(ErrorKind::InvalidTone, input, 0))
             }
         } else {
-            Err(())
+            Err(Error::new(ErrorKind::InvalidInitial(input[0]), input, 0))
         }
     }
+
+    // 不带声调的拼写
+    pub(crate) fn spelling(&self) -> Letters {
+        self.initial.letters()
+    }
 }
 
-impl Syllable for NasalSyllable {
-    fn kind(&self) -> SyllableKind {
+impl NasalSyllable {
+    pub fn kind(&self) -> SyllableKind {
         SyllableKind::Nasal
     }
 
-    fn initial(&self) -> Option<Initial> {
+    pub fn initial(&self) -> Option<Initial> {
         Some(self.initial)
     }
     
-    fn finals(&self) -> Option<Rhyme> {
+    pub fn finals(&self) -> Option<Rhyme> {
         None
     }
 
-    fn vowel(&self) -> char {
+    pub fn vowel(&self) -> char {
         if self.initial ==  Initial::M {
             'm'
         } else if self.initial == Initial::N {
@@ -318,223 +600,394 @@ impl Syllable for NasalSyllable {
         }
     }
 
-    fn tone(&self) -> Tone {
+    pub fn tone(&self) -> Tone {
         self.tone
     }
+
+    pub fn tone_mark(&self) -> ToneMark {
+        ToneMark::new(self.vowel(), self.tone())
+            .expect("错误的音节元音音调！")
+    }
 }
 
 impl fmt::Display for NasalSyllable {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.tone_mark())
+        self.show_to(f, ToneFormat::Symbol)
     }
 }
 
 
 
-impl FromStr for PrimitiveSyllable {
-    type Err = ();
+// 解析不带声调的韵母部分 ( 例如 `iou`, `üan` )，`offset` 为韵母在原始输入 `input` 当中的字符位置
+fn parse_rhyme(input: &str, offset: usize, letters: &[char]) -> Result<Rhyme, Error> {
+    let invalid = || {
+        let rhyme = Letters::from_chars(letters.iter().cloned()).unwrap_or_else(Letters::new);
+        Error::new(ErrorKind::InvalidRhyme(ErrorText::new(rhyme)), input, offset)
+    };
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let tone_marks = ToneMark::find(&s).iter()
-                            .cloned()
-                            .filter(|tone_mark| tone_mark.tone() != Tone::Neutral)
-                            .collect::<Vec<ToneMark>>();
-        
-        if tone_marks.len() > 1 {
-            return Err(());
+    let query = Letters::from_chars(letters.iter().cloned())
+                    .and_then(|letters| letters.to_record())
+                    .ok_or_else(invalid)?;
+
+    Rhyme::new(query).map_err(|e| e.with_input(input, offset))
+}
+
+// 拆分音节当中的声调，支持 调号 ( zhōng, 包括组合附加符号 ) 以及 数字 ( zhong1, zho1ng, lv3, ma5 ) 两种标记方式，
+// 返回不带声调的小写字母（ `v` 被还原为 `ü` ）以及声调。
+pub(crate) fn split_tone(s: &str) -> Result<(Letters, Tone), Error> {
+    split_tone_chars(s, s.chars())
+}
+
+// 同 `split_tone`，`chars` 为预先处理过的输入字符，`s` 只用于错误信息
+pub(crate) fn split_tone_chars<I: Iterator<Item = char>>(s: &str, chars: I) -> Result<(Letters, Tone), Error> {
+    let mut letters = Letters::new();
+    let mut tone = Tone::Neutral;
+    // 第一个声调 ( 调号或者数字 ) 的字符位置
+    let mut tone_position: Option<usize> = None;
+    // 位于音节当中的声调数字: ( 字符位置, 数字之前的字母数量 )
+    let mut inner_digit: Option<(usize, usize)> = None;
+    // 需要跳过的组合附加符号数量 ( 例如 `ê̄` 当中的 `\u{304}` )
+    let mut skip = 0usize;
+
+    // NOTE: 大写字母转换为小写 ( Zhōng, ZHONG1 )，小写形式不止一个字符的字母保持不变
+    let mut chars = chars.map(|c| {
+                            let mut lower = c.to_lowercase();
+                            match (lower.next(), lower.next()) {
+                                (Some(lower), None) => lower,
+                                _ => c,
+                            }
+                        })
+                        .enumerate()
+                        .peekable();
+
+    while let Some((position, c)) = chars.next() {
+        if skip > 0 {
+            skip -= 1;
+            continue;
         }
 
-        let mut tone: Tone = Tone::Neutral;
-        
-        let chars = if tone_marks.len() == 1 {
-            let tone_mark = tone_marks[0];
-            let mark = tone_mark.mark();
-            tone = tone_mark.tone();
-            s.replace(format!("{}", tone_mark).as_str(), mark.to_string().as_ref())
-                .chars()
-                .collect::<Vec<char>>()
+        let next = chars.peek().map(|(_, next)| *next);
+        // NOTE: 调号都不是 ASCII 字符
+        let tone_mark = TONE_MARK_TABLE.iter()
+                            .filter(|_| !c.is_ascii())
+                            .filter(|(_k, _c, t)| t != &Tone::Neutral)
+                            .find(|(k, _c, _t)| {
+                                let mut k = k.chars();
+                                k.next() == Some(c) && k.next().is_none_or(|m| next == Some(m))
+                            });
+
+        let (letter, found) = if let Some((k, base, t)) = tone_mark {
+            skip = k.chars().count() - 1;
+            (Some(*base), Some(*t))
+        } else if c.is_ascii_digit() {
+            let t = match c {
+                '0' | '5' => Tone::Neutral,
+                '1' => Tone::First,
+                '2' => Tone::Second,
+                '3' => Tone::Third,
+                '4' => Tone::Fourth,
+                _ => return Err(Error::new(ErrorKind::InvalidTone, s, position)),
+            };
+
+            if next.is_some() {
+                inner_digit = Some((position, letters.len()));
+            }
+
+            (None, Some(t))
+        } else if let Some(index) = COMBINING_TONE_MARKS.iter().position(|m| *m == c) {
+            (None, Some(TONE_TABLE[index]))
+        } else if c == 'v' {
+            (Some('ü'), None)
         } else {
-            s.to_string().chars().collect::<Vec<char>>()
+            (Some(c), None)
         };
 
-        let mut query: [char; 4] = [' '; 4];
-        let n = cmp::min(query.len(), chars.len());
+        if let Some(t) = found {
+            if tone_position.is_some() {
+                return Err(Error::new(ErrorKind::MultipleToneMarks, s, position));
+            }
+
+            tone = t;
+            tone_position = Some(position);
+        }
+
+        if let Some(letter) = letter {
+            if !letters.push(letter) {
+                return Err(Error::new(ErrorKind::InvalidSyllable, s, 0));
+            }
+        }
+    }
 
-        for i in 0..n {
-            query[i] = chars[i];
+    // 数字位于音节当中的时候，必须紧跟在标注声调的字母之后 ( zho1ng )
+    if let Some((position, head)) = inner_digit {
+        if tone_mark_position(&letters) + 1 != head {
+            return Err(Error::new(ErrorKind::MisplacedToneNumber, s, position));
         }
+    }
+
+    Ok((letters, tone))
+}
+
+impl PrimitiveSyllable {
+    // 解析 `split_tone` 拆分以后的字母以及声调，`s` 为原始输入
+    fn parse_letters(s: &str, letters: &Letters, tone: Tone) -> Result<Self, Error> {
+        let query = letters.to_record()
+                        .ok_or_else(|| Error::new(ErrorKind::InvalidSyllable, s, 0))?;
 
-        PrimitiveSyllable::new(query, tone)
+        PrimitiveSyllable::new(query, tone).map_err(|e| e.with_input(s, 0))
     }
 }
 
-impl FromStr for NormalSyllable {
-    type Err = ();
+impl FromStr for PrimitiveSyllable {
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() < 2 {
-            return Err(());
-        }
+        let (letters, tone) = split_tone(s)?;
+        PrimitiveSyllable::parse_letters(s, &letters, tone)
+    }
+}
 
-        let tone_marks = ToneMark::find(&s).iter()
-                            .cloned()
-                            .filter(|tone_mark| tone_mark.tone() != Tone::Neutral)
-                            .collect::<Vec<ToneMark>>();
-        
-        if tone_marks.len() > 1 {
-            return Err(());
-        }
+impl NormalSyllable {
+    // 解析 `split_tone` 拆分以后的字母以及声调，`s` 为原始输入
+    fn parse_letters(s: &str, letters: &Letters, tone: Tone) -> Result<Self, Error> {
+        // 韵母在原始输入当中的位置
+        let (first_char, offset) = match letters[..] {
+            ['z', 'h', _, ..] => ('ẑ', 2),
+            ['c', 'h', _, ..] => ('ĉ', 2),
+            ['s', 'h', _, ..] => ('ŝ', 2),
+            [c, _, ..] => (c, 1),
+            _ => return Err(Error::new(ErrorKind::InvalidSyllable, s, 0)),
+        };
 
-        let mut tone: Tone = Tone::Neutral;
-        
-        let text = if tone_marks.len() == 1 {
-            let tone_mark = tone_marks[0];
-            let mark = tone_mark.mark();
-            tone = tone_mark.tone();
-            s.replace(format!("{}", tone_mark).as_str(), mark.to_string().as_ref())
-                .replace("zh", "ẑ")
-                .replace("ch", "ĉ")
-                .replace("sh", "ŝ")
-        } else {
-            s.replace("zh", "ẑ")
-                .replace("ch", "ĉ")
-                .replace("sh", "ŝ")
+        let initial = Initial::new(first_char).map_err(|e| e.with_input(s, 0))?;
+        let is_jqx = initial == Initial::J || initial == Initial::Q || initial == Initial::X;
+
+        let finals: &[char] = match (is_jqx, &letters[offset..]) {
+            // NOTE: 还原规则
+            (_, ['i', 'u']) => &['i', 'o', 'u'],
+            (_, ['u', 'i']) => &['u', 'e', 'i'],
+            // NOTE: `ü` 行的韵母跟声母 j，q，x 拼的时候，`ü` 上两点省略。
+            (true, ['u', 'n']) => &['ü', 'n'],
+            (true, ['u']) => &['ü'],
+            (true, ['u', 'e']) => &['ü', 'e'],
+            (true, ['u', 'a', 'n']) => &['ü', 'a', 'n'],
+            (_, ['u', 'n']) => &['u', 'e', 'n'],
+            (_, finals) => finals,
         };
 
-        let chars = text.chars().collect::<Vec<char>>();
-        let first_char = chars[0];
+        let rhyme = parse_rhyme(s, offset, finals)?;
 
-        match Initial::new(first_char) {
-            Ok(initial) => {
-                let mut finals = chars[1..].iter().collect::<String>();
+        NormalSyllable::new(initial, rhyme, tone).map_err(|e| e.with_input(s, offset))
+    }
+}
 
-                // NOTE: 还原规则
-                if &finals == "iu" {
-                    finals = "iou".to_string();
-                }
-                if &finals == "ui" {
-                    finals = "uei".to_string();
-                }
-                if &finals == "un" {
-                    finals = "uen".to_string();
-                }
+impl FromStr for NormalSyllable {
+    type Err = Error;
 
-                if initial == Initial::J || initial == Initial::Q || initial == Initial::X {
-                    if &finals == "u" || &finals == "ue" {
-                        finals = finals.replace('u', "ü");
-                    }
-                }
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (letters, tone) = split_tone(s)?;
+        NormalSyllable::parse_letters(s, &letters, tone)
+    }
+}
 
-                match RhymeSyllable::from_str(&finals) {
-                    Ok(rhyme_syllable) => {
-                        NormalSyllable::new(initial, rhyme_syllable.rhyme, tone)
-                    },
-                    Err(e) => Err(e),
+impl RhymeSyllable {
+    // 解析 `split_tone` 拆分以后的字母以及声调，`s` 为原始输入
+    fn parse_letters(s: &str, letters: &Letters, tone: Tone) -> Result<Self, Error> {
+        if s.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidSyllable, s, 0));
+        }
+
+        let mut written = Letters::new();
+        for c in letters.iter() {
+            let ok = if *c == 'ŋ' {
+                written.push('n') && written.push('g')
+            } else {
+                written.push(*c)
+            };
+
+            if !ok {
+                return Err(Error::new(ErrorKind::InvalidSyllable, s, 0));
+            }
+        }
+
+        // `i` 列规则
+        let ia = ["yi", "ya", "ye", "yao", "you", "yan", "yin", "yang", "ying", "yong"];
+        // `u` 列规则
+        let ua = ["wu", "wa", "wo", "wai", "wei", "wan", "wen", "wang", "weng"];
+        // `ü` 列规则
+        let u2a = ["yu", "yue", "yuan", "yun"];
+
+        let rule = ia.iter().zip(RHYME_TABLE_COLUMN_I.iter())
+                    .chain(ua.iter().zip(RHYME_TABLE_COLUMN_U.iter()))
+                    .chain(u2a.iter().zip(RHYME_TABLE_COLUMN_YU.iter()))
+                    .find(|(a, _b)| written.eq_str(a));
+
+        let rhyme = match rule {
+            Some((_a, b)) => Rhyme::new(*b).map_err(|e| e.with_input(s, 0))?,
+            None => {
+                // NOTE: `i`、`u`、`ü` 开头的韵母自成音节时必须按照补写规则书写 ( 例如 `yi`，而不是 `i` )
+                //       韵母本身无效的时候报告韵母错误 ( 例如 `iai` )
+                if let Some('i') | Some('u') | Some('ü') = written.first() {
+                    parse_rhyme(s, 0, &written)?;
+                    return Err(Error::new(ErrorKind::InvalidSyllable, s, 0));
                 }
+
+                parse_rhyme(s, 0, &written)?
             },
-            Err(e) => Err(e),
-        }
+        };
+
+        RhymeSyllable::new(rhyme, tone).map_err(|e| e.with_input(s, 0))
     }
 }
 
 impl FromStr for RhymeSyllable {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() < 1 {
-            return Err(());
-        }
+        let (letters, tone) = split_tone(s)?;
+        RhymeSyllable::parse_letters(s, &letters, tone)
+    }
+}
 
-        let tone_marks = ToneMark::find(&s).iter()
-                            .cloned()
-                            .filter(|tone_mark| tone_mark.tone() != Tone::Neutral)
-                            .collect::<Vec<ToneMark>>();
-        
-        if tone_marks.len() > 1 {
-            return Err(());
+
+impl NasalSyllable {
+    // 解析 `split_tone` 拆分以后的字母以及声调，`s` 为原始输入
+    fn parse_letters(s: &str, letters: &Letters, tone: Tone) -> Result<Self, Error> {
+        // 'ń' | 'ň' | 'ǹ'
+        // 'ḿ' |       "m̀"
+        match letters[..] {
+            ['n'] => NasalSyllable::new(Initial::N, tone),
+            ['m'] => NasalSyllable::new(Initial::M, tone),
+            _ => Err(Error::new(ErrorKind::InvalidSyllable, s, 0)),
+        }.map_err(|e| e.with_input(s, 0))
+    }
+}
+
+impl FromStr for NasalSyllable {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (letters, tone) = split_tone(s)?;
+        NasalSyllable::parse_letters(s, &letters, tone)
+    }
+}
+
+
+impl FromStr for Syllable {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (letters, tone) = split_tone(s)?;
+
+        if let Ok(v) = PrimitiveSyllable::parse_letters(s, &letters, tone) {
+            return Ok(Syllable::Primitive(v));
         }
 
-        let mut tone: Tone = Tone::Neutral;
-        
-        let mut text = if tone_marks.len() == 1 {
-            let tone_mark = tone_marks[0];
-            let mark = tone_mark.mark();
-            tone = tone_mark.tone();
-            s.replace(format!("{}", tone_mark).as_str(), mark.to_string().as_ref())
-                .replace('ŋ', "ng")
-        } else {
-            s.replace('ŋ', "ng")
+        let normal = match NormalSyllable::parse_letters(s, &letters, tone) {
+            Ok(v) => return Ok(Syllable::Normal(v)),
+            Err(e) => e,
+        };
+        let rhyme = match RhymeSyllable::parse_letters(s, &letters, tone) {
+            Ok(v) => return Ok(Syllable::Rhyme(v)),
+            Err(e) => e,
+        };
+        let nasal = match NasalSyllable::parse_letters(s, &letters, tone) {
+            Ok(v) => return Ok(Syllable::Nasal(v)),
+            Err(e) => e,
         };
 
-        // `i` 列规则
-        let ia = ["yi", "ya", "ye", "yao", "you", "yan", "yin", "yang", "ying", "yong"];
-        for (a, b) in ia.iter().zip(RHYME_TABLE_COLUMN_I.iter()) {
-            if a == &text {
-                text = text.replace(a, b.iter().collect::<String>().replace(' ', "").as_str() );
-            }
+        // NOTE: 选择最有意义的错误: 鼻音音节的声调错误，然后按照第一个字母区分:
+        //       辅音字母开头的报告常规音节的错误 ( 声母错误或者韵母错误 )，没有元音字母的报告音节错误 ( hm, ng )，
+        //       其它情况报告自成音节的错误。
+        if nasal.kind() == &ErrorKind::InvalidTone {
+            return Err(nasal);
         }
 
-        // `u` 列规则
-        let ua = ["wu", "wa", "wo", "wai", "wei", "wan", "wen", "wang", "weng"];
-        for (a, b) in ua.iter().zip(RHYME_TABLE_COLUMN_U.iter()) {
-            if a == &text {
-                text = text.replace(a, b.iter().collect::<String>().replace(' ', "").as_str() );
-            }
+        let is_vowel = |c: &char| matches!(*c, 'a' | 'o' | 'e' | 'ê' | 'i' | 'u' | 'ü');
+
+        match letters.first() {
+            Some(c) if is_vowel(c) || *c == 'y' || *c == 'w' || *c == 'ŋ' => Err(rhyme),
+            Some(_) if !letters.iter().any(is_vowel) => Err(Error::new(ErrorKind::InvalidSyllable, s, 0)),
+            Some(_) => Err(normal),
+            None => Err(Error::new(ErrorKind::InvalidSyllable, s, 0)),
         }
+    }
+}
 
-        // `ü` 列规则
-        let u2a = ["yu", "yue", "yuan", "yun"];
-        for (a, b) in u2a.iter().zip(RHYME_TABLE_COLUMN_YU.iter()) {
-            if a == &text {
-                text = text.replace(a, b.iter().collect::<String>().replace(' ', "").as_str() );
+pub fn from_str(s: &str) -> Result<Syllable, Error> {
+    s.parse::<Syllable>()
+}
+
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{ Syllable, from_str };
+    use tone::{ Tone, ToneFormat, TONE_TABLE };
+    use letters::Letters;
+    use format::Show;
+    use error::{ ErrorKind, ErrorText };
+    use inventory::syllables;
+
+    #[test]
+    fn round_trip() {
+        for syllable in syllables(&TONE_TABLE) {
+            for tone_format in [ToneFormat::Symbol, ToneFormat::Index].iter() {
+                let s = syllable.show(*tone_format);
+                assert_eq!(from_str(&s), Ok(syllable), "{}", s);
             }
         }
+    }
 
-        let chars = text.chars().collect::<Vec<char>>();
-        let mut query: [char; 4] = [' '; 4];
+    #[test]
+    fn numbered_tones() {
+        let zhong: Syllable = "zhōng".parse().unwrap();
 
-        let n = cmp::min(query.len(), chars.len());
+        assert_eq!(from_str("zho1ng"), Ok(zhong));
+        assert_eq!(from_str("zhong1"), Ok(zhong));
+        assert_eq!(from_str("lv3"), from_str("lǚ"));
+        assert_eq!(from_str("ma0").map(|s| s.tone()), Ok(Tone::Neutral));
+        assert_eq!(from_str("ma0"), from_str("ma5"));
 
-        for i in 0..n {
-            query[i] = chars[i];
-        }
+        assert_eq!(from_str("zhōng1").unwrap_err().kind(), &ErrorKind::MultipleToneMarks);
+        assert_eq!(from_str("zh1ong").unwrap_err().kind(), &ErrorKind::MisplacedToneNumber);
+        assert_eq!(from_str("ma6").unwrap_err().kind(), &ErrorKind::InvalidTone);
+    }
 
-        match Rhyme::new(query) {
-            Ok(rhyme) => RhymeSyllable::new(rhyme, tone),
-            Err(e) => Err(e),
-        }
+    #[test]
+    fn uppercase() {
+        let zhong: Syllable = "zhōng".parse().unwrap();
+
+        assert_eq!(from_str("Zhōng"), Ok(zhong));
+        assert_eq!(from_str("ZHONG1"), Ok(zhong));
+        assert_eq!(from_str("LǙ"), from_str("lǚ"));
+        assert_eq!(from_str("Ê̄"), from_str("ê̄"));
     }
-}
 
+    #[test]
+    fn size() {
+        use core::mem::size_of;
+        use initial::Initial;
 
-impl FromStr for NasalSyllable {
-    type Err = ();
+        assert!(size_of::<Syllable>() <= 4);
+        assert_eq!(size_of::<Option<Syllable>>(), size_of::<Syllable>());
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // 'ń' | 'ň' | 'ǹ'
-        // 'ḿ' |       "m̀"
-        match s {
-            "ń" => NasalSyllable::new(Initial::N, Tone::Second),
-            "ň" => NasalSyllable::new(Initial::N, Tone::Third),
-            "ǹ" => NasalSyllable::new(Initial::N, Tone::Fourth),
-            "m̀" => NasalSyllable::new(Initial::M, Tone::Fourth),
-            "ḿ" | "" => NasalSyllable::new(Initial::M, Tone::Second),
-            _ => Err(()),
+        for (initial, c) in [(Initial::M, 'm'), (Initial::N, 'n'), (Initial::J, 'j'), (Initial::Q, 'q'), (Initial::X, 'x')].iter() {
+            assert_eq!(Initial::new(*c), Ok(*initial));
         }
     }
-}
 
+    #[test]
+    fn errors() {
+        let kind = |s: &str| *from_str(s).unwrap_err().kind();
 
-pub fn from_str(s: &str) -> Result<Box<Syllable>, ()> {
-    if let Ok(v) = s.parse::<PrimitiveSyllable>() {
-        Ok(Box::new(v))
-    } else if let Ok(v) = s.parse::<NormalSyllable>() {
-        Ok(Box::new(v))
-    } else if let Ok(v) = s.parse::<RhymeSyllable>() {
-        Ok(Box::new(v))
-    } else if let Ok(v) = s.parse::<NasalSyllable>() {
-        Ok(Box::new(v))
-    } else {
-        Err(())
+        assert_eq!(kind("ßa"), ErrorKind::InvalidInitial('ß'));
+        assert_eq!(kind("zhqa"), ErrorKind::InvalidRhyme(ErrorText::new(Letters::from_chars("qa".chars()).unwrap())));
+        assert_eq!(kind("ab"), ErrorKind::InvalidRhyme(ErrorText::new(Letters::from_chars("ab".chars()).unwrap())));
+        assert_eq!(kind("iai"), ErrorKind::InvalidRhyme(ErrorText::new(Letters::from_chars("iai".chars()).unwrap())));
+        assert_eq!(kind("uaa3"), ErrorKind::InvalidRhyme(ErrorText::new(Letters::from_chars("uaa".chars()).unwrap())));
+        assert_eq!(kind("ia"), ErrorKind::InvalidSyllable);
+        assert_eq!(kind("hm"), ErrorKind::InvalidSyllable);
+        assert_eq!(kind("ng"), ErrorKind::InvalidSyllable);
+        assert_eq!(kind("m1"), ErrorKind::InvalidTone);
     }
 }
@@ -0,0 +1,243 @@
+use hanzi::is_hanzi;
+use phrase::{ PHRASE_MAP, PHRASE_MAX_LEN };
+use syllable::{ self, Syllable };
+use PinYin;
+
+use core::cmp;
+use core::fmt;
+use core::ops::Range;
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+
+/// 未知字符（没有读音的汉字）的处理方式
+pub enum Fallback {
+    /// 原样保留
+    Keep,
+    /// 丢弃
+    Drop,
+    /// 替换成指定的文本
+    Replace(String),
+    /// 交由回调函数处理，返回 `None` 时丢弃该字符
+    Callback(Box<dyn Fn(char) -> Option<String>>),
+}
+
+impl fmt::Debug for Fallback {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Fallback::Keep => write!(f, "Keep"),
+            Fallback::Drop => write!(f, "Drop"),
+            Fallback::Replace(ref s) => write!(f, "Replace({:?})", s),
+            Fallback::Callback(_) => write!(f, "Callback(..)"),
+        }
+    }
+}
+
+/// 文本片段
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Span<'a> {
+    /// 汉字以及它的拼音
+    Hanzi {
+        range: Range<usize>,
+        hanzi: char,
+        syllable: Syllable,
+    },
+    /// 非汉字片段（原样保留或者经过替换的文本）
+    Text {
+        range: Range<usize>,
+        text: Cow<'a, str>,
+    },
+}
+
+impl<'a> Span<'a> {
+    /// 片段在原文当中的字节范围
+    pub fn range(&self) -> Range<usize> {
+        match *self {
+            Span::Hanzi { ref range, .. } => range.clone(),
+            Span::Text { ref range, .. } => range.clone(),
+        }
+    }
+}
+
+
+// 从文本开头寻找词组表当中最长的词组，返回词组的字数以及读音
+fn match_phrase(text: &str, chars: &[(usize, char)]) -> Option<(usize, &'static str)> {
+    let max_len = cmp::min(PHRASE_MAX_LEN, chars.len());
+    let start = chars[0].0;
+
+    for len in (2..=max_len).rev() {
+        let end = if len < chars.len() { chars[len].0 } else { text.len() };
+
+        if let Ok(index) = PHRASE_MAP.binary_search_by_key(&&text[start..end], |&(k, _)| k) {
+            return Some((len, PHRASE_MAP[index].1));
+        }
+    }
+
+    None
+}
+
+/// 将文本转换为拼音
+/// 
+/// 按照最长匹配的规则优先采用词组读音，无法匹配词组时使用汉字的默认读音。
+/// 返回值与文本当中的字符一一对应，没有读音的字符对应 `None` 。
+pub fn to_pinyin(text: &str) -> Vec<Option<Syllable>> {
+    let chars = text.char_indices().collect::<Vec<(usize, char)>>();
+    let mut result: Vec<Option<Syllable>> = Vec::with_capacity(chars.len());
+    let mut i = 0usize;
+
+    while i < chars.len() {
+        match match_phrase(text, &chars[i..]) {
+            Some((len, readings)) => {
+                for reading in readings.split(' ') {
+                    result.push(Some(syllable::from_str(reading).expect("数据库记录有误！")));
+                }
+                i += len;
+            },
+            None => {
+                result.push(chars[i].1.pinyin().ok().and_then(|mut iter| iter.next()));
+                i += 1;
+            },
+        }
+    }
+
+    result
+}
+
+/// 将文本标注为拼音片段
+/// 
+/// 汉字片段携带它的拼音，非汉字片段原样输出，所有片段均带有它在原文当中的字节范围。
+/// 没有读音的汉字按照 `fallback` 指定的方式处理。
+pub fn annotate<'a>(text: &'a str, fallback: &Fallback) -> Vec<Span<'a>> {
+    let mut spans: Vec<Span<'a>> = Vec::new();
+    // 尚未输出的非汉字片段的起始位置
+    let mut run_start: Option<usize> = None;
+
+    for ((index, c), syllable) in text.char_indices().zip(to_pinyin(text)) {
+        let end = index + c.len_utf8();
+
+        let syllable = match syllable {
+            Some(syllable) => syllable,
+            None => {
+                if !is_hanzi(c) {
+                    run_start = run_start.or(Some(index));
+                    continue;
+                }
+
+                let replacement = match *fallback {
+                    Fallback::Keep => {
+                        run_start = run_start.or(Some(index));
+                        continue;
+                    },
+                    Fallback::Drop => None,
+                    Fallback::Replace(ref s) => Some(s.clone()),
+                    Fallback::Callback(ref callback) => callback(c),
+                };
+
+                if let Some(start) = run_start.take() {
+                    spans.push(Span::Text { range: start..index, text: Cow::Borrowed(&text[start..index]) });
+                }
+                if let Some(replacement) = replacement {
+                    spans.push(Span::Text { range: index..end, text: Cow::Owned(replacement) });
+                }
+                continue;
+            },
+        };
+
+        if let Some(start) = run_start.take() {
+            spans.push(Span::Text { range: start..index, text: Cow::Borrowed(&text[start..index]) });
+        }
+
+        spans.push(Span::Hanzi { range: index..end, hanzi: c, syllable });
+    }
+
+    if let Some(start) = run_start {
+        spans.push(Span::Text { range: start..text.len(), text: Cow::Borrowed(&text[start..]) });
+    }
+
+    spans
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{ Span, Fallback, to_pinyin, annotate };
+    use syllable::{ self, Syllable };
+
+    use alloc::borrow::Cow;
+    use alloc::boxed::Box;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    // NOTE: 位于汉字码位范围之内的未分配码位，不会有读音
+    const UNKNOWN: char = '\u{3134F}';
+
+    fn syllable(s: &str) -> Syllable {
+        syllable::from_str(s).unwrap()
+    }
+
+    fn hanzi(range: ::core::ops::Range<usize>, hanzi: char, pinyin: &str) -> Span<'static> {
+        Span::Hanzi { range, hanzi, syllable: syllable(pinyin) }
+    }
+
+    fn text(range: ::core::ops::Range<usize>, text: &str) -> Span<'static> {
+        Span::Text { range, text: Cow::Owned(String::from(text)) }
+    }
+
+    #[test]
+    fn byte_offsets() {
+        let spans = annotate("A银行，ok", &Fallback::Keep);
+
+        assert_eq!(spans, [
+            text(0..1, "A"),
+            hanzi(1..4, '银', "yín"),
+            hanzi(4..7, '行', "háng"),
+            text(7..12, "，ok"),
+        ]);
+        assert_eq!(spans.iter().map(Span::range).collect::<Vec<_>>(), [0..1, 1..4, 4..7, 7..12]);
+    }
+
+    #[test]
+    fn fallback() {
+        let s = format!("中{}文", UNKNOWN);
+        let (before, after) = (0..3, 7..10);
+
+        assert_eq!(annotate(&s, &Fallback::Keep), [
+            hanzi(before.clone(), '中', "zhōng"),
+            Span::Text { range: 3..7, text: Cow::Borrowed(&s[3..7]) },
+            hanzi(after.clone(), '文', "wén"),
+        ]);
+        assert_eq!(annotate(&s, &Fallback::Drop), [
+            hanzi(before.clone(), '中', "zhōng"),
+            hanzi(after.clone(), '文', "wén"),
+        ]);
+        assert_eq!(annotate(&s, &Fallback::Replace(String::from("?"))), [
+            hanzi(before.clone(), '中', "zhōng"),
+            text(3..7, "?"),
+            hanzi(after.clone(), '文', "wén"),
+        ]);
+
+        let callback = Fallback::Callback(Box::new(|c: char| Some(format!("U+{:X}", c as u32))));
+        assert_eq!(annotate(&s, &callback), [
+            hanzi(before.clone(), '中', "zhōng"),
+            text(3..7, "U+3134F"),
+            hanzi(after.clone(), '文', "wén"),
+        ]);
+
+        let callback = Fallback::Callback(Box::new(|_c: char| None));
+        assert_eq!(annotate(&s, &callback), annotate(&s, &Fallback::Drop));
+    }
+
+    #[test]
+    fn phrases() {
+        assert_eq!(to_pinyin("银行行长"), [
+            Some(syllable("yín")), Some(syllable("háng")), Some(syllable("háng")), Some(syllable("zhǎng")),
+        ]);
+        assert_eq!(to_pinyin("a行"), [None, Some(syllable("xíng"))]);
+
+        let spans = annotate("银行行长", &Fallback::Keep);
+        assert_eq!(spans[2], hanzi(6..9, '行', "háng"));
+        assert_eq!(spans[3], hanzi(9..12, '长', "zhǎng"));
+    }
+}
@@ -1,4 +1,11 @@
-use std::fmt;
+use error::{ Error, ErrorKind };
+
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 // 声调和音节:
 //     https://zh.wikipedia.org/wiki/%E8%AA%BF%E5%80%BC
@@ -10,17 +17,19 @@ use std::fmt;
 // "i", "ī", "í", "ǐ", "ì",
 // "u", "ū", "ú", "ǔ", "ù",
 // "ü", "ǖ", "ǘ", "ǚ", "ǜ",
+// "ê", "ê̄", "ế", "ê̌", "ề",
 // "ń", "ň", "ǹ",
 // "ḿ", "m̀",
 
 
-pub const TONE_MARK_TABLE: [(&str, char, Tone); 35] = [
+pub const TONE_MARK_TABLE: [(&str, char, Tone); 40] = [
     ("a", 'a', Tone::Neutral), ("ā", 'a', Tone::First), ("á", 'a', Tone::Second), ("ǎ", 'a', Tone::Third), ("à", 'a', Tone::Fourth),
     ("e", 'e', Tone::Neutral), ("ē", 'e', Tone::First), ("é", 'e', Tone::Second), ("ě", 'e', Tone::Third), ("è", 'e', Tone::Fourth),
     ("o", 'o', Tone::Neutral), ("ō", 'o', Tone::First), ("ó", 'o', Tone::Second), ("ǒ", 'o', Tone::Third), ("ò", 'o', Tone::Fourth),
     ("i", 'i', Tone::Neutral), ("ī", 'i', Tone::First), ("í", 'i', Tone::Second), ("ǐ", 'i', Tone::Third), ("ì", 'i', Tone::Fourth),
     ("u", 'u', Tone::Neutral), ("ū", 'u', Tone::First), ("ú", 'u', Tone::Second), ("ǔ", 'u', Tone::Third), ("ù", 'u', Tone::Fourth),
     ("ü", 'ü', Tone::Neutral), ("ǖ", 'ü', Tone::First), ("ǘ", 'ü', Tone::Second), ("ǚ", 'ü', Tone::Third), ("ǜ", 'ü', Tone::Fourth),
+    ("ê", 'ê', Tone::Neutral), ("ê̄", 'ê', Tone::First), ("ế", 'ê', Tone::Second), ("ê̌", 'ê', Tone::Third), ("ề", 'ê', Tone::Fourth),
     
     ("ń", 'n', Tone::Second), ("ň", 'n', Tone::Third), ("ǹ", 'n', Tone::Fourth),
     ("ḿ", 'm', Tone::Second), ("m̀", 'm', Tone::Fourth),
@@ -28,6 +37,11 @@ pub const TONE_MARK_TABLE: [(&str, char, Tone); 35] = [
 
 // 上标数字: ⁰¹²³⁴⁵⁶⁷⁸⁹⁺⁻⁼⁽⁾ⁿⁱ
 
+/// 声调表 ( 第一声至第四声以及轻声 )
+pub const TONE_TABLE: [Tone; 5] = [
+    Tone::First, Tone::Second, Tone::Third, Tone::Fourth, Tone::Neutral,
+];
+
 
 /// 音调标记方式
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -38,11 +52,17 @@ pub enum ToneFormat {
     Digit,
     /// 声序法 ( fan, fan1 )
     Index,
+    /// 赵元任五度标调符号，平调只写一个符号，与 `Syllable::ipa` 相同 ( fan, fan˥, fan˧˥, fan˨˩˦ )
+    ToneLetter,
+    /// 国语罗马字，以拼写区分声调 ( chiu, chyu, cheu, chiuh, .de )
+    ///
+    /// 其它拼音方案 ( 耶鲁拼音、通用拼音、注音二式 ) 无法以拼写区分声调，使用该标记方式的时候不标注声调。
+    GwoyeuRomatzyh,
 }
 
 
 /// 声调
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
 pub enum Tone {
     /// 第一声: 平调(阴平)
     First,
@@ -56,11 +76,11 @@ pub enum Tone {
     Neutral,
 }
 
-impl<'a> Into<u8> for &'a Tone {
-    fn into(self) -> u8 {
+impl<'a> From<&'a Tone> for u8 {
+    fn from(tone: &'a Tone) -> u8 {
         use self::Tone::*;
 
-        match self {
+        match *tone {
             Neutral => 0u8,
             First => 1,
             Second => 2,
@@ -69,9 +89,9 @@ impl<'a> Into<u8> for &'a Tone {
         }
     }
 }
-impl Into<u8> for Tone {
-    fn into(self) -> u8 {
-        (&self).into()
+impl From<Tone> for u8 {
+    fn from(tone: Tone) -> u8 {
+        u8::from(&tone)
     }
 }
 
@@ -136,29 +156,99 @@ impl Tone {
             Fourth => Some(51u8),
         }
     }
+
+    /// 连读变调以后的调值，`prev`、`next` 为同一个词里面前后音节的声调，`hanzi` 为音节对应的汉字
+    ///
+    /// 上声在上声前面变为 35，在其它声调前面变为半上 21，单念或者在词末保持 214；
+    /// 轻声的调值由前一个音节决定 ( 阴平后 2，阳平后 3，上声后 4，去声后 1 )。
+    ///
+    /// `一`、`不` 在去声前面变为阳平 35 ( yí gè, bú shì )，`一` 在阴平、阳平、上声前面变为去声 51 ( yì tiān )，
+    /// 单念、在词末或者在轻声前面保持本调。只凭拼音无法区分 `一` 和 `衣`，所以这两条规则需要提供 `hanzi`。
+    pub fn surface_value(&self, hanzi: Option<char>, prev: Option<Tone>, next: Option<Tone>) -> Option<u8> {
+        use self::Tone::*;
+
+        match (*self, hanzi, prev, next) {
+            (First, Some('一'), _, Some(Fourth)) | (Fourth, Some('不'), _, Some(Fourth)) => Some(35u8),
+            (First, Some('一'), _, Some(First)) | (First, Some('一'), _, Some(Second)) | (First, Some('一'), _, Some(Third)) => Some(51u8),
+            (Third, _, _, Some(Third)) => Some(35u8),
+            (Third, _, _, Some(_)) => Some(21u8),
+            (Neutral, _, Some(First), _) => Some(2u8),
+            (Neutral, _, Some(Second), _) => Some(3u8),
+            (Neutral, _, Some(Third), _) => Some(4u8),
+            (Neutral, _, Some(Fourth), _) => Some(1u8),
+            _ => self.value(),
+        }
+    }
+}
+
+// 组合附加符号形式的调号 ( 第一声至第四声: ̄ ́ ̌ ̀ )
+#[cfg(feature = "alloc")]
+pub(crate) fn combining_tone(c: char) -> Option<Tone> {
+    use self::Tone::*;
+
+    match c {
+        '\u{304}' => Some(First),
+        '\u{301}' => Some(Second),
+        '\u{30C}' => Some(Third),
+        '\u{300}' => Some(Fourth),
+        _ => None,
+    }
+}
+
+// 拆分单个字符的带调号字母，保持大小写 ( `Ǎ` => `A`, 第三声 )
+#[cfg(feature = "alloc")]
+pub(crate) fn split_tone_mark(c: char) -> Option<(char, Tone)> {
+    if c.is_ascii() {
+        return None;
+    }
+
+    let mut lower = c.to_lowercase();
+    let lower = match (lower.next(), lower.next()) {
+        (Some(lower), None) => lower,
+        _ => return None,
+    };
+
+    TONE_MARK_TABLE.iter()
+        .filter(|(_k, _c, t)| t != &Tone::Neutral)
+        .find(|(k, _c, _t)| k.chars().eq(Some(lower)))
+        .map(|(_k, base, tone)| {
+            let base = if c == lower { *base } else { base.to_uppercase().next().unwrap_or(*base) };
+            (base, *tone)
+        })
+}
+
+// 去掉字符的调号，组合附加符号形式的调号返回 `None`
+#[cfg(feature = "alloc")]
+fn without_tone_mark(c: char) -> Option<char> {
+    if combining_tone(c).is_some() {
+        return None;
+    }
+
+    Some(split_tone_mark(c).map_or(c, |(base, _tone)| base))
 }
 
 /// 发声字母以及声调
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
 pub struct ToneMark(char, Tone);
 
 impl ToneMark {
-    pub fn new(c: char, tone: Tone) -> Result<Self, ()> {
-        let res=  TONE_MARK_TABLE.iter()
-                    .filter(|(_s, cc, t)| &c == cc && &tone == t)
-                    .map(|(_s, cc, t)| ToneMark(*cc, *t))
-                    .collect::<Vec<ToneMark>>();
+    pub fn new(c: char, tone: Tone) -> Result<Self, Error> {
+        let res = TONE_MARK_TABLE.iter()
+                    .find(|(_s, cc, t)| &c == cc && &tone == t)
+                    .map(|(_s, cc, t)| ToneMark(*cc, *t));
         
-        if res.len() == 0 {
-            Err(())
-        } else if res.len() == 1 {
-            Ok(res[0])
-        } else {
-            unreachable!();
+        match res {
+            Some(tone_mark) => Ok(tone_mark),
+            // NOTE: 该字母可以标注声调，只是不存在这个声调 ( 例如 `m` 没有第一声 )
+            None if TONE_MARK_TABLE.iter().any(|(_s, cc, _t)| &c == cc) => {
+                Err(Error::new(ErrorKind::InvalidTone, c, 0))
+            },
+            None => Err(Error::new(ErrorKind::InvalidToneMark(c), c, 0)),
         }
     }
 
     // 寻找音调字母
+    #[cfg(feature = "alloc")]
     pub fn find(s: &str) -> Vec<Self> {
         TONE_MARK_TABLE.iter()
             .filter(|(k, _c, _t)| s.contains(k))
@@ -166,9 +256,18 @@ impl ToneMark {
             .collect::<Vec<ToneMark>>()
     }
     
-    // 替换 音调 字母 为普通字母
-    pub fn replace_tone_marks(_s: &str) -> String {
-        unimplemented!()
+    // 替换 音调 字母 为普通字母，并去掉组合附加符号形式的调号 ( `ma\u{300}` )
+    #[cfg(feature = "alloc")]
+    pub fn replace_tone_marks(s: &str) -> String {
+        s.chars().filter_map(without_tone_mark).collect()
+    }
+
+    /// 带调号的字母 ( 例如 `ā`、`ê̄` )
+    pub fn as_str(&self) -> &'static str {
+        TONE_MARK_TABLE.iter()
+            .find(|(_s, c, t)| &self.0 == c && &self.1 == t)
+            .map(|(s, _c, _t)| *s)
+            .expect("调号必须来自调号表！")
     }
 
     pub fn mark(&self) -> char {
@@ -182,13 +281,61 @@ impl ToneMark {
 
 impl fmt::Display for ToneMark {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let res=  TONE_MARK_TABLE.iter()
-                    .filter(|(_s, c, t)| &self.0 == c && &self.1 == t)
-                    .map(|(s, _c, _t)| s.to_string())
-                    .collect::<Vec<String>>();
-        
-        assert_eq!(res.len(), 1);
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ToneMark {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TONE_MARK_TABLE.iter()
+            .find(|(k, _c, _t)| *k == s)
+            .map(|(_k, c, t)| ToneMark(*c, *t))
+            .ok_or_else(|| {
+                let c = s.chars().next().unwrap_or(' ');
+                Error::new(ErrorKind::InvalidToneMark(c), s, 0)
+            })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Tone::{ self, * };
+
+    #[test]
+    fn surface_value() {
+        // 上声连读
+        assert_eq!(Third.surface_value(None, None, Some(Third)), Some(35));
+        assert_eq!(Third.surface_value(None, Some(Third), None), Some(214));
+        assert_eq!(Third.surface_value(None, None, Some(First)), Some(21));
+        assert_eq!(Third.surface_value(None, None, Some(Neutral)), Some(21));
+        assert_eq!(Third.surface_value(None, None, None), Some(214));
+
+        // 轻声跟在各个声调后面
+        let neutral = |prev: Option<Tone>| Neutral.surface_value(None, prev, None);
+        assert_eq!(neutral(Some(First)), Some(2));
+        assert_eq!(neutral(Some(Second)), Some(3));
+        assert_eq!(neutral(Some(Third)), Some(4));
+        assert_eq!(neutral(Some(Fourth)), Some(1));
+        assert_eq!(neutral(Some(Neutral)), None);
+        assert_eq!(neutral(None), None);
+
+        // 一、不
+        let yi = |next: Option<Tone>| First.surface_value(Some('一'), None, next);
+        assert_eq!(yi(Some(Fourth)), Some(35));
+        assert_eq!(yi(Some(First)), Some(51));
+        assert_eq!(yi(Some(Second)), Some(51));
+        assert_eq!(yi(Some(Third)), Some(51));
+        assert_eq!(yi(Some(Neutral)), Some(55));
+        assert_eq!(yi(None), Some(55));
+        assert_eq!(Fourth.surface_value(Some('不'), None, Some(Fourth)), Some(35));
+        assert_eq!(Fourth.surface_value(Some('不'), None, Some(First)), Some(51));
 
-        write!(f, "{}", res[0])
+        // 其它字不按照 `一`、`不` 变调
+        assert_eq!(First.surface_value(Some('衣'), None, Some(Fourth)), Some(55));
+        assert_eq!(First.surface_value(None, None, Some(Fourth)), Some(55));
+        assert_eq!(Fourth.surface_value(None, None, Some(Fourth)), Some(51));
     }
 }
@@ -0,0 +1,83 @@
+use tone::ToneFormat;
+use initial::Initial;
+use rhyme::Rhyme;
+use syllable::Syllable;
+use error::Error;
+use romanization::Scheme;
+
+use core::fmt;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use format::write_to_string;
+
+// 通用拼音:
+//     https://zh.wikipedia.org/wiki/%E9%80%9A%E7%94%A8%E6%8B%BC%E9%9F%B3
+//
+// 与汉语拼音的主要区别: q、x、zh 写作 c、s、jh，空韵写作 ih ( jhih, sih, cih )，
+// ü 写作 yu ( jyu, cyuan, lyu )，iong 写作 yong，
+// b、p、m、f、w 后面的 eng 写作 ong ( fong, wong )，iou、uei 不省略 ( liou, duei )，零声母的 uen 写作 wun。
+
+
+// b, c, ch, d, f, g, h, j, k, l, m, n, p, q, r, s, sh, t, x, z, zh
+const TONGYONG: Scheme = Scheme {
+    initials: [
+        "b", "c", "ch", "d", "f", "g",
+        "h", "j", "k", "l", "m", "n",
+        "p", "c", "r", "s", "sh", "t",
+        "s", "z", "jh",
+    ],
+    rhymes: [
+        "a", "o", "e", "e", "er", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "ong",
+        "i", "ia", "ie", "iao", "iou", "ian", "in", "iang", "ing", "yong",
+        "u", "ua", "uo", "uai", "uei", "uan", "un", "uang", "ueng",
+        "yu", "yue", "yuan", "yun",
+    ],
+    zero_initial_rhymes: [
+        "a", "o", "e", "e", "er", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "",
+        "yi", "ya", "ye", "yao", "you", "yan", "yin", "yang", "ying", "yong",
+        "wu", "wa", "wo", "wai", "wei", "wan", "wun", "wang", "wong",
+        "yu", "yue", "yuan", "yun",
+    ],
+    empty_rhymes: ["ih", "ih", "ih", "ih", "ih", "ih", "ih"],
+    finals: tongyong_finals,
+    preferred: &[],
+};
+
+
+fn tongyong_finals(initial: Initial, rhyme: Rhyme, finals: &'static str) -> &'static str {
+    match (&initial.letters()[..], &rhyme.letters()[..]) {
+        // bong, pong, mong, fong
+        (['b'], ['e', 'n', 'g']) | (['p'], ['e', 'n', 'g'])
+            | (['m'], ['e', 'n', 'g']) | (['f'], ['e', 'n', 'g']) => "ong",
+        _ => finals,
+    }
+}
+
+
+impl Initial {
+    /// 通用拼音 ( c, s, jh )
+    pub fn tongyong(&self) -> &'static str {
+        TONGYONG.initial(self)
+    }
+}
+
+impl Syllable {
+    /// 按照指定的音调标记方式写入通用拼音形式 ( jhōng, jhong1, sìh, cyuán )
+    ///
+    /// 这种拼音方案不以拼写区分声调，`ToneFormat::GwoyeuRomatzyh` 只写入不带声调的拼写 ( jhong )，声调会丢失。
+    pub fn tongyong_to(&self, w: &mut dyn fmt::Write, tone_format: ToneFormat) -> fmt::Result {
+        TONGYONG.write_toned(w, self, tone_format)
+    }
+
+    /// 按照指定的音调标记方式输出通用拼音形式
+    #[cfg(feature = "alloc")]
+    pub fn tongyong(&self, tone_format: ToneFormat) -> String {
+        write_to_string(|w| self.tongyong_to(w, tone_format))
+    }
+
+    /// 解析通用拼音形式的音节，支持调号以及数字 ( jhōng, Jhong1, sih4 )
+    pub fn from_tongyong(s: &str) -> Result<Syllable, Error> {
+        TONGYONG.parse_toned(s)
+    }
+}
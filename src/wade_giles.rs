@@ -0,0 +1,265 @@
+use tone::Tone;
+use initial::Initial;
+use syllable::{ Syllable, SyllableKind };
+use error::{ Error, ErrorKind };
+use format::SUPERSCRIPT_DIGITS;
+use letters::Letters;
+use inventory::{ candidates, inventory_order };
+
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use format::write_to_string;
+
+// 威妥玛拼音:
+//     https://zh.wikipedia.org/wiki/%E5%A8%81%E5%A6%A5%E7%91%AA%E6%8B%BC%E9%9F%B3
+//
+// 声调使用上标数字标注在音节后面，轻声不标 ( chung¹, ch'ing², hsüeh⁴, tzŭ )。
+
+
+// 威妥玛拼音声母表 ( 顺序与 `INITIAL_TABLE` 相同 )
+// b, c, ch, d, f, g, h, j, k, l, m, n, p, q, r, s, sh, t, x, z, zh
+const WADE_GILES_INITIAL_TABLE: [&str; 21] = [
+    "p", "ts'", "ch'", "t", "f", "k",
+    "h", "ch", "k'", "l", "m", "n",
+    "p'", "ch'", "j", "s", "sh", "t'",
+    "hs", "ts", "ch",
+];
+
+// 跟在声母后面的韵母 ( 顺序与 `RHYME_TABLE` 相同 )
+const WADE_GILES_RHYME_TABLE: [&str; 37] = [
+    "a", "o", "ê", "eh", "êrh", "ai", "ei", "ao", "ou", "an", "ên", "ang", "êng", "ung",
+    "i", "ia", "ieh", "iao", "iu", "ien", "in", "iang", "ing", "iung",
+    "u", "ua", "o", "uai", "ui", "uan", "un", "uang", "uêng",
+    "ü", "üeh", "üan", "ün",
+];
+
+// 自成音节的韵母 ( 顺序与 `RHYME_TABLE` 相同，`ong` 没有自成音节的形式 )
+const WADE_GILES_ZERO_INITIAL_RHYME_TABLE: [&str; 37] = [
+    "a", "o", "o", "eh", "êrh", "ai", "ei", "ao", "ou", "an", "ên", "ang", "êng", "",
+    "i", "ya", "yeh", "yao", "yu", "yen", "yin", "yang", "ying", "yung",
+    "wu", "wa", "wo", "wai", "wei", "wan", "wên", "wang", "wêng",
+    "yü", "yüeh", "yüan", "yün",
+];
+
+// 拼写相同的音节，优先选择常用的那一个 ( `o` 饿/哦, `lo` 落/咯 )
+const WADE_GILES_PREFERRED: [(&str, &str); 2] = [("o", "e"), ("lo", "luo")];
+
+
+// 带空韵的 `zhi`、`chi`、`shi`、`ri`、`zi`、`ci`、`si`
+fn write_empty_rhyme(w: &mut dyn fmt::Write, initial: Initial) -> fmt::Result {
+    let letters = initial.letters();
+
+    match &letters[..] {
+        ['z'] => w.write_str("tzŭ"),
+        ['c'] => w.write_str("tz'ŭ"),
+        ['s'] => w.write_str("ssŭ"),
+        _ => {
+            w.write_str(initial.wade_giles())?;
+            w.write_str("ih")
+        },
+    }
+}
+
+// 不带声调的拼写
+fn write_spelling(w: &mut dyn fmt::Write, syllable: &Syllable) -> fmt::Result {
+    let (initial, rhyme) = syllable.parts();
+
+    match (initial, rhyme) {
+        (Some(initial), Some(rhyme)) => {
+            let initial_letters = initial.letters();
+            let rhyme_letters = rhyme.letters();
+            // g, k, h
+            let is_velar = matches!(initial_letters[..], ['g'] | ['k'] | ['h']);
+
+            let finals = match &rhyme_letters[..] {
+                ['e'] if is_velar => "o",
+                ['u', 'o'] if is_velar || initial_letters.eq_str("sh") => "uo",
+                ['u', 'e', 'i'] if matches!(initial_letters[..], ['g'] | ['k']) => "uei",
+                _ => WADE_GILES_RHYME_TABLE[rhyme.index()],
+            };
+
+            w.write_str(initial.wade_giles())?;
+            w.write_str(finals)
+        },
+        (Some(initial), None) => {
+            if syllable.kind() == SyllableKind::Nasal {
+                w.write_str(initial.wade_giles())
+            } else {
+                write_empty_rhyme(w, initial)
+            }
+        },
+        (None, Some(rhyme)) => w.write_str(WADE_GILES_ZERO_INITIAL_RHYME_TABLE[rhyme.index()]),
+        (None, None) => Ok(()),
+    }
+}
+
+// 拆分音节后面的声调 ( 上标数字或者数字，没有声调的时候为轻声 )
+fn split_tone(s: &str) -> (&str, Tone) {
+    let last = match s.chars().last() {
+        Some(c) => c,
+        None => return (s, Tone::Neutral),
+    };
+
+    let digit = SUPERSCRIPT_DIGITS.iter()
+                    .position(|c| *c == last)
+                    .or_else(|| last.to_digit(10).map(|n| n as usize));
+
+    let tone = match digit {
+        Some(0) | Some(5) => Tone::Neutral,
+        Some(1) => Tone::First,
+        Some(2) => Tone::Second,
+        Some(3) => Tone::Third,
+        Some(4) => Tone::Fourth,
+        _ => return (s, Tone::Neutral),
+    };
+
+    (&s[..s.len() - last.len_utf8()], tone)
+}
+
+// 统一大小写以及送气符号的写法 ( ’ ‘ ʻ ʼ => ' )
+fn normalize(s: &str) -> Option<Letters> {
+    let mut letters = Letters::new();
+
+    for c in s.chars() {
+        let c = match c {
+            '’' | '‘' | 'ʻ' | 'ʼ' | '`' => '\'',
+            _ => c.to_lowercase().next().unwrap_or(c),
+        };
+
+        if !letters.push(c) {
+            return None;
+        }
+    }
+
+    Some(letters)
+}
+
+// 忽略 `ê`、`ŭ` 和 `ü` 上面的附加符号 ( 很多目录数据省略了这些符号 )
+fn fold(c: char) -> char {
+    match c {
+        'ê' => 'e',
+        'ŭ' | 'ü' => 'u',
+        _ => c,
+    }
+}
+
+
+impl Initial {
+    /// 威妥玛拼音 ( p, ch', hs )
+    pub fn wade_giles(&self) -> &'static str {
+        WADE_GILES_INITIAL_TABLE[self.index()]
+    }
+}
+
+impl Syllable {
+    /// 写入威妥玛拼音形式 ( chung¹, ch'ing², hsüeh⁴, tzŭ )
+    pub fn wade_giles_to(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        write_spelling(w, self)?;
+
+        match self.tone() {
+            Tone::Neutral => Ok(()),
+            tone => w.write_char(SUPERSCRIPT_DIGITS[u8::from(tone) as usize]),
+        }
+    }
+
+    /// 输出威妥玛拼音形式
+    #[cfg(feature = "alloc")]
+    pub fn wade_giles(&self) -> String {
+        write_to_string(|w| self.wade_giles_to(w))
+    }
+
+    /// 解析威妥玛拼音形式的音节 ( chung¹, Ch'ing2, hsüeh⁴ )
+    ///
+    /// 声调可以使用上标数字或者数字，送气符号可以使用 `'`、`’` 或者 `ʻ`，
+    /// 也接受省略了 `ê`、`ŭ`、`ü` 上面附加符号的写法 ( 优先匹配带附加符号的音节 )。
+    pub fn from_wade_giles(s: &str) -> Result<Syllable, Error> {
+        let (body, tone) = split_tone(s);
+
+        let input = normalize(body)
+                        .filter(|letters| !letters.is_empty())
+                        .ok_or_else(|| Error::new(ErrorKind::InvalidSyllable, s, 0))?;
+
+        for (spelling, pinyin) in WADE_GILES_PREFERRED.iter() {
+            if input.eq_str(spelling) {
+                let (initial, rhyme) = Syllable::from_str(pinyin)?.parts();
+                return Syllable::from_parts(initial, rhyme, tone).map_err(|e| e.with_input(s, 0));
+            }
+        }
+
+        let matches = |syllable: &Syllable, folded: bool| {
+            let mut spelling = Letters::new();
+            if write_spelling(&mut spelling, syllable).is_err() || spelling.len() != input.len() {
+                return false;
+            }
+
+            if folded {
+                spelling.iter().zip(input.iter()).all(|(a, b)| fold(*a) == fold(*b))
+            } else {
+                spelling[..] == input[..]
+            }
+        };
+        let find = |folded: bool| {
+            candidates(&input, Initial::wade_giles, &[tone]).filter(|syllable| matches(syllable, folded)).min_by_key(inventory_order)
+        };
+
+        find(false)
+            .or_else(|| find(true))
+            .ok_or_else(|| Error::new(ErrorKind::InvalidSyllable, s, 0))
+    }
+}
+
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use syllable::Syllable;
+    use tone::TONE_TABLE;
+    use inventory::syllables;
+
+    fn pinyin(s: &str) -> Syllable {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        // NOTE: 拼写相同的音节 ( 例如 `ch'ing` 只能是 `qing` ) 解析为其中一个，这里只比较拼写。
+        for syllable in syllables(&TONE_TABLE) {
+            let spelling = syllable.wade_giles();
+            let parsed = Syllable::from_wade_giles(&spelling).unwrap();
+
+            assert_eq!(parsed.wade_giles(), spelling, "{}", syllable);
+            assert_eq!(parsed.tone(), syllable.tone(), "{}", spelling);
+        }
+    }
+
+    #[test]
+    fn spellings() {
+        let cases = [
+            ("chī", "ch'ih¹"), ("zhí", "chih²"), ("rì", "jih⁴"), ("zǐ", "tzŭ³"), ("cí", "tz'ŭ²"), ("sī", "ssŭ¹"),
+            ("zhōng", "chung¹"), ("qíng", "ch'ing²"), ("xuè", "hsüeh⁴"), ("guō", "kuo¹"), ("kè", "k'o⁴"),
+            ("luò", "lo⁴"), ("ér", "êrh²"), ("yī", "i¹"),
+        ];
+
+        for (pinyin_spelling, wade_giles) in cases.iter() {
+            assert_eq!(pinyin(pinyin_spelling).wade_giles(), *wade_giles);
+            assert_eq!(Syllable::from_wade_giles(wade_giles), Ok(pinyin(pinyin_spelling)), "{}", wade_giles);
+        }
+
+        // 省略附加符号、使用数字以及其它送气符号的写法
+        assert_eq!(Syllable::from_wade_giles("tzu3"), Ok(pinyin("zǐ")));
+        assert_eq!(Syllable::from_wade_giles("Ch’ih1"), Ok(pinyin("chī")));
+        assert_eq!(Syllable::from_wade_giles("hsueh4"), Ok(pinyin("xuè")));
+        assert!(Syllable::from_wade_giles("chx").is_err());
+    }
+
+    #[test]
+    fn preferred() {
+        // `lo` 可以是 `luo` 或者 `lo` ( 咯 )，`o` 可以是 `e` ( 饿 )、`o` 或者 `ê`，取常用的那一个
+        assert_eq!(pinyin("lo").wade_giles(), "lo");
+        assert_eq!(Syllable::from_wade_giles("lo"), Ok(pinyin("luo")));
+        assert_eq!(Syllable::from_wade_giles("lo4"), Ok(pinyin("luò")));
+        assert_eq!(Syllable::from_wade_giles("o4"), Ok(pinyin("è")));
+    }
+}
@@ -0,0 +1,84 @@
+use tone::ToneFormat;
+use initial::Initial;
+use rhyme::Rhyme;
+use syllable::Syllable;
+use error::Error;
+use romanization::Scheme;
+
+use core::fmt;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use format::write_to_string;
+
+// 耶鲁拼音 ( 官话 ):
+//     https://en.wikipedia.org/wiki/Yale_romanization_of_Mandarin
+//
+// 声调可以使用调号 ( jūng, gwó ) 或者数字 ( jung1, gwo2 ) 标注，
+// 空韵音节写作 jr、chr、shr、r、dz、tsz、sz，调号标在最后一个字母上 ( shr̀ )。
+
+
+// b, c, ch, d, f, g, h, j, k, l, m, n, p, q, r, s, sh, t, x, z, zh
+const YALE: Scheme = Scheme {
+    initials: [
+        "b", "ts", "ch", "d", "f", "g",
+        "h", "j", "k", "l", "m", "n",
+        "p", "ch", "r", "s", "sh", "t",
+        "sy", "dz", "j",
+    ],
+    rhymes: [
+        "a", "o", "e", "e", "er", "ai", "ei", "au", "ou", "an", "en", "ang", "eng", "ung",
+        "i", "ya", "ye", "yau", "you", "yan", "in", "yang", "ing", "yung",
+        "u", "wa", "wo", "wai", "wei", "wan", "wun", "wang", "weng",
+        "yu", "ywe", "ywan", "yun",
+    ],
+    zero_initial_rhymes: [
+        "a", "o", "e", "e", "er", "ai", "ei", "au", "ou", "an", "en", "ang", "eng", "",
+        "yi", "ya", "ye", "yau", "you", "yan", "yin", "yang", "ying", "yung",
+        "wu", "wa", "wo", "wai", "wei", "wan", "wen", "wang", "weng",
+        "yu", "ywe", "ywan", "yun",
+    ],
+    // zhi, chi, shi 写作 jr, chr, shr；ci, si 写作 tsz, sz；zi, ri 只写声母 dz, r
+    empty_rhymes: ["r", "r", "r", "", "", "z", "z"],
+    finals: yale_finals,
+    preferred: &[],
+};
+
+
+fn yale_finals(initial: Initial, rhyme: Rhyme, finals: &'static str) -> &'static str {
+    match (&initial.letters()[..], &rhyme.letters()[..]) {
+        // bo, po, mo, fo
+        (['b'], ['o']) | (['p'], ['o']) | (['m'], ['o']) | (['f'], ['o']) => "wo",
+        // 声母 `sy` 后面省略韵母开头的 `y` ( sywe, syung )
+        (['x'], _) => finals.trim_start_matches('y'),
+        _ => finals,
+    }
+}
+
+
+impl Initial {
+    /// 耶鲁拼音 ( j, ch, sy, dz )
+    pub fn yale(&self) -> &'static str {
+        YALE.initial(self)
+    }
+}
+
+impl Syllable {
+    /// 按照指定的音调标记方式写入耶鲁拼音形式 ( jūng, jung1, shr̀, ywán )
+    ///
+    /// 这种拼音方案不以拼写区分声调，`ToneFormat::GwoyeuRomatzyh` 只写入不带声调的拼写 ( jung )，声调会丢失。
+    pub fn yale_to(&self, w: &mut dyn fmt::Write, tone_format: ToneFormat) -> fmt::Result {
+        YALE.write_toned(w, self, tone_format)
+    }
+
+    /// 按照指定的音调标记方式输出耶鲁拼音形式
+    #[cfg(feature = "alloc")]
+    pub fn yale(&self, tone_format: ToneFormat) -> String {
+        write_to_string(|w| self.yale_to(w, tone_format))
+    }
+
+    /// 解析耶鲁拼音形式的音节，支持调号以及数字 ( jūng, jung1, shr̀, shr4 )，以及 NFC 形式的合成字母 ( shŕ, dž )
+    pub fn from_yale(s: &str) -> Result<Syllable, Error> {
+        YALE.parse_toned(s)
+    }
+}
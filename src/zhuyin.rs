@@ -0,0 +1,195 @@
+use tone::{ Tone, TONE_TABLE };
+use initial::{ Initial, INITIAL_TABLE };
+use rhyme::{ Rhyme, RHYME_TABLE };
+use syllable::Syllable;
+use error::{ Error, ErrorKind, ErrorText };
+
+use core::fmt;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use format::write_to_string;
+
+// 注音符号:
+//     https://zh.wikipedia.org/wiki/%E6%B3%A8%E9%9F%B3%E7%AC%A6%E8%99%9F
+//
+// 第一声不标调号，轻声的 `˙` 标在音节前面 ( ㄓㄨㄥ, ㄇㄚˊ, ˙ㄇㄚ )，
+// `zhi`、`chi`、`shi`、`ri`、`zi`、`ci`、`si` 的韵母为空韵，只写声母 ( ㄓ, ㄘˋ )。
+
+
+// 注音符号声母表 ( 顺序与 `INITIAL_TABLE` 相同 )
+// b, c, ch, d, f, g, h, j, k, l, m, n, p, q, r, s, sh, t, x, z, zh
+const ZHUYIN_INITIAL_TABLE: [char; 21] = [
+    'ㄅ', 'ㄘ', 'ㄔ', 'ㄉ', 'ㄈ', 'ㄍ',
+    'ㄏ', 'ㄐ', 'ㄎ', 'ㄌ', 'ㄇ', 'ㄋ',
+    'ㄆ', 'ㄑ', 'ㄖ', 'ㄙ', 'ㄕ', 'ㄊ',
+    'ㄒ', 'ㄗ', 'ㄓ',
+];
+
+// 注音符号韵母表 ( 顺序与 `RHYME_TABLE` 相同 )
+//
+// NOTE: `ong` 和 `ueng` 都写作 `ㄨㄥ`，前者只跟在声母后面，后者只自成音节。
+const ZHUYIN_RHYME_TABLE: [&str; 37] = [
+    "ㄚ", "ㄛ", "ㄜ", "ㄝ", "ㄦ", "ㄞ", "ㄟ", "ㄠ", "ㄡ", "ㄢ", "ㄣ", "ㄤ", "ㄥ", "ㄨㄥ",
+    "ㄧ", "ㄧㄚ", "ㄧㄝ", "ㄧㄠ", "ㄧㄡ", "ㄧㄢ", "ㄧㄣ", "ㄧㄤ", "ㄧㄥ", "ㄩㄥ",
+    "ㄨ", "ㄨㄚ", "ㄨㄛ", "ㄨㄞ", "ㄨㄟ", "ㄨㄢ", "ㄨㄣ", "ㄨㄤ", "ㄨㄥ",
+    "ㄩ", "ㄩㄝ", "ㄩㄢ", "ㄩㄣ",
+];
+
+// 轻声符号
+const NEUTRAL_TONE_MARK: char = '˙';
+
+
+// 根据调号查找声调 ( 第一声的调号可以省略 )
+fn tone_from_mark(c: char) -> Option<Tone> {
+    match c {
+        'ˉ' => Some(Tone::First),
+        NEUTRAL_TONE_MARK => Some(Tone::Neutral),
+        _ => TONE_TABLE.iter().find(|tone| tone.mask() == Some(c)).cloned(),
+    }
+}
+
+
+impl Initial {
+    /// 注音符号 ( ㄅ, ㄓ )
+    pub fn zhuyin(&self) -> char {
+        ZHUYIN_INITIAL_TABLE[self.index()]
+    }
+}
+
+impl Rhyme {
+    /// 注音符号 ( ㄚ, ㄧㄢ, ㄩㄥ )
+    pub fn zhuyin(&self) -> &'static str {
+        ZHUYIN_RHYME_TABLE[self.index()]
+    }
+}
+
+impl Syllable {
+    /// 写入注音符号形式 ( ㄓㄨㄥ, ㄇㄚˊ, ˙ㄇㄚ )
+    pub fn zhuyin_to(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        let tone = self.tone();
+
+        if tone == Tone::Neutral {
+            w.write_char(NEUTRAL_TONE_MARK)?;
+        }
+
+        let (initial, rhyme) = self.parts();
+
+        if let Some(initial) = initial {
+            w.write_char(initial.zhuyin())?;
+        }
+
+        if let Some(rhyme) = rhyme {
+            w.write_str(rhyme.zhuyin())?;
+        }
+
+        match tone {
+            Tone::First | Tone::Neutral => Ok(()),
+            _ => w.write_char(tone.mask().expect("声调必须有调号！")),
+        }
+    }
+
+    /// 输出注音符号形式
+    #[cfg(feature = "alloc")]
+    pub fn zhuyin(&self) -> String {
+        write_to_string(|w| self.zhuyin_to(w))
+    }
+
+    /// 解析注音符号形式的音节 ( ㄓㄨㄥ, ㄇㄚˊ, ˙ㄇㄚ )
+    ///
+    /// 轻声符号 `˙` 可以写在音节前面或者后面，第一声的调号 `ˉ` 可以省略。
+    pub fn from_zhuyin(s: &str) -> Result<Syllable, Error> {
+        let mut body = s;
+        // `body` 在原始输入当中的字符位置
+        let mut offset = 0usize;
+        let mut tone: Option<Tone> = None;
+
+        if let Some(rest) = body.strip_prefix(NEUTRAL_TONE_MARK) {
+            body = rest;
+            offset = 1;
+            tone = Some(Tone::Neutral);
+        }
+
+        if let Some((c, found)) = body.chars().last().and_then(|c| tone_from_mark(c).map(|t| (c, t))) {
+            if tone.is_some() {
+                return Err(Error::new(ErrorKind::MultipleToneMarks, s, s.chars().count() - 1));
+            }
+
+            body = &body[..body.len() - c.len_utf8()];
+            tone = Some(found);
+        }
+
+        let tone = tone.unwrap_or(Tone::First);
+
+        let first = match body.chars().next() {
+            Some(c) => c,
+            None => return Err(Error::new(ErrorKind::InvalidSyllable, s, 0)),
+        };
+
+        let (initial, finals, finals_offset) = match ZHUYIN_INITIAL_TABLE.iter().position(|c| *c == first) {
+            Some(index) => {
+                let initial = Initial::new(INITIAL_TABLE[index]).map_err(|e| e.with_input(s, offset))?;
+                (Some(initial), &body[first.len_utf8()..], offset + 1)
+            },
+            None => (None, body, offset),
+        };
+
+        if finals.is_empty() {
+            return Syllable::from_parts(initial, None, tone).map_err(|e| e.with_input(s, offset));
+        }
+
+        let mut error: Option<Error> = None;
+
+        // NOTE: `ㄨㄥ` 对应两个韵母，依次尝试
+        for (index, _) in ZHUYIN_RHYME_TABLE.iter().enumerate().filter(|(_, zhuyin)| **zhuyin == finals) {
+            let rhyme = Rhyme::new(RHYME_TABLE[index]).map_err(|e| e.with_input(s, finals_offset))?;
+
+            match Syllable::from_parts(initial, Some(rhyme), tone) {
+                Ok(syllable) => return Ok(syllable),
+                Err(e) => error = error.or(Some(e.with_input(s, offset))),
+            }
+        }
+
+        Err(error.unwrap_or_else(|| Error::new(ErrorKind::InvalidRhyme(ErrorText::new(finals)), s, finals_offset)))
+    }
+}
+
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use syllable::Syllable;
+    use tone::TONE_TABLE;
+    use inventory::syllables;
+
+    fn pinyin(s: &str) -> Syllable {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        for syllable in syllables(&TONE_TABLE) {
+            let zhuyin = syllable.zhuyin();
+            assert_eq!(Syllable::from_zhuyin(&zhuyin), Ok(syllable), "{}", zhuyin);
+        }
+    }
+
+    #[test]
+    fn spellings() {
+        let cases = [
+            ("zhī", "ㄓ"), ("shì", "ㄕˋ"), ("ér", "ㄦˊ"), ("yuè", "ㄩㄝˋ"), ("de", "˙ㄉㄜ"),
+            ("zhōng", "ㄓㄨㄥ"), ("wēng", "ㄨㄥ"), ("ê", "˙ㄝ"), ("ń", "ㄋˊ"),
+        ];
+
+        for (pinyin_spelling, zhuyin) in cases.iter() {
+            assert_eq!(pinyin(pinyin_spelling).zhuyin(), *zhuyin);
+            assert_eq!(Syllable::from_zhuyin(zhuyin), Ok(pinyin(pinyin_spelling)), "{}", zhuyin);
+        }
+
+        // 轻声符号写在后面，以及写出第一声的调号
+        assert_eq!(Syllable::from_zhuyin("ㄉㄜ˙"), Ok(pinyin("de")));
+        assert_eq!(Syllable::from_zhuyin("ㄓˉ"), Ok(pinyin("zhī")));
+        assert!(Syllable::from_zhuyin("˙ㄉㄜˊ").is_err());
+        assert!(Syllable::from_zhuyin("ㄅㄩ").is_err());
+        assert!(Syllable::from_zhuyin("").is_err());
+    }
+}
@@ -0,0 +1,263 @@
+#!/usr/bin/env python3
+# -*- coding: utf-8 -*-
+"""生成 `src/hanzi.rs` 当中的汉字读音表 `PINYIN_MAP`
+
+用法:
+
+    # 使用 Unihan 数据库 ( https://www.unicode.org/Public/UCD/latest/ucd/Unihan.zip )
+    python3 tools/gen_hanzi.py --unihan Unihan_Readings.txt
+
+    # 使用 ICU 的 `Han-Latin` 转写规则 ( 需要 `uconv` 命令 )，只包含每个汉字的首选读音
+    python3 tools/gen_hanzi.py --icu
+
+    # 两者同时使用的时候，ICU 的读音追加在 Unihan 的读音后面
+    python3 tools/gen_hanzi.py --unihan Unihan_Readings.txt --icu
+
+表格原有的读音 ( 人工整理的多音字读音以及它们的顺序 ) 会被保留，新的读音追加在后面，
+所以重复运行的结果是稳定的。
+
+只保留 `syllables` 音节表当中的读音: `yo` ( 哟、唷 )、`hm`、`hng`、`ng` 以及方言字的读音
+( 例如 biáng、cèi ) 无法使用 `Syllable` 表示，会被跳过，只有这些读音的汉字不在表格当中。
+"""
+
+import argparse
+import os
+import re
+import subprocess
+import sys
+import unicodedata
+
+SRC = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "src")
+HANZI_RS = os.path.join(SRC, "hanzi.rs")
+SYLLABLE_RS = os.path.join(SRC, "syllable.rs")
+RHYME_RS = os.path.join(SRC, "rhyme.rs")
+
+# 与 `is_hanzi` 相同的码位范围
+HANZI_RANGES = [
+    (0x3007, 0x3007),
+    (0x3400, 0x4DBF),
+    (0x4E00, 0x9FFF),
+    (0xF900, 0xFAFF),
+    (0x20000, 0x2A6DF),
+    (0x2A700, 0x2EE5F),
+    (0x2F800, 0x2FA1F),
+    (0x30000, 0x323AF),
+]
+
+# 第一声至第四声的组合附加符号
+TONE_MARKS = {"\u0304": 1, "\u0301": 2, "\u030c": 3, "\u0300": 4}
+
+# 鼻音音节可以使用的声调 ( 与 `NasalSyllable::new` 相同 )
+NASAL_TONES = {"m": {2, 4}, "n": {2, 3, 4}}
+
+# `INITIAL_TABLE` 当中 zh、ch、sh 的写法
+INITIAL_LETTERS = {"ẑ": "zh", "ĉ": "ch", "ŝ": "sh"}
+
+ENTRY_RE = re.compile(r"^    \('(.+)', \"(.*)\"\),$")
+
+TABLE_START = "// 汉字读音表"
+TABLE_END = "];\n"
+
+
+def is_hanzi(code):
+    return any(start <= code <= end for start, end in HANZI_RANGES)
+
+
+def table_source(text, name):
+    start = text.index(name)
+    return text[start:text.index("\n];", start)]
+
+
+def read_spellings():
+    """按照 `src/syllable.rs`、`src/rhyme.rs` 的表格列举全部音节的拼写 ( 不带声调，不包括鼻音音节 )"""
+    with open(SYLLABLE_RS, encoding="utf-8") as f:
+        syllable_rs = f.read()
+    with open(RHYME_RS, encoding="utf-8") as f:
+        rhyme_rs = f.read()
+
+    spellings = set()
+
+    # 常规音节 ( 省写规则与 `NormalSyllable::spelling` 相同 )
+    for initial, finals in re.findall(r"\('(.)', &\[(.*?)\]\)", table_source(syllable_rs, "NORMAL_SYLLABLE_TABLE:"), re.S):
+        initial = INITIAL_LETTERS.get(initial, initial)
+        for final in re.findall(r'"(\w+)"', finals):
+            final = {"iou": "iu", "uei": "ui", "uen": "un"}.get(final, final)
+            if initial in ("j", "q", "x"):
+                final = final.replace("ü", "u")
+            spellings.add(initial + final)
+
+    # 整体认读音节
+    for chars in re.findall(r"\(\[(.*?)\], '.'\)", table_source(syllable_rs, "PRIMITIVE_SYLLABLE_TABLE:")):
+        spellings.add("".join(re.findall(r"'(\S)'", chars)))
+
+    # 零声母音节 ( 补写规则与 `RhymeSyllable::spelling` 相同，`ong` 没有零声母的形式 )
+    for chars in re.findall(r"\[('.*?)\]", table_source(rhyme_rs, "RHYME_TABLE:")):
+        rhyme = "".join(re.findall(r"'(\S)'", chars))
+        if rhyme == "ong":
+            continue
+        elif rhyme in ("i", "in", "ing"):
+            rhyme = "y" + rhyme
+        elif rhyme == "u":
+            rhyme = "wu"
+        elif rhyme.startswith("ü"):
+            rhyme = "yu" + rhyme[1:]
+        elif rhyme.startswith("i"):
+            rhyme = "y" + rhyme[1:]
+        elif rhyme.startswith("u"):
+            rhyme = "w" + rhyme[1:]
+        spellings.add(rhyme)
+
+    return spellings
+
+
+def mark_tone(spelling, tone):
+    """按照 `tone_mark_position` 的规则标注调号: 有 `a` 标 `a`，没 `a` 找 `o`、`e`，`i`、`u` 并列标在后"""
+    if tone == 0:
+        return spelling
+
+    for vowel in ("a", "o", "e", "ê"):
+        if vowel in spelling:
+            position = spelling.index(vowel)
+            break
+    else:
+        positions = [i for i, c in enumerate(spelling) if c in "iuü"]
+        position = positions[-1] if positions else len(spelling) - 1
+
+    mark = [m for m, t in TONE_MARKS.items() if t == tone][0]
+    return unicodedata.normalize("NFC", spelling[:position + 1] + mark + spelling[position + 1:])
+
+
+def normalize(reading, spellings):
+    """转换为带调号的规范形式，不是音节的读音返回 `None`"""
+    reading = unicodedata.normalize("NFD", reading.strip().lower())
+
+    tones = [TONE_MARKS[c] for c in reading if c in TONE_MARKS]
+    if len(tones) > 1:
+        return None
+
+    tone = tones[0] if tones else 0
+    spelling = unicodedata.normalize("NFC", "".join(c for c in reading if c not in TONE_MARKS))
+
+    if spelling in NASAL_TONES:
+        if tone not in NASAL_TONES[spelling]:
+            return None
+    elif spelling not in spellings:
+        return None
+
+    return mark_tone(spelling, tone)
+
+
+def read_unihan(path):
+    """`kMandarin` 为首选读音，`kHanyuPinyin` 补充其它读音"""
+    mandarin = {}
+    hanyu_pinyin = {}
+
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            if line.startswith("#") or not line.strip():
+                continue
+
+            code, field, value = line.rstrip("\n").split("\t", 2)
+            char = chr(int(code[2:], 16))
+
+            if field == "kMandarin":
+                mandarin[char] = value.split()
+            elif field == "kHanyuPinyin":
+                # 10019.020:tiàn 74801.090:tiàn,tián
+                hanyu_pinyin[char] = [r for item in value.split() for r in item.split(":", 1)[1].split(",")]
+
+    result = {}
+    for char in set(mandarin) | set(hanyu_pinyin):
+        result[char] = mandarin.get(char, []) + hanyu_pinyin.get(char, [])
+
+    return result, "Unihan 数据库的 `kMandarin` 与 `kHanyuPinyin` 字段"
+
+
+def read_icu():
+    """每个汉字单独转写，得到 CLDR 的首选读音"""
+    chars = [chr(code) for start, end in HANZI_RANGES for code in range(start, end + 1)]
+    output = subprocess.run(["uconv", "-x", "Han-Latin"],
+                            input="\n".join(chars) + "\n",
+                            capture_output=True, text=True, check=True).stdout.split("\n")
+
+    result = {}
+    for char, reading in zip(chars, output):
+        if reading != char:
+            result[char] = [reading]
+
+    return result, "ICU 的 `Han-Latin` 转写规则 ( CLDR 根据 Unihan 数据库的 `kMandarin` 字段生成 )"
+
+
+def read_table(text):
+    start = text.index(TABLE_START)
+    end = text.index(TABLE_END, start) + len(TABLE_END)
+
+    table = {}
+    for line in text[start:end].split("\n"):
+        m = ENTRY_RE.match(line)
+        if m:
+            table[m.group(1)] = m.group(2).split(",")
+
+    return table, text[:start], text[end:]
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument("--unihan", metavar="Unihan_Readings.txt")
+    parser.add_argument("--icu", action="store_true")
+    args = parser.parse_args()
+
+    if not args.unihan and not args.icu:
+        parser.error("需要指定 --unihan 或者 --icu")
+
+    sources = []
+    if args.unihan:
+        sources.append(read_unihan(args.unihan))
+    if args.icu:
+        sources.append(read_icu())
+
+    readings = {}
+    for values, _description in sources:
+        for char, reading in values.items():
+            readings.setdefault(char, []).extend(reading)
+    description = "，".join(description for _values, description in sources)
+
+    spellings = read_spellings()
+
+    with open(HANZI_RS, encoding="utf-8") as f:
+        table, head, tail = read_table(f.read())
+
+    for char, values in readings.items():
+        if not is_hanzi(ord(char)):
+            continue
+
+        entry = table.setdefault(char, [])
+        for value in values:
+            value = normalize(value, spellings)
+            if value is not None and value not in entry:
+                entry.append(value)
+
+    entries = sorted((char, values) for char, values in table.items() if values)
+
+    lines = [
+        TABLE_START,
+        "//",
+        "// 由 `tools/gen_hanzi.py` 生成。可以手动补充多音字读音，重新生成的时候会被保留。",
+        "// 数据来源: " + description + "，",
+        "// 以及表格原有的多音字读音。",
+        "// 不在音节表当中的读音 ( 例如 `哟`、`唷` 的 `yo`，叹词 `hm`、`ng` ) 无法表示，生成的时候被跳过。",
+        "// 每个汉字的多个读音以 `,` 分隔，第一个读音为最常用读音。",
+        "//",
+        "// NOTE: 该表按照码位排序，以便使用二分查找。",
+        "pub static PINYIN_MAP: [(char, &str); %d] = [" % len(entries),
+    ]
+    lines.extend("    ('%s', \"%s\")," % (char, ",".join(values)) for char, values in entries)
+
+    with open(HANZI_RS, "w", encoding="utf-8") as f:
+        f.write(head + "\n".join(lines) + "\n" + TABLE_END + tail)
+
+    print("%d characters" % len(entries), file=sys.stderr)
+
+
+if __name__ == "__main__":
+    main()